@@ -0,0 +1,5 @@
+-- Customer account API: list a user's orders newest-first
+
+CREATE INDEX IF NOT EXISTS orders_tenant_user_created_idx
+    ON orders (tenant_id, user_id, created_at DESC)
+    WHERE user_id IS NOT NULL;
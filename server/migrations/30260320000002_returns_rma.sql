@@ -0,0 +1,7 @@
+-- Return merchandise authorization: restocking fees, refund amounts and refund linkage
+
+ALTER TABLE returns ADD COLUMN IF NOT EXISTS restocking_fee BIGINT;
+ALTER TABLE returns ADD COLUMN IF NOT EXISTS refund_amount BIGINT;
+ALTER TABLE returns ADD COLUMN IF NOT EXISTS refund_method TEXT;
+ALTER TABLE returns ADD COLUMN IF NOT EXISTS refund_id TEXT;
+ALTER TABLE returns ADD COLUMN IF NOT EXISTS restocked_at TIMESTAMPTZ;
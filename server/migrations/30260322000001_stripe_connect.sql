@@ -0,0 +1,30 @@
+-- Stripe Connect: per-tenant connected accounts and collected application fees
+
+CREATE TABLE IF NOT EXISTS stripe_connect_accounts (
+    tenant_id TEXT NOT NULL PRIMARY KEY,
+    account_id TEXT NOT NULL,
+    charges_enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    payouts_enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    details_submitted BOOLEAN NOT NULL DEFAULT FALSE,
+    application_fee_bps INTEGER,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_stripe_connect_accounts_account_id
+    ON stripe_connect_accounts(account_id);
+
+CREATE TABLE IF NOT EXISTS stripe_application_fees (
+    id TEXT NOT NULL,
+    tenant_id TEXT NOT NULL,
+    account_id TEXT NOT NULL,
+    charge_id TEXT,
+    amount BIGINT NOT NULL,
+    amount_refunded BIGINT NOT NULL DEFAULT 0,
+    currency TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_stripe_application_fees_tenant_created_at
+    ON stripe_application_fees(tenant_id, created_at);
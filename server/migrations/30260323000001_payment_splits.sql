@@ -0,0 +1,4 @@
+-- x402 revenue splits: optional platform fee / seller / affiliate split declared
+-- on a product or, as a default for member products, on a collection.
+ALTER TABLE products ADD COLUMN IF NOT EXISTS payment_split JSONB;
+ALTER TABLE collections ADD COLUMN IF NOT EXISTS payment_split JSONB;
@@ -0,0 +1,41 @@
+-- Affiliate referral codes and append-only commission ledger
+
+CREATE TABLE IF NOT EXISTS affiliates (
+    id TEXT NOT NULL,
+    tenant_id TEXT NOT NULL,
+    code TEXT NOT NULL,
+    name TEXT NOT NULL,
+    email TEXT,
+    payout_wallet TEXT,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    commission_type TEXT NOT NULL DEFAULT 'percentage',
+    commission_value BIGINT NOT NULL DEFAULT 0,
+    rules JSONB NOT NULL DEFAULT '[]'::jsonb,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, id)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_affiliates_tenant_code
+    ON affiliates(tenant_id, code);
+
+CREATE TABLE IF NOT EXISTS affiliate_commissions (
+    id TEXT NOT NULL,
+    tenant_id TEXT NOT NULL,
+    affiliate_id TEXT NOT NULL,
+    order_id TEXT NOT NULL,
+    purchase_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    amount BIGINT NOT NULL,
+    order_amount BIGINT NOT NULL,
+    currency TEXT NOT NULL,
+    refund_id TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_affiliate_commissions_tenant_created_at
+    ON affiliate_commissions(tenant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS idx_affiliate_commissions_tenant_purchase
+    ON affiliate_commissions(tenant_id, purchase_id);
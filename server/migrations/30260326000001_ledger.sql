@@ -0,0 +1,20 @@
+-- Append-only double-entry journal
+
+CREATE TABLE IF NOT EXISTS ledger_entries (
+    id TEXT NOT NULL,
+    tenant_id TEXT NOT NULL,
+    source TEXT NOT NULL,
+    source_id TEXT NOT NULL,
+    currency TEXT NOT NULL,
+    description TEXT,
+    lines JSONB NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_ledger_entries_tenant_occurred_at
+    ON ledger_entries(tenant_id, occurred_at);
+
+CREATE INDEX IF NOT EXISTS idx_ledger_entries_tenant_source
+    ON ledger_entries(tenant_id, source, source_id);
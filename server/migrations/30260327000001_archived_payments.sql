@@ -0,0 +1,12 @@
+-- Index of payments moved to cold storage, for lookup by signature
+
+CREATE TABLE IF NOT EXISTS archived_payments (
+    tenant_id TEXT NOT NULL,
+    signature TEXT NOT NULL,
+    object_key TEXT NOT NULL,
+    archived_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (tenant_id, signature)
+);
+
+CREATE INDEX IF NOT EXISTS idx_orders_tenant_last_updated
+    ON orders(tenant_id, (COALESCE(updated_at, created_at)));
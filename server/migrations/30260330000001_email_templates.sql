@@ -0,0 +1,12 @@
+-- Tenant overrides for transactional email templates, per kind and locale
+
+CREATE TABLE IF NOT EXISTS email_templates (
+    tenant_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    locale TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    body_text TEXT NOT NULL,
+    body_html TEXT,
+    updated_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (tenant_id, kind, locale)
+);
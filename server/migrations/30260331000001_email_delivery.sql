@@ -0,0 +1,21 @@
+-- Delivery status per queued email and the per-tenant suppression list fed
+-- by bounce/complaint webhooks
+
+ALTER TABLE email_queue ADD COLUMN IF NOT EXISTS provider TEXT;
+ALTER TABLE email_queue ADD COLUMN IF NOT EXISTS provider_message_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_email_queue_provider_message
+    ON email_queue(tenant_id, provider_message_id)
+    WHERE provider_message_id IS NOT NULL;
+
+CREATE TABLE IF NOT EXISTS email_suppressions (
+    tenant_id TEXT NOT NULL,
+    email TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    detail TEXT,
+    created_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (tenant_id, email)
+);
+
+CREATE INDEX IF NOT EXISTS idx_email_suppressions_created
+    ON email_suppressions(tenant_id, created_at DESC);
@@ -0,0 +1,8 @@
+-- Human handoff for chat sessions: sessions can wait for / be claimed by a
+-- staff member, whose replies are stored as role 'agent' messages
+
+ALTER TABLE chat_sessions ADD COLUMN IF NOT EXISTS assigned_agent TEXT;
+ALTER TABLE chat_sessions ADD COLUMN IF NOT EXISTS handoff_reason TEXT;
+ALTER TABLE chat_sessions ADD COLUMN IF NOT EXISTS handoff_requested_at TIMESTAMPTZ;
+
+ALTER TABLE chat_messages ADD COLUMN IF NOT EXISTS author_id TEXT;
@@ -0,0 +1,21 @@
+-- Background bulk product import jobs, polled by the admin for progress.
+-- Errors hold the (capped) per-product error report.
+
+CREATE TABLE IF NOT EXISTS product_import_jobs (
+    id TEXT NOT NULL,
+    tenant_id TEXT NOT NULL,
+    format TEXT NOT NULL,
+    dry_run BOOLEAN NOT NULL DEFAULT FALSE,
+    status TEXT NOT NULL,
+    total_count BIGINT NOT NULL DEFAULT 0,
+    processed_count BIGINT NOT NULL DEFAULT 0,
+    created_count BIGINT NOT NULL DEFAULT 0,
+    updated_count BIGINT NOT NULL DEFAULT 0,
+    failed_count BIGINT NOT NULL DEFAULT 0,
+    errors JSONB NOT NULL DEFAULT '[]'::jsonb,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL,
+    completed_at TIMESTAMPTZ,
+    PRIMARY KEY (tenant_id, id)
+);
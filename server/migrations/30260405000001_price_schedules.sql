@@ -0,0 +1,33 @@
+-- Scheduled price changes and time-boxed sales. The price schedule worker
+-- applies a schedule at starts_at and restores the prices it replaced
+-- (kept in `original`) at ends_at.
+
+CREATE TABLE IF NOT EXISTS price_schedules (
+    id TEXT NOT NULL,
+    tenant_id TEXT NOT NULL,
+    product_id TEXT NOT NULL,
+    variant_id TEXT,
+    fiat_price JSONB,
+    crypto_price JSONB,
+    variant_price JSONB,
+    show_as_sale BOOLEAN NOT NULL DEFAULT FALSE,
+    starts_at TIMESTAMPTZ NOT NULL,
+    ends_at TIMESTAMPTZ,
+    status TEXT NOT NULL,
+    original JSONB,
+    stripe_price_id TEXT,
+    note TEXT,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL,
+    applied_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ,
+    PRIMARY KEY (tenant_id, id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_price_schedules_product
+    ON price_schedules (tenant_id, product_id);
+
+CREATE INDEX IF NOT EXISTS idx_price_schedules_open
+    ON price_schedules (starts_at)
+    WHERE status IN ('scheduled', 'active');
@@ -0,0 +1,38 @@
+-- Add tenant_id column to all tables for multi-tenant isolation
+-- Per spec (10-middleware.md): All database queries must include tenant filter
+--
+-- Tables whose primary key includes tenant_id (payment_transactions,
+-- cart_quotes, refund_quotes, admin_nonces, subscriptions, products, coupons)
+-- already have the column from the initial schema.
+
+-- Payment transactions
+CREATE INDEX IF NOT EXISTS idx_payment_transactions_tenant ON payment_transactions(tenant_id);
+CREATE INDEX IF NOT EXISTS idx_payment_transactions_tenant_resource ON payment_transactions(tenant_id, resource_id);
+
+-- Cart quotes
+CREATE INDEX IF NOT EXISTS idx_cart_quotes_tenant ON cart_quotes(tenant_id);
+
+-- Refund quotes
+CREATE INDEX IF NOT EXISTS idx_refund_quotes_tenant ON refund_quotes(tenant_id);
+
+-- Admin nonces
+CREATE INDEX IF NOT EXISTS idx_admin_nonces_tenant ON admin_nonces(tenant_id);
+
+-- Webhook queue
+ALTER TABLE webhook_queue ADD COLUMN tenant_id TEXT NOT NULL DEFAULT 'default';
+CREATE INDEX IF NOT EXISTS idx_webhook_queue_tenant ON webhook_queue(tenant_id);
+
+-- Subscriptions
+CREATE INDEX IF NOT EXISTS idx_subscriptions_tenant ON subscriptions(tenant_id);
+CREATE INDEX IF NOT EXISTS idx_subscriptions_tenant_wallet ON subscriptions(tenant_id, wallet);
+CREATE INDEX IF NOT EXISTS idx_subscriptions_tenant_product ON subscriptions(tenant_id, product_id);
+
+-- Webhook DLQ
+ALTER TABLE webhook_dlq ADD COLUMN tenant_id TEXT NOT NULL DEFAULT 'default';
+CREATE INDEX IF NOT EXISTS idx_webhook_dlq_tenant ON webhook_dlq(tenant_id);
+
+-- Products
+CREATE INDEX IF NOT EXISTS idx_products_tenant ON products(tenant_id);
+
+-- Coupons
+CREATE INDEX IF NOT EXISTS idx_coupons_tenant ON coupons(tenant_id);
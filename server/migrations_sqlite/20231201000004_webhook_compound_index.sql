@@ -0,0 +1,8 @@
+-- Add compound index for webhook_queue queries
+-- Queries typically filter by status AND next_attempt_at together
+
+-- Compound index for pending webhook polling queries
+-- Covers: WHERE status = 'pending' AND next_attempt_at <= now
+CREATE INDEX IF NOT EXISTS idx_webhook_queue_status_next_attempt
+    ON webhook_queue(status, next_attempt_at)
+    WHERE status = 'pending';
@@ -0,0 +1,11 @@
+-- Add index for webhook queue DEQUEUE query
+
+-- Partial index for pending webhooks ordered by creation time
+CREATE INDEX IF NOT EXISTS idx_webhook_queue_pending_dequeue
+    ON webhook_queue(created_at ASC)
+    WHERE status = 'pending';
+
+-- Additional index for scheduled retries (next_attempt_at is set)
+CREATE INDEX IF NOT EXISTS idx_webhook_queue_pending_next_attempt
+    ON webhook_queue(next_attempt_at ASC, created_at ASC)
+    WHERE status = 'pending' AND next_attempt_at IS NOT NULL;
@@ -0,0 +1,10 @@
+-- Add compound index for refund queries
+-- Foreign keys are intentionally not declared (see the PostgreSQL migration).
+
+-- Compound index for refund lookups (used in get_refund_by_original_purchase_id)
+CREATE INDEX IF NOT EXISTS idx_refund_quotes_tenant_original
+    ON refund_quotes(tenant_id, original_purchase_id);
+
+-- Compound index for subscription product lookups
+CREATE INDEX IF NOT EXISTS idx_subscriptions_tenant_product_status
+    ON subscriptions(tenant_id, product_id, status);
@@ -0,0 +1,4 @@
+-- Composite primary keys for tenant-scoped tables.
+-- cart_quotes, refund_quotes, admin_nonces and subscriptions are created with
+-- PRIMARY KEY (tenant_id, id) in the initial schema; nothing to change here.
+SELECT 1;
@@ -0,0 +1,4 @@
+-- Composite primary keys for products and coupons.
+-- Both tables are created with tenant-scoped primary keys in the initial
+-- schema: products (tenant_id, id) and coupons (tenant_id, code).
+SELECT 1;
@@ -0,0 +1,8 @@
+-- PERF-001: Add created_at to payment access check index
+CREATE INDEX IF NOT EXISTS idx_payment_transactions_access_check
+    ON payment_transactions(tenant_id, resource_id, wallet, created_at DESC);
+
+-- PERF-002: Optimized subscription lookup by wallet + product
+CREATE INDEX IF NOT EXISTS idx_subscriptions_wallet_product_status
+    ON subscriptions(tenant_id, wallet, product_id, status)
+    WHERE status IN ('active', 'trialing', 'past_due');
@@ -0,0 +1,6 @@
+-- Add raw payload bytes for webhook signature stability
+-- Allows delivering exactly the bytes used for signature generation.
+
+ALTER TABLE webhook_queue ADD COLUMN payload_bytes BLOB;
+
+ALTER TABLE webhook_dlq ADD COLUMN payload_bytes BLOB;
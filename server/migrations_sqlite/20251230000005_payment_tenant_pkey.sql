@@ -0,0 +1,3 @@
+-- Tenant-scoped primary key for payment_transactions.
+-- The initial schema already declares PRIMARY KEY (tenant_id, signature).
+SELECT 1;
@@ -0,0 +1,93 @@
+-- Config storage tables for database-based configuration management
+-- Supports encrypted secrets via envelope encryption (KEK in env, DEK in DB)
+--
+-- SQLite has no LISTEN/NOTIFY, so the change-notification trigger from the
+-- PostgreSQL migration is omitted; the timestamp and audit triggers are kept.
+
+-- Config entries with optional encryption for secrets
+CREATE TABLE IF NOT EXISTS app_config (
+    tenant_id TEXT NOT NULL,
+    config_key TEXT NOT NULL,
+    value TEXT NOT NULL,
+    encrypted BOOLEAN NOT NULL DEFAULT FALSE,
+    key_version INTEGER,
+    category TEXT NOT NULL,
+    description TEXT,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+    updated_by TEXT,
+    PRIMARY KEY (tenant_id, category, config_key)
+);
+
+-- Index for listing configs by category
+CREATE INDEX IF NOT EXISTS idx_app_config_category ON app_config (tenant_id, category);
+
+-- Audit trail for config changes
+CREATE TABLE IF NOT EXISTS app_config_audit (
+    id TEXT PRIMARY KEY DEFAULT (lower(hex(randomblob(16)))),
+    tenant_id TEXT NOT NULL,
+    category TEXT NOT NULL,
+    config_key TEXT NOT NULL,
+    action TEXT NOT NULL,
+    old_value TEXT,
+    new_value TEXT,
+    changed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+    changed_by TEXT
+);
+
+-- Index for querying audit history
+CREATE INDEX IF NOT EXISTS idx_app_config_audit_tenant_key ON app_config_audit (tenant_id, category, config_key, changed_at DESC);
+CREATE INDEX IF NOT EXISTS idx_app_config_audit_changed_at ON app_config_audit (changed_at DESC);
+
+-- Encryption keys table (envelope encryption)
+-- DEKs are encrypted by KEK (from env var) and stored here
+CREATE TABLE IF NOT EXISTS encryption_keys (
+    tenant_id TEXT NOT NULL,
+    key_version INTEGER NOT NULL,
+    encrypted_dek BLOB NOT NULL,
+    algorithm TEXT NOT NULL DEFAULT 'AES-256-GCM',
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    PRIMARY KEY (tenant_id, key_version)
+);
+
+-- Index for finding active keys
+CREATE INDEX IF NOT EXISTS idx_encryption_keys_active ON encryption_keys (tenant_id, active) WHERE active = TRUE;
+
+-- Auto-update updated_at unless the statement set it explicitly
+DROP TRIGGER IF EXISTS app_config_updated_at_trigger;
+CREATE TRIGGER app_config_updated_at_trigger
+    AFTER UPDATE ON app_config
+    FOR EACH ROW WHEN NEW.updated_at = OLD.updated_at
+BEGIN
+    UPDATE app_config SET updated_at = (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'))
+    WHERE tenant_id = NEW.tenant_id AND category = NEW.category AND config_key = NEW.config_key;
+END;
+
+-- Audit trail
+DROP TRIGGER IF EXISTS app_config_audit_insert_trigger;
+CREATE TRIGGER app_config_audit_insert_trigger
+    AFTER INSERT ON app_config
+    FOR EACH ROW
+BEGIN
+    INSERT INTO app_config_audit (tenant_id, category, config_key, action, old_value, new_value, changed_by)
+    VALUES (NEW.tenant_id, NEW.category, NEW.config_key, 'INSERT', NULL, NEW.value, NEW.updated_by);
+END;
+
+DROP TRIGGER IF EXISTS app_config_audit_update_trigger;
+CREATE TRIGGER app_config_audit_update_trigger
+    AFTER UPDATE OF value ON app_config
+    FOR EACH ROW WHEN OLD.value IS NOT NEW.value
+BEGIN
+    INSERT INTO app_config_audit (tenant_id, category, config_key, action, old_value, new_value, changed_by)
+    VALUES (NEW.tenant_id, NEW.category, NEW.config_key, 'UPDATE', OLD.value, NEW.value, NEW.updated_by);
+END;
+
+DROP TRIGGER IF EXISTS app_config_audit_delete_trigger;
+CREATE TRIGGER app_config_audit_delete_trigger
+    AFTER DELETE ON app_config
+    FOR EACH ROW
+BEGIN
+    INSERT INTO app_config_audit (tenant_id, category, config_key, action, old_value, new_value, changed_by)
+    VALUES (OLD.tenant_id, OLD.category, OLD.config_key, 'DELETE', OLD.value, NULL, NULL);
+END;
@@ -0,0 +1,5 @@
+-- Rename amount columns to match code expectations (_atomic suffix)
+-- The code uses fiat_amount_atomic/crypto_amount_atomic but schema has fiat_amount/crypto_amount
+
+ALTER TABLE products RENAME COLUMN fiat_amount TO fiat_amount_atomic;
+ALTER TABLE products RENAME COLUMN crypto_amount TO crypto_amount_atomic;
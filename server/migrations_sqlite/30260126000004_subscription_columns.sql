@@ -0,0 +1,21 @@
+-- Add individual subscription columns (code expects these instead of JSON subscription_config)
+
+ALTER TABLE products ADD COLUMN subscription_billing_period TEXT;
+ALTER TABLE products ADD COLUMN subscription_billing_interval INTEGER;
+ALTER TABLE products ADD COLUMN subscription_trial_days INTEGER;
+ALTER TABLE products ADD COLUMN subscription_stripe_price_id TEXT;
+ALTER TABLE products ADD COLUMN subscription_allow_x402 BOOLEAN;
+ALTER TABLE products ADD COLUMN subscription_grace_period_hours INTEGER;
+
+-- Migrate data from subscription_config JSON to individual columns (if any data exists)
+UPDATE products SET
+    subscription_billing_period = json_extract(subscription_config, '$.billing_period'),
+    subscription_billing_interval = CAST(json_extract(subscription_config, '$.billing_interval') AS INTEGER),
+    subscription_trial_days = CAST(json_extract(subscription_config, '$.trial_days') AS INTEGER),
+    subscription_stripe_price_id = json_extract(subscription_config, '$.stripe_price_id'),
+    subscription_allow_x402 = json_extract(subscription_config, '$.allow_x402'),
+    subscription_grace_period_hours = CAST(json_extract(subscription_config, '$.grace_period_hours') AS INTEGER)
+WHERE subscription_config IS NOT NULL;
+
+-- Drop the old JSON column
+ALTER TABLE products DROP COLUMN subscription_config;
@@ -0,0 +1,24 @@
+-- Product display/catalog fields for ecommerce templates
+-- Kept optional/defaulted to preserve backwards compatibility.
+
+ALTER TABLE products ADD COLUMN title TEXT;
+ALTER TABLE products ADD COLUMN short_description TEXT;
+ALTER TABLE products ADD COLUMN slug TEXT;
+
+ALTER TABLE products ADD COLUMN tags TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE products ADD COLUMN category_ids TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE products ADD COLUMN images TEXT NOT NULL DEFAULT '[]';
+
+ALTER TABLE products ADD COLUMN featured BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE products ADD COLUMN sort_order INTEGER;
+
+ALTER TABLE products ADD COLUMN compare_at_fiat_amount_atomic INTEGER;
+ALTER TABLE products ADD COLUMN compare_at_fiat_currency TEXT;
+
+ALTER TABLE products ADD COLUMN inventory_status TEXT;
+ALTER TABLE products ADD COLUMN variants TEXT NOT NULL DEFAULT '[]';
+
+-- Optional indexes to support common catalogue queries
+CREATE INDEX IF NOT EXISTS idx_products_tenant_sort_order ON products(tenant_id, sort_order);
+CREATE INDEX IF NOT EXISTS idx_products_tenant_featured ON products(tenant_id, featured);
+CREATE INDEX IF NOT EXISTS idx_products_tenant_slug ON products(tenant_id, slug);
@@ -0,0 +1,9 @@
+-- Upgrade config storage to namespace keys by category.
+--
+-- Fixes CFG-001 for PostgreSQL installs. The SQLite schema was created with
+-- app_config keyed on (tenant_id, category, config_key), a NOT NULL audit
+-- category, and category-aware audit triggers, so only the index is refreshed.
+
+DROP INDEX IF EXISTS idx_app_config_audit_tenant_key;
+CREATE INDEX IF NOT EXISTS idx_app_config_audit_tenant_key
+    ON app_config_audit (tenant_id, category, config_key, changed_at DESC);
@@ -0,0 +1,5 @@
+-- Ecommerce checkout requirements per product
+
+ALTER TABLE products ADD COLUMN shipping_profile TEXT;
+ALTER TABLE products ADD COLUMN checkout_requirements TEXT;
+ALTER TABLE products ADD COLUMN fulfillment TEXT;
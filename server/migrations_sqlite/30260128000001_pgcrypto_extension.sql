@@ -0,0 +1,3 @@
+-- PostgreSQL-only: enables pgcrypto for gen_random_uuid().
+-- SQLite defaults use randomblob() instead; nothing to do.
+SELECT 1;
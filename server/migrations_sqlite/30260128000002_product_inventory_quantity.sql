@@ -0,0 +1,6 @@
+-- Add optional tracked inventory quantity to products
+
+ALTER TABLE products
+    ADD COLUMN inventory_quantity INTEGER
+    CONSTRAINT products_inventory_quantity_nonnegative
+    CHECK (inventory_quantity IS NULL OR inventory_quantity >= 0);
@@ -0,0 +1,16 @@
+-- Phase 2: inventory adjustment ledger
+
+CREATE TABLE IF NOT EXISTS inventory_adjustments (
+    id TEXT PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    product_id TEXT NOT NULL,
+    delta INTEGER NOT NULL,
+    quantity_before INTEGER NOT NULL,
+    quantity_after INTEGER NOT NULL,
+    reason TEXT,
+    actor TEXT,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS inventory_adjustments_tenant_product_idx
+    ON inventory_adjustments (tenant_id, product_id, created_at DESC);
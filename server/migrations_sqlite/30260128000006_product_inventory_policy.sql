@@ -0,0 +1,4 @@
+-- Add inventory policy to products (deny|allow_backorder)
+
+ALTER TABLE products
+    ADD COLUMN inventory_policy TEXT;
@@ -0,0 +1,23 @@
+-- Returns (Phase 5)
+
+CREATE TABLE IF NOT EXISTS returns (
+    id TEXT PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    order_id TEXT NOT NULL,
+    status TEXT NOT NULL,
+    items TEXT NOT NULL,
+    reason TEXT,
+    metadata TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT,
+    status_updated_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS returns_tenant_created_idx
+    ON returns (tenant_id, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS returns_tenant_status_idx
+    ON returns (tenant_id, status);
+
+CREATE INDEX IF NOT EXISTS returns_tenant_order_idx
+    ON returns (tenant_id, order_id);
@@ -0,0 +1,23 @@
+-- Gift cards (Phase 8)
+-- Keyed on (tenant_id, code) from the start; SQLite cannot change the primary
+-- key later (see 30260218000004).
+
+CREATE TABLE IF NOT EXISTS gift_cards (
+    code TEXT NOT NULL,
+    tenant_id TEXT NOT NULL,
+    initial_balance INTEGER NOT NULL,
+    balance INTEGER NOT NULL,
+    currency TEXT NOT NULL,
+    active BOOLEAN NOT NULL,
+    expires_at TEXT,
+    metadata TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (tenant_id, code)
+);
+
+CREATE INDEX IF NOT EXISTS gift_cards_tenant_created_idx
+    ON gift_cards (tenant_id, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS gift_cards_tenant_active_idx
+    ON gift_cards (tenant_id, active);
@@ -0,0 +1,3 @@
+-- Phase 10: product SEO fields
+ALTER TABLE products ADD COLUMN seo_title TEXT;
+ALTER TABLE products ADD COLUMN seo_description TEXT;
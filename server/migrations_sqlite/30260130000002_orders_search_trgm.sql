@@ -0,0 +1,3 @@
+-- PERF-006: PostgreSQL-only trigram indexes for order search.
+-- SQLite has no pg_trgm; order search scans the tenant's orders instead.
+SELECT 1;
@@ -0,0 +1,2 @@
+-- Add variation_config column to products table for storing variation type definitions
+ALTER TABLE products ADD COLUMN variation_config TEXT;
@@ -0,0 +1,17 @@
+-- FAQs table for knowledge base entries
+-- keywords is a JSON array of strings.
+CREATE TABLE IF NOT EXISTS faqs (
+    id TEXT NOT NULL,
+    tenant_id TEXT NOT NULL,
+    question TEXT NOT NULL,
+    answer TEXT NOT NULL,
+    keywords TEXT NOT NULL DEFAULT '[]',
+    active BOOLEAN NOT NULL DEFAULT true,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (tenant_id, id)
+);
+
+-- Index for listing active FAQs by tenant
+CREATE INDEX IF NOT EXISTS faqs_tenant_active_idx
+    ON faqs (tenant_id, active, updated_at DESC);
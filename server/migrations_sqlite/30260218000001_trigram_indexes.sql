@@ -0,0 +1,3 @@
+-- RS-HIGH-6: PostgreSQL-only pg_trgm indexes for ILIKE search on orders.
+-- Not applicable to SQLite.
+SELECT 1;
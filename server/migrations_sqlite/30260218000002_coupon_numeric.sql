@@ -0,0 +1,4 @@
+-- DB-FLOAT: PostgreSQL moves coupons.discount_value to NUMERIC(18,6).
+-- SQLite stores the value with REAL affinity and has no fixed-point type;
+-- the column is left as declared.
+SELECT 1;
@@ -0,0 +1,3 @@
+-- DB-NULL-TS: subscriptions.created_at and updated_at are declared NOT NULL
+-- in the SQLite initial schema.
+SELECT 1;
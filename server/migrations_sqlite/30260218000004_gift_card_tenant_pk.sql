@@ -0,0 +1,3 @@
+-- DB-GIFT-PK: gift_cards is created with PRIMARY KEY (tenant_id, code) in
+-- 30260128000012 for SQLite.
+SELECT 1;
@@ -0,0 +1,36 @@
+-- Gift card product support: adds gift_card_config to products,
+-- redemption tracking, and tenant Token-22 mint configuration.
+
+ALTER TABLE products ADD COLUMN gift_card_config TEXT;
+
+CREATE TABLE IF NOT EXISTS gift_card_redemptions (
+    id TEXT PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    order_id TEXT NOT NULL,
+    product_id TEXT NOT NULL,
+    buyer_user_id TEXT NOT NULL,
+    recipient_user_id TEXT NOT NULL,
+    face_value_cents INTEGER NOT NULL,
+    currency TEXT NOT NULL,
+    credits_issued INTEGER NOT NULL,
+    token_minted BOOLEAN NOT NULL DEFAULT FALSE,
+    token_mint_signature TEXT,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'))
+);
+CREATE INDEX IF NOT EXISTS idx_gc_redemptions_tenant ON gift_card_redemptions (tenant_id, created_at DESC);
+
+CREATE TABLE IF NOT EXISTS tenant_token22_mints (
+    tenant_id TEXT NOT NULL,
+    mint_address TEXT NOT NULL,
+    mint_authority TEXT NOT NULL,
+    transfer_fee_bps INTEGER NOT NULL DEFAULT 0,
+    max_transfer_fee INTEGER NOT NULL DEFAULT 0,
+    treasury_address TEXT NOT NULL,
+    token_symbol TEXT NOT NULL DEFAULT 'storeUSD',
+    token_decimals INTEGER NOT NULL DEFAULT 2,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'))
+);
+-- One mint per tenant. Declared as an index rather than the primary key so
+-- 30260305000002 can widen it to per-collection mints.
+CREATE UNIQUE INDEX IF NOT EXISTS tenant_token22_mints_pkey ON tenant_token22_mints (tenant_id);
@@ -0,0 +1,4 @@
+-- Add compliance_requirements TEXT column to products table.
+-- Stores per-product compliance gates (sanctions, KYC, accredited investor).
+-- NULL means defaults apply (sanctions check only).
+ALTER TABLE products ADD COLUMN compliance_requirements TEXT;
@@ -0,0 +1,7 @@
+-- Return merchandise authorization: restocking fees, refund amounts and refund linkage
+
+ALTER TABLE returns ADD COLUMN restocking_fee INTEGER;
+ALTER TABLE returns ADD COLUMN refund_amount INTEGER;
+ALTER TABLE returns ADD COLUMN refund_method TEXT;
+ALTER TABLE returns ADD COLUMN refund_id TEXT;
+ALTER TABLE returns ADD COLUMN restocked_at TEXT;
@@ -0,0 +1,4 @@
+-- x402 revenue splits: optional platform fee / seller / affiliate split declared
+-- on a product or, as a default for member products, on a collection.
+ALTER TABLE products ADD COLUMN payment_split TEXT;
+ALTER TABLE collections ADD COLUMN payment_split TEXT;
@@ -0,0 +1,4 @@
+-- W3C traceparent of the request that queued the email, so background
+-- delivery is linked to the originating trace
+
+ALTER TABLE email_queue ADD COLUMN traceparent TEXT;
@@ -0,0 +1,20 @@
+-- Append-only double-entry journal
+
+CREATE TABLE IF NOT EXISTS ledger_entries (
+    id TEXT NOT NULL,
+    tenant_id TEXT NOT NULL,
+    source TEXT NOT NULL,
+    source_id TEXT NOT NULL,
+    currency TEXT NOT NULL,
+    description TEXT,
+    lines TEXT NOT NULL,
+    occurred_at TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')),
+    PRIMARY KEY (tenant_id, id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_ledger_entries_tenant_occurred_at
+    ON ledger_entries(tenant_id, occurred_at);
+
+CREATE INDEX IF NOT EXISTS idx_ledger_entries_tenant_source
+    ON ledger_entries(tenant_id, source, source_id);
@@ -0,0 +1,10 @@
+-- Track which KEK wrapped each DEK, and when a DEK was rotated out
+
+ALTER TABLE encryption_keys
+    ADD COLUMN kek_version INTEGER NOT NULL DEFAULT 1;
+
+ALTER TABLE encryption_keys
+    ADD COLUMN rotated_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_encryption_keys_kek_version
+    ON encryption_keys (kek_version);
@@ -0,0 +1,14 @@
+-- Durable log of emitted notifications, readable by cursor and replayable
+
+CREATE TABLE IF NOT EXISTS event_log (
+    sequence INTEGER PRIMARY KEY AUTOINCREMENT,
+    tenant_id TEXT NOT NULL,
+    event_id TEXT NOT NULL,
+    event_type TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    UNIQUE (tenant_id, event_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_event_log_tenant_sequence ON event_log(tenant_id, sequence);
+CREATE INDEX IF NOT EXISTS idx_event_log_created_at ON event_log(created_at);
@@ -0,0 +1,8 @@
+-- Human handoff for chat sessions: sessions can wait for / be claimed by a
+-- staff member, whose replies are stored as role 'agent' messages
+
+ALTER TABLE chat_sessions ADD COLUMN assigned_agent TEXT;
+ALTER TABLE chat_sessions ADD COLUMN handoff_reason TEXT;
+ALTER TABLE chat_sessions ADD COLUMN handoff_requested_at TEXT;
+
+ALTER TABLE chat_messages ADD COLUMN author_id TEXT;
@@ -0,0 +1,34 @@
+-- Embeddings of products, variants and FAQs for semantic search, and the
+-- queue of sources waiting to be (re-)embedded by the embedding worker.
+-- Vectors are little-endian f32 blobs ranked by the server.
+
+CREATE TABLE IF NOT EXISTS embeddings (
+    tenant_id TEXT NOT NULL,
+    entity_type TEXT NOT NULL,
+    entity_id TEXT NOT NULL,
+    source_id TEXT NOT NULL,
+    model TEXT NOT NULL,
+    content_hash TEXT NOT NULL,
+    dimensions INTEGER NOT NULL,
+    embedding BLOB NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (tenant_id, entity_type, entity_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_embeddings_source
+    ON embeddings(tenant_id, source_id);
+
+CREATE INDEX IF NOT EXISTS idx_embeddings_model
+    ON embeddings(tenant_id, model, dimensions);
+
+CREATE TABLE IF NOT EXISTS embedding_jobs (
+    tenant_id TEXT NOT NULL,
+    source_type TEXT NOT NULL,
+    source_id TEXT NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    available_at TEXT NOT NULL,
+    PRIMARY KEY (tenant_id, source_type, source_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_embedding_jobs_available
+    ON embedding_jobs(available_at);
@@ -0,0 +1,15 @@
+-- Monthly AI token usage and priced cost per tenant, task and model.
+-- Each AI call adds to the row for its (tenant, month, task, model).
+
+CREATE TABLE IF NOT EXISTS ai_usage (
+    tenant_id TEXT NOT NULL,
+    period TEXT NOT NULL,
+    task TEXT NOT NULL,
+    model TEXT NOT NULL,
+    calls INTEGER NOT NULL DEFAULT 0,
+    input_tokens INTEGER NOT NULL DEFAULT 0,
+    output_tokens INTEGER NOT NULL DEFAULT 0,
+    cost_micros INTEGER NOT NULL DEFAULT 0,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (tenant_id, period, task, model)
+);
@@ -4,16 +4,21 @@ use std::time::Duration;
 use sqlx::PgPool;
 
 use crate::callback::PaymentCallback;
-use crate::config::{Config, CouponSource, PaywallResource, ProductSource};
+use crate::config::{
+    CallbacksConfig, Config, ConfigBus, CouponSource, MessagingConfig, PaywallResource,
+    ProductSource,
+};
 use crate::handlers;
 use crate::middleware;
 use crate::models::{get_asset, Money, Product};
 use crate::repositories::{
-    new_coupon_repository, new_product_repository, CachedProductRepository, CouponBackend,
-    CouponRepository, CouponRepositoryConfig, InMemoryProductRepository, ProductBackend,
-    ProductRepository, ProductRepositoryConfig, RepositoryCacheConfig,
+    new_coupon_repository, new_product_repository, CachedCouponRepository, CachedProductRepository,
+    CouponBackend, CouponRepository, CouponRepositoryConfig, InMemoryProductRepository,
+    ProductBackend, ProductRepository, ProductRepositoryConfig, RepositoryCacheConfig,
+    SqliteCouponRepository, SqliteProductRepository,
 };
-use crate::server::build_postgres_pool;
+use crate::server::{build_postgres_pool, build_sqlite_pool};
+use crate::services::messaging::transport::EmailTransportResolver;
 use crate::services::{
     self, create_messaging_service, BlockhashCache, PaywallService, StripeClient,
     StripeWebhookProcessor, SubscriptionService,
@@ -21,7 +26,7 @@ use crate::services::{
 use crate::storage::Store;
 use crate::webhooks;
 use crate::workers;
-use crate::x402::{SolanaVerifier, Verifier};
+use crate::x402::{ComputeBudget, SolanaVerifier, Verifier};
 use crate::NoopVerifier;
 
 /// Built services for cedros-pay, exposed for advanced library usage.
@@ -39,6 +44,10 @@ pub struct BuiltServices<S: Store> {
     pub stripe_client: Option<Arc<StripeClient>>,
     /// Webhook notifier
     pub notifier: Arc<dyn webhooks::Notifier>,
+    /// Concrete notifier behind `notifier`, used for event log replay
+    pub(crate) http_notifier: Arc<webhooks::HttpNotifier<S>>,
+    /// Transactional email + order webhook service
+    pub(crate) messaging_service: Arc<dyn services::MessagingService>,
     /// Product repository
     pub product_repo: Arc<dyn ProductRepository>,
     /// Coupon repository
@@ -51,6 +60,12 @@ pub struct BuiltServices<S: Store> {
     pub(crate) stripe_webhook_processor: Option<Arc<StripeWebhookProcessor<S>>>,
     pub(crate) health_state: Arc<parking_lot::RwLock<handlers::health::HealthState>>,
     pub(crate) storage_pg_pool: Option<PgPool>,
+    /// Envelope encryption for config secrets (if `CEDROS_CONFIG_KEK` is set) —
+    /// shared so every config repository sees the same active DEKs.
+    pub(crate) config_encryption: Option<Arc<crate::config::ConfigEncryption>>,
+    /// Hot-reloaded secrets (if `secrets.provider` is set). Embedders that
+    /// spawn workers themselves should run a `SecretsRefreshWorker` on it.
+    pub secrets: Option<Arc<crate::config::SecretStore>>,
     /// Cedros-login client for JWT validation (if configured)
     pub(crate) cedros_login_client: Option<Arc<services::CedrosLoginClient>>,
     /// Token-22 service — shared by fulfillment services and admin routes.
@@ -59,10 +74,16 @@ pub struct BuiltServices<S: Store> {
     pub(crate) asset_fulfillment: Option<Arc<services::AssetFulfillmentService>>,
     /// Dynamic sanctions list service — shared by compliance checker and workers.
     pub(crate) sanctions_list_service: Option<Arc<services::SanctionsListService>>,
+    /// Per-tenant email transports — shared by the email worker and the
+    /// bounce/complaint webhook.
+    pub(crate) email_transports: Arc<EmailTransportResolver>,
     /// Email worker handle — kept alive so panics are logged instead of silently lost.
     /// Not read directly; held to keep the worker alive for the server's lifetime.
     #[allow(dead_code)]
     pub(crate) email_worker_handle: Option<workers::EmailWorkerHandle>,
+    /// Config change bus (if services were built from database config).
+    /// Admin config writes publish to it; subscribed services reload in place.
+    pub config_bus: Option<Arc<ConfigBus>>,
 }
 
 /// Build all cedros-pay services from config and storage.
@@ -74,7 +95,7 @@ pub async fn build_services<S: Store + 'static>(
     store: Arc<S>,
     storage_pg_pool: Option<PgPool>,
 ) -> anyhow::Result<BuiltServices<S>> {
-    build_services_internal(cfg, store, storage_pg_pool, None).await
+    build_services_internal(cfg, store, storage_pg_pool, None, None).await
 }
 
 pub async fn build_services_with_callback<S: Store + 'static>(
@@ -83,7 +104,18 @@ pub async fn build_services_with_callback<S: Store + 'static>(
     storage_pg_pool: Option<PgPool>,
     callback: Arc<dyn PaymentCallback>,
 ) -> anyhow::Result<BuiltServices<S>> {
-    build_services_internal(cfg, store, storage_pg_pool, Some(callback)).await
+    build_services_internal(cfg, store, storage_pg_pool, Some(callback), None).await
+}
+
+/// Build services from config loaded with `Config::load_from_db` for
+/// `tenant_id`, with a [`ConfigBus`] so admin config changes are hot-reloaded.
+pub(crate) async fn build_services_with_config_bus<S: Store + 'static>(
+    cfg: &Config,
+    store: Arc<S>,
+    storage_pg_pool: Option<PgPool>,
+    tenant_id: &str,
+) -> anyhow::Result<BuiltServices<S>> {
+    build_services_internal(cfg, store, storage_pg_pool, None, Some(tenant_id)).await
 }
 
 async fn build_services_internal<S: Store + 'static>(
@@ -91,6 +123,7 @@ async fn build_services_internal<S: Store + 'static>(
     store: Arc<S>,
     storage_pg_pool: Option<PgPool>,
     callback: Option<Arc<dyn PaymentCallback>>,
+    config_tenant: Option<&str>,
 ) -> anyhow::Result<BuiltServices<S>> {
     if let Some(ref pool) = storage_pg_pool {
         let mut migrator = sqlx::migrate!("./migrations");
@@ -102,9 +135,35 @@ async fn build_services_internal<S: Store + 'static>(
         tracing::info!("Cedros Pay database migrations applied");
     }
 
+    let config_encryption = match storage_pg_pool {
+        Some(ref pool) => crate::config::ConfigEncryption::from_env_optional(pool.clone())
+            .map_err(|e| anyhow::anyhow!("config encryption: {}", e))?,
+        None => None,
+    };
+
+    // Provider secrets override YAML/env/DB values for every service built below
+    let secrets = crate::config::SecretStore::from_config(
+        &cfg.secrets,
+        storage_pg_pool.clone(),
+        config_encryption.clone(),
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("secret provider: {}", e))?;
+    let cfg_with_secrets;
+    let cfg = match secrets {
+        Some(ref secrets) => {
+            let mut overlaid = cfg.clone();
+            secrets.apply_to_config(&mut overlaid);
+            cfg_with_secrets = overlaid;
+            &cfg_with_secrets
+        }
+        None => cfg,
+    };
+
     let product_repo = build_product_repository(cfg, storage_pg_pool.clone()).await?;
     let coupon_repo = build_coupon_repository(cfg, storage_pg_pool.clone()).await?;
 
+    let mut solana_verifier: Option<Arc<SolanaVerifier>> = None;
     let verifier: Arc<dyn Verifier> = if !cfg.x402.rpc_url.is_empty() {
         match SolanaVerifier::new_with_circuit_breaker(&cfg.x402, &cfg.circuit_breaker.solana_rpc) {
             Ok(mut v) => {
@@ -112,7 +171,9 @@ async fn build_services_internal<S: Store + 'static>(
                 if !cfg.x402.rpc_url.is_empty() {
                     v.setup_ws_confirmation(&cfg.x402.rpc_url);
                 }
-                Arc::new(v)
+                let v = Arc::new(v);
+                solana_verifier = Some(v.clone());
+                v
             }
             Err(e) => {
                 tracing::warn!("Failed to create SolanaVerifier: {}, using NoopVerifier", e);
@@ -124,23 +185,21 @@ async fn build_services_internal<S: Store + 'static>(
         Arc::new(NoopVerifier)
     };
 
-    let notifier: Arc<dyn webhooks::Notifier> =
-        if let Some(url) = cfg.callbacks.payment_success_url.as_ref() {
-            let max_attempts = if cfg.callbacks.retry.enabled {
-                cfg.callbacks.retry.max_attempts.max(1) as i32
-            } else {
-                1
-            };
-            Arc::new(webhooks::HttpNotifier::new_with_headers(
-                store.clone(),
-                url.clone(),
-                cfg.callbacks.hmac_secret.clone(),
-                cfg.callbacks.headers.clone(),
-                max_attempts,
-            ))
-        } else {
-            Arc::new(webhooks::NoopNotifier)
-        };
+    // Built even without a callback URL so events still reach the event log
+    // and a URL set later through the config bus takes effect in place.
+    let http_notifier = Arc::new(
+        webhooks::HttpNotifier::without_url(
+            store.clone(),
+            cfg.callbacks.hmac_secret.clone(),
+            cfg.callbacks.headers.clone(),
+            notifier_max_attempts(&cfg.callbacks),
+        )
+        .with_event_log(cfg.callbacks.event_log.enabled),
+    );
+    if cfg.callbacks.payment_success_url.is_some() {
+        reconfigure_notifier(&http_notifier, &cfg.callbacks);
+    }
+    let notifier: Arc<dyn webhooks::Notifier> = http_notifier.clone();
 
     let cedros_login_client = if cfg.cedros_login.enabled && !cfg.cedros_login.base_url.is_empty() {
         match services::CedrosLoginClient::new(
@@ -163,8 +222,15 @@ async fn build_services_internal<S: Store + 'static>(
         None
     };
 
-    let messaging_service = create_messaging_service(&cfg.messaging, store.clone());
-    let email_worker_handle = workers::spawn_email_worker(store.clone(), cfg.messaging.clone());
+    let messaging_service =
+        create_messaging_service(&cfg.messaging, store.clone(), product_repo.clone());
+    let email_transports = build_email_transports(
+        &cfg.messaging,
+        secrets.clone(),
+        storage_pg_pool.as_ref(),
+        config_encryption.clone(),
+    );
+    let email_worker_handle = workers::spawn_email_worker(store.clone(), email_transports.clone());
 
     let mut paywall_service = PaywallService::new(
         cfg.clone(),
@@ -181,6 +247,17 @@ async fn build_services_internal<S: Store + 'static>(
         paywall_service = paywall_service.with_payment_callback(cb.clone());
     }
     paywall_service = paywall_service.with_messaging(messaging_service.clone());
+    if let Some(ref secrets) = secrets {
+        if cfg.x402.gasless_enabled {
+            match crate::x402::GaslessTransactionBuilder::new(&cfg.x402) {
+                Ok(builder) => {
+                    paywall_service = paywall_service
+                        .with_gasless_builder(Arc::new(builder.with_secrets(secrets.clone())));
+                }
+                Err(e) => tracing::warn!(error = %e, "Failed to create gasless builder"),
+            }
+        }
+    }
 
     // Token-22 service — shared by fulfillment services and admin routes
     let built_token22_service = if !cfg.x402.rpc_url.is_empty() {
@@ -214,11 +291,14 @@ async fn build_services_internal<S: Store + 'static>(
     let mut built_asset_fulfillment: Option<Arc<services::AssetFulfillmentService>> = None;
     let mut compliance_checker: Option<Arc<services::ComplianceChecker>> = None;
     if let Some(ref cl) = cedros_login_client {
-        let gc_fulfillment = Arc::new(services::GiftCardFulfillmentService::new(
-            cl.clone(),
-            built_token22_service.clone(),
-            store.clone() as Arc<dyn Store>,
-        ));
+        let gc_fulfillment = Arc::new(
+            services::GiftCardFulfillmentService::new(
+                cl.clone(),
+                built_token22_service.clone(),
+                store.clone() as Arc<dyn Store>,
+            )
+            .with_messaging(messaging_service.clone()),
+        );
         paywall_service = paywall_service.with_gift_card_fulfillment(gc_fulfillment);
 
         // Build compliance checker if sanctions list service is available
@@ -268,27 +348,33 @@ async fn build_services_internal<S: Store + 'static>(
             "stripe_api",
             &cfg.circuit_breaker.stripe_api,
         );
-        Some(Arc::new(StripeClient::with_circuit_breaker(
+        let client = StripeClient::with_circuit_breaker(
             cfg.clone(),
             store.clone() as Arc<dyn Store>,
             notifier.clone(),
             stripe_cb,
-        )?))
+        )?;
+        Some(Arc::new(match secrets {
+            Some(ref secrets) => client.with_secrets(secrets.clone()),
+            None => client,
+        }))
     } else {
         None
     };
 
     let stripe_webhook_processor = if !cfg.stripe.secret_key.is_empty() {
-        Some(Arc::new(
-            StripeWebhookProcessor::new(
-                Arc::new(cfg.clone()),
-                store.clone(),
-                notifier.clone(),
-                subscription_service.clone(),
-                product_repo.clone(),
-            )
-            .with_messaging(messaging_service.clone()),
-        ))
+        let processor = StripeWebhookProcessor::new(
+            Arc::new(cfg.clone()),
+            store.clone(),
+            notifier.clone(),
+            subscription_service.clone(),
+            product_repo.clone(),
+        )
+        .with_messaging(messaging_service.clone());
+        Some(Arc::new(match secrets {
+            Some(ref secrets) => processor.with_secrets(secrets.clone()),
+            None => processor,
+        }))
     } else {
         None
     };
@@ -305,6 +391,31 @@ async fn build_services_internal<S: Store + 'static>(
         None
     };
 
+    let config_bus = match (config_tenant, storage_pg_pool.as_ref()) {
+        (Some(tenant_id), Some(pool)) => {
+            let repo = Arc::new(
+                crate::config::PostgresConfigRepository::with_optional_encryption(
+                    pool.clone(),
+                    config_encryption.clone(),
+                ),
+            );
+            let mut bus = ConfigBus::new(repo, tenant_id, cfg.clone());
+            if let Some(ref secrets) = secrets {
+                bus = bus.with_secrets(secrets.clone());
+            }
+            let bus = Arc::new(bus);
+            register_config_subscribers(
+                &bus,
+                solana_verifier,
+                paywall_service.gasless_builder().cloned(),
+                stripe_client.clone(),
+                http_notifier.clone(),
+            );
+            Some(bus)
+        }
+        _ => None,
+    };
+
     let health_state = Arc::new(parking_lot::RwLock::new(handlers::health::HealthState {
         health_checker: None,
         network: cfg.x402.network.clone(),
@@ -320,6 +431,8 @@ async fn build_services_internal<S: Store + 'static>(
         subscription_service,
         stripe_client,
         notifier,
+        http_notifier,
+        messaging_service,
         product_repo,
         coupon_repo,
         blockhash_cache,
@@ -327,14 +440,113 @@ async fn build_services_internal<S: Store + 'static>(
         stripe_webhook_processor,
         health_state,
         storage_pg_pool,
+        config_encryption,
+        secrets,
         cedros_login_client,
+        email_transports,
         email_worker_handle,
         token22_service: built_token22_service,
         asset_fulfillment: built_asset_fulfillment,
         sanctions_list_service,
+        config_bus,
     })
 }
 
+/// Email transports: global settings plus per-tenant overrides when config
+/// lives in Postgres.
+fn build_email_transports(
+    messaging: &MessagingConfig,
+    secrets: Option<Arc<crate::config::SecretStore>>,
+    pg_pool: Option<&PgPool>,
+    config_encryption: Option<Arc<crate::config::ConfigEncryption>>,
+) -> Arc<EmailTransportResolver> {
+    let mut transports = EmailTransportResolver::new(messaging.clone());
+    if let Some(secrets) = secrets {
+        transports = transports.with_secrets(secrets);
+    }
+    if let Some(pool) = pg_pool {
+        transports = transports.with_config_repo(Arc::new(
+            crate::config::PostgresConfigRepository::with_optional_encryption(
+                pool.clone(),
+                config_encryption,
+            ),
+        ));
+    }
+    Arc::new(transports)
+}
+
+/// Webhook retry budget for notifications enqueued under `callbacks`.
+fn notifier_max_attempts(callbacks: &CallbacksConfig) -> i32 {
+    if callbacks.retry.enabled {
+        callbacks.retry.max_attempts.max(1) as i32
+    } else {
+        1
+    }
+}
+
+/// Subscribe services built above to config changes.
+///
+/// Only settings that can be swapped in place are applied; anything that
+/// would need the service rebuilt (RPC URL, wallets) is reported as an
+/// error on the service status and takes effect on restart.
+fn register_config_subscribers<S: Store + 'static>(
+    bus: &Arc<ConfigBus>,
+    verifier: Option<Arc<SolanaVerifier>>,
+    gasless_builder: Option<Arc<crate::x402::GaslessTransactionBuilder>>,
+    stripe_client: Option<Arc<StripeClient>>,
+    http_notifier: Arc<webhooks::HttpNotifier<S>>,
+) {
+    if let Some(verifier) = verifier {
+        bus.register(
+            "solana_verifier",
+            &["x402", "circuit_breaker"],
+            move |cfg| {
+                verifier.reconfigure(&cfg.x402, &cfg.circuit_breaker.solana_rpc);
+                Ok(())
+            },
+        );
+    }
+
+    if let Some(builder) = gasless_builder {
+        bus.register("gasless_builder", &["x402"], move |cfg| {
+            builder.set_compute_budget(ComputeBudget::from_config(&cfg.x402));
+            Ok(())
+        });
+    }
+
+    if let Some(client) = stripe_client {
+        bus.register("stripe_client", &["circuit_breaker"], move |cfg| {
+            client.reconfigure_circuit_breaker(
+                middleware::CircuitBreakerConfig::from_service_config(
+                    "stripe_api",
+                    &cfg.circuit_breaker.stripe_api,
+                ),
+            );
+            Ok(())
+        });
+    }
+
+    bus.register("notifier", &["callbacks"], move |cfg| {
+        reconfigure_notifier(&http_notifier, &cfg.callbacks);
+        Ok(())
+    });
+}
+
+/// Apply `callbacks` to the notifier; an unset URL stops deliveries but
+/// events are still logged if the event log is enabled.
+fn reconfigure_notifier<S: Store + 'static>(
+    notifier: &webhooks::HttpNotifier<S>,
+    callbacks: &CallbacksConfig,
+) {
+    notifier.reconfigure(
+        callbacks.payment_success_url.clone(),
+        callbacks.hmac_secret.clone(),
+        callbacks.headers.clone(),
+        notifier_max_attempts(callbacks),
+    );
+    notifier.set_event_log(callbacks.event_log.enabled);
+}
+
 // ============================================================================
 // Repository builders
 // ============================================================================
@@ -376,6 +588,15 @@ pub(crate) async fn build_product_repository(
             };
             Ok(new_product_repository(repo_config, Some(pg_pool)).await?)
         }
+        ProductSource::Sqlite => {
+            let pool = build_sqlite_pool(cfg).await?;
+            let repo = SqliteProductRepository::new(pool.inner().clone())
+                .with_table_name(&cfg.storage.schema_mapping.products_table);
+            Ok(wrap_product_cache(
+                Arc::new(repo),
+                cfg.paywall.product_cache_ttl,
+            ))
+        }
     }
 }
 
@@ -419,6 +640,12 @@ pub(crate) async fn build_coupon_repository(
             };
             Ok(new_coupon_repository(repo_config, Some(pg_pool)).await?)
         }
+        CouponSource::Sqlite => {
+            let pool = build_sqlite_pool(cfg).await?;
+            let repo = SqliteCouponRepository::new(pool.inner().clone())
+                .with_table_name(&cfg.storage.schema_mapping.coupons_table);
+            Ok(wrap_coupon_cache(Arc::new(repo), cfg.coupons.cache_ttl))
+        }
     }
 }
 
@@ -439,6 +666,23 @@ fn wrap_product_cache<R: ProductRepository + 'static>(
     }
 }
 
+fn wrap_coupon_cache<R: CouponRepository + 'static>(
+    repo: Arc<R>,
+    ttl: Duration,
+) -> Arc<dyn CouponRepository> {
+    if ttl > Duration::ZERO {
+        let cache_config = RepositoryCacheConfig {
+            item_ttl: ttl,
+            list_ttl: ttl,
+            enabled: true,
+            ..Default::default()
+        };
+        Arc::new(CachedCouponRepository::new(repo, cache_config))
+    } else {
+        repo
+    }
+}
+
 async fn resolve_pg_pool(
     cfg: &Config,
     url: &str,
@@ -517,6 +761,7 @@ pub(crate) fn paywall_resource_to_product(resource: &PaywallResource) -> Product
         gift_card_config: None,
         tokenized_asset_config: None,
         compliance_requirements: None,
+        payment_split: None,
         created_at: None,
         updated_at: None,
     }
@@ -8,9 +8,11 @@
 pub mod encryption;
 pub mod repository;
 
-pub use encryption::{ConfigEncryption, EncryptedValue, EncryptionError};
+pub use encryption::{
+    ConfigEncryption, DekInfo, DekRotation, EncryptedValue, EncryptionError, RewrappedDek,
+};
 pub use repository::{
     default_keys_for_category, secret_fields_for_category, BatchUpsertItem, ConfigCategoryMeta,
-    ConfigEntry, ConfigHistoryEntry, ConfigRepositoryError, PostgresConfigRepository,
-    KNOWN_CATEGORIES, REDACTED_PLACEHOLDER,
+    ConfigEntry, ConfigHistoryEntry, ConfigRepositoryError, DekRotationSummary,
+    PostgresConfigRepository, ENCRYPTION_AUDIT_CATEGORY, KNOWN_CATEGORIES, REDACTED_PLACEHOLDER,
 };
@@ -3,7 +3,7 @@
 //! Stores configuration as JSON in the app_config table with optional
 //! encryption for secrets using the ConfigEncryption service.
 
-use super::encryption::{ConfigEncryption, EncryptedValue, EncryptionError};
+use super::encryption::{ConfigEncryption, DekInfo, EncryptedValue, EncryptionError};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use sqlx::PgPool;
@@ -90,9 +90,11 @@ pub const KNOWN_CATEGORIES: &[&str] = &[
     "cedros_login",
     "messaging",
     "ai",
+    "ai_pricing",
     "gift_cards",
     "storage",
     "compliance",
+    "reports",
 ];
 
 /// Default config keys for each known category.
@@ -118,6 +120,12 @@ pub fn default_keys_for_category(category: &str) -> &'static [&'static str] {
             "cancel_url",
             "tax_rate_id",
             "mode",
+            "connect_enabled",
+            "connect_charge_type",
+            "connect_application_fee_bps",
+            "connect_refresh_url",
+            "connect_return_url",
+            "connect_webhook_secret",
         ],
         "x402" => &[
             "payment_address",
@@ -139,7 +147,15 @@ pub fn default_keys_for_category(category: &str) -> &'static [&'static str] {
             "rounding_mode",
         ],
         "paywall" => &["product_cache_ttl", "quote_ttl", "product_source"],
-        "shop" => &["guest_checkout"],
+        "shop" => &[
+            "guest_checkout",
+            "returns_enabled",
+            "return_window_days",
+            "return_eligible_statuses",
+            "return_restocking_fee_bps",
+            "return_restock_on_receive",
+            "return_refund_quote_ttl_hours",
+        ],
         "coupons" => &["cache_ttl", "coupon_source"],
         "subscriptions" => &["enabled", "grace_period_hours"],
         "callbacks" => &[
@@ -149,6 +165,7 @@ pub fn default_keys_for_category(category: &str) -> &'static [&'static str] {
             "body_template",
             "headers",
             "retry",
+            "event_log",
         ],
         "monitoring" => &[
             "check_interval",
@@ -173,6 +190,11 @@ pub fn default_keys_for_category(category: &str) -> &'static [&'static str] {
         ],
         "messaging" => &[
             "email_enabled",
+            "email_provider",
+            "email_api_url",
+            "email_api_key",
+            "email_webhook_secret",
+            "email_transport",
             "smtp_host",
             "smtp_port",
             "smtp_username",
@@ -184,7 +206,14 @@ pub fn default_keys_for_category(category: &str) -> &'static [&'static str] {
             "webhook_secret",
             "webhook_timeout",
         ],
-        "ai" => &["gemini_api_key", "openai_api_key"],
+        "ai" => &[
+            "gemini_api_key",
+            "openai_api_key",
+            "anthropic_api_key",
+            "openai_compatible_api_key",
+            "openai_compatible_base_url",
+        ],
+        "ai_pricing" => &["models"],
         "gift_cards" => &[
             "enabled",
             "secondary_market_enabled",
@@ -211,8 +240,10 @@ pub fn default_keys_for_category(category: &str) -> &'static [&'static str] {
             "access_key_id",
             "secret_access_key",
             "cdn_url",
+            "force_path_style",
         ],
         "compliance" => &["sanctions_sweep", "sanctions_api"],
+        "reports" => &["financial_schedule"],
         _ => &[],
     }
 }
@@ -221,14 +252,25 @@ pub fn default_keys_for_category(category: &str) -> &'static [&'static str] {
 /// These fields will be encrypted when stored
 pub fn secret_fields_for_category(category: &str) -> HashSet<&'static str> {
     match category {
-        "stripe" => ["secret_key", "webhook_secret"].into_iter().collect(),
+        "stripe" => ["secret_key", "webhook_secret", "connect_webhook_secret"]
+            .into_iter()
+            .collect(),
         "x402" => ["server_wallets"].into_iter().collect(),
         "callbacks" => ["hmac_secret"].into_iter().collect(),
         "cedros_login" => ["api_key"].into_iter().collect(),
         "api_keys" => ["keys"].into_iter().collect(),
         "server" => ["admin_metrics_api_key"].into_iter().collect(),
-        "ai" => ["gemini_api_key", "openai_api_key"].into_iter().collect(),
-        "storage" => ["access_key_id", "secret_access_key"]
+        "ai" => [
+            "gemini_api_key",
+            "openai_api_key",
+            "anthropic_api_key",
+            "openai_compatible_api_key",
+        ]
+        .into_iter()
+        .collect(),
+        "storage" => ["access_key_id", "secret_access_key"].into_iter().collect(),
+        // Fields of the per-tenant `email_transport` object
+        "messaging" => ["smtp_password", "api_key", "webhook_secret"]
             .into_iter()
             .collect(),
         _ => HashSet::new(),
@@ -238,6 +280,19 @@ pub fn secret_fields_for_category(category: &str) -> HashSet<&'static str> {
 /// Placeholder used for redacted secrets in API responses
 pub const REDACTED_PLACEHOLDER: &str = "[REDACTED]";
 
+/// Audit category for key rotation events
+pub const ENCRYPTION_AUDIT_CATEGORY: &str = "encryption";
+
+/// Outcome of a tenant DEK rotation
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DekRotationSummary {
+    pub previous_version: Option<i32>,
+    pub new_version: i32,
+    /// Config entries re-encrypted under the new DEK
+    pub reencrypted: usize,
+}
+
 /// PostgreSQL config repository
 pub struct PostgresConfigRepository {
     pool: PgPool,
@@ -304,6 +359,27 @@ impl PostgresConfigRepository {
         }
     }
 
+    /// Create repository, encrypting secrets only if an encryption service is given
+    pub fn with_optional_encryption(
+        pool: PgPool,
+        encryption: Option<Arc<ConfigEncryption>>,
+    ) -> Self {
+        Self { pool, encryption }
+    }
+
+    /// Whether secrets are encrypted at rest
+    pub fn encryption_enabled(&self) -> bool {
+        self.encryption.is_some()
+    }
+
+    fn require_encryption(&self) -> Result<&Arc<ConfigEncryption>, ConfigRepositoryError> {
+        self.encryption
+            .as_ref()
+            .ok_or(ConfigRepositoryError::Encryption(
+                EncryptionError::KekNotConfigured,
+            ))
+    }
+
     /// List all config categories for a tenant
     pub async fn list_categories(
         &self,
@@ -332,6 +408,28 @@ impl PostgresConfigRepository {
             .collect())
     }
 
+    /// List tenants that have a value stored under `category`/`config_key`
+    pub async fn list_tenants_with_config(
+        &self,
+        category: &str,
+        config_key: &str,
+    ) -> Result<Vec<String>, ConfigRepositoryError> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            r#"
+            SELECT DISTINCT tenant_id
+            FROM app_config
+            WHERE category = $1 AND config_key = $2
+            ORDER BY tenant_id
+            "#,
+        )
+        .bind(category)
+        .bind(config_key)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|(tenant_id,)| tenant_id).collect())
+    }
+
     /// Get all config entries for a category
     pub async fn get_config(
         &self,
@@ -651,6 +749,174 @@ impl PostgresConfigRepository {
             .collect())
     }
 
+    /// List the tenant's DEK versions
+    pub async fn list_encryption_keys(
+        &self,
+        tenant_id: &str,
+    ) -> Result<Vec<DekInfo>, ConfigRepositoryError> {
+        Ok(self.require_encryption()?.list_deks(tenant_id).await?)
+    }
+
+    /// Rotate the tenant's DEK and re-encrypt all of its secret config values
+    ///
+    /// Re-encryption runs in one transaction, so a failure leaves every value
+    /// readable under the DEK version it was written with.
+    pub async fn rotate_tenant_dek(
+        &self,
+        tenant_id: &str,
+        rotated_by: Option<&str>,
+    ) -> Result<DekRotationSummary, ConfigRepositoryError> {
+        let encryption = self.require_encryption()?;
+        let rotation = encryption.rotate_dek(tenant_id).await?;
+
+        // Lock the encrypted rows for the whole re-encryption so a concurrent
+        // config write cannot land between the read and the write-back.
+        let mut tx = self.pool.begin().await?;
+        let rows: Vec<ConfigRow> = sqlx::query_as(
+            r#"
+            SELECT tenant_id, config_key, value, encrypted, key_version, category, description,
+                   created_at, updated_at, updated_by
+            FROM app_config
+            WHERE tenant_id = $1 AND encrypted = TRUE
+            ORDER BY category, config_key
+            FOR UPDATE
+            "#,
+        )
+        .bind(tenant_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut updates = Vec::with_capacity(rows.len());
+        for row in rows {
+            let entry = ConfigEntry {
+                tenant_id: row.0,
+                config_key: row.1,
+                value: row.2,
+                encrypted: row.3,
+                key_version: row.4,
+                category: row.5,
+                description: row.6,
+                created_at: row.7,
+                updated_at: row.8,
+                updated_by: row.9,
+            };
+            let plaintext = self.decrypt_entry(&entry).await?;
+            let secret_fields = secret_fields_for_category(&entry.category);
+            let (value, encrypted, key_version) = self
+                .process_value_for_storage(tenant_id, &plaintext, &secret_fields)
+                .await?;
+            updates.push((
+                entry.category,
+                entry.config_key,
+                value,
+                encrypted,
+                key_version,
+            ));
+        }
+
+        for (category, config_key, value, encrypted, key_version) in &updates {
+            sqlx::query(
+                r#"
+                UPDATE app_config
+                SET value = $1, encrypted = $2, key_version = $3, updated_by = $4
+                WHERE tenant_id = $5 AND category = $6 AND config_key = $7
+                "#,
+            )
+            .bind(value)
+            .bind(encrypted)
+            .bind(key_version)
+            .bind(rotated_by)
+            .bind(tenant_id)
+            .bind(category)
+            .bind(config_key)
+            .execute(&mut *tx)
+            .await?;
+        }
+        Self::insert_key_event(
+            &mut tx,
+            tenant_id,
+            "dek",
+            "ROTATE_DEK",
+            rotation
+                .previous_version
+                .map(|v| serde_json::json!({ "keyVersion": v })),
+            serde_json::json!({
+                "keyVersion": rotation.new_version,
+                "reencrypted": updates.len(),
+            }),
+            rotated_by,
+        )
+        .await?;
+        tx.commit().await?;
+
+        Ok(DekRotationSummary {
+            previous_version: rotation.previous_version,
+            new_version: rotation.new_version,
+            reencrypted: updates.len(),
+        })
+    }
+
+    /// Re-wrap up to `limit` DEKs still wrapped under a retired KEK
+    ///
+    /// Returns the number of DEKs re-wrapped; each is recorded in the audit log.
+    pub async fn rewrap_deks(&self, limit: i64) -> Result<usize, ConfigRepositoryError> {
+        let encryption = self.require_encryption()?;
+        let rewrapped = encryption.rewrap_deks(limit).await?;
+
+        if !rewrapped.is_empty() {
+            let mut tx = self.pool.begin().await?;
+            for dek in &rewrapped {
+                Self::insert_key_event(
+                    &mut tx,
+                    &dek.tenant_id,
+                    "kek",
+                    "REWRAP_DEK",
+                    Some(serde_json::json!({
+                        "keyVersion": dek.key_version,
+                        "kekVersion": dek.from_kek_version,
+                    })),
+                    serde_json::json!({
+                        "keyVersion": dek.key_version,
+                        "kekVersion": dek.to_kek_version,
+                    }),
+                    None,
+                )
+                .await?;
+            }
+            tx.commit().await?;
+        }
+
+        Ok(rewrapped.len())
+    }
+
+    /// Record a key rotation event in the config audit log
+    async fn insert_key_event(
+        tx: &mut sqlx::Transaction<'_, Postgres>,
+        tenant_id: &str,
+        config_key: &str,
+        action: &str,
+        old_value: Option<JsonValue>,
+        new_value: JsonValue,
+        changed_by: Option<&str>,
+    ) -> Result<(), ConfigRepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO app_config_audit (tenant_id, category, config_key, action, old_value, new_value, changed_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(ENCRYPTION_AUDIT_CATEGORY)
+        .bind(config_key)
+        .bind(action)
+        .bind(old_value)
+        .bind(new_value)
+        .bind(changed_by)
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
     /// Delete config entry
     pub async fn delete_config(
         &self,
@@ -1,16 +1,23 @@
+pub mod bus;
 pub mod db;
+pub mod secrets;
 pub mod types;
 
+pub use bus::{ConfigBus, ConfigChange, ConfigOverride, ServiceConfigStatus};
 pub use db::{
     default_keys_for_category, secret_fields_for_category, BatchUpsertItem, ConfigCategoryMeta,
-    ConfigEncryption, ConfigEntry, ConfigHistoryEntry, ConfigRepositoryError, EncryptedValue,
-    EncryptionError, PostgresConfigRepository, KNOWN_CATEGORIES, REDACTED_PLACEHOLDER,
+    ConfigEncryption, ConfigEntry, ConfigHistoryEntry, ConfigRepositoryError, DekInfo,
+    DekRotationSummary, EncryptedValue, EncryptionError, PostgresConfigRepository,
+    KNOWN_CATEGORIES, REDACTED_PLACEHOLDER,
 };
+pub use secrets::{SecretError, SecretProvider, SecretSnapshot, SecretStore};
 pub use types::{
-    AdminConfig, ApiKeyConfig, ApiKeyEntry, ApiKeyTier, CallbacksConfig, CedrosLoginConfig,
-    CircuitBreakerConfig, CircuitBreakerServiceConfig, Config, ConfigError, CouponConfig,
-    CouponSource, LoggingConfig, MessagingConfig, MonitoringConfig, PaywallConfig, PaywallResource,
-    PostgresPoolConfig, ProductSource, RateLimitConfig, RateLimitSetting, RetryConfig,
-    SchemaMapping, ServerConfig, ShopConfig, StorageBackend, StorageConfig, StripeConfig,
-    SubscriptionsConfig, X402Config,
+    AdminConfig, AiModelPrice, AiPricingConfig, ApiKeyConfig, ApiKeyEntry, ApiKeyTier,
+    CallbacksConfig, CedrosLoginConfig, CircuitBreakerConfig, CircuitBreakerServiceConfig, Config,
+    ConfigError, CouponConfig, CouponSource, EventLogConfig, LoggingConfig, MessagingConfig,
+    MonitoringConfig, PaywallConfig, PaywallResource, PostgresPoolConfig, ProductSource,
+    RateLimitConfig, RateLimitSetting, RetryConfig, SchemaMapping, SecretProviderKind,
+    SecretsConfig, ServerConfig, ShopConfig, ShopReturnsConfig, SignerConfig, SignerMode,
+    SigningPolicyConfig, StorageBackend, StorageConfig, StripeConfig, StripeConnectConfig,
+    SubscriptionsConfig, VaultSecretsConfig, X402Config, EMAIL_PROVIDER_HTTP,
 };
@@ -0,0 +1,629 @@
+//! Pluggable secret providers with hot reload.
+//!
+//! Runtime credentials (Stripe keys, server wallet keypairs, the SMTP password
+//! and AI provider keys) can be supplied by a [`SecretProvider`] instead of
+//! YAML/env. Three providers are available:
+//!
+//! - [`FileSecretProvider`] — one file per secret in a mounted directory
+//!   (Kubernetes/Docker secrets)
+//! - [`VaultSecretProvider`] — a HashiCorp Vault-compatible KV v2 HTTP API
+//! - [`DatabaseSecretProvider`] — the encrypted `app_config` table
+//!
+//! Values are held in a [`SecretStore`], polled by the secrets refresh worker
+//! and published through a watch channel so consumers read the latest value on
+//! each use without a restart.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde_json::Value as JsonValue;
+use sqlx::PgPool;
+use thiserror::Error;
+use tokio::sync::watch;
+use zeroize::Zeroizing;
+
+use super::{
+    Config, ConfigEncryption, PostgresConfigRepository, SecretProviderKind, SecretsConfig,
+};
+
+/// Stripe API secret key (`stripe.secret_key`)
+pub const SECRET_STRIPE_SECRET_KEY: &str = "stripe_secret_key";
+/// Stripe webhook signing secret (`stripe.webhook_secret`)
+pub const SECRET_STRIPE_WEBHOOK_SECRET: &str = "stripe_webhook_secret";
+/// Server wallet keypairs, one per line (`x402.server_wallets`)
+pub const SECRET_SERVER_WALLETS: &str = "x402_server_wallets";
+/// SMTP password (`messaging.smtp_password`)
+pub const SECRET_SMTP_PASSWORD: &str = "smtp_password";
+/// HTTP email API key (`messaging.email_api_key`)
+pub const SECRET_EMAIL_API_KEY: &str = "email_api_key";
+/// OpenAI API key (`ai.openai_api_key`)
+pub const SECRET_OPENAI_API_KEY: &str = "openai_api_key";
+/// Gemini API key (`ai.gemini_api_key`)
+pub const SECRET_GEMINI_API_KEY: &str = "gemini_api_key";
+/// Anthropic API key (`ai.anthropic_api_key`)
+pub const SECRET_ANTHROPIC_API_KEY: &str = "anthropic_api_key";
+
+/// Secret names with their `app_config` category and key.
+pub const KNOWN_SECRETS: &[(&str, &str, &str)] = &[
+    (SECRET_STRIPE_SECRET_KEY, "stripe", "secret_key"),
+    (SECRET_STRIPE_WEBHOOK_SECRET, "stripe", "webhook_secret"),
+    (SECRET_SERVER_WALLETS, "x402", "server_wallets"),
+    (SECRET_SMTP_PASSWORD, "messaging", "smtp_password"),
+    (SECRET_EMAIL_API_KEY, "messaging", "email_api_key"),
+    (SECRET_OPENAI_API_KEY, "ai", "openai_api_key"),
+    (SECRET_GEMINI_API_KEY, "ai", "gemini_api_key"),
+    (SECRET_ANTHROPIC_API_KEY, "ai", "anthropic_api_key"),
+];
+
+/// Tenant whose `app_config` entries back the database provider.
+const DATABASE_SECRETS_TENANT: &str = "default";
+
+const VAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Error)]
+pub enum SecretError {
+    #[error("secret provider not configured: {0}")]
+    NotConfigured(String),
+    #[error("failed to read secrets directory: {0}")]
+    Io(String),
+    #[error("vault request failed: {0}")]
+    Vault(String),
+    #[error("failed to load secrets from database: {0}")]
+    Database(String),
+}
+
+/// Source of runtime secrets.
+///
+/// `load` returns every secret the provider currently holds; the store diffs
+/// successive loads to detect changes.
+#[async_trait]
+pub trait SecretProvider: Send + Sync {
+    /// Short provider name for logs
+    fn name(&self) -> &'static str;
+
+    async fn load(&self) -> Result<HashMap<String, String>, SecretError>;
+}
+
+// ============================================================================
+// File provider
+// ============================================================================
+
+/// Reads one secret per regular file in a directory, named after the file.
+///
+/// Hidden entries are skipped, which covers the `..data` symlinks Kubernetes
+/// uses for atomic secret updates. A single trailing newline is trimmed.
+pub struct FileSecretProvider {
+    dir: PathBuf,
+}
+
+impl FileSecretProvider {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+#[async_trait]
+impl SecretProvider for FileSecretProvider {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    async fn load(&self) -> Result<HashMap<String, String>, SecretError> {
+        let mut entries = tokio::fs::read_dir(&self.dir)
+            .await
+            .map_err(|e| SecretError::Io(format!("{}: {}", self.dir.display(), e)))?;
+
+        let mut secrets = HashMap::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| SecretError::Io(e.to_string()))?
+        {
+            let Some(name) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            if name.starts_with('.') {
+                continue;
+            }
+            // Follow symlinks so mounted secrets resolve to their current target
+            let path = entry.path();
+            match tokio::fs::metadata(&path).await {
+                Ok(meta) if meta.is_file() => {}
+                _ => continue,
+            }
+            let contents = tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|e| SecretError::Io(format!("{}: {}", path.display(), e)))?;
+            let value = contents
+                .strip_suffix('\n')
+                .map(|v| v.strip_suffix('\r').unwrap_or(v))
+                .unwrap_or(&contents);
+            secrets.insert(name, value.to_string());
+        }
+
+        Ok(secrets)
+    }
+}
+
+// ============================================================================
+// Vault provider
+// ============================================================================
+
+/// Reads a single KV v2 secret whose fields are the secret names.
+pub struct VaultSecretProvider {
+    http_client: reqwest::Client,
+    url: String,
+    token: Zeroizing<String>,
+    namespace: Option<String>,
+}
+
+impl VaultSecretProvider {
+    pub fn new(
+        address: &str,
+        token: &str,
+        namespace: Option<String>,
+        mount: &str,
+        path: &str,
+    ) -> Result<Self, SecretError> {
+        let http_client = reqwest::Client::builder()
+            .timeout(VAULT_REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| SecretError::Vault(format!("http client: {}", e)))?;
+
+        Ok(Self {
+            http_client,
+            url: vault_data_url(address, mount, path),
+            token: Zeroizing::new(token.to_string()),
+            namespace,
+        })
+    }
+}
+
+/// KV v2 read URL: `{address}/v1/{mount}/data/{path}`
+fn vault_data_url(address: &str, mount: &str, path: &str) -> String {
+    format!(
+        "{}/v1/{}/data/{}",
+        address.trim_end_matches('/'),
+        mount.trim_matches('/'),
+        path.trim_matches('/')
+    )
+}
+
+/// Extract `data.data` from a KV v2 read response, stringifying non-string values.
+fn parse_vault_response(body: &JsonValue) -> Result<HashMap<String, String>, SecretError> {
+    let data = body
+        .get("data")
+        .and_then(|d| d.get("data"))
+        .and_then(JsonValue::as_object)
+        .ok_or_else(|| SecretError::Vault("response has no data.data object".into()))?;
+
+    Ok(data
+        .iter()
+        .filter_map(|(key, value)| match value {
+            JsonValue::Null => None,
+            JsonValue::String(s) => Some((key.clone(), s.clone())),
+            other => Some((key.clone(), other.to_string())),
+        })
+        .collect())
+}
+
+#[async_trait]
+impl SecretProvider for VaultSecretProvider {
+    fn name(&self) -> &'static str {
+        "vault"
+    }
+
+    async fn load(&self) -> Result<HashMap<String, String>, SecretError> {
+        let mut request = self
+            .http_client
+            .get(&self.url)
+            .header("X-Vault-Token", self.token.as_str());
+        if let Some(ref namespace) = self.namespace {
+            request = request.header("X-Vault-Namespace", namespace);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| SecretError::Vault(e.to_string()))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(SecretError::Vault(format!(
+                "{} returned {}",
+                self.url, status
+            )));
+        }
+
+        let body: JsonValue = response
+            .json()
+            .await
+            .map_err(|e| SecretError::Vault(format!("invalid response: {}", e)))?;
+        parse_vault_response(&body)
+    }
+}
+
+// ============================================================================
+// Database provider
+// ============================================================================
+
+/// Reads the secret fields of the default tenant's `app_config` entries,
+/// decrypting them with the repository's envelope encryption.
+pub struct DatabaseSecretProvider {
+    repo: Arc<PostgresConfigRepository>,
+}
+
+impl DatabaseSecretProvider {
+    pub fn new(repo: Arc<PostgresConfigRepository>) -> Self {
+        Self { repo }
+    }
+}
+
+#[async_trait]
+impl SecretProvider for DatabaseSecretProvider {
+    fn name(&self) -> &'static str {
+        "database"
+    }
+
+    async fn load(&self) -> Result<HashMap<String, String>, SecretError> {
+        let mut secrets = HashMap::new();
+        let mut categories: Vec<&str> = KNOWN_SECRETS.iter().map(|(_, c, _)| *c).collect();
+        categories.dedup();
+
+        for category in categories {
+            let entries = self
+                .repo
+                .get_config(DATABASE_SECRETS_TENANT, category)
+                .await
+                .map_err(|e| SecretError::Database(e.to_string()))?;
+
+            for (name, _, key) in KNOWN_SECRETS.iter().filter(|(_, c, _)| *c == category) {
+                let Some(entry) = entries.iter().find(|e| e.config_key == *key) else {
+                    continue;
+                };
+                let value = self
+                    .repo
+                    .decrypt_entry(entry)
+                    .await
+                    .map_err(|e| SecretError::Database(e.to_string()))?;
+                let value = match value {
+                    JsonValue::String(s) => s,
+                    // Wallet lists are stored as JSON arrays; secrets use one per line
+                    JsonValue::Array(items) => items
+                        .iter()
+                        .filter_map(JsonValue::as_str)
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                    _ => continue,
+                };
+                secrets.insert((*name).to_string(), value);
+            }
+        }
+
+        Ok(secrets)
+    }
+}
+
+// ============================================================================
+// Secret store
+// ============================================================================
+
+/// Immutable view of the secrets at one point in time.
+///
+/// `version` increases every time a refresh observes a change, so consumers
+/// that derive state from a secret (parsed keypairs, SMTP transports) can
+/// cheaply tell whether to rebuild it.
+pub struct SecretSnapshot {
+    version: u64,
+    values: HashMap<String, Zeroizing<String>>,
+}
+
+impl SecretSnapshot {
+    fn new(version: u64, values: HashMap<String, String>) -> Self {
+        Self {
+            version,
+            values: values
+                .into_iter()
+                .map(|(k, v)| (k, Zeroizing::new(v)))
+                .collect(),
+        }
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Non-empty value for a secret, if the provider has it.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .get(name)
+            .map(|v| v.as_str())
+            .filter(|v| !v.is_empty())
+    }
+
+    /// Names whose values differ between two snapshots.
+    fn changed_names(&self, next: &HashMap<String, String>) -> Vec<String> {
+        let mut changed: Vec<String> = next
+            .iter()
+            .filter(|(k, v)| self.values.get(*k).map(|old| old.as_str()) != Some(v.as_str()))
+            .map(|(k, _)| k.clone())
+            .chain(
+                self.values
+                    .keys()
+                    .filter(|k| !next.contains_key(*k))
+                    .cloned(),
+            )
+            .collect();
+        changed.sort();
+        changed
+    }
+}
+
+impl std::fmt::Debug for SecretSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names: Vec<&String> = self.values.keys().collect();
+        names.sort();
+        f.debug_struct("SecretSnapshot")
+            .field("version", &self.version)
+            .field("names", &names)
+            .finish()
+    }
+}
+
+/// Current secrets from a provider, shared by every consuming service.
+pub struct SecretStore {
+    provider: Box<dyn SecretProvider>,
+    current: watch::Sender<Arc<SecretSnapshot>>,
+}
+
+impl SecretStore {
+    /// Perform the initial load; fails if the provider cannot be read.
+    pub async fn load(provider: Box<dyn SecretProvider>) -> Result<Self, SecretError> {
+        let values = provider.load().await?;
+        tracing::info!(
+            provider = provider.name(),
+            count = values.len(),
+            "Secrets loaded"
+        );
+        let (current, _) = watch::channel(Arc::new(SecretSnapshot::new(1, values)));
+        Ok(Self { provider, current })
+    }
+
+    /// Build the store selected by `secrets.provider`, or `None` when secrets
+    /// come from YAML/env only.
+    pub async fn from_config(
+        cfg: &SecretsConfig,
+        pg_pool: Option<PgPool>,
+        encryption: Option<Arc<ConfigEncryption>>,
+    ) -> Result<Option<Arc<Self>>, SecretError> {
+        let provider: Box<dyn SecretProvider> = match cfg.provider {
+            SecretProviderKind::None => return Ok(None),
+            SecretProviderKind::File => Box::new(FileSecretProvider::new(&cfg.dir)),
+            SecretProviderKind::Vault => Box::new(VaultSecretProvider::new(
+                &cfg.vault.address,
+                &cfg.vault.token,
+                cfg.vault.namespace.clone(),
+                &cfg.vault.mount,
+                &cfg.vault.path,
+            )?),
+            SecretProviderKind::Database => {
+                let pool = pg_pool.ok_or_else(|| {
+                    SecretError::NotConfigured(
+                        "secrets.provider=database requires PostgreSQL storage".into(),
+                    )
+                })?;
+                Box::new(DatabaseSecretProvider::new(Arc::new(
+                    PostgresConfigRepository::with_optional_encryption(pool, encryption),
+                )))
+            }
+        };
+        Ok(Some(Arc::new(Self::load(provider).await?)))
+    }
+
+    pub fn provider_name(&self) -> &'static str {
+        self.provider.name()
+    }
+
+    pub fn snapshot(&self) -> Arc<SecretSnapshot> {
+        self.current.borrow().clone()
+    }
+
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.current.borrow().get(name).map(str::to_string)
+    }
+
+    /// Receiver notified whenever a refresh publishes changed secrets.
+    pub fn subscribe(&self) -> watch::Receiver<Arc<SecretSnapshot>> {
+        self.current.subscribe()
+    }
+
+    /// Reload from the provider and publish a new snapshot if anything changed.
+    ///
+    /// Returns the names of changed secrets (empty when nothing changed). On
+    /// error the previous values stay in effect.
+    pub async fn refresh(&self) -> Result<Vec<String>, SecretError> {
+        let values = self.provider.load().await?;
+        let current = self.snapshot();
+        let changed = current.changed_names(&values);
+        if !changed.is_empty() {
+            self.current
+                .send_replace(Arc::new(SecretSnapshot::new(current.version + 1, values)));
+        }
+        Ok(changed)
+    }
+
+    /// Overlay provider values onto the startup config so services built from
+    /// it (and config validation of dependent features) see them.
+    pub fn apply_to_config(&self, cfg: &mut Config) {
+        let snapshot = self.snapshot();
+        if let Some(v) = snapshot.get(SECRET_STRIPE_SECRET_KEY) {
+            cfg.stripe.secret_key = v.to_string();
+        }
+        if let Some(v) = snapshot.get(SECRET_STRIPE_WEBHOOK_SECRET) {
+            cfg.stripe.webhook_secret = v.to_string();
+        }
+        if let Some(v) = snapshot.get(SECRET_SERVER_WALLETS) {
+            cfg.x402.server_wallets = split_wallets(v);
+        }
+        if let Some(v) = snapshot.get(SECRET_SMTP_PASSWORD) {
+            cfg.messaging.smtp_password = v.to_string();
+        }
+        if let Some(v) = snapshot.get(SECRET_EMAIL_API_KEY) {
+            cfg.messaging.email_api_key = v.to_string();
+        }
+    }
+}
+
+impl std::fmt::Debug for SecretStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretStore")
+            .field("provider", &self.provider.name())
+            .field("snapshot", &*self.current.borrow())
+            .finish()
+    }
+}
+
+/// Split a server wallets secret into keypair strings, one per non-empty line.
+///
+/// Lines rather than commas, since JSON byte-array keypairs contain commas.
+pub fn split_wallets(value: &str) -> Vec<String> {
+    value
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct StaticProvider {
+        loads: AtomicUsize,
+        values: parking_lot::Mutex<HashMap<String, String>>,
+    }
+
+    impl StaticProvider {
+        fn new(values: &[(&str, &str)]) -> Arc<Self> {
+            Arc::new(Self {
+                loads: AtomicUsize::new(0),
+                values: parking_lot::Mutex::new(
+                    values
+                        .iter()
+                        .map(|(k, v)| (k.to_string(), v.to_string()))
+                        .collect(),
+                ),
+            })
+        }
+
+        fn set(&self, name: &str, value: &str) {
+            self.values
+                .lock()
+                .insert(name.to_string(), value.to_string());
+        }
+    }
+
+    #[async_trait]
+    impl SecretProvider for Arc<StaticProvider> {
+        fn name(&self) -> &'static str {
+            "static"
+        }
+
+        async fn load(&self) -> Result<HashMap<String, String>, SecretError> {
+            self.loads.fetch_add(1, Ordering::SeqCst);
+            Ok(self.values.lock().clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_provider_reads_files_and_skips_hidden() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(SECRET_STRIPE_SECRET_KEY), "sk_live_abc\n").unwrap();
+        std::fs::write(dir.path().join(SECRET_SMTP_PASSWORD), "hunter2").unwrap();
+        std::fs::write(dir.path().join("..data"), "ignored").unwrap();
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+
+        let secrets = FileSecretProvider::new(dir.path()).load().await.unwrap();
+
+        assert_eq!(secrets.len(), 2);
+        assert_eq!(secrets[SECRET_STRIPE_SECRET_KEY], "sk_live_abc");
+        assert_eq!(secrets[SECRET_SMTP_PASSWORD], "hunter2");
+    }
+
+    #[tokio::test]
+    async fn test_file_provider_missing_dir_errors() {
+        let provider = FileSecretProvider::new("/nonexistent/cedros-secrets");
+        assert!(matches!(provider.load().await, Err(SecretError::Io(_))));
+    }
+
+    #[test]
+    fn test_vault_url_and_response_parsing() {
+        assert_eq!(
+            vault_data_url("https://vault:8200/", "/secret/", "cedros-pay"),
+            "https://vault:8200/v1/secret/data/cedros-pay"
+        );
+
+        let body = serde_json::json!({
+            "data": {
+                "data": {"stripe_secret_key": "sk_1", "retries": 3, "unset": null},
+                "metadata": {"version": 4}
+            }
+        });
+        let secrets = parse_vault_response(&body).unwrap();
+        assert_eq!(secrets.len(), 2);
+        assert_eq!(secrets["stripe_secret_key"], "sk_1");
+        assert_eq!(secrets["retries"], "3");
+
+        assert!(parse_vault_response(&serde_json::json!({"errors": []})).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_publishes_only_on_change() {
+        let provider = StaticProvider::new(&[(SECRET_STRIPE_SECRET_KEY, "sk_old")]);
+        let store = SecretStore::load(Box::new(provider.clone())).await.unwrap();
+        let mut rx = store.subscribe();
+        assert_eq!(store.snapshot().version(), 1);
+
+        assert!(store.refresh().await.unwrap().is_empty());
+        assert!(!rx.has_changed().unwrap());
+
+        provider.set(SECRET_STRIPE_SECRET_KEY, "sk_new");
+        provider.set(SECRET_SMTP_PASSWORD, "pw");
+        let changed = store.refresh().await.unwrap();
+        assert_eq!(
+            changed,
+            vec![SECRET_SMTP_PASSWORD, SECRET_STRIPE_SECRET_KEY]
+        );
+        assert!(rx.has_changed().unwrap());
+        assert_eq!(rx.borrow_and_update().version(), 2);
+        assert_eq!(
+            store.get(SECRET_STRIPE_SECRET_KEY).as_deref(),
+            Some("sk_new")
+        );
+        assert_eq!(provider.loads.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_apply_to_config_overlays_secrets() {
+        let provider = StaticProvider::new(&[
+            (SECRET_STRIPE_SECRET_KEY, "sk_vault"),
+            (SECRET_SERVER_WALLETS, "walletA\n\n  [1,2,3]  \n"),
+            (SECRET_SMTP_PASSWORD, ""),
+        ]);
+        let store = SecretStore::load(Box::new(provider)).await.unwrap();
+
+        let mut cfg = Config::default();
+        cfg.messaging.smtp_password = "from-yaml".to_string();
+        store.apply_to_config(&mut cfg);
+
+        assert_eq!(cfg.stripe.secret_key, "sk_vault");
+        assert_eq!(cfg.x402.server_wallets, vec!["walletA", "[1,2,3]"]);
+        // Empty provider values do not clobber configured ones
+        assert_eq!(cfg.messaging.smtp_password, "from-yaml");
+    }
+}
@@ -50,7 +50,7 @@ const DEFAULT_STRIPE_MODE: &str = "test";
 
 /// Validate a webhook URL to prevent SSRF attacks.
 /// Rejects private IP ranges, localhost, and non-HTTPS in production.
-fn validate_webhook_url(url: &str, allow_http: bool) -> Result<(), String> {
+pub(crate) fn validate_webhook_url(url: &str, allow_http: bool) -> Result<(), String> {
     let parsed = url::Url::parse(url).map_err(|e| format!("invalid URL: {}", e))?;
 
     // Require HTTPS for production (configurable for development)
@@ -332,6 +332,12 @@ pub struct StripeConfig {
     pub tax_rate_id: String,
     #[serde(default = "default_stripe_mode")]
     pub mode: String,
+    /// Stripe API base URL. Override to point at a local mock server (e.g. stripe-mock).
+    #[serde(default = "default_stripe_api_base_url")]
+    pub api_base_url: String,
+    /// Stripe Connect settings for per-tenant connected accounts.
+    #[serde(default)]
+    pub connect: StripeConnectConfig,
 }
 
 // SEC-001: Custom Debug implementation to prevent secret exposure in logs
@@ -346,6 +352,49 @@ impl std::fmt::Debug for StripeConfig {
             .field("cancel_url", &self.cancel_url)
             .field("tax_rate_id", &self.tax_rate_id)
             .field("mode", &self.mode)
+            .field("api_base_url", &self.api_base_url)
+            .field("connect", &self.connect)
+            .finish()
+    }
+}
+
+/// Stripe Connect settings for multi-vendor marketplaces.
+///
+/// When enabled, checkout sessions for a tenant with an onboarded connected
+/// account route funds to that account and collect an application fee for the
+/// platform.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct StripeConnectConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// `destination` (platform is merchant of record) or `on_behalf_of`
+    /// (connected account is merchant of record; still a destination charge).
+    #[serde(default = "default_stripe_connect_charge_type")]
+    pub charge_type: String,
+    /// Default platform fee in basis points. Per-account overrides take precedence.
+    #[serde(default)]
+    pub application_fee_bps: u32,
+    /// Where Stripe sends the user when an onboarding link expires.
+    #[serde(default)]
+    pub refresh_url: String,
+    /// Where Stripe sends the user after finishing onboarding.
+    #[serde(default)]
+    pub return_url: String,
+    /// Signing secret of the Connect webhook endpoint (events from connected
+    /// accounts, e.g. `account.updated`). Falls back to `stripe.webhook_secret`.
+    #[serde(default)]
+    pub webhook_secret: String,
+}
+
+impl std::fmt::Debug for StripeConnectConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StripeConnectConfig")
+            .field("enabled", &self.enabled)
+            .field("charge_type", &self.charge_type)
+            .field("application_fee_bps", &self.application_fee_bps)
+            .field("refresh_url", &self.refresh_url)
+            .field("return_url", &self.return_url)
+            .field("webhook_secret", &"[REDACTED]")
             .finish()
     }
 }
@@ -395,6 +444,9 @@ pub struct X402Config {
     pub tx_queue_min_time_between: Option<Duration>,
     #[serde(default = "default_tx_queue_max_in_flight")]
     pub tx_queue_max_in_flight: usize,
+    /// How server wallet transactions are signed (local keypair or remote signer)
+    #[serde(default)]
+    pub signer: SignerConfig,
 }
 
 // SEC-001c: Custom Debug implementation to prevent server wallet private key exposure in logs
@@ -427,10 +479,68 @@ impl std::fmt::Debug for X402Config {
             .field("rounding_mode", &self.rounding_mode)
             .field("tx_queue_min_time_between", &self.tx_queue_min_time_between)
             .field("tx_queue_max_in_flight", &self.tx_queue_max_in_flight)
+            .field("signer", &self.signer)
             .finish()
     }
 }
 
+/// Where server wallet signatures are produced, per spec 13-security.md.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[derive(Default)]
+pub enum SignerMode {
+    /// `server_wallets` hold private keys and are signed with in-process
+    #[default]
+    Local,
+    /// `server_wallets` hold public keys; an external signing service holds the keys
+    Remote,
+}
+
+/// Transaction signer configuration (`x402.signer`).
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignerConfig {
+    #[serde(default)]
+    pub mode: SignerMode,
+    /// Base URL of the remote signing service, e.g. `https://signer.internal:8443`
+    #[serde(default)]
+    pub remote_url: String,
+    /// PEM client certificate presented to the signing service (mTLS)
+    #[serde(default)]
+    pub client_cert_path: Option<String>,
+    /// PEM private key for `client_cert_path`
+    #[serde(default)]
+    pub client_key_path: Option<String>,
+    /// PEM CA bundle used to verify the signing service certificate
+    #[serde(default)]
+    pub ca_cert_path: Option<String>,
+    /// Accept a plain-HTTP `remote_url` without a client certificate.
+    /// Development only: rejected when `logging.environment` is production.
+    #[serde(default)]
+    pub allow_insecure: bool,
+    /// Per-request timeout for remote signing (default: 5s)
+    #[serde(default = "default_signer_timeout")]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub timeout: Duration,
+    /// Policy applied to every server wallet without its own entry in `policies`
+    #[serde(default)]
+    pub policy: SigningPolicyConfig,
+    /// Per-wallet policies keyed by base58 public key
+    #[serde(default)]
+    pub policies: HashMap<String, SigningPolicyConfig>,
+}
+
+/// Limits checked before a server wallet signs anything.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SigningPolicyConfig {
+    /// Program IDs a signed transaction may invoke (empty = no restriction)
+    #[serde(default)]
+    pub allowed_programs: Vec<String>,
+    /// Largest raw amount for a single SPL Token / Token-22 transfer, mint or burn
+    #[serde(default)]
+    pub max_token_amount: Option<u64>,
+}
+
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PostgresPoolConfig {
@@ -455,6 +565,8 @@ pub enum StorageBackend {
     Memory,
     /// PostgreSQL database (production)
     Postgres,
+    /// SQLite database file (single-node and edge deployments)
+    Sqlite,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -486,6 +598,9 @@ pub struct StorageConfig {
     pub backend: StorageBackend,
     #[serde(default)]
     pub postgres_url: Option<String>,
+    /// Path to the SQLite database file when `backend = sqlite`
+    #[serde(default)]
+    pub sqlite_path: Option<String>,
     #[serde(default = "default_cart_quote_ttl")]
     #[serde_as(as = "DurationSeconds<u64>")]
     pub cart_quote_ttl: Duration,
@@ -524,6 +639,14 @@ pub struct ArchivalConfig {
     #[serde(default = "default_archival_run_interval")]
     #[serde_as(as = "DurationSeconds<u64>")]
     pub run_interval: Duration,
+    /// Write payments, orders, order history and webhooks to the tenant's
+    /// object storage bucket (the `storage` config category used for images)
+    /// before removing them. When false, old payments are deleted outright.
+    #[serde(default)]
+    pub cold_storage: bool,
+    /// Maximum records of each kind written per archive file (default: 1000)
+    #[serde(default = "default_archival_batch_size")]
+    pub batch_size: i64,
 }
 
 impl Default for ArchivalConfig {
@@ -532,6 +655,94 @@ impl Default for ArchivalConfig {
             enabled: false,
             retention_period: default_archival_retention_period(),
             run_interval: default_archival_run_interval(),
+            cold_storage: false,
+            batch_size: default_archival_batch_size(),
         }
     }
 }
+
+/// Where runtime secrets (Stripe keys, server wallets, SMTP password, AI
+/// provider keys) are read from, per spec 13-security.md.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[derive(Default)]
+pub enum SecretProviderKind {
+    /// Secrets come from YAML/env or the `app_config` table only (no hot reload)
+    #[default]
+    None,
+    /// One file per secret in a mounted directory (Kubernetes/Docker secrets)
+    File,
+    /// HashiCorp Vault-compatible KV v2 HTTP API
+    Vault,
+    /// Encrypted `app_config` entries for the default tenant
+    Database,
+}
+
+/// Token prices used to cost AI usage.
+///
+/// Keyed by provider model id (`gpt-4o`, `claude-sonnet-4-5`, or the model
+/// name of an OpenAI-compatible server). Entries override the built-in list
+/// prices; usage of a model with no price is recorded at zero cost.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AiPricingConfig {
+    #[serde(default)]
+    pub models: HashMap<String, AiModelPrice>,
+}
+
+/// Price of a model in USD per million tokens
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AiModelPrice {
+    pub input_per_million: f64,
+    pub output_per_million: f64,
+}
+
+/// Secret provider configuration.
+///
+/// Provider settings are bootstrap values: they are also read from the
+/// environment when the rest of the config comes from the database.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretsConfig {
+    #[serde(default)]
+    pub provider: SecretProviderKind,
+    /// Directory holding one file per secret (`provider = file`)
+    #[serde(default = "default_secrets_dir")]
+    pub dir: String,
+    #[serde(default)]
+    pub vault: VaultSecretsConfig,
+    /// How often the provider is polled for changed values (default: 30s)
+    #[serde(default = "default_secrets_refresh_interval")]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub refresh_interval: Duration,
+}
+
+/// Vault KV v2 settings (`provider = vault`)
+#[derive(Clone, Serialize, Deserialize)]
+pub struct VaultSecretsConfig {
+    /// Vault address, e.g. `https://vault.internal:8200`
+    #[serde(default)]
+    pub address: String,
+    #[serde(default)]
+    pub token: String,
+    /// Optional Vault Enterprise namespace
+    #[serde(default)]
+    pub namespace: Option<String>,
+    /// KV v2 mount point (default: `secret`)
+    #[serde(default = "default_vault_mount")]
+    pub mount: String,
+    /// Secret path under the mount (default: `cedros-pay`)
+    #[serde(default = "default_vault_path")]
+    pub path: String,
+}
+
+impl std::fmt::Debug for VaultSecretsConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VaultSecretsConfig")
+            .field("address", &self.address)
+            .field("token", &"[REDACTED]")
+            .field("namespace", &self.namespace)
+            .field("mount", &self.mount)
+            .field("path", &self.path)
+            .finish()
+    }
+}
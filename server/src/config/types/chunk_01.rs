@@ -8,6 +8,31 @@ fn default_archival_run_interval() -> Duration {
     Duration::from_secs(24 * 60 * 60) // 24 hours
 }
 
+fn default_archival_batch_size() -> i64 {
+    1000
+}
+
+/// Default secrets directory: the Docker/Kubernetes secrets mount
+fn default_secrets_dir() -> String {
+    "/run/secrets".to_string()
+}
+
+fn default_secrets_refresh_interval() -> Duration {
+    Duration::from_secs(30)
+}
+
+fn default_vault_mount() -> String {
+    "secret".to_string()
+}
+
+fn default_vault_path() -> String {
+    "cedros-pay".to_string()
+}
+
+fn default_signer_timeout() -> Duration {
+    Duration::from_secs(5)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[derive(Default)]
@@ -17,6 +42,8 @@ pub enum ProductSource {
     /// PostgreSQL database (production)
     #[default]
     Postgres,
+    /// SQLite database at `storage.sqlite_path`
+    Sqlite,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +55,8 @@ pub enum CouponSource {
     /// PostgreSQL database (production)
     #[default]
     Postgres,
+    /// SQLite database at `storage.sqlite_path`
+    Sqlite,
     /// Coupons disabled
     Disabled,
 }
@@ -151,6 +180,9 @@ pub struct CallbacksConfig {
     /// If not set, uses default JSON payload format.
     #[serde(default)]
     pub body_template: Option<String>,
+    /// Durable log of emitted events, kept separately from the delivery queue
+    #[serde(default)]
+    pub event_log: EventLogConfig,
 }
 
 // SEC-001d: Custom Debug implementation to prevent HMAC secret exposure in logs
@@ -168,10 +200,25 @@ impl std::fmt::Debug for CallbacksConfig {
                 &self.hmac_secret.as_ref().map(|_| "[REDACTED]"),
             )
             .field("body_template", &self.body_template)
+            .field("event_log", &self.event_log)
             .finish()
     }
 }
 
+/// Event log configuration (`callbacks.event_log`)
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventLogConfig {
+    /// Record every emitted event, even when no callback URL is set (default: true)
+    #[serde(default = "default_event_log_enabled")]
+    pub enabled: bool,
+    /// How long events stay readable and replayable (default: 90 days).
+    /// Independent of the webhook queue's 7-day cleanup.
+    #[serde(default = "default_event_log_retention")]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub retention: Duration,
+}
+
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonitoringConfig {
@@ -303,16 +350,75 @@ pub struct ShopCheckoutConfig {
     pub guest_checkout: bool,
 }
 
+/// Customer self-service return policy.
+///
+/// Products can override the window via the `return_window_days` metadata key
+/// (`0` marks the product as non-returnable).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShopReturnsConfig {
+    /// Allow customers to file return requests from the account API.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Default return window in days, counted from delivery (or order date when
+    /// no delivery has been recorded).
+    #[serde(default = "default_return_window_days")]
+    pub window_days: u32,
+    /// Order statuses from which a return may be requested.
+    #[serde(default = "default_returnable_order_statuses")]
+    pub eligible_statuses: Vec<String>,
+    /// Restocking fee in basis points of the returned items' value. Waived for
+    /// merchant-fault reason codes (defective, wrong item, ...).
+    #[serde(default)]
+    pub restocking_fee_bps: u32,
+    /// Return resellable items to inventory when a return is marked received.
+    #[serde(default = "default_true")]
+    pub restock_on_receive: bool,
+    /// How long x402/credits refund quotes created for returns stay pending (hours).
+    #[serde(default = "default_return_refund_quote_ttl_hours")]
+    pub refund_quote_ttl_hours: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ShopConfig {
     #[serde(default)]
     pub checkout: ShopCheckoutConfig,
+    #[serde(default)]
+    pub returns: ShopReturnsConfig,
 }
 
 fn default_guest_checkout() -> bool {
     true
 }
 
+fn default_return_window_days() -> u32 {
+    30
+}
+
+fn default_return_refund_quote_ttl_hours() -> u32 {
+    72
+}
+
+fn default_returnable_order_statuses() -> Vec<String> {
+    vec![
+        "fulfilled".to_string(),
+        "shipped".to_string(),
+        "delivered".to_string(),
+    ]
+}
+
+impl Default for ShopReturnsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            window_days: default_return_window_days(),
+            eligible_statuses: default_returnable_order_statuses(),
+            restocking_fee_bps: 0,
+            restock_on_receive: true,
+            refund_quote_ttl_hours: default_return_refund_quote_ttl_hours(),
+        }
+    }
+}
+
 impl Default for ShopCheckoutConfig {
     fn default() -> Self {
         Self {
@@ -328,9 +434,19 @@ pub struct MessagingConfig {
     /// Enable email receipts to customers after purchase
     #[serde(default)]
     pub email_enabled: bool,
-    /// Email service provider (UI metadata: mailgun, sendgrid, postmark, ses, resend, custom)
+    /// Email transport: `http` posts JSON to `email_api_url`; anything else
+    /// (smtp, or UI labels such as mailgun, sendgrid, postmark) relays via SMTP
     #[serde(default)]
     pub email_provider: String,
+    /// HTTP email API endpoint (when `email_provider` is `http`)
+    #[serde(default)]
+    pub email_api_url: String,
+    /// HTTP email API key, sent as a bearer token (secret)
+    #[serde(default)]
+    pub email_api_key: String,
+    /// HMAC-SHA256 secret verifying inbound bounce/complaint webhooks (secret)
+    #[serde(default)]
+    pub email_webhook_secret: String,
     /// SMTP server hostname
     #[serde(default)]
     pub smtp_host: String,
@@ -370,6 +486,9 @@ impl std::fmt::Debug for MessagingConfig {
         f.debug_struct("MessagingConfig")
             .field("email_enabled", &self.email_enabled)
             .field("email_provider", &self.email_provider)
+            .field("email_api_url", &self.email_api_url)
+            .field("email_api_key", &"[REDACTED]")
+            .field("email_webhook_secret", &"[REDACTED]")
             .field("smtp_host", &self.smtp_host)
             .field("smtp_port", &self.smtp_port)
             .field("smtp_username", &self.smtp_username)
@@ -391,6 +510,18 @@ impl std::fmt::Debug for MessagingConfig {
     }
 }
 
+impl MessagingConfig {
+    /// Whether email goes through the HTTP API transport rather than SMTP
+    pub fn uses_http_api(&self) -> bool {
+        self.email_provider
+            .trim()
+            .eq_ignore_ascii_case(EMAIL_PROVIDER_HTTP)
+    }
+}
+
+/// `email_provider` value selecting the HTTP API transport
+pub const EMAIL_PROVIDER_HTTP: &str = "http";
+
 fn default_smtp_port() -> u16 {
     587
 }
@@ -488,8 +619,35 @@ pub struct Config {
     /// Messaging config for email receipts and webhook notifications
     #[serde(default)]
     pub messaging: MessagingConfig,
+    /// Secret provider for hot-reloaded credentials
+    #[serde(default)]
+    pub secrets: SecretsConfig,
+    /// Token prices for AI usage accounting
+    #[serde(default)]
+    pub ai_pricing: AiPricingConfig,
 }
 
+/// Config categories overlaid from the database, in merge order.
+const DB_MERGE_CATEGORIES: &[&str] = &[
+    "server",
+    "logging",
+    "stripe",
+    "x402",
+    "paywall",
+    "shop",
+    "coupons",
+    "subscriptions",
+    "callbacks",
+    "monitoring",
+    "rate_limit",
+    "circuit_breaker",
+    "admin",
+    "api_keys",
+    "cedros_login",
+    "messaging",
+    "ai_pricing",
+];
+
 impl Config {
     /// Load config from YAML file with env overrides (legacy mode)
     pub fn load(path: Option<&str>) -> Result<Self, ConfigError> {
@@ -521,6 +679,44 @@ impl Config {
         tenant_id: &str,
         postgres_url: &str,
         server_address: &str,
+    ) -> Result<Self, ConfigError> {
+        let categories = repo
+            .list_categories(tenant_id)
+            .await
+            .map_err(|e| ConfigError::Validation(format!("failed to list config categories: {e}")))?;
+        ensure_db_config_present(tenant_id, categories.len())?;
+
+        Self::build_from_db(repo, tenant_id, postgres_url, server_address, &[]).await
+    }
+
+    /// Rebuild this config from the database as [`Self::load_from_db`] would,
+    /// with `overrides` applied on top of the stored entries.
+    ///
+    /// Used by the config bus to publish new versions and to validate admin
+    /// edits before they are saved. Bootstrap values (Postgres URL, server
+    /// address) are carried over from `self`.
+    pub async fn reload_from_db(
+        &self,
+        repo: &crate::config::PostgresConfigRepository,
+        tenant_id: &str,
+        overrides: &[crate::config::ConfigEntry],
+    ) -> Result<Self, ConfigError> {
+        Self::build_from_db(
+            repo,
+            tenant_id,
+            self.storage.postgres_url.as_deref().unwrap_or_default(),
+            &self.server.address,
+            overrides,
+        )
+        .await
+    }
+
+    async fn build_from_db(
+        repo: &crate::config::PostgresConfigRepository,
+        tenant_id: &str,
+        postgres_url: &str,
+        server_address: &str,
+        overrides: &[crate::config::ConfigEntry],
     ) -> Result<Self, ConfigError> {
         // Start with defaults
         let mut cfg = Config::default();
@@ -529,17 +725,24 @@ impl Config {
         cfg.storage.backend = StorageBackend::Postgres;
         cfg.storage.postgres_url = Some(postgres_url.to_string());
         cfg.server.address = server_address.to_string();
-
-        let categories = repo
-            .list_categories(tenant_id)
-            .await
-            .map_err(|e| ConfigError::Validation(format!("failed to list config categories: {e}")))?;
-        ensure_db_config_present(tenant_id, categories.len())?;
+        cfg.apply_secrets_env_overrides();
+        cfg.apply_signer_env_overrides();
 
         // Load from database - this populates all other fields.
         // Missing categories are tolerated (defaults apply), but an empty table is not.
         cfg.merge_from_db(repo, tenant_id).await?;
 
+        for category in DB_MERGE_CATEGORIES {
+            let entries: Vec<_> = overrides
+                .iter()
+                .filter(|entry| entry.category == *category)
+                .cloned()
+                .collect();
+            if !entries.is_empty() {
+                cfg.merge_category(repo, category, &entries).await;
+            }
+        }
+
         // Apply defaults based on storage backend
         cfg.apply_defaults_from_storage();
         cfg.normalize();
@@ -565,6 +768,7 @@ impl Config {
         if self.paywall.product_source.is_none() {
             self.paywall.product_source = match self.storage.backend {
                 StorageBackend::Postgres => Some(ProductSource::Postgres),
+                StorageBackend::Sqlite => Some(ProductSource::Sqlite),
                 StorageBackend::Memory => Some(ProductSource::Memory),
             };
         }
@@ -572,6 +776,7 @@ impl Config {
         if self.coupons.coupon_source.is_none() {
             self.coupons.coupon_source = match self.storage.backend {
                 StorageBackend::Postgres => Some(CouponSource::Postgres),
+                StorageBackend::Sqlite => Some(CouponSource::Sqlite),
                 StorageBackend::Memory => Some(CouponSource::Memory),
             };
         }
@@ -631,8 +836,10 @@ impl Config {
                 ));
             }
         }
+        // Wallets may instead be supplied by the secret provider at startup
         if (self.x402.gasless_enabled || self.x402.auto_create_token_account)
             && self.x402.server_wallets.is_empty()
+            && self.secrets.provider == SecretProviderKind::None
         {
             return Err(ConfigError::Validation(
                 "x402.server_wallets required when gasless_enabled or auto_create_token_account enabled"
@@ -659,9 +866,28 @@ impl Config {
                     ));
                 }
             }
+            StorageBackend::Sqlite => {
+                if self
+                    .storage
+                    .sqlite_path
+                    .as_ref()
+                    .map(|path| path.trim().is_empty())
+                    .unwrap_or(true)
+                {
+                    return Err(ConfigError::Validation(
+                        "storage.sqlite_path is required when storage.backend=sqlite".into(),
+                    ));
+                }
+            }
             StorageBackend::Memory => {}
         }
 
+        if self.storage.archival.cold_storage && self.storage.archival.batch_size <= 0 {
+            return Err(ConfigError::Validation(
+                "storage.archival.batch_size must be positive when cold_storage is enabled".into(),
+            ));
+        }
+
         // Validate Stripe webhook secret when Stripe is configured
         // Webhook verification is critical for security - require explicit opt-out
         if !self.stripe.secret_key.is_empty() {
@@ -684,6 +910,23 @@ impl Config {
             }
         }
 
+        if self.stripe.connect.enabled {
+            if !matches!(
+                self.stripe.connect.charge_type.as_str(),
+                "destination" | "on_behalf_of"
+            ) {
+                return Err(ConfigError::Validation(format!(
+                    "stripe.connect.charge_type must be 'destination' or 'on_behalf_of', got '{}'",
+                    self.stripe.connect.charge_type
+                )));
+            }
+            if self.stripe.connect.application_fee_bps > 10_000 {
+                return Err(ConfigError::Validation(
+                    "stripe.connect.application_fee_bps must be <= 10000".into(),
+                ));
+            }
+        }
+
         // Warn when using test mode in what appears to be production
         if self.stripe.mode == "test" && !self.stripe.secret_key.is_empty() {
             tracing::warn!(
@@ -858,9 +1101,85 @@ impl Config {
             }
         }
 
+        match self.secrets.provider {
+            SecretProviderKind::File if self.secrets.dir.trim().is_empty() => {
+                return Err(ConfigError::Validation(
+                    "secrets.dir is required when secrets.provider=file".into(),
+                ));
+            }
+            SecretProviderKind::Vault => {
+                let address = self.secrets.vault.address.trim();
+                if !address.starts_with("http://") && !address.starts_with("https://") {
+                    return Err(ConfigError::Validation(
+                        "secrets.vault.address must be an HTTP or HTTPS URL when secrets.provider=vault"
+                            .into(),
+                    ));
+                }
+                if self.secrets.vault.token.trim().is_empty() {
+                    return Err(ConfigError::Validation(
+                        "secrets.vault.token (VAULT_TOKEN) is required when secrets.provider=vault"
+                            .into(),
+                    ));
+                }
+            }
+            _ => {}
+        }
+        if self.secrets.provider != SecretProviderKind::None
+            && self.secrets.refresh_interval.is_zero()
+        {
+            return Err(ConfigError::Validation(
+                "secrets.refresh_interval must be positive".into(),
+            ));
+        }
+        if self.x402.signer.mode == SignerMode::Remote {
+            let signer = &self.x402.signer;
+            let url = signer.remote_url.trim();
+            if signer.allow_insecure && self.logging.environment == "production" {
+                return Err(ConfigError::Validation(
+                    "x402.signer.allow_insecure is not allowed in production".into(),
+                ));
+            }
+            // Keys stay out of process only if the signing channel is mutually
+            // authenticated; plain HTTP is a development escape hatch.
+            if signer.allow_insecure {
+                if !url.starts_with("https://") && !url.starts_with("http://") {
+                    return Err(ConfigError::Validation(
+                        "x402.signer.remote_url must be an HTTP(S) URL when signer.mode=remote"
+                            .into(),
+                    ));
+                }
+            } else if !url.starts_with("https://") {
+                return Err(ConfigError::Validation(
+                    "x402.signer.remote_url must be an HTTPS URL when signer.mode=remote".into(),
+                ));
+            }
+            if signer.client_cert_path.is_some() != signer.client_key_path.is_some() {
+                return Err(ConfigError::Validation(
+                    "x402.signer.client_cert_path and client_key_path must be set together".into(),
+                ));
+            }
+            if signer.client_cert_path.is_none() && !signer.allow_insecure {
+                return Err(ConfigError::Validation(
+                    "x402.signer.client_cert_path and client_key_path are required for mTLS when signer.mode=remote"
+                        .into(),
+                ));
+            }
+            if self.x402.signer.timeout.is_zero() {
+                return Err(ConfigError::Validation(
+                    "x402.signer.timeout must be positive".into(),
+                ));
+            }
+        }
+
         // OPS-10: Validate SMTP config when email is enabled
         if self.messaging.email_enabled {
-            if self.messaging.smtp_host.trim().is_empty() {
+            if self.messaging.uses_http_api() {
+                if self.messaging.email_api_url.trim().is_empty() {
+                    return Err(ConfigError::Validation(
+                        "messaging.email_api_url is required when email_provider=http".into(),
+                    ));
+                }
+            } else if self.messaging.smtp_host.trim().is_empty() {
                 return Err(ConfigError::Validation(
                     "messaging.smtp_host is required when email_enabled=true".into(),
                 ));
@@ -926,6 +1245,12 @@ impl Config {
         if let Some(v) = env_var("CEDROS_STRIPE_MODE") {
             self.stripe.mode = v;
         }
+        if let Some(v) = env_var("CEDROS_STRIPE_API_BASE_URL") {
+            self.stripe.api_base_url = v;
+        }
+        if let Some(v) = env_var("CEDROS_STRIPE_CONNECT_WEBHOOK_SECRET") {
+            self.stripe.connect.webhook_secret = v;
+        }
 
         // X402
         if let Some(v) = env_var("CEDROS_X402_PAYMENT_ADDRESS") {
@@ -965,6 +1290,9 @@ impl Config {
         if let Some(v) = env_var("POSTGRES_URL") {
             self.storage.postgres_url = Some(v);
             self.storage.backend = StorageBackend::Postgres;
+        } else if let Some(v) = env_var("SQLITE_PATH") {
+            self.storage.sqlite_path = Some(v);
+            self.storage.backend = StorageBackend::Sqlite;
         }
 
         // Note: Storage archival settings are YAML-only per spec 09-configuration.md lines 290-301
@@ -1002,6 +1330,12 @@ impl Config {
         if let Some(v) = env_duration("CALLBACK_TIMEOUT") {
             self.callbacks.timeout = v;
         }
+        if let Some(v) = env_bool("CALLBACK_EVENT_LOG_ENABLED") {
+            self.callbacks.event_log.enabled = v;
+        }
+        if let Some(v) = env_duration("CALLBACK_EVENT_LOG_RETENTION") {
+            self.callbacks.event_log.retention = v;
+        }
         for (key, val) in env::vars() {
             if let Some(header) = key.strip_prefix("CALLBACK_HEADER_") {
                 self.callbacks
@@ -1076,94 +1410,146 @@ impl Config {
         if let Some(v) = env_duration("CEDROS_LOGIN_TIMEOUT") {
             self.cedros_login.timeout = v;
         }
-    }
 
-    /// Merge configuration from database, overlaying on top of file/env config.
-    ///
-    /// This allows storing config in PostgreSQL while keeping bootstrap config
-    /// (POSTGRES_URL, SERVER_ADDRESS) in environment variables.
-    ///
-    /// Categories loaded from DB: stripe, x402, callbacks, cedros_login, api_keys,
-    /// server, logging, paywall, coupons, subscriptions, rate_limit, circuit_breaker,
-    /// monitoring, admin.
-    ///
-    /// Note: Storage config is NOT loaded from DB since it's needed to connect.
-    pub async fn merge_from_db(
-        &mut self,
-        repo: &crate::config::PostgresConfigRepository,
-        tenant_id: &str,
-    ) -> Result<(), ConfigError> {
-        // Load each category and merge into self
-        // Skip errors for missing categories (use file/env defaults)
+        self.apply_secrets_env_overrides();
+        self.apply_signer_env_overrides();
+    }
 
-        if let Ok(entries) = repo.get_config(tenant_id, "server").await {
-            self.merge_server_config(repo, &entries).await;
+    /// Secret provider bootstrap settings — applied in both YAML and DB modes,
+    /// since the provider must be known before any secret can be read.
+    fn apply_secrets_env_overrides(&mut self) {
+        if let Some(v) = env_var("CEDROS_SECRETS_PROVIDER") {
+            match parse_secret_provider(&v) {
+                Some(kind) => self.secrets.provider = kind,
+                None => tracing::warn!(value = %v, "Unknown CEDROS_SECRETS_PROVIDER, ignoring"),
+            }
         }
-
-        if let Ok(entries) = repo.get_config(tenant_id, "logging").await {
-            self.merge_logging_config(&entries);
+        if let Some(v) = env_var("CEDROS_SECRETS_DIR") {
+            self.secrets.dir = v;
         }
-
-        if let Ok(entries) = repo.get_config(tenant_id, "stripe").await {
-            self.merge_stripe_config(repo, &entries).await;
+        if let Some(v) = env_duration("CEDROS_SECRETS_REFRESH_INTERVAL") {
+            self.secrets.refresh_interval = v;
         }
-
-        if let Ok(entries) = repo.get_config(tenant_id, "x402").await {
-            self.merge_x402_config(repo, &entries).await;
+        if let Some(v) = env_var("VAULT_ADDR") {
+            self.secrets.vault.address = v;
         }
-
-        if let Ok(entries) = repo.get_config(tenant_id, "paywall").await {
-            self.merge_paywall_config(&entries);
+        if let Some(v) = env_var("VAULT_TOKEN") {
+            self.secrets.vault.token = v;
         }
-
-        if let Ok(entries) = repo.get_config(tenant_id, "shop").await {
-            self.merge_shop_config(&entries);
+        if let Some(v) = env_var("VAULT_NAMESPACE") {
+            self.secrets.vault.namespace = Some(v);
         }
-
-        if let Ok(entries) = repo.get_config(tenant_id, "coupons").await {
-            self.merge_coupons_config(&entries);
+        if let Some(v) = env_var("CEDROS_SECRETS_VAULT_MOUNT") {
+            self.secrets.vault.mount = v;
         }
-
-        if let Ok(entries) = repo.get_config(tenant_id, "subscriptions").await {
-            self.merge_subscriptions_config(&entries);
+        if let Some(v) = env_var("CEDROS_SECRETS_VAULT_PATH") {
+            self.secrets.vault.path = v;
         }
+    }
 
-        if let Ok(entries) = repo.get_config(tenant_id, "callbacks").await {
-            self.merge_callbacks_config(repo, &entries).await;
+    /// Transaction signer settings — like the secret provider, these are
+    /// bootstrap values applied in both YAML and DB modes.
+    fn apply_signer_env_overrides(&mut self) {
+        if let Some(v) = env_var("CEDROS_SIGNER_MODE") {
+            match parse_signer_mode(&v) {
+                Some(mode) => self.x402.signer.mode = mode,
+                None => tracing::warn!(value = %v, "Unknown CEDROS_SIGNER_MODE, ignoring"),
+            }
         }
-
-        if let Ok(entries) = repo.get_config(tenant_id, "monitoring").await {
-            self.merge_monitoring_config(&entries);
+        if let Some(v) = env_var("CEDROS_SIGNER_URL") {
+            self.x402.signer.remote_url = v;
         }
-
-        if let Ok(entries) = repo.get_config(tenant_id, "rate_limit").await {
-            self.merge_rate_limit_config(&entries);
+        if let Some(v) = env_var("CEDROS_SIGNER_CLIENT_CERT") {
+            self.x402.signer.client_cert_path = Some(v);
         }
-
-        if let Ok(entries) = repo.get_config(tenant_id, "circuit_breaker").await {
-            self.merge_circuit_breaker_config(&entries);
+        if let Some(v) = env_var("CEDROS_SIGNER_CLIENT_KEY") {
+            self.x402.signer.client_key_path = Some(v);
         }
-
-        if let Ok(entries) = repo.get_config(tenant_id, "admin").await {
-            self.merge_admin_config(&entries);
+        if let Some(v) = env_var("CEDROS_SIGNER_CA_CERT") {
+            self.x402.signer.ca_cert_path = Some(v);
         }
-
-        if let Ok(entries) = repo.get_config(tenant_id, "api_keys").await {
-            self.merge_api_key_config(repo, &entries).await;
+        if let Some(v) = env_bool("CEDROS_SIGNER_ALLOW_INSECURE") {
+            self.x402.signer.allow_insecure = v;
         }
-
-        if let Ok(entries) = repo.get_config(tenant_id, "cedros_login").await {
-            self.merge_cedros_login_config(repo, &entries).await;
+        if let Some(v) = env_duration("CEDROS_SIGNER_TIMEOUT") {
+            self.x402.signer.timeout = v;
+        }
+        if let Some(v) = env_var("CEDROS_SIGNER_ALLOWED_PROGRAMS") {
+            self.x402.signer.policy.allowed_programs = v
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
         }
+        if let Some(v) = env_var("CEDROS_SIGNER_MAX_TOKEN_AMOUNT") {
+            match v.parse() {
+                Ok(max) => self.x402.signer.policy.max_token_amount = Some(max),
+                Err(_) => {
+                    tracing::warn!(value = %v, "Invalid CEDROS_SIGNER_MAX_TOKEN_AMOUNT, ignoring")
+                }
+            }
+        }
+    }
 
-        if let Ok(entries) = repo.get_config(tenant_id, "messaging").await {
-            self.merge_messaging_config(repo, &entries).await;
+    /// Merge configuration from database, overlaying on top of file/env config.
+    ///
+    /// This allows storing config in PostgreSQL while keeping bootstrap config
+    /// (POSTGRES_URL, SERVER_ADDRESS) in environment variables.
+    ///
+    /// Categories loaded from DB: stripe, x402, callbacks, cedros_login, api_keys,
+    /// server, logging, paywall, coupons, subscriptions, rate_limit, circuit_breaker,
+    /// monitoring, admin.
+    ///
+    /// Note: Storage config is NOT loaded from DB since it's needed to connect.
+    pub async fn merge_from_db(
+        &mut self,
+        repo: &crate::config::PostgresConfigRepository,
+        tenant_id: &str,
+    ) -> Result<(), ConfigError> {
+        // Load each category and merge into self
+        // Skip errors for missing categories (use file/env defaults)
+        for category in DB_MERGE_CATEGORIES {
+            if let Ok(entries) = repo.get_config(tenant_id, category).await {
+                self.merge_category(repo, category, &entries).await;
+            }
         }
 
         tracing::info!(tenant_id = %tenant_id, "Config merged from database");
         Ok(())
     }
 
+    /// Overlay one category's entries onto this config.
+    ///
+    /// Unknown categories are ignored. Used by [`Self::merge_from_db`] and to
+    /// build candidate configs when validating admin edits.
+    pub async fn merge_category(
+        &mut self,
+        repo: &crate::config::PostgresConfigRepository,
+        category: &str,
+        entries: &[crate::config::ConfigEntry],
+    ) {
+        match category {
+            "server" => self.merge_server_config(repo, entries).await,
+            "logging" => self.merge_logging_config(entries),
+            "stripe" => self.merge_stripe_config(repo, entries).await,
+            "x402" => self.merge_x402_config(repo, entries).await,
+            "paywall" => self.merge_paywall_config(entries),
+            "shop" => self.merge_shop_config(entries),
+            "coupons" => self.merge_coupons_config(entries),
+            "subscriptions" => self.merge_subscriptions_config(entries),
+            "callbacks" => self.merge_callbacks_config(repo, entries).await,
+            "monitoring" => self.merge_monitoring_config(entries),
+            "rate_limit" => self.merge_rate_limit_config(entries),
+            "circuit_breaker" => self.merge_circuit_breaker_config(entries),
+            "admin" => self.merge_admin_config(entries),
+            "api_keys" => self.merge_api_key_config(repo, entries).await,
+            "cedros_login" => self.merge_cedros_login_config(repo, entries).await,
+            "messaging" => self.merge_messaging_config(repo, entries).await,
+            "ai_pricing" => self.merge_ai_pricing_config(entries),
+            _ => {}
+        }
+    }
+
     async fn merge_server_config(
         &mut self,
         repo: &crate::config::PostgresConfigRepository,
@@ -1319,6 +1705,38 @@ impl Config {
                         self.stripe.mode = v.to_string();
                     }
                 }
+                "connect_enabled" => {
+                    if let Some(v) = value.as_bool() {
+                        self.stripe.connect.enabled = v;
+                    }
+                }
+                "connect_charge_type" => {
+                    if let Some(v) = value.as_str() {
+                        self.stripe.connect.charge_type = v.to_string();
+                    }
+                }
+                "connect_application_fee_bps" => {
+                    if let Some(v) = value.as_u64() {
+                        self.stripe.connect.application_fee_bps = v.min(10_000) as u32;
+                    }
+                }
+                "connect_refresh_url" => {
+                    if let Some(v) = value.as_str() {
+                        self.stripe.connect.refresh_url = v.to_string();
+                    }
+                }
+                "connect_return_url" => {
+                    if let Some(v) = value.as_str() {
+                        self.stripe.connect.return_url = v.to_string();
+                    }
+                }
+                "connect_webhook_secret" => {
+                    if let Some(v) = value.as_str() {
+                        if v != crate::config::REDACTED_PLACEHOLDER {
+                            self.stripe.connect.webhook_secret = v.to_string();
+                        }
+                    }
+                }
                 _ => {}
             }
         }
@@ -1491,6 +1909,40 @@ impl Config {
                         self.shop.checkout.guest_checkout = !v;
                     }
                 }
+                "returns_enabled" | "returns.enabled" => {
+                    if let Some(v) = entry.value.as_bool() {
+                        self.shop.returns.enabled = v;
+                    }
+                }
+                "return_window_days" | "returns.window_days" => {
+                    if let Some(v) = entry.value.as_u64() {
+                        self.shop.returns.window_days = v as u32;
+                    }
+                }
+                "return_eligible_statuses" | "returns.eligible_statuses" => {
+                    if let Some(arr) = entry.value.as_array() {
+                        self.shop.returns.eligible_statuses = arr
+                            .iter()
+                            .filter_map(|v| v.as_str().map(|s| s.trim().to_lowercase()))
+                            .filter(|s| !s.is_empty())
+                            .collect();
+                    }
+                }
+                "return_restocking_fee_bps" | "returns.restocking_fee_bps" => {
+                    if let Some(v) = entry.value.as_u64() {
+                        self.shop.returns.restocking_fee_bps = v.min(10_000) as u32;
+                    }
+                }
+                "return_restock_on_receive" | "returns.restock_on_receive" => {
+                    if let Some(v) = entry.value.as_bool() {
+                        self.shop.returns.restock_on_receive = v;
+                    }
+                }
+                "return_refund_quote_ttl_hours" | "returns.refund_quote_ttl_hours" => {
+                    if let Some(v) = entry.value.as_u64() {
+                        self.shop.returns.refund_quote_ttl_hours = v as u32;
+                    }
+                }
                 _ => {}
             }
         }
@@ -1729,6 +2181,19 @@ impl Config {
         }
     }
 
+    fn merge_ai_pricing_config(&mut self, entries: &[crate::config::ConfigEntry]) {
+        for entry in entries {
+            if entry.config_key == "models" {
+                match serde_json::from_value(entry.value.clone()) {
+                    Ok(models) => self.ai_pricing.models = models,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Invalid ai_pricing.models config, ignoring")
+                    }
+                }
+            }
+        }
+    }
+
     async fn merge_api_key_config(
         &mut self,
         repo: &crate::config::PostgresConfigRepository,
@@ -1841,6 +2306,30 @@ impl Config {
                         self.messaging.email_enabled = v;
                     }
                 }
+                "email_provider" => {
+                    if let Some(v) = value.as_str() {
+                        self.messaging.email_provider = v.to_string();
+                    }
+                }
+                "email_api_url" => {
+                    if let Some(v) = value.as_str() {
+                        self.messaging.email_api_url = v.to_string();
+                    }
+                }
+                "email_api_key" => {
+                    if let Some(v) = value.as_str() {
+                        if v != crate::config::REDACTED_PLACEHOLDER {
+                            self.messaging.email_api_key = v.to_string();
+                        }
+                    }
+                }
+                "email_webhook_secret" => {
+                    if let Some(v) = value.as_str() {
+                        if v != crate::config::REDACTED_PLACEHOLDER {
+                            self.messaging.email_webhook_secret = v.to_string();
+                        }
+                    }
+                }
                 "smtp_host" => {
                     if let Some(v) = value.as_str() {
                         self.messaging.smtp_host = v.to_string();
@@ -47,6 +47,25 @@ fn parse_product_source(value: &str) -> Option<ProductSource> {
     match value.to_ascii_lowercase().as_str() {
         "memory" => Some(ProductSource::Memory),
         "postgres" => Some(ProductSource::Postgres),
+        "sqlite" => Some(ProductSource::Sqlite),
+        _ => None,
+    }
+}
+
+fn parse_secret_provider(value: &str) -> Option<SecretProviderKind> {
+    match value.to_ascii_lowercase().as_str() {
+        "none" => Some(SecretProviderKind::None),
+        "file" => Some(SecretProviderKind::File),
+        "vault" => Some(SecretProviderKind::Vault),
+        "database" | "db" => Some(SecretProviderKind::Database),
+        _ => None,
+    }
+}
+
+fn parse_signer_mode(value: &str) -> Option<SignerMode> {
+    match value.to_ascii_lowercase().as_str() {
+        "local" => Some(SignerMode::Local),
+        "remote" => Some(SignerMode::Remote),
         _ => None,
     }
 }
@@ -55,6 +74,7 @@ fn parse_coupon_source(value: &str) -> Option<CouponSource> {
     match value.to_ascii_lowercase().as_str() {
         "memory" => Some(CouponSource::Memory),
         "postgres" => Some(CouponSource::Postgres),
+        "sqlite" => Some(CouponSource::Sqlite),
         "disabled" => Some(CouponSource::Disabled),
         _ => None,
     }
@@ -164,6 +184,14 @@ fn default_stripe_mode() -> String {
     DEFAULT_STRIPE_MODE.to_string()
 }
 
+fn default_stripe_api_base_url() -> String {
+    "https://api.stripe.com".to_string()
+}
+
+fn default_stripe_connect_charge_type() -> String {
+    "destination".to_string()
+}
+
 fn default_token_decimals() -> u8 {
     6
 }
@@ -335,6 +363,14 @@ fn default_callback_timeout() -> Duration {
     Duration::from_secs(3)
 }
 
+fn default_event_log_enabled() -> bool {
+    true
+}
+
+fn default_event_log_retention() -> Duration {
+    Duration::from_secs(90 * 24 * 60 * 60) // 90 days
+}
+
 fn default_low_balance_threshold() -> f64 {
     0.01
 }
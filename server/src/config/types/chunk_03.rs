@@ -69,6 +69,21 @@ impl Default for StripeConfig {
             cancel_url: String::new(),
             tax_rate_id: String::new(),
             mode: default_stripe_mode(),
+            api_base_url: default_stripe_api_base_url(),
+            connect: StripeConnectConfig::default(),
+        }
+    }
+}
+
+impl Default for StripeConnectConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            charge_type: default_stripe_connect_charge_type(),
+            application_fee_bps: 0,
+            refresh_url: String::new(),
+            return_url: String::new(),
+            webhook_secret: String::new(),
         }
     }
 }
@@ -96,6 +111,23 @@ impl Default for X402Config {
             rounding_mode: default_rounding_mode(),
             tx_queue_min_time_between: default_tx_queue_min_time_between(),
             tx_queue_max_in_flight: default_tx_queue_max_in_flight(),
+            signer: SignerConfig::default(),
+        }
+    }
+}
+
+impl Default for SignerConfig {
+    fn default() -> Self {
+        Self {
+            mode: SignerMode::Local,
+            remote_url: String::new(),
+            client_cert_path: None,
+            client_key_path: None,
+            ca_cert_path: None,
+            allow_insecure: false,
+            timeout: default_signer_timeout(),
+            policy: SigningPolicyConfig::default(),
+            policies: HashMap::new(),
         }
     }
 }
@@ -131,6 +163,7 @@ impl Default for StorageConfig {
         Self {
             backend: StorageBackend::Memory,
             postgres_url: None,
+            sqlite_path: None,
             cart_quote_ttl: default_cart_quote_ttl(),
             refund_quote_ttl: default_refund_quote_ttl(),
             postgres_pool: PostgresPoolConfig::default(),
@@ -201,6 +234,16 @@ impl Default for CallbacksConfig {
             dlq_path: None,
             hmac_secret: None,
             body_template: None,
+            event_log: EventLogConfig::default(),
+        }
+    }
+}
+
+impl Default for EventLogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_event_log_enabled(),
+            retention: default_event_log_retention(),
         }
     }
 }
@@ -274,6 +317,9 @@ impl Default for MessagingConfig {
         Self {
             email_enabled: false,
             email_provider: String::new(),
+            email_api_url: String::new(),
+            email_api_key: String::new(),
+            email_webhook_secret: String::new(),
             smtp_host: String::new(),
             smtp_port: default_smtp_port(),
             smtp_username: String::new(),
@@ -288,6 +334,29 @@ impl Default for MessagingConfig {
     }
 }
 
+impl Default for SecretsConfig {
+    fn default() -> Self {
+        Self {
+            provider: SecretProviderKind::None,
+            dir: default_secrets_dir(),
+            vault: VaultSecretsConfig::default(),
+            refresh_interval: default_secrets_refresh_interval(),
+        }
+    }
+}
+
+impl Default for VaultSecretsConfig {
+    fn default() -> Self {
+        Self {
+            address: String::new(),
+            token: String::new(),
+            namespace: None,
+            mount: default_vault_mount(),
+            path: default_vault_path(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -321,6 +390,63 @@ mod tests {
         ensure_db_config_present("default", 1).unwrap();
     }
 
+    #[test]
+    fn test_vault_secret_provider_requires_address_and_token() {
+        let mut cfg = base_config();
+        cfg.secrets.provider = SecretProviderKind::Vault;
+        assert!(matches!(cfg.validate(), Err(ConfigError::Validation(_))));
+
+        cfg.secrets.vault.address = "https://vault.internal:8200".to_string();
+        assert!(matches!(cfg.validate(), Err(ConfigError::Validation(_))));
+
+        cfg.secrets.vault.token = "s.token".to_string();
+        cfg.validate().unwrap();
+        assert!(!format!("{:?}", cfg.secrets).contains("s.token"));
+    }
+
+    #[test]
+    fn test_secret_provider_supplies_server_wallets() {
+        let mut cfg = base_config();
+        cfg.x402.gasless_enabled = true;
+        assert!(matches!(cfg.validate(), Err(ConfigError::Validation(_))));
+
+        cfg.secrets.provider = SecretProviderKind::File;
+        cfg.validate().unwrap();
+    }
+
+    #[test]
+    fn test_remote_signer_requires_url_and_complete_identity() {
+        let mut cfg = base_config();
+        cfg.x402.signer.mode = SignerMode::Remote;
+        assert!(matches!(cfg.validate(), Err(ConfigError::Validation(_))));
+
+        cfg.x402.signer.remote_url = "https://signer.internal:8443".to_string();
+        cfg.x402.signer.client_cert_path = Some("/etc/cedros/client.pem".to_string());
+        assert!(matches!(cfg.validate(), Err(ConfigError::Validation(_))));
+
+        cfg.x402.signer.client_key_path = Some("/etc/cedros/client.key".to_string());
+        cfg.validate().unwrap();
+    }
+
+    #[test]
+    fn test_remote_signer_requires_mtls_unless_insecure_dev() {
+        let mut cfg = base_config();
+        cfg.x402.signer.mode = SignerMode::Remote;
+        cfg.x402.signer.remote_url = "http://127.0.0.1:9000".to_string();
+        assert!(matches!(cfg.validate(), Err(ConfigError::Validation(_))));
+
+        cfg.x402.signer.remote_url = "https://signer.internal:8443".to_string();
+        assert!(matches!(cfg.validate(), Err(ConfigError::Validation(_))));
+
+        cfg.x402.signer.remote_url = "http://127.0.0.1:9000".to_string();
+        cfg.x402.signer.allow_insecure = true;
+        cfg.logging.environment = "development".to_string();
+        cfg.validate().unwrap();
+
+        cfg.logging.environment = "production".to_string();
+        assert!(matches!(cfg.validate(), Err(ConfigError::Validation(_))));
+    }
+
     #[test]
     fn test_callbacks_webhook_url_rejects_private_ip() {
         let mut cfg = base_config();
@@ -481,6 +607,8 @@ mod tests {
             cancel_url: "https://example.com/cancel".to_string(),
             tax_rate_id: "taxr_123".to_string(),
             mode: "live".to_string(),
+            api_base_url: "https://api.stripe.com".to_string(),
+            connect: StripeConnectConfig::default(),
         };
 
         let debug_output = format!("{:?}", config);
@@ -535,6 +663,7 @@ mod tests {
             rounding_mode: "up".to_string(),
             tx_queue_min_time_between: None,
             tx_queue_max_in_flight: 10,
+            signer: SignerConfig::default(),
         };
 
         let debug_output = format!("{:?}", config);
@@ -610,6 +739,7 @@ mod tests {
             dlq_path: None,
             hmac_secret: Some("supersecret_hmac_key_12345".to_string()),
             body_template: None,
+            event_log: EventLogConfig::default(),
         };
 
         let debug_output = format!("{:?}", config);
@@ -109,6 +109,9 @@ pub const MAX_REQUEST_BODY_SIZE: usize = 2 * 1024 * 1024;
 /// Maximum image upload size (10 MiB)
 pub const MAX_IMAGE_UPLOAD_SIZE: usize = 10 * 1024 * 1024;
 
+/// Maximum bulk product import file size (20 MiB)
+pub const MAX_PRODUCT_IMPORT_SIZE: usize = 20 * 1024 * 1024;
+
 /// Maximum number of items in a cart
 pub const MAX_CART_ITEMS: usize = 100;
 
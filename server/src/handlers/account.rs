@@ -0,0 +1,642 @@
+//! Customer account endpoints (cedros-login authenticated).
+//!
+//! Lets signed-in customers view their orders, manage saved addresses and file
+//! return requests without admin involvement.
+//!
+//! Routes (nested under `/paywall/v1`):
+//! - `GET  /account/orders`                   — list the caller's orders
+//! - `GET  /account/orders/:order_id`         — order detail with fulfillments, history, returns
+//! - `POST /account/orders/:order_id/returns` — file a return request
+//! - `GET  /account/addresses`                — list saved addresses
+//! - `PUT  /account/addresses`                — replace saved addresses
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::config::ShopReturnsConfig;
+use crate::errors::validation::validate_resource_id;
+use crate::errors::{error_response, ErrorCode};
+use crate::handlers::response::{json_error, json_ok};
+use crate::middleware::tenant::TenantContext;
+use crate::models::{
+    Customer, CustomerAddress, Fulfillment, Order, OrderHistoryEntry, ReturnItem, ReturnRequest,
+};
+use crate::repositories::ProductRepository;
+use crate::services::returns::{
+    load_order_products, new_customer_return, returnable_items, validate_customer_return,
+    validate_items, ReturnPolicyError, ReturnableItem,
+};
+use crate::services::CedrosLoginClient;
+use crate::storage::Store;
+
+/// Maximum number of saved addresses per customer.
+const MAX_SAVED_ADDRESSES: usize = 10;
+/// Maximum length of a single address field.
+const MAX_ADDRESS_FIELD_LEN: usize = 200;
+/// Maximum length of a customer-supplied return reason.
+const MAX_RETURN_REASON_LEN: usize = 1000;
+
+/// State for customer account routes.
+pub struct AccountState {
+    pub store: Arc<dyn Store>,
+    pub products: Arc<dyn ProductRepository>,
+    /// Auth client — when absent every account route responds 401.
+    pub cedros_login: Option<Arc<CedrosLoginClient>>,
+    pub returns: ShopReturnsConfig,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListAccountOrdersQuery {
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAccountOrdersResponse {
+    pub orders: Vec<Order>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountOrderDetailResponse {
+    pub order: Order,
+    pub fulfillments: Vec<Fulfillment>,
+    pub history: Vec<OrderHistoryEntry>,
+    pub returns: Vec<ReturnRequest>,
+    pub returnable_items: Vec<ReturnableItem>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAccountReturnRequest {
+    pub items: Vec<ReturnItem>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAddressesRequest {
+    pub addresses: Vec<CustomerAddress>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressesResponse {
+    pub addresses: Vec<CustomerAddress>,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Handlers
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// GET /account/orders
+pub async fn list_orders(
+    State(state): State<Arc<AccountState>>,
+    tenant: TenantContext,
+    headers: HeaderMap,
+    Query(query): Query<ListAccountOrdersQuery>,
+) -> Response {
+    let user_id = match require_user(&state, &headers).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    list_orders_for_user(&state, &tenant.tenant_id, &user_id, query).await
+}
+
+/// GET /account/orders/:order_id
+pub async fn get_order(
+    State(state): State<Arc<AccountState>>,
+    tenant: TenantContext,
+    headers: HeaderMap,
+    Path(order_id): Path<String>,
+) -> Response {
+    let user_id = match require_user(&state, &headers).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    get_order_for_user(&state, &tenant.tenant_id, &user_id, &order_id).await
+}
+
+/// POST /account/orders/:order_id/returns
+pub async fn create_return(
+    State(state): State<Arc<AccountState>>,
+    tenant: TenantContext,
+    headers: HeaderMap,
+    Path(order_id): Path<String>,
+    Json(req): Json<CreateAccountReturnRequest>,
+) -> Response {
+    let user_id = match require_user(&state, &headers).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    create_return_for_user(&state, &tenant.tenant_id, &user_id, &order_id, req).await
+}
+
+/// GET /account/addresses
+pub async fn list_addresses(
+    State(state): State<Arc<AccountState>>,
+    tenant: TenantContext,
+    headers: HeaderMap,
+) -> Response {
+    let user_id = match require_user(&state, &headers).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    match state.store.get_customer(&tenant.tenant_id, &user_id).await {
+        Ok(customer) => json_ok(AddressesResponse {
+            addresses: customer.map(|c| c.addresses).unwrap_or_default(),
+        })
+        .into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to load customer addresses");
+            let (status, body) = error_response(ErrorCode::DatabaseError, None, None);
+            json_error(status, body).into_response()
+        }
+    }
+}
+
+/// PUT /account/addresses
+pub async fn update_addresses(
+    State(state): State<Arc<AccountState>>,
+    tenant: TenantContext,
+    headers: HeaderMap,
+    Json(req): Json<UpdateAddressesRequest>,
+) -> Response {
+    let user_id = match require_user(&state, &headers).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    update_addresses_for_user(&state, &tenant.tenant_id, &user_id, req).await
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Implementation (caller already authenticated)
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub(crate) async fn list_orders_for_user(
+    state: &AccountState,
+    tenant_id: &str,
+    user_id: &str,
+    query: ListAccountOrdersQuery,
+) -> Response {
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    let offset = query.offset.unwrap_or(0).max(0);
+    match state
+        .store
+        .list_orders_by_user_id(tenant_id, user_id, limit, offset)
+        .await
+    {
+        Ok(orders) => json_ok(ListAccountOrdersResponse { orders }).into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to list customer orders");
+            let (status, body) = error_response(ErrorCode::DatabaseError, None, None);
+            json_error(status, body).into_response()
+        }
+    }
+}
+
+pub(crate) async fn get_order_for_user(
+    state: &AccountState,
+    tenant_id: &str,
+    user_id: &str,
+    order_id: &str,
+) -> Response {
+    let order = match load_owned_order(state, tenant_id, user_id, order_id).await {
+        Ok(order) => order,
+        Err(resp) => return resp,
+    };
+
+    let (fulfillments, history, returns) = match tokio::try_join!(
+        state.store.list_fulfillments(tenant_id, &order.id, 100),
+        state.store.list_order_history(tenant_id, &order.id, 100),
+        state
+            .store
+            .list_return_requests(tenant_id, None, Some(&order.id), 100, 0),
+    ) {
+        Ok(parts) => parts,
+        Err(e) => {
+            tracing::error!(error = %e, order_id = %order.id, "Failed to load order detail");
+            let (status, body) = error_response(ErrorCode::DatabaseError, None, None);
+            return json_error(status, body).into_response();
+        }
+    };
+
+    let products = load_order_products(&*state.products, tenant_id, &order)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(error = %e, order_id = %order.id, "Failed to load order products");
+            HashMap::new()
+        });
+    let returnable_items = returnable_items(
+        &state.returns,
+        &order,
+        &fulfillments,
+        &returns,
+        &products,
+        Utc::now(),
+    );
+
+    json_ok(AccountOrderDetailResponse {
+        order,
+        fulfillments,
+        history,
+        returns,
+        returnable_items,
+    })
+    .into_response()
+}
+
+pub(crate) async fn create_return_for_user(
+    state: &AccountState,
+    tenant_id: &str,
+    user_id: &str,
+    order_id: &str,
+    req: CreateAccountReturnRequest,
+) -> Response {
+    if let Err(message) = validate_items(&req.items) {
+        let (status, body) = error_response(ErrorCode::InvalidField, Some(message), None);
+        return json_error(status, body).into_response();
+    }
+    let reason = req
+        .reason
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty());
+    if reason
+        .as_ref()
+        .is_some_and(|r| r.len() > MAX_RETURN_REASON_LEN)
+    {
+        let (status, body) = error_response(
+            ErrorCode::InvalidField,
+            Some(format!(
+                "reason must be at most {} characters",
+                MAX_RETURN_REASON_LEN
+            )),
+            Some(serde_json::json!({ "field": "reason" })),
+        );
+        return json_error(status, body).into_response();
+    }
+
+    let order = match load_owned_order(state, tenant_id, user_id, order_id).await {
+        Ok(order) => order,
+        Err(resp) => return resp,
+    };
+
+    let now = Utc::now();
+    match validate_customer_return(
+        &*state.store,
+        &*state.products,
+        &state.returns,
+        &order,
+        &req.items,
+        now,
+    )
+    .await
+    {
+        Ok(()) => {}
+        Err(ReturnPolicyError::Ineligible(reason)) => {
+            let (status, body) =
+                error_response(ErrorCode::InvalidOperation, Some(reason.to_string()), None);
+            return json_error(status, body).into_response();
+        }
+        Err(e) => {
+            tracing::error!(error = %e, order_id = %order.id, "Failed to evaluate return eligibility");
+            let (status, body) = error_response(ErrorCode::DatabaseError, None, None);
+            return json_error(status, body).into_response();
+        }
+    }
+
+    let request = new_customer_return(&order, user_id, req.items, reason, now);
+
+    match state.store.create_return_request(request.clone()).await {
+        Ok(()) => json_ok(request).into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, order_id = %order.id, "Failed to create return request");
+            let (status, body) = error_response(ErrorCode::DatabaseError, None, None);
+            json_error(status, body).into_response()
+        }
+    }
+}
+
+pub(crate) async fn update_addresses_for_user(
+    state: &AccountState,
+    tenant_id: &str,
+    user_id: &str,
+    req: UpdateAddressesRequest,
+) -> Response {
+    if let Err(message) = validate_addresses(&req.addresses) {
+        let (status, body) = error_response(
+            ErrorCode::InvalidField,
+            Some(message),
+            Some(serde_json::json!({ "field": "addresses" })),
+        );
+        return json_error(status, body).into_response();
+    }
+
+    let now = Utc::now();
+    let existing = match state.store.get_customer(tenant_id, user_id).await {
+        Ok(c) => c,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to load customer");
+            let (status, body) = error_response(ErrorCode::DatabaseError, None, None);
+            return json_error(status, body).into_response();
+        }
+    };
+
+    let result = match existing {
+        Some(mut customer) => {
+            customer.addresses = req.addresses.clone();
+            customer.updated_at = now;
+            state.store.update_customer(customer).await
+        }
+        None => {
+            // Customer records for account holders are keyed by their cedros-login user id.
+            // Seed the email from the most recent order so admins can find the record.
+            let email = state
+                .store
+                .list_orders_by_user_id(tenant_id, user_id, 1, 0)
+                .await
+                .ok()
+                .and_then(|orders| orders.into_iter().find_map(|o| o.customer_email))
+                .unwrap_or_default();
+            state
+                .store
+                .create_customer(Customer {
+                    id: user_id.to_string(),
+                    tenant_id: tenant_id.to_string(),
+                    email,
+                    name: None,
+                    phone: None,
+                    addresses: req.addresses.clone(),
+                    created_at: now,
+                    updated_at: now,
+                })
+                .await
+        }
+    };
+
+    match result {
+        Ok(()) => json_ok(AddressesResponse {
+            addresses: req.addresses,
+        })
+        .into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to save customer addresses");
+            let (status, body) = error_response(ErrorCode::DatabaseError, None, None);
+            json_error(status, body).into_response()
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Resolve the caller's user id from `Authorization: Bearer <jwt>`.
+async fn require_user(state: &AccountState, headers: &HeaderMap) -> Result<String, Response> {
+    let auth = headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    let user_id = match state.cedros_login.as_ref() {
+        Some(client) if !auth.is_empty() => client.extract_user_id_from_auth_header(auth).await,
+        _ => None,
+    };
+    user_id.ok_or_else(|| {
+        let (status, body) = error_response(
+            ErrorCode::Unauthorized,
+            Some("missing or invalid authorization".into()),
+            None,
+        );
+        json_error(status, body).into_response()
+    })
+}
+
+/// Load an order and verify it belongs to the caller.
+///
+/// Orders owned by someone else are reported as not found so that order ids
+/// cannot be probed.
+async fn load_owned_order(
+    state: &AccountState,
+    tenant_id: &str,
+    user_id: &str,
+    order_id: &str,
+) -> Result<Order, Response> {
+    if let Err(e) = validate_resource_id(order_id) {
+        let (status, body) = error_response(ErrorCode::InvalidResource, Some(e.message), None);
+        return Err(json_error(status, body).into_response());
+    }
+    match state.store.get_order(tenant_id, order_id).await {
+        Ok(Some(order)) if order.user_id.as_deref() == Some(user_id) => Ok(order),
+        Ok(_) => {
+            let (status, body) = error_response(
+                ErrorCode::ResourceNotFound,
+                Some("order not found".into()),
+                None,
+            );
+            Err(json_error(status, body).into_response())
+        }
+        Err(e) => {
+            tracing::error!(error = %e, order_id = %order_id, "Failed to load order");
+            let (status, body) = error_response(ErrorCode::DatabaseError, None, None);
+            Err(json_error(status, body).into_response())
+        }
+    }
+}
+
+fn validate_addresses(addresses: &[CustomerAddress]) -> Result<(), String> {
+    if addresses.len() > MAX_SAVED_ADDRESSES {
+        return Err(format!(
+            "at most {} addresses may be saved",
+            MAX_SAVED_ADDRESSES
+        ));
+    }
+    for address in addresses {
+        let fields = [
+            &address.line1,
+            &address.line2,
+            &address.city,
+            &address.state,
+            &address.postal_code,
+            &address.country,
+        ];
+        if fields
+            .iter()
+            .any(|f| f.as_ref().is_some_and(|v| v.len() > MAX_ADDRESS_FIELD_LEN))
+        {
+            return Err(format!(
+                "address fields must be at most {} characters",
+                MAX_ADDRESS_FIELD_LEN
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::http::StatusCode;
+    use chrono::Duration;
+
+    use crate::repositories::InMemoryProductRepository;
+    use crate::storage::InMemoryStore;
+
+    fn state(store: Arc<InMemoryStore>) -> AccountState {
+        AccountState {
+            store,
+            products: Arc::new(InMemoryProductRepository::new(Vec::new())),
+            cedros_login: None,
+            returns: ShopReturnsConfig::default(),
+        }
+    }
+
+    fn order(id: &str, user_id: &str, status: &str) -> Order {
+        let now = Utc::now();
+        Order {
+            id: id.to_string(),
+            tenant_id: "default".to_string(),
+            source: "stripe".to_string(),
+            purchase_id: format!("pi_{id}"),
+            resource_id: "prod-1".to_string(),
+            user_id: Some(user_id.to_string()),
+            customer: None,
+            status: status.to_string(),
+            items: vec![crate::models::OrderItem {
+                product_id: "prod-1".to_string(),
+                variant_id: None,
+                quantity: 1,
+                line_total: None,
+            }],
+            amount: 1000,
+            amount_asset: "USD".to_string(),
+            customer_email: Some("buyer@example.com".to_string()),
+            customer_name: None,
+            receipt_url: None,
+            shipping: None,
+            metadata: HashMap::new(),
+            created_at: now - Duration::days(1),
+            updated_at: Some(now),
+            status_updated_at: Some(now),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_requires_authorization() {
+        let state = Arc::new(state(Arc::new(InMemoryStore::new())));
+        let response = list_orders(
+            State(state),
+            TenantContext::default(),
+            HeaderMap::new(),
+            Query(ListAccountOrdersQuery {
+                limit: None,
+                offset: None,
+            }),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_order_detail_hides_other_users_orders() {
+        let store = Arc::new(InMemoryStore::new());
+        store
+            .try_store_order(order("ord-1", "user-1", "delivered"))
+            .await
+            .unwrap();
+        let state = state(store);
+
+        let own = get_order_for_user(&state, "default", "user-1", "ord-1").await;
+        assert_eq!(own.status(), StatusCode::OK);
+        let other = get_order_for_user(&state, "default", "user-2", "ord-1").await;
+        assert_eq!(other.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_create_return_enforces_policy() {
+        let store = Arc::new(InMemoryStore::new());
+        store
+            .try_store_order(order("ord-1", "user-1", "delivered"))
+            .await
+            .unwrap();
+        store
+            .try_store_order(order("ord-2", "user-1", "paid"))
+            .await
+            .unwrap();
+        let state = state(store.clone());
+
+        let req = || CreateAccountReturnRequest {
+            items: vec![ReturnItem {
+                product_id: "prod-1".to_string(),
+                variant_id: None,
+                quantity: 1,
+                condition: None,
+                reason_code: Some("size_or_fit".to_string()),
+            }],
+            reason: Some("too small".to_string()),
+        };
+
+        let ok = create_return_for_user(&state, "default", "user-1", "ord-1", req()).await;
+        assert_eq!(ok.status(), StatusCode::OK);
+        let returns = store
+            .list_return_requests("default", None, Some("ord-1"), 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(returns.len(), 1);
+        assert_eq!(
+            returns[0].metadata.get("user_id").map(String::as_str),
+            Some("user-1")
+        );
+
+        // Quantity already covered by the first return.
+        let dup = create_return_for_user(&state, "default", "user-1", "ord-1", req()).await;
+        assert_eq!(dup.status(), StatusCode::BAD_REQUEST);
+
+        // Paid-but-unshipped orders are not eligible under the default policy.
+        let unshipped = create_return_for_user(&state, "default", "user-1", "ord-2", req()).await;
+        assert_eq!(unshipped.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_update_addresses_creates_customer() {
+        let store = Arc::new(InMemoryStore::new());
+        store
+            .try_store_order(order("ord-1", "user-1", "paid"))
+            .await
+            .unwrap();
+        let state = state(store.clone());
+
+        let response = update_addresses_for_user(
+            &state,
+            "default",
+            "user-1",
+            UpdateAddressesRequest {
+                addresses: vec![CustomerAddress {
+                    line1: Some("1 Main St".to_string()),
+                    city: Some("Springfield".to_string()),
+                    ..Default::default()
+                }],
+            },
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let customer = store
+            .get_customer("default", "user-1")
+            .await
+            .unwrap()
+            .expect("customer created");
+        assert_eq!(customer.email, "buyer@example.com");
+        assert_eq!(customer.addresses.len(), 1);
+    }
+}
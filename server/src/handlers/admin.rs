@@ -23,7 +23,7 @@ use crate::handlers::response::{json_error, json_ok};
 use crate::middleware::TenantContext;
 use crate::models::{AdminAuditEntry, Product, ProductImage, ProductVariant};
 use crate::repositories::{CouponRepository, ProductRepository};
-use crate::storage::Store;
+use crate::storage::{EmbeddingEntity, EmbeddingJob, Store};
 
 // Re-export moved handlers so the router in lib.rs keeps working unchanged.
 pub use crate::handlers::admin_coupons::{
@@ -75,6 +75,26 @@ pub(crate) async fn audit(
     }
 }
 
+/// Queue a product or FAQ for re-embedding after a write (best-effort, never
+/// fails the request). The embedding worker skips tenants without an
+/// embeddings model.
+pub(crate) async fn queue_embedding(
+    store: &dyn Store,
+    tenant_id: &str,
+    source_type: EmbeddingEntity,
+    source_id: &str,
+) {
+    let job = EmbeddingJob::new(tenant_id, source_type, source_id);
+    if let Err(e) = store.enqueue_embedding_job(job).await {
+        tracing::warn!(
+            error = %e,
+            source_type = %source_type,
+            source_id,
+            "Failed to queue embedding job"
+        );
+    }
+}
+
 // ============================================================================
 // Admin audit history endpoint (R12)
 // ============================================================================
@@ -135,6 +155,8 @@ pub struct AdminState {
     pub coupon_repo: Arc<dyn CouponRepository>,
     /// Optional Stripe client for auto-creating products/prices
     pub stripe_client: Option<Arc<crate::services::StripeClient>>,
+    /// Optional messaging service for customer emails (e.g. shipment notices)
+    pub messaging: Option<Arc<dyn crate::services::MessagingService>>,
 }
 
 // ============================================================================
@@ -558,6 +580,7 @@ mod tests {
             product_repo: Arc::new(FailingListProductsRepo),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
 
         let tenant = TenantContext::default();
@@ -602,6 +625,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(products)),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
 
         let response = super::get_stats(State(state), TenantContext::default())
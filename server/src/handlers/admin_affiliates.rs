@@ -0,0 +1,553 @@
+//! Admin affiliate handlers
+//!
+//! Referral partner management, commission ledger and payout reports.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{error_response, ErrorCode};
+use crate::handlers::admin::{audit, AdminState};
+use crate::handlers::response::{json_error, json_ok};
+use crate::middleware::TenantContext;
+use crate::models::affiliate::normalize_referral_code;
+use crate::models::{
+    Affiliate, AffiliateCommission, AffiliateCommissionRule, CommissionEntryKind, CommissionType,
+};
+use crate::storage::StorageError;
+
+use super::cap_limit_opt;
+
+/// Default report window when `from` is omitted.
+const DEFAULT_PAYOUT_REPORT_DAYS: i64 = 30;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAffiliatesQuery {
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AffiliateRequest {
+    /// Ignored on update
+    pub id: Option<String>,
+    pub code: String,
+    pub name: String,
+    pub email: Option<String>,
+    pub payout_wallet: Option<String>,
+    #[serde(default = "default_active")]
+    pub active: bool,
+    #[serde(default)]
+    pub commission_type: CommissionType,
+    pub commission_value: i64,
+    #[serde(default)]
+    pub rules: Vec<AffiliateCommissionRule>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAffiliatesResponse {
+    pub affiliates: Vec<Affiliate>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct ReportWindowQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AffiliateCommissionsResponse {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub totals: Vec<AffiliatePayoutLine>,
+    pub commissions: Vec<AffiliateCommission>,
+}
+
+/// Per-affiliate, per-currency commission totals.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AffiliatePayoutLine {
+    pub affiliate_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payout_wallet: Option<String>,
+    pub currency: String,
+    pub orders: usize,
+    pub earned: i64,
+    /// Reversed commission (positive amount)
+    pub reversed: i64,
+    /// Amount owed for the window (`earned - reversed`)
+    pub net: i64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AffiliatePayoutsResponse {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub payouts: Vec<AffiliatePayoutLine>,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+fn report_window(
+    query: &ReportWindowQuery,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), (axum::http::StatusCode, Json<serde_json::Value>)> {
+    let to = query.to.unwrap_or_else(Utc::now);
+    let from = query
+        .from
+        .unwrap_or_else(|| to - Duration::days(DEFAULT_PAYOUT_REPORT_DAYS));
+    if from >= to {
+        let (status, body) = error_response(
+            ErrorCode::InvalidField,
+            Some("from must be before to".to_string()),
+            None,
+        );
+        return Err(json_error(status, body));
+    }
+    Ok((from, to))
+}
+
+/// Sum ledger entries per affiliate and currency (sorted by affiliate, currency).
+pub(crate) fn summarize_commissions(entries: &[AffiliateCommission]) -> Vec<AffiliatePayoutLine> {
+    let mut totals: BTreeMap<(String, String), AffiliatePayoutLine> = BTreeMap::new();
+    for entry in entries {
+        let line = totals
+            .entry((entry.affiliate_id.clone(), entry.currency.clone()))
+            .or_insert_with(|| AffiliatePayoutLine {
+                affiliate_id: entry.affiliate_id.clone(),
+                code: None,
+                payout_wallet: None,
+                currency: entry.currency.clone(),
+                orders: 0,
+                earned: 0,
+                reversed: 0,
+                net: 0,
+            });
+        match entry.kind {
+            CommissionEntryKind::Earned => {
+                line.orders += 1;
+                line.earned += entry.amount;
+            }
+            CommissionEntryKind::Reversed => line.reversed -= entry.amount,
+        }
+        line.net += entry.amount;
+    }
+    totals.into_values().collect()
+}
+
+fn build_affiliate(
+    tenant_id: &str,
+    id: String,
+    req: AffiliateRequest,
+    created_at: DateTime<Utc>,
+) -> Result<Affiliate, String> {
+    let affiliate = Affiliate {
+        id,
+        tenant_id: tenant_id.to_string(),
+        code: normalize_referral_code(&req.code),
+        name: req.name.trim().to_string(),
+        email: req.email.filter(|e| !e.trim().is_empty()),
+        payout_wallet: req.payout_wallet.filter(|w| !w.trim().is_empty()),
+        active: req.active,
+        commission_type: req.commission_type,
+        commission_value: req.commission_value,
+        rules: req.rules,
+        created_at,
+        updated_at: Utc::now(),
+    };
+    affiliate.validate()?;
+    if let Some(ref wallet) = affiliate.payout_wallet {
+        crate::x402::utils::validate_wallet_address(wallet)
+            .map_err(|_| "payoutWallet must be a valid Solana address".to_string())?;
+    }
+    Ok(affiliate)
+}
+
+async fn save_affiliate(
+    state: &AdminState,
+    tenant: &TenantContext,
+    affiliate: Affiliate,
+    action: &str,
+) -> axum::response::Response {
+    match state.store.upsert_affiliate(affiliate.clone()).await {
+        Ok(()) => {
+            audit(
+                &*state.store,
+                tenant,
+                "affiliate",
+                &affiliate.id,
+                action,
+                None,
+            )
+            .await;
+            json_ok(affiliate).into_response()
+        }
+        Err(StorageError::Conflict) => {
+            let (status, body) = error_response(
+                ErrorCode::InvalidField,
+                Some("referral code already in use".to_string()),
+                None,
+            );
+            json_error(status, body).into_response()
+        }
+        Err(e) => {
+            let (status, body) = error_response(
+                ErrorCode::DatabaseError,
+                Some(format!("Failed to save affiliate: {e}")),
+                None,
+            );
+            json_error(status, body).into_response()
+        }
+    }
+}
+
+/// GET /admin/affiliates
+pub async fn list_affiliates(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Query(params): Query<ListAffiliatesQuery>,
+) -> impl IntoResponse {
+    let limit = cap_limit_opt(params.limit, 50);
+    let offset = params.offset.unwrap_or(0).max(0);
+    match state
+        .store
+        .list_affiliates(&tenant.tenant_id, limit, offset)
+        .await
+    {
+        Ok(affiliates) => json_ok(ListAffiliatesResponse { affiliates }),
+        Err(e) => {
+            let (status, body) = error_response(
+                ErrorCode::DatabaseError,
+                Some(format!("Failed to list affiliates: {e}")),
+                None,
+            );
+            json_error(status, body)
+        }
+    }
+}
+
+/// GET /admin/affiliates/{id}
+pub async fn get_affiliate(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    match state.store.get_affiliate(&tenant.tenant_id, &id).await {
+        Ok(Some(affiliate)) => json_ok(affiliate),
+        Ok(None) => {
+            let (status, body) = error_response(
+                ErrorCode::ResourceNotFound,
+                Some("affiliate not found".to_string()),
+                None,
+            );
+            json_error(status, body)
+        }
+        Err(e) => {
+            let (status, body) = error_response(
+                ErrorCode::DatabaseError,
+                Some(format!("Failed to get affiliate: {e}")),
+                None,
+            );
+            json_error(status, body)
+        }
+    }
+}
+
+/// POST /admin/affiliates
+pub async fn create_affiliate(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Json(mut req): Json<AffiliateRequest>,
+) -> impl IntoResponse {
+    let id = req
+        .id
+        .take()
+        .filter(|id| !id.trim().is_empty())
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    match state.store.get_affiliate(&tenant.tenant_id, &id).await {
+        Ok(None) => {}
+        Ok(Some(_)) => {
+            let (status, body) = error_response(
+                ErrorCode::InvalidField,
+                Some("affiliate id already exists".to_string()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+        Err(e) => {
+            let (status, body) = error_response(
+                ErrorCode::DatabaseError,
+                Some(format!("Failed to load affiliate: {e}")),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+    }
+
+    let affiliate = match build_affiliate(&tenant.tenant_id, id, req, Utc::now()) {
+        Ok(a) => a,
+        Err(message) => {
+            let (status, body) = error_response(ErrorCode::InvalidField, Some(message), None);
+            return json_error(status, body).into_response();
+        }
+    };
+    save_affiliate(&state, &tenant, affiliate, "create").await
+}
+
+/// PUT /admin/affiliates/{id}
+pub async fn update_affiliate(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Path(id): Path<String>,
+    Json(req): Json<AffiliateRequest>,
+) -> impl IntoResponse {
+    let existing = match state.store.get_affiliate(&tenant.tenant_id, &id).await {
+        Ok(Some(a)) => a,
+        Ok(None) => {
+            let (status, body) = error_response(
+                ErrorCode::ResourceNotFound,
+                Some("affiliate not found".to_string()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+        Err(e) => {
+            let (status, body) = error_response(
+                ErrorCode::DatabaseError,
+                Some(format!("Failed to load affiliate: {e}")),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+    };
+
+    let affiliate = match build_affiliate(&tenant.tenant_id, existing.id, req, existing.created_at)
+    {
+        Ok(a) => a,
+        Err(message) => {
+            let (status, body) = error_response(ErrorCode::InvalidField, Some(message), None);
+            return json_error(status, body).into_response();
+        }
+    };
+    save_affiliate(&state, &tenant, affiliate, "update").await
+}
+
+/// GET /admin/affiliates/{id}/commissions?from={rfc3339}&to={rfc3339}
+///
+/// Ledger entries for one affiliate, with per-currency totals.
+pub async fn list_affiliate_commissions(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Path(id): Path<String>,
+    Query(query): Query<ReportWindowQuery>,
+) -> impl IntoResponse {
+    let (from, to) = match report_window(&query) {
+        Ok(window) => window,
+        Err(resp) => return resp.into_response(),
+    };
+
+    match state
+        .store
+        .list_affiliate_commissions(&tenant.tenant_id, Some(&id), from, to)
+        .await
+    {
+        Ok(commissions) => json_ok(AffiliateCommissionsResponse {
+            from,
+            to,
+            totals: summarize_commissions(&commissions),
+            commissions,
+        })
+        .into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to list affiliate commissions");
+            let (status, body) = error_response(
+                ErrorCode::InternalError,
+                Some("Failed to list affiliate commissions".to_string()),
+                None,
+            );
+            json_error(status, body).into_response()
+        }
+    }
+}
+
+/// GET /admin/affiliates/payouts?from={rfc3339}&to={rfc3339}
+///
+/// Net commission owed to each affiliate for the window, per currency.
+pub async fn affiliate_payout_report(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Query(query): Query<ReportWindowQuery>,
+) -> impl IntoResponse {
+    let (from, to) = match report_window(&query) {
+        Ok(window) => window,
+        Err(resp) => return resp.into_response(),
+    };
+
+    let entries = match state
+        .store
+        .list_affiliate_commissions(&tenant.tenant_id, None, from, to)
+        .await
+    {
+        Ok(entries) => entries,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to list affiliate commissions");
+            let (status, body) = error_response(
+                ErrorCode::InternalError,
+                Some("Failed to build affiliate payout report".to_string()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+    };
+
+    let mut payouts = summarize_commissions(&entries);
+    for line in &mut payouts {
+        if let Ok(Some(affiliate)) = state
+            .store
+            .get_affiliate(&tenant.tenant_id, &line.affiliate_id)
+            .await
+        {
+            line.code = Some(affiliate.code);
+            line.payout_wallet = affiliate.payout_wallet;
+        }
+    }
+
+    json_ok(AffiliatePayoutsResponse { from, to, payouts }).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::http::StatusCode;
+
+    use crate::repositories::{InMemoryCouponRepository, InMemoryProductRepository};
+    use crate::storage::{InMemoryStore, Store};
+
+    fn state(store: Arc<InMemoryStore>) -> Arc<AdminState> {
+        Arc::new(AdminState {
+            store,
+            product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
+            coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
+            stripe_client: None,
+            messaging: None,
+        })
+    }
+
+    fn request(code: &str) -> AffiliateRequest {
+        AffiliateRequest {
+            id: Some("aff-1".to_string()),
+            code: code.to_string(),
+            name: "Partner".to_string(),
+            email: None,
+            payout_wallet: None,
+            active: true,
+            commission_type: CommissionType::Percentage,
+            commission_value: 1_000,
+            rules: Vec::new(),
+        }
+    }
+
+    fn entry(id: &str, kind: CommissionEntryKind, amount: i64) -> AffiliateCommission {
+        AffiliateCommission {
+            id: id.to_string(),
+            tenant_id: "default".to_string(),
+            affiliate_id: "aff-1".to_string(),
+            order_id: "order-1".to_string(),
+            purchase_id: "sig-1".to_string(),
+            kind,
+            amount,
+            order_amount: 10_000,
+            currency: "USDC".to_string(),
+            refund_id: None,
+            created_at: Utc::now() - Duration::hours(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_affiliate_normalizes_code_and_rejects_duplicates() {
+        let store = Arc::new(InMemoryStore::new());
+        let tenant = TenantContext::default();
+
+        let resp = create_affiliate(
+            State(state(store.clone())),
+            tenant.clone(),
+            Json(request(" partner10 ")),
+        )
+        .await
+        .into_response();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let stored = store
+            .get_affiliate_by_code(&tenant.tenant_id, "PARTNER10")
+            .await
+            .unwrap()
+            .expect("affiliate stored");
+        assert_eq!(stored.id, "aff-1");
+
+        let mut dup = request("PARTNER10");
+        dup.id = Some("aff-2".to_string());
+        let resp = create_affiliate(State(state(store)), tenant, Json(dup))
+            .await
+            .into_response();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_payout_report_nets_reversals() {
+        let store = Arc::new(InMemoryStore::new());
+        store
+            .record_affiliate_commission(entry("earned:sig-1", CommissionEntryKind::Earned, 1_000))
+            .await
+            .unwrap();
+        store
+            .record_affiliate_commission(entry(
+                "reversed:rf-1:aff-1",
+                CommissionEntryKind::Reversed,
+                -400,
+            ))
+            .await
+            .unwrap();
+
+        let resp = affiliate_payout_report(
+            State(state(store.clone())),
+            TenantContext::default(),
+            Query(ReportWindowQuery::default()),
+        )
+        .await
+        .into_response();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let entries = store
+            .list_affiliate_commissions("default", None, Utc::now() - Duration::days(1), Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(
+            summarize_commissions(&entries),
+            vec![AffiliatePayoutLine {
+                affiliate_id: "aff-1".to_string(),
+                code: None,
+                payout_wallet: None,
+                currency: "USDC".to_string(),
+                orders: 1,
+                earned: 1_000,
+                reversed: 400,
+                net: 600,
+            }]
+        );
+    }
+}
@@ -3,21 +3,24 @@
 //! Provides endpoints for managing AI provider API keys and model assignments.
 //! API keys are stored encrypted at rest and returned masked in responses.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     response::IntoResponse,
     Json,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::config::PostgresConfigRepository;
+use crate::config::{ConfigEntry, PostgresConfigRepository};
 use crate::errors::{error_response, ErrorCode};
 use crate::handlers::admin::audit;
 use crate::handlers::response::json_error;
 use crate::middleware::TenantContext;
-use crate::storage::Store;
+use crate::services::ai::usage::{micros_to_usd, usage_period};
+use crate::services::ai::{AiBudget, BudgetMode};
+use crate::storage::{AiUsageRecord, Store};
 
 // ============================================================================
 // Types - matching UI team RFC
@@ -29,13 +32,39 @@ use crate::storage::Store;
 pub enum AiProvider {
     Gemini,
     Openai,
+    Anthropic,
+    /// Any server speaking the OpenAI Chat Completions API (vLLM, Ollama,
+    /// LM Studio, ...) at a tenant-configured base URL
+    #[serde(rename = "openai_compatible")]
+    OpenaiCompatible,
 }
 
 impl AiProvider {
-    fn config_key(&self) -> &'static str {
+    /// Returns all supported providers
+    pub fn all() -> &'static [AiProvider] {
+        &[
+            AiProvider::Gemini,
+            AiProvider::Openai,
+            AiProvider::Anthropic,
+            AiProvider::OpenaiCompatible,
+        ]
+    }
+
+    /// Config key holding the tenant's API key for this provider
+    pub fn config_key(&self) -> &'static str {
         match self {
             AiProvider::Gemini => "gemini_api_key",
             AiProvider::Openai => "openai_api_key",
+            AiProvider::Anthropic => "anthropic_api_key",
+            AiProvider::OpenaiCompatible => "openai_compatible_api_key",
+        }
+    }
+
+    /// Config key holding the server base URL (OpenAI-compatible only)
+    pub fn base_url_key(&self) -> Option<&'static str> {
+        match self {
+            AiProvider::OpenaiCompatible => Some(OPENAI_COMPATIBLE_BASE_URL_KEY),
+            _ => None,
         }
     }
 }
@@ -45,6 +74,8 @@ impl std::fmt::Display for AiProvider {
         match self {
             AiProvider::Gemini => write!(f, "gemini"),
             AiProvider::Openai => write!(f, "openai"),
+            AiProvider::Anthropic => write!(f, "anthropic"),
+            AiProvider::OpenaiCompatible => write!(f, "openai_compatible"),
         }
     }
 }
@@ -56,6 +87,8 @@ impl std::str::FromStr for AiProvider {
         match s.to_lowercase().as_str() {
             "gemini" => Ok(AiProvider::Gemini),
             "openai" => Ok(AiProvider::Openai),
+            "anthropic" => Ok(AiProvider::Anthropic),
+            "openai_compatible" | "openai-compatible" => Ok(AiProvider::OpenaiCompatible),
             _ => Err(format!("Unknown provider: {}", s)),
         }
     }
@@ -74,16 +107,49 @@ pub enum AiModel {
     OpenAi51,
     #[serde(rename = "OpenAI52")]
     OpenAi52,
+    ClaudeSonnet45,
+    ClaudeHaiku45,
+    ClaudeOpus41,
+    /// Free-form model on an OpenAI-compatible server; the model name is
+    /// stored per task alongside the assignment
+    #[serde(rename = "OpenAICompatible")]
+    OpenAiCompatible,
+    #[serde(rename = "OpenAITextEmbedding3Small")]
+    OpenAiTextEmbedding3Small,
+    #[serde(rename = "OpenAITextEmbedding3Large")]
+    OpenAiTextEmbedding3Large,
+    GeminiTextEmbedding004,
 }
 
 impl AiModel {
-    fn provider(&self) -> Option<AiProvider> {
+    /// Provider serving this model
+    pub fn provider(&self) -> Option<AiProvider> {
         match self {
             AiModel::NotSet => None,
-            AiModel::Gemini25Flash | AiModel::Gemini25Pro => Some(AiProvider::Gemini),
-            AiModel::OpenAi4o | AiModel::OpenAi51 | AiModel::OpenAi52 => Some(AiProvider::Openai),
+            AiModel::Gemini25Flash | AiModel::Gemini25Pro | AiModel::GeminiTextEmbedding004 => {
+                Some(AiProvider::Gemini)
+            }
+            AiModel::OpenAi4o
+            | AiModel::OpenAi51
+            | AiModel::OpenAi52
+            | AiModel::OpenAiTextEmbedding3Small
+            | AiModel::OpenAiTextEmbedding3Large => Some(AiProvider::Openai),
+            AiModel::ClaudeSonnet45 | AiModel::ClaudeHaiku45 | AiModel::ClaudeOpus41 => {
+                Some(AiProvider::Anthropic)
+            }
+            AiModel::OpenAiCompatible => Some(AiProvider::OpenaiCompatible),
         }
     }
+
+    /// Text embedding model (only assignable to the embeddings task)
+    pub fn is_embedding_model(&self) -> bool {
+        matches!(
+            self,
+            AiModel::OpenAiTextEmbedding3Small
+                | AiModel::OpenAiTextEmbedding3Large
+                | AiModel::GeminiTextEmbedding004
+        )
+    }
 }
 
 /// AI Task identifiers
@@ -94,6 +160,8 @@ pub enum AiTask {
     RelatedProductFinder,
     ProductDetailAssistant,
     FactFinder,
+    /// Embeds products, variants and FAQs for semantic search
+    Embeddings,
 }
 
 impl AiTask {
@@ -105,6 +173,7 @@ impl AiTask {
             AiTask::RelatedProductFinder,
             AiTask::ProductDetailAssistant,
             AiTask::FactFinder,
+            AiTask::Embeddings,
         ]
     }
 }
@@ -117,6 +186,7 @@ impl std::fmt::Display for AiTask {
             AiTask::RelatedProductFinder => write!(f, "related_product_finder"),
             AiTask::ProductDetailAssistant => write!(f, "product_detail_assistant"),
             AiTask::FactFinder => write!(f, "fact_finder"),
+            AiTask::Embeddings => write!(f, "embeddings"),
         }
     }
 }
@@ -132,6 +202,8 @@ pub struct MaskedApiKey {
     pub provider: AiProvider,
     pub masked_key: String,
     pub configured: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
 }
 
 /// Model assignment for a task
@@ -140,6 +212,9 @@ pub struct MaskedApiKey {
 pub struct TaskAssignment {
     pub task: AiTask,
     pub assigned_model: AiModel,
+    /// Model name sent to the server when `assigned_model` is `OpenAICompatible`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub system_prompt: Option<String>,
 }
@@ -150,6 +225,25 @@ pub struct TaskAssignment {
 pub struct GetAiSettingsResponse {
     pub api_keys: Vec<MaskedApiKey>,
     pub assignments: Vec<TaskAssignment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<BudgetSettings>,
+}
+
+/// Monthly AI budget of a tenant
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetSettings {
+    pub monthly_budget_usd: f64,
+    pub mode: BudgetMode,
+}
+
+impl From<AiBudget> for BudgetSettings {
+    fn from(budget: AiBudget) -> Self {
+        Self {
+            monthly_budget_usd: budget.monthly_limit_usd,
+            mode: budget.mode,
+        }
+    }
 }
 
 /// PUT /admin/config/ai/api-key request
@@ -157,7 +251,12 @@ pub struct GetAiSettingsResponse {
 #[serde(rename_all = "camelCase")]
 pub struct SaveApiKeyRequest {
     pub provider: AiProvider,
+    /// May be empty for an OpenAI-compatible server that needs no key
+    #[serde(default)]
     pub api_key: String,
+    /// Required for `openai_compatible`, e.g. `http://llm.internal:8000/v1`
+    #[serde(default)]
+    pub base_url: Option<String>,
 }
 
 /// PUT /admin/config/ai/api-key response
@@ -184,6 +283,9 @@ pub struct DeleteApiKeyResponse {
 pub struct SaveAssignmentRequest {
     pub task: AiTask,
     pub model: AiModel,
+    /// Required when `model` is `OpenAICompatible`
+    #[serde(default)]
+    pub model_name: Option<String>,
 }
 
 /// PUT /admin/config/ai/assignment response
@@ -192,6 +294,8 @@ pub struct SaveAssignmentRequest {
 pub struct SaveAssignmentResponse {
     pub task: AiTask,
     pub model: AiModel,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_name: Option<String>,
     pub saved: bool,
     pub message: String,
 }
@@ -213,6 +317,86 @@ pub struct SavePromptResponse {
     pub message: String,
 }
 
+/// PUT /admin/config/ai/budget request
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveBudgetRequest {
+    /// `null` removes the budget
+    pub monthly_budget_usd: Option<f64>,
+    #[serde(default)]
+    pub mode: BudgetMode,
+}
+
+/// PUT /admin/config/ai/budget response
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveBudgetResponse {
+    pub budget: Option<BudgetSettings>,
+    pub saved: bool,
+    pub message: String,
+}
+
+/// GET /admin/ai/usage query
+#[derive(Debug, Deserialize)]
+pub struct AiUsageQuery {
+    /// Month as `YYYY-MM`; defaults to the current month
+    pub period: Option<String>,
+}
+
+/// Calls, tokens and cost summed over some usage rows
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiUsageTotals {
+    pub calls: i64,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cost_micros: i64,
+    pub cost_usd: f64,
+}
+
+impl AiUsageTotals {
+    fn add(&mut self, record: &AiUsageRecord) {
+        self.calls += record.calls;
+        self.input_tokens += record.input_tokens;
+        self.output_tokens += record.output_tokens;
+        self.cost_micros += record.cost_micros;
+        self.cost_usd = micros_to_usd(self.cost_micros);
+    }
+}
+
+/// Usage of one model for one task
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiUsageRow {
+    pub task: String,
+    pub model: String,
+    #[serde(flatten)]
+    pub totals: AiUsageTotals,
+}
+
+/// Spend of the reported month against the tenant's budget
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiBudgetStatus {
+    pub monthly_budget_usd: f64,
+    pub mode: BudgetMode,
+    pub remaining_usd: f64,
+    pub exceeded: bool,
+}
+
+/// GET /admin/ai/usage response
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiUsageReportResponse {
+    pub period: String,
+    pub totals: AiUsageTotals,
+    pub by_task: BTreeMap<String, AiUsageTotals>,
+    pub by_model: BTreeMap<String, AiUsageTotals>,
+    pub rows: Vec<AiUsageRow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<AiBudgetStatus>,
+}
+
 /// Shared state for AI settings handlers
 pub struct AdminAiState {
     pub repo: Arc<PostgresConfigRepository>,
@@ -221,10 +405,46 @@ pub struct AdminAiState {
 
 const AI_CATEGORY: &str = "ai";
 
+/// Config key holding the OpenAI-compatible server base URL (not a secret)
+pub const OPENAI_COMPATIBLE_BASE_URL_KEY: &str = "openai_compatible_base_url";
+
+/// Maximum length of a free-form model name
+const MAX_MODEL_NAME_LEN: usize = 200;
+
+/// Config key holding the tenant's monthly AI budget in USD
+pub const AI_BUDGET_KEY: &str = "monthly_budget_usd";
+
+/// Config key holding what happens once the budget is spent (`warn` / `block`)
+pub const AI_BUDGET_MODE_KEY: &str = "budget_mode";
+
+/// Config key holding the free-form model name assigned to a task
+pub fn model_name_key(task: AiTask) -> String {
+    format!("model_name_{}", task)
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// The tenant's AI budget from its `ai` config entries; none when unset
+pub fn budget_from_entries(entries: &[ConfigEntry]) -> Option<AiBudget> {
+    let value_of = |key: &str| {
+        entries
+            .iter()
+            .find(|e| e.config_key == key)
+            .map(|e| &e.value)
+    };
+    let monthly_limit_usd = value_of(AI_BUDGET_KEY)?.as_f64()?;
+    let mode = value_of(AI_BUDGET_MODE_KEY)
+        .and_then(|v| v.as_str())
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_default();
+    Some(AiBudget {
+        monthly_limit_usd,
+        mode,
+    })
+}
+
 /// Mask an API key, showing only first 4 and last 4 chars
 fn mask_api_key(key: &str) -> String {
     if key.len() <= 8 {
@@ -238,6 +458,21 @@ fn mask_api_key(key: &str) -> String {
     )
 }
 
+/// Validate an OpenAI-compatible base URL, returning it without a trailing slash
+fn normalize_base_url(url: &str) -> Result<String, String> {
+    let trimmed = url.trim().trim_end_matches('/');
+    let parsed = reqwest::Url::parse(trimmed).map_err(|e| format!("Invalid base URL: {}", e))?;
+    if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str().is_none() {
+        return Err("Base URL must be an http(s) URL with a host".to_string());
+    }
+    Ok(trimmed.to_string())
+}
+
+fn invalid_field(message: impl Into<String>) -> axum::response::Response {
+    let (status, body) = error_response(ErrorCode::InvalidField, Some(message.into()), None);
+    json_error(status, body).into_response()
+}
+
 // ============================================================================
 // Handlers
 // ============================================================================
@@ -265,23 +500,23 @@ pub async fn get_ai_settings(
     let mut api_keys = Vec::new();
 
     // Check each provider
-    for provider in [AiProvider::Gemini, AiProvider::Openai] {
-        let key_name = provider.config_key();
-        let entry = entries.iter().find(|e| e.config_key == key_name);
+    for &provider in AiProvider::all() {
+        let base_url = provider
+            .base_url_key()
+            .and_then(|key| entries.iter().find(|e| e.config_key == key))
+            .and_then(|e| e.value.as_str())
+            .map(|s| s.to_string());
 
-        if let Some(entry) = entry {
+        let mut masked_key = String::new();
+        if let Some(entry) = entries
+            .iter()
+            .find(|e| e.config_key == provider.config_key())
+        {
             // Decrypt the key to get masked version
             match state.repo.decrypt_entry(entry).await {
                 Ok(decrypted) => {
                     if let Some(key_str) = decrypted.as_str() {
-                        if !key_str.is_empty() {
-                            api_keys.push(MaskedApiKey {
-                                provider,
-                                masked_key: mask_api_key(key_str),
-                                configured: true,
-                            });
-                            continue;
-                        }
+                        masked_key = mask_api_key(key_str);
                     }
                 }
                 Err(e) => {
@@ -290,11 +525,16 @@ pub async fn get_ai_settings(
             }
         }
 
-        // Not configured
+        // A self-hosted server may not need a key; its base URL is what makes it usable
+        let configured = match provider.base_url_key() {
+            Some(_) => base_url.is_some(),
+            None => !masked_key.is_empty(),
+        };
         api_keys.push(MaskedApiKey {
             provider,
-            masked_key: String::new(),
-            configured: false,
+            masked_key,
+            configured,
+            base_url,
         });
     }
 
@@ -313,10 +553,16 @@ pub async fn get_ai_settings(
             .unwrap_or(AiModel::NotSet);
 
         let system_prompt = prompt_entry.and_then(|e| e.value.as_str().map(|s| s.to_string()));
+        let model_name_key = model_name_key(*task);
+        let model_name = entries
+            .iter()
+            .find(|e| e.config_key == model_name_key)
+            .and_then(|e| e.value.as_str().map(|s| s.to_string()));
 
         assignments.push(TaskAssignment {
             task: *task,
             assigned_model,
+            model_name,
             system_prompt,
         });
     }
@@ -324,42 +570,93 @@ pub async fn get_ai_settings(
     let response = GetAiSettingsResponse {
         api_keys,
         assignments,
+        budget: budget_from_entries(&entries).map(BudgetSettings::from),
     };
     Json(response).into_response()
 }
 
 /// PUT /admin/config/ai/api-key - Save API key (encrypted)
+///
+/// For `openai_compatible` the request also carries the server base URL, and
+/// the API key may be empty for servers that do not authenticate.
 pub async fn save_api_key(
     State(state): State<Arc<AdminAiState>>,
     tenant: TenantContext,
     Json(request): Json<SaveApiKeyRequest>,
 ) -> impl IntoResponse {
+    let base_url = match (request.provider.base_url_key(), request.base_url.as_deref()) {
+        (Some(_), Some(url)) => match normalize_base_url(url) {
+            Ok(url) => Some(url),
+            Err(message) => return invalid_field(message),
+        },
+        (Some(_), None) => {
+            return invalid_field(format!("Base URL is required for {}", request.provider))
+        }
+        (None, Some(_)) => {
+            return invalid_field(format!(
+                "Base URL is not supported for {}",
+                request.provider
+            ))
+        }
+        (None, None) => None,
+    };
+
     // Validate API key is not empty
-    if request.api_key.trim().is_empty() {
-        let (status, body) = error_response(
-            ErrorCode::InvalidField,
-            Some("API key cannot be empty".to_string()),
-            None,
-        );
-        return json_error(status, body).into_response();
+    let api_key = request.api_key.trim();
+    if api_key.is_empty() && base_url.is_none() {
+        return invalid_field("API key cannot be empty");
     }
 
     let key_name = request.provider.config_key();
 
-    // Store encrypted (PostgresConfigRepository handles encryption for secret fields)
-    match state
-        .repo
-        .upsert_config(
-            &tenant.tenant_id,
-            key_name,
-            AI_CATEGORY,
-            serde_json::Value::String(request.api_key),
-            Some("AI API key updated"),
-            Some(&tenant.tenant_id),
-        )
-        .await
-    {
-        Ok(_) => {
+    if let (Some(url_key), Some(url)) = (request.provider.base_url_key(), base_url) {
+        if let Err(e) = state
+            .repo
+            .upsert_config(
+                &tenant.tenant_id,
+                url_key,
+                AI_CATEGORY,
+                serde_json::Value::String(url),
+                Some("AI base URL updated"),
+                Some(&tenant.tenant_id),
+            )
+            .await
+        {
+            tracing::error!(error = %e, provider = %request.provider, "Failed to save base URL");
+            let (status, body) = error_response(
+                ErrorCode::InternalError,
+                Some("Failed to save base URL".to_string()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+    }
+
+    // Store encrypted (PostgresConfigRepository handles encryption for secret fields).
+    // An empty key clears any previous one so it is no longer sent.
+    let result = if api_key.is_empty() {
+        state
+            .repo
+            .delete_config(&tenant.tenant_id, key_name)
+            .await
+            .map(|_| ())
+    } else {
+        state
+            .repo
+            .upsert_config(
+                &tenant.tenant_id,
+                key_name,
+                AI_CATEGORY,
+                serde_json::Value::String(api_key.to_string()),
+                Some("AI API key updated"),
+                Some(&tenant.tenant_id),
+            )
+            .await
+            .map(|_| ())
+    };
+
+    match result {
+        Ok(()) => {
             let provider_str = request.provider.to_string();
             audit(
                 &*state.store,
@@ -409,8 +706,17 @@ pub async fn delete_api_key(
 
     let key_name = provider.config_key();
 
-    // Delete the config entry
-    match state.repo.delete_config(&tenant.tenant_id, key_name).await {
+    // Delete the config entry (and the base URL of an OpenAI-compatible server)
+    let mut result = state.repo.delete_config(&tenant.tenant_id, key_name).await;
+    if let (Ok(deleted), Some(url_key)) = (&result, provider.base_url_key()) {
+        let key_deleted = *deleted;
+        result = state
+            .repo
+            .delete_config(&tenant.tenant_id, url_key)
+            .await
+            .map(|url_deleted| key_deleted || url_deleted);
+    }
+    match result {
         Ok(deleted) => {
             audit(
                 &*state.store,
@@ -450,9 +756,48 @@ pub async fn save_assignment(
     tenant: TenantContext,
     Json(request): Json<SaveAssignmentRequest>,
 ) -> impl IntoResponse {
+    // A free-form model name only applies to (and is required by) OpenAI-compatible models
+    let model_name = match (request.model, request.model_name.as_deref().map(str::trim)) {
+        (AiModel::OpenAiCompatible, Some(name)) if !name.is_empty() => {
+            if name.len() > MAX_MODEL_NAME_LEN {
+                return invalid_field(format!(
+                    "Model name must be at most {} characters",
+                    MAX_MODEL_NAME_LEN
+                ));
+            }
+            Some(name.to_string())
+        }
+        (AiModel::OpenAiCompatible, _) => {
+            return invalid_field("modelName is required for OpenAICompatible models")
+        }
+        (_, Some(_)) => {
+            return invalid_field("modelName is only supported for OpenAICompatible models")
+        }
+        (_, None) => None,
+    };
+
+    // Embedding models only produce vectors; the embeddings task needs one
+    // (an OpenAI-compatible server may serve either kind)
+    let embeddings_task = request.task == AiTask::Embeddings;
+    if request.model.is_embedding_model() && !embeddings_task {
+        return invalid_field(format!(
+            "Embedding models can only be assigned to the {:?} task",
+            AiTask::Embeddings
+        ));
+    }
+    if embeddings_task
+        && !request.model.is_embedding_model()
+        && !matches!(request.model, AiModel::NotSet | AiModel::OpenAiCompatible)
+    {
+        return invalid_field("The Embeddings task requires an embedding model");
+    }
+
     // Validate: if model requires a provider, check that provider's API key is configured
+    // (for an OpenAI-compatible server, its base URL)
     if let Some(required_provider) = request.model.provider() {
-        let key_name = required_provider.config_key();
+        let key_name = required_provider
+            .base_url_key()
+            .unwrap_or_else(|| required_provider.config_key());
         match state.repo.get_config(&tenant.tenant_id, AI_CATEGORY).await {
             Ok(entries) => {
                 let has_key = entries.iter().any(|e| e.config_key == key_name);
@@ -460,13 +805,14 @@ pub async fn save_assignment(
                     let (status, body) = error_response(
                         ErrorCode::InvalidField,
                         Some(format!(
-                            "Cannot assign {} model without {} API key configured",
-                            request
-                                .model
-                                .provider()
-                                .map(|p| p.to_string())
-                                .unwrap_or_default(),
-                            required_provider
+                            "Cannot assign {} model without {} {} configured",
+                            required_provider,
+                            required_provider,
+                            if required_provider.base_url_key().is_some() {
+                                "base URL"
+                            } else {
+                                "API key"
+                            }
                         )),
                         None,
                     );
@@ -485,6 +831,29 @@ pub async fn save_assignment(
         }
     }
 
+    if let Some(name) = &model_name {
+        if let Err(e) = state
+            .repo
+            .upsert_config(
+                &tenant.tenant_id,
+                &model_name_key(request.task),
+                AI_CATEGORY,
+                serde_json::Value::String(name.clone()),
+                Some(&format!("AI task {} model name updated", request.task)),
+                Some(&tenant.tenant_id),
+            )
+            .await
+        {
+            tracing::error!(error = %e, task = %request.task, "Failed to save model name");
+            let (status, body) = error_response(
+                ErrorCode::InternalError,
+                Some("Failed to save assignment".to_string()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+    }
+
     let key_name = format!("assignment_{}", request.task);
     let model_str = serde_json::to_string(&request.model)
         .unwrap_or_default()
@@ -521,6 +890,7 @@ pub async fn save_assignment(
             let response = SaveAssignmentResponse {
                 task: request.task,
                 model: request.model,
+                model_name,
                 saved: true,
                 message: format!("{:?} assigned to {:?}", request.task, request.model),
             };
@@ -589,6 +959,184 @@ pub async fn save_prompt(
     }
 }
 
+/// PUT /admin/config/ai/budget - Set or remove the monthly AI budget
+pub async fn save_budget(
+    State(state): State<Arc<AdminAiState>>,
+    tenant: TenantContext,
+    Json(request): Json<SaveBudgetRequest>,
+) -> impl IntoResponse {
+    let result = match request.monthly_budget_usd {
+        Some(limit) if !limit.is_finite() || limit < 0.0 => {
+            return invalid_field("Monthly budget must be a non-negative amount in USD")
+        }
+        Some(limit) => {
+            let Some(amount) = serde_json::Number::from_f64(limit) else {
+                return invalid_field("Monthly budget must be a non-negative amount in USD");
+            };
+            let saved = state
+                .repo
+                .upsert_config(
+                    &tenant.tenant_id,
+                    AI_BUDGET_KEY,
+                    AI_CATEGORY,
+                    serde_json::Value::Number(amount),
+                    Some("AI monthly budget updated"),
+                    Some(&tenant.tenant_id),
+                )
+                .await;
+            match saved {
+                Ok(_) => state
+                    .repo
+                    .upsert_config(
+                        &tenant.tenant_id,
+                        AI_BUDGET_MODE_KEY,
+                        AI_CATEGORY,
+                        serde_json::Value::String(request.mode.as_str().to_string()),
+                        Some("AI budget mode updated"),
+                        Some(&tenant.tenant_id),
+                    )
+                    .await
+                    .map(|_| ()),
+                Err(e) => Err(e),
+            }
+        }
+        None => {
+            let deleted = state
+                .repo
+                .delete_config(&tenant.tenant_id, AI_BUDGET_KEY)
+                .await;
+            match deleted {
+                Ok(_) => state
+                    .repo
+                    .delete_config(&tenant.tenant_id, AI_BUDGET_MODE_KEY)
+                    .await
+                    .map(|_| ()),
+                Err(e) => Err(e),
+            }
+        }
+    };
+
+    if let Err(e) = result {
+        tracing::error!(error = %e, "Failed to save AI budget");
+        let (status, body) = error_response(
+            ErrorCode::InternalError,
+            Some("Failed to save AI budget".to_string()),
+            None,
+        );
+        return json_error(status, body).into_response();
+    }
+
+    audit(
+        &*state.store,
+        &tenant,
+        "ai_config",
+        AI_BUDGET_KEY,
+        "save_budget",
+        None,
+    )
+    .await;
+    let budget = request.monthly_budget_usd.map(|limit| BudgetSettings {
+        monthly_budget_usd: limit,
+        mode: request.mode,
+    });
+    let message = match &budget {
+        Some(b) => format!(
+            "Monthly AI budget set to ${:.2} ({})",
+            b.monthly_budget_usd,
+            b.mode.as_str()
+        ),
+        None => "Monthly AI budget removed".to_string(),
+    };
+    Json(SaveBudgetResponse {
+        budget,
+        saved: true,
+        message,
+    })
+    .into_response()
+}
+
+/// GET /admin/ai/usage - Token usage and cost for a month, with budget status
+pub async fn get_ai_usage(
+    State(state): State<Arc<AdminAiState>>,
+    tenant: TenantContext,
+    Query(query): Query<AiUsageQuery>,
+) -> impl IntoResponse {
+    let period = match query.period {
+        Some(period) => {
+            if period.len() != 7
+                || chrono::NaiveDate::parse_from_str(&format!("{}-01", period), "%Y-%m-%d").is_err()
+            {
+                return invalid_field("period must be a month formatted as YYYY-MM");
+            }
+            period
+        }
+        None => usage_period(chrono::Utc::now()),
+    };
+
+    let records = match state.store.list_ai_usage(&tenant.tenant_id, &period).await {
+        Ok(records) => records,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to list AI usage");
+            let (status, body) = error_response(
+                ErrorCode::InternalError,
+                Some("Failed to load AI usage".to_string()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+    };
+    let budget = match state.repo.get_config(&tenant.tenant_id, AI_CATEGORY).await {
+        Ok(entries) => budget_from_entries(&entries),
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to load AI budget for usage report");
+            None
+        }
+    };
+
+    Json(build_usage_report(period, &records, budget)).into_response()
+}
+
+fn build_usage_report(
+    period: String,
+    records: &[AiUsageRecord],
+    budget: Option<AiBudget>,
+) -> AiUsageReportResponse {
+    let mut totals = AiUsageTotals::default();
+    let mut by_task: BTreeMap<String, AiUsageTotals> = BTreeMap::new();
+    let mut by_model: BTreeMap<String, AiUsageTotals> = BTreeMap::new();
+    let mut rows = Vec::with_capacity(records.len());
+    for record in records {
+        totals.add(record);
+        by_task.entry(record.task.clone()).or_default().add(record);
+        by_model
+            .entry(record.model.clone())
+            .or_default()
+            .add(record);
+        let mut row_totals = AiUsageTotals::default();
+        row_totals.add(record);
+        rows.push(AiUsageRow {
+            task: record.task.clone(),
+            model: record.model.clone(),
+            totals: row_totals,
+        });
+    }
+
+    let budget = budget.map(|b| AiBudgetStatus {
+        monthly_budget_usd: b.monthly_limit_usd,
+        mode: b.mode,
+        remaining_usd: (b.monthly_limit_usd - totals.cost_usd).max(0.0),
+        exceeded: totals.cost_usd >= b.monthly_limit_usd,
+    });
+    AiUsageReportResponse {
+        period,
+        totals,
+        by_task,
+        by_model,
+        rows,
+        budget,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -607,6 +1155,12 @@ mod tests {
     fn test_provider_config_key() {
         assert_eq!(AiProvider::Gemini.config_key(), "gemini_api_key");
         assert_eq!(AiProvider::Openai.config_key(), "openai_api_key");
+        assert_eq!(AiProvider::Anthropic.config_key(), "anthropic_api_key");
+        assert_eq!(
+            AiProvider::OpenaiCompatible.base_url_key(),
+            Some(OPENAI_COMPATIBLE_BASE_URL_KEY)
+        );
+        assert_eq!(AiProvider::Anthropic.base_url_key(), None);
     }
 
     #[test]
@@ -617,6 +1171,14 @@ mod tests {
         assert_eq!(AiModel::OpenAi4o.provider(), Some(AiProvider::Openai));
         assert_eq!(AiModel::OpenAi51.provider(), Some(AiProvider::Openai));
         assert_eq!(AiModel::OpenAi52.provider(), Some(AiProvider::Openai));
+        assert_eq!(
+            AiModel::ClaudeSonnet45.provider(),
+            Some(AiProvider::Anthropic)
+        );
+        assert_eq!(
+            AiModel::OpenAiCompatible.provider(),
+            Some(AiProvider::OpenaiCompatible)
+        );
     }
 
     #[test]
@@ -624,6 +1186,124 @@ mod tests {
         assert_eq!("gemini".parse::<AiProvider>().unwrap(), AiProvider::Gemini);
         assert_eq!("openai".parse::<AiProvider>().unwrap(), AiProvider::Openai);
         assert_eq!("GEMINI".parse::<AiProvider>().unwrap(), AiProvider::Gemini);
+        assert_eq!(
+            "anthropic".parse::<AiProvider>().unwrap(),
+            AiProvider::Anthropic
+        );
+        assert_eq!(
+            "openai_compatible".parse::<AiProvider>().unwrap(),
+            AiProvider::OpenaiCompatible
+        );
         assert!("unknown".parse::<AiProvider>().is_err());
     }
+
+    #[test]
+    fn test_new_provider_serde_names() {
+        assert_eq!(
+            serde_json::to_string(&AiProvider::OpenaiCompatible).unwrap(),
+            "\"openai_compatible\""
+        );
+        let model: AiModel = serde_json::from_str("\"OpenAICompatible\"").unwrap();
+        assert_eq!(model, AiModel::OpenAiCompatible);
+        let model: AiModel = serde_json::from_str("\"ClaudeHaiku45\"").unwrap();
+        assert_eq!(model, AiModel::ClaudeHaiku45);
+    }
+
+    #[test]
+    fn test_embedding_models() {
+        for model in [
+            AiModel::OpenAiTextEmbedding3Small,
+            AiModel::OpenAiTextEmbedding3Large,
+        ] {
+            assert!(model.is_embedding_model());
+            assert_eq!(model.provider(), Some(AiProvider::Openai));
+        }
+        assert_eq!(
+            AiModel::GeminiTextEmbedding004.provider(),
+            Some(AiProvider::Gemini)
+        );
+        assert!(!AiModel::OpenAi4o.is_embedding_model());
+        assert!(!AiModel::OpenAiCompatible.is_embedding_model());
+
+        let model: AiModel = serde_json::from_str("\"OpenAITextEmbedding3Small\"").unwrap();
+        assert_eq!(model, AiModel::OpenAiTextEmbedding3Small);
+        assert_eq!(AiTask::Embeddings.to_string(), "embeddings");
+        assert!(AiTask::all().contains(&AiTask::Embeddings));
+    }
+
+    #[test]
+    fn test_normalize_base_url() {
+        assert_eq!(
+            normalize_base_url(" http://llm.internal:8000/v1/ ").unwrap(),
+            "http://llm.internal:8000/v1"
+        );
+        assert!(normalize_base_url("ftp://llm.internal/v1").is_err());
+        assert!(normalize_base_url("not a url").is_err());
+    }
+
+    fn config_entry(key: &str, value: serde_json::Value) -> ConfigEntry {
+        ConfigEntry {
+            tenant_id: "t1".to_string(),
+            config_key: key.to_string(),
+            value,
+            encrypted: false,
+            key_version: None,
+            category: AI_CATEGORY.to_string(),
+            description: None,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            updated_by: None,
+        }
+    }
+
+    fn usage_record(task: &str, model: &str, cost_micros: i64) -> AiUsageRecord {
+        AiUsageRecord {
+            tenant_id: "t1".to_string(),
+            period: "2026-04".to_string(),
+            task: task.to_string(),
+            model: model.to_string(),
+            calls: 2,
+            input_tokens: 1_000,
+            output_tokens: 100,
+            cost_micros,
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_budget_and_usage_report() {
+        assert!(budget_from_entries(&[]).is_none());
+        let budget = budget_from_entries(&[
+            config_entry(AI_BUDGET_KEY, serde_json::json!(2.5)),
+            config_entry(AI_BUDGET_MODE_KEY, serde_json::json!("block")),
+        ])
+        .unwrap();
+        assert_eq!(budget.monthly_limit_usd, 2.5);
+        assert_eq!(budget.mode, BudgetMode::Block);
+        // Mode defaults to warn
+        let budget = budget_from_entries(&[config_entry(AI_BUDGET_KEY, serde_json::json!(1))]);
+        assert_eq!(budget.unwrap().mode, BudgetMode::Warn);
+
+        let records = [
+            usage_record("chat", "gpt-4o", 1_500_000),
+            usage_record("seo", "gpt-4o", 500_000),
+            usage_record("seo", "gemini-2.5-pro-preview-05-06", 250_000),
+        ];
+        let report = build_usage_report(
+            "2026-04".to_string(),
+            &records,
+            Some(AiBudget {
+                monthly_limit_usd: 2.0,
+                mode: BudgetMode::Warn,
+            }),
+        );
+        assert_eq!(report.totals.calls, 6);
+        assert_eq!(report.totals.cost_micros, 2_250_000);
+        assert_eq!(report.by_task["seo"].input_tokens, 2_000);
+        assert_eq!(report.by_model["gpt-4o"].cost_usd, 2.0);
+        assert_eq!(report.rows.len(), 3);
+        let status = report.budget.unwrap();
+        assert!(status.exceeded);
+        assert_eq!(status.remaining_usd, 0.0);
+    }
 }
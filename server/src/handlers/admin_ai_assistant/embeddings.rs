@@ -0,0 +1,115 @@
+//! Embeddings reindex handler - queues the whole catalog for re-embedding.
+
+use std::sync::Arc;
+
+use axum::{extract::State, response::IntoResponse};
+use serde::Serialize;
+
+use crate::errors::{error_response, ErrorCode};
+use crate::handlers::response::{json_error, json_ok};
+use crate::middleware::TenantContext;
+use crate::storage::{EmbeddingEntity, EmbeddingJob};
+
+use super::AdminAiAssistantState;
+
+/// FAQs loaded per page while queueing
+const FAQ_PAGE_SIZE: i32 = 200;
+
+/// POST /admin/ai/embeddings/reindex response
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReindexEmbeddingsResponse {
+    pub queued_products: usize,
+    pub queued_faqs: usize,
+    pub model: String,
+}
+
+/// POST /admin/ai/embeddings/reindex - Queue every product and FAQ for embedding.
+///
+/// Needed after assigning or changing the embeddings model; unchanged
+/// documents are skipped by the worker, so repeating it is cheap.
+pub async fn reindex_embeddings(
+    State(state): State<Arc<AdminAiAssistantState>>,
+    tenant: TenantContext,
+) -> impl IntoResponse {
+    let Some(endpoint) = state.search.embeddings_endpoint(&tenant.tenant_id).await else {
+        let (status, body) = error_response(
+            ErrorCode::ConfigError,
+            Some("No model is assigned to the embeddings task".into()),
+            None,
+        );
+        return json_error(status, body).into_response();
+    };
+
+    let products = match state
+        .product_repo
+        .list_all_products(&tenant.tenant_id)
+        .await
+    {
+        Ok(products) => products,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to load products for reindex");
+            let (status, body) = error_response(
+                ErrorCode::InternalError,
+                Some("Failed to load product catalog".into()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+    };
+
+    let mut faq_ids = Vec::new();
+    let mut offset = 0;
+    loop {
+        match state
+            .store
+            .list_faqs(&tenant.tenant_id, false, FAQ_PAGE_SIZE, offset)
+            .await
+        {
+            Ok((page, _)) => {
+                let full_page = page.len() as i32 == FAQ_PAGE_SIZE;
+                faq_ids.extend(page.into_iter().map(|f| f.id));
+                if !full_page {
+                    break;
+                }
+                offset += FAQ_PAGE_SIZE;
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to load FAQs for reindex");
+                let (status, body) = error_response(
+                    ErrorCode::DatabaseError,
+                    Some("Failed to load FAQs".into()),
+                    None,
+                );
+                return json_error(status, body).into_response();
+            }
+        }
+    }
+
+    let jobs = products
+        .iter()
+        .map(|p| EmbeddingJob::new(&tenant.tenant_id, EmbeddingEntity::Product, &p.id))
+        .chain(
+            faq_ids
+                .iter()
+                .map(|id| EmbeddingJob::new(&tenant.tenant_id, EmbeddingEntity::Faq, id)),
+        );
+    for job in jobs {
+        if let Err(e) = state.store.enqueue_embedding_job(job).await {
+            tracing::error!(error = %e, "Failed to queue embedding job");
+            let (status, body) = error_response(
+                ErrorCode::DatabaseError,
+                Some("Failed to queue embedding jobs".into()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+    }
+
+    json_ok(ReindexEmbeddingsResponse {
+        queued_products: products.len(),
+        queued_faqs: faq_ids.len(),
+        model: endpoint.model_id().to_string(),
+    })
+    .into_response()
+}
@@ -3,6 +3,7 @@
 //! Takes product name/description and generates SEO fields, tags, categories via parallel AI calls.
 //! Includes per-tenant rate limiting and response caching.
 
+mod embeddings;
 mod product_assistant;
 mod product_search;
 mod rate_limit;
@@ -11,12 +12,13 @@ mod related_products;
 use std::sync::Arc;
 
 use crate::config::PostgresConfigRepository;
-use crate::handlers::admin_ai::{AiModel, AiProvider, AiTask};
+use crate::handlers::admin_ai::{budget_from_entries, model_name_key, AiModel, AiTask};
 use crate::repositories::ProductRepository;
-use crate::services::{AiError, AiService};
+use crate::services::{AiEndpoint, AiError, AiService, SemanticSearch};
 use crate::storage::Store;
 
 // Re-exports
+pub use embeddings::{reindex_embeddings, ReindexEmbeddingsResponse};
 pub use product_assistant::{
     product_assistant, AiResponseCache, ProductAssistantRequest, ProductAssistantResponse,
 };
@@ -38,6 +40,8 @@ pub struct AdminAiAssistantState {
     pub ai_service: AiService,
     pub rate_limiter: AiRateLimiter,
     pub cache: AiResponseCache,
+    /// Hybrid keyword + embedding product search
+    pub search: Arc<SemanticSearch>,
 }
 
 // ============================================================================
@@ -46,57 +50,92 @@ pub struct AdminAiAssistantState {
 
 const AI_CATEGORY: &str = "ai";
 
-/// Load AI configuration for a task
+/// Load AI configuration for the product detail assistant.
 pub async fn load_ai_config(
     repo: &PostgresConfigRepository,
+    ai_service: &AiService,
     tenant_id: &str,
-) -> Result<(AiProvider, AiModel, String), AiError> {
-    let task = AiTask::ProductDetailAssistant;
+) -> Result<AiEndpoint, AiError> {
+    load_task_endpoint(repo, ai_service, tenant_id, AiTask::ProductDetailAssistant).await
+}
+
+/// Resolve the provider, model and credentials assigned to a task.
+///
+/// The tenant's own API key wins; otherwise the platform key from the secret
+/// provider (if any) is used. OpenAI-compatible servers use the tenant's base
+/// URL and the model name stored with the assignment, and may have no key.
+/// The endpoint carries the tenant's id and AI budget for usage accounting.
+pub async fn load_task_endpoint(
+    repo: &PostgresConfigRepository,
+    ai_service: &AiService,
+    tenant_id: &str,
+    task: AiTask,
+) -> Result<AiEndpoint, AiError> {
     let entries = repo
         .get_config(tenant_id, AI_CATEGORY)
         .await
         .map_err(|e| AiError::NotConfigured(format!("Failed to load AI config: {}", e)))?;
+    let value_of = |key: &str| {
+        entries
+            .iter()
+            .find(|e| e.config_key == key)
+            .and_then(|e| e.value.as_str())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+    };
 
     // Get model assignment
     let assignment_key = format!("assignment_{}", task);
-    let model = entries
-        .iter()
-        .find(|e| e.config_key == assignment_key)
-        .and_then(|e| e.value.as_str())
+    let model = value_of(&assignment_key)
         .and_then(|s| serde_json::from_str::<AiModel>(&format!("\"{}\"", s)).ok())
         .unwrap_or(AiModel::NotSet);
 
     if model == AiModel::NotSet {
-        return Err(AiError::NotConfigured(
-            "No model assigned for ProductDetailAssistant task".to_string(),
-        ));
+        return Err(AiError::NotConfigured(format!(
+            "No model assigned for {:?} task",
+            task
+        )));
     }
 
     // Get provider from model
-    let provider = model_to_provider(model).ok_or_else(|| {
+    let provider = model.provider().ok_or_else(|| {
         AiError::NotConfigured(format!("Model {:?} has no associated provider", model))
     })?;
 
     // Get API key
-    let key_name = provider_config_key(provider);
-    let entry = entries
-        .iter()
-        .find(|e| e.config_key == key_name)
-        .ok_or_else(|| AiError::ApiKeyMissing(provider.to_string()))?;
+    let key_name = provider.config_key();
+    let tenant_key = match entries.iter().find(|e| e.config_key == key_name) {
+        Some(entry) => repo
+            .decrypt_entry(entry)
+            .await
+            .map_err(|e| AiError::ServiceError(format!("Failed to decrypt API key: {}", e)))?
+            .as_str()
+            .map(|s| s.to_string()),
+        None => None,
+    };
+
+    if let Some(url_key) = provider.base_url_key() {
+        let base_url = value_of(url_key).ok_or_else(|| {
+            AiError::NotConfigured(format!("No base URL configured for {}", provider))
+        })?;
+        let model_name = value_of(&model_name_key(task)).ok_or_else(|| {
+            AiError::NotConfigured(format!("No model name assigned for {:?} task", task))
+        })?;
+        return Ok(AiEndpoint::openai_compatible(
+            base_url,
+            model_name,
+            tenant_key.unwrap_or_default(),
+        )
+        .with_tenant(tenant_id, budget_from_entries(&entries)));
+    }
 
-    let api_key = repo
-        .decrypt_entry(entry)
-        .await
-        .map_err(|e| AiError::ServiceError(format!("Failed to decrypt API key: {}", e)))?
-        .as_str()
-        .map(|s| s.to_string())
+    let api_key = tenant_key
+        .filter(|key| !key.is_empty())
+        .or_else(|| ai_service.provider_api_key(provider))
         .ok_or_else(|| AiError::ApiKeyMissing(provider.to_string()))?;
 
-    if api_key.is_empty() {
-        return Err(AiError::ApiKeyMissing(provider.to_string()));
-    }
-
-    Ok((provider, model, api_key))
+    Ok(AiEndpoint::new(provider, model, api_key)
+        .with_tenant(tenant_id, budget_from_entries(&entries)))
 }
 
 /// Load custom prompt for a sub-task, or use default
@@ -120,18 +159,3 @@ pub async fn load_prompt(
 
     default.to_string()
 }
-
-pub fn model_to_provider(model: AiModel) -> Option<AiProvider> {
-    match model {
-        AiModel::NotSet => None,
-        AiModel::Gemini25Flash | AiModel::Gemini25Pro => Some(AiProvider::Gemini),
-        AiModel::OpenAi4o | AiModel::OpenAi51 | AiModel::OpenAi52 => Some(AiProvider::Openai),
-    }
-}
-
-pub fn provider_config_key(provider: AiProvider) -> &'static str {
-    match provider {
-        AiProvider::Gemini => "gemini_api_key",
-        AiProvider::Openai => "openai_api_key",
-    }
-}
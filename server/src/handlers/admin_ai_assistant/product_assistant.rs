@@ -1,19 +1,29 @@
 //! Product Assistant handler - generates SEO, tags, categories via parallel AI calls.
+//!
+//! With `Accept: text/event-stream` the four generations stream as `delta`
+//! events tagged with their `field`, followed by a `done` event carrying the
+//! parsed [`ProductAssistantResponse`].
 
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use axum::{extract::State, response::IntoResponse, Json};
+use axum::{
+    extract::State,
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+    Json,
+};
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
 
 use crate::errors::{error_response, ErrorCode};
-use crate::handlers::response::json_error;
+use crate::handlers::response::{event_stream, json_error, sse_event, wants_event_stream};
 use crate::middleware::TenantContext;
 use crate::observability::{record_ai_cache_hit, record_ai_rate_limit_rejection};
 use crate::services::{
-    parse_json_response, slugify, CategoriesResult, SeoResult, TagsResult,
+    parse_json_response, slugify, AiEndpoint, AiError, CategoriesResult, SeoResult, TagsResult,
     DEFAULT_CATEGORIES_PROMPT, DEFAULT_SEO_PROMPT, DEFAULT_SHORT_DESC_PROMPT, DEFAULT_TAGS_PROMPT,
 };
 
@@ -134,6 +144,22 @@ pub struct ProductAssistantResponse {
     pub suggested_category_ids: Vec<String>,
 }
 
+/// Streamed fragment of one generation (`field`: seo, tags, categories, short_desc)
+#[derive(Debug, Serialize)]
+struct AssistantDelta<'a> {
+    field: &'a str,
+    text: &'a str,
+}
+
+/// System prompts for the four generations plus the shared user prompt
+struct AssistantPrompts {
+    user: String,
+    seo: String,
+    tags: String,
+    categories: String,
+    short_desc: String,
+}
+
 // ============================================================================
 // Handler
 // ============================================================================
@@ -142,6 +168,7 @@ pub struct ProductAssistantResponse {
 pub async fn product_assistant(
     State(state): State<Arc<AdminAiAssistantState>>,
     tenant: TenantContext,
+    headers: HeaderMap,
     Json(request): Json<ProductAssistantRequest>,
 ) -> impl IntoResponse {
     // Validate input
@@ -171,11 +198,16 @@ pub async fn product_assistant(
         .get(&tenant.tenant_id, &request.name, &request.description)
     {
         record_ai_cache_hit("product_assistant");
+        if wants_event_stream(&headers) {
+            let (tx, rx) = mpsc::unbounded_channel();
+            let _ = tx.send(sse_event("done", &cached));
+            return event_stream(rx);
+        }
         return Json(cached).into_response();
     }
 
     // Load AI config (model + API key)
-    let (provider, model, api_key) = match load_ai_config(&state.repo, &tenant.tenant_id).await {
+    let endpoint = match load_ai_config(&state.repo, &state.ai_service, &tenant.tenant_id).await {
         Ok(cfg) => cfg,
         Err(e) => {
             tracing::warn!(error = %e, "AI not configured");
@@ -188,10 +220,69 @@ pub async fn product_assistant(
         }
     };
 
+    // Refuse up front so a blocked budget is not cached as an empty generation
+    if let Err(e) = state.ai_service.check_budget(&endpoint).await {
+        let (status, body) = error_response(ErrorCode::RateLimited, Some(e.to_string()), None);
+        return json_error(status, body).into_response();
+    }
+
+    let prompts = build_prompts(&state, &tenant.tenant_id, &request).await;
+
+    if wants_event_stream(&headers) {
+        return stream_product_assistant(state, tenant.tenant_id, request, endpoint, prompts);
+    }
+
+    // Make 4 parallel AI calls with metrics
+    let (seo_result, tags_result, categories_result, short_desc_result) = tokio::join!(
+        state
+            .ai_service
+            .complete_with_metrics(&endpoint, &prompts.seo, &prompts.user, "seo"),
+        state
+            .ai_service
+            .complete_with_metrics(&endpoint, &prompts.tags, &prompts.user, "tags"),
+        state.ai_service.complete_with_metrics(
+            &endpoint,
+            &prompts.categories,
+            &prompts.user,
+            "categories"
+        ),
+        state.ai_service.complete_with_metrics(
+            &endpoint,
+            &prompts.short_desc,
+            &prompts.user,
+            "short_desc"
+        ),
+    );
+
+    let response = assemble_response(
+        &request.name,
+        seo_result,
+        tags_result,
+        categories_result,
+        short_desc_result,
+    );
+
+    // Cache the response
+    state.cache.set(
+        &tenant.tenant_id,
+        &request.name,
+        &request.description,
+        response.clone(),
+    );
+
+    Json(response).into_response()
+}
+
+/// Load collections and prompt overrides for the four generations
+async fn build_prompts(
+    state: &AdminAiAssistantState,
+    tenant_id: &str,
+    request: &ProductAssistantRequest,
+) -> AssistantPrompts {
     // Load collections for category suggestions (active only, up to 100)
     let collections = state
         .store
-        .list_collections(&tenant.tenant_id, Some(true), 100, 0)
+        .list_collections(tenant_id, Some(true), 100, 0)
         .await
         .unwrap_or_default();
 
@@ -208,67 +299,46 @@ pub async fn product_assistant(
 
     // Load custom prompts (or use defaults)
     let (seo_prompt, tags_prompt, categories_prompt, short_desc_prompt) = tokio::join!(
-        load_prompt(&state.repo, &tenant.tenant_id, "seo", DEFAULT_SEO_PROMPT),
-        load_prompt(&state.repo, &tenant.tenant_id, "tags", DEFAULT_TAGS_PROMPT),
+        load_prompt(&state.repo, tenant_id, "seo", DEFAULT_SEO_PROMPT),
+        load_prompt(&state.repo, tenant_id, "tags", DEFAULT_TAGS_PROMPT),
         load_prompt(
             &state.repo,
-            &tenant.tenant_id,
+            tenant_id,
             "categories",
             DEFAULT_CATEGORIES_PROMPT
         ),
         load_prompt(
             &state.repo,
-            &tenant.tenant_id,
+            tenant_id,
             "short_desc",
             DEFAULT_SHORT_DESC_PROMPT
         ),
     );
 
     // Build user prompt with product info
-    let user_prompt = format!(
+    let user = format!(
         "Product Name: {}\n\nProduct Description: {}",
         request.name, request.description
     );
 
-    // Replace {categories} placeholder in categories prompt
-    let categories_system_prompt = categories_prompt.replace("{categories}", &categories_context);
-
-    // Make 4 parallel AI calls with metrics
-    let (seo_result, tags_result, categories_result, short_desc_result) = tokio::join!(
-        state.ai_service.complete_with_metrics(
-            provider,
-            model,
-            &api_key,
-            &seo_prompt,
-            &user_prompt,
-            "seo"
-        ),
-        state.ai_service.complete_with_metrics(
-            provider,
-            model,
-            &api_key,
-            &tags_prompt,
-            &user_prompt,
-            "tags"
-        ),
-        state.ai_service.complete_with_metrics(
-            provider,
-            model,
-            &api_key,
-            &categories_system_prompt,
-            &user_prompt,
-            "categories"
-        ),
-        state.ai_service.complete_with_metrics(
-            provider,
-            model,
-            &api_key,
-            &short_desc_prompt,
-            &user_prompt,
-            "short_desc"
-        ),
-    );
+    AssistantPrompts {
+        user,
+        seo: seo_prompt,
+        tags: tags_prompt,
+        // Replace {categories} placeholder in categories prompt
+        categories: categories_prompt.replace("{categories}", &categories_context),
+        short_desc: short_desc_prompt,
+    }
+}
 
+/// Parse the four generations into a response; failed parts are left empty
+fn assemble_response(
+    name: &str,
+    seo_result: Result<String, AiError>,
+    tags_result: Result<String, AiError>,
+    categories_result: Result<String, AiError>,
+    short_desc_result: Result<String, AiError>,
+) -> ProductAssistantResponse {
     // Parse SEO result
     let (seo_title, seo_description) = match seo_result {
         Ok(raw) => match parse_json_response::<SeoResult>(&raw) {
@@ -324,26 +394,82 @@ pub async fn product_assistant(
     };
 
     // Generate slug from name (no AI needed)
-    let slug = slugify(&request.name);
+    let slug = slugify(name);
 
-    let response = ProductAssistantResponse {
+    ProductAssistantResponse {
         seo_title,
         seo_description,
         short_description,
         tags,
         slug,
         suggested_category_ids,
-    };
+    }
+}
 
-    // Cache the response
-    state.cache.set(
-        &tenant.tenant_id,
-        &request.name,
-        &request.description,
-        response.clone(),
-    );
+/// Stream the four generations as SSE, then cache and send the parsed result
+fn stream_product_assistant(
+    state: Arc<AdminAiAssistantState>,
+    tenant_id: String,
+    request: ProductAssistantRequest,
+    endpoint: AiEndpoint,
+    prompts: AssistantPrompts,
+) -> Response {
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let delta = |field: &'static str| {
+            let tx = tx.clone();
+            move |text: &str| {
+                let _ = tx.send(sse_event("delta", &AssistantDelta { field, text }));
+            }
+        };
+        let (on_seo, on_tags, on_categories, on_short_desc) = (
+            delta("seo"),
+            delta("tags"),
+            delta("categories"),
+            delta("short_desc"),
+        );
+        let ai = &state.ai_service;
+        let (seo_result, tags_result, categories_result, short_desc_result) = tokio::join!(
+            ai.stream_complete_with_metrics(&endpoint, &prompts.seo, &prompts.user, "seo", &on_seo),
+            ai.stream_complete_with_metrics(
+                &endpoint,
+                &prompts.tags,
+                &prompts.user,
+                "tags",
+                &on_tags
+            ),
+            ai.stream_complete_with_metrics(
+                &endpoint,
+                &prompts.categories,
+                &prompts.user,
+                "categories",
+                &on_categories
+            ),
+            ai.stream_complete_with_metrics(
+                &endpoint,
+                &prompts.short_desc,
+                &prompts.user,
+                "short_desc",
+                &on_short_desc
+            ),
+        );
 
-    Json(response).into_response()
+        let response = assemble_response(
+            &request.name,
+            seo_result,
+            tags_result,
+            categories_result,
+            short_desc_result,
+        );
+        state.cache.set(
+            &tenant_id,
+            &request.name,
+            &request.description,
+            response.clone(),
+        );
+        let _ = tx.send(sse_event("done", &response));
+    });
+    event_stream(rx)
 }
 
 #[cfg(test)]
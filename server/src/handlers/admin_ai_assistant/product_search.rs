@@ -1,4 +1,8 @@
 //! Product Search handler - finds products matching a user query using AI.
+//!
+//! Tenants with an embeddings model get hybrid keyword + embedding search;
+//! others fall back to asking the product assistant model to pick from the
+//! catalog.
 
 use std::sync::Arc;
 
@@ -163,20 +167,6 @@ pub async fn product_search(
         return json_error(status, body).into_response();
     }
 
-    // Load AI config
-    let (provider, model, api_key) = match load_ai_config(&state.repo, &tenant.tenant_id).await {
-        Ok(cfg) => cfg,
-        Err(e) => {
-            tracing::warn!(error = %e, "AI not configured");
-            let (status, body) = error_response(
-                ErrorCode::ConfigError,
-                Some(format!("AI not configured: {}", e)),
-                None,
-            );
-            return json_error(status, body).into_response();
-        }
-    };
-
     // Load all products for catalog context
     let all_products = match state.product_repo.list_products(&tenant.tenant_id).await {
         Ok(products) => products,
@@ -200,6 +190,39 @@ pub async fn product_search(
         .into_response();
     }
 
+    // Embedding search needs no completion call
+    let ranked = state
+        .search
+        .rank_products(&tenant.tenant_id, query, &all_products, 3)
+        .await;
+    if ranked.semantic {
+        let products: Vec<ProductMatch> = ranked
+            .items
+            .into_iter()
+            .map(|p| product_to_match(p, "Matches by keyword and meaning".to_string()))
+            .collect();
+        let reasoning = format!("Found {} products matching '{}'", products.len(), query);
+        return Json(ProductSearchResponse {
+            products,
+            reasoning,
+        })
+        .into_response();
+    }
+
+    // Load AI config
+    let endpoint = match load_ai_config(&state.repo, &state.ai_service, &tenant.tenant_id).await {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            tracing::warn!(error = %e, "AI not configured");
+            let (status, body) = error_response(
+                ErrorCode::ConfigError,
+                Some(format!("AI not configured: {}", e)),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+    };
+
     // Format catalog for AI (max 50 products to stay within token limits)
     let catalog_context = format_catalog_for_search(&all_products, 50);
 
@@ -221,14 +244,7 @@ pub async fn product_search(
     // Call AI
     let ai_result = state
         .ai_service
-        .complete_with_metrics(
-            provider,
-            model,
-            &api_key,
-            &system_prompt,
-            &user_prompt,
-            "product_search",
-        )
+        .complete_with_metrics(&endpoint, &system_prompt, &user_prompt, "product_search")
         .await;
 
     let (products, reasoning) = match ai_result {
@@ -157,7 +157,7 @@ pub async fn related_products(
         };
 
     // Load AI config
-    let (provider, model, api_key) = match load_ai_config(&state.repo, &tenant.tenant_id).await {
+    let endpoint = match load_ai_config(&state.repo, &state.ai_service, &tenant.tenant_id).await {
         Ok(cfg) => cfg,
         Err(e) => {
             tracing::warn!(error = %e, "AI not configured");
@@ -263,14 +263,7 @@ pub async fn related_products(
     // Call AI
     let ai_result = state
         .ai_service
-        .complete_with_metrics(
-            provider,
-            model,
-            &api_key,
-            &system_prompt,
-            &user_prompt,
-            "related_products",
-        )
+        .complete_with_metrics(&endpoint, &system_prompt, &user_prompt, "related_products")
         .await;
 
     let (related_ids, reasoning) = match ai_result {
@@ -0,0 +1,61 @@
+//! Admin cold-archive lookup handlers.
+//!
+//! - `GET /admin/archive/purchases/{signature}` — fetch an archived purchase
+//!   from cold storage, verifying the archive file checksum
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+
+use crate::errors::{error_response, ErrorCode};
+use crate::handlers::response::{json_error, json_ok};
+use crate::middleware::TenantContext;
+use crate::services::cold_archive::{ColdArchiveError, ColdArchiveService};
+
+/// Handler state for cold-archive routes.
+pub struct ArchiveState {
+    pub archive: Arc<ColdArchiveService>,
+}
+
+/// GET /admin/archive/purchases/{signature}
+///
+/// Returns the archived purchase together with the object key, archive time
+/// and checksum of the file it was restored from.
+pub async fn get_archived_purchase(
+    State(state): State<Arc<ArchiveState>>,
+    tenant: TenantContext,
+    Path(signature): Path<String>,
+) -> impl IntoResponse {
+    match state
+        .archive
+        .find_purchase(&tenant.tenant_id, &signature)
+        .await
+    {
+        Ok(Some(archived)) => json_ok(archived),
+        Ok(None) => {
+            let (status, body) = error_response(
+                ErrorCode::ResourceNotFound,
+                Some("archived purchase not found".into()),
+                None,
+            );
+            json_error(status, body)
+        }
+        Err(e) => {
+            tracing::warn!(
+                tenant_id = %tenant.tenant_id,
+                signature = %signature,
+                error = %e,
+                "Archived purchase lookup failed"
+            );
+            let code = match e {
+                ColdArchiveError::NotConfigured(_) => ErrorCode::ConfigError,
+                _ => ErrorCode::InternalError,
+            };
+            let (status, body) = error_response(code, Some(e.to_string()), None);
+            json_error(status, body)
+        }
+    }
+}
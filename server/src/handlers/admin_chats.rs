@@ -1,6 +1,9 @@
 //! Admin chat endpoints for viewing chat sessions and history.
 //!
-//! Provides CRM-style access to customer chat conversations.
+//! Provides CRM-style access to customer chat conversations, plus the live
+//! agent inbox: sessions waiting for a human (`?status=waiting_for_agent`)
+//! can be claimed, answered as role `agent`, and released back to the
+//! assistant.
 
 use std::sync::Arc;
 
@@ -12,11 +15,14 @@ use axum::{
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::chat::handoff_error;
 use super::response::json_error;
 use crate::errors::{error_response, ErrorCode};
+use crate::handlers::admin::audit;
 use crate::handlers::admin_ai_assistant::ProductMatch;
 use crate::middleware::TenantContext;
-use crate::models::ChatMessage;
+use crate::models::{ChatMessage, ChatSession};
+use crate::services::ChatHandoffService;
 use crate::storage::Store;
 
 // ============================================================================
@@ -26,11 +32,12 @@ use crate::storage::Store;
 /// Shared state for admin chat handlers
 pub struct AdminChatState {
     pub store: Arc<dyn Store>,
+    pub handoff: Arc<ChatHandoffService>,
 }
 
 impl AdminChatState {
-    pub fn new(store: Arc<dyn Store>) -> Self {
-        Self { store }
+    pub fn new(store: Arc<dyn Store>, handoff: Arc<ChatHandoffService>) -> Self {
+        Self { store, handoff }
     }
 }
 
@@ -45,7 +52,7 @@ pub struct ListSessionsQuery {
     /// Filter by customer ID
     #[serde(default)]
     pub customer_id: Option<String>,
-    /// Filter by status (active, archived)
+    /// Filter by status (active, waiting_for_agent, with_agent, archived)
     #[serde(default)]
     pub status: Option<String>,
     /// Page size (default 20, max 100)
@@ -71,10 +78,33 @@ pub struct ChatSessionSummary {
     pub customer_email: Option<String>,
     pub status: String,
     pub message_count: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_agent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handoff_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handoff_requested_at: Option<DateTime<Utc>>,
     pub last_message_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
 }
 
+impl From<ChatSession> for ChatSessionSummary {
+    fn from(s: ChatSession) -> Self {
+        Self {
+            id: s.id,
+            customer_id: s.customer_id,
+            customer_email: s.customer_email,
+            status: s.status,
+            message_count: s.message_count,
+            assigned_agent: s.assigned_agent,
+            handoff_reason: s.handoff_reason,
+            handoff_requested_at: s.handoff_requested_at,
+            last_message_at: s.last_message_at,
+            created_at: s.created_at,
+        }
+    }
+}
+
 /// GET /admin/chats response
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -92,6 +122,9 @@ pub struct ChatMessageView {
     pub id: String,
     pub role: String,
     pub content: String,
+    /// Staff member who wrote an `agent` message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub products: Option<Vec<ProductMatch>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -143,18 +176,8 @@ pub async fn list_chat_sessions(
         }
     };
 
-    let session_summaries: Vec<ChatSessionSummary> = sessions
-        .into_iter()
-        .map(|s| ChatSessionSummary {
-            id: s.id,
-            customer_id: s.customer_id,
-            customer_email: s.customer_email,
-            status: s.status,
-            message_count: s.message_count,
-            last_message_at: s.last_message_at,
-            created_at: s.created_at,
-        })
-        .collect();
+    let session_summaries: Vec<ChatSessionSummary> =
+        sessions.into_iter().map(ChatSessionSummary::from).collect();
 
     Json(ListSessionsResponse {
         sessions: session_summaries,
@@ -218,20 +241,106 @@ pub async fn get_chat_session(
     let message_views: Vec<ChatMessageView> = messages.into_iter().map(message_to_view).collect();
 
     Json(GetSessionResponse {
-        session: ChatSessionSummary {
-            id: session.id,
-            customer_id: session.customer_id,
-            customer_email: session.customer_email,
-            status: session.status,
-            message_count: session.message_count,
-            last_message_at: session.last_message_at,
-            created_at: session.created_at,
-        },
+        session: session.into(),
         messages: message_views,
     })
     .into_response()
 }
 
+/// POST /admin/chats/:sessionId/messages request
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentMessageRequest {
+    pub content: String,
+}
+
+/// Identity recorded on claims and agent replies
+fn agent_id(tenant: &TenantContext) -> String {
+    tenant
+        .admin_actor
+        .clone()
+        .unwrap_or_else(|| "admin".to_string())
+}
+
+/// POST /admin/chats/:sessionId/claim - Take over a session waiting for an agent
+pub async fn claim_chat_session(
+    State(state): State<Arc<AdminChatState>>,
+    tenant: TenantContext,
+    Path(session_id): Path<String>,
+) -> impl IntoResponse {
+    let agent = agent_id(&tenant);
+    match state
+        .handoff
+        .claim(&tenant.tenant_id, &session_id, &agent)
+        .await
+    {
+        Ok(session) => {
+            audit(
+                &*state.store,
+                &tenant,
+                "chat_session",
+                &session_id,
+                "claim",
+                None,
+            )
+            .await;
+            Json(ChatSessionSummary::from(session)).into_response()
+        }
+        Err(e) => handoff_error(e).into_response(),
+    }
+}
+
+/// POST /admin/chats/:sessionId/messages - Reply to the customer as the claiming agent
+pub async fn post_agent_message(
+    State(state): State<Arc<AdminChatState>>,
+    tenant: TenantContext,
+    Path(session_id): Path<String>,
+    Json(request): Json<AgentMessageRequest>,
+) -> impl IntoResponse {
+    let content = request.content.trim();
+    if content.is_empty() {
+        let (status, body) = error_response(
+            ErrorCode::InvalidField,
+            Some("content is required".into()),
+            None,
+        );
+        return json_error(status, body).into_response();
+    }
+
+    let agent = agent_id(&tenant);
+    match state
+        .handoff
+        .post_agent_message(&tenant.tenant_id, &session_id, &agent, content)
+        .await
+    {
+        Ok(message) => Json(message_to_view(message)).into_response(),
+        Err(e) => handoff_error(e).into_response(),
+    }
+}
+
+/// POST /admin/chats/:sessionId/release - Hand the session back to the assistant
+pub async fn release_chat_session(
+    State(state): State<Arc<AdminChatState>>,
+    tenant: TenantContext,
+    Path(session_id): Path<String>,
+) -> impl IntoResponse {
+    match state.handoff.release(&tenant.tenant_id, &session_id).await {
+        Ok(session) => {
+            audit(
+                &*state.store,
+                &tenant,
+                "chat_session",
+                &session_id,
+                "release",
+                None,
+            )
+            .await;
+            Json(ChatSessionSummary::from(session)).into_response()
+        }
+        Err(e) => handoff_error(e).into_response(),
+    }
+}
+
 /// Query params for listing user chat sessions
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -280,18 +389,8 @@ pub async fn list_user_chat_sessions(
         }
     };
 
-    let session_summaries: Vec<ChatSessionSummary> = sessions
-        .into_iter()
-        .map(|s| ChatSessionSummary {
-            id: s.id,
-            customer_id: s.customer_id,
-            customer_email: s.customer_email,
-            status: s.status,
-            message_count: s.message_count,
-            last_message_at: s.last_message_at,
-            created_at: s.created_at,
-        })
-        .collect();
+    let session_summaries: Vec<ChatSessionSummary> =
+        sessions.into_iter().map(ChatSessionSummary::from).collect();
 
     Json(ListSessionsResponse {
         sessions: session_summaries,
@@ -320,6 +419,7 @@ fn message_to_view(msg: ChatMessage) -> ChatMessageView {
         id: msg.id,
         role: msg.role,
         content: msg.content,
+        author_id: msg.author_id,
         products,
         actions,
         created_at: msg.created_at,
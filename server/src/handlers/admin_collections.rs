@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::{error_response, ErrorCode};
 use crate::handlers::admin::{audit, AdminState};
+use crate::handlers::admin_products_types::validate_payment_split;
 use crate::handlers::response::{json_error, json_ok};
 use crate::middleware::TenantContext;
 use crate::models::Collection;
@@ -37,6 +38,8 @@ pub struct CreateCollectionRequest {
     #[serde(default = "default_active")]
     pub active: bool,
     pub tokenization_config: Option<crate::models::TokenizationConfig>,
+    #[serde(default)]
+    pub payment_split: Option<crate::models::PaymentSplit>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -49,6 +52,8 @@ pub struct UpdateCollectionRequest {
     #[serde(default = "default_active")]
     pub active: bool,
     pub tokenization_config: Option<crate::models::TokenizationConfig>,
+    #[serde(default)]
+    pub payment_split: Option<crate::models::PaymentSplit>,
 }
 
 #[derive(Debug, Serialize)]
@@ -144,6 +149,17 @@ pub async fn create_collection(
         }
     };
 
+    if let Some(ref split) = req.payment_split {
+        if let Err(message) = validate_payment_split(split) {
+            let (status, body) = error_response(
+                ErrorCode::InvalidField,
+                Some(format!("paymentSplit: {message}")),
+                None,
+            );
+            return json_error(status, body);
+        }
+    }
+
     // Validate tokenization config if present
     if let Some(ref tc) = req.tokenization_config {
         if tc.transfer_fee_bps < 0 || tc.transfer_fee_bps > 10_000 {
@@ -181,6 +197,7 @@ pub async fn create_collection(
         product_ids,
         active: req.active,
         tokenization_config: req.tokenization_config,
+        payment_split: req.payment_split,
         created_at: now,
         updated_at: now,
     };
@@ -231,6 +248,17 @@ pub async fn update_collection(
         }
     };
 
+    if let Some(ref split) = req.payment_split {
+        if let Err(message) = validate_payment_split(split) {
+            let (status, body) = error_response(
+                ErrorCode::InvalidField,
+                Some(format!("paymentSplit: {message}")),
+                None,
+            );
+            return json_error(status, body);
+        }
+    }
+
     let existing = match state.store.get_collection(&tenant.tenant_id, &id).await {
         Ok(Some(collection)) => collection,
         Ok(None) => {
@@ -259,6 +287,7 @@ pub async fn update_collection(
         product_ids,
         active: req.active,
         tokenization_config: req.tokenization_config.or(existing.tokenization_config),
+        payment_split: req.payment_split.or(existing.payment_split),
         created_at: existing.created_at,
         updated_at: Utc::now(),
     };
@@ -334,6 +363,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
 
         let tenant = TenantContext::default();
@@ -344,6 +374,7 @@ mod tests {
             product_ids: vec!["prod-1".to_string(), "prod-2".to_string()],
             active: true,
             tokenization_config: None,
+            payment_split: None,
         };
 
         let response = create_collection(State(state), tenant.clone(), Json(request))
@@ -367,6 +398,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
 
         let tenant = TenantContext::default();
@@ -377,6 +409,7 @@ mod tests {
             product_ids: vec![],
             active: true,
             tokenization_config: None,
+            payment_split: None,
         };
 
         let response = create_collection(State(state), tenant, Json(request))
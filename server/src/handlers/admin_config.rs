@@ -14,8 +14,9 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
 use crate::config::{
-    default_keys_for_category, secret_fields_for_category, BatchUpsertItem,
-    PostgresConfigRepository, KNOWN_CATEGORIES, REDACTED_PLACEHOLDER,
+    default_keys_for_category, secret_fields_for_category, BatchUpsertItem, ConfigBus,
+    ConfigOverride, ConfigRepositoryError, DekInfo, EncryptionError, PostgresConfigRepository,
+    ServiceConfigStatus, KNOWN_CATEGORIES, REDACTED_PLACEHOLDER,
 };
 use crate::errors::{error_response, ErrorCode};
 use crate::handlers::response::json_error;
@@ -93,6 +94,9 @@ pub struct UpdateConfigResponse {
     pub category: String,
     pub updated: bool,
     pub message: String,
+    /// Config version published to running services, if hot reload is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config_version: Option<u64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -122,6 +126,9 @@ pub struct BatchUpdateItem {
 pub struct BatchUpdateResponse {
     pub updated_count: usize,
     pub message: String,
+    /// Config version published to running services, if hot reload is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config_version: Option<u64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -156,9 +163,85 @@ pub struct HistoryEntry {
     pub changed_by: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigStatusResponse {
+    pub tenant_id: String,
+    /// Latest published config version (0 = startup config)
+    pub version: u64,
+    pub services: Vec<ServiceConfigStatus>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListEncryptionKeysResponse {
+    pub keys: Vec<DekInfo>,
+    pub count: usize,
+}
+
 /// Shared state for admin config handlers
 pub struct AdminConfigState {
     pub repo: Arc<PostgresConfigRepository>,
+    /// Set when running services hot-reload config; writes are validated
+    /// against the full config and published through it.
+    pub config_bus: Option<Arc<ConfigBus>>,
+}
+
+impl AdminConfigState {
+    /// Bus for `tenant_id`, if that tenant's config drives the running services
+    fn bus_for(&self, tenant_id: &str) -> Option<&Arc<ConfigBus>> {
+        self.config_bus
+            .as_ref()
+            .filter(|bus| bus.tenant_id() == tenant_id)
+    }
+
+    /// Full-config validation errors the overrides would cause (empty if valid
+    /// or if hot reload is not enabled for this tenant).
+    async fn candidate_errors(&self, tenant_id: &str, overrides: &[ConfigOverride]) -> Vec<String> {
+        match self.bus_for(tenant_id) {
+            Some(bus) => match bus.candidate(tenant_id, overrides).await {
+                Ok(_) => Vec::new(),
+                Err(e) => vec![e.to_string()],
+            },
+            None => Vec::new(),
+        }
+    }
+
+    /// Publish saved changes to running services, returning the new version.
+    async fn publish(&self, tenant_id: &str, mut categories: Vec<String>) -> Option<u64> {
+        let bus = self.bus_for(tenant_id)?;
+        categories.sort();
+        categories.dedup();
+        match bus.publish(tenant_id, &categories).await {
+            Ok(version) => version,
+            Err(e) => {
+                tracing::error!(error = %e, "Saved config failed validation; not published");
+                None
+            }
+        }
+    }
+}
+
+fn overrides_from_object(
+    category: &str,
+    obj: &serde_json::Map<String, JsonValue>,
+) -> Vec<ConfigOverride> {
+    obj.iter()
+        .map(|(key, value)| ConfigOverride {
+            category: category.to_string(),
+            config_key: key.clone(),
+            value: value.clone(),
+        })
+        .collect()
+}
+
+fn validation_failed_response(errors: Vec<String>) -> axum::response::Response {
+    let (status, body) = error_response(
+        ErrorCode::InvalidField,
+        Some("Config validation failed".to_string()),
+        Some(serde_json::json!({ "errors": errors })),
+    );
+    json_error(status, body).into_response()
 }
 
 // ============================================================================
@@ -323,6 +406,16 @@ pub async fn update_config(
         return json_error(status, body).into_response();
     };
 
+    let errors = state
+        .candidate_errors(
+            &tenant.tenant_id,
+            &overrides_from_object(&category, &config_obj),
+        )
+        .await;
+    if !errors.is_empty() {
+        return validation_failed_response(errors);
+    }
+
     // Collect items for atomic batch upsert
     let items: Vec<BatchUpsertItem> = config_obj
         .into_iter()
@@ -353,10 +446,18 @@ pub async fn update_config(
         }
     };
 
+    let config_version = if update_count > 0 {
+        state
+            .publish(&tenant.tenant_id, vec![category.clone()])
+            .await
+    } else {
+        None
+    };
     let response = UpdateConfigResponse {
         category,
         updated: update_count > 0,
         message: format!("Updated {} config keys", update_count),
+        config_version,
     };
     Json(response).into_response()
 }
@@ -381,6 +482,16 @@ pub async fn patch_config(
         return json_error(status, body).into_response();
     };
 
+    let errors = state
+        .candidate_errors(
+            &tenant.tenant_id,
+            &overrides_from_object(&category, &updates_obj),
+        )
+        .await;
+    if !errors.is_empty() {
+        return validation_failed_response(errors);
+    }
+
     let mut update_count = 0;
     for (key, value) in updates_obj {
         // Skip if value is [REDACTED]
@@ -413,10 +524,18 @@ pub async fn patch_config(
         }
     }
 
+    let config_version = if update_count > 0 {
+        state
+            .publish(&tenant.tenant_id, vec![category.clone()])
+            .await
+    } else {
+        None
+    };
     let response = UpdateConfigResponse {
         category,
         updated: update_count > 0,
         message: format!("Patched {} config keys", update_count),
+        config_version,
     };
     Json(response).into_response()
 }
@@ -429,6 +548,20 @@ pub async fn batch_update(
 ) -> impl IntoResponse {
     let updated_by = Some(tenant.tenant_id.as_str());
 
+    let overrides: Vec<ConfigOverride> = request
+        .updates
+        .iter()
+        .map(|item| ConfigOverride {
+            category: item.category.clone(),
+            config_key: item.config_key.clone(),
+            value: item.value.clone(),
+        })
+        .collect();
+    let errors = state.candidate_errors(&tenant.tenant_id, &overrides).await;
+    if !errors.is_empty() {
+        return validation_failed_response(errors);
+    }
+
     let items: Vec<BatchUpsertItem> = request
         .updates
         .into_iter()
@@ -442,6 +575,8 @@ pub async fn batch_update(
         })
         .collect();
 
+    let categories: Vec<String> = items.iter().map(|item| item.category.clone()).collect();
+
     let update_count = match state
         .repo
         .batch_upsert_config(&tenant.tenant_id, items)
@@ -459,20 +594,28 @@ pub async fn batch_update(
         }
     };
 
+    let config_version = if update_count > 0 {
+        state.publish(&tenant.tenant_id, categories).await
+    } else {
+        None
+    };
     let response = BatchUpdateResponse {
         updated_count: update_count,
         message: format!("Batch updated {} config keys", update_count),
+        config_version,
     };
     Json(response).into_response()
 }
 
 /// POST /admin/config/validate - Validate config without saving
+///
+/// When hot reload is enabled, the change is also merged into the tenant's
+/// full config and run through the same validation as startup.
 pub async fn validate_config(
-    State(_state): State<Arc<AdminConfigState>>,
+    State(state): State<Arc<AdminConfigState>>,
     tenant: TenantContext,
     Json(request): Json<ValidateConfigRequest>,
 ) -> impl IntoResponse {
-    let _ = tenant; // Used for logging context
     let mut errors = Vec::new();
     let mut warnings = Vec::new();
 
@@ -506,6 +649,17 @@ pub async fn validate_config(
         _ => {}
     }
 
+    if let Some(obj) = request.config.as_object() {
+        errors.extend(
+            state
+                .candidate_errors(
+                    &tenant.tenant_id,
+                    &overrides_from_object(&request.category, obj),
+                )
+                .await,
+        );
+    }
+
     let response = ValidateConfigResponse {
         valid: errors.is_empty(),
         errors,
@@ -555,6 +709,90 @@ pub async fn get_history(
     }
 }
 
+/// GET /admin/config/status - Config version applied by each running service
+pub async fn get_config_status(
+    State(state): State<Arc<AdminConfigState>>,
+    tenant: TenantContext,
+) -> impl IntoResponse {
+    match state.bus_for(&tenant.tenant_id) {
+        Some(bus) => Json(ConfigStatusResponse {
+            tenant_id: bus.tenant_id().to_string(),
+            version: bus.version(),
+            services: bus.statuses(),
+        })
+        .into_response(),
+        None => {
+            let (status, body) = error_response(
+                ErrorCode::ServiceUnavailable,
+                Some("Config hot reload is not enabled for this tenant".to_string()),
+                None,
+            );
+            json_error(status, body).into_response()
+        }
+    }
+}
+
+/// GET /admin/config/encryption/keys - List the tenant's DEK versions
+pub async fn list_encryption_keys(
+    State(state): State<Arc<AdminConfigState>>,
+    tenant: TenantContext,
+) -> impl IntoResponse {
+    match state.repo.list_encryption_keys(&tenant.tenant_id).await {
+        Ok(keys) => Json(ListEncryptionKeysResponse {
+            count: keys.len(),
+            keys,
+        })
+        .into_response(),
+        Err(e) => encryption_error_response(e, "Failed to list encryption keys"),
+    }
+}
+
+/// POST /admin/config/encryption/rotate - Rotate the tenant's DEK
+///
+/// Creates a new DEK version and re-encrypts every secret config value under it.
+pub async fn rotate_encryption_key(
+    State(state): State<Arc<AdminConfigState>>,
+    tenant: TenantContext,
+) -> impl IntoResponse {
+    let rotated_by = Some(tenant.tenant_id.as_str());
+
+    match state
+        .repo
+        .rotate_tenant_dek(&tenant.tenant_id, rotated_by)
+        .await
+    {
+        Ok(summary) => {
+            tracing::info!(
+                tenant_id = %tenant.tenant_id,
+                new_version = summary.new_version,
+                reencrypted = summary.reencrypted,
+                "Rotated config DEK"
+            );
+            Json(summary).into_response()
+        }
+        Err(e) => encryption_error_response(e, "Failed to rotate encryption key"),
+    }
+}
+
+fn encryption_error_response(e: ConfigRepositoryError, context: &str) -> axum::response::Response {
+    let (code, message) = match e {
+        ConfigRepositoryError::Encryption(EncryptionError::KekNotConfigured) => (
+            ErrorCode::ConfigError,
+            "Config encryption is not enabled (CEDROS_CONFIG_KEK is not set)".to_string(),
+        ),
+        ConfigRepositoryError::Encryption(EncryptionError::RotationConflict(_)) => (
+            ErrorCode::InvalidOperation,
+            "A key rotation is already in progress".to_string(),
+        ),
+        e => {
+            tracing::error!(error = %e, "{}", context);
+            (ErrorCode::InternalError, context.to_string())
+        }
+    };
+    let (status, body) = error_response(code, Some(message), None);
+    json_error(status, body).into_response()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
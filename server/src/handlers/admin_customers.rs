@@ -244,6 +244,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
 
         let tenant = TenantContext::default();
@@ -276,6 +277,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
 
         let tenant = TenantContext::default();
@@ -278,6 +278,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
 
         let tenant = TenantContext::default();
@@ -315,6 +316,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
 
         let tenant = TenantContext::default();
@@ -0,0 +1,146 @@
+//! Admin handlers for the email suppression list.
+//!
+//! - `GET /admin/email-suppressions` — suppressed addresses, newest first
+//! - `POST /admin/email-suppressions` — suppress an address manually
+//! - `DELETE /admin/email-suppressions/{email}` — allow sending to an address again
+//!
+//! S-01: All handlers enforce tenant isolation via TenantContext extractor.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{error_response, ErrorCode};
+use crate::handlers::admin::{audit, AdminState};
+use crate::handlers::response::{json_error, json_ok};
+use crate::middleware::TenantContext;
+use crate::storage::{normalize_email_address, EmailSuppression, SuppressionReason};
+
+use super::cap_limit_opt;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListEmailSuppressionsQuery {
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListEmailSuppressionsResponse {
+    pub suppressions: Vec<EmailSuppression>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEmailSuppressionRequest {
+    pub email: String,
+    pub detail: Option<String>,
+}
+
+fn database_error(message: String) -> (axum::http::StatusCode, Json<serde_json::Value>) {
+    let (status, body) = error_response(ErrorCode::DatabaseError, Some(message), None);
+    json_error(status, body)
+}
+
+/// GET /admin/email-suppressions - List suppressed addresses
+pub async fn list_email_suppressions(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Query(params): Query<ListEmailSuppressionsQuery>,
+) -> impl IntoResponse {
+    let limit = cap_limit_opt(params.limit, 50);
+    let offset = params.offset.unwrap_or(0).max(0);
+    match state
+        .store
+        .list_email_suppressions(&tenant.tenant_id, limit, offset)
+        .await
+    {
+        Ok(suppressions) => json_ok(ListEmailSuppressionsResponse { suppressions }),
+        Err(e) => database_error(format!("Failed to list email suppressions: {e}")),
+    }
+}
+
+/// POST /admin/email-suppressions - Suppress an address manually
+pub async fn create_email_suppression(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Json(req): Json<CreateEmailSuppressionRequest>,
+) -> impl IntoResponse {
+    let email = normalize_email_address(&req.email);
+    if email.is_empty() || !email.contains('@') {
+        let (status, body) = error_response(
+            ErrorCode::InvalidField,
+            Some("email must be a valid address".to_string()),
+            None,
+        );
+        return json_error(status, body);
+    }
+
+    let suppression = EmailSuppression {
+        tenant_id: tenant.tenant_id.clone(),
+        email: email.clone(),
+        reason: SuppressionReason::Manual,
+        detail: req.detail,
+        created_at: Utc::now(),
+    };
+    if let Err(e) = state
+        .store
+        .upsert_email_suppression(suppression.clone())
+        .await
+    {
+        return database_error(format!("Failed to suppress email: {e}"));
+    }
+    audit(
+        &*state.store,
+        &tenant,
+        "email_suppression",
+        &email,
+        "create",
+        None,
+    )
+    .await;
+    json_ok(suppression)
+}
+
+/// DELETE /admin/email-suppressions/{email} - Remove an address from the list
+pub async fn delete_email_suppression(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Path(email): Path<String>,
+) -> impl IntoResponse {
+    let email = normalize_email_address(&email);
+    match state
+        .store
+        .delete_email_suppression(&tenant.tenant_id, &email)
+        .await
+    {
+        Ok(true) => {
+            audit(
+                &*state.store,
+                &tenant,
+                "email_suppression",
+                &email,
+                "delete",
+                None,
+            )
+            .await;
+            json_ok(serde_json::json!({ "deleted": true }))
+        }
+        Ok(false) => {
+            let (status, body) = error_response(
+                ErrorCode::ResourceNotFound,
+                Some("email suppression not found".to_string()),
+                None,
+            );
+            json_error(status, body)
+        }
+        Err(e) => database_error(format!("Failed to delete email suppression: {e}")),
+    }
+}
@@ -0,0 +1,355 @@
+//! Admin handlers for tenant email templates.
+//!
+//! - `GET /admin/email-templates` — kinds, their sample data and the tenant's overrides
+//! - `GET /admin/email-templates/{kind}/{locale}` — override for a locale, or the built-in
+//! - `PUT /admin/email-templates/{kind}/{locale}` — create or replace an override
+//! - `DELETE /admin/email-templates/{kind}/{locale}` — revert to fallback
+//! - `POST /admin/email-templates/{kind}/preview` — render a stored or draft template
+//!   against sample data
+//!
+//! S-01: All handlers enforce tenant isolation via TenantContext extractor.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{error_response, ErrorCode};
+use crate::handlers::admin::{audit, AdminState};
+use crate::handlers::response::{json_error, json_ok};
+use crate::middleware::TenantContext;
+use crate::services::messaging::email_templates::{
+    normalize_locale, resolve_template, EmailTemplateKind, TemplateParts, MAX_SUBJECT_CHARS,
+};
+use crate::services::messaging::template::Template;
+use crate::storage::EmailTemplate;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailTemplateKindInfo {
+    pub kind: EmailTemplateKind,
+    /// Locales the tenant has overridden
+    pub locales: Vec<String>,
+    /// Preview data; lists the variables available to the kind
+    pub sample_data: serde_json::Value,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListEmailTemplatesResponse {
+    pub kinds: Vec<EmailTemplateKindInfo>,
+    pub templates: Vec<EmailTemplate>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailTemplateResponse {
+    pub kind: EmailTemplateKind,
+    pub locale: String,
+    /// False when no override exists and the built-in template is shown
+    pub custom: bool,
+    #[serde(flatten)]
+    pub parts: TemplateParts,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutEmailTemplateRequest {
+    pub subject: String,
+    pub body_text: String,
+    pub body_html: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewEmailTemplateRequest {
+    /// Locale to resolve the stored template for (fallback applies)
+    pub locale: Option<String>,
+    /// Draft parts; omitted parts come from the resolved template
+    pub subject: Option<String>,
+    pub body_text: Option<String>,
+    pub body_html: Option<String>,
+    /// Top-level fields merged over the kind's sample data
+    pub data: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewEmailTemplateResponse {
+    #[serde(flatten)]
+    pub rendered: TemplateParts,
+    /// Locale of the tenant override used; absent for the built-in template
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+}
+
+fn invalid_field(field: &str, message: String) -> (StatusCode, Json<serde_json::Value>) {
+    let (status, body) = error_response(
+        ErrorCode::InvalidField,
+        Some(message),
+        Some(serde_json::json!({ "field": field })),
+    );
+    json_error(status, body)
+}
+
+fn parse_kind(kind: &str) -> Result<EmailTemplateKind, (StatusCode, Json<serde_json::Value>)> {
+    EmailTemplateKind::parse(kind)
+        .ok_or_else(|| invalid_field("kind", format!("unknown email template kind '{kind}'")))
+}
+
+fn parse_locale(locale: &str) -> Result<String, (StatusCode, Json<serde_json::Value>)> {
+    normalize_locale(locale)
+        .ok_or_else(|| invalid_field("locale", format!("invalid locale '{locale}'")))
+}
+
+/// Parse each part so errors name the offending field
+fn validate_parts(parts: &TemplateParts) -> Result<(), (StatusCode, Json<serde_json::Value>)> {
+    if parts.subject.trim().is_empty() {
+        return Err(invalid_field("subject", "subject is required".to_string()));
+    }
+    if parts.subject.chars().count() > MAX_SUBJECT_CHARS {
+        return Err(invalid_field(
+            "subject",
+            format!("subject must be {MAX_SUBJECT_CHARS} characters or less"),
+        ));
+    }
+    let fields = [
+        ("subject", Some(&parts.subject)),
+        ("bodyText", Some(&parts.body_text)),
+        ("bodyHtml", parts.body_html.as_ref()),
+    ];
+    for (field, source) in fields {
+        if let Some(source) = source {
+            if let Err(e) = Template::parse(source) {
+                return Err(invalid_field(field, format!("{field}: {e}")));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn database_error(message: String) -> (StatusCode, Json<serde_json::Value>) {
+    let (status, body) = error_response(ErrorCode::DatabaseError, Some(message), None);
+    json_error(status, body)
+}
+
+/// GET /admin/email-templates - List kinds and the tenant's overrides
+pub async fn list_email_templates(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+) -> impl IntoResponse {
+    let templates = match state.store.list_email_templates(&tenant.tenant_id).await {
+        Ok(templates) => templates,
+        Err(e) => return database_error(format!("Failed to list email templates: {e}")),
+    };
+
+    let kinds = EmailTemplateKind::ALL
+        .into_iter()
+        .map(|kind| EmailTemplateKindInfo {
+            kind,
+            locales: templates
+                .iter()
+                .filter(|t| t.kind == kind.as_str())
+                .map(|t| t.locale.clone())
+                .collect(),
+            sample_data: kind.sample_data(),
+        })
+        .collect();
+
+    json_ok(ListEmailTemplatesResponse { kinds, templates })
+}
+
+/// GET /admin/email-templates/{kind}/{locale} - Override for exactly this locale,
+/// or the built-in template to start editing from
+pub async fn get_email_template(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Path((kind, locale)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let kind = match parse_kind(&kind) {
+        Ok(kind) => kind,
+        Err(response) => return response,
+    };
+    let locale = match parse_locale(&locale) {
+        Ok(locale) => locale,
+        Err(response) => return response,
+    };
+
+    match state
+        .store
+        .get_email_template(&tenant.tenant_id, kind.as_str(), &locale)
+        .await
+    {
+        Ok(Some(template)) => json_ok(EmailTemplateResponse {
+            kind,
+            locale,
+            custom: true,
+            parts: TemplateParts {
+                subject: template.subject,
+                body_text: template.body_text,
+                body_html: template.body_html,
+            },
+            updated_at: Some(template.updated_at),
+        }),
+        Ok(None) => json_ok(EmailTemplateResponse {
+            kind,
+            locale,
+            custom: false,
+            parts: kind.builtin(),
+            updated_at: None,
+        }),
+        Err(e) => database_error(format!("Failed to load email template: {e}")),
+    }
+}
+
+/// PUT /admin/email-templates/{kind}/{locale} - Create or replace an override
+pub async fn put_email_template(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Path((kind, locale)): Path<(String, String)>,
+    Json(req): Json<PutEmailTemplateRequest>,
+) -> impl IntoResponse {
+    let kind = match parse_kind(&kind) {
+        Ok(kind) => kind,
+        Err(response) => return response,
+    };
+    let locale = match parse_locale(&locale) {
+        Ok(locale) => locale,
+        Err(response) => return response,
+    };
+    let parts = TemplateParts {
+        subject: req.subject,
+        body_text: req.body_text,
+        body_html: req.body_html.filter(|html| !html.trim().is_empty()),
+    };
+    if let Err(response) = validate_parts(&parts) {
+        return response;
+    }
+
+    let template = EmailTemplate {
+        tenant_id: tenant.tenant_id.clone(),
+        kind: kind.as_str().to_string(),
+        locale: locale.clone(),
+        subject: parts.subject.clone(),
+        body_text: parts.body_text.clone(),
+        body_html: parts.body_html.clone(),
+        updated_at: Utc::now(),
+    };
+    if let Err(e) = state.store.upsert_email_template(template.clone()).await {
+        return database_error(format!("Failed to save email template: {e}"));
+    }
+
+    audit(
+        &*state.store,
+        &tenant,
+        "email_template",
+        &format!("{}/{}", kind.as_str(), locale),
+        "update",
+        None,
+    )
+    .await;
+
+    json_ok(EmailTemplateResponse {
+        kind,
+        locale,
+        custom: true,
+        parts,
+        updated_at: Some(template.updated_at),
+    })
+}
+
+/// DELETE /admin/email-templates/{kind}/{locale} - Remove an override
+pub async fn delete_email_template(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Path((kind, locale)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let kind = match parse_kind(&kind) {
+        Ok(kind) => kind,
+        Err(response) => return response,
+    };
+    let locale = match parse_locale(&locale) {
+        Ok(locale) => locale,
+        Err(response) => return response,
+    };
+
+    match state
+        .store
+        .delete_email_template(&tenant.tenant_id, kind.as_str(), &locale)
+        .await
+    {
+        Ok(true) => {
+            audit(
+                &*state.store,
+                &tenant,
+                "email_template",
+                &format!("{}/{}", kind.as_str(), locale),
+                "delete",
+                None,
+            )
+            .await;
+            json_ok(serde_json::json!({ "deleted": true }))
+        }
+        Ok(false) => {
+            let (status, body) = error_response(
+                ErrorCode::ResourceNotFound,
+                Some("email template not found".to_string()),
+                None,
+            );
+            json_error(status, body)
+        }
+        Err(e) => database_error(format!("Failed to delete email template: {e}")),
+    }
+}
+
+/// POST /admin/email-templates/{kind}/preview - Render against sample data
+pub async fn preview_email_template(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Path(kind): Path<String>,
+    Json(req): Json<PreviewEmailTemplateRequest>,
+) -> impl IntoResponse {
+    let kind = match parse_kind(&kind) {
+        Ok(kind) => kind,
+        Err(response) => return response,
+    };
+    let locale = match req.locale.as_deref().map(parse_locale).transpose() {
+        Ok(locale) => locale,
+        Err(response) => return response,
+    };
+
+    let resolved =
+        match resolve_template(&*state.store, &tenant.tenant_id, kind, locale.as_deref()).await {
+            Ok(resolved) => resolved,
+            Err(e) => return database_error(format!("Failed to load email template: {e}")),
+        };
+    let parts = TemplateParts {
+        subject: req.subject.unwrap_or(resolved.parts.subject),
+        body_text: req.body_text.unwrap_or(resolved.parts.body_text),
+        body_html: req.body_html.or(resolved.parts.body_html),
+    };
+    if let Err(response) = validate_parts(&parts) {
+        return response;
+    }
+
+    let mut data = kind.sample_data();
+    if let (Some(sample), Some(overrides)) = (data.as_object_mut(), req.data) {
+        sample.extend(overrides);
+    }
+
+    match parts.render(&data) {
+        Ok(rendered) => json_ok(PreviewEmailTemplateResponse {
+            rendered,
+            locale: resolved.locale,
+        }),
+        Err(e) => invalid_field("template", e.to_string()),
+    }
+}
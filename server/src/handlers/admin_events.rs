@@ -0,0 +1,195 @@
+//! Event log handlers.
+//!
+//! - `GET /admin/events` — page through the tenant's event log by cursor,
+//!   optionally filtered by event type and time range. Also served read-only
+//!   at `GET {prefix}/events` for API-key consumers
+//! - `POST /admin/events/replay` — re-deliver logged events from a time range
+//!   to a chosen endpoint
+//!
+//! S-01: All handlers enforce tenant isolation via TenantContext extractor.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::config::types::validate_webhook_url;
+use crate::errors::{error_response, ErrorCode};
+use crate::handlers::admin::audit;
+use crate::handlers::response::{json_error, json_ok};
+use crate::middleware::TenantContext;
+use crate::storage::{EventLogEntry, EventLogQuery, Store};
+use crate::webhooks::HttpNotifier;
+
+use super::cap_limit_opt;
+
+const DEFAULT_PAGE_SIZE: i32 = 100;
+
+/// Events re-queued per replay request; callers continue from `nextCursor`
+const MAX_REPLAY_EVENTS: i64 = 1000;
+
+/// Handler state for event log routes.
+pub struct EventsState<S: Store> {
+    pub store: Arc<S>,
+    pub notifier: Arc<HttpNotifier<S>>,
+    /// Accept `http://` replay targets (non-production environments)
+    pub allow_http: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListEventsQuery {
+    /// Cursor from a previous page's `nextCursor`
+    pub after: Option<i64>,
+    #[serde(rename = "type")]
+    pub event_type: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub limit: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListEventsResponse {
+    pub events: Vec<EventLogEntry>,
+    pub count: usize,
+    /// Pass as `after` to continue; unchanged when the page is empty so
+    /// pollers keep their position
+    pub next_cursor: Option<i64>,
+    pub has_more: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayEventsRequest {
+    pub url: String,
+    pub since: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+    pub event_type: Option<String>,
+    /// Resume a replay that returned `hasMore`
+    pub after: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayEventsResponse {
+    pub replayed: usize,
+    pub next_cursor: Option<i64>,
+    pub has_more: bool,
+}
+
+fn invalid_field(message: &str) -> axum::response::Response {
+    let (status, body) = error_response(ErrorCode::InvalidField, Some(message.into()), None);
+    json_error(status, body).into_response()
+}
+
+/// GET /admin/events - List logged events after a cursor
+pub async fn list_events<S: Store + 'static>(
+    State(state): State<Arc<EventsState<S>>>,
+    tenant: TenantContext,
+    Query(query): Query<ListEventsQuery>,
+) -> impl IntoResponse {
+    let limit = cap_limit_opt(query.limit, DEFAULT_PAGE_SIZE);
+    let filter = EventLogQuery {
+        after: query.after,
+        event_type: query.event_type,
+        since: query.since,
+        until: query.until,
+        limit: limit as i64,
+    };
+
+    match state.store.list_events(&tenant.tenant_id, &filter).await {
+        Ok(events) => {
+            let next_cursor = events.last().map(|e| e.sequence).or(query.after);
+            json_ok(ListEventsResponse {
+                count: events.len(),
+                has_more: events.len() == limit as usize,
+                events,
+                next_cursor,
+            })
+            .into_response()
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to list events");
+            let (status, body) = error_response(ErrorCode::DatabaseError, None, None);
+            json_error(status, body).into_response()
+        }
+    }
+}
+
+/// POST /admin/events/replay - Re-deliver logged events to an endpoint
+///
+/// Replayed deliveries are signed with the current callback secret and keep
+/// the original `eventId`, so receivers can discard events they already have.
+pub async fn replay_events<S: Store + 'static>(
+    State(state): State<Arc<EventsState<S>>>,
+    tenant: TenantContext,
+    Json(request): Json<ReplayEventsRequest>,
+) -> impl IntoResponse {
+    if request.until <= request.since {
+        return invalid_field("until must be after since");
+    }
+    // Same SSRF rules as the configured callback URL
+    if let Err(e) = validate_webhook_url(&request.url, state.allow_http) {
+        return invalid_field(&format!("url: {}", e));
+    }
+
+    let filter = EventLogQuery {
+        after: request.after,
+        event_type: request.event_type.clone(),
+        since: Some(request.since),
+        until: Some(request.until),
+        limit: MAX_REPLAY_EVENTS,
+    };
+    let events = match state.store.list_events(&tenant.tenant_id, &filter).await {
+        Ok(events) => events,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to list events for replay");
+            let (status, body) = error_response(ErrorCode::DatabaseError, None, None);
+            return json_error(status, body).into_response();
+        }
+    };
+
+    let mut replayed = 0usize;
+    let mut next_cursor = request.after;
+    for event in &events {
+        if let Err(e) = state.notifier.replay_event(event, &request.url).await {
+            tracing::error!(error = %e, event_id = %event.event_id, "Failed to enqueue event replay");
+            let (status, body) = error_response(
+                ErrorCode::DatabaseError,
+                Some(format!("replay stopped after {} events", replayed)),
+                Some(serde_json::json!({ "replayed": replayed, "nextCursor": next_cursor })),
+            );
+            return json_error(status, body).into_response();
+        }
+        replayed += 1;
+        next_cursor = Some(event.sequence);
+    }
+
+    audit(
+        &*state.store,
+        &tenant,
+        "event_log",
+        &request.url,
+        "replay",
+        Some(serde_json::json!({
+            "since": request.since,
+            "until": request.until,
+            "eventType": request.event_type,
+            "replayed": replayed,
+        })),
+    )
+    .await;
+
+    json_ok(ReplayEventsResponse {
+        replayed,
+        next_cursor,
+        has_more: events.len() as i64 == MAX_REPLAY_EVENTS,
+    })
+    .into_response()
+}
@@ -11,10 +11,11 @@ use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{error_response, ErrorCode};
-use crate::handlers::admin::{audit, AdminState};
+use crate::handlers::admin::{audit, queue_embedding, AdminState};
 use crate::handlers::response::{json_error, json_ok};
 use crate::middleware::TenantContext;
 use crate::models::Faq;
+use crate::storage::EmbeddingEntity;
 
 use super::cap_limit_opt;
 
@@ -187,6 +188,13 @@ pub async fn create_faq(
     match state.store.create_faq(faq.clone()).await {
         Ok(()) => {
             audit(&*state.store, &tenant, "faq", &faq.id, "create", None).await;
+            queue_embedding(
+                &*state.store,
+                &tenant.tenant_id,
+                EmbeddingEntity::Faq,
+                &faq.id,
+            )
+            .await;
             json_ok(faq)
         }
         Err(e) => {
@@ -258,6 +266,13 @@ pub async fn update_faq(
     match state.store.update_faq(updated.clone()).await {
         Ok(()) => {
             audit(&*state.store, &tenant, "faq", &faq_id, "update", None).await;
+            queue_embedding(
+                &*state.store,
+                &tenant.tenant_id,
+                EmbeddingEntity::Faq,
+                &faq_id,
+            )
+            .await;
             json_ok(updated)
         }
         Err(crate::storage::StorageError::NotFound) => {
@@ -287,6 +302,13 @@ pub async fn delete_faq(
     match state.store.delete_faq(&tenant.tenant_id, &faq_id).await {
         Ok(()) => {
             audit(&*state.store, &tenant, "faq", &faq_id, "delete", None).await;
+            queue_embedding(
+                &*state.store,
+                &tenant.tenant_id,
+                EmbeddingEntity::Faq,
+                &faq_id,
+            )
+            .await;
             json_ok(serde_json::json!({ "deleted": true }))
         }
         Err(crate::storage::StorageError::NotFound) => {
@@ -326,6 +348,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
 
         let tenant = TenantContext::default();
@@ -360,6 +383,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
 
         let tenant = TenantContext::default();
@@ -194,6 +194,9 @@ pub async fn create_gift_card(
 
     match state.store.create_gift_card(card.clone()).await {
         Ok(()) => {
+            if let Some(entry) = crate::services::ledger::gift_card_issue_entry(&card) {
+                crate::services::ledger::post_entry(&*state.store, entry).await;
+            }
             audit(
                 &*state.store,
                 &tenant,
@@ -308,6 +311,17 @@ pub async fn adjust_gift_card_balance(
     }
     let code = code.trim().to_uppercase();
     let now = Utc::now();
+    let previous_balance = match state.store.get_gift_card(&tenant.tenant_id, &code).await {
+        Ok(card) => card.map(|c| c.balance),
+        Err(e) => {
+            let (status, body) = error_response(
+                ErrorCode::DatabaseError,
+                Some(format!("Failed to load gift card: {e}")),
+                None,
+            );
+            return json_error(status, body);
+        }
+    };
     match state
         .store
         .adjust_gift_card_balance(&tenant.tenant_id, &code, req.new_balance, now)
@@ -315,6 +329,11 @@ pub async fn adjust_gift_card_balance(
     {
         Ok(()) => match state.store.get_gift_card(&tenant.tenant_id, &code).await {
             Ok(Some(card)) => {
+                if let Some(entry) = previous_balance.and_then(|previous| {
+                    crate::services::ledger::gift_card_adjustment_entry(&card, previous)
+                }) {
+                    crate::services::ledger::post_entry(&*state.store, entry).await;
+                }
                 audit(
                     &*state.store,
                     &tenant,
@@ -380,6 +399,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
 
         let tenant = TenantContext::default();
@@ -415,6 +435,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
 
         let tenant = TenantContext::default();
@@ -0,0 +1,388 @@
+//! Admin accounting ledger handlers
+//!
+//! Journal listing and CSV export, trial balance and manual entries
+//! (opening balances, Token-22 fee harvests, corrections).
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{error_response, ErrorCode};
+use crate::handlers::admin::{audit, AdminState};
+use crate::handlers::admin_reports::ReportFormat;
+use crate::handlers::response::{json_error, json_ok};
+use crate::middleware::TenantContext;
+use crate::models::{JournalEntry, JournalLine, JournalSource, TrialBalanceRow};
+use crate::services::ledger::journal_to_csv;
+
+use super::cap_limit_opt;
+
+/// Default journal window when `from` is omitted.
+const DEFAULT_JOURNAL_DAYS: i64 = 30;
+
+/// Page size used when exporting a whole window as CSV.
+const EXPORT_PAGE_SIZE: i32 = 500;
+
+/// Maximum length of a manual entry reference.
+const MAX_REFERENCE_LEN: usize = 128;
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+    #[serde(default)]
+    pub format: ReportFormat,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalResponse {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub entries: Vec<JournalEntry>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TrialBalanceQuery {
+    pub as_of: Option<DateTime<Utc>>,
+}
+
+/// Debit and credit totals of one currency; `balanced` is false only if the
+/// journal has been corrupted.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrencyTotals {
+    pub currency: String,
+    pub debit: i64,
+    pub credit: i64,
+    pub balanced: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrialBalanceResponse {
+    pub as_of: DateTime<Utc>,
+    pub accounts: Vec<TrialBalanceRow>,
+    pub totals: Vec<CurrencyTotals>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualEntryRequest {
+    /// Caller-chosen reference (e.g. harvest transaction signature); reposting
+    /// the same reference is rejected.
+    pub reference: String,
+    pub currency: String,
+    pub description: Option<String>,
+    pub occurred_at: Option<DateTime<Utc>>,
+    pub lines: Vec<JournalLine>,
+}
+
+/// GET /admin/ledger/entries?from&to&limit&offset&format=json|csv
+///
+/// Journal entries that occurred in `[from, to)`, oldest first. CSV exports
+/// every line in the window (limit/offset are ignored).
+pub async fn list_journal_entries(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Query(query): Query<JournalQuery>,
+) -> impl IntoResponse {
+    let to = query.to.unwrap_or_else(Utc::now);
+    let from = query
+        .from
+        .unwrap_or_else(|| to - Duration::days(DEFAULT_JOURNAL_DAYS));
+    if from >= to {
+        let (status, body) = error_response(
+            ErrorCode::InvalidField,
+            Some("from must be before to".to_string()),
+            None,
+        );
+        return json_error(status, body).into_response();
+    }
+
+    if query.format == ReportFormat::Json {
+        let limit = cap_limit_opt(query.limit, 100);
+        let offset = query.offset.unwrap_or(0).max(0);
+        return match state
+            .store
+            .list_journal_entries(&tenant.tenant_id, from, to, limit, offset)
+            .await
+        {
+            Ok(entries) => json_ok(JournalResponse { from, to, entries }).into_response(),
+            Err(e) => {
+                let (status, body) = error_response(
+                    ErrorCode::DatabaseError,
+                    Some(format!("Failed to list journal entries: {e}")),
+                    None,
+                );
+                json_error(status, body).into_response()
+            }
+        };
+    }
+
+    let mut entries = Vec::new();
+    loop {
+        let page = match state
+            .store
+            .list_journal_entries(
+                &tenant.tenant_id,
+                from,
+                to,
+                EXPORT_PAGE_SIZE,
+                entries.len() as i32,
+            )
+            .await
+        {
+            Ok(page) => page,
+            Err(e) => {
+                let (status, body) = error_response(
+                    ErrorCode::DatabaseError,
+                    Some(format!("Failed to export journal entries: {e}")),
+                    None,
+                );
+                return json_error(status, body).into_response();
+            }
+        };
+        let done = page.len() < EXPORT_PAGE_SIZE as usize;
+        entries.extend(page);
+        if done {
+            break;
+        }
+    }
+
+    let filename = format!(
+        "attachment; filename=\"journal-{}-{}.csv\"",
+        from.format("%Y%m%d"),
+        to.format("%Y%m%d")
+    );
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (header::CONTENT_DISPOSITION, filename),
+        ],
+        journal_to_csv(&entries),
+    )
+        .into_response()
+}
+
+/// GET /admin/ledger/trial-balance?asOf
+///
+/// Account balances from entries that occurred before `asOf` (default: now).
+pub async fn trial_balance(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Query(query): Query<TrialBalanceQuery>,
+) -> impl IntoResponse {
+    let as_of = query.as_of.unwrap_or_else(Utc::now);
+    match state.store.trial_balance(&tenant.tenant_id, as_of).await {
+        Ok(accounts) => {
+            let mut by_currency: BTreeMap<&str, (i64, i64)> = BTreeMap::new();
+            for row in &accounts {
+                let total = by_currency.entry(row.currency.as_str()).or_default();
+                total.0 += row.debit;
+                total.1 += row.credit;
+            }
+            let totals = by_currency
+                .into_iter()
+                .map(|(currency, (debit, credit))| CurrencyTotals {
+                    currency: currency.to_string(),
+                    debit,
+                    credit,
+                    balanced: debit == credit,
+                })
+                .collect();
+            json_ok(TrialBalanceResponse {
+                as_of,
+                accounts,
+                totals,
+            })
+        }
+        Err(e) => {
+            let (status, body) = error_response(
+                ErrorCode::DatabaseError,
+                Some(format!("Failed to compute trial balance: {e}")),
+                None,
+            );
+            json_error(status, body)
+        }
+    }
+}
+
+/// POST /admin/ledger/entries
+///
+/// Post a balanced manual entry. Entries are immutable; correct mistakes
+/// with a reversing entry.
+pub async fn create_manual_entry(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Json(req): Json<ManualEntryRequest>,
+) -> impl IntoResponse {
+    let reference = req.reference.trim();
+    if reference.is_empty() || reference.len() > MAX_REFERENCE_LEN {
+        let (status, body) = error_response(
+            ErrorCode::InvalidField,
+            Some(format!(
+                "reference must be 1-{} characters",
+                MAX_REFERENCE_LEN
+            )),
+            None,
+        );
+        return json_error(status, body);
+    }
+
+    let mut entry = JournalEntry::new(
+        &tenant.tenant_id,
+        JournalSource::Manual,
+        reference,
+        req.currency.trim(),
+        req.lines,
+        req.occurred_at.unwrap_or_else(Utc::now),
+    );
+    entry.description = req.description.filter(|d| !d.trim().is_empty());
+    if let Err(message) = entry.validate() {
+        let (status, body) = error_response(ErrorCode::InvalidField, Some(message), None);
+        return json_error(status, body);
+    }
+
+    match state.store.append_journal_entry(entry.clone()).await {
+        Ok(true) => {
+            audit(
+                &*state.store,
+                &tenant,
+                "ledger_entry",
+                &entry.id,
+                "create",
+                None,
+            )
+            .await;
+            json_ok(entry)
+        }
+        Ok(false) => {
+            let (status, body) = error_response(
+                ErrorCode::InvalidField,
+                Some("an entry with this reference already exists".to_string()),
+                None,
+            );
+            json_error(status, body)
+        }
+        Err(e) => {
+            let (status, body) = error_response(
+                ErrorCode::DatabaseError,
+                Some(format!("Failed to post journal entry: {e}")),
+                None,
+            );
+            json_error(status, body)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::models::ledger::{receivable_account, ACCOUNT_FEE_INCOME};
+    use crate::repositories::{InMemoryCouponRepository, InMemoryProductRepository};
+    use crate::storage::InMemoryStore;
+
+    fn state() -> Arc<AdminState> {
+        Arc::new(AdminState {
+            store: Arc::new(InMemoryStore::new()),
+            product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
+            coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
+            stripe_client: None,
+            messaging: None,
+        })
+    }
+
+    fn harvest(reference: &str, debit: i64, credit: i64) -> ManualEntryRequest {
+        ManualEntryRequest {
+            reference: reference.to_string(),
+            currency: "usdc".to_string(),
+            description: Some("Token-22 fee harvest".to_string()),
+            occurred_at: Some(Utc::now() - Duration::minutes(5)),
+            lines: vec![
+                JournalLine::debit(receivable_account("token22"), debit),
+                JournalLine::credit(ACCOUNT_FEE_INCOME, credit),
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_manual_entry_and_trial_balance() {
+        let state = state();
+        let tenant = TenantContext::default();
+
+        let resp = create_manual_entry(
+            State(state.clone()),
+            tenant.clone(),
+            Json(harvest("sig-harvest", 250, 250)),
+        )
+        .await
+        .into_response();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let dup = create_manual_entry(
+            State(state.clone()),
+            tenant.clone(),
+            Json(harvest("sig-harvest", 250, 250)),
+        )
+        .await
+        .into_response();
+        assert_eq!(dup.status(), StatusCode::BAD_REQUEST);
+
+        let unbalanced = create_manual_entry(
+            State(state.clone()),
+            tenant.clone(),
+            Json(harvest("sig-2", 250, 200)),
+        )
+        .await
+        .into_response();
+        assert_eq!(unbalanced.status(), StatusCode::BAD_REQUEST);
+
+        let resp = trial_balance(
+            State(state.clone()),
+            tenant.clone(),
+            Query(TrialBalanceQuery::default()),
+        )
+        .await
+        .into_response();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["accounts"].as_array().unwrap().len(), 2);
+        assert_eq!(json["totals"][0]["currency"], "USDC");
+        assert_eq!(json["totals"][0]["balanced"], true);
+
+        let resp = list_journal_entries(
+            State(state),
+            tenant,
+            Query(JournalQuery {
+                format: ReportFormat::Csv,
+                ..Default::default()
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let csv = String::from_utf8(body.to_vec()).unwrap();
+        assert_eq!(csv.lines().count(), 3);
+        assert!(csv.contains(",manual:sig-harvest,manual,sig-harvest,fee_income,0,250,USDC,6,"));
+    }
+}
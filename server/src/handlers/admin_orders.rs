@@ -156,6 +156,7 @@ mod tests {
                 product_id: "prod-1".to_string(),
                 variant_id: None,
                 quantity: 1,
+                line_total: None,
             }],
             amount: 1000,
             amount_asset: "USD".to_string(),
@@ -178,6 +179,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
         let order = base_order("paid");
         store.try_store_order(order.clone()).await.unwrap();
@@ -216,6 +218,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
         let order = base_order("processing");
         store.try_store_order(order.clone()).await.unwrap();
@@ -242,6 +245,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
         let order = base_order("fulfilled");
         store.try_store_order(order.clone()).await.unwrap();
@@ -253,6 +257,7 @@ mod tests {
                 product_id: "prod-1".to_string(),
                 variant_id: None,
                 quantity: 1,
+                line_total: None,
             }],
             carrier: Some("ups".to_string()),
             tracking_number: Some("1Z".to_string()),
@@ -295,6 +300,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
         let order = base_order("processing");
         store.try_store_order(order.clone()).await.unwrap();
@@ -306,6 +312,7 @@ mod tests {
                 product_id: "prod-1".to_string(),
                 variant_id: None,
                 quantity: 1,
+                line_total: None,
             }],
             carrier: Some("ups".to_string()),
             tracking_number: Some("1Z".to_string()),
@@ -342,6 +349,7 @@ mod tests {
             product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
             coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
             stripe_client: None,
+            messaging: None,
         });
 
         let order = base_order("processing");
@@ -769,6 +777,11 @@ pub async fn create_fulfillment(
     )
     .await;
 
+    // Best-effort shipment email to the customer
+    if let (Some(messaging), "shipped") = (&state.messaging, status.as_str()) {
+        messaging.notify_order_shipped(&order, &fulfillment).await;
+    }
+
     json_ok(FulfillmentResponse { fulfillment })
 }
 
@@ -986,5 +999,18 @@ pub async fn update_fulfillment_status(
     )
     .await;
 
+    // Best-effort shipment email, once per fulfillment
+    if let Some(ref messaging) = state.messaging {
+        if status == "shipped" && existing_fulfillment.status != "shipped" {
+            if let Ok(Some(order)) = state
+                .store
+                .get_order(&tenant.tenant_id, &fulfillment.order_id)
+                .await
+            {
+                messaging.notify_order_shipped(&order, &fulfillment).await;
+            }
+        }
+    }
+
     json_ok(FulfillmentResponse { fulfillment })
 }
@@ -0,0 +1,474 @@
+//! Admin scheduled price change handlers
+//!
+//! Schedules are applied and reverted by the price schedule worker (see
+//! [`crate::services::price_schedules`]); these endpoints create, list and
+//! cancel them. Cancelling an active schedule restores the replaced prices
+//! immediately.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{error_response, ErrorCode};
+use crate::handlers::admin::{audit, AdminState};
+use crate::handlers::admin_products_types::{resolve_crypto, resolve_fiat};
+use crate::handlers::response::{json_error, json_ok};
+use crate::middleware::TenantContext;
+use crate::models::{PriceSchedule, PriceScheduleStatus, VariantPrice};
+use crate::services::price_schedules::{schedule_detail, PriceScheduler};
+
+/// Longest note kept on a schedule.
+const MAX_NOTE_LEN: usize = 500;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePriceScheduleRequest {
+    /// Change one variant's price instead of the product's prices
+    pub variant_id: Option<String>,
+    pub fiat_amount_cents: Option<i64>,
+    pub fiat_currency: Option<String>,
+    pub crypto_atomic_amount: Option<i64>,
+    pub crypto_token: Option<String>,
+    pub variant_price: Option<VariantPrice>,
+    /// Show the replaced price as the compare-at price while active
+    #[serde(default)]
+    pub show_as_sale: bool,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPriceSchedulesResponse {
+    pub schedules: Vec<PriceSchedule>,
+}
+
+fn invalid(message: &str, field: &str) -> axum::response::Response {
+    let (status, body) = error_response(
+        ErrorCode::InvalidField,
+        Some(message.to_string()),
+        Some(serde_json::json!({ "field": field })),
+    );
+    json_error(status, body).into_response()
+}
+
+fn not_found(message: &str) -> axum::response::Response {
+    let (status, body) =
+        error_response(ErrorCode::ResourceNotFound, Some(message.to_string()), None);
+    json_error(status, body).into_response()
+}
+
+fn internal(message: &str) -> axum::response::Response {
+    let (status, body) = error_response(ErrorCode::InternalError, Some(message.to_string()), None);
+    json_error(status, body).into_response()
+}
+
+/// POST /admin/products/:id/price-schedules - Schedule a price change or sale
+pub async fn create_price_schedule(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Path(id): Path<String>,
+    Json(req): Json<CreatePriceScheduleRequest>,
+) -> impl IntoResponse {
+    let fiat_price = match resolve_fiat(req.fiat_amount_cents, req.fiat_currency.as_deref()) {
+        Ok(price) => price,
+        Err((status, body)) => return json_error(status, body).into_response(),
+    };
+    let crypto_price = match resolve_crypto(req.crypto_atomic_amount, req.crypto_token.as_deref()) {
+        Ok(price) => price,
+        Err((status, body)) => return json_error(status, body).into_response(),
+    };
+
+    if req.variant_id.is_some() {
+        if fiat_price.is_some() || crypto_price.is_some() {
+            return invalid(
+                "A variant schedule only changes variantPrice",
+                "variantPrice",
+            );
+        }
+        match req.variant_price.as_ref().and_then(|p| p.amount) {
+            None => return invalid("variantPrice.amount is required", "variantPrice"),
+            Some(amount) if !amount.is_finite() || amount < 0.0 => {
+                return invalid("variantPrice.amount must be >= 0", "variantPrice");
+            }
+            Some(_) => {}
+        }
+    } else {
+        if req.variant_price.is_some() {
+            return invalid("variantPrice requires variantId", "variantId");
+        }
+        if fiat_price.is_none() && crypto_price.is_none() {
+            return invalid("A fiat or crypto price is required", "fiatAmountCents");
+        }
+        if fiat_price.as_ref().is_some_and(|p| p.atomic < 0) {
+            return invalid("fiatAmountCents must be >= 0", "fiatAmountCents");
+        }
+        if crypto_price.as_ref().is_some_and(|p| p.atomic < 0) {
+            return invalid("cryptoAtomicAmount must be >= 0", "cryptoAtomicAmount");
+        }
+    }
+
+    let now = Utc::now();
+    if let Some(ends_at) = req.ends_at {
+        if ends_at <= req.starts_at {
+            return invalid("endsAt must be after startsAt", "endsAt");
+        }
+        if ends_at <= now {
+            return invalid("endsAt must be in the future", "endsAt");
+        }
+    }
+    let note = req
+        .note
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty());
+    if note.as_ref().is_some_and(|n| n.len() > MAX_NOTE_LEN) {
+        return invalid("note is too long", "note");
+    }
+
+    let product = match state.product_repo.get_product(&tenant.tenant_id, &id).await {
+        Ok(p) => p,
+        Err(_) => return not_found("Product not found"),
+    };
+    if let Some(ref variant_id) = req.variant_id {
+        if product.get_variant(variant_id).is_none() {
+            return invalid("Variant not found", "variantId");
+        }
+    }
+    // The worker adds a Stripe price for the new fiat price; check up front
+    // that it will be able to
+    if fiat_price.is_some() && product.stripe_price_id.is_some() {
+        if product.stripe_product_id.is_none() {
+            let (status, body) = error_response(
+                ErrorCode::InvalidOperation,
+                Some("Product has a Stripe price but no Stripe product".to_string()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+        if state.stripe_client.is_none() {
+            let (status, body) = error_response(
+                ErrorCode::InvalidOperation,
+                Some("Stripe is not configured".to_string()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+    }
+
+    let schedule = PriceSchedule {
+        id: uuid::Uuid::new_v4().to_string(),
+        tenant_id: tenant.tenant_id.clone(),
+        product_id: id.clone(),
+        variant_id: req.variant_id,
+        fiat_price,
+        crypto_price,
+        variant_price: req.variant_price,
+        show_as_sale: req.show_as_sale,
+        starts_at: req.starts_at,
+        ends_at: req.ends_at,
+        status: PriceScheduleStatus::Scheduled,
+        original: None,
+        stripe_price_id: None,
+        note,
+        error: None,
+        created_at: now,
+        updated_at: now,
+        applied_at: None,
+        completed_at: None,
+    };
+
+    let existing = match state
+        .store
+        .list_product_price_schedules(&tenant.tenant_id, &id)
+        .await
+    {
+        Ok(existing) => existing,
+        Err(e) => {
+            tracing::error!(error = %e, product_id = %id, "Failed to list price schedules");
+            return internal("Failed to create price schedule");
+        }
+    };
+    if let Some(other) = existing.iter().find(|other| schedule.overlaps(other)) {
+        let (status, body) = error_response(
+            ErrorCode::InvalidOperation,
+            Some("Schedule overlaps another scheduled or active price change".to_string()),
+            Some(serde_json::json!({ "scheduleId": other.id })),
+        );
+        return json_error(status, body).into_response();
+    }
+
+    if let Err(e) = state.store.save_price_schedule(schedule.clone()).await {
+        tracing::error!(error = %e, product_id = %id, "Failed to save price schedule");
+        return internal("Failed to create price schedule");
+    }
+    let mut detail = schedule_detail(&schedule);
+    detail["startsAt"] = serde_json::json!(schedule.starts_at);
+    detail["endsAt"] = serde_json::json!(schedule.ends_at);
+    detail["showAsSale"] = serde_json::json!(schedule.show_as_sale);
+    audit(
+        &*state.store,
+        &tenant,
+        "product",
+        &id,
+        "schedule_price",
+        Some(detail),
+    )
+    .await;
+    json_ok(schedule).into_response()
+}
+
+/// GET /admin/products/:id/price-schedules - Schedules of a product, latest start first
+pub async fn list_price_schedules(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    match state
+        .store
+        .list_product_price_schedules(&tenant.tenant_id, &id)
+        .await
+    {
+        Ok(schedules) => json_ok(ListPriceSchedulesResponse { schedules }).into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, product_id = %id, "Failed to list price schedules");
+            internal("Failed to list price schedules")
+        }
+    }
+}
+
+/// POST /admin/products/:id/price-schedules/:schedule_id/cancel - Cancel a
+/// schedule; an active one has its replaced prices restored now
+pub async fn cancel_price_schedule(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Path((id, schedule_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let schedule = match state
+        .store
+        .get_price_schedule(&tenant.tenant_id, &schedule_id)
+        .await
+    {
+        Ok(Some(s)) if s.product_id == id => s,
+        Ok(_) => return not_found("Price schedule not found"),
+        Err(e) => {
+            tracing::error!(error = %e, schedule_id = %schedule_id, "Failed to get price schedule");
+            return internal("Failed to cancel price schedule");
+        }
+    };
+
+    let now = Utc::now();
+    match schedule.status {
+        PriceScheduleStatus::Scheduled => {
+            let mut cancelled = schedule;
+            cancelled.status = PriceScheduleStatus::Cancelled;
+            cancelled.completed_at = Some(now);
+            cancelled.updated_at = now;
+            match state
+                .store
+                .update_price_schedule_if_status(cancelled.clone(), PriceScheduleStatus::Scheduled)
+                .await
+            {
+                Ok(true) => {
+                    audit(
+                        &*state.store,
+                        &tenant,
+                        "product",
+                        &id,
+                        "cancel_price_schedule",
+                        Some(schedule_detail(&cancelled)),
+                    )
+                    .await;
+                    json_ok(cancelled).into_response()
+                }
+                // Applied by the worker in the meantime
+                Ok(false) => not_ended("Price schedule was just applied; cancel it again"),
+                Err(e) => {
+                    tracing::error!(error = %e, schedule_id = %schedule_id, "Failed to cancel price schedule");
+                    internal("Failed to cancel price schedule")
+                }
+            }
+        }
+        PriceScheduleStatus::Active => {
+            let scheduler = PriceScheduler::new(
+                state.store.clone(),
+                state.product_repo.clone(),
+                state.stripe_client.clone(),
+            );
+            match scheduler
+                .revert(
+                    schedule,
+                    now,
+                    PriceScheduleStatus::Cancelled,
+                    tenant.admin_actor.clone(),
+                )
+                .await
+            {
+                Some(ended) if ended.status == PriceScheduleStatus::Cancelled => {
+                    json_ok(ended).into_response()
+                }
+                Some(_) => internal("Failed to restore the replaced prices"),
+                None => not_ended("Price schedule has already ended"),
+            }
+        }
+        _ => not_ended("Price schedule has already ended"),
+    }
+}
+
+fn not_ended(message: &str) -> axum::response::Response {
+    let (status, body) =
+        error_response(ErrorCode::InvalidOperation, Some(message.to_string()), None);
+    json_error(status, body).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::http::StatusCode;
+
+    use crate::models::{get_asset, Money, Product};
+    use crate::repositories::{InMemoryCouponRepository, InMemoryProductRepository};
+    use crate::storage::InMemoryStore;
+
+    fn state(product: Product) -> Arc<AdminState> {
+        Arc::new(AdminState {
+            store: Arc::new(InMemoryStore::new()),
+            product_repo: Arc::new(InMemoryProductRepository::new(vec![product])),
+            coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
+            stripe_client: None,
+            messaging: None,
+        })
+    }
+
+    fn product() -> Product {
+        Product {
+            id: "prod-1".to_string(),
+            tenant_id: TenantContext::default().tenant_id,
+            fiat_price: Some(Money::new(get_asset("USD").unwrap(), 1000)),
+            ..Default::default()
+        }
+    }
+
+    fn sale(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> CreatePriceScheduleRequest {
+        CreatePriceScheduleRequest {
+            variant_id: None,
+            fiat_amount_cents: Some(800),
+            fiat_currency: Some("USD".to_string()),
+            crypto_atomic_amount: None,
+            crypto_token: None,
+            variant_price: None,
+            show_as_sale: true,
+            starts_at,
+            ends_at: Some(ends_at),
+            note: None,
+        }
+    }
+
+    async fn create(
+        state: &Arc<AdminState>,
+        req: CreatePriceScheduleRequest,
+    ) -> (StatusCode, serde_json::Value) {
+        let resp = create_price_schedule(
+            State(state.clone()),
+            TenantContext::default(),
+            Path("prod-1".to_string()),
+            Json(req),
+        )
+        .await
+        .into_response();
+        let status = resp.status();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_overlapping_schedule() {
+        let state = state(product());
+        let now = Utc::now();
+        let hour = chrono::Duration::hours(1);
+
+        let (status, first) = create(&state, sale(now + hour, now + hour * 3)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(first["status"], "scheduled");
+
+        let (status, _) = create(&state, sale(now + hour * 2, now + hour * 4)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = create(&state, sale(now + hour * 3, now + hour * 4)).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = create(&state, sale(now + hour * 2, now + hour)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_active_schedule_restores_prices() {
+        let state = state(product());
+        let now = Utc::now();
+        let (status, created) = create(
+            &state,
+            sale(
+                now - chrono::Duration::minutes(1),
+                now + chrono::Duration::hours(1),
+            ),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let scheduler = PriceScheduler::new(
+            state.store.clone(),
+            state.product_repo.clone(),
+            state.stripe_client.clone(),
+        );
+        assert_eq!(scheduler.run_due(now, 10).await.unwrap(), 1);
+        let on_sale = state
+            .product_repo
+            .get_product(&TenantContext::default().tenant_id, "prod-1")
+            .await
+            .unwrap();
+        assert_eq!(on_sale.fiat_price.as_ref().map(|m| m.atomic), Some(800));
+        assert_eq!(
+            on_sale.compare_at_fiat_price.as_ref().map(|m| m.atomic),
+            Some(1000)
+        );
+
+        let resp = cancel_price_schedule(
+            State(state.clone()),
+            TenantContext::default(),
+            Path((
+                "prod-1".to_string(),
+                created["id"].as_str().unwrap().to_string(),
+            )),
+        )
+        .await
+        .into_response();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let restored = state
+            .product_repo
+            .get_product(&TenantContext::default().tenant_id, "prod-1")
+            .await
+            .unwrap();
+        assert_eq!(restored.fiat_price.as_ref().map(|m| m.atomic), Some(1000));
+        assert!(restored.compare_at_fiat_price.is_none());
+
+        let audit = state
+            .store
+            .list_admin_audit(&TenantContext::default().tenant_id, None, None, None, 10, 0)
+            .await
+            .unwrap();
+        let actions: Vec<&str> = audit.iter().map(|e| e.action.as_str()).collect();
+        assert!(actions.contains(&"schedule_price"));
+        assert!(actions.contains(&"apply_price_schedule"));
+        assert!(actions.contains(&"cancel_price_schedule"));
+    }
+}
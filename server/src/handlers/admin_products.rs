@@ -4,13 +4,16 @@ use std::sync::Arc;
 
 use axum::{
     extract::{Path, Query, State},
+    http::StatusCode,
     response::IntoResponse,
     Json,
 };
 use chrono::Utc;
 
-use crate::errors::{error_response, ErrorCode};
-use crate::handlers::admin::{audit, AdminProductInfo, AdminState, ListProductsResponse};
+use crate::errors::{error_response, ErrorCode, ErrorResponse};
+use crate::handlers::admin::{
+    audit, queue_embedding, AdminProductInfo, AdminState, ListProductsResponse,
+};
 use crate::handlers::admin_products_stripe::{stripe_ids_for_create, stripe_ids_for_update};
 use crate::handlers::admin_products_types::{
     resolve_crypto, resolve_fiat, validate_product_checkout_fields, AdjustInventoryRequest,
@@ -20,6 +23,8 @@ use crate::handlers::cap_limit;
 use crate::handlers::response::{json_error, json_ok};
 use crate::middleware::TenantContext;
 use crate::models::Product;
+use crate::services::StripeClient;
+use crate::storage::{EmbeddingEntity, Store};
 
 /// GET /api/admin/products - List all products
 pub async fn list_products(
@@ -90,67 +95,86 @@ pub async fn get_product(
     }
 }
 
-/// POST /api/admin/products - Create a new product
-pub async fn create_product(
-    State(state): State<Arc<AdminState>>,
-    tenant: TenantContext,
-    Json(req): Json<CreateProductRequest>,
-) -> impl IntoResponse {
-    if let Err((status, body)) = validate_product_checkout_fields(&req) {
-        return json_error(status, body).into_response();
-    }
-
-    let fiat_price = match resolve_fiat(req.fiat_amount_cents, req.fiat_currency.as_deref()) {
-        Ok(v) => v,
-        Err((status, body)) => return json_error(status, body).into_response(),
-    };
-    let compare_at_fiat_price = match resolve_fiat(
+/// Builds the product to save from a create/update request.
+///
+/// Validates the request, resolves prices, syncs Stripe when a client is given
+/// and keeps the fields the request does not carry (variation config, crypto
+/// account, subscription, created_at, ...) from `existing`. Shared with bulk
+/// import so both paths save identical products.
+pub(crate) async fn product_from_request(
+    store: &dyn Store,
+    stripe_client: Option<&StripeClient>,
+    tenant_id: &str,
+    id: &str,
+    req: CreateProductRequest,
+    existing: Option<Product>,
+) -> Result<Product, (StatusCode, ErrorResponse)> {
+    validate_product_checkout_fields(&req)?;
+
+    let fiat_price = resolve_fiat(req.fiat_amount_cents, req.fiat_currency.as_deref())?;
+    let compare_at_fiat_price = resolve_fiat(
         req.compare_at_fiat_amount_cents,
         req.compare_at_fiat_currency.as_deref(),
-    ) {
-        Ok(v) => v,
-        Err((status, body)) => return json_error(status, body).into_response(),
-    };
-    let crypto_price = match resolve_crypto(req.crypto_atomic_amount, req.crypto_token.as_deref()) {
-        Ok(v) => v,
-        Err((status, body)) => return json_error(status, body).into_response(),
-    };
-
-    // Auto-create Stripe product/price if fiat price exists but no stripe_price_id provided
-    let stripe_name = req.title.as_deref().unwrap_or(&req.id);
-
-    let (stripe_product_id, stripe_price_id) =
-        if let (Some(amount_cents), None, Some(stripe_client)) = (
-            req.fiat_amount_cents,
-            req.stripe_price_id.as_ref(),
-            state.stripe_client.as_ref(),
-        ) {
-            let currency = req.fiat_currency.as_deref().unwrap_or("usd");
-            match stripe_ids_for_create(
+    )?;
+    let crypto_price = resolve_crypto(req.crypto_atomic_amount, req.crypto_token.as_deref())?;
+
+    // Determine Stripe product/price IDs: auto-create on create when a fiat
+    // price exists but no stripe_price_id was provided, sync on update.
+    let stripe_name = req.title.as_deref().unwrap_or(id);
+    let (stripe_product_id, stripe_price_id) = match (stripe_client, &existing) {
+        (Some(stripe_client), Some(existing)) => {
+            stripe_ids_for_update(
                 stripe_client,
-                &req.id,
-                &tenant.tenant_id,
+                id,
+                tenant_id,
                 stripe_name,
                 &req.description,
-                amount_cents,
-                currency,
+                req.fiat_amount_cents,
+                req.fiat_currency.as_deref(),
+                req.stripe_price_id.clone(),
+                existing.stripe_product_id.as_deref(),
+                existing.stripe_price_id.clone(),
                 req.metadata.clone(),
             )
-            .await
-            {
-                Ok(ids) => ids,
-                Err(resp) => return resp,
+            .await?
+        }
+        (Some(stripe_client), None) => match (req.fiat_amount_cents, &req.stripe_price_id) {
+            (Some(amount_cents), None) => {
+                let currency = req.fiat_currency.as_deref().unwrap_or("usd");
+                stripe_ids_for_create(
+                    stripe_client,
+                    id,
+                    tenant_id,
+                    stripe_name,
+                    &req.description,
+                    amount_cents,
+                    currency,
+                    req.metadata.clone(),
+                )
+                .await?
             }
-        } else {
-            (None, req.stripe_price_id)
-        };
+            _ => (None, req.stripe_price_id.clone()),
+        },
+        (None, Some(existing)) => (
+            existing.stripe_product_id.clone(),
+            req.stripe_price_id
+                .clone()
+                .or(existing.stripe_price_id.clone()),
+        ),
+        (None, None) => (None, req.stripe_price_id.clone()),
+    };
+
+    let is_update = existing.is_some();
+    let existing = existing.unwrap_or_default();
 
     // Denormalize regulatory notice from collection onto product config
-    let tokenized_asset_config = if let Some(mut tac) = req.tokenized_asset_config {
+    let tokenized_asset_config = if let Some(mut tac) = req
+        .tokenized_asset_config
+        .or(existing.tokenized_asset_config)
+    {
         if tac.regulatory_notice.is_none() {
-            if let Ok(Some(coll)) = state
-                .store
-                .get_collection(&tenant.tenant_id, &tac.asset_class_collection_id)
+            if let Ok(Some(coll)) = store
+                .get_collection(tenant_id, &tac.asset_class_collection_id)
                 .await
             {
                 if let Some(tc) = &coll.tokenization_config {
@@ -163,9 +187,10 @@ pub async fn create_product(
         None
     };
 
-    let product = Product {
-        id: req.id.clone(),
-        tenant_id: tenant.tenant_id.clone(),
+    let now = Utc::now();
+    Ok(Product {
+        id: id.to_string(),
+        tenant_id: tenant_id.to_string(),
         title: req.title,
         short_description: req.short_description,
         slug: req.slug,
@@ -189,17 +214,44 @@ pub async fn create_product(
         inventory_quantity: req.inventory_quantity,
         inventory_policy: req.inventory_policy,
         variants: req.variants,
-        variation_config: None,
-        crypto_account: None,
-        memo_template: None,
+        variation_config: existing.variation_config,
+        crypto_account: existing.crypto_account,
+        memo_template: existing.memo_template,
         metadata: req.metadata,
         active: req.active,
-        subscription: None,
-        gift_card_config: req.gift_card_config,
+        subscription: existing.subscription,
+        gift_card_config: req.gift_card_config.or(existing.gift_card_config),
         tokenized_asset_config,
-        compliance_requirements: req.compliance_requirements,
-        created_at: Some(Utc::now()),
-        updated_at: Some(Utc::now()),
+        compliance_requirements: req.compliance_requirements.or(existing.compliance_requirements),
+        payment_split: req.payment_split.or(existing.payment_split),
+        created_at: if is_update {
+            existing.created_at
+        } else {
+            Some(now)
+        },
+        updated_at: Some(now),
+    })
+}
+
+/// POST /api/admin/products - Create a new product
+pub async fn create_product(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Json(req): Json<CreateProductRequest>,
+) -> impl IntoResponse {
+    let id = req.id.clone();
+    let product = match product_from_request(
+        &*state.store,
+        state.stripe_client.as_deref(),
+        &tenant.tenant_id,
+        &id,
+        req,
+        None,
+    )
+    .await
+    {
+        Ok(p) => p,
+        Err((status, body)) => return json_error(status, body).into_response(),
     };
 
     match state.product_repo.create_product(product.clone()).await {
@@ -213,6 +265,13 @@ pub async fn create_product(
                 Some(serde_json::json!({"title": &product.title})),
             )
             .await;
+            queue_embedding(
+                &*state.store,
+                &tenant.tenant_id,
+                EmbeddingEntity::Product,
+                &product.id,
+            )
+            .await;
             json_ok(AdminProductInfo::from(&product)).into_response()
         }
         Err(e) => {
@@ -250,115 +309,30 @@ pub async fn update_product(
         }
     };
 
-    let fiat_price = match resolve_fiat(req.fiat_amount_cents, req.fiat_currency.as_deref()) {
-        Ok(v) => v,
-        Err((status, body)) => return json_error(status, body).into_response(),
-    };
-    let compare_at_fiat_price = match resolve_fiat(
-        req.compare_at_fiat_amount_cents,
-        req.compare_at_fiat_currency.as_deref(),
-    ) {
-        Ok(v) => v,
-        Err((status, body)) => return json_error(status, body).into_response(),
-    };
-    let crypto_price = match resolve_crypto(req.crypto_atomic_amount, req.crypto_token.as_deref()) {
-        Ok(v) => v,
-        Err((status, body)) => return json_error(status, body).into_response(),
-    };
-
-    // Determine Stripe product/price IDs
-    let stripe_name = req.title.as_deref().unwrap_or(&id);
-
-    let (stripe_product_id, stripe_price_id) = if let Some(ref stripe_client) = state.stripe_client
-    {
-        match stripe_ids_for_update(
-            stripe_client,
-            &id,
-            &tenant.tenant_id,
-            stripe_name,
-            &req.description,
-            req.fiat_amount_cents,
-            req.fiat_currency.as_deref(),
-            req.stripe_price_id.clone(),
-            existing.stripe_product_id.as_deref(),
-            existing.stripe_price_id.clone(),
-            req.metadata.clone(),
-        )
-        .await
-        {
-            Ok(ids) => ids,
-            Err(resp) => return resp,
-        }
-    } else {
-        (
-            existing.stripe_product_id.clone(),
-            req.stripe_price_id.or(existing.stripe_price_id.clone()),
-        )
-    };
-
-    // Denormalize regulatory notice from collection onto product config
-    let tokenized_asset_config = if let Some(mut tac) = req
-        .tokenized_asset_config
-        .or(existing.tokenized_asset_config)
+    let product = match product_from_request(
+        &*state.store,
+        state.stripe_client.as_deref(),
+        &tenant.tenant_id,
+        &id,
+        req,
+        Some(existing),
+    )
+    .await
     {
-        if tac.regulatory_notice.is_none() {
-            if let Ok(Some(coll)) = state
-                .store
-                .get_collection(&tenant.tenant_id, &tac.asset_class_collection_id)
-                .await
-            {
-                if let Some(tc) = &coll.tokenization_config {
-                    tac.regulatory_notice = tc.regulatory_notice.clone();
-                }
-            }
-        }
-        Some(tac)
-    } else {
-        None
-    };
-
-    let product = Product {
-        id: id.clone(),
-        tenant_id: tenant.tenant_id.clone(),
-        title: req.title,
-        short_description: req.short_description,
-        slug: req.slug,
-        seo_title: req.seo_title,
-        seo_description: req.seo_description,
-        description: req.description,
-        tags: req.tags,
-        category_ids: req.category_ids,
-        images: req.images,
-        featured: req.featured,
-        sort_order: req.sort_order,
-        shipping_profile: req.shipping_profile,
-        checkout_requirements: req.checkout_requirements,
-        fulfillment: req.fulfillment,
-        fiat_price,
-        compare_at_fiat_price,
-        stripe_product_id,
-        stripe_price_id,
-        crypto_price,
-        inventory_status: req.inventory_status,
-        inventory_quantity: req.inventory_quantity,
-        inventory_policy: req.inventory_policy,
-        variants: req.variants,
-        variation_config: existing.variation_config,
-        crypto_account: existing.crypto_account,
-        memo_template: existing.memo_template,
-        metadata: req.metadata,
-        active: req.active,
-        subscription: existing.subscription,
-        gift_card_config: req.gift_card_config.or(existing.gift_card_config),
-        tokenized_asset_config,
-        compliance_requirements: req.compliance_requirements.or(existing.compliance_requirements),
-        created_at: existing.created_at,
-        updated_at: Some(Utc::now()),
+        Ok(p) => p,
+        Err((status, body)) => return json_error(status, body).into_response(),
     };
 
     match state.product_repo.update_product(product.clone()).await {
         Ok(()) => {
             audit(&*state.store, &tenant, "product", &id, "update", None).await;
+            queue_embedding(
+                &*state.store,
+                &tenant.tenant_id,
+                EmbeddingEntity::Product,
+                &id,
+            )
+            .await;
             json_ok(AdminProductInfo::from(&product)).into_response()
         }
         Err(e) => {
@@ -418,6 +392,13 @@ pub async fn delete_product(
     {
         Ok(()) => {
             audit(&*state.store, &tenant, "product", &id, "delete", None).await;
+            queue_embedding(
+                &*state.store,
+                &tenant.tenant_id,
+                EmbeddingEntity::Product,
+                &id,
+            )
+            .await;
             json_ok(serde_json::json!({"deleted": true})).into_response()
         }
         Err(e) => {
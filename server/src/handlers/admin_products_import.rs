@@ -0,0 +1,561 @@
+//! Admin bulk product import / export handlers
+//!
+//! An import is parsed up front (a malformed file is rejected with 400) and
+//! then runs as a background job whose progress and per-product error report
+//! are polled via `GET /admin/products/import/{jobId}`. Each product goes
+//! through the same validation and build as the single-product create/update
+//! handlers ([`product_from_request`]); a dry run stops before saving and
+//! makes no Stripe calls. See [`crate::services::product_import`] for the
+//! file formats.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+};
+use chrono::Utc;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::errors::{error_response, ErrorCode};
+use crate::handlers::admin::{audit, queue_embedding, AdminState};
+use crate::handlers::admin_products::product_from_request;
+use crate::handlers::admin_products_types::CreateProductRequest;
+use crate::handlers::admin_variations::{validate_variation_config, MAX_VARIANTS};
+use crate::handlers::response::{json_error, json_ok};
+use crate::middleware::TenantContext;
+use crate::models::{
+    Product, ProductImportFormat, ProductImportJob, ProductImportRowError, ProductImportStatus,
+    ProductVariationConfig, PRODUCT_IMPORT_HEARTBEAT_SECS,
+};
+use crate::services::product_import::{
+    fill_variation_ids, merge_record, parse_import, product_record, products_to_csv, ImportRecord,
+};
+use crate::storage::EmbeddingEntity;
+
+/// Progress is saved after this many products, or after
+/// [`PRODUCT_IMPORT_HEARTBEAT_SECS`] so a live job is never taken for an
+/// interrupted one.
+const PROGRESS_SAVE_INTERVAL: i64 = 25;
+
+/// Per-product errors kept on the job (the `failed` count is not capped).
+const MAX_REPORTED_ERRORS: usize = 1_000;
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportProductsQuery {
+    #[serde(default)]
+    pub format: ProductImportFormat,
+    /// Validate every product without saving
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportProductsQuery {
+    #[serde(default)]
+    pub format: ProductImportFormat,
+}
+
+/// POST /admin/products/import?format=json|csv&dryRun=true - Start a bulk import
+///
+/// Products are upserted by `id`, or by `slug` when the record has no id.
+/// Returns the queued job.
+pub async fn import_products(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Query(query): Query<ImportProductsQuery>,
+    body: String,
+) -> impl IntoResponse {
+    let records = match parse_import(query.format, &body) {
+        Ok(records) => records,
+        Err(message) => {
+            let (status, body) = error_response(ErrorCode::InvalidField, Some(message), None);
+            return json_error(status, body).into_response();
+        }
+    };
+
+    let now = Utc::now();
+    let job = ProductImportJob {
+        id: uuid::Uuid::new_v4().to_string(),
+        tenant_id: tenant.tenant_id.clone(),
+        format: query.format,
+        dry_run: query.dry_run,
+        status: ProductImportStatus::Queued,
+        total: records.len() as i64,
+        processed: 0,
+        created: 0,
+        updated: 0,
+        failed: 0,
+        errors: Vec::new(),
+        error: None,
+        created_at: now,
+        updated_at: now,
+        completed_at: None,
+    };
+    if let Err(e) = state.store.save_product_import_job(job.clone()).await {
+        tracing::error!(error = %e, "Failed to save product import job");
+        let (status, body) = error_response(
+            ErrorCode::InternalError,
+            Some("Failed to start import".to_string()),
+            None,
+        );
+        return json_error(status, body).into_response();
+    }
+
+    tokio::spawn(run_import(state, tenant, job.clone(), records));
+    json_ok(job).into_response()
+}
+
+/// GET /admin/products/import/:job_id - Import job progress and error report
+pub async fn get_import_job(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Path(job_id): Path<String>,
+) -> impl IntoResponse {
+    match state
+        .store
+        .get_product_import_job(&tenant.tenant_id, &job_id)
+        .await
+    {
+        Ok(Some(job)) => json_ok(job).into_response(),
+        Ok(None) => {
+            let (status, body) = error_response(
+                ErrorCode::ResourceNotFound,
+                Some("Import job not found".to_string()),
+                None,
+            );
+            json_error(status, body).into_response()
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to get product import job");
+            let (status, body) = error_response(
+                ErrorCode::InternalError,
+                Some("Failed to get import job".to_string()),
+                None,
+            );
+            json_error(status, body).into_response()
+        }
+    }
+}
+
+/// GET /admin/products/export?format=json|csv - Export the full catalog
+///
+/// The output is accepted as-is by the import endpoint.
+pub async fn export_products(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Query(query): Query<ExportProductsQuery>,
+) -> impl IntoResponse {
+    let products = match state
+        .product_repo
+        .list_all_products(&tenant.tenant_id)
+        .await
+    {
+        Ok(products) => products,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to list products for export");
+            let (status, body) = error_response(
+                ErrorCode::InternalError,
+                Some("Failed to export products".to_string()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+    };
+
+    match query.format {
+        ProductImportFormat::Json => {
+            let products: Vec<Value> = products
+                .iter()
+                .map(|p| Value::Object(product_record(p)))
+                .collect();
+            json_ok(serde_json::json!({ "products": products })).into_response()
+        }
+        ProductImportFormat::Csv => {
+            let filename = format!(
+                "attachment; filename=\"products-{}.csv\"",
+                Utc::now().format("%Y%m%d")
+            );
+            (
+                StatusCode::OK,
+                [
+                    (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                    (header::CONTENT_DISPOSITION, filename),
+                ],
+                products_to_csv(&products),
+            )
+                .into_response()
+        }
+    }
+}
+
+// ============================================================================
+// Import job
+// ============================================================================
+
+/// Catalog state as the import progresses, so later records see earlier ones
+/// (including in a dry run).
+struct Catalog {
+    products: HashMap<String, Product>,
+    slugs: HashMap<String, String>,
+    seen: HashSet<String>,
+}
+
+async fn run_import(
+    state: Arc<AdminState>,
+    tenant: TenantContext,
+    mut job: ProductImportJob,
+    records: Vec<ImportRecord>,
+) {
+    job.status = ProductImportStatus::Running;
+    save_progress(&state, &mut job).await;
+
+    let products = match state
+        .product_repo
+        .list_all_products(&tenant.tenant_id)
+        .await
+    {
+        Ok(products) => products,
+        Err(e) => {
+            tracing::error!(error = %e, job_id = %job.id, "Product import failed to load catalog");
+            job.status = ProductImportStatus::Failed;
+            job.error = Some("Failed to load existing products".to_string());
+            job.completed_at = Some(Utc::now());
+            save_progress(&state, &mut job).await;
+            return;
+        }
+    };
+    let mut catalog = Catalog {
+        slugs: products
+            .iter()
+            .filter_map(|p| p.slug.clone().map(|s| (s, p.id.clone())))
+            .collect(),
+        products: products.into_iter().map(|p| (p.id.clone(), p)).collect(),
+        seen: HashSet::new(),
+    };
+
+    for ImportRecord { row, record } in records {
+        let result = match record {
+            Ok(record) => import_record(&state, &tenant, job.dry_run, &mut catalog, record).await,
+            Err(message) => Err((None, message)),
+        };
+        match result {
+            Ok(true) => job.created += 1,
+            Ok(false) => job.updated += 1,
+            Err((product_id, message)) => {
+                job.failed += 1;
+                if job.errors.len() < MAX_REPORTED_ERRORS {
+                    job.errors.push(ProductImportRowError {
+                        row,
+                        product_id,
+                        message,
+                    });
+                }
+            }
+        }
+        job.processed += 1;
+        let heartbeat_due =
+            Utc::now() - job.updated_at >= chrono::Duration::seconds(PRODUCT_IMPORT_HEARTBEAT_SECS);
+        if job.processed % PROGRESS_SAVE_INTERVAL == 0 || heartbeat_due {
+            save_progress(&state, &mut job).await;
+        }
+    }
+
+    if !job.dry_run {
+        audit(
+            &*state.store,
+            &tenant,
+            "product_import",
+            &job.id,
+            "import",
+            Some(serde_json::json!({
+                "format": job.format,
+                "created": job.created,
+                "updated": job.updated,
+                "failed": job.failed,
+            })),
+        )
+        .await;
+    }
+    job.status = ProductImportStatus::Completed;
+    job.completed_at = Some(Utc::now());
+    save_progress(&state, &mut job).await;
+}
+
+async fn save_progress(state: &AdminState, job: &mut ProductImportJob) {
+    job.updated_at = Utc::now();
+    if let Err(e) = state.store.save_product_import_job(job.clone()).await {
+        tracing::warn!(error = %e, job_id = %job.id, "Failed to save product import progress");
+    }
+}
+
+/// Upserts one product. Returns whether it was created, or the product id
+/// (when known) and the error.
+async fn import_record(
+    state: &AdminState,
+    tenant: &TenantContext,
+    dry_run: bool,
+    catalog: &mut Catalog,
+    mut record: Map<String, Value>,
+) -> Result<bool, (Option<String>, String)> {
+    let field = |record: &Map<String, Value>, name: &str| {
+        record
+            .get(name)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+    let id = match (field(&record, "id"), field(&record, "slug")) {
+        (Some(id), _) => id,
+        (None, Some(slug)) => catalog.slugs.get(&slug).cloned().unwrap_or(slug),
+        (None, None) => return Err((None, "id or slug is required".to_string())),
+    };
+    let fail = |message: String| (Some(id.clone()), message);
+    if !catalog.seen.insert(id.clone()) {
+        return Err(fail(
+            "product appears more than once in the import".to_string(),
+        ));
+    }
+    record.insert("id".to_string(), Value::String(id.clone()));
+
+    let existing = catalog.products.get(&id).cloned();
+    let mut merged = merge_record(existing.as_ref(), record);
+    let mut variation_config: Option<ProductVariationConfig> =
+        match merged.remove("variationConfig") {
+            None | Some(Value::Null) => None,
+            Some(value) => Some(
+                serde_json::from_value(value).map_err(|e| fail(format!("variationConfig: {e}")))?,
+            ),
+        };
+    let mut req: CreateProductRequest =
+        serde_json::from_value(Value::Object(merged)).map_err(|e| fail(e.to_string()))?;
+
+    fill_variation_ids(
+        variation_config.as_mut(),
+        existing.as_ref().and_then(|p| p.variation_config.as_ref()),
+        &mut req.variants,
+    )
+    .map_err(fail)?;
+    if let Some(config) = &variation_config {
+        validate_variation_config(config).map_err(fail)?;
+    }
+    if req.variants.len() > MAX_VARIANTS {
+        return Err(fail(format!(
+            "Too many variants: {} exceeds limit of {}",
+            req.variants.len(),
+            MAX_VARIANTS
+        )));
+    }
+    if let Some(slug) = req.slug.as_deref() {
+        if let Some(other) = catalog.slugs.get(slug).filter(|other| **other != id) {
+            return Err(fail(format!(
+                "slug '{slug}' is already used by product '{other}'"
+            )));
+        }
+    }
+
+    let stripe_client = if dry_run {
+        None
+    } else {
+        state.stripe_client.as_deref()
+    };
+    let mut product = product_from_request(
+        &*state.store,
+        stripe_client,
+        &tenant.tenant_id,
+        &id,
+        req,
+        existing.clone(),
+    )
+    .await
+    .map_err(|(_, body)| fail(body.error.message))?;
+    product.variation_config = variation_config;
+
+    if !dry_run {
+        let saved = match existing {
+            Some(_) => state.product_repo.update_product(product.clone()).await,
+            None => state.product_repo.create_product(product.clone()).await,
+        };
+        if let Err(e) = saved {
+            tracing::error!(error = %e, product_id = %id, "Product import failed to save product");
+            return Err(fail("Failed to save product".to_string()));
+        }
+        queue_embedding(
+            &*state.store,
+            &tenant.tenant_id,
+            EmbeddingEntity::Product,
+            &id,
+        )
+        .await;
+    }
+
+    if let Some(old_slug) = existing.as_ref().and_then(|p| p.slug.as_ref()) {
+        catalog.slugs.remove(old_slug);
+    }
+    if let Some(slug) = &product.slug {
+        catalog.slugs.insert(slug.clone(), id.clone());
+    }
+    catalog.products.insert(id, product);
+    Ok(existing.is_none())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::repositories::{InMemoryCouponRepository, InMemoryProductRepository};
+    use crate::storage::InMemoryStore;
+
+    fn state(products: Vec<Product>) -> Arc<AdminState> {
+        Arc::new(AdminState {
+            store: Arc::new(InMemoryStore::new()),
+            product_repo: Arc::new(InMemoryProductRepository::new(products)),
+            coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
+            stripe_client: None,
+            messaging: None,
+        })
+    }
+
+    async fn start_import(
+        state: &Arc<AdminState>,
+        format: ProductImportFormat,
+        dry_run: bool,
+        body: &str,
+    ) -> ProductImportJob {
+        let resp = import_products(
+            State(state.clone()),
+            TenantContext::default(),
+            Query(ImportProductsQuery { format, dry_run }),
+            body.to_string(),
+        )
+        .await
+        .into_response();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let job: ProductImportJob = serde_json::from_slice(&body).unwrap();
+
+        for _ in 0..200 {
+            let current = state
+                .store
+                .get_product_import_job(&job.tenant_id, &job.id)
+                .await
+                .unwrap()
+                .unwrap();
+            if current.status == ProductImportStatus::Completed {
+                return current;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+        panic!("import job did not complete");
+    }
+
+    const CSV: &str = "id,slug,title,description,fiatAmountCents,fiatCurrency,inventoryQuantity,variationTypes,variantSku,variantOptions\n\
+        tee,tee,Tee,Cotton tee,2500,usd,,Size=S|M,TEE-S,Size=S\n\
+        tee,,,,,,,,TEE-M,Size=M\n\
+        ,mug,Mug,Stoneware,1200,usd,5,,,\n\
+        ,bad,Bad,Broken,100,zzz,,,,\n";
+
+    #[tokio::test]
+    async fn test_import_dry_run_reports_errors_without_saving() {
+        let state = state(Vec::new());
+        let job = start_import(&state, ProductImportFormat::Csv, true, CSV).await;
+
+        assert_eq!((job.total, job.processed), (3, 3));
+        assert_eq!((job.created, job.updated, job.failed), (2, 0, 1));
+        assert_eq!(job.errors.len(), 1);
+        assert_eq!(job.errors[0].row, 4);
+        assert_eq!(job.errors[0].product_id.as_deref(), Some("bad"));
+        assert!(job.errors[0].message.contains("zzz"));
+        assert!(state
+            .product_repo
+            .list_all_products(&job.tenant_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_import_upserts_by_id_and_slug() {
+        let existing = Product {
+            id: "mug-1".to_string(),
+            tenant_id: TenantContext::default().tenant_id,
+            slug: Some("mug".to_string()),
+            title: Some("Old mug".to_string()),
+            description: "Old".to_string(),
+            tags: vec!["kitchen".to_string()],
+            active: true,
+            ..Default::default()
+        };
+        let state = state(vec![existing]);
+        let job = start_import(&state, ProductImportFormat::Csv, false, CSV).await;
+        assert_eq!((job.created, job.updated, job.failed), (1, 1, 1));
+
+        let tee = state
+            .product_repo
+            .get_product(&job.tenant_id, "tee")
+            .await
+            .unwrap();
+        let config = tee.variation_config.as_ref().unwrap();
+        assert_eq!(config.variation_types[0].id, "size");
+        assert_eq!(tee.variants.len(), 2);
+        assert_eq!(tee.variants[1].option_value_ids, ["m"]);
+        assert_eq!(tee.variants[1].title, "M");
+        assert_eq!(tee.fiat_price.as_ref().unwrap().atomic, 2500);
+
+        // Matched by slug: updated in place, omitted fields kept.
+        let mug = state
+            .product_repo
+            .get_product(&job.tenant_id, "mug-1")
+            .await
+            .unwrap();
+        assert_eq!(mug.title.as_deref(), Some("Mug"));
+        assert_eq!(mug.inventory_quantity, Some(5));
+        assert_eq!(mug.tags, ["kitchen"]);
+
+        // The JSON export imports back as pure updates.
+        let resp = export_products(
+            State(state.clone()),
+            TenantContext::default(),
+            Query(ExportProductsQuery::default()),
+        )
+        .await
+        .into_response();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let export = String::from_utf8(body.to_vec()).unwrap();
+        let job = start_import(&state, ProductImportFormat::Json, false, &export).await;
+        assert_eq!((job.created, job.updated, job.failed), (0, 2, 0));
+        let tee_again = state
+            .product_repo
+            .get_product(&job.tenant_id, "tee")
+            .await
+            .unwrap();
+        assert_eq!(tee_again.variants[1].id, tee.variants[1].id);
+    }
+
+    #[tokio::test]
+    async fn test_import_rejects_malformed_file() {
+        let state = state(Vec::new());
+        let resp = import_products(
+            State(state),
+            TenantContext::default(),
+            Query(ImportProductsQuery {
+                format: ProductImportFormat::Csv,
+                dry_run: false,
+            }),
+            "name,price\nx,1\n".to_string(),
+        )
+        .await
+        .into_response();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
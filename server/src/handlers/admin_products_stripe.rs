@@ -4,17 +4,16 @@
 
 use std::collections::HashMap;
 
-use axum::response::{IntoResponse, Response};
+use axum::http::StatusCode;
 
-use crate::errors::{error_response, ErrorCode};
-use crate::handlers::response::json_error;
+use crate::errors::{error_response, ErrorCode, ErrorResponse};
 use crate::services::StripeClient;
 
 /// Creates Stripe product + price when a new product with a fiat price is saved
 /// and no `stripe_price_id` was supplied by the caller.
 ///
-/// Returns `(stripe_product_id, stripe_price_id)`. On Stripe error, returns
-/// `Err(response)` so the caller can propagate it directly.
+/// Returns `(stripe_product_id, stripe_price_id)`, or the error to send back
+/// when Stripe fails.
 pub(crate) async fn stripe_ids_for_create(
     stripe_client: &StripeClient,
     product_id: &str,
@@ -24,7 +23,7 @@ pub(crate) async fn stripe_ids_for_create(
     amount_cents: i64,
     currency: &str,
     metadata: HashMap<String, String>,
-) -> Result<(Option<String>, Option<String>), Response> {
+) -> Result<(Option<String>, Option<String>), (StatusCode, ErrorResponse)> {
     let mut meta = metadata;
     meta.insert("product_id".to_string(), product_id.to_string());
     meta.insert("tenant_id".to_string(), tenant_id.to_string());
@@ -34,12 +33,11 @@ pub(crate) async fn stripe_ids_for_create(
         .await
         .map_err(|e| {
             tracing::error!(error = %e, "Failed to auto-create Stripe product");
-            let (status, body) = error_response(
+            error_response(
                 ErrorCode::StripeError,
                 Some("Failed to create Stripe product".to_string()),
                 None,
-            );
-            json_error(status, body).into_response()
+            )
         })?;
 
     let price_id = stripe_client
@@ -47,12 +45,11 @@ pub(crate) async fn stripe_ids_for_create(
         .await
         .map_err(|e| {
             tracing::error!(error = %e, "Failed to auto-create Stripe price");
-            let (status, body) = error_response(
+            error_response(
                 ErrorCode::StripeError,
                 Some("Failed to create Stripe price".to_string()),
                 None,
-            );
-            json_error(status, body).into_response()
+            )
         })?;
 
     tracing::info!(
@@ -83,7 +80,7 @@ pub(crate) async fn stripe_ids_for_update(
     existing_stripe_product_id: Option<&str>,
     existing_stripe_price_id: Option<String>,
     metadata: HashMap<String, String>,
-) -> Result<(Option<String>, Option<String>), Response> {
+) -> Result<(Option<String>, Option<String>), (StatusCode, ErrorResponse)> {
     if let Some(existing_prod_id) = existing_stripe_product_id {
         // Sync metadata / name / description to Stripe (non-fatal on failure)
         let mut meta = metadata;
@@ -124,12 +121,11 @@ pub(crate) async fn stripe_ids_for_update(
                 .await
                 .map_err(|e| {
                     tracing::error!(error = %e, "Failed to auto-create Stripe product");
-                    let (status, body) = error_response(
+                    error_response(
                         ErrorCode::StripeError,
                         Some("Failed to create Stripe product".to_string()),
                         None,
-                    );
-                    json_error(status, body).into_response()
+                    )
                 })?;
 
             let currency = fiat_currency.unwrap_or("usd");
@@ -138,12 +134,11 @@ pub(crate) async fn stripe_ids_for_update(
                 .await
                 .map_err(|e| {
                     tracing::error!(error = %e, "Failed to auto-create Stripe price");
-                    let (status, body) = error_response(
+                    error_response(
                         ErrorCode::StripeError,
                         Some("Failed to create Stripe price".to_string()),
                         None,
-                    );
-                    json_error(status, body).into_response()
+                    )
                 })?;
 
             tracing::info!(
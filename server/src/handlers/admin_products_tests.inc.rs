@@ -45,6 +45,7 @@ fn base_create_product_request() -> CreateProductRequest {
         gift_card_config: None,
         tokenized_asset_config: None,
         compliance_requirements: None,
+        payment_split: None,
     }
 }
 
@@ -85,6 +86,7 @@ async fn test_create_product_persists_seo_fields() {
         product_repo: product_repo.clone(),
         coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
         stripe_client: None,
+        messaging: None,
     });
 
     let mut req = base_create_product_request();
@@ -124,6 +126,7 @@ async fn test_update_product_clears_seo_fields() {
         product_repo: product_repo.clone(),
         coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
         stripe_client: None,
+        messaging: None,
     });
 
     let mut req = base_create_product_request();
@@ -177,6 +180,7 @@ async fn test_admin_list_products_includes_inactive_and_returns_total_count() {
         product_repo: Arc::new(InMemoryProductRepository::new(vec![p1, p2])),
         coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
         stripe_client: None,
+        messaging: None,
     });
 
     let resp = super::list_products(
@@ -221,6 +225,7 @@ async fn test_set_product_inventory_updates_quantity() {
         product_repo: product_repo.clone(),
         coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
         stripe_client: None,
+        messaging: None,
     });
 
     let resp = super::set_product_inventory(
@@ -261,6 +266,7 @@ async fn test_adjust_product_inventory_updates_quantity() {
         product_repo: product_repo.clone(),
         coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
         stripe_client: None,
+        messaging: None,
     });
 
     let resp = super::adjust_product_inventory(
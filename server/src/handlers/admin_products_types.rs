@@ -87,6 +87,8 @@ pub struct CreateProductRequest {
     pub tokenized_asset_config: Option<crate::models::TokenizedAssetConfig>,
     #[serde(default)]
     pub compliance_requirements: Option<crate::models::compliance::ComplianceRequirements>,
+    #[serde(default)]
+    pub payment_split: Option<crate::models::PaymentSplit>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -213,6 +215,29 @@ pub(crate) fn validate_product_checkout_fields(
         }
     }
 
+    if let Some(ref split) = req.payment_split {
+        if let Err(message) = validate_payment_split(split) {
+            let (status, body) = error_response(
+                ErrorCode::InvalidField,
+                Some(format!("paymentSplit: {message}")),
+                Some(serde_json::json!({ "field": "paymentSplit" })),
+            );
+            return Err((status, body));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate a revenue split's basis points and wallet addresses.
+pub(crate) fn validate_payment_split(split: &crate::models::PaymentSplit) -> Result<(), String> {
+    split.validate()?;
+    crate::x402::utils::validate_wallet_address(&split.seller_wallet)
+        .map_err(|_| "sellerWallet must be a valid Solana address".to_string())?;
+    if let Some(ref wallet) = split.affiliate_wallet {
+        crate::x402::utils::validate_wallet_address(wallet)
+            .map_err(|_| "affiliateWallet must be a valid Solana address".to_string())?;
+    }
     Ok(())
 }
 
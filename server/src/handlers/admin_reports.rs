@@ -0,0 +1,209 @@
+//! Admin financial report handlers
+//!
+//! Period reports of sales, refunds, disputes and net revenue in atomic
+//! units per currency, as JSON or CSV.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+
+use crate::errors::{error_response, ErrorCode};
+use crate::handlers::admin::AdminState;
+use crate::handlers::response::{json_error, json_ok};
+use crate::middleware::TenantContext;
+use crate::services::financial_reports::{
+    build_financial_report, report_to_csv, FinancialReportError, ReportGroupBy, ReportParams,
+    ReportPeriod,
+};
+
+/// Default report window when `from` is omitted.
+const DEFAULT_REPORT_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FinancialReportQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub period: ReportPeriod,
+    pub group_by: Option<ReportGroupBy>,
+    #[serde(default)]
+    pub format: ReportFormat,
+}
+
+/// GET /admin/reports/financial?from&to&period=day|week|month&groupBy=rail|product|collection|tenant&format=json|csv
+///
+/// Gross sales, discounts, tax, shipping, gift card redemptions, refunds,
+/// disputes and net per period and currency. Defaults to the last 30 days.
+pub async fn financial_report(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Query(query): Query<FinancialReportQuery>,
+) -> impl IntoResponse {
+    let to = query.to.unwrap_or_else(Utc::now);
+    let from = query
+        .from
+        .unwrap_or_else(|| to - Duration::days(DEFAULT_REPORT_DAYS));
+    let params = ReportParams {
+        from,
+        to,
+        period: query.period,
+        group_by: query.group_by,
+    };
+
+    let report = match build_financial_report(
+        &*state.store,
+        Some(&*state.product_repo),
+        &tenant.tenant_id,
+        params,
+    )
+    .await
+    {
+        Ok(report) => report,
+        Err(FinancialReportError::InvalidRange(msg)) => {
+            let (status, body) = error_response(ErrorCode::InvalidField, Some(msg), None);
+            return json_error(status, body).into_response();
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to build financial report");
+            let (status, body) = error_response(
+                ErrorCode::InternalError,
+                Some("Failed to build financial report".to_string()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+    };
+
+    match query.format {
+        ReportFormat::Json => json_ok(report).into_response(),
+        ReportFormat::Csv => {
+            let filename = format!(
+                "attachment; filename=\"financial-report-{}-{}.csv\"",
+                from.format("%Y%m%d"),
+                to.format("%Y%m%d")
+            );
+            (
+                StatusCode::OK,
+                [
+                    (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                    (header::CONTENT_DISPOSITION, filename),
+                ],
+                report_to_csv(&report),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+
+    use crate::models::{Order, OrderItem};
+    use crate::repositories::{InMemoryCouponRepository, InMemoryProductRepository};
+    use crate::storage::{InMemoryStore, Store};
+
+    fn state(store: Arc<InMemoryStore>) -> Arc<AdminState> {
+        Arc::new(AdminState {
+            store,
+            product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
+            coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
+            stripe_client: None,
+            messaging: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_financial_report_csv() {
+        let store = Arc::new(InMemoryStore::new());
+        let now = Utc::now();
+        let tenant = TenantContext::default();
+        store
+            .try_store_order(Order {
+                id: "o1".to_string(),
+                tenant_id: tenant.tenant_id.clone(),
+                source: "stripe".to_string(),
+                purchase_id: "cs_1".to_string(),
+                resource_id: "p1".to_string(),
+                user_id: None,
+                customer: None,
+                status: "paid".to_string(),
+                items: vec![OrderItem {
+                    product_id: "p1".to_string(),
+                    variant_id: None,
+                    quantity: 1,
+                    line_total: None,
+                }],
+                amount: 1_500,
+                amount_asset: "USD".to_string(),
+                customer_email: None,
+                customer_name: None,
+                receipt_url: None,
+                shipping: None,
+                metadata: HashMap::new(),
+                created_at: now - Duration::hours(1),
+                updated_at: None,
+                status_updated_at: None,
+            })
+            .await
+            .unwrap();
+
+        let resp = financial_report(
+            State(state(store)),
+            tenant,
+            Query(FinancialReportQuery {
+                group_by: Some(ReportGroupBy::Rail),
+                format: ReportFormat::Csv,
+                ..Default::default()
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/csv; charset=utf-8"
+        );
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let csv = String::from_utf8(body.to_vec()).unwrap();
+        let row = csv.lines().nth(1).unwrap();
+        assert!(row.contains(",USD,2,stripe,1,1500,"));
+        assert!(row.ends_with(",1500,0,0,1500"));
+    }
+
+    #[tokio::test]
+    async fn test_financial_report_rejects_inverted_range() {
+        let now = Utc::now();
+        let resp = financial_report(
+            State(state(Arc::new(InMemoryStore::new()))),
+            TenantContext::default(),
+            Query(FinancialReportQuery {
+                from: Some(now),
+                to: Some(now - Duration::days(1)),
+                ..Default::default()
+            }),
+        )
+        .await
+        .into_response();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
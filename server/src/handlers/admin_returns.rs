@@ -1,24 +1,43 @@
-//! Admin return request handlers
+//! Admin return request (RMA) handlers
+//!
+//! Status changes drive the RMA workflow: approving a return prices it
+//! (refund amount and restocking fee), receiving it restocks resellable items
+//! and refunding it issues the refund through the order's payment path.
 
 use std::collections::HashMap;
 use std::sync::Arc;
 
 use axum::{
     extract::{Path, Query, State},
+    http::StatusCode,
     response::IntoResponse,
     Json,
 };
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
+use crate::config::ShopReturnsConfig;
 use crate::errors::{error_response, ErrorCode};
-use crate::handlers::admin::{audit, AdminState};
+use crate::handlers::admin::audit;
 use crate::handlers::response::{json_error, json_ok};
 use crate::middleware::TenantContext;
-use crate::models::{is_valid_return_transition, OrderItem, ReturnRequest};
+use crate::models::{is_valid_return_transition, Order, ReturnItem, ReturnRequest};
+use crate::repositories::ProductRepository;
+use crate::services::returns::{
+    issue_return_refund, quote_return_refund, refund_method_for_order, restock_return_items,
+    validate_items, ReturnPolicyError,
+};
+use crate::storage::Store;
 
 use super::cap_limit_opt;
 
+/// State for admin return routes.
+pub struct AdminReturnsState {
+    pub store: Arc<dyn Store>,
+    pub product_repo: Arc<dyn ProductRepository>,
+    pub policy: ShopReturnsConfig,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListReturnsQuery {
@@ -34,7 +53,7 @@ pub struct CreateReturnRequest {
     pub id: Option<String>,
     pub order_id: String,
     #[serde(default)]
-    pub items: Vec<OrderItem>,
+    pub items: Vec<ReturnItem>,
     pub reason: Option<String>,
     #[serde(default)]
     pub metadata: HashMap<String, String>,
@@ -44,6 +63,18 @@ pub struct CreateReturnRequest {
 #[serde(rename_all = "camelCase")]
 pub struct UpdateReturnStatusRequest {
     pub status: String,
+    /// Replace line items, e.g. to record conditions after inspection. Items must
+    /// be a subset of the original request.
+    pub items: Option<Vec<ReturnItem>>,
+    /// Override the policy restocking fee (atomic units of the order asset).
+    pub restocking_fee: Option<i64>,
+    /// Restock resellable items on `received` (default: policy setting).
+    pub restock: Option<bool>,
+    /// Issue the refund automatically on `refunded` (default: true). When false
+    /// the refund is recorded as handled manually.
+    pub issue_refund: Option<bool>,
+    /// Refund destination for x402/credits orders without a recorded wallet.
+    pub recipient_wallet: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -59,23 +90,43 @@ fn is_allowed_return_status(status: &str) -> bool {
     )
 }
 
-fn validate_items(items: &[OrderItem]) -> Result<(), String> {
-    if items.is_empty() {
-        return Err("items must not be empty".to_string());
-    }
-    for item in items {
-        if item.product_id.trim().is_empty() {
-            return Err("item product_id is required".to_string());
-        }
-        if item.quantity <= 0 {
-            return Err("item quantity must be positive".to_string());
+/// Replacement items may refine conditions/reasons but not add lines or quantity.
+fn validate_item_subset(original: &[ReturnItem], updated: &[ReturnItem]) -> Result<(), String> {
+    for item in updated {
+        let allowed: i32 = original
+            .iter()
+            .filter(|o| o.product_id == item.product_id && o.variant_id == item.variant_id)
+            .map(|o| o.quantity)
+            .sum();
+        let requested: i32 = updated
+            .iter()
+            .filter(|u| u.product_id == item.product_id && u.variant_id == item.variant_id)
+            .map(|u| u.quantity)
+            .sum();
+        if requested > allowed {
+            return Err(format!(
+                "items may not exceed the original return for product {}",
+                item.product_id
+            ));
         }
     }
     Ok(())
 }
 
+fn policy_error_response(e: ReturnPolicyError) -> (StatusCode, Json<serde_json::Value>) {
+    let (code, message) = match e {
+        ReturnPolicyError::Storage(msg) => (
+            ErrorCode::DatabaseError,
+            format!("Failed to process return: {msg}"),
+        ),
+        other => (ErrorCode::InvalidOperation, other.to_string()),
+    };
+    let (status_code, body) = error_response(code, Some(message), None);
+    json_error(status_code, body)
+}
+
 pub async fn list_returns(
-    State(state): State<Arc<AdminState>>,
+    State(state): State<Arc<AdminReturnsState>>,
     tenant: TenantContext,
     Query(params): Query<ListReturnsQuery>,
 ) -> impl IntoResponse {
@@ -118,7 +169,7 @@ pub async fn list_returns(
 }
 
 pub async fn get_return(
-    State(state): State<Arc<AdminState>>,
+    State(state): State<Arc<AdminReturnsState>>,
     tenant: TenantContext,
     Path(id): Path<String>,
 ) -> impl IntoResponse {
@@ -144,7 +195,7 @@ pub async fn get_return(
 }
 
 pub async fn create_return(
-    State(state): State<Arc<AdminState>>,
+    State(state): State<Arc<AdminReturnsState>>,
     tenant: TenantContext,
     Json(req): Json<CreateReturnRequest>,
 ) -> impl IntoResponse {
@@ -189,6 +240,11 @@ pub async fn create_return(
         created_at: now,
         updated_at: Some(now),
         status_updated_at: Some(now),
+        restocking_fee: None,
+        refund_amount: None,
+        refund_method: None,
+        refund_id: None,
+        restocked_at: None,
     };
 
     match state.store.create_return_request(request.clone()).await {
@@ -216,7 +272,7 @@ pub async fn create_return(
 }
 
 pub async fn update_return_status(
-    State(state): State<Arc<AdminState>>,
+    State(state): State<Arc<AdminReturnsState>>,
     tenant: TenantContext,
     Path(id): Path<String>,
     Json(req): Json<UpdateReturnStatusRequest>,
@@ -259,49 +315,262 @@ pub async fn update_return_status(
         );
         return json_error(status_code, body);
     }
+    if existing.status == status {
+        // Re-submitting the current status must not restock or refund twice.
+        return json_ok(existing);
+    }
+
+    let mut updated = existing.clone();
+    if let Some(items) = req.items {
+        if let Err(message) =
+            validate_items(&items).and_then(|_| validate_item_subset(&existing.items, &items))
+        {
+            let (status_code, body) = error_response(ErrorCode::InvalidField, Some(message), None);
+            return json_error(status_code, body);
+        }
+        updated.items = items;
+    }
+    if req.restocking_fee.is_some_and(|fee| fee < 0) {
+        let (status_code, body) = error_response(
+            ErrorCode::InvalidField,
+            Some("restockingFee must not be negative".to_string()),
+            Some(serde_json::json!({ "field": "restockingFee" })),
+        );
+        return json_error(status_code, body);
+    }
 
     let now = Utc::now();
-    if let Err(e) = state
+    let order = if matches!(status.as_str(), "approved" | "received" | "refunded") {
+        let order = match load_order(&state, &tenant.tenant_id, &existing.order_id).await {
+            Ok(order) => order,
+            Err(resp) => return resp,
+        };
+
+        if status == "approved" || req.restocking_fee.is_some() || updated.refund_amount.is_none() {
+            match quote_return_refund(
+                &*state.store,
+                &*state.product_repo,
+                &state.policy,
+                &order,
+                &updated,
+                req.restocking_fee.or(updated.restocking_fee),
+            )
+            .await
+            {
+                Ok(breakdown) => {
+                    updated.restocking_fee = Some(breakdown.restocking_fee);
+                    updated.refund_amount = Some(breakdown.refund_amount);
+                }
+                Err(e) => return policy_error_response(e),
+            }
+        }
+        Some(order)
+    } else {
+        None
+    };
+
+    updated.status = status.clone();
+    updated.status_updated_at = Some(now);
+    updated.updated_at = Some(now);
+
+    // Claim the transition before restocking or refunding: a concurrent
+    // request, or a retry after a failed write below, then finds the new
+    // status and has nothing left to do.
+    match state
         .store
-        .update_return_status(&tenant.tenant_id, &id, &status, now, now)
+        .update_return_request_if_status(updated.clone(), &existing.status)
         .await
     {
-        let (code, message) = match e {
-            crate::storage::StorageError::NotFound => (
-                ErrorCode::ResourceNotFound,
-                "return request not found".to_string(),
-            ),
-            _ => (
+        Ok(true) => {}
+        Ok(false) => {
+            let (status_code, body) = error_response(
+                ErrorCode::InvalidOperation,
+                Some("return request was updated concurrently; reload and retry".to_string()),
+                None,
+            );
+            return json_error(status_code, body);
+        }
+        Err(e) => {
+            let (status_code, body) = error_response(
                 ErrorCode::DatabaseError,
-                format!("Failed to update return status: {e}"),
-            ),
-        };
-        let (status_code, body) = error_response(code, Some(message), None);
-        return json_error(status_code, body);
+                Some(format!("Failed to update return status: {e}")),
+                None,
+            );
+            return json_error(status_code, body);
+        }
     }
 
-    let updated = ReturnRequest {
-        status,
-        status_updated_at: Some(now),
-        updated_at: Some(now),
-        ..existing
-    };
+    if let Some(order) = order {
+        let mut changed = false;
+        if status == "received"
+            && updated.restocked_at.is_none()
+            && req.restock.unwrap_or(state.policy.restock_on_receive)
+        {
+            if let Err(e) = restock_return_items(&*state.store, &updated).await {
+                release_claim(&state, &updated, existing).await;
+                return policy_error_response(e);
+            }
+            updated.restocked_at = Some(now);
+            changed = true;
+        }
 
-    audit(&*state.store, &tenant, "return", &id, "update_status", None).await;
+        if status == "refunded" && updated.refund_id.is_none() {
+            let amount = updated.refund_amount.unwrap_or(0);
+            if !req.issue_refund.unwrap_or(true) {
+                updated.refund_method = Some("manual".to_string());
+                changed = true;
+            } else if amount > 0 {
+                match issue_return_refund(
+                    &*state.store,
+                    &state.policy,
+                    &order,
+                    &updated,
+                    amount,
+                    req.recipient_wallet.as_deref(),
+                    now,
+                )
+                .await
+                {
+                    Ok(refund_id) => {
+                        updated.refund_method = Some(refund_method_for_order(&order).to_string());
+                        updated.refund_id = Some(refund_id);
+                        changed = true;
+                    }
+                    Err(e) => {
+                        release_claim(&state, &updated, existing).await;
+                        return policy_error_response(e);
+                    }
+                }
+            }
+        }
+
+        if changed {
+            let result = state
+                .store
+                .update_return_request_if_status(updated.clone(), &status)
+                .await;
+            if !matches!(result, Ok(true)) {
+                tracing::error!(
+                    error = ?result.err(),
+                    return_id = %id,
+                    refund_id = ?updated.refund_id,
+                    restocked_at = ?updated.restocked_at,
+                    "Return side effects applied but return record update failed - requires reconciliation"
+                );
+                let (status_code, body) = error_response(
+                    ErrorCode::DatabaseError,
+                    Some("Failed to record return refund or restock".to_string()),
+                    None,
+                );
+                return json_error(status_code, body);
+            }
+        }
+    }
+
+    audit(
+        &*state.store,
+        &tenant,
+        "return",
+        &id,
+        "update_status",
+        Some(serde_json::json!({
+            "status": &updated.status,
+            "refundAmount": updated.refund_amount,
+            "restockingFee": updated.restocking_fee,
+            "refundId": &updated.refund_id,
+        })),
+    )
+    .await;
 
     json_ok(updated)
 }
 
+/// Put a claimed return back to its previous status after the restock or
+/// refund failed, so the transition can be retried.
+async fn release_claim(
+    state: &AdminReturnsState,
+    claimed: &ReturnRequest,
+    previous: ReturnRequest,
+) {
+    if let Err(e) = state
+        .store
+        .update_return_request_if_status(previous, &claimed.status)
+        .await
+    {
+        tracing::error!(
+            error = %e,
+            return_id = %claimed.id,
+            status = %claimed.status,
+            "Failed to release return status claim"
+        );
+    }
+}
+
+async fn load_order(
+    state: &AdminReturnsState,
+    tenant_id: &str,
+    order_id: &str,
+) -> Result<Order, (StatusCode, Json<serde_json::Value>)> {
+    match state.store.get_order(tenant_id, order_id).await {
+        Ok(Some(order)) => Ok(order),
+        Ok(None) => {
+            let (status_code, body) = error_response(
+                ErrorCode::ResourceNotFound,
+                Some("order not found".to_string()),
+                None,
+            );
+            Err(json_error(status_code, body))
+        }
+        Err(e) => {
+            let (status_code, body) = error_response(
+                ErrorCode::DatabaseError,
+                Some(format!("Failed to load order: {e}")),
+                None,
+            );
+            Err(json_error(status_code, body))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use axum::http::StatusCode;
     use axum::response::IntoResponse;
     use chrono::Utc;
 
-    use crate::repositories::{InMemoryCouponRepository, InMemoryProductRepository};
-    use crate::storage::{InMemoryStore, Store};
+    use crate::models::OrderItem;
+    use crate::repositories::InMemoryProductRepository;
+    use crate::storage::InMemoryStore;
+
+    fn state(store: Arc<InMemoryStore>) -> Arc<AdminReturnsState> {
+        Arc::new(AdminReturnsState {
+            store,
+            product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
+            policy: ShopReturnsConfig::default(),
+        })
+    }
+
+    fn return_item(quantity: i32) -> ReturnItem {
+        ReturnItem {
+            product_id: "prod-1".to_string(),
+            variant_id: None,
+            quantity,
+            condition: None,
+            reason_code: None,
+        }
+    }
+
+    fn status_request(status: &str) -> UpdateReturnStatusRequest {
+        UpdateReturnStatusRequest {
+            status: status.to_string(),
+            items: None,
+            restocking_fee: None,
+            restock: None,
+            issue_refund: None,
+            recipient_wallet: None,
+        }
+    }
 
     fn base_order() -> crate::models::Order {
         let now = Utc::now();
@@ -318,6 +587,7 @@ mod tests {
                 product_id: "prod-1".to_string(),
                 variant_id: None,
                 quantity: 1,
+                line_total: None,
             }],
             amount: 1200,
             amount_asset: "USD".to_string(),
@@ -335,12 +605,7 @@ mod tests {
     #[tokio::test]
     async fn test_create_return_persists() {
         let store = Arc::new(InMemoryStore::new());
-        let state = Arc::new(AdminState {
-            store: store.clone(),
-            product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
-            coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
-            stripe_client: None,
-        });
+        let state = state(store.clone());
         let order = base_order();
         store.try_store_order(order).await.unwrap();
 
@@ -348,11 +613,7 @@ mod tests {
         let request = CreateReturnRequest {
             id: Some("ret-1".to_string()),
             order_id: "ord-1".to_string(),
-            items: vec![OrderItem {
-                product_id: "prod-1".to_string(),
-                variant_id: None,
-                quantity: 1,
-            }],
+            items: vec![return_item(1)],
             reason: Some("damaged".to_string()),
             metadata: HashMap::new(),
         };
@@ -373,12 +634,7 @@ mod tests {
     #[tokio::test]
     async fn test_update_return_status_rejects_invalid_transition() {
         let store = Arc::new(InMemoryStore::new());
-        let state = Arc::new(AdminState {
-            store: store.clone(),
-            product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
-            coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
-            stripe_client: None,
-        });
+        let state = state(store.clone());
         let now = Utc::now();
         store
             .create_return_request(ReturnRequest {
@@ -386,24 +642,23 @@ mod tests {
                 tenant_id: "default".to_string(),
                 order_id: "ord-2".to_string(),
                 status: "requested".to_string(),
-                items: vec![OrderItem {
-                    product_id: "prod-1".to_string(),
-                    variant_id: None,
-                    quantity: 1,
-                }],
+                items: vec![return_item(1)],
                 reason: None,
                 metadata: HashMap::new(),
                 created_at: now,
                 updated_at: Some(now),
                 status_updated_at: Some(now),
+                restocking_fee: None,
+                refund_amount: None,
+                refund_method: None,
+                refund_id: None,
+                restocked_at: None,
             })
             .await
             .unwrap();
 
         let tenant = TenantContext::default();
-        let request = UpdateReturnStatusRequest {
-            status: "refunded".to_string(),
-        };
+        let request = status_request("refunded");
 
         let response = update_return_status(
             State(state),
@@ -415,4 +670,143 @@ mod tests {
         .into_response();
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
+
+    #[tokio::test]
+    async fn test_rma_flow_restocks_and_issues_refund_quote() {
+        let store = Arc::new(InMemoryStore::new());
+        let state = Arc::new(AdminReturnsState {
+            store: store.clone(),
+            product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
+            policy: ShopReturnsConfig {
+                restocking_fee_bps: 1000,
+                ..Default::default()
+            },
+        });
+        store.set_product_inventory("default", "prod-1", 4);
+        let mut order = base_order();
+        order.source = "x402".to_string();
+        order.purchase_id = "sig-123".to_string();
+        order.customer = Some("wallet-1".to_string());
+        order.amount = 2_000_000;
+        order.amount_asset = "USDC".to_string();
+        store.try_store_order(order).await.unwrap();
+
+        let tenant = TenantContext::default();
+        let request = CreateReturnRequest {
+            id: Some("ret-3".to_string()),
+            order_id: "ord-1".to_string(),
+            items: vec![return_item(1)],
+            reason: None,
+            metadata: HashMap::new(),
+        };
+        let response = create_return(State(state.clone()), tenant.clone(), Json(request))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        for status in ["approved", "received", "refunded"] {
+            let response = update_return_status(
+                State(state.clone()),
+                tenant.clone(),
+                Path("ret-3".to_string()),
+                Json(status_request(status)),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::OK, "transition to {status}");
+        }
+
+        let stored = store
+            .get_return_request("default", "ret-3")
+            .await
+            .unwrap()
+            .expect("return stored");
+        assert_eq!(stored.status, "refunded");
+        assert_eq!(stored.restocking_fee, Some(200_000));
+        assert_eq!(stored.refund_amount, Some(1_800_000));
+        assert_eq!(stored.refund_method.as_deref(), Some("x402"));
+        assert!(stored.restocked_at.is_some());
+
+        let (_, after) = store
+            .adjust_inventory_atomic("default", "prod-1", 0)
+            .await
+            .unwrap();
+        assert_eq!(after, 5);
+
+        let quote = store
+            .get_refund_quote("default", stored.refund_id.as_deref().unwrap())
+            .await
+            .unwrap()
+            .expect("refund quote stored");
+        assert_eq!(quote.amount.atomic, 1_800_000);
+        assert_eq!(quote.recipient_wallet, "wallet-1");
+        assert_eq!(
+            quote.metadata.get("return_id").map(String::as_str),
+            Some("ret-3")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_receive_retry_after_partial_restock_does_not_double_count() {
+        let store = Arc::new(InMemoryStore::new());
+        let state = state(store.clone());
+        store.set_product_inventory("default", "prod-1", 4);
+        store.set_product_inventory("default", "prod-2", 4);
+        let mut order = base_order();
+        order.items.push(OrderItem {
+            product_id: "prod-2".to_string(),
+            variant_id: None,
+            quantity: 1,
+            line_total: None,
+        });
+        store.try_store_order(order).await.unwrap();
+
+        let tenant = TenantContext::default();
+        let mut second = return_item(1);
+        second.product_id = "prod-2".to_string();
+        let request = CreateReturnRequest {
+            id: Some("ret-4".to_string()),
+            order_id: "ord-1".to_string(),
+            items: vec![return_item(1), second],
+            reason: None,
+            metadata: HashMap::new(),
+        };
+        let response = create_return(State(state.clone()), tenant.clone(), Json(request))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let transition = |status: &'static str| {
+            let state = state.clone();
+            let tenant = tenant.clone();
+            async move {
+                update_return_status(
+                    State(state),
+                    tenant,
+                    Path("ret-4".to_string()),
+                    Json(status_request(status)),
+                )
+                .await
+                .into_response()
+                .status()
+            }
+        };
+        assert_eq!(transition("approved").await, StatusCode::OK);
+
+        store.set_fail_inventory_update_for(Some("prod-2"));
+        assert_ne!(transition("received").await, StatusCode::OK);
+        let stored = store.get_return_request("default", "ret-4").await.unwrap();
+        assert_eq!(stored.expect("return stored").status, "approved");
+
+        store.set_fail_inventory_update_for(None);
+        assert_eq!(transition("received").await, StatusCode::OK);
+
+        for product_id in ["prod-1", "prod-2"] {
+            let (_, after) = store
+                .adjust_inventory_atomic("default", product_id, 0)
+                .await
+                .unwrap();
+            assert_eq!(after, 5, "{product_id} restocked once");
+        }
+    }
 }
@@ -0,0 +1,431 @@
+//! Admin Stripe Connect handlers
+//!
+//! Per-tenant connected account onboarding, status and platform fee reporting.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{error_response, ErrorCode};
+use crate::handlers::admin::{audit, AdminState};
+use crate::handlers::response::{json_error, json_ok};
+use crate::middleware::TenantContext;
+use crate::models::{StripeApplicationFee, StripeConnectAccount};
+use crate::services::{ServiceError, StripeClient};
+
+/// Default fee report window when `from` is omitted.
+const DEFAULT_FEE_REPORT_DAYS: i64 = 30;
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectStatusQuery {
+    /// Re-fetch account capabilities from Stripe before responding
+    #[serde(default)]
+    pub refresh: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectStatusResponse {
+    pub enabled: bool,
+    pub charge_type: String,
+    /// Fee applied to this tenant's charges (account override or platform default)
+    pub application_fee_bps: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<StripeConnectAccount>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOnboardingLinkRequest {
+    pub email: Option<String>,
+    pub refresh_url: Option<String>,
+    pub return_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateConnectFeeRequest {
+    /// Per-account fee override in basis points; `null` reverts to the platform default
+    pub application_fee_bps: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct ConnectFeesQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectFeeTotal {
+    pub currency: String,
+    pub count: usize,
+    pub gross: i64,
+    pub refunded: i64,
+    pub net: i64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectFeesResponse {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub totals: Vec<ConnectFeeTotal>,
+    pub fees: Vec<StripeApplicationFee>,
+}
+
+fn require_connect(
+    state: &AdminState,
+) -> Result<&Arc<StripeClient>, (StatusCode, Json<serde_json::Value>)> {
+    match state.stripe_client.as_ref() {
+        Some(c) if c.connect_enabled() => Ok(c),
+        _ => {
+            let (status, body) = error_response(
+                ErrorCode::ServiceUnavailable,
+                Some("Stripe Connect not configured".to_string()),
+                None,
+            );
+            Err(json_error(status, body))
+        }
+    }
+}
+
+fn service_error(e: ServiceError) -> (StatusCode, Json<serde_json::Value>) {
+    let (status, body) = error_response(e.code(), Some(e.to_string()), None);
+    json_error(status, body)
+}
+
+/// Sum application fees per currency (sorted by currency code).
+pub(crate) fn summarize_fees(fees: &[StripeApplicationFee]) -> Vec<ConnectFeeTotal> {
+    let mut totals: BTreeMap<String, ConnectFeeTotal> = BTreeMap::new();
+    for fee in fees {
+        let entry = totals
+            .entry(fee.currency.clone())
+            .or_insert_with(|| ConnectFeeTotal {
+                currency: fee.currency.clone(),
+                count: 0,
+                gross: 0,
+                refunded: 0,
+                net: 0,
+            });
+        entry.count += 1;
+        entry.gross += fee.amount;
+        entry.refunded += fee.amount_refunded;
+        entry.net += fee.net_amount();
+    }
+    totals.into_values().collect()
+}
+
+/// GET /admin/stripe/connect?refresh={bool}
+pub async fn get_connect_status(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Query(query): Query<ConnectStatusQuery>,
+) -> impl IntoResponse {
+    let stripe = match require_connect(&state) {
+        Ok(c) => c,
+        Err(resp) => return resp.into_response(),
+    };
+
+    let account = if query.refresh {
+        stripe.sync_connect_account(&tenant.tenant_id).await
+    } else {
+        state
+            .store
+            .get_stripe_connect_account(&tenant.tenant_id)
+            .await
+            .map_err(|e| ServiceError::Coded {
+                code: ErrorCode::DatabaseError,
+                message: e.to_string(),
+            })
+    };
+
+    match account {
+        Ok(account) => {
+            let connect = &stripe.config.stripe.connect;
+            json_ok(ConnectStatusResponse {
+                enabled: connect.enabled,
+                charge_type: connect.charge_type.clone(),
+                application_fee_bps: account
+                    .as_ref()
+                    .and_then(|a| a.application_fee_bps)
+                    .unwrap_or(connect.application_fee_bps),
+                account,
+            })
+            .into_response()
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to load Stripe connected account");
+            service_error(e).into_response()
+        }
+    }
+}
+
+/// POST /admin/stripe/connect/onboarding
+///
+/// Creates the tenant's Express account on first use and returns a hosted onboarding link.
+pub async fn create_onboarding_link(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Json(req): Json<CreateOnboardingLinkRequest>,
+) -> impl IntoResponse {
+    let stripe = match require_connect(&state) {
+        Ok(c) => c,
+        Err(resp) => return resp.into_response(),
+    };
+
+    match stripe
+        .create_connect_onboarding_link(
+            &tenant.tenant_id,
+            req.email.as_deref(),
+            req.refresh_url,
+            req.return_url,
+        )
+        .await
+    {
+        Ok(link) => {
+            audit(
+                &*state.store,
+                &tenant,
+                "stripe_connect_account",
+                &link.account_id,
+                "onboarding_link",
+                None,
+            )
+            .await;
+            json_ok(link).into_response()
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to create Stripe Connect onboarding link");
+            service_error(e).into_response()
+        }
+    }
+}
+
+/// PUT /admin/stripe/connect/fee
+pub async fn update_connect_fee(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Json(req): Json<UpdateConnectFeeRequest>,
+) -> impl IntoResponse {
+    if let Err(resp) = require_connect(&state) {
+        return resp.into_response();
+    }
+    if req.application_fee_bps.is_some_and(|bps| bps > 10_000) {
+        let (status, body) = error_response(
+            ErrorCode::InvalidField,
+            Some("applicationFeeBps must be <= 10000".to_string()),
+            None,
+        );
+        return json_error(status, body).into_response();
+    }
+
+    let mut account = match state
+        .store
+        .get_stripe_connect_account(&tenant.tenant_id)
+        .await
+    {
+        Ok(Some(a)) => a,
+        Ok(None) => {
+            let (status, body) = error_response(
+                ErrorCode::ResourceNotFound,
+                Some("No connected account; start onboarding first".to_string()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to load Stripe connected account");
+            let (status, body) = error_response(
+                ErrorCode::InternalError,
+                Some("Failed to load connected account".to_string()),
+                None,
+            );
+            return json_error(status, body).into_response();
+        }
+    };
+
+    account.application_fee_bps = req.application_fee_bps;
+    account.updated_at = Utc::now();
+    if let Err(e) = state
+        .store
+        .upsert_stripe_connect_account(account.clone())
+        .await
+    {
+        tracing::error!(error = %e, "Failed to update Stripe connected account");
+        let (status, body) = error_response(
+            ErrorCode::InternalError,
+            Some("Failed to update connected account".to_string()),
+            None,
+        );
+        return json_error(status, body).into_response();
+    }
+
+    audit(
+        &*state.store,
+        &tenant,
+        "stripe_connect_account",
+        &account.account_id,
+        "update_fee",
+        Some(serde_json::json!({ "applicationFeeBps": req.application_fee_bps })),
+    )
+    .await;
+
+    json_ok(account).into_response()
+}
+
+/// GET /admin/stripe/connect/fees?from={rfc3339}&to={rfc3339}
+///
+/// Application fees collected on this tenant's charges, with per-currency totals.
+/// Works without Stripe credentials so historical fees stay reportable.
+pub async fn list_connect_fees(
+    State(state): State<Arc<AdminState>>,
+    tenant: TenantContext,
+    Query(query): Query<ConnectFeesQuery>,
+) -> impl IntoResponse {
+    let to = query.to.unwrap_or_else(Utc::now);
+    let from = query
+        .from
+        .unwrap_or_else(|| to - Duration::days(DEFAULT_FEE_REPORT_DAYS));
+    if from >= to {
+        let (status, body) = error_response(
+            ErrorCode::InvalidField,
+            Some("from must be before to".to_string()),
+            None,
+        );
+        return json_error(status, body).into_response();
+    }
+
+    match state
+        .store
+        .list_stripe_application_fees(&tenant.tenant_id, from, to)
+        .await
+    {
+        Ok(fees) => json_ok(ConnectFeesResponse {
+            from,
+            to,
+            totals: summarize_fees(&fees),
+            fees,
+        })
+        .into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to list Stripe application fees");
+            let (status, body) = error_response(
+                ErrorCode::InternalError,
+                Some("Failed to list application fees".to_string()),
+                None,
+            );
+            json_error(status, body).into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::repositories::{InMemoryCouponRepository, InMemoryProductRepository};
+    use crate::storage::{InMemoryStore, Store};
+
+    fn fee(id: &str, currency: &str, amount: i64, refunded: i64) -> StripeApplicationFee {
+        StripeApplicationFee {
+            id: id.to_string(),
+            tenant_id: "default".to_string(),
+            account_id: "acct_1".to_string(),
+            charge_id: None,
+            amount,
+            amount_refunded: refunded,
+            currency: currency.to_string(),
+            created_at: Utc::now() - Duration::hours(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_connect_fees_totals_per_currency() {
+        let store = Arc::new(InMemoryStore::new());
+        store
+            .upsert_stripe_application_fee(fee("fee_1", "usd", 300, 0))
+            .await
+            .unwrap();
+        store
+            .upsert_stripe_application_fee(fee("fee_2", "usd", 200, 50))
+            .await
+            .unwrap();
+        store
+            .upsert_stripe_application_fee(fee("fee_3", "eur", 100, 0))
+            .await
+            .unwrap();
+
+        let state = Arc::new(AdminState {
+            store: store.clone(),
+            product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
+            coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
+            stripe_client: None,
+            messaging: None,
+        });
+
+        let resp = list_connect_fees(
+            State(state),
+            TenantContext::default(),
+            Query(ConnectFeesQuery::default()),
+        )
+        .await
+        .into_response();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let fees = store
+            .list_stripe_application_fees("default", Utc::now() - Duration::days(1), Utc::now())
+            .await
+            .unwrap();
+        let totals = summarize_fees(&fees);
+        assert_eq!(
+            totals,
+            vec![
+                ConnectFeeTotal {
+                    currency: "eur".to_string(),
+                    count: 1,
+                    gross: 100,
+                    refunded: 0,
+                    net: 100,
+                },
+                ConnectFeeTotal {
+                    currency: "usd".to_string(),
+                    count: 2,
+                    gross: 500,
+                    refunded: 50,
+                    net: 450,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_onboarding_requires_connect() {
+        let state = Arc::new(AdminState {
+            store: Arc::new(InMemoryStore::new()),
+            product_repo: Arc::new(InMemoryProductRepository::new(Vec::new())),
+            coupon_repo: Arc::new(InMemoryCouponRepository::new(Vec::new())),
+            stripe_client: None,
+            messaging: None,
+        });
+
+        let resp = create_onboarding_link(
+            State(state),
+            TenantContext::default(),
+            Json(CreateOnboardingLinkRequest::default()),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
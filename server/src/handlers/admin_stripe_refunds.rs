@@ -212,6 +212,12 @@ pub async fn process_stripe_refund(
             if let Err(e) = state.store.store_stripe_refund_request(req.clone()).await {
                 tracing::error!(error = %e, refund_request_id = %refund_request_id, "Failed to persist processed refund request");
             }
+            crate::services::ledger::post_stripe_refund(&*state.store, &req).await;
+            crate::services::paywall::service::reverse_stripe_refund_commission(
+                &*state.store,
+                &req,
+            )
+            .await;
 
             let info = StripeRefundInfo {
                 id: req.id.clone(),
@@ -282,6 +288,7 @@ mod tests {
             product_repo,
             coupon_repo,
             stripe_client: None,
+            messaging: None,
         });
 
         let resp = list_stripe_refunds(
@@ -306,6 +313,7 @@ mod tests {
             product_repo,
             coupon_repo,
             stripe_client: None,
+            messaging: None,
         });
 
         let resp = process_stripe_refund(
@@ -12,17 +12,18 @@ use axum::{
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{error_response, ErrorCode};
-use crate::handlers::admin::{audit, AdminState};
+use crate::handlers::admin::{audit, queue_embedding, AdminState};
 use crate::handlers::response::{json_error, json_ok};
 use crate::middleware::TenantContext;
 #[cfg(test)]
 use crate::models::VariationType;
 use crate::models::{ProductVariant, ProductVariationConfig, VariationValue};
+use crate::storage::EmbeddingEntity;
 
 /// Limits for variation configuration
 const MAX_VARIATION_TYPES: usize = 5;
 const MAX_VALUES_PER_TYPE: usize = 20;
-const MAX_VARIANTS: usize = 100;
+pub(crate) const MAX_VARIANTS: usize = 100;
 
 // ============================================================================
 // Request/Response Types
@@ -219,6 +220,13 @@ pub async fn update_variations(
                 None,
             )
             .await;
+            queue_embedding(
+                &*state.store,
+                &tenant.tenant_id,
+                EmbeddingEntity::Product,
+                &product_id,
+            )
+            .await;
             let response = UpdateVariationsResponse {
                 success: true,
                 message: format!(
@@ -343,7 +351,7 @@ pub async fn bulk_update_inventory(
 // ============================================================================
 
 /// Validate variation config against limits
-fn validate_variation_config(config: &ProductVariationConfig) -> Result<(), String> {
+pub(crate) fn validate_variation_config(config: &ProductVariationConfig) -> Result<(), String> {
     if config.variation_types.len() > MAX_VARIATION_TYPES {
         return Err(format!(
             "Too many variation types: {} exceeds limit of {}",
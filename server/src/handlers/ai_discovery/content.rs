@@ -334,6 +334,18 @@ X-Signature: <base58-signature>
 | POST | {prefix}/paywall/v1/asset-redemption/{{productId}}/submit | Submit |
 | GET | {prefix}/paywall/v1/asset-redemption/{{productId}}/status | Status |
 
+### Customer Account
+
+Requires `Authorization: Bearer <cedros-login JWT>`.
+
+| Method | Path | Description |
+|--------|------|-------------|
+| GET | {prefix}/paywall/v1/account/orders | List my orders |
+| GET | {prefix}/paywall/v1/account/orders/{{orderId}} | Order detail, tracking, returns |
+| POST | {prefix}/paywall/v1/account/orders/{{orderId}}/returns | Request a return |
+| GET | {prefix}/paywall/v1/account/addresses | List saved addresses |
+| PUT | {prefix}/paywall/v1/account/addresses | Replace saved addresses |
+
 ### Chat (AI Assistant)
 
 | Method | Path | Description |
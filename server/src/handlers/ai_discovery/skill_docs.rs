@@ -119,11 +119,14 @@ Content-Type: application/json
   "items": [
     {"productId": "prod_123", "variantId": "var_456", "quantity": 1}
   ],
-  "couponCode": "SAVE10"
+  "couponCode": "SAVE10",
+  "referralCode": "PARTNER10"
 }
 ```
 
 Response includes `cartId`, line items, subtotal, total, and payment options.
+`referralCode` (e.g. from a `?ref=PARTNER10` link) attributes the order to an affiliate;
+unknown codes are ignored.
 
 ## Get Cart
 
@@ -246,6 +249,10 @@ POST /paywall/v1/quote
 ```
 → Returns HTTP 402 with x402 payment header.
 
+If the quote's `extra.splits` is non-empty (revenue-split product, or a cart containing one), the
+transaction must contain one `TransferChecked` per leg — each to the leg's `recipientTokenAccount`
+for its `amountAtomic` — instead of a single transfer to `payTo`. Gasless transactions include all legs automatically.
+
 After payment:
 ```
 POST /paywall/v1/verify
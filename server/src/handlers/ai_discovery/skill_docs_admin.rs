@@ -37,6 +37,12 @@ Authorization: Bearer <admin-jwt>
 | POST | /admin/products | Create product |
 | PUT | /admin/products/{id} | Update product |
 | DELETE | /admin/products/{id} | Delete product |
+| POST | /admin/products/import | Bulk import products (CSV/JSON, `dryRun`) |
+| GET | /admin/products/import/{jobId} | Import job progress and errors |
+| GET | /admin/products/export | Export catalog (CSV/JSON) |
+| POST | /admin/products/{id}/price-schedules | Schedule a price change or sale |
+| GET | /admin/products/{id}/price-schedules | List price schedules |
+| POST | /admin/products/{id}/price-schedules/{scheduleId}/cancel | Cancel a schedule (reverts an active one) |
 
 ## Product Variations
 
@@ -71,6 +77,54 @@ Authorization: Bearer <admin-jwt>
 | PUT | /admin/collections/{id} | Update collection |
 | DELETE | /admin/collections/{id} | Delete collection |
 
+Products and collections accept an optional `paymentSplit` for x402 revenue sharing:
+`{ "platformFeeBps": 500, "sellerWallet": "<owner>", "affiliateWallet": "<owner>", "affiliateBps": 1000 }`.
+A product's own split wins over its collection's. Quotes then list one leg per recipient in
+`extra.splits`; refund quotes for split purchases report each leg's share in `extra.splitLegs`.
+
+## Affiliates
+
+| Method | Path | Description |
+|--------|------|-------------|
+| GET | /admin/affiliates | List affiliates |
+| POST | /admin/affiliates | Create affiliate |
+| GET | /admin/affiliates/payouts | Net commission owed per affiliate (`from`, `to`) |
+| GET | /admin/affiliates/{id} | Get affiliate |
+| PUT | /admin/affiliates/{id} | Update affiliate |
+| GET | /admin/affiliates/{id}/commissions | Commission ledger (`from`, `to`) |
+
+Affiliates have a referral `code`, a default `commissionType` (`percentage` in bps or `fixed`
+atomic amount per unit) with `commissionValue`, and optional `rules` overriding it per
+`productId` or `collectionId`. Cart quotes with a valid `referralCode` record `affiliate_id`
+on the order; commission is earned when the cart is paid and reversed pro-rata by x402 refunds.
+
+## Reports
+
+| Method | Path | Description |
+|--------|------|-------------|
+| GET | /admin/reports/financial | Sales, refunds, disputes and net per period (`from`, `to`, `period`, `groupBy`, `format`: `json` or `csv`) |
+
+`period` is `day`, `week` (Monday start, UTC) or `month`; `groupBy` is `rail`, `product`,
+`collection` or `tenant`. Amounts are atomic units per currency. Scheduled delivery is set with the
+`reports.financial_schedule` config entry:
+`{ "enabled": true, "frequency": "week", "recipients": ["finance@example.com"], "groupBy": "rail" }`.
+
+## Ledger
+
+| Method | Path | Description |
+|--------|------|-------------|
+| GET | /admin/ledger/entries | Journal entries (`from`, `to`, `limit`, `offset`, `format`: `json` or `csv`) |
+| POST | /admin/ledger/entries | Post a balanced manual entry (`reference`, `currency`, `lines`) |
+| GET | /admin/ledger/trial-balance | Debit/credit totals per account and currency (`asOf`) |
+
+Payments, refunds, Stripe refunds, gift card issuance/adjustments/redemptions, Stripe application
+fees and credits holds (placed, captured, released) post append-only double-entry entries
+automatically. Accounts: `receivable:{rail}` (`x402`, `stripe`, `credits`), `revenue`, `tax_payable`,
+`gift_card_liability`, `refunds`, `fees`, `fee_income`, `promotions`, `equity`, `credits_held`,
+`credits_hold_clearing`. Token-22 fee harvests are not posted; record them as manual entries
+(`receivable:token22` / `fee_income`). Lines are `{ "account": "...", "debit": 0, "credit": 0 }`
+in atomic units.
+
 ## Orders
 
 | Method | Path | Description |
@@ -99,6 +153,8 @@ Authorization: Bearer <admin-jwt>
 | POST | /admin/returns | Create return |
 | POST | /admin/returns/{id}/status | Update return status |
 
+Status flow: `requested` → `approved` (prices the refund and restocking fee) → `received` (restocks resellable items) → `refunded` (issues a Stripe refund request, x402 refund quote or credits refund). Line items accept `condition` and `reasonCode`; merchant-fault reason codes waive the restocking fee.
+
 ## Disputes
 
 | Method | Path | Description |
@@ -202,6 +258,8 @@ Authorization: Bearer <admin-jwt>
 | DELETE | /admin/config/ai/api-key/{provider} | Delete AI API key |
 | PUT | /admin/config/ai/assignment | Save AI assignment |
 | PUT | /admin/config/ai/prompt | Save AI prompt |
+| PUT | /admin/config/ai/budget | Save AI monthly budget |
+| GET | /admin/ai/usage | Get AI token usage and cost |
 
 ## AI Assistants
 
@@ -260,6 +318,19 @@ Authorization: Bearer <admin-jwt>
 | GET | /admin/credits/refund-requests | List credit refund requests |
 | GET | /admin/transactions | List transactions |
 
+## Stripe Connect
+
+| Method | Path | Description |
+|--------|------|-------------|
+| GET | /admin/stripe/connect | Connected account status (`?refresh=true` re-syncs from Stripe) |
+| POST | /admin/stripe/connect/onboarding | Create account if missing and return onboarding link |
+| PUT | /admin/stripe/connect/fee | Set per-account application fee override (bps) |
+| GET | /admin/stripe/connect/fees | Application fees with per-currency totals (`from`, `to`) |
+
+Once the account can accept charges, checkout sessions for the tenant become destination charges
+(plus `on_behalf_of` when `stripe.connect.charge_type = "on_behalf_of"`) with the platform fee
+withheld. Subscribe the webhook endpoint to `account.updated` and `application_fee.*`.
+
 ## Audit & Stats
 
 | Method | Path | Description |
@@ -16,7 +16,10 @@ use crate::handlers::paywall::{AcceptEntry, AppState};
 use crate::handlers::verify::{convert_metadata, decode_x_payment_header, X402PaymentHeader};
 use crate::middleware::tenant::TenantContext;
 use crate::models::PaymentProof;
-use crate::services::paywall::service::CartQuoteItemInput;
+use crate::services::paywall::service::{
+    CartQuoteItemInput, PAYMENT_SPLIT_LEGS_METADATA_KEY, QUOTED_FIAT_CENTS_METADATA_KEY,
+    QUOTED_STRIPE_PRICE_METADATA_KEY,
+};
 use crate::storage::Store;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -41,6 +44,8 @@ pub struct CartQuoteRequest {
     pub metadata: Option<serde_json::Value>,
     pub coupon_code: Option<String>,
     pub gift_card_code: Option<String>,
+    /// Affiliate referral code (e.g. from a `?ref=CODE` link)
+    pub referral_code: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -256,11 +261,17 @@ pub async fn cart_quote<S: Store + 'static>(
         }
     }
 
-    let cart_metadata = req
+    let mut cart_metadata = req
         .metadata
         .as_ref()
         .map(convert_metadata)
         .unwrap_or_default();
+    if let Some(ref code) = req.referral_code {
+        cart_metadata.insert(
+            crate::services::paywall::service::REFERRAL_CODE_METADATA_KEY.to_string(),
+            code.clone(),
+        );
+    }
     let items: Vec<CartQuoteItemInput> = req
         .items
         .iter()
@@ -306,6 +317,20 @@ pub async fn cart_quote<S: Store + 'static>(
 
             // Build AcceptEntry for x402 payment
             let cfg = &state.paywall_service.config;
+            let mut extra = serde_json::json!({
+                "recipientTokenAccount": cfg.x402.payment_address,
+                "decimals": cfg.x402.token_decimals,
+                "tokenSymbol": cfg.x402.token_symbol,
+                "memo": format!("{}cart:{}", cfg.x402.memo_prefix, cart_quote.id)
+            });
+            // Carts holding split products must pay one transfer per leg
+            if let Some(legs) = cart_quote
+                .metadata
+                .get(PAYMENT_SPLIT_LEGS_METADATA_KEY)
+                .and_then(|raw| serde_json::from_str::<serde_json::Value>(raw).ok())
+            {
+                extra["splits"] = legs;
+            }
             let accept_entry = AcceptEntry {
                 scheme: "solana-spl-transfer".to_string(),
                 network: cfg.x402.network.clone(),
@@ -320,12 +345,7 @@ pub async fn cart_quote<S: Store + 'static>(
                 pay_to: cfg.x402.payment_address.clone(),
                 max_timeout_seconds: Some(cfg.storage.cart_quote_ttl.as_secs() as i64),
                 asset: cfg.x402.token_mint.clone(),
-                extra: Some(serde_json::json!({
-                    "recipientTokenAccount": cfg.x402.payment_address,
-                    "decimals": cfg.x402.token_decimals,
-                    "tokenSymbol": cfg.x402.token_symbol,
-                    "memo": format!("{}cart:{}", cfg.x402.memo_prefix, cart_quote.id)
-                })),
+                extra: Some(extra),
             };
 
             // Build credits option if credits are configured and enabled
@@ -392,7 +412,7 @@ pub async fn cart_checkout<S: Store + 'static>(
     }
 
     // Ensure cart quote exists and is not expired.
-    match state
+    let cart = match state
         .store
         .get_cart_quote(&tenant.tenant_id, &req.cart_id)
         .await
@@ -406,6 +426,7 @@ pub async fn cart_checkout<S: Store + 'static>(
                 );
                 return json_error(status, body);
             }
+            cart
         }
         Ok(None) => {
             let (status, body) = error_response(
@@ -423,7 +444,21 @@ pub async fn cart_checkout<S: Store + 'static>(
             );
             return json_error(status, body);
         }
-    }
+    };
+    // Products keep the Stripe price they were quoted at until the quote
+    // expires, even when a scheduled price change has applied since
+    let quoted_prices: HashMap<&str, (&str, Option<i64>)> = cart
+        .items
+        .iter()
+        .filter_map(|item| {
+            let price_id = item.metadata.get(QUOTED_STRIPE_PRICE_METADATA_KEY)?;
+            let cents = item
+                .metadata
+                .get(QUOTED_FIAT_CENTS_METADATA_KEY)
+                .and_then(|c| c.parse().ok());
+            Some((item.resource_id.as_str(), (price_id.as_str(), cents)))
+        })
+        .collect();
 
     // Validate email if provided
     if let Some(ref email) = req.customer_email {
@@ -610,15 +645,34 @@ pub async fn cart_checkout<S: Store + 'static>(
         Ok(Some(countries))
     };
 
+    // Expected total after the quote's coupons; used for Stripe Connect
+    // application fees. Unknown when any product lacks a fiat price.
+    let mut expected_total_cents: Option<i64> = Some(0);
+
     for item in &req.items {
         let price_id = match &item.price_id {
             Some(id) => {
+                // A price quoted before a scheduled change no longer maps to
+                // its product, so look that product up by the quoted resource.
+                let quoted = quoted_prices
+                    .iter()
+                    .find(|(_, (price_id, _))| *price_id == id.as_str());
+                let lookup = match quoted {
+                    Some((resource, _)) => {
+                        state
+                            .product_repo
+                            .get_product(&tenant.tenant_id, resource)
+                            .await
+                    }
+                    None => {
+                        state
+                            .product_repo
+                            .get_product_by_stripe_price_id(&tenant.tenant_id, id)
+                            .await
+                    }
+                };
                 // Validate the Stripe price maps to an active product for this tenant.
-                match state
-                    .product_repo
-                    .get_product_by_stripe_price_id(&tenant.tenant_id, id)
-                    .await
-                {
+                match lookup {
                     Ok(p) => {
                         if !p.active {
                             let (status, body) = error_response(
@@ -681,6 +735,20 @@ pub async fn cart_checkout<S: Store + 'static>(
                                 || matches!(reqs.name.as_deref(), Some("required"));
                             phone_required |= matches!(reqs.phone.as_deref(), Some("required"));
                         }
+
+                        let unit_cents = quoted
+                            .and_then(|(_, (_, cents))| *cents)
+                            .or_else(|| p.fiat_price.as_ref().map(|m| m.atomic));
+                        let line_cents = unit_cents.map(|c| {
+                            discounted_line_cents(
+                                &cart,
+                                &p.id,
+                                item.variant_id.as_deref(),
+                                c * i64::from(item.quantity),
+                            )
+                        });
+                        expected_total_cents =
+                            expected_total_cents.and_then(|t| line_cents.map(|c| t + c));
                     }
                     Err(_) => {
                         let (status, body) = error_response(
@@ -766,8 +834,26 @@ pub async fn cart_checkout<S: Store + 'static>(
                             phone_required |= matches!(reqs.phone.as_deref(), Some("required"));
                         }
 
-                        match &product.stripe_price_id {
-                            Some(id) => id.clone(),
+                        let quoted = quoted_prices.get(resource);
+                        let unit_cents = quoted
+                            .and_then(|(_, cents)| *cents)
+                            .or_else(|| product.fiat_price.as_ref().map(|m| m.atomic));
+                        let line_cents = unit_cents.map(|c| {
+                            discounted_line_cents(
+                                &cart,
+                                resource,
+                                item.variant_id.as_deref(),
+                                c * i64::from(item.quantity),
+                            )
+                        });
+                        expected_total_cents =
+                            expected_total_cents.and_then(|t| line_cents.map(|c| t + c));
+
+                        match quoted
+                            .map(|(id, _)| id.to_string())
+                            .or_else(|| product.stripe_price_id.clone())
+                        {
+                            Some(id) => id,
                             None => {
                                 let (status, body) = error_response(
                                     ErrorCode::InvalidResource,
@@ -837,6 +923,7 @@ pub async fn cart_checkout<S: Store + 'static>(
         cancel_url: req.cancel_url.clone(),
         coupon_code: req.coupon_code.clone(),
         stripe_coupon_id: None,
+        expected_total_cents,
     };
 
     // Create checkout session
@@ -858,6 +945,43 @@ pub async fn cart_checkout<S: Store + 'static>(
     }
 }
 
+/// Fiat cents a line charges after the cart quote's discounts: the line's
+/// catalog coupons, then the cart's checkout coupons. Lines missing from the
+/// quote are charged at list price.
+fn discounted_line_cents(
+    cart: &crate::models::CartQuote,
+    resource: &str,
+    variant_id: Option<&str>,
+    list_cents: i64,
+) -> i64 {
+    let Some(line) = cart
+        .items
+        .iter()
+        .find(|i| i.resource_id == resource && i.variant_id.as_deref() == variant_id)
+        .or_else(|| cart.items.iter().find(|i| i.resource_id == resource))
+    else {
+        return list_cents;
+    };
+
+    let mut cents = i128::from(list_cents);
+    if let Some(original) = line.original_price.as_ref().filter(|o| o.atomic > 0) {
+        cents = cents * i128::from(line.price.atomic) / i128::from(original.atomic);
+    }
+    // Checkout coupons discount the subtotal of the discounted lines. A gift
+    // card pays part of the total rather than discounting it.
+    let subtotal: i128 = cart.items.iter().map(|i| i128::from(i.price.atomic)).sum();
+    let gift_card: i128 = cart
+        .metadata
+        .get("gift_card_applied_amount")
+        .and_then(|a| a.parse().ok())
+        .unwrap_or(0);
+    let after_coupons = i128::from(cart.total.atomic) + gift_card;
+    if subtotal > 0 && after_coupons < subtotal {
+        cents = cents * after_coupons / subtotal;
+    }
+    i64::try_from(cents).unwrap_or(list_cents)
+}
+
 /// GET /paywall/v1/cart/{cartId} - Get cart status
 /// Per spec (08-storage.md): Query filters by tenant_id for isolation
 pub async fn get_cart<S: Store + 'static>(
@@ -1873,6 +1997,7 @@ mod tests {
             metadata: Some(serde_json::json!({"cart_key": "cart_value"})),
             coupon_code: None,
             gift_card_code: None,
+            referral_code: None,
         };
 
         let response = cart_quote(State(state.clone()), tenant, Json(req))
@@ -1900,4 +2025,100 @@ mod tests {
             Some(&"item_value".to_string())
         );
     }
+
+    #[tokio::test]
+    async fn test_cart_quote_records_quoted_stripe_price() {
+        let asset = crate::models::get_asset("USDC").expect("asset");
+        let product = crate::models::Product {
+            id: "product-1".to_string(),
+            tenant_id: "default".to_string(),
+            crypto_price: Some(Money::new(asset, 100)),
+            fiat_price: Some(Money::new(
+                crate::models::get_asset("USD").expect("USD"),
+                1000,
+            )),
+            stripe_price_id: Some("price_regular".to_string()),
+            active: true,
+            ..Default::default()
+        };
+        let state = build_state_with_products(vec![product]);
+        let req = CartQuoteRequest {
+            items: vec![CartItem {
+                resource: "product-1".to_string(),
+                variant_id: None,
+                quantity: 1,
+                // Client-supplied quoted prices are ignored
+                metadata: Some(serde_json::json!({
+                    QUOTED_STRIPE_PRICE_METADATA_KEY: "price_cheap",
+                    QUOTED_FIAT_CENTS_METADATA_KEY: "1",
+                })),
+            }],
+            metadata: None,
+            coupon_code: None,
+            gift_card_code: None,
+            referral_code: None,
+        };
+
+        let response = cart_quote(State(state.clone()), TenantContext::default(), Json(req))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let cart_id = json["cartId"].as_str().expect("cartId");
+
+        let stored = state
+            .store
+            .get_cart_quote("default", cart_id)
+            .await
+            .unwrap()
+            .expect("stored cart");
+        let metadata = &stored.items[0].metadata;
+        assert_eq!(
+            metadata.get(QUOTED_STRIPE_PRICE_METADATA_KEY),
+            Some(&"price_regular".to_string())
+        );
+        assert_eq!(
+            metadata.get(QUOTED_FIAT_CENTS_METADATA_KEY),
+            Some(&"1000".to_string())
+        );
+    }
+
+    #[test]
+    fn test_discounted_line_cents_applies_quote_coupons() {
+        let usdc = crate::models::get_asset("USDC").expect("asset");
+        let line = |resource: &str, price: i64, original: Option<i64>| crate::models::CartItem {
+            resource_id: resource.to_string(),
+            variant_id: None,
+            quantity: 1,
+            price: Money::new(usdc.clone(), price),
+            original_price: original.map(|o| Money::new(usdc.clone(), o)),
+            description: None,
+            applied_coupons: Vec::new(),
+            metadata: HashMap::new(),
+        };
+        // "a" has a 20% catalog coupon; a checkout coupon then takes 10% off
+        // the 18 USDC subtotal
+        let cart = crate::models::CartQuote {
+            items: vec![
+                line("a", 8_000_000, Some(10_000_000)),
+                line("b", 10_000_000, None),
+            ],
+            total: Money::new(usdc.clone(), 16_200_000),
+            ..Default::default()
+        };
+
+        assert_eq!(discounted_line_cents(&cart, "a", None, 1000), 720);
+        assert_eq!(discounted_line_cents(&cart, "b", None, 1000), 900);
+        assert_eq!(discounted_line_cents(&cart, "missing", None, 1000), 1000);
+
+        // A gift card is a payment, not a discount
+        let mut with_gift_card = cart.clone();
+        with_gift_card.total = Money::new(usdc, 0);
+        with_gift_card.metadata.insert(
+            "gift_card_applied_amount".to_string(),
+            "16200000".to_string(),
+        );
+        assert_eq!(discounted_line_cents(&with_gift_card, "a", None, 1000), 720);
+    }
 }
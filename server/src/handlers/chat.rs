@@ -1,26 +1,41 @@
 //! Public chat endpoint for customer-facing AI assistant.
 //!
 //! Provides conversational AI with tool calling for product search.
+//! Sessions handed off to a human (`waiting_for_agent` / `with_agent`) skip
+//! the assistant; customers long-poll `GET /chat/{session_id}/messages` for
+//! agent replies.
+//!
+//! A valid cedros-login `Authorization` header binds the session to that
+//! customer and unlocks the account tools (order lookup, returns,
+//! subscriptions). A bound session is only visible to the same customer.
 
 use std::sync::Arc;
 
-use axum::{extract::State, response::IntoResponse, Json};
-use chrono::Utc;
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
 
-use super::response::json_error;
-use crate::config::PostgresConfigRepository;
-use crate::errors::{error_response, ErrorCode};
+use super::response::{event_stream, json_error, sse_event, wants_event_stream};
+use crate::config::{PostgresConfigRepository, ShopReturnsConfig};
+use crate::errors::{error_response, ErrorCode, ErrorResponse};
 use crate::handlers::admin_ai::AiTask;
-use crate::handlers::admin_ai::{AiModel, AiProvider};
 use crate::handlers::admin_ai_assistant::{AiRateLimiter, ProductMatch};
 use crate::middleware::tenant::TenantContext;
-use crate::models::{ChatMessage, ChatSession};
+use crate::models::chat::{role, status as chat_status};
+use crate::models::{ChatMessage, ChatSession, Faq, Product};
 use crate::observability::record_ai_rate_limit_rejection;
 use crate::repositories::ProductRepository;
+use crate::services::ai::{CustomerToolContext, HandoffError, SearchToolContext};
 use crate::services::{
-    AiError, AiService, ChatOrchestrator, FactFinderConfig, FaqMatch, DEFAULT_CHAT_SYSTEM_PROMPT,
-    DEFAULT_FACT_FINDER_PROMPT,
+    AiEndpoint, AiError, AiService, CedrosLoginClient, ChatHandoffService, ChatOrchestrator,
+    ChatResult, ChatStreamEvent, FactFinderConfig, FaqMatch, SemanticSearch,
+    DEFAULT_CHAT_SYSTEM_PROMPT, DEFAULT_FACT_FINDER_PROMPT,
 };
 use crate::storage::Store;
 
@@ -35,6 +50,13 @@ pub struct ChatState {
     pub product_repo: Arc<dyn ProductRepository>,
     pub orchestrator: ChatOrchestrator,
     pub rate_limiter: AiRateLimiter,
+    pub handoff: Arc<ChatHandoffService>,
+    /// Verifies customer tokens; account tools are disabled without it
+    pub cedros_login: Option<Arc<CedrosLoginClient>>,
+    /// Return policy applied by the start_return tool
+    pub returns: ShopReturnsConfig,
+    /// Hybrid keyword + embedding search behind product_search and fact_finder
+    pub search: Arc<SemanticSearch>,
 }
 
 impl ChatState {
@@ -44,13 +66,24 @@ impl ChatState {
         product_repo: Arc<dyn ProductRepository>,
         ai_service: Arc<AiService>,
         rate_limiter: AiRateLimiter,
+        handoff: Arc<ChatHandoffService>,
+        cedros_login: Option<Arc<CedrosLoginClient>>,
+        returns: ShopReturnsConfig,
     ) -> Self {
+        let search = Arc::new(
+            SemanticSearch::new(store.clone(), product_repo.clone())
+                .with_embeddings(config_repo.clone(), ai_service.clone()),
+        );
         Self {
             store,
             config_repo,
             product_repo,
             orchestrator: ChatOrchestrator::new(ai_service),
             rate_limiter,
+            handoff,
+            cedros_login,
+            returns,
+            search,
         }
     }
 }
@@ -87,6 +120,50 @@ pub struct ChatResponse {
     /// Actions taken (for future extensibility)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub actions: Vec<String>,
+    /// Session status; "waiting_for_agent" / "with_agent" mean a human answers
+    /// and `message` is empty
+    pub status: String,
+}
+
+/// POST /chat/{session_id}/handoff request
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HandoffRequest {
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// GET /chat/{session_id}/messages query
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatMessagesQuery {
+    /// Only return messages created after this time
+    #[serde(default)]
+    pub since: Option<DateTime<Utc>>,
+    /// Long-poll for up to this many seconds when nothing is new
+    #[serde(default)]
+    pub wait_secs: Option<u64>,
+}
+
+/// Longest a customer long-poll may wait
+const MAX_WAIT_SECS: u64 = 30;
+
+/// A message as seen by the customer
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerChatMessage {
+    pub id: String,
+    pub role: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// GET /chat/{session_id}/messages response
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatMessagesResponse {
+    pub status: String,
+    pub messages: Vec<CustomerChatMessage>,
 }
 
 // ============================================================================
@@ -94,9 +171,13 @@ pub struct ChatResponse {
 // ============================================================================
 
 /// POST /chat - Public chat endpoint
+///
+/// With `Accept: text/event-stream` the reply is streamed as server-sent events
+/// (`delta`, `tool_call`, `tool_result`, then `done` or `error`).
 pub async fn chat(
     State(state): State<Arc<ChatState>>,
     tenant: TenantContext,
+    headers: HeaderMap,
     Json(request): Json<ChatRequest>,
 ) -> impl IntoResponse {
     // Validate message
@@ -122,39 +203,134 @@ pub async fn chat(
     }
 
     // Load or create session
-    let (session, _is_new) = match load_or_create_session(&state, &tenant.tenant_id, &request).await
-    {
-        Ok(result) => result,
-        Err(e) => {
-            tracing::error!(error = %e, "Failed to load/create session");
+    let customer_id = verified_customer(&state, &headers).await;
+    let (session, _is_new) =
+        match load_or_create_session(&state, &tenant.tenant_id, &request, customer_id.as_deref())
+            .await
+        {
+            Ok(Some(result)) => result,
+            Ok(None) => return handoff_error(HandoffError::NotFound).into_response(),
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to load/create session");
+                let (status, body) = error_response(
+                    ErrorCode::InternalError,
+                    Some("Failed to manage chat session".into()),
+                    None,
+                );
+                return json_error(status, body).into_response();
+            }
+        };
+
+    let stream = wants_event_stream(&headers);
+
+    // A human is handling this session: store the message for them and skip the assistant
+    if session.is_handed_off() {
+        if let Err(e) = state.handoff.post_customer_message(&session, message).await {
+            tracing::error!(error = %e, "Failed to save user message");
             let (status, body) = error_response(
                 ErrorCode::InternalError,
-                Some("Failed to manage chat session".into()),
+                Some("Failed to save message".into()),
                 None,
             );
             return json_error(status, body).into_response();
         }
+        let response = ChatResponse {
+            session_id: session.id,
+            message: String::new(),
+            products: vec![],
+            faqs: vec![],
+            actions: vec![],
+            status: session.status,
+        };
+        if stream {
+            let (tx, rx) = mpsc::unbounded_channel();
+            let _ = tx.send(sse_event("done", &response));
+            return event_stream(rx);
+        }
+        return Json(response).into_response();
+    }
+
+    let turn = match prepare_turn(&state, &tenant.tenant_id, session, message).await {
+        Ok(turn) => turn,
+        Err(response) => return response,
     };
 
+    if stream {
+        return stream_turn(state, turn);
+    }
+
+    // Process the message
+    let result = state
+        .orchestrator
+        .process_message(
+            &turn.endpoint,
+            Some(&turn.system_prompt),
+            &turn.history,
+            &turn.message,
+            &turn.products,
+            &turn.faqs,
+            turn.fact_finder_config.as_ref(),
+            turn.customer.as_ref(),
+            Some(&turn.search),
+        )
+        .await;
+
+    match result {
+        Ok(chat_result) => Json(finish_turn(&state, turn, chat_result).await).into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Chat processing failed");
+            let (status, body) = chat_error(&e);
+            json_error(status, body).into_response()
+        }
+    }
+}
+
+/// Everything the orchestrator needs for one assistant turn
+struct ChatTurn {
+    tenant_id: String,
+    session: ChatSession,
+    message: String,
+    endpoint: AiEndpoint,
+    system_prompt: String,
+    history: Vec<ChatMessage>,
+    products: Vec<Product>,
+    faqs: Vec<Faq>,
+    fact_finder_config: Option<FactFinderConfig>,
+    customer: Option<CustomerToolContext>,
+    search: SearchToolContext,
+}
+
+/// Load AI config and context for a turn and persist the user's message
+async fn prepare_turn(
+    state: &ChatState,
+    tenant_id: &str,
+    session: ChatSession,
+    message: &str,
+) -> Result<ChatTurn, Response> {
     // Load AI config
-    let (provider, model, api_key) =
-        match load_ai_config(&state.config_repo, &tenant.tenant_id).await {
-            Ok(cfg) => cfg,
-            Err(e) => {
-                tracing::warn!(error = %e, "AI not configured for chat");
-                let (status, body) = error_response(
-                    ErrorCode::ConfigError,
-                    Some(format!("Chat not configured: {}", e)),
-                    None,
-                );
-                return json_error(status, body).into_response();
-            }
-        };
+    let endpoint = match load_ai_config(
+        &state.config_repo,
+        state.orchestrator.ai_service(),
+        tenant_id,
+    )
+    .await
+    {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            tracing::warn!(error = %e, "AI not configured for chat");
+            let (status, body) = error_response(
+                ErrorCode::ConfigError,
+                Some(format!("Chat not configured: {}", e)),
+                None,
+            );
+            return Err(json_error(status, body).into_response());
+        }
+    };
 
     // Load conversation history
     let history = match state
         .store
-        .list_chat_messages(&tenant.tenant_id, &session.id, 20, 0)
+        .list_chat_messages(tenant_id, &session.id, 20, 0)
         .await
     {
         Ok(msgs) => msgs,
@@ -166,10 +342,14 @@ pub async fn chat(
 
     // Load products, FAQs, prompts, and fact_finder config in parallel
     let (products_result, faqs_result, system_prompt, fact_finder_config) = tokio::join!(
-        state.product_repo.list_products(&tenant.tenant_id),
-        state.store.search_faqs(&tenant.tenant_id, "", 100),
-        load_prompt(&state.config_repo, &tenant.tenant_id),
-        load_fact_finder_config(&state.config_repo, &tenant.tenant_id),
+        state.product_repo.list_products(tenant_id),
+        state.store.search_faqs(tenant_id, "", 100),
+        load_prompt(&state.config_repo, tenant_id),
+        load_fact_finder_config(
+            &state.config_repo,
+            state.orchestrator.ai_service(),
+            tenant_id
+        ),
     );
 
     let products = match products_result {
@@ -188,9 +368,20 @@ pub async fn chat(
         }
     };
 
+    let customer = session
+        .customer_id
+        .clone()
+        .map(|customer_id| CustomerToolContext {
+            tenant_id: tenant_id.to_string(),
+            customer_id,
+            store: state.store.clone(),
+            products: state.product_repo.clone(),
+            returns: state.returns.clone(),
+        });
+
     // Save user message first
     let user_msg = ChatMessage::user(
-        tenant.tenant_id.clone(),
+        tenant_id.to_string(),
         session.id.clone(),
         generate_id(),
         message.to_string(),
@@ -201,124 +392,363 @@ pub async fn chat(
         // Continue anyway - don't fail the chat
     }
 
-    // Process the message
-    let result = state
-        .orchestrator
-        .process_message(
-            provider,
-            model,
-            &api_key,
-            Some(&system_prompt),
-            &history,
-            message,
-            &products,
-            &faqs,
-            fact_finder_config.as_ref(),
-        )
-        .await;
+    Ok(ChatTurn {
+        tenant_id: tenant_id.to_string(),
+        session,
+        message: message.to_string(),
+        endpoint,
+        system_prompt,
+        history,
+        products,
+        faqs,
+        fact_finder_config,
+        customer,
+        search: SearchToolContext {
+            tenant_id: tenant_id.to_string(),
+            search: state.search.clone(),
+        },
+    })
+}
 
-    match result {
-        Ok(chat_result) => {
-            // Save assistant message
-            let tool_results = if chat_result.products.is_empty() && chat_result.faqs.is_empty() {
-                None
-            } else {
-                Some(serde_json::json!({
-                    "products": chat_result.products,
-                    "faqs": chat_result.faqs,
-                    "actions": chat_result.actions,
-                }))
-            };
-
-            let assistant_msg = ChatMessage::assistant(
-                tenant.tenant_id.clone(),
-                session.id.clone(),
-                generate_id(),
-                chat_result.message.clone(),
-                tool_results,
-            );
+/// Persist the assistant reply, update the session and apply any handoff
+async fn finish_turn(state: &ChatState, turn: ChatTurn, chat_result: ChatResult) -> ChatResponse {
+    let ChatTurn {
+        tenant_id, session, ..
+    } = turn;
+
+    // Save assistant message
+    let tool_results = if chat_result.products.is_empty() && chat_result.faqs.is_empty() {
+        None
+    } else {
+        Some(serde_json::json!({
+            "products": chat_result.products,
+            "faqs": chat_result.faqs,
+            "actions": chat_result.actions,
+        }))
+    };
 
-            if let Err(e) = state.store.create_chat_message(assistant_msg).await {
-                tracing::error!(error = %e, "Failed to save assistant message");
-            }
+    let assistant_msg = ChatMessage::assistant(
+        tenant_id.clone(),
+        session.id.clone(),
+        generate_id(),
+        chat_result.message.clone(),
+        tool_results,
+    );
 
-            // Update session
-            let now = Utc::now();
-            let new_count = session.message_count + 2; // user + assistant
-            if let Err(e) = state
-                .store
-                .update_chat_session(&tenant.tenant_id, &session.id, new_count, now, now)
-                .await
-            {
-                tracing::error!(error = %e, "Failed to update session");
-            }
+    if let Err(e) = state.store.create_chat_message(assistant_msg).await {
+        tracing::error!(error = %e, "Failed to save assistant message");
+    }
 
-            Json(ChatResponse {
-                session_id: session.id,
-                message: chat_result.message,
-                products: chat_result.products,
-                faqs: chat_result.faqs,
-                actions: chat_result.actions,
-            })
-            .into_response()
+    // Update session
+    let now = Utc::now();
+    let new_count = session.message_count + 2; // user + assistant
+    if let Err(e) = state
+        .store
+        .update_chat_session(&tenant_id, &session.id, new_count, now, now)
+        .await
+    {
+        tracing::error!(error = %e, "Failed to update session");
+    }
+
+    let mut status = session.status.clone();
+    if chat_result.handoff_requested {
+        match state
+            .handoff
+            .request_handoff(
+                &tenant_id,
+                &session.id,
+                chat_result.handoff_reason.as_deref(),
+                "assistant",
+            )
+            .await
+        {
+            Ok(_) => status = chat_status::WAITING_FOR_AGENT.to_string(),
+            Err(e) => tracing::error!(error = %e, "Failed to request chat handoff"),
         }
-        Err(e) => {
-            tracing::error!(error = %e, "Chat processing failed");
-            let (status, body) = error_response(
-                ErrorCode::InternalError,
-                Some("Failed to process message".into()),
-                None,
-            );
-            json_error(status, body).into_response()
+    }
+
+    ChatResponse {
+        session_id: session.id,
+        message: chat_result.message,
+        products: chat_result.products,
+        faqs: chat_result.faqs,
+        actions: chat_result.actions,
+        status,
+    }
+}
+
+/// Run a turn in the background and stream its progress as SSE.
+///
+/// The turn runs to completion (and the reply is persisted) even if the client
+/// disconnects mid-stream.
+fn stream_turn(state: Arc<ChatState>, turn: ChatTurn) -> Response {
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let on_event = |event: ChatStreamEvent| {
+            let _ = tx.send(sse_event(event.event_name(), &event));
+        };
+        let result = state
+            .orchestrator
+            .process_message_stream(
+                &turn.endpoint,
+                Some(&turn.system_prompt),
+                &turn.history,
+                &turn.message,
+                &turn.products,
+                &turn.faqs,
+                turn.fact_finder_config.as_ref(),
+                turn.customer.as_ref(),
+                Some(&turn.search),
+                &on_event,
+            )
+            .await;
+        match result {
+            Ok(chat_result) => {
+                let response = finish_turn(&state, turn, chat_result).await;
+                let _ = tx.send(sse_event("done", &response));
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Chat processing failed");
+                let (_, body) = chat_error(&e);
+                let _ = tx.send(sse_event("error", &body));
+            }
         }
+    });
+    event_stream(rx)
+}
+
+/// POST /chat/{session_id}/handoff - Customer asks for a human
+pub async fn request_handoff(
+    State(state): State<Arc<ChatState>>,
+    tenant: TenantContext,
+    headers: HeaderMap,
+    Path(session_id): Path<String>,
+    body: Option<Json<HandoffRequest>>,
+) -> impl IntoResponse {
+    if let Err(e) = authorize_session(&state, &tenant.tenant_id, &session_id, &headers).await {
+        return handoff_error(e).into_response();
+    }
+    let request = body.map(|Json(b)| b).unwrap_or_default();
+    let reason = request
+        .reason
+        .as_deref()
+        .map(str::trim)
+        .filter(|r| !r.is_empty());
+    match state
+        .handoff
+        .request_handoff(&tenant.tenant_id, &session_id, reason, "customer")
+        .await
+    {
+        Ok(requested) => Json(serde_json::json!({
+            "sessionId": session_id,
+            "requested": requested,
+        }))
+        .into_response(),
+        Err(e) => handoff_error(e).into_response(),
     }
 }
 
+/// GET /chat/{session_id}/messages - New messages (long-poll with `waitSecs`)
+pub async fn list_messages(
+    State(state): State<Arc<ChatState>>,
+    tenant: TenantContext,
+    headers: HeaderMap,
+    Path(session_id): Path<String>,
+    Query(query): Query<ChatMessagesQuery>,
+) -> impl IntoResponse {
+    if let Err(e) = authorize_session(&state, &tenant.tenant_id, &session_id, &headers).await {
+        return handoff_error(e).into_response();
+    }
+
+    let since = query.since.unwrap_or(DateTime::UNIX_EPOCH);
+    let wait = std::time::Duration::from_secs(query.wait_secs.unwrap_or(0).min(MAX_WAIT_SECS));
+    let messages = match state
+        .handoff
+        .wait_for_messages(&tenant.tenant_id, &session_id, since, wait)
+        .await
+    {
+        Ok(messages) => messages,
+        Err(e) => return handoff_error(e).into_response(),
+    };
+
+    // Re-read so the status reflects a claim/release that woke the poll
+    let status = match state
+        .store
+        .get_chat_session(&tenant.tenant_id, &session_id)
+        .await
+    {
+        Ok(Some(session)) => session.status,
+        Ok(None) => return handoff_error(HandoffError::NotFound).into_response(),
+        Err(e) => return handoff_error(e.into()).into_response(),
+    };
+
+    Json(ChatMessagesResponse {
+        status,
+        messages: messages
+            .into_iter()
+            .filter(|m| m.role != role::TOOL)
+            .map(|m| CustomerChatMessage {
+                id: m.id,
+                role: m.role,
+                content: m.content,
+                created_at: m.created_at,
+            })
+            .collect(),
+    })
+    .into_response()
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
-/// Load existing session or create a new one
+/// Map handoff errors to API errors
+pub(crate) fn handoff_error(e: HandoffError) -> (axum::http::StatusCode, Json<serde_json::Value>) {
+    let (status, body) = match e {
+        HandoffError::NotFound => error_response(
+            ErrorCode::ResourceNotFound,
+            Some("Chat session not found".into()),
+            None,
+        ),
+        HandoffError::InvalidState(msg) => {
+            error_response(ErrorCode::InvalidOperation, Some(msg), None)
+        }
+        HandoffError::Storage(e) => {
+            tracing::error!(error = %e, "Chat handoff storage error");
+            error_response(ErrorCode::DatabaseError, None, None)
+        }
+    };
+    json_error(status, body)
+}
+
+/// Customer id from a valid cedros-login `Authorization` header, if any
+async fn verified_customer(state: &ChatState, headers: &HeaderMap) -> Option<String> {
+    let auth = headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .filter(|v| !v.is_empty())?;
+    state
+        .cedros_login
+        .as_ref()?
+        .extract_user_id_from_auth_header(auth)
+        .await
+}
+
+/// A session bound to a customer is only visible to that customer
+fn session_visible(session: &ChatSession, customer_id: Option<&str>) -> bool {
+    session
+        .customer_id
+        .as_deref()
+        .map_or(true, |owner| Some(owner) == customer_id)
+}
+
+/// Load a session for the caller; sessions of other customers are "not found"
+async fn authorize_session(
+    state: &ChatState,
+    tenant_id: &str,
+    session_id: &str,
+    headers: &HeaderMap,
+) -> Result<ChatSession, HandoffError> {
+    let session = state
+        .store
+        .get_chat_session(tenant_id, session_id)
+        .await?
+        .ok_or(HandoffError::NotFound)?;
+    if session.customer_id.is_some()
+        && !session_visible(&session, verified_customer(state, headers).await.as_deref())
+    {
+        return Err(HandoffError::NotFound);
+    }
+    Ok(session)
+}
+
+/// Load existing session or create a new one.
+///
+/// Anonymous sessions are bound to `customer_id` on first authenticated use.
+/// Returns `None` when the session belongs to a different customer.
 async fn load_or_create_session(
     state: &ChatState,
     tenant_id: &str,
     request: &ChatRequest,
-) -> Result<(ChatSession, bool), String> {
+    customer_id: Option<&str>,
+) -> Result<Option<(ChatSession, bool)>, String> {
     if let Some(ref session_id) = request.session_id {
         // Try to load existing session
         match state.store.get_chat_session(tenant_id, session_id).await {
-            Ok(Some(session)) => return Ok((session, false)),
+            Ok(Some(mut session)) => {
+                if !session_visible(&session, customer_id) {
+                    return Ok(None);
+                }
+                if let (None, Some(customer_id)) = (&session.customer_id, customer_id) {
+                    let bound = state
+                        .store
+                        .bind_chat_session_customer(tenant_id, session_id, customer_id, Utc::now())
+                        .await
+                        .map_err(|e| format!("Failed to bind session: {}", e))?;
+                    if !bound {
+                        // Lost a race with another bind; re-check ownership
+                        return match state.store.get_chat_session(tenant_id, session_id).await {
+                            Ok(Some(s)) if session_visible(&s, Some(customer_id)) => {
+                                Ok(Some((s, false)))
+                            }
+                            Ok(_) => Ok(None),
+                            Err(e) => Err(format!("Failed to load session: {}", e)),
+                        };
+                    }
+                    session.customer_id = Some(customer_id.to_string());
+                }
+                return Ok(Some((session, false)));
+            }
             Ok(None) => {
                 // Session ID provided but doesn't exist - create with that ID
-                let session = ChatSession::new(tenant_id.to_string(), session_id.clone());
+                let mut session = ChatSession::new(tenant_id.to_string(), session_id.clone());
+                session.customer_id = customer_id.map(str::to_string);
                 state
                     .store
                     .create_chat_session(session.clone())
                     .await
                     .map_err(|e| format!("Failed to create session: {}", e))?;
-                return Ok((session, true));
+                return Ok(Some((session, true)));
             }
             Err(e) => return Err(format!("Failed to load session: {}", e)),
         }
     }
 
     // No session ID - create new
-    let session = ChatSession::new(tenant_id.to_string(), generate_id());
+    let mut session = ChatSession::new(tenant_id.to_string(), generate_id());
+    session.customer_id = customer_id.map(str::to_string);
     state
         .store
         .create_chat_session(session.clone())
         .await
         .map_err(|e| format!("Failed to create session: {}", e))?;
-    Ok((session, true))
+    Ok(Some((session, true)))
+}
+
+/// Error for a failed assistant turn; a spent `block` budget is a rate limit
+fn chat_error(e: &AiError) -> (StatusCode, ErrorResponse) {
+    match e {
+        AiError::BudgetExceeded(_) => error_response(
+            ErrorCode::RateLimited,
+            Some("Monthly AI budget reached".into()),
+            None,
+        ),
+        _ => error_response(
+            ErrorCode::InternalError,
+            Some("Failed to process message".into()),
+            None,
+        ),
+    }
 }
 
 /// Load AI configuration
 async fn load_ai_config(
     repo: &PostgresConfigRepository,
+    ai_service: &AiService,
     tenant_id: &str,
-) -> Result<(AiProvider, AiModel, String), AiError> {
+) -> Result<AiEndpoint, AiError> {
     // Reuse the existing config loading logic
-    crate::handlers::admin_ai_assistant::load_ai_config(repo, tenant_id).await
+    crate::handlers::admin_ai_assistant::load_ai_config(repo, ai_service, tenant_id).await
 }
 
 /// Load custom chat system prompt or use default
@@ -335,41 +765,17 @@ async fn load_prompt(repo: &PostgresConfigRepository, tenant_id: &str) -> String
 /// Load fact finder AI configuration (optional - falls back to keyword search if not configured)
 async fn load_fact_finder_config(
     repo: &PostgresConfigRepository,
+    ai_service: &AiService,
     tenant_id: &str,
 ) -> Option<FactFinderConfig> {
-    use crate::handlers::admin_ai_assistant::{model_to_provider, provider_config_key};
-
-    let entries = repo.get_config(tenant_id, "ai").await.ok()?;
-
-    // Get model assignment for FactFinder task
-    let assignment_key = format!("assignment_{}", AiTask::FactFinder);
-    let model = entries
-        .iter()
-        .find(|e| e.config_key == assignment_key)
-        .and_then(|e| e.value.as_str())
-        .and_then(|s| serde_json::from_str::<AiModel>(&format!("\"{}\"", s)).ok())
-        .unwrap_or(AiModel::NotSet);
-
-    if model == AiModel::NotSet {
-        return None;
-    }
-
-    let provider = model_to_provider(model)?;
-
-    // Get API key
-    let key_name = provider_config_key(provider);
-    let entry = entries.iter().find(|e| e.config_key == key_name)?;
-
-    let api_key = repo
-        .decrypt_entry(entry)
-        .await
-        .ok()?
-        .as_str()
-        .map(|s| s.to_string())?;
-
-    if api_key.is_empty() {
-        return None;
-    }
+    let endpoint = crate::handlers::admin_ai_assistant::load_task_endpoint(
+        repo,
+        ai_service,
+        tenant_id,
+        AiTask::FactFinder,
+    )
+    .await
+    .ok()?;
 
     // Load custom prompt or use default
     let prompt = crate::handlers::admin_ai_assistant::load_prompt(
@@ -380,12 +786,7 @@ async fn load_fact_finder_config(
     )
     .await;
 
-    Some(FactFinderConfig {
-        provider,
-        model,
-        api_key,
-        prompt,
-    })
+    Some(FactFinderConfig { endpoint, prompt })
 }
 
 /// Generate a unique ID
@@ -151,6 +151,7 @@ mod tests {
                 product_ids: vec!["p1".to_string()],
                 active: true,
                 tokenization_config: None,
+                payment_split: None,
                 created_at: now,
                 updated_at: now,
             })
@@ -166,6 +167,7 @@ mod tests {
                 product_ids: vec![],
                 active: false,
                 tokenization_config: None,
+                payment_split: None,
                 created_at: now,
                 updated_at: now,
             })
@@ -206,6 +208,7 @@ mod tests {
                 product_ids: vec![],
                 active: false,
                 tokenization_config: None,
+                payment_split: None,
                 created_at: now,
                 updated_at: now,
             })
@@ -0,0 +1,284 @@
+//! Inbound bounce/complaint webhooks from email providers.
+//!
+//! `POST /webhook/email/{tenant_id}` accepts one event or an array of events:
+//! `{type: "bounce" | "complaint", email, messageId?, permanent?, reason?}`.
+//! Requests are signed with `X-Signature: sha256=<hex HMAC-SHA256 of the body>`
+//! using the tenant's email webhook secret; tenants without one cannot receive
+//! events. Hard bounces and complaints add the address to the tenant's
+//! suppression list so the email worker stops sending to it.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+
+use crate::errors::{error_response, ErrorCode};
+use crate::handlers::response::json_error;
+use crate::services::messaging::transport::EmailTransportResolver;
+use crate::storage::{normalize_email_address, EmailSuppression, Store, SuppressionReason};
+use crate::x402::utils::hex_encode;
+
+/// Signature header on inbound email events
+pub const EMAIL_SIGNATURE_HEADER: &str = "X-Signature";
+
+pub struct EmailWebhookState<S: Store> {
+    pub store: Arc<S>,
+    pub transports: Arc<EmailTransportResolver>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailEventType {
+    Bounce,
+    Complaint,
+}
+
+fn default_permanent() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailEvent {
+    #[serde(rename = "type")]
+    pub event_type: EmailEventType,
+    pub email: String,
+    /// Provider message id of the email that bounced
+    #[serde(default)]
+    pub message_id: Option<String>,
+    /// Hard bounce; soft bounces are acknowledged but do not suppress
+    #[serde(default = "default_permanent")]
+    pub permanent: bool,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EmailEventPayload {
+    Batch(Vec<EmailEvent>),
+    Single(EmailEvent),
+}
+
+/// Check `sha256=<hex>` against the HMAC-SHA256 of the raw body
+pub fn verify_signature(secret: &str, body: &[u8], header: &str) -> bool {
+    let Some(provided) = header.trim().strip_prefix("sha256=") else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(body);
+    let expected = hex_encode(mac.finalize().into_bytes());
+    expected
+        .as_bytes()
+        .ct_eq(provided.to_ascii_lowercase().as_bytes())
+        .into()
+}
+
+/// POST /webhook/email/{tenant_id} - Provider bounce/complaint events
+pub async fn email_events<S: Store + 'static>(
+    State(state): State<Arc<EmailWebhookState<S>>>,
+    Path(tenant_id): Path<String>,
+    headers: HeaderMap,
+    body: axum::body::Bytes,
+) -> impl IntoResponse {
+    let secret = state.transports.webhook_secret(&tenant_id).await;
+    if secret.is_empty() {
+        let (status, body) = error_response(
+            ErrorCode::ServiceUnavailable,
+            Some("Email webhook secret not configured".to_string()),
+            None,
+        );
+        return json_error(status, body);
+    }
+
+    let signature = headers
+        .get(EMAIL_SIGNATURE_HEADER)
+        .and_then(|h| h.to_str().ok())
+        .unwrap_or_default();
+    if !verify_signature(&secret, &body, signature) {
+        let (status, body) = error_response(ErrorCode::InvalidSignature, None, None);
+        return json_error(status, body);
+    }
+
+    let events = match serde_json::from_slice::<EmailEventPayload>(&body) {
+        Ok(EmailEventPayload::Batch(events)) => events,
+        Ok(EmailEventPayload::Single(event)) => vec![event],
+        Err(e) => {
+            let (status, body) = error_response(ErrorCode::InvalidField, Some(e.to_string()), None);
+            return json_error(status, body);
+        }
+    };
+
+    let mut suppressed = 0usize;
+    for event in events {
+        let email = normalize_email_address(&event.email);
+        if email.is_empty() {
+            continue;
+        }
+        let reason = match event.event_type {
+            EmailEventType::Complaint => SuppressionReason::Complaint,
+            EmailEventType::Bounce if event.permanent => SuppressionReason::Bounce,
+            // Soft bounces are retried by the provider; nothing to record
+            EmailEventType::Bounce => continue,
+        };
+
+        if let Some(ref message_id) = event.message_id {
+            let detail = event
+                .reason
+                .clone()
+                .unwrap_or_else(|| format!("provider reported {}", reason));
+            if let Err(e) = state
+                .store
+                .mark_email_bounced(&tenant_id, message_id, &detail)
+                .await
+            {
+                tracing::warn!(error = %e, tenant_id = %tenant_id, "Failed to mark email bounced");
+            }
+        }
+
+        let suppression = EmailSuppression {
+            tenant_id: tenant_id.clone(),
+            email,
+            reason,
+            detail: event.reason,
+            created_at: Utc::now(),
+        };
+        if let Err(e) = state.store.upsert_email_suppression(suppression).await {
+            tracing::error!(error = %e, tenant_id = %tenant_id, "Failed to record email suppression");
+            let (status, body) = error_response(ErrorCode::DatabaseError, None, None);
+            return json_error(status, body);
+        }
+        suppressed += 1;
+    }
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({ "suppressed": suppressed })),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MessagingConfig;
+    use crate::storage::InMemoryStore;
+
+    fn sign(secret: &str, body: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(body.as_bytes());
+        format!("sha256={}", hex_encode(mac.finalize().into_bytes()))
+    }
+
+    fn state(secret: &str) -> Arc<EmailWebhookState<InMemoryStore>> {
+        let config = MessagingConfig {
+            email_webhook_secret: secret.into(),
+            ..Default::default()
+        };
+        Arc::new(EmailWebhookState {
+            store: Arc::new(InMemoryStore::new()),
+            transports: Arc::new(EmailTransportResolver::new(config)),
+        })
+    }
+
+    async fn post(
+        state: &Arc<EmailWebhookState<InMemoryStore>>,
+        body: &str,
+        signature: &str,
+    ) -> StatusCode {
+        let mut headers = HeaderMap::new();
+        headers.insert(EMAIL_SIGNATURE_HEADER, signature.parse().unwrap());
+        email_events(
+            State(state.clone()),
+            Path("default".to_string()),
+            headers,
+            axum::body::Bytes::from(body.to_string()),
+        )
+        .await
+        .into_response()
+        .status()
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let sig = sign("whsec", "{}");
+        assert!(verify_signature("whsec", b"{}", &sig));
+        assert!(verify_signature(
+            "whsec",
+            b"{}",
+            &sig.to_uppercase().replace("SHA256=", "sha256=")
+        ));
+        assert!(!verify_signature("other", b"{}", &sig));
+        assert!(!verify_signature("whsec", b"{ }", &sig));
+        assert!(!verify_signature(
+            "whsec",
+            b"{}",
+            sig.trim_start_matches("sha256=")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_bad_signature_and_missing_secret() {
+        let body = r#"{"type":"bounce","email":"a@example.com"}"#;
+        let signed = state("whsec");
+        assert_ne!(
+            post(&signed, body, &sign("wrong", body)).await,
+            StatusCode::OK
+        );
+        assert!(signed
+            .store
+            .get_email_suppression("default", "a@example.com")
+            .await
+            .unwrap()
+            .is_none());
+
+        let unsigned = state("");
+        assert_ne!(post(&unsigned, body, &sign("", body)).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_hard_bounces_and_complaints_suppress() {
+        let state = state("whsec");
+        let body = r#"[
+            {"type":"bounce","email":"Hard@Example.com","reason":"mailbox unavailable"},
+            {"type":"bounce","email":"soft@example.com","permanent":false},
+            {"type":"complaint","email":"spam@example.com"}
+        ]"#;
+        assert_eq!(
+            post(&state, body, &sign("whsec", body)).await,
+            StatusCode::OK
+        );
+
+        let hard = state
+            .store
+            .get_email_suppression("default", "hard@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(hard.reason, SuppressionReason::Bounce);
+        assert_eq!(hard.detail.as_deref(), Some("mailbox unavailable"));
+        assert!(state
+            .store
+            .get_email_suppression("default", "soft@example.com")
+            .await
+            .unwrap()
+            .is_none());
+        let complaint = state
+            .store
+            .get_email_suppression("default", "spam@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(complaint.reason, SuppressionReason::Complaint);
+    }
+}
@@ -1,6 +1,9 @@
+pub mod account;
 pub mod admin;
+pub mod admin_affiliates;
 pub mod admin_ai;
 pub mod admin_ai_assistant;
+pub mod admin_archive;
 pub mod admin_asset_redemptions;
 pub mod admin_compliance;
 pub mod admin_compliance_kyc;
@@ -13,17 +16,25 @@ pub mod admin_coupons;
 pub mod admin_coupons_stripe;
 pub mod admin_customers;
 pub mod admin_disputes;
+pub mod admin_email_suppressions;
+pub mod admin_email_templates;
+pub mod admin_events;
 pub mod admin_faqs;
 pub mod admin_gift_cards;
 pub mod admin_images;
 pub mod admin_inventory;
+pub mod admin_ledger;
 pub mod admin_orders;
+pub mod admin_price_schedules;
 pub mod admin_products;
+pub mod admin_products_import;
 pub mod admin_products_stripe;
 pub mod admin_products_types;
 pub mod admin_refunds;
+pub mod admin_reports;
 pub mod admin_returns;
 pub mod admin_shipping;
+pub mod admin_stripe_connect;
 pub mod admin_stripe_refunds;
 pub mod admin_subscriptions;
 pub mod admin_tax;
@@ -39,6 +50,7 @@ pub mod collections;
 pub mod credits;
 pub mod credits_holds;
 pub mod discovery;
+pub mod email_webhooks;
 pub mod openapi_spec;
 pub mod faqs;
 pub mod gasless;
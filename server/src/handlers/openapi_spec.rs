@@ -74,6 +74,7 @@ pub const OPENAPI_SPEC: &str = r##"{
     { "name": "Chat", "description": "AI shopping assistant" },
     { "name": "GiftCards", "description": "Gift card claiming and balance" },
     { "name": "AssetRedemptions", "description": "Token-gated asset redemption" },
+    { "name": "Account", "description": "Customer self-service (orders, addresses, returns)" },
     { "name": "Refunds", "description": "Refund requests and status" },
     { "name": "Compliance", "description": "Compliance check" },
     { "name": "Storefront", "description": "Shop configuration" },
@@ -93,6 +94,14 @@ pub const OPENAPI_SPEC: &str = r##"{
         ],
         "responses": { "200": { "description": "Product list" } } }
     },
+    "/paywall/v1/products/search": {
+      "get": { "tags": ["Products"], "operationId": "searchProducts", "summary": "Search products by keyword and meaning",
+        "parameters": [
+          { "name": "q", "in": "query", "required": true, "schema": { "type": "string", "maxLength": 200 } },
+          { "name": "limit", "in": "query", "schema": { "type": "integer", "default": 10, "maximum": 50 } }
+        ],
+        "responses": { "200": { "description": "Ranked products and whether semantic ranking was used" }, "400": { "description": "Missing or too long query" } } }
+    },
     "/paywall/v1/products/{id}": {
       "get": { "tags": ["Products"], "operationId": "getProduct", "summary": "Get product by ID",
         "parameters": [{ "name": "id", "in": "path", "required": true, "schema": { "type": "string" } }],
@@ -297,8 +306,9 @@ pub const OPENAPI_SPEC: &str = r##"{
     "/paywall/v1/chat": {
       "post": { "tags": ["Chat"], "operationId": "chat", "summary": "Send message to AI shopping assistant",
         "requestBody": { "content": { "application/json": { "schema": { "$ref": "#/components/schemas/ChatMessage" } } } },
-        "responses": { "200": { "description": "Chat response with products and FAQs",
-          "content": { "application/json": { "schema": { "$ref": "#/components/schemas/ChatResponse" } } } } } }
+        "responses": { "200": { "description": "Chat response with products and FAQs. With Accept: text/event-stream, a stream of delta, tool_call and tool_result events ending in done (ChatResponse) or error",
+          "content": { "application/json": { "schema": { "$ref": "#/components/schemas/ChatResponse" } },
+            "text/event-stream": { "schema": { "type": "string" } } } } } }
     },
     "/paywall/v1/gift-card/claim/{token}": {
       "get": { "tags": ["GiftCards"], "operationId": "getGiftCardClaim", "summary": "Get gift card claim info",
@@ -328,6 +338,30 @@ pub const OPENAPI_SPEC: &str = r##"{
         "parameters": [{ "name": "productId", "in": "path", "required": true, "schema": { "type": "string" } }],
         "responses": { "200": { "description": "Redemption status" } } }
     },
+    "/paywall/v1/account/orders": {
+      "get": { "tags": ["Account"], "operationId": "listAccountOrders", "summary": "List the signed-in customer's orders",
+        "parameters": [
+          { "name": "limit", "in": "query", "schema": { "type": "integer" } },
+          { "name": "offset", "in": "query", "schema": { "type": "integer" } }
+        ],
+        "responses": { "200": { "description": "Orders, newest first" }, "401": { "description": "Missing or invalid bearer token" } } }
+    },
+    "/paywall/v1/account/orders/{orderId}": {
+      "get": { "tags": ["Account"], "operationId": "getAccountOrder", "summary": "Get order detail with fulfillments, history and returns",
+        "parameters": [{ "name": "orderId", "in": "path", "required": true, "schema": { "type": "string" } }],
+        "responses": { "200": { "description": "Order detail" }, "404": { "description": "Order not found" } } }
+    },
+    "/paywall/v1/account/orders/{orderId}/returns": {
+      "post": { "tags": ["Account"], "operationId": "createAccountReturn", "summary": "Request a return for order items",
+        "parameters": [{ "name": "orderId", "in": "path", "required": true, "schema": { "type": "string" } }],
+        "responses": { "200": { "description": "Return request created" }, "400": { "description": "Items not eligible for return" } } }
+    },
+    "/paywall/v1/account/addresses": {
+      "get": { "tags": ["Account"], "operationId": "listAccountAddresses", "summary": "List saved addresses",
+        "responses": { "200": { "description": "Saved addresses" } } },
+      "put": { "tags": ["Account"], "operationId": "updateAccountAddresses", "summary": "Replace saved addresses",
+        "responses": { "200": { "description": "Saved addresses" } } }
+    },
     "/paywall/v1/shop": {
       "get": { "tags": ["Storefront"], "operationId": "getShop", "summary": "Get shop configuration",
         "responses": { "200": { "description": "Shop config" } } }
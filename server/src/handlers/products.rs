@@ -10,10 +10,13 @@ use axum::{
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::response::{json_error, json_ok_cached};
+use super::response::{json_error, json_ok, json_ok_cached};
 use crate::constants::PRODUCTS_CACHE_MAX_AGE;
+use crate::handlers::admin_ai_assistant::AiRateLimiter;
 use crate::middleware::tenant::TenantContext;
+use crate::observability::record_ai_rate_limit_rejection;
 use crate::repositories::{CouponRepository, ProductRepository, ProductRepositoryError};
+use crate::services::SemanticSearch;
 use crate::storage::Store;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -131,6 +134,23 @@ pub struct ListProductsQuery {
     pub collection_id: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchProductsQuery {
+    #[serde(default)]
+    pub q: String,
+    #[serde(default = "default_search_limit")]
+    pub limit: i32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchProductsResponse {
+    pub products: Vec<ProductInfo>,
+    /// Whether embedding similarity contributed to the ranking (keyword-only otherwise)
+    pub semantic: bool,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ValidateCouponResponse {
@@ -153,6 +173,11 @@ pub struct ProductsAppState {
     pub store: Arc<dyn Store>,
     pub product_repo: Arc<dyn ProductRepository>,
     pub coupon_repo: Arc<dyn CouponRepository>,
+    /// Hybrid keyword + embedding search for `/products/search`
+    pub search: Arc<SemanticSearch>,
+    /// Per-tenant budget of query embeddings for `/products/search`; past it
+    /// searches are keyword-only
+    pub search_rate_limiter: AiRateLimiter,
 }
 
 use super::cap_limit;
@@ -161,6 +186,19 @@ fn default_limit() -> i32 {
     100
 }
 
+fn default_search_limit() -> i32 {
+    10
+}
+
+/// Query embeddings per tenant per minute for `/products/search`
+pub const SEARCH_EMBEDDINGS_PER_MINUTE: u32 = 60;
+
+/// Upper bound on `limit` for `/products/search`
+const MAX_SEARCH_LIMIT: i32 = 50;
+
+/// Longest accepted search query, in characters
+const MAX_SEARCH_QUERY_CHARS: usize = 200;
+
 fn remaining_uses(limit: Option<i32>, usage_count: i32) -> Option<i32> {
     limit.map(|m| if usage_count >= m { 0 } else { m - usage_count })
 }
@@ -387,6 +425,52 @@ pub async fn get_product_by_slug(
     }
 }
 
+/// GET /paywall/v1/products/search?q= - Search active products by keyword and meaning
+pub async fn search_products(
+    State(state): State<Arc<ProductsAppState>>,
+    tenant: TenantContext,
+    Query(query): Query<SearchProductsQuery>,
+) -> impl IntoResponse {
+    let q = query.q.trim();
+    if q.is_empty() || q.chars().count() > MAX_SEARCH_QUERY_CHARS {
+        let (status, error_body) = crate::errors::error_response(
+            crate::errors::ErrorCode::InvalidField,
+            Some(format!(
+                "q is required and must be at most {} characters",
+                MAX_SEARCH_QUERY_CHARS
+            )),
+            Some(serde_json::json!({ "field": "q" })),
+        );
+        return json_error(status, error_body).into_response();
+    }
+    let limit = query.limit.clamp(1, MAX_SEARCH_LIMIT) as usize;
+
+    // Query embeddings are paid calls on a public endpoint; once the tenant's
+    // budget is spent, rank by keyword instead of rejecting the search
+    let semantic = state.search_rate_limiter.try_consume(&tenant.tenant_id);
+    if !semantic {
+        record_ai_rate_limit_rejection(&tenant.tenant_id);
+    }
+
+    match state
+        .search
+        .search_products(&tenant.tenant_id, q, limit, semantic)
+        .await
+    {
+        Ok(results) => json_ok(SearchProductsResponse {
+            products: results.items.iter().map(product_to_info).collect(),
+            semantic: results.semantic,
+        })
+        .into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to search products");
+            let (status, error_body) =
+                crate::errors::error_response(crate::errors::ErrorCode::InternalError, None, None);
+            json_error(status, error_body).into_response()
+        }
+    }
+}
+
 /// POST /paywall/v1/coupons/validate - Validate a coupon code
 pub async fn validate_coupon(
     State(state): State<Arc<ProductsAppState>>,
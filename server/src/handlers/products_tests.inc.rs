@@ -199,10 +199,13 @@ impl CouponRepository for EmptyCouponRepo {
 
 fn build_state_with_store(products: Vec<Product>) -> (Arc<ProductsAppState>, Arc<InMemoryStore>) {
     let store = Arc::new(InMemoryStore::new());
+    let product_repo: Arc<dyn ProductRepository> = Arc::new(TestProductRepo { products });
     let state = Arc::new(ProductsAppState {
         store: store.clone(),
-        product_repo: Arc::new(TestProductRepo { products }),
+        product_repo: product_repo.clone(),
         coupon_repo: Arc::new(EmptyCouponRepo),
+        search: Arc::new(SemanticSearch::new(store.clone(), product_repo)),
+        search_rate_limiter: AiRateLimiter::new(SEARCH_EMBEDDINGS_PER_MINUTE),
     });
     (state, store)
 }
@@ -389,6 +392,7 @@ async fn test_list_products_filters_by_collection() {
             product_ids: vec![p1.id.clone(), p2.id.clone()],
             active: true,
             tokenization_config: None,
+            payment_split: None,
             created_at: now,
             updated_at: now,
         })
@@ -436,6 +440,7 @@ async fn test_list_products_collection_pagination_skips_inactive() {
             product_ids: vec![p2.id.clone(), p1.id.clone()],
             active: true,
             tokenization_config: None,
+            payment_split: None,
             created_at: now,
             updated_at: now,
         })
@@ -487,6 +492,7 @@ async fn test_list_products_collection_pagination_preserves_order_with_offset()
             ],
             active: true,
             tokenization_config: None,
+            payment_split: None,
             created_at: now,
             updated_at: now,
         })
@@ -538,6 +544,7 @@ async fn test_list_products_collection_deep_offset_returns_empty_after_filtering
             ],
             active: true,
             tokenization_config: None,
+            payment_split: None,
             created_at: now,
             updated_at: now,
         })
@@ -628,3 +635,81 @@ async fn test_products_txt_renders_catalog_from_lightweight_listing() {
     assert!(text.contains("- Subscription: 1 monthly"));
     assert!(text.contains("- Trial: 14 days"));
 }
+
+#[tokio::test]
+async fn test_search_products_ranks_keyword_matches() {
+    let mut mug = product("p1");
+    mug.title = Some("Ceramic mug".to_string());
+    let mut plate = product("p2");
+    plate.title = Some("Dinner plate".to_string());
+    plate.tags = vec!["ceramic".to_string()];
+    let state = build_state(vec![plate, mug]);
+
+    let response = search_products(
+        State(state.clone()),
+        TenantContext::default(),
+        Query(SearchProductsQuery {
+            q: "ceramic mug".to_string(),
+            limit: 10,
+        }),
+    )
+    .await
+    .into_response();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let products = json["products"].as_array().unwrap();
+    assert_eq!(products.len(), 2);
+    assert_eq!(products[0]["id"], "p1");
+    assert_eq!(json["semantic"], false);
+
+    let response = search_products(
+        State(state),
+        TenantContext::default(),
+        Query(SearchProductsQuery {
+            q: "  ".to_string(),
+            limit: 10,
+        }),
+    )
+    .await
+    .into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_search_products_falls_back_to_keywords_past_embedding_budget() {
+    let mut mug = product("p1");
+    mug.title = Some("Ceramic mug".to_string());
+    let store = Arc::new(InMemoryStore::new());
+    let product_repo: Arc<dyn ProductRepository> = Arc::new(TestProductRepo {
+        products: vec![mug],
+    });
+    let state = Arc::new(ProductsAppState {
+        store: store.clone(),
+        product_repo: product_repo.clone(),
+        coupon_repo: Arc::new(EmptyCouponRepo),
+        search: Arc::new(SemanticSearch::new(store, product_repo)),
+        search_rate_limiter: AiRateLimiter::new(1),
+    });
+
+    for _ in 0..3 {
+        let response = search_products(
+            State(state.clone()),
+            TenantContext::default(),
+            Query(SearchProductsQuery {
+                q: "mug".to_string(),
+                limit: 10,
+            }),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["products"][0]["id"], "p1");
+        assert_eq!(json["semantic"], false);
+    }
+    assert!(!state.search_rate_limiter.try_consume("default"));
+}
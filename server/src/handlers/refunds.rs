@@ -120,6 +120,9 @@ pub struct RefundQuoteExtra {
     pub token_symbol: String,
     pub memo: String,
     pub fee_payer: Option<String>,
+    /// Per-leg amounts to reverse when the original purchase was a split payment
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub split_legs: Vec<crate::models::PaymentLeg>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -442,6 +445,7 @@ pub async fn approve_refund<S: Store + 'static>(
                         token_symbol: quote_response.token_symbol,
                         memo: quote_response.memo,
                         fee_payer: quote_response.fee_payer,
+                        split_legs: quote_response.split_legs,
                     },
                 },
                 expires_at: quote_response.expires_at,
@@ -1,14 +1,21 @@
 //! Response utilities for safe JSON serialization
 //!
 //! This module provides helper functions to avoid `.unwrap()` calls when
-//! serializing response types to JSON, preventing potential panics.
+//! serializing response types to JSON, preventing potential panics, plus
+//! server-sent event helpers for streaming endpoints.
+
+use std::convert::Infallible;
 
 use axum::{
     http::{header, HeaderMap, StatusCode},
-    response::IntoResponse,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
     Json,
 };
 use serde::Serialize;
+use tokio::sync::mpsc::UnboundedReceiver;
 
 /// Safely convert a serializable value to JSON, falling back to an error response on failure.
 ///
@@ -68,6 +75,33 @@ pub fn json_ok_cached<T: Serialize>(value: T, max_age_secs: u32) -> impl IntoRes
     (StatusCode::OK, headers, to_json(value))
 }
 
+/// True when the client asked for a server-sent event stream (`Accept: text/event-stream`)
+pub fn wants_event_stream(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::ACCEPT)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.contains("text/event-stream"))
+}
+
+/// SSE event with a JSON payload
+pub fn sse_event<T: Serialize>(name: &str, data: &T) -> Event {
+    Event::default()
+        .event(name)
+        .data(to_json(data).0.to_string())
+}
+
+/// SSE response fed by a channel; the stream ends once every sender is dropped
+pub fn event_stream(rx: UnboundedReceiver<Event>) -> Response {
+    let stream = futures_util::stream::unfold(rx, |mut rx| async move {
+        rx.recv()
+            .await
+            .map(|event| (Ok::<_, Infallible>(event), rx))
+    });
+    Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,6 +142,19 @@ mod tests {
         assert_eq!(json.0["message"], "created");
     }
 
+    #[test]
+    fn test_wants_event_stream() {
+        let mut headers = HeaderMap::new();
+        assert!(!wants_event_stream(&headers));
+        headers.insert(header::ACCEPT, "application/json".parse().unwrap());
+        assert!(!wants_event_stream(&headers));
+        headers.insert(
+            header::ACCEPT,
+            "text/event-stream, application/json".parse().unwrap(),
+        );
+        assert!(wants_event_stream(&headers));
+    }
+
     #[test]
     fn test_json_ok_cached_sets_private_cache_and_vary() {
         let resp = TestResponse {
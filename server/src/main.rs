@@ -15,9 +15,15 @@ async fn main() -> anyhow::Result<()> {
                 .unwrap_or(false)
         });
 
+    // OTLP trace export is opt-in via OTEL_EXPORTER_OTLP_ENDPOINT
+    let otel_guard =
+        cedros_pay::observability::otel::init_from_env().map_err(|e| anyhow::anyhow!(e))?;
+    let otel_layer = otel_guard.as_ref().map(|guard| guard.layer());
+
     if use_json {
         tracing_subscriber::registry()
             .with(env_filter)
+            .with(otel_layer)
             .with(
                 fmt::layer()
                     .json()
@@ -29,9 +35,12 @@ async fn main() -> anyhow::Result<()> {
     } else {
         tracing_subscriber::registry()
             .with(env_filter)
+            .with(otel_layer)
             .with(fmt::layer().with_target(false))
             .init();
     }
 
-    run().await
+    let result = run().await;
+    drop(otel_guard);
+    result
 }
@@ -176,6 +176,33 @@ pub async fn api_key_middleware(
     Ok(next.run(request).await)
 }
 
+/// Require a valid API key allowed for the request's tenant, even when
+/// `api_key.enabled` does not gate the rest of the API. Used for routes that
+/// expose tenant data to server-side consumers. With API keys disabled these
+/// routes are unavailable.
+pub async fn require_api_key_middleware(
+    axum::extract::State(state): axum::extract::State<Arc<AuthState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    if !state.api_key_config.enabled {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    let validation = request
+        .headers()
+        .get(HEADER_API_KEY)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|key| state.validate_api_key(key))
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let (tenant_id, _source) = crate::middleware::tenant::extract_tenant_id(&request);
+    let tenant_id = tenant_id.unwrap_or_else(|| "default".to_string());
+    if !is_tenant_allowed_for_api_key(&tenant_id, &validation.allowed_tenants) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    Ok(next.run(request).await)
+}
+
 fn is_tenant_allowed_for_api_key(tenant_id: &str, allowed_tenants: &[String]) -> bool {
     // Per docs/spec: empty allowlist restricts key to default tenant only.
     if allowed_tenants.is_empty() {
@@ -639,6 +666,17 @@ pub(crate) fn admin_nonce_purpose_for_path_and_method(
     if method == axum::http::Method::GET && path.starts_with("/admin/chats/") {
         return Some("admin_chats_get");
     }
+    if method == axum::http::Method::POST && path.starts_with("/admin/chats/") {
+        if path.ends_with("/claim") {
+            return Some("admin_chats_claim");
+        }
+        if path.ends_with("/messages") {
+            return Some("admin_chats_message");
+        }
+        if path.ends_with("/release") {
+            return Some("admin_chats_release");
+        }
+    }
     if method == axum::http::Method::GET
         && path.starts_with("/admin/users/")
         && path.ends_with("/chats")
@@ -1227,4 +1265,48 @@ mod tests {
             .unwrap();
         assert_eq!(super::admin_nonce_purpose_for_request(&req), None);
     }
+
+    #[tokio::test]
+    async fn test_require_api_key_middleware_ignores_global_toggle() {
+        let app = |enabled: bool| {
+            let state = Arc::new(AuthState::new(
+                ApiKeyConfig {
+                    enabled,
+                    keys: vec![crate::config::types::ApiKeyEntry {
+                        key: "test-key".to_string(),
+                        tier: ApiKeyTier::Free,
+                        allowed_tenants: vec![],
+                    }],
+                },
+                Vec::new(),
+            ));
+            Router::new()
+                .route("/events", get(|| async { StatusCode::OK }))
+                .layer(axum::middleware::from_fn_with_state(
+                    state,
+                    require_api_key_middleware,
+                ))
+        };
+        let request = |key: Option<&str>| {
+            let mut builder = Request::builder().uri("/events");
+            if let Some(key) = key {
+                builder = builder.header(HEADER_API_KEY, key);
+            }
+            builder.body(Body::empty()).unwrap()
+        };
+
+        let status =
+            |app: Router, key| async move { app.oneshot(request(key)).await.unwrap().status() };
+        assert_eq!(status(app(true), None).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            status(app(true), Some("wrong")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(status(app(true), Some("test-key")).await, StatusCode::OK);
+        // Disabled API keys leave the route closed rather than open
+        assert_eq!(
+            status(app(false), Some("test-key")).await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
 }
@@ -162,7 +162,7 @@ struct BreakerState {
 
 /// Circuit breaker for protecting external service calls
 pub struct CircuitBreaker {
-    config: CircuitBreakerConfig,
+    config: RwLock<Arc<CircuitBreakerConfig>>,
     stats: Stats,
     state: RwLock<BreakerState>,
 }
@@ -171,7 +171,7 @@ impl CircuitBreaker {
     /// Create a new circuit breaker with the given config
     pub fn new(config: CircuitBreakerConfig) -> Self {
         Self {
-            config,
+            config: RwLock::new(Arc::new(config)),
             stats: Stats::new(),
             state: RwLock::new(BreakerState {
                 state: CircuitBreakerState::Closed,
@@ -182,6 +182,16 @@ impl CircuitBreaker {
         }
     }
 
+    fn config(&self) -> Arc<CircuitBreakerConfig> {
+        self.config.read().clone()
+    }
+
+    /// Apply new thresholds. The current state and stats are kept, so an
+    /// open breaker stays open until the (new) timeout elapses.
+    pub fn reconfigure(&self, config: CircuitBreakerConfig) {
+        *self.config.write() = Arc::new(config);
+    }
+
     /// Get the current state
     pub fn state(&self) -> CircuitBreakerState {
         self.state.read().state
@@ -193,11 +203,12 @@ impl CircuitBreaker {
     /// This synchronizes with record_success() and record_failure() which also
     /// acquire the state lock before modifying stats, preventing race conditions.
     pub fn allow(&self) -> bool {
+        let config = self.config();
         let mut breaker = self.state.write();
 
         // L-006: Stats reset inside lock prevents race with record_success/failure.
         // Both this reset and the stats modifications in record_* hold state.write().
-        if breaker.interval_start.elapsed() >= self.config.interval {
+        if breaker.interval_start.elapsed() >= config.interval {
             self.stats.reset();
             breaker.interval_start = Instant::now();
         }
@@ -207,15 +218,12 @@ impl CircuitBreaker {
             CircuitBreakerState::Open => {
                 // Check if timeout has elapsed
                 if let Some(opened_at) = breaker.opened_at {
-                    if opened_at.elapsed() >= self.config.timeout {
+                    if opened_at.elapsed() >= config.timeout {
                         // Transition to half-open
                         breaker.state = CircuitBreakerState::HalfOpen;
                         breaker.half_open_requests = 0;
                         self.stats.reset();
-                        record_circuit_breaker_state(
-                            &self.config.service_name,
-                            MetricState::HalfOpen,
-                        );
+                        record_circuit_breaker_state(&config.service_name, MetricState::HalfOpen);
                         true
                     } else {
                         false
@@ -226,7 +234,7 @@ impl CircuitBreaker {
             }
             CircuitBreakerState::HalfOpen => {
                 // Allow limited requests in half-open
-                if breaker.half_open_requests < self.config.max_requests {
+                if breaker.half_open_requests < config.max_requests {
                     breaker.half_open_requests += 1;
                     true
                 } else {
@@ -240,6 +248,7 @@ impl CircuitBreaker {
     ///
     /// Acquires state lock BEFORE modifying stats to prevent race with reset()
     pub fn record_success(&self) {
+        let config = self.config();
         // Acquire lock first to synchronize with reset() in allow()
         let mut breaker = self.state.write();
 
@@ -248,11 +257,11 @@ impl CircuitBreaker {
         if breaker.state == CircuitBreakerState::HalfOpen {
             // Check if we've had enough successes to close
             let successes = self.stats.consecutive_successes.load(Ordering::SeqCst);
-            if successes >= self.config.max_requests as u64 {
+            if successes >= config.max_requests as u64 {
                 breaker.state = CircuitBreakerState::Closed;
                 breaker.opened_at = None;
                 self.stats.reset();
-                record_circuit_breaker_state(&self.config.service_name, MetricState::Closed);
+                record_circuit_breaker_state(&config.service_name, MetricState::Closed);
             }
         }
     }
@@ -261,7 +270,8 @@ impl CircuitBreaker {
     ///
     /// Acquires state lock BEFORE modifying stats to prevent race with reset()
     pub fn record_failure(&self) {
-        record_circuit_breaker_failure(&self.config.service_name);
+        let config = self.config();
+        record_circuit_breaker_failure(&config.service_name);
 
         // Acquire lock first to synchronize with reset() in allow()
         let mut breaker = self.state.write();
@@ -275,14 +285,13 @@ impl CircuitBreaker {
                 let requests = self.stats.requests.load(Ordering::SeqCst);
                 let ratio = self.stats.failure_ratio();
 
-                let should_trip = consecutive >= self.config.consecutive_failures as u64
-                    || (requests >= self.config.min_requests as u64
-                        && ratio >= self.config.failure_ratio);
+                let should_trip = consecutive >= config.consecutive_failures as u64
+                    || (requests >= config.min_requests as u64 && ratio >= config.failure_ratio);
 
                 if should_trip {
                     breaker.state = CircuitBreakerState::Open;
                     breaker.opened_at = Some(Instant::now());
-                    record_circuit_breaker_state(&self.config.service_name, MetricState::Open);
+                    record_circuit_breaker_state(&config.service_name, MetricState::Open);
                 }
             }
             CircuitBreakerState::HalfOpen => {
@@ -290,7 +299,7 @@ impl CircuitBreaker {
                 breaker.state = CircuitBreakerState::Open;
                 breaker.opened_at = Some(Instant::now());
                 breaker.half_open_requests = 0;
-                record_circuit_breaker_state(&self.config.service_name, MetricState::Open);
+                record_circuit_breaker_state(&config.service_name, MetricState::Open);
             }
             CircuitBreakerState::Open => {}
         }
@@ -405,4 +414,25 @@ mod tests {
         cb.record_failure();
         assert_eq!(cb.state(), CircuitBreakerState::Open);
     }
+
+    #[test]
+    fn test_circuit_breaker_reconfigure_changes_trip_threshold() {
+        let config = CircuitBreakerConfig {
+            service_name: "test".to_string(),
+            max_requests: 1,
+            interval: Duration::from_secs(60),
+            timeout: Duration::from_secs(1),
+            consecutive_failures: 3,
+            failure_ratio: 0.5,
+            min_requests: 10,
+        };
+        let cb = CircuitBreaker::new(config.clone());
+
+        cb.reconfigure(CircuitBreakerConfig {
+            consecutive_failures: 1,
+            ..config
+        });
+        cb.record_failure();
+        assert_eq!(cb.state(), CircuitBreakerState::Open);
+    }
 }
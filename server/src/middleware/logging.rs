@@ -1,9 +1,9 @@
 use std::time::Instant;
 
 use axum::{body::Body, extract::MatchedPath, extract::Request, http::Response, middleware::Next};
-use tracing::{info, warn};
+use tracing::{info, warn, Instrument};
 
-use crate::observability::{dec_http_in_flight, inc_http_in_flight, record_http_request};
+use crate::observability::{dec_http_in_flight, inc_http_in_flight, otel, record_http_request};
 
 /// Structured logging layer for request/response logging per spec (14-observability.md)
 ///
@@ -59,13 +59,32 @@ pub async fn structured_logging_middleware(req: Request, next: Next) -> Response
     // Extract remote IP (set by real_ip middleware)
     let remote_ip = extract_remote_ip(&req);
 
+    // Server span for the whole request; continues an upstream trace when the
+    // caller sent `traceparent`. tenant_id is recorded by the tenant middleware.
+    let span = tracing::info_span!(
+        "http.request",
+        otel.name = %format!("{} {}", method, path),
+        otel.kind = "server",
+        otel.status_code = tracing::field::Empty,
+        http.request.method = %method,
+        http.route = %path,
+        http.response.status_code = tracing::field::Empty,
+        request_id = %request_id,
+        tenant_id = tracing::field::Empty,
+    );
+    otel::set_parent_from_headers(&span, req.headers());
+
     // Process request
-    let response = next.run(req).await;
+    let response = next.run(req).instrument(span.clone()).await;
     dec_http_in_flight();
 
     // Calculate duration
     let duration_ms = start.elapsed().as_millis() as u64;
     let status = response.status().as_u16();
+    span.record("http.response.status_code", status);
+    if status >= 500 {
+        span.record("otel.status_code", "ERROR");
+    }
 
     // Record HTTP metrics per spec (14-observability.md)
     let duration_secs = start.elapsed().as_secs_f64();
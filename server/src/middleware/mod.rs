@@ -19,7 +19,7 @@ pub use api_version::{
 };
 pub use auth::{
     admin_middleware, api_key_middleware, auth_middleware, extract_wallet_from_request,
-    require_wallet_middleware, AdminAuthState, AuthContext, AuthState,
+    require_api_key_middleware, require_wallet_middleware, AdminAuthState, AuthContext, AuthState,
 };
 pub use circuit_breaker::{
     new_circuit_breaker, CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError,
@@ -32,7 +32,7 @@ use axum::{
     middleware::Next,
     response::{IntoResponse, Response},
 };
-use parking_lot::Mutex;
+use parking_lot::{Mutex, RwLock};
 
 use crate::config::types::{ApiKeyTier, RateLimitConfig};
 use crate::middleware::auth::AuthContext;
@@ -168,7 +168,8 @@ impl TokenBucket {
 /// Rate limiter state using token bucket algorithm
 #[derive(Clone)]
 pub struct RateLimiter {
-    config: RateLimitConfig,
+    /// Swapped as a whole on reconfigure so each check sees one consistent config
+    config: Arc<RwLock<Arc<RateLimitConfig>>>,
     global: Arc<Mutex<TokenBucket>>,
     /// Per-IP buckets using IndexMap for O(1) LRU eviction
     per_ip: Arc<Mutex<IndexMap<String, TokenBucket>>>,
@@ -185,10 +186,22 @@ impl RateLimiter {
             ))),
             per_ip: Arc::new(Mutex::new(IndexMap::new())),
             per_wallet: Arc::new(Mutex::new(IndexMap::new())),
-            config,
+            config: Arc::new(RwLock::new(Arc::new(config))),
         }
     }
 
+    fn config(&self) -> Arc<RateLimitConfig> {
+        self.config.read().clone()
+    }
+
+    /// Apply new limits. All buckets restart full at the new capacity.
+    pub fn reconfigure(&self, config: RateLimitConfig) {
+        *self.global.lock() = TokenBucket::new(config.global.limit, config.global.window);
+        self.per_ip.lock().clear();
+        self.per_wallet.lock().clear();
+        *self.config.write() = Arc::new(config);
+    }
+
     /// Check if request is allowed (consumes a token if allowed)
     ///
     /// # Thread Safety
@@ -206,8 +219,9 @@ impl RateLimiter {
     /// The behavior is: global tokens are consumed before per-IP/wallet checks, which is
     /// slightly more conservative but acceptable for rate limiting (fails closed).
     pub fn check(&self, ip: Option<&str>, wallet: Option<&str>) -> RateLimitResult {
+        let config = self.config();
         // Check global limit first - token consumed if passes
-        if self.config.global.enabled {
+        if config.global.enabled {
             let mut bucket = self.global.lock();
             if !bucket.try_consume() {
                 return RateLimitResult::GlobalExceeded;
@@ -215,7 +229,7 @@ impl RateLimiter {
         }
 
         // Check per-IP limit - token consumed if passes
-        if self.config.per_ip.enabled {
+        if config.per_ip.enabled {
             if let Some(ip_str) = ip {
                 // M-008 FIX: Normalize IPv6 addresses to canonical form to prevent
                 // rate limit bypass via different equivalent representations.
@@ -231,8 +245,7 @@ impl RateLimiter {
                     map.insert(normalized_ip, bucket);
                     result
                 } else {
-                    let mut bucket =
-                        TokenBucket::new(self.config.per_ip.limit, self.config.per_ip.window);
+                    let mut bucket = TokenBucket::new(config.per_ip.limit, config.per_ip.window);
                     let result = bucket.try_consume();
                     map.insert(normalized_ip, bucket);
                     result
@@ -244,7 +257,7 @@ impl RateLimiter {
         }
 
         // Check per-wallet limit - token consumed if passes
-        if self.config.per_wallet.enabled {
+        if config.per_wallet.enabled {
             if let Some(wallet_str) = wallet {
                 let mut map = self.per_wallet.lock();
                 // O(1) eviction: remove first (oldest) entry if at capacity
@@ -257,10 +270,8 @@ impl RateLimiter {
                     map.insert(wallet_str.to_string(), bucket);
                     result
                 } else {
-                    let mut bucket = TokenBucket::new(
-                        self.config.per_wallet.limit,
-                        self.config.per_wallet.window,
-                    );
+                    let mut bucket =
+                        TokenBucket::new(config.per_wallet.limit, config.per_wallet.window);
                     let result = bucket.try_consume();
                     map.insert(wallet_str.to_string(), bucket);
                     result
@@ -277,7 +288,8 @@ impl RateLimiter {
     /// Check only global limit (for Enterprise tier per spec)
     /// Enterprise tier bypasses per-IP and per-wallet limits
     pub fn check_global_only(&self) -> RateLimitResult {
-        if self.config.global.enabled {
+        let config = self.config();
+        if config.global.enabled {
             let mut bucket = self.global.lock();
             if !bucket.try_consume() {
                 return RateLimitResult::GlobalExceeded;
@@ -346,6 +358,7 @@ impl RateLimiter {
 
     /// Get rate limit info for headers
     pub fn get_info(&self) -> RateLimitInfo {
+        let config = self.config();
         let mut bucket = self.global.lock();
         let available = bucket.available();
         let time_to_reset = bucket.time_to_next_token();
@@ -358,7 +371,7 @@ impl RateLimiter {
             + time_to_reset;
 
         RateLimitInfo {
-            limit: self.config.global.limit,
+            limit: config.global.limit,
             remaining: available,
             reset,
             retry_after: time_to_reset,
@@ -367,6 +380,7 @@ impl RateLimiter {
 
     /// Get per-IP rate limit info
     pub fn get_ip_info(&self, ip: &str) -> RateLimitInfo {
+        let config = self.config();
         // M-008 FIX: Normalize IPv6 addresses to canonical form
         let normalized_ip = normalize_ip(ip);
         let mut map = self.per_ip.lock();
@@ -382,7 +396,7 @@ impl RateLimiter {
             map.insert(normalized_ip, bucket);
             (available, time_to_reset)
         } else {
-            let mut bucket = TokenBucket::new(self.config.per_ip.limit, self.config.per_ip.window);
+            let mut bucket = TokenBucket::new(config.per_ip.limit, config.per_ip.window);
             let available = bucket.available();
             let time_to_reset = bucket.time_to_next_token();
             map.insert(normalized_ip, bucket);
@@ -397,7 +411,7 @@ impl RateLimiter {
             + time_to_reset;
 
         RateLimitInfo {
-            limit: self.config.per_ip.limit,
+            limit: config.per_ip.limit,
             remaining: available,
             reset,
             retry_after: time_to_reset,
@@ -406,6 +420,7 @@ impl RateLimiter {
 
     /// Get per-wallet rate limit info
     pub fn get_wallet_info(&self, wallet: &str) -> RateLimitInfo {
+        let config = self.config();
         let mut map = self.per_wallet.lock();
         // O(1) eviction: remove first (oldest) entry if at capacity
         if !map.contains_key(wallet) && map.len() >= MAX_WALLET_BUCKETS {
@@ -418,8 +433,7 @@ impl RateLimiter {
             map.insert(wallet.to_string(), bucket);
             (available, time_to_reset)
         } else {
-            let mut bucket =
-                TokenBucket::new(self.config.per_wallet.limit, self.config.per_wallet.window);
+            let mut bucket = TokenBucket::new(config.per_wallet.limit, config.per_wallet.window);
             let available = bucket.available();
             let time_to_reset = bucket.time_to_next_token();
             map.insert(wallet.to_string(), bucket);
@@ -434,7 +448,7 @@ impl RateLimiter {
             + time_to_reset;
 
         RateLimitInfo {
-            limit: self.config.per_wallet.limit,
+            limit: config.per_wallet.limit,
             remaining: available,
             reset,
             retry_after: time_to_reset,
@@ -710,6 +724,25 @@ mod tests {
         assert_eq!(limiter.check(None, None), RateLimitResult::GlobalExceeded);
     }
 
+    #[test]
+    fn test_rate_limiter_reconfigure_applies_new_limits() {
+        let limiter = RateLimiter::new(test_config());
+        for _ in 0..10 {
+            assert_eq!(limiter.check(None, None), RateLimitResult::Allowed);
+        }
+        assert_eq!(limiter.check(None, None), RateLimitResult::GlobalExceeded);
+
+        let mut config = test_config();
+        config.global.limit = 20;
+        limiter.reconfigure(config);
+
+        assert_eq!(limiter.get_info().limit, 20);
+        for _ in 0..20 {
+            assert_eq!(limiter.check(None, None), RateLimitResult::Allowed);
+        }
+        assert_eq!(limiter.check(None, None), RateLimitResult::GlobalExceeded);
+    }
+
     #[test]
     fn test_rate_limiter_per_ip() {
         let config = test_config();
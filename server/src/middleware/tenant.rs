@@ -228,6 +228,7 @@ pub async fn tenant_middleware(mut request: Request, next: Next) -> Result<Respo
     };
 
     // Store tenant context in request extensions
+    tracing::Span::current().record("tenant_id", tenant_context.tenant_id.as_str());
     request.extensions_mut().insert(tenant_context);
 
     Ok(next.run(request).await)
@@ -261,6 +262,7 @@ pub async fn tenant_middleware_required(
         admin_actor: None,
     };
 
+    tracing::Span::current().record("tenant_id", tenant_context.tenant_id.as_str());
     request.extensions_mut().insert(tenant_context);
 
     Ok(next.run(request).await)
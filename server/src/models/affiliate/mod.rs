@@ -0,0 +1,295 @@
+//! Affiliate referral tracking and commission ledger.
+//!
+//! Affiliates are identified by a referral code captured on the cart quote.
+//! When the cart is paid, a commission is earned per item using the most
+//! specific matching rule (product, then collection, then the affiliate
+//! default). Refunds append negative `reversed` entries to the same ledger.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::payment_split::BPS_DENOMINATOR;
+
+/// Maximum referral code length.
+pub const MAX_REFERRAL_CODE_LEN: usize = 64;
+
+/// How a commission is computed.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CommissionType {
+    /// `commission_value` is in basis points of the discounted line amount.
+    #[default]
+    Percentage,
+    /// `commission_value` is a fixed atomic amount per unit sold.
+    Fixed,
+}
+
+/// Commission override for a product or collection.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AffiliateCommissionRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<String>,
+    pub commission_type: CommissionType,
+    pub commission_value: i64,
+}
+
+/// A referral partner.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Affiliate {
+    pub id: String,
+    pub tenant_id: String,
+    /// Referral code (stored uppercase)
+    pub code: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Wallet used for payouts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payout_wallet: Option<String>,
+    pub active: bool,
+    /// Default commission applied when no rule matches
+    pub commission_type: CommissionType,
+    pub commission_value: i64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<AffiliateCommissionRule>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Normalize a referral code for lookup (trimmed, uppercase).
+pub fn normalize_referral_code(code: &str) -> String {
+    code.trim().to_uppercase()
+}
+
+fn validate_commission(kind: CommissionType, value: i64) -> Result<(), String> {
+    if value < 0 {
+        return Err("commissionValue must be non-negative".into());
+    }
+    if kind == CommissionType::Percentage && value > BPS_DENOMINATOR as i64 {
+        return Err(format!(
+            "percentage commissionValue must not exceed {} bps",
+            BPS_DENOMINATOR
+        ));
+    }
+    Ok(())
+}
+
+fn commission_amount(kind: CommissionType, value: i64, line_amount: i64, quantity: i32) -> i64 {
+    match kind {
+        CommissionType::Percentage => {
+            ((line_amount.max(0) as i128 * value as i128) / BPS_DENOMINATOR as i128) as i64
+        }
+        CommissionType::Fixed => value.saturating_mul(quantity.max(0) as i64),
+    }
+}
+
+impl Affiliate {
+    /// Validate code, name and commission settings.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.code.is_empty() || self.code.len() > MAX_REFERRAL_CODE_LEN {
+            return Err(format!(
+                "code must be 1-{} characters",
+                MAX_REFERRAL_CODE_LEN
+            ));
+        }
+        if !self
+            .code
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err("code may only contain letters, digits, '-' and '_'".into());
+        }
+        if self.name.trim().is_empty() {
+            return Err("name is required".into());
+        }
+        validate_commission(self.commission_type, self.commission_value)?;
+        for (i, rule) in self.rules.iter().enumerate() {
+            if rule.product_id.is_some() == rule.collection_id.is_some() {
+                return Err(format!(
+                    "rules[{}] must set exactly one of productId or collectionId",
+                    i
+                ));
+            }
+            validate_commission(rule.commission_type, rule.commission_value)
+                .map_err(|e| format!("rules[{}]: {}", i, e))?;
+        }
+        Ok(())
+    }
+
+    /// Collection IDs referenced by collection rules.
+    pub fn rule_collection_ids(&self) -> impl Iterator<Item = &str> {
+        self.rules.iter().filter_map(|r| r.collection_id.as_deref())
+    }
+
+    /// Commission earned on one cart line.
+    ///
+    /// `collection_ids` are the collections containing the product; the first
+    /// matching collection rule (in rule order) wins.
+    pub fn commission_for_item(
+        &self,
+        product_id: &str,
+        collection_ids: &[String],
+        line_amount: i64,
+        quantity: i32,
+    ) -> i64 {
+        let rule = self
+            .rules
+            .iter()
+            .find(|r| r.product_id.as_deref() == Some(product_id))
+            .or_else(|| {
+                self.rules.iter().find(|r| {
+                    r.collection_id
+                        .as_deref()
+                        .is_some_and(|c| collection_ids.iter().any(|id| id == c))
+                })
+            });
+        match rule {
+            Some(r) => {
+                commission_amount(r.commission_type, r.commission_value, line_amount, quantity)
+            }
+            None => commission_amount(
+                self.commission_type,
+                self.commission_value,
+                line_amount,
+                quantity,
+            ),
+        }
+    }
+}
+
+/// Ledger entry kind.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CommissionEntryKind {
+    Earned,
+    Reversed,
+}
+
+impl CommissionEntryKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommissionEntryKind::Earned => "earned",
+            CommissionEntryKind::Reversed => "reversed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "earned" => Some(CommissionEntryKind::Earned),
+            "reversed" => Some(CommissionEntryKind::Reversed),
+            _ => None,
+        }
+    }
+}
+
+/// Append-only commission ledger entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AffiliateCommission {
+    /// Deterministic ID (`earned:{purchase_id}` / `reversed:{refund_id}:{affiliate_id}`)
+    /// so retries never double-book.
+    pub id: String,
+    pub tenant_id: String,
+    pub affiliate_id: String,
+    pub order_id: String,
+    pub purchase_id: String,
+    pub kind: CommissionEntryKind,
+    /// Signed atomic amount (negative for reversals)
+    pub amount: i64,
+    /// Order total the commission was earned on
+    pub order_amount: i64,
+    pub currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Commission to reverse for a refund of `refund_amount` against an order of
+/// `order_amount`: the `earned` commission pro-rated by the refunded share,
+/// capped at the commission still outstanding (`net`).
+pub fn reversal_amount(earned: i64, net: i64, order_amount: i64, refund_amount: i64) -> i64 {
+    if earned <= 0 || net <= 0 || order_amount <= 0 || refund_amount <= 0 {
+        return 0;
+    }
+    let refund_amount = refund_amount.min(order_amount);
+    let pro_rated = ((earned as i128 * refund_amount as i128) / order_amount as i128) as i64;
+    pro_rated.min(net)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn affiliate() -> Affiliate {
+        let now = Utc::now();
+        Affiliate {
+            id: "aff-1".into(),
+            tenant_id: "default".into(),
+            code: "PARTNER".into(),
+            name: "Partner".into(),
+            email: None,
+            payout_wallet: None,
+            active: true,
+            commission_type: CommissionType::Percentage,
+            commission_value: 1_000,
+            rules: vec![
+                AffiliateCommissionRule {
+                    product_id: Some("prod-fixed".into()),
+                    collection_id: None,
+                    commission_type: CommissionType::Fixed,
+                    commission_value: 250,
+                },
+                AffiliateCommissionRule {
+                    product_id: None,
+                    collection_id: Some("col-1".into()),
+                    commission_type: CommissionType::Percentage,
+                    commission_value: 2_000,
+                },
+            ],
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_commission_rule_precedence() {
+        let a = affiliate();
+        let cols = vec!["col-1".to_string()];
+        // Product rule beats collection rule
+        assert_eq!(a.commission_for_item("prod-fixed", &cols, 10_000, 3), 750);
+        // Collection rule
+        assert_eq!(a.commission_for_item("prod-2", &cols, 10_000, 1), 2_000);
+        // Default
+        assert_eq!(a.commission_for_item("prod-3", &[], 10_000, 1), 1_000);
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_rules() {
+        let mut a = affiliate();
+        assert!(a.validate().is_ok());
+        a.rules[0].collection_id = Some("col-2".into());
+        assert!(a.validate().is_err());
+
+        let mut a = affiliate();
+        a.commission_value = 10_001;
+        assert!(a.validate().is_err());
+
+        let mut a = affiliate();
+        a.code = "BAD CODE".into();
+        assert!(a.validate().is_err());
+    }
+
+    #[test]
+    fn test_reversal_amount_is_pro_rated_and_capped() {
+        assert_eq!(reversal_amount(1_000, 1_000, 10_000, 5_000), 500);
+        // Second half-refund reverses the same share of the original commission
+        assert_eq!(reversal_amount(1_000, 500, 10_000, 5_000), 500);
+        assert_eq!(reversal_amount(1_000, 1_000, 10_000, 20_000), 1_000);
+        assert_eq!(reversal_amount(1_000, 300, 10_000, 10_000), 300);
+        assert_eq!(reversal_amount(1_000, 0, 10_000, 10_000), 0);
+    }
+}
@@ -16,10 +16,18 @@ pub struct ChatSession {
     /// Email for anonymous users or from checkout flow
     #[serde(skip_serializing_if = "Option::is_none")]
     pub customer_email: Option<String>,
-    /// Session status: "active", "archived"
+    /// Session status: "active", "waiting_for_agent", "with_agent", "archived"
     pub status: String,
     /// Number of messages in this session
     pub message_count: i32,
+    /// Staff member who claimed the session (while "with_agent")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigned_agent: Option<String>,
+    /// Why a human was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handoff_reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handoff_requested_at: Option<DateTime<Utc>>,
     pub last_message_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -36,11 +44,19 @@ impl ChatSession {
             customer_email: None,
             status: "active".to_string(),
             message_count: 0,
+            assigned_agent: None,
+            handoff_reason: None,
+            handoff_requested_at: None,
             last_message_at: now,
             created_at: now,
             updated_at: now,
         }
     }
+
+    /// Whether a human is (or is about to be) answering instead of the assistant
+    pub fn is_handed_off(&self) -> bool {
+        self.status == status::WAITING_FOR_AGENT || self.status == status::WITH_AGENT
+    }
 }
 
 /// A single message in a chat session.
@@ -50,7 +66,7 @@ pub struct ChatMessage {
     pub id: String,
     pub tenant_id: String,
     pub session_id: String,
-    /// Message role: "user", "assistant", "tool"
+    /// Message role: "user", "assistant", "tool", "agent"
     pub role: String,
     /// Text content of the message
     pub content: String,
@@ -60,6 +76,9 @@ pub struct ChatMessage {
     /// Results from tool execution (JSON object with products, actions, etc.)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tool_results: Option<Value>,
+    /// Staff member who wrote an "agent" message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_id: Option<String>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -74,6 +93,7 @@ impl ChatMessage {
             content,
             tool_calls: None,
             tool_results: None,
+            author_id: None,
             created_at: Utc::now(),
         }
     }
@@ -94,6 +114,28 @@ impl ChatMessage {
             content,
             tool_calls: None,
             tool_results,
+            author_id: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Create a new message from a human agent
+    pub fn agent(
+        tenant_id: String,
+        session_id: String,
+        id: String,
+        content: String,
+        author_id: String,
+    ) -> Self {
+        Self {
+            id,
+            tenant_id,
+            session_id,
+            role: role::AGENT.to_string(),
+            content,
+            tool_calls: None,
+            tool_results: None,
+            author_id: Some(author_id),
             created_at: Utc::now(),
         }
     }
@@ -104,10 +146,15 @@ pub mod role {
     pub const USER: &str = "user";
     pub const ASSISTANT: &str = "assistant";
     pub const TOOL: &str = "tool";
+    pub const AGENT: &str = "agent";
 }
 
 /// Session status constants
 pub mod status {
     pub const ACTIVE: &str = "active";
+    /// A human was requested; the assistant stops answering
+    pub const WAITING_FOR_AGENT: &str = "waiting_for_agent";
+    /// Claimed by a staff member
+    pub const WITH_AGENT: &str = "with_agent";
     pub const ARCHIVED: &str = "archived";
 }
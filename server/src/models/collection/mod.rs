@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::payment_split::PaymentSplit;
 use super::tokenization::TokenizationConfig;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +18,9 @@ pub struct Collection {
     /// When set, this collection acts as an asset class for tokenized assets.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tokenization_config: Option<TokenizationConfig>,
+    /// Default x402 revenue split for member products without their own split.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_split: Option<PaymentSplit>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -0,0 +1,362 @@
+//! Double-entry accounting journal.
+//!
+//! Every money movement (payments, refunds, gift card liability changes,
+//! Stripe platform fees, credits holds) is posted as a balanced journal entry: the sum of
+//! debits equals the sum of credits for the entry's currency. Entries are
+//! append-only and carry deterministic IDs derived from their source event,
+//! so reposting the same event is a no-op. Corrections are new entries.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of lines in one journal entry.
+pub const MAX_JOURNAL_LINES: usize = 32;
+
+/// Prefix of per-rail receivable accounts (`receivable:x402`, `receivable:stripe`, ...).
+pub const RECEIVABLE_ACCOUNT_PREFIX: &str = "receivable:";
+/// Sales revenue, net of discounts and tax.
+pub const ACCOUNT_REVENUE: &str = "revenue";
+/// Tax collected on behalf of tax authorities.
+pub const ACCOUNT_TAX_PAYABLE: &str = "tax_payable";
+/// Outstanding gift card balances.
+pub const ACCOUNT_GIFT_CARD_LIABILITY: &str = "gift_card_liability";
+/// Refunds issued (contra-revenue).
+pub const ACCOUNT_REFUNDS: &str = "refunds";
+/// Processor and platform fees paid.
+pub const ACCOUNT_FEES: &str = "fees";
+/// Token-22 transfer fees harvested to the treasury.
+pub const ACCOUNT_FEE_INCOME: &str = "fee_income";
+/// Gift card value issued or topped up without a sale.
+pub const ACCOUNT_PROMOTIONS: &str = "promotions";
+/// Opening balances and owner adjustments.
+pub const ACCOUNT_EQUITY: &str = "equity";
+/// Customer credits reserved by open holds, not yet captured or released.
+pub const ACCOUNT_CREDITS_HELD: &str = "credits_held";
+/// Offset of [`ACCOUNT_CREDITS_HELD`]; the pair nets to zero once every hold settles.
+pub const ACCOUNT_CREDITS_HOLD_CLEARING: &str = "credits_hold_clearing";
+
+/// Receivable account for a payment rail.
+pub fn receivable_account(rail: &str) -> String {
+    format!("{}{}", RECEIVABLE_ACCOUNT_PREFIX, rail)
+}
+
+/// Account classification, used for trial balance presentation.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountType {
+    Asset,
+    Liability,
+    Equity,
+    Revenue,
+    ContraRevenue,
+    Expense,
+}
+
+impl AccountType {
+    /// Whether the account's balance normally sits on the debit side.
+    pub fn is_debit_normal(&self) -> bool {
+        matches!(
+            self,
+            AccountType::Asset | AccountType::ContraRevenue | AccountType::Expense
+        )
+    }
+}
+
+/// Classify an account code; `None` for unknown accounts.
+pub fn account_type(account: &str) -> Option<AccountType> {
+    if let Some(rail) = account.strip_prefix(RECEIVABLE_ACCOUNT_PREFIX) {
+        let valid = !rail.is_empty()
+            && rail
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        return valid.then_some(AccountType::Asset);
+    }
+    match account {
+        ACCOUNT_REVENUE | ACCOUNT_FEE_INCOME => Some(AccountType::Revenue),
+        ACCOUNT_CREDITS_HELD => Some(AccountType::Asset),
+        ACCOUNT_TAX_PAYABLE | ACCOUNT_GIFT_CARD_LIABILITY | ACCOUNT_CREDITS_HOLD_CLEARING => {
+            Some(AccountType::Liability)
+        }
+        ACCOUNT_REFUNDS => Some(AccountType::ContraRevenue),
+        ACCOUNT_FEES | ACCOUNT_PROMOTIONS => Some(AccountType::Expense),
+        ACCOUNT_EQUITY => Some(AccountType::Equity),
+        _ => None,
+    }
+}
+
+/// Event that produced a journal entry.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalSource {
+    Payment,
+    Refund,
+    StripeRefund,
+    GiftCardRedemption,
+    GiftCardIssue,
+    GiftCardAdjustment,
+    StripeFee,
+    StripeFeeRefund,
+    CreditsHold,
+    CreditsCapture,
+    CreditsRelease,
+    Manual,
+}
+
+impl JournalSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JournalSource::Payment => "payment",
+            JournalSource::Refund => "refund",
+            JournalSource::StripeRefund => "stripe_refund",
+            JournalSource::GiftCardRedemption => "gift_card_redemption",
+            JournalSource::GiftCardIssue => "gift_card_issue",
+            JournalSource::GiftCardAdjustment => "gift_card_adjustment",
+            JournalSource::StripeFee => "stripe_fee",
+            JournalSource::StripeFeeRefund => "stripe_fee_refund",
+            JournalSource::CreditsHold => "credits_hold",
+            JournalSource::CreditsCapture => "credits_capture",
+            JournalSource::CreditsRelease => "credits_release",
+            JournalSource::Manual => "manual",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "payment" => Some(JournalSource::Payment),
+            "refund" => Some(JournalSource::Refund),
+            "stripe_refund" => Some(JournalSource::StripeRefund),
+            "gift_card_redemption" => Some(JournalSource::GiftCardRedemption),
+            "gift_card_issue" => Some(JournalSource::GiftCardIssue),
+            "gift_card_adjustment" => Some(JournalSource::GiftCardAdjustment),
+            "stripe_fee" => Some(JournalSource::StripeFee),
+            "stripe_fee_refund" => Some(JournalSource::StripeFeeRefund),
+            "credits_hold" => Some(JournalSource::CreditsHold),
+            "credits_capture" => Some(JournalSource::CreditsCapture),
+            "credits_release" => Some(JournalSource::CreditsRelease),
+            "manual" => Some(JournalSource::Manual),
+            _ => None,
+        }
+    }
+}
+
+/// One side of a journal entry. Exactly one of `debit` / `credit` is positive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalLine {
+    pub account: String,
+    #[serde(default)]
+    pub debit: i64,
+    #[serde(default)]
+    pub credit: i64,
+}
+
+impl JournalLine {
+    pub fn debit(account: impl Into<String>, amount: i64) -> Self {
+        Self {
+            account: account.into(),
+            debit: amount,
+            credit: 0,
+        }
+    }
+
+    pub fn credit(account: impl Into<String>, amount: i64) -> Self {
+        Self {
+            account: account.into(),
+            debit: 0,
+            credit: amount,
+        }
+    }
+}
+
+/// Balanced journal entry in a single currency (atomic units).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalEntry {
+    /// Deterministic ID (`{source}:{source_id}`) so reposting is a no-op.
+    pub id: String,
+    pub tenant_id: String,
+    pub source: JournalSource,
+    /// Payment signature, refund ID, gift card code, fee ID, ...
+    pub source_id: String,
+    /// Uppercase asset code (e.g. `USD`, `USDC`)
+    pub currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub lines: Vec<JournalLine>,
+    /// When the money moved
+    pub occurred_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl JournalEntry {
+    /// Entry whose ID is derived from its source event.
+    pub fn new(
+        tenant_id: &str,
+        source: JournalSource,
+        source_id: &str,
+        currency: &str,
+        lines: Vec<JournalLine>,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: format!("{}:{}", source.as_str(), source_id),
+            tenant_id: tenant_id.to_string(),
+            source,
+            source_id: source_id.to_string(),
+            currency: currency.to_uppercase(),
+            description: None,
+            lines,
+            occurred_at,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn total_debits(&self) -> i64 {
+        self.lines.iter().map(|l| l.debit).sum()
+    }
+
+    pub fn total_credits(&self) -> i64 {
+        self.lines.iter().map(|l| l.credit).sum()
+    }
+
+    /// Check the entry is well-formed and balanced.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.currency.trim().is_empty() {
+            return Err("currency is required".into());
+        }
+        if self.lines.len() < 2 || self.lines.len() > MAX_JOURNAL_LINES {
+            return Err(format!(
+                "an entry needs 2-{} lines, got {}",
+                MAX_JOURNAL_LINES,
+                self.lines.len()
+            ));
+        }
+        let mut debits: i64 = 0;
+        let mut credits: i64 = 0;
+        for (i, line) in self.lines.iter().enumerate() {
+            if account_type(&line.account).is_none() {
+                return Err(format!("lines[{}]: unknown account '{}'", i, line.account));
+            }
+            if line.debit < 0 || line.credit < 0 || (line.debit > 0) == (line.credit > 0) {
+                return Err(format!(
+                    "lines[{}]: exactly one of debit or credit must be positive",
+                    i
+                ));
+            }
+            debits = debits
+                .checked_add(line.debit)
+                .ok_or_else(|| "debit total overflows".to_string())?;
+            credits = credits
+                .checked_add(line.credit)
+                .ok_or_else(|| "credit total overflows".to_string())?;
+        }
+        if debits != credits {
+            return Err(format!(
+                "entry is unbalanced: debits {} != credits {}",
+                debits, credits
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Cumulative debits and credits of one account in one currency.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TrialBalanceRow {
+    pub account: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<AccountType>,
+    pub currency: String,
+    pub debit: i64,
+    pub credit: i64,
+    /// Balance on the account's normal side (debit - credit for debit-normal accounts)
+    pub balance: i64,
+}
+
+impl TrialBalanceRow {
+    pub fn new(account: String, currency: String, debit: i64, credit: i64) -> Self {
+        let account_type = account_type(&account);
+        let balance = match account_type {
+            Some(t) if !t.is_debit_normal() => credit - debit,
+            _ => debit - credit,
+        };
+        Self {
+            account,
+            account_type,
+            currency,
+            debit,
+            credit,
+            balance,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(lines: Vec<JournalLine>) -> JournalEntry {
+        JournalEntry::new(
+            "t1",
+            JournalSource::Manual,
+            "ref-1",
+            "usd",
+            lines,
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_entry_validation() {
+        let ok = entry(vec![
+            JournalLine::debit(receivable_account("x402"), 1_000),
+            JournalLine::credit(ACCOUNT_REVENUE, 900),
+            JournalLine::credit(ACCOUNT_TAX_PAYABLE, 100),
+        ]);
+        assert_eq!(ok.id, "manual:ref-1");
+        assert_eq!(ok.currency, "USD");
+        assert!(ok.validate().is_ok());
+
+        let unbalanced = entry(vec![
+            JournalLine::debit(ACCOUNT_FEES, 10),
+            JournalLine::credit(receivable_account("stripe"), 9),
+        ]);
+        assert!(unbalanced.validate().unwrap_err().contains("unbalanced"));
+
+        let unknown = entry(vec![
+            JournalLine::debit("cash", 10),
+            JournalLine::credit(ACCOUNT_REVENUE, 10),
+        ]);
+        assert!(unknown.validate().unwrap_err().contains("unknown account"));
+
+        let two_sided = entry(vec![
+            JournalLine {
+                account: ACCOUNT_FEES.to_string(),
+                debit: 10,
+                credit: 10,
+            },
+            JournalLine::credit(ACCOUNT_REVENUE, 0),
+        ]);
+        assert!(two_sided.validate().is_err());
+    }
+
+    #[test]
+    fn test_trial_balance_row_normal_side() {
+        let receivable = TrialBalanceRow::new(receivable_account("x402"), "USD".into(), 500, 200);
+        assert_eq!(receivable.account_type, Some(AccountType::Asset));
+        assert_eq!(receivable.balance, 300);
+
+        let revenue = TrialBalanceRow::new(ACCOUNT_REVENUE.into(), "USD".into(), 50, 400);
+        assert_eq!(revenue.balance, 350);
+
+        let refunds = TrialBalanceRow::new(ACCOUNT_REFUNDS.into(), "USD".into(), 50, 0);
+        assert_eq!(refunds.balance, 50);
+        assert!(account_type("receivable:").is_none());
+    }
+}
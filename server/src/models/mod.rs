@@ -1,4 +1,5 @@
 pub mod admin_audit;
+pub mod affiliate;
 pub mod asset_redemption;
 pub mod compliance;
 pub mod cart;
@@ -11,14 +12,19 @@ pub mod faq;
 pub mod gift_card;
 pub mod gift_card_redemption;
 pub mod inventory;
+pub mod ledger;
 pub mod money;
 pub mod order;
 pub mod payment;
+pub mod payment_split;
+pub mod price_schedule;
 pub mod product;
+pub mod product_import;
 pub mod refund;
 pub mod returns;
 pub mod shipping;
 pub mod stablecoins;
+pub mod stripe_connect;
 pub mod stripe_refund_request;
 pub mod subscription;
 pub mod subscription_settings;
@@ -27,6 +33,9 @@ pub mod tenant_token22_mint;
 pub mod tokenization;
 pub mod webhook;
 
+pub use affiliate::{
+    Affiliate, AffiliateCommission, AffiliateCommissionRule, CommissionEntryKind, CommissionType,
+};
 pub use cart::{CartItem, CartQuote};
 pub use chat::{ChatMessage, ChatSession};
 pub use collection::Collection;
@@ -37,6 +46,7 @@ pub use faq::Faq;
 pub use gift_card::GiftCard;
 pub use gift_card_redemption::GiftCardRedemption;
 pub use inventory::InventoryAdjustment;
+pub use ledger::{AccountType, JournalEntry, JournalLine, JournalSource, TrialBalanceRow};
 pub use money::{
     get_asset, list_assets, must_get_asset, register_asset, try_get_asset, Asset, AssetMetadata,
     AssetType, Money, MoneyError, RoundingMode,
@@ -44,26 +54,38 @@ pub use money::{
 pub use order::{
     is_valid_order_transition, Fulfillment, FulfillmentStatus, InventoryReservation, Order,
     OrderHistoryEntry, OrderItem, OrderShipping, OrderStatus, ReservationStatus,
+    ORDER_DISCOUNT_AMOUNT_KEY, ORDER_GIFT_CARD_AMOUNT_KEY, ORDER_SHIPPING_AMOUNT_KEY,
+    ORDER_TAX_AMOUNT_KEY,
 };
 pub use payment::{
     AuthorizationResult, CreditsOption, CryptoQuote, PaymentPayload, PaymentProof,
     PaymentTransaction, Quote, Requirement, SettlementResponse, SolanaExtra, SolanaPayload,
     StripeOption, SubscriptionInfo, VerificationResult,
 };
+pub use payment_split::{PaymentLeg, PaymentSplit, SplitRole};
+pub use price_schedule::{PriceSchedule, PriceScheduleStatus, PriceSnapshot};
 pub use product::{
     CheckoutRequirements, FulfillmentInfo, GiftCardConfig, Product, ProductImage, ProductVariant,
     ProductVariationConfig, SubscriptionConfig, VariantPrice, VariationType, VariationValue,
 };
+pub use product_import::{
+    ProductImportFormat, ProductImportJob, ProductImportRowError, ProductImportStatus,
+    PRODUCT_IMPORT_HEARTBEAT_SECS, PRODUCT_IMPORT_INTERRUPTED_ERROR,
+    PRODUCT_IMPORT_STALE_AFTER_SECS,
+};
 // TokenizedAssetConfig is re-exported from tokenization module above
 pub use admin_audit::AdminAuditEntry;
 pub use asset_redemption::{AssetRedemption, AssetRedemptionStatus};
 pub use refund::RefundQuote;
-pub use returns::{is_valid_return_transition, ReturnRequest};
+pub use returns::{
+    is_valid_return_transition, ReturnItem, ReturnRequest, RETURN_CONDITIONS, RETURN_REASON_CODES,
+};
 pub use shipping::{ShippingProfile, ShippingRate};
 pub use stablecoins::{
     get_mint_for_symbol, get_stablecoin_symbol, is_stablecoin, validate_stablecoin_mint,
     KNOWN_STABLECOINS,
 };
+pub use stripe_connect::{StripeApplicationFee, StripeConnectAccount};
 pub use stripe_refund_request::StripeRefundRequest;
 pub use subscription::{BillingPeriod, PaymentMethod, Subscription, SubscriptionStatus};
 pub use subscription_settings::{SubscriptionPlan, SubscriptionSettings};
@@ -71,6 +71,10 @@ pub struct OrderItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub variant_id: Option<String>,
     pub quantity: i32,
+    /// Amount paid for the line after item-level discounts, in atomic units
+    /// of the order's `amount_asset`. `None` when the purchase did not record it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_total: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -84,6 +88,15 @@ pub struct OrderShipping {
     pub address: Option<serde_json::Value>,
 }
 
+/// Order metadata key: discounts applied, in atomic units of `amount_asset`.
+pub const ORDER_DISCOUNT_AMOUNT_KEY: &str = "discount_amount";
+/// Order metadata key: tax collected, in atomic units of `amount_asset`.
+pub const ORDER_TAX_AMOUNT_KEY: &str = "tax_amount";
+/// Order metadata key: shipping charged, in atomic units of `amount_asset`.
+pub const ORDER_SHIPPING_AMOUNT_KEY: &str = "shipping_amount";
+/// Order metadata key: gift card balance redeemed, in atomic units of `amount_asset`.
+pub const ORDER_GIFT_CARD_AMOUNT_KEY: &str = "gift_card_applied_amount";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
@@ -120,6 +133,17 @@ pub struct Order {
     pub status_updated_at: Option<DateTime<Utc>>,
 }
 
+impl Order {
+    /// Atomic amount stored under a metadata key; missing or malformed values count as zero.
+    pub fn metadata_amount(&self, key: &str) -> i64 {
+        self.metadata
+            .get(key)
+            .and_then(|v| v.trim().parse::<i64>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(0)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderHistoryEntry {
@@ -4,6 +4,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::models::money::Money;
+use crate::models::payment_split::PaymentLeg;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -101,6 +102,10 @@ pub struct SolanaExtra {
     pub memo: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fee_payer: Option<String>,
+    /// Revenue split legs; when present the payment must include one
+    /// `TransferChecked` per leg instead of a single transfer to `payTo`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub splits: Vec<PaymentLeg>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -206,6 +211,10 @@ pub struct Requirement {
     pub skip_preflight: bool,
     #[serde(default)]
     pub commitment: String,
+    /// Split payment legs. When non-empty the verifier requires one transfer per
+    /// leg and `recipient_token_account` is not used.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub splits: Vec<PaymentLeg>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
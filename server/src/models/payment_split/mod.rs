@@ -0,0 +1,227 @@
+//! x402 revenue splits for multi-party payouts.
+//!
+//! A product (or a collection it belongs to) can declare how crypto revenue is
+//! divided between the platform, the seller and an optional affiliate. Quotes
+//! for such products require one SPL `TransferChecked` per leg instead of a
+//! single transfer to the platform payment address.
+
+use serde::{Deserialize, Serialize};
+
+/// Basis points denominator (100% = 10_000 bps).
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Revenue split declared on a product or collection.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentSplit {
+    /// Platform fee in basis points, paid to the configured x402 payment address.
+    #[serde(default)]
+    pub platform_fee_bps: u32,
+    /// Seller wallet (owner address) receiving the remainder.
+    pub seller_wallet: String,
+    /// Optional affiliate wallet (owner address).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub affiliate_wallet: Option<String>,
+    /// Affiliate commission in basis points (requires `affiliate_wallet`).
+    #[serde(default)]
+    pub affiliate_bps: u32,
+}
+
+/// Who receives a leg of a split payment.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum SplitRole {
+    Platform,
+    Seller,
+    Affiliate,
+}
+
+impl SplitRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SplitRole::Platform => "platform",
+            SplitRole::Seller => "seller",
+            SplitRole::Affiliate => "affiliate",
+        }
+    }
+}
+
+/// One recipient leg of a split x402 payment.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentLeg {
+    pub role: SplitRole,
+    /// Wallet (owner) address of the recipient.
+    pub recipient_owner: String,
+    /// Associated token account the transfer must credit.
+    pub recipient_token_account: String,
+    /// Exact leg amount in atomic units.
+    pub amount_atomic: u64,
+}
+
+impl PaymentSplit {
+    /// Validate basis points and wallet presence.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.seller_wallet.trim().is_empty() {
+            return Err("sellerWallet is required".into());
+        }
+        if self.affiliate_bps > 0
+            && self
+                .affiliate_wallet
+                .as_deref()
+                .map_or(true, |w| w.trim().is_empty())
+        {
+            return Err("affiliateWallet is required when affiliateBps is set".into());
+        }
+        let total = self.platform_fee_bps.saturating_add(self.affiliate_bps);
+        if total > BPS_DENOMINATOR {
+            return Err(format!(
+                "platformFeeBps + affiliateBps must not exceed {}",
+                BPS_DENOMINATOR
+            ));
+        }
+        Ok(())
+    }
+
+    /// Divide `total` atomic units into `(role, owner, amount)` shares.
+    ///
+    /// Fees round down; the seller receives the remainder so the legs always
+    /// sum to `total`. Zero-amount legs are omitted.
+    pub fn allocate(&self, total: u64, platform_owner: &str) -> Vec<(SplitRole, String, u64)> {
+        let share = |bps: u32| -> u64 {
+            ((total as u128 * bps.min(BPS_DENOMINATOR) as u128) / BPS_DENOMINATOR as u128) as u64
+        };
+
+        let platform = share(self.platform_fee_bps);
+        let affiliate = match self.affiliate_wallet.as_deref() {
+            Some(w) if !w.is_empty() => share(self.affiliate_bps),
+            _ => 0,
+        };
+        let seller = total.saturating_sub(platform).saturating_sub(affiliate);
+
+        let mut legs = Vec::with_capacity(3);
+        if platform > 0 {
+            legs.push((SplitRole::Platform, platform_owner.to_string(), platform));
+        }
+        if seller > 0 {
+            legs.push((SplitRole::Seller, self.seller_wallet.clone(), seller));
+        }
+        if affiliate > 0 {
+            if let Some(wallet) = &self.affiliate_wallet {
+                legs.push((SplitRole::Affiliate, wallet.clone(), affiliate));
+            }
+        }
+        legs
+    }
+}
+
+/// Pro-rate a refund of `refund_amount` across the legs of the original payment.
+///
+/// Returns the amount each leg's recipient must reverse. Rounding remainders are
+/// assigned to the seller leg (or the last leg when there is no seller).
+pub fn reverse_legs(legs: &[PaymentLeg], refund_amount: u64) -> Vec<PaymentLeg> {
+    let total: u64 = legs.iter().map(|l| l.amount_atomic).sum();
+    if total == 0 || legs.is_empty() {
+        return Vec::new();
+    }
+    let refund_amount = refund_amount.min(total);
+
+    let mut reversed: Vec<PaymentLeg> = legs
+        .iter()
+        .map(|leg| PaymentLeg {
+            amount_atomic: ((leg.amount_atomic as u128 * refund_amount as u128) / total as u128)
+                as u64,
+            ..leg.clone()
+        })
+        .collect();
+
+    let allocated: u64 = reversed.iter().map(|l| l.amount_atomic).sum();
+    let remainder = refund_amount.saturating_sub(allocated);
+    if remainder > 0 {
+        let idx = reversed
+            .iter()
+            .position(|l| l.role == SplitRole::Seller)
+            .unwrap_or(reversed.len() - 1);
+        reversed[idx].amount_atomic += remainder;
+    }
+    reversed.retain(|l| l.amount_atomic > 0);
+    reversed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split() -> PaymentSplit {
+        PaymentSplit {
+            platform_fee_bps: 500,
+            seller_wallet: "seller".into(),
+            affiliate_wallet: Some("affiliate".into()),
+            affiliate_bps: 1_000,
+        }
+    }
+
+    #[test]
+    fn test_allocate_sums_to_total() {
+        let legs = split().allocate(1_000_001, "platform");
+        assert_eq!(legs.len(), 3);
+        assert_eq!(
+            legs[0],
+            (SplitRole::Platform, "platform".to_string(), 50_000)
+        );
+        assert_eq!(
+            legs[2],
+            (SplitRole::Affiliate, "affiliate".to_string(), 100_000)
+        );
+        let sum: u64 = legs.iter().map(|l| l.2).sum();
+        assert_eq!(sum, 1_000_001);
+    }
+
+    #[test]
+    fn test_allocate_omits_zero_legs() {
+        let s = PaymentSplit {
+            seller_wallet: "seller".into(),
+            ..Default::default()
+        };
+        let legs = s.allocate(100, "platform");
+        assert_eq!(legs, vec![(SplitRole::Seller, "seller".to_string(), 100)]);
+    }
+
+    #[test]
+    fn test_validate_rejects_excess_bps_and_missing_affiliate() {
+        let mut s = split();
+        s.platform_fee_bps = 9_500;
+        assert!(s.validate().is_err());
+
+        let s = PaymentSplit {
+            seller_wallet: "seller".into(),
+            affiliate_bps: 100,
+            ..Default::default()
+        };
+        assert!(s.validate().is_err());
+        assert!(split().validate().is_ok());
+    }
+
+    #[test]
+    fn test_reverse_legs_pro_rates_refund() {
+        let legs: Vec<PaymentLeg> = split()
+            .allocate(1_000, "platform")
+            .into_iter()
+            .map(|(role, owner, amount)| PaymentLeg {
+                role,
+                recipient_token_account: format!("{}-ata", owner),
+                recipient_owner: owner,
+                amount_atomic: amount,
+            })
+            .collect();
+
+        let reversed = reverse_legs(&legs, 333);
+        let sum: u64 = reversed.iter().map(|l| l.amount_atomic).sum();
+        assert_eq!(sum, 333);
+        let platform = reversed
+            .iter()
+            .find(|l| l.role == SplitRole::Platform)
+            .unwrap();
+        assert_eq!(platform.amount_atomic, 16);
+    }
+}
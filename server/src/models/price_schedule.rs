@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::money::Money;
+use crate::models::product::VariantPrice;
+
+/// Status flow: scheduled → active → completed (reverted at `ends_at`, or
+///                                   never reverted when there is no end)
+///                        → cancelled
+///                        → failed (the change could not be applied)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceScheduleStatus {
+    Scheduled,
+    Active,
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+impl PriceScheduleStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Scheduled => "scheduled",
+            Self::Active => "active",
+            Self::Completed => "completed",
+            Self::Cancelled => "cancelled",
+            Self::Failed => "failed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "scheduled" => Some(Self::Scheduled),
+            "active" => Some(Self::Active),
+            "completed" => Some(Self::Completed),
+            "cancelled" => Some(Self::Cancelled),
+            "failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// Prices a schedule replaced, captured when it is applied and restored when
+/// it ends
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceSnapshot {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fiat_price: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compare_at_fiat_price: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stripe_price_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crypto_price: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_price: Option<VariantPrice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_compare_at_price: Option<VariantPrice>,
+}
+
+/// Price change of a product (fiat and/or crypto) or of one variant, applied
+/// at `starts_at` and reverted at `ends_at` by the price schedule worker.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceSchedule {
+    pub id: String,
+    pub tenant_id: String,
+    pub product_id: String,
+    /// Set for a variant price change; product prices are left alone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fiat_price: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crypto_price: Option<Money>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_price: Option<VariantPrice>,
+    /// Show the replaced price as the compare-at price while active (a sale)
+    #[serde(default)]
+    pub show_as_sale: bool,
+    pub starts_at: DateTime<Utc>,
+    /// No end: the change is permanent once applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<DateTime<Utc>>,
+    pub status: PriceScheduleStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original: Option<PriceSnapshot>,
+    /// Stripe price created for the new fiat price
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stripe_price_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Why the change failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl PriceSchedule {
+    /// Scheduled or active schedules for the same product/variant whose
+    /// windows overlap cannot both be applied
+    pub fn overlaps(&self, other: &PriceSchedule) -> bool {
+        let open = |s: &PriceSchedule| {
+            matches!(
+                s.status,
+                PriceScheduleStatus::Scheduled | PriceScheduleStatus::Active
+            )
+        };
+        if !open(self) || !open(other) || self.variant_id != other.variant_id {
+            return false;
+        }
+        let starts_before_end = |s: &PriceSchedule, end: Option<DateTime<Utc>>| {
+            end.map_or(true, |end| s.starts_at < end)
+        };
+        starts_before_end(self, other.ends_at) && starts_before_end(other, self.ends_at)
+    }
+}
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::models::compliance::ComplianceRequirements;
 use crate::models::money::Money;
+use crate::models::payment_split::PaymentSplit;
 use crate::models::tokenization::TokenizedAssetConfig;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -15,7 +16,7 @@ pub struct ProductImage {
     pub alt: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct VariantPrice {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -233,6 +234,10 @@ pub struct Product {
     /// When `None`, defaults apply: sanctions check only.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compliance_requirements: Option<ComplianceRequirements>,
+    /// Optional x402 revenue split (platform fee, seller and affiliate wallets).
+    /// Overrides any split declared on a collection containing this product.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_split: Option<PaymentSplit>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,113 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Source format of a bulk product import (and of a catalog export)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProductImportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+impl ProductImportFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Status flow: queued → running → completed
+///                              → failed (the job could not run at all, or
+///                                the process running it stopped)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProductImportStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl ProductImportStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "queued" => Some(Self::Queued),
+            "running" => Some(Self::Running),
+            "completed" => Some(Self::Completed),
+            "failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// A running import saves progress at least this often (seconds)
+pub const PRODUCT_IMPORT_HEARTBEAT_SECS: i64 = 30;
+
+/// A queued or running import not updated for this long (seconds) was
+/// interrupted, e.g. by a restart: its records only lived in that process
+pub const PRODUCT_IMPORT_STALE_AFTER_SECS: i64 = 600;
+
+/// Error set on imports that were interrupted
+pub const PRODUCT_IMPORT_INTERRUPTED_ERROR: &str =
+    "Import was interrupted before it finished (server restart); run it again";
+
+/// A product of the import that was rejected
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductImportRowError {
+    /// 1-based record number in the file: CSV data row (after the header) of
+    /// the product's first row, or index in the JSON array
+    pub row: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<String>,
+    pub message: String,
+}
+
+/// Background bulk import of products, polled for progress.
+///
+/// Counts are per product (a CSV product may span several rows). In a dry run
+/// `created` / `updated` count the products that would be written.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductImportJob {
+    pub id: String,
+    pub tenant_id: String,
+    pub format: ProductImportFormat,
+    pub dry_run: bool,
+    pub status: ProductImportStatus,
+    pub total: i64,
+    pub processed: i64,
+    pub created: i64,
+    pub updated: i64,
+    pub failed: i64,
+    /// Per-product errors (capped; `failed` is the full count)
+    #[serde(default)]
+    pub errors: Vec<ProductImportRowError>,
+    /// Why the job failed as a whole
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+}
@@ -5,6 +5,66 @@ use serde::{Deserialize, Serialize};
 
 use crate::models::OrderItem;
 
+/// Item conditions accepted on return line items.
+pub const RETURN_CONDITIONS: &[&str] = &["unopened", "opened", "used", "damaged", "defective"];
+
+/// Reason codes accepted on return line items.
+pub const RETURN_REASON_CODES: &[&str] = &[
+    "defective",
+    "damaged_in_transit",
+    "wrong_item",
+    "not_as_described",
+    "size_or_fit",
+    "changed_mind",
+    "other",
+];
+
+/// A single returned line. Deserializes from a plain [`OrderItem`] payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnItem {
+    pub product_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_id: Option<String>,
+    pub quantity: i32,
+    /// Condition of the item as received (see [`RETURN_CONDITIONS`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+    /// Why the item is being returned (see [`RETURN_REASON_CODES`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason_code: Option<String>,
+}
+
+impl ReturnItem {
+    /// Whether the merchant is at fault, in which case no restocking fee applies.
+    pub fn is_merchant_fault(&self) -> bool {
+        matches!(
+            self.reason_code.as_deref(),
+            Some("defective" | "damaged_in_transit" | "wrong_item" | "not_as_described")
+        )
+    }
+
+    /// Whether the item can go back on the shelf (unknown condition counts as resellable).
+    pub fn is_restockable(&self) -> bool {
+        matches!(
+            self.condition.as_deref(),
+            None | Some("unopened") | Some("opened")
+        )
+    }
+}
+
+impl From<OrderItem> for ReturnItem {
+    fn from(item: OrderItem) -> Self {
+        Self {
+            product_id: item.product_id,
+            variant_id: item.variant_id,
+            quantity: item.quantity,
+            condition: None,
+            reason_code: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReturnRequest {
@@ -12,11 +72,25 @@ pub struct ReturnRequest {
     pub tenant_id: String,
     pub order_id: String,
     pub status: String,
-    pub items: Vec<OrderItem>,
+    pub items: Vec<ReturnItem>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
     #[serde(default)]
     pub metadata: HashMap<String, String>,
+    /// Restocking fee withheld from the refund, in atomic units of the order asset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restocking_fee: Option<i64>,
+    /// Amount to refund (after restocking fee), in atomic units of the order asset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_amount: Option<i64>,
+    /// Refund path used: `stripe`, `x402`, `credits` or `manual`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_method: Option<String>,
+    /// ID of the Stripe refund request or refund quote created for this return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restocked_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<DateTime<Utc>>,
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A tenant's Stripe Connect (Express) account.
+///
+/// Mirrors the capability flags Stripe reports via `account.updated` so checkout
+/// can decide whether to route funds to the account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StripeConnectAccount {
+    pub tenant_id: String,
+    /// Stripe account ID (`acct_...`)
+    pub account_id: String,
+    pub charges_enabled: bool,
+    pub payouts_enabled: bool,
+    pub details_submitted: bool,
+    /// Per-account platform fee override in basis points
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_bps: Option<u32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl StripeConnectAccount {
+    /// Funds can only be routed to accounts Stripe has enabled for charges.
+    pub fn can_accept_charges(&self) -> bool {
+        self.charges_enabled
+    }
+}
+
+/// Platform application fee collected on a connected account charge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StripeApplicationFee {
+    /// Stripe application fee ID (`fee_...`)
+    pub id: String,
+    pub tenant_id: String,
+    pub account_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge_id: Option<String>,
+    /// Fee amount in cents (atomic units)
+    pub amount: i64,
+    pub amount_refunded: i64,
+    /// Lowercase currency code (e.g., "usd")
+    pub currency: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl StripeApplicationFee {
+    /// Fee retained by the platform after refunds.
+    pub fn net_amount(&self) -> i64 {
+        self.amount - self.amount_refunded
+    }
+}
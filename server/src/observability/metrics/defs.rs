@@ -361,3 +361,23 @@ pub(super) static AI_CACHE_HITS_TOTAL: LazyLock<CounterVec> = LazyLock::new(|| {
     )
     .expect("ai_cache_hits_total metric")
 });
+
+pub(super) static AI_TOKENS_TOTAL: LazyLock<CounterVec> = LazyLock::new(|| {
+    register_counter_vec_with_registry!(
+        "ai_tokens_total",
+        "Total AI tokens consumed",
+        &["provider", "model", "task", "direction"],
+        REGISTRY.clone()
+    )
+    .expect("ai_tokens_total metric")
+});
+
+pub(super) static AI_BUDGET_EXCEEDED_TOTAL: LazyLock<CounterVec> = LazyLock::new(|| {
+    register_counter_vec_with_registry!(
+        "ai_budget_exceeded_total",
+        "Total AI calls made or refused while a tenant was over its monthly budget",
+        &["mode"],
+        REGISTRY.clone()
+    )
+    .expect("ai_budget_exceeded_total metric")
+});
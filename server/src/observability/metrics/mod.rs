@@ -23,12 +23,13 @@ mod types;
 
 pub use gather::Metrics;
 pub use record::{
-    dec_http_in_flight, inc_http_in_flight, record_ai_cache_hit, record_ai_call,
-    record_ai_rate_limit_rejection, record_circuit_breaker_failure, record_circuit_breaker_state,
-    record_coupon_discount, record_coupon_operation, record_db_error, record_db_pool_stats,
-    record_db_query, record_http_request, record_payment, record_rate_limit_rejection,
-    record_solana_rpc_call, record_solana_tx_confirmation, record_solana_wallet_balance,
-    record_stripe_api_call, record_stripe_error, record_webhook_delivery, record_webhook_dlq_size,
+    dec_http_in_flight, inc_http_in_flight, record_ai_budget_exceeded, record_ai_cache_hit,
+    record_ai_call, record_ai_rate_limit_rejection, record_ai_tokens,
+    record_circuit_breaker_failure, record_circuit_breaker_state, record_coupon_discount,
+    record_coupon_operation, record_db_error, record_db_pool_stats, record_db_query,
+    record_http_request, record_payment, record_rate_limit_rejection, record_solana_rpc_call,
+    record_solana_tx_confirmation, record_solana_wallet_balance, record_stripe_api_call,
+    record_stripe_error, record_webhook_delivery, record_webhook_dlq_size,
     record_webhook_queue_size,
 };
 pub use types::CircuitBreakerState;
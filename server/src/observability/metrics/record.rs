@@ -199,6 +199,23 @@ pub fn record_ai_call(provider: &str, model: &str, task: &str, success: bool, du
         .observe(duration_secs);
 }
 
+/// Record the input and output tokens of an AI call.
+pub fn record_ai_tokens(provider: &str, model: &str, task: &str, input: u64, output: u64) {
+    defs::AI_TOKENS_TOTAL
+        .with_label_values(&[provider, model, task, "input"])
+        .inc_by(input as f64);
+    defs::AI_TOKENS_TOTAL
+        .with_label_values(&[provider, model, task, "output"])
+        .inc_by(output as f64);
+}
+
+/// Record an AI call made (`warn`) or refused (`block`) over a tenant's budget.
+pub fn record_ai_budget_exceeded(mode: &str) {
+    defs::AI_BUDGET_EXCEEDED_TOTAL
+        .with_label_values(&[mode])
+        .inc();
+}
+
 /// Record an AI rate limit rejection.
 pub fn record_ai_rate_limit_rejection(tenant: &str) {
     defs::AI_RATE_LIMIT_REJECTIONS_TOTAL
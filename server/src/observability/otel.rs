@@ -0,0 +1,257 @@
+//! OpenTelemetry trace export and W3C trace-context propagation.
+//!
+//! Export is enabled when `OTEL_EXPORTER_OTLP_ENDPOINT` (or the signal-specific
+//! `OTEL_EXPORTER_OTLP_TRACES_ENDPOINT`) is set; spans are shipped over
+//! OTLP/HTTP in batches. `OTEL_SERVICE_NAME`, `OTEL_RESOURCE_ATTRIBUTES` and
+//! `OTEL_EXPORTER_OTLP_HEADERS` follow the standard SDK semantics, and
+//! `OTEL_TRACES_SAMPLER_ARG` sets a parent-based trace-id ratio (default 1.0).
+//!
+//! Propagation helpers work on `tracing` spans, so call sites never touch the
+//! OpenTelemetry API directly. Without an exporter they are no-ops.
+
+use std::collections::HashMap;
+
+use axum::http::HeaderMap;
+use opentelemetry::propagation::{Extractor, TextMapPropagator};
+use opentelemetry::trace::{TraceContextExt, TracerProvider as _};
+use opentelemetry::Context;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
+use opentelemetry_sdk::Resource;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Service name reported when `OTEL_SERVICE_NAME` is unset.
+pub const DEFAULT_SERVICE_NAME: &str = "cedros-pay";
+
+/// W3C trace-context header carrying the trace and parent span IDs.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+/// W3C trace-context header carrying vendor-specific state.
+pub const TRACESTATE_HEADER: &str = "tracestate";
+
+const OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+const OTLP_TRACES_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT";
+const SERVICE_NAME_ENV: &str = "OTEL_SERVICE_NAME";
+const SAMPLER_ARG_ENV: &str = "OTEL_TRACES_SAMPLER_ARG";
+
+/// Flushes and shuts down the tracer provider when dropped.
+///
+/// Hold this for the lifetime of the process so buffered spans are exported
+/// on shutdown.
+pub struct OtelGuard {
+    provider: SdkTracerProvider,
+}
+
+impl OtelGuard {
+    /// Build a `tracing` layer that exports spans through this provider.
+    pub fn layer<S>(
+        &self,
+    ) -> tracing_opentelemetry::OpenTelemetryLayer<S, opentelemetry_sdk::trace::SdkTracer>
+    where
+        S: tracing::Subscriber + for<'span> LookupSpan<'span>,
+    {
+        tracing_opentelemetry::layer().with_tracer(self.provider.tracer(DEFAULT_SERVICE_NAME))
+    }
+}
+
+#[cfg(test)]
+impl OtelGuard {
+    /// Provider without an exporter, for asserting on propagated context.
+    pub(crate) fn for_tests() -> Self {
+        Self {
+            provider: SdkTracerProvider::builder().build(),
+        }
+    }
+}
+
+impl Drop for OtelGuard {
+    fn drop(&mut self) {
+        if let Err(e) = self.provider.shutdown() {
+            eprintln!("OpenTelemetry tracer provider shutdown failed: {e}");
+        }
+    }
+}
+
+fn env_non_empty(key: &str) -> Option<String> {
+    std::env::var(key)
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Whether an OTLP endpoint is configured for traces.
+pub fn otlp_enabled() -> bool {
+    env_non_empty(OTLP_TRACES_ENDPOINT_ENV).is_some() || env_non_empty(OTLP_ENDPOINT_ENV).is_some()
+}
+
+fn sampler_ratio(raw: Option<&str>) -> f64 {
+    raw.and_then(|v| v.parse::<f64>().ok())
+        .filter(|v| v.is_finite())
+        .map(|v| v.clamp(0.0, 1.0))
+        .unwrap_or(1.0)
+}
+
+/// Initialise OTLP trace export from the environment.
+///
+/// Returns `Ok(None)` when no endpoint is configured. Must be called before
+/// the global `tracing` subscriber is installed.
+pub fn init_from_env() -> Result<Option<OtelGuard>, String> {
+    if !otlp_enabled() {
+        return Ok(None);
+    }
+
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .build()
+        .map_err(|e| format!("failed to build OTLP span exporter: {e}"))?;
+
+    let service_name =
+        env_non_empty(SERVICE_NAME_ENV).unwrap_or_else(|| DEFAULT_SERVICE_NAME.to_string());
+    let resource = Resource::builder()
+        .with_service_name(service_name)
+        .with_attribute(opentelemetry::KeyValue::new(
+            "service.version",
+            env!("CARGO_PKG_VERSION"),
+        ))
+        .build();
+
+    let ratio = sampler_ratio(env_non_empty(SAMPLER_ARG_ENV).as_deref());
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            ratio,
+        ))))
+        .with_resource(resource)
+        .build();
+
+    Ok(Some(OtelGuard { provider }))
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|v| v.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|k| k.as_str()).collect()
+    }
+}
+
+fn set_parent_if_valid(span: &Span, cx: Context) {
+    if cx.span().span_context().is_valid() {
+        span.set_parent(cx);
+    }
+}
+
+/// Continue an upstream trace from incoming `traceparent`/`tracestate` headers.
+///
+/// Must be called before the span is first entered.
+pub fn set_parent_from_headers(span: &Span, headers: &HeaderMap) {
+    let cx = TraceContextPropagator::new().extract(&HeaderExtractor(headers));
+    set_parent_if_valid(span, cx);
+}
+
+/// Continue a trace from a string map carrying trace-context keys
+/// (e.g. persisted outbound webhook headers).
+pub fn set_parent_from_map(span: &Span, carrier: &HashMap<String, String>) {
+    let cx = TraceContextPropagator::new().extract(carrier);
+    set_parent_if_valid(span, cx);
+}
+
+/// Continue a trace from a stored `traceparent` value.
+pub fn set_parent_from_traceparent(span: &Span, traceparent: &str) {
+    let carrier = HashMap::from([(TRACEPARENT_HEADER.to_string(), traceparent.to_string())]);
+    set_parent_from_map(span, &carrier);
+}
+
+/// Write the current span's trace context into `carrier`.
+///
+/// Existing trace-context keys are replaced; nothing is written when no
+/// trace is being recorded.
+pub fn inject_current_context(carrier: &mut HashMap<String, String>) {
+    let cx = Span::current().context();
+    if !cx.span().span_context().is_valid() {
+        return;
+    }
+    carrier.retain(|k, _| {
+        !k.eq_ignore_ascii_case(TRACEPARENT_HEADER) && !k.eq_ignore_ascii_case(TRACESTATE_HEADER)
+    });
+    TraceContextPropagator::new().inject_context(&cx, carrier);
+}
+
+/// `traceparent` of the current span, for linking queued background work.
+pub fn current_traceparent() -> Option<String> {
+    let mut carrier = HashMap::new();
+    inject_current_context(&mut carrier);
+    carrier.remove(TRACEPARENT_HEADER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    const PARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    fn with_tracing<F: FnOnce()>(f: F) {
+        let guard = OtelGuard::for_tests();
+        let subscriber = tracing_subscriber::registry().with(guard.layer());
+        tracing::subscriber::with_default(subscriber, f);
+    }
+
+    #[test]
+    fn test_traceparent_round_trip() {
+        with_tracing(|| {
+            let span = tracing::info_span!("test");
+            set_parent_from_traceparent(&span, PARENT);
+            let _entered = span.enter();
+
+            let mut carrier = HashMap::from([(
+                "Traceparent".to_string(),
+                "00-00000000000000000000000000000001-0000000000000001-01".to_string(),
+            )]);
+            inject_current_context(&mut carrier);
+            let traceparent = carrier.get(TRACEPARENT_HEADER).expect("traceparent");
+            assert!(traceparent.starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+            // Child span ID replaces the upstream parent
+            assert!(!traceparent.contains("00f067aa0ba902b7"));
+            assert!(!carrier.contains_key("Traceparent"));
+        });
+    }
+
+    #[test]
+    fn test_parent_from_headers() {
+        with_tracing(|| {
+            let mut headers = HeaderMap::new();
+            headers.insert(TRACEPARENT_HEADER, PARENT.parse().unwrap());
+            let span = tracing::info_span!("http");
+            set_parent_from_headers(&span, &headers);
+            let _entered = span.enter();
+            let traceparent = current_traceparent().expect("traceparent");
+            assert!(traceparent.contains("4bf92f3577b34da6a3ce929d0e0e4736"));
+        });
+    }
+
+    #[test]
+    fn test_no_context_without_exporter() {
+        let span = tracing::info_span!("untraced");
+        let _entered = span.enter();
+        assert!(current_traceparent().is_none());
+        let mut carrier = HashMap::new();
+        inject_current_context(&mut carrier);
+        assert!(carrier.is_empty());
+    }
+
+    #[test]
+    fn test_sampler_ratio_parsing() {
+        assert_eq!(sampler_ratio(None), 1.0);
+        assert_eq!(sampler_ratio(Some("0.25")), 0.25);
+        assert_eq!(sampler_ratio(Some("5")), 1.0);
+        assert_eq!(sampler_ratio(Some("-1")), 0.0);
+        assert_eq!(sampler_ratio(Some("abc")), 1.0);
+    }
+}
@@ -5,14 +5,19 @@ use std::time::Duration;
 
 use futures_util::FutureExt;
 
-use crate::config::{Config, PostgresConfigRepository};
+use crate::config::{Config, ConfigBus, PostgresConfigRepository, SecretStore};
 use crate::handlers;
 use crate::middleware;
+use crate::repositories::ProductRepository;
 use crate::services::token22::Token22Service;
+use crate::services::StripeClient;
 use crate::storage::Store;
 use crate::webhooks;
-use crate::services::SanctionsListService;
-use crate::workers::{CleanupWorker, HealthChecker, SanctionsRefreshWorker, SanctionsSweepWorker};
+use crate::services::{ColdArchiveService, ImageStorageService, SanctionsListService};
+use crate::workers::{
+    CleanupWorker, EmbeddingWorker, FinancialReportWorker, HealthChecker, KeyRewrapWorker,
+    PriceScheduleWorker, SanctionsRefreshWorker, SanctionsSweepWorker, SecretsRefreshWorker,
+};
 
 /// OPS-01: Supervised spawn that catches worker panics and logs them at error level.
 /// Without this, a panicked worker silently disappears until shutdown.
@@ -48,6 +53,11 @@ pub struct PaymentWorkers {
     pub(crate) subscription_handle: crate::workers::SubscriptionWorkerHandle,
     pub(crate) sanctions_sweep_handle: Option<crate::workers::SanctionsSweepWorkerHandle>,
     pub(crate) sanctions_refresh_handle: Option<crate::workers::SanctionsRefreshWorkerHandle>,
+    pub(crate) financial_report_handle: Option<crate::workers::FinancialReportWorkerHandle>,
+    pub(crate) key_rewrap_handle: Option<crate::workers::KeyRewrapWorkerHandle>,
+    pub(crate) secrets_refresh_handle: Option<crate::workers::SecretsRefreshWorkerHandle>,
+    pub(crate) embedding_handle: Option<crate::workers::EmbeddingWorkerHandle>,
+    pub(crate) price_schedule_handle: Option<crate::workers::PriceScheduleWorkerHandle>,
     pub(crate) rate_limiter_cleanup_handle: Option<middleware::RateLimiterCleanupHandle>,
 }
 
@@ -73,6 +83,21 @@ impl PaymentWorkers {
         if let Some(ref handle) = self.sanctions_refresh_handle {
             handle.shutdown();
         }
+        if let Some(ref handle) = self.financial_report_handle {
+            handle.shutdown();
+        }
+        if let Some(ref handle) = self.key_rewrap_handle {
+            handle.shutdown();
+        }
+        if let Some(ref handle) = self.secrets_refresh_handle {
+            handle.shutdown();
+        }
+        if let Some(ref handle) = self.embedding_handle {
+            handle.shutdown();
+        }
+        if let Some(ref handle) = self.price_schedule_handle {
+            handle.shutdown();
+        }
         if let Some(ref handle) = self.rate_limiter_cleanup_handle {
             handle.shutdown();
         }
@@ -94,6 +119,21 @@ impl PaymentWorkers {
             if let Some(handle) = self.sanctions_refresh_handle {
                 handle.wait().await;
             }
+            if let Some(handle) = self.financial_report_handle {
+                handle.wait().await;
+            }
+            if let Some(handle) = self.key_rewrap_handle {
+                handle.wait().await;
+            }
+            if let Some(handle) = self.secrets_refresh_handle {
+                handle.wait().await;
+            }
+            if let Some(handle) = self.embedding_handle {
+                handle.wait().await;
+            }
+            if let Some(handle) = self.price_schedule_handle {
+                handle.wait().await;
+            }
         })
         .await;
 
@@ -132,7 +172,20 @@ pub fn spawn_workers<S: Store + 'static>(
         balance_monitoring_enabled: cfg.monitoring.low_balance_alert_url.is_some(),
     }));
 
-    spawn_workers_internal(store, cfg, health_state, None, notifier, None, None, None)
+    spawn_workers_internal(
+        store,
+        cfg,
+        health_state,
+        None,
+        notifier,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
 }
 
 pub(crate) fn spawn_workers_internal<S: Store + 'static>(
@@ -144,18 +197,50 @@ pub(crate) fn spawn_workers_internal<S: Store + 'static>(
     token22: Option<Arc<Token22Service>>,
     config_repo: Option<Arc<PostgresConfigRepository>>,
     sanctions_service: Option<Arc<SanctionsListService>>,
+    product_repo: Option<Arc<dyn ProductRepository>>,
+    stripe_client: Option<Arc<StripeClient>>,
+    secrets: Option<Arc<SecretStore>>,
+    config_bus: Option<Arc<ConfigBus>>,
 ) -> anyhow::Result<PaymentWorkers> {
     let rate_limiter_cleanup_handle = rate_limiter.map(|rl| rl.start_cleanup_task());
 
-    let archival_enabled = cfg.storage.archival.enabled;
+    let mut archival_enabled = cfg.storage.archival.enabled;
     let retention_period = cfg.storage.archival.retention_period;
     let run_interval = cfg.storage.archival.run_interval;
+    // Cold archival uploads to the tenant's object storage bucket, which is
+    // configured through the database-backed config repository.
+    let cold_archive = if archival_enabled && cfg.storage.archival.cold_storage {
+        match config_repo.as_ref() {
+            Some(repo) => Some(Arc::new(ColdArchiveService::new(
+                store.clone(),
+                Arc::new(ImageStorageService::new(repo.clone())),
+                cfg.storage.archival.batch_size,
+            ))),
+            None => {
+                tracing::error!(
+                    "storage.archival.cold_storage requires the database config repository; \
+                     archival disabled to avoid deleting unarchived records"
+                );
+                archival_enabled = false;
+                None
+            }
+        }
+    } else {
+        None
+    };
     let (cleanup_worker, cleanup_handle) = CleanupWorker::with_shutdown(
         store.clone(),
         retention_period,
         run_interval,
         archival_enabled,
     );
+    // Applied even with the event log disabled so earlier entries still expire
+    let cleanup_worker = cleanup_worker.with_event_log_retention(cfg.callbacks.event_log.retention);
+    let webhooks_archived = cold_archive.is_some();
+    let cleanup_worker = match cold_archive {
+        Some(cold_archive) => cleanup_worker.with_cold_archive(cold_archive),
+        None => cleanup_worker,
+    };
     let cleanup_join = spawn_supervised("cleanup", async move {
         cleanup_worker.run().await;
     });
@@ -171,6 +256,27 @@ pub(crate) fn spawn_workers_internal<S: Store + 'static>(
         webhook_cb,
     )
     .map_err(|e| anyhow::anyhow!(e))?;
+    let webhook_worker = if webhooks_archived {
+        webhook_worker.with_cold_archive()
+    } else {
+        webhook_worker
+    };
+    if let Some(ref bus) = config_bus {
+        let settings = webhook_worker.settings_handle();
+        bus.register(
+            "webhook_worker",
+            &["callbacks", "circuit_breaker"],
+            move |cfg| {
+                settings.reconfigure(
+                    &cfg.callbacks,
+                    middleware::CircuitBreakerConfig::from_service_config(
+                        "webhook",
+                        &cfg.circuit_breaker.webhook,
+                    ),
+                )
+            },
+        );
+    }
     let webhook_join = spawn_supervised("webhook", async move {
         webhook_worker.run().await;
     });
@@ -244,6 +350,97 @@ pub(crate) fn spawn_workers_internal<S: Store + 'static>(
         None
     };
 
+    // Keep product and FAQ embeddings current for semantic search (the model
+    // is assigned per tenant in the config DB)
+    let embedding_handle = match (&config_repo, &product_repo) {
+        (Some(repo), Some(products)) => {
+            let usage = crate::services::ai::AiUsageTracker::new(
+                store.clone() as Arc<dyn Store>,
+                crate::services::ai::AiPricing::new(&cfg.ai_pricing),
+            );
+            let ai_service = crate::services::AiService::new().with_usage(Arc::new(usage));
+            let ai_service = match secrets.as_ref() {
+                Some(secrets) => ai_service.with_secrets(secrets.clone()),
+                None => ai_service,
+            };
+            let poll_interval = Duration::from_secs(30);
+            let (embedding_worker, embedding_handle) = EmbeddingWorker::with_shutdown(
+                store.clone(),
+                products.clone(),
+                repo.clone(),
+                Arc::new(ai_service),
+                poll_interval,
+            );
+            let embedding_join = spawn_supervised("embeddings", async move {
+                embedding_worker.run().await;
+            });
+            tracing::info!("Embedding worker spawned");
+            Some(embedding_handle.with_join_handle(embedding_join))
+        }
+        _ => None,
+    };
+
+    // Apply scheduled price changes and revert ended sales
+    let price_schedule_handle = product_repo.as_ref().map(|products| {
+        let check_interval = Duration::from_secs(60);
+        let (schedule_worker, schedule_handle) = PriceScheduleWorker::with_shutdown(
+            store.clone() as Arc<dyn Store>,
+            products.clone(),
+            stripe_client,
+            check_interval,
+        );
+        let schedule_join = spawn_supervised("price_schedules", async move {
+            schedule_worker.run().await;
+        });
+        tracing::info!("Price schedule worker spawned");
+        schedule_handle.with_join_handle(schedule_join)
+    });
+
+    // Scheduled financial report emails (schedules live in the config DB)
+    let financial_report_handle = if let Some(ref repo) = config_repo {
+        let check_interval = Duration::from_secs(3600); // 1 hour
+        let (report_worker, report_handle) = FinancialReportWorker::with_shutdown(
+            store.clone(),
+            product_repo,
+            repo.clone(),
+            cfg.messaging.clone(),
+            check_interval,
+        );
+        let report_join = spawn_supervised("financial_reports", async move {
+            report_worker.run().await;
+        });
+        tracing::info!("Financial report worker spawned");
+        Some(report_handle.with_join_handle(report_join))
+    } else {
+        None
+    };
+
+    // Re-wrap config DEKs left under a retired KEK (only with encryption enabled)
+    let key_rewrap_handle = match config_repo {
+        Some(ref repo) if repo.encryption_enabled() => {
+            let check_interval = Duration::from_secs(3600); // 1 hour
+            let (rewrap_worker, rewrap_handle) =
+                KeyRewrapWorker::with_shutdown(repo.clone(), check_interval);
+            let rewrap_join = spawn_supervised("key_rewrap", async move {
+                rewrap_worker.run().await;
+            });
+            tracing::info!("Key re-wrap worker spawned");
+            Some(rewrap_handle.with_join_handle(rewrap_join))
+        }
+        _ => None,
+    };
+
+    // Poll the secret provider so rotated credentials apply without a restart
+    let secrets_refresh_handle = secrets.map(|secrets| {
+        let (refresh_worker, refresh_handle) =
+            SecretsRefreshWorker::with_shutdown(secrets, cfg.secrets.refresh_interval);
+        let refresh_join = spawn_supervised("secrets_refresh", async move {
+            refresh_worker.run().await;
+        });
+        tracing::info!("Secrets refresh worker spawned");
+        refresh_handle.with_join_handle(refresh_join)
+    });
+
     // Sanctions refresh worker (fetches dynamic lists per tenant)
     let sanctions_refresh_handle = if let Some(ref svc) = sanctions_service {
         let refresh_interval = Duration::from_secs(3600); // 1 hour
@@ -271,6 +468,11 @@ pub(crate) fn spawn_workers_internal<S: Store + 'static>(
         subscription_handle,
         sanctions_sweep_handle,
         sanctions_refresh_handle,
+        financial_report_handle,
+        key_rewrap_handle,
+        secrets_refresh_handle,
+        embedding_handle,
+        price_schedule_handle,
         rate_limiter_cleanup_handle,
     })
 }
@@ -4,6 +4,7 @@ pub mod factory;
 pub mod memory;
 pub mod postgres;
 pub mod products;
+pub mod sqlite;
 pub mod transactional_ops;
 
 pub use cached::{CachedCouponRepository, CachedProductRepository, RepositoryCacheConfig};
@@ -19,4 +20,5 @@ pub use products::{
     AiCatalogProduct, DiscoveryProduct, ProductRepository, ProductRepositoryError,
     ProductsTxtProduct,
 };
-pub use transactional_ops::TransactionalOps;
+pub use sqlite::{SqliteCouponRepository, SqliteProductRepository};
+pub use transactional_ops::{SqliteTransactionalOps, TransactionalOps};
@@ -63,6 +63,7 @@ struct ProductRow {
     gift_card_config: Option<serde_json::Value>,
     tokenized_asset_config: Option<serde_json::Value>,
     compliance_requirements: Option<serde_json::Value>,
+    payment_split: Option<serde_json::Value>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -127,7 +128,7 @@ const PRODUCT_SELECT_COLUMNS: &str = r#"
     metadata, active, subscription_billing_period, subscription_billing_interval,
     subscription_trial_days, subscription_stripe_price_id, subscription_allow_x402,
     subscription_grace_period_hours, gift_card_config, tokenized_asset_config,
-    compliance_requirements, created_at, updated_at
+    compliance_requirements, payment_split, created_at, updated_at
 "#;
 
 const DISCOVERY_SELECT_COLUMNS: &str = r#"
@@ -208,6 +209,9 @@ impl ProductRow {
         let compliance_requirements: Option<crate::models::compliance::ComplianceRequirements> =
             self.compliance_requirements
                 .and_then(|v| serde_json::from_value(v).ok());
+        let payment_split: Option<crate::models::PaymentSplit> = self
+            .payment_split
+            .and_then(|v| serde_json::from_value(v).ok());
 
         Product {
             id: self.id,
@@ -244,6 +248,7 @@ impl ProductRow {
             gift_card_config,
             tokenized_asset_config,
             compliance_requirements,
+            payment_split,
             created_at: Some(self.created_at),
             updated_at: Some(self.updated_at),
         }
@@ -892,6 +897,13 @@ impl ProductRepository for PostgresProductRepository {
             .transpose()
             .map_err(|e| ProductRepositoryError::Storage(e.to_string()))?;
 
+        let payment_split_json: Option<serde_json::Value> = product
+            .payment_split
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| ProductRepositoryError::Storage(e.to_string()))?;
+
         let (sub_period, sub_interval, sub_trial, sub_stripe, sub_x402, sub_grace) =
             match &product.subscription {
                 Some(s) => (
@@ -920,7 +932,7 @@ impl ProductRepository for PostgresProductRepository {
                 subscription_trial_days, subscription_stripe_price_id, subscription_allow_x402,
                 subscription_grace_period_hours, inventory_quantity, inventory_policy,
                 gift_card_config, tokenized_asset_config, compliance_requirements,
-                created_at, updated_at
+                payment_split, created_at, updated_at
             )
             VALUES (
                 $1, $2, $3, $4, $5, $6, $7, $8,
@@ -931,7 +943,7 @@ impl ProductRepository for PostgresProductRepository {
                 $23, $24, $25, $26, $27,
                 $28, $29,
                 $30, $31, $32, $33, $34, $35, $36, $37,
-                $38, $39, $40, $41, $42, $43, $44
+                $38, $39, $40, $41, $42, $43, $44, $45
             )
             "#,
             self.table_name
@@ -980,6 +992,7 @@ impl ProductRepository for PostgresProductRepository {
             .bind(&gift_card_config)
             .bind(&tokenized_asset_config)
             .bind(&compliance_requirements_json)
+            .bind(&payment_split_json)
             .bind(now)
             .bind(now)
             .execute(&self.pool)
@@ -1072,6 +1085,13 @@ impl ProductRepository for PostgresProductRepository {
             .transpose()
             .map_err(|e| ProductRepositoryError::Storage(e.to_string()))?;
 
+        let payment_split_json: Option<serde_json::Value> = product
+            .payment_split
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| ProductRepositoryError::Storage(e.to_string()))?;
+
         let (sub_period, sub_interval, sub_trial, sub_stripe, sub_x402, sub_grace) =
             match &product.subscription {
                 Some(s) => (
@@ -1129,8 +1149,9 @@ impl ProductRepository for PostgresProductRepository {
                 gift_card_config = $39,
                 tokenized_asset_config = $40,
                 compliance_requirements = $41,
-                updated_at = $42
-            WHERE id = $1 AND tenant_id = $43
+                payment_split = $42,
+                updated_at = $43
+            WHERE id = $1 AND tenant_id = $44
             "#,
             self.table_name
         );
@@ -1177,8 +1198,9 @@ impl ProductRepository for PostgresProductRepository {
             .bind(&gift_card_config)
             .bind(&tokenized_asset_config)
             .bind(&compliance_requirements_json)
+            .bind(&payment_split_json)
             .bind(Utc::now())
-            .bind(&product.tenant_id) // $43: tenant isolation
+            .bind(&product.tenant_id) // $44: tenant isolation
             .execute(&self.pool)
             .await
             .map_err(|e| ProductRepositoryError::Storage(e.to_string()))?;
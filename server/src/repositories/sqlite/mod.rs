@@ -0,0 +1,7 @@
+//! SQLite-backed product and coupon repositories
+
+mod coupons;
+mod products;
+
+pub use coupons::SqliteCouponRepository;
+pub use products::SqliteProductRepository;
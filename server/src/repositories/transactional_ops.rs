@@ -19,6 +19,10 @@ use crate::errors::ErrorCode;
 use crate::models::{InventoryAdjustment, Order, PaymentTransaction, RefundQuote};
 use crate::services::{ServiceError, ServiceResult};
 
+mod sqlite;
+
+pub use sqlite::SqliteTransactionalOps;
+
 /// Apply word-boundary-aware identifier replacement (same algorithm as PostgresStore).
 fn map_table(query: &str, from: &str, to: &str) -> String {
     if from == to {
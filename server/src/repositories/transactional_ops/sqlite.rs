@@ -0,0 +1,543 @@
+//! Transactional operations for the SQLite backend
+//!
+//! SQLite has no row locks, so every operation here opens its transaction with
+//! `BEGIN IMMEDIATE`. That takes the database write lock before the first
+//! read, which gives the read-check-write sequences below the same isolation
+//! `SELECT ... FOR UPDATE` provides on PostgreSQL. Concurrent writers wait up
+//! to the pool's busy timeout for the lock.
+
+use chrono::Utc;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+use crate::errors::ErrorCode;
+use crate::models::{InventoryAdjustment, ProductVariant};
+use crate::services::{ServiceError, ServiceResult};
+
+/// Transactional inventory, gift card and coupon usage operations on SQLite.
+pub struct SqliteTransactionalOps;
+
+impl SqliteTransactionalOps {
+    async fn begin_immediate(pool: &SqlitePool) -> ServiceResult<Transaction<'static, Sqlite>> {
+        pool.begin_with("BEGIN IMMEDIATE")
+            .await
+            .map_err(|e| ServiceError::Internal(format!("Failed to begin transaction: {}", e)))
+    }
+
+    /// Atomic: Convert a cart's active reservations into inventory deductions
+    ///
+    /// Marks each unexpired reservation as converted, decrements the product
+    /// (or variant) quantity and records an inventory adjustment, all in one
+    /// transaction. Quantities never drop below zero. Products without
+    /// tracked inventory are converted without an adjustment.
+    pub async fn convert_reservations_to_inventory(
+        pool: &SqlitePool,
+        tenant_id: &str,
+        cart_id: &str,
+    ) -> ServiceResult<Vec<InventoryAdjustment>> {
+        let mut tx = Self::begin_immediate(pool).await?;
+        let now = Utc::now();
+
+        let reservations: Vec<(String, String, Option<String>, i32)> = sqlx::query_as(
+            r#"
+            SELECT id, product_id, variant_id, quantity
+            FROM inventory_reservations
+            WHERE tenant_id = $1 AND cart_id = $2 AND status = 'active' AND expires_at > $3
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(cart_id)
+        .bind(now)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| ServiceError::Internal(format!("Failed to fetch reservations: {}", e)))?;
+
+        if reservations.is_empty() {
+            tx.rollback().await.ok();
+            return Ok(vec![]);
+        }
+
+        let mut adjustments = Vec::new();
+
+        for (reservation_id, product_id, variant_id, quantity) in reservations {
+            sqlx::query(
+                "UPDATE inventory_reservations SET status = 'converted' WHERE id = $1 AND tenant_id = $2",
+            )
+            .bind(&reservation_id)
+            .bind(tenant_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ServiceError::Internal(format!("Failed to convert reservation: {}", e)))?;
+
+            let row: Option<(Option<i32>, Option<serde_json::Value>)> = sqlx::query_as(
+                "SELECT inventory_quantity, variants FROM products WHERE tenant_id = $1 AND id = $2",
+            )
+            .bind(tenant_id)
+            .bind(&product_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| ServiceError::Internal(format!("Failed to get product inventory: {}", e)))?;
+
+            let Some((product_qty, variants_json)) = row else {
+                continue;
+            };
+
+            let (current_qty, next_qty) = match variant_id.as_deref() {
+                Some(vid) => {
+                    let mut variants: Vec<ProductVariant> = variants_json
+                        .and_then(|v| serde_json::from_value(v).ok())
+                        .unwrap_or_default();
+                    let Some(current) = variants
+                        .iter()
+                        .find(|v| v.id == vid)
+                        .and_then(|v| v.inventory_quantity)
+                    else {
+                        continue;
+                    };
+                    let next = current.saturating_sub(quantity).max(0);
+                    for v in variants.iter_mut().filter(|v| v.id == vid) {
+                        v.inventory_quantity = Some(next);
+                    }
+                    let variants_json = serde_json::to_value(&variants).map_err(|e| {
+                        ServiceError::Internal(format!("Failed to serialize variants: {}", e))
+                    })?;
+                    sqlx::query(
+                        "UPDATE products SET variants = $3, updated_at = $4 WHERE tenant_id = $1 AND id = $2",
+                    )
+                    .bind(tenant_id)
+                    .bind(&product_id)
+                    .bind(&variants_json)
+                    .bind(now)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| {
+                        ServiceError::Internal(format!("Failed to update variant inventory: {}", e))
+                    })?;
+                    (current, next)
+                }
+                None => {
+                    let Some(current) = product_qty else {
+                        continue;
+                    };
+                    let next = current.saturating_sub(quantity).max(0);
+                    sqlx::query(
+                        "UPDATE products SET inventory_quantity = $3, updated_at = $4 WHERE tenant_id = $1 AND id = $2",
+                    )
+                    .bind(tenant_id)
+                    .bind(&product_id)
+                    .bind(next)
+                    .bind(now)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| {
+                        ServiceError::Internal(format!("Failed to update product inventory: {}", e))
+                    })?;
+                    (current, next)
+                }
+            };
+
+            let adjustment = InventoryAdjustment {
+                id: uuid::Uuid::new_v4().to_string(),
+                tenant_id: tenant_id.to_string(),
+                product_id: product_id.clone(),
+                variant_id: variant_id.clone(),
+                delta: next_qty - current_qty,
+                quantity_before: current_qty,
+                quantity_after: next_qty,
+                reason: Some("cart_paid".to_string()),
+                actor: Some("system".to_string()),
+                created_at: now,
+            };
+
+            sqlx::query(
+                r#"
+                INSERT INTO inventory_adjustments (id, tenant_id, product_id, variant_id, delta,
+                                                  quantity_before, quantity_after, reason, actor, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                "#,
+            )
+            .bind(&adjustment.id)
+            .bind(&adjustment.tenant_id)
+            .bind(&adjustment.product_id)
+            .bind(&adjustment.variant_id)
+            .bind(adjustment.delta)
+            .bind(adjustment.quantity_before)
+            .bind(adjustment.quantity_after)
+            .bind(&adjustment.reason)
+            .bind(&adjustment.actor)
+            .bind(adjustment.created_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ServiceError::Internal(format!("Failed to record adjustment: {}", e)))?;
+
+            adjustments.push(adjustment);
+        }
+
+        tx.commit().await.map_err(|e| {
+            ServiceError::Internal(format!("Failed to commit conversion transaction: {}", e))
+        })?;
+
+        Ok(adjustments)
+    }
+
+    /// Atomic: Create gift card with initial balance
+    ///
+    /// Fails with `InvalidField` if the code already exists for the tenant.
+    pub async fn create_gift_card_with_balance(
+        pool: &SqlitePool,
+        tenant_id: &str,
+        code: &str,
+        initial_balance: i64,
+        currency: &str,
+        created_by: &str,
+    ) -> ServiceResult<String> {
+        let mut tx = Self::begin_immediate(pool).await?;
+        let now = Utc::now();
+        let metadata = serde_json::json!({ "created_by": created_by });
+
+        let inserted = sqlx::query(
+            r#"
+            INSERT INTO gift_cards (code, tenant_id, initial_balance, balance, currency, active,
+                                    metadata, created_at, updated_at)
+            VALUES ($1, $2, $3, $3, $4, true, $5, $6, $6)
+            ON CONFLICT (tenant_id, code) DO NOTHING
+            "#,
+        )
+        .bind(code)
+        .bind(tenant_id)
+        .bind(initial_balance)
+        .bind(currency)
+        .bind(&metadata)
+        .bind(now)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServiceError::Internal(format!("Failed to create gift card: {}", e)))?;
+
+        if inserted.rows_affected() == 0 {
+            tx.rollback().await.ok();
+            return Err(ServiceError::Coded {
+                code: ErrorCode::InvalidField,
+                message: "Gift card code already exists".to_string(),
+            });
+        }
+
+        tx.commit().await.map_err(|e| {
+            ServiceError::Internal(format!("Failed to commit gift card transaction: {}", e))
+        })?;
+
+        Ok(code.to_string())
+    }
+
+    /// Atomic: Debit a gift card and return the remaining balance
+    ///
+    /// The card must be active, unexpired and hold at least `amount`.
+    pub async fn redeem_gift_card(
+        pool: &SqlitePool,
+        tenant_id: &str,
+        code: &str,
+        amount: i64,
+    ) -> ServiceResult<i64> {
+        if amount <= 0 {
+            return Err(ServiceError::Coded {
+                code: ErrorCode::InvalidAmount,
+                message: "Redemption amount must be positive".to_string(),
+            });
+        }
+
+        let mut tx = Self::begin_immediate(pool).await?;
+        let now = Utc::now();
+
+        let row: Option<(i64, bool, Option<chrono::DateTime<Utc>>)> = sqlx::query_as(
+            "SELECT balance, active, expires_at FROM gift_cards WHERE tenant_id = $1 AND code = $2",
+        )
+        .bind(tenant_id)
+        .bind(code)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| ServiceError::Internal(format!("Failed to get gift card: {}", e)))?;
+
+        let Some((balance, active, expires_at)) = row else {
+            tx.rollback().await.ok();
+            return Err(ServiceError::Coded {
+                code: ErrorCode::ResourceNotFound,
+                message: "Gift card not found".to_string(),
+            });
+        };
+
+        if !active || expires_at.is_some_and(|exp| exp <= now) {
+            tx.rollback().await.ok();
+            return Err(ServiceError::Coded {
+                code: ErrorCode::InvalidField,
+                message: "Gift card is inactive or expired".to_string(),
+            });
+        }
+        if balance < amount {
+            tx.rollback().await.ok();
+            return Err(ServiceError::Coded {
+                code: ErrorCode::InsufficientCredits,
+                message: "Gift card balance is insufficient".to_string(),
+            });
+        }
+
+        let remaining = balance - amount;
+        sqlx::query(
+            "UPDATE gift_cards SET balance = $3, updated_at = $4 WHERE tenant_id = $1 AND code = $2",
+        )
+        .bind(tenant_id)
+        .bind(code)
+        .bind(remaining)
+        .bind(now)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServiceError::Internal(format!("Failed to update gift card: {}", e)))?;
+
+        tx.commit().await.map_err(|e| {
+            ServiceError::Internal(format!("Failed to commit gift card transaction: {}", e))
+        })?;
+
+        Ok(remaining)
+    }
+
+    /// Atomic: Record one use of a coupon, globally and per customer
+    ///
+    /// Checks the coupon's total and per-customer limits and increments both
+    /// counters together, so a coupon can never be over-redeemed.
+    pub async fn record_coupon_usage(
+        pool: &SqlitePool,
+        tenant_id: &str,
+        code: &str,
+        customer_id: Option<&str>,
+    ) -> ServiceResult<()> {
+        let mut tx = Self::begin_immediate(pool).await?;
+        let now = Utc::now();
+
+        let row: Option<(Option<i32>, i32, Option<i32>)> = sqlx::query_as(
+            r#"
+            SELECT usage_limit, usage_count, usage_limit_per_customer
+            FROM coupons
+            WHERE tenant_id = $1 AND UPPER(code) = UPPER($2)
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(code)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| ServiceError::Internal(format!("Failed to get coupon: {}", e)))?;
+
+        let Some((usage_limit, usage_count, per_customer_limit)) = row else {
+            tx.rollback().await.ok();
+            return Err(ServiceError::Coded {
+                code: ErrorCode::CouponNotFound,
+                message: "Coupon not found".to_string(),
+            });
+        };
+
+        if usage_limit.is_some_and(|limit| usage_count >= limit) {
+            tx.rollback().await.ok();
+            return Err(ServiceError::Coded {
+                code: ErrorCode::CouponUsageLimitReached,
+                message: "Coupon usage limit reached".to_string(),
+            });
+        }
+
+        if let Some(customer_id) = customer_id {
+            if let Some(limit) = per_customer_limit {
+                let used: Option<(i32,)> = sqlx::query_as(
+                    r#"
+                    SELECT usage_count
+                    FROM coupon_customer_usage
+                    WHERE tenant_id = $1 AND UPPER(coupon_code) = UPPER($2) AND customer_id = $3
+                    "#,
+                )
+                .bind(tenant_id)
+                .bind(code)
+                .bind(customer_id)
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| {
+                    ServiceError::Internal(format!("Failed to get customer coupon usage: {}", e))
+                })?;
+
+                if used.map(|(c,)| c).unwrap_or(0) >= limit {
+                    tx.rollback().await.ok();
+                    return Err(ServiceError::Coded {
+                        code: ErrorCode::CouponUsageLimitReached,
+                        message: "Coupon usage limit reached for this customer".to_string(),
+                    });
+                }
+            }
+
+            sqlx::query(
+                r#"
+                INSERT INTO coupon_customer_usage (id, tenant_id, coupon_code, customer_id, usage_count, first_used_at, last_used_at)
+                VALUES ($1, $2, UPPER($3), $4, 1, $5, $5)
+                ON CONFLICT (tenant_id, UPPER(coupon_code), customer_id)
+                DO UPDATE SET usage_count = coupon_customer_usage.usage_count + 1, last_used_at = $5
+                "#,
+            )
+            .bind(uuid::Uuid::new_v4().to_string())
+            .bind(tenant_id)
+            .bind(code)
+            .bind(customer_id)
+            .bind(now)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                ServiceError::Internal(format!("Failed to record customer coupon usage: {}", e))
+            })?;
+        }
+
+        sqlx::query(
+            "UPDATE coupons SET usage_count = usage_count + 1, updated_at = $3 WHERE tenant_id = $1 AND UPPER(code) = UPPER($2)",
+        )
+        .bind(tenant_id)
+        .bind(code)
+        .bind(now)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServiceError::Internal(format!("Failed to increment coupon usage: {}", e)))?;
+
+        tx.commit().await.map_err(|e| {
+            ServiceError::Internal(format!("Failed to commit coupon usage transaction: {}", e))
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::SqlitePool as StoragePool;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = StoragePool::in_memory().await.expect("open");
+        pool.migrate().await.expect("migrate");
+        pool.inner().clone()
+    }
+
+    async fn insert_coupon(pool: &SqlitePool, usage_limit: Option<i32>, per_customer: Option<i32>) {
+        sqlx::query(
+            r#"
+            INSERT INTO coupons (code, tenant_id, discount_type, discount_value, scope, usage_limit,
+                                 usage_count, usage_limit_per_customer, active, created_at, updated_at)
+            VALUES ('SAVE10', 't1', 'percentage', 10, 'all', $1, 0, $2, true, $3, $3)
+            "#,
+        )
+        .bind(usage_limit)
+        .bind(per_customer)
+        .bind(Utc::now())
+        .execute(pool)
+        .await
+        .expect("insert coupon");
+    }
+
+    #[tokio::test]
+    async fn test_coupon_usage_respects_global_and_customer_limits() {
+        let pool = test_pool().await;
+        insert_coupon(&pool, Some(3), Some(1)).await;
+
+        SqliteTransactionalOps::record_coupon_usage(&pool, "t1", "save10", Some("alice"))
+            .await
+            .expect("first use");
+        let err = SqliteTransactionalOps::record_coupon_usage(&pool, "t1", "SAVE10", Some("alice"))
+            .await
+            .expect_err("customer limit");
+        assert!(matches!(
+            err,
+            ServiceError::Coded {
+                code: ErrorCode::CouponUsageLimitReached,
+                ..
+            }
+        ));
+
+        SqliteTransactionalOps::record_coupon_usage(&pool, "t1", "SAVE10", Some("bob"))
+            .await
+            .expect("bob");
+        SqliteTransactionalOps::record_coupon_usage(&pool, "t1", "SAVE10", None)
+            .await
+            .expect("anonymous");
+        assert!(
+            SqliteTransactionalOps::record_coupon_usage(&pool, "t1", "SAVE10", Some("carol"))
+                .await
+                .is_err()
+        );
+
+        let (count,): (i32,) =
+            sqlx::query_as("SELECT usage_count FROM coupons WHERE code = 'SAVE10'")
+                .fetch_one(&pool)
+                .await
+                .expect("count");
+        assert_eq!(count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_gift_card_create_and_redeem() {
+        let pool = test_pool().await;
+        SqliteTransactionalOps::create_gift_card_with_balance(
+            &pool, "t1", "GC1", 500, "USD", "admin",
+        )
+        .await
+        .expect("create");
+        assert!(SqliteTransactionalOps::create_gift_card_with_balance(
+            &pool, "t1", "GC1", 500, "USD", "admin"
+        )
+        .await
+        .is_err());
+
+        let remaining = SqliteTransactionalOps::redeem_gift_card(&pool, "t1", "GC1", 200)
+            .await
+            .expect("redeem");
+        assert_eq!(remaining, 300);
+        assert!(
+            SqliteTransactionalOps::redeem_gift_card(&pool, "t1", "GC1", 301)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_convert_reservations_deducts_inventory() {
+        let pool = test_pool().await;
+        let now = Utc::now();
+        sqlx::query(
+            r#"
+            INSERT INTO products (id, tenant_id, description, inventory_quantity, active, created_at, updated_at)
+            VALUES ('p1', 't1', 'Widget', 5, true, $1, $1)
+            "#,
+        )
+        .bind(now)
+        .execute(&pool)
+        .await
+        .expect("insert product");
+        sqlx::query(
+            r#"
+            INSERT INTO inventory_reservations (id, tenant_id, product_id, quantity, expires_at, cart_id, status, created_at)
+            VALUES ('r1', 't1', 'p1', 2, $1, 'cart1', 'active', $2)
+            "#,
+        )
+        .bind(now + chrono::Duration::minutes(10))
+        .bind(now)
+        .execute(&pool)
+        .await
+        .expect("insert reservation");
+
+        let adjustments =
+            SqliteTransactionalOps::convert_reservations_to_inventory(&pool, "t1", "cart1")
+                .await
+                .expect("convert");
+        assert_eq!(adjustments.len(), 1);
+        assert_eq!(adjustments[0].quantity_after, 3);
+
+        let (qty,): (i32,) =
+            sqlx::query_as("SELECT inventory_quantity FROM products WHERE id = 'p1'")
+                .fetch_one(&pool)
+                .await
+                .expect("qty");
+        assert_eq!(qty, 3);
+
+        let again = SqliteTransactionalOps::convert_reservations_to_inventory(&pool, "t1", "cart1")
+            .await
+            .expect("convert again");
+        assert!(again.is_empty());
+    }
+}
@@ -30,21 +30,29 @@ pub(crate) struct RouterStates<S: Store> {
     pub admin_ai_state: Option<Arc<handlers::admin_ai::AdminAiState>>,
     pub admin_ai_assistant_state: Option<Arc<handlers::admin_ai_assistant::AdminAiAssistantState>>,
     pub admin_dashboard_state: Arc<handlers::admin::AdminState>,
+    pub admin_returns_state: Arc<handlers::admin_returns::AdminReturnsState>,
     pub chat_state: Option<Arc<handlers::chat::ChatState>>,
     pub admin_chat_state: Arc<handlers::admin_chats::AdminChatState>,
     pub faqs_state: Arc<handlers::faqs::FaqsState>,
     pub storefront_state: Option<Arc<handlers::storefront::StorefrontState>>,
     pub asset_redemption_state: Arc<handlers::asset_redemptions::AssetRedemptionState>,
+    pub account_state: Arc<handlers::account::AccountState>,
     /// Token-22 service — used by admin routes for mint management.
     pub token22_service: Option<Arc<crate::services::token22::Token22Service>>,
     /// Asset fulfillment service — threaded for admin-side token burn on completion.
     pub asset_fulfillment: Option<Arc<crate::services::AssetFulfillmentService>>,
     /// Image upload/delete state — requires PostgreSQL for storage config.
     pub admin_images_state: Option<Arc<handlers::admin_images::ImageUploadState>>,
+    /// Cold-archive lookup state — shares the image storage bucket config.
+    pub admin_archive_state: Option<Arc<handlers::admin_archive::ArchiveState>>,
     /// Dynamic sanctions list service — for admin compliance policy routes.
     pub sanctions_list_service: Option<Arc<crate::services::SanctionsListService>>,
     /// Cedros-login client — for admin KYC/compliance user lookups.
     pub cedros_login_client: Option<Arc<crate::services::CedrosLoginClient>>,
+    /// Event log listing and replay.
+    pub events_state: Arc<handlers::admin_events::EventsState<S>>,
+    /// Inbound bounce/complaint events from email providers.
+    pub email_webhook_state: Arc<handlers::email_webhooks::EmailWebhookState<S>>,
 }
 
 pub(crate) fn build_router<S: Store + 'static>(states: RouterStates<S>) -> Router {
@@ -61,6 +69,7 @@ pub(crate) fn build_router<S: Store + 'static>(states: RouterStates<S>) -> Route
     let gift_card_claim_routes = build_gift_card_claim_routes(states.app_state.clone());
     let stripe_redirects = build_stripe_redirect_routes(states.app_state.clone());
     let stripe_webhook = build_stripe_webhook_route(states.app_state.clone());
+    let email_webhook = build_email_webhook_route(states.email_webhook_state.clone());
     let products_routes = build_product_routes(states.products_state.clone());
     let collections_routes = build_collections_routes(states.collections_state.clone());
     let faqs_routes = build_faqs_routes(states.faqs_state.clone());
@@ -73,6 +82,7 @@ pub(crate) fn build_router<S: Store + 'static>(states: RouterStates<S>) -> Route
 
     let asset_redemption_routes =
         build_asset_redemption_routes(states.asset_redemption_state.clone());
+    let account_routes = build_account_routes(states.account_state.clone());
     let admin_states = AdminRouteStates::from_router_states(&states, paywall_prefix.clone());
 
     let mut router = Router::new()
@@ -80,12 +90,14 @@ pub(crate) fn build_router<S: Store + 'static>(states: RouterStates<S>) -> Route
         .nest(&paywall_prefix, paywall_routes)
         .nest(&paywall_prefix, gift_card_claim_routes)
         .nest(&paywall_prefix, asset_redemption_routes)
+        .nest(&paywall_prefix, account_routes)
         .nest(&paywall_prefix, products_routes)
         .nest(&paywall_prefix, collections_routes)
         .nest(&paywall_prefix, faqs_routes)
         .nest(&subscription_prefix, subscription_routes)
         .nest("/stripe", stripe_redirects)
-        .nest("/webhook", stripe_webhook);
+        .nest("/webhook", stripe_webhook)
+        .nest("/webhook", email_webhook);
 
     router = attach_admin_routes(router, admin_states);
 
@@ -219,9 +231,21 @@ fn build_stripe_webhook_route<S: Store + 'static>(
         .with_state(app_state)
 }
 
+fn build_email_webhook_route<S: Store + 'static>(
+    state: Arc<handlers::email_webhooks::EmailWebhookState<S>>,
+) -> Router {
+    Router::new()
+        .route(
+            "/email/{tenant_id}",
+            post(handlers::email_webhooks::email_events::<S>),
+        )
+        .with_state(state)
+}
+
 fn build_product_routes(state: Arc<handlers::products::ProductsAppState>) -> Router {
     Router::new()
         .route("/products", get(handlers::products::list_products))
+        .route("/products/search", get(handlers::products::search_products))
         .route("/products/{id}", get(handlers::products::get_product))
         .route(
             "/products/by-slug/{slug}",
@@ -405,6 +429,22 @@ fn build_asset_redemption_routes(
         .with_state(state)
 }
 
+/// Customer account routes — authenticated via cedros-login bearer tokens.
+fn build_account_routes(state: Arc<handlers::account::AccountState>) -> Router {
+    Router::new()
+        .route("/account/orders", get(handlers::account::list_orders))
+        .route("/account/orders/{orderId}", get(handlers::account::get_order))
+        .route(
+            "/account/orders/{orderId}/returns",
+            post(handlers::account::create_return),
+        )
+        .route(
+            "/account/addresses",
+            get(handlers::account::list_addresses).put(handlers::account::update_addresses),
+        )
+        .with_state(state)
+}
+
 /// Gift card claim routes — no payment timeout or idempotency middleware.
 fn build_gift_card_claim_routes<S: Store + 'static>(
     app_state: Arc<handlers::paywall::AppState<S>>,
@@ -31,6 +31,7 @@ pub(crate) fn attach_admin_routes<S: Store + 'static>(
         admin_ai_state,
         admin_ai_assistant_state,
         admin_dashboard_state,
+        admin_returns_state,
         admin_chat_state,
         chat_state,
         storefront_state,
@@ -40,7 +41,9 @@ pub(crate) fn attach_admin_routes<S: Store + 'static>(
         sweep_settings_state,
         compliance_policy_state,
         admin_images_state,
+        admin_archive_state,
         compliance_kyc_state,
+        events_state,
         paywall_prefix,
         store,
     } = states;
@@ -49,6 +52,13 @@ pub(crate) fn attach_admin_routes<S: Store + 'static>(
     let admin_webhook_routes = build_webhook_routes(store, admin_auth_state.clone());
     router = router.nest("/admin", admin_webhook_routes);
 
+    // Event log routes: read-only listing for API-key consumers, with listing
+    // and replay under /admin
+    let event_routes = build_event_routes(events_state.clone(), admin_auth_state.clone());
+    router = router.nest("/admin", event_routes);
+    let public_event_routes = build_public_event_routes(events_state, admin_auth_state.clone());
+    router = router.nest(&paywall_prefix, public_event_routes);
+
     // Admin config routes (PostgreSQL only)
     if let Some(config_state) = admin_config_state {
         let config_routes = build_config_routes(config_state, admin_auth_state.clone());
@@ -79,6 +89,14 @@ pub(crate) fn attach_admin_routes<S: Store + 'static>(
     if let Some(chat_state) = chat_state {
         let chat_routes = Router::new()
             .route("/chat", post(handlers::chat::chat))
+            .route(
+                "/chat/{session_id}/handoff",
+                post(handlers::chat::request_handoff),
+            )
+            .route(
+                "/chat/{session_id}/messages",
+                get(handlers::chat::list_messages),
+            )
             .with_state(chat_state);
         router = router.nest(&paywall_prefix, chat_routes);
     }
@@ -100,9 +118,18 @@ pub(crate) fn attach_admin_routes<S: Store + 'static>(
 
     // Admin dashboard routes
     let admin_dashboard_routes =
-        build_dashboard_routes(admin_dashboard_state, admin_auth_state.clone());
+        build_dashboard_routes(admin_dashboard_state.clone(), admin_auth_state.clone());
     router = router.nest("/admin", admin_dashboard_routes);
 
+    // Bulk product import/export (larger body limit for catalog files)
+    let product_import_routes =
+        build_product_import_routes(admin_dashboard_state, admin_auth_state.clone());
+    router = router.nest("/admin", product_import_routes);
+
+    // Admin returns / RMA routes
+    let admin_returns_routes = build_returns_routes(admin_returns_state, admin_auth_state.clone());
+    router = router.nest("/admin", admin_returns_routes);
+
     // Token-22 admin routes (optional — only registered when Token22Service is configured)
     if let Some(t22_state) = token22_admin_state {
         let token22_routes = build_token22_routes(t22_state, admin_auth_state.clone());
@@ -147,6 +174,12 @@ pub(crate) fn attach_admin_routes<S: Store + 'static>(
         router = router.nest("/admin", image_routes);
     }
 
+    // Cold-archive lookup routes (PostgreSQL only, shares the image bucket)
+    if let Some(archive_state) = admin_archive_state {
+        let archive_routes = build_archive_routes(archive_state, admin_auth_state.clone());
+        router = router.nest("/admin", archive_routes);
+    }
+
     router
 }
 
@@ -209,6 +242,7 @@ pub(crate) struct AdminRouteStates<S: Store + 'static> {
     pub admin_ai_state: Option<Arc<handlers::admin_ai::AdminAiState>>,
     pub admin_ai_assistant_state: Option<Arc<handlers::admin_ai_assistant::AdminAiAssistantState>>,
     pub admin_dashboard_state: Arc<handlers::admin::AdminState>,
+    pub admin_returns_state: Arc<handlers::admin_returns::AdminReturnsState>,
     pub admin_chat_state: Arc<handlers::admin_chats::AdminChatState>,
     pub chat_state: Option<Arc<handlers::chat::ChatState>>,
     pub storefront_state: Option<Arc<handlers::storefront::StorefrontState>>,
@@ -221,8 +255,10 @@ pub(crate) struct AdminRouteStates<S: Store + 'static> {
     pub compliance_policy_state:
         Option<Arc<handlers::admin_compliance_policy::CompliancePolicyState>>,
     pub admin_images_state: Option<Arc<handlers::admin_images::ImageUploadState>>,
+    pub admin_archive_state: Option<Arc<handlers::admin_archive::ArchiveState>>,
     pub compliance_kyc_state:
         Option<Arc<handlers::admin_compliance_kyc::ComplianceKycState>>,
+    pub events_state: Arc<handlers::admin_events::EventsState<S>>,
     pub paywall_prefix: String,
     pub store: Arc<S>,
 }
@@ -236,6 +272,7 @@ impl<S: Store + 'static> AdminRouteStates<S> {
             admin_ai_state: states.admin_ai_state.clone(),
             admin_ai_assistant_state: states.admin_ai_assistant_state.clone(),
             admin_dashboard_state: states.admin_dashboard_state.clone(),
+            admin_returns_state: states.admin_returns_state.clone(),
             admin_chat_state: states.admin_chat_state.clone(),
             chat_state: states.chat_state.clone(),
             storefront_state: states.storefront_state.clone(),
@@ -246,6 +283,7 @@ impl<S: Store + 'static> AdminRouteStates<S> {
                 })
             }),
             admin_images_state: states.admin_images_state.clone(),
+            admin_archive_state: states.admin_archive_state.clone(),
             compliance_admin_state: states.token22_service.clone().map(|t22| {
                 Arc::new(handlers::admin_compliance::ComplianceAdminState {
                     token22: t22,
@@ -286,6 +324,7 @@ impl<S: Store + 'static> AdminRouteStates<S> {
                     store: states.store.clone() as Arc<dyn Store>,
                 })
             }),
+            events_state: states.events_state.clone(),
             paywall_prefix,
             store: states.store.clone(),
         }
@@ -332,6 +371,36 @@ fn build_webhook_routes<S: Store + 'static>(
         ))
 }
 
+fn build_event_routes<S: Store + 'static>(
+    events_state: Arc<handlers::admin_events::EventsState<S>>,
+    admin_auth_state: Arc<middleware::AdminAuthState<S>>,
+) -> Router {
+    Router::new()
+        .route("/events", get(handlers::admin_events::list_events::<S>))
+        .route(
+            "/events/replay",
+            post(handlers::admin_events::replay_events::<S>),
+        )
+        .with_state(events_state)
+        .layer(axum::middleware::from_fn_with_state(
+            admin_auth_state,
+            middleware::admin_middleware,
+        ))
+}
+
+fn build_public_event_routes<S: Store + 'static>(
+    events_state: Arc<handlers::admin_events::EventsState<S>>,
+    admin_auth_state: Arc<middleware::AdminAuthState<S>>,
+) -> Router {
+    Router::new()
+        .route("/events", get(handlers::admin_events::list_events::<S>))
+        .with_state(events_state)
+        .layer(axum::middleware::from_fn_with_state(
+            admin_auth_state.auth.clone(),
+            middleware::require_api_key_middleware,
+        ))
+}
+
 fn build_config_routes<S: Store + 'static>(
     config_state: Arc<handlers::admin_config::AdminConfigState>,
     admin_auth_state: Arc<middleware::AdminAuthState<S>>,
@@ -344,6 +413,18 @@ fn build_config_routes<S: Store + 'static>(
             post(handlers::admin_config::validate_config),
         )
         .route("/config/history", get(handlers::admin_config::get_history))
+        .route(
+            "/config/status",
+            get(handlers::admin_config::get_config_status),
+        )
+        .route(
+            "/config/encryption/keys",
+            get(handlers::admin_config::list_encryption_keys),
+        )
+        .route(
+            "/config/encryption/rotate",
+            post(handlers::admin_config::rotate_encryption_key),
+        )
         .route(
             "/config/{category}",
             get(handlers::admin_config::get_config),
@@ -379,6 +460,8 @@ fn build_ai_settings_routes<S: Store + 'static>(
             put(handlers::admin_ai::save_assignment),
         )
         .route("/config/ai/prompt", put(handlers::admin_ai::save_prompt))
+        .route("/config/ai/budget", put(handlers::admin_ai::save_budget))
+        .route("/ai/usage", get(handlers::admin_ai::get_ai_usage))
         .with_state(ai_state)
         .layer(axum::middleware::from_fn_with_state(
             admin_auth_state,
@@ -423,6 +506,10 @@ fn build_ai_assistant_routes<S: Store + 'static>(
             "/ai/product-search",
             post(handlers::admin_ai_assistant::product_search),
         )
+        .route(
+            "/ai/embeddings/reindex",
+            post(handlers::admin_ai_assistant::reindex_embeddings),
+        )
         .with_state(ai_assistant_state)
         .layer(axum::middleware::from_fn_with_state(
             admin_auth_state,
@@ -440,6 +527,18 @@ fn build_admin_chat_routes<S: Store + 'static>(
             "/chats/{session_id}",
             get(handlers::admin_chats::get_chat_session),
         )
+        .route(
+            "/chats/{session_id}/claim",
+            post(handlers::admin_chats::claim_chat_session),
+        )
+        .route(
+            "/chats/{session_id}/messages",
+            post(handlers::admin_chats::post_agent_message),
+        )
+        .route(
+            "/chats/{session_id}/release",
+            post(handlers::admin_chats::release_chat_session),
+        )
         .route(
             "/users/{user_id}/chats",
             get(handlers::admin_chats::list_user_chat_sessions),
@@ -451,6 +550,25 @@ fn build_admin_chat_routes<S: Store + 'static>(
         ))
 }
 
+fn build_returns_routes<S: Store + 'static>(
+    admin_returns_state: Arc<handlers::admin_returns::AdminReturnsState>,
+    admin_auth_state: Arc<middleware::AdminAuthState<S>>,
+) -> Router {
+    Router::new()
+        .route("/returns", get(handlers::admin_returns::list_returns))
+        .route("/returns/{id}", get(handlers::admin_returns::get_return))
+        .route("/returns", post(handlers::admin_returns::create_return))
+        .route(
+            "/returns/{id}/status",
+            post(handlers::admin_returns::update_return_status),
+        )
+        .with_state(admin_returns_state)
+        .layer(axum::middleware::from_fn_with_state(
+            admin_auth_state,
+            middleware::admin_middleware,
+        ))
+}
+
 #[allow(clippy::too_many_lines)]
 fn build_dashboard_routes<S: Store + 'static>(
     admin_dashboard_state: Arc<handlers::admin::AdminState>,
@@ -487,14 +605,6 @@ fn build_dashboard_routes<S: Store + 'static>(
             "/fulfillments/{id}/status",
             post(handlers::admin_orders::update_fulfillment_status),
         )
-        // Returns
-        .route("/returns", get(handlers::admin_returns::list_returns))
-        .route("/returns/{id}", get(handlers::admin_returns::get_return))
-        .route("/returns", post(handlers::admin_returns::create_return))
-        .route(
-            "/returns/{id}/status",
-            post(handlers::admin_returns::update_return_status),
-        )
         // Disputes / chargebacks
         .route("/disputes", get(handlers::admin_disputes::list_disputes))
         .route("/disputes", post(handlers::admin_disputes::create_dispute))
@@ -509,6 +619,40 @@ fn build_dashboard_routes<S: Store + 'static>(
         .route("/faqs/{id}", get(handlers::admin_faqs::get_faq))
         .route("/faqs/{id}", put(handlers::admin_faqs::update_faq))
         .route("/faqs/{id}", delete(handlers::admin_faqs::delete_faq))
+        // Email templates
+        .route(
+            "/email-templates",
+            get(handlers::admin_email_templates::list_email_templates),
+        )
+        .route(
+            "/email-templates/{kind}/preview",
+            post(handlers::admin_email_templates::preview_email_template),
+        )
+        .route(
+            "/email-templates/{kind}/{locale}",
+            get(handlers::admin_email_templates::get_email_template),
+        )
+        .route(
+            "/email-templates/{kind}/{locale}",
+            put(handlers::admin_email_templates::put_email_template),
+        )
+        .route(
+            "/email-templates/{kind}/{locale}",
+            delete(handlers::admin_email_templates::delete_email_template),
+        )
+        // Email suppressions
+        .route(
+            "/email-suppressions",
+            get(handlers::admin_email_suppressions::list_email_suppressions),
+        )
+        .route(
+            "/email-suppressions",
+            post(handlers::admin_email_suppressions::create_email_suppression),
+        )
+        .route(
+            "/email-suppressions/{email}",
+            delete(handlers::admin_email_suppressions::delete_email_suppression),
+        )
         // Gift cards
         .route(
             "/gift-cards",
@@ -551,6 +695,46 @@ fn build_dashboard_routes<S: Store + 'static>(
             "/collections/{id}",
             delete(handlers::admin_collections::delete_collection),
         )
+        // Affiliates (referral codes, commission ledger, payouts)
+        .route(
+            "/affiliates",
+            get(handlers::admin_affiliates::list_affiliates),
+        )
+        .route(
+            "/affiliates",
+            post(handlers::admin_affiliates::create_affiliate),
+        )
+        .route(
+            "/affiliates/payouts",
+            get(handlers::admin_affiliates::affiliate_payout_report),
+        )
+        .route(
+            "/affiliates/{id}",
+            get(handlers::admin_affiliates::get_affiliate),
+        )
+        .route(
+            "/affiliates/{id}",
+            put(handlers::admin_affiliates::update_affiliate),
+        )
+        .route(
+            "/affiliates/{id}/commissions",
+            get(handlers::admin_affiliates::list_affiliate_commissions),
+        )
+        // Financial reports
+        .route(
+            "/reports/financial",
+            get(handlers::admin_reports::financial_report),
+        )
+        // Accounting ledger
+        .route(
+            "/ledger/entries",
+            get(handlers::admin_ledger::list_journal_entries)
+                .post(handlers::admin_ledger::create_manual_entry),
+        )
+        .route(
+            "/ledger/trial-balance",
+            get(handlers::admin_ledger::trial_balance),
+        )
         // Products CRUD
         .route("/products", get(handlers::admin::list_products))
         .route("/products/{id}", get(handlers::admin::get_product))
@@ -569,6 +753,16 @@ fn build_dashboard_routes<S: Store + 'static>(
             "/products/{id}/inventory/adjustments",
             get(handlers::admin_inventory::list_inventory_adjustments),
         )
+        // Scheduled price changes
+        .route(
+            "/products/{id}/price-schedules",
+            get(handlers::admin_price_schedules::list_price_schedules)
+                .post(handlers::admin_price_schedules::create_price_schedule),
+        )
+        .route(
+            "/products/{id}/price-schedules/{schedule_id}/cancel",
+            post(handlers::admin_price_schedules::cancel_price_schedule),
+        )
         // Product variations
         .route(
             "/products/{id}/variations",
@@ -634,6 +828,23 @@ fn build_dashboard_routes<S: Store + 'static>(
             "/stripe/refunds/{id}/process",
             post(handlers::admin_stripe_refunds::process_stripe_refund),
         )
+        // Stripe Connect (per-tenant connected accounts)
+        .route(
+            "/stripe/connect",
+            get(handlers::admin_stripe_connect::get_connect_status),
+        )
+        .route(
+            "/stripe/connect/onboarding",
+            post(handlers::admin_stripe_connect::create_onboarding_link),
+        )
+        .route(
+            "/stripe/connect/fee",
+            put(handlers::admin_stripe_connect::update_connect_fee),
+        )
+        .route(
+            "/stripe/connect/fees",
+            get(handlers::admin_stripe_connect::list_connect_fees),
+        )
         // Transactions (read-only)
         .route("/transactions", get(handlers::admin::list_transactions))
         // Coupons CRUD
@@ -657,6 +868,31 @@ fn build_dashboard_routes<S: Store + 'static>(
         ))
 }
 
+fn build_product_import_routes<S: Store + 'static>(
+    admin_dashboard_state: Arc<handlers::admin::AdminState>,
+    admin_auth_state: Arc<middleware::AdminAuthState<S>>,
+) -> Router {
+    Router::new()
+        .route(
+            "/products/import",
+            post(handlers::admin_products_import::import_products),
+        )
+        .route(
+            "/products/import/{job_id}",
+            get(handlers::admin_products_import::get_import_job),
+        )
+        .route(
+            "/products/export",
+            get(handlers::admin_products_import::export_products),
+        )
+        .with_state(admin_dashboard_state)
+        .layer(DefaultBodyLimit::max(constants::MAX_PRODUCT_IMPORT_SIZE))
+        .layer(axum::middleware::from_fn_with_state(
+            admin_auth_state,
+            middleware::admin_middleware,
+        ))
+}
+
 fn build_compliance_routes<S: Store + 'static>(
     compliance_state: Arc<handlers::admin_compliance::ComplianceAdminState>,
     admin_auth_state: Arc<middleware::AdminAuthState<S>>,
@@ -769,3 +1005,19 @@ fn build_image_routes<S: Store + 'static>(
             middleware::admin_middleware,
         ))
 }
+
+fn build_archive_routes<S: Store + 'static>(
+    archive_state: Arc<handlers::admin_archive::ArchiveState>,
+    admin_auth_state: Arc<middleware::AdminAuthState<S>>,
+) -> Router {
+    Router::new()
+        .route(
+            "/archive/purchases/{signature}",
+            get(handlers::admin_archive::get_archived_purchase),
+        )
+        .with_state(archive_state)
+        .layer(axum::middleware::from_fn_with_state(
+            admin_auth_state,
+            middleware::admin_middleware,
+        ))
+}
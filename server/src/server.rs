@@ -4,12 +4,14 @@ use std::time::Duration;
 
 use tower_http::timeout::TimeoutLayer;
 
-use crate::app::build_services;
+use crate::app::{build_services, build_services_with_config_bus};
 use crate::config::{Config, ServerConfig};
 use crate::middleware;
 use crate::payment_workers::spawn_workers_internal;
 use crate::router::build_router;
-use crate::storage::{PostgresConfig, PostgresPool, PostgresStore};
+use crate::storage::{
+    PostgresConfig, PostgresPool, PostgresStore, SqliteConfig, SqlitePool, SqliteStore,
+};
 use crate::Store;
 
 /// Default server address fallback - known valid at compile time
@@ -54,10 +56,22 @@ pub(crate) fn normalize_addr(raw: &str) -> SocketAddr {
 ///
 /// Bootstraps from environment: only `POSTGRES_URL` is required.
 /// Everything else is loaded from the database.
+///
+/// When `POSTGRES_URL` is unset and `SQLITE_PATH` is set, the server runs on
+/// SQLite instead and reads its config from YAML/env (see [`run_sqlite`]).
 pub async fn run() -> anyhow::Result<()> {
-    let postgres_url = std::env::var("POSTGRES_URL")
-        .or_else(|_| std::env::var("DATABASE_URL"))
-        .map_err(|_| anyhow::anyhow!("POSTGRES_URL environment variable is required"))?;
+    let postgres_url =
+        match std::env::var("POSTGRES_URL").or_else(|_| std::env::var("DATABASE_URL")) {
+            Ok(url) => url,
+            Err(_) if std::env::var("SQLITE_PATH").is_ok_and(|p| !p.is_empty()) => {
+                return run_sqlite().await;
+            }
+            Err(_) => {
+                return Err(anyhow::anyhow!(
+                    "POSTGRES_URL (or SQLITE_PATH) environment variable is required"
+                ))
+            }
+        };
 
     let server_address = std::env::var("SERVER_ADDRESS")
         .or_else(|_| std::env::var("CEDROS_SERVER_ADDRESS"))
@@ -68,7 +82,13 @@ pub async fn run() -> anyhow::Result<()> {
 
     pool.migrate().await?;
 
-    let config_repo = crate::config::PostgresConfigRepository::new(pool.inner().clone());
+    let config_encryption =
+        crate::config::ConfigEncryption::from_env_optional(pool.inner().clone())
+            .map_err(|e| anyhow::anyhow!("config encryption: {}", e))?;
+    let config_repo = crate::config::PostgresConfigRepository::with_optional_encryption(
+        pool.inner().clone(),
+        config_encryption,
+    );
     let tenant_id = "default";
     let cfg = Config::load_from_db(&config_repo, tenant_id, &postgres_url, &server_address).await?;
 
@@ -83,23 +103,59 @@ pub async fn run() -> anyhow::Result<()> {
         pool.clone(),
         cfg.storage.schema_mapping.clone(),
     ));
-    run_with_store(cfg, store, Some(pool.inner().clone())).await
+    run_with_store(cfg, store, Some(pool.inner().clone()), Some(tenant_id)).await
+}
+
+/// SQLite entry point.
+///
+/// The database-backed config repository is PostgreSQL-only, so config comes
+/// from `CEDROS_CONFIG_PATH` (or `config/default`) plus environment overrides;
+/// `SQLITE_PATH` selects the SQLite storage backend.
+async fn run_sqlite() -> anyhow::Result<()> {
+    let config_path = std::env::var("CEDROS_CONFIG_PATH").ok();
+    let cfg = Config::load(config_path.as_deref())?;
+
+    let pool = build_sqlite_pool(&cfg).await?;
+    pool.migrate().await?;
+
+    tracing::info!(
+        address = %cfg.server.address,
+        path = cfg.storage.sqlite_path.as_deref().unwrap_or_default(),
+        "Config loaded from file; using SQLite storage"
+    );
+
+    let store = Arc::new(SqliteStore::new(pool));
+    run_with_store(cfg, store, None, None).await
 }
 
 pub(crate) async fn run_with_store<S: Store + 'static>(
     cfg: Config,
     store: Arc<S>,
     storage_pg_pool: Option<sqlx::PgPool>,
+    config_tenant: Option<&str>,
 ) -> anyhow::Result<()> {
-    let built = build_services(&cfg, store.clone(), storage_pg_pool).await?;
+    let built = match config_tenant {
+        Some(tenant_id) => {
+            build_services_with_config_bus(&cfg, store.clone(), storage_pg_pool, tenant_id).await?
+        }
+        None => build_services(&cfg, store.clone(), storage_pg_pool).await?,
+    };
+    let config_bus = built.config_bus.clone();
     let health_state = built.health_state.clone();
     let notifier = built.notifier.clone();
     let token22_for_workers = built.token22_service.clone();
     let sanctions_list_for_workers = built.sanctions_list_service.clone();
-    let config_repo_for_workers = built
-        .storage_pg_pool
-        .as_ref()
-        .map(|pool| Arc::new(crate::config::PostgresConfigRepository::new(pool.clone())));
+    let product_repo_for_workers = built.product_repo.clone();
+    let secrets_for_workers = built.secrets.clone();
+    let stripe_client_for_workers = built.stripe_client.clone();
+    let config_repo_for_workers = built.storage_pg_pool.as_ref().map(|pool| {
+        Arc::new(
+            crate::config::PostgresConfigRepository::with_optional_encryption(
+                pool.clone(),
+                built.config_encryption.clone(),
+            ),
+        )
+    });
 
     let base_router = build_router(built.into_router_states());
 
@@ -129,6 +185,13 @@ pub(crate) async fn run_with_store<S: Store + 'static>(
     let api_version = axum::middleware::from_fn(middleware::api_version_middleware);
 
     let rate_limiter = Arc::new(middleware::RateLimiter::new(cfg.rate_limit.clone()));
+    if let Some(ref bus) = config_bus {
+        let limiter = rate_limiter.clone();
+        bus.register("rate_limiter", &["rate_limit"], move |cfg| {
+            limiter.reconfigure(cfg.rate_limit.clone());
+            Ok(())
+        });
+    }
 
     let auth_state = Arc::new(middleware::AuthState::new(
         cfg.api_key.clone(),
@@ -180,6 +243,10 @@ pub(crate) async fn run_with_store<S: Store + 'static>(
         token22_for_workers,
         config_repo_for_workers,
         sanctions_list_for_workers,
+        Some(product_repo_for_workers),
+        stripe_client_for_workers,
+        secrets_for_workers,
+        config_bus,
     )?;
 
     const SERVER_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(15);
@@ -277,3 +344,14 @@ pub(crate) async fn build_postgres_pool(cfg: &Config, url: &str) -> anyhow::Resu
     pg_config.max_lifetime = cfg.storage.postgres_pool.conn_max_lifetime;
     Ok(PostgresPool::new(&pg_config).await?)
 }
+
+pub(crate) async fn build_sqlite_pool(cfg: &Config) -> anyhow::Result<SqlitePool> {
+    let path = cfg
+        .storage
+        .sqlite_path
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("storage.sqlite_path is required for SQLite storage"))?;
+    let mut sqlite_config = SqliteConfig::from_env();
+    sqlite_config.path = path.to_string();
+    Ok(SqlitePool::new(&sqlite_config).await?)
+}
@@ -0,0 +1,289 @@
+//! Anthropic Messages API.
+//!
+//! The Messages API differs from Chat Completions in a few ways that matter for
+//! tool calling: the system prompt is a top-level field, assistant tool calls
+//! are `tool_use` content blocks, tool results are `tool_result` blocks sent
+//! in a user turn, and consecutive turns must alternate between user and
+//! assistant.
+
+use serde_json::{json, Value};
+
+use super::tools::{
+    to_anthropic_tools, ConversationMessage, ToolCall, ToolCallingResponse, ToolDefinition,
+};
+use super::{AiEndpoint, AiError, AiService, TokenUsage};
+
+/// Messages API endpoint
+const ANTHROPIC_MESSAGES_URL: &str = "https://api.anthropic.com/v1/messages";
+
+/// API version sent in the `anthropic-version` header
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// `max_tokens` is required by the Messages API
+const ANTHROPIC_MAX_TOKENS: u32 = 4096;
+
+impl AiService {
+    /// Anthropic Messages API with tool calling
+    #[tracing::instrument(
+        name = "ai.request",
+        skip_all,
+        fields(otel.kind = "client", gen_ai.system = "anthropic", gen_ai.request.model = endpoint.model_id())
+    )]
+    pub(super) async fn anthropic_complete_with_tools(
+        &self,
+        endpoint: &AiEndpoint,
+        messages: &[ConversationMessage],
+        tools: &[ToolDefinition],
+    ) -> Result<ToolCallingResponse, AiError> {
+        let response = anthropic_request_builder(&self.http_client, endpoint)
+            .json(&anthropic_request(endpoint.model_id(), messages, tools))
+            .send()
+            .await
+            .map_err(|e| AiError::HttpError(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(AiError::ServiceError(format!(
+                "Anthropic API error ({}): {}",
+                status, error_text
+            )));
+        }
+
+        let body: Value = response.json().await.map_err(|e| {
+            AiError::ParseError(format!("Failed to parse Anthropic response: {}", e))
+        })?;
+        parse_anthropic_response(&body)
+    }
+}
+
+/// POST to the Messages API with authentication headers set
+pub(super) fn anthropic_request_builder(
+    client: &reqwest::Client,
+    endpoint: &AiEndpoint,
+) -> reqwest::RequestBuilder {
+    client
+        .post(ANTHROPIC_MESSAGES_URL)
+        .header("x-api-key", &endpoint.api_key)
+        .header("anthropic-version", ANTHROPIC_VERSION)
+        .header("Content-Type", "application/json")
+}
+
+/// Messages API request body for a conversation (shared by streaming and non-streaming calls)
+pub(super) fn anthropic_request(
+    model_id: &str,
+    messages: &[ConversationMessage],
+    tools: &[ToolDefinition],
+) -> Value {
+    let system: String = messages
+        .iter()
+        .filter_map(|m| match m {
+            ConversationMessage::System { content } => Some(content.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    // Turns must alternate, so a tool result following a user message (or
+    // several tool results in a row) are merged into a single user turn
+    let mut turns: Vec<(&'static str, Vec<Value>)> = Vec::new();
+    for message in messages {
+        let (role, blocks) = match message {
+            ConversationMessage::System { .. } => continue,
+            ConversationMessage::User { content } => {
+                ("user", vec![json!({"type": "text", "text": content})])
+            }
+            ConversationMessage::Assistant {
+                content,
+                tool_calls,
+            } => {
+                let mut blocks = Vec::new();
+                if !content.is_empty() {
+                    blocks.push(json!({"type": "text", "text": content}));
+                }
+                for tc in tool_calls.iter().flatten() {
+                    let input = match &tc.arguments {
+                        Value::Object(_) => tc.arguments.clone(),
+                        _ => json!({}),
+                    };
+                    blocks.push(json!({
+                        "type": "tool_use",
+                        "id": tc.id,
+                        "name": tc.name,
+                        "input": input
+                    }));
+                }
+                ("assistant", blocks)
+            }
+            ConversationMessage::Tool {
+                tool_call_id,
+                content,
+            } => (
+                "user",
+                vec![json!({
+                    "type": "tool_result",
+                    "tool_use_id": tool_call_id,
+                    "content": content
+                })],
+            ),
+        };
+        if blocks.is_empty() {
+            continue;
+        }
+        match turns.last_mut() {
+            Some((last_role, last_blocks)) if *last_role == role => last_blocks.extend(blocks),
+            _ => turns.push((role, blocks)),
+        }
+    }
+
+    let anthropic_messages: Vec<Value> = turns
+        .into_iter()
+        .map(|(role, content)| json!({"role": role, "content": content}))
+        .collect();
+
+    let mut request_body = json!({
+        "model": model_id,
+        "max_tokens": ANTHROPIC_MAX_TOKENS,
+        "messages": anthropic_messages,
+        "temperature": 0.7
+    });
+
+    if !system.is_empty() {
+        request_body["system"] = Value::String(system);
+    }
+
+    if !tools.is_empty() {
+        request_body["tools"] = to_anthropic_tools(tools);
+    }
+
+    request_body
+}
+
+/// Collect the text and `tool_use` blocks of a Messages API response
+fn parse_anthropic_response(body: &Value) -> Result<ToolCallingResponse, AiError> {
+    let blocks = body
+        .get("content")
+        .and_then(Value::as_array)
+        .ok_or_else(|| AiError::ParseError("No completion returned".to_string()))?;
+
+    let mut content = String::new();
+    let mut tool_calls = Vec::new();
+    for block in blocks {
+        match block.get("type").and_then(Value::as_str) {
+            Some("text") => {
+                if let Some(text) = block.get("text").and_then(Value::as_str) {
+                    content.push_str(text);
+                }
+            }
+            Some("tool_use") => tool_calls.push(ToolCall {
+                id: block
+                    .get("id")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                name: block
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                arguments: block.get("input").cloned().unwrap_or_else(|| json!({})),
+            }),
+            _ => {}
+        }
+    }
+
+    let usage = TokenUsage::from_anthropic(body.get("usage").unwrap_or(&Value::Null));
+    Ok(ToolCallingResponse::with_tools(content, tool_calls).with_usage(usage))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::ai::tools::get_chat_tools;
+
+    #[test]
+    fn test_request_maps_system_tools_and_tool_results() {
+        let messages = vec![
+            ConversationMessage::system("Be helpful"),
+            ConversationMessage::user("Any rings?"),
+            ConversationMessage::assistant_with_tools(
+                "Let me check",
+                vec![ToolCall {
+                    id: "toolu_1".to_string(),
+                    name: "product_search".to_string(),
+                    arguments: json!({"query": "rings"}),
+                }],
+            ),
+            ConversationMessage::tool("toolu_1", r#"{"products":[]}"#),
+        ];
+        let body = anthropic_request("claude-sonnet-4-5", &messages, &get_chat_tools());
+
+        assert_eq!(body["system"], "Be helpful");
+        assert_eq!(body["max_tokens"], ANTHROPIC_MAX_TOKENS);
+        assert_eq!(body["tools"][0]["name"], "product_search");
+
+        let turns = body["messages"].as_array().unwrap();
+        assert_eq!(turns.len(), 3);
+        assert_eq!(turns[0]["role"], "user");
+        assert_eq!(turns[1]["role"], "assistant");
+        assert_eq!(turns[1]["content"][1]["type"], "tool_use");
+        assert_eq!(turns[1]["content"][1]["input"]["query"], "rings");
+        assert_eq!(turns[2]["role"], "user");
+        assert_eq!(turns[2]["content"][0]["type"], "tool_result");
+        assert_eq!(turns[2]["content"][0]["tool_use_id"], "toolu_1");
+    }
+
+    #[test]
+    fn test_consecutive_tool_results_share_a_user_turn() {
+        let messages = vec![
+            ConversationMessage::user("Hi"),
+            ConversationMessage::assistant_with_tools(
+                "",
+                vec![
+                    ToolCall {
+                        id: "a".to_string(),
+                        name: "fact_finder".to_string(),
+                        arguments: Value::Null,
+                    },
+                    ToolCall {
+                        id: "b".to_string(),
+                        name: "product_search".to_string(),
+                        arguments: json!({"query": "mugs"}),
+                    },
+                ],
+            ),
+            ConversationMessage::tool("a", "{}"),
+            ConversationMessage::tool("b", "{}"),
+        ];
+        let body = anthropic_request("claude-haiku-4-5", &messages, &[]);
+
+        let turns = body["messages"].as_array().unwrap();
+        assert_eq!(turns.len(), 3);
+        assert_eq!(turns[1]["content"].as_array().unwrap().len(), 2);
+        assert_eq!(turns[1]["content"][0]["input"], json!({}));
+        assert_eq!(turns[2]["content"].as_array().unwrap().len(), 2);
+        assert!(body.get("system").is_none());
+        assert!(body.get("tools").is_none());
+    }
+
+    #[test]
+    fn test_parse_response_text_and_tool_use() {
+        let body = json!({
+            "content": [
+                {"type": "text", "text": "Checking your order"},
+                {"type": "tool_use", "id": "toolu_9", "name": "order_lookup", "input": {"orderId": "o1"}}
+            ],
+            "stop_reason": "tool_use",
+            "usage": {"input_tokens": 310, "output_tokens": 42}
+        });
+        let response = parse_anthropic_response(&body).unwrap();
+        assert_eq!(response.content, "Checking your order");
+        assert!(!response.is_complete);
+        assert_eq!(response.tool_calls[0].id, "toolu_9");
+        assert_eq!(response.tool_calls[0].arguments["orderId"], "o1");
+        assert_eq!(response.usage.input_tokens, 310);
+        assert_eq!(response.usage.output_tokens, 42);
+
+        assert!(parse_anthropic_response(&json!({"type": "error"})).is_err());
+    }
+}
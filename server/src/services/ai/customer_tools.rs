@@ -0,0 +1,469 @@
+//! Account tools for the signed-in chat customer.
+//!
+//! `order_lookup`, `start_return` and `subscription_status` act only on the
+//! customer bound to the chat session ([`CustomerToolContext::customer_id`],
+//! verified from the caller's cedros-login token). The model never supplies a
+//! customer: orders belonging to anyone else are reported as not found, the
+//! same as in the account API, so ids cannot be probed.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde_json::{json, Value};
+
+use crate::config::ShopReturnsConfig;
+use crate::errors::validation::validate_resource_id;
+use crate::models::{Order, ReturnItem};
+use crate::repositories::ProductRepository;
+use crate::services::returns::{
+    load_order_products, new_customer_return, returnable_items, validate_customer_return,
+    validate_items, ReturnPolicyError,
+};
+use crate::storage::Store;
+
+use super::tools::{
+    OrderLookupArgs, StartReturnArgs, ToolCall, ORDER_LOOKUP, START_RETURN, SUBSCRIPTION_STATUS,
+};
+
+/// Recent orders listed when order_lookup has no order_id
+const RECENT_ORDERS_LIMIT: i32 = 5;
+
+/// Maximum length of a return reason supplied through chat
+const MAX_RETURN_REASON_LEN: usize = 1000;
+
+/// Everything the account tools may touch for one chat turn
+#[derive(Clone)]
+pub struct CustomerToolContext {
+    pub tenant_id: String,
+    /// Verified cedros-login user id of the session's customer
+    pub customer_id: String,
+    pub store: Arc<dyn Store>,
+    pub products: Arc<dyn ProductRepository>,
+    pub returns: ShopReturnsConfig,
+}
+
+/// Execute an account tool - returns (result_string, action)
+pub async fn execute_customer_tool(
+    ctx: &CustomerToolContext,
+    tool_call: &ToolCall,
+) -> (String, Option<String>) {
+    let result = match tool_call.name.as_str() {
+        ORDER_LOOKUP => order_lookup(ctx, tool_call).await,
+        START_RETURN => start_return(ctx, tool_call).await,
+        SUBSCRIPTION_STATUS => subscription_status(ctx).await,
+        other => Err(format!("Unknown tool: {}", other)),
+    };
+    match result {
+        Ok((response, action)) => (
+            json!({ "name": tool_call.name, "response": response }).to_string(),
+            Some(action),
+        ),
+        Err(error) => (json!({ "error": error }).to_string(), None),
+    }
+}
+
+async fn order_lookup(
+    ctx: &CustomerToolContext,
+    tool_call: &ToolCall,
+) -> Result<(Value, String), String> {
+    let args: OrderLookupArgs = serde_json::from_value(tool_call.arguments.clone())
+        .map_err(|e| format!("Invalid arguments: {}", e))?;
+
+    let Some(order_id) = args.order_id.filter(|id| !id.trim().is_empty()) else {
+        let orders = ctx
+            .store
+            .list_orders_by_user_id(&ctx.tenant_id, &ctx.customer_id, RECENT_ORDERS_LIMIT, 0)
+            .await
+            .map_err(|e| storage_error("list orders", e))?;
+        let orders: Vec<Value> = orders
+            .iter()
+            .map(|o| {
+                json!({
+                    "orderId": o.id,
+                    "status": o.status,
+                    "amount": o.amount,
+                    "asset": o.amount_asset,
+                    "itemCount": o.items.iter().map(|i| i.quantity).sum::<i32>(),
+                    "createdAt": o.created_at,
+                })
+            })
+            .collect();
+        return Ok((
+            json!({ "orders": orders }),
+            "Listed recent orders".to_string(),
+        ));
+    };
+
+    let order = load_owned_order(ctx, order_id.trim()).await?;
+    let (fulfillments, history, returns) = tokio::try_join!(
+        ctx.store.list_fulfillments(&ctx.tenant_id, &order.id, 100),
+        ctx.store.list_order_history(&ctx.tenant_id, &order.id, 100),
+        ctx.store
+            .list_return_requests(&ctx.tenant_id, None, Some(&order.id), 100, 0),
+    )
+    .map_err(|e| storage_error("load order detail", e))?;
+    let products = load_order_products(&*ctx.products, &ctx.tenant_id, &order)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(error = %e, order_id = %order.id, "Failed to load order products");
+            HashMap::new()
+        });
+    let returnable = returnable_items(
+        &ctx.returns,
+        &order,
+        &fulfillments,
+        &returns,
+        &products,
+        Utc::now(),
+    );
+
+    // Customer-facing fields only: admin notes and actors stay out of the transcript
+    let response = json!({
+        "orderId": order.id,
+        "status": order.status,
+        "amount": order.amount,
+        "asset": order.amount_asset,
+        "items": order.items,
+        "createdAt": order.created_at,
+        "history": history
+            .iter()
+            .map(|h| json!({ "status": h.to_status, "at": h.created_at }))
+            .collect::<Vec<_>>(),
+        "shipments": fulfillments
+            .iter()
+            .map(|f| json!({
+                "status": f.status,
+                "carrier": f.carrier,
+                "trackingNumber": f.tracking_number,
+                "trackingUrl": f.tracking_url,
+                "shippedAt": f.shipped_at,
+                "deliveredAt": f.delivered_at,
+            }))
+            .collect::<Vec<_>>(),
+        "returns": returns
+            .iter()
+            .map(|r| json!({ "returnId": r.id, "status": r.status, "createdAt": r.created_at }))
+            .collect::<Vec<_>>(),
+        "returnableItems": returnable,
+    });
+    Ok((response, format!("Looked up order {}", order.id)))
+}
+
+async fn start_return(
+    ctx: &CustomerToolContext,
+    tool_call: &ToolCall,
+) -> Result<(Value, String), String> {
+    let args: StartReturnArgs = serde_json::from_value(tool_call.arguments.clone())
+        .map_err(|e| format!("Invalid arguments: {}", e))?;
+
+    let items: Vec<ReturnItem> = args
+        .items
+        .into_iter()
+        .map(|i| ReturnItem {
+            product_id: i.product_id,
+            variant_id: i.variant_id.filter(|v| !v.is_empty()),
+            quantity: i.quantity,
+            condition: None,
+            reason_code: i.reason_code.filter(|c| !c.is_empty()),
+        })
+        .collect();
+    validate_items(&items)?;
+    let reason = args
+        .reason
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty());
+    if reason
+        .as_ref()
+        .is_some_and(|r| r.len() > MAX_RETURN_REASON_LEN)
+    {
+        return Err(format!(
+            "reason must be at most {} characters",
+            MAX_RETURN_REASON_LEN
+        ));
+    }
+
+    let order = load_owned_order(ctx, args.order_id.trim()).await?;
+    let now = Utc::now();
+    match validate_customer_return(
+        &*ctx.store,
+        &*ctx.products,
+        &ctx.returns,
+        &order,
+        &items,
+        now,
+    )
+    .await
+    {
+        Ok(()) => {}
+        Err(ReturnPolicyError::Ineligible(reason)) => return Err(reason.to_string()),
+        Err(e) => {
+            tracing::error!(error = %e, order_id = %order.id, "Failed to evaluate return eligibility");
+            return Err("Could not check return eligibility right now".to_string());
+        }
+    }
+
+    let mut request = new_customer_return(&order, &ctx.customer_id, items, reason, now);
+    request
+        .metadata
+        .insert("channel".to_string(), "chat".to_string());
+    ctx.store
+        .create_return_request(request.clone())
+        .await
+        .map_err(|e| storage_error("create return request", e))?;
+
+    Ok((
+        json!({
+            "returnId": request.id,
+            "orderId": request.order_id,
+            "status": request.status,
+            "note": "The return is pending review by staff; the customer will be contacted with next steps.",
+        }),
+        format!("Started return {} for order {}", request.id, order.id),
+    ))
+}
+
+async fn subscription_status(ctx: &CustomerToolContext) -> Result<(Value, String), String> {
+    let subscriptions = ctx
+        .store
+        .get_subscriptions_by_user_id(&ctx.tenant_id, &ctx.customer_id)
+        .await
+        .map_err(|e| storage_error("list subscriptions", e))?;
+    let subscriptions: Vec<Value> = subscriptions
+        .iter()
+        .map(|s| {
+            json!({
+                "subscriptionId": s.id,
+                "productId": s.product_id,
+                "status": s.status,
+                "billingPeriod": s.billing_period,
+                "billingInterval": s.billing_interval,
+                "currentPeriodEnd": s.current_period_end,
+                "cancelAtPeriodEnd": s.cancel_at_period_end,
+                "trialEnd": s.trial_end,
+            })
+        })
+        .collect();
+    Ok((
+        json!({ "subscriptions": subscriptions }),
+        "Checked subscriptions".to_string(),
+    ))
+}
+
+/// Load an order owned by the session's customer; anything else is "not found"
+async fn load_owned_order(ctx: &CustomerToolContext, order_id: &str) -> Result<Order, String> {
+    validate_resource_id(order_id).map_err(|e| e.message)?;
+    match ctx.store.get_order(&ctx.tenant_id, order_id).await {
+        Ok(Some(order)) if order.user_id.as_deref() == Some(ctx.customer_id.as_str()) => Ok(order),
+        Ok(_) => Err(format!("Order {} not found for this customer", order_id)),
+        Err(e) => Err(storage_error("load order", e)),
+    }
+}
+
+fn storage_error(what: &str, e: impl std::fmt::Display) -> String {
+    tracing::error!(error = %e, "Chat tool failed to {}", what);
+    "Account data is unavailable right now".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        BillingPeriod, Fulfillment, OrderItem, PaymentMethod, Subscription, SubscriptionStatus,
+    };
+    use crate::repositories::InMemoryProductRepository;
+    use crate::storage::InMemoryStore;
+
+    fn order(id: &str, user_id: &str, status: &str) -> Order {
+        Order {
+            id: id.to_string(),
+            tenant_id: "t1".to_string(),
+            source: "stripe".to_string(),
+            purchase_id: format!("cs_{id}"),
+            resource_id: "prod-1".to_string(),
+            user_id: Some(user_id.to_string()),
+            customer: None,
+            status: status.to_string(),
+            items: vec![OrderItem {
+                product_id: "prod-1".to_string(),
+                variant_id: None,
+                quantity: 2,
+                line_total: None,
+            }],
+            amount: 2000,
+            amount_asset: "USD".to_string(),
+            customer_email: None,
+            customer_name: None,
+            receipt_url: None,
+            shipping: None,
+            metadata: HashMap::new(),
+            created_at: Utc::now(),
+            updated_at: None,
+            status_updated_at: None,
+        }
+    }
+
+    async fn context() -> (CustomerToolContext, Arc<InMemoryStore>) {
+        let store = Arc::new(InMemoryStore::new());
+        store
+            .try_store_order(order("order-alice", "alice", "delivered"))
+            .await
+            .unwrap();
+        store
+            .try_store_order(order("order-bob", "bob", "delivered"))
+            .await
+            .unwrap();
+        let ctx = CustomerToolContext {
+            tenant_id: "t1".to_string(),
+            customer_id: "alice".to_string(),
+            store: store.clone(),
+            products: Arc::new(InMemoryProductRepository::new(Vec::new())),
+            returns: ShopReturnsConfig {
+                enabled: true,
+                ..Default::default()
+            },
+        };
+        (ctx, store)
+    }
+
+    fn call(name: &str, arguments: Value) -> ToolCall {
+        ToolCall {
+            id: "call_1".to_string(),
+            name: name.to_string(),
+            arguments,
+        }
+    }
+
+    fn parse(result: &str) -> Value {
+        serde_json::from_str(result).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_order_lookup_only_sees_own_orders() {
+        let (ctx, store) = context().await;
+        store
+            .create_fulfillment(Fulfillment {
+                id: "ful-1".to_string(),
+                tenant_id: "t1".to_string(),
+                order_id: "order-alice".to_string(),
+                status: "shipped".to_string(),
+                carrier: Some("UPS".to_string()),
+                tracking_number: Some("1Z999".to_string()),
+                tracking_url: None,
+                items: vec![],
+                shipped_at: Some(Utc::now()),
+                delivered_at: None,
+                metadata: HashMap::new(),
+                created_at: Utc::now(),
+                updated_at: None,
+            })
+            .await
+            .unwrap();
+
+        let (result, _) = execute_customer_tool(&ctx, &call(ORDER_LOOKUP, json!({}))).await;
+        let orders = parse(&result)["response"]["orders"].clone();
+        assert_eq!(orders.as_array().unwrap().len(), 1);
+        assert_eq!(orders[0]["orderId"], "order-alice");
+
+        let (result, action) = execute_customer_tool(
+            &ctx,
+            &call(ORDER_LOOKUP, json!({ "order_id": "order-alice" })),
+        )
+        .await;
+        let detail = parse(&result)["response"].clone();
+        assert_eq!(detail["status"], "delivered");
+        assert_eq!(detail["shipments"][0]["trackingNumber"], "1Z999");
+        assert!(action.is_some());
+
+        // Another customer's order is indistinguishable from a missing one
+        let (result, action) = execute_customer_tool(
+            &ctx,
+            &call(ORDER_LOOKUP, json!({ "order_id": "order-bob" })),
+        )
+        .await;
+        assert!(parse(&result)["error"]
+            .as_str()
+            .unwrap()
+            .contains("not found"));
+        assert!(action.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_start_return_creates_pending_request_for_own_order_only() {
+        let (ctx, store) = context().await;
+        let items = json!([{ "product_id": "prod-1", "quantity": 1, "reason_code": "defective" }]);
+
+        let (result, _) = execute_customer_tool(
+            &ctx,
+            &call(
+                START_RETURN,
+                json!({ "order_id": "order-bob", "items": items.clone() }),
+            ),
+        )
+        .await;
+        assert!(parse(&result)["error"].is_string());
+
+        let (result, _) = execute_customer_tool(
+            &ctx,
+            &call(
+                START_RETURN,
+                json!({ "order_id": "order-alice", "items": items, "reason": "Broken" }),
+            ),
+        )
+        .await;
+        let response = parse(&result)["response"].clone();
+        assert_eq!(response["status"], "requested");
+
+        let returns = store
+            .list_return_requests("t1", None, Some("order-alice"), 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(returns.len(), 1);
+        assert_eq!(returns[0].metadata.get("user_id").unwrap(), "alice");
+        assert_eq!(returns[0].metadata.get("channel").unwrap(), "chat");
+        assert!(store
+            .list_return_requests("t1", None, Some("order-bob"), 10, 0)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_subscription_status_lists_own_subscriptions() {
+        let (ctx, store) = context().await;
+        for (id, user) in [("sub-alice", "alice"), ("sub-bob", "bob")] {
+            let now = Utc::now();
+            store
+                .save_subscription(Subscription {
+                    id: id.to_string(),
+                    tenant_id: "t1".to_string(),
+                    product_id: "plan-pro".to_string(),
+                    plan_id: None,
+                    wallet: None,
+                    user_id: Some(user.to_string()),
+                    stripe_customer_id: None,
+                    stripe_subscription_id: None,
+                    payment_method: PaymentMethod::Stripe,
+                    billing_period: BillingPeriod::Month,
+                    billing_interval: 1,
+                    status: SubscriptionStatus::Active,
+                    current_period_start: now,
+                    current_period_end: now + chrono::Duration::days(30),
+                    trial_end: None,
+                    cancelled_at: None,
+                    cancel_at_period_end: false,
+                    metadata: HashMap::new(),
+                    payment_signature: None,
+                    created_at: Some(now),
+                    updated_at: Some(now),
+                })
+                .await
+                .unwrap();
+        }
+
+        let (result, _) = execute_customer_tool(&ctx, &call(SUBSCRIPTION_STATUS, json!({}))).await;
+        let subs = parse(&result)["response"]["subscriptions"].clone();
+        assert_eq!(subs.as_array().unwrap().len(), 1);
+        assert_eq!(subs[0]["subscriptionId"], "sub-alice");
+    }
+}
@@ -0,0 +1,231 @@
+//! Text embeddings for semantic search.
+//!
+//! OpenAI and OpenAI-compatible servers use the Embeddings API and Gemini uses
+//! `batchEmbedContents`. Anthropic has no embeddings API, so it cannot be
+//! assigned to the embeddings task. Gemini reports no token usage for
+//! embeddings, so those calls are counted without tokens or cost.
+
+use std::time::Instant;
+
+use serde_json::{json, Value};
+
+use super::{
+    gemini_url, model_to_string, provider_to_string, AiEndpoint, AiError, AiService, TokenUsage,
+};
+use crate::handlers::admin_ai::AiProvider;
+use crate::observability::record_ai_call;
+
+impl AiService {
+    /// Embed `inputs`, returning one vector per input in the same order
+    pub async fn embed(
+        &self,
+        endpoint: &AiEndpoint,
+        inputs: &[String],
+    ) -> Result<Vec<Vec<f32>>, AiError> {
+        if inputs.is_empty() {
+            return Ok(vec![]);
+        }
+        self.check_budget(endpoint).await?;
+
+        let start = Instant::now();
+        let result = match endpoint.provider {
+            AiProvider::Openai | AiProvider::OpenaiCompatible => {
+                self.openai_embed(endpoint, inputs).await
+            }
+            AiProvider::Gemini => self
+                .gemini_embed(endpoint, inputs)
+                .await
+                .map(|vectors| (vectors, TokenUsage::default())),
+            AiProvider::Anthropic => Err(AiError::NotConfigured(
+                "Anthropic does not provide an embeddings API".to_string(),
+            )),
+        }
+        .and_then(|(vectors, usage)| {
+            if vectors.len() == inputs.len() {
+                Ok((vectors, usage))
+            } else {
+                Err(AiError::ParseError(format!(
+                    "Expected {} embeddings, got {}",
+                    inputs.len(),
+                    vectors.len()
+                )))
+            }
+        });
+
+        record_ai_call(
+            provider_to_string(endpoint.provider),
+            model_to_string(endpoint.model),
+            "embeddings",
+            result.is_ok(),
+            start.elapsed().as_secs_f64(),
+        );
+        let (vectors, usage) = result?;
+        self.record_usage(endpoint, "embeddings", usage).await;
+        Ok(vectors)
+    }
+
+    /// OpenAI Embeddings API
+    #[tracing::instrument(
+        name = "ai.request",
+        skip_all,
+        fields(otel.kind = "client", gen_ai.system = provider_to_string(endpoint.provider), gen_ai.request.model = endpoint.model_id())
+    )]
+    async fn openai_embed(
+        &self,
+        endpoint: &AiEndpoint,
+        inputs: &[String],
+    ) -> Result<(Vec<Vec<f32>>, TokenUsage), AiError> {
+        let url = match &endpoint.base_url {
+            Some(base) => format!("{}/embeddings", base.trim_end_matches('/')),
+            None => "https://api.openai.com/v1/embeddings".to_string(),
+        };
+        let response = endpoint
+            .bearer_auth(self.http_client.post(url))
+            .header("Content-Type", "application/json")
+            .json(&json!({"model": endpoint.model_id(), "input": inputs}))
+            .send()
+            .await
+            .map_err(|e| AiError::HttpError(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(AiError::ServiceError(format!(
+                "{} API error ({}): {}",
+                endpoint.display_name(),
+                status,
+                error_text
+            )));
+        }
+
+        let body: Value = response.json().await.map_err(|e| {
+            AiError::ParseError(format!("Failed to parse embeddings response: {}", e))
+        })?;
+        let usage = TokenUsage::from_openai(body.get("usage").unwrap_or(&Value::Null));
+        Ok((parse_openai_embeddings(&body)?, usage))
+    }
+
+    /// Gemini batchEmbedContents API
+    #[tracing::instrument(
+        name = "ai.request",
+        skip_all,
+        fields(otel.kind = "client", gen_ai.system = "gemini", gen_ai.request.model = endpoint.model_id())
+    )]
+    async fn gemini_embed(
+        &self,
+        endpoint: &AiEndpoint,
+        inputs: &[String],
+    ) -> Result<Vec<Vec<f32>>, AiError> {
+        let response = self
+            .http_client
+            .post(gemini_url(endpoint, "batchEmbedContents"))
+            .header("Content-Type", "application/json")
+            .header("x-goog-api-key", &endpoint.api_key)
+            .json(&gemini_embed_request(endpoint.model_id(), inputs))
+            .send()
+            .await
+            .map_err(|e| AiError::HttpError(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(AiError::ServiceError(format!(
+                "Gemini API error ({}): {}",
+                status, error_text
+            )));
+        }
+
+        let body: Value = response.json().await.map_err(|e| {
+            AiError::ParseError(format!("Failed to parse embeddings response: {}", e))
+        })?;
+        parse_gemini_embeddings(&body)
+    }
+}
+
+fn gemini_embed_request(model_id: &str, inputs: &[String]) -> Value {
+    let model = format!("models/{}", model_id);
+    let requests: Vec<Value> = inputs
+        .iter()
+        .map(|text| json!({"model": model, "content": {"parts": [{"text": text}]}}))
+        .collect();
+    json!({ "requests": requests })
+}
+
+fn parse_vector(values: Option<&Value>) -> Result<Vec<f32>, AiError> {
+    values
+        .and_then(Value::as_array)
+        .ok_or_else(|| AiError::ParseError("Embedding without values".to_string()))?
+        .iter()
+        .map(|v| {
+            v.as_f64()
+                .map(|f| f as f32)
+                .ok_or_else(|| AiError::ParseError("Non-numeric embedding value".to_string()))
+        })
+        .collect()
+}
+
+/// `data[].embedding`, ordered by `data[].index`
+fn parse_openai_embeddings(body: &Value) -> Result<Vec<Vec<f32>>, AiError> {
+    let data = body
+        .get("data")
+        .and_then(Value::as_array)
+        .ok_or_else(|| AiError::ParseError("No embeddings returned".to_string()))?;
+    let mut indexed = data
+        .iter()
+        .enumerate()
+        .map(|(position, item)| {
+            let index = item
+                .get("index")
+                .and_then(Value::as_u64)
+                .unwrap_or(position as u64);
+            Ok((index, parse_vector(item.get("embedding"))?))
+        })
+        .collect::<Result<Vec<_>, AiError>>()?;
+    indexed.sort_by_key(|(index, _)| *index);
+    Ok(indexed.into_iter().map(|(_, vector)| vector).collect())
+}
+
+/// `embeddings[].values`, in request order
+fn parse_gemini_embeddings(body: &Value) -> Result<Vec<Vec<f32>>, AiError> {
+    body.get("embeddings")
+        .and_then(Value::as_array)
+        .ok_or_else(|| AiError::ParseError("No embeddings returned".to_string()))?
+        .iter()
+        .map(|item| parse_vector(item.get("values")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_openai_embeddings_orders_by_index() {
+        let body = json!({
+            "data": [
+                {"index": 1, "embedding": [0.5, 0.25]},
+                {"index": 0, "embedding": [1.0, 0.0]}
+            ]
+        });
+        let vectors = parse_openai_embeddings(&body).unwrap();
+        assert_eq!(vectors, vec![vec![1.0, 0.0], vec![0.5, 0.25]]);
+
+        assert!(parse_openai_embeddings(&json!({"error": "bad"})).is_err());
+        assert!(parse_openai_embeddings(&json!({"data": [{"embedding": ["x"]}]})).is_err());
+    }
+
+    #[test]
+    fn test_gemini_request_and_response() {
+        let body = gemini_embed_request("text-embedding-004", &["red boots".to_string()]);
+        assert_eq!(body["requests"][0]["model"], "models/text-embedding-004");
+        assert_eq!(
+            body["requests"][0]["content"]["parts"][0]["text"],
+            "red boots"
+        );
+
+        let response = json!({"embeddings": [{"values": [0.1, 0.2]}, {"values": [0.3]}]});
+        let vectors = parse_gemini_embeddings(&response).unwrap();
+        assert_eq!(vectors.len(), 2);
+        assert_eq!(vectors[1], vec![0.3]);
+    }
+}
@@ -0,0 +1,424 @@
+//! Human handoff for chat sessions.
+//!
+//! A session moves `active -> waiting_for_agent -> with_agent` when the
+//! assistant (request_human_agent tool) or the customer asks for a person and
+//! a staff member claims it; releasing hands it back to the assistant. While
+//! handed off the assistant stays silent and agent replies are stored as
+//! `agent` messages. Customers pick up new messages by long-polling
+//! [`ChatHandoffService::wait_for_messages`], which is woken in-process and
+//! also re-reads the store so replies posted on another instance arrive too.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use thiserror::Error;
+use tokio::sync::Notify;
+
+use crate::models::chat::status;
+use crate::models::{ChatMessage, ChatSession};
+use crate::storage::{StorageError, Store};
+use crate::webhooks::Notifier;
+
+/// How often a waiting long-poll re-reads the store
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Maximum messages returned by one poll
+const MAX_MESSAGES_PER_POLL: i32 = 100;
+
+#[derive(Debug, Error)]
+pub enum HandoffError {
+    #[error("chat session not found")]
+    NotFound,
+    #[error("{0}")]
+    InvalidState(String),
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+}
+
+/// Coordinates handoff state changes, agent replies and waiting customers
+pub struct ChatHandoffService {
+    store: Arc<dyn Store>,
+    notifier: Arc<dyn Notifier>,
+    /// Per-session wakeups for long-polling customers
+    waiters: Mutex<HashMap<String, Arc<Notify>>>,
+}
+
+impl ChatHandoffService {
+    pub fn new(store: Arc<dyn Store>, notifier: Arc<dyn Notifier>) -> Self {
+        Self {
+            store,
+            notifier,
+            waiters: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Ask for a human. Returns false if one was already requested or assigned.
+    pub async fn request_handoff(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        reason: Option<&str>,
+        requested_by: &str,
+    ) -> Result<bool, HandoffError> {
+        let requested = self
+            .store
+            .request_chat_handoff(tenant_id, session_id, reason, Utc::now())
+            .await?;
+        if !requested {
+            self.load_session(tenant_id, session_id).await?;
+            return Ok(false);
+        }
+
+        tracing::info!(
+            tenant_id = %tenant_id,
+            session_id = %session_id,
+            requested_by = %requested_by,
+            "Chat handoff requested"
+        );
+        self.notifier
+            .chat_handoff_requested(tenant_id, session_id, reason, requested_by)
+            .await;
+        self.wake(tenant_id, session_id);
+        Ok(true)
+    }
+
+    /// Assign a waiting session to `agent_id`
+    pub async fn claim(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        agent_id: &str,
+    ) -> Result<ChatSession, HandoffError> {
+        let claimed = self
+            .store
+            .claim_chat_session(tenant_id, session_id, agent_id, Utc::now())
+            .await?;
+        let session = self.load_session(tenant_id, session_id).await?;
+        if !claimed {
+            return Err(HandoffError::InvalidState(format!(
+                "chat session is {}, not waiting for an agent",
+                session.status
+            )));
+        }
+        self.wake(tenant_id, session_id);
+        Ok(session)
+    }
+
+    /// Hand the session back to the assistant
+    pub async fn release(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+    ) -> Result<ChatSession, HandoffError> {
+        let released = self
+            .store
+            .release_chat_session(tenant_id, session_id, Utc::now())
+            .await?;
+        let session = self.load_session(tenant_id, session_id).await?;
+        if !released {
+            return Err(HandoffError::InvalidState(
+                "chat session is not handed off".to_string(),
+            ));
+        }
+        self.wake(tenant_id, session_id);
+        Ok(session)
+    }
+
+    /// Store a reply from the agent that claimed the session
+    pub async fn post_agent_message(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        agent_id: &str,
+        content: &str,
+    ) -> Result<ChatMessage, HandoffError> {
+        let session = self.load_session(tenant_id, session_id).await?;
+        if session.status != status::WITH_AGENT {
+            return Err(HandoffError::InvalidState(
+                "chat session must be claimed before replying".to_string(),
+            ));
+        }
+        if session.assigned_agent.as_deref() != Some(agent_id) {
+            return Err(HandoffError::InvalidState(
+                "chat session is claimed by another agent".to_string(),
+            ));
+        }
+
+        let message = ChatMessage::agent(
+            tenant_id.to_string(),
+            session_id.to_string(),
+            uuid::Uuid::new_v4().to_string(),
+            content.to_string(),
+            agent_id.to_string(),
+        );
+        self.append(&session, message).await
+    }
+
+    /// Store a customer message on a handed-off session (the assistant does not answer)
+    pub async fn post_customer_message(
+        &self,
+        session: &ChatSession,
+        content: &str,
+    ) -> Result<ChatMessage, HandoffError> {
+        let message = ChatMessage::user(
+            session.tenant_id.clone(),
+            session.id.clone(),
+            uuid::Uuid::new_v4().to_string(),
+            content.to_string(),
+        );
+        self.append(session, message).await
+    }
+
+    /// Wait up to `wait` for messages newer than `since`.
+    ///
+    /// Returns as soon as any exist (or immediately when `wait` is zero).
+    pub async fn wait_for_messages(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        since: DateTime<Utc>,
+        wait: Duration,
+    ) -> Result<Vec<ChatMessage>, HandoffError> {
+        let deadline = tokio::time::Instant::now() + wait;
+        let notify = self.waiter(tenant_id, session_id);
+        let result = loop {
+            // Register before reading so a message stored in between still wakes us
+            let notified = notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            let messages = match self
+                .store
+                .list_chat_messages_since(tenant_id, session_id, since, MAX_MESSAGES_PER_POLL)
+                .await
+            {
+                Ok(messages) => messages,
+                Err(e) => break Err(e.into()),
+            };
+            let now = tokio::time::Instant::now();
+            if !messages.is_empty() || now >= deadline {
+                break Ok(messages);
+            }
+
+            let sleep_until = deadline.min(now + POLL_INTERVAL);
+            tokio::select! {
+                _ = &mut notified => {}
+                _ = tokio::time::sleep_until(sleep_until) => {}
+            }
+        };
+        drop(notify);
+        self.prune_waiters();
+        result
+    }
+
+    /// Wake customers long-polling this session
+    pub fn wake(&self, tenant_id: &str, session_id: &str) {
+        let key = waiter_key(tenant_id, session_id);
+        if let Some(notify) = self.waiters.lock().get(&key) {
+            notify.notify_waiters();
+        }
+    }
+
+    async fn load_session(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+    ) -> Result<ChatSession, HandoffError> {
+        self.store
+            .get_chat_session(tenant_id, session_id)
+            .await?
+            .ok_or(HandoffError::NotFound)
+    }
+
+    async fn append(
+        &self,
+        session: &ChatSession,
+        message: ChatMessage,
+    ) -> Result<ChatMessage, HandoffError> {
+        self.store.create_chat_message(message.clone()).await?;
+        let now = Utc::now();
+        self.store
+            .update_chat_session(
+                &session.tenant_id,
+                &session.id,
+                session.message_count + 1,
+                now,
+                now,
+            )
+            .await?;
+        self.wake(&session.tenant_id, &session.id);
+        Ok(message)
+    }
+
+    fn waiter(&self, tenant_id: &str, session_id: &str) -> Arc<Notify> {
+        self.waiters
+            .lock()
+            .entry(waiter_key(tenant_id, session_id))
+            .or_insert_with(|| Arc::new(Notify::new()))
+            .clone()
+    }
+
+    /// Drop wakeups nobody is waiting on
+    fn prune_waiters(&self) {
+        self.waiters
+            .lock()
+            .retain(|_, notify| Arc::strong_count(notify) > 1);
+    }
+}
+
+fn waiter_key(tenant_id: &str, session_id: &str) -> String {
+    format!("{tenant_id}:{session_id}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{PaymentEvent, RefundEvent};
+    use crate::storage::InMemoryStore;
+    use async_trait::async_trait;
+
+    #[derive(Default)]
+    struct RecordingNotifier {
+        handoffs: Mutex<Vec<(String, Option<String>, String)>>,
+    }
+
+    #[async_trait]
+    impl Notifier for RecordingNotifier {
+        async fn payment_succeeded(&self, _event: PaymentEvent) {}
+        async fn refund_succeeded(&self, _event: RefundEvent) {}
+        async fn subscription_created(&self, _: &str, _: &str, _: &str, _: Option<&str>) {}
+        async fn subscription_updated(&self, _: &str, _: &str, _: &str, _: Option<&str>) {}
+        async fn subscription_cancelled(&self, _: &str, _: &str, _: &str, _: Option<&str>) {}
+        async fn subscription_renewed(&self, _: &str, _: &str, _: &str, _: Option<&str>) {}
+        async fn subscription_payment_failed(&self, _: &str, _: &str, _: &str, _: Option<&str>) {}
+        async fn refund_processed(&self, _: &str, _: &str, _: i64, _: &str) {}
+        async fn chat_handoff_requested(
+            &self,
+            _tenant_id: &str,
+            session_id: &str,
+            reason: Option<&str>,
+            requested_by: &str,
+        ) {
+            self.handoffs.lock().push((
+                session_id.to_string(),
+                reason.map(str::to_string),
+                requested_by.to_string(),
+            ));
+        }
+    }
+
+    async fn setup() -> (Arc<ChatHandoffService>, Arc<RecordingNotifier>) {
+        let store = Arc::new(InMemoryStore::new());
+        store
+            .create_chat_session(ChatSession::new("t1".into(), "s1".into()))
+            .await
+            .unwrap();
+        let notifier = Arc::new(RecordingNotifier::default());
+        let service = Arc::new(ChatHandoffService::new(store, notifier.clone()));
+        (service, notifier)
+    }
+
+    #[tokio::test]
+    async fn test_handoff_claim_reply_release() {
+        let (service, notifier) = setup().await;
+
+        assert!(service
+            .request_handoff("t1", "s1", Some("billing"), "assistant")
+            .await
+            .unwrap());
+        // A second request is a no-op and does not notify again
+        assert!(!service
+            .request_handoff("t1", "s1", None, "customer")
+            .await
+            .unwrap());
+        assert_eq!(
+            notifier.handoffs.lock().clone(),
+            vec![(
+                "s1".to_string(),
+                Some("billing".to_string()),
+                "assistant".to_string()
+            )]
+        );
+
+        // Replying before claiming is rejected
+        assert!(matches!(
+            service.post_agent_message("t1", "s1", "alice", "hi").await,
+            Err(HandoffError::InvalidState(_))
+        ));
+
+        let session = service.claim("t1", "s1", "alice").await.unwrap();
+        assert_eq!(session.status, status::WITH_AGENT);
+        assert_eq!(session.assigned_agent.as_deref(), Some("alice"));
+        assert!(matches!(
+            service.claim("t1", "s1", "bob").await,
+            Err(HandoffError::InvalidState(_))
+        ));
+        assert!(matches!(
+            service.post_agent_message("t1", "s1", "bob", "hi").await,
+            Err(HandoffError::InvalidState(_))
+        ));
+
+        let message = service
+            .post_agent_message("t1", "s1", "alice", "Hello, I can help")
+            .await
+            .unwrap();
+        assert_eq!(message.role, "agent");
+        assert_eq!(message.author_id.as_deref(), Some("alice"));
+
+        let session = service.release("t1", "s1").await.unwrap();
+        assert_eq!(session.status, status::ACTIVE);
+        assert!(session.assigned_agent.is_none());
+        assert_eq!(session.message_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_messages_wakes_on_agent_reply() {
+        let (service, _) = setup().await;
+        service
+            .request_handoff("t1", "s1", None, "customer")
+            .await
+            .unwrap();
+        service.claim("t1", "s1", "alice").await.unwrap();
+        let since = Utc::now();
+
+        let waiter = {
+            let service = service.clone();
+            tokio::spawn(async move {
+                service
+                    .wait_for_messages("t1", "s1", since, Duration::from_secs(10))
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        service
+            .post_agent_message("t1", "s1", "alice", "Still there?")
+            .await
+            .unwrap();
+
+        let messages = tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("long-poll should be woken")
+            .unwrap()
+            .unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].content, "Still there?");
+        assert!(service.waiters.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_messages_times_out_empty() {
+        let (service, _) = setup().await;
+        let messages = service
+            .wait_for_messages("t1", "s1", Utc::now(), Duration::from_millis(20))
+            .await
+            .unwrap();
+        assert!(messages.is_empty());
+        assert!(matches!(
+            service.claim("t1", "missing", "alice").await,
+            Err(HandoffError::NotFound)
+        ));
+    }
+}
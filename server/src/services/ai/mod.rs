@@ -1,31 +1,53 @@
-//! AI service for making completion requests to OpenAI and Gemini.
+//! AI service for making completion requests to OpenAI, Gemini, Anthropic and
+//! OpenAI-compatible servers.
 //!
 //! Provides a unified interface for AI completions with provider-specific API handling.
+//! Streaming variants live in [`streaming`]; the Anthropic Messages API in [`anthropic`];
+//! text embeddings in [`embeddings`]; token usage accounting and budgets in [`usage`].
 
+pub mod anthropic;
+pub mod customer_tools;
+pub mod embeddings;
+pub mod handoff;
 pub mod orchestrator;
+pub mod streaming;
 pub mod tool_executors;
 pub mod tools;
+pub mod usage;
 
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use thiserror::Error;
 
+use crate::config::secrets::{
+    SECRET_ANTHROPIC_API_KEY, SECRET_GEMINI_API_KEY, SECRET_OPENAI_API_KEY,
+};
+use crate::config::SecretStore;
 use crate::handlers::admin_ai::{AiModel, AiProvider};
 use crate::observability::record_ai_call;
 
+pub use customer_tools::{execute_customer_tool, CustomerToolContext};
+pub use handoff::{ChatHandoffService, HandoffError};
 pub use orchestrator::{
-    ChatOrchestrator, ChatResult, FactFinderConfig, FaqMatch, DEFAULT_CHAT_SYSTEM_PROMPT,
+    ChatOrchestrator, ChatResult, ChatStreamEvent, FactFinderConfig, FaqMatch,
+    DEFAULT_CHAT_SYSTEM_PROMPT,
 };
+pub use tool_executors::SearchToolContext;
 pub use tools::{
-    get_chat_tools, to_gemini_tools, to_openai_tools, ConversationMessage, ProductSearchArgs,
-    ToolCall, ToolCallingResponse, ToolDefinition, ToolResult,
+    get_chat_tools, to_anthropic_tools, to_gemini_tools, to_openai_tools, ConversationMessage,
+    ProductSearchArgs, ToolCall, ToolCallingResponse, ToolDefinition, ToolResult,
 };
+pub use usage::{AiBudget, AiPricing, AiUsageTracker, BudgetMode, TokenUsage};
 
 /// Default timeout for AI API requests
 const AI_API_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// Usage task label of chat tool-calling requests
+const CHAT_TASK: &str = "chat";
+
 /// AI service error types
 #[derive(Debug, Error)]
 pub enum AiError {
@@ -39,11 +61,123 @@ pub enum AiError {
     ParseError(String),
     #[error("HTTP error: {0}")]
     HttpError(String),
+    #[error("AI budget exceeded: {0}")]
+    BudgetExceeded(String),
+}
+
+/// Resolved provider, model and credentials for AI requests.
+///
+/// OpenAI-compatible servers additionally carry their base URL and the
+/// free-form model name assigned to the task. Endpoints resolved for a tenant
+/// carry its id and budget so usage is recorded and limited per tenant.
+#[derive(Clone)]
+pub struct AiEndpoint {
+    pub provider: AiProvider,
+    pub model: AiModel,
+    /// May be empty for an OpenAI-compatible server without authentication
+    pub api_key: String,
+    pub model_name: Option<String>,
+    pub base_url: Option<String>,
+    pub tenant_id: Option<String>,
+    pub budget: Option<AiBudget>,
+}
+
+impl AiEndpoint {
+    pub fn new(provider: AiProvider, model: AiModel, api_key: impl Into<String>) -> Self {
+        Self {
+            provider,
+            model,
+            api_key: api_key.into(),
+            model_name: None,
+            base_url: None,
+            tenant_id: None,
+            budget: None,
+        }
+    }
+
+    /// Endpoint for a model served by an OpenAI-compatible server
+    pub fn openai_compatible(
+        base_url: impl Into<String>,
+        model_name: impl Into<String>,
+        api_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            provider: AiProvider::OpenaiCompatible,
+            model: AiModel::OpenAiCompatible,
+            api_key: api_key.into(),
+            model_name: Some(model_name.into()),
+            base_url: Some(base_url.into()),
+            tenant_id: None,
+            budget: None,
+        }
+    }
+
+    /// Attribute usage through this endpoint to a tenant, limited by its budget
+    pub fn with_tenant(mut self, tenant_id: impl Into<String>, budget: Option<AiBudget>) -> Self {
+        self.tenant_id = Some(tenant_id.into());
+        self.budget = budget;
+        self
+    }
+
+    /// Model identifier sent to the provider
+    pub fn model_id(&self) -> &str {
+        match self.provider {
+            AiProvider::Openai => model_to_openai_id(self.model),
+            AiProvider::Gemini => model_to_gemini_id(self.model),
+            AiProvider::Anthropic => model_to_anthropic_id(self.model),
+            AiProvider::OpenaiCompatible => self.model_name.as_deref().unwrap_or_default(),
+        }
+    }
+
+    /// Chat Completions URL (OpenAI and OpenAI-compatible providers)
+    fn chat_completions_url(&self) -> String {
+        match &self.base_url {
+            Some(base) => format!("{}/chat/completions", base.trim_end_matches('/')),
+            None => "https://api.openai.com/v1/chat/completions".to_string(),
+        }
+    }
+
+    /// Attach the `Authorization` header unless there is no key to send
+    fn bearer_auth(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        if self.api_key.is_empty() {
+            request
+        } else {
+            request.header("Authorization", format!("Bearer {}", self.api_key))
+        }
+    }
+
+    /// Provider name used in error messages
+    fn display_name(&self) -> &'static str {
+        match self.provider {
+            AiProvider::Openai => "OpenAI",
+            AiProvider::Gemini => "Gemini",
+            AiProvider::Anthropic => "Anthropic",
+            AiProvider::OpenaiCompatible => "OpenAI-compatible",
+        }
+    }
+}
+
+impl std::fmt::Debug for AiEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AiEndpoint")
+            .field("provider", &self.provider)
+            .field("model", &self.model)
+            .field("api_key", &"[REDACTED]")
+            .field("model_name", &self.model_name)
+            .field("base_url", &self.base_url)
+            .field("tenant_id", &self.tenant_id)
+            .field("budget", &self.budget)
+            .finish()
+    }
 }
 
 /// AI service for making completion requests
 pub struct AiService {
     http_client: reqwest::Client,
+    /// Secret provider holding platform-wide provider API keys
+    secrets: Option<Arc<SecretStore>>,
+    /// Usage accounting and budget enforcement
+    usage: Option<Arc<AiUsageTracker>>,
 }
 
 impl Default for AiService {
@@ -59,28 +193,95 @@ impl AiService {
             .build()
             .unwrap_or_else(|_| reqwest::Client::new());
 
-        Self { http_client }
+        Self {
+            http_client,
+            secrets: None,
+            usage: None,
+        }
+    }
+
+    /// Fall back to provider API keys from a hot-reloaded secret provider
+    /// when a tenant has not configured its own.
+    pub fn with_secrets(mut self, secrets: Arc<SecretStore>) -> Self {
+        self.secrets = Some(secrets);
+        self
+    }
+
+    /// Record token usage of tenant calls and enforce their monthly budgets.
+    pub fn with_usage(mut self, usage: Arc<AiUsageTracker>) -> Self {
+        self.usage = Some(usage);
+        self
+    }
+
+    /// Fail with [`AiError::BudgetExceeded`] when the endpoint's tenant is
+    /// over a blocking monthly budget.
+    pub async fn check_budget(&self, endpoint: &AiEndpoint) -> Result<(), AiError> {
+        match &self.usage {
+            Some(usage) => usage.check_budget(endpoint).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn record_usage(&self, endpoint: &AiEndpoint, task: &str, usage: TokenUsage) {
+        if let Some(tracker) = &self.usage {
+            tracker.record(endpoint, task, usage).await;
+        }
+    }
+
+    /// Current platform API key for a provider from the secret provider.
+    ///
+    /// OpenAI-compatible servers are tenant infrastructure and have no platform key.
+    pub fn provider_api_key(&self, provider: AiProvider) -> Option<String> {
+        let name = match provider {
+            AiProvider::Gemini => SECRET_GEMINI_API_KEY,
+            AiProvider::Openai => SECRET_OPENAI_API_KEY,
+            AiProvider::Anthropic => SECRET_ANTHROPIC_API_KEY,
+            AiProvider::OpenaiCompatible => return None,
+        };
+        self.secrets.as_ref()?.get(name)
     }
 
     /// Make a completion request to the configured AI provider
     pub async fn complete(
         &self,
-        provider: AiProvider,
-        model: AiModel,
-        api_key: &str,
+        endpoint: &AiEndpoint,
         system_prompt: &str,
         user_prompt: &str,
     ) -> Result<String, AiError> {
-        match provider {
-            AiProvider::Openai => {
-                self.openai_complete(model, api_key, system_prompt, user_prompt)
+        self.complete_for_task(endpoint, system_prompt, user_prompt, "completion")
+            .await
+    }
+
+    /// Completion whose usage is recorded under `task`
+    async fn complete_for_task(
+        &self,
+        endpoint: &AiEndpoint,
+        system_prompt: &str,
+        user_prompt: &str,
+        task: &str,
+    ) -> Result<String, AiError> {
+        self.check_budget(endpoint).await?;
+        let (content, usage) = match endpoint.provider {
+            AiProvider::Openai | AiProvider::OpenaiCompatible => {
+                self.openai_complete(endpoint, system_prompt, user_prompt)
                     .await
             }
             AiProvider::Gemini => {
-                self.gemini_complete(model, api_key, system_prompt, user_prompt)
+                self.gemini_complete(endpoint, system_prompt, user_prompt)
                     .await
             }
-        }
+            AiProvider::Anthropic => {
+                let messages = [
+                    ConversationMessage::system(system_prompt),
+                    ConversationMessage::user(user_prompt),
+                ];
+                self.anthropic_complete_with_tools(endpoint, &messages, &[])
+                    .await
+                    .map(|r| (r.content, r.usage))
+            }
+        }?;
+        self.record_usage(endpoint, task, usage).await;
+        Ok(content)
     }
 
     /// Make a completion request with metrics tracking
@@ -89,24 +290,19 @@ impl AiService {
     /// * `task` - The task name for metrics (e.g., "seo", "tags", "categories", "short_desc")
     pub async fn complete_with_metrics(
         &self,
-        provider: AiProvider,
-        model: AiModel,
-        api_key: &str,
+        endpoint: &AiEndpoint,
         system_prompt: &str,
         user_prompt: &str,
         task: &str,
     ) -> Result<String, AiError> {
         let start = Instant::now();
         let result = self
-            .complete(provider, model, api_key, system_prompt, user_prompt)
+            .complete_for_task(endpoint, system_prompt, user_prompt, task)
             .await;
         let duration = start.elapsed().as_secs_f64();
 
-        let provider_str = match provider {
-            AiProvider::Openai => "openai",
-            AiProvider::Gemini => "gemini",
-        };
-        let model_str = model_to_string(model);
+        let provider_str = provider_to_string(endpoint.provider);
+        let model_str = model_to_string(endpoint.model);
 
         record_ai_call(provider_str, model_str, task, result.is_ok(), duration);
 
@@ -114,14 +310,18 @@ impl AiService {
     }
 
     /// OpenAI Chat Completions API
+    #[tracing::instrument(
+        name = "ai.request",
+        skip_all,
+        fields(otel.kind = "client", gen_ai.system = provider_to_string(endpoint.provider), gen_ai.request.model = endpoint.model_id())
+    )]
     async fn openai_complete(
         &self,
-        model: AiModel,
-        api_key: &str,
+        endpoint: &AiEndpoint,
         system_prompt: &str,
         user_prompt: &str,
-    ) -> Result<String, AiError> {
-        let model_id = model_to_openai_id(model);
+    ) -> Result<(String, TokenUsage), AiError> {
+        let model_id = endpoint.model_id();
 
         let request_body = serde_json::json!({
             "model": model_id,
@@ -132,10 +332,8 @@ impl AiService {
             "temperature": 0.7
         });
 
-        let response = self
-            .http_client
-            .post("https://api.openai.com/v1/chat/completions")
-            .header("Authorization", format!("Bearer {}", api_key))
+        let response = endpoint
+            .bearer_auth(self.http_client.post(endpoint.chat_completions_url()))
             .header("Content-Type", "application/json")
             .json(&request_body)
             .send()
@@ -146,8 +344,10 @@ impl AiService {
         if !status.is_success() {
             let error_text = response.text().await.unwrap_or_default();
             return Err(AiError::ServiceError(format!(
-                "OpenAI API error ({}): {}",
-                status, error_text
+                "{} API error ({}): {}",
+                endpoint.display_name(),
+                status,
+                error_text
             )));
         }
 
@@ -156,26 +356,27 @@ impl AiService {
             .await
             .map_err(|e| AiError::ParseError(format!("Failed to parse OpenAI response: {}", e)))?;
 
+        let usage = TokenUsage::from_openai(&body.usage);
         body.choices
             .into_iter()
             .next()
-            .map(|c| c.message.content)
+            .map(|c| (c.message.content, usage))
             .ok_or_else(|| AiError::ParseError("No completion returned".to_string()))
     }
 
     /// Gemini GenerateContent API
+    #[tracing::instrument(
+        name = "ai.request",
+        skip_all,
+        fields(otel.kind = "client", gen_ai.system = "gemini", gen_ai.request.model = endpoint.model_id())
+    )]
     async fn gemini_complete(
         &self,
-        model: AiModel,
-        api_key: &str,
+        endpoint: &AiEndpoint,
         system_prompt: &str,
         user_prompt: &str,
-    ) -> Result<String, AiError> {
-        let model_id = model_to_gemini_id(model);
-        let url = format!(
-            "https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent",
-            model_id
-        );
+    ) -> Result<(String, TokenUsage), AiError> {
+        let url = gemini_url(endpoint, "generateContent");
 
         let request_body = serde_json::json!({
             "contents": [{"parts": [{"text": user_prompt}]}],
@@ -189,7 +390,7 @@ impl AiService {
             .http_client
             .post(&url)
             .header("Content-Type", "application/json")
-            .header("x-goog-api-key", api_key)
+            .header("x-goog-api-key", &endpoint.api_key)
             .json(&request_body)
             .send()
             .await
@@ -209,107 +410,60 @@ impl AiService {
             .await
             .map_err(|e| AiError::ParseError(format!("Failed to parse Gemini response: {}", e)))?;
 
+        let usage = TokenUsage::from_gemini(&body.usage_metadata);
         body.candidates
             .into_iter()
             .next()
             .and_then(|c| c.content.parts.into_iter().next())
-            .map(|p| p.text)
+            .map(|p| (p.text, usage))
             .ok_or_else(|| AiError::ParseError("No completion returned".to_string()))
     }
 
     /// Make a completion request with tool calling support
     ///
     /// Returns a ToolCallingResponse that may contain tool calls the AI wants to make.
+    /// Usage is recorded under the `chat` task.
     pub async fn complete_with_tools(
         &self,
-        provider: AiProvider,
-        model: AiModel,
-        api_key: &str,
+        endpoint: &AiEndpoint,
         messages: &[ConversationMessage],
         tools: &[ToolDefinition],
     ) -> Result<ToolCallingResponse, AiError> {
-        match provider {
-            AiProvider::Openai => {
-                self.openai_complete_with_tools(model, api_key, messages, tools)
+        self.check_budget(endpoint).await?;
+        let response = match endpoint.provider {
+            AiProvider::Openai | AiProvider::OpenaiCompatible => {
+                self.openai_complete_with_tools(endpoint, messages, tools)
                     .await
             }
             AiProvider::Gemini => {
-                self.gemini_complete_with_tools(model, api_key, messages, tools)
+                self.gemini_complete_with_tools(endpoint, messages, tools)
                     .await
             }
-        }
+            AiProvider::Anthropic => {
+                self.anthropic_complete_with_tools(endpoint, messages, tools)
+                    .await
+            }
+        }?;
+        self.record_usage(endpoint, CHAT_TASK, response.usage).await;
+        Ok(response)
     }
 
     /// OpenAI Chat Completions with tool calling
+    #[tracing::instrument(
+        name = "ai.request",
+        skip_all,
+        fields(otel.kind = "client", gen_ai.system = provider_to_string(endpoint.provider), gen_ai.request.model = endpoint.model_id())
+    )]
     async fn openai_complete_with_tools(
         &self,
-        model: AiModel,
-        api_key: &str,
+        endpoint: &AiEndpoint,
         messages: &[ConversationMessage],
         tools: &[ToolDefinition],
     ) -> Result<ToolCallingResponse, AiError> {
-        let model_id = model_to_openai_id(model);
-
-        // Convert messages to OpenAI format
-        let openai_messages: Vec<Value> = messages
-            .iter()
-            .map(|m| match m {
-                ConversationMessage::System { content } => {
-                    serde_json::json!({"role": "system", "content": content})
-                }
-                ConversationMessage::User { content } => {
-                    serde_json::json!({"role": "user", "content": content})
-                }
-                ConversationMessage::Assistant {
-                    content,
-                    tool_calls,
-                } => {
-                    let mut msg = serde_json::json!({"role": "assistant", "content": content});
-                    if let Some(calls) = tool_calls {
-                        let openai_calls: Vec<Value> = calls
-                            .iter()
-                            .map(|tc| {
-                                serde_json::json!({
-                                    "id": tc.id,
-                                    "type": "function",
-                                    "function": {
-                                        "name": tc.name,
-                                        "arguments": tc.arguments.to_string()
-                                    }
-                                })
-                            })
-                            .collect();
-                        msg["tool_calls"] = serde_json::json!(openai_calls);
-                    }
-                    msg
-                }
-                ConversationMessage::Tool {
-                    tool_call_id,
-                    content,
-                } => {
-                    serde_json::json!({
-                        "role": "tool",
-                        "tool_call_id": tool_call_id,
-                        "content": content
-                    })
-                }
-            })
-            .collect();
-
-        let mut request_body = serde_json::json!({
-            "model": model_id,
-            "messages": openai_messages,
-            "temperature": 0.7
-        });
+        let request_body = openai_tool_request(endpoint.model_id(), messages, tools);
 
-        if !tools.is_empty() {
-            request_body["tools"] = to_openai_tools(tools);
-        }
-
-        let response = self
-            .http_client
-            .post("https://api.openai.com/v1/chat/completions")
-            .header("Authorization", format!("Bearer {}", api_key))
+        let response = endpoint
+            .bearer_auth(self.http_client.post(endpoint.chat_completions_url()))
             .header("Content-Type", "application/json")
             .json(&request_body)
             .send()
@@ -320,8 +474,10 @@ impl AiService {
         if !status.is_success() {
             let error_text = response.text().await.unwrap_or_default();
             return Err(AiError::ServiceError(format!(
-                "OpenAI API error ({}): {}",
-                status, error_text
+                "{} API error ({}): {}",
+                endpoint.display_name(),
+                status,
+                error_text
             )));
         }
 
@@ -329,6 +485,7 @@ impl AiService {
             AiError::ParseError(format!("Failed to parse OpenAI tool response: {}", e))
         })?;
 
+        let usage = TokenUsage::from_openai(&body.usage);
         let choice = body
             .choices
             .into_iter()
@@ -348,110 +505,29 @@ impl AiService {
             })
             .collect();
 
-        Ok(ToolCallingResponse::with_tools(content, tool_calls))
+        Ok(ToolCallingResponse::with_tools(content, tool_calls).with_usage(usage))
     }
 
     /// Gemini GenerateContent with tool calling
+    #[tracing::instrument(
+        name = "ai.request",
+        skip_all,
+        fields(otel.kind = "client", gen_ai.system = "gemini", gen_ai.request.model = endpoint.model_id())
+    )]
     async fn gemini_complete_with_tools(
         &self,
-        model: AiModel,
-        api_key: &str,
+        endpoint: &AiEndpoint,
         messages: &[ConversationMessage],
         tools: &[ToolDefinition],
     ) -> Result<ToolCallingResponse, AiError> {
-        let model_id = model_to_gemini_id(model);
-        let url = format!(
-            "https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent",
-            model_id
-        );
-
-        // Extract system instruction from messages
-        let system_content: String = messages
-            .iter()
-            .filter_map(|m| match m {
-                ConversationMessage::System { content } => Some(content.clone()),
-                _ => None,
-            })
-            .collect::<Vec<_>>()
-            .join("\n\n");
-
-        // Convert other messages to Gemini format
-        let gemini_contents: Vec<Value> = messages
-            .iter()
-            .filter_map(|m| match m {
-                ConversationMessage::User { content } => Some(serde_json::json!({
-                    "role": "user",
-                    "parts": [{"text": content}]
-                })),
-                ConversationMessage::Assistant { content, tool_calls } => {
-                    let mut parts: Vec<Value> = vec![];
-                    if !content.is_empty() {
-                        parts.push(serde_json::json!({"text": content}));
-                    }
-                    if let Some(calls) = tool_calls {
-                        for tc in calls {
-                            parts.push(serde_json::json!({
-                                "functionCall": {
-                                    "name": tc.name,
-                                    "args": tc.arguments
-                                }
-                            }));
-                        }
-                    }
-                    if parts.is_empty() {
-                        None
-                    } else {
-                        Some(serde_json::json!({
-                            "role": "model",
-                            "parts": parts
-                        }))
-                    }
-                }
-                ConversationMessage::Tool {
-                    tool_call_id: _,
-                    content,
-                } => {
-                    // Gemini uses functionResponse in parts
-                    // Parse the content as JSON to get the name and response
-                    if let Ok(parsed) = serde_json::from_str::<Value>(content) {
-                        Some(serde_json::json!({
-                            "role": "user",
-                            "parts": [{
-                                "functionResponse": {
-                                    "name": parsed.get("name").and_then(|n| n.as_str()).unwrap_or("unknown"),
-                                    "response": parsed.get("response").unwrap_or(&Value::Null)
-                                }
-                            }]
-                        }))
-                    } else {
-                        None
-                    }
-                }
-                ConversationMessage::System { .. } => None,
-            })
-            .collect();
-
-        let mut request_body = serde_json::json!({
-            "contents": gemini_contents,
-            "generationConfig": {
-                "temperature": 0.7
-            }
-        });
-
-        if !system_content.is_empty() {
-            request_body["systemInstruction"] =
-                serde_json::json!({"parts": [{"text": system_content}]});
-        }
-
-        if !tools.is_empty() {
-            request_body["tools"] = to_gemini_tools(tools);
-        }
+        let url = gemini_url(endpoint, "generateContent");
+        let request_body = gemini_tool_request(messages, tools);
 
         let response = self
             .http_client
             .post(&url)
             .header("Content-Type", "application/json")
-            .header("x-goog-api-key", api_key)
+            .header("x-goog-api-key", &endpoint.api_key)
             .json(&request_body)
             .send()
             .await
@@ -470,6 +546,7 @@ impl AiService {
             AiError::ParseError(format!("Failed to parse Gemini tool response: {}", e))
         })?;
 
+        let usage = TokenUsage::from_gemini(&body.usage_metadata);
         let candidate = body
             .candidates
             .into_iter()
@@ -492,7 +569,7 @@ impl AiService {
             }
         }
 
-        Ok(ToolCallingResponse::with_tools(content, tool_calls))
+        Ok(ToolCallingResponse::with_tools(content, tool_calls).with_usage(usage))
     }
 }
 
@@ -503,6 +580,8 @@ impl AiService {
 #[derive(Debug, Deserialize)]
 struct OpenAiResponse {
     choices: Vec<OpenAiChoice>,
+    #[serde(default)]
+    usage: Value,
 }
 
 #[derive(Debug, Deserialize)]
@@ -522,6 +601,8 @@ struct OpenAiMessage {
 #[derive(Debug, Deserialize)]
 struct GeminiResponse {
     candidates: Vec<GeminiCandidate>,
+    #[serde(default, rename = "usageMetadata")]
+    usage_metadata: Value,
 }
 
 #[derive(Debug, Deserialize)]
@@ -546,6 +627,8 @@ struct GeminiPart {
 #[derive(Debug, Deserialize)]
 struct OpenAiToolResponse {
     choices: Vec<OpenAiToolChoice>,
+    #[serde(default)]
+    usage: Value,
 }
 
 #[derive(Debug, Deserialize)]
@@ -579,6 +662,8 @@ struct OpenAiFunctionCall {
 #[derive(Debug, Deserialize)]
 struct GeminiToolResponse {
     candidates: Vec<GeminiToolCandidate>,
+    #[serde(default, rename = "usageMetadata")]
+    usage_metadata: Value,
 }
 
 #[derive(Debug, Deserialize)]
@@ -605,6 +690,171 @@ struct GeminiFunctionCall {
     args: Value,
 }
 
+// ============================================================================
+// Request Builders
+// ============================================================================
+
+/// Chat Completions request body for a conversation (shared by streaming and non-streaming calls)
+fn openai_tool_request(
+    model_id: &str,
+    messages: &[ConversationMessage],
+    tools: &[ToolDefinition],
+) -> Value {
+    // Convert messages to OpenAI format
+    let openai_messages: Vec<Value> = messages
+        .iter()
+        .map(|m| match m {
+            ConversationMessage::System { content } => {
+                serde_json::json!({"role": "system", "content": content})
+            }
+            ConversationMessage::User { content } => {
+                serde_json::json!({"role": "user", "content": content})
+            }
+            ConversationMessage::Assistant {
+                content,
+                tool_calls,
+            } => {
+                let mut msg = serde_json::json!({"role": "assistant", "content": content});
+                if let Some(calls) = tool_calls {
+                    let openai_calls: Vec<Value> = calls
+                        .iter()
+                        .map(|tc| {
+                            serde_json::json!({
+                                "id": tc.id,
+                                "type": "function",
+                                "function": {
+                                    "name": tc.name,
+                                    "arguments": tc.arguments.to_string()
+                                }
+                            })
+                        })
+                        .collect();
+                    msg["tool_calls"] = serde_json::json!(openai_calls);
+                }
+                msg
+            }
+            ConversationMessage::Tool {
+                tool_call_id,
+                content,
+            } => {
+                serde_json::json!({
+                    "role": "tool",
+                    "tool_call_id": tool_call_id,
+                    "content": content
+                })
+            }
+        })
+        .collect();
+
+    let mut request_body = serde_json::json!({
+        "model": model_id,
+        "messages": openai_messages,
+        "temperature": 0.7
+    });
+
+    if !tools.is_empty() {
+        request_body["tools"] = to_openai_tools(tools);
+    }
+
+    request_body
+}
+
+/// GenerateContent endpoint for a model and method (`generateContent` / `streamGenerateContent`)
+fn gemini_url(endpoint: &AiEndpoint, method: &str) -> String {
+    format!(
+        "https://generativelanguage.googleapis.com/v1beta/models/{}:{}",
+        endpoint.model_id(),
+        method
+    )
+}
+
+/// GenerateContent request body for a conversation (shared by streaming and non-streaming calls)
+fn gemini_tool_request(messages: &[ConversationMessage], tools: &[ToolDefinition]) -> Value {
+    // Extract system instruction from messages
+    let system_content: String = messages
+        .iter()
+        .filter_map(|m| match m {
+            ConversationMessage::System { content } => Some(content.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    // Convert other messages to Gemini format
+    let gemini_contents: Vec<Value> = messages
+        .iter()
+        .filter_map(|m| match m {
+            ConversationMessage::User { content } => Some(serde_json::json!({
+                "role": "user",
+                "parts": [{"text": content}]
+            })),
+            ConversationMessage::Assistant { content, tool_calls } => {
+                let mut parts: Vec<Value> = vec![];
+                if !content.is_empty() {
+                    parts.push(serde_json::json!({"text": content}));
+                }
+                if let Some(calls) = tool_calls {
+                    for tc in calls {
+                        parts.push(serde_json::json!({
+                            "functionCall": {
+                                "name": tc.name,
+                                "args": tc.arguments
+                            }
+                        }));
+                    }
+                }
+                if parts.is_empty() {
+                    None
+                } else {
+                    Some(serde_json::json!({
+                        "role": "model",
+                        "parts": parts
+                    }))
+                }
+            }
+            ConversationMessage::Tool {
+                tool_call_id: _,
+                content,
+            } => {
+                // Gemini uses functionResponse in parts
+                // Parse the content as JSON to get the name and response
+                if let Ok(parsed) = serde_json::from_str::<Value>(content) {
+                    Some(serde_json::json!({
+                        "role": "user",
+                        "parts": [{
+                            "functionResponse": {
+                                "name": parsed.get("name").and_then(|n| n.as_str()).unwrap_or("unknown"),
+                                "response": parsed.get("response").unwrap_or(&Value::Null)
+                            }
+                        }]
+                    }))
+                } else {
+                    None
+                }
+            }
+            ConversationMessage::System { .. } => None,
+        })
+        .collect();
+
+    let mut request_body = serde_json::json!({
+        "contents": gemini_contents,
+        "generationConfig": {
+            "temperature": 0.7
+        }
+    });
+
+    if !system_content.is_empty() {
+        request_body["systemInstruction"] =
+            serde_json::json!({"parts": [{"text": system_content}]});
+    }
+
+    if !tools.is_empty() {
+        request_body["tools"] = to_gemini_tools(tools);
+    }
+
+    request_body
+}
+
 // ============================================================================
 // Model ID Mapping
 // ============================================================================
@@ -614,6 +864,8 @@ fn model_to_openai_id(model: AiModel) -> &'static str {
         AiModel::OpenAi4o => "gpt-4o",
         AiModel::OpenAi51 => "o1",
         AiModel::OpenAi52 => "o3",
+        AiModel::OpenAiTextEmbedding3Small => "text-embedding-3-small",
+        AiModel::OpenAiTextEmbedding3Large => "text-embedding-3-large",
         // Fallback for non-OpenAI models
         _ => "gpt-4o",
     }
@@ -623,11 +875,24 @@ fn model_to_gemini_id(model: AiModel) -> &'static str {
     match model {
         AiModel::Gemini25Flash => "gemini-2.5-flash-preview-05-20",
         AiModel::Gemini25Pro => "gemini-2.5-pro-preview-05-06",
+        AiModel::GeminiTextEmbedding004 => "text-embedding-004",
         // Fallback for non-Gemini models
         _ => "gemini-2.5-flash-preview-05-20",
     }
 }
 
+fn model_to_anthropic_id(model: AiModel) -> &'static str {
+    match model {
+        AiModel::ClaudeSonnet45 => "claude-sonnet-4-5",
+        AiModel::ClaudeHaiku45 => "claude-haiku-4-5",
+        AiModel::ClaudeOpus41 => "claude-opus-4-1",
+        // Fallback for non-Anthropic models
+        _ => "claude-sonnet-4-5",
+    }
+}
+
+/// Model label for metrics. Free-form OpenAI-compatible model names are not
+/// used so the label set stays bounded.
 fn model_to_string(model: AiModel) -> &'static str {
     match model {
         AiModel::NotSet => "not_set",
@@ -636,6 +901,22 @@ fn model_to_string(model: AiModel) -> &'static str {
         AiModel::OpenAi4o => "gpt-4o",
         AiModel::OpenAi51 => "o1",
         AiModel::OpenAi52 => "o3",
+        AiModel::ClaudeSonnet45 => "claude-sonnet-4-5",
+        AiModel::ClaudeHaiku45 => "claude-haiku-4-5",
+        AiModel::ClaudeOpus41 => "claude-opus-4-1",
+        AiModel::OpenAiCompatible => "openai-compatible",
+        AiModel::OpenAiTextEmbedding3Small => "text-embedding-3-small",
+        AiModel::OpenAiTextEmbedding3Large => "text-embedding-3-large",
+        AiModel::GeminiTextEmbedding004 => "text-embedding-004",
+    }
+}
+
+fn provider_to_string(provider: AiProvider) -> &'static str {
+    match provider {
+        AiProvider::Openai => "openai",
+        AiProvider::Gemini => "gemini",
+        AiProvider::Anthropic => "anthropic",
+        AiProvider::OpenaiCompatible => "openai_compatible",
     }
 }
 
@@ -8,15 +8,16 @@ use futures_util::future::join_all;
 
 use serde::{Deserialize, Serialize};
 
-use crate::handlers::admin_ai::{AiModel, AiProvider};
 use crate::handlers::admin_ai_assistant::ProductMatch;
 use crate::models::{ChatMessage, Faq, Product};
 
-use super::tool_executors::execute_tool;
+use super::customer_tools::CustomerToolContext;
+use super::tool_executors::{execute_tool, parse_request_human_agent, SearchToolContext};
 use super::tools::{
-    get_chat_tools, ConversationMessage, ToolCall, ToolCallingResponse, ToolDefinition,
+    get_chat_tools, get_customer_chat_tools, ConversationMessage, ToolCall, ToolCallingResponse,
+    ToolDefinition, REQUEST_HUMAN_AGENT,
 };
-use super::{AiError, AiService};
+use super::{AiEndpoint, AiError, AiService};
 
 /// Maximum number of tool-calling rounds to prevent infinite loops
 const MAX_TOOL_ROUNDS: usize = 3;
@@ -24,6 +25,9 @@ const MAX_TOOL_ROUNDS: usize = 3;
 /// Maximum messages to include in conversation context
 const MAX_CONTEXT_MESSAGES: usize = 20;
 
+/// Reply used when the tool loop stops at MAX_TOOL_ROUNDS
+const MAX_ROUNDS_MESSAGE: &str = "I've found some information for you.";
+
 /// Default system prompt for chat
 pub const DEFAULT_CHAT_SYSTEM_PROMPT: &str = r#"You are a helpful shopping assistant for an online store. Your role is to:
 
@@ -33,6 +37,9 @@ pub const DEFAULT_CHAT_SYSTEM_PROMPT: &str = r#"You are a helpful shopping assis
 
 When customers ask about products, use the product_search tool to find relevant items.
 When customers ask about policies, shipping, returns, store info, or other factual questions, use the fact_finder tool to search the FAQ.
+When a signed-in customer asks about their orders, shipments, returns or subscriptions, use the order_lookup, start_return and subscription_status tools. These tools only see the signed-in customer's own account; never ask for or act on anyone else's details. If these tools are unavailable, ask the customer to sign in to their account first.
+Before starting a return, confirm the order, items and reason with the customer. Returns are reviewed by staff; never promise a refund.
+If the customer asks for a person, or you cannot resolve their issue, use the request_human_agent tool to hand the chat to staff.
 Be conversational and helpful. If you can't find what they're looking for, suggest alternatives or ask clarifying questions.
 
 Keep responses concise but friendly."#;
@@ -49,9 +56,7 @@ pub struct FaqMatch {
 /// Configuration for AI-powered fact finder
 #[derive(Debug, Clone)]
 pub struct FactFinderConfig {
-    pub provider: AiProvider,
-    pub model: AiModel,
-    pub api_key: String,
+    pub endpoint: AiEndpoint,
     pub prompt: String,
 }
 
@@ -67,6 +72,72 @@ pub struct ChatResult {
     pub faqs: Vec<FaqMatch>,
     /// Actions taken (for future extensibility)
     pub actions: Vec<String>,
+    /// The model asked for a human agent (via request_human_agent)
+    #[serde(default)]
+    pub handoff_requested: bool,
+    /// Reason given with the handoff request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handoff_reason: Option<String>,
+}
+
+/// Progress of a streamed chat reply
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChatStreamEvent {
+    /// A fragment of assistant text
+    Delta { text: String },
+    /// The model called a tool; its result follows as `ToolResult`
+    ToolCall { id: String, name: String },
+    /// A tool finished; `action` is absent when it failed
+    ToolResult {
+        id: String,
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        action: Option<String>,
+    },
+}
+
+impl ChatStreamEvent {
+    /// SSE event name
+    pub fn event_name(&self) -> &'static str {
+        match self {
+            Self::Delta { .. } => "delta",
+            Self::ToolCall { .. } => "tool_call",
+            Self::ToolResult { .. } => "tool_result",
+        }
+    }
+}
+
+/// Everything collected from tool calls during one reply
+#[derive(Default)]
+struct ToolOutputs {
+    products: Vec<ProductMatch>,
+    faqs: Vec<FaqMatch>,
+    actions: Vec<String>,
+    handoff_requested: bool,
+    handoff_reason: Option<String>,
+}
+
+impl ToolOutputs {
+    fn into_result(self, message: String) -> ChatResult {
+        ChatResult {
+            message,
+            products: self.products,
+            faqs: self.faqs,
+            actions: self.actions,
+            handoff_requested: self.handoff_requested,
+            handoff_reason: self.handoff_reason,
+        }
+    }
+}
+
+/// Tools offered for a turn; account tools only when the session has a verified customer
+fn chat_tools(customer: Option<&CustomerToolContext>) -> Vec<ToolDefinition> {
+    let mut tools = get_chat_tools();
+    if customer.is_some() {
+        tools.extend(get_customer_chat_tools());
+    }
+    tools
 }
 
 /// Chat orchestrator for managing AI conversations
@@ -79,6 +150,10 @@ impl ChatOrchestrator {
         Self { ai_service }
     }
 
+    pub fn ai_service(&self) -> &AiService {
+        &self.ai_service
+    }
+
     /// Process a user message and return the AI response
     ///
     /// Uses speculative execution: runs tool-decision AND direct-response in parallel.
@@ -86,15 +161,15 @@ impl ChatOrchestrator {
     #[allow(clippy::too_many_arguments)]
     pub async fn process_message(
         &self,
-        provider: AiProvider,
-        model: AiModel,
-        api_key: &str,
+        endpoint: &AiEndpoint,
         system_prompt: Option<&str>,
         history: &[ChatMessage],
         user_message: &str,
         products: &[Product],
         faqs: &[Faq],
         fact_finder_config: Option<&FactFinderConfig>,
+        customer: Option<&CustomerToolContext>,
+        search: Option<&SearchToolContext>,
     ) -> Result<ChatResult, AiError> {
         // Build conversation from history
         let mut messages = self.build_conversation(system_prompt, history);
@@ -102,16 +177,16 @@ impl ChatOrchestrator {
         // Add the new user message
         messages.push(ConversationMessage::user(user_message));
 
-        let tools = get_chat_tools();
+        let tools = chat_tools(customer);
 
         // SPECULATIVE EXECUTION: Run tool-decision AND direct-response in parallel
         // This reduces latency when no tools are needed (common for greetings, simple questions)
         let tool_future = self
             .ai_service
-            .complete_with_tools(provider, model, api_key, &messages, &tools);
-        let speculative_future =
-            self.ai_service
-                .complete_with_tools(provider, model, api_key, &messages, &[]); // No tools = direct response
+            .complete_with_tools(endpoint, &messages, &tools);
+        let speculative_future = self
+            .ai_service
+            .complete_with_tools(endpoint, &messages, &[]); // No tools = direct response
 
         let (tool_result, speculative_result) = tokio::join!(tool_future, speculative_future);
 
@@ -128,6 +203,8 @@ impl ChatOrchestrator {
                 products: vec![],
                 faqs: vec![],
                 actions: vec![],
+                handoff_requested: false,
+                handoff_reason: None,
             });
         }
 
@@ -136,68 +213,127 @@ impl ChatOrchestrator {
 
         // Execute tool calls
         self.execute_tool_loop(
-            provider,
-            model,
-            api_key,
+            endpoint,
             messages,
             &tools,
             tool_response,
             products,
             faqs,
             fact_finder_config,
+            customer,
+            search,
             speculative_draft,
         )
         .await
     }
 
+    /// Process a user message, streaming the reply as it is generated.
+    ///
+    /// Text deltas and tool progress are passed to `on_event`; the returned
+    /// [`ChatResult`] is the same as [`Self::process_message`] produces. No
+    /// speculative call is made: the tool-enabled response is streamed directly.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn process_message_stream(
+        &self,
+        endpoint: &AiEndpoint,
+        system_prompt: Option<&str>,
+        history: &[ChatMessage],
+        user_message: &str,
+        products: &[Product],
+        faqs: &[Faq],
+        fact_finder_config: Option<&FactFinderConfig>,
+        customer: Option<&CustomerToolContext>,
+        search: Option<&SearchToolContext>,
+        on_event: &(dyn Fn(ChatStreamEvent) + Send + Sync),
+    ) -> Result<ChatResult, AiError> {
+        let mut messages = self.build_conversation(system_prompt, history);
+        messages.push(ConversationMessage::user(user_message));
+        let tools = chat_tools(customer);
+        let on_delta = |text: &str| {
+            on_event(ChatStreamEvent::Delta {
+                text: text.to_string(),
+            })
+        };
+
+        let mut outputs = ToolOutputs::default();
+        let mut response = self
+            .ai_service
+            .stream_with_tools(endpoint, &messages, &tools, &on_delta)
+            .await?;
+        let mut rounds = 0;
+
+        while !response.is_complete {
+            if rounds >= MAX_TOOL_ROUNDS {
+                tracing::warn!("Max tool rounds reached, returning current response");
+                return Ok(outputs.into_result(MAX_ROUNDS_MESSAGE.to_string()));
+            }
+            for tc in &response.tool_calls {
+                on_event(ChatStreamEvent::ToolCall {
+                    id: tc.id.clone(),
+                    name: tc.name.clone(),
+                });
+            }
+            let round_actions = self
+                .run_tool_round(
+                    &response,
+                    &mut messages,
+                    &mut outputs,
+                    products,
+                    faqs,
+                    fact_finder_config,
+                    customer,
+                    search,
+                )
+                .await;
+            for (tc, action) in response.tool_calls.iter().zip(round_actions) {
+                on_event(ChatStreamEvent::ToolResult {
+                    id: tc.id.clone(),
+                    name: tc.name.clone(),
+                    action,
+                });
+            }
+            rounds += 1;
+
+            response = self
+                .ai_service
+                .stream_with_tools(endpoint, &messages, &tools, &on_delta)
+                .await?;
+        }
+
+        Ok(outputs.into_result(response.content))
+    }
+
     /// Execute tool calling loop and return final response
     #[allow(clippy::too_many_arguments)]
     async fn execute_tool_loop(
         &self,
-        provider: AiProvider,
-        model: AiModel,
-        api_key: &str,
+        endpoint: &AiEndpoint,
         mut messages: Vec<ConversationMessage>,
         tools: &[ToolDefinition],
         initial_response: ToolCallingResponse,
         products: &[Product],
         faqs: &[Faq],
         fact_finder_config: Option<&FactFinderConfig>,
+        customer: Option<&CustomerToolContext>,
+        search: Option<&SearchToolContext>,
         speculative_draft: Option<String>,
     ) -> Result<ChatResult, AiError> {
-        let mut collected_products: Vec<ProductMatch> = vec![];
-        let mut collected_faqs: Vec<FaqMatch> = vec![];
-        let mut actions: Vec<String> = vec![];
+        let mut outputs = ToolOutputs::default();
         let mut rounds = 0;
         let mut current_response = initial_response;
 
         loop {
-            // Process tool calls in parallel
-            let tool_futures: Vec<_> = current_response
-                .tool_calls
-                .iter()
-                .map(|tc| execute_tool(&self.ai_service, tc, products, faqs, fact_finder_config))
-                .collect();
-
-            let tool_results = join_all(tool_futures).await;
-
-            // Add assistant message with all tool calls
-            messages.push(ConversationMessage::assistant_with_tools(
-                &current_response.content,
-                current_response.tool_calls.clone(),
-            ));
-
-            // Collect results and add tool result messages
-            for (tool_call, (result_str, found_products, found_faqs, action)) in
-                current_response.tool_calls.iter().zip(tool_results)
-            {
-                messages.push(ConversationMessage::tool(&tool_call.id, result_str));
-                collected_products.extend(found_products);
-                collected_faqs.extend(found_faqs);
-                if let Some(a) = action {
-                    actions.push(a);
-                }
-            }
+            self.run_tool_round(
+                &current_response,
+                &mut messages,
+                &mut outputs,
+                products,
+                faqs,
+                fact_finder_config,
+                customer,
+                search,
+            )
+            .await;
 
             rounds += 1;
 
@@ -224,28 +360,82 @@ impl ChatOrchestrator {
             // Get next response
             let next_response = self
                 .ai_service
-                .complete_with_tools(provider, model, api_key, &messages, tools)
+                .complete_with_tools(endpoint, &messages, tools)
                 .await?;
 
             if next_response.is_complete {
-                return Ok(ChatResult {
-                    message: next_response.content,
-                    products: collected_products,
-                    faqs: collected_faqs,
-                    actions,
-                });
+                return Ok(outputs.into_result(next_response.content));
             }
 
             current_response = next_response;
         }
 
         // If we hit max rounds, generate a summary response
-        Ok(ChatResult {
-            message: "I've found some information for you.".to_string(),
-            products: collected_products,
-            faqs: collected_faqs,
-            actions,
-        })
+        Ok(outputs.into_result(MAX_ROUNDS_MESSAGE.to_string()))
+    }
+
+    /// Run one response's tool calls in parallel, appending the assistant and
+    /// tool messages to the conversation. Returns each call's action.
+    #[allow(clippy::too_many_arguments)]
+    async fn run_tool_round(
+        &self,
+        response: &ToolCallingResponse,
+        messages: &mut Vec<ConversationMessage>,
+        outputs: &mut ToolOutputs,
+        products: &[Product],
+        faqs: &[Faq],
+        fact_finder_config: Option<&FactFinderConfig>,
+        customer: Option<&CustomerToolContext>,
+        search: Option<&SearchToolContext>,
+    ) -> Vec<Option<String>> {
+        for tc in &response.tool_calls {
+            if tc.name == REQUEST_HUMAN_AGENT {
+                outputs.handoff_requested = true;
+                if let Some(reason) = parse_request_human_agent(tc).and_then(|a| a.reason) {
+                    outputs.handoff_reason = Some(reason);
+                }
+            }
+        }
+
+        // Process tool calls in parallel
+        let tool_futures: Vec<_> = response
+            .tool_calls
+            .iter()
+            .map(|tc| {
+                execute_tool(
+                    &self.ai_service,
+                    tc,
+                    products,
+                    faqs,
+                    fact_finder_config,
+                    customer,
+                    search,
+                )
+            })
+            .collect();
+
+        let tool_results = join_all(tool_futures).await;
+
+        // Add assistant message with all tool calls
+        messages.push(ConversationMessage::assistant_with_tools(
+            &response.content,
+            response.tool_calls.clone(),
+        ));
+
+        // Collect results and add tool result messages
+        let mut round_actions = Vec::with_capacity(tool_results.len());
+        for (tool_call, (result_str, found_products, found_faqs, action)) in
+            response.tool_calls.iter().zip(tool_results)
+        {
+            messages.push(ConversationMessage::tool(&tool_call.id, result_str));
+            outputs.products.extend(found_products);
+            outputs.faqs.extend(found_faqs);
+            if let Some(a) = &action {
+                outputs.actions.push(a.clone());
+            }
+            round_actions.push(action);
+        }
+        round_actions
     }
 
     /// Build conversation messages from history
@@ -289,6 +479,10 @@ impl ChatOrchestrator {
                         messages.push(ConversationMessage::assistant(&msg.content));
                     }
                 }
+                "agent" => {
+                    // Staff replies read as the store's side of the conversation
+                    messages.push(ConversationMessage::assistant(&msg.content));
+                }
                 "tool" => {
                     // Tool messages should have tool_call_id in tool_results
                     if let Some(ref results) = msg.tool_results {
@@ -315,5 +509,7 @@ mod tests {
     fn test_default_chat_system_prompt() {
         assert!(DEFAULT_CHAT_SYSTEM_PROMPT.contains("shopping assistant"));
         assert!(DEFAULT_CHAT_SYSTEM_PROMPT.contains("product_search"));
+        assert!(DEFAULT_CHAT_SYSTEM_PROMPT.contains("request_human_agent"));
+        assert!(DEFAULT_CHAT_SYSTEM_PROMPT.contains("order_lookup"));
     }
 }
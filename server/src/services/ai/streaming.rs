@@ -0,0 +1,485 @@
+//! Streaming completions for every provider.
+//!
+//! All providers answer with server-sent events: Chat Completions (OpenAI and
+//! OpenAI-compatible servers) and the Anthropic Messages API with
+//! `"stream": true`, and Gemini `streamGenerateContent?alt=sse`. Text deltas are
+//! handed to a callback as they arrive and the full response (including any
+//! tool calls) is assembled into the same [`ToolCallingResponse`] the
+//! non-streaming calls return, so the orchestrator's tool loop is unchanged.
+//! Token usage arrives in the final events (OpenAI is asked for it with
+//! `stream_options.include_usage`; OpenAI-compatible servers may omit it).
+
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use futures_util::StreamExt;
+use serde_json::Value;
+
+use crate::handlers::admin_ai::AiProvider;
+use crate::observability::record_ai_call;
+
+use super::anthropic::{anthropic_request, anthropic_request_builder};
+use super::tools::{ConversationMessage, ToolCall, ToolCallingResponse, ToolDefinition};
+use super::{
+    gemini_tool_request, gemini_url, model_to_string, openai_tool_request, provider_to_string,
+    AiEndpoint, AiError, AiService, TokenUsage, CHAT_TASK,
+};
+
+/// Receives each text delta as it streams in
+pub type DeltaCallback<'a> = &'a (dyn Fn(&str) + Send + Sync);
+
+impl AiService {
+    /// Stream a completion with tool calling support.
+    ///
+    /// `on_delta` is called for every text fragment; the assembled response is
+    /// returned once the provider closes the stream. Usage is recorded under
+    /// the `chat` task.
+    pub async fn stream_with_tools(
+        &self,
+        endpoint: &AiEndpoint,
+        messages: &[ConversationMessage],
+        tools: &[ToolDefinition],
+        on_delta: DeltaCallback<'_>,
+    ) -> Result<ToolCallingResponse, AiError> {
+        self.stream_for_task(endpoint, messages, tools, on_delta, CHAT_TASK)
+            .await
+    }
+
+    /// Streamed completion whose usage is recorded under `task`
+    async fn stream_for_task(
+        &self,
+        endpoint: &AiEndpoint,
+        messages: &[ConversationMessage],
+        tools: &[ToolDefinition],
+        on_delta: DeltaCallback<'_>,
+        task: &str,
+    ) -> Result<ToolCallingResponse, AiError> {
+        self.check_budget(endpoint).await?;
+        let request = match endpoint.provider {
+            AiProvider::Openai | AiProvider::OpenaiCompatible => {
+                let mut body = openai_tool_request(endpoint.model_id(), messages, tools);
+                body["stream"] = Value::Bool(true);
+                if endpoint.provider == AiProvider::Openai {
+                    body["stream_options"] = serde_json::json!({"include_usage": true});
+                }
+                endpoint
+                    .bearer_auth(self.http_client.post(endpoint.chat_completions_url()))
+                    .json(&body)
+            }
+            AiProvider::Gemini => self
+                .http_client
+                .post(format!(
+                    "{}?alt=sse",
+                    gemini_url(endpoint, "streamGenerateContent")
+                ))
+                .header("x-goog-api-key", &endpoint.api_key)
+                .json(&gemini_tool_request(messages, tools)),
+            AiProvider::Anthropic => {
+                let mut body = anthropic_request(endpoint.model_id(), messages, tools);
+                body["stream"] = Value::Bool(true);
+                anthropic_request_builder(&self.http_client, endpoint).json(&body)
+            }
+        };
+
+        let response = request
+            .header("Content-Type", "application/json")
+            .send()
+            .await
+            .map_err(|e| AiError::HttpError(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(AiError::ServiceError(format!(
+                "{} API error ({}): {}",
+                endpoint.display_name(),
+                status,
+                error_text
+            )));
+        }
+
+        let mut decoder = SseDecoder::default();
+        let mut assembler = StreamAssembler::new(endpoint.provider);
+        let mut body = response.bytes_stream();
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk.map_err(|e| AiError::HttpError(e.to_string()))?;
+            for data in decoder.push(&chunk) {
+                if let Some(delta) = assembler.apply(&data)? {
+                    on_delta(&delta);
+                }
+            }
+        }
+        let response = assembler.finish();
+        self.record_usage(endpoint, task, response.usage).await;
+        Ok(response)
+    }
+
+    /// Stream a single-prompt completion, recording metrics like
+    /// [`AiService::complete_with_metrics`]
+    pub async fn stream_complete_with_metrics(
+        &self,
+        endpoint: &AiEndpoint,
+        system_prompt: &str,
+        user_prompt: &str,
+        task: &str,
+        on_delta: DeltaCallback<'_>,
+    ) -> Result<String, AiError> {
+        let start = Instant::now();
+        let messages = [
+            ConversationMessage::system(system_prompt),
+            ConversationMessage::user(user_prompt),
+        ];
+        let result = self
+            .stream_for_task(endpoint, &messages, &[], on_delta, task)
+            .await
+            .map(|r| r.content);
+
+        record_ai_call(
+            provider_to_string(endpoint.provider),
+            model_to_string(endpoint.model),
+            task,
+            result.is_ok(),
+            start.elapsed().as_secs_f64(),
+        );
+        result
+    }
+}
+
+// ============================================================================
+// SSE Decoding
+// ============================================================================
+
+/// Splits a byte stream into SSE `data` payloads.
+///
+/// Chunks may end mid-line (or mid-character); incomplete input is buffered
+/// until the rest arrives.
+#[derive(Debug, Default)]
+struct SseDecoder {
+    buf: Vec<u8>,
+    data: Vec<String>,
+}
+
+impl SseDecoder {
+    /// Feed bytes, returning the payloads of every event completed by them
+    fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.buf.extend_from_slice(chunk);
+        let mut events = Vec::new();
+        while let Some(pos) = self.buf.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buf.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\n', '\r']);
+            if line.is_empty() {
+                if !self.data.is_empty() {
+                    events.push(self.data.join("\n"));
+                    self.data.clear();
+                }
+            } else if let Some(value) = line.strip_prefix("data:") {
+                self.data
+                    .push(value.strip_prefix(' ').unwrap_or(value).to_string());
+            }
+        }
+        events
+    }
+}
+
+// ============================================================================
+// Response Assembly
+// ============================================================================
+
+/// A tool call whose name/arguments arrive in fragments (OpenAI, Anthropic)
+#[derive(Debug, Default)]
+struct PartialToolCall {
+    id: String,
+    name: String,
+    arguments: String,
+}
+
+/// Accumulates provider stream chunks into a [`ToolCallingResponse`]
+struct StreamAssembler {
+    provider: AiProvider,
+    content: String,
+    /// OpenAI tool calls keyed by their stream index (Anthropic: content block index)
+    partial_calls: BTreeMap<u64, PartialToolCall>,
+    /// Gemini sends each function call whole
+    tool_calls: Vec<ToolCall>,
+    usage: TokenUsage,
+}
+
+impl StreamAssembler {
+    fn new(provider: AiProvider) -> Self {
+        Self {
+            provider,
+            content: String::new(),
+            partial_calls: BTreeMap::new(),
+            tool_calls: Vec::new(),
+            usage: TokenUsage::default(),
+        }
+    }
+
+    /// Apply one SSE payload, returning its text delta (if any)
+    fn apply(&mut self, data: &str) -> Result<Option<String>, AiError> {
+        if data.trim() == "[DONE]" {
+            return Ok(None);
+        }
+        let chunk: Value = serde_json::from_str(data)
+            .map_err(|e| AiError::ParseError(format!("Invalid stream chunk: {}", e)))?;
+        if let Some(error) = chunk.get("error") {
+            return Err(AiError::ServiceError(format!("Stream error: {}", error)));
+        }
+        self.apply_usage(&chunk);
+        let delta = match self.provider {
+            AiProvider::Openai | AiProvider::OpenaiCompatible => self.apply_openai(&chunk),
+            AiProvider::Gemini => self.apply_gemini(&chunk),
+            AiProvider::Anthropic => self.apply_anthropic(&chunk),
+        };
+        if let Some(text) = &delta {
+            self.content.push_str(text);
+        }
+        Ok(delta)
+    }
+
+    /// OpenAI reports usage in a final chunk without choices and Gemini in
+    /// every chunk (cumulative). Anthropic sends input tokens in
+    /// `message_start` and the running output count in `message_delta`.
+    fn apply_usage(&mut self, chunk: &Value) {
+        match self.provider {
+            AiProvider::Openai | AiProvider::OpenaiCompatible => {
+                if let Some(usage) = chunk.get("usage").filter(|u| u.is_object()) {
+                    self.usage = TokenUsage::from_openai(usage);
+                }
+            }
+            AiProvider::Gemini => {
+                if let Some(metadata) = chunk.get("usageMetadata") {
+                    self.usage = TokenUsage::from_gemini(metadata);
+                }
+            }
+            AiProvider::Anthropic => {
+                let usage = match chunk.get("type").and_then(Value::as_str) {
+                    Some("message_start") => chunk.pointer("/message/usage"),
+                    Some("message_delta") => chunk.get("usage"),
+                    _ => None,
+                };
+                if let Some(usage) = usage.map(TokenUsage::from_anthropic) {
+                    if usage.input_tokens > 0 {
+                        self.usage.input_tokens = usage.input_tokens;
+                    }
+                    self.usage.output_tokens = usage.output_tokens;
+                }
+            }
+        }
+    }
+
+    fn apply_openai(&mut self, chunk: &Value) -> Option<String> {
+        let delta = chunk.pointer("/choices/0/delta")?;
+        for call in delta
+            .get("tool_calls")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let index = call.get("index").and_then(Value::as_u64).unwrap_or(0);
+            let partial = self.partial_calls.entry(index).or_default();
+            if let Some(id) = call.get("id").and_then(Value::as_str) {
+                partial.id = id.to_string();
+            }
+            if let Some(name) = call.pointer("/function/name").and_then(Value::as_str) {
+                partial.name.push_str(name);
+            }
+            if let Some(args) = call.pointer("/function/arguments").and_then(Value::as_str) {
+                partial.arguments.push_str(args);
+            }
+        }
+        delta
+            .get("content")
+            .and_then(Value::as_str)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+    }
+
+    fn apply_gemini(&mut self, chunk: &Value) -> Option<String> {
+        let parts = chunk
+            .pointer("/candidates/0/content/parts")
+            .and_then(Value::as_array)?;
+        let mut text = String::new();
+        for part in parts {
+            if let Some(t) = part.get("text").and_then(Value::as_str) {
+                text.push_str(t);
+            }
+            if let Some(fc) = part.get("functionCall") {
+                self.tool_calls.push(ToolCall {
+                    id: format!("gemini_{}", self.tool_calls.len()),
+                    name: fc
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string(),
+                    arguments: fc.get("args").cloned().unwrap_or(Value::Null),
+                });
+            }
+        }
+        (!text.is_empty()).then_some(text)
+    }
+
+    /// Anthropic sends `tool_use` blocks as a `content_block_start` followed
+    /// by `input_json_delta` fragments, keyed by the content block index
+    fn apply_anthropic(&mut self, chunk: &Value) -> Option<String> {
+        let index = chunk.get("index").and_then(Value::as_u64).unwrap_or(0);
+        match chunk.get("type").and_then(Value::as_str)? {
+            "content_block_start" => {
+                let block = chunk.get("content_block")?;
+                if block.get("type").and_then(Value::as_str) == Some("tool_use") {
+                    let partial = self.partial_calls.entry(index).or_default();
+                    partial.id = block
+                        .get("id")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string();
+                    partial.name = block
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string();
+                }
+                None
+            }
+            "content_block_delta" => {
+                let delta = chunk.get("delta")?;
+                match delta.get("type").and_then(Value::as_str)? {
+                    "text_delta" => delta
+                        .get("text")
+                        .and_then(Value::as_str)
+                        .filter(|t| !t.is_empty())
+                        .map(str::to_string),
+                    "input_json_delta" => {
+                        if let Some(json) = delta.get("partial_json").and_then(Value::as_str) {
+                            self.partial_calls
+                                .entry(index)
+                                .or_default()
+                                .arguments
+                                .push_str(json);
+                        }
+                        None
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn finish(self) -> ToolCallingResponse {
+        let mut tool_calls = self.tool_calls;
+        tool_calls.extend(self.partial_calls.into_values().map(|p| ToolCall {
+            id: p.id,
+            name: p.name,
+            // A call without arguments streams no argument fragments at all
+            arguments: if p.arguments.trim().is_empty() {
+                Value::Object(Default::default())
+            } else {
+                serde_json::from_str(&p.arguments).unwrap_or(Value::Null)
+            },
+        }));
+        ToolCallingResponse::with_tools(self.content, tool_calls).with_usage(self.usage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sse_decoder_handles_split_chunks() {
+        let mut decoder = SseDecoder::default();
+        assert!(decoder.push(b"data: {\"a\":").is_empty());
+        assert!(decoder.push(b"1}\r\n").is_empty());
+        let events = decoder.push(b"\r\ndata: [DONE]\n\n: comment\n\n");
+        assert_eq!(events, vec!["{\"a\":1}".to_string(), "[DONE]".to_string()]);
+    }
+
+    #[test]
+    fn test_openai_stream_assembles_text_and_tool_calls() {
+        let mut asm = StreamAssembler::new(AiProvider::Openai);
+        let chunks = [
+            r#"{"choices":[{"delta":{"content":"Let me "}}]}"#,
+            r#"{"choices":[{"delta":{"content":"check"}}]}"#,
+            r#"{"choices":[{"delta":{"tool_calls":[{"index":0,"id":"call_1","function":{"name":"product_search","arguments":"{\"qu"}}]}}]}"#,
+            r#"{"choices":[{"delta":{"tool_calls":[{"index":0,"function":{"arguments":"ery\":\"rings\"}"}}]}}]}"#,
+            r#"{"choices":[],"usage":{"prompt_tokens":120,"completion_tokens":18}}"#,
+            "[DONE]",
+        ];
+        let deltas: Vec<String> = chunks
+            .iter()
+            .filter_map(|c| asm.apply(c).unwrap())
+            .collect();
+        assert_eq!(deltas, vec!["Let me ", "check"]);
+
+        let response = asm.finish();
+        assert_eq!(response.content, "Let me check");
+        assert!(!response.is_complete);
+        assert_eq!(response.tool_calls.len(), 1);
+        assert_eq!(response.tool_calls[0].id, "call_1");
+        assert_eq!(response.tool_calls[0].name, "product_search");
+        assert_eq!(response.tool_calls[0].arguments["query"], "rings");
+        assert_eq!(response.usage.input_tokens, 120);
+        assert_eq!(response.usage.output_tokens, 18);
+    }
+
+    #[test]
+    fn test_gemini_stream_assembles_text_and_tool_calls() {
+        let mut asm = StreamAssembler::new(AiProvider::Gemini);
+        let text = asm
+            .apply(r#"{"candidates":[{"content":{"parts":[{"text":"Hello"}]}}]}"#)
+            .unwrap();
+        assert_eq!(text.as_deref(), Some("Hello"));
+        let none = asm
+            .apply(r#"{"candidates":[{"content":{"parts":[{"functionCall":{"name":"fact_finder","args":{"query":"shipping"}}}]}}]}"#)
+            .unwrap();
+        assert!(none.is_none());
+
+        let response = asm.finish();
+        assert_eq!(response.content, "Hello");
+        assert_eq!(response.tool_calls.len(), 1);
+        assert_eq!(response.tool_calls[0].name, "fact_finder");
+        assert_eq!(response.tool_calls[0].arguments["query"], "shipping");
+    }
+
+    #[test]
+    fn test_anthropic_stream_assembles_text_and_tool_calls() {
+        let mut asm = StreamAssembler::new(AiProvider::Anthropic);
+        let chunks = [
+            r#"{"type":"message_start","message":{"id":"msg_1","content":[],"usage":{"input_tokens":250,"output_tokens":1}}}"#,
+            r#"{"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}"#,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"One moment"}}"#,
+            r#"{"type":"content_block_stop","index":0}"#,
+            r#"{"type":"content_block_start","index":1,"content_block":{"type":"tool_use","id":"toolu_1","name":"product_search","input":{}}}"#,
+            r#"{"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"{\"query\":"}}"#,
+            r#"{"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"\"mugs\"}"}}"#,
+            r#"{"type":"content_block_start","index":2,"content_block":{"type":"tool_use","id":"toolu_2","name":"subscription_status","input":{}}}"#,
+            r#"{"type":"message_delta","delta":{"stop_reason":"tool_use"},"usage":{"output_tokens":37}}"#,
+            r#"{"type":"message_stop"}"#,
+        ];
+        let deltas: Vec<String> = chunks
+            .iter()
+            .filter_map(|c| asm.apply(c).unwrap())
+            .collect();
+        assert_eq!(deltas, vec!["One moment"]);
+
+        let response = asm.finish();
+        assert_eq!(response.content, "One moment");
+        assert_eq!(response.tool_calls.len(), 2);
+        assert_eq!(response.tool_calls[0].id, "toolu_1");
+        assert_eq!(response.tool_calls[0].arguments["query"], "mugs");
+        assert_eq!(response.tool_calls[1].name, "subscription_status");
+        assert!(response.tool_calls[1].arguments.is_object());
+        assert_eq!(response.usage.input_tokens, 250);
+        assert_eq!(response.usage.output_tokens, 37);
+    }
+
+    #[test]
+    fn test_stream_error_chunk_is_an_error() {
+        let mut asm = StreamAssembler::new(AiProvider::Openai);
+        assert!(asm
+            .apply(r#"{"error":{"message":"rate limited"}}"#)
+            .is_err());
+    }
+}
@@ -1,6 +1,12 @@
 //! Tool execution implementations for the chat orchestrator.
 //!
 //! Contains the actual logic for executing product_search and fact_finder tools.
+//! Both rank with [`SemanticSearch`] when the chat has one (hybrid keyword +
+//! embedding search) and fall back to keyword matching otherwise.
+//! Account tools are delegated to [`super::customer_tools`] and only run when
+//! the chat session has a verified customer.
+//! request_human_agent has no side effects here; the orchestrator reports it
+//! back to the caller, which performs the handoff.
 
 use std::sync::Arc;
 
@@ -8,29 +14,76 @@ use serde_json::json;
 
 use crate::handlers::admin_ai_assistant::{ProductMatch, ProductSearchResponse};
 use crate::models::{Faq, Product};
+use crate::services::semantic_search::{keyword_rank_faqs, keyword_rank_products, SemanticSearch};
 
+use super::customer_tools::{execute_customer_tool, CustomerToolContext};
 use super::orchestrator::{FactFinderConfig, FaqMatch};
-use super::tools::{FactFinderArgs, ProductSearchArgs, ToolCall};
+use super::tools::{
+    is_customer_tool, FactFinderArgs, ProductSearchArgs, RequestHumanAgentArgs, ToolCall,
+    REQUEST_HUMAN_AGENT,
+};
 use super::{parse_json_response, AiService, FactFinderResult};
 
+/// Results returned by product_search and fact_finder
+const SEARCH_RESULT_LIMIT: usize = 3;
+
+/// Search service used by product_search and fact_finder for the chat's tenant
+pub struct SearchToolContext {
+    pub tenant_id: String,
+    pub search: Arc<SemanticSearch>,
+}
+
 /// Execute a tool call and return (result_string, products, faqs, action)
+#[allow(clippy::too_many_arguments)]
 pub async fn execute_tool(
     ai_service: &Arc<AiService>,
     tool_call: &ToolCall,
     products: &[Product],
     faqs: &[Faq],
     fact_finder_config: Option<&FactFinderConfig>,
+    customer: Option<&CustomerToolContext>,
+    search: Option<&SearchToolContext>,
 ) -> (String, Vec<ProductMatch>, Vec<FaqMatch>, Option<String>) {
     match tool_call.name.as_str() {
+        name if is_customer_tool(name) => {
+            let (result, action) = match customer {
+                Some(ctx) => execute_customer_tool(ctx, tool_call).await,
+                None => (
+                    json!({
+                        "error": "The customer is not signed in. Ask them to sign in to their account first."
+                    })
+                    .to_string(),
+                    None,
+                ),
+            };
+            (result, vec![], vec![], action)
+        }
         "product_search" => {
-            let (result, found_products, action) = execute_product_search(tool_call, products);
+            let (result, found_products, action) =
+                execute_product_search(tool_call, products, search).await;
             (result, found_products, vec![], Some(action))
         }
         "fact_finder" => {
             let (result, found_faqs, action) =
-                execute_fact_finder(ai_service, tool_call, faqs, fact_finder_config).await;
+                execute_fact_finder(ai_service, tool_call, faqs, fact_finder_config, search).await;
             (result, vec![], found_faqs, Some(action))
         }
+        REQUEST_HUMAN_AGENT => {
+            let args = parse_request_human_agent(tool_call).unwrap_or_default();
+            let result = json!({
+                "name": REQUEST_HUMAN_AGENT,
+                "response": {
+                    "status": "requested",
+                    "note": "A member of staff has been notified and will join this chat. Tell the customer they will be connected shortly."
+                }
+            })
+            .to_string();
+            let action = match args.reason {
+                Some(reason) => format!("Requested human agent: {}", reason),
+                None => "Requested human agent".to_string(),
+            };
+            (result, vec![], vec![], Some(action))
+        }
         _ => {
             tracing::warn!(tool = %tool_call.name, "Unknown tool called");
             let result = json!({
@@ -42,10 +95,16 @@ pub async fn execute_tool(
     }
 }
 
+/// Arguments of a request_human_agent call (None if unparseable)
+pub fn parse_request_human_agent(tool_call: &ToolCall) -> Option<RequestHumanAgentArgs> {
+    serde_json::from_value(tool_call.arguments.clone()).ok()
+}
+
 /// Execute product search tool - returns (result_string, found_products, action)
-fn execute_product_search(
+async fn execute_product_search(
     tool_call: &ToolCall,
     products: &[Product],
+    search: Option<&SearchToolContext>,
 ) -> (String, Vec<ProductMatch>, String) {
     // Parse arguments
     let args: ProductSearchArgs = match serde_json::from_value(tool_call.arguments.clone()) {
@@ -61,42 +120,16 @@ fn execute_product_search(
 
     let action = format!("Searched for: {}", args.query);
 
-    // Simple keyword-based search
-    let query_lower = args.query.to_lowercase();
-    let keywords: Vec<&str> = query_lower.split_whitespace().collect();
-
-    let mut matches: Vec<(Product, i32)> = products
-        .iter()
-        .filter(|p| p.active)
-        .map(|p| {
-            let mut score = 0i32;
-            let title = p.title.as_deref().unwrap_or("").to_lowercase();
-            let desc = p.description.to_lowercase();
-            let tags = p.tags.join(" ").to_lowercase();
-
-            for kw in &keywords {
-                if title.contains(kw) {
-                    score += 10;
-                }
-                if desc.contains(kw) {
-                    score += 5;
-                }
-                if tags.contains(kw) {
-                    score += 3;
-                }
-            }
-            (p.clone(), score)
-        })
-        .filter(|(_, score)| *score > 0)
-        .collect();
-
-    matches.sort_by(|a, b| b.1.cmp(&a.1));
-
-    let found_products: Vec<ProductMatch> = matches
-        .into_iter()
-        .take(3)
-        .map(|(p, _)| product_to_match(&p))
-        .collect();
+    let matches = match search {
+        Some(ctx) => {
+            ctx.search
+                .rank_products(&ctx.tenant_id, &args.query, products, SEARCH_RESULT_LIMIT)
+                .await
+                .items
+        }
+        None => keyword_rank_products(&args.query, products, SEARCH_RESULT_LIMIT),
+    };
+    let found_products: Vec<ProductMatch> = matches.into_iter().map(product_to_match).collect();
 
     let response = ProductSearchResponse {
         products: found_products.clone(),
@@ -122,6 +155,7 @@ async fn execute_fact_finder(
     tool_call: &ToolCall,
     faqs: &[Faq],
     config: Option<&FactFinderConfig>,
+    search: Option<&SearchToolContext>,
 ) -> (String, Vec<FaqMatch>, String) {
     // Parse arguments
     let args: FactFinderArgs = match serde_json::from_value(tool_call.arguments.clone()) {
@@ -137,48 +171,55 @@ async fn execute_fact_finder(
 
     let action = format!("Searched FAQ for: {}", args.query);
 
+    // Embedding search answers without an extra LLM round trip; it is only
+    // used when the tenant has an embeddings model assigned
+    if let Some(ctx) = search {
+        let ranked = ctx
+            .search
+            .rank_faqs(&ctx.tenant_id, &args.query, faqs, SEARCH_RESULT_LIMIT)
+            .await;
+        if ranked.semantic {
+            let found: Vec<FaqMatch> = ranked.items.into_iter().map(faq_to_match).collect();
+            return (fact_finder_result(&args.query, &found), found, action);
+        }
+    }
+
     // Try AI-powered search if config is available
     if let Some(cfg) = config {
         if let Some(found) = ai_fact_finder(ai_service, &args.query, faqs, cfg).await {
-            let response = json!({
-                "faqs": &found,
-                "count": found.len(),
-                "message": if found.is_empty() {
-                    format!("No FAQ entries found for '{}'", args.query)
-                } else {
-                    format!("Found {} FAQ entries matching '{}'", found.len(), args.query)
-                }
-            });
-            let result = json!({
-                "name": "fact_finder",
-                "response": response
-            })
-            .to_string();
-            return (result, found, action);
+            return (fact_finder_result(&args.query, &found), found, action);
         }
         tracing::warn!("AI fact finder failed, falling back to keyword search");
     }
 
     // Keyword-based fallback search
-    let found_faqs = keyword_fact_finder(&args.query, faqs);
+    let found_faqs: Vec<FaqMatch> = keyword_rank_faqs(&args.query, faqs, SEARCH_RESULT_LIMIT)
+        .into_iter()
+        .map(faq_to_match)
+        .collect();
+    (
+        fact_finder_result(&args.query, &found_faqs),
+        found_faqs,
+        action,
+    )
+}
 
+/// Tool result message for fact_finder
+fn fact_finder_result(query: &str, found: &[FaqMatch]) -> String {
     let response = json!({
-        "faqs": &found_faqs,
-        "count": found_faqs.len(),
-        "message": if found_faqs.is_empty() {
-            format!("No FAQ entries found for '{}'", args.query)
+        "faqs": found,
+        "count": found.len(),
+        "message": if found.is_empty() {
+            format!("No FAQ entries found for '{}'", query)
         } else {
-            format!("Found {} FAQ entries matching '{}'", found_faqs.len(), args.query)
+            format!("Found {} FAQ entries matching '{}'", found.len(), query)
         }
     });
-
-    let result = json!({
+    json!({
         "name": "fact_finder",
         "response": response
     })
-    .to_string();
-
-    (result, found_faqs, action)
+    .to_string()
 }
 
 /// AI-powered fact finder search
@@ -215,9 +256,7 @@ async fn ai_fact_finder(
 
     let response = ai_service
         .complete_with_metrics(
-            config.provider,
-            config.model,
-            &config.api_key,
+            &config.endpoint,
             &config.prompt,
             &user_prompt,
             "fact_finder",
@@ -234,63 +273,20 @@ async fn ai_fact_finder(
     let found: Vec<FaqMatch> = result
         .matches
         .iter()
-        .filter_map(|m| {
-            faq_map.get(m.faq_id.as_str()).map(|f| FaqMatch {
-                id: f.id.clone(),
-                question: f.question.clone(),
-                answer: f.answer.clone(),
-            })
-        })
-        .take(3)
+        .filter_map(|m| faq_map.get(m.faq_id.as_str()).copied().map(faq_to_match))
+        .take(SEARCH_RESULT_LIMIT)
         .collect();
 
     Some(found)
 }
 
-/// Keyword-based fallback fact finder
-fn keyword_fact_finder(query: &str, faqs: &[Faq]) -> Vec<FaqMatch> {
-    let query_lower = query.to_lowercase();
-    let keywords: Vec<&str> = query_lower.split_whitespace().collect();
-
-    let mut matches: Vec<(&Faq, i32)> = faqs
-        .iter()
-        .filter(|f| f.active)
-        .map(|f| {
-            let mut score = 0i32;
-            let question = f.question.to_lowercase();
-            let answer = f.answer.to_lowercase();
-            let faq_keywords = f.keywords.join(" ").to_lowercase();
-
-            for kw in &keywords {
-                if f.keywords.iter().any(|k| k.to_lowercase() == *kw) {
-                    score += 15;
-                }
-                if question.contains(kw) {
-                    score += 10;
-                }
-                if answer.contains(kw) {
-                    score += 5;
-                }
-                if faq_keywords.contains(kw) {
-                    score += 3;
-                }
-            }
-            (f, score)
-        })
-        .filter(|(_, score)| *score > 0)
-        .collect();
-
-    matches.sort_by(|a, b| b.1.cmp(&a.1));
-
-    matches
-        .into_iter()
-        .take(3)
-        .map(|(f, _)| FaqMatch {
-            id: f.id.clone(),
-            question: f.question.clone(),
-            answer: f.answer.clone(),
-        })
-        .collect()
+/// Convert an FAQ to a FaqMatch
+fn faq_to_match(faq: &Faq) -> FaqMatch {
+    FaqMatch {
+        id: faq.id.clone(),
+        question: faq.question.clone(),
+        answer: faq.answer.clone(),
+    }
 }
 
 /// Convert a Product to a ProductMatch
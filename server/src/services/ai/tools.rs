@@ -5,6 +5,10 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
+use crate::models::RETURN_REASON_CODES;
+
+use super::usage::TokenUsage;
+
 // ============================================================================
 // Tool Definitions
 // ============================================================================
@@ -53,9 +57,119 @@ pub fn fact_finder_tool() -> ToolDefinition {
     }
 }
 
+/// Handoff tool - asks for a human agent to take over the conversation
+pub fn request_human_agent_tool() -> ToolDefinition {
+    ToolDefinition {
+        name: REQUEST_HUMAN_AGENT,
+        description: "Hand the conversation over to a human member of staff. Use this when the customer asks to talk to a person, or when you cannot resolve their issue (order problems, complaints, anything needing account access).",
+        parameters: json!({
+            "type": "object",
+            "properties": {
+                "reason": {
+                    "type": "string",
+                    "description": "Short summary of what the customer needs help with"
+                }
+            }
+        }),
+    }
+}
+
+/// Name of the handoff tool (the orchestrator reacts to it)
+pub const REQUEST_HUMAN_AGENT: &str = "request_human_agent";
+
+/// Order lookup tool - the signed-in customer's orders, status and tracking
+pub fn order_lookup_tool() -> ToolDefinition {
+    ToolDefinition {
+        name: ORDER_LOOKUP,
+        description: "Look up the signed-in customer's orders. Without order_id, lists their recent orders; with order_id, returns status, status history, shipment tracking and which items can be returned. Use this for \"where is my order\" questions.",
+        parameters: json!({
+            "type": "object",
+            "properties": {
+                "order_id": {
+                    "type": "string",
+                    "description": "Order ID to look up (omit to list recent orders)"
+                }
+            }
+        }),
+    }
+}
+
+/// Return initiation tool - files a return request for admin approval
+pub fn start_return_tool() -> ToolDefinition {
+    ToolDefinition {
+        name: START_RETURN,
+        description: "Start a return for items in one of the signed-in customer's orders. The request is reviewed by staff before anything is refunded. Confirm the order, items and quantities with the customer first; use order_lookup to see which items are returnable.",
+        parameters: json!({
+            "type": "object",
+            "properties": {
+                "order_id": {
+                    "type": "string",
+                    "description": "Order containing the items"
+                },
+                "items": {
+                    "type": "array",
+                    "description": "Items to return",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "product_id": { "type": "string" },
+                            "variant_id": { "type": "string" },
+                            "quantity": { "type": "integer" },
+                            "reason_code": {
+                                "type": "string",
+                                "enum": RETURN_REASON_CODES
+                            }
+                        },
+                        "required": ["product_id", "quantity"]
+                    }
+                },
+                "reason": {
+                    "type": "string",
+                    "description": "The customer's explanation"
+                }
+            },
+            "required": ["order_id", "items"]
+        }),
+    }
+}
+
+/// Subscription status tool - the signed-in customer's subscriptions
+pub fn subscription_status_tool() -> ToolDefinition {
+    ToolDefinition {
+        name: SUBSCRIPTION_STATUS,
+        description: "List the signed-in customer's subscriptions with their status, billing period and renewal or end date.",
+        parameters: json!({
+            "type": "object",
+            "properties": {}
+        }),
+    }
+}
+
+pub const ORDER_LOOKUP: &str = "order_lookup";
+pub const START_RETURN: &str = "start_return";
+pub const SUBSCRIPTION_STATUS: &str = "subscription_status";
+
+/// Tools that act on the session's verified customer; only offered when one is signed in
+pub fn get_customer_chat_tools() -> Vec<ToolDefinition> {
+    vec![
+        order_lookup_tool(),
+        start_return_tool(),
+        subscription_status_tool(),
+    ]
+}
+
+/// Whether `name` is one of [`get_customer_chat_tools`]
+pub fn is_customer_tool(name: &str) -> bool {
+    matches!(name, ORDER_LOOKUP | START_RETURN | SUBSCRIPTION_STATUS)
+}
+
 /// Get all available chat tools
 pub fn get_chat_tools() -> Vec<ToolDefinition> {
-    vec![product_search_tool(), fact_finder_tool()]
+    vec![
+        product_search_tool(),
+        fact_finder_tool(),
+        request_human_agent_tool(),
+    ]
 }
 
 // ============================================================================
@@ -94,6 +208,40 @@ pub struct FactFinderArgs {
     pub query: String,
 }
 
+/// Order lookup tool arguments
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OrderLookupArgs {
+    #[serde(default)]
+    pub order_id: Option<String>,
+}
+
+/// Start return tool arguments
+#[derive(Debug, Clone, Deserialize)]
+pub struct StartReturnArgs {
+    pub order_id: String,
+    pub items: Vec<StartReturnItem>,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// One item of a start_return call
+#[derive(Debug, Clone, Deserialize)]
+pub struct StartReturnItem {
+    pub product_id: String,
+    #[serde(default)]
+    pub variant_id: Option<String>,
+    pub quantity: i32,
+    #[serde(default)]
+    pub reason_code: Option<String>,
+}
+
+/// Handoff tool arguments
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RequestHumanAgentArgs {
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
 // ============================================================================
 // Message Types for Tool Calling
 // ============================================================================
@@ -171,6 +319,8 @@ pub struct ToolCallingResponse {
     pub tool_calls: Vec<ToolCall>,
     /// Whether the AI is done (no more tool calls needed)
     pub is_complete: bool,
+    /// Tokens the request consumed, as reported by the provider
+    pub usage: TokenUsage,
 }
 
 impl ToolCallingResponse {
@@ -180,6 +330,7 @@ impl ToolCallingResponse {
             content: content.into(),
             tool_calls: vec![],
             is_complete: true,
+            usage: TokenUsage::default(),
         }
     }
 
@@ -190,8 +341,15 @@ impl ToolCallingResponse {
             content: content.into(),
             tool_calls,
             is_complete,
+            usage: TokenUsage::default(),
         }
     }
+
+    /// Attach the provider-reported token usage
+    pub fn with_usage(mut self, usage: TokenUsage) -> Self {
+        self.usage = usage;
+        self
+    }
 }
 
 // ============================================================================
@@ -216,6 +374,21 @@ pub fn to_openai_tools(tools: &[ToolDefinition]) -> Value {
     json!(tool_defs)
 }
 
+/// Convert tool definitions to Anthropic Messages API format
+pub fn to_anthropic_tools(tools: &[ToolDefinition]) -> Value {
+    let tool_defs: Vec<Value> = tools
+        .iter()
+        .map(|t| {
+            json!({
+                "name": t.name,
+                "description": t.description,
+                "input_schema": t.parameters
+            })
+        })
+        .collect();
+    json!(tool_defs)
+}
+
 /// Convert tool definitions to Gemini format
 pub fn to_gemini_tools(tools: &[ToolDefinition]) -> Value {
     let function_declarations: Vec<Value> = tools
@@ -247,9 +420,10 @@ mod tests {
     #[test]
     fn test_get_chat_tools() {
         let tools = get_chat_tools();
-        assert_eq!(tools.len(), 2);
+        assert_eq!(tools.len(), 3);
         assert_eq!(tools[0].name, "product_search");
         assert_eq!(tools[1].name, "fact_finder");
+        assert_eq!(tools[2].name, "request_human_agent");
     }
 
     #[test]
@@ -257,7 +431,7 @@ mod tests {
         let tools = get_chat_tools();
         let openai_format = to_openai_tools(&tools);
         let arr = openai_format.as_array().unwrap();
-        assert_eq!(arr.len(), 2);
+        assert_eq!(arr.len(), 3);
         assert_eq!(arr[0]["type"], "function");
         assert_eq!(arr[0]["function"]["name"], "product_search");
         assert_eq!(arr[1]["function"]["name"], "fact_finder");
@@ -270,15 +444,51 @@ mod tests {
         let arr = gemini_format.as_array().unwrap();
         assert_eq!(arr.len(), 1);
         let funcs = arr[0]["function_declarations"].as_array().unwrap();
-        assert_eq!(funcs.len(), 2);
+        assert_eq!(funcs.len(), 3);
         assert_eq!(funcs[0]["name"], "product_search");
         assert_eq!(funcs[1]["name"], "fact_finder");
     }
 
+    #[test]
+    fn test_to_anthropic_tools() {
+        let tools = get_chat_tools();
+        let anthropic_format = to_anthropic_tools(&tools);
+        let arr = anthropic_format.as_array().unwrap();
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr[0]["name"], "product_search");
+        assert_eq!(arr[0]["input_schema"]["type"], "object");
+    }
+
     #[test]
     fn test_fact_finder_tool_definition() {
         let tool = fact_finder_tool();
         assert_eq!(tool.name, "fact_finder");
         assert!(tool.description.contains("FAQ"));
     }
+
+    #[test]
+    fn test_customer_chat_tools() {
+        let tools = get_customer_chat_tools();
+        let names: Vec<&str> = tools.iter().map(|t| t.name).collect();
+        assert_eq!(
+            names,
+            vec!["order_lookup", "start_return", "subscription_status"]
+        );
+        assert!(names.iter().all(|n| is_customer_tool(n)));
+        assert!(!is_customer_tool("product_search"));
+        // The customer is never a tool argument; it comes from the session
+        for tool in &tools {
+            let props = tool.parameters["properties"].as_object().unwrap();
+            assert!(!props
+                .keys()
+                .any(|k| k.contains("customer") || k.contains("user")));
+        }
+    }
+
+    #[test]
+    fn test_request_human_agent_tool_definition() {
+        let tool = request_human_agent_tool();
+        assert_eq!(tool.name, REQUEST_HUMAN_AGENT);
+        assert!(tool.parameters["required"].is_null());
+    }
 }
@@ -0,0 +1,366 @@
+//! Token usage accounting and per-tenant monthly budgets.
+//!
+//! Every AI call made for a tenant adds its token counts and priced cost to
+//! the tenant's row for the month, task and model. Before a call, the month's
+//! spend is compared to the tenant's budget: a `warn` budget logs and counts
+//! the overrun, a `block` budget refuses the call.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config::{AiModelPrice, AiPricingConfig};
+use crate::observability::{record_ai_budget_exceeded, record_ai_tokens};
+use crate::storage::{AiUsageRecord, StorageResult, Store};
+
+use super::{model_to_string, provider_to_string, AiEndpoint, AiError};
+
+/// Built-in list prices: model id, USD per million input and output tokens
+const DEFAULT_PRICES: &[(&str, f64, f64)] = &[
+    ("gpt-4o", 2.50, 10.00),
+    ("o1", 15.00, 60.00),
+    ("o3", 2.00, 8.00),
+    ("text-embedding-3-small", 0.02, 0.0),
+    ("text-embedding-3-large", 0.13, 0.0),
+    ("gemini-2.5-flash-preview-05-20", 0.30, 2.50),
+    ("gemini-2.5-pro-preview-05-06", 1.25, 10.00),
+    ("claude-sonnet-4-5", 3.00, 15.00),
+    ("claude-haiku-4-5", 1.00, 5.00),
+    ("claude-opus-4-1", 15.00, 75.00),
+];
+
+/// Tokens consumed by one AI call
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+}
+
+impl TokenUsage {
+    /// `usage` object of Chat Completions and Embeddings responses
+    pub fn from_openai(usage: &Value) -> Self {
+        Self {
+            input_tokens: count(usage, "prompt_tokens"),
+            output_tokens: count(usage, "completion_tokens"),
+        }
+    }
+
+    /// `usageMetadata` object of Gemini responses
+    pub fn from_gemini(metadata: &Value) -> Self {
+        Self {
+            input_tokens: count(metadata, "promptTokenCount"),
+            output_tokens: count(metadata, "candidatesTokenCount"),
+        }
+    }
+
+    /// `usage` object of Anthropic Messages responses
+    pub fn from_anthropic(usage: &Value) -> Self {
+        Self {
+            input_tokens: count(usage, "input_tokens"),
+            output_tokens: count(usage, "output_tokens"),
+        }
+    }
+}
+
+fn count(value: &Value, key: &str) -> u64 {
+    value.get(key).and_then(Value::as_u64).unwrap_or(0)
+}
+
+/// What happens once a tenant's monthly spend reaches its budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetMode {
+    /// Log and count the overrun; AI features keep working
+    #[default]
+    Warn,
+    /// Refuse further AI calls until the next month
+    Block,
+}
+
+impl BudgetMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BudgetMode::Warn => "warn",
+            BudgetMode::Block => "block",
+        }
+    }
+}
+
+impl std::str::FromStr for BudgetMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "warn" => Ok(BudgetMode::Warn),
+            "block" => Ok(BudgetMode::Block),
+            other => Err(format!("Unknown budget mode: {}", other)),
+        }
+    }
+}
+
+/// A tenant's monthly AI spending limit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AiBudget {
+    pub monthly_limit_usd: f64,
+    pub mode: BudgetMode,
+}
+
+/// Model prices: configured entries over the built-in list prices
+#[derive(Debug, Clone)]
+pub struct AiPricing {
+    models: HashMap<String, AiModelPrice>,
+}
+
+impl AiPricing {
+    pub fn new(config: &AiPricingConfig) -> Self {
+        let mut models: HashMap<String, AiModelPrice> = DEFAULT_PRICES
+            .iter()
+            .map(|(model, input, output)| {
+                (
+                    model.to_string(),
+                    AiModelPrice {
+                        input_per_million: *input,
+                        output_per_million: *output,
+                    },
+                )
+            })
+            .collect();
+        models.extend(config.models.iter().map(|(k, v)| (k.clone(), *v)));
+        Self { models }
+    }
+
+    pub fn price(&self, model: &str) -> Option<AiModelPrice> {
+        self.models.get(model).copied()
+    }
+
+    /// Cost of `usage` in millionths of a dollar; zero for unpriced models.
+    ///
+    /// A price per million tokens is exactly the cost per token in micro-dollars.
+    pub fn cost_micros(&self, model: &str, usage: TokenUsage) -> i64 {
+        self.price(model).map_or(0, |price| {
+            (usage.input_tokens as f64 * price.input_per_million
+                + usage.output_tokens as f64 * price.output_per_million)
+                .round() as i64
+        })
+    }
+}
+
+impl Default for AiPricing {
+    fn default() -> Self {
+        Self::new(&AiPricingConfig::default())
+    }
+}
+
+/// Accounting period (calendar month, UTC) containing `at`, as `YYYY-MM`
+pub fn usage_period(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m").to_string()
+}
+
+pub fn micros_to_usd(micros: i64) -> f64 {
+    micros as f64 / 1_000_000.0
+}
+
+/// Records usage and enforces budgets for the calls of an [`super::AiService`]
+pub struct AiUsageTracker {
+    store: Arc<dyn Store>,
+    pricing: AiPricing,
+}
+
+impl AiUsageTracker {
+    pub fn new(store: Arc<dyn Store>, pricing: AiPricing) -> Self {
+        Self { store, pricing }
+    }
+
+    /// Total cost of a tenant's AI usage this month, in micro-dollars
+    pub async fn month_spend_micros(&self, tenant_id: &str) -> StorageResult<i64> {
+        let rows = self
+            .store
+            .list_ai_usage(tenant_id, &usage_period(Utc::now()))
+            .await?;
+        Ok(rows.iter().map(|r| r.cost_micros).sum())
+    }
+
+    /// Refuse the call when the endpoint's tenant has spent its `block` budget.
+    ///
+    /// Fails open: if the month's spend cannot be loaded the call proceeds.
+    pub async fn check_budget(&self, endpoint: &AiEndpoint) -> Result<(), AiError> {
+        let (Some(tenant_id), Some(budget)) = (endpoint.tenant_id.as_deref(), endpoint.budget)
+        else {
+            return Ok(());
+        };
+        let spent = match self.month_spend_micros(tenant_id).await {
+            Ok(spent) => spent,
+            Err(e) => {
+                tracing::warn!(error = %e, tenant_id = %tenant_id, "Failed to load AI spend for budget check");
+                return Ok(());
+            }
+        };
+        if micros_to_usd(spent) < budget.monthly_limit_usd {
+            return Ok(());
+        }
+
+        record_ai_budget_exceeded(budget.mode.as_str());
+        match budget.mode {
+            BudgetMode::Warn => {
+                tracing::warn!(
+                    tenant_id = %tenant_id,
+                    spent_usd = micros_to_usd(spent),
+                    budget_usd = budget.monthly_limit_usd,
+                    "Tenant is over its monthly AI budget"
+                );
+                Ok(())
+            }
+            BudgetMode::Block => Err(AiError::BudgetExceeded(format!(
+                "monthly AI budget of ${:.2} reached",
+                budget.monthly_limit_usd
+            ))),
+        }
+    }
+
+    /// Add a completed call to the tenant's usage for the month
+    pub async fn record(&self, endpoint: &AiEndpoint, task: &str, usage: TokenUsage) {
+        record_ai_tokens(
+            provider_to_string(endpoint.provider),
+            model_to_string(endpoint.model),
+            task,
+            usage.input_tokens,
+            usage.output_tokens,
+        );
+        let Some(tenant_id) = endpoint.tenant_id.clone() else {
+            return;
+        };
+
+        let model = endpoint.model_id().to_string();
+        let now = Utc::now();
+        let record = AiUsageRecord {
+            tenant_id,
+            period: usage_period(now),
+            task: task.to_string(),
+            cost_micros: self.pricing.cost_micros(&model, usage),
+            model,
+            calls: 1,
+            input_tokens: usage.input_tokens as i64,
+            output_tokens: usage.output_tokens as i64,
+            updated_at: now,
+        };
+        if let Err(e) = self.store.add_ai_usage(record).await {
+            tracing::warn!(error = %e, task = %task, "Failed to record AI usage");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::admin_ai::{AiModel, AiProvider};
+    use crate::storage::InMemoryStore;
+    use serde_json::json;
+
+    fn tracker() -> (Arc<InMemoryStore>, AiUsageTracker) {
+        let store = Arc::new(InMemoryStore::new());
+        let mut config = AiPricingConfig::default();
+        config.models.insert(
+            "gpt-4o".to_string(),
+            AiModelPrice {
+                input_per_million: 5.0,
+                output_per_million: 20.0,
+            },
+        );
+        let tracker = AiUsageTracker::new(store.clone(), AiPricing::new(&config));
+        (store, tracker)
+    }
+
+    fn tenant_endpoint(budget: Option<AiBudget>) -> AiEndpoint {
+        AiEndpoint::new(AiProvider::Openai, AiModel::OpenAi4o, "sk-test")
+            .with_tenant("tenant-a", budget)
+    }
+
+    #[test]
+    fn test_parse_provider_usage() {
+        let openai = json!({"prompt_tokens": 12, "completion_tokens": 3, "total_tokens": 15});
+        assert_eq!(
+            TokenUsage::from_openai(&openai),
+            TokenUsage {
+                input_tokens: 12,
+                output_tokens: 3
+            }
+        );
+        // Embeddings responses report prompt tokens only
+        assert_eq!(
+            TokenUsage::from_openai(&json!({"prompt_tokens": 8})).output_tokens,
+            0
+        );
+        let gemini = json!({"promptTokenCount": 40, "candidatesTokenCount": 7});
+        assert_eq!(TokenUsage::from_gemini(&gemini).input_tokens, 40);
+        assert_eq!(TokenUsage::from_gemini(&gemini).output_tokens, 7);
+        let anthropic = json!({"input_tokens": 9, "output_tokens": 2});
+        assert_eq!(TokenUsage::from_anthropic(&anthropic).output_tokens, 2);
+        assert_eq!(TokenUsage::from_openai(&Value::Null), TokenUsage::default());
+    }
+
+    #[test]
+    fn test_pricing_overrides_defaults() {
+        let (_, tracker) = tracker();
+        let usage = TokenUsage {
+            input_tokens: 1_000,
+            output_tokens: 100,
+        };
+        // Configured: 1000 * 5 + 100 * 20 micro-dollars
+        assert_eq!(tracker.pricing.cost_micros("gpt-4o", usage), 7_000);
+        // Built-in list price
+        assert_eq!(
+            tracker.pricing.cost_micros("claude-haiku-4-5", usage),
+            1_500
+        );
+        assert_eq!(tracker.pricing.cost_micros("llama-local", usage), 0);
+        assert_eq!(
+            usage_period("2026-03-31T23:59:59Z".parse().unwrap()),
+            "2026-03"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_record_and_block_budget() {
+        let (store, tracker) = tracker();
+        let usage = TokenUsage {
+            input_tokens: 100_000,
+            output_tokens: 10_000,
+        };
+        let block = AiBudget {
+            monthly_limit_usd: 1.0,
+            mode: BudgetMode::Block,
+        };
+        let endpoint = tenant_endpoint(Some(block));
+
+        tracker.check_budget(&endpoint).await.unwrap();
+        tracker.record(&endpoint, "chat", usage).await;
+        tracker.record(&endpoint, "chat", usage).await;
+
+        let rows = store
+            .list_ai_usage("tenant-a", &usage_period(Utc::now()))
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].calls, 2);
+        assert_eq!(rows[0].model, "gpt-4o");
+        // 2 * (100k * 5 + 10k * 20) = $1.40
+        assert_eq!(rows[0].cost_micros, 1_400_000);
+
+        assert!(matches!(
+            tracker.check_budget(&endpoint).await,
+            Err(AiError::BudgetExceeded(_))
+        ));
+        let warn = AiBudget {
+            mode: BudgetMode::Warn,
+            ..block
+        };
+        tracker
+            .check_budget(&tenant_endpoint(Some(warn)))
+            .await
+            .unwrap();
+        tracker.check_budget(&tenant_endpoint(None)).await.unwrap();
+    }
+}
@@ -0,0 +1,1012 @@
+//! Cold-storage archival of payments, orders, order history and webhooks.
+//!
+//! Records past `storage.archival.retention_period` are written to the
+//! tenant's object storage bucket (the one configured for images) as
+//! gzip-compressed JSONL, partitioned by tenant and month:
+//!
+//! ```text
+//! {tenant}/archive/{yyyy}/{mm}/{kind}-{batch}.jsonl.gz
+//! {tenant}/archive/{yyyy}/{mm}/manifest.json
+//! ```
+//!
+//! Each partition manifest lists its files with record counts and SHA-256
+//! checksums. Rows are deleted only after their files and manifests have been
+//! written, and archived payments stay indexed by signature so they can be
+//! looked up later.
+
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use aws_sdk_s3::primitives::ByteStream;
+use chrono::{DateTime, Datelike, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::models::PaymentTransaction;
+use crate::services::image_storage::{ImageStorageError, ImageStorageService};
+use crate::storage::{
+    ArchivableRecords, ArchivePurge, ArchivedPaymentRef, Purchase, StorageError, Store,
+};
+use crate::x402::utils::hex_encode;
+
+/// Key segment under the tenant prefix that holds all archive objects.
+const ARCHIVE_PREFIX: &str = "archive";
+
+/// Manifest object name within a partition.
+const MANIFEST_NAME: &str = "manifest.json";
+
+/// Upper bound on batches per tenant per run, so one large tenant cannot
+/// starve the others.
+const MAX_BATCHES_PER_TENANT: usize = 50;
+
+#[derive(Debug, Error)]
+pub enum ColdArchiveError {
+    #[error("object storage not configured: {0}")]
+    NotConfigured(String),
+
+    #[error("object storage error: {0}")]
+    ObjectStore(String),
+
+    #[error("checksum mismatch for {0}")]
+    ChecksumMismatch(String),
+
+    #[error("archive format error: {0}")]
+    Format(String),
+
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+}
+
+impl From<ImageStorageError> for ColdArchiveError {
+    fn from(e: ImageStorageError) -> Self {
+        match e {
+            ImageStorageError::NotConfigured(msg) => ColdArchiveError::NotConfigured(msg),
+            other => ColdArchiveError::ObjectStore(other.to_string()),
+        }
+    }
+}
+
+/// Object storage the archiver writes to, scoped per tenant.
+#[async_trait]
+pub trait ArchiveObjectStore: Send + Sync {
+    /// Tenants that have a bucket configured.
+    async fn archive_tenants(&self) -> Result<Vec<String>, ColdArchiveError>;
+    async fn put_object(
+        &self,
+        tenant_id: &str,
+        key: &str,
+        body: Vec<u8>,
+        content_type: &str,
+    ) -> Result<(), ColdArchiveError>;
+    /// Returns `None` if the object does not exist.
+    async fn get_object(
+        &self,
+        tenant_id: &str,
+        key: &str,
+    ) -> Result<Option<Vec<u8>>, ColdArchiveError>;
+}
+
+#[async_trait]
+impl ArchiveObjectStore for ImageStorageService {
+    async fn archive_tenants(&self) -> Result<Vec<String>, ColdArchiveError> {
+        Ok(self.configured_tenants().await?)
+    }
+
+    async fn put_object(
+        &self,
+        tenant_id: &str,
+        key: &str,
+        body: Vec<u8>,
+        content_type: &str,
+    ) -> Result<(), ColdArchiveError> {
+        let (client, bucket, _) = self.get_client(tenant_id).await?;
+        client
+            .put_object()
+            .bucket(&bucket)
+            .key(key)
+            .body(ByteStream::from(body))
+            .content_type(content_type)
+            .send()
+            .await
+            .map_err(|e| ColdArchiveError::ObjectStore(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn get_object(
+        &self,
+        tenant_id: &str,
+        key: &str,
+    ) -> Result<Option<Vec<u8>>, ColdArchiveError> {
+        let (client, bucket, _) = self.get_client(tenant_id).await?;
+        let output = match client.get_object().bucket(&bucket).key(key).send().await {
+            Ok(output) => output,
+            Err(e) if e.as_service_error().is_some_and(|se| se.is_no_such_key()) => {
+                return Ok(None)
+            }
+            Err(e) => return Err(ColdArchiveError::ObjectStore(e.to_string())),
+        };
+        let body = output
+            .body
+            .collect()
+            .await
+            .map_err(|e| ColdArchiveError::ObjectStore(e.to_string()))?;
+        Ok(Some(body.into_bytes().to_vec()))
+    }
+}
+
+/// In-process object store for tests and local runs without S3.
+#[derive(Default)]
+pub struct InMemoryObjectStore {
+    tenants: Vec<String>,
+    objects: Mutex<BTreeMap<(String, String), Vec<u8>>>,
+}
+
+impl InMemoryObjectStore {
+    pub fn new(tenants: Vec<String>) -> Self {
+        Self {
+            tenants,
+            objects: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Keys stored for a tenant, in lexical order.
+    pub fn keys(&self, tenant_id: &str) -> Vec<String> {
+        self.objects
+            .lock()
+            .keys()
+            .filter(|(t, _)| t == tenant_id)
+            .map(|(_, k)| k.clone())
+            .collect()
+    }
+
+    /// Overwrite an object, e.g. to simulate corruption in tests.
+    pub fn replace_object(&self, tenant_id: &str, key: &str, body: Vec<u8>) {
+        self.objects
+            .lock()
+            .insert((tenant_id.to_string(), key.to_string()), body);
+    }
+}
+
+#[async_trait]
+impl ArchiveObjectStore for InMemoryObjectStore {
+    async fn archive_tenants(&self) -> Result<Vec<String>, ColdArchiveError> {
+        Ok(self.tenants.clone())
+    }
+
+    async fn put_object(
+        &self,
+        tenant_id: &str,
+        key: &str,
+        body: Vec<u8>,
+        _content_type: &str,
+    ) -> Result<(), ColdArchiveError> {
+        if !self.tenants.iter().any(|t| t == tenant_id) {
+            return Err(ColdArchiveError::NotConfigured(format!(
+                "no bucket for tenant {}",
+                tenant_id
+            )));
+        }
+        self.objects
+            .lock()
+            .insert((tenant_id.to_string(), key.to_string()), body);
+        Ok(())
+    }
+
+    async fn get_object(
+        &self,
+        tenant_id: &str,
+        key: &str,
+    ) -> Result<Option<Vec<u8>>, ColdArchiveError> {
+        Ok(self
+            .objects
+            .lock()
+            .get(&(tenant_id.to_string(), key.to_string()))
+            .cloned())
+    }
+}
+
+/// Kind of records held by an archive file.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ArchiveKind {
+    Payments,
+    Orders,
+    OrderHistory,
+    Webhooks,
+}
+
+impl ArchiveKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArchiveKind::Payments => "payments",
+            ArchiveKind::Orders => "orders",
+            ArchiveKind::OrderHistory => "order_history",
+            ArchiveKind::Webhooks => "webhooks",
+        }
+    }
+}
+
+/// One compressed JSONL file listed in a partition manifest.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveFile {
+    pub key: String,
+    pub kind: ArchiveKind,
+    pub records: usize,
+    /// Size of the compressed object in bytes.
+    pub bytes: usize,
+    /// Hex SHA-256 of the compressed object.
+    pub sha256: String,
+    pub archived_at: DateTime<Utc>,
+}
+
+/// Index of the archive files in one tenant/month partition.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveManifest {
+    pub tenant_id: String,
+    pub year: i32,
+    pub month: u32,
+    pub files: Vec<ArchiveFile>,
+}
+
+/// Totals for one archival run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArchiveRunSummary {
+    pub tenants: usize,
+    pub files: usize,
+    pub records: usize,
+    /// Database rows removed after archiving.
+    pub deleted: u64,
+}
+
+/// A purchase fetched back from cold storage.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedPurchase {
+    pub purchase: Purchase,
+    pub object_key: String,
+    pub archived_at: DateTime<Utc>,
+    pub sha256: String,
+}
+
+/// Moves records past retention from the store to object storage.
+pub struct ColdArchiveService {
+    store: Arc<dyn Store>,
+    objects: Arc<dyn ArchiveObjectStore>,
+    batch_size: i64,
+}
+
+impl ColdArchiveService {
+    pub fn new(
+        store: Arc<dyn Store>,
+        objects: Arc<dyn ArchiveObjectStore>,
+        batch_size: i64,
+    ) -> Self {
+        Self {
+            store,
+            objects,
+            batch_size: batch_size.max(1),
+        }
+    }
+
+    /// Archive and remove every tenant's records older than `cutoff`.
+    ///
+    /// A tenant whose bucket is missing or unreachable keeps its rows and is
+    /// retried on the next run; other tenants are unaffected.
+    pub async fn archive_older_than(
+        &self,
+        cutoff: DateTime<Utc>,
+    ) -> Result<ArchiveRunSummary, ColdArchiveError> {
+        let mut summary = ArchiveRunSummary::default();
+        for tenant_id in self.objects.archive_tenants().await? {
+            match self.archive_tenant(&tenant_id, cutoff, &mut summary).await {
+                Ok(()) => {}
+                Err(ColdArchiveError::NotConfigured(reason)) => {
+                    tracing::debug!(tenant_id = %tenant_id, reason = %reason, "Skipping cold archival");
+                }
+                Err(e) => {
+                    tracing::warn!(tenant_id = %tenant_id, error = %e, "Cold archival failed for tenant");
+                }
+            }
+        }
+        Ok(summary)
+    }
+
+    async fn archive_tenant(
+        &self,
+        tenant_id: &str,
+        cutoff: DateTime<Utc>,
+        summary: &mut ArchiveRunSummary,
+    ) -> Result<(), ColdArchiveError> {
+        let mut archived_any = false;
+        for _ in 0..MAX_BATCHES_PER_TENANT {
+            let records = self
+                .store
+                .list_archivable_records(tenant_id, cutoff, self.batch_size)
+                .await?;
+            if records.is_empty() {
+                break;
+            }
+            let limit = self.batch_size as usize;
+            let more = records.payments.len() >= limit
+                || records.orders.len() >= limit
+                || records.webhooks.len() >= limit;
+
+            let (files, purge) = self.write_batch(tenant_id, &records).await?;
+            summary.files += files;
+            summary.records += records.len();
+            summary.deleted += self.store.purge_archived_records(tenant_id, &purge).await?;
+            archived_any = true;
+
+            if !more {
+                break;
+            }
+        }
+        if archived_any {
+            summary.tenants += 1;
+        }
+        Ok(())
+    }
+
+    /// Write one batch as per-partition files, then update the manifests.
+    async fn write_batch(
+        &self,
+        tenant_id: &str,
+        records: &ArchivableRecords,
+    ) -> Result<(usize, ArchivePurge), ColdArchiveError> {
+        let archived_at = Utc::now();
+        let batch_id = format!(
+            "{}-{}",
+            archived_at.format("%Y%m%dT%H%M%SZ"),
+            &uuid::Uuid::new_v4().simple().to_string()[..8]
+        );
+        let mut writer = BatchWriter {
+            objects: self.objects.as_ref(),
+            tenant_id,
+            batch_id: &batch_id,
+            archived_at,
+            manifests: BTreeMap::new(),
+        };
+
+        let mut purge = ArchivePurge::default();
+        for (partition, payments) in partition_by(&records.payments, |p| p.created_at) {
+            let key = writer
+                .write(partition, ArchiveKind::Payments, &payments)
+                .await?;
+            purge
+                .payments
+                .extend(payments.iter().map(|p| ArchivedPaymentRef {
+                    tenant_id: tenant_id.to_string(),
+                    signature: p.signature.clone(),
+                    object_key: key.clone(),
+                    archived_at,
+                }));
+        }
+        for (partition, orders) in partition_by(&records.orders, |o| o.created_at) {
+            writer
+                .write(partition, ArchiveKind::Orders, &orders)
+                .await?;
+        }
+        for (partition, history) in partition_by(&records.order_history, |h| h.created_at) {
+            writer
+                .write(partition, ArchiveKind::OrderHistory, &history)
+                .await?;
+        }
+        for (partition, webhooks) in partition_by(&records.webhooks, |w| {
+            w.completed_at.unwrap_or(w.created_at)
+        }) {
+            writer
+                .write(partition, ArchiveKind::Webhooks, &webhooks)
+                .await?;
+        }
+
+        let files = writer.manifests.values().map(Vec::len).sum();
+        for ((year, month), new_files) in writer.manifests {
+            let key = manifest_key(tenant_id, year, month);
+            let mut manifest = self
+                .load_manifest(tenant_id, &key)
+                .await?
+                .unwrap_or_else(|| ArchiveManifest {
+                    tenant_id: tenant_id.to_string(),
+                    year,
+                    month,
+                    files: Vec::new(),
+                });
+            manifest.files.extend(new_files);
+            let body = serde_json::to_vec_pretty(&manifest)
+                .map_err(|e| ColdArchiveError::Format(e.to_string()))?;
+            self.objects
+                .put_object(tenant_id, &key, body, "application/json")
+                .await?;
+        }
+
+        purge.order_ids = records.orders.iter().map(|o| o.id.clone()).collect();
+        purge.webhook_ids = records.webhooks.iter().map(|w| w.id.clone()).collect();
+        Ok((files, purge))
+    }
+
+    async fn load_manifest(
+        &self,
+        tenant_id: &str,
+        key: &str,
+    ) -> Result<Option<ArchiveManifest>, ColdArchiveError> {
+        match self.objects.get_object(tenant_id, key).await? {
+            Some(body) => serde_json::from_slice(&body)
+                .map(Some)
+                .map_err(|e| ColdArchiveError::Format(format!("manifest {}: {}", key, e))),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetch an archived purchase by signature, verifying the file checksum
+    /// against its partition manifest.
+    pub async fn find_purchase(
+        &self,
+        tenant_id: &str,
+        signature: &str,
+    ) -> Result<Option<ArchivedPurchase>, ColdArchiveError> {
+        let Some(location) = self
+            .store
+            .find_archived_payment(tenant_id, signature)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let manifest_key = manifest_key_for(&location.object_key).ok_or_else(|| {
+            ColdArchiveError::Format(format!("unexpected object key {}", location.object_key))
+        })?;
+        let manifest = self
+            .load_manifest(tenant_id, &manifest_key)
+            .await?
+            .ok_or_else(|| ColdArchiveError::Format(format!("missing {}", manifest_key)))?;
+        let file = manifest
+            .files
+            .into_iter()
+            .find(|f| f.key == location.object_key)
+            .ok_or_else(|| {
+                ColdArchiveError::Format(format!(
+                    "{} not listed in {}",
+                    location.object_key, manifest_key
+                ))
+            })?;
+
+        let body = self
+            .objects
+            .get_object(tenant_id, &location.object_key)
+            .await?
+            .ok_or_else(|| ColdArchiveError::Format(format!("missing {}", location.object_key)))?;
+        if sha256_hex(&body) != file.sha256 {
+            return Err(ColdArchiveError::ChecksumMismatch(location.object_key));
+        }
+
+        let payments: Vec<PaymentTransaction> = decode_jsonl(&body)?;
+        Ok(payments
+            .into_iter()
+            .find(|p| p.tenant_id == tenant_id && p.signature == signature)
+            .map(|tx| ArchivedPurchase {
+                purchase: Purchase {
+                    signature: tx.signature,
+                    tenant_id: tx.tenant_id,
+                    resource_id: tx.resource_id,
+                    wallet: (!tx.wallet.is_empty()).then_some(tx.wallet),
+                    user_id: tx.user_id,
+                    amount: tx.amount.to_major().to_string(),
+                    paid_at: tx.created_at,
+                    metadata: Some(serde_json::to_value(&tx.metadata).unwrap_or_default()),
+                },
+                object_key: location.object_key,
+                archived_at: location.archived_at,
+                sha256: file.sha256,
+            }))
+    }
+}
+
+/// Writes the files of one batch and collects their manifest entries.
+struct BatchWriter<'a> {
+    objects: &'a dyn ArchiveObjectStore,
+    tenant_id: &'a str,
+    batch_id: &'a str,
+    archived_at: DateTime<Utc>,
+    manifests: BTreeMap<(i32, u32), Vec<ArchiveFile>>,
+}
+
+impl BatchWriter<'_> {
+    async fn write<T: Serialize>(
+        &mut self,
+        (year, month): (i32, u32),
+        kind: ArchiveKind,
+        records: &[&T],
+    ) -> Result<String, ColdArchiveError> {
+        let body = encode_jsonl(records)?;
+        let key = format!(
+            "{}/{}-{}.jsonl.gz",
+            partition_prefix(self.tenant_id, year, month),
+            kind.as_str(),
+            self.batch_id
+        );
+        let file = ArchiveFile {
+            key: key.clone(),
+            kind,
+            records: records.len(),
+            bytes: body.len(),
+            sha256: sha256_hex(&body),
+            archived_at: self.archived_at,
+        };
+        self.objects
+            .put_object(self.tenant_id, &key, body, "application/gzip")
+            .await?;
+        self.manifests.entry((year, month)).or_default().push(file);
+        Ok(key)
+    }
+}
+
+fn partition_prefix(tenant_id: &str, year: i32, month: u32) -> String {
+    format!("{}/{}/{:04}/{:02}", tenant_id, ARCHIVE_PREFIX, year, month)
+}
+
+fn manifest_key(tenant_id: &str, year: i32, month: u32) -> String {
+    format!(
+        "{}/{}",
+        partition_prefix(tenant_id, year, month),
+        MANIFEST_NAME
+    )
+}
+
+/// Manifest key of the partition holding `object_key`.
+fn manifest_key_for(object_key: &str) -> Option<String> {
+    let (partition, _) = object_key.rsplit_once('/')?;
+    Some(format!("{}/{}", partition, MANIFEST_NAME))
+}
+
+/// Group records by the (year, month) of their timestamp.
+fn partition_by<T>(
+    records: &[T],
+    timestamp: impl Fn(&T) -> DateTime<Utc>,
+) -> BTreeMap<(i32, u32), Vec<&T>> {
+    let mut partitions: BTreeMap<(i32, u32), Vec<&T>> = BTreeMap::new();
+    for record in records {
+        let at = timestamp(record);
+        partitions
+            .entry((at.year(), at.month()))
+            .or_default()
+            .push(record);
+    }
+    partitions
+}
+
+fn encode_jsonl<T: Serialize>(records: &[&T]) -> Result<Vec<u8>, ColdArchiveError> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    for record in records {
+        serde_json::to_writer(&mut encoder, record)
+            .map_err(|e| ColdArchiveError::Format(e.to_string()))?;
+        encoder
+            .write_all(b"\n")
+            .map_err(|e| ColdArchiveError::Format(e.to_string()))?;
+    }
+    encoder
+        .finish()
+        .map_err(|e| ColdArchiveError::Format(e.to_string()))
+}
+
+fn decode_jsonl<T: DeserializeOwned>(body: &[u8]) -> Result<Vec<T>, ColdArchiveError> {
+    let mut text = String::new();
+    GzDecoder::new(body)
+        .read_to_string(&mut text)
+        .map_err(|e| ColdArchiveError::Format(e.to_string()))?;
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(|e| ColdArchiveError::Format(e.to_string())))
+        .collect()
+}
+
+fn sha256_hex(body: &[u8]) -> String {
+    hex_encode(Sha256::digest(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use std::collections::HashMap;
+
+    use crate::models::{get_asset, Money, Order, OrderHistoryEntry};
+    use crate::storage::{InMemoryStore, PendingWebhook, WebhookStatus};
+
+    const TENANT: &str = "default";
+
+    fn payment(signature: &str, created_at: DateTime<Utc>) -> PaymentTransaction {
+        PaymentTransaction {
+            signature: signature.to_string(),
+            tenant_id: TENANT.to_string(),
+            resource_id: "res-1".to_string(),
+            wallet: "wallet-1".to_string(),
+            user_id: None,
+            amount: Money {
+                asset: get_asset("USDC").expect("USDC asset registered"),
+                atomic: 1_500_000,
+            },
+            created_at,
+            metadata: HashMap::new(),
+        }
+    }
+
+    fn order(id: &str, created_at: DateTime<Utc>) -> Order {
+        Order {
+            id: id.to_string(),
+            tenant_id: TENANT.to_string(),
+            source: "x402".to_string(),
+            purchase_id: format!("pur-{}", id),
+            resource_id: "res-1".to_string(),
+            user_id: None,
+            customer: None,
+            status: "paid".to_string(),
+            items: Vec::new(),
+            amount: 1500,
+            amount_asset: "USD".to_string(),
+            customer_email: None,
+            customer_name: None,
+            receipt_url: None,
+            shipping: None,
+            metadata: HashMap::new(),
+            created_at,
+            updated_at: None,
+            status_updated_at: None,
+        }
+    }
+
+    fn webhook(id: &str, completed_at: DateTime<Utc>) -> PendingWebhook {
+        PendingWebhook {
+            id: id.to_string(),
+            tenant_id: TENANT.to_string(),
+            url: "https://example.com/hook".to_string(),
+            payload: serde_json::json!({ "id": id }),
+            payload_bytes: Vec::new(),
+            headers: HashMap::new(),
+            event_type: "payment.succeeded".to_string(),
+            status: WebhookStatus::Success,
+            attempts: 1,
+            max_attempts: 3,
+            last_error: None,
+            last_attempt_at: Some(completed_at),
+            next_attempt_at: None,
+            created_at: completed_at,
+            completed_at: Some(completed_at),
+        }
+    }
+
+    async fn seeded_store(old: DateTime<Utc>) -> Arc<InMemoryStore> {
+        let store = Arc::new(InMemoryStore::new());
+        store
+            .record_payment(payment("sig-old", old))
+            .await
+            .expect("record old payment");
+        store
+            .record_payment(payment("sig-new", Utc::now()))
+            .await
+            .expect("record new payment");
+        store
+            .try_store_order(order("ord-old", old))
+            .await
+            .expect("store order");
+        store
+            .append_order_history(OrderHistoryEntry {
+                id: "hist-1".to_string(),
+                tenant_id: TENANT.to_string(),
+                order_id: "ord-old".to_string(),
+                from_status: "created".to_string(),
+                to_status: "paid".to_string(),
+                note: None,
+                actor: None,
+                created_at: old,
+            })
+            .await
+            .expect("append history");
+        store
+            .enqueue_webhook(webhook("wh-old", old))
+            .await
+            .expect("enqueue webhook");
+        store
+    }
+
+    #[tokio::test]
+    async fn test_archive_writes_partitioned_files_and_purges_rows() {
+        let old = Utc.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap();
+        let store = seeded_store(old).await;
+        let objects = Arc::new(InMemoryObjectStore::new(vec![TENANT.to_string()]));
+        let service = ColdArchiveService::new(store.clone(), objects.clone(), 100);
+
+        let summary = service
+            .archive_older_than(Utc::now() - Duration::days(30))
+            .await
+            .expect("archive");
+
+        assert_eq!(summary.tenants, 1);
+        assert_eq!(summary.files, 4);
+        assert_eq!(summary.records, 4);
+        assert_eq!(summary.deleted, 4);
+
+        let keys = objects.keys(TENANT);
+        assert!(keys.contains(&"default/archive/2024/03/manifest.json".to_string()));
+        for kind in ["payments", "orders", "order_history", "webhooks"] {
+            let prefix = format!("default/archive/2024/03/{}-", kind);
+            assert!(
+                keys.iter()
+                    .any(|k| k.starts_with(&prefix) && k.ends_with(".jsonl.gz")),
+                "missing {} file in {:?}",
+                kind,
+                keys
+            );
+        }
+
+        let manifest: ArchiveManifest = serde_json::from_slice(
+            &objects
+                .get_object(TENANT, "default/archive/2024/03/manifest.json")
+                .await
+                .unwrap()
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(manifest.files.len(), 4);
+        for file in &manifest.files {
+            let body = objects
+                .get_object(TENANT, &file.key)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(sha256_hex(&body), file.sha256);
+            assert_eq!(body.len(), file.bytes);
+            assert_eq!(file.records, 1);
+        }
+
+        assert!(store
+            .get_payment(TENANT, "sig-old")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(store
+            .get_payment(TENANT, "sig-new")
+            .await
+            .unwrap()
+            .is_some());
+        assert!(store.get_order(TENANT, "ord-old").await.unwrap().is_none());
+        assert!(store
+            .list_order_history(TENANT, "ord-old", 10)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(store.get_webhook("wh-old").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_purchase_reads_back_archived_payment() {
+        let old = Utc.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap();
+        let store = seeded_store(old).await;
+        let objects = Arc::new(InMemoryObjectStore::new(vec![TENANT.to_string()]));
+        let service = ColdArchiveService::new(store.clone(), objects.clone(), 100);
+        service
+            .archive_older_than(Utc::now() - Duration::days(30))
+            .await
+            .expect("archive");
+
+        let archived = service
+            .find_purchase(TENANT, "sig-old")
+            .await
+            .expect("lookup")
+            .expect("archived purchase");
+        assert_eq!(archived.purchase.signature, "sig-old");
+        assert_eq!(archived.purchase.paid_at, old);
+        assert_eq!(archived.purchase.amount, "1.5");
+        assert!(archived
+            .object_key
+            .starts_with("default/archive/2024/03/payments-"));
+
+        assert!(service
+            .find_purchase(TENANT, "sig-new")
+            .await
+            .expect("lookup")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_purchase_rejects_checksum_mismatch() {
+        let old = Utc.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap();
+        let store = seeded_store(old).await;
+        let objects = Arc::new(InMemoryObjectStore::new(vec![TENANT.to_string()]));
+        let service = ColdArchiveService::new(store.clone(), objects.clone(), 100);
+        service
+            .archive_older_than(Utc::now() - Duration::days(30))
+            .await
+            .expect("archive");
+
+        let location = store
+            .find_archived_payment(TENANT, "sig-old")
+            .await
+            .unwrap()
+            .expect("indexed");
+        objects.replace_object(TENANT, &location.object_key, b"tampered".to_vec());
+
+        let err = service
+            .find_purchase(TENANT, "sig-old")
+            .await
+            .expect_err("checksum mismatch");
+        assert!(matches!(err, ColdArchiveError::ChecksumMismatch(_)));
+    }
+
+    #[tokio::test]
+    async fn test_archive_batches_and_appends_to_existing_manifest() {
+        let old = Utc.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap();
+        let store = Arc::new(InMemoryStore::new());
+        for i in 0..5 {
+            store
+                .record_payment(payment(&format!("sig-{}", i), old + Duration::minutes(i)))
+                .await
+                .expect("record payment");
+        }
+        let objects = Arc::new(InMemoryObjectStore::new(vec![TENANT.to_string()]));
+        let service = ColdArchiveService::new(store.clone(), objects.clone(), 2);
+
+        let summary = service
+            .archive_older_than(Utc::now() - Duration::days(30))
+            .await
+            .expect("archive");
+        assert_eq!(summary.records, 5);
+        assert_eq!(summary.files, 3);
+
+        let manifest: ArchiveManifest = serde_json::from_slice(
+            &objects
+                .get_object(TENANT, "default/archive/2024/03/manifest.json")
+                .await
+                .unwrap()
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(manifest.files.len(), 3);
+        assert_eq!(manifest.files.iter().map(|f| f.records).sum::<usize>(), 5);
+        for i in 0..5 {
+            assert!(service
+                .find_purchase(TENANT, &format!("sig-{}", i))
+                .await
+                .expect("lookup")
+                .is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unconfigured_tenant_keeps_rows() {
+        let old = Utc.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap();
+        let store = seeded_store(old).await;
+        let objects = Arc::new(InMemoryObjectStore::new(Vec::new()));
+        let service = ColdArchiveService::new(store.clone(), objects.clone(), 100);
+
+        let summary = service
+            .archive_older_than(Utc::now() - Duration::days(30))
+            .await
+            .expect("archive");
+        assert_eq!(summary, ArchiveRunSummary::default());
+        assert!(store
+            .get_payment(TENANT, "sig-old")
+            .await
+            .unwrap()
+            .is_some());
+        assert!(objects.keys(TENANT).is_empty());
+    }
+
+    type S3Objects = Arc<Mutex<HashMap<String, (Vec<u8>, String)>>>;
+
+    fn s3_error(status: axum::http::StatusCode, code: &str) -> axum::response::Response {
+        use axum::response::IntoResponse;
+        let body = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Error><Code>{}</Code><Message>{}</Message></Error>",
+            code, code
+        );
+        (status, [("content-type", "application/xml")], body).into_response()
+    }
+
+    /// Path-style S3 stand-in for one bucket; keys under `forbidden/` are denied.
+    async fn spawn_s3_stand_in(bucket: &'static str) -> (String, S3Objects) {
+        use axum::body::Bytes;
+        use axum::extract::{Path, State};
+        use axum::http::{HeaderMap, StatusCode};
+        use axum::response::IntoResponse;
+        use axum::routing::put;
+        use axum::Router;
+
+        let objects: S3Objects = Arc::new(Mutex::new(HashMap::new()));
+        let app = Router::new()
+            .route(
+                "/{bucket}/{*key}",
+                put(
+                    move |Path((b, key)): Path<(String, String)>,
+                          State(objects): State<S3Objects>,
+                          headers: HeaderMap,
+                          body: Bytes| async move {
+                        if b != bucket {
+                            return s3_error(StatusCode::NOT_FOUND, "NoSuchBucket");
+                        }
+                        let content_type = headers
+                            .get("content-type")
+                            .and_then(|v| v.to_str().ok())
+                            .unwrap_or_default()
+                            .to_string();
+                        objects.lock().insert(key, (body.to_vec(), content_type));
+                        (StatusCode::OK, [("etag", "\"stand-in\"")]).into_response()
+                    },
+                )
+                .get(
+                    move |Path((b, key)): Path<(String, String)>,
+                          State(objects): State<S3Objects>| async move {
+                        if b != bucket {
+                            return s3_error(StatusCode::NOT_FOUND, "NoSuchBucket");
+                        }
+                        if key.starts_with("forbidden/") {
+                            return s3_error(StatusCode::FORBIDDEN, "AccessDenied");
+                        }
+                        match objects.lock().get(&key) {
+                            Some((body, _)) => (StatusCode::OK, body.clone()).into_response(),
+                            None => s3_error(StatusCode::NOT_FOUND, "NoSuchKey"),
+                        }
+                    },
+                ),
+            )
+            .with_state(objects.clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        (format!("http://{}", addr), objects)
+    }
+
+    #[tokio::test]
+    async fn test_s3_object_store_put_get_and_missing_key() {
+        let (endpoint, objects) = spawn_s3_stand_in("archive-bucket").await;
+        let config = aws_sdk_s3::Config::builder()
+            .region(aws_sdk_s3::config::Region::new("us-east-1"))
+            .credentials_provider(aws_credential_types::Credentials::new(
+                "test", "test", None, None, "test",
+            ))
+            .endpoint_url(endpoint)
+            .force_path_style(true)
+            .behavior_version_latest()
+            .build();
+        let service = ImageStorageService::with_client(
+            TENANT,
+            aws_sdk_s3::Client::from_conf(config),
+            "archive-bucket",
+        );
+
+        let key = "default/archive/2024/03/payments-0.jsonl.gz";
+        let record = serde_json::json!({ "signature": "sig-1" });
+        let body = encode_jsonl(&[&record]).unwrap();
+        service
+            .put_object(TENANT, key, body.clone(), "application/gzip")
+            .await
+            .expect("put");
+        let (stored, content_type) = objects.lock().get(key).cloned().expect("stored");
+        assert_eq!(stored, body);
+        assert_eq!(content_type, "application/gzip");
+
+        let fetched = service.get_object(TENANT, key).await.expect("get");
+        assert_eq!(fetched, Some(body));
+
+        let missing = service
+            .get_object(TENANT, "default/archive/2024/03/manifest.json")
+            .await
+            .expect("missing key is not an error");
+        assert_eq!(missing, None);
+
+        let denied = service.get_object(TENANT, "forbidden/manifest.json").await;
+        assert!(matches!(denied, Err(ColdArchiveError::ObjectStore(_))));
+    }
+}
@@ -0,0 +1,1076 @@
+//! Financial period reports.
+//!
+//! Aggregates orders, executed refunds and lost or open disputes into
+//! day/week/month buckets per currency, optionally broken down by payment
+//! rail, product, collection or tenant. All amounts are atomic units of the
+//! row currency (cents for fiat, token base units for crypto), so assets are
+//! never mixed.
+//!
+//! Order totals are split using the breakdown recorded in order metadata
+//! ([`ORDER_DISCOUNT_AMOUNT_KEY`], [`ORDER_TAX_AMOUNT_KEY`],
+//! [`ORDER_SHIPPING_AMOUNT_KEY`], [`ORDER_GIFT_CARD_AMOUNT_KEY`]):
+//!
+//! - `gross_sales = collected + gift_cards + discounts - tax - shipping`
+//! - `net = collected - refunds - disputes`
+//!
+//! Orders are bucketed by creation time, refunds by processing time and
+//! disputes by creation time. Period boundaries are UTC; weeks start on Monday.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::constants::STRIPE_SIGNATURE_PREFIX;
+use crate::models::{
+    get_asset, Order, Product, ORDER_DISCOUNT_AMOUNT_KEY, ORDER_GIFT_CARD_AMOUNT_KEY,
+    ORDER_SHIPPING_AMOUNT_KEY, ORDER_TAX_AMOUNT_KEY,
+};
+use crate::repositories::ProductRepository;
+use crate::services::returns::{order_line_amounts, refund_method_for_order};
+use crate::storage::Store;
+
+/// Longest window a single report may cover.
+pub const MAX_REPORT_RANGE_DAYS: i64 = 400;
+
+/// Group label for amounts that cannot be attributed (e.g. refunds whose order
+/// was deleted, or products outside every collection).
+pub const UNASSIGNED_GROUP: &str = "unassigned";
+
+const PAGE_SIZE: i32 = 500;
+
+/// Dispute statuses that do not cost the merchant money.
+const NON_LOSS_DISPUTE_STATUSES: &[&str] = &["won", "warning_closed"];
+
+#[derive(Debug, Error)]
+pub enum FinancialReportError {
+    #[error("{0}")]
+    InvalidRange(String),
+    #[error("storage error: {0}")]
+    Storage(String),
+}
+
+/// Report bucket size.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportPeriod {
+    #[default]
+    Day,
+    Week,
+    Month,
+}
+
+impl ReportPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportPeriod::Day => "day",
+            ReportPeriod::Week => "week",
+            ReportPeriod::Month => "month",
+        }
+    }
+
+    /// Start (UTC midnight) of the period containing `at`.
+    pub fn start_of(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        let date = at.date_naive();
+        let date = match self {
+            ReportPeriod::Day => date,
+            ReportPeriod::Week => {
+                date - Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            ReportPeriod::Month => date - Duration::days(date.day0() as i64),
+        };
+        date.and_time(NaiveTime::MIN).and_utc()
+    }
+
+    /// Start of the period following the one that starts at `start`.
+    pub fn next_start(&self, start: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            ReportPeriod::Day => start + Duration::days(1),
+            ReportPeriod::Week => start + Duration::days(7),
+            ReportPeriod::Month => self.start_of(start + Duration::days(32)),
+        }
+    }
+
+    /// Bounds of the last complete period before `now`.
+    pub fn previous(&self, now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+        let end = self.start_of(now);
+        (self.start_of(end - Duration::seconds(1)), end)
+    }
+}
+
+/// Optional breakdown dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportGroupBy {
+    /// Payment rail: `stripe`, `x402` or `credits`
+    Rail,
+    Product,
+    Collection,
+    Tenant,
+}
+
+/// Per-tenant scheduled report settings, stored in `app_config`
+/// (category `reports`, key `financial_schedule`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinancialReportSchedule {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Report cadence; each email covers the previous complete period
+    #[serde(default)]
+    pub frequency: ReportPeriod,
+    #[serde(default)]
+    pub recipients: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<ReportGroupBy>,
+}
+
+/// Report window and layout.
+#[derive(Debug, Clone, Copy)]
+pub struct ReportParams {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub period: ReportPeriod,
+    pub group_by: Option<ReportGroupBy>,
+}
+
+/// Amounts for one bucket, in atomic units of the bucket currency.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportAmounts {
+    pub orders: i64,
+    pub gross_sales: i64,
+    pub discounts: i64,
+    pub tax: i64,
+    pub shipping: i64,
+    /// Paid with gift card balance (collected when the card was sold)
+    pub gift_cards: i64,
+    pub collected: i64,
+    pub refunds: i64,
+    pub disputes: i64,
+    pub net: i64,
+}
+
+impl ReportAmounts {
+    fn add(&mut self, other: &ReportAmounts) {
+        self.orders += other.orders;
+        self.gross_sales += other.gross_sales;
+        self.discounts += other.discounts;
+        self.tax += other.tax;
+        self.shipping += other.shipping;
+        self.gift_cards += other.gift_cards;
+        self.collected += other.collected;
+        self.refunds += other.refunds;
+        self.disputes += other.disputes;
+        self.net = self.collected - self.refunds - self.disputes;
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinancialReportRow {
+    pub tenant_id: String,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub currency: String,
+    /// Decimal places of `currency`, for converting atomic amounts
+    pub decimals: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(flatten)]
+    pub amounts: ReportAmounts,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinancialReportTotals {
+    pub currency: String,
+    pub decimals: u8,
+    #[serde(flatten)]
+    pub amounts: ReportAmounts,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinancialReport {
+    pub tenant_id: String,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub period: ReportPeriod,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<ReportGroupBy>,
+    pub rows: Vec<FinancialReportRow>,
+    /// Per-currency totals for the whole window
+    pub totals: Vec<FinancialReportTotals>,
+}
+
+/// One dated, grouped contribution to the report.
+#[derive(Debug, Clone)]
+struct Entry {
+    at: DateTime<Utc>,
+    currency: String,
+    group: Option<String>,
+    amounts: ReportAmounts,
+}
+
+/// Check a report window.
+pub fn validate_range(from: DateTime<Utc>, to: DateTime<Utc>) -> Result<(), FinancialReportError> {
+    if from >= to {
+        return Err(FinancialReportError::InvalidRange(
+            "from must be before to".to_string(),
+        ));
+    }
+    if to - from > Duration::days(MAX_REPORT_RANGE_DAYS) {
+        return Err(FinancialReportError::InvalidRange(format!(
+            "report range cannot exceed {} days",
+            MAX_REPORT_RANGE_DAYS
+        )));
+    }
+    Ok(())
+}
+
+/// Build a report for one tenant.
+///
+/// `products` is used to weight product and collection breakdowns by list
+/// price; without it order amounts are split evenly per unit.
+pub async fn build_financial_report(
+    store: &dyn Store,
+    products: Option<&dyn ProductRepository>,
+    tenant_id: &str,
+    params: ReportParams,
+) -> Result<FinancialReport, FinancialReportError> {
+    validate_range(params.from, params.to)?;
+    let storage = |e: crate::storage::StorageError| FinancialReportError::Storage(e.to_string());
+
+    let orders = load_orders(store, tenant_id, params.from, params.to).await?;
+    let refunds = store
+        .list_processed_refunds(tenant_id, params.from, params.to)
+        .await
+        .map_err(storage)?;
+    let stripe_refunds = store
+        .list_processed_stripe_refund_requests(tenant_id, params.from, params.to)
+        .await
+        .map_err(storage)?;
+    let disputes = load_disputes(store, tenant_id, params.from, params.to).await?;
+
+    // Orders referenced by refunds and disputes may predate the window.
+    let mut by_purchase: HashMap<String, Option<Order>> = orders
+        .iter()
+        .map(|o| (o.purchase_id.clone(), Some(o.clone())))
+        .collect();
+    let mut by_id: HashMap<String, Option<Order>> = orders
+        .iter()
+        .map(|o| (o.id.clone(), Some(o.clone())))
+        .collect();
+    let refund_purchase_ids = refunds
+        .iter()
+        .map(|r| r.original_purchase_id.clone())
+        .chain(stripe_refunds.iter().map(|r| {
+            r.original_purchase_id
+                .strip_prefix(STRIPE_SIGNATURE_PREFIX)
+                .unwrap_or(&r.original_purchase_id)
+                .to_string()
+        }));
+    for purchase_id in refund_purchase_ids.collect::<Vec<_>>() {
+        if by_purchase.contains_key(&purchase_id) {
+            continue;
+        }
+        let order = store
+            .get_order_by_purchase_id(tenant_id, &purchase_id)
+            .await
+            .map_err(storage)?;
+        by_purchase.insert(purchase_id, order);
+    }
+    for order_id in disputes.iter().filter_map(|d| d.order_id.clone()) {
+        if by_id.contains_key(&order_id) {
+            continue;
+        }
+        let order = store
+            .get_order(tenant_id, &order_id)
+            .await
+            .map_err(storage)?;
+        by_id.insert(order_id, order);
+    }
+
+    let needs_lines = matches!(
+        params.group_by,
+        Some(ReportGroupBy::Product | ReportGroupBy::Collection)
+    );
+    let catalog = match (needs_lines, products) {
+        (true, Some(repo)) => {
+            let mut ids: Vec<String> = orders
+                .iter()
+                .chain(by_purchase.values().flatten())
+                .chain(by_id.values().flatten())
+                .flat_map(|o| o.items.iter().map(|i| i.product_id.clone()))
+                .collect();
+            ids.sort();
+            ids.dedup();
+            load_products(repo, tenant_id, &ids).await?
+        }
+        _ => HashMap::new(),
+    };
+    let collections = if params.group_by == Some(ReportGroupBy::Collection) {
+        load_product_collections(store, tenant_id).await?
+    } else {
+        HashMap::new()
+    };
+    let grouper = Grouper {
+        tenant_id,
+        group_by: params.group_by,
+        catalog: &catalog,
+        collections: &collections,
+    };
+
+    let mut entries = Vec::new();
+    let mut totals: BTreeMap<String, ReportAmounts> = BTreeMap::new();
+    let mut push = |at, currency: &str, order, rail, amounts: ReportAmounts| {
+        totals
+            .entry(currency.to_uppercase())
+            .or_default()
+            .add(&amounts);
+        entries.extend(grouper.split(at, currency, order, rail, amounts));
+    };
+    for order in &orders {
+        push(
+            order.created_at,
+            &order.amount_asset,
+            Some(order),
+            None,
+            order_amounts(order),
+        );
+    }
+    for refund in &refunds {
+        let Some(at) = refund.processed_at else {
+            continue;
+        };
+        let order = by_purchase
+            .get(&refund.original_purchase_id)
+            .and_then(|o| o.as_ref());
+        let rail = if refund.original_purchase_id.starts_with("credits:") {
+            "credits"
+        } else {
+            "x402"
+        };
+        let amounts = ReportAmounts {
+            refunds: refund.amount.atomic,
+            ..Default::default()
+        };
+        push(at, &refund.amount.asset.code, order, Some(rail), amounts);
+    }
+    for refund in &stripe_refunds {
+        let Some(at) = refund.processed_at else {
+            continue;
+        };
+        let purchase_id = refund
+            .original_purchase_id
+            .strip_prefix(STRIPE_SIGNATURE_PREFIX)
+            .unwrap_or(&refund.original_purchase_id);
+        let order = by_purchase.get(purchase_id).and_then(|o| o.as_ref());
+        let amounts = ReportAmounts {
+            refunds: refund.amount,
+            ..Default::default()
+        };
+        push(at, &refund.currency, order, Some("stripe"), amounts);
+    }
+    for dispute in &disputes {
+        let order = dispute
+            .order_id
+            .as_ref()
+            .and_then(|id| by_id.get(id))
+            .and_then(|o| o.as_ref());
+        let amounts = ReportAmounts {
+            disputes: dispute.amount,
+            ..Default::default()
+        };
+        push(
+            dispute.created_at,
+            &dispute.currency,
+            order,
+            Some(&dispute.source),
+            amounts,
+        );
+    }
+
+    let rows = aggregate(tenant_id, params.period, entries);
+    let totals = totals
+        .into_iter()
+        .map(|(currency, amounts)| FinancialReportTotals {
+            decimals: currency_decimals(&currency),
+            currency,
+            amounts,
+        })
+        .collect();
+    Ok(FinancialReport {
+        tenant_id: tenant_id.to_string(),
+        from: params.from,
+        to: params.to,
+        period: params.period,
+        group_by: params.group_by,
+        rows,
+        totals,
+    })
+}
+
+/// Render report rows as CSV (one row per bucket, amounts in atomic units).
+pub fn report_to_csv(report: &FinancialReport) -> String {
+    let mut out = String::from(
+        "tenant_id,period_start,period_end,currency,decimals,group,orders,gross_sales,discounts,\
+         tax,shipping,gift_cards,collected,refunds,disputes,net\n",
+    );
+    for row in &report.rows {
+        let a = &row.amounts;
+        let fields = [
+            csv_field(&row.tenant_id),
+            row.period_start.format("%Y-%m-%d").to_string(),
+            row.period_end.format("%Y-%m-%d").to_string(),
+            csv_field(&row.currency),
+            row.decimals.to_string(),
+            csv_field(row.group.as_deref().unwrap_or("")),
+            a.orders.to_string(),
+            a.gross_sales.to_string(),
+            a.discounts.to_string(),
+            a.tax.to_string(),
+            a.shipping.to_string(),
+            a.gift_cards.to_string(),
+            a.collected.to_string(),
+            a.refunds.to_string(),
+            a.disputes.to_string(),
+            a.net.to_string(),
+        ];
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+pub(crate) fn csv_field(value: &str) -> String {
+    // Leading formula characters are neutralised so spreadsheets treat them as text.
+    let value = if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn order_amounts(order: &Order) -> ReportAmounts {
+    let discounts = order.metadata_amount(ORDER_DISCOUNT_AMOUNT_KEY);
+    let tax = order.metadata_amount(ORDER_TAX_AMOUNT_KEY);
+    let shipping = order.metadata_amount(ORDER_SHIPPING_AMOUNT_KEY);
+    let gift_cards = order.metadata_amount(ORDER_GIFT_CARD_AMOUNT_KEY);
+    ReportAmounts {
+        orders: 1,
+        gross_sales: order.amount + gift_cards + discounts - tax - shipping,
+        discounts,
+        tax,
+        shipping,
+        gift_cards,
+        collected: order.amount,
+        ..Default::default()
+    }
+}
+
+/// Assigns entries to breakdown groups.
+struct Grouper<'a> {
+    tenant_id: &'a str,
+    group_by: Option<ReportGroupBy>,
+    catalog: &'a HashMap<String, Product>,
+    collections: &'a HashMap<String, String>,
+}
+
+impl Grouper<'_> {
+    /// Split `amounts` across the groups of `order`. `rail` overrides the rail
+    /// derived from the order (refunds and disputes know their own rail).
+    fn split(
+        &self,
+        at: DateTime<Utc>,
+        currency: &str,
+        order: Option<&Order>,
+        rail: Option<&str>,
+        amounts: ReportAmounts,
+    ) -> Vec<Entry> {
+        let entry = |group: Option<String>, amounts: ReportAmounts| Entry {
+            at,
+            currency: currency.to_uppercase(),
+            group,
+            amounts,
+        };
+        match self.group_by {
+            None => vec![entry(None, amounts)],
+            Some(ReportGroupBy::Tenant) => vec![entry(Some(self.tenant_id.to_string()), amounts)],
+            Some(ReportGroupBy::Rail) => {
+                let rail = rail
+                    .map(str::to_string)
+                    .or_else(|| order.map(|o| refund_method_for_order(o).to_string()))
+                    .unwrap_or_else(|| UNASSIGNED_GROUP.to_string());
+                vec![entry(Some(rail), amounts)]
+            }
+            Some(ReportGroupBy::Product | ReportGroupBy::Collection) => {
+                let Some(order) = order else {
+                    return vec![entry(Some(UNASSIGNED_GROUP.to_string()), amounts)];
+                };
+                let mut weights: BTreeMap<String, i64> = BTreeMap::new();
+                for ((product_id, _), (line_total, qty)) in order_line_amounts(order, self.catalog)
+                {
+                    let group = if self.group_by == Some(ReportGroupBy::Collection) {
+                        self.collections
+                            .get(&product_id)
+                            .cloned()
+                            .unwrap_or_else(|| UNASSIGNED_GROUP.to_string())
+                    } else {
+                        product_id
+                    };
+                    // Zero-amount orders fall back to quantity weights.
+                    let weight = if order.amount > 0 {
+                        line_total
+                    } else {
+                        qty as i64
+                    };
+                    *weights.entry(group).or_insert(0) += weight.max(0);
+                }
+                if weights.is_empty() {
+                    return vec![entry(Some(UNASSIGNED_GROUP.to_string()), amounts)];
+                }
+                let weights: Vec<(String, i64)> = weights.into_iter().collect();
+                allocate(&amounts, &weights)
+                    .into_iter()
+                    .map(|(group, amounts)| entry(Some(group), amounts))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Split every amount proportionally to `weights`; the last group absorbs
+/// rounding so shares add up. Each group counts the order once.
+fn allocate(amounts: &ReportAmounts, weights: &[(String, i64)]) -> Vec<(String, ReportAmounts)> {
+    let total_weight: i128 = weights.iter().map(|(_, w)| *w as i128).sum();
+    let share = |value: i64, idx: usize, allocated: i64| -> i64 {
+        if idx + 1 == weights.len() {
+            value - allocated
+        } else if total_weight > 0 {
+            (value as i128 * weights[idx].1 as i128 / total_weight) as i64
+        } else {
+            0
+        }
+    };
+
+    let mut out = Vec::with_capacity(weights.len());
+    let mut allocated = ReportAmounts::default();
+    for (idx, (group, _)) in weights.iter().enumerate() {
+        let part = ReportAmounts {
+            orders: amounts.orders,
+            gross_sales: share(amounts.gross_sales, idx, allocated.gross_sales),
+            discounts: share(amounts.discounts, idx, allocated.discounts),
+            tax: share(amounts.tax, idx, allocated.tax),
+            shipping: share(amounts.shipping, idx, allocated.shipping),
+            gift_cards: share(amounts.gift_cards, idx, allocated.gift_cards),
+            collected: share(amounts.collected, idx, allocated.collected),
+            refunds: share(amounts.refunds, idx, allocated.refunds),
+            disputes: share(amounts.disputes, idx, allocated.disputes),
+            net: 0,
+        };
+        allocated.add(&part);
+        out.push((group.clone(), part));
+    }
+    out
+}
+
+fn currency_decimals(currency: &str) -> u8 {
+    get_asset(currency).map(|a| a.decimals).unwrap_or(0)
+}
+
+/// Bucket entries by (period, currency, group).
+fn aggregate(
+    tenant_id: &str,
+    period: ReportPeriod,
+    entries: Vec<Entry>,
+) -> Vec<FinancialReportRow> {
+    let mut buckets: BTreeMap<(DateTime<Utc>, String, Option<String>), ReportAmounts> =
+        BTreeMap::new();
+    for entry in entries {
+        buckets
+            .entry((period.start_of(entry.at), entry.currency, entry.group))
+            .or_default()
+            .add(&entry.amounts);
+    }
+    buckets
+        .into_iter()
+        .map(|((start, currency, group), amounts)| FinancialReportRow {
+            tenant_id: tenant_id.to_string(),
+            period_start: start,
+            period_end: period.next_start(start),
+            decimals: currency_decimals(&currency),
+            currency,
+            group,
+            amounts,
+        })
+        .collect()
+}
+
+async fn load_orders(
+    store: &dyn Store,
+    tenant_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<Order>, FinancialReportError> {
+    // `created_after` is exclusive; step back a microsecond to include `from`.
+    let after = from - Duration::microseconds(1);
+    let mut out = Vec::new();
+    let mut offset = 0;
+    loop {
+        let (page, _) = store
+            .list_orders_filtered(
+                tenant_id,
+                None,
+                None,
+                Some(to),
+                Some(after),
+                PAGE_SIZE,
+                offset,
+            )
+            .await
+            .map_err(|e| FinancialReportError::Storage(e.to_string()))?;
+        let len = page.len() as i32;
+        // Orders still awaiting payment have not collected anything.
+        out.extend(page.into_iter().filter(|o| o.status != "created"));
+        if len < PAGE_SIZE {
+            break;
+        }
+        offset += PAGE_SIZE;
+    }
+    Ok(out)
+}
+
+async fn load_disputes(
+    store: &dyn Store,
+    tenant_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<crate::models::DisputeRecord>, FinancialReportError> {
+    // Disputes are listed newest first, so stop at the first page reaching past `from`.
+    let mut out = Vec::new();
+    let mut offset = 0;
+    loop {
+        let page = store
+            .list_disputes(tenant_id, None, None, None, PAGE_SIZE, offset)
+            .await
+            .map_err(|e| FinancialReportError::Storage(e.to_string()))?;
+        let len = page.len() as i32;
+        let reached_start = page.iter().any(|d| d.created_at < from);
+        out.extend(page.into_iter().filter(|d| {
+            d.created_at >= from
+                && d.created_at < to
+                && !NON_LOSS_DISPUTE_STATUSES.contains(&d.status.as_str())
+        }));
+        if len < PAGE_SIZE || reached_start {
+            break;
+        }
+        offset += PAGE_SIZE;
+    }
+    Ok(out)
+}
+
+async fn load_products(
+    repo: &dyn ProductRepository,
+    tenant_id: &str,
+    ids: &[String],
+) -> Result<HashMap<String, Product>, FinancialReportError> {
+    let mut out = HashMap::new();
+    for chunk in ids.chunks(PAGE_SIZE as usize) {
+        let products = repo
+            .get_products_by_ids(tenant_id, chunk)
+            .await
+            .map_err(|e| FinancialReportError::Storage(e.to_string()))?;
+        out.extend(products.into_iter().map(|p| (p.id.clone(), p)));
+    }
+    Ok(out)
+}
+
+/// Map each product to a single collection so collection totals stay additive.
+/// Products in several collections are attributed to the lowest collection ID.
+async fn load_product_collections(
+    store: &dyn Store,
+    tenant_id: &str,
+) -> Result<HashMap<String, String>, FinancialReportError> {
+    let mut out: HashMap<String, String> = HashMap::new();
+    let mut offset = 0;
+    loop {
+        let page = store
+            .list_collections(tenant_id, None, PAGE_SIZE, offset)
+            .await
+            .map_err(|e| FinancialReportError::Storage(e.to_string()))?;
+        let len = page.len() as i32;
+        for collection in page {
+            for product_id in collection.product_ids {
+                out.entry(product_id)
+                    .and_modify(|existing| {
+                        if collection.id < *existing {
+                            existing.clone_from(&collection.id);
+                        }
+                    })
+                    .or_insert_with(|| collection.id.clone());
+            }
+        }
+        if len < PAGE_SIZE {
+            break;
+        }
+        offset += PAGE_SIZE;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::TimeZone;
+
+    use crate::models::{
+        Collection, DisputeRecord, Money, OrderItem, RefundQuote, StripeRefundRequest,
+    };
+    use crate::repositories::InMemoryProductRepository;
+    use crate::storage::InMemoryStore;
+
+    const TENANT: &str = "tenant-a";
+
+    fn ts(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    fn order(
+        id: &str,
+        source: &str,
+        amount: i64,
+        items: &[(&str, i32)],
+        at: DateTime<Utc>,
+    ) -> Order {
+        Order {
+            id: id.to_string(),
+            tenant_id: TENANT.to_string(),
+            source: source.to_string(),
+            purchase_id: format!("pur-{id}"),
+            resource_id: "cart:1".to_string(),
+            user_id: None,
+            customer: None,
+            status: "paid".to_string(),
+            items: items
+                .iter()
+                .map(|(p, q)| OrderItem {
+                    product_id: p.to_string(),
+                    variant_id: None,
+                    quantity: *q,
+                    line_total: None,
+                })
+                .collect(),
+            amount,
+            amount_asset: "USD".to_string(),
+            customer_email: None,
+            customer_name: None,
+            receipt_url: None,
+            shipping: None,
+            metadata: HashMap::new(),
+            created_at: at,
+            updated_at: None,
+            status_updated_at: None,
+        }
+    }
+
+    fn params(group_by: Option<ReportGroupBy>) -> ReportParams {
+        ReportParams {
+            from: ts(2026, 3, 1, 0),
+            to: ts(2026, 4, 1, 0),
+            period: ReportPeriod::Month,
+            group_by,
+        }
+    }
+
+    #[test]
+    fn test_period_boundaries() {
+        // 2026-03-18 is a Wednesday
+        let at = ts(2026, 3, 18, 15);
+        assert_eq!(ReportPeriod::Day.start_of(at), ts(2026, 3, 18, 0));
+        assert_eq!(ReportPeriod::Week.start_of(at), ts(2026, 3, 16, 0));
+        assert_eq!(ReportPeriod::Month.start_of(at), ts(2026, 3, 1, 0));
+        assert_eq!(
+            ReportPeriod::Month.next_start(ts(2026, 1, 1, 0)),
+            ts(2026, 2, 1, 0)
+        );
+        assert_eq!(
+            ReportPeriod::Month.next_start(ts(2026, 12, 1, 0)),
+            ts(2027, 1, 1, 0)
+        );
+        assert_eq!(
+            ReportPeriod::Month.previous(ts(2026, 3, 1, 2)),
+            (ts(2026, 2, 1, 0), ts(2026, 3, 1, 0))
+        );
+        assert_eq!(
+            ReportPeriod::Week.previous(at),
+            (ts(2026, 3, 9, 0), ts(2026, 3, 16, 0))
+        );
+    }
+
+    #[test]
+    fn test_validate_range() {
+        assert!(validate_range(ts(2026, 3, 2, 0), ts(2026, 3, 1, 0)).is_err());
+        assert!(validate_range(ts(2024, 1, 1, 0), ts(2026, 1, 1, 0)).is_err());
+        assert!(validate_range(ts(2026, 1, 1, 0), ts(2026, 2, 1, 0)).is_ok());
+    }
+
+    #[test]
+    fn test_allocate_sums_to_total() {
+        let amounts = ReportAmounts {
+            orders: 1,
+            collected: 1_000,
+            tax: 101,
+            ..Default::default()
+        };
+        let parts = allocate(
+            &amounts,
+            &[
+                ("a".to_string(), 1),
+                ("b".to_string(), 1),
+                ("c".to_string(), 1),
+            ],
+        );
+        assert_eq!(parts.iter().map(|(_, a)| a.collected).sum::<i64>(), 1_000);
+        assert_eq!(parts.iter().map(|(_, a)| a.tax).sum::<i64>(), 101);
+        assert!(parts.iter().all(|(_, a)| a.orders == 1));
+    }
+
+    #[test]
+    fn test_csv_escapes_fields() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("=SUM(A1)"), "'=SUM(A1)");
+    }
+
+    #[tokio::test]
+    async fn test_report_nets_refunds_and_disputes() {
+        let store = InMemoryStore::new();
+        let mut stripe = order("o1", "stripe", 1_140, &[("p1", 1)], ts(2026, 3, 5, 10));
+        stripe
+            .metadata
+            .insert(ORDER_DISCOUNT_AMOUNT_KEY.to_string(), "100".to_string());
+        stripe
+            .metadata
+            .insert(ORDER_TAX_AMOUNT_KEY.to_string(), "90".to_string());
+        stripe
+            .metadata
+            .insert(ORDER_SHIPPING_AMOUNT_KEY.to_string(), "50".to_string());
+        store.try_store_order(stripe).await.unwrap();
+        let mut crypto = order("o2", "x402", 2_000_000, &[("p2", 2)], ts(2026, 3, 20, 1));
+        crypto.amount_asset = "USDC".to_string();
+        store.try_store_order(crypto).await.unwrap();
+        let mut pending = order("o3", "x402", 999, &[("p2", 1)], ts(2026, 3, 21, 1));
+        pending.status = "created".to_string();
+        store.try_store_order(pending).await.unwrap();
+        // Outside the window
+        store
+            .try_store_order(order("o4", "stripe", 500, &[("p1", 1)], ts(2026, 4, 2, 0)))
+            .await
+            .unwrap();
+
+        store
+            .store_stripe_refund_request(StripeRefundRequest {
+                id: "srr-1".to_string(),
+                tenant_id: TENANT.to_string(),
+                original_purchase_id: "stripe:pur-o1".to_string(),
+                stripe_payment_intent_id: "pi_1".to_string(),
+                stripe_refund_id: Some("re_1".to_string()),
+                stripe_charge_id: None,
+                amount: 200,
+                currency: "usd".to_string(),
+                status: "succeeded".to_string(),
+                reason: None,
+                metadata: HashMap::new(),
+                created_at: ts(2026, 3, 6, 0),
+                processed_by: Some("admin".to_string()),
+                processed_at: Some(ts(2026, 3, 6, 0)),
+                last_error: None,
+            })
+            .await
+            .unwrap();
+        let mut refund = RefundQuote {
+            id: "rf-1".to_string(),
+            tenant_id: TENANT.to_string(),
+            original_purchase_id: "pur-o2".to_string(),
+            recipient_wallet: "wallet".to_string(),
+            amount: Money::new(get_asset("USDC").unwrap(), 500_000),
+            reason: None,
+            metadata: HashMap::new(),
+            created_at: ts(2026, 3, 21, 0),
+            expires_at: ts(2026, 3, 22, 0),
+            processed_by: Some("wallet".to_string()),
+            processed_at: Some(ts(2026, 3, 21, 0)),
+            signature: Some("sig".to_string()),
+        };
+        store.store_refund_quote(refund.clone()).await.unwrap();
+        // Denied refunds are not money out
+        refund.id = "rf-2".to_string();
+        refund.signature = None;
+        store.store_refund_quote(refund).await.unwrap();
+
+        for (id, status) in [("dp-1", "lost"), ("dp-2", "won")] {
+            store
+                .create_dispute(DisputeRecord {
+                    id: id.to_string(),
+                    tenant_id: TENANT.to_string(),
+                    source: "stripe".to_string(),
+                    order_id: Some("o1".to_string()),
+                    payment_intent_id: None,
+                    charge_id: None,
+                    status: status.to_string(),
+                    reason: None,
+                    amount: 300,
+                    currency: "usd".to_string(),
+                    metadata: HashMap::new(),
+                    created_at: ts(2026, 3, 10, 0),
+                    updated_at: None,
+                    status_updated_at: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        let report = build_financial_report(&store, None, TENANT, params(None))
+            .await
+            .unwrap();
+        assert_eq!(report.rows.len(), 2);
+        let usd = report.rows.iter().find(|r| r.currency == "USD").unwrap();
+        assert_eq!(usd.period_start, ts(2026, 3, 1, 0));
+        assert_eq!(usd.decimals, 2);
+        assert_eq!(usd.amounts.orders, 1);
+        assert_eq!(usd.amounts.collected, 1_140);
+        assert_eq!(usd.amounts.gross_sales, 1_100);
+        assert_eq!(usd.amounts.refunds, 200);
+        assert_eq!(usd.amounts.disputes, 300);
+        assert_eq!(usd.amounts.net, 640);
+        let usdc = report.rows.iter().find(|r| r.currency == "USDC").unwrap();
+        assert_eq!(usdc.decimals, 6);
+        assert_eq!(usdc.amounts.collected, 2_000_000);
+        assert_eq!(usdc.amounts.refunds, 500_000);
+        assert_eq!(usdc.amounts.net, 1_500_000);
+
+        let by_rail =
+            build_financial_report(&store, None, TENANT, params(Some(ReportGroupBy::Rail)))
+                .await
+                .unwrap();
+        let group = |rail: &str| {
+            by_rail
+                .rows
+                .iter()
+                .find(|r| r.group.as_deref() == Some(rail))
+                .unwrap()
+                .amounts
+        };
+        assert_eq!(group("stripe").net, 640);
+        assert_eq!(group("x402").net, 1_500_000);
+
+        let csv = report_to_csv(&report);
+        let mut lines = csv.lines();
+        assert!(lines.next().unwrap().starts_with("tenant_id,period_start"));
+        assert_eq!(
+            lines.next().unwrap(),
+            "tenant-a,2026-03-01,2026-04-01,USD,2,,1,1100,100,90,50,0,1140,200,300,640"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_report_groups_by_product_and_collection() {
+        let store = InMemoryStore::new();
+        store
+            .try_store_order(order(
+                "o1",
+                "x402",
+                3_000,
+                &[("p1", 1), ("p2", 1)],
+                ts(2026, 3, 3, 0),
+            ))
+            .await
+            .unwrap();
+        store
+            .create_collection(Collection {
+                id: "col-b".to_string(),
+                tenant_id: TENANT.to_string(),
+                name: "B".to_string(),
+                description: None,
+                product_ids: vec!["p1".to_string(), "p2".to_string()],
+                active: true,
+                tokenization_config: None,
+                payment_split: None,
+                created_at: ts(2026, 1, 1, 0),
+                updated_at: ts(2026, 1, 1, 0),
+            })
+            .await
+            .unwrap();
+        store
+            .create_collection(Collection {
+                id: "col-a".to_string(),
+                tenant_id: TENANT.to_string(),
+                name: "A".to_string(),
+                description: None,
+                product_ids: vec!["p1".to_string()],
+                active: true,
+                tokenization_config: None,
+                payment_split: None,
+                created_at: ts(2026, 1, 1, 0),
+                updated_at: ts(2026, 1, 1, 0),
+            })
+            .await
+            .unwrap();
+
+        let mut p1 = Product {
+            id: "p1".to_string(),
+            tenant_id: TENANT.to_string(),
+            ..Default::default()
+        };
+        p1.fiat_price = Some(Money::new(get_asset("USD").unwrap(), 2_000));
+        let mut p2 = p1.clone();
+        p2.id = "p2".to_string();
+        p2.fiat_price = Some(Money::new(get_asset("USD").unwrap(), 1_000));
+        let repo = InMemoryProductRepository::new(vec![p1, p2]);
+
+        let report = build_financial_report(
+            &store,
+            Some(&repo),
+            TENANT,
+            params(Some(ReportGroupBy::Product)),
+        )
+        .await
+        .unwrap();
+        let collected: HashMap<_, _> = report
+            .rows
+            .iter()
+            .map(|r| (r.group.clone().unwrap(), r.amounts.collected))
+            .collect();
+        assert_eq!(collected["p1"], 2_000);
+        assert_eq!(collected["p2"], 1_000);
+
+        let report = build_financial_report(
+            &store,
+            Some(&repo),
+            TENANT,
+            params(Some(ReportGroupBy::Collection)),
+        )
+        .await
+        .unwrap();
+        let collected: HashMap<_, _> = report
+            .rows
+            .iter()
+            .map(|r| (r.group.clone().unwrap(), r.amounts.collected))
+            .collect();
+        assert_eq!(collected["col-a"], 2_000);
+        assert_eq!(collected["col-b"], 1_000);
+        assert_eq!(report.totals[0].amounts.collected, 3_000);
+        // Split orders count once per group but once in the totals
+        assert_eq!(report.totals[0].amounts.orders, 1);
+    }
+}
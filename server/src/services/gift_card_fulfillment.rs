@@ -3,6 +3,9 @@
 //! After a gift card product is purchased, this service:
 //! 1. Deposits credits to the recipient's cedros-login account
 //! 2. Optionally mints Token-22 tokens for secondary market
+//!
+//! When the recipient is only known by email, it records a claimable
+//! redemption and emails the claim code to the recipient.
 
 use std::sync::Arc;
 
@@ -12,6 +15,7 @@ use tracing::{info, warn};
 
 use crate::models::{GiftCardConfig, GiftCardRedemption, Product, TenantToken22Mint};
 use crate::services::cedros_login::CedrosLoginClient;
+use crate::services::messaging::{GiftCardDelivery, MessagingService};
 use crate::services::token22::Token22Service;
 use crate::storage::Store;
 
@@ -20,6 +24,8 @@ pub struct GiftCardFulfillmentService {
     cedros_login: Arc<CedrosLoginClient>,
     token22: Option<Arc<Token22Service>>,
     store: Arc<dyn Store>,
+    /// Delivers claim codes to recipients by email
+    messaging: Option<Arc<dyn MessagingService>>,
 }
 
 impl GiftCardFulfillmentService {
@@ -32,9 +38,16 @@ impl GiftCardFulfillmentService {
             cedros_login,
             token22,
             store,
+            messaging: None,
         }
     }
 
+    /// Email claim codes to recipients of unclaimed gift cards
+    pub fn with_messaging(mut self, messaging: Arc<dyn MessagingService>) -> Self {
+        self.messaging = Some(messaging);
+        self
+    }
+
     /// Fulfill a gift card purchase: deposit credits and optionally mint tokens.
     ///
     /// When `recipient_user_id` is `None`, a one-time claim token is generated and
@@ -191,6 +204,20 @@ impl GiftCardFulfillmentService {
                     token = %redemption_token,
                     "Gift card pending redemption recorded; awaiting recipient claim"
                 );
+                if let (Some(messaging), Some(email)) = (&self.messaging, recipient_email) {
+                    messaging
+                        .notify_gift_card_delivered(&GiftCardDelivery {
+                            tenant_id: tenant_id.to_string(),
+                            to_email: email.to_string(),
+                            order_id: order_id.to_string(),
+                            product_id: product.id.clone(),
+                            face_value_cents: gc.face_value_cents,
+                            currency: gc.currency.clone(),
+                            claim_code: redemption_token,
+                            locale: None,
+                        })
+                        .await;
+                }
             }
             Err(e) => {
                 warn!(
@@ -0,0 +1,712 @@
+//! Double-entry postings for money movements.
+//!
+//! Each builder turns one source event into a balanced [`JournalEntry`]:
+//!
+//! | Event | Debit | Credit |
+//! |-------|-------|--------|
+//! | Payment | `receivable:{rail}` | `revenue`, `tax_payable` |
+//! | x402 / credits refund | `refunds` | `receivable:{rail}` |
+//! | Stripe refund | `refunds` | `receivable:stripe` |
+//! | Gift card redemption | `gift_card_liability` | `revenue` |
+//! | Gift card issued / topped up | `promotions` | `gift_card_liability` |
+//! | Gift card balance reduced | `gift_card_liability` | `promotions` |
+//! | Stripe application fee | `fees` | `receivable:stripe` |
+//! | Application fee refund | `receivable:stripe` | `fees` |
+//! | Credits hold placed | `credits_held` | `credits_hold_clearing` |
+//! | Credits hold captured / released / expired | `credits_hold_clearing` | `credits_held` |
+//!
+//! Rails are `x402`, `credits` and `stripe`. Entry IDs derive from the source
+//! event, so posting is idempotent and safe to retry. Posting is best-effort:
+//! failures are logged and never fail the payment flow.
+//!
+//! A credits hold is a reservation, not a sale: the hold pair nets to zero
+//! when the hold settles, and a captured hold's revenue is the payment entry
+//! on `receivable:credits`. A hold settles once; capture and release post
+//! only against a posted hold that has not settled yet.
+//!
+//! Token-22 transfer fee harvests are not posted: the harvest endpoint does
+//! not withdraw anything yet, so there is no amount to post. Record harvests
+//! as manual entries (`receivable:token22` / `fee_income`).
+
+use std::fmt::Write as _;
+
+use chrono::{DateTime, Utc};
+use tracing::{error, warn};
+
+use crate::constants::STRIPE_SIGNATURE_PREFIX;
+use crate::models::ledger::{
+    receivable_account, ACCOUNT_CREDITS_HELD, ACCOUNT_CREDITS_HOLD_CLEARING, ACCOUNT_FEES,
+    ACCOUNT_GIFT_CARD_LIABILITY, ACCOUNT_PROMOTIONS, ACCOUNT_REFUNDS, ACCOUNT_REVENUE,
+    ACCOUNT_TAX_PAYABLE,
+};
+use crate::models::{
+    get_asset, GiftCard, JournalEntry, JournalLine, JournalSource, PaymentTransaction, RefundQuote,
+    StripeApplicationFee, StripeRefundRequest, ORDER_TAX_AMOUNT_KEY,
+};
+use crate::services::financial_reports::csv_field;
+use crate::storage::{CreditsHold, Store};
+
+/// Payment rail for card payments via Stripe Checkout.
+pub const RAIL_STRIPE: &str = "stripe";
+/// Payment rail for cedros-login credits.
+pub const RAIL_CREDITS: &str = "credits";
+/// Payment rail for on-chain x402 payments.
+pub const RAIL_X402: &str = "x402";
+
+/// Stripe refund status that moves money.
+const STRIPE_REFUND_SUCCEEDED: &str = "succeeded";
+
+/// Rail a payment or refund settled on, from its purchase signature.
+pub fn payment_rail(signature: &str) -> &'static str {
+    if signature.starts_with(STRIPE_SIGNATURE_PREFIX) {
+        RAIL_STRIPE
+    } else if signature.starts_with("credits:") {
+        RAIL_CREDITS
+    } else {
+        RAIL_X402
+    }
+}
+
+/// Payment received: receivable against revenue and tax payable.
+///
+/// Tax is read from the payment's [`ORDER_TAX_AMOUNT_KEY`] metadata (capped at
+/// the payment amount). Zero-amount records such as idempotency markers post
+/// nothing.
+pub fn payment_entry(tx: &PaymentTransaction) -> Option<JournalEntry> {
+    let amount = tx.amount.atomic;
+    if amount <= 0 {
+        return None;
+    }
+    let tax = tx
+        .metadata
+        .get(ORDER_TAX_AMOUNT_KEY)
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(0)
+        .clamp(0, amount);
+
+    let mut lines = vec![JournalLine::debit(
+        receivable_account(payment_rail(&tx.signature)),
+        amount,
+    )];
+    if amount > tax {
+        lines.push(JournalLine::credit(ACCOUNT_REVENUE, amount - tax));
+    }
+    if tax > 0 {
+        lines.push(JournalLine::credit(ACCOUNT_TAX_PAYABLE, tax));
+    }
+    Some(
+        JournalEntry::new(
+            &tx.tenant_id,
+            JournalSource::Payment,
+            &tx.signature,
+            &tx.amount.asset.code,
+            lines,
+            tx.created_at,
+        )
+        .with_description(format!("Payment for {}", tx.resource_id)),
+    )
+}
+
+/// Executed x402 or credits refund.
+pub fn refund_entry(refund: &RefundQuote) -> Option<JournalEntry> {
+    let processed_at = refund.processed_at.filter(|_| refund.is_processed())?;
+    let amount = refund.amount.atomic;
+    if amount <= 0 {
+        return None;
+    }
+    let rail = payment_rail(&refund.original_purchase_id);
+    Some(
+        JournalEntry::new(
+            &refund.tenant_id,
+            JournalSource::Refund,
+            &refund.id,
+            &refund.amount.asset.code,
+            vec![
+                JournalLine::debit(ACCOUNT_REFUNDS, amount),
+                JournalLine::credit(receivable_account(rail), amount),
+            ],
+            processed_at,
+        )
+        .with_description(format!("Refund of {}", refund.original_purchase_id)),
+    )
+}
+
+/// Stripe refund, once Stripe reports it succeeded.
+pub fn stripe_refund_entry(req: &StripeRefundRequest) -> Option<JournalEntry> {
+    if req.status != STRIPE_REFUND_SUCCEEDED || req.amount <= 0 {
+        return None;
+    }
+    let occurred_at = req.processed_at.unwrap_or_else(Utc::now);
+    Some(
+        JournalEntry::new(
+            &req.tenant_id,
+            JournalSource::StripeRefund,
+            &req.id,
+            &req.currency,
+            vec![
+                JournalLine::debit(ACCOUNT_REFUNDS, req.amount),
+                JournalLine::credit(receivable_account(RAIL_STRIPE), req.amount),
+            ],
+            occurred_at,
+        )
+        .with_description(format!("Stripe refund of {}", req.original_purchase_id)),
+    )
+}
+
+/// Gift card balance applied to a paid cart: the liability is settled as revenue.
+pub fn gift_card_redemption_entry(
+    tenant_id: &str,
+    cart_id: &str,
+    code: &str,
+    currency: &str,
+    amount: i64,
+    occurred_at: DateTime<Utc>,
+) -> Option<JournalEntry> {
+    if amount <= 0 {
+        return None;
+    }
+    Some(
+        JournalEntry::new(
+            tenant_id,
+            JournalSource::GiftCardRedemption,
+            cart_id,
+            currency,
+            vec![
+                JournalLine::debit(ACCOUNT_GIFT_CARD_LIABILITY, amount),
+                JournalLine::credit(ACCOUNT_REVENUE, amount),
+            ],
+            occurred_at,
+        )
+        .with_description(format!("Gift card {} redeemed", code)),
+    )
+}
+
+/// Gift card created by an admin with an opening balance.
+pub fn gift_card_issue_entry(card: &GiftCard) -> Option<JournalEntry> {
+    if card.balance <= 0 {
+        return None;
+    }
+    Some(
+        JournalEntry::new(
+            &card.tenant_id,
+            JournalSource::GiftCardIssue,
+            &card.code,
+            &card.currency,
+            vec![
+                JournalLine::debit(ACCOUNT_PROMOTIONS, card.balance),
+                JournalLine::credit(ACCOUNT_GIFT_CARD_LIABILITY, card.balance),
+            ],
+            card.created_at,
+        )
+        .with_description(format!("Gift card {} issued", card.code)),
+    )
+}
+
+/// Manual gift card balance change from `previous_balance` to `card.balance`.
+pub fn gift_card_adjustment_entry(card: &GiftCard, previous_balance: i64) -> Option<JournalEntry> {
+    let delta = card.balance - previous_balance;
+    let lines = match delta {
+        0 => return None,
+        d if d > 0 => vec![
+            JournalLine::debit(ACCOUNT_PROMOTIONS, d),
+            JournalLine::credit(ACCOUNT_GIFT_CARD_LIABILITY, d),
+        ],
+        d => vec![
+            JournalLine::debit(ACCOUNT_GIFT_CARD_LIABILITY, -d),
+            JournalLine::credit(ACCOUNT_PROMOTIONS, -d),
+        ],
+    };
+    let mut entry = JournalEntry::new(
+        &card.tenant_id,
+        JournalSource::GiftCardAdjustment,
+        &card.code,
+        &card.currency,
+        lines,
+        card.updated_at,
+    )
+    .with_description(format!(
+        "Gift card {} balance {} -> {}",
+        card.code, previous_balance, card.balance
+    ));
+    // Several adjustments share the card code as source; key each by time.
+    entry.id = format!("{}@{}", entry.id, card.updated_at.timestamp_micros());
+    Some(entry)
+}
+
+/// Platform application fee on a connected-account charge, plus a refund
+/// entry for any fee refunded beyond `refunded_posted`.
+pub fn application_fee_entries(
+    fee: &StripeApplicationFee,
+    refunded_posted: i64,
+) -> Vec<JournalEntry> {
+    let mut entries = Vec::new();
+    let receivable = receivable_account(RAIL_STRIPE);
+    if fee.amount > 0 {
+        entries.push(
+            JournalEntry::new(
+                &fee.tenant_id,
+                JournalSource::StripeFee,
+                &fee.id,
+                &fee.currency,
+                vec![
+                    JournalLine::debit(ACCOUNT_FEES, fee.amount),
+                    JournalLine::credit(receivable.clone(), fee.amount),
+                ],
+                fee.created_at,
+            )
+            .with_description("Stripe application fee"),
+        );
+    }
+    let refunded = fee.amount_refunded.min(fee.amount) - refunded_posted;
+    if refunded > 0 {
+        let mut entry = JournalEntry::new(
+            &fee.tenant_id,
+            JournalSource::StripeFeeRefund,
+            &fee.id,
+            &fee.currency,
+            vec![
+                JournalLine::debit(receivable, refunded),
+                JournalLine::credit(ACCOUNT_FEES, refunded),
+            ],
+            Utc::now(),
+        )
+        .with_description("Stripe application fee refund");
+        // Keyed by the cumulative refunded amount so each increase posts once.
+        entry.id = format!("{}@{}", entry.id, fee.amount_refunded);
+        entries.push(entry);
+    }
+    entries
+}
+
+/// Credits reserved by a cedros-login hold.
+pub fn credits_hold_entry(hold: &CreditsHold) -> Option<JournalEntry> {
+    if hold.amount <= 0 {
+        return None;
+    }
+    Some(
+        JournalEntry::new(
+            &hold.tenant_id,
+            JournalSource::CreditsHold,
+            &hold.hold_id,
+            &hold.amount_asset,
+            vec![
+                JournalLine::debit(ACCOUNT_CREDITS_HELD, hold.amount),
+                JournalLine::credit(ACCOUNT_CREDITS_HOLD_CLEARING, hold.amount),
+            ],
+            hold.created_at,
+        )
+        .with_description(format!("Credits hold for {}", hold.resource_id)),
+    )
+}
+
+/// Capture or release of a posted hold: reverses the hold entry's lines.
+pub fn credits_hold_settlement_entry(
+    hold_entry: &JournalEntry,
+    source: JournalSource,
+    occurred_at: DateTime<Utc>,
+) -> JournalEntry {
+    let lines = hold_entry
+        .lines
+        .iter()
+        .map(|l| JournalLine {
+            account: l.account.clone(),
+            debit: l.credit,
+            credit: l.debit,
+        })
+        .collect();
+    let action = match source {
+        JournalSource::CreditsCapture => "captured",
+        _ => "released",
+    };
+    JournalEntry::new(
+        &hold_entry.tenant_id,
+        source,
+        &hold_entry.source_id,
+        &hold_entry.currency,
+        lines,
+        occurred_at,
+    )
+    .with_description(format!("Credits hold {} {}", hold_entry.source_id, action))
+}
+
+/// Append an entry to the journal (best-effort).
+///
+/// Unbalanced entries are rejected and logged; duplicates are ignored.
+pub async fn post_entry<S: Store + ?Sized>(store: &S, entry: JournalEntry) {
+    if let Err(e) = entry.validate() {
+        error!(
+            tenant_id = %entry.tenant_id,
+            entry_id = %entry.id,
+            error = %e,
+            "Refusing to post invalid journal entry"
+        );
+        return;
+    }
+    if let Err(e) = store.append_journal_entry(entry.clone()).await {
+        warn!(
+            error = %e,
+            tenant_id = %entry.tenant_id,
+            entry_id = %entry.id,
+            "Failed to post journal entry"
+        );
+    }
+}
+
+/// Post a payment (see [`payment_entry`]).
+pub async fn post_payment<S: Store + ?Sized>(store: &S, tx: &PaymentTransaction) {
+    if let Some(entry) = payment_entry(tx) {
+        post_entry(store, entry).await;
+    }
+}
+
+/// Post an executed refund (see [`refund_entry`]).
+pub async fn post_refund<S: Store + ?Sized>(store: &S, refund: &RefundQuote) {
+    if let Some(entry) = refund_entry(refund) {
+        post_entry(store, entry).await;
+    }
+}
+
+/// Post a succeeded Stripe refund (see [`stripe_refund_entry`]).
+pub async fn post_stripe_refund<S: Store + ?Sized>(store: &S, req: &StripeRefundRequest) {
+    if let Some(entry) = stripe_refund_entry(req) {
+        post_entry(store, entry).await;
+    }
+}
+
+/// Post an application fee and any newly refunded share of it.
+pub async fn post_application_fee<S: Store + ?Sized>(store: &S, fee: &StripeApplicationFee) {
+    let refunded_posted = match store
+        .list_journal_entries_for_source(&fee.tenant_id, JournalSource::StripeFeeRefund, &fee.id)
+        .await
+    {
+        Ok(entries) => entries.iter().map(JournalEntry::total_debits).sum(),
+        Err(e) => {
+            warn!(error = %e, fee_id = %fee.id, "Failed to load posted fee refunds");
+            return;
+        }
+    };
+    for entry in application_fee_entries(fee, refunded_posted) {
+        post_entry(store, entry).await;
+    }
+}
+
+/// Post a newly placed credits hold (see [`credits_hold_entry`]).
+pub async fn post_credits_hold<S: Store + ?Sized>(store: &S, hold: &CreditsHold) {
+    if let Some(entry) = credits_hold_entry(hold) {
+        post_entry(store, entry).await;
+    }
+}
+
+/// Post the capture of a credits hold; its revenue is the payment entry.
+pub async fn post_credits_capture<S: Store + ?Sized>(store: &S, tenant_id: &str, hold_id: &str) {
+    post_credits_settlement(store, tenant_id, hold_id, JournalSource::CreditsCapture).await;
+}
+
+/// Post the release (or expiry) of a credits hold.
+pub async fn post_credits_release<S: Store + ?Sized>(store: &S, tenant_id: &str, hold_id: &str) {
+    post_credits_settlement(store, tenant_id, hold_id, JournalSource::CreditsRelease).await;
+}
+
+/// Reverse the hold entry unless the hold was never posted or already settled.
+async fn post_credits_settlement<S: Store + ?Sized>(
+    store: &S,
+    tenant_id: &str,
+    hold_id: &str,
+    source: JournalSource,
+) {
+    let mut posted = Vec::new();
+    for kind in [
+        JournalSource::CreditsHold,
+        JournalSource::CreditsCapture,
+        JournalSource::CreditsRelease,
+    ] {
+        match store
+            .list_journal_entries_for_source(tenant_id, kind, hold_id)
+            .await
+        {
+            Ok(entries) => posted.extend(entries),
+            Err(e) => {
+                warn!(error = %e, hold_id = %hold_id, "Failed to load posted credits hold entries");
+                return;
+            }
+        }
+    }
+    if posted
+        .iter()
+        .any(|e| e.source != JournalSource::CreditsHold)
+    {
+        return;
+    }
+    if let Some(hold_entry) = posted.first() {
+        post_entry(
+            store,
+            credits_hold_settlement_entry(hold_entry, source, Utc::now()),
+        )
+        .await;
+    }
+}
+
+/// Journal lines as CSV, one row per line, amounts in atomic units.
+pub fn journal_to_csv(entries: &[JournalEntry]) -> String {
+    let mut out = String::from(
+        "date,entry_id,source,source_id,account,debit,credit,currency,decimals,description\n",
+    );
+    for entry in entries {
+        let decimals = get_asset(&entry.currency).map(|a| a.decimals).unwrap_or(0);
+        let description = entry.description.as_deref().unwrap_or("");
+        for line in &entry.lines {
+            let _ = writeln!(
+                out,
+                "{},{},{},{},{},{},{},{},{},{}",
+                entry.occurred_at.format("%Y-%m-%d"),
+                csv_field(&entry.id),
+                entry.source.as_str(),
+                csv_field(&entry.source_id),
+                csv_field(&line.account),
+                line.debit,
+                line.credit,
+                csv_field(&entry.currency),
+                decimals,
+                csv_field(description),
+            );
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+
+    use chrono::Duration;
+
+    use crate::models::{AccountType, Money};
+    use crate::storage::InMemoryStore;
+
+    fn payment(signature: &str, asset: &str, amount: i64, tax: Option<i64>) -> PaymentTransaction {
+        let mut metadata = HashMap::new();
+        if let Some(tax) = tax {
+            metadata.insert(ORDER_TAX_AMOUNT_KEY.to_string(), tax.to_string());
+        }
+        PaymentTransaction {
+            signature: signature.to_string(),
+            tenant_id: "t1".to_string(),
+            resource_id: "p1".to_string(),
+            wallet: "w1".to_string(),
+            user_id: None,
+            amount: Money::new(get_asset(asset).unwrap(), amount),
+            created_at: Utc::now() - Duration::hours(1),
+            metadata,
+        }
+    }
+
+    #[test]
+    fn test_payment_entry_splits_tax() {
+        let entry = payment_entry(&payment("stripe:cs_1", "USD", 1_100, Some(100))).unwrap();
+        assert!(entry.validate().is_ok());
+        assert_eq!(entry.id, "payment:stripe:cs_1");
+        assert_eq!(
+            entry.lines,
+            vec![
+                JournalLine::debit("receivable:stripe", 1_100),
+                JournalLine::credit(ACCOUNT_REVENUE, 1_000),
+                JournalLine::credit(ACCOUNT_TAX_PAYABLE, 100),
+            ]
+        );
+
+        let credits = payment_entry(&payment("credits:hold-1", "USD", 500, None)).unwrap();
+        assert_eq!(credits.lines[0].account, "receivable:credits");
+        assert_eq!(credits.lines.len(), 2);
+
+        assert!(payment_entry(&payment("marker", "USD", 0, None)).is_none());
+    }
+
+    #[test]
+    fn test_application_fee_refund_posts_delta() {
+        let fee = StripeApplicationFee {
+            id: "fee_1".to_string(),
+            tenant_id: "t1".to_string(),
+            account_id: "acct_1".to_string(),
+            charge_id: None,
+            amount: 300,
+            amount_refunded: 200,
+            currency: "usd".to_string(),
+            created_at: Utc::now(),
+        };
+        let entries = application_fee_entries(&fee, 50);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id, "stripe_fee:fee_1");
+        assert_eq!(entries[1].id, "stripe_fee_refund:fee_1@200");
+        assert_eq!(entries[1].total_debits(), 150);
+        assert!(entries.iter().all(|e| e.validate().is_ok()));
+
+        assert_eq!(application_fee_entries(&fee, 200).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_credits_hold_settles_once() {
+        let store = InMemoryStore::new();
+        let now = Utc::now();
+        let hold = |hold_id: &str| CreditsHold {
+            hold_id: hold_id.to_string(),
+            tenant_id: "t1".to_string(),
+            user_id: "u1".to_string(),
+            resource_id: "p1".to_string(),
+            amount: 500,
+            amount_asset: "USD".to_string(),
+            created_at: now - Duration::minutes(5),
+            expires_at: now + Duration::minutes(5),
+        };
+
+        // Settling a hold that was never posted posts nothing
+        post_credits_capture(&store, "t1", "unknown").await;
+
+        post_credits_hold(&store, &hold("hold-1")).await;
+        post_credits_hold(&store, &hold("hold-1")).await;
+        post_credits_hold(&store, &hold("hold-2")).await;
+        post_credits_capture(&store, "t1", "hold-1").await;
+        post_credits_capture(&store, "t1", "hold-1").await;
+        // A captured hold cannot also be released
+        post_credits_release(&store, "t1", "hold-1").await;
+
+        let rows = store
+            .trial_balance("t1", now + Duration::seconds(1))
+            .await
+            .unwrap();
+        let balance = |account: &str| {
+            rows.iter()
+                .find(|r| r.account == account)
+                .map(|r| r.balance)
+                .unwrap()
+        };
+        assert_eq!(balance(ACCOUNT_CREDITS_HELD), 500);
+        assert_eq!(balance(ACCOUNT_CREDITS_HOLD_CLEARING), 500);
+
+        post_credits_release(&store, "t1", "hold-2").await;
+        let rows = store
+            .trial_balance("t1", now + Duration::seconds(1))
+            .await
+            .unwrap();
+        assert!(rows
+            .iter()
+            .filter(|r| {
+                r.account == ACCOUNT_CREDITS_HELD || r.account == ACCOUNT_CREDITS_HOLD_CLEARING
+            })
+            .all(|r| r.balance == 0));
+
+        let entries = store
+            .list_journal_entries(
+                "t1",
+                now - Duration::days(1),
+                now + Duration::seconds(1),
+                100,
+                0,
+            )
+            .await
+            .unwrap();
+        let mut ids: Vec<&str> = entries.iter().map(|e| e.id.as_str()).collect();
+        ids.sort();
+        assert_eq!(
+            ids,
+            vec![
+                "credits_capture:hold-1",
+                "credits_hold:hold-1",
+                "credits_hold:hold-2",
+                "credits_release:hold-2",
+            ]
+        );
+        assert!(entries.iter().all(|e| e.validate().is_ok()));
+    }
+
+    #[tokio::test]
+    async fn test_trial_balance_after_sale_and_refund() {
+        let store = InMemoryStore::new();
+        let tx = payment("sig-1", "USDC", 2_000_000, None);
+        post_payment(&store, &tx).await;
+        // Reposting the same payment is a no-op
+        post_payment(&store, &tx).await;
+
+        let now = Utc::now();
+        let refund = RefundQuote {
+            id: "refund-1".to_string(),
+            tenant_id: "t1".to_string(),
+            original_purchase_id: "sig-1".to_string(),
+            recipient_wallet: "w1".to_string(),
+            amount: Money::new(get_asset("USDC").unwrap(), 500_000),
+            reason: None,
+            metadata: HashMap::new(),
+            created_at: now,
+            expires_at: now,
+            processed_by: Some("admin".to_string()),
+            processed_at: Some(now),
+            signature: Some("refund-sig".to_string()),
+        };
+        post_refund(&store, &refund).await;
+
+        let card = GiftCard {
+            code: "GIFT1".to_string(),
+            tenant_id: "t1".to_string(),
+            initial_balance: 1_000,
+            balance: 1_000,
+            currency: "USD".to_string(),
+            active: true,
+            expires_at: None,
+            metadata: HashMap::new(),
+            created_at: now,
+            updated_at: now,
+        };
+        post_entry(&store, gift_card_issue_entry(&card).unwrap()).await;
+        let adjusted = GiftCard {
+            balance: 400,
+            ..card
+        };
+        post_entry(
+            &store,
+            gift_card_adjustment_entry(&adjusted, 1_000).unwrap(),
+        )
+        .await;
+
+        let rows = store
+            .trial_balance("t1", now + Duration::seconds(1))
+            .await
+            .unwrap();
+        let row = |account: &str, currency: &str| {
+            rows.iter()
+                .find(|r| r.account == account && r.currency == currency)
+                .cloned()
+                .unwrap()
+        };
+        assert_eq!(row("receivable:x402", "USDC").balance, 1_500_000);
+        assert_eq!(row(ACCOUNT_REVENUE, "USDC").balance, 2_000_000);
+        assert_eq!(row(ACCOUNT_REFUNDS, "USDC").balance, 500_000);
+        assert_eq!(row(ACCOUNT_GIFT_CARD_LIABILITY, "USD").balance, 400);
+        assert_eq!(
+            row(ACCOUNT_GIFT_CARD_LIABILITY, "USD").account_type,
+            Some(AccountType::Liability)
+        );
+        for currency in ["USD", "USDC"] {
+            let (debits, credits) = rows
+                .iter()
+                .filter(|r| r.currency == currency)
+                .fold((0, 0), |(d, c), r| (d + r.debit, c + r.credit));
+            assert_eq!(debits, credits);
+        }
+
+        let entries = store
+            .list_journal_entries(
+                "t1",
+                now - Duration::days(1),
+                now + Duration::seconds(1),
+                100,
+                0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 4);
+        let csv = journal_to_csv(&entries);
+        assert_eq!(csv.lines().count(), 1 + 8);
+        assert!(csv.contains(",payment,sig-1,receivable:x402,2000000,0,USDC,6,Payment for p1"));
+    }
+}
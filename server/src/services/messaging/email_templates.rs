@@ -0,0 +1,559 @@
+//! Transactional email template catalog.
+//!
+//! Every email kind has a built-in English template. Tenants override any
+//! kind per locale via `/admin/email-templates`; overrides are stored in the
+//! `email_templates` table and resolved with locale fallback:
+//! `pt-br` → `pt` → `en` → built-in.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::template::{Escape, Template, TemplateError};
+use crate::storage::{StorageResult, Store};
+
+/// Locale tried after the requested one and its base language
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Longest accepted subject template
+pub const MAX_SUBJECT_CHARS: usize = 500;
+
+/// Transactional email kinds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailTemplateKind {
+    OrderConfirmation,
+    OrderShipped,
+    RefundProcessed,
+    SubscriptionRenewed,
+    SubscriptionPaymentFailed,
+    GiftCardDelivery,
+}
+
+impl EmailTemplateKind {
+    pub const ALL: [EmailTemplateKind; 6] = [
+        EmailTemplateKind::OrderConfirmation,
+        EmailTemplateKind::OrderShipped,
+        EmailTemplateKind::RefundProcessed,
+        EmailTemplateKind::SubscriptionRenewed,
+        EmailTemplateKind::SubscriptionPaymentFailed,
+        EmailTemplateKind::GiftCardDelivery,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailTemplateKind::OrderConfirmation => "order_confirmation",
+            EmailTemplateKind::OrderShipped => "order_shipped",
+            EmailTemplateKind::RefundProcessed => "refund_processed",
+            EmailTemplateKind::SubscriptionRenewed => "subscription_renewed",
+            EmailTemplateKind::SubscriptionPaymentFailed => "subscription_payment_failed",
+            EmailTemplateKind::GiftCardDelivery => "gift_card_delivery",
+        }
+    }
+
+    pub fn parse(kind: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == kind)
+    }
+
+    /// Sample data for previews; also documents the variables of each kind
+    pub fn sample_data(&self) -> Value {
+        let mut data = match self {
+            EmailTemplateKind::OrderConfirmation => json!({
+                "orderId": "ord_7f3a9c",
+                "customerName": "Ada Lovelace",
+                "items": [
+                    {
+                        "productId": "prod_mug",
+                        "title": "Ceramic Mug",
+                        "quantity": 2,
+                        "unitPrice": "12.00",
+                        "lineTotal": "24.00",
+                    },
+                    {
+                        "productId": "prod_tee",
+                        "title": "Logo T-Shirt",
+                        "quantity": 1,
+                        "unitPrice": "25.00",
+                        "lineTotal": "25.00",
+                    },
+                ],
+                "total": "49.00",
+                "currency": "USD",
+                "paymentMethod": "stripe",
+                "receiptUrl": "https://shop.example.com/receipt/ord_7f3a9c",
+            }),
+            EmailTemplateKind::OrderShipped => json!({
+                "orderId": "ord_7f3a9c",
+                "customerName": "Ada Lovelace",
+                "items": [{ "productId": "prod_mug", "title": "Ceramic Mug", "quantity": 2 }],
+                "carrier": "UPS",
+                "trackingNumber": "1Z999AA10123456784",
+                "trackingUrl": "https://www.ups.com/track?tracknum=1Z999AA10123456784",
+            }),
+            EmailTemplateKind::RefundProcessed => json!({
+                "refundId": "ref_41bd",
+                "orderId": "ord_7f3a9c",
+                "customerName": "Ada Lovelace",
+                "amount": "24.00",
+                "currency": "USD",
+                "reason": "Item arrived damaged",
+            }),
+            EmailTemplateKind::SubscriptionRenewed => json!({
+                "subscriptionId": "sub_9e21",
+                "productId": "prod_pro",
+                "productTitle": "Pro Plan",
+                "periodEnd": "2026-12-01",
+            }),
+            EmailTemplateKind::SubscriptionPaymentFailed => json!({
+                "subscriptionId": "sub_9e21",
+                "productId": "prod_pro",
+                "productTitle": "Pro Plan",
+                "periodEnd": "2026-12-01",
+                "updatePaymentUrl": "https://billing.example.com/update",
+            }),
+            EmailTemplateKind::GiftCardDelivery => json!({
+                "orderId": "ord_7f3a9c",
+                "productTitle": "Gift Card",
+                "amount": "50.00",
+                "currency": "USD",
+                "claimCode": "0b6a4f9e-2d1c-4c55-9a0e-6f1d7b3e8c21",
+                "recipientEmail": "friend@example.com",
+            }),
+        };
+        data["storeName"] = json!("Example Store");
+        data
+    }
+
+    /// Built-in (English) template
+    pub fn builtin(&self) -> TemplateParts {
+        let (subject, body_text, title, content) = match self {
+            EmailTemplateKind::OrderConfirmation => (
+                "Order Confirmation - {{orderId}}",
+                ORDER_CONFIRMATION_TEXT,
+                "Order Confirmation",
+                ORDER_CONFIRMATION_HTML,
+            ),
+            EmailTemplateKind::OrderShipped => (
+                "Your order {{orderId}} has shipped",
+                ORDER_SHIPPED_TEXT,
+                "Your order has shipped",
+                ORDER_SHIPPED_HTML,
+            ),
+            EmailTemplateKind::RefundProcessed => (
+                "Your refund of {{amount}} {{currency}} has been processed",
+                REFUND_PROCESSED_TEXT,
+                "Refund processed",
+                REFUND_PROCESSED_HTML,
+            ),
+            EmailTemplateKind::SubscriptionRenewed => (
+                "Your {{productTitle}} subscription has renewed",
+                SUBSCRIPTION_RENEWED_TEXT,
+                "Subscription renewed",
+                SUBSCRIPTION_RENEWED_HTML,
+            ),
+            EmailTemplateKind::SubscriptionPaymentFailed => (
+                "Payment failed for your {{productTitle}} subscription",
+                SUBSCRIPTION_PAYMENT_FAILED_TEXT,
+                "Payment failed",
+                SUBSCRIPTION_PAYMENT_FAILED_HTML,
+            ),
+            EmailTemplateKind::GiftCardDelivery => (
+                "You've received a {{amount}} {{currency}} gift card",
+                GIFT_CARD_DELIVERY_TEXT,
+                "You've received a gift card",
+                GIFT_CARD_DELIVERY_HTML,
+            ),
+        };
+        TemplateParts {
+            subject: subject.to_string(),
+            body_text: body_text.to_string(),
+            body_html: Some(
+                HTML_LAYOUT
+                    .replace("%TITLE%", title)
+                    .replace("%CONTENT%", content),
+            ),
+        }
+    }
+}
+
+/// Subject and bodies of a template (source) or of a rendered email
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateParts {
+    pub subject: String,
+    pub body_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_html: Option<String>,
+}
+
+impl TemplateParts {
+    /// Check that every part parses and the subject fits
+    pub fn validate(&self) -> Result<(), TemplateError> {
+        if self.subject.chars().count() > MAX_SUBJECT_CHARS {
+            return Err(TemplateError::TooLarge);
+        }
+        Template::parse(&self.subject)?;
+        Template::parse(&self.body_text)?;
+        if let Some(ref html) = self.body_html {
+            Template::parse(html)?;
+        }
+        Ok(())
+    }
+
+    /// Render all parts against `data`.
+    ///
+    /// The subject is flattened to one line so values cannot inject headers.
+    pub fn render(&self, data: &Value) -> Result<TemplateParts, TemplateError> {
+        let subject = Template::parse(&self.subject)?.render(data, Escape::None);
+        let body_text = Template::parse(&self.body_text)?.render(data, Escape::None);
+        let body_html = match self.body_html {
+            Some(ref html) => Some(Template::parse(html)?.render(data, Escape::Html)),
+            None => None,
+        };
+        Ok(TemplateParts {
+            subject: subject.split_whitespace().collect::<Vec<_>>().join(" "),
+            body_text,
+            body_html,
+        })
+    }
+}
+
+/// Template selected for a (tenant, kind, locale) after fallback
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedTemplate {
+    #[serde(flatten)]
+    pub parts: TemplateParts,
+    /// Locale of the tenant override; `None` for the built-in template
+    pub locale: Option<String>,
+}
+
+/// Normalize a language tag to the stored form (`pt_BR` → `pt-br`).
+///
+/// Returns `None` for anything that is not `letters(-alphanumerics)*`.
+pub fn normalize_locale(raw: &str) -> Option<String> {
+    let locale = raw.trim().replace('_', "-").to_ascii_lowercase();
+    let mut subtags = locale.split('-');
+    let language = subtags.next()?;
+    let valid = (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_lowercase())
+        && subtags
+            .all(|s| (1..=8).contains(&s.len()) && s.chars().all(|c| c.is_ascii_alphanumeric()));
+    valid.then_some(locale)
+}
+
+/// Locales to try in order: the requested tag, its base language, the default
+fn locale_chain(locale: Option<&str>) -> Vec<String> {
+    let mut chain = Vec::new();
+    if let Some(locale) = locale.and_then(normalize_locale) {
+        if let Some((language, _)) = locale.split_once('-') {
+            let language = language.to_string();
+            chain.push(locale);
+            chain.push(language);
+        } else {
+            chain.push(locale);
+        }
+    }
+    if !chain.iter().any(|l| l == DEFAULT_LOCALE) {
+        chain.push(DEFAULT_LOCALE.to_string());
+    }
+    chain
+}
+
+/// Pick the tenant's override for the closest locale, else the built-in.
+pub async fn resolve_template<S: Store + ?Sized>(
+    store: &S,
+    tenant_id: &str,
+    kind: EmailTemplateKind,
+    locale: Option<&str>,
+) -> StorageResult<ResolvedTemplate> {
+    for candidate in locale_chain(locale) {
+        if let Some(template) = store
+            .get_email_template(tenant_id, kind.as_str(), &candidate)
+            .await?
+        {
+            return Ok(ResolvedTemplate {
+                parts: TemplateParts {
+                    subject: template.subject,
+                    body_text: template.body_text,
+                    body_html: template.body_html,
+                },
+                locale: Some(template.locale),
+            });
+        }
+    }
+    Ok(ResolvedTemplate {
+        parts: kind.builtin(),
+        locale: None,
+    })
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Built-in templates
+// ─────────────────────────────────────────────────────────────────────────────
+
+const HTML_LAYOUT: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="utf-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>%TITLE%</title>
+</head>
+<body style="font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, Helvetica, Arial, sans-serif; margin: 0; padding: 20px; background-color: #f5f5f5;">
+    <div style="max-width: 600px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; overflow: hidden; box-shadow: 0 2px 4px rgba(0,0,0,0.1);">
+        <div style="background-color: #4F46E5; color: white; padding: 24px; text-align: center;">
+            <h1 style="margin: 0; font-size: 24px;">%TITLE%</h1>
+        </div>
+        <div style="padding: 24px; color: #374151; font-size: 16px;">
+%CONTENT%
+        </div>
+        <div style="background-color: #f9fafb; padding: 16px; text-align: center;">
+            <p style="margin: 0; color: #6b7280; font-size: 12px;">{{storeName}} · Questions? Contact our support team.</p>
+        </div>
+    </div>
+</body>
+</html>"#;
+
+const ORDER_CONFIRMATION_TEXT: &str = "Order Confirmation
+
+Thank you for your purchase{{#customerName}}, {{customerName}}{{/customerName}}!
+
+Order ID: {{orderId}}
+
+Items:
+{{#items}}
+- {{title}} (qty: {{quantity}}){{#lineTotal}} - {{lineTotal}} {{currency}}{{/lineTotal}}
+{{/items}}
+
+Total: {{total}} {{currency}}
+
+Payment Method: {{paymentMethod}}
+{{#receiptUrl}}
+Receipt: {{receiptUrl}}
+{{/receiptUrl}}
+";
+
+const ORDER_CONFIRMATION_HTML: &str = r#"            <p style="margin-bottom: 24px;">Thank you for your purchase{{#customerName}}, {{customerName}}{{/customerName}}!</p>
+            <div style="background-color: #f9fafb; padding: 16px; border-radius: 6px; margin-bottom: 24px;">
+                <p style="margin: 0; color: #6b7280; font-size: 14px;">Order ID</p>
+                <p style="margin: 4px 0 0 0; color: #111827; font-weight: 600;">{{orderId}}</p>
+            </div>
+            <table style="width: 100%; border-collapse: collapse; margin-bottom: 24px;">
+                <thead>
+                    <tr style="background-color: #f9fafb;">
+                        <th style="padding: 8px; text-align: left; color: #6b7280; font-weight: 500;">Product</th>
+                        <th style="padding: 8px; text-align: center; color: #6b7280; font-weight: 500;">Qty</th>
+                        <th style="padding: 8px; text-align: right; color: #6b7280; font-weight: 500;">Price</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {{#items}}
+                    <tr><td style="padding: 8px; border-bottom: 1px solid #eee;">{{title}}</td><td style="padding: 8px; border-bottom: 1px solid #eee; text-align: center;">{{quantity}}</td><td style="padding: 8px; border-bottom: 1px solid #eee; text-align: right;">{{lineTotal}}</td></tr>
+                    {{/items}}
+                </tbody>
+            </table>
+            <div style="border-top: 2px solid #e5e7eb; padding-top: 16px; font-size: 18px; font-weight: 600;">
+                Total: {{total}} {{currency}}
+            </div>
+            <p style="color: #6b7280; font-size: 14px; margin-top: 24px;">Payment Method: {{paymentMethod}}</p>
+            {{#receiptUrl}}
+            <p style="font-size: 14px;"><a href="{{receiptUrl}}">View your receipt</a></p>
+            {{/receiptUrl}}"#;
+
+const ORDER_SHIPPED_TEXT: &str = "Good news{{#customerName}}, {{customerName}}{{/customerName}}! Your order {{orderId}} is on its way.
+
+Items:
+{{#items}}
+- {{title}} (qty: {{quantity}})
+{{/items}}
+{{#carrier}}
+
+Carrier: {{carrier}}
+{{/carrier}}
+{{#trackingNumber}}
+Tracking number: {{trackingNumber}}
+{{/trackingNumber}}
+{{#trackingUrl}}
+Track your package: {{trackingUrl}}
+{{/trackingUrl}}
+";
+
+const ORDER_SHIPPED_HTML: &str = r#"            <p>Good news{{#customerName}}, {{customerName}}{{/customerName}}! Your order <strong>{{orderId}}</strong> is on its way.</p>
+            <ul>
+                {{#items}}
+                <li>{{title}} (qty: {{quantity}})</li>
+                {{/items}}
+            </ul>
+            {{#carrier}}
+            <p style="margin: 4px 0;">Carrier: {{carrier}}</p>
+            {{/carrier}}
+            {{#trackingNumber}}
+            <p style="margin: 4px 0;">Tracking number: {{trackingNumber}}</p>
+            {{/trackingNumber}}
+            {{#trackingUrl}}
+            <p style="margin-top: 24px;"><a href="{{trackingUrl}}">Track your package</a></p>
+            {{/trackingUrl}}"#;
+
+const REFUND_PROCESSED_TEXT: &str = "Your refund of {{amount}} {{currency}} has been processed.
+{{#orderId}}
+
+Order ID: {{orderId}}
+{{/orderId}}
+Refund ID: {{refundId}}
+{{#reason}}
+Reason: {{reason}}
+{{/reason}}
+
+Depending on your payment method, it may take a few days to appear.
+";
+
+const REFUND_PROCESSED_HTML: &str = r#"            <p>Your refund of <strong>{{amount}} {{currency}}</strong> has been processed.</p>
+            {{#orderId}}
+            <p style="margin: 4px 0;">Order ID: {{orderId}}</p>
+            {{/orderId}}
+            <p style="margin: 4px 0;">Refund ID: {{refundId}}</p>
+            {{#reason}}
+            <p style="margin: 4px 0;">Reason: {{reason}}</p>
+            {{/reason}}
+            <p style="color: #6b7280; font-size: 14px; margin-top: 24px;">Depending on your payment method, it may take a few days to appear.</p>"#;
+
+const SUBSCRIPTION_RENEWED_TEXT: &str = "Your {{productTitle}} subscription has renewed.
+
+Your current period now runs until {{periodEnd}}.
+
+Subscription ID: {{subscriptionId}}
+";
+
+const SUBSCRIPTION_RENEWED_HTML: &str = r#"            <p>Your <strong>{{productTitle}}</strong> subscription has renewed.</p>
+            <p>Your current period now runs until {{periodEnd}}.</p>
+            <p style="color: #6b7280; font-size: 14px;">Subscription ID: {{subscriptionId}}</p>"#;
+
+const SUBSCRIPTION_PAYMENT_FAILED_TEXT: &str =
+    "We couldn't collect the payment for your {{productTitle}} subscription.
+
+Please update your payment method to keep your subscription active.
+{{#updatePaymentUrl}}
+Update payment method: {{updatePaymentUrl}}
+{{/updatePaymentUrl}}
+
+Subscription ID: {{subscriptionId}}
+";
+
+const SUBSCRIPTION_PAYMENT_FAILED_HTML: &str = r#"            <p>We couldn't collect the payment for your <strong>{{productTitle}}</strong> subscription.</p>
+            <p>Please update your payment method to keep your subscription active.</p>
+            {{#updatePaymentUrl}}
+            <p style="margin-top: 24px;"><a href="{{updatePaymentUrl}}">Update payment method</a></p>
+            {{/updatePaymentUrl}}
+            <p style="color: #6b7280; font-size: 14px;">Subscription ID: {{subscriptionId}}</p>"#;
+
+const GIFT_CARD_DELIVERY_TEXT: &str = "You've received a {{amount}} {{currency}} {{productTitle}}!
+
+Your claim code: {{claimCode}}
+
+Redeem it at checkout or from your account to add the credit to your balance.
+";
+
+const GIFT_CARD_DELIVERY_HTML: &str = r#"            <p>You've received a <strong>{{amount}} {{currency}}</strong> {{productTitle}}!</p>
+            <div style="background-color: #f9fafb; padding: 16px; border-radius: 6px; margin: 24px 0; text-align: center;">
+                <p style="margin: 0; color: #6b7280; font-size: 14px;">Your claim code</p>
+                <p style="margin: 4px 0 0 0; color: #111827; font-family: monospace; font-size: 16px; font-weight: 600;">{{claimCode}}</p>
+            </div>
+            <p>Redeem it at checkout or from your account to add the credit to your balance.</p>"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{EmailTemplate, InMemoryStore};
+    use chrono::Utc;
+
+    #[test]
+    fn test_builtin_templates_render_sample_data() {
+        for kind in EmailTemplateKind::ALL {
+            let rendered = kind
+                .builtin()
+                .render(&kind.sample_data())
+                .unwrap_or_else(|e| panic!("{}: {}", kind.as_str(), e));
+            assert!(!rendered.subject.contains("{{"), "{}", kind.as_str());
+            assert!(!rendered.subject.is_empty());
+            assert!(rendered.body_html.unwrap().contains("Example Store"));
+        }
+    }
+
+    #[test]
+    fn test_order_confirmation_lists_titles_and_prices() {
+        let kind = EmailTemplateKind::OrderConfirmation;
+        let rendered = kind.builtin().render(&kind.sample_data()).unwrap();
+        assert_eq!(rendered.subject, "Order Confirmation - ord_7f3a9c");
+        assert!(rendered
+            .body_text
+            .contains("- Ceramic Mug (qty: 2) - 24.00 USD\n- Logo T-Shirt (qty: 1) - 25.00 USD\n"));
+        assert!(!rendered.body_text.contains("prod_mug"));
+        assert!(rendered.body_html.unwrap().contains(">Ceramic Mug</td>"));
+    }
+
+    #[test]
+    fn test_subject_is_single_line() {
+        let parts = TemplateParts {
+            subject: "Order {{orderId}}".into(),
+            body_text: String::new(),
+            body_html: None,
+        };
+        let rendered = parts
+            .render(&json!({ "orderId": "1\r\nBcc: victim@example.com" }))
+            .unwrap();
+        assert_eq!(rendered.subject, "Order 1 Bcc: victim@example.com");
+    }
+
+    #[test]
+    fn test_normalize_locale() {
+        assert_eq!(normalize_locale("pt_BR").as_deref(), Some("pt-br"));
+        assert_eq!(normalize_locale(" EN ").as_deref(), Some("en"));
+        assert_eq!(
+            normalize_locale("zh-Hant-TW").as_deref(),
+            Some("zh-hant-tw")
+        );
+        assert_eq!(normalize_locale("e"), None);
+        assert_eq!(normalize_locale("en-"), None);
+        assert_eq!(normalize_locale("../etc"), None);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_falls_back_by_locale() {
+        let store = InMemoryStore::new();
+        let kind = EmailTemplateKind::OrderShipped;
+        for (tenant_id, locale, subject) in [
+            ("tenant-a", "pt", "Pedido enviado"),
+            ("tenant-a", "en", "Shipped!"),
+            ("tenant-b", "pt", "Outro"),
+        ] {
+            store
+                .upsert_email_template(EmailTemplate {
+                    tenant_id: tenant_id.into(),
+                    kind: kind.as_str().into(),
+                    locale: locale.into(),
+                    subject: subject.into(),
+                    body_text: "{{orderId}}".into(),
+                    body_html: None,
+                    updated_at: Utc::now(),
+                })
+                .await
+                .unwrap();
+        }
+
+        let resolved = resolve_template(&store, "tenant-a", kind, Some("pt-BR"))
+            .await
+            .unwrap();
+        assert_eq!(resolved.parts.subject, "Pedido enviado");
+        assert_eq!(resolved.locale.as_deref(), Some("pt"));
+
+        let resolved = resolve_template(&store, "tenant-a", kind, Some("de"))
+            .await
+            .unwrap();
+        assert_eq!(resolved.parts.subject, "Shipped!");
+
+        let resolved = resolve_template(&store, "tenant-c", kind, Some("pt"))
+            .await
+            .unwrap();
+        assert_eq!(resolved.locale, None);
+        assert_eq!(resolved.parts, kind.builtin());
+    }
+}
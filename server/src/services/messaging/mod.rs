@@ -1,9 +1,17 @@
-//! Messaging service for email receipts and webhook notifications on order events.
+//! Messaging service for transactional emails and webhook notifications on order events.
 //!
 //! This module provides:
-//! - Email receipts to customers after purchase (queued via email_queue, sent by email worker)
+//! - Transactional emails to customers (order confirmation, shipment, refund,
+//!   subscription renewal/failed payment, gift card delivery), rendered from
+//!   tenant-editable templates and queued via email_queue for the email worker
 //! - Webhook notifications to admin after purchase (with HMAC-SHA256 signing)
+//! - Pluggable email transports (SMTP or HTTP API), selected per tenant
 
+pub mod email_templates;
+pub mod template;
+pub mod transport;
+
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -12,10 +20,16 @@ use hmac::{Hmac, Mac};
 use sha2::Sha256;
 
 use crate::config::MessagingConfig;
-use crate::models::Order;
+use crate::models::{get_asset, Fulfillment, Order, OrderItem, Product};
+use crate::repositories::ProductRepository;
 use crate::storage::{PendingEmail, Store};
 use crate::x402::utils::hex_encode;
 
+use email_templates::{resolve_template, EmailTemplateKind, ResolvedTemplate};
+
+/// Order/subscription metadata key selecting the email locale (e.g. `pt-BR`)
+pub const LOCALE_METADATA_KEY: &str = "locale";
+
 type HmacSha256 = Hmac<Sha256>;
 
 /// Event payload for order.created webhook
@@ -46,12 +60,61 @@ pub struct TotalPayload {
     pub currency: String,
 }
 
+/// Refund email details
+#[derive(Debug, Clone)]
+pub struct RefundNotice {
+    pub tenant_id: String,
+    pub to_email: String,
+    pub refund_id: String,
+    pub order_id: Option<String>,
+    pub customer_name: Option<String>,
+    /// Refunded amount in atomic units of `currency`
+    pub amount: i64,
+    pub currency: String,
+    pub reason: Option<String>,
+    pub locale: Option<String>,
+}
+
+/// Subscription renewal / failed payment email details
+#[derive(Debug, Clone)]
+pub struct SubscriptionNotice {
+    pub tenant_id: String,
+    pub to_email: String,
+    pub subscription_id: String,
+    pub product_id: String,
+    pub period_end: DateTime<Utc>,
+    /// Where the customer can fix a failed payment (e.g. Stripe hosted invoice)
+    pub update_payment_url: Option<String>,
+    pub locale: Option<String>,
+}
+
+/// Gift card claim email details
+#[derive(Debug, Clone)]
+pub struct GiftCardDelivery {
+    pub tenant_id: String,
+    pub to_email: String,
+    pub order_id: String,
+    pub product_id: String,
+    pub face_value_cents: i64,
+    pub currency: String,
+    /// One-time token for `POST /paywall/v1/gift-card/claim/{token}`
+    pub claim_code: String,
+    pub locale: Option<String>,
+}
+
 /// Messaging service trait for order notifications
 #[async_trait]
 pub trait MessagingService: Send + Sync {
     /// Send order notifications (email + webhook) after a successful order.
     /// Email is sent to order.customer_email if present and email_enabled is true.
     async fn notify_order_created(&self, order: &Order);
+    /// Email order.customer_email that a fulfillment has shipped.
+    async fn notify_order_shipped(&self, order: &Order, fulfillment: &Fulfillment);
+    async fn notify_refund_processed(&self, refund: &RefundNotice);
+    async fn notify_subscription_renewed(&self, notice: &SubscriptionNotice);
+    async fn notify_subscription_payment_failed(&self, notice: &SubscriptionNotice);
+    /// Email a gift card recipient their claim code.
+    async fn notify_gift_card_delivered(&self, delivery: &GiftCardDelivery);
 }
 
 /// No-op messaging service for when messaging is disabled
@@ -61,6 +124,11 @@ pub struct NoopMessagingService;
 #[async_trait]
 impl MessagingService for NoopMessagingService {
     async fn notify_order_created(&self, _order: &Order) {}
+    async fn notify_order_shipped(&self, _order: &Order, _fulfillment: &Fulfillment) {}
+    async fn notify_refund_processed(&self, _refund: &RefundNotice) {}
+    async fn notify_subscription_renewed(&self, _notice: &SubscriptionNotice) {}
+    async fn notify_subscription_payment_failed(&self, _notice: &SubscriptionNotice) {}
+    async fn notify_gift_card_delivered(&self, _delivery: &GiftCardDelivery) {}
 }
 
 /// HTTP messaging service for email and webhook notifications
@@ -68,6 +136,8 @@ pub struct HttpMessagingService<S: Store> {
     config: MessagingConfig,
     http_client: reqwest::Client,
     store: Arc<S>,
+    /// Resolves product titles and prices for email line items
+    product_repo: Option<Arc<dyn ProductRepository>>,
 }
 
 impl<S: Store + 'static> HttpMessagingService<S> {
@@ -81,9 +151,16 @@ impl<S: Store + 'static> HttpMessagingService<S> {
             config,
             http_client,
             store,
+            product_repo: None,
         }
     }
 
+    /// Show product titles and prices instead of product IDs in emails
+    pub fn with_product_repository(mut self, repo: Arc<dyn ProductRepository>) -> Self {
+        self.product_repo = Some(repo);
+        self
+    }
+
     /// Sign the payload with HMAC-SHA256
     fn sign_payload(&self, payload_bytes: &[u8]) -> Option<String> {
         if self.config.webhook_secret.is_empty() {
@@ -194,42 +271,55 @@ impl<S: Store + 'static> HttpMessagingService<S> {
         }
     }
 
-    /// Queue email receipt for async delivery by the email worker
-    async fn queue_email_receipt(&self, order: &Order, customer_email: &str) {
+    /// Render the tenant's template for `kind` and queue it for the email worker
+    async fn queue_email(
+        &self,
+        tenant_id: &str,
+        to_email: &str,
+        kind: EmailTemplateKind,
+        locale: Option<&str>,
+        mut data: serde_json::Value,
+    ) {
         if !self.config.email_enabled {
             return;
         }
+        data["storeName"] = serde_json::Value::String(self.config.from_name.clone());
 
-        // Build plain text email body
-        let mut body_text = format!(
-            "Order Confirmation\n\nThank you for your purchase!\n\nOrder ID: {}\n\nItems:\n",
-            order.id
-        );
-
-        for item in &order.items {
-            body_text.push_str(&format!("- {} (qty: {})\n", item.product_id, item.quantity));
-        }
-
-        // amount is in smallest units (cents for USD), convert to major units for display
-        let amount_major = order.amount as f64 / 100.0;
-        body_text.push_str(&format!(
-            "\nTotal: {:.2} {}\n\nPayment Method: {}\n",
-            amount_major, order.amount_asset, order.source
-        ));
-
-        // Build HTML email body
-        let body_html = self.build_html_receipt(order);
+        let resolved = match resolve_template(&*self.store, tenant_id, kind, locale).await {
+            Ok(resolved) => resolved,
+            Err(e) => {
+                tracing::warn!(error = %e, tenant_id = %tenant_id, kind = kind.as_str(), "Failed to load email template; using built-in");
+                ResolvedTemplate {
+                    parts: kind.builtin(),
+                    locale: None,
+                }
+            }
+        };
+        // Overrides are validated on save; fall back rather than drop the email
+        let rendered = match resolved.parts.render(&data) {
+            Ok(rendered) => rendered,
+            Err(e) => {
+                tracing::warn!(error = %e, tenant_id = %tenant_id, kind = kind.as_str(), "Email template failed to render; using built-in");
+                match kind.builtin().render(&data) {
+                    Ok(rendered) => rendered,
+                    Err(e) => {
+                        tracing::error!(error = %e, kind = kind.as_str(), "Built-in email template failed to render");
+                        return;
+                    }
+                }
+            }
+        };
 
         let email_id = format!("email_{}", uuid::Uuid::new_v4());
         let pending_email = PendingEmail {
             id: email_id.clone(),
-            tenant_id: order.tenant_id.clone(),
-            to_email: customer_email.to_string(),
+            tenant_id: tenant_id.to_string(),
+            to_email: to_email.to_string(),
             from_email: self.config.from_email.clone(),
             from_name: self.config.from_name.clone(),
-            subject: format!("Order Confirmation - {}", order.id),
-            body_text,
-            body_html: Some(body_html),
+            subject: rendered.subject,
+            body_text: rendered.body_text,
+            body_html: rendered.body_html,
             status: crate::storage::EmailStatus::Pending,
             attempts: 0,
             max_attempts: 5,
@@ -238,97 +328,147 @@ impl<S: Store + 'static> HttpMessagingService<S> {
             next_attempt_at: None,
             created_at: Utc::now(),
             completed_at: None,
+            traceparent: crate::observability::otel::current_traceparent(),
+            provider: None,
+            provider_message_id: None,
         };
 
         match self.store.enqueue_email(pending_email).await {
             Ok(_) => {
                 tracing::info!(
                     email_id = %email_id,
-                    order_id = %order.id,
-                    customer_email = %customer_email,
-                    "Queued order receipt email for delivery"
+                    tenant_id = %tenant_id,
+                    kind = kind.as_str(),
+                    to_email = %to_email,
+                    "Queued email for delivery"
                 );
             }
             Err(e) => {
                 tracing::error!(
                     error = %e,
-                    order_id = %order.id,
-                    customer_email = %customer_email,
-                    "Failed to queue order receipt email"
+                    tenant_id = %tenant_id,
+                    kind = kind.as_str(),
+                    to_email = %to_email,
+                    "Failed to queue email"
                 );
             }
         }
     }
 
-    /// Build HTML email receipt
-    fn build_html_receipt(&self, order: &Order) -> String {
-        let amount_major = order.amount as f64 / 100.0;
+    /// Look up a product for display; `None` when unavailable
+    async fn product(&self, tenant_id: &str, product_id: &str) -> Option<Product> {
+        let repo = self.product_repo.as_ref()?;
+        match repo.get_product(tenant_id, product_id).await {
+            Ok(product) => Some(product),
+            Err(e) => {
+                tracing::debug!(error = %e, product_id = %product_id, "Product lookup for email failed");
+                None
+            }
+        }
+    }
 
-        let items_html: String = order
-            .items
-            .iter()
-            .map(|item| {
-                format!(
-                    r#"<tr><td style="padding: 8px; border-bottom: 1px solid #eee;">{}</td><td style="padding: 8px; border-bottom: 1px solid #eee; text-align: center;">{}</td></tr>"#,
-                    item.product_id, item.quantity
-                )
-            })
-            .collect();
+    async fn product_title(&self, tenant_id: &str, product_id: &str) -> String {
+        self.product(tenant_id, product_id)
+            .await
+            .and_then(|p| p.title)
+            .unwrap_or_else(|| product_id.to_string())
+    }
 
-        format!(
-            r#"<!DOCTYPE html>
-<html>
-<head>
-    <meta charset="utf-8">
-    <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>Order Confirmation</title>
-</head>
-<body style="font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, Helvetica, Arial, sans-serif; margin: 0; padding: 20px; background-color: #f5f5f5;">
-    <div style="max-width: 600px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; overflow: hidden; box-shadow: 0 2px 4px rgba(0,0,0,0.1);">
-        <div style="background-color: #4F46E5; color: white; padding: 24px; text-align: center;">
-            <h1 style="margin: 0; font-size: 24px;">Order Confirmation</h1>
-        </div>
-        <div style="padding: 24px;">
-            <p style="color: #374151; font-size: 16px; margin-bottom: 24px;">Thank you for your purchase!</p>
-            <div style="background-color: #f9fafb; padding: 16px; border-radius: 6px; margin-bottom: 24px;">
-                <p style="margin: 0; color: #6b7280; font-size: 14px;">Order ID</p>
-                <p style="margin: 4px 0 0 0; color: #111827; font-size: 16px; font-weight: 600;">{order_id}</p>
-            </div>
-            <h2 style="color: #111827; font-size: 18px; margin-bottom: 16px;">Items</h2>
-            <table style="width: 100%; border-collapse: collapse; margin-bottom: 24px;">
-                <thead>
-                    <tr style="background-color: #f9fafb;">
-                        <th style="padding: 8px; text-align: left; color: #6b7280; font-weight: 500;">Product</th>
-                        <th style="padding: 8px; text-align: center; color: #6b7280; font-weight: 500;">Qty</th>
-                    </tr>
-                </thead>
-                <tbody>
-                    {items}
-                </tbody>
-            </table>
-            <div style="border-top: 2px solid #e5e7eb; padding-top: 16px;">
-                <div style="display: flex; justify-content: space-between; align-items: center;">
-                    <span style="color: #374151; font-size: 18px; font-weight: 600;">Total</span>
-                    <span style="color: #111827; font-size: 24px; font-weight: 700;">{amount:.2} {currency}</span>
-                </div>
-            </div>
-            <p style="color: #6b7280; font-size: 14px; margin-top: 24px;">Payment Method: {payment_method}</p>
-        </div>
-        <div style="background-color: #f9fafb; padding: 16px; text-align: center;">
-            <p style="margin: 0; color: #6b7280; font-size: 12px;">Questions? Contact our support team.</p>
-        </div>
-    </div>
-</body>
-</html>"#,
-            order_id = order.id,
-            items = items_html,
-            amount = amount_major,
-            currency = order.amount_asset,
-            payment_method = order.source
+    /// Template items with product titles and, when the order recorded what
+    /// was paid for the line, unit and line prices. Catalog prices are never
+    /// used, since they may have changed since checkout.
+    async fn item_data(&self, order: &Order, items: &[OrderItem]) -> Vec<serde_json::Value> {
+        let mut data = Vec::with_capacity(items.len());
+        for item in items {
+            let title = self
+                .product(&order.tenant_id, &item.product_id)
+                .await
+                .and_then(|p| p.title)
+                .unwrap_or_else(|| item.product_id.clone());
+            let mut entry = serde_json::json!({
+                "productId": item.product_id,
+                "variantId": item.variant_id,
+                "title": title,
+                "quantity": item.quantity,
+            });
+            let (unit_price, line_total) = paid_prices(order, item);
+            if let Some(unit) = unit_price {
+                entry["unitPrice"] = format_amount(unit, &order.amount_asset).into();
+            }
+            if let Some(line) = line_total {
+                entry["lineTotal"] = format_amount(line, &order.amount_asset).into();
+            }
+            data.push(entry);
+        }
+        data
+    }
+
+    /// Shared body of the renewal and failed-payment emails
+    async fn queue_subscription_email(&self, notice: &SubscriptionNotice, kind: EmailTemplateKind) {
+        let data = serde_json::json!({
+            "subscriptionId": notice.subscription_id,
+            "productId": notice.product_id,
+            "productTitle": self.product_title(&notice.tenant_id, &notice.product_id).await,
+            "periodEnd": notice.period_end.format("%Y-%m-%d").to_string(),
+            "updatePaymentUrl": notice.update_payment_url,
+        });
+        self.queue_email(
+            &notice.tenant_id,
+            &notice.to_email,
+            kind,
+            notice.locale.as_deref(),
+            data,
         )
+        .await;
     }
 }
 
+/// Format an atomic amount in major units using the asset's decimals.
+///
+/// Trailing zeros past two decimals are dropped, so 6-decimal tokens show
+/// `1.50` rather than `1.500000`. Unknown assets are treated as cents.
+pub fn format_amount(atomic: i64, asset_code: &str) -> String {
+    let decimals = get_asset(asset_code).map_or(2, |a| a.decimals as usize);
+    let divisor = 10_u64.pow(decimals as u32);
+    let sign = if atomic < 0 { "-" } else { "" };
+    let whole = atomic.unsigned_abs() / divisor;
+    let frac = atomic.unsigned_abs() % divisor;
+    if decimals == 0 {
+        return format!("{}{}", sign, whole);
+    }
+    let mut frac = format!("{:0width$}", frac, width = decimals);
+    while frac.len() > 2 && frac.ends_with('0') {
+        frac.pop();
+    }
+    format!("{}{}.{}", sign, whole, frac)
+}
+
+/// Unit price and line total paid for `item`, taken from the matching order
+/// line. A partial quantity (e.g. one shipment) is priced only when the line
+/// divides evenly into units.
+fn paid_prices(order: &Order, item: &OrderItem) -> (Option<i64>, Option<i64>) {
+    let Some((paid, quantity)) = order
+        .items
+        .iter()
+        .find(|line| line.product_id == item.product_id && line.variant_id == item.variant_id)
+        .and_then(|line| Some((line.line_total?, line.quantity)))
+    else {
+        return (None, None);
+    };
+    let unit = (quantity > 0 && paid % quantity as i64 == 0).then(|| paid / quantity as i64);
+    let line = if item.quantity == quantity {
+        Some(paid)
+    } else {
+        unit.map(|u| u.saturating_mul(item.quantity as i64))
+    };
+    (unit, line)
+}
+
+/// Locale hint carried in order or subscription metadata
+fn metadata_locale(metadata: &HashMap<String, String>) -> Option<&str> {
+    metadata.get(LOCALE_METADATA_KEY).map(String::as_str)
+}
+
 #[async_trait]
 impl<S: Store + 'static> MessagingService for HttpMessagingService<S> {
     async fn notify_order_created(&self, order: &Order) {
@@ -336,9 +476,108 @@ impl<S: Store + 'static> MessagingService for HttpMessagingService<S> {
         self.send_webhook(order).await;
 
         // Queue email receipt if customer email provided (delivered by email worker)
-        if let Some(ref email) = order.customer_email {
-            self.queue_email_receipt(order, email).await;
+        let Some(ref email) = order.customer_email else {
+            return;
+        };
+        if !self.config.email_enabled {
+            return;
+        }
+        let data = serde_json::json!({
+            "orderId": order.id,
+            "customerName": order.customer_name,
+            "items": self.item_data(order, &order.items).await,
+            "total": format_amount(order.amount, &order.amount_asset),
+            "currency": order.amount_asset,
+            "paymentMethod": order.source,
+            "receiptUrl": order.receipt_url,
+        });
+        self.queue_email(
+            &order.tenant_id,
+            email,
+            EmailTemplateKind::OrderConfirmation,
+            metadata_locale(&order.metadata),
+            data,
+        )
+        .await;
+    }
+
+    async fn notify_order_shipped(&self, order: &Order, fulfillment: &Fulfillment) {
+        let Some(ref email) = order.customer_email else {
+            return;
+        };
+        if !self.config.email_enabled {
+            return;
         }
+        // Partial shipments list only the items in this fulfillment
+        let items = if fulfillment.items.is_empty() {
+            &order.items
+        } else {
+            &fulfillment.items
+        };
+        let data = serde_json::json!({
+            "orderId": order.id,
+            "customerName": order.customer_name,
+            "items": self.item_data(order, items).await,
+            "carrier": fulfillment.carrier,
+            "trackingNumber": fulfillment.tracking_number,
+            "trackingUrl": fulfillment.tracking_url,
+        });
+        self.queue_email(
+            &order.tenant_id,
+            email,
+            EmailTemplateKind::OrderShipped,
+            metadata_locale(&order.metadata),
+            data,
+        )
+        .await;
+    }
+
+    async fn notify_refund_processed(&self, refund: &RefundNotice) {
+        let data = serde_json::json!({
+            "refundId": refund.refund_id,
+            "orderId": refund.order_id,
+            "customerName": refund.customer_name,
+            "amount": format_amount(refund.amount, &refund.currency),
+            "currency": refund.currency.to_uppercase(),
+            "reason": refund.reason,
+        });
+        self.queue_email(
+            &refund.tenant_id,
+            &refund.to_email,
+            EmailTemplateKind::RefundProcessed,
+            refund.locale.as_deref(),
+            data,
+        )
+        .await;
+    }
+
+    async fn notify_subscription_renewed(&self, notice: &SubscriptionNotice) {
+        self.queue_subscription_email(notice, EmailTemplateKind::SubscriptionRenewed)
+            .await;
+    }
+
+    async fn notify_subscription_payment_failed(&self, notice: &SubscriptionNotice) {
+        self.queue_subscription_email(notice, EmailTemplateKind::SubscriptionPaymentFailed)
+            .await;
+    }
+
+    async fn notify_gift_card_delivered(&self, delivery: &GiftCardDelivery) {
+        let data = serde_json::json!({
+            "orderId": delivery.order_id,
+            "productTitle": self.product_title(&delivery.tenant_id, &delivery.product_id).await,
+            "amount": format_amount(delivery.face_value_cents, &delivery.currency),
+            "currency": delivery.currency.to_uppercase(),
+            "claimCode": delivery.claim_code,
+            "recipientEmail": delivery.to_email,
+        });
+        self.queue_email(
+            &delivery.tenant_id,
+            &delivery.to_email,
+            EmailTemplateKind::GiftCardDelivery,
+            delivery.locale.as_deref(),
+            data,
+        )
+        .await;
     }
 }
 
@@ -346,9 +585,12 @@ impl<S: Store + 'static> MessagingService for HttpMessagingService<S> {
 pub fn create_messaging_service<S: Store + 'static>(
     config: &MessagingConfig,
     store: Arc<S>,
+    product_repo: Arc<dyn ProductRepository>,
 ) -> Arc<dyn MessagingService> {
     if config.email_enabled || config.webhook_enabled {
-        Arc::new(HttpMessagingService::new(config.clone(), store))
+        Arc::new(
+            HttpMessagingService::new(config.clone(), store).with_product_repository(product_repo),
+        )
     } else {
         Arc::new(NoopMessagingService)
     }
@@ -357,8 +599,9 @@ pub fn create_messaging_service<S: Store + 'static>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::OrderItem;
-    use crate::storage::InMemoryStore;
+    use crate::models::Money;
+    use crate::repositories::InMemoryProductRepository;
+    use crate::storage::{EmailTemplate, InMemoryStore};
     use std::collections::HashMap;
     use std::time::Duration;
 
@@ -376,8 +619,9 @@ mod tests {
                 product_id: "product-1".to_string(),
                 variant_id: None,
                 quantity: 2,
+                line_total: Some(1800),
             }],
-            amount: 2000,
+            amount: 1800,
             amount_asset: "USD".to_string(),
             customer_email: Some("test@example.com".to_string()),
             customer_name: Some("Test User".to_string()),
@@ -431,4 +675,130 @@ mod tests {
         // Should not panic or error
         service.notify_order_created(&order).await;
     }
+
+    #[test]
+    fn test_format_amount() {
+        assert_eq!(format_amount(2000, "USD"), "20.00");
+        assert_eq!(format_amount(-5, "USD"), "-0.05");
+        assert_eq!(format_amount(1_500_000, "USDC"), "1.50");
+        assert_eq!(format_amount(1_234_567, "USDC"), "1.234567");
+        assert_eq!(format_amount(123, "UNKNOWN"), "1.23");
+    }
+
+    fn email_service(store: Arc<InMemoryStore>) -> HttpMessagingService<InMemoryStore> {
+        let config = MessagingConfig {
+            email_enabled: true,
+            from_email: "shop@example.com".to_string(),
+            from_name: "Example Shop".to_string(),
+            ..Default::default()
+        };
+        let product = Product {
+            id: "product-1".to_string(),
+            tenant_id: "default".to_string(),
+            title: Some("Ceramic <Mug>".to_string()),
+            fiat_price: Some(Money::from_atomic(get_asset("USD").unwrap(), 1000)),
+            ..Default::default()
+        };
+        HttpMessagingService::new(config, store)
+            .with_product_repository(Arc::new(InMemoryProductRepository::new(vec![product])))
+    }
+
+    #[tokio::test]
+    async fn test_order_receipt_lists_titles_and_prices() {
+        let store = Arc::new(InMemoryStore::new());
+        let service = email_service(store.clone());
+
+        service.notify_order_created(&sample_order()).await;
+
+        let emails = store.dequeue_emails(10).await.unwrap();
+        assert_eq!(emails.len(), 1);
+        let email = &emails[0];
+        assert_eq!(email.to_email, "test@example.com");
+        assert_eq!(email.subject, "Order Confirmation - ord_test123");
+        // The paid line total, not the 10.00 catalog price
+        assert!(email
+            .body_text
+            .contains("- Ceramic <Mug> (qty: 2) - 18.00 USD"));
+        assert!(email.body_text.contains("Total: 18.00 USD"));
+        let html = email.body_html.as_deref().unwrap();
+        assert!(html.contains("Ceramic &lt;Mug&gt;"));
+        assert!(html.contains("Example Shop"));
+    }
+
+    #[tokio::test]
+    async fn test_order_receipt_omits_unrecorded_prices() {
+        let store = Arc::new(InMemoryStore::new());
+        let service = email_service(store.clone());
+
+        let mut order = sample_order();
+        order.items[0].line_total = None;
+        service.notify_order_created(&order).await;
+
+        let emails = store.dequeue_emails(10).await.unwrap();
+        assert!(emails[0].body_text.contains("- Ceramic <Mug> (qty: 2)\n"));
+    }
+
+    #[test]
+    fn test_paid_prices_for_partial_shipment() {
+        let order = sample_order();
+        let mut shipped = order.items[0].clone();
+        shipped.quantity = 1;
+        assert_eq!(paid_prices(&order, &shipped), (Some(900), Some(900)));
+
+        let mut uneven = sample_order();
+        uneven.items[0].line_total = Some(1001);
+        assert_eq!(paid_prices(&uneven, &uneven.items[0]), (None, Some(1001)));
+        assert_eq!(paid_prices(&uneven, &shipped), (None, None));
+    }
+
+    #[tokio::test]
+    async fn test_tenant_template_used_for_order_locale() {
+        let store = Arc::new(InMemoryStore::new());
+        store
+            .upsert_email_template(EmailTemplate {
+                tenant_id: "default".to_string(),
+                kind: "order_confirmation".to_string(),
+                locale: "de".to_string(),
+                subject: "Bestellung {{orderId}}".to_string(),
+                body_text: "{{#items}}{{quantity}}x {{title}}\n{{/items}}".to_string(),
+                body_html: None,
+                updated_at: Utc::now(),
+            })
+            .await
+            .unwrap();
+        let service = email_service(store.clone());
+
+        let mut order = sample_order();
+        order
+            .metadata
+            .insert(LOCALE_METADATA_KEY.to_string(), "de-AT".to_string());
+        service.notify_order_created(&order).await;
+
+        let emails = store.dequeue_emails(10).await.unwrap();
+        assert_eq!(emails[0].subject, "Bestellung ord_test123");
+        assert_eq!(emails[0].body_text, "2x Ceramic <Mug>\n");
+        assert!(emails[0].body_html.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_email_disabled_queues_nothing() {
+        let store = Arc::new(InMemoryStore::new());
+        let service = HttpMessagingService::new(MessagingConfig::default(), store.clone());
+
+        service.notify_order_created(&sample_order()).await;
+        service
+            .notify_gift_card_delivered(&GiftCardDelivery {
+                tenant_id: "default".to_string(),
+                to_email: "friend@example.com".to_string(),
+                order_id: "ord_test123".to_string(),
+                product_id: "gift".to_string(),
+                face_value_cents: 5000,
+                currency: "USD".to_string(),
+                claim_code: "code".to_string(),
+                locale: None,
+            })
+            .await;
+
+        assert!(store.dequeue_emails(10).await.unwrap().is_empty());
+    }
 }
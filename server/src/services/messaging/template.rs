@@ -0,0 +1,370 @@
+//! Logic-less template language for transactional emails.
+//!
+//! The syntax is a strict subset of Mustache:
+//! - `{{name}}`, `{{order.id}}` — insert a value; `{{.}}` is the current item
+//! - `{{#name}}…{{/name}}` — repeat for each element of an array, or render
+//!   once when the value is present and truthy
+//! - `{{^name}}…{{/name}}` — render when the value is missing or falsy
+//! - `{{! comment }}` — ignored
+//!
+//! Templates are edited by tenants, so the language is deliberately small:
+//! no raw (unescaped) output, no partials, no delimiter changes and no code.
+//! Values are HTML-escaped when rendering HTML bodies.
+
+use serde_json::Value;
+
+/// Largest accepted template source
+pub const MAX_TEMPLATE_BYTES: usize = 64 * 1024;
+
+/// Deepest accepted section nesting
+const MAX_SECTION_DEPTH: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TemplateError {
+    #[error("template exceeds {MAX_TEMPLATE_BYTES} bytes")]
+    TooLarge,
+    #[error("unclosed tag at byte {0}")]
+    UnclosedTag(usize),
+    #[error("invalid tag '{0}'")]
+    InvalidTag(String),
+    #[error("unexpected closing tag '{0}'")]
+    UnexpectedClose(String),
+    #[error("section '{0}' is never closed")]
+    UnclosedSection(String),
+    #[error("sections nested deeper than {MAX_SECTION_DEPTH}")]
+    TooDeep,
+}
+
+/// How inserted values are escaped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Escape {
+    Html,
+    None,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Text(String),
+    /// Dotted lookup path; empty for `{{.}}`
+    Var(Vec<String>),
+    Section {
+        path: Vec<String>,
+        inverted: bool,
+        children: Vec<Node>,
+    },
+}
+
+/// A parsed template, ready to render any number of times.
+#[derive(Debug, Clone)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+enum TagKind {
+    Var,
+    Open { inverted: bool },
+    Close,
+    Comment,
+}
+
+struct Frame {
+    name: String,
+    path: Vec<String>,
+    inverted: bool,
+    nodes: Vec<Node>,
+}
+
+impl Template {
+    pub fn parse(source: &str) -> Result<Self, TemplateError> {
+        if source.len() > MAX_TEMPLATE_BYTES {
+            return Err(TemplateError::TooLarge);
+        }
+
+        let mut stack: Vec<Frame> = Vec::new();
+        let mut nodes: Vec<Node> = Vec::new();
+        let mut text = String::new();
+        let mut rest = source;
+        // Whether `text` starts at the beginning of a line
+        let mut at_line_start = true;
+
+        while let Some(open) = rest.find("{{") {
+            let offset = source.len() - rest.len() + open;
+            let after_open = &rest[open + 2..];
+            let close = after_open
+                .find("}}")
+                .ok_or(TemplateError::UnclosedTag(offset))?;
+            let raw = after_open[..close].trim();
+            let after_tag = &after_open[close + 2..];
+            text.push_str(&rest[..open]);
+
+            let (kind, name) = match raw.chars().next() {
+                Some('#') => (TagKind::Open { inverted: false }, raw[1..].trim()),
+                Some('^') => (TagKind::Open { inverted: true }, raw[1..].trim()),
+                Some('/') => (TagKind::Close, raw[1..].trim()),
+                Some('!') => (TagKind::Comment, ""),
+                _ => (TagKind::Var, raw),
+            };
+
+            // Section and comment tags alone on a line leave no blank line behind
+            let mut next = after_tag;
+            if !matches!(kind, TagKind::Var) {
+                let line_start = text.rfind('\n').map(|i| i + 1);
+                let indent = &text[line_start.unwrap_or(0)..];
+                let trailing = next.find('\n').map_or(next, |i| &next[..i]);
+                if (line_start.is_some() || at_line_start)
+                    && indent.trim().is_empty()
+                    && trailing.trim().is_empty()
+                {
+                    text.truncate(text.len() - indent.len());
+                    next = next.find('\n').map_or("", |i| &next[i + 1..]);
+                }
+            }
+            at_line_start = next.len() != after_tag.len();
+
+            let current = stack.last_mut().map_or(&mut nodes, |f| &mut f.nodes);
+            if !text.is_empty() {
+                current.push(Node::Text(std::mem::take(&mut text)));
+            }
+
+            match kind {
+                TagKind::Comment => {}
+                TagKind::Var => current.push(Node::Var(parse_path(name)?)),
+                TagKind::Open { inverted } => {
+                    if stack.len() >= MAX_SECTION_DEPTH {
+                        return Err(TemplateError::TooDeep);
+                    }
+                    stack.push(Frame {
+                        name: name.to_string(),
+                        path: parse_path(name)?,
+                        inverted,
+                        nodes: Vec::new(),
+                    });
+                }
+                TagKind::Close => {
+                    let frame = match stack.pop() {
+                        Some(frame) if frame.name == name => frame,
+                        _ => return Err(TemplateError::UnexpectedClose(name.to_string())),
+                    };
+                    let section = Node::Section {
+                        path: frame.path,
+                        inverted: frame.inverted,
+                        children: frame.nodes,
+                    };
+                    stack
+                        .last_mut()
+                        .map_or(&mut nodes, |f| &mut f.nodes)
+                        .push(section);
+                }
+            }
+            rest = next;
+        }
+
+        if let Some(frame) = stack.pop() {
+            return Err(TemplateError::UnclosedSection(frame.name));
+        }
+        text.push_str(rest);
+        if !text.is_empty() {
+            nodes.push(Node::Text(text));
+        }
+        Ok(Self { nodes })
+    }
+
+    /// Render against `data`. Missing values render as empty strings.
+    pub fn render(&self, data: &Value, escape: Escape) -> String {
+        let mut out = String::new();
+        let mut scopes = vec![data];
+        render_nodes(&self.nodes, &mut scopes, escape, &mut out);
+        out
+    }
+}
+
+fn parse_path(name: &str) -> Result<Vec<String>, TemplateError> {
+    if name == "." {
+        return Ok(Vec::new());
+    }
+    let valid = !name.is_empty()
+        && name.split('.').all(|segment| {
+            !segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        });
+    if !valid {
+        return Err(TemplateError::InvalidTag(name.to_string()));
+    }
+    Ok(name.split('.').map(String::from).collect())
+}
+
+/// Resolve the first segment against the innermost scope that defines it
+fn lookup<'a>(scopes: &[&'a Value], path: &[String]) -> Option<&'a Value> {
+    let Some((first, rest)) = path.split_first() else {
+        return scopes.last().copied();
+    };
+    let mut value = scopes.iter().rev().find_map(|scope| scope.get(first))?;
+    for segment in rest {
+        value = value.get(segment)?;
+    }
+    Some(value)
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Number(_) | Value::Object(_) => true,
+    }
+}
+
+fn render_nodes(nodes: &[Node], scopes: &mut Vec<&Value>, escape: Escape, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var(path) => match lookup(scopes, path) {
+                Some(Value::String(s)) => push_escaped(out, s, escape),
+                Some(v @ (Value::Number(_) | Value::Bool(_))) => {
+                    push_escaped(out, &v.to_string(), escape)
+                }
+                // Nulls, objects and arrays have no text form
+                _ => {}
+            },
+            Node::Section {
+                path,
+                inverted,
+                children,
+            } => {
+                let value = lookup(scopes, path);
+                let truthy = value.is_some_and(is_truthy);
+                if *inverted {
+                    if !truthy {
+                        render_nodes(children, scopes, escape, out);
+                    }
+                    continue;
+                }
+                let Some(value) = value.filter(|_| truthy) else {
+                    continue;
+                };
+                let items = match value {
+                    Value::Array(items) => items.iter().collect(),
+                    other => vec![other],
+                };
+                for item in items {
+                    scopes.push(item);
+                    render_nodes(children, scopes, escape, out);
+                    scopes.pop();
+                }
+            }
+        }
+    }
+}
+
+fn push_escaped(out: &mut String, value: &str, escape: Escape) {
+    if escape == Escape::None {
+        out.push_str(value);
+        return;
+    }
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn render(source: &str, data: Value) -> String {
+        Template::parse(source).unwrap().render(&data, Escape::Html)
+    }
+
+    #[test]
+    fn test_variables_and_dotted_paths() {
+        let data = json!({ "name": "Ada", "order": { "id": "ord_1", "count": 3 } });
+        assert_eq!(
+            render(
+                "Hi {{ name }}, order {{order.id}} x{{order.count}}{{missing}}",
+                data
+            ),
+            "Hi Ada, order ord_1 x3"
+        );
+    }
+
+    #[test]
+    fn test_html_escaping_is_not_optional() {
+        let data = json!({ "title": "<script>alert('x')</script> & co" });
+        assert_eq!(
+            render("{{title}}", data.clone()),
+            "&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt; &amp; co"
+        );
+        // Triple braces are not raw output
+        assert!(Template::parse("{{{title}}}").is_err());
+        let text = Template::parse("{{title}}")
+            .unwrap()
+            .render(&data, Escape::None);
+        assert_eq!(text, "<script>alert('x')</script> & co");
+    }
+
+    #[test]
+    fn test_sections_iterate_and_branch() {
+        let data = json!({
+            "items": [{ "title": "Mug", "qty": 2 }, { "title": "Hat", "qty": 1 }],
+            "currency": "USD",
+            "reason": "",
+        });
+        let source = "{{#items}}{{title}} x{{qty}} {{currency}};{{/items}}\
+                      {{#reason}}Reason: {{reason}}{{/reason}}{{^reason}}No reason{{/reason}}";
+        assert_eq!(render(source, data), "Mug x2 USD;Hat x1 USD;No reason");
+        assert_eq!(
+            render("{{#tags}}[{{.}}]{{/tags}}", json!({ "tags": ["a", "b"] })),
+            "[a][b]"
+        );
+    }
+
+    #[test]
+    fn test_standalone_section_lines_are_removed() {
+        let source = "Items:\n{{#items}}\n- {{.}}\n{{/items}}\nDone\n";
+        assert_eq!(
+            render(source, json!({ "items": ["a", "b"] })),
+            "Items:\n- a\n- b\nDone\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Template::parse("{{#a}}x").unwrap_err(),
+            TemplateError::UnclosedSection("a".into())
+        );
+        assert_eq!(
+            Template::parse("{{#a}}{{/b}}").unwrap_err(),
+            TemplateError::UnexpectedClose("b".into())
+        );
+        assert_eq!(
+            Template::parse("Hi {{name").unwrap_err(),
+            TemplateError::UnclosedTag(3)
+        );
+        assert!(matches!(
+            Template::parse("{{> partial}}"),
+            Err(TemplateError::InvalidTag(_))
+        ));
+        assert!(matches!(
+            Template::parse("{{=<% %>=}}"),
+            Err(TemplateError::InvalidTag(_))
+        ));
+        let deep = "{{#a}}".repeat(9) + &"{{/a}}".repeat(9);
+        assert_eq!(Template::parse(&deep).unwrap_err(), TemplateError::TooDeep);
+        let large = "x".repeat(MAX_TEMPLATE_BYTES + 1);
+        assert_eq!(
+            Template::parse(&large).unwrap_err(),
+            TemplateError::TooLarge
+        );
+    }
+}
@@ -0,0 +1,671 @@
+//! Email transports used by the email worker.
+//!
+//! - SMTP (lettre), the default
+//! - HTTP API: JSON POST to a provider endpoint authenticated with a bearer API key
+//!
+//! The transport is selected per tenant. A tenant's `email_transport` entry in
+//! the `messaging` config category replaces the global settings entirely, so
+//! one tenant's mail never goes out with another's credentials.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use zeroize::Zeroize;
+
+use crate::config::secrets::{SECRET_EMAIL_API_KEY, SECRET_SMTP_PASSWORD};
+use crate::config::{MessagingConfig, PostgresConfigRepository, SecretStore, EMAIL_PROVIDER_HTTP};
+use crate::storage::PendingEmail;
+
+/// Provider name recorded for SMTP deliveries
+pub const PROVIDER_SMTP: &str = "smtp";
+
+/// Config category holding the per-tenant transport override
+pub const TRANSPORT_CONFIG_CATEGORY: &str = "messaging";
+
+/// Config key of the per-tenant transport override
+pub const TRANSPORT_CONFIG_KEY: &str = "email_transport";
+
+/// How long a tenant's resolved transport is reused before re-reading config
+const TENANT_TRANSPORT_TTL: Duration = Duration::from_secs(300);
+
+/// Failure to hand an email to the transport
+#[derive(Debug, Clone)]
+pub struct SendError {
+    pub message: String,
+    /// The provider rejected the email outright; retrying cannot succeed
+    pub permanent: bool,
+}
+
+impl SendError {
+    fn transient(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            permanent: false,
+        }
+    }
+
+    fn permanent(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            permanent: true,
+        }
+    }
+}
+
+impl std::fmt::Display for SendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Delivers queued emails to a mail provider
+#[async_trait]
+pub trait EmailTransport: Send + Sync {
+    /// Provider name recorded on delivered emails
+    fn provider(&self) -> &'static str;
+
+    /// Send one email, returning the provider's message id when known
+    async fn send(&self, email: &PendingEmail) -> Result<Option<String>, SendError>;
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+/// Settings for building a transport (global config or a tenant override)
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct EmailTransportSettings {
+    /// `http` selects the HTTP API; anything else uses SMTP
+    #[serde(default)]
+    pub provider: String,
+    #[serde(default)]
+    pub smtp_host: String,
+    #[serde(default = "default_smtp_port")]
+    pub smtp_port: u16,
+    #[serde(default)]
+    pub smtp_username: String,
+    #[serde(default)]
+    pub smtp_password: String,
+    #[serde(default)]
+    pub api_url: String,
+    #[serde(default)]
+    pub api_key: String,
+    /// Shared secret signing inbound bounce/complaint webhooks
+    #[serde(default)]
+    pub webhook_secret: String,
+}
+
+impl std::fmt::Debug for EmailTransportSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EmailTransportSettings")
+            .field("provider", &self.provider)
+            .field("smtp_host", &self.smtp_host)
+            .field("smtp_port", &self.smtp_port)
+            .field("smtp_username", &self.smtp_username)
+            .field("smtp_password", &"[REDACTED]")
+            .field("api_url", &self.api_url)
+            .field("api_key", &"[REDACTED]")
+            .field("webhook_secret", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl EmailTransportSettings {
+    /// Global settings from the messaging config
+    pub fn from_config(config: &MessagingConfig) -> Self {
+        Self {
+            provider: config.email_provider.clone(),
+            smtp_host: config.smtp_host.clone(),
+            smtp_port: config.smtp_port,
+            smtp_username: config.smtp_username.clone(),
+            smtp_password: config.smtp_password.clone(),
+            api_url: config.email_api_url.clone(),
+            api_key: config.email_api_key.clone(),
+            webhook_secret: config.email_webhook_secret.clone(),
+        }
+    }
+
+    pub fn uses_http_api(&self) -> bool {
+        self.provider
+            .trim()
+            .eq_ignore_ascii_case(EMAIL_PROVIDER_HTTP)
+    }
+
+    /// Build the transport these settings describe
+    pub fn build(&self, timeout: Duration) -> Result<Arc<dyn EmailTransport>, String> {
+        if self.uses_http_api() {
+            Ok(Arc::new(HttpApiTransport::new(
+                &self.api_url,
+                &self.api_key,
+                timeout,
+            )?))
+        } else {
+            Ok(Arc::new(SmtpTransport::new(self, timeout)?))
+        }
+    }
+}
+
+/// Zeroizing container for SMTP credentials (L-005 fix).
+///
+/// Wraps sensitive credentials to ensure they are cleared from memory
+/// after use, preventing potential memory scraping attacks.
+struct ZeroizingCredentials {
+    username: Option<String>,
+    password: Option<String>,
+}
+
+impl ZeroizingCredentials {
+    fn new(username: String, password: String) -> Self {
+        Self {
+            username: Some(username),
+            password: Some(password),
+        }
+    }
+
+    fn take_credentials(&mut self) -> lettre::transport::smtp::authentication::Credentials {
+        lettre::transport::smtp::authentication::Credentials::new(
+            self.username.take().unwrap_or_default(),
+            self.password.take().unwrap_or_default(),
+        )
+    }
+}
+
+impl Drop for ZeroizingCredentials {
+    fn drop(&mut self) {
+        if let Some(ref mut u) = self.username {
+            u.zeroize();
+        }
+        if let Some(ref mut p) = self.password {
+            p.zeroize();
+        }
+    }
+}
+
+/// SMTP relay over STARTTLS.
+///
+/// Each message gets a `Message-ID` derived from the queue id, which is
+/// recorded as the provider message id so bounces can be matched back.
+pub struct SmtpTransport {
+    // P-01 fix: one pooled transport reused across all sends
+    mailer: lettre::AsyncSmtpTransport<lettre::Tokio1Executor>,
+    timeout: Duration,
+}
+
+impl SmtpTransport {
+    pub fn new(settings: &EmailTransportSettings, timeout: Duration) -> Result<Self, String> {
+        use lettre::{AsyncSmtpTransport, Tokio1Executor};
+
+        let mut zeroizing_creds = ZeroizingCredentials::new(
+            settings.smtp_username.clone(),
+            settings.smtp_password.clone(),
+        );
+        let creds = zeroizing_creds.take_credentials();
+
+        let mailer = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&settings.smtp_host)
+            .map_err(|e| format!("smtp transport: {}", e))?
+            .port(settings.smtp_port)
+            .credentials(creds)
+            .build();
+        Ok(Self { mailer, timeout })
+    }
+
+    fn build_message(email: &PendingEmail) -> Result<(lettre::Message, String), SendError> {
+        use lettre::message::header::ContentType;
+        use lettre::message::{Mailbox, MultiPart, SinglePart};
+        use lettre::Message;
+
+        let from_mailbox: Mailbox = format!("{} <{}>", email.from_name, email.from_email)
+            .parse()
+            .map_err(|e| SendError::permanent(format!("invalid from address: {}", e)))?;
+
+        let to_mailbox: Mailbox = email
+            .to_email
+            .parse()
+            .map_err(|e| SendError::permanent(format!("invalid to address: {}", e)))?;
+
+        let domain = email
+            .from_email
+            .rsplit_once('@')
+            .map_or("localhost", |(_, domain)| domain.trim());
+        let message_id = format!("<{}@{}>", email.id, domain);
+
+        let builder = Message::builder()
+            .from(from_mailbox)
+            .to(to_mailbox)
+            .subject(&email.subject)
+            .message_id(Some(message_id.clone()));
+
+        let message = if let Some(ref html) = email.body_html {
+            builder.multipart(
+                MultiPart::alternative()
+                    .singlepart(
+                        SinglePart::builder()
+                            .header(ContentType::TEXT_PLAIN)
+                            .body(email.body_text.clone()),
+                    )
+                    .singlepart(
+                        SinglePart::builder()
+                            .header(ContentType::TEXT_HTML)
+                            .body(html.clone()),
+                    ),
+            )
+        } else {
+            builder
+                .header(ContentType::TEXT_PLAIN)
+                .body(email.body_text.clone())
+        }
+        .map_err(|e| SendError::permanent(format!("build email: {}", e)))?;
+
+        Ok((message, message_id))
+    }
+}
+
+#[async_trait]
+impl EmailTransport for SmtpTransport {
+    fn provider(&self) -> &'static str {
+        PROVIDER_SMTP
+    }
+
+    async fn send(&self, email: &PendingEmail) -> Result<Option<String>, SendError> {
+        use lettre::AsyncTransport;
+
+        let (message, message_id) = Self::build_message(email)?;
+
+        // OPS-03: Wrap SMTP send in timeout to prevent unbounded hangs
+        tokio::time::timeout(self.timeout, self.mailer.send(message))
+            .await
+            .map_err(|_| {
+                SendError::transient(format!("smtp send timed out after {:?}", self.timeout))
+            })?
+            .map_err(|e| SendError {
+                message: format!("smtp send: {}", e),
+                permanent: e.is_permanent(),
+            })?;
+
+        Ok(Some(message_id))
+    }
+}
+
+/// Generic HTTP email API.
+///
+/// POSTs JSON to the configured endpoint with `Authorization: Bearer <key>`:
+/// `{from: {email, name}, to: [{email}], subject, text, html, metadata: {emailId, tenantId}}`.
+/// The provider message id is read from the response's `id` or `messageId`.
+pub struct HttpApiTransport {
+    client: reqwest::Client,
+    url: String,
+    api_key: String,
+}
+
+impl HttpApiTransport {
+    pub fn new(url: &str, api_key: &str, timeout: Duration) -> Result<Self, String> {
+        let url = url.trim();
+        if url.is_empty() {
+            return Err("email api url is empty".into());
+        }
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| format!("email api client: {}", e))?;
+        Ok(Self {
+            client,
+            url: url.to_string(),
+            api_key: api_key.to_string(),
+        })
+    }
+
+    fn request_body(email: &PendingEmail) -> serde_json::Value {
+        serde_json::json!({
+            "from": { "email": email.from_email, "name": email.from_name },
+            "to": [{ "email": email.to_email }],
+            "subject": email.subject,
+            "text": email.body_text,
+            "html": email.body_html,
+            "metadata": { "emailId": email.id, "tenantId": email.tenant_id },
+        })
+    }
+}
+
+impl Drop for HttpApiTransport {
+    fn drop(&mut self) {
+        self.api_key.zeroize();
+    }
+}
+
+#[async_trait]
+impl EmailTransport for HttpApiTransport {
+    fn provider(&self) -> &'static str {
+        EMAIL_PROVIDER_HTTP
+    }
+
+    async fn send(&self, email: &PendingEmail) -> Result<Option<String>, SendError> {
+        let mut request = self.client.post(&self.url).json(&Self::request_body(email));
+        if !self.api_key.is_empty() {
+            request = request.bearer_auth(&self.api_key);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| SendError::transient(format!("email api request: {}", e)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let message = format!("email api returned {}", status);
+            // Rate limits, timeouts and server errors are worth retrying
+            let retryable = status.is_server_error()
+                || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                || status == reqwest::StatusCode::REQUEST_TIMEOUT;
+            return Err(if retryable {
+                SendError::transient(message)
+            } else {
+                SendError::permanent(message)
+            });
+        }
+
+        // Providers that return no (or non-JSON) body still count as accepted
+        let body: serde_json::Value = response.json().await.unwrap_or_default();
+        Ok(["id", "messageId", "message_id"]
+            .iter()
+            .find_map(|key| body.get(key).and_then(|v| v.as_str()))
+            .map(str::to_string))
+    }
+}
+
+struct CachedTransport {
+    /// `None` = tenant has no override and uses the global transport
+    transport: Option<Arc<dyn EmailTransport>>,
+    loaded_at: Instant,
+    secrets_version: u64,
+}
+
+/// Resolves transport settings and transports per tenant.
+///
+/// Global settings come from the messaging config, with the SMTP password and
+/// API key taken from the secret provider when it has them. Tenant overrides
+/// are read from the config repository and cached for a few minutes.
+pub struct EmailTransportResolver {
+    config: MessagingConfig,
+    secrets: Option<Arc<SecretStore>>,
+    config_repo: Option<Arc<PostgresConfigRepository>>,
+    timeout: Duration,
+    global: parking_lot::RwLock<Option<(u64, Arc<dyn EmailTransport>)>>,
+    tenants: parking_lot::RwLock<HashMap<String, CachedTransport>>,
+}
+
+impl EmailTransportResolver {
+    pub fn new(config: MessagingConfig) -> Self {
+        Self {
+            config,
+            secrets: None,
+            config_repo: None,
+            timeout: Duration::from_secs(30),
+            global: parking_lot::RwLock::new(None),
+            tenants: parking_lot::RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Take credentials from a hot-reloaded secret provider when it has them
+    pub fn with_secrets(mut self, secrets: Arc<SecretStore>) -> Self {
+        self.secrets = Some(secrets);
+        self
+    }
+
+    /// Read per-tenant `email_transport` overrides from the config repository
+    pub fn with_config_repo(mut self, repo: Arc<PostgresConfigRepository>) -> Self {
+        self.config_repo = Some(repo);
+        self
+    }
+
+    /// Timeout for a single send
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn config(&self) -> &MessagingConfig {
+        &self.config
+    }
+
+    /// Secret snapshot version transports are built from (0 = config only)
+    fn secrets_version(&self) -> u64 {
+        self.secrets
+            .as_ref()
+            .map_or(0, |secrets| secrets.snapshot().version())
+    }
+
+    /// Global settings with rotated secrets applied
+    pub fn global_settings(&self) -> EmailTransportSettings {
+        let mut settings = EmailTransportSettings::from_config(&self.config);
+        if let Some(ref secrets) = self.secrets {
+            if let Some(password) = secrets.get(SECRET_SMTP_PASSWORD) {
+                settings.smtp_password = password;
+            }
+            if let Some(key) = secrets.get(SECRET_EMAIL_API_KEY) {
+                settings.api_key = key;
+            }
+        }
+        settings
+    }
+
+    /// The tenant's own transport settings, if it configured any
+    pub async fn tenant_settings(&self, tenant_id: &str) -> Option<EmailTransportSettings> {
+        let repo = self.config_repo.as_ref()?;
+        let entries = match repo.get_config(tenant_id, TRANSPORT_CONFIG_CATEGORY).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                warn!(error = %e, tenant_id = %tenant_id, "Failed to load email transport config");
+                return None;
+            }
+        };
+        let entry = entries
+            .iter()
+            .find(|e| e.config_key == TRANSPORT_CONFIG_KEY)?;
+        let value = match repo.decrypt_entry(entry).await {
+            Ok(value) => value,
+            Err(e) => {
+                warn!(error = %e, tenant_id = %tenant_id, "Failed to decrypt email transport config");
+                return None;
+            }
+        };
+        match serde_json::from_value(value) {
+            Ok(settings) => Some(settings),
+            Err(e) => {
+                warn!(error = %e, tenant_id = %tenant_id, "Invalid email transport config");
+                None
+            }
+        }
+    }
+
+    /// Effective settings for a tenant: its override, else the global settings
+    pub async fn settings(&self, tenant_id: &str) -> EmailTransportSettings {
+        match self.tenant_settings(tenant_id).await {
+            Some(settings) => settings,
+            None => self.global_settings(),
+        }
+    }
+
+    /// Secret verifying a tenant's inbound bounce/complaint webhooks (empty = none)
+    pub async fn webhook_secret(&self, tenant_id: &str) -> String {
+        self.settings(tenant_id).await.webhook_secret
+    }
+
+    /// Global transport, rebuilt when the secret provider rotates credentials
+    pub fn global_transport(&self) -> Result<Arc<dyn EmailTransport>, String> {
+        let version = self.secrets_version();
+        if let Some((built_version, ref transport)) = *self.global.read() {
+            if built_version == version {
+                return Ok(transport.clone());
+            }
+        }
+        let transport = self.global_settings().build(self.timeout)?;
+        if self
+            .global
+            .write()
+            .replace((version, transport.clone()))
+            .is_some()
+        {
+            info!("Email credentials rotated; transport rebuilt");
+        }
+        Ok(transport)
+    }
+
+    /// Transport for a tenant's emails
+    pub async fn transport(&self, tenant_id: &str) -> Result<Arc<dyn EmailTransport>, String> {
+        if self.config_repo.is_none() {
+            return self.global_transport();
+        }
+
+        let version = self.secrets_version();
+        let cached = self.tenants.read().get(tenant_id).and_then(|cached| {
+            (cached.loaded_at.elapsed() < TENANT_TRANSPORT_TTL && cached.secrets_version == version)
+                .then(|| cached.transport.clone())
+        });
+        let transport = match cached {
+            Some(transport) => transport,
+            None => {
+                let transport = match self.tenant_settings(tenant_id).await {
+                    Some(settings) => Some(settings.build(self.timeout)?),
+                    None => None,
+                };
+                self.tenants.write().insert(
+                    tenant_id.to_string(),
+                    CachedTransport {
+                        transport: transport.clone(),
+                        loaded_at: Instant::now(),
+                        secrets_version: version,
+                    },
+                );
+                transport
+            }
+        };
+
+        match transport {
+            Some(transport) => Ok(transport),
+            None => self.global_transport(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderMap;
+    use axum::routing::post;
+    use axum::{Json, Router};
+    use chrono::Utc;
+    use parking_lot::Mutex;
+
+    fn pending_email() -> PendingEmail {
+        let now = Utc::now();
+        PendingEmail {
+            id: "email-1".into(),
+            tenant_id: "tenant-a".into(),
+            to_email: "buyer@example.com".into(),
+            from_email: "shop@example.com".into(),
+            from_name: "Shop".into(),
+            subject: "Your order".into(),
+            body_text: "Thanks".into(),
+            body_html: Some("<p>Thanks</p>".into()),
+            status: crate::storage::EmailStatus::Pending,
+            attempts: 0,
+            max_attempts: 5,
+            last_error: None,
+            last_attempt_at: None,
+            next_attempt_at: Some(now),
+            created_at: now,
+            completed_at: None,
+            traceparent: None,
+            provider: None,
+            provider_message_id: None,
+        }
+    }
+
+    type Captured = Arc<Mutex<Vec<(Option<String>, serde_json::Value)>>>;
+
+    async fn spawn_api(status: axum::http::StatusCode) -> (String, Captured) {
+        let captured: Captured = Arc::new(Mutex::new(Vec::new()));
+        let sink = captured.clone();
+        let app = Router::new().route(
+            "/send",
+            post(
+                move |headers: HeaderMap, Json(body): Json<serde_json::Value>| {
+                    let sink = sink.clone();
+                    async move {
+                        let auth = headers
+                            .get("authorization")
+                            .and_then(|v| v.to_str().ok())
+                            .map(str::to_string);
+                        sink.lock().push((auth, body));
+                        (status, Json(serde_json::json!({ "id": "msg-123" })))
+                    }
+                },
+            ),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        (format!("http://{}/send", addr), captured)
+    }
+
+    #[tokio::test]
+    async fn test_http_transport_posts_json_with_api_key() {
+        let (url, captured) = spawn_api(axum::http::StatusCode::OK).await;
+        let transport = HttpApiTransport::new(&url, "key-1", Duration::from_secs(5)).unwrap();
+
+        let message_id = transport.send(&pending_email()).await.unwrap();
+        assert_eq!(message_id.as_deref(), Some("msg-123"));
+
+        let requests = captured.lock();
+        assert_eq!(requests.len(), 1);
+        let (auth, body) = &requests[0];
+        assert_eq!(auth.as_deref(), Some("Bearer key-1"));
+        assert_eq!(body["from"]["email"], "shop@example.com");
+        assert_eq!(body["to"][0]["email"], "buyer@example.com");
+        assert_eq!(body["subject"], "Your order");
+        assert_eq!(body["html"], "<p>Thanks</p>");
+        assert_eq!(body["metadata"]["emailId"], "email-1");
+        assert_eq!(body["metadata"]["tenantId"], "tenant-a");
+    }
+
+    #[tokio::test]
+    async fn test_http_transport_classifies_failures() {
+        let (url, _) = spawn_api(axum::http::StatusCode::UNPROCESSABLE_ENTITY).await;
+        let transport = HttpApiTransport::new(&url, "key-1", Duration::from_secs(5)).unwrap();
+        let err = transport.send(&pending_email()).await.unwrap_err();
+        assert!(err.permanent, "4xx rejections are permanent");
+
+        let (url, _) = spawn_api(axum::http::StatusCode::SERVICE_UNAVAILABLE).await;
+        let transport = HttpApiTransport::new(&url, "key-1", Duration::from_secs(5)).unwrap();
+        let err = transport.send(&pending_email()).await.unwrap_err();
+        assert!(!err.permanent, "5xx responses are retried");
+    }
+
+    #[test]
+    fn test_settings_select_transport() {
+        let http = EmailTransportSettings {
+            provider: "HTTP".into(),
+            api_url: "https://mail.example.com/send".into(),
+            ..Default::default()
+        };
+        let transport = http.build(Duration::from_secs(5)).unwrap();
+        assert_eq!(transport.provider(), EMAIL_PROVIDER_HTTP);
+
+        let missing_url = EmailTransportSettings {
+            provider: "http".into(),
+            ..Default::default()
+        };
+        assert!(missing_url.build(Duration::from_secs(5)).is_err());
+    }
+
+    #[test]
+    fn test_smtp_message_id_uses_sender_domain() {
+        let (_, message_id) = SmtpTransport::build_message(&pending_email()).unwrap();
+        assert_eq!(message_id, "<email-1@example.com>");
+    }
+}
@@ -2,14 +2,21 @@ pub mod ai;
 pub mod asset_fulfillment;
 pub mod blockhash_cache;
 pub mod cedros_login;
+pub mod cold_archive;
 pub mod compliance_checker;
+pub mod financial_reports;
 pub mod gift_card_fulfillment;
 pub mod health;
 pub mod image_storage;
+pub mod ledger;
 pub mod messaging;
 pub mod paywall;
+pub mod price_schedules;
+pub mod product_import;
+pub mod returns;
 pub mod sanctions;
 pub mod sanctions_list;
+pub mod semantic_search;
 pub mod stripe;
 pub mod stripe_webhooks;
 pub mod subscriptions;
@@ -129,11 +136,14 @@ pub use sanctions_list::SanctionsListService;
 pub use token_gate::TokenGateChecker;
 pub use gift_card_fulfillment::GiftCardFulfillmentService;
 pub use image_storage::ImageStorageService;
+pub use cold_archive::{ArchiveObjectStore, ColdArchiveService, InMemoryObjectStore};
+pub use semantic_search::{HybridResults, SemanticSearch};
 
 pub use ai::{
-    parse_json_response, slugify, AiError, AiService, CategoriesResult, ChatOrchestrator,
-    ChatResult, FactFinderConfig, FactFinderMatch, FactFinderResult, FaqMatch, ProductSearchMatch,
-    ProductSearchResult, RelatedProductsResult, SeoResult, TagsResult, DEFAULT_CATEGORIES_PROMPT,
+    parse_json_response, slugify, AiEndpoint, AiError, AiService, CategoriesResult,
+    ChatHandoffService, ChatOrchestrator, ChatResult, ChatStreamEvent, FactFinderConfig,
+    FactFinderMatch, FactFinderResult, FaqMatch, ProductSearchMatch, ProductSearchResult,
+    RelatedProductsResult, SeoResult, TagsResult, DEFAULT_CATEGORIES_PROMPT,
     DEFAULT_CHAT_SYSTEM_PROMPT, DEFAULT_FACT_FINDER_PROMPT, DEFAULT_PRODUCT_SEARCH_PROMPT,
     DEFAULT_RELATED_PRODUCTS_PROMPT, DEFAULT_SEO_PROMPT, DEFAULT_SHORT_DESC_PROMPT,
     DEFAULT_TAGS_PROMPT,
@@ -0,0 +1,237 @@
+// ============================================================================
+// Affiliate attribution and commission ledger
+// ============================================================================
+
+/// Cart/order metadata key carrying the normalized referral code.
+pub const REFERRAL_CODE_METADATA_KEY: &str = "referral_code";
+
+/// Cart/order metadata key carrying the attributed affiliate ID.
+pub const AFFILIATE_ID_METADATA_KEY: &str = "affiliate_id";
+
+impl PaywallService {
+    /// Resolve a referral code to an active affiliate.
+    ///
+    /// Unknown or inactive codes resolve to `None` so a stale referral link
+    /// never blocks checkout.
+    pub(crate) async fn resolve_referral(
+        &self,
+        tenant_id: &str,
+        code: &str,
+    ) -> Option<crate::models::Affiliate> {
+        let code = crate::models::affiliate::normalize_referral_code(code);
+        if code.is_empty() {
+            return None;
+        }
+        match self.store.get_affiliate_by_code(tenant_id, &code).await {
+            Ok(Some(affiliate)) if affiliate.active => Some(affiliate),
+            Ok(_) => {
+                debug!(tenant_id = %tenant_id, code = %code, "Ignoring unknown or inactive referral code");
+                None
+            }
+            Err(e) => {
+                warn!(error = %e, tenant_id = %tenant_id, "Failed to resolve referral code");
+                None
+            }
+        }
+    }
+}
+
+/// Record the commission earned on a paid cart order (best-effort).
+///
+/// Shared by every rail that stores cart orders (x402, credits and Stripe).
+/// The ledger entry ID is derived from the purchase so replays are no-ops.
+pub async fn record_cart_affiliate_commission<S: Store + ?Sized>(
+    store: &S,
+    tenant_id: &str,
+    cart: &CartQuote,
+    order: &Order,
+) {
+    let Some(affiliate_id) = cart.metadata.get(AFFILIATE_ID_METADATA_KEY) else {
+        return;
+    };
+    let affiliate = match store.get_affiliate(tenant_id, affiliate_id).await {
+        Ok(Some(a)) => a,
+        Ok(None) => {
+            warn!(tenant_id = %tenant_id, affiliate_id = %affiliate_id, "Attributed affiliate no longer exists");
+            return;
+        }
+        Err(e) => {
+            warn!(error = %e, tenant_id = %tenant_id, affiliate_id = %affiliate_id, "Failed to load affiliate");
+            return;
+        }
+    };
+
+    // Map products to the rule collections containing them.
+    let mut product_collections: HashMap<String, Vec<String>> = HashMap::new();
+    for collection_id in affiliate.rule_collection_ids() {
+        match store.get_collection(tenant_id, collection_id).await {
+            Ok(Some(collection)) => {
+                for product_id in collection.product_ids {
+                    product_collections
+                        .entry(product_id)
+                        .or_default()
+                        .push(collection.id.clone());
+                }
+            }
+            Ok(None) => {}
+            Err(e) => {
+                warn!(error = %e, collection_id = %collection_id, "Failed to load collection for affiliate rule");
+            }
+        }
+    }
+
+    let commission: i64 = cart
+        .items
+        .iter()
+        .map(|item| {
+            let collections = product_collections
+                .get(&item.resource_id)
+                .map(Vec::as_slice)
+                .unwrap_or(&[]);
+            affiliate.commission_for_item(
+                &item.resource_id,
+                collections,
+                item.price.atomic,
+                item.quantity,
+            )
+        })
+        .fold(0i64, |acc, v| acc.saturating_add(v));
+    // Item prices are in the cart's currency; an order settled in another
+    // currency (Stripe checkout of a crypto-priced cart) is converted at the
+    // ratio of the two totals.
+    let amount = if order.amount_asset == cart.total.asset.code {
+        commission
+    } else if cart.total.atomic > 0 {
+        (commission as i128 * order.amount as i128 / cart.total.atomic as i128) as i64
+    } else {
+        0
+    }
+    // Commission can never exceed what the customer paid.
+    .min(order.amount);
+    if amount <= 0 {
+        return;
+    }
+
+    let entry = crate::models::AffiliateCommission {
+        id: format!("earned:{}", order.purchase_id),
+        tenant_id: tenant_id.to_string(),
+        affiliate_id: affiliate.id.clone(),
+        order_id: order.id.clone(),
+        purchase_id: order.purchase_id.clone(),
+        kind: crate::models::CommissionEntryKind::Earned,
+        amount,
+        order_amount: order.amount,
+        currency: order.amount_asset.clone(),
+        refund_id: None,
+        created_at: Utc::now(),
+    };
+    if let Err(e) = store.record_affiliate_commission(entry).await {
+        warn!(
+            error = %e,
+            tenant_id = %tenant_id,
+            order_id = %order.id,
+            affiliate_id = %affiliate.id,
+            "Failed to record affiliate commission"
+        );
+    }
+}
+
+/// Reverse commission for a processed refund of `refund_amount` (in
+/// `refund_currency`) against `purchase_id`, pro-rated by the refunded share
+/// of the order (best-effort).
+pub async fn reverse_affiliate_commission<S: Store + ?Sized>(
+    store: &S,
+    tenant_id: &str,
+    purchase_id: &str,
+    refund_id: &str,
+    refund_amount: i64,
+    refund_currency: &str,
+) {
+    let entries = match store
+        .list_affiliate_commissions_for_purchase(tenant_id, purchase_id)
+        .await
+    {
+        Ok(entries) => entries,
+        Err(e) => {
+            warn!(error = %e, refund_id = %refund_id, "Failed to load affiliate commissions for refund");
+            return;
+        }
+    };
+
+    let earned_entries = entries
+        .iter()
+        .filter(|e| e.kind == crate::models::CommissionEntryKind::Earned);
+    for earned in earned_entries {
+        if earned.currency != refund_currency {
+            warn!(
+                refund_id = %refund_id,
+                commission_currency = %earned.currency,
+                refund_currency = %refund_currency,
+                "Refund currency differs from commission currency; skipping reversal"
+            );
+            continue;
+        }
+        let net: i64 = entries
+            .iter()
+            .filter(|e| e.affiliate_id == earned.affiliate_id)
+            .map(|e| e.amount)
+            .sum();
+        let amount = crate::models::affiliate::reversal_amount(
+            earned.amount,
+            net,
+            earned.order_amount,
+            refund_amount,
+        );
+        if amount <= 0 {
+            continue;
+        }
+
+        let entry = crate::models::AffiliateCommission {
+            id: format!("reversed:{}:{}", refund_id, earned.affiliate_id),
+            tenant_id: tenant_id.to_string(),
+            affiliate_id: earned.affiliate_id.clone(),
+            order_id: earned.order_id.clone(),
+            purchase_id: earned.purchase_id.clone(),
+            kind: crate::models::CommissionEntryKind::Reversed,
+            amount: -amount,
+            order_amount: earned.order_amount,
+            currency: earned.currency.clone(),
+            refund_id: Some(refund_id.to_string()),
+            created_at: Utc::now(),
+        };
+        if let Err(e) = store.record_affiliate_commission(entry).await {
+            warn!(
+                error = %e,
+                refund_id = %refund_id,
+                affiliate_id = %earned.affiliate_id,
+                "Failed to record affiliate commission reversal"
+            );
+        }
+    }
+}
+
+/// Reverse commission for a Stripe refund once it has succeeded (best-effort).
+///
+/// Stripe orders are keyed by checkout session ID, while refund requests
+/// reference the prefixed payment signature.
+pub async fn reverse_stripe_refund_commission<S: Store + ?Sized>(
+    store: &S,
+    req: &crate::models::StripeRefundRequest,
+) {
+    if req.status != "succeeded" {
+        return;
+    }
+    let purchase_id = req
+        .original_purchase_id
+        .strip_prefix(STRIPE_SIGNATURE_PREFIX)
+        .unwrap_or(&req.original_purchase_id);
+    reverse_affiliate_commission(
+        store,
+        &req.tenant_id,
+        purchase_id,
+        &req.id,
+        req.amount,
+        &req.currency.to_ascii_uppercase(),
+    )
+    .await;
+}
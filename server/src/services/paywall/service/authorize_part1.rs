@@ -49,6 +49,7 @@ impl PaywallService {
     /// - Stripe: Via stripe_session_id
     /// - X402: Via payment_header (Solana transaction proof)
     /// - Credits: Via credits_hold_id (cedros-login credits hold)
+    #[tracing::instrument(name = "paywall.authorize", skip_all, fields(tenant_id = %tenant_id, resource_id = %resource))]
     pub async fn authorize_with_wallet(
         &self,
         tenant_id: &str,
@@ -195,6 +196,7 @@ impl PaywallService {
     }
 
     /// Authorize via Stripe session
+    #[tracing::instrument(name = "paywall.authorize_stripe", skip_all, fields(tenant_id = %tenant_id, resource_id = %resource, payment_method = "stripe"))]
     async fn authorize_stripe(
         &self,
         tenant_id: &str,
@@ -268,6 +270,7 @@ impl PaywallService {
 
     /// Internal x402 authorization that takes a pre-parsed PaymentProof
     /// DEAD-002: Removed unused _wallet param - wallet info is in proof.payer
+    #[tracing::instrument(name = "paywall.authorize_x402", skip_all, fields(tenant_id = %tenant_id, resource_id = %resource, payment_method = "x402"))]
     pub(crate) async fn authorize_x402_with_proof(
         &self,
         tenant_id: &str,
@@ -377,6 +380,7 @@ impl PaywallService {
                 message: "product has no crypto price".into(),
             })?;
 
+        let list_price_atomic = base_price.atomic;
         let required_price = stack_coupons_on_money(base_price, &applied_coupons, rounding_mode);
 
         // Get token mint first (needed for ATA derivation)
@@ -399,6 +403,19 @@ impl PaywallService {
                 })?
         };
 
+        // Split products require one transfer per leg instead of a single transfer.
+        let splits = match self.resolve_payment_split(tenant_id, &product).await? {
+            Some(split) => self.build_split_legs(&split, &required_price, &token_mint)?,
+            None => Vec::new(),
+        };
+        let split_metadata = if splits.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(&splits).map_err(|e| {
+                ServiceError::Internal(format!("failed to encode payment split legs: {}", e))
+            })?)
+        };
+
         let requirement = Requirement {
             resource_id: resource.to_string(),
             amount_atomic: Some(u64::try_from(required_price.atomic).map_err(|_| {
@@ -417,6 +434,7 @@ impl PaywallService {
             quote_ttl: None,
             skip_preflight: self.config.x402.skip_preflight,
             commitment: self.config.x402.commitment.clone(),
+            splits,
         };
 
         // Verify payment
@@ -459,7 +477,9 @@ impl PaywallService {
             user_id,
             amount: required_price.clone(),
             created_at: Utc::now(),
-            metadata: HashMap::new(),
+            metadata: split_metadata
+                .map(|legs| HashMap::from([(PAYMENT_SPLIT_LEGS_METADATA_KEY.to_string(), legs)]))
+                .unwrap_or_default(),
         };
 
         // Retry on transient errors, but not on duplicates (which are expected in races)
@@ -514,6 +534,7 @@ impl PaywallService {
                 message: "payment recording failed".into(),
             });
         }
+        crate::services::ledger::post_payment(&*self.store, &payment).await;
 
         // Persist order + decrement inventory (best-effort). This is separate from payment
         // recording so that later idempotent replays can fill gaps.
@@ -526,6 +547,10 @@ impl PaywallService {
                 .join(",");
             order_metadata.insert("coupon_codes".to_string(), codes);
         }
+        let discount = list_price_atomic - required_price.atomic;
+        if discount > 0 {
+            order_metadata.insert(ORDER_DISCOUNT_AMOUNT_KEY.to_string(), discount.to_string());
+        }
 
         let now = Utc::now();
         let order_id = uuid::Uuid::new_v4().to_string();
@@ -542,6 +567,7 @@ impl PaywallService {
                 product_id: resource.to_string(),
                 variant_id: None,
                 quantity: 1,
+                line_total: Some(required_price.atomic),
             }],
             amount: required_price.atomic,
             amount_asset: required_price.asset.code.clone(),
@@ -116,6 +116,7 @@ impl PaywallService {
                 code: ErrorCode::DatabaseError,
                 message: format!("failed to store credits hold binding: {e}"),
             })?;
+        crate::services::ledger::post_credits_hold(&*self.store, &hold).await;
 
         Ok(hold)
     }
@@ -160,9 +161,13 @@ impl PaywallService {
         }
 
         match client.release_hold(hold_id).await {
-            Ok(())
-            | Err(crate::services::cedros_login::CedrosLoginError::HoldNotFound(_))
-            | Err(crate::services::cedros_login::CedrosLoginError::HoldAlreadyProcessed(_)) => {}
+            // Not found: the hold already expired, which releases it too
+            Ok(()) | Err(crate::services::cedros_login::CedrosLoginError::HoldNotFound(_)) => {
+                crate::services::ledger::post_credits_release(&*self.store, tenant_id, hold_id)
+                    .await;
+            }
+            // Captured or released earlier; that settlement posted its own entry
+            Err(crate::services::cedros_login::CedrosLoginError::HoldAlreadyProcessed(_)) => {}
             Err(e) => {
                 return Err(ServiceError::Coded {
                     code: ErrorCode::VerificationFailed,
@@ -297,6 +302,9 @@ impl PaywallService {
                     }
                     self.clear_credits_capture_recovery_marker(tenant_id, hold_id)
                         .await;
+                    crate::services::ledger::post_payment(&*self.store, &payment).await;
+                    crate::services::ledger::post_credits_capture(&*self.store, tenant_id, hold_id)
+                        .await;
                     return Ok((true, payment));
                 }
                 Ok(false) => {
@@ -315,6 +323,9 @@ impl PaywallService {
 
                     self.clear_credits_capture_recovery_marker(tenant_id, hold_id)
                         .await;
+                    crate::services::ledger::post_payment(&*self.store, &existing).await;
+                    crate::services::ledger::post_credits_capture(&*self.store, tenant_id, hold_id)
+                        .await;
                     return Ok((false, existing));
                 }
                 Err(e) => {
@@ -399,6 +410,7 @@ impl PaywallService {
         Ok(Some((recorded_new, recorded)))
     }
 
+    #[tracing::instrument(name = "paywall.authorize_credits", skip_all, fields(tenant_id = %tenant_id, resource_id = %resource, payment_method = "credits"))]
     async fn authorize_credits_internal(
         &self,
         tenant_id: &str,
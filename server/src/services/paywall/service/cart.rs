@@ -6,6 +6,7 @@ impl PaywallService {
     /// Authorize a cart payment
     /// Accepts a pre-parsed PaymentProof to avoid double-parsing issues
     /// DEAD-002: Removed unused _wallet param - wallet info is in proof.payer
+    #[tracing::instrument(name = "paywall.authorize_cart", skip_all, fields(tenant_id = %tenant_id, resource_id = %cart_id, payment_method = "x402"))]
     pub async fn authorize_cart(
         &self,
         tenant_id: &str,
@@ -273,6 +274,8 @@ impl PaywallService {
             quote_ttl: None,
             skip_preflight: self.config.x402.skip_preflight,
             commitment: self.config.x402.commitment.clone(),
+            // Legs fixed at quote time when the cart holds split products
+            splits: split_legs_from_metadata(&cart.metadata).unwrap_or_default(),
         };
 
         // Verify payment
@@ -355,7 +358,13 @@ impl PaywallService {
             user_id,
             amount: cart.total.clone(),
             created_at: Utc::now(),
-            metadata: HashMap::new(),
+            metadata: cart
+                .metadata
+                .get(PAYMENT_SPLIT_LEGS_METADATA_KEY)
+                .map(|legs| {
+                    HashMap::from([(PAYMENT_SPLIT_LEGS_METADATA_KEY.to_string(), legs.clone())])
+                })
+                .unwrap_or_default(),
         };
 
         // Retry payment recording with exponential backoff
@@ -419,6 +428,7 @@ impl PaywallService {
                 message: "failed to record payment after verification - contact support with transaction signature".into(),
             });
         }
+        crate::services::ledger::post_payment(&*self.store, &payment).await;
 
         if payment_recorded_new {
             // SECURITY: Use atomic mark_cart_paid to prevent race condition (C-004 fix).
@@ -708,6 +718,7 @@ impl PaywallService {
                 code: ErrorCode::DatabaseError,
                 message: format!("failed to store credits hold binding: {e}"),
             })?;
+        crate::services::ledger::post_credits_hold(&*self.store, &hold).await;
 
         Ok(hold)
     }
@@ -724,6 +735,7 @@ impl PaywallService {
             .await
     }
 
+    #[tracing::instrument(name = "paywall.authorize_cart_credits", skip_all, fields(tenant_id = %tenant_id, resource_id = %cart_id, payment_method = "credits"))]
     async fn authorize_cart_credits_internal(
         &self,
         tenant_id: &str,
@@ -1172,6 +1184,25 @@ impl PaywallService {
         if let Some(currency) = cart.metadata.get("gift_card_currency") {
             order_metadata.insert("gift_card_currency".to_string(), currency.clone());
         }
+        // Coupon discounts, net of the gift card portion (which is tender, not a discount)
+        let gift_card_applied = cart
+            .metadata
+            .get("gift_card_applied_amount")
+            .and_then(|v| v.parse::<i64>().ok())
+            .unwrap_or(0);
+        let discount = cart
+            .original_total
+            .as_ref()
+            .map(|o| o.atomic - cart.total.atomic - gift_card_applied)
+            .unwrap_or(0);
+        if discount > 0 {
+            order_metadata.insert(ORDER_DISCOUNT_AMOUNT_KEY.to_string(), discount.to_string());
+        }
+        for key in [REFERRAL_CODE_METADATA_KEY, AFFILIATE_ID_METADATA_KEY] {
+            if let Some(value) = cart.metadata.get(key) {
+                order_metadata.insert(key.to_string(), value.clone());
+            }
+        }
 
         let items: Vec<OrderItem> = cart
             .items
@@ -1180,6 +1211,7 @@ impl PaywallService {
                 product_id: i.resource_id.clone(),
                 variant_id: i.variant_id.clone(),
                 quantity: i.quantity,
+                line_total: Some(i.price.atomic),
             })
             .collect();
 
@@ -1211,6 +1243,13 @@ impl PaywallService {
         match self.store.try_store_order(order).await {
             Ok(true) => {
                 self.notify_order_created(&order_for_messaging).await;
+                record_cart_affiliate_commission(
+                    &*self.store,
+                    tenant_id,
+                    cart,
+                    &order_for_messaging,
+                )
+                .await;
                 let inventory_updates: Vec<(String, Option<String>, i32)> = items
                     .iter()
                     .filter(|item| item.quantity > 0)
@@ -1353,6 +1392,16 @@ impl PaywallService {
                     new_balance,
                     "Gift card balance atomically adjusted after cart payment"
                 );
+                if let Some(entry) = crate::services::ledger::gift_card_redemption_entry(
+                    tenant_id,
+                    &cart.id,
+                    &code,
+                    &cart.total.asset.code,
+                    applied,
+                    Utc::now(),
+                ) {
+                    crate::services::ledger::post_entry(&*self.store, entry).await;
+                }
             }
             Ok(None) => {
                 // Insufficient funds - gift card was likely used by concurrent request
@@ -335,12 +335,164 @@ impl PaywallService {
         true
     }
 
+    /// Resolve the x402 revenue split for a product: its own split, otherwise the
+    /// split of the first active collection that contains it.
+    async fn resolve_payment_split(
+        &self,
+        tenant_id: &str,
+        product: &Product,
+    ) -> ServiceResult<Option<PaymentSplit>> {
+        Ok(self
+            .resolve_payment_splits(tenant_id, &[product])
+            .await?
+            .pop()
+            .flatten())
+    }
+
+    /// Resolve the payment split of each product, loading collections at most once.
+    async fn resolve_payment_splits(
+        &self,
+        tenant_id: &str,
+        products: &[&Product],
+    ) -> ServiceResult<Vec<Option<PaymentSplit>>> {
+        if products.iter().all(|p| p.payment_split.is_some()) {
+            return Ok(products.iter().map(|p| p.payment_split.clone()).collect());
+        }
+
+        // Fail closed: silently dropping a split would route seller funds to the platform.
+        let collections = self
+            .store
+            .list_collections(tenant_id, Some(true), MAX_SPLIT_COLLECTIONS_SCAN, 0)
+            .await
+            .map_err(|e| ServiceError::Coded {
+                code: ErrorCode::DatabaseError,
+                message: format!("failed to load collections: {}", e),
+            })?;
+
+        Ok(products
+            .iter()
+            .map(|product| {
+                product.payment_split.clone().or_else(|| {
+                    collections
+                        .iter()
+                        .filter(|c| c.product_ids.iter().any(|id| id == &product.id))
+                        .find_map(|c| c.payment_split.clone())
+                })
+            })
+            .collect())
+    }
+
+    /// Expand a split into per-recipient legs for `total` atomic units of `mint`.
+    ///
+    /// The platform leg is paid to the configured `payment_address`.
+    fn build_split_legs(
+        &self,
+        split: &PaymentSplit,
+        total: &Money,
+        mint: &str,
+    ) -> ServiceResult<Vec<PaymentLeg>> {
+        let total = u64::try_from(total.atomic).map_err(|_| ServiceError::Coded {
+            code: ErrorCode::InvalidAmount,
+            message: "required amount must be non-negative".into(),
+        })?;
+
+        split
+            .allocate(total, &self.config.x402.payment_address)
+            .into_iter()
+            .map(|(role, owner, amount_atomic)| {
+                let recipient_token_account = crate::x402::utils::derive_ata_safe(&owner, mint)
+                    .ok_or_else(|| ServiceError::Coded {
+                        code: ErrorCode::InvalidRecipient,
+                        message: format!("invalid {} wallet in payment split", role.as_str()),
+                    })?;
+                Ok(PaymentLeg {
+                    role,
+                    recipient_owner: owner,
+                    recipient_token_account,
+                    amount_atomic,
+                })
+            })
+            .collect()
+    }
+
+    /// Expand cart lines into merged per-recipient legs for the cart `total`.
+    ///
+    /// Each line receives its pro-rata share of `total` (the last line takes the
+    /// rounding remainder); split lines are divided per their split and plain
+    /// lines go to the platform. Legs paying the same token account are merged.
+    /// Returns no legs when no line carries a split.
+    fn build_cart_split_legs(
+        &self,
+        lines: &[(i64, Option<PaymentSplit>)],
+        total: &Money,
+        mint: &str,
+    ) -> ServiceResult<Vec<PaymentLeg>> {
+        if lines.iter().all(|(_, split)| split.is_none()) {
+            return Ok(Vec::new());
+        }
+
+        let total_atomic = u64::try_from(total.atomic).map_err(|_| ServiceError::Coded {
+            code: ErrorCode::InvalidAmount,
+            message: "cart total must be non-negative".into(),
+        })?;
+        let subtotal: i128 = lines.iter().map(|(line, _)| (*line).max(0) as i128).sum();
+
+        let mut legs: Vec<PaymentLeg> = Vec::new();
+        let mut remaining = total_atomic;
+        for (idx, (line, split)) in lines.iter().enumerate() {
+            let share = if idx + 1 == lines.len() {
+                remaining
+            } else if subtotal > 0 {
+                ((*line).max(0) as i128 * total_atomic as i128 / subtotal) as u64
+            } else {
+                0
+            };
+            let share = share.min(remaining);
+            remaining -= share;
+
+            let line_legs = match split {
+                Some(split) => self.build_split_legs(
+                    split,
+                    &Money::new(total.asset.clone(), share as i64),
+                    mint,
+                )?,
+                None if share > 0 => {
+                    let owner = &self.config.x402.payment_address;
+                    let recipient_token_account = crate::x402::utils::derive_ata_safe(owner, mint)
+                        .ok_or_else(|| ServiceError::Coded {
+                            code: ErrorCode::InvalidRecipient,
+                            message: "failed to derive cart recipient token account".into(),
+                        })?;
+                    vec![PaymentLeg {
+                        role: SplitRole::Platform,
+                        recipient_owner: owner.clone(),
+                        recipient_token_account,
+                        amount_atomic: share,
+                    }]
+                }
+                None => Vec::new(),
+            };
+
+            for leg in line_legs {
+                match legs
+                    .iter_mut()
+                    .find(|l| l.recipient_token_account == leg.recipient_token_account)
+                {
+                    Some(existing) => existing.amount_atomic += leg.amount_atomic,
+                    None => legs.push(leg),
+                }
+            }
+        }
+        Ok(legs)
+    }
+
     /// Build crypto quote from product
     fn build_crypto_quote(
         &self,
         product: &Product,
         coupons: &[Coupon],
         rounding_mode: RoundingMode,
+        split: Option<&PaymentSplit>,
     ) -> ServiceResult<Option<CryptoQuote>> {
         let crypto_price = match &product.crypto_price {
             Some(p) => p,
@@ -363,6 +515,11 @@ impl PaywallService {
 
         let token_symbol = Some(crypto_price.asset.code.clone());
 
+        let splits = match split {
+            Some(split) => self.build_split_legs(split, &discounted, &asset)?,
+            None => Vec::new(),
+        };
+
         // Per spec (19-services-paywall.md): Interpolate memo template with resource ID and nonce
         let memo = interpolate_memo(product.memo_template.as_deref(), &product.id);
 
@@ -388,6 +545,7 @@ impl PaywallService {
                 } else {
                     None
                 },
+                splits,
             }),
         }))
     }
@@ -22,14 +22,16 @@ use crate::models::{
     get_asset, Asset, AssetMetadata, AssetType, AuthorizationResult, CartItem, CartQuote, Coupon,
     CreditsOption, CryptoQuote, Money, Order, OrderItem, PaymentEvent, PaymentTransaction, Product,
     Quote, RefundQuote, Requirement, RoundingMode, SettlementResponse, SolanaExtra, StripeOption,
+    ORDER_DISCOUNT_AMOUNT_KEY,
 };
+use crate::models::{PaymentLeg, PaymentSplit, SplitRole};
 use crate::observability::record_payment;
 use crate::repositories::{CouponRepository, ProductRepository};
 use crate::services::asset_fulfillment::AssetFulfillmentService;
 use crate::services::cedros_login::CedrosLoginClient;
 use crate::services::compliance_checker::ComplianceChecker;
 use crate::services::gift_card_fulfillment::GiftCardFulfillmentService;
-use crate::services::messaging::MessagingService;
+use crate::services::messaging::{MessagingService, RefundNotice, LOCALE_METADATA_KEY};
 use crate::services::{ServiceError, ServiceResult, SubscriptionChecker};
 use crate::storage::Store;
 use crate::webhooks::Notifier;
@@ -47,6 +49,21 @@ pub use super::types::{CouponScope, RefundQuoteResponse};
 
 include!("refund_locks.rs");
 
+/// Upper bound on collections scanned when resolving a product's payment split.
+const MAX_SPLIT_COLLECTIONS_SCAN: i32 = 1000;
+
+/// Payment metadata key holding the JSON-encoded split legs of an x402 payment.
+pub const PAYMENT_SPLIT_LEGS_METADATA_KEY: &str = "payment_split_legs";
+
+/// Cart item metadata key holding the product's Stripe price at quote time.
+/// Stripe checkout of an unexpired quote charges this price, so a scheduled
+/// price change does not reprice carts that were already quoted.
+pub const QUOTED_STRIPE_PRICE_METADATA_KEY: &str = "quoted_stripe_price_id";
+
+/// Cart item metadata key holding the product's fiat unit price (in cents)
+/// at quote time, alongside [`QUOTED_STRIPE_PRICE_METADATA_KEY`].
+pub const QUOTED_FIAT_CENTS_METADATA_KEY: &str = "quoted_fiat_amount_cents";
+
 // ============================================================================
 // PaywallService
 // ============================================================================
@@ -128,6 +145,11 @@ impl PaywallService {
         self
     }
 
+    /// Gasless builder, if gasless mode is enabled
+    pub fn gasless_builder(&self) -> Option<&Arc<GaslessTransactionBuilder>> {
+        self.gasless_builder.as_ref()
+    }
+
     /// Per spec (19-services-paywall.md): Set subscription checker for access control
     pub fn set_subscription_checker(&mut self, checker: Arc<dyn SubscriptionChecker>) {
         self.subscription_checker = Some(checker);
@@ -182,6 +204,45 @@ impl PaywallService {
         }
     }
 
+    /// Email the buyer about a settled crypto refund (fire-and-forget)
+    pub(crate) async fn notify_refund_processed(&self, refund: &RefundQuote) {
+        let Some(ref messaging) = self.messaging else {
+            return;
+        };
+        let order = match self
+            .store
+            .get_order_by_purchase_id(&refund.tenant_id, &refund.original_purchase_id)
+            .await
+        {
+            Ok(Some(order)) => order,
+            Ok(None) => return,
+            Err(e) => {
+                warn!(
+                    error = %e,
+                    refund_id = %refund.id,
+                    "Failed to look up order for refund email"
+                );
+                return;
+            }
+        };
+        let Some(to_email) = order.customer_email.clone() else {
+            return;
+        };
+        messaging
+            .notify_refund_processed(&RefundNotice {
+                tenant_id: refund.tenant_id.clone(),
+                to_email,
+                refund_id: refund.id.clone(),
+                order_id: Some(order.id.clone()),
+                customer_name: order.customer_name.clone(),
+                amount: refund.amount.atomic,
+                currency: refund.amount.asset.code.clone(),
+                reason: refund.reason.clone(),
+                locale: order.metadata.get(LOCALE_METADATA_KEY).cloned(),
+            })
+            .await;
+    }
+
     pub(crate) async fn call_payment_callback(&self, event: &PaymentEvent) {
         let Some(cb) = self.payment_callback.as_ref() else {
             return;
@@ -305,6 +366,7 @@ pub use authorize_part1::AuthorizeWithWalletRequest;
 include!("cart.rs");
 include!("refunds.rs");
 include!("helpers.rs");
+include!("affiliates.rs");
 include!("verify.rs");
 
 #[cfg(test)]
@@ -12,6 +12,7 @@ impl PaywallService {
     // ========================================================================
 
     /// Generate a payment quote for a single product
+    #[tracing::instrument(name = "paywall.generate_quote", skip_all, fields(tenant_id = %tenant_id, resource_id = %resource))]
     pub async fn generate_quote(
         &self,
         tenant_id: &str,
@@ -52,7 +53,9 @@ impl PaywallService {
         let expires_at = Utc::now() + to_chrono_duration(self.config.paywall.quote_ttl);
 
         // Build crypto quote if product has crypto pricing
-        let crypto_quote = self.build_crypto_quote(&product, &applied_coupons, rounding_mode)?;
+        let split = self.resolve_payment_split(tenant_id, &product).await?;
+        let crypto_quote =
+            self.build_crypto_quote(&product, &applied_coupons, rounding_mode, split.as_ref())?;
 
         // Build Stripe option if product has fiat pricing
         let stripe_option = self.build_stripe_option(&product, &applied_coupons, rounding_mode);
@@ -90,11 +93,12 @@ impl PaywallService {
     }
 
     /// Generate a payment quote for a cart of items with metadata
+    #[tracing::instrument(name = "paywall.generate_cart_quote", skip_all, fields(tenant_id = %tenant_id))]
     pub(crate) async fn generate_cart_quote_with_metadata(
         &self,
         tenant_id: &str,
         items: Vec<CartQuoteItemInput>,
-        cart_metadata: HashMap<String, String>,
+        mut cart_metadata: HashMap<String, String>,
         coupon_code: Option<&str>,
         gift_card_code: Option<&str>,
     ) -> ServiceResult<CartQuote> {
@@ -108,6 +112,7 @@ impl PaywallService {
         let cart_id = generate_cart_id();
         let rounding_mode = self.get_rounding_mode();
         let mut cart_items = Vec::with_capacity(items.len());
+        let mut line_products: Vec<&Product> = Vec::with_capacity(items.len());
         let mut total_asset: Option<Asset> = None;
         let mut total_atomic = 0i64;
         let mut original_total_atomic = 0i64;
@@ -382,6 +387,24 @@ impl PaywallService {
 
             let item_coupon_codes = catalog_coupons.iter().map(|c| c.code.clone()).collect();
 
+            // Only the server records quoted Stripe prices
+            let mut item_metadata = item.metadata.clone();
+            item_metadata.remove(QUOTED_STRIPE_PRICE_METADATA_KEY);
+            item_metadata.remove(QUOTED_FIAT_CENTS_METADATA_KEY);
+            if let Some(ref price_id) = product.stripe_price_id {
+                item_metadata.insert(
+                    QUOTED_STRIPE_PRICE_METADATA_KEY.to_string(),
+                    price_id.clone(),
+                );
+                if let Some(ref fiat) = product.fiat_price {
+                    item_metadata.insert(
+                        QUOTED_FIAT_CENTS_METADATA_KEY.to_string(),
+                        fiat.atomic.to_string(),
+                    );
+                }
+            }
+
+            line_products.push(product);
             cart_items.push(CartItem {
                 resource_id: resource_id.clone(),
                 variant_id: item.variant_id.clone(),
@@ -394,7 +417,7 @@ impl PaywallService {
                 },
                 description: Some(product.description.clone()),
                 applied_coupons: item_coupon_codes,
-                metadata: item.metadata.clone(),
+                metadata: item_metadata,
             });
         }
 
@@ -497,6 +520,23 @@ impl PaywallService {
             gift_card_applied = Some((normalized_code, applied_amount, card.currency, remaining));
         }
 
+        // Split products pay their sellers directly, so the cart's legs are
+        // fixed at quote time and enforced when the cart is paid.
+        let line_splits = self
+            .resolve_payment_splits(tenant_id, &line_products)
+            .await?;
+        let split_lines: Vec<(i64, Option<PaymentSplit>)> = cart_items
+            .iter()
+            .map(|item| item.price.atomic)
+            .zip(line_splits)
+            .collect();
+        let token_mint = asset
+            .metadata
+            .solana_mint
+            .clone()
+            .unwrap_or_else(|| self.config.x402.token_mint.clone());
+        let split_legs = self.build_cart_split_legs(&split_lines, &final_total, &token_mint)?;
+
         let created_at = Utc::now();
         let expires_at = created_at + to_chrono_duration(self.config.storage.cart_quote_ttl);
 
@@ -538,8 +578,24 @@ impl PaywallService {
                 remaining.to_string(),
             );
         }
+        if !split_legs.is_empty() {
+            let encoded = serde_json::to_string(&split_legs).map_err(|e| {
+                ServiceError::Internal(format!("failed to encode payment split legs: {}", e))
+            })?;
+            metadata.insert(PAYMENT_SPLIT_LEGS_METADATA_KEY.to_string(), encoded);
+        }
         metadata.insert("item_count".to_string(), items.len().to_string());
         metadata.insert("total_quantity".to_string(), total_quantity.to_string());
+        // Attribution is only ever set from a resolved referral code; callers
+        // cannot inject an affiliate ID through free-form metadata.
+        cart_metadata.remove(AFFILIATE_ID_METADATA_KEY);
+        cart_metadata.remove(PAYMENT_SPLIT_LEGS_METADATA_KEY);
+        if let Some(code) = cart_metadata.remove(REFERRAL_CODE_METADATA_KEY) {
+            if let Some(affiliate) = self.resolve_referral(tenant_id, &code).await {
+                metadata.insert(REFERRAL_CODE_METADATA_KEY.to_string(), affiliate.code);
+                metadata.insert(AFFILIATE_ID_METADATA_KEY.to_string(), affiliate.id);
+            }
+        }
         for (key, value) in cart_metadata {
             metadata.entry(key).or_insert(value);
         }
@@ -183,6 +183,21 @@ impl PaywallService {
             });
         }
 
+        // Split payments: record how much each original leg recipient must reverse.
+        let mut refund_metadata = metadata.unwrap_or_default();
+        if let Some(legs) = split_legs_from_metadata(&original.metadata) {
+            let reversed = crate::models::payment_split::reverse_legs(
+                &legs,
+                u64::try_from(refund_amount.atomic).unwrap_or(0),
+            );
+            if !reversed.is_empty() {
+                let encoded = serde_json::to_string(&reversed).map_err(|e| {
+                    ServiceError::Internal(format!("failed to encode refund split legs: {}", e))
+                })?;
+                refund_metadata.insert(PAYMENT_SPLIT_LEGS_METADATA_KEY.to_string(), encoded);
+            }
+        }
+
         let refund_id = generate_refund_id();
         let now = Utc::now();
         let expires_at = now + to_chrono_duration(self.config.storage.refund_quote_ttl);
@@ -199,7 +214,7 @@ impl PaywallService {
                 .to_string(),
             amount: refund_amount,
             reason,
-            metadata: metadata.unwrap_or_default(),
+            metadata: refund_metadata,
             created_at: now,
             expires_at,
             processed_by: None,
@@ -222,6 +237,7 @@ impl PaywallService {
     }
 
     /// Process a refund (execute the refund transaction)
+    #[tracing::instrument(name = "paywall.process_refund", skip_all, fields(tenant_id = %tenant_id, resource_id = %refund_id))]
     pub async fn process_refund(
         &self,
         tenant_id: &str,
@@ -340,6 +356,10 @@ impl PaywallService {
                         code: ErrorCode::TransactionFailed,
                         message: format!("refund transaction failed: {}", msg),
                     },
+                    GaslessError::SigningFailed(msg) => ServiceError::Coded {
+                        code: ErrorCode::TransactionFailed,
+                        message: format!("refund signing failed: {}", msg),
+                    },
                     _ => ServiceError::Internal(format!("refund execution error: {}", e)),
                 });
             }
@@ -376,8 +396,19 @@ impl PaywallService {
             refunded_at: now,
         };
 
+        reverse_affiliate_commission(
+            &*self.store,
+            &refund.tenant_id,
+            &refund.original_purchase_id,
+            &refund.id,
+            refund.amount.atomic,
+            &refund.amount.asset.code,
+        )
+        .await;
+        crate::services::ledger::post_refund(&*self.store, &refund).await;
         self.call_refund_callback(&refund_event).await;
         self.notifier.refund_succeeded(refund_event).await;
+        self.notify_refund_processed(&refund).await;
 
         info!(
             refund_id = %refund_id,
@@ -552,6 +583,7 @@ impl PaywallService {
             memo: format!("refund:{}", refund.id),
             fee_payer,
             expires_at,
+            split_legs: split_legs_from_metadata(&refund.metadata).unwrap_or_default(),
         })
     }
 
@@ -559,6 +591,7 @@ impl PaywallService {
     ///
     /// This is called when the admin executes the refund and submits the X-PAYMENT header
     /// with the transaction proof.
+    #[tracing::instrument(name = "paywall.authorize_refund", skip_all, fields(tenant_id = %tenant_id, resource_id = %refund_id, payment_method = "x402"))]
     pub async fn authorize_refund(
         &self,
         tenant_id: &str,
@@ -709,6 +742,7 @@ impl PaywallService {
             quote_ttl: None,
             skip_preflight: self.config.x402.skip_preflight,
             commitment: self.config.x402.commitment.clone(),
+            splits: Vec::new(),
         };
 
         // Verify the transaction
@@ -755,10 +789,22 @@ impl PaywallService {
                 ))
             })?;
 
+        reverse_affiliate_commission(
+            &*self.store,
+            &refund.tenant_id,
+            &refund.original_purchase_id,
+            &refund.id,
+            refund.amount.atomic,
+            &refund.amount.asset.code,
+        )
+        .await;
+        crate::services::ledger::post_refund(&*self.store, &refund).await;
+
         let event = build_refund_succeeded_event(&refund, &result.wallet, &result.signature, now);
 
         self.call_refund_callback(&event).await;
         self.notifier.refund_succeeded(event).await;
+        self.notify_refund_processed(&refund).await;
 
         info!(
             refund_id = %refund_id,
@@ -778,6 +824,13 @@ impl PaywallService {
     }
 }
 
+/// Decode split legs stored under [`PAYMENT_SPLIT_LEGS_METADATA_KEY`].
+fn split_legs_from_metadata(metadata: &HashMap<String, String>) -> Option<Vec<PaymentLeg>> {
+    metadata
+        .get(PAYMENT_SPLIT_LEGS_METADATA_KEY)
+        .and_then(|raw| serde_json::from_str(raw).ok())
+}
+
 fn build_refund_succeeded_event(
     refund: &RefundQuote,
     processed_by: &str,
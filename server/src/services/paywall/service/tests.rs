@@ -2460,3 +2460,593 @@ fn test_refund_lock_manager_cleanup() {
         assert!(locks.contains_key("sig2"));
     }
 }
+
+struct RecordingVerifier {
+    result: VerificationResult,
+    requirement: Mutex<Option<Requirement>>,
+}
+
+#[async_trait]
+impl Verifier for RecordingVerifier {
+    async fn verify(
+        &self,
+        _proof: crate::models::PaymentProof,
+        requirement: Requirement,
+    ) -> Result<VerificationResult, VerifierError> {
+        *self.requirement.lock() = Some(requirement);
+        Ok(self.result.clone())
+    }
+}
+
+fn split_config(mint: String) -> Config {
+    let mut config = Config::default();
+    config.x402.payment_address = Pubkey::new_unique().to_string();
+    config.x402.token_mint = mint;
+    config
+}
+
+#[tokio::test]
+async fn test_split_product_authorize_requires_each_leg_and_refund_reverses_legs() {
+    let asset = get_asset("USDC").expect("asset should be registered");
+    let mint = asset.metadata.solana_mint.clone().expect("USDC mint");
+    let signature =
+        "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW";
+    let seller = Pubkey::new_unique().to_string();
+    let affiliate = Pubkey::new_unique().to_string();
+
+    let config = split_config(mint.clone());
+    let store = Arc::new(InMemoryStore::new());
+    let product = Product {
+        id: "product-1".to_string(),
+        tenant_id: "tenant-1".to_string(),
+        crypto_price: Some(Money::new(asset.clone(), 1_000)),
+        active: true,
+        payment_split: Some(PaymentSplit {
+            platform_fee_bps: 1_000,
+            seller_wallet: seller.clone(),
+            affiliate_wallet: Some(affiliate.clone()),
+            affiliate_bps: 500,
+        }),
+        ..Product::default()
+    };
+
+    let verifier = Arc::new(RecordingVerifier {
+        result: VerificationResult {
+            wallet: "wallet-1".to_string(),
+            amount: 1_000,
+            signature: signature.to_string(),
+            expires_at: Utc::now() + chrono::Duration::minutes(10),
+        },
+        requirement: Mutex::new(None),
+    });
+
+    let product_repo = Arc::new(InMemoryProductRepository::new(vec![product]));
+    let coupon_repo = Arc::new(InMemoryCouponRepository::new(Vec::new()));
+    let service = PaywallService::new(
+        config,
+        store.clone(),
+        verifier.clone(),
+        Arc::new(NoopNotifier),
+        product_repo,
+        coupon_repo,
+    );
+
+    let quote = service
+        .generate_quote("tenant-1", "product-1", None)
+        .await
+        .unwrap();
+    let quoted_legs = quote.crypto.and_then(|c| c.extra).expect("extra").splits;
+    assert_eq!(quoted_legs.len(), 3);
+
+    let header = json!({
+        "x402Version": X402_VERSION,
+        "scheme": X402_SCHEME_SPL,
+        "network": service.config.x402.network.clone(),
+        "payload": {
+            "signature": signature,
+            "transaction": "tx",
+            "resource": "product-1",
+            "resourceType": "regular"
+        }
+    })
+    .to_string();
+
+    let result = service
+        .authorize_with_wallet(
+            "tenant-1",
+            "product-1",
+            AuthorizeWithWalletRequest {
+                stripe_session_id: None,
+                payment_header: Some(&header),
+                coupon_code: None,
+                wallet: None,
+                credits_hold_id: None,
+                country_code: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert!(result.granted);
+
+    let requirement = verifier.requirement.lock().clone().expect("requirement");
+    assert_eq!(requirement.splits, quoted_legs);
+    let amounts: Vec<(crate::models::SplitRole, u64)> = requirement
+        .splits
+        .iter()
+        .map(|l| (l.role, l.amount_atomic))
+        .collect();
+    assert_eq!(
+        amounts,
+        vec![
+            (crate::models::SplitRole::Platform, 100),
+            (crate::models::SplitRole::Seller, 850),
+            (crate::models::SplitRole::Affiliate, 50),
+        ]
+    );
+    let seller_leg = &requirement.splits[1];
+    assert_eq!(
+        Some(seller_leg.recipient_token_account.clone()),
+        crate::x402::utils::derive_ata_safe(&seller, &mint)
+    );
+
+    let stored = store
+        .get_payment("tenant-1", signature)
+        .await
+        .unwrap()
+        .expect("payment stored");
+    assert!(stored
+        .metadata
+        .contains_key(PAYMENT_SPLIT_LEGS_METADATA_KEY));
+
+    let refund = match service
+        .create_refund_request(
+            "tenant-1",
+            signature,
+            Some("wallet-1"),
+            Some(Money::new(asset, 500)),
+            None,
+            None,
+        )
+        .await
+        .unwrap()
+    {
+        crate::services::paywall::service::RefundRequestResult::Crypto(r) => r,
+        crate::services::paywall::service::RefundRequestResult::Stripe(_) => {
+            panic!("expected crypto refund quote")
+        }
+    };
+    let reversed = split_legs_from_metadata(&refund.metadata).expect("refund legs");
+    let reversed_amounts: Vec<u64> = reversed.iter().map(|l| l.amount_atomic).collect();
+    assert_eq!(reversed_amounts, vec![50, 425, 25]);
+    assert_eq!(reversed[2].recipient_owner, affiliate);
+}
+
+#[tokio::test]
+async fn test_cart_with_split_product_requires_per_item_legs() {
+    let asset = get_asset("USDC").expect("asset should be registered");
+    let mint = asset.metadata.solana_mint.clone().expect("USDC mint");
+    let signature =
+        "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW";
+    let seller = Pubkey::new_unique().to_string();
+
+    let config = split_config(mint.clone());
+    let platform = config.x402.payment_address.clone();
+    let store = Arc::new(InMemoryStore::new());
+    let split_product = Product {
+        id: "product-1".to_string(),
+        tenant_id: "tenant-1".to_string(),
+        crypto_price: Some(Money::new(asset.clone(), 1_000)),
+        active: true,
+        payment_split: Some(PaymentSplit {
+            platform_fee_bps: 1_000,
+            seller_wallet: seller.clone(),
+            affiliate_wallet: None,
+            affiliate_bps: 0,
+        }),
+        ..Product::default()
+    };
+    let plain_product = Product {
+        id: "product-2".to_string(),
+        tenant_id: "tenant-1".to_string(),
+        crypto_price: Some(Money::new(asset.clone(), 500)),
+        active: true,
+        ..Product::default()
+    };
+
+    let verifier = Arc::new(RecordingVerifier {
+        result: VerificationResult {
+            wallet: "wallet-1".to_string(),
+            amount: 1_500,
+            signature: signature.to_string(),
+            expires_at: Utc::now() + chrono::Duration::minutes(10),
+        },
+        requirement: Mutex::new(None),
+    });
+
+    let product_repo = Arc::new(InMemoryProductRepository::new(vec![
+        split_product,
+        plain_product,
+    ]));
+    let coupon_repo = Arc::new(InMemoryCouponRepository::new(Vec::new()));
+    let service = PaywallService::new(
+        config,
+        store.clone(),
+        verifier.clone(),
+        Arc::new(NoopNotifier),
+        product_repo,
+        coupon_repo,
+    );
+
+    let quote = service
+        .generate_cart_quote(
+            "tenant-1",
+            vec![("product-1".to_string(), 1), ("product-2".to_string(), 1)],
+            None,
+        )
+        .await
+        .unwrap();
+    let quoted_legs = split_legs_from_metadata(&quote.metadata).expect("cart legs");
+
+    let proof = PaymentProof {
+        x402_version: 0,
+        scheme: "solana".to_string(),
+        network: service.config.x402.network.clone(),
+        signature: signature.to_string(),
+        payer: "wallet-1".to_string(),
+        transaction: "tx".to_string(),
+        resource_id: format!("cart:{}", quote.id),
+        resource_type: "cart".to_string(),
+        recipient_token_account: None,
+        memo: None,
+        fee_payer: None,
+        metadata: HashMap::new(),
+    };
+    let result = service
+        .authorize_cart("tenant-1", &quote.id, proof, None)
+        .await
+        .unwrap();
+    assert!(result.granted);
+
+    // The platform's fee on the split item and the plain item share one leg
+    let requirement = verifier.requirement.lock().clone().expect("requirement");
+    assert_eq!(requirement.splits, quoted_legs);
+    let legs: Vec<(String, u64)> = requirement
+        .splits
+        .iter()
+        .map(|l| (l.recipient_owner.clone(), l.amount_atomic))
+        .collect();
+    assert_eq!(legs, vec![(platform, 600), (seller, 900)]);
+
+    let stored = store
+        .get_payment("tenant-1", signature)
+        .await
+        .unwrap()
+        .expect("payment stored");
+    assert_eq!(
+        split_legs_from_metadata(&stored.metadata),
+        Some(quoted_legs)
+    );
+}
+
+#[tokio::test]
+async fn test_generate_quote_falls_back_to_collection_split() {
+    let asset = get_asset("USDC").expect("asset should be registered");
+    let mint = asset.metadata.solana_mint.clone().expect("USDC mint");
+    let seller = Pubkey::new_unique().to_string();
+
+    let store = Arc::new(InMemoryStore::new());
+    let now = Utc::now();
+    store
+        .create_collection(crate::models::Collection {
+            id: "col-1".to_string(),
+            tenant_id: "tenant-1".to_string(),
+            name: "Marketplace".to_string(),
+            description: None,
+            product_ids: vec!["product-1".to_string()],
+            active: true,
+            tokenization_config: None,
+            payment_split: Some(PaymentSplit {
+                platform_fee_bps: 250,
+                seller_wallet: seller.clone(),
+                ..PaymentSplit::default()
+            }),
+            created_at: now,
+            updated_at: now,
+        })
+        .await
+        .unwrap();
+
+    let product = Product {
+        id: "product-1".to_string(),
+        tenant_id: "tenant-1".to_string(),
+        crypto_price: Some(Money::new(asset, 1_000)),
+        active: true,
+        ..Product::default()
+    };
+    let product_repo = Arc::new(InMemoryProductRepository::new(vec![product]));
+    let coupon_repo = Arc::new(InMemoryCouponRepository::new(Vec::new()));
+    let service = PaywallService::new(
+        split_config(mint),
+        store,
+        Arc::new(NoopVerifier),
+        Arc::new(NoopNotifier),
+        product_repo,
+        coupon_repo,
+    );
+
+    let quote = service
+        .generate_quote("tenant-1", "product-1", None)
+        .await
+        .unwrap();
+    let legs = quote.crypto.and_then(|c| c.extra).expect("extra").splits;
+    assert_eq!(legs.len(), 2);
+    assert_eq!(legs[0].amount_atomic, 25);
+    assert_eq!(legs[1].recipient_owner, seller);
+    assert_eq!(legs[1].amount_atomic, 975);
+}
+
+async fn seed_affiliate(store: &Arc<InMemoryStore>) {
+    let now = Utc::now();
+    store
+        .create_collection(crate::models::Collection {
+            id: "col-1".to_string(),
+            tenant_id: "tenant-1".to_string(),
+            name: "Featured".to_string(),
+            description: None,
+            product_ids: vec!["product-1".to_string()],
+            active: true,
+            tokenization_config: None,
+            payment_split: None,
+            created_at: now,
+            updated_at: now,
+        })
+        .await
+        .unwrap();
+    store
+        .upsert_affiliate(crate::models::Affiliate {
+            id: "aff-1".to_string(),
+            tenant_id: "tenant-1".to_string(),
+            code: "PARTNER".to_string(),
+            name: "Partner".to_string(),
+            email: None,
+            payout_wallet: None,
+            active: true,
+            commission_type: crate::models::CommissionType::Percentage,
+            commission_value: 1_000,
+            rules: vec![crate::models::AffiliateCommissionRule {
+                product_id: None,
+                collection_id: Some("col-1".to_string()),
+                commission_type: crate::models::CommissionType::Percentage,
+                commission_value: 2_000,
+            }],
+            created_at: now,
+            updated_at: now,
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_cart_quote_captures_referral_and_ignores_injected_affiliate() {
+    let (service, store) = build_service(Duration::from_secs(60), Duration::from_secs(60));
+    seed_affiliate(&store).await;
+
+    let items = || {
+        vec![CartQuoteItemInput {
+            resource_id: "product-1".to_string(),
+            variant_id: None,
+            quantity: 1,
+            metadata: HashMap::new(),
+        }]
+    };
+
+    let mut metadata = HashMap::new();
+    metadata.insert(
+        REFERRAL_CODE_METADATA_KEY.to_string(),
+        " partner ".to_string(),
+    );
+    metadata.insert(AFFILIATE_ID_METADATA_KEY.to_string(), "forged".to_string());
+    let quote = service
+        .generate_cart_quote_with_metadata("tenant-1", items(), metadata, None, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        quote.metadata.get(REFERRAL_CODE_METADATA_KEY),
+        Some(&"PARTNER".to_string())
+    );
+    assert_eq!(
+        quote.metadata.get(AFFILIATE_ID_METADATA_KEY),
+        Some(&"aff-1".to_string())
+    );
+
+    // Unknown codes never block checkout and never attribute.
+    let mut metadata = HashMap::new();
+    metadata.insert(REFERRAL_CODE_METADATA_KEY.to_string(), "NOPE".to_string());
+    metadata.insert(AFFILIATE_ID_METADATA_KEY.to_string(), "forged".to_string());
+    let quote = service
+        .generate_cart_quote_with_metadata("tenant-1", items(), metadata, None, None)
+        .await
+        .unwrap();
+    assert!(!quote.metadata.contains_key(AFFILIATE_ID_METADATA_KEY));
+    assert!(!quote.metadata.contains_key(REFERRAL_CODE_METADATA_KEY));
+}
+
+#[tokio::test]
+async fn test_affiliate_commission_earned_on_order_and_reversed_on_refund() {
+    let (service, store) = build_service(Duration::from_secs(60), Duration::from_secs(60));
+    seed_affiliate(&store).await;
+
+    let mut metadata = HashMap::new();
+    metadata.insert(
+        REFERRAL_CODE_METADATA_KEY.to_string(),
+        "PARTNER".to_string(),
+    );
+    let quote = service
+        .generate_cart_quote_with_metadata(
+            "tenant-1",
+            vec![CartQuoteItemInput {
+                resource_id: "product-1".to_string(),
+                variant_id: None,
+                quantity: 2,
+                metadata: HashMap::new(),
+            }],
+            metadata,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(quote.total.atomic, 200);
+
+    service
+        .persist_cart_order_and_inventory("tenant-1", &quote, "sig-aff", None, None, "x402")
+        .await;
+
+    let orders = store.list_orders("tenant-1", 10, 0).await.unwrap();
+    assert_eq!(
+        orders[0].metadata.get(AFFILIATE_ID_METADATA_KEY),
+        Some(&"aff-1".to_string())
+    );
+
+    // Collection rule (20%) applies over the affiliate default (10%).
+    let entries = store
+        .list_affiliate_commissions_for_purchase("tenant-1", "sig-aff")
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].amount, 40);
+
+    let asset = get_asset("USDC").expect("asset should be registered");
+    let refund = RefundQuote {
+        id: "refund-aff".to_string(),
+        tenant_id: "tenant-1".to_string(),
+        original_purchase_id: "sig-aff".to_string(),
+        recipient_wallet: "wallet-1".to_string(),
+        amount: Money::new(asset, 50),
+        reason: None,
+        metadata: HashMap::new(),
+        created_at: Utc::now(),
+        expires_at: Utc::now(),
+        processed_by: None,
+        processed_at: None,
+        signature: None,
+    };
+    for _ in 0..2 {
+        // Replays of the same refund are no-ops.
+        reverse_affiliate_commission(
+            &*store,
+            &refund.tenant_id,
+            &refund.original_purchase_id,
+            &refund.id,
+            refund.amount.atomic,
+            &refund.amount.asset.code,
+        )
+        .await;
+    }
+
+    let entries = store
+        .list_affiliate_commissions_for_purchase("tenant-1", "sig-aff")
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 2);
+    let net: i64 = entries.iter().map(|e| e.amount).sum();
+    assert_eq!(net, 30);
+}
+
+#[tokio::test]
+async fn test_credits_hold_lifecycle_posts_to_ledger() {
+    use axum::extract::Path;
+    use axum::http::StatusCode;
+    use axum::{routing::post, Router};
+    use tokio::net::TcpListener;
+
+    let app = Router::new()
+        .route(
+            "/credits/hold/{user_id}",
+            post(|Path(user_id): Path<String>| async move {
+                axum::Json(json!({
+                    "holdId": format!("hold-{}", user_id),
+                    "isNew": true,
+                    "amountLamports": 1234,
+                    "expiresAt": (Utc::now() + chrono::Duration::minutes(5)).to_rfc3339(),
+                    "currency": "USDC"
+                }))
+            }),
+        )
+        .route(
+            "/credits/capture/{hold_id}",
+            post(|| async { StatusCode::OK }),
+        )
+        .route(
+            "/credits/release/{hold_id}",
+            post(|| async { StatusCode::OK }),
+        );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    let asset = get_asset("USDC").expect("asset");
+    let product = Product {
+        id: "product-1".to_string(),
+        tenant_id: "tenant-1".to_string(),
+        crypto_price: Some(Money::new(asset, 1234)),
+        active: true,
+        ..Product::default()
+    };
+    let (service, store) =
+        build_credits_service_with_products(format!("http://{}", addr), vec![product]);
+
+    for user_id in ["user-1", "user-2"] {
+        service
+            .create_credits_hold_for_user("tenant-1", "product-1", None, user_id)
+            .await
+            .unwrap();
+    }
+    service
+        .authorize_credits_for_user("tenant-1", "product-1", "hold-user-1", None, None, "user-1")
+        .await
+        .unwrap();
+    service
+        .release_credits_hold_for_user("tenant-1", "hold-user-2", "user-2")
+        .await
+        .unwrap();
+
+    let entries = store
+        .list_journal_entries(
+            "tenant-1",
+            Utc::now() - chrono::Duration::minutes(1),
+            Utc::now() + chrono::Duration::minutes(1),
+            100,
+            0,
+        )
+        .await
+        .unwrap();
+    let mut ids: Vec<&str> = entries.iter().map(|e| e.id.as_str()).collect();
+    ids.sort();
+    assert_eq!(
+        ids,
+        vec![
+            "credits_capture:hold-user-1",
+            "credits_hold:hold-user-1",
+            "credits_hold:hold-user-2",
+            "credits_release:hold-user-2",
+            "payment:credits:hold-user-1",
+        ]
+    );
+
+    let rows = store
+        .trial_balance("tenant-1", Utc::now() + chrono::Duration::minutes(1))
+        .await
+        .unwrap();
+    let balance = |account: &str| {
+        rows.iter()
+            .find(|r| r.account == account)
+            .map(|r| r.balance)
+            .unwrap()
+    };
+    assert_eq!(balance("credits_held"), 0);
+    assert_eq!(balance("credits_hold_clearing"), 0);
+    assert_eq!(balance("receivable:credits"), 1234);
+}
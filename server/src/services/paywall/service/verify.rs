@@ -46,7 +46,8 @@ impl PaywallService {
         })?;
 
         // Lookup product or cart
-        let (amount, recipient_ata, memo) = if let Some(cart_id) = resource_id.strip_prefix("cart:")
+        let (amount, recipient_ata, memo, legs) = if let Some(cart_id) =
+            resource_id.strip_prefix("cart:")
         {
             // Cart payment
             let cart = self
@@ -77,8 +78,10 @@ impl PaywallService {
                 });
             }
 
-            // Cart uses platform payment address - ATA will be derived below
-            (cart.total, None, Some(format!("cart:{}", cart.id)))
+            // Cart pays the platform payment address (ATA derived below) unless
+            // it holds split products, whose legs were fixed at quote time.
+            let legs = split_legs_from_metadata(&cart.metadata).unwrap_or_default();
+            (cart.total, None, Some(format!("cart:{}", cart.id)), legs)
         } else {
             // Single product payment
             let product = self
@@ -90,23 +93,40 @@ impl PaywallService {
                     message: "resource not found".into(),
                 })?;
 
-            let crypto_price = product.crypto_price.ok_or_else(|| ServiceError::Coded {
-                code: ErrorCode::InvalidAmount,
-                message: "product has no crypto price".into(),
-            })?;
+            let crypto_price = product
+                .crypto_price
+                .clone()
+                .ok_or_else(|| ServiceError::Coded {
+                    code: ErrorCode::InvalidAmount,
+                    message: "product has no crypto price".into(),
+                })?;
 
             // Apply coupons and round to cents (like Go does)
             let coupons = self
                 .select_coupons(tenant_id, resource_id, coupon_code, Some("x402"))
                 .await?;
-            let discounted =
-                stack_coupons_on_money(crypto_price, &coupons, self.get_rounding_mode())
-                    .round_up_to_cents();
+            let required = stack_coupons_on_money(crypto_price, &coupons, self.get_rounding_mode());
+            // Split legs are derived from the same (unrounded) amount the
+            // authorize path requires so they match exactly.
+            let legs = match self.resolve_payment_split(tenant_id, &product).await? {
+                Some(split) => {
+                    let split_mint = required
+                        .asset
+                        .metadata
+                        .solana_mint
+                        .clone()
+                        .unwrap_or_else(|| self.config.x402.token_mint.clone());
+                    self.build_split_legs(&split, &required, &split_mint)?
+                }
+                None => Vec::new(),
+            };
+            let discounted = required.round_up_to_cents();
 
             (
                 discounted,
                 product.crypto_account.clone(),
                 product.memo_template.clone(),
+                legs,
             )
         };
 
@@ -141,13 +161,28 @@ impl PaywallService {
             spl_associated_token_account::get_associated_token_address(&owner, &mint)
         };
 
+        // Split payments pay each leg directly
+        let transfers: Vec<(Pubkey, u64)> = if legs.is_empty() {
+            vec![(recipient_ata_pubkey, amount.atomic as u64)]
+        } else {
+            legs.into_iter()
+                .map(|leg| {
+                    Pubkey::from_str(&leg.recipient_token_account)
+                        .map(|ata| (ata, leg.amount_atomic))
+                        .map_err(|_| ServiceError::Coded {
+                            code: ErrorCode::InvalidRecipient,
+                            message: "invalid recipient token account".into(),
+                        })
+                })
+                .collect::<ServiceResult<_>>()?
+        };
+
         // Build gasless transaction using the builder
         let tx_data = gasless_builder
             .build_payment_transaction(
                 &user_pubkey,
-                &recipient_ata_pubkey,
+                &transfers,
                 &mint,
-                amount.atomic as u64,
                 self.config.x402.token_decimals,
                 memo.as_deref(),
             )
@@ -49,6 +49,9 @@ pub struct RefundQuoteResponse {
     pub memo: String,
     pub fee_payer: Option<String>,
     pub expires_at: chrono::DateTime<Utc>,
+    /// For split payments: the share of the refund attributable to each original
+    /// leg, so seller/affiliate portions can be reversed from their recipients.
+    pub split_legs: Vec<crate::models::PaymentLeg>,
 }
 
 /// Verification result for payment
@@ -0,0 +1,589 @@
+//! Scheduled price changes and time-boxed sales.
+//!
+//! A [`PriceSchedule`] replaces a product's fiat and/or crypto price, or one
+//! variant's price, at `starts_at` and restores the replaced prices at
+//! `ends_at`. With `show_as_sale` the replaced price becomes the compare-at
+//! price while the schedule is active.
+//!
+//! A new fiat price gets its own Stripe price on the product's Stripe product;
+//! the revert points the product back at the original Stripe price. Stripe
+//! prices are never archived here, so Stripe checkout of a cart quote created
+//! before a change keeps the quoted price until the quote expires (see
+//! [`crate::services::paywall::service::QUOTED_STRIPE_PRICE_METADATA_KEY`]).
+//!
+//! Every transition is claimed with a status-guarded update first, so two
+//! workers (or a worker and an admin cancelling) never apply or revert the
+//! same schedule twice. Each apply, revert and failure is written to the
+//! admin audit log against the product.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde_json::json;
+
+use crate::models::{
+    AdminAuditEntry, Money, PriceSchedule, PriceScheduleStatus, PriceSnapshot, Product,
+};
+use crate::repositories::ProductRepository;
+use crate::services::StripeClient;
+use crate::storage::{StorageResult, Store};
+
+/// Applies and reverts price schedules. Shared by the price schedule worker
+/// and the admin cancel endpoint.
+pub struct PriceScheduler {
+    store: Arc<dyn Store>,
+    products: Arc<dyn ProductRepository>,
+    stripe_client: Option<Arc<StripeClient>>,
+}
+
+impl PriceScheduler {
+    pub fn new(
+        store: Arc<dyn Store>,
+        products: Arc<dyn ProductRepository>,
+        stripe_client: Option<Arc<StripeClient>>,
+    ) -> Self {
+        Self {
+            store,
+            products,
+            stripe_client,
+        }
+    }
+
+    /// Apply or revert up to `limit` schedules due at `now`. Returns how many
+    /// were processed.
+    pub async fn run_due(&self, now: DateTime<Utc>, limit: i32) -> StorageResult<usize> {
+        let due = self.store.list_due_price_schedules(now, limit).await?;
+        let count = due.len();
+        for schedule in due {
+            match schedule.status {
+                PriceScheduleStatus::Scheduled => self.apply(schedule, now).await,
+                PriceScheduleStatus::Active => {
+                    self.revert(schedule, now, PriceScheduleStatus::Completed, None)
+                        .await;
+                }
+                _ => {}
+            }
+        }
+        Ok(count)
+    }
+
+    /// Apply a scheduled change to its product
+    pub async fn apply(&self, schedule: PriceSchedule, now: DateTime<Utc>) {
+        if schedule.ends_at.is_some_and(|end| end <= now) {
+            self.fail(
+                schedule,
+                PriceScheduleStatus::Scheduled,
+                now,
+                "schedule ended before it could be applied".to_string(),
+            )
+            .await;
+            return;
+        }
+
+        let mut product = match self
+            .products
+            .get_product(&schedule.tenant_id, &schedule.product_id)
+            .await
+        {
+            Ok(product) => product,
+            Err(e) => {
+                self.fail(
+                    schedule,
+                    PriceScheduleStatus::Scheduled,
+                    now,
+                    format!("failed to load product: {}", e),
+                )
+                .await;
+                return;
+            }
+        };
+        let original = match apply_prices(&mut product, &schedule) {
+            Ok(original) => original,
+            Err(message) => {
+                self.fail(schedule, PriceScheduleStatus::Scheduled, now, message)
+                    .await;
+                return;
+            }
+        };
+
+        let mut active = schedule;
+        active.status = PriceScheduleStatus::Active;
+        active.original = Some(original);
+        active.applied_at = Some(now);
+        active.updated_at = now;
+        if !self
+            .claim(active.clone(), PriceScheduleStatus::Scheduled)
+            .await
+        {
+            return;
+        }
+
+        if let Some(ref fiat) = active.fiat_price {
+            match self.stripe_price_for(&product, fiat).await {
+                Ok(Some(price_id)) => {
+                    product.stripe_price_id = Some(price_id.clone());
+                    active.stripe_price_id = Some(price_id);
+                }
+                Ok(None) => {}
+                Err(message) => {
+                    self.fail(active, PriceScheduleStatus::Active, now, message)
+                        .await;
+                    return;
+                }
+            }
+        }
+
+        product.updated_at = Some(now);
+        if let Err(e) = self.products.update_product(product).await {
+            self.fail(
+                active,
+                PriceScheduleStatus::Active,
+                now,
+                format!("failed to update product: {}", e),
+            )
+            .await;
+            return;
+        }
+        if active.stripe_price_id.is_some() {
+            // Still active unless an admin cancelled in between
+            self.claim(active.clone(), PriceScheduleStatus::Active)
+                .await;
+        }
+
+        tracing::info!(
+            tenant_id = %active.tenant_id,
+            product_id = %active.product_id,
+            schedule_id = %active.id,
+            "Applied scheduled price change"
+        );
+        self.audit(
+            &active,
+            "apply_price_schedule",
+            None,
+            schedule_detail(&active),
+        )
+        .await;
+    }
+
+    /// End an active schedule and restore the prices it replaced. Prices
+    /// edited since the schedule was applied are kept. `status` is
+    /// `Completed` when the schedule ran its course, `Cancelled` when an
+    /// admin ended it early. Returns the saved schedule, or None when it was
+    /// no longer active.
+    pub async fn revert(
+        &self,
+        schedule: PriceSchedule,
+        now: DateTime<Utc>,
+        status: PriceScheduleStatus,
+        actor: Option<String>,
+    ) -> Option<PriceSchedule> {
+        let product = match self
+            .products
+            .get_product(&schedule.tenant_id, &schedule.product_id)
+            .await
+        {
+            Ok(product) => Some(product),
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    schedule_id = %schedule.id,
+                    "Price schedule product not found; nothing to revert"
+                );
+                None
+            }
+        };
+
+        let mut ended = schedule;
+        ended.status = status;
+        ended.completed_at = Some(now);
+        ended.updated_at = now;
+        if !self.claim(ended.clone(), PriceScheduleStatus::Active).await {
+            return None;
+        }
+
+        let mut kept = Vec::new();
+        if let (Some(mut product), Some(original)) = (product, ended.original.clone()) {
+            kept = revert_prices(&mut product, &ended, &original);
+            product.updated_at = Some(now);
+            if let Err(e) = self.products.update_product(product).await {
+                let message = format!("failed to restore prices: {}", e);
+                tracing::error!(schedule_id = %ended.id, error = %message, "Price schedule revert failed");
+                ended.status = PriceScheduleStatus::Failed;
+                ended.error = Some(message);
+                if let Err(e) = self.store.save_price_schedule(ended.clone()).await {
+                    tracing::error!(error = %e, schedule_id = %ended.id, "Failed to save price schedule");
+                }
+                self.audit(
+                    &ended,
+                    "fail_price_schedule",
+                    actor,
+                    schedule_detail(&ended),
+                )
+                .await;
+                return Some(ended);
+            }
+        }
+
+        let action = match status {
+            PriceScheduleStatus::Cancelled => "cancel_price_schedule",
+            _ => "revert_price_schedule",
+        };
+        let mut detail = schedule_detail(&ended);
+        if !kept.is_empty() {
+            tracing::warn!(
+                schedule_id = %ended.id,
+                kept = ?kept,
+                "Prices edited during a price schedule were kept"
+            );
+            detail["keptEditedPrices"] = json!(kept);
+        }
+        tracing::info!(
+            tenant_id = %ended.tenant_id,
+            product_id = %ended.product_id,
+            schedule_id = %ended.id,
+            status = ended.status.as_str(),
+            "Reverted scheduled price change"
+        );
+        self.audit(&ended, action, actor, detail).await;
+        Some(ended)
+    }
+
+    /// Stripe price to charge for `fiat`: a new price on the product's Stripe
+    /// product, or None when the product is not sold through Stripe
+    async fn stripe_price_for(
+        &self,
+        product: &Product,
+        fiat: &Money,
+    ) -> Result<Option<String>, String> {
+        let Some(stripe_product_id) = product.stripe_product_id.as_deref() else {
+            if product.stripe_price_id.is_some() {
+                return Err(
+                    "product has a Stripe price but no Stripe product to add the new price to"
+                        .to_string(),
+                );
+            }
+            return Ok(None);
+        };
+        let Some(client) = self.stripe_client.as_deref() else {
+            return Err("Stripe is not configured".to_string());
+        };
+        client
+            .create_stripe_price(
+                stripe_product_id,
+                fiat.atomic,
+                &fiat.asset.code.to_lowercase(),
+            )
+            .await
+            .map(Some)
+            .map_err(|e| format!("failed to create Stripe price: {}", e))
+    }
+
+    async fn claim(&self, schedule: PriceSchedule, expected: PriceScheduleStatus) -> bool {
+        match self
+            .store
+            .update_price_schedule_if_status(schedule.clone(), expected)
+            .await
+        {
+            Ok(claimed) => claimed,
+            Err(e) => {
+                tracing::error!(error = %e, schedule_id = %schedule.id, "Failed to update price schedule");
+                false
+            }
+        }
+    }
+
+    async fn fail(
+        &self,
+        mut schedule: PriceSchedule,
+        expected: PriceScheduleStatus,
+        now: DateTime<Utc>,
+        message: String,
+    ) {
+        tracing::warn!(
+            tenant_id = %schedule.tenant_id,
+            schedule_id = %schedule.id,
+            error = %message,
+            "Scheduled price change failed"
+        );
+        schedule.status = PriceScheduleStatus::Failed;
+        schedule.error = Some(message);
+        schedule.completed_at = Some(now);
+        schedule.updated_at = now;
+        if self.claim(schedule.clone(), expected).await {
+            self.audit(
+                &schedule,
+                "fail_price_schedule",
+                None,
+                schedule_detail(&schedule),
+            )
+            .await;
+        }
+    }
+
+    async fn audit(
+        &self,
+        schedule: &PriceSchedule,
+        action: &str,
+        actor: Option<String>,
+        detail: serde_json::Value,
+    ) {
+        let entry = AdminAuditEntry::new(
+            &schedule.tenant_id,
+            "product",
+            &schedule.product_id,
+            action,
+            actor,
+            Some(detail),
+        );
+        if let Err(e) = self.store.record_admin_audit(entry).await {
+            tracing::error!(error = %e, schedule_id = %schedule.id, action, "Failed to record admin audit entry");
+        }
+    }
+}
+
+/// Audit detail of a schedule: what it changed and how it ended
+pub fn schedule_detail(schedule: &PriceSchedule) -> serde_json::Value {
+    let money = |m: &Option<Money>| {
+        m.as_ref()
+            .map(|m| json!({ "atomic": m.atomic, "asset": m.asset.code }))
+    };
+    json!({
+        "scheduleId": schedule.id,
+        "variantId": schedule.variant_id,
+        "fiatPrice": money(&schedule.fiat_price),
+        "cryptoPrice": money(&schedule.crypto_price),
+        "variantPrice": schedule.variant_price,
+        "stripePriceId": schedule.stripe_price_id,
+        "status": schedule.status,
+        "error": schedule.error,
+    })
+}
+
+/// Set the schedule's prices on `product` and return the prices replaced
+pub fn apply_prices(
+    product: &mut Product,
+    schedule: &PriceSchedule,
+) -> Result<PriceSnapshot, String> {
+    let mut original = PriceSnapshot::default();
+
+    if let Some(variant_id) = schedule.variant_id.as_deref() {
+        let variant = product
+            .get_variant_mut(variant_id)
+            .ok_or_else(|| format!("variant {} not found", variant_id))?;
+        original.variant_price = variant.price.clone();
+        original.variant_compare_at_price = variant.compare_at_price.clone();
+        if let Some(ref price) = schedule.variant_price {
+            variant.price = Some(price.clone());
+            if schedule.show_as_sale {
+                variant.compare_at_price = original.variant_price.clone();
+            }
+        }
+        return Ok(original);
+    }
+
+    original.fiat_price = product.fiat_price.clone();
+    original.compare_at_fiat_price = product.compare_at_fiat_price.clone();
+    original.stripe_price_id = product.stripe_price_id.clone();
+    original.crypto_price = product.crypto_price.clone();
+    if let Some(ref fiat) = schedule.fiat_price {
+        product.fiat_price = Some(fiat.clone());
+        if schedule.show_as_sale {
+            product.compare_at_fiat_price = original.fiat_price.clone();
+        }
+    }
+    if let Some(ref crypto) = schedule.crypto_price {
+        product.crypto_price = Some(crypto.clone());
+    }
+    Ok(original)
+}
+
+/// Restore the prices `schedule` replaced. A price no longer at the
+/// scheduled value was edited while the schedule was active and is kept;
+/// the names of kept prices are returned.
+pub fn revert_prices(
+    product: &mut Product,
+    schedule: &PriceSchedule,
+    original: &PriceSnapshot,
+) -> Vec<&'static str> {
+    let mut kept = Vec::new();
+
+    if let Some(variant_id) = schedule.variant_id.as_deref() {
+        let Some(variant) = product.get_variant_mut(variant_id) else {
+            kept.push("variantPrice");
+            return kept;
+        };
+        if variant.price.is_some() && variant.price == schedule.variant_price {
+            variant.price = original.variant_price.clone();
+            if schedule.show_as_sale {
+                variant.compare_at_price = original.variant_compare_at_price.clone();
+            }
+        } else if schedule.variant_price.is_some() {
+            kept.push("variantPrice");
+        }
+        return kept;
+    }
+
+    if let Some(ref fiat) = schedule.fiat_price {
+        if same_money(product.fiat_price.as_ref(), fiat) {
+            product.fiat_price = original.fiat_price.clone();
+            if schedule.show_as_sale {
+                product.compare_at_fiat_price = original.compare_at_fiat_price.clone();
+            }
+            if schedule.stripe_price_id.is_some()
+                && product.stripe_price_id == schedule.stripe_price_id
+            {
+                product.stripe_price_id = original.stripe_price_id.clone();
+            }
+        } else {
+            kept.push("fiatPrice");
+        }
+    }
+    if let Some(ref crypto) = schedule.crypto_price {
+        if same_money(product.crypto_price.as_ref(), crypto) {
+            product.crypto_price = original.crypto_price.clone();
+        } else {
+            kept.push("cryptoPrice");
+        }
+    }
+    kept
+}
+
+fn same_money(current: Option<&Money>, scheduled: &Money) -> bool {
+    current.is_some_and(|m| m.atomic == scheduled.atomic && m.asset.code == scheduled.asset.code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{get_asset, ProductVariant, VariantPrice};
+
+    fn usd(cents: i64) -> Money {
+        Money::new(get_asset("USD").unwrap(), cents)
+    }
+
+    fn product() -> Product {
+        Product {
+            id: "prod-1".to_string(),
+            fiat_price: Some(usd(1000)),
+            stripe_product_id: Some("prod_stripe".to_string()),
+            stripe_price_id: Some("price_regular".to_string()),
+            crypto_price: Some(Money::new(get_asset("USDC").unwrap(), 10_000_000)),
+            variants: vec![ProductVariant {
+                id: "v-red".to_string(),
+                title: "Red".to_string(),
+                price: Some(VariantPrice {
+                    amount: Some(12.0),
+                    currency: Some("USDC".to_string()),
+                }),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn schedule() -> PriceSchedule {
+        let now = Utc::now();
+        PriceSchedule {
+            id: "sched-1".to_string(),
+            tenant_id: "default".to_string(),
+            product_id: "prod-1".to_string(),
+            variant_id: None,
+            fiat_price: Some(usd(800)),
+            crypto_price: None,
+            variant_price: None,
+            show_as_sale: true,
+            starts_at: now,
+            ends_at: Some(now + chrono::Duration::days(1)),
+            status: PriceScheduleStatus::Scheduled,
+            original: None,
+            stripe_price_id: None,
+            note: None,
+            error: None,
+            created_at: now,
+            updated_at: now,
+            applied_at: None,
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn sale_sets_compare_at_and_revert_restores_prices() {
+        let mut product = product();
+        let mut sale = schedule();
+        let original = apply_prices(&mut product, &sale).unwrap();
+        assert_eq!(product.fiat_price, Some(usd(800)));
+        assert_eq!(product.compare_at_fiat_price, Some(usd(1000)));
+
+        // The worker points the product at the sale's Stripe price
+        sale.stripe_price_id = Some("price_sale".to_string());
+        product.stripe_price_id = sale.stripe_price_id.clone();
+
+        let kept = revert_prices(&mut product, &sale, &original);
+        assert!(kept.is_empty());
+        assert_eq!(product.fiat_price, Some(usd(1000)));
+        assert_eq!(product.compare_at_fiat_price, None);
+        assert_eq!(product.stripe_price_id.as_deref(), Some("price_regular"));
+        assert_eq!(product.crypto_price, original.crypto_price);
+    }
+
+    #[test]
+    fn revert_keeps_prices_edited_during_the_schedule() {
+        let mut product = product();
+        let mut change = schedule();
+        change.show_as_sale = false;
+        change.crypto_price = Some(Money::new(get_asset("USDC").unwrap(), 8_000_000));
+        let original = apply_prices(&mut product, &change).unwrap();
+        assert_eq!(product.compare_at_fiat_price, None);
+
+        product.fiat_price = Some(usd(900));
+        let kept = revert_prices(&mut product, &change, &original);
+        assert_eq!(kept, vec!["fiatPrice"]);
+        assert_eq!(product.fiat_price, Some(usd(900)));
+        assert_eq!(product.crypto_price, original.crypto_price);
+    }
+
+    #[test]
+    fn variant_change_leaves_product_prices_alone() {
+        let mut product = product();
+        let mut change = schedule();
+        change.variant_id = Some("v-red".to_string());
+        change.fiat_price = None;
+        change.variant_price = Some(VariantPrice {
+            amount: Some(9.5),
+            currency: Some("USDC".to_string()),
+        });
+        let original = apply_prices(&mut product, &change).unwrap();
+        let variant = product.get_variant("v-red").unwrap();
+        assert_eq!(variant.price, change.variant_price);
+        assert_eq!(variant.compare_at_price, original.variant_price);
+        assert_eq!(product.fiat_price, Some(usd(1000)));
+
+        assert!(revert_prices(&mut product, &change, &original).is_empty());
+        let variant = product.get_variant("v-red").unwrap();
+        assert_eq!(variant.price, original.variant_price);
+        assert_eq!(variant.compare_at_price, None);
+
+        change.variant_id = Some("v-missing".to_string());
+        assert!(apply_prices(&mut product, &change).is_err());
+    }
+
+    #[test]
+    fn open_windows_for_the_same_target_overlap() {
+        let first = schedule();
+        let mut second = schedule();
+        second.id = "sched-2".to_string();
+        second.starts_at = first.ends_at.unwrap();
+        second.ends_at = None;
+        assert!(!first.overlaps(&second));
+
+        second.starts_at = first.starts_at + chrono::Duration::hours(1);
+        assert!(first.overlaps(&second));
+
+        second.variant_id = Some("v-red".to_string());
+        assert!(!first.overlaps(&second));
+
+        second.variant_id = None;
+        second.status = PriceScheduleStatus::Cancelled;
+        assert!(!first.overlaps(&second));
+    }
+}
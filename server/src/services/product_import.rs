@@ -0,0 +1,958 @@
+//! Bulk product import / export formats.
+//!
+//! Imports are parsed into one JSON record per product, shaped like the admin
+//! create/update request (`CreateProductRequest`) plus `variationConfig`. A
+//! record only carries the fields present in the file; the import job merges it
+//! over the existing product (see [`merge_record`]) so omitted fields are kept.
+//!
+//! JSON imports are an array of records (or `{"products": [...]}`, which is
+//! what the JSON export writes). CSV imports use the [`CSV_COLUMNS`] header
+//! names, any subset and order, with one row per variant:
+//!
+//! - rows are grouped into a product by `id`, or by `slug` when `id` is blank;
+//!   product columns are read from the product's first row
+//! - a row with any `variant*` column set adds a variant; when a product has
+//!   variant rows they replace its variant list
+//! - blank cells are omitted (the existing value is kept)
+//! - list cells (`tags`, `categoryIds`, `imageUrls`, `variantImageUrls`) are
+//!   `|`-separated
+//! - `variationTypes` is `Size=S|M|L; Color=Red|Blue` and `variantOptions` is
+//!   `Size=M; Color=Blue`
+//!
+//! Variation type, value and variant ids may be left blank; [`fill_variation_ids`]
+//! reuses the existing product's ids (matched by name, label, SKU or title) and
+//! generates the rest.
+
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::handlers::admin::AdminProductInfo;
+use crate::models::{Product, ProductImportFormat, ProductVariant, ProductVariationConfig};
+use crate::services::financial_reports::csv_field;
+
+/// CSV header names: product columns, then variant columns.
+pub const CSV_COLUMNS: &[&str] = &[
+    "id",
+    "slug",
+    "title",
+    "description",
+    "shortDescription",
+    "seoTitle",
+    "seoDescription",
+    "tags",
+    "categoryIds",
+    "imageUrls",
+    "featured",
+    "sortOrder",
+    "active",
+    "shippingProfile",
+    "fiatAmountCents",
+    "fiatCurrency",
+    "compareAtFiatAmountCents",
+    "compareAtFiatCurrency",
+    "cryptoAtomicAmount",
+    "cryptoToken",
+    "stripePriceId",
+    "inventoryQuantity",
+    "inventoryStatus",
+    "inventoryPolicy",
+    "variationTypes",
+    "variantId",
+    "variantTitle",
+    "variantSku",
+    "variantOptions",
+    "variantPriceAmount",
+    "variantPriceCurrency",
+    "variantInventoryQuantity",
+    "variantInventoryStatus",
+    "variantImageUrls",
+];
+
+const LIST_SEPARATOR: char = '|';
+
+/// One product of an import file.
+#[derive(Debug)]
+pub struct ImportRecord {
+    /// 1-based CSV data row (of the product's first row) or JSON array index
+    pub row: i64,
+    /// The product record, or why the row could not be read
+    pub record: Result<Map<String, Value>, String>,
+}
+
+/// Parses an import file into product records.
+///
+/// Returns `Err` when the file as a whole is unreadable (malformed JSON or
+/// CSV, unknown CSV columns); problems with a single product are reported in
+/// its [`ImportRecord`] instead.
+pub fn parse_import(format: ProductImportFormat, body: &str) -> Result<Vec<ImportRecord>, String> {
+    match format {
+        ProductImportFormat::Json => json_records(body),
+        ProductImportFormat::Csv => csv_records(body),
+    }
+}
+
+fn json_records(body: &str) -> Result<Vec<ImportRecord>, String> {
+    let value: Value = serde_json::from_str(body).map_err(|e| format!("invalid JSON: {e}"))?;
+    let items = match value {
+        Value::Array(items) => items,
+        Value::Object(mut obj) => match obj.remove("products") {
+            Some(Value::Array(items)) => items,
+            _ => return Err("expected an array of products or {\"products\": [...]}".to_string()),
+        },
+        _ => return Err("expected an array of products or {\"products\": [...]}".to_string()),
+    };
+
+    Ok(items
+        .into_iter()
+        .enumerate()
+        .map(|(i, item)| ImportRecord {
+            row: i as i64 + 1,
+            record: match item {
+                Value::Object(map) => Ok(map),
+                _ => Err("product must be a JSON object".to_string()),
+            },
+        })
+        .collect())
+}
+
+fn csv_records(body: &str) -> Result<Vec<ImportRecord>, String> {
+    let mut rows = parse_csv(body)?.into_iter();
+    let header = rows.next().ok_or("CSV file is empty")?;
+    let columns: Vec<&'static str> = header
+        .iter()
+        .map(|name| {
+            let name = name.trim();
+            CSV_COLUMNS
+                .iter()
+                .find(|c| **c == name)
+                .copied()
+                .ok_or_else(|| format!("unknown CSV column: {name}"))
+        })
+        .collect::<Result<_, _>>()?;
+    if !columns.contains(&"id") && !columns.contains(&"slug") {
+        return Err("CSV needs an id or slug column".to_string());
+    }
+
+    // Group rows into products, keeping file order.
+    let mut records: Vec<ImportRecord> = Vec::new();
+    let mut groups: HashMap<String, usize> = HashMap::new();
+    for (i, row) in rows.enumerate() {
+        let row_number = i as i64 + 1;
+        if row.len() != columns.len() {
+            records.push(ImportRecord {
+                row: row_number,
+                record: Err(format!(
+                    "expected {} columns, found {}",
+                    columns.len(),
+                    row.len()
+                )),
+            });
+            continue;
+        }
+        let cells: Vec<(&str, &str)> = columns
+            .iter()
+            .zip(&row)
+            .map(|(column, cell)| (*column, unescape_cell(cell.trim())))
+            .filter(|(_, cell)| !cell.is_empty())
+            .collect();
+        let cell = |name: &str| cells.iter().find(|(c, _)| *c == name).map(|(_, v)| *v);
+        let key = match (cell("id"), cell("slug")) {
+            (Some(id), _) => format!("id:{id}"),
+            (None, Some(slug)) => format!("slug:{slug}"),
+            (None, None) => {
+                records.push(ImportRecord {
+                    row: row_number,
+                    record: Err("id or slug is required".to_string()),
+                });
+                continue;
+            }
+        };
+
+        let index = match groups.get(&key) {
+            Some(index) => *index,
+            None => {
+                let mut product = Map::new();
+                let parsed = cells
+                    .iter()
+                    .filter(|(column, _)| !column.starts_with("variant"))
+                    .try_for_each(|(column, value)| product_cell(&mut product, column, value))
+                    .map(|_| product);
+                groups.insert(key, records.len());
+                records.push(ImportRecord {
+                    row: row_number,
+                    record: parsed,
+                });
+                records.len() - 1
+            }
+        };
+
+        let variant_cells: Vec<_> = cells
+            .iter()
+            .filter(|(column, _)| column.starts_with("variant"))
+            .collect();
+        if variant_cells.is_empty() {
+            continue;
+        }
+        let mut variant = Map::new();
+        let parsed = variant_cells
+            .iter()
+            .try_for_each(|(column, value)| variant_cell(&mut variant, column, value))
+            .map_err(|e| format!("row {row_number}: {e}"));
+        let record = &mut records[index].record;
+        match (parsed, record.as_mut()) {
+            (Ok(()), Ok(product)) => {
+                let variants = product
+                    .entry("variants")
+                    .or_insert_with(|| Value::Array(Vec::new()));
+                if let Value::Array(variants) = variants {
+                    variants.push(Value::Object(variant));
+                }
+            }
+            (Err(e), Ok(_)) => *record = Err(e),
+            (_, Err(_)) => {}
+        }
+    }
+    Ok(records)
+}
+
+fn product_cell(product: &mut Map<String, Value>, column: &str, cell: &str) -> Result<(), String> {
+    let (key, value) = match column {
+        "tags" | "categoryIds" => (column, json!(split_list(cell))),
+        "imageUrls" => ("images", image_list(cell)),
+        "featured" | "active" => (column, Value::Bool(parse_bool(column, cell)?)),
+        "sortOrder" | "inventoryQuantity" => (column, json!(parse_number::<i32>(column, cell)?)),
+        "fiatAmountCents" | "compareAtFiatAmountCents" | "cryptoAtomicAmount" => {
+            (column, json!(parse_number::<i64>(column, cell)?))
+        }
+        "variationTypes" => ("variationConfig", parse_variation_types(cell)?),
+        _ => (column, Value::String(cell.to_string())),
+    };
+    product.insert(key.to_string(), value);
+    Ok(())
+}
+
+fn variant_cell(variant: &mut Map<String, Value>, column: &str, cell: &str) -> Result<(), String> {
+    let (key, value) = match column {
+        "variantId" => ("id", Value::String(cell.to_string())),
+        "variantTitle" => ("title", Value::String(cell.to_string())),
+        "variantSku" => ("sku", Value::String(cell.to_string())),
+        "variantOptions" => ("options", Value::Object(parse_options(column, cell)?)),
+        "variantInventoryQuantity" => (
+            "inventoryQuantity",
+            json!(parse_number::<i32>(column, cell)?),
+        ),
+        "variantInventoryStatus" => ("inventoryStatus", Value::String(cell.to_string())),
+        "variantImageUrls" => ("images", image_list(cell)),
+        "variantPriceAmount" | "variantPriceCurrency" => {
+            let (field, value) = if column == "variantPriceAmount" {
+                ("amount", json!(parse_number::<f64>(column, cell)?))
+            } else {
+                ("currency", Value::String(cell.to_string()))
+            };
+            if let Value::Object(price) = variant.entry("price").or_insert_with(|| json!({})) {
+                price.insert(field.to_string(), value);
+            }
+            return Ok(());
+        }
+        _ => return Err(format!("unknown variant column: {column}")),
+    };
+    variant.insert(key.to_string(), value);
+    Ok(())
+}
+
+/// Strips the `'` that [`csv_field`] puts before formula characters.
+fn unescape_cell(cell: &str) -> &str {
+    match cell.strip_prefix('\'') {
+        Some(rest) if rest.starts_with(['=', '+', '-', '@']) => rest,
+        _ => cell,
+    }
+}
+
+fn split_list(cell: &str) -> Vec<&str> {
+    cell.split(LIST_SEPARATOR)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn image_list(cell: &str) -> Value {
+    Value::Array(
+        split_list(cell)
+            .into_iter()
+            .map(|url| json!({ "url": url }))
+            .collect(),
+    )
+}
+
+fn parse_bool(column: &str, cell: &str) -> Result<bool, String> {
+    match cell.to_ascii_lowercase().as_str() {
+        "true" | "yes" | "1" => Ok(true),
+        "false" | "no" | "0" => Ok(false),
+        _ => Err(format!("{column} must be true or false")),
+    }
+}
+
+fn parse_number<T: FromStr>(column: &str, cell: &str) -> Result<T, String> {
+    cell.parse()
+        .map_err(|_| format!("{column} must be a number, got '{cell}'"))
+}
+
+/// `Size=S|M|L; Color=Red|Blue` → `variationConfig` with blank ids.
+fn parse_variation_types(cell: &str) -> Result<Value, String> {
+    let mut types = Vec::new();
+    for (order, part) in cell
+        .split(';')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .enumerate()
+    {
+        let (name, values) = part
+            .split_once('=')
+            .map(|(name, values)| (name.trim(), split_list(values)))
+            .filter(|(name, values)| !name.is_empty() && !values.is_empty())
+            .ok_or_else(|| format!("variationTypes: expected Name=Value|Value, got '{part}'"))?;
+        let values: Vec<Value> = values
+            .into_iter()
+            .map(|label| json!({ "id": "", "label": label }))
+            .collect();
+        types.push(json!({ "id": "", "name": name, "displayOrder": order, "values": values }));
+    }
+    Ok(json!({ "variationTypes": types }))
+}
+
+/// `Size=M; Color=Blue` → `{"Size": "M", "Color": "Blue"}`.
+fn parse_options(column: &str, cell: &str) -> Result<Map<String, Value>, String> {
+    let mut options = Map::new();
+    for part in cell.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+        let (name, value) = part
+            .split_once('=')
+            .map(|(name, value)| (name.trim(), value.trim()))
+            .filter(|(name, value)| !name.is_empty() && !value.is_empty())
+            .ok_or_else(|| format!("{column}: expected Name=Value, got '{part}'"))?;
+        options.insert(name.to_string(), Value::String(value.to_string()));
+    }
+    Ok(options)
+}
+
+/// Minimal RFC 4180 reader: quoted fields may hold commas, quotes (`""`) and
+/// newlines. Blank lines are skipped.
+fn parse_csv(body: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut closed_quote = false;
+    let mut line = 1;
+
+    let mut chars = body.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\n' {
+            line += 1;
+        }
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                    closed_quote = true;
+                }
+            } else {
+                field.push(c);
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() && !closed_quote => in_quotes = true,
+            ',' => {
+                row.push(std::mem::take(&mut field));
+                closed_quote = false;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\r' | '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+                closed_quote = false;
+            }
+            _ if closed_quote => {
+                return Err(format!(
+                    "malformed CSV: text after closing quote on line {line}"
+                ))
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err("malformed CSV: unterminated quoted field".to_string());
+    }
+    if !field.is_empty() || !row.is_empty() || closed_quote {
+        row.push(field);
+        rows.push(row);
+    }
+    rows.retain(|r| !(r.len() == 1 && r[0].is_empty()));
+    Ok(rows)
+}
+
+// ============================================================================
+// Merge and variation ids
+// ============================================================================
+
+/// The product as an import record: the admin product JSON (minus read-only
+/// fields) plus the configs the admin list omits. Used for the JSON export and
+/// as the base an import record is merged over.
+pub fn product_record(product: &Product) -> Map<String, Value> {
+    let mut record = match serde_json::to_value(AdminProductInfo::from(product)) {
+        Ok(Value::Object(map)) => map,
+        _ => Map::new(),
+    };
+    for key in ["stripeProductId", "createdAt", "updatedAt"] {
+        record.remove(key);
+    }
+    insert_some(&mut record, "variationConfig", &product.variation_config);
+    insert_some(&mut record, "giftCardConfig", &product.gift_card_config);
+    insert_some(
+        &mut record,
+        "tokenizedAssetConfig",
+        &product.tokenized_asset_config,
+    );
+    insert_some(
+        &mut record,
+        "complianceRequirements",
+        &product.compliance_requirements,
+    );
+    insert_some(&mut record, "paymentSplit", &product.payment_split);
+    record
+}
+
+fn insert_some<T: Serialize>(record: &mut Map<String, Value>, key: &str, value: &Option<T>) {
+    if let Some(value) = value.as_ref().and_then(|v| serde_json::to_value(v).ok()) {
+        record.insert(key.to_string(), value);
+    }
+}
+
+/// Merges an import record over the existing product.
+///
+/// Top-level fields in the record replace the existing ones. Imported variants
+/// replace the variant list, but each is laid over the existing variant with
+/// the same id (or, without an id, the same SKU or title) so fields the file
+/// does not carry, such as compare-at prices, are kept. Variants left without
+/// an id or title get blank ones for [`fill_variation_ids`] to fill.
+pub fn merge_record(existing: Option<&Product>, record: Map<String, Value>) -> Map<String, Value> {
+    let existing_variants = existing.map(|p| p.variants.as_slice()).unwrap_or_default();
+    let mut merged = existing.map(product_record).unwrap_or_default();
+    for (key, mut value) in record {
+        if key == "variants" {
+            if let Value::Array(variants) = &mut value {
+                for variant in variants.iter_mut() {
+                    merge_variant(existing_variants, variant);
+                }
+            }
+        }
+        merged.insert(key, value);
+    }
+    merged
+}
+
+fn merge_variant(existing: &[ProductVariant], variant: &mut Value) {
+    let Value::Object(imported) = variant else {
+        return;
+    };
+    imported.retain(|key, value| !(matches!(key.as_str(), "id" | "title") && value == ""));
+    let field = |name: &str| imported.get(name).and_then(Value::as_str);
+    let matched = match (field("id"), field("sku"), field("title")) {
+        (Some(id), _, _) => existing.iter().find(|v| v.id == id),
+        (None, Some(sku), _) => existing.iter().find(|v| v.sku.as_deref() == Some(sku)),
+        (None, None, Some(title)) => existing.iter().find(|v| v.title == title),
+        _ => None,
+    };
+    if let Some(Value::Object(mut base)) = matched.and_then(|v| serde_json::to_value(v).ok()) {
+        if imported.contains_key("options") {
+            // Recomputed from the imported options.
+            base.remove("optionValueIds");
+        }
+        base.extend(std::mem::take(imported));
+        *imported = base;
+    }
+    for key in ["id", "title"] {
+        imported
+            .entry(key)
+            .or_insert_with(|| Value::String(String::new()));
+    }
+}
+
+/// Fills blank variation type / value ids and variant ids, option value ids,
+/// options and titles.
+///
+/// Type and value ids are reused from `existing` by name / label, otherwise
+/// derived from the name. Variants with `options` but no `optionValueIds` are
+/// linked to the config's values; new variants get a `var_` id.
+pub fn fill_variation_ids(
+    config: Option<&mut ProductVariationConfig>,
+    existing: Option<&ProductVariationConfig>,
+    variants: &mut [ProductVariant],
+) -> Result<(), String> {
+    let mut empty = ProductVariationConfig::default();
+    let config = config.unwrap_or(&mut empty);
+
+    let mut type_ids = HashSet::new();
+    for vtype in &mut config.variation_types {
+        let existing_type = existing.and_then(|c| {
+            c.variation_types
+                .iter()
+                .find(|t| t.name.eq_ignore_ascii_case(&vtype.name))
+        });
+        if vtype.id.is_empty() {
+            vtype.id = match existing_type {
+                Some(t) => t.id.clone(),
+                None => unique_slug(&vtype.name, &type_ids),
+            };
+        }
+        type_ids.insert(vtype.id.clone());
+
+        let mut value_ids = HashSet::new();
+        for value in &mut vtype.values {
+            if value.id.is_empty() {
+                let existing_value = existing_type.and_then(|t| {
+                    t.values
+                        .iter()
+                        .find(|v| v.label.eq_ignore_ascii_case(&value.label))
+                });
+                value.id = match existing_value {
+                    Some(v) => v.id.clone(),
+                    None => unique_slug(&value.label, &value_ids),
+                };
+            }
+            value_ids.insert(value.id.clone());
+        }
+    }
+
+    let mut variant_ids = HashSet::new();
+    for variant in variants.iter_mut() {
+        if variant.option_value_ids.is_empty() && !config.variation_types.is_empty() {
+            for vtype in &config.variation_types {
+                let Some(option) = variant.options.get_mut(&vtype.name) else {
+                    continue;
+                };
+                let value = vtype
+                    .values
+                    .iter()
+                    .find(|v| v.label.eq_ignore_ascii_case(option))
+                    .ok_or_else(|| format!("unknown {} option '{}'", vtype.name, option))?;
+                option.clone_from(&value.label);
+                variant.option_value_ids.push(value.id.clone());
+            }
+        } else {
+            for value_id in &variant.option_value_ids {
+                let found = config.variation_types.iter().find_map(|t| {
+                    t.values
+                        .iter()
+                        .find(|v| &v.id == value_id)
+                        .map(|v| (t.name.clone(), v.label.clone()))
+                });
+                match found {
+                    Some((name, label)) => {
+                        variant.options.entry(name).or_insert(label);
+                    }
+                    None if config.variation_types.is_empty() => {}
+                    None => return Err(format!("unknown option value id '{value_id}'")),
+                }
+            }
+        }
+
+        if variant.title.is_empty() {
+            variant.title = config
+                .variation_types
+                .iter()
+                .filter_map(|t| variant.options.get(&t.name).map(String::as_str))
+                .collect::<Vec<_>>()
+                .join(" / ");
+        }
+        if variant.title.is_empty() {
+            return Err("variant needs a title or options".to_string());
+        }
+        if variant.id.is_empty() {
+            variant.id = format!(
+                "var_{}",
+                uuid::Uuid::new_v4()
+                    .to_string()
+                    .split('-')
+                    .next()
+                    .unwrap_or("unknown")
+            );
+        }
+        if !variant_ids.insert(variant.id.clone()) {
+            return Err(format!("duplicate variant id '{}'", variant.id));
+        }
+    }
+    Ok(())
+}
+
+/// Lowercase, dash-separated id from a display name, unique within `taken`.
+fn unique_slug(name: &str, taken: &HashSet<String>) -> String {
+    let slug = name
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    let base = if slug.is_empty() {
+        "option".to_string()
+    } else {
+        slug
+    };
+    let mut candidate = base.clone();
+    let mut n = 2;
+    while taken.contains(&candidate) {
+        candidate = format!("{base}-{n}");
+        n += 1;
+    }
+    candidate
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+/// Full-catalog CSV in the import layout: one row per variant (or one row for
+/// a product without variants), product columns on the first row only.
+pub fn products_to_csv(products: &[Product]) -> String {
+    let mut out = CSV_COLUMNS.join(",");
+    out.push('\n');
+    for product in products {
+        let info = AdminProductInfo::from(product);
+        let product_cells: Vec<String> = vec![
+            product.id.clone(),
+            product.slug.clone().unwrap_or_default(),
+            product.title.clone().unwrap_or_default(),
+            product.description.clone(),
+            product.short_description.clone().unwrap_or_default(),
+            product.seo_title.clone().unwrap_or_default(),
+            product.seo_description.clone().unwrap_or_default(),
+            product.tags.join("|"),
+            product.category_ids.join("|"),
+            join_images(&product.images),
+            product.featured.to_string(),
+            opt_string(product.sort_order),
+            product.active.to_string(),
+            product.shipping_profile.clone().unwrap_or_default(),
+            opt_string(info.fiat_amount_cents),
+            info.fiat_currency.unwrap_or_default(),
+            opt_string(info.compare_at_fiat_amount_cents),
+            info.compare_at_fiat_currency.unwrap_or_default(),
+            opt_string(info.crypto_atomic_amount),
+            info.crypto_token.unwrap_or_default(),
+            product.stripe_price_id.clone().unwrap_or_default(),
+            opt_string(product.inventory_quantity),
+            product.inventory_status.clone().unwrap_or_default(),
+            product.inventory_policy.clone().unwrap_or_default(),
+            product
+                .variation_config
+                .as_ref()
+                .map(format_variation_types)
+                .unwrap_or_default(),
+        ];
+
+        if product.variants.is_empty() {
+            let blank = vec![String::new(); CSV_COLUMNS.len() - product_cells.len()];
+            push_csv_row(&mut out, product_cells.iter().chain(&blank));
+            continue;
+        }
+        for (i, variant) in product.variants.iter().enumerate() {
+            let variant_cells = vec![
+                variant.id.clone(),
+                variant.title.clone(),
+                variant.sku.clone().unwrap_or_default(),
+                format_options(variant, product.variation_config.as_ref()),
+                variant
+                    .price
+                    .as_ref()
+                    .and_then(|p| p.amount)
+                    .map(|a| a.to_string())
+                    .unwrap_or_default(),
+                variant
+                    .price
+                    .as_ref()
+                    .and_then(|p| p.currency.clone())
+                    .unwrap_or_default(),
+                opt_string(variant.inventory_quantity),
+                variant.inventory_status.clone().unwrap_or_default(),
+                join_images(&variant.images),
+            ];
+            if i == 0 {
+                push_csv_row(&mut out, product_cells.iter().chain(&variant_cells));
+            } else {
+                // Continuation rows only repeat the id.
+                let mut cells = vec![String::new(); product_cells.len()];
+                cells[0] = product.id.clone();
+                push_csv_row(&mut out, cells.iter().chain(&variant_cells));
+            }
+        }
+    }
+    out
+}
+
+fn push_csv_row<'a>(out: &mut String, cells: impl Iterator<Item = &'a String>) {
+    let row: Vec<String> = cells.map(|c| csv_field(c)).collect();
+    out.push_str(&row.join(","));
+    out.push('\n');
+}
+
+fn opt_string<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn join_images(images: &[crate::models::ProductImage]) -> String {
+    images
+        .iter()
+        .map(|i| i.url.as_str())
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+fn format_variation_types(config: &ProductVariationConfig) -> String {
+    let mut types: Vec<_> = config.variation_types.iter().collect();
+    types.sort_by_key(|t| t.display_order);
+    types
+        .iter()
+        .map(|t| {
+            let values: Vec<&str> = t.values.iter().map(|v| v.label.as_str()).collect();
+            format!("{}={}", t.name, values.join("|"))
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Options in variation type order, then any legacy keys alphabetically.
+fn format_options(variant: &ProductVariant, config: Option<&ProductVariationConfig>) -> String {
+    let mut names: Vec<&str> = config
+        .map(|c| {
+            c.variation_types
+                .iter()
+                .map(|t| t.name.as_str())
+                .filter(|name| variant.options.contains_key(*name))
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut rest: Vec<&str> = variant
+        .options
+        .keys()
+        .map(String::as_str)
+        .filter(|k| !names.contains(k))
+        .collect();
+    rest.sort_unstable();
+    names.extend(rest);
+    names
+        .iter()
+        .map(|name| format!("{}={}", name, variant.options[*name]))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{get_asset, Money, VariationType, VariationValue};
+
+    fn records(format: ProductImportFormat, body: &str) -> Vec<ImportRecord> {
+        parse_import(format, body).expect("parse import")
+    }
+
+    #[test]
+    fn csv_groups_variant_rows_into_one_product() {
+        let body = "id,title,description,tags,fiatAmountCents,fiatCurrency,variationTypes,variantSku,variantOptions,variantInventoryQuantity\n\
+            tee,\"Tee, classic\",Soft,summer|cotton,2500,usd,Size=S|M; Color=Red,TEE-S-R,Size=S; Color=Red,4\n\
+            tee,,,,,,,TEE-M-R,Size=M; Color=Red,0\n\
+            \n\
+            mug,Mug,\"Line one\nline two\",,'-5,usd,,,,\n";
+        let parsed = records(ProductImportFormat::Csv, body);
+        assert_eq!(parsed.len(), 2);
+
+        let tee = parsed[0].record.as_ref().expect("tee record");
+        assert_eq!(parsed[0].row, 1);
+        assert_eq!(tee["title"], "Tee, classic");
+        assert_eq!(tee["tags"], json!(["summer", "cotton"]));
+        assert_eq!(tee["fiatAmountCents"], 2500);
+        assert_eq!(
+            tee["variationConfig"]["variationTypes"][1]["values"][0]["label"],
+            "Red"
+        );
+        let variants = tee["variants"].as_array().expect("variants");
+        assert_eq!(variants.len(), 2);
+        assert_eq!(variants[1]["sku"], "TEE-M-R");
+        assert_eq!(variants[1]["options"], json!({"Size": "M", "Color": "Red"}));
+        assert_eq!(variants[1]["inventoryQuantity"], 0);
+
+        let mug = parsed[1].record.as_ref().expect("mug record");
+        assert_eq!(parsed[1].row, 3);
+        assert_eq!(mug["description"], "Line one\nline two");
+        assert_eq!(mug["fiatAmountCents"], -5);
+        assert!(!mug.contains_key("variants"));
+    }
+
+    #[test]
+    fn csv_reports_bad_rows_and_rejects_bad_files() {
+        let body = "slug,title,sortOrder\nshirt,Shirt,first\n,Nameless,1\nhat,Hat\n";
+        let parsed = records(ProductImportFormat::Csv, body);
+        assert_eq!(parsed.len(), 3);
+        assert!(parsed[0]
+            .record
+            .as_ref()
+            .expect_err("bad number")
+            .contains("sortOrder"));
+        assert_eq!(
+            parsed[1].record.as_ref().expect_err("no key"),
+            "id or slug is required"
+        );
+        assert!(parsed[2].record.is_err());
+
+        assert!(parse_import(ProductImportFormat::Csv, "id,colour\nx,red\n").is_err());
+        assert!(parse_import(ProductImportFormat::Csv, "title\nx\n").is_err());
+        assert!(parse_import(ProductImportFormat::Csv, "id\n\"open\n").is_err());
+        assert!(parse_import(ProductImportFormat::Json, "{\"items\": []}").is_err());
+    }
+
+    #[test]
+    fn json_accepts_array_or_products_wrapper() {
+        let parsed = records(ProductImportFormat::Json, r#"[{"id": "a"}, 3]"#);
+        assert!(parsed[0].record.is_ok());
+        assert!(parsed[1].record.is_err());
+        let parsed = records(ProductImportFormat::Json, r#"{"products": [{"id": "a"}]}"#);
+        assert_eq!(parsed.len(), 1);
+    }
+
+    fn size_config(ids: bool) -> ProductVariationConfig {
+        let id = |s: &str| if ids { s.to_string() } else { String::new() };
+        ProductVariationConfig {
+            variation_types: vec![VariationType {
+                id: id("t_size"),
+                name: "Size".to_string(),
+                display_order: 0,
+                values: ["Small", "Large"]
+                    .iter()
+                    .map(|label| VariationValue {
+                        id: id(&format!("v_{}", label.to_lowercase())),
+                        label: label.to_string(),
+                        parent_value_id: None,
+                    })
+                    .collect(),
+            }],
+        }
+    }
+
+    fn variant(title: &str, options: &[(&str, &str)]) -> ProductVariant {
+        ProductVariant {
+            title: title.to_string(),
+            options: options
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn fill_variation_ids_reuses_existing_ids() {
+        let existing = size_config(true);
+        let mut config = size_config(false);
+        config.variation_types[0].values.push(VariationValue {
+            id: String::new(),
+            label: "Extra Large".to_string(),
+            parent_value_id: None,
+        });
+        let mut variants = vec![
+            variant("", &[("Size", "large")]),
+            variant("", &[("Size", "Extra Large")]),
+        ];
+
+        fill_variation_ids(Some(&mut config), Some(&existing), &mut variants).expect("fill");
+        assert_eq!(config.variation_types[0].id, "t_size");
+        let value_ids: Vec<&str> = config.variation_types[0]
+            .values
+            .iter()
+            .map(|v| v.id.as_str())
+            .collect();
+        assert_eq!(value_ids, ["v_small", "v_large", "extra-large"]);
+        assert_eq!(variants[0].option_value_ids, ["v_large"]);
+        assert_eq!(variants[0].title, "Large");
+        assert!(variants[1].id.starts_with("var_"));
+
+        let mut bad = vec![variant("", &[("Size", "Huge")])];
+        assert!(fill_variation_ids(Some(&mut config), None, &mut bad).is_err());
+    }
+
+    #[test]
+    fn merge_keeps_omitted_fields_and_variant_details() {
+        let mut existing_variant = variant("Small", &[("Size", "Small")]);
+        existing_variant.id = "var_1".to_string();
+        existing_variant.sku = Some("S-1".to_string());
+        existing_variant.compare_at_price = Some(crate::models::VariantPrice {
+            amount: Some(30.0),
+            currency: Some("usd".to_string()),
+        });
+        let existing = Product {
+            id: "p1".to_string(),
+            title: Some("Old".to_string()),
+            description: "Keep me".to_string(),
+            variants: vec![existing_variant],
+            ..Default::default()
+        };
+        let record = json!({"title": "New", "variants": [{"sku": "S-1", "inventoryQuantity": 3}]});
+        let record = record.as_object().cloned().expect("object");
+
+        let merged = merge_record(Some(&existing), record);
+        assert_eq!(merged["title"], "New");
+        assert_eq!(merged["description"], "Keep me");
+        assert_eq!(merged["variants"][0]["id"], "var_1");
+        assert_eq!(merged["variants"][0]["compareAtPrice"]["amount"], 30.0);
+        assert_eq!(merged["variants"][0]["inventoryQuantity"], 3);
+    }
+
+    #[test]
+    fn csv_export_round_trips() {
+        let usd = get_asset("USD").expect("usd asset");
+        let mut config = size_config(true);
+        config.variation_types[0].values[0].label = "=Small".to_string();
+        let mut small = variant("=Small", &[("Size", "=Small")]);
+        small.id = "var_s".to_string();
+        small.option_value_ids = vec!["v_small".to_string()];
+        small.inventory_quantity = Some(2);
+        let mut large = variant("Large", &[("Size", "Large")]);
+        large.id = "var_l".to_string();
+        large.option_value_ids = vec!["v_large".to_string()];
+        let product = Product {
+            id: "p1".to_string(),
+            title: Some("Shirt, \"heavy\"".to_string()),
+            description: "Warm".to_string(),
+            tags: vec!["a".to_string(), "b".to_string()],
+            fiat_price: Some(Money::new(usd, 1999)),
+            variation_config: Some(config),
+            variants: vec![small, large],
+            active: true,
+            ..Default::default()
+        };
+
+        let csv = products_to_csv(&[product]);
+        assert_eq!(csv.lines().count(), 3);
+        let parsed = records(ProductImportFormat::Csv, &csv);
+        assert_eq!(parsed.len(), 1);
+        let record = parsed[0].record.as_ref().expect("record");
+        assert_eq!(record["title"], "Shirt, \"heavy\"");
+        assert_eq!(record["fiatAmountCents"], 1999);
+        assert_eq!(record["fiatCurrency"], "USD");
+        assert_eq!(record["tags"], json!(["a", "b"]));
+        assert_eq!(
+            record["variationConfig"]["variationTypes"][0]["values"][0]["label"],
+            "=Small"
+        );
+        let variants = record["variants"].as_array().expect("variants");
+        assert_eq!(variants.len(), 2);
+        assert_eq!(variants[0]["id"], "var_s");
+        assert_eq!(variants[0]["options"], json!({"Size": "=Small"}));
+        assert_eq!(variants[0]["inventoryQuantity"], 2);
+        assert_eq!(variants[1]["title"], "Large");
+    }
+}
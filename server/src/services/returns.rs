@@ -0,0 +1,823 @@
+//! Return merchandise authorization (RMA) rules.
+//!
+//! Evaluates whether items of an order may be returned under the shop's
+//! [`ShopReturnsConfig`] policy, calculates refund amounts and restocking fees,
+//! restocks received items and issues the refund through the path matching the
+//! original payment (Stripe refund request, x402 refund quote or credits refund).
+//! Products may override the default window with the `return_window_days`
+//! metadata key (`0` = not returnable).
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::config::ShopReturnsConfig;
+use crate::constants::STRIPE_SIGNATURE_PREFIX;
+use crate::models::{
+    get_asset, Fulfillment, Money, Order, Product, RefundQuote, ReturnItem, ReturnRequest,
+    StripeRefundRequest, RETURN_CONDITIONS, RETURN_REASON_CODES,
+};
+use crate::repositories::{ProductRepository, ProductRepositoryError};
+use crate::storage::Store;
+use crate::x402::utils::generate_refund_id;
+
+/// Product metadata key that overrides the default return window (in days).
+pub const RETURN_WINDOW_METADATA_KEY: &str = "return_window_days";
+
+/// Reasons a return request is rejected before it is created.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ReturnIneligibility {
+    #[error("returns are disabled")]
+    Disabled,
+    #[error("order status '{0}' is not eligible for returns")]
+    OrderStatus(String),
+    #[error("product {0} is not part of this order")]
+    ItemNotInOrder(String),
+    #[error("product {0} is not returnable")]
+    NotReturnable(String),
+    #[error("return window for product {0} has expired")]
+    WindowExpired(String),
+    #[error("requested quantity for product {product_id} exceeds returnable quantity {remaining}")]
+    QuantityExceeded { product_id: String, remaining: i32 },
+}
+
+#[derive(Debug, Error)]
+pub enum ReturnPolicyError {
+    #[error(transparent)]
+    Ineligible(#[from] ReturnIneligibility),
+    #[error("storage error: {0}")]
+    Storage(String),
+    #[error("{0}")]
+    Refund(String),
+}
+
+/// Money breakdown of a return, in atomic units of the order asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundBreakdown {
+    pub subtotal: i64,
+    pub restocking_fee: i64,
+    pub refund_amount: i64,
+}
+
+/// Per-item return eligibility for display in the customer account API.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnableItem {
+    pub product_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_id: Option<String>,
+    pub ordered_quantity: i32,
+    pub returnable_quantity: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_deadline: Option<DateTime<Utc>>,
+}
+
+/// The moment the return window starts: the latest delivery, falling back to the
+/// latest shipment and finally the order creation date.
+pub fn return_window_start(order: &Order, fulfillments: &[Fulfillment]) -> DateTime<Utc> {
+    fulfillments
+        .iter()
+        .filter_map(|f| f.delivered_at)
+        .max()
+        .or_else(|| fulfillments.iter().filter_map(|f| f.shipped_at).max())
+        .unwrap_or(order.created_at)
+}
+
+/// Effective return window in days for a product (`None` product = policy default).
+pub fn product_return_window_days(product: Option<&Product>, policy: &ShopReturnsConfig) -> u32 {
+    product
+        .and_then(|p| p.metadata.get(RETURN_WINDOW_METADATA_KEY))
+        .and_then(|v| v.trim().parse::<u32>().ok())
+        .unwrap_or(policy.window_days)
+}
+
+/// Quantities already covered by non-rejected returns, keyed by (product, variant).
+fn already_returned(existing: &[ReturnRequest]) -> HashMap<(String, Option<String>), i32> {
+    let mut totals = HashMap::new();
+    for request in existing.iter().filter(|r| r.status != "rejected") {
+        for item in &request.items {
+            *totals
+                .entry((item.product_id.clone(), item.variant_id.clone()))
+                .or_insert(0) += item.quantity.max(0);
+        }
+    }
+    totals
+}
+
+/// Compute what can still be returned for each order line.
+pub fn returnable_items(
+    policy: &ShopReturnsConfig,
+    order: &Order,
+    fulfillments: &[Fulfillment],
+    existing: &[ReturnRequest],
+    products: &HashMap<String, Product>,
+    now: DateTime<Utc>,
+) -> Vec<ReturnableItem> {
+    let status_ok = policy.enabled
+        && policy
+            .eligible_statuses
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(order.status.trim()));
+    let start = return_window_start(order, fulfillments);
+    let returned = already_returned(existing);
+
+    order
+        .items
+        .iter()
+        .map(|item| {
+            let days = product_return_window_days(products.get(&item.product_id), policy);
+            let deadline = (days > 0).then(|| start + Duration::days(days as i64));
+            let open = status_ok && deadline.map(|d| now <= d).unwrap_or(false);
+            let used = returned
+                .get(&(item.product_id.clone(), item.variant_id.clone()))
+                .copied()
+                .unwrap_or(0);
+            ReturnableItem {
+                product_id: item.product_id.clone(),
+                variant_id: item.variant_id.clone(),
+                ordered_quantity: item.quantity,
+                returnable_quantity: if open {
+                    (item.quantity - used).max(0)
+                } else {
+                    0
+                },
+                return_deadline: deadline,
+            }
+        })
+        .collect()
+}
+
+/// Validate a customer-initiated return against the policy.
+pub fn check_return_eligibility(
+    policy: &ShopReturnsConfig,
+    order: &Order,
+    fulfillments: &[Fulfillment],
+    existing: &[ReturnRequest],
+    products: &HashMap<String, Product>,
+    items: &[ReturnItem],
+    now: DateTime<Utc>,
+) -> Result<(), ReturnIneligibility> {
+    if !policy.enabled {
+        return Err(ReturnIneligibility::Disabled);
+    }
+    if !policy
+        .eligible_statuses
+        .iter()
+        .any(|s| s.eq_ignore_ascii_case(order.status.trim()))
+    {
+        return Err(ReturnIneligibility::OrderStatus(order.status.clone()));
+    }
+
+    let start = return_window_start(order, fulfillments);
+    let mut remaining: HashMap<(String, Option<String>), i32> = HashMap::new();
+    for line in returnable_items(policy, order, fulfillments, existing, products, now) {
+        *remaining
+            .entry((line.product_id, line.variant_id))
+            .or_insert(0) += line.returnable_quantity;
+    }
+
+    for item in items {
+        let key = (item.product_id.clone(), item.variant_id.clone());
+        let Some(available) = remaining.get_mut(&key) else {
+            return Err(ReturnIneligibility::ItemNotInOrder(item.product_id.clone()));
+        };
+        let days = product_return_window_days(products.get(&item.product_id), policy);
+        if days == 0 {
+            return Err(ReturnIneligibility::NotReturnable(item.product_id.clone()));
+        }
+        if now > start + Duration::days(days as i64) {
+            return Err(ReturnIneligibility::WindowExpired(item.product_id.clone()));
+        }
+        if item.quantity > *available {
+            return Err(ReturnIneligibility::QuantityExceeded {
+                product_id: item.product_id.clone(),
+                remaining: *available,
+            });
+        }
+        *available -= item.quantity;
+    }
+    Ok(())
+}
+
+/// Validate the shape of requested return items (non-empty, positive
+/// quantities, known conditions and reason codes).
+pub fn validate_items(items: &[ReturnItem]) -> Result<(), String> {
+    if items.is_empty() {
+        return Err("items must not be empty".to_string());
+    }
+    for item in items {
+        if item.product_id.trim().is_empty() {
+            return Err("item product_id is required".to_string());
+        }
+        if item.quantity <= 0 {
+            return Err("item quantity must be positive".to_string());
+        }
+        if let Some(condition) = item.condition.as_deref() {
+            if !RETURN_CONDITIONS.contains(&condition) {
+                return Err(format!("invalid item condition: {condition}"));
+            }
+        }
+        if let Some(code) = item.reason_code.as_deref() {
+            if !RETURN_REASON_CODES.contains(&code) {
+                return Err(format!("invalid item reason code: {code}"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Load the products referenced by an order. Missing products are skipped and
+/// fall back to the policy default window.
+pub async fn load_order_products(
+    products: &dyn ProductRepository,
+    tenant_id: &str,
+    order: &Order,
+) -> Result<HashMap<String, Product>, ReturnPolicyError> {
+    let mut out = HashMap::new();
+    for item in &order.items {
+        if out.contains_key(&item.product_id) {
+            continue;
+        }
+        match products.get_product(tenant_id, &item.product_id).await {
+            Ok(p) => {
+                out.insert(item.product_id.clone(), p);
+            }
+            Err(ProductRepositoryError::NotFound) => {}
+            Err(e) => return Err(ReturnPolicyError::Storage(e.to_string())),
+        }
+    }
+    Ok(out)
+}
+
+/// Load fulfillments and prior returns for an order and evaluate eligibility.
+pub async fn validate_customer_return(
+    store: &dyn Store,
+    products: &dyn ProductRepository,
+    policy: &ShopReturnsConfig,
+    order: &Order,
+    items: &[ReturnItem],
+    now: DateTime<Utc>,
+) -> Result<(), ReturnPolicyError> {
+    let fulfillments = store
+        .list_fulfillments(&order.tenant_id, &order.id, 100)
+        .await
+        .map_err(|e| ReturnPolicyError::Storage(e.to_string()))?;
+    let existing = store
+        .list_return_requests(&order.tenant_id, None, Some(&order.id), 100, 0)
+        .await
+        .map_err(|e| ReturnPolicyError::Storage(e.to_string()))?;
+    let catalog = load_order_products(products, &order.tenant_id, order).await?;
+    check_return_eligibility(
+        policy,
+        order,
+        &fulfillments,
+        &existing,
+        &catalog,
+        items,
+        now,
+    )?;
+    Ok(())
+}
+
+/// A customer-filed return awaiting admin review (status `requested`).
+pub fn new_customer_return(
+    order: &Order,
+    user_id: &str,
+    items: Vec<ReturnItem>,
+    reason: Option<String>,
+    now: DateTime<Utc>,
+) -> ReturnRequest {
+    let mut metadata = HashMap::new();
+    metadata.insert("user_id".to_string(), user_id.to_string());
+    metadata.insert("requested_by".to_string(), "customer".to_string());
+
+    ReturnRequest {
+        id: uuid::Uuid::new_v4().to_string(),
+        tenant_id: order.tenant_id.clone(),
+        order_id: order.id.clone(),
+        status: "requested".to_string(),
+        items,
+        reason,
+        metadata,
+        created_at: now,
+        updated_at: Some(now),
+        status_updated_at: Some(now),
+        restocking_fee: None,
+        refund_amount: None,
+        refund_method: None,
+        refund_id: None,
+        restocked_at: None,
+    }
+}
+
+/// List price of a product in the order's asset, if known.
+fn list_price(product: Option<&Product>, asset_code: &str) -> Option<i64> {
+    let product = product?;
+    [product.fiat_price.as_ref(), product.crypto_price.as_ref()]
+        .into_iter()
+        .flatten()
+        .find(|m| m.asset.code.eq_ignore_ascii_case(asset_code))
+        .map(|m| m.atomic)
+        .filter(|a| *a > 0)
+}
+
+/// Allocate the amount actually paid for an order across its lines.
+///
+/// Lines are weighted by list price × quantity so discounts are spread
+/// proportionally; when any price is unknown every unit gets an equal share.
+/// Returns `(line_total, ordered_quantity)` keyed by (product, variant).
+pub fn order_line_amounts(
+    order: &Order,
+    products: &HashMap<String, Product>,
+) -> HashMap<(String, Option<String>), (i64, i32)> {
+    let lines: Vec<_> = order.items.iter().filter(|i| i.quantity > 0).collect();
+    let prices: Option<Vec<i64>> = lines
+        .iter()
+        .map(|i| list_price(products.get(&i.product_id), &order.amount_asset))
+        .collect();
+    let weights: Vec<i128> = lines
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| {
+            let unit = prices.as_ref().map(|p| p[idx]).unwrap_or(1);
+            unit as i128 * item.quantity as i128
+        })
+        .collect();
+    let total_weight: i128 = weights.iter().sum();
+
+    let mut out: HashMap<(String, Option<String>), (i64, i32)> = HashMap::new();
+    let mut allocated: i64 = 0;
+    for (idx, item) in lines.iter().enumerate() {
+        let share = if idx + 1 == lines.len() {
+            // Last line absorbs rounding so the shares add up to the order amount.
+            order.amount - allocated
+        } else if total_weight > 0 {
+            (order.amount as i128 * weights[idx] / total_weight) as i64
+        } else {
+            0
+        };
+        allocated += share;
+        let entry = out
+            .entry((item.product_id.clone(), item.variant_id.clone()))
+            .or_insert((0, 0));
+        entry.0 += share;
+        entry.1 += item.quantity;
+    }
+    out
+}
+
+/// Amount already refunded (or being refunded) through other returns of the order.
+pub fn refunded_through_returns(existing: &[ReturnRequest], exclude_id: &str) -> i64 {
+    existing
+        .iter()
+        .filter(|r| r.id != exclude_id && r.refund_id.is_some())
+        .filter_map(|r| r.refund_amount)
+        .sum()
+}
+
+/// Calculate subtotal, restocking fee and refund for the returned items.
+///
+/// `fee_override` replaces the policy-derived restocking fee. The refund is
+/// capped at what is left of the order amount after `already_refunded`.
+pub fn calculate_refund(
+    policy: &ShopReturnsConfig,
+    order: &Order,
+    products: &HashMap<String, Product>,
+    items: &[ReturnItem],
+    already_refunded: i64,
+    fee_override: Option<i64>,
+) -> RefundBreakdown {
+    let lines = order_line_amounts(order, products);
+    let mut subtotal: i64 = 0;
+    let mut fee_base: i64 = 0;
+    for item in items {
+        let Some((line_total, ordered)) =
+            lines.get(&(item.product_id.clone(), item.variant_id.clone()))
+        else {
+            continue;
+        };
+        let quantity = item.quantity.clamp(0, *ordered);
+        let amount = (*line_total as i128 * quantity as i128 / (*ordered).max(1) as i128) as i64;
+        subtotal += amount;
+        if !item.is_merchant_fault() {
+            fee_base += amount;
+        }
+    }
+
+    let policy_fee =
+        (fee_base as i128 * policy.restocking_fee_bps.min(10_000) as i128 / 10_000) as i64;
+    let restocking_fee = fee_override.unwrap_or(policy_fee).clamp(0, subtotal);
+    let remaining = (order.amount - already_refunded).max(0);
+    RefundBreakdown {
+        subtotal,
+        restocking_fee,
+        refund_amount: (subtotal - restocking_fee).min(remaining),
+    }
+}
+
+/// Load the order's products and prior returns, then calculate the refund breakdown.
+pub async fn quote_return_refund(
+    store: &dyn Store,
+    products: &dyn ProductRepository,
+    policy: &ShopReturnsConfig,
+    order: &Order,
+    request: &ReturnRequest,
+    fee_override: Option<i64>,
+) -> Result<RefundBreakdown, ReturnPolicyError> {
+    let existing = store
+        .list_return_requests(&order.tenant_id, None, Some(&order.id), 100, 0)
+        .await
+        .map_err(|e| ReturnPolicyError::Storage(e.to_string()))?;
+    let catalog = load_order_products(products, &order.tenant_id, order).await?;
+    Ok(calculate_refund(
+        policy,
+        order,
+        &catalog,
+        &request.items,
+        refunded_through_returns(&existing, &request.id),
+        fee_override,
+    ))
+}
+
+/// Put resellable items of a received return back into inventory.
+///
+/// Each product and variant is restocked in its own inventory transaction,
+/// recorded under the reason `return:{id}:{product}:{variant}`. Lines that
+/// already have that adjustment are skipped, so retrying after a partial
+/// failure does not restock them twice. Items without tracked inventory (or since deleted) are
+/// skipped too. Returns the number of units restocked.
+pub async fn restock_return_items(
+    store: &dyn Store,
+    request: &ReturnRequest,
+) -> Result<i32, ReturnPolicyError> {
+    // Lines for the same product and variant share one adjustment
+    let mut lines: Vec<(&str, Option<&str>, i32)> = Vec::new();
+    for item in request
+        .items
+        .iter()
+        .filter(|i| i.is_restockable() && i.quantity > 0)
+    {
+        let key = (item.product_id.as_str(), item.variant_id.as_deref());
+        match lines.iter_mut().find(|(p, v, _)| (*p, *v) == key) {
+            Some(line) => line.2 += item.quantity,
+            None => lines.push((key.0, key.1, item.quantity)),
+        }
+    }
+
+    let mut restocked = 0;
+    for (product_id, variant_id, quantity) in lines {
+        let reason = format!(
+            "return:{}:{}:{}",
+            request.id,
+            product_id,
+            variant_id.unwrap_or("-")
+        );
+        if restock_recorded(store, request, product_id, &reason).await? {
+            restocked += quantity;
+            continue;
+        }
+
+        // Batch deltas are subtracted from stock, so a restock is negative
+        let updated = store
+            .update_inventory_batch(
+                &request.tenant_id,
+                vec![(
+                    product_id.to_string(),
+                    variant_id.map(str::to_string),
+                    -quantity,
+                )],
+                Some(&reason),
+                None,
+            )
+            .await
+            .map_err(|e| ReturnPolicyError::Storage(e.to_string()))?;
+        if updated.is_empty() {
+            tracing::debug!(
+                product_id = %product_id,
+                return_id = %request.id,
+                "Skipping restock for product without tracked inventory"
+            );
+            continue;
+        }
+        restocked += quantity;
+    }
+    Ok(restocked)
+}
+
+/// Whether the product has an inventory adjustment recorded under `reason`.
+///
+/// Adjustments are listed newest first, so the scan stops at the first page
+/// reaching back before the return was created.
+async fn restock_recorded(
+    store: &dyn Store,
+    request: &ReturnRequest,
+    product_id: &str,
+    reason: &str,
+) -> Result<bool, ReturnPolicyError> {
+    const PAGE_SIZE: i32 = 100;
+    let mut offset = 0;
+    loop {
+        let page = store
+            .list_inventory_adjustments(&request.tenant_id, product_id, PAGE_SIZE, offset)
+            .await
+            .map_err(|e| ReturnPolicyError::Storage(e.to_string()))?;
+        if page.iter().any(|a| a.reason.as_deref() == Some(reason)) {
+            return Ok(true);
+        }
+        let reached_start = page
+            .last()
+            .map_or(true, |a| a.created_at < request.created_at);
+        if page.len() < PAGE_SIZE as usize || reached_start {
+            return Ok(false);
+        }
+        offset += PAGE_SIZE;
+    }
+}
+
+/// Refund path for an order, derived from how it was paid.
+pub fn refund_method_for_order(order: &Order) -> &'static str {
+    if order.purchase_id.starts_with("credits:") {
+        "credits"
+    } else if order.source == "stripe" || order.purchase_id.starts_with(STRIPE_SIGNATURE_PREFIX) {
+        "stripe"
+    } else {
+        "x402"
+    }
+}
+
+/// Create the refund for a return through the order's payment path.
+///
+/// Stripe orders get a pending [`StripeRefundRequest`] for an admin to process;
+/// x402 and credits orders get a [`RefundQuote`]. Returns the refund id.
+pub async fn issue_return_refund(
+    store: &dyn Store,
+    policy: &ShopReturnsConfig,
+    order: &Order,
+    request: &ReturnRequest,
+    amount: i64,
+    recipient_wallet: Option<&str>,
+    now: DateTime<Utc>,
+) -> Result<String, ReturnPolicyError> {
+    let mut metadata = HashMap::new();
+    metadata.insert("return_id".to_string(), request.id.clone());
+    metadata.insert("order_id".to_string(), order.id.clone());
+    metadata.insert("resource_id".to_string(), order.resource_id.clone());
+    let reason = Some(format!("Return {}", request.id));
+
+    if refund_method_for_order(order) == "stripe" {
+        let signature = if order.purchase_id.starts_with(STRIPE_SIGNATURE_PREFIX) {
+            order.purchase_id.clone()
+        } else {
+            format!("{}{}", STRIPE_SIGNATURE_PREFIX, order.purchase_id)
+        };
+        let payment = store
+            .get_payment(&order.tenant_id, &signature)
+            .await
+            .map_err(|e| ReturnPolicyError::Storage(e.to_string()))?;
+        let payment_intent = payment
+            .as_ref()
+            .and_then(|p| p.metadata.get("stripe_payment_intent_id"))
+            .or_else(|| order.metadata.get("stripe_payment_intent_id"))
+            .filter(|s| !s.is_empty())
+            .cloned()
+            .ok_or_else(|| {
+                ReturnPolicyError::Refund("missing stripe_payment_intent_id for order".into())
+            })?;
+
+        let refund = StripeRefundRequest {
+            id: generate_refund_id(),
+            tenant_id: order.tenant_id.clone(),
+            original_purchase_id: signature,
+            stripe_payment_intent_id: payment_intent,
+            stripe_refund_id: None,
+            stripe_charge_id: None,
+            amount,
+            currency: order.amount_asset.to_lowercase(),
+            status: "pending".to_string(),
+            reason,
+            metadata,
+            created_at: now,
+            processed_by: None,
+            processed_at: None,
+            last_error: None,
+        };
+        let id = refund.id.clone();
+        store
+            .store_stripe_refund_request(refund)
+            .await
+            .map_err(|e| ReturnPolicyError::Storage(e.to_string()))?;
+        return Ok(id);
+    }
+
+    let recipient = recipient_wallet
+        .map(str::to_string)
+        .or_else(|| {
+            if refund_method_for_order(order) == "credits" {
+                order.user_id.clone().or_else(|| order.customer.clone())
+            } else {
+                order.customer.clone()
+            }
+        })
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| ReturnPolicyError::Refund("recipient wallet is required".into()))?;
+    let asset = get_asset(&order.amount_asset).ok_or_else(|| {
+        ReturnPolicyError::Refund(format!("unknown order asset {}", order.amount_asset))
+    })?;
+
+    let quote = RefundQuote {
+        id: generate_refund_id(),
+        tenant_id: order.tenant_id.clone(),
+        original_purchase_id: order.purchase_id.clone(),
+        recipient_wallet: recipient,
+        amount: Money::new(asset, amount),
+        reason,
+        metadata,
+        created_at: now,
+        expires_at: now + Duration::hours(policy.refund_quote_ttl_hours as i64),
+        processed_by: None,
+        processed_at: None,
+        signature: None,
+    };
+    let id = quote.id.clone();
+    store
+        .store_refund_quote(quote)
+        .await
+        .map_err(|e| ReturnPolicyError::Storage(e.to_string()))?;
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(status: &str, created_at: DateTime<Utc>) -> Order {
+        Order {
+            id: "ord-1".to_string(),
+            tenant_id: "default".to_string(),
+            source: "stripe".to_string(),
+            purchase_id: "pi_1".to_string(),
+            resource_id: "prod-1".to_string(),
+            user_id: Some("user-1".to_string()),
+            customer: None,
+            status: status.to_string(),
+            items: vec![crate::models::OrderItem {
+                product_id: "prod-1".to_string(),
+                variant_id: None,
+                quantity: 2,
+                line_total: None,
+            }],
+            amount: 2000,
+            amount_asset: "USD".to_string(),
+            customer_email: None,
+            customer_name: None,
+            receipt_url: None,
+            shipping: None,
+            metadata: HashMap::new(),
+            created_at,
+            updated_at: None,
+            status_updated_at: None,
+        }
+    }
+
+    fn item(quantity: i32) -> ReturnItem {
+        ReturnItem {
+            product_id: "prod-1".to_string(),
+            variant_id: None,
+            quantity,
+            condition: None,
+            reason_code: None,
+        }
+    }
+
+    #[test]
+    fn test_rejects_ineligible_status() {
+        let now = Utc::now();
+        let err = check_return_eligibility(
+            &ShopReturnsConfig::default(),
+            &order("paid", now),
+            &[],
+            &[],
+            &HashMap::new(),
+            &[item(1)],
+            now,
+        )
+        .unwrap_err();
+        assert_eq!(err, ReturnIneligibility::OrderStatus("paid".to_string()));
+    }
+
+    #[test]
+    fn test_window_expires() {
+        let now = Utc::now();
+        let err = check_return_eligibility(
+            &ShopReturnsConfig::default(),
+            &order("delivered", now - Duration::days(45)),
+            &[],
+            &[],
+            &HashMap::new(),
+            &[item(1)],
+            now,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ReturnIneligibility::WindowExpired("prod-1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_prior_returns_reduce_quantity() {
+        let now = Utc::now();
+        let ord = order("delivered", now - Duration::days(2));
+        let prior = ReturnRequest {
+            id: "ret-1".to_string(),
+            tenant_id: "default".to_string(),
+            order_id: ord.id.clone(),
+            status: "requested".to_string(),
+            items: vec![item(1)],
+            reason: None,
+            metadata: HashMap::new(),
+            created_at: now,
+            updated_at: None,
+            status_updated_at: None,
+            restocking_fee: None,
+            refund_amount: None,
+            refund_method: None,
+            refund_id: None,
+            restocked_at: None,
+        };
+        let policy = ShopReturnsConfig::default();
+        assert!(check_return_eligibility(
+            &policy,
+            &ord,
+            &[],
+            std::slice::from_ref(&prior),
+            &HashMap::new(),
+            &[item(1)],
+            now
+        )
+        .is_ok());
+        let err = check_return_eligibility(
+            &policy,
+            &ord,
+            &[],
+            &[prior],
+            &HashMap::new(),
+            &[item(2)],
+            now,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ReturnIneligibility::QuantityExceeded { remaining: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn test_product_window_override() {
+        let policy = ShopReturnsConfig::default();
+        let mut product = crate::models::Product {
+            id: "prod-1".to_string(),
+            ..Default::default()
+        };
+        product
+            .metadata
+            .insert(RETURN_WINDOW_METADATA_KEY.to_string(), "0".to_string());
+        assert_eq!(product_return_window_days(Some(&product), &policy), 0);
+        assert_eq!(product_return_window_days(None, &policy), 30);
+    }
+
+    #[test]
+    fn test_refund_applies_restocking_fee_unless_merchant_fault() {
+        let now = Utc::now();
+        let ord = order("delivered", now);
+        let policy = ShopReturnsConfig {
+            restocking_fee_bps: 1500,
+            ..Default::default()
+        };
+
+        let breakdown = calculate_refund(&policy, &ord, &HashMap::new(), &[item(1)], 0, None);
+        assert_eq!(
+            breakdown,
+            RefundBreakdown {
+                subtotal: 1000,
+                restocking_fee: 150,
+                refund_amount: 850,
+            }
+        );
+
+        let defective = ReturnItem {
+            reason_code: Some("defective".to_string()),
+            ..item(2)
+        };
+        let breakdown = calculate_refund(&policy, &ord, &HashMap::new(), &[defective], 0, None);
+        assert_eq!(breakdown.restocking_fee, 0);
+        assert_eq!(breakdown.refund_amount, 2000);
+
+        // Refund is capped by what other returns already refunded.
+        let breakdown = calculate_refund(&policy, &ord, &HashMap::new(), &[item(2)], 1500, Some(0));
+        assert_eq!(breakdown.refund_amount, 500);
+    }
+}
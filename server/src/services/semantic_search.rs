@@ -0,0 +1,486 @@
+//! Hybrid lexical + vector search over products and FAQs.
+//!
+//! Lexical ranking is keyword scoring over product titles, descriptions and
+//! tags (FAQ questions, answers and keywords). Vector ranking compares an
+//! embedding of the query with the product, variant and FAQ embeddings kept
+//! current by [`crate::workers::EmbeddingWorker`]. The two rankings are merged
+//! with reciprocal rank fusion, so an item that is near the top of either list
+//! ranks well without the raw scores having to be comparable.
+//!
+//! Tenants without a model assigned to the embeddings task get lexical results
+//! only; a failed embedding call or vector query also falls back to lexical.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use sha2::{Digest, Sha256};
+
+use crate::config::PostgresConfigRepository;
+use crate::handlers::admin_ai::AiTask;
+use crate::handlers::admin_ai_assistant::load_task_endpoint;
+use crate::models::{Faq, Product, ProductVariant};
+use crate::repositories::{ProductRepository, ProductRepositoryError};
+use crate::services::ai::{AiEndpoint, AiService};
+use crate::storage::{EmbeddingEntity, Store};
+
+/// Reciprocal rank fusion constant; larger values flatten the advantage of top ranks
+const RRF_K: f64 = 60.0;
+
+/// Vector matches below this cosine similarity are treated as unrelated
+const MIN_SIMILARITY: f32 = 0.2;
+
+/// Vector candidates fetched per requested result (variants collapse into their product)
+const VECTOR_CANDIDATES_PER_RESULT: usize = 4;
+
+/// Ranked results of a hybrid search
+#[derive(Debug)]
+pub struct HybridResults<T> {
+    pub items: Vec<T>,
+    /// Whether a vector ranking took part (an embeddings model is assigned and answered)
+    pub semantic: bool,
+}
+
+/// Product and FAQ search shared by the chat tools, the admin assistant and
+/// the public search endpoint
+pub struct SemanticSearch {
+    store: Arc<dyn Store>,
+    product_repo: Arc<dyn ProductRepository>,
+    embeddings: Option<(Arc<PostgresConfigRepository>, Arc<AiService>)>,
+}
+
+impl SemanticSearch {
+    /// Lexical-only search until [`Self::with_embeddings`] is called
+    pub fn new(store: Arc<dyn Store>, product_repo: Arc<dyn ProductRepository>) -> Self {
+        Self {
+            store,
+            product_repo,
+            embeddings: None,
+        }
+    }
+
+    /// Use vector search for tenants with a model assigned to the embeddings task
+    pub fn with_embeddings(
+        mut self,
+        config_repo: Arc<PostgresConfigRepository>,
+        ai_service: Arc<AiService>,
+    ) -> Self {
+        self.embeddings = Some((config_repo, ai_service));
+        self
+    }
+
+    /// Endpoint of the tenant's embeddings model, if one is assigned
+    pub async fn embeddings_endpoint(&self, tenant_id: &str) -> Option<AiEndpoint> {
+        let (repo, ai_service) = self.embeddings.as_ref()?;
+        load_task_endpoint(repo, ai_service, tenant_id, AiTask::Embeddings)
+            .await
+            .ok()
+    }
+
+    /// Search the tenant's active products; with `semantic` false the query is
+    /// ranked by keyword only and no embedding is requested
+    pub async fn search_products(
+        &self,
+        tenant_id: &str,
+        query: &str,
+        limit: usize,
+        semantic: bool,
+    ) -> Result<HybridResults<Product>, ProductRepositoryError> {
+        let products = self.product_repo.list_products(tenant_id).await?;
+        let ranked = if semantic {
+            self.rank_products(tenant_id, query, &products, limit).await
+        } else {
+            fuse_products(query, &products, None, limit)
+        };
+        Ok(HybridResults {
+            items: ranked.items.into_iter().cloned().collect(),
+            semantic: ranked.semantic,
+        })
+    }
+
+    /// Rank already loaded products; inactive products are skipped
+    pub async fn rank_products<'a>(
+        &self,
+        tenant_id: &str,
+        query: &str,
+        products: &'a [Product],
+        limit: usize,
+    ) -> HybridResults<&'a Product> {
+        let vector = self
+            .vector_ranking(
+                tenant_id,
+                query,
+                &[EmbeddingEntity::Product, EmbeddingEntity::Variant],
+                limit,
+            )
+            .await;
+        fuse_products(query, products, vector, limit)
+    }
+
+    /// Rank already loaded FAQs; inactive entries are skipped
+    pub async fn rank_faqs<'a>(
+        &self,
+        tenant_id: &str,
+        query: &str,
+        faqs: &'a [Faq],
+        limit: usize,
+    ) -> HybridResults<&'a Faq> {
+        let vector = self
+            .vector_ranking(tenant_id, query, &[EmbeddingEntity::Faq], limit)
+            .await;
+        fuse_faqs(query, faqs, vector, limit)
+    }
+
+    /// Source ids (product or FAQ ids) ordered by similarity to the query, or
+    /// None when vector search is unavailable for the tenant
+    async fn vector_ranking(
+        &self,
+        tenant_id: &str,
+        query: &str,
+        entity_types: &[EmbeddingEntity],
+        limit: usize,
+    ) -> Option<Vec<String>> {
+        let (_, ai_service) = self.embeddings.as_ref()?;
+        let endpoint = self.embeddings_endpoint(tenant_id).await?;
+
+        let query_vector = match ai_service.embed(&endpoint, &[query.to_string()]).await {
+            Ok(mut vectors) => vectors.pop()?,
+            Err(e) => {
+                tracing::warn!(error = %e, tenant_id = %tenant_id, "Query embedding failed, using lexical search");
+                return None;
+            }
+        };
+
+        let candidates = (limit * VECTOR_CANDIDATES_PER_RESULT).clamp(1, 200) as i32;
+        let matches = match self
+            .store
+            .search_embeddings(
+                tenant_id,
+                endpoint.model_id(),
+                entity_types,
+                &query_vector,
+                candidates,
+            )
+            .await
+        {
+            Ok(matches) => matches,
+            Err(e) => {
+                tracing::warn!(error = %e, tenant_id = %tenant_id, "Vector search failed, using lexical search");
+                return None;
+            }
+        };
+
+        let mut seen = HashSet::new();
+        Some(
+            matches
+                .into_iter()
+                .filter(|m| m.score >= MIN_SIMILARITY)
+                .filter_map(|m| seen.insert(m.source_id.clone()).then_some(m.source_id))
+                .collect(),
+        )
+    }
+}
+
+/// Keyword-only product ranking, for callers without a search service
+pub fn keyword_rank_products<'a>(
+    query: &str,
+    products: &'a [Product],
+    limit: usize,
+) -> Vec<&'a Product> {
+    fuse_products(query, products, None, limit).items
+}
+
+/// Keyword-only FAQ ranking, for callers without a search service
+pub fn keyword_rank_faqs<'a>(query: &str, faqs: &'a [Faq], limit: usize) -> Vec<&'a Faq> {
+    fuse_faqs(query, faqs, None, limit).items
+}
+
+fn fuse_products<'a>(
+    query: &str,
+    products: &'a [Product],
+    vector: Option<Vec<String>>,
+    limit: usize,
+) -> HybridResults<&'a Product> {
+    let active: Vec<&Product> = products.iter().filter(|p| p.active).collect();
+    let keywords = query_keywords(query);
+    let lexical = lexical_ranking(
+        &active,
+        |p| p.id.as_str(),
+        |p| product_keyword_score(p, &keywords),
+    );
+    fuse(&active, |p| p.id.as_str(), lexical, vector, limit)
+}
+
+fn fuse_faqs<'a>(
+    query: &str,
+    faqs: &'a [Faq],
+    vector: Option<Vec<String>>,
+    limit: usize,
+) -> HybridResults<&'a Faq> {
+    let active: Vec<&Faq> = faqs.iter().filter(|f| f.active).collect();
+    let keywords = query_keywords(query);
+    let lexical = lexical_ranking(
+        &active,
+        |f| f.id.as_str(),
+        |f| faq_keyword_score(f, &keywords),
+    );
+    fuse(&active, |f| f.id.as_str(), lexical, vector, limit)
+}
+
+/// Lowercased whitespace-separated query terms
+fn query_keywords(query: &str) -> Vec<String> {
+    query
+        .to_lowercase()
+        .split_whitespace()
+        .map(str::to_string)
+        .collect()
+}
+
+/// Keyword score of a product: title 10, description 5, tags 3 per matching term
+fn product_keyword_score(product: &Product, keywords: &[String]) -> i32 {
+    let title = product.title.as_deref().unwrap_or("").to_lowercase();
+    let desc = product.description.to_lowercase();
+    let tags = product.tags.join(" ").to_lowercase();
+
+    let mut score = 0i32;
+    for kw in keywords {
+        if title.contains(kw.as_str()) {
+            score += 10;
+        }
+        if desc.contains(kw.as_str()) {
+            score += 5;
+        }
+        if tags.contains(kw.as_str()) {
+            score += 3;
+        }
+    }
+    score
+}
+
+/// Keyword score of an FAQ: exact keyword 15, question 10, answer 5, keyword substring 3
+fn faq_keyword_score(faq: &Faq, keywords: &[String]) -> i32 {
+    let question = faq.question.to_lowercase();
+    let answer = faq.answer.to_lowercase();
+    let faq_keywords = faq.keywords.join(" ").to_lowercase();
+
+    let mut score = 0i32;
+    for kw in keywords {
+        if faq.keywords.iter().any(|k| k.to_lowercase() == *kw) {
+            score += 15;
+        }
+        if question.contains(kw.as_str()) {
+            score += 10;
+        }
+        if answer.contains(kw.as_str()) {
+            score += 5;
+        }
+        if faq_keywords.contains(kw.as_str()) {
+            score += 3;
+        }
+    }
+    score
+}
+
+/// Ids of the items with a positive score, best first
+fn lexical_ranking<'a, T>(
+    items: &[&'a T],
+    id: impl Fn(&'a T) -> &'a str,
+    score: impl Fn(&T) -> i32,
+) -> Vec<String> {
+    let mut scored: Vec<(&'a T, i32)> = items
+        .iter()
+        .map(|item| (*item, score(item)))
+        .filter(|(_, s)| *s > 0)
+        .collect();
+    // Stable sort keeps catalog order among equal scores
+    scored.sort_by_key(|(_, s)| std::cmp::Reverse(*s));
+    scored
+        .into_iter()
+        .map(|(item, _)| id(item).to_string())
+        .collect()
+}
+
+/// Merge the lexical and (optional) vector rankings and resolve ids back to items
+fn fuse<'a, T>(
+    items: &[&'a T],
+    id: impl Fn(&'a T) -> &'a str,
+    lexical: Vec<String>,
+    vector: Option<Vec<String>>,
+    limit: usize,
+) -> HybridResults<&'a T> {
+    let by_id: HashMap<&str, &'a T> = items.iter().map(|item| (id(item), *item)).collect();
+    let semantic = vector.is_some();
+
+    let mut rankings = vec![lexical];
+    if let Some(vector) = vector {
+        // Embeddings of deleted or deactivated items may linger until the
+        // worker catches up
+        rankings.push(
+            vector
+                .into_iter()
+                .filter(|source_id| by_id.contains_key(source_id.as_str()))
+                .collect(),
+        );
+    }
+
+    let items = reciprocal_rank_fusion(&rankings)
+        .iter()
+        .filter_map(|source_id| by_id.get(source_id.as_str()).copied())
+        .take(limit)
+        .collect();
+    HybridResults { items, semantic }
+}
+
+/// Reciprocal rank fusion: each ranking contributes `1 / (k + rank)` per id.
+/// Ties keep the order in which ids were first seen.
+pub fn reciprocal_rank_fusion(rankings: &[Vec<String>]) -> Vec<String> {
+    let mut scores: HashMap<&str, (f64, usize)> = HashMap::new();
+    for ranking in rankings {
+        for (rank, id) in ranking.iter().enumerate() {
+            let first_seen = scores.len();
+            let entry = scores.entry(id.as_str()).or_insert((0.0, first_seen));
+            entry.0 += 1.0 / (RRF_K + rank as f64 + 1.0);
+        }
+    }
+
+    let mut fused: Vec<(&str, f64, usize)> = scores
+        .into_iter()
+        .map(|(id, (score, first_seen))| (id, score, first_seen))
+        .collect();
+    fused.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.2.cmp(&b.2)));
+    fused.into_iter().map(|(id, _, _)| id.to_string()).collect()
+}
+
+// ============================================================================
+// Embedded documents (used by the embedding worker)
+// ============================================================================
+
+/// Text embedded for a product: title, descriptions and tags
+pub fn product_embedding_text(product: &Product) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    if let Some(title) = product.title.as_deref() {
+        parts.push(title);
+    }
+    if let Some(short) = product.short_description.as_deref() {
+        parts.push(short);
+    }
+    if !product.description.is_empty() {
+        parts.push(&product.description);
+    }
+    let tags = product.tags.join(", ");
+    if !tags.is_empty() {
+        parts.push(&tags);
+    }
+    parts.join("\n")
+}
+
+/// Text embedded for a variant: the product title with the variant title,
+/// options and SKU. None for variants with nothing to tell them apart.
+pub fn variant_embedding_text(product: &Product, variant: &ProductVariant) -> Option<String> {
+    if variant.title.is_empty() && variant.options.is_empty() {
+        return None;
+    }
+    let mut options: Vec<String> = variant
+        .options
+        .iter()
+        .map(|(name, value)| format!("{}: {}", name, value))
+        .collect();
+    options.sort();
+
+    let mut parts: Vec<String> = Vec::new();
+    if let Some(title) = product.title.as_deref() {
+        parts.push(title.to_string());
+    }
+    if !variant.title.is_empty() {
+        parts.push(variant.title.clone());
+    }
+    if !options.is_empty() {
+        parts.push(options.join(", "));
+    }
+    if let Some(sku) = variant.sku.as_deref() {
+        parts.push(format!("SKU {}", sku));
+    }
+    Some(parts.join("\n"))
+}
+
+/// Text embedded for an FAQ: question, answer and keywords
+pub fn faq_embedding_text(faq: &Faq) -> String {
+    let mut text = format!("{}\n{}", faq.question, faq.answer);
+    if !faq.keywords.is_empty() {
+        text.push('\n');
+        text.push_str(&faq.keywords.join(", "));
+    }
+    text
+}
+
+/// Hash stored with an embedding; changes whenever the text or the model does
+pub fn embedding_content_hash(model: &str, text: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(model.as_bytes());
+    hasher.update([0u8]);
+    hasher.update(text.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_rrf_rewards_items_ranked_by_both_lists() {
+        let lexical = ids(&["a", "b", "c"]);
+        let vector = ids(&["d", "c", "a"]);
+        let fused = reciprocal_rank_fusion(&[lexical, vector]);
+        assert_eq!(fused[0], "a");
+        assert_eq!(fused[1], "c");
+        assert_eq!(fused.len(), 4);
+    }
+
+    #[test]
+    fn test_rrf_ties_keep_first_seen_order() {
+        let fused = reciprocal_rank_fusion(&[ids(&["x"]), ids(&["y"])]);
+        assert_eq!(fused, ids(&["x", "y"]));
+    }
+
+    #[test]
+    fn test_fuse_drops_unknown_vector_ids() {
+        let items = ["p1", "p2"];
+        let refs: Vec<&&str> = items.iter().collect();
+        let result = fuse(&refs, |s| *s, vec![], Some(ids(&["deleted", "p2"])), 10);
+        assert!(result.semantic);
+        assert_eq!(result.items, vec![&"p2"]);
+    }
+
+    #[test]
+    fn test_keyword_ranking_skips_inactive_products() {
+        let product = |id: &str, title: &str, active: bool| Product {
+            id: id.to_string(),
+            title: Some(title.to_string()),
+            active,
+            ..Default::default()
+        };
+        let products = vec![
+            product("p1", "Blue mug", true),
+            product("p2", "Blue mug deluxe", false),
+            product("p3", "Red plate", true),
+        ];
+        let ranked = keyword_rank_products("blue mug", &products, 5);
+        let ids: Vec<&str> = ranked.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["p1"]);
+    }
+
+    #[test]
+    fn test_content_hash_depends_on_model() {
+        assert_ne!(
+            embedding_content_hash("m1", "text"),
+            embedding_content_hash("m2", "text")
+        );
+        assert_eq!(
+            embedding_content_hash("m1", "text"),
+            embedding_content_hash("m1", "text")
+        );
+    }
+}
@@ -60,6 +60,9 @@ pub struct CreateCartSessionRequest {
     pub cancel_url: Option<String>,
     pub coupon_code: Option<String>,
     pub stripe_coupon_id: Option<String>,
+    /// Expected cart total in cents, used to size Stripe Connect application fees
+    #[serde(default)]
+    pub expected_total_cents: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -183,6 +186,32 @@ pub(super) struct StripeRefundObject {
     pub metadata: HashMap<String, String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub(super) struct StripeAccountObject {
+    pub id: String,
+    #[serde(default)]
+    pub charges_enabled: bool,
+    #[serde(default)]
+    pub payouts_enabled: bool,
+    #[serde(default)]
+    pub details_submitted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct StripeAccountLinkObject {
+    pub url: String,
+    pub expires_at: i64,
+}
+
+/// Hosted Stripe Connect onboarding link for a connected account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectOnboardingLink {
+    pub account_id: String,
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Deserialize)]
 pub(super) struct StripeCheckoutSession {
     pub id: String,
@@ -156,6 +156,12 @@ impl StripeClient {
             form.push(("discounts[0][promotion_code]".into(), promo_code.clone()));
         }
 
+        // Stripe Connect: route funds to the tenant's connected account
+        form.extend(
+            self.connect_payment_params(&metadata, Some(req.amount_cents))
+                .await?,
+        );
+
         // Call Stripe API
         let response = self.stripe_post("checkout/sessions", &form).await?;
 
@@ -144,6 +144,11 @@ impl StripeClient {
             form.push(("discounts[0][promotion_code]".into(), promo.clone()));
         }
 
+        form.extend(
+            self.connect_payment_params(&metadata, req.expected_total_cents)
+                .await?,
+        );
+
         let response = self.stripe_post("checkout/sessions", &form).await?;
         let session: StripeCheckoutSession =
             serde_json::from_value(response).map_err(|e| ServiceError::Coded {
@@ -229,6 +234,8 @@ impl StripeClient {
             form.push((format!("subscription_data[metadata][{}]", k), v.clone()));
         }
 
+        form.extend(self.connect_subscription_params(&metadata).await?);
+
         let response = self
             .stripe_post_with_idempotency(
                 "checkout/sessions",
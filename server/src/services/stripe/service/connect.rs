@@ -0,0 +1,289 @@
+//! Stripe Connect support for multi-vendor marketplaces
+//!
+//! Manages per-tenant Express connected accounts and decorates checkout sessions
+//! so funds settle on the tenant's account (destination charges, optionally
+//! `on_behalf_of`) while the platform keeps an application fee.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use tracing::info;
+
+use crate::errors::ErrorCode;
+use crate::models::StripeConnectAccount;
+use crate::services::{ServiceError, ServiceResult};
+
+use super::super::models::{ConnectOnboardingLink, StripeAccountLinkObject, StripeAccountObject};
+use super::{timestamp_to_datetime, StripeClient};
+
+const CHARGE_TYPE_ON_BEHALF_OF: &str = "on_behalf_of";
+
+impl StripeClient {
+    /// Check if Stripe Connect routing is enabled
+    pub fn connect_enabled(&self) -> bool {
+        self.is_enabled() && self.config.stripe.connect.enabled
+    }
+
+    fn require_connect(&self) -> ServiceResult<()> {
+        if !self.connect_enabled() {
+            return Err(ServiceError::Coded {
+                code: ErrorCode::ConfigError,
+                message: "Stripe Connect is not enabled".into(),
+            });
+        }
+        Ok(())
+    }
+
+    async fn load_connect_account(
+        &self,
+        tenant_id: &str,
+    ) -> ServiceResult<Option<StripeConnectAccount>> {
+        self.store
+            .get_stripe_connect_account(tenant_id)
+            .await
+            .map_err(|e| ServiceError::Coded {
+                code: ErrorCode::DatabaseError,
+                message: format!("failed to load connected account: {}", e),
+            })
+    }
+
+    async fn save_connect_account(&self, account: &StripeConnectAccount) -> ServiceResult<()> {
+        self.store
+            .upsert_stripe_connect_account(account.clone())
+            .await
+            .map_err(|e| ServiceError::Coded {
+                code: ErrorCode::DatabaseError,
+                message: format!("failed to store connected account: {}", e),
+            })
+    }
+
+    /// Return the tenant's connected account, creating an Express account if missing.
+    pub async fn get_or_create_connect_account(
+        &self,
+        tenant_id: &str,
+        email: Option<&str>,
+    ) -> ServiceResult<StripeConnectAccount> {
+        self.require_connect()?;
+
+        if let Some(existing) = self.load_connect_account(tenant_id).await? {
+            return Ok(existing);
+        }
+
+        // https://docs.stripe.com/api/accounts/create
+        let mut form: Vec<(String, String)> = vec![
+            ("type".into(), "express".to_string()),
+            (
+                "capabilities[card_payments][requested]".into(),
+                "true".to_string(),
+            ),
+            (
+                "capabilities[transfers][requested]".into(),
+                "true".to_string(),
+            ),
+            ("metadata[tenant_id]".into(), tenant_id.to_string()),
+        ];
+        if let Some(email) = email.filter(|e| !e.is_empty()) {
+            form.push(("email".into(), email.to_string()));
+        }
+
+        // One connected account per tenant, even if the admin double-submits.
+        let idempotency_key = format!("connect_account:{}", tenant_id);
+        let response = self
+            .stripe_post_with_idempotency("accounts", &form, Some(&idempotency_key))
+            .await?;
+        let parsed: StripeAccountObject =
+            serde_json::from_value(response).map_err(|e| ServiceError::Coded {
+                code: ErrorCode::StripeError,
+                message: format!("failed to parse response: {}", e),
+            })?;
+
+        let now = Utc::now();
+        let account = StripeConnectAccount {
+            tenant_id: tenant_id.to_string(),
+            account_id: parsed.id,
+            charges_enabled: parsed.charges_enabled,
+            payouts_enabled: parsed.payouts_enabled,
+            details_submitted: parsed.details_submitted,
+            application_fee_bps: None,
+            created_at: now,
+            updated_at: now,
+        };
+        self.save_connect_account(&account).await?;
+
+        info!(tenant_id = %tenant_id, account_id = %account.account_id, "Created Stripe connected account");
+        Ok(account)
+    }
+
+    /// Create a hosted onboarding link for the tenant's connected account.
+    pub async fn create_connect_onboarding_link(
+        &self,
+        tenant_id: &str,
+        email: Option<&str>,
+        refresh_url: Option<String>,
+        return_url: Option<String>,
+    ) -> ServiceResult<ConnectOnboardingLink> {
+        let account = self.get_or_create_connect_account(tenant_id, email).await?;
+
+        let refresh_url = refresh_url
+            .or_else(|| Some(self.config.stripe.connect.refresh_url.clone()))
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| ServiceError::Coded {
+                code: ErrorCode::ConfigError,
+                message: "refresh_url is required".into(),
+            })?;
+        let return_url = return_url
+            .or_else(|| Some(self.config.stripe.connect.return_url.clone()))
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| ServiceError::Coded {
+                code: ErrorCode::ConfigError,
+                message: "return_url is required".into(),
+            })?;
+
+        // https://docs.stripe.com/api/account_links/create
+        let form: Vec<(String, String)> = vec![
+            ("account".into(), account.account_id.clone()),
+            ("type".into(), "account_onboarding".to_string()),
+            ("refresh_url".into(), refresh_url),
+            ("return_url".into(), return_url),
+        ];
+        let response = self.stripe_post("account_links", &form).await?;
+        let link: StripeAccountLinkObject =
+            serde_json::from_value(response).map_err(|e| ServiceError::Coded {
+                code: ErrorCode::StripeError,
+                message: format!("failed to parse response: {}", e),
+            })?;
+
+        Ok(ConnectOnboardingLink {
+            account_id: account.account_id,
+            url: link.url,
+            expires_at: timestamp_to_datetime(link.expires_at),
+        })
+    }
+
+    /// Refresh the tenant's connected account capabilities from Stripe.
+    pub async fn sync_connect_account(
+        &self,
+        tenant_id: &str,
+    ) -> ServiceResult<Option<StripeConnectAccount>> {
+        self.require_connect()?;
+
+        let Some(mut account) = self.load_connect_account(tenant_id).await? else {
+            return Ok(None);
+        };
+
+        let response = self
+            .stripe_get(&format!("accounts/{}", account.account_id))
+            .await?;
+        let parsed: StripeAccountObject =
+            serde_json::from_value(response).map_err(|e| ServiceError::Coded {
+                code: ErrorCode::StripeError,
+                message: format!("failed to parse response: {}", e),
+            })?;
+
+        account.charges_enabled = parsed.charges_enabled;
+        account.payouts_enabled = parsed.payouts_enabled;
+        account.details_submitted = parsed.details_submitted;
+        account.updated_at = Utc::now();
+        self.save_connect_account(&account).await?;
+
+        Ok(Some(account))
+    }
+
+    /// Resolve the connected account that should receive funds for a session,
+    /// keyed by the `tenant_id` session metadata. Tenants without an account
+    /// (or whose account cannot accept charges yet) are charged on the platform.
+    async fn routable_connect_account(
+        &self,
+        metadata: &HashMap<String, String>,
+    ) -> ServiceResult<Option<(StripeConnectAccount, u32)>> {
+        if !self.connect_enabled() {
+            return Ok(None);
+        }
+        let Some(tenant_id) = metadata.get("tenant_id").filter(|t| !t.is_empty()) else {
+            return Ok(None);
+        };
+        let Some(account) = self.load_connect_account(tenant_id).await? else {
+            return Ok(None);
+        };
+        if !account.can_accept_charges() {
+            return Ok(None);
+        }
+        let fee_bps = account
+            .application_fee_bps
+            .unwrap_or(self.config.stripe.connect.application_fee_bps);
+        Ok(Some((account, fee_bps)))
+    }
+
+    /// Checkout form parameters for a one-time payment session.
+    ///
+    /// The application fee is only set when the charged amount is known.
+    pub(super) async fn connect_payment_params(
+        &self,
+        metadata: &HashMap<String, String>,
+        amount_cents: Option<i64>,
+    ) -> ServiceResult<Vec<(String, String)>> {
+        let Some((account, fee_bps)) = self.routable_connect_account(metadata).await? else {
+            return Ok(Vec::new());
+        };
+
+        let mut form: Vec<(String, String)> = vec![(
+            "payment_intent_data[transfer_data][destination]".into(),
+            account.account_id.clone(),
+        )];
+        if self.config.stripe.connect.charge_type == CHARGE_TYPE_ON_BEHALF_OF {
+            form.push((
+                "payment_intent_data[on_behalf_of]".into(),
+                account.account_id.clone(),
+            ));
+        }
+        if let Some(amount) = amount_cents.filter(|a| *a > 0) {
+            let fee = application_fee_amount(amount, fee_bps);
+            if fee > 0 {
+                form.push((
+                    "payment_intent_data[application_fee_amount]".into(),
+                    fee.to_string(),
+                ));
+            }
+        }
+        Ok(form)
+    }
+
+    /// Checkout form parameters for a subscription session.
+    pub(super) async fn connect_subscription_params(
+        &self,
+        metadata: &HashMap<String, String>,
+    ) -> ServiceResult<Vec<(String, String)>> {
+        let Some((account, fee_bps)) = self.routable_connect_account(metadata).await? else {
+            return Ok(Vec::new());
+        };
+
+        let mut form: Vec<(String, String)> = vec![(
+            "subscription_data[transfer_data][destination]".into(),
+            account.account_id.clone(),
+        )];
+        if self.config.stripe.connect.charge_type == CHARGE_TYPE_ON_BEHALF_OF {
+            form.push((
+                "subscription_data[on_behalf_of]".into(),
+                account.account_id.clone(),
+            ));
+        }
+        if fee_bps > 0 {
+            form.push((
+                "subscription_data[application_fee_percent]".into(),
+                application_fee_percent(fee_bps),
+            ));
+        }
+        Ok(form)
+    }
+}
+
+/// Platform fee in cents for an amount at `bps` basis points (rounded down).
+pub(super) fn application_fee_amount(amount_cents: i64, bps: u32) -> i64 {
+    amount_cents.saturating_mul(i64::from(bps.min(10_000))) / 10_000
+}
+
+/// Stripe's `application_fee_percent` for `bps` basis points (e.g. 250 -> "2.50").
+pub(super) fn application_fee_percent(bps: u32) -> String {
+    let bps = bps.min(10_000);
+    format!("{}.{:02}", bps / 100, bps % 100)
+}
@@ -4,6 +4,7 @@
 
 mod checkout;
 mod checkout_multi;
+mod connect;
 mod coupons;
 mod products;
 mod refunds;
@@ -18,8 +19,10 @@ use std::sync::Arc;
 
 use chrono::{DateTime, LocalResult, TimeZone, Utc};
 use tracing::warn;
+use zeroize::Zeroizing;
 
-use crate::config::Config;
+use crate::config::secrets::{SECRET_STRIPE_SECRET_KEY, SECRET_STRIPE_WEBHOOK_SECRET};
+use crate::config::{Config, SecretStore};
 use crate::constants::STRIPE_API_TIMEOUT;
 use crate::middleware::circuit_breaker::{
     new_circuit_breaker, CircuitBreakerConfig, CircuitBreakerError, SharedCircuitBreaker,
@@ -68,6 +71,8 @@ pub struct StripeClient {
     pub(super) cedros_login: Option<Arc<CedrosLoginClient>>,
     pub(super) http_client: reqwest::Client,
     pub(super) circuit_breaker: SharedCircuitBreaker,
+    /// Secret provider whose Stripe keys take precedence over `config.stripe`
+    pub(super) secrets: Option<Arc<SecretStore>>,
 }
 
 impl StripeClient {
@@ -93,6 +98,7 @@ impl StripeClient {
             cedros_login,
             http_client: Self::build_http_client()?,
             circuit_breaker: new_circuit_breaker(CircuitBreakerConfig::stripe_api()),
+            secrets: None,
         })
     }
 
@@ -102,6 +108,12 @@ impl StripeClient {
         self
     }
 
+    /// Read the API and webhook secrets from a hot-reloaded secret provider
+    pub fn with_secrets(mut self, secrets: Arc<SecretStore>) -> Self {
+        self.secrets = Some(secrets);
+        self
+    }
+
     /// Create with custom circuit breaker config
     pub fn with_circuit_breaker(
         config: Config,
@@ -118,6 +130,7 @@ impl StripeClient {
             cedros_login,
             http_client: Self::build_http_client()?,
             circuit_breaker: new_circuit_breaker(cb_config),
+            secrets: None,
         })
     }
 
@@ -144,9 +157,36 @@ impl StripeClient {
         }
     }
 
+    /// Apply reloaded `circuit_breaker.stripe_api` thresholds.
+    pub fn reconfigure_circuit_breaker(&self, cb_config: CircuitBreakerConfig) {
+        self.circuit_breaker.reconfigure(cb_config);
+    }
+
     /// Check if Stripe is enabled
     pub fn is_enabled(&self) -> bool {
-        !self.config.stripe.secret_key.is_empty()
+        !self.secret_key().is_empty()
+    }
+
+    /// Current API secret key — the provider value when present, else config.
+    pub(super) fn secret_key(&self) -> Zeroizing<String> {
+        self.current_secret(SECRET_STRIPE_SECRET_KEY, &self.config.stripe.secret_key)
+    }
+
+    /// Current webhook signing secret — the provider value when present, else config.
+    pub(super) fn webhook_secret(&self) -> Zeroizing<String> {
+        self.current_secret(
+            SECRET_STRIPE_WEBHOOK_SECRET,
+            &self.config.stripe.webhook_secret,
+        )
+    }
+
+    fn current_secret(&self, name: &str, fallback: &str) -> Zeroizing<String> {
+        let value = self
+            .secrets
+            .as_ref()
+            .and_then(|secrets| secrets.get(name))
+            .unwrap_or_else(|| fallback.to_string());
+        Zeroizing::new(value)
     }
 }
 
@@ -155,6 +195,15 @@ impl StripeClient {
 // ============================================================================
 
 impl StripeClient {
+    /// Resolve a `v1` endpoint against the configured API base URL.
+    pub(super) fn api_url(&self, endpoint: &str) -> String {
+        format!(
+            "{}/v1/{}",
+            self.config.stripe.api_base_url.trim_end_matches('/'),
+            endpoint
+        )
+    }
+
     pub(super) async fn stripe_post(
         &self,
         endpoint: &str,
@@ -164,6 +213,15 @@ impl StripeClient {
             .await
     }
 
+    #[tracing::instrument(
+        name = "stripe.request",
+        skip_all,
+        fields(
+            otel.kind = "client",
+            http.request.method = "POST",
+            stripe.operation = endpoint.split('/').next().unwrap_or(endpoint)
+        )
+    )]
     pub(super) async fn stripe_post_with_idempotency(
         &self,
         endpoint: &str,
@@ -171,7 +229,7 @@ impl StripeClient {
         idempotency_key: Option<&str>,
     ) -> ServiceResult<serde_json::Value> {
         use crate::errors::ErrorCode;
-        let url = format!("https://api.stripe.com/v1/{}", endpoint);
+        let url = self.api_url(endpoint);
 
         let start = std::time::Instant::now();
         let operation = endpoint.split('/').next().unwrap_or(endpoint);
@@ -182,7 +240,7 @@ impl StripeClient {
                 let mut req = self
                     .http_client
                     .post(&url)
-                    .basic_auth(&self.config.stripe.secret_key, None::<&str>)
+                    .basic_auth(self.secret_key().as_str(), None::<&str>)
                     .form(form);
 
                 if let Some(key) = idempotency_key {
@@ -229,9 +287,18 @@ impl StripeClient {
         }
     }
 
+    #[tracing::instrument(
+        name = "stripe.request",
+        skip_all,
+        fields(
+            otel.kind = "client",
+            http.request.method = "GET",
+            stripe.operation = endpoint.split('/').next().unwrap_or(endpoint)
+        )
+    )]
     pub(super) async fn stripe_get(&self, endpoint: &str) -> ServiceResult<serde_json::Value> {
         use crate::errors::ErrorCode;
-        let url = format!("https://api.stripe.com/v1/{}", endpoint);
+        let url = self.api_url(endpoint);
         let start = std::time::Instant::now();
         let operation = endpoint.split('/').next().unwrap_or(endpoint);
 
@@ -240,7 +307,7 @@ impl StripeClient {
             .execute(async {
                 self.http_client
                     .get(&url)
-                    .basic_auth(&self.config.stripe.secret_key, None::<&str>)
+                    .basic_auth(self.secret_key().as_str(), None::<&str>)
                     .send()
                     .await
                     .map_err(|e| ServiceError::Coded {
@@ -282,13 +349,22 @@ impl StripeClient {
         }
     }
 
+    #[tracing::instrument(
+        name = "stripe.request",
+        skip_all,
+        fields(
+            otel.kind = "client",
+            http.request.method = "GET",
+            stripe.operation = endpoint.split('/').next().unwrap_or(endpoint)
+        )
+    )]
     pub(super) async fn stripe_get_with_params(
         &self,
         endpoint: &str,
         params: &[(&str, String)],
     ) -> ServiceResult<serde_json::Value> {
         use crate::errors::ErrorCode;
-        let url = format!("https://api.stripe.com/v1/{}", endpoint);
+        let url = self.api_url(endpoint);
         let params_owned: Vec<(String, String)> = params
             .iter()
             .map(|(k, v)| (k.to_string(), v.clone()))
@@ -301,7 +377,7 @@ impl StripeClient {
             .execute(async {
                 self.http_client
                     .get(&url)
-                    .basic_auth(&self.config.stripe.secret_key, None::<&str>)
+                    .basic_auth(self.secret_key().as_str(), None::<&str>)
                     .query(&params_owned)
                     .send()
                     .await
@@ -344,9 +420,18 @@ impl StripeClient {
         }
     }
 
+    #[tracing::instrument(
+        name = "stripe.request",
+        skip_all,
+        fields(
+            otel.kind = "client",
+            http.request.method = "DELETE",
+            stripe.operation = endpoint.split('/').next().unwrap_or(endpoint)
+        )
+    )]
     pub(super) async fn stripe_delete(&self, endpoint: &str) -> ServiceResult<serde_json::Value> {
         use crate::errors::ErrorCode;
-        let url = format!("https://api.stripe.com/v1/{}", endpoint);
+        let url = self.api_url(endpoint);
         let start = std::time::Instant::now();
         let operation = endpoint.split('/').next().unwrap_or(endpoint);
 
@@ -355,7 +440,7 @@ impl StripeClient {
             .execute(async {
                 self.http_client
                     .delete(&url)
-                    .basic_auth(&self.config.stripe.secret_key, None::<&str>)
+                    .basic_auth(self.secret_key().as_str(), None::<&str>)
                     .send()
                     .await
                     .map_err(|e| ServiceError::Coded {
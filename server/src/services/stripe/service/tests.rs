@@ -98,6 +98,14 @@ impl Notifier for TestNotifier {
         _currency: &str,
     ) {
     }
+    async fn chat_handoff_requested(
+        &self,
+        _tenant_id: &str,
+        _session_id: &str,
+        _reason: Option<&str>,
+        _requested_by: &str,
+    ) {
+    }
 }
 
 #[tokio::test]
@@ -127,3 +135,175 @@ async fn test_handle_completion_uses_tenant_id() {
     assert_eq!(events.len(), 1);
     assert_eq!(events[0].tenant_id, "tenant-a");
 }
+
+#[test]
+fn test_connect_application_fee_math() {
+    use super::connect::{application_fee_amount, application_fee_percent};
+
+    assert_eq!(application_fee_amount(10_000, 250), 250);
+    assert_eq!(application_fee_amount(999, 250), 24);
+    assert_eq!(application_fee_amount(10_000, 0), 0);
+    assert_eq!(application_fee_percent(250), "2.50");
+    assert_eq!(application_fee_percent(5), "0.05");
+    assert_eq!(application_fee_percent(10_000), "100.00");
+}
+
+/// Minimal local Stripe mock: records checkout session forms and serves
+/// Connect account/account link objects.
+async fn spawn_stripe_mock() -> (String, Arc<Mutex<Vec<Vec<(String, String)>>>>) {
+    use axum::{routing::post, Form, Json, Router};
+
+    let sessions: Arc<Mutex<Vec<Vec<(String, String)>>>> = Arc::new(Mutex::new(Vec::new()));
+    let recorded = sessions.clone();
+
+    let app = Router::new()
+        .route(
+            "/v1/accounts",
+            post(|| async {
+                Json(serde_json::json!({
+                    "id": "acct_mock",
+                    "charges_enabled": false,
+                    "payouts_enabled": false,
+                    "details_submitted": false
+                }))
+            }),
+        )
+        .route(
+            "/v1/account_links",
+            post(|| async {
+                Json(serde_json::json!({
+                    "url": "https://connect.stripe.test/setup/acct_mock",
+                    "expires_at": 1_900_000_000
+                }))
+            }),
+        )
+        .route(
+            "/v1/checkout/sessions",
+            post(move |Form(form): Form<Vec<(String, String)>>| {
+                let recorded = recorded.clone();
+                async move {
+                    recorded.lock().push(form);
+                    Json(serde_json::json!({
+                        "id": "cs_mock",
+                        "url": "https://checkout.stripe.test/cs_mock",
+                        "metadata": {}
+                    }))
+                }
+            }),
+        );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let _ = axum::serve(listener, app).await;
+    });
+
+    (format!("http://{}", addr), sessions)
+}
+
+fn form_value<'a>(form: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    form.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+}
+
+#[tokio::test]
+async fn test_connect_onboarding_and_destination_charges_against_mock() {
+    use crate::services::stripe::{
+        CartLineItem, CreateCartSessionRequest, CreateSubscriptionRequest,
+    };
+    use crate::storage::Store;
+
+    let (base_url, sessions) = spawn_stripe_mock().await;
+
+    let mut cfg = Config::default();
+    cfg.stripe.secret_key = "sk_test_mock".to_string();
+    cfg.stripe.api_base_url = base_url;
+    cfg.stripe.success_url = "https://shop.test/success".to_string();
+    cfg.stripe.cancel_url = "https://shop.test/cancel".to_string();
+    cfg.stripe.connect.enabled = true;
+    cfg.stripe.connect.charge_type = "on_behalf_of".to_string();
+    cfg.stripe.connect.application_fee_bps = 250;
+    cfg.stripe.connect.refresh_url = "https://admin.test/connect/refresh".to_string();
+    cfg.stripe.connect.return_url = "https://admin.test/connect/done".to_string();
+
+    let store = Arc::new(InMemoryStore::new());
+    let client = StripeClient::new(cfg, store.clone(), Arc::new(TestNotifier::default())).unwrap();
+
+    let link = client
+        .create_connect_onboarding_link("tenant-a", Some("vendor@example.com"), None, None)
+        .await
+        .unwrap();
+    assert_eq!(link.account_id, "acct_mock");
+    assert!(link.url.contains("acct_mock"));
+
+    let mut metadata = HashMap::new();
+    metadata.insert("tenant_id".to_string(), "tenant-a".to_string());
+    let cart = CreateCartSessionRequest {
+        items: vec![CartLineItem {
+            price_id: "price_1".to_string(),
+            resource: "prod-1".to_string(),
+            quantity: 2,
+            ..Default::default()
+        }],
+        metadata: metadata.clone(),
+        expected_total_cents: Some(10_000),
+        ..Default::default()
+    };
+
+    // Not onboarded yet: charge stays on the platform account.
+    client
+        .create_cart_checkout_session(cart.clone())
+        .await
+        .unwrap();
+    {
+        let recorded = sessions.lock();
+        let form = recorded.last().unwrap();
+        assert!(form_value(form, "payment_intent_data[transfer_data][destination]").is_none());
+    }
+
+    // account.updated flips charges_enabled.
+    let mut account = store
+        .get_stripe_connect_account("tenant-a")
+        .await
+        .unwrap()
+        .unwrap();
+    account.charges_enabled = true;
+    store.upsert_stripe_connect_account(account).await.unwrap();
+
+    client.create_cart_checkout_session(cart).await.unwrap();
+    {
+        let recorded = sessions.lock();
+        let form = recorded.last().unwrap();
+        assert_eq!(
+            form_value(form, "payment_intent_data[transfer_data][destination]"),
+            Some("acct_mock")
+        );
+        assert_eq!(
+            form_value(form, "payment_intent_data[on_behalf_of]"),
+            Some("acct_mock")
+        );
+        assert_eq!(
+            form_value(form, "payment_intent_data[application_fee_amount]"),
+            Some("250")
+        );
+    }
+
+    client
+        .create_subscription_checkout(CreateSubscriptionRequest {
+            product_id: "plan-1".to_string(),
+            price_id: "price_sub".to_string(),
+            metadata,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let recorded = sessions.lock();
+    let form = recorded.last().unwrap();
+    assert_eq!(
+        form_value(form, "subscription_data[transfer_data][destination]"),
+        Some("acct_mock")
+    );
+    assert_eq!(
+        form_value(form, "subscription_data[application_fee_percent]"),
+        Some("2.50")
+    );
+}
@@ -170,6 +170,7 @@ impl StripeClient {
                 message: "payment recording failed".into(),
             });
         }
+        crate::services::ledger::post_payment(&*self.store, &payment).await;
 
         // Skip webhook/callback for duplicate payments (already fired on first recording)
         if is_duplicate {
@@ -194,6 +194,7 @@ impl StripeClient {
                 message: "payment recording failed".into(),
             });
         }
+        crate::services::ledger::post_payment(&*self.store, &payment).await;
 
         // Per spec (21-stripe-client.md): Increment coupon usage if coupon_code in metadata
         // Uses atomic increment to prevent race conditions exceeding usage limit
@@ -292,7 +293,7 @@ impl StripeClient {
         crate::services::stripe::verify_stripe_webhook_signature(
             payload,
             signature_header,
-            &self.config.stripe.webhook_secret,
+            &self.webhook_secret(),
         )
     }
 
@@ -6,14 +6,25 @@ use chrono::{DateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, error, info, warn};
 
-use crate::config::Config;
+use crate::config::secrets::SECRET_STRIPE_WEBHOOK_SECRET;
+use crate::config::{Config, SecretStore};
 use crate::errors::ErrorCode;
-use crate::models::{BillingPeriod, Order, OrderItem, OrderShipping, SubscriptionStatus};
+use crate::models::{
+    BillingPeriod, Order, OrderItem, OrderShipping, Subscription, SubscriptionStatus,
+    ORDER_DISCOUNT_AMOUNT_KEY, ORDER_SHIPPING_AMOUNT_KEY, ORDER_TAX_AMOUNT_KEY,
+};
 use crate::repositories::ProductRepository;
-use crate::services::messaging::MessagingService;
+use crate::services::messaging::{
+    MessagingService, RefundNotice, SubscriptionNotice, LOCALE_METADATA_KEY,
+};
+use crate::services::paywall::service::{
+    record_cart_affiliate_commission, reverse_stripe_refund_commission,
+};
 use crate::services::subscriptions::StripeSubscriptionUpdate;
 use crate::services::{CedrosLoginClient, ServiceError, ServiceResult, SubscriptionService};
-use crate::storage::{IdempotencyResponse, InventoryAdjustmentRequest, PostgresStore, Store};
+use crate::storage::{
+    IdempotencyResponse, InventoryAdjustmentRequest, PostgresStore, SqliteStore, Store,
+};
 use crate::webhooks::Notifier;
 
 // ============================================================================
@@ -30,6 +41,9 @@ pub enum StripeEventType {
     InvoicePaid,
     InvoicePaymentFailed,
     ChargeRefunded,
+    AccountUpdated,
+    ApplicationFeeCreated,
+    ApplicationFeeRefunded,
     Unknown(String),
 }
 
@@ -43,6 +57,9 @@ impl From<&str> for StripeEventType {
             "invoice.paid" => Self::InvoicePaid,
             "invoice.payment_failed" => Self::InvoicePaymentFailed,
             "charge.refunded" => Self::ChargeRefunded,
+            "account.updated" => Self::AccountUpdated,
+            "application_fee.created" => Self::ApplicationFeeCreated,
+            "application_fee.refunded" => Self::ApplicationFeeRefunded,
             other => Self::Unknown(other.to_string()),
         }
     }
@@ -62,6 +79,8 @@ pub struct StripeWebhookProcessor<S: Store> {
     product_repo: Arc<dyn ProductRepository>,
     /// Optional messaging service for email receipts and order webhooks
     messaging: Option<Arc<dyn MessagingService>>,
+    /// Secret provider whose webhook secret takes precedence over config
+    secrets: Option<Arc<SecretStore>>,
 }
 
 const WEBHOOK_PROCESSING_TTL: Duration = Duration::from_secs(5 * 60);
@@ -104,6 +123,7 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
             cedros_login,
             product_repo,
             messaging: None,
+            secrets: None,
         }
     }
 
@@ -113,6 +133,12 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
         self
     }
 
+    /// Read the webhook signing secret from a hot-reloaded secret provider
+    pub fn with_secrets(mut self, secrets: Arc<SecretStore>) -> Self {
+        self.secrets = Some(secrets);
+        self
+    }
+
     /// Validate tenant_id format from webhook metadata.
     /// Prevents injection via malformed tenant_id values in Stripe session metadata.
     fn validate_webhook_tenant_id(raw_tenant_id: &str) -> ServiceResult<String> {
@@ -234,6 +260,10 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
                 self.handle_invoice_payment_failed(&raw_event).await
             }
             StripeEventType::ChargeRefunded => self.handle_charge_refunded(&raw_event).await,
+            StripeEventType::AccountUpdated => self.handle_account_updated(&raw_event).await,
+            StripeEventType::ApplicationFeeCreated | StripeEventType::ApplicationFeeRefunded => {
+                self.handle_application_fee(&raw_event).await
+            }
             StripeEventType::Unknown(ref t) => {
                 warn!(event_type = %t, "Unhandled Stripe event type");
                 Ok(())
@@ -361,22 +391,34 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
                 }
             }
 
+            // Tax is carried on the payment so the ledger can book it as a liability.
+            let mut metadata = session.metadata.clone();
+            if let Some(tax) = session
+                .total_details
+                .as_ref()
+                .and_then(|d| d.amount_tax)
+                .filter(|t| *t > 0)
+            {
+                metadata.insert(ORDER_TAX_AMOUNT_KEY.to_string(), tax.to_string());
+            }
+            let payment = crate::models::PaymentTransaction {
+                signature,
+                tenant_id: tenant_id.clone(),
+                resource_id: rid.clone(),
+                wallet: session.customer.clone().unwrap_or_default(),
+                user_id: user_id.clone(),
+                amount: crate::models::Money::from_atomic(asset, amount_cents),
+                created_at: Utc::now(),
+                metadata,
+            };
             let claimed = self
                 .store
-                .try_record_payment(crate::models::PaymentTransaction {
-                    signature,
-                    tenant_id: tenant_id.clone(),
-                    resource_id: rid.clone(),
-                    wallet: session.customer.clone().unwrap_or_default(),
-                    user_id: user_id.clone(),
-                    amount: crate::models::Money::from_atomic(asset, amount_cents),
-                    created_at: Utc::now(),
-                    metadata: session.metadata.clone(),
-                })
+                .try_record_payment(payment.clone())
                 .await
                 .map_err(|e| {
                     ServiceError::Internal(format!("failed to record stripe payment: {e}"))
                 })?;
+            crate::services::ledger::post_payment(&*self.store, &payment).await;
 
             if claimed {
                 let rid = rid.clone();
@@ -421,23 +463,32 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
         currency: &str,
         user_id: Option<String>,
     ) -> ServiceResult<()> {
+        let mut cart = None;
         let items: Vec<OrderItem> = if let Some(cart_id) = resource_id.strip_prefix("cart:") {
             match self.store.get_cart_quote(tenant_id, cart_id).await {
-                Ok(Some(cart)) => cart
-                    .items
-                    .into_iter()
-                    .map(|i| OrderItem {
-                        product_id: i.resource_id,
-                        variant_id: i.variant_id,
-                        quantity: i.quantity,
-                    })
-                    .collect(),
+                Ok(Some(quote)) => {
+                    // Line prices only apply when Stripe charged the cart's currency
+                    let same_currency = quote.total.asset.code.eq_ignore_ascii_case(currency);
+                    let items = quote
+                        .items
+                        .iter()
+                        .map(|i| OrderItem {
+                            product_id: i.resource_id.clone(),
+                            variant_id: i.variant_id.clone(),
+                            quantity: i.quantity,
+                            line_total: same_currency.then_some(i.price.atomic),
+                        })
+                        .collect();
+                    cart = Some(quote);
+                    items
+                }
                 Ok(None) => {
                     warn!(tenant_id = %tenant_id, cart_id = %cart_id, "Cart not found while creating order");
                     vec![OrderItem {
                         product_id: resource_id.clone(),
                         variant_id: None,
                         quantity: 1,
+                        line_total: None,
                     }]
                 }
                 Err(e) => {
@@ -446,6 +497,7 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
                         product_id: resource_id.clone(),
                         variant_id: None,
                         quantity: 1,
+                        line_total: None,
                     }]
                 }
             }
@@ -454,6 +506,7 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
                 product_id: resource_id.clone(),
                 variant_id: None,
                 quantity: 1,
+                line_total: None,
             }]
         };
 
@@ -494,6 +547,19 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
                 .and_then(|a| serde_json::to_value(a).ok()),
         });
 
+        let mut metadata = session.metadata.clone();
+        if let Some(details) = &session.total_details {
+            for (key, amount) in [
+                (ORDER_DISCOUNT_AMOUNT_KEY, details.amount_discount),
+                (ORDER_TAX_AMOUNT_KEY, details.amount_tax),
+                (ORDER_SHIPPING_AMOUNT_KEY, details.amount_shipping),
+            ] {
+                if let Some(amount) = amount.filter(|a| *a > 0) {
+                    metadata.insert(key.to_string(), amount.to_string());
+                }
+            }
+        }
+
         let now = Utc::now();
         let order_id = uuid::Uuid::new_v4().to_string();
         let order = Order {
@@ -512,7 +578,7 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
             customer_name,
             receipt_url: Some(format!("/receipt/{}", order_id)),
             shipping,
-            metadata: session.metadata.clone(),
+            metadata,
             created_at: now,
             updated_at: Some(now),
             status_updated_at: Some(now),
@@ -564,33 +630,54 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
         let order_for_messaging = order.clone();
 
         if !has_variants {
-            if let Some(pg_store) = self.store.as_any().downcast_ref::<PostgresStore>() {
-                let adjustments: Vec<InventoryAdjustmentRequest> = items_by_key
-                    .iter()
-                    .map(
-                        |((product_id, variant_id), quantity)| InventoryAdjustmentRequest {
-                            product_id: product_id.clone(),
-                            variant_id: variant_id.clone(),
-                            quantity: *quantity,
-                            allow_backorder: allow_backorders
-                                .get(product_id)
-                                .copied()
-                                .unwrap_or(false),
-                            reason: Some("stripe_order_paid".to_string()),
-                            actor: Some("system".to_string()),
-                        },
-                    )
-                    .collect();
+            // SQL backends can store the order and decrement inventory in one transaction
+            let store_any = self.store.as_any();
+            let adjustments: Vec<InventoryAdjustmentRequest> = items_by_key
+                .iter()
+                .map(
+                    |((product_id, variant_id), quantity)| InventoryAdjustmentRequest {
+                        product_id: product_id.clone(),
+                        variant_id: variant_id.clone(),
+                        quantity: *quantity,
+                        allow_backorder: allow_backorders.get(product_id).copied().unwrap_or(false),
+                        reason: Some("stripe_order_paid".to_string()),
+                        actor: Some("system".to_string()),
+                    },
+                )
+                .collect();
 
-                match pg_store
-                    .try_store_order_with_inventory_adjustments(order, adjustments)
-                    .await
-                {
+            let atomic_result = if let Some(pg_store) = store_any.downcast_ref::<PostgresStore>() {
+                Some(
+                    pg_store
+                        .try_store_order_with_inventory_adjustments(order.clone(), adjustments)
+                        .await,
+                )
+            } else if let Some(sqlite_store) = store_any.downcast_ref::<SqliteStore>() {
+                Some(
+                    sqlite_store
+                        .try_store_order_with_inventory_adjustments(order.clone(), adjustments)
+                        .await,
+                )
+            } else {
+                None
+            };
+
+            if let Some(result) = atomic_result {
+                match result {
                     Ok(true) => {
                         // Send order notifications (fire-and-forget)
                         if let Some(ref messaging) = self.messaging {
                             messaging.notify_order_created(&order_for_messaging).await;
                         }
+                        if let Some(cart) = &cart {
+                            record_cart_affiliate_commission(
+                                &*self.store,
+                                tenant_id,
+                                cart,
+                                &order_for_messaging,
+                            )
+                            .await;
+                        }
 
                         // Convert inventory reservations for cart-based or direct purchases
                         if let Some(cart_id) = resource_id.strip_prefix("cart:") {
@@ -683,6 +770,15 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
                 if let Some(ref messaging) = self.messaging {
                     messaging.notify_order_created(&order_for_messaging).await;
                 }
+                if let Some(cart) = &cart {
+                    record_cart_affiliate_commission(
+                        &*self.store,
+                        tenant_id,
+                        cart,
+                        &order_for_messaging,
+                    )
+                    .await;
+                }
 
                 // Convert inventory reservations for cart-based or direct purchases
                 if let Some(cart_id) = resource_id.strip_prefix("cart:") {
@@ -1117,6 +1213,13 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
                         subscription.wallet.as_deref(),
                     )
                     .await;
+
+                if let (Some(messaging), Some(notice)) = (
+                    &self.messaging,
+                    subscription_notice(&subscription, &invoice),
+                ) {
+                    messaging.notify_subscription_renewed(&notice).await;
+                }
             }
             Err(e) => {
                 warn!(
@@ -1184,6 +1287,13 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
                         subscription.wallet.as_deref(),
                     )
                     .await;
+
+                if let (Some(messaging), Some(notice)) = (
+                    &self.messaging,
+                    subscription_notice(&subscription, &invoice),
+                ) {
+                    messaging.notify_subscription_payment_failed(&notice).await;
+                }
             }
             Err(e) => {
                 warn!(
@@ -1226,6 +1336,8 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
                 if req.status != "succeeded" {
                     req.status = "succeeded".to_string();
                     req.last_error = None;
+                    crate::services::ledger::post_stripe_refund(&*self.store, &req).await;
+                    reverse_stripe_refund_commission(&*self.store, &req).await;
 
                     if let Err(e) = self.store.store_stripe_refund_request(req).await {
                         warn!(
@@ -1271,6 +1383,114 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
             )
             .await;
 
+        // Customer refund email, when Stripe knows where to send it
+        if let (Some(messaging), Some(to_email)) = (&self.messaging, charge.receipt_email.clone()) {
+            messaging
+                .notify_refund_processed(&RefundNotice {
+                    tenant_id: tenant_id.to_string(),
+                    to_email,
+                    refund_id: charge.id.clone(),
+                    order_id: charge.metadata.get("order_id").cloned(),
+                    customer_name: None,
+                    amount: charge.amount_refunded,
+                    currency: charge
+                        .currency
+                        .as_deref()
+                        .unwrap_or("usd")
+                        .to_ascii_uppercase(),
+                    reason: None,
+                    locale: charge.metadata.get(LOCALE_METADATA_KEY).cloned(),
+                })
+                .await;
+        }
+
+        Ok(())
+    }
+
+    /// Stripe Connect: mirror capability flags of a tenant's connected account.
+    async fn handle_account_updated(&self, event: &RawStripeEvent) -> ServiceResult<()> {
+        let account: AccountObject = serde_json::from_value(event.data.object.clone())
+            .map_err(|e| ServiceError::Internal(format!("failed to parse account: {}", e)))?;
+
+        let existing = self
+            .store
+            .find_stripe_connect_account(&account.id)
+            .await
+            .map_err(|e| ServiceError::Internal(format!("failed to look up account: {}", e)))?;
+
+        let Some(mut connect_account) = existing else {
+            // Not one of ours (or created outside this service); nothing to sync.
+            debug!(account_id = %account.id, "account.updated for unknown connected account");
+            return Ok(());
+        };
+
+        connect_account.charges_enabled = account.charges_enabled;
+        connect_account.payouts_enabled = account.payouts_enabled;
+        connect_account.details_submitted = account.details_submitted;
+        connect_account.updated_at = Utc::now();
+
+        info!(
+            tenant_id = %connect_account.tenant_id,
+            account_id = %account.id,
+            charges_enabled = account.charges_enabled,
+            payouts_enabled = account.payouts_enabled,
+            "Connected account updated"
+        );
+
+        self.store
+            .upsert_stripe_connect_account(connect_account)
+            .await
+            .map_err(|e| ServiceError::Internal(format!("failed to store account: {}", e)))
+    }
+
+    /// Stripe Connect: record platform application fees (created and refunded).
+    async fn handle_application_fee(&self, event: &RawStripeEvent) -> ServiceResult<()> {
+        let fee: ApplicationFeeObject =
+            serde_json::from_value(event.data.object.clone()).map_err(|e| {
+                ServiceError::Internal(format!("failed to parse application fee: {}", e))
+            })?;
+
+        let account = self
+            .store
+            .find_stripe_connect_account(&fee.account)
+            .await
+            .map_err(|e| ServiceError::Internal(format!("failed to look up account: {}", e)))?;
+
+        let Some(account) = account else {
+            warn!(
+                fee_id = %fee.id,
+                account_id = %fee.account,
+                "Application fee for unknown connected account"
+            );
+            return Ok(());
+        };
+
+        let record = crate::models::StripeApplicationFee {
+            id: fee.id.clone(),
+            tenant_id: account.tenant_id.clone(),
+            account_id: fee.account,
+            charge_id: fee.charge,
+            amount: fee.amount,
+            amount_refunded: fee.amount_refunded,
+            currency: fee.currency.to_lowercase(),
+            created_at: timestamp_to_datetime(fee.created)?,
+        };
+
+        info!(
+            tenant_id = %account.tenant_id,
+            fee_id = %fee.id,
+            amount = record.amount,
+            amount_refunded = record.amount_refunded,
+            "Recorded application fee"
+        );
+
+        self.store
+            .upsert_stripe_application_fee(record.clone())
+            .await
+            .map_err(|e| {
+                ServiceError::Internal(format!("failed to store application fee: {}", e))
+            })?;
+        crate::services::ledger::post_application_fee(&*self.store, &record).await;
         Ok(())
     }
 
@@ -1279,11 +1499,30 @@ impl<S: Store + 'static> StripeWebhookProcessor<S> {
     // ========================================================================
 
     fn verify_signature(&self, payload: &[u8], signature_header: &str) -> ServiceResult<()> {
-        crate::services::stripe::verify_stripe_webhook_signature(
+        let webhook_secret = zeroize::Zeroizing::new(
+            self.secrets
+                .as_ref()
+                .and_then(|secrets| secrets.get(SECRET_STRIPE_WEBHOOK_SECRET))
+                .unwrap_or_else(|| self.config.stripe.webhook_secret.clone()),
+        );
+        let result = crate::services::stripe::verify_stripe_webhook_signature(
             payload,
             signature_header,
-            &self.config.stripe.webhook_secret,
-        )
+            &webhook_secret,
+        );
+
+        // Connect webhook endpoints are signed with their own secret.
+        let connect_secret = &self.config.stripe.connect.webhook_secret;
+        match result {
+            Err(_) if !connect_secret.is_empty() => {
+                crate::services::stripe::verify_stripe_webhook_signature(
+                    payload,
+                    signature_header,
+                    connect_secret,
+                )
+            }
+            other => other,
+        }
     }
 
     async fn try_claim_webhook(&self, key: &str) -> ServiceResult<bool> {
@@ -1455,10 +1694,19 @@ struct CheckoutSessionObject {
     currency: Option<String>,
     customer_details: Option<CheckoutCustomerDetails>,
     shipping_details: Option<CheckoutShippingDetails>,
+    total_details: Option<CheckoutTotalDetails>,
     #[serde(default)]
     metadata: HashMap<String, String>,
 }
 
+/// Breakdown of `amount_total`, in the session currency's smallest unit.
+#[derive(Debug, Deserialize)]
+struct CheckoutTotalDetails {
+    amount_discount: Option<i64>,
+    amount_tax: Option<i64>,
+    amount_shipping: Option<i64>,
+}
+
 #[derive(Debug, Deserialize)]
 struct CheckoutCustomerDetails {
     email: Option<String>,
@@ -1525,6 +1773,8 @@ struct InvoiceObject {
     id: String,
     subscription: Option<String>,
     lines: Option<InvoiceLines>,
+    customer_email: Option<String>,
+    hosted_invoice_url: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1543,11 +1793,35 @@ struct InvoicePeriod {
     end: i64,
 }
 
+#[derive(Debug, Deserialize)]
+struct AccountObject {
+    id: String,
+    #[serde(default)]
+    charges_enabled: bool,
+    #[serde(default)]
+    payouts_enabled: bool,
+    #[serde(default)]
+    details_submitted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApplicationFeeObject {
+    id: String,
+    account: String,
+    amount: i64,
+    #[serde(default)]
+    amount_refunded: i64,
+    currency: String,
+    charge: Option<String>,
+    created: i64,
+}
+
 #[derive(Debug, Deserialize)]
 struct ChargeObject {
     id: String,
     amount_refunded: i64,
     currency: Option<String>,
+    receipt_email: Option<String>,
     #[serde(default)]
     metadata: HashMap<String, String>,
 }
@@ -1556,6 +1830,22 @@ struct ChargeObject {
 // Helper Functions
 // ============================================================================
 
+/// Email details for a subscription invoice; `None` when Stripe has no customer email
+fn subscription_notice(
+    subscription: &Subscription,
+    invoice: &InvoiceObject,
+) -> Option<SubscriptionNotice> {
+    Some(SubscriptionNotice {
+        tenant_id: subscription.tenant_id.clone(),
+        to_email: invoice.customer_email.clone().filter(|e| !e.is_empty())?,
+        subscription_id: subscription.id.clone(),
+        product_id: subscription.product_id.clone(),
+        period_end: subscription.current_period_end,
+        update_payment_url: invoice.hosted_invoice_url.clone(),
+        locale: subscription.metadata.get(LOCALE_METADATA_KEY).cloned(),
+    })
+}
+
 fn timestamp_to_datetime(ts: i64) -> ServiceResult<DateTime<Utc>> {
     Utc.timestamp_opt(ts, 0)
         .single()
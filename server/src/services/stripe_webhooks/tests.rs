@@ -4,8 +4,11 @@ use parking_lot::Mutex;
 
 use crate::models::{CartQuote, PaymentTransaction, RefundQuote, Subscription, SubscriptionStatus};
 use crate::storage::{
-    AdminNonce, AdminStats, CreditsHold, DlqWebhook, IdempotencyResponse, InMemoryStore,
-    PendingEmail, PendingWebhook, Purchase, StorageError, StorageResult, Store, WebhookStatus,
+    AdminNonce, AdminStats, AiUsageRecord, ArchivableRecords, ArchivePurge, ArchivedPaymentRef,
+    CreditsHold, DlqWebhook, EmailSuppression, EmailTemplate, EmbeddingEntity, EmbeddingJob,
+    EmbeddingMatch, EmbeddingRecord, EventLogEntry, EventLogQuery, IdempotencyResponse,
+    InMemoryStore, PendingEmail, PendingWebhook, Purchase, StorageError, StorageResult, Store,
+    WebhookStatus,
 };
 use crate::webhooks::{NoopNotifier, Notifier};
 use crate::x402::utils::hex_encode;
@@ -76,6 +79,14 @@ impl Notifier for TestNotifier {
         _currency: &str,
     ) {
     }
+    async fn chat_handoff_requested(
+        &self,
+        _tenant_id: &str,
+        _session_id: &str,
+        _reason: Option<&str>,
+        _requested_by: &str,
+    ) {
+    }
 }
 
 use hmac::{Hmac, Mac};
@@ -174,6 +185,15 @@ impl Store for FailingCompleteIdempotencyStore {
         unimplemented!()
     }
 
+    async fn list_processed_refunds(
+        &self,
+        _tenant_id: &str,
+        _from: DateTime<Utc>,
+        _to: DateTime<Utc>,
+    ) -> StorageResult<Vec<RefundQuote>> {
+        unimplemented!()
+    }
+
     async fn list_credits_refund_requests(
         &self,
         _tenant_id: &str,
@@ -225,6 +245,15 @@ impl Store for FailingCompleteIdempotencyStore {
         unimplemented!()
     }
 
+    async fn list_processed_stripe_refund_requests(
+        &self,
+        _tenant_id: &str,
+        _from: DateTime<Utc>,
+        _to: DateTime<Utc>,
+    ) -> StorageResult<Vec<crate::models::StripeRefundRequest>> {
+        unimplemented!()
+    }
+
     async fn get_pending_stripe_refund_request_by_original_purchase_id(
         &self,
         _tenant_id: &str,
@@ -241,6 +270,132 @@ impl Store for FailingCompleteIdempotencyStore {
         Ok(None)
     }
 
+    async fn upsert_stripe_connect_account(
+        &self,
+        _account: crate::models::StripeConnectAccount,
+    ) -> StorageResult<()> {
+        Ok(())
+    }
+
+    async fn get_stripe_connect_account(
+        &self,
+        _tenant_id: &str,
+    ) -> StorageResult<Option<crate::models::StripeConnectAccount>> {
+        Ok(None)
+    }
+
+    async fn find_stripe_connect_account(
+        &self,
+        _account_id: &str,
+    ) -> StorageResult<Option<crate::models::StripeConnectAccount>> {
+        Ok(None)
+    }
+
+    async fn upsert_stripe_application_fee(
+        &self,
+        _fee: crate::models::StripeApplicationFee,
+    ) -> StorageResult<()> {
+        Ok(())
+    }
+
+    async fn list_stripe_application_fees(
+        &self,
+        _tenant_id: &str,
+        _from: chrono::DateTime<chrono::Utc>,
+        _to: chrono::DateTime<chrono::Utc>,
+    ) -> StorageResult<Vec<crate::models::StripeApplicationFee>> {
+        Ok(Vec::new())
+    }
+
+    async fn upsert_affiliate(&self, _affiliate: crate::models::Affiliate) -> StorageResult<()> {
+        Ok(())
+    }
+
+    async fn get_affiliate(
+        &self,
+        _tenant_id: &str,
+        _id: &str,
+    ) -> StorageResult<Option<crate::models::Affiliate>> {
+        Ok(None)
+    }
+
+    async fn get_affiliate_by_code(
+        &self,
+        _tenant_id: &str,
+        _code: &str,
+    ) -> StorageResult<Option<crate::models::Affiliate>> {
+        Ok(None)
+    }
+
+    async fn list_affiliates(
+        &self,
+        _tenant_id: &str,
+        _limit: i32,
+        _offset: i32,
+    ) -> StorageResult<Vec<crate::models::Affiliate>> {
+        Ok(Vec::new())
+    }
+
+    async fn record_affiliate_commission(
+        &self,
+        _entry: crate::models::AffiliateCommission,
+    ) -> StorageResult<bool> {
+        Ok(false)
+    }
+
+    async fn list_affiliate_commissions(
+        &self,
+        _tenant_id: &str,
+        _affiliate_id: Option<&str>,
+        _from: chrono::DateTime<chrono::Utc>,
+        _to: chrono::DateTime<chrono::Utc>,
+    ) -> StorageResult<Vec<crate::models::AffiliateCommission>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_affiliate_commissions_for_purchase(
+        &self,
+        _tenant_id: &str,
+        _purchase_id: &str,
+    ) -> StorageResult<Vec<crate::models::AffiliateCommission>> {
+        Ok(Vec::new())
+    }
+
+    async fn append_journal_entry(
+        &self,
+        _entry: crate::models::JournalEntry,
+    ) -> StorageResult<bool> {
+        Ok(false)
+    }
+
+    async fn list_journal_entries(
+        &self,
+        _tenant_id: &str,
+        _from: chrono::DateTime<chrono::Utc>,
+        _to: chrono::DateTime<chrono::Utc>,
+        _limit: i32,
+        _offset: i32,
+    ) -> StorageResult<Vec<crate::models::JournalEntry>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_journal_entries_for_source(
+        &self,
+        _tenant_id: &str,
+        _source: crate::models::JournalSource,
+        _source_id: &str,
+    ) -> StorageResult<Vec<crate::models::JournalEntry>> {
+        Ok(Vec::new())
+    }
+
+    async fn trial_balance(
+        &self,
+        _tenant_id: &str,
+        _as_of: chrono::DateTime<chrono::Utc>,
+    ) -> StorageResult<Vec<crate::models::TrialBalanceRow>> {
+        Ok(Vec::new())
+    }
+
     async fn try_store_order(&self, _order: crate::models::Order) -> StorageResult<bool> {
         Ok(false)
     }
@@ -253,6 +408,14 @@ impl Store for FailingCompleteIdempotencyStore {
         Ok(None)
     }
 
+    async fn get_order_by_purchase_id(
+        &self,
+        _tenant_id: &str,
+        _purchase_id: &str,
+    ) -> StorageResult<Option<crate::models::Order>> {
+        Ok(None)
+    }
+
     async fn list_orders(
         &self,
         _tenant_id: &str,
@@ -262,6 +425,16 @@ impl Store for FailingCompleteIdempotencyStore {
         Ok(Vec::new())
     }
 
+    async fn list_orders_by_user_id(
+        &self,
+        _tenant_id: &str,
+        _user_id: &str,
+        _limit: i32,
+        _offset: i32,
+    ) -> StorageResult<Vec<crate::models::Order>> {
+        Ok(Vec::new())
+    }
+
     async fn list_orders_filtered(
         &self,
         _tenant_id: &str,
@@ -363,6 +536,21 @@ impl Store for FailingCompleteIdempotencyStore {
         Ok(())
     }
 
+    async fn update_return_request(
+        &self,
+        _request: crate::models::ReturnRequest,
+    ) -> StorageResult<()> {
+        Ok(())
+    }
+
+    async fn update_return_request_if_status(
+        &self,
+        _request: crate::models::ReturnRequest,
+        _expected_status: &str,
+    ) -> StorageResult<bool> {
+        Ok(true)
+    }
+
     async fn get_return_request(
         &self,
         _tenant_id: &str,
@@ -761,7 +949,7 @@ impl Store for FailingCompleteIdempotencyStore {
         unimplemented!()
     }
 
-    async fn cleanup_expired_credits_holds(&self) -> StorageResult<u64> {
+    async fn cleanup_expired_credits_holds(&self) -> StorageResult<Vec<CreditsHold>> {
         unimplemented!()
     }
 
@@ -788,6 +976,31 @@ impl Store for FailingCompleteIdempotencyStore {
         unimplemented!()
     }
 
+    async fn list_archivable_records(
+        &self,
+        _tenant_id: &str,
+        _older_than: DateTime<Utc>,
+        _limit: i64,
+    ) -> StorageResult<ArchivableRecords> {
+        unimplemented!()
+    }
+
+    async fn purge_archived_records(
+        &self,
+        _tenant_id: &str,
+        _purge: &ArchivePurge,
+    ) -> StorageResult<u64> {
+        unimplemented!()
+    }
+
+    async fn find_archived_payment(
+        &self,
+        _tenant_id: &str,
+        _signature: &str,
+    ) -> StorageResult<Option<ArchivedPaymentRef>> {
+        unimplemented!()
+    }
+
     async fn create_nonce(&self, _nonce: AdminNonce) -> StorageResult<()> {
         unimplemented!()
     }
@@ -867,6 +1080,22 @@ impl Store for FailingCompleteIdempotencyStore {
         unimplemented!()
     }
 
+    async fn append_event(&self, _entry: EventLogEntry) -> StorageResult<i64> {
+        unimplemented!()
+    }
+
+    async fn list_events(
+        &self,
+        _tenant_id: &str,
+        _query: &EventLogQuery,
+    ) -> StorageResult<Vec<EventLogEntry>> {
+        unimplemented!()
+    }
+
+    async fn cleanup_old_events(&self, _older_than: DateTime<Utc>) -> StorageResult<u64> {
+        unimplemented!()
+    }
+
     async fn save_idempotency_key(
         &self,
         key: &str,
@@ -937,6 +1166,14 @@ impl Store for FailingCompleteIdempotencyStore {
         unimplemented!()
     }
 
+    async fn get_subscriptions_by_user_id(
+        &self,
+        _tenant_id: &str,
+        _user_id: &str,
+    ) -> StorageResult<Vec<Subscription>> {
+        unimplemented!()
+    }
+
     async fn get_subscription_by_stripe_id(
         &self,
         _tenant_id: &str,
@@ -1108,6 +1345,55 @@ impl Store for FailingCompleteIdempotencyStore {
         unimplemented!()
     }
 
+    async fn list_chat_messages_since(
+        &self,
+        _tenant_id: &str,
+        _session_id: &str,
+        _since: DateTime<Utc>,
+        _limit: i32,
+    ) -> StorageResult<Vec<crate::models::ChatMessage>> {
+        unimplemented!()
+    }
+
+    async fn request_chat_handoff(
+        &self,
+        _tenant_id: &str,
+        _session_id: &str,
+        _reason: Option<&str>,
+        _requested_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        unimplemented!()
+    }
+
+    async fn claim_chat_session(
+        &self,
+        _tenant_id: &str,
+        _session_id: &str,
+        _agent_id: &str,
+        _claimed_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        unimplemented!()
+    }
+
+    async fn bind_chat_session_customer(
+        &self,
+        _tenant_id: &str,
+        _session_id: &str,
+        _customer_id: &str,
+        _updated_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        unimplemented!()
+    }
+
+    async fn release_chat_session(
+        &self,
+        _tenant_id: &str,
+        _session_id: &str,
+        _updated_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        unimplemented!()
+    }
+
     async fn create_faq(&self, _faq: crate::models::Faq) -> StorageResult<()> {
         unimplemented!()
     }
@@ -1156,6 +1442,125 @@ impl Store for FailingCompleteIdempotencyStore {
         unimplemented!()
     }
 
+    async fn upsert_embedding(&self, _record: EmbeddingRecord) -> StorageResult<()> {
+        unimplemented!()
+    }
+
+    async fn list_source_embeddings(
+        &self,
+        _tenant_id: &str,
+        _source_id: &str,
+        _entity_types: &[EmbeddingEntity],
+    ) -> StorageResult<Vec<EmbeddingRecord>> {
+        unimplemented!()
+    }
+
+    async fn delete_embedding(
+        &self,
+        _tenant_id: &str,
+        _entity_type: EmbeddingEntity,
+        _entity_id: &str,
+    ) -> StorageResult<bool> {
+        unimplemented!()
+    }
+
+    async fn search_embeddings(
+        &self,
+        _tenant_id: &str,
+        _model: &str,
+        _entity_types: &[EmbeddingEntity],
+        _query: &[f32],
+        _limit: i32,
+    ) -> StorageResult<Vec<EmbeddingMatch>> {
+        unimplemented!()
+    }
+
+    async fn enqueue_embedding_job(&self, _job: EmbeddingJob) -> StorageResult<()> {
+        unimplemented!()
+    }
+
+    async fn claim_embedding_jobs(
+        &self,
+        _now: chrono::DateTime<chrono::Utc>,
+        _limit: i32,
+    ) -> StorageResult<Vec<EmbeddingJob>> {
+        unimplemented!()
+    }
+
+    async fn add_ai_usage(&self, _usage: AiUsageRecord) -> StorageResult<()> {
+        unimplemented!()
+    }
+
+    async fn list_ai_usage(
+        &self,
+        _tenant_id: &str,
+        _period: &str,
+    ) -> StorageResult<Vec<AiUsageRecord>> {
+        unimplemented!()
+    }
+
+    async fn save_product_import_job(
+        &self,
+        _job: crate::models::ProductImportJob,
+    ) -> StorageResult<()> {
+        unimplemented!()
+    }
+
+    async fn get_product_import_job(
+        &self,
+        _tenant_id: &str,
+        _job_id: &str,
+    ) -> StorageResult<Option<crate::models::ProductImportJob>> {
+        unimplemented!()
+    }
+
+    async fn fail_stale_product_import_jobs(
+        &self,
+        _updated_before: DateTime<Utc>,
+        _error: &str,
+    ) -> StorageResult<u64> {
+        unimplemented!()
+    }
+
+    async fn save_price_schedule(
+        &self,
+        _schedule: crate::models::PriceSchedule,
+    ) -> StorageResult<()> {
+        unimplemented!()
+    }
+
+    async fn update_price_schedule_if_status(
+        &self,
+        _schedule: crate::models::PriceSchedule,
+        _expected: crate::models::PriceScheduleStatus,
+    ) -> StorageResult<bool> {
+        unimplemented!()
+    }
+
+    async fn get_price_schedule(
+        &self,
+        _tenant_id: &str,
+        _schedule_id: &str,
+    ) -> StorageResult<Option<crate::models::PriceSchedule>> {
+        unimplemented!()
+    }
+
+    async fn list_product_price_schedules(
+        &self,
+        _tenant_id: &str,
+        _product_id: &str,
+    ) -> StorageResult<Vec<crate::models::PriceSchedule>> {
+        unimplemented!()
+    }
+
+    async fn list_due_price_schedules(
+        &self,
+        _now: chrono::DateTime<chrono::Utc>,
+        _limit: i32,
+    ) -> StorageResult<Vec<crate::models::PriceSchedule>> {
+        unimplemented!()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1173,7 +1578,12 @@ impl Store for FailingCompleteIdempotencyStore {
         unimplemented!()
     }
 
-    async fn mark_email_success(&self, _email_id: &str) -> StorageResult<()> {
+    async fn mark_email_success(
+        &self,
+        _email_id: &str,
+        _provider: &str,
+        _provider_message_id: Option<&str>,
+    ) -> StorageResult<()> {
         unimplemented!()
     }
 
@@ -1190,6 +1600,19 @@ impl Store for FailingCompleteIdempotencyStore {
         unimplemented!()
     }
 
+    async fn mark_email_suppressed(&self, _email_id: &str, _reason: &str) -> StorageResult<()> {
+        unimplemented!()
+    }
+
+    async fn mark_email_bounced(
+        &self,
+        _tenant_id: &str,
+        _provider_message_id: &str,
+        _reason: &str,
+    ) -> StorageResult<bool> {
+        unimplemented!()
+    }
+
     async fn get_email(&self, _email_id: &str) -> StorageResult<Option<PendingEmail>> {
         unimplemented!()
     }
@@ -1198,6 +1621,61 @@ impl Store for FailingCompleteIdempotencyStore {
         unimplemented!()
     }
 
+    async fn upsert_email_template(&self, _template: EmailTemplate) -> StorageResult<()> {
+        unimplemented!()
+    }
+
+    async fn get_email_template(
+        &self,
+        _tenant_id: &str,
+        _kind: &str,
+        _locale: &str,
+    ) -> StorageResult<Option<EmailTemplate>> {
+        unimplemented!()
+    }
+
+    async fn list_email_templates(&self, _tenant_id: &str) -> StorageResult<Vec<EmailTemplate>> {
+        unimplemented!()
+    }
+
+    async fn delete_email_template(
+        &self,
+        _tenant_id: &str,
+        _kind: &str,
+        _locale: &str,
+    ) -> StorageResult<bool> {
+        unimplemented!()
+    }
+
+    async fn upsert_email_suppression(&self, _suppression: EmailSuppression) -> StorageResult<()> {
+        unimplemented!()
+    }
+
+    async fn get_email_suppression(
+        &self,
+        _tenant_id: &str,
+        _email: &str,
+    ) -> StorageResult<Option<EmailSuppression>> {
+        unimplemented!()
+    }
+
+    async fn list_email_suppressions(
+        &self,
+        _tenant_id: &str,
+        _limit: i32,
+        _offset: i32,
+    ) -> StorageResult<Vec<EmailSuppression>> {
+        unimplemented!()
+    }
+
+    async fn delete_email_suppression(
+        &self,
+        _tenant_id: &str,
+        _email: &str,
+    ) -> StorageResult<bool> {
+        unimplemented!()
+    }
+
     async fn record_gift_card_redemption(
         &self,
         _r: crate::models::GiftCardRedemption,
@@ -1636,6 +2114,11 @@ async fn test_checkout_completed_stores_order_and_decrements_inventory() {
                         "country": "US"
                     }
                 },
+                "total_details": {
+                    "amount_discount": 100,
+                    "amount_tax": 40,
+                    "amount_shipping": 0
+                },
                 "metadata": {
                     "tenant_id": "tenant-a",
                     "resource_id": "res-1"
@@ -1653,6 +2136,36 @@ async fn test_checkout_completed_stores_order_and_decrements_inventory() {
     assert_eq!(orders[0].resource_id, "res-1");
     assert_eq!(orders[0].customer_email.as_deref(), Some("a@example.com"));
     assert!(orders[0].shipping.is_some());
+    assert_eq!(
+        orders[0].metadata_amount(crate::models::ORDER_DISCOUNT_AMOUNT_KEY),
+        100
+    );
+    assert_eq!(
+        orders[0].metadata_amount(crate::models::ORDER_TAX_AMOUNT_KEY),
+        40
+    );
+    assert!(!orders[0]
+        .metadata
+        .contains_key(crate::models::ORDER_SHIPPING_AMOUNT_KEY));
+
+    // Tax is booked as a liability, the rest as revenue
+    let entries = store
+        .list_journal_entries_for_source(
+            "tenant-a",
+            crate::models::JournalSource::Payment,
+            "stripe:cs_test_1",
+        )
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(
+        entries[0].lines,
+        vec![
+            crate::models::JournalLine::debit("receivable:stripe", 500),
+            crate::models::JournalLine::credit("revenue", 460),
+            crate::models::JournalLine::credit("tax_payable", 40),
+        ]
+    );
 
     let updated = product_repo.get_product("tenant-a", "res-1").await.unwrap();
     assert_eq!(updated.inventory_quantity, Some(1));
@@ -1849,6 +2362,7 @@ async fn test_checkout_completed_replay_converts_reservations() {
             product_id: "res-1".to_string(),
             variant_id: None,
             quantity: 1,
+            line_total: None,
         }],
         amount: 500,
         amount_asset: "USD".to_string(),
@@ -2608,6 +3122,197 @@ async fn test_charge_refunded_updates_stripe_refund_request_status() {
     assert_eq!(updated.status, "succeeded");
 }
 
+fn test_affiliate(tenant_id: &str) -> crate::models::Affiliate {
+    let now = Utc::now();
+    crate::models::Affiliate {
+        id: "aff-1".to_string(),
+        tenant_id: tenant_id.to_string(),
+        code: "PARTNER".to_string(),
+        name: "Partner".to_string(),
+        email: None,
+        payout_wallet: None,
+        active: true,
+        commission_type: crate::models::CommissionType::Percentage,
+        commission_value: 1_000,
+        rules: Vec::new(),
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+#[tokio::test]
+async fn test_checkout_completed_cart_records_affiliate_commission() {
+    let mut cfg = Config::default();
+    cfg.stripe.webhook_secret = "whsec_test".to_string();
+
+    let cfg = Arc::new(cfg);
+    let store = Arc::new(InMemoryStore::new());
+    let subscription_service = Arc::new(SubscriptionService::new(
+        cfg.clone(),
+        store.clone(),
+        Arc::new(NoopNotifier),
+    ));
+    let processor = StripeWebhookProcessor::new(
+        cfg,
+        store.clone(),
+        Arc::new(TestNotifier::default()),
+        subscription_service,
+        Arc::new(crate::repositories::InMemoryProductRepository::new(
+            Vec::new(),
+        )),
+    );
+    store
+        .upsert_affiliate(test_affiliate("tenant-a"))
+        .await
+        .unwrap();
+
+    // Crypto-priced cart (2 USDC) checked out through Stripe for $2.00
+    let usdc = crate::models::get_asset("USDC").unwrap();
+    let cart = crate::models::CartQuote {
+        id: "cart-aff".to_string(),
+        tenant_id: "tenant-a".to_string(),
+        items: vec![crate::models::CartItem {
+            resource_id: "res-1".to_string(),
+            variant_id: None,
+            quantity: 1,
+            price: crate::models::Money::new(usdc.clone(), 2_000_000),
+            original_price: None,
+            description: None,
+            applied_coupons: Vec::new(),
+            metadata: Default::default(),
+        }],
+        total: crate::models::Money::new(usdc, 2_000_000),
+        original_total: None,
+        metadata: HashMap::from([(
+            crate::services::paywall::service::AFFILIATE_ID_METADATA_KEY.to_string(),
+            "aff-1".to_string(),
+        )]),
+        applied_coupons: Vec::new(),
+        created_at: Utc::now(),
+        expires_at: Utc::now(),
+        wallet_paid_by: None,
+    };
+    store.store_cart_quote(cart).await.unwrap();
+
+    let event: RawStripeEvent = serde_json::from_value(serde_json::json!({
+        "id": "evt_aff",
+        "type": "checkout.session.completed",
+        "data": {
+            "object": {
+                "id": "cs_aff",
+                "mode": "payment",
+                "amount_total": 200,
+                "currency": "usd",
+                "metadata": {
+                    "tenant_id": "tenant-a",
+                    "resource_id": "cart:cart-aff"
+                }
+            }
+        }
+    }))
+    .unwrap();
+
+    processor.handle_checkout_completed(&event).await.unwrap();
+    // Replayed webhooks do not double-count
+    processor.handle_checkout_completed(&event).await.unwrap();
+
+    let entries = store
+        .list_affiliate_commissions_for_purchase("tenant-a", "cs_aff")
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].kind, crate::models::CommissionEntryKind::Earned);
+    // 10% of the $2.00 actually charged, in cents
+    assert_eq!(entries[0].amount, 20);
+    assert_eq!(entries[0].currency, "USD");
+}
+
+#[tokio::test]
+async fn test_charge_refunded_reverses_affiliate_commission() {
+    let cfg = Arc::new(Config::default());
+    let store = Arc::new(InMemoryStore::new());
+    let subscription_service = Arc::new(SubscriptionService::new(
+        cfg.clone(),
+        store.clone(),
+        Arc::new(NoopNotifier),
+    ));
+    let processor = StripeWebhookProcessor::new(
+        cfg,
+        store.clone(),
+        Arc::new(NoopNotifier),
+        subscription_service,
+        Arc::new(crate::repositories::InMemoryProductRepository::new(
+            Vec::new(),
+        )),
+    );
+
+    store
+        .record_affiliate_commission(crate::models::AffiliateCommission {
+            id: "earned:cs_test".to_string(),
+            tenant_id: "default".to_string(),
+            affiliate_id: "aff-1".to_string(),
+            order_id: "order-1".to_string(),
+            purchase_id: "cs_test".to_string(),
+            kind: crate::models::CommissionEntryKind::Earned,
+            amount: 100,
+            order_amount: 1_000,
+            currency: "USD".to_string(),
+            refund_id: None,
+            created_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+    store
+        .store_stripe_refund_request(crate::models::StripeRefundRequest {
+            id: "srr_aff".to_string(),
+            tenant_id: "default".to_string(),
+            original_purchase_id: "stripe:cs_test".to_string(),
+            stripe_payment_intent_id: "pi_1".to_string(),
+            stripe_refund_id: Some("re_1".to_string()),
+            stripe_charge_id: Some("ch_aff".to_string()),
+            amount: 500,
+            currency: "usd".to_string(),
+            status: "pending".to_string(),
+            reason: None,
+            metadata: HashMap::new(),
+            created_at: Utc::now(),
+            processed_by: Some("admin".to_string()),
+            processed_at: Some(Utc::now()),
+            last_error: None,
+        })
+        .await
+        .unwrap();
+
+    let event: RawStripeEvent = serde_json::from_value(serde_json::json!({
+        "id": "evt_ref_aff",
+        "type": "charge.refunded",
+        "data": {
+            "object": {
+                "id": "ch_aff",
+                "amount_refunded": 500,
+                "currency": "usd",
+                "metadata": { "tenant_id": "default" }
+            }
+        }
+    }))
+    .unwrap();
+
+    processor.handle_charge_refunded(&event).await.unwrap();
+
+    // Half the order refunded reverses half the commission
+    let entries = store
+        .list_affiliate_commissions_for_purchase("default", "cs_test")
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 2);
+    let reversed = entries
+        .iter()
+        .find(|e| e.kind == crate::models::CommissionEntryKind::Reversed)
+        .expect("reversal entry");
+    assert_eq!(reversed.amount, -50);
+    assert_eq!(reversed.refund_id.as_deref(), Some("srr_aff"));
+}
+
 #[test]
 fn test_timestamp_to_datetime_rejects_invalid() {
     let err = timestamp_to_datetime(i64::MAX).expect_err("expected invalid timestamp error");
@@ -2616,3 +3321,98 @@ fn test_timestamp_to_datetime_rejects_invalid() {
         other => panic!("unexpected error type: {other:?}"),
     }
 }
+
+#[tokio::test]
+async fn test_connect_events_signed_with_connect_secret() {
+    let mut cfg = Config::default();
+    cfg.stripe.webhook_secret = "whsec_platform".to_string();
+    cfg.stripe.connect.webhook_secret = "whsec_connect".to_string();
+    let cfg = Arc::new(cfg);
+
+    let store = Arc::new(InMemoryStore::new());
+    let now = Utc::now();
+    store
+        .upsert_stripe_connect_account(crate::models::StripeConnectAccount {
+            tenant_id: "tenant-a".to_string(),
+            account_id: "acct_1".to_string(),
+            charges_enabled: false,
+            payouts_enabled: false,
+            details_submitted: false,
+            application_fee_bps: None,
+            created_at: now,
+            updated_at: now,
+        })
+        .await
+        .unwrap();
+
+    let subscription_service = Arc::new(SubscriptionService::new(
+        cfg.clone(),
+        store.clone(),
+        Arc::new(NoopNotifier),
+    ));
+    let processor = StripeWebhookProcessor::new(
+        cfg.clone(),
+        store.clone(),
+        Arc::new(NoopNotifier),
+        subscription_service,
+        Arc::new(crate::repositories::InMemoryProductRepository::new(
+            Vec::new(),
+        )),
+    );
+
+    let sign = |payload: &serde_json::Value| {
+        let ts = Utc::now().timestamp();
+        let body = serde_json::to_vec(payload).unwrap();
+        let signed_payload = format!("{}.{}", ts, String::from_utf8_lossy(&body));
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"whsec_connect").unwrap();
+        mac.update(signed_payload.as_bytes());
+        let header = format!("t={},v1={}", ts, hex_encode(mac.finalize().into_bytes()));
+        (body, header)
+    };
+
+    let (body, header) = sign(&serde_json::json!({
+        "id": "evt_acct",
+        "type": "account.updated",
+        "data": {"object": {
+            "id": "acct_1",
+            "charges_enabled": true,
+            "payouts_enabled": true,
+            "details_submitted": true
+        }}
+    }));
+    processor.process_webhook(&body, &header).await.unwrap();
+
+    let account = store
+        .get_stripe_connect_account("tenant-a")
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(account.charges_enabled && account.payouts_enabled);
+
+    let (body, header) = sign(&serde_json::json!({
+        "id": "evt_fee",
+        "type": "application_fee.refunded",
+        "data": {"object": {
+            "id": "fee_1",
+            "account": "acct_1",
+            "amount": 250,
+            "amount_refunded": 100,
+            "currency": "USD",
+            "charge": "ch_1",
+            "created": now.timestamp()
+        }}
+    }));
+    processor.process_webhook(&body, &header).await.unwrap();
+
+    let fees = store
+        .list_stripe_application_fees(
+            "tenant-a",
+            now - chrono::Duration::minutes(1),
+            now + chrono::Duration::minutes(1),
+        )
+        .await
+        .unwrap();
+    assert_eq!(fees.len(), 1);
+    assert_eq!(fees[0].currency, "usd");
+    assert_eq!(fees[0].net_amount(), 150);
+}
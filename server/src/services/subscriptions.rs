@@ -1744,6 +1744,14 @@ mod tests {
             _currency: &str,
         ) {
         }
+        async fn chat_handoff_requested(
+            &self,
+            _tenant_id: &str,
+            _session_id: &str,
+            _reason: Option<&str>,
+            _requested_by: &str,
+        ) {
+        }
     }
 
     #[tokio::test]
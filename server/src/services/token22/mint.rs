@@ -11,7 +11,6 @@ use solana_sdk::{
     pubkey::Pubkey,
     signature::Keypair,
     signer::Signer,
-    transaction::Transaction,
 };
 
 use super::{Token22Service, TOKEN_2022_PROGRAM_ID};
@@ -154,12 +153,15 @@ pub async fn create_mint_with_transfer_fee(
     let blockhash = Hash::from_str(&bh_response.blockhash)
         .map_err(|e| format!("invalid blockhash from cache: {e}"))?;
 
-    let tx = Transaction::new_signed_with_payer(
-        &[create_account_ix, init_fee_ix, init_mint_ix],
-        Some(&authority),
-        &[&*service.authority, &mint_keypair],
-        blockhash,
-    );
+    let tx = service
+        .authority
+        .sign_transaction(
+            &[create_account_ix, init_fee_ix, init_mint_ix],
+            &[&mint_keypair],
+            blockhash,
+        )
+        .await
+        .map_err(|e| format!("signing failed: {e}"))?;
 
     let sig = service
         .rpc
@@ -7,10 +7,11 @@
 use std::sync::Arc;
 
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
-use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use solana_sdk::pubkey::Pubkey;
 
 use crate::config::X402Config;
 use crate::services::BlockhashCache;
+use crate::x402::signer::load_server_wallets;
 use crate::x402::ServerWallet;
 
 mod mint;
@@ -31,14 +32,15 @@ pub const TOKEN_2022_PROGRAM_ID: Pubkey =
 /// Token-22 service for creating mints and minting tokens.
 pub struct Token22Service {
     rpc: Arc<RpcClient>,
-    authority: Arc<Keypair>,
+    /// Mint/freeze authority and fee payer; signs locally or via the remote signer
+    authority: Arc<ServerWallet>,
     blockhash_cache: Arc<BlockhashCache>,
 }
 
 impl Token22Service {
     pub fn new(
         rpc: Arc<RpcClient>,
-        authority: Arc<Keypair>,
+        authority: Arc<ServerWallet>,
         blockhash_cache: Arc<BlockhashCache>,
     ) -> Self {
         Self {
@@ -60,18 +62,21 @@ impl Token22Service {
             .server_wallets
             .first()
             .ok_or("x402.server_wallets is empty; need at least one for Token22Service")?;
-        let server_wallet = ServerWallet::from_string(wallet_str)
-            .map_err(|e| format!("invalid server wallet: {e}"))?;
+        let server_wallet = load_server_wallets(&config.signer, std::slice::from_ref(wallet_str))
+            .map_err(|e| format!("invalid server wallet: {e}"))?
+            .into_iter()
+            .next()
+            .ok_or("invalid server wallet")?;
         let rpc = Arc::new(RpcClient::new(config.rpc_url.clone()));
         let blockhash_cache = Arc::new(BlockhashCache::with_default_ttl(rpc.clone()));
         Ok(Self {
             rpc,
-            authority: Arc::new(server_wallet.keypair),
+            authority: Arc::new(server_wallet),
             blockhash_cache,
         })
     }
 
     pub fn authority_pubkey(&self) -> Pubkey {
-        self.authority.pubkey()
+        self.authority.pubkey
     }
 }
@@ -14,7 +14,6 @@ use solana_sdk::{
     pubkey::Pubkey,
     signature::Keypair,
     signer::Signer,
-    transaction::Transaction,
 };
 
 use super::operations::{build_burn_ix, build_create_ata_idempotent_ix, build_mint_to_ix};
@@ -89,9 +88,11 @@ pub async fn create_nft_mint(
     let blockhash =
         Hash::from_str(&bh.blockhash).map_err(|e| format!("invalid blockhash: {e}"))?;
 
-    let tx = Transaction::new_signed_with_payer(
-        &ixs, Some(&authority), &[&*service.authority, &mint_kp], blockhash,
-    );
+    let tx = service
+        .authority
+        .sign_transaction(&ixs, &[&mint_kp], blockhash)
+        .await
+        .map_err(|e| format!("signing failed: {e}"))?;
 
     let sig = service
         .rpc
@@ -128,12 +129,11 @@ pub async fn burn_nft(
     let blockhash =
         Hash::from_str(&bh.blockhash).map_err(|e| format!("invalid blockhash: {e}"))?;
 
-    let tx = Transaction::new_signed_with_payer(
-        &[burn_ix, close_ix],
-        Some(&authority),
-        &[&*service.authority],
-        blockhash,
-    );
+    let tx = service
+        .authority
+        .sign_transaction(&[burn_ix, close_ix], &[], blockhash)
+        .await
+        .map_err(|e| format!("signing failed: {e}"))?;
 
     let sig = service
         .rpc
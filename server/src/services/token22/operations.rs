@@ -6,7 +6,6 @@ use solana_sdk::{
     hash::Hash,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
-    transaction::Transaction,
 };
 
 use super::{Token22Service, TOKEN_2022_PROGRAM_ID};
@@ -127,12 +126,11 @@ pub async fn mint_tokens(
     let blockhash = Hash::from_str(&bh_response.blockhash)
         .map_err(|e| format!("invalid blockhash from cache: {e}"))?;
 
-    let tx = Transaction::new_signed_with_payer(
-        &[create_ata_ix, mint_ix],
-        Some(&authority),
-        &[&*service.authority],
-        blockhash,
-    );
+    let tx = service
+        .authority
+        .sign_transaction(&[create_ata_ix, mint_ix], &[], blockhash)
+        .await
+        .map_err(|e| format!("signing failed: {e}"))?;
 
     let sig = service
         .rpc
@@ -169,12 +167,11 @@ pub async fn burn_tokens(
     let blockhash = Hash::from_str(&bh_response.blockhash)
         .map_err(|e| format!("invalid blockhash from cache: {e}"))?;
 
-    let tx = Transaction::new_signed_with_payer(
-        &[burn_ix],
-        Some(&authority),
-        &[&*service.authority],
-        blockhash,
-    );
+    let tx = service
+        .authority
+        .sign_transaction(&[burn_ix], &[], blockhash)
+        .await
+        .map_err(|e| format!("signing failed: {e}"))?;
 
     let sig = service
         .rpc
@@ -248,12 +245,11 @@ pub async fn freeze_account(
     let blockhash = Hash::from_str(&bh_response.blockhash)
         .map_err(|e| format!("invalid blockhash from cache: {e}"))?;
 
-    let tx = Transaction::new_signed_with_payer(
-        &[ix],
-        Some(&authority),
-        &[&*service.authority],
-        blockhash,
-    );
+    let tx = service
+        .authority
+        .sign_transaction(&[ix], &[], blockhash)
+        .await
+        .map_err(|e| format!("signing failed: {e}"))?;
 
     let sig = service
         .rpc
@@ -287,12 +283,11 @@ pub async fn thaw_account(
     let blockhash = Hash::from_str(&bh_response.blockhash)
         .map_err(|e| format!("invalid blockhash from cache: {e}"))?;
 
-    let tx = Transaction::new_signed_with_payer(
-        &[ix],
-        Some(&authority),
-        &[&*service.authority],
-        blockhash,
-    );
+    let tx = service
+        .authority
+        .sign_transaction(&[ix], &[], blockhash)
+        .await
+        .map_err(|e| format!("signing failed: {e}"))?;
 
     let sig = service
         .rpc
@@ -374,12 +369,11 @@ pub async fn harvest_fees(
     let blockhash = Hash::from_str(&bh_response.blockhash)
         .map_err(|e| format!("invalid blockhash from cache: {e}"))?;
 
-    let tx = Transaction::new_signed_with_payer(
-        &[create_treasury_ata_ix, harvest_ix],
-        Some(&authority),
-        &[&*service.authority],
-        blockhash,
-    );
+    let tx = service
+        .authority
+        .sign_transaction(&[create_treasury_ata_ix, harvest_ix], &[], blockhash)
+        .await
+        .map_err(|e| format!("signing failed: {e}"))?;
 
     let sig = service
         .rpc
@@ -409,12 +403,14 @@ mod tests {
                 "http://localhost:8899".to_string(),
             ),
         );
-        let keypair = std::sync::Arc::new(solana_sdk::signature::Keypair::new());
+        let authority = std::sync::Arc::new(crate::x402::ServerWallet::from_keypair(
+            solana_sdk::signature::Keypair::new(),
+        ));
         let cache = std::sync::Arc::new(crate::services::BlockhashCache::new(
             rpc.clone(),
             std::time::Duration::from_secs(1),
         ));
-        let service = Token22Service::new(rpc, keypair, cache);
+        let service = Token22Service::new(rpc, authority, cache);
         let mint = Pubkey::new_unique();
         let treasury = Pubkey::new_unique();
 
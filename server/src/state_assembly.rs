@@ -32,11 +32,6 @@ impl<S: Store + 'static> BuiltServices<S> {
             blockhash_cache: self.blockhash_cache.clone(),
         });
 
-        let products_state = Arc::new(ProductsAppState {
-            store: self.store.clone(),
-            product_repo: self.product_repo.clone(),
-            coupon_repo: self.coupon_repo.clone(),
-        });
         let collections_state = Arc::new(CollectionsAppState {
             store: self.store.clone(),
         });
@@ -80,6 +75,11 @@ impl<S: Store + 'static> BuiltServices<S> {
             cedros_login: self.cedros_login_client.clone(),
         });
 
+        let chat_handoff = Arc::new(services::ChatHandoffService::new(
+            app_state.store.clone(),
+            self.notifier.clone(),
+        ));
+
         let (
             admin_config_state,
             admin_subscriptions_state,
@@ -88,22 +88,56 @@ impl<S: Store + 'static> BuiltServices<S> {
             chat_state,
             storefront_state,
             admin_images_state,
+            admin_archive_state,
+            search,
         ) = build_pg_dependent_states(
             self.storage_pg_pool,
+            self.config_encryption,
+            self.config_bus,
+            self.secrets.clone(),
             stripe_client_for_admin.clone(),
             app_state.store.clone(),
             self.product_repo.clone(),
+            self.config.storage.archival.batch_size,
+            chat_handoff.clone(),
+            self.cedros_login_client.clone(),
+            self.config.shop.returns.clone(),
+            self.config.ai_pricing.clone(),
         );
 
+        let search = search.unwrap_or_else(|| {
+            Arc::new(services::SemanticSearch::new(
+                app_state.store.clone(),
+                self.product_repo.clone(),
+            ))
+        });
+        let products_state = Arc::new(ProductsAppState {
+            store: self.store.clone(),
+            product_repo: self.product_repo.clone(),
+            coupon_repo: self.coupon_repo.clone(),
+            search,
+            search_rate_limiter: handlers::admin_ai_assistant::AiRateLimiter::new(
+                handlers::products::SEARCH_EMBEDDINGS_PER_MINUTE,
+            ),
+        });
+
         let admin_dashboard_state = Arc::new(handlers::admin::AdminState {
             store: app_state.store.clone(),
             product_repo: self.product_repo.clone(),
             coupon_repo: self.coupon_repo.clone(),
             stripe_client: stripe_client_for_admin,
+            messaging: Some(self.messaging_service.clone()),
+        });
+
+        let admin_returns_state = Arc::new(handlers::admin_returns::AdminReturnsState {
+            store: app_state.store.clone(),
+            product_repo: self.product_repo.clone(),
+            policy: self.config.shop.returns.clone(),
         });
 
         let admin_chat_state = Arc::new(handlers::admin_chats::AdminChatState::new(
             app_state.store.clone(),
+            chat_handoff,
         ));
 
         let faqs_state = Arc::new(handlers::faqs::FaqsState::new(app_state.store.clone()));
@@ -114,6 +148,24 @@ impl<S: Store + 'static> BuiltServices<S> {
             cedros_login: self.cedros_login_client.clone(),
         });
 
+        let account_state = Arc::new(handlers::account::AccountState {
+            store: app_state.store.clone(),
+            products: self.product_repo.clone(),
+            cedros_login: self.cedros_login_client.clone(),
+            returns: self.config.shop.returns.clone(),
+        });
+
+        let events_state = Arc::new(handlers::admin_events::EventsState {
+            store: app_state.store.clone(),
+            notifier: self.http_notifier,
+            allow_http: self.config.logging.environment != "production",
+        });
+
+        let email_webhook_state = Arc::new(handlers::email_webhooks::EmailWebhookState {
+            store: app_state.store.clone(),
+            transports: self.email_transports,
+        });
+
         let route_prefix = self.config.server.route_prefix.clone();
 
         RouterStates {
@@ -132,16 +184,21 @@ impl<S: Store + 'static> BuiltServices<S> {
             admin_ai_state,
             admin_ai_assistant_state,
             admin_dashboard_state,
+            admin_returns_state,
             chat_state,
             admin_chat_state,
             faqs_state,
             storefront_state,
             asset_redemption_state,
+            account_state,
             token22_service: self.token22_service,
             asset_fulfillment: self.asset_fulfillment,
             admin_images_state,
+            admin_archive_state,
             sanctions_list_service: self.sanctions_list_service,
             cedros_login_client: self.cedros_login_client,
+            events_state,
+            email_webhook_state,
         }
     }
 }
@@ -154,22 +211,51 @@ type PgDependentStates = (
     Option<Arc<handlers::chat::ChatState>>,
     Option<Arc<handlers::storefront::StorefrontState>>,
     Option<Arc<handlers::admin_images::ImageUploadState>>,
+    Option<Arc<handlers::admin_archive::ArchiveState>>,
+    Option<Arc<services::SemanticSearch>>,
 );
 
 /// Build states that require a PostgreSQL pool (config, AI, chat, etc.).
 ///
-/// Returns `None` variants for all states when no pool is available.
+/// Returns `None` variants for all states when no pool is available; product
+/// search is then keyword-only.
 fn build_pg_dependent_states<S: Store + 'static>(
     storage_pg_pool: Option<sqlx::PgPool>,
+    config_encryption: Option<Arc<crate::config::ConfigEncryption>>,
+    config_bus: Option<Arc<crate::config::ConfigBus>>,
+    secrets: Option<Arc<crate::config::SecretStore>>,
     stripe_client: Option<Arc<crate::services::StripeClient>>,
     store: Arc<S>,
     product_repo: Arc<dyn crate::repositories::ProductRepository>,
+    archive_batch_size: i64,
+    chat_handoff: Arc<services::ChatHandoffService>,
+    cedros_login: Option<Arc<services::CedrosLoginClient>>,
+    returns: crate::config::ShopReturnsConfig,
+    ai_pricing: crate::config::AiPricingConfig,
 ) -> PgDependentStates {
     match storage_pg_pool {
         Some(pool) => {
-            let repo = Arc::new(crate::config::PostgresConfigRepository::new(pool));
-            let config_state =
-                Arc::new(handlers::admin_config::AdminConfigState { repo: repo.clone() });
+            let repo = Arc::new(
+                crate::config::PostgresConfigRepository::with_optional_encryption(
+                    pool,
+                    config_encryption,
+                ),
+            );
+            let usage = Arc::new(services::ai::AiUsageTracker::new(
+                store.clone() as Arc<dyn Store>,
+                services::ai::AiPricing::new(&ai_pricing),
+            ));
+            let ai_service = || {
+                let service = services::AiService::new().with_usage(usage.clone());
+                match secrets.clone() {
+                    Some(secrets) => service.with_secrets(secrets),
+                    None => service,
+                }
+            };
+            let config_state = Arc::new(handlers::admin_config::AdminConfigState {
+                repo: repo.clone(),
+                config_bus,
+            });
             let subscriptions_state =
                 Arc::new(handlers::admin_subscriptions::AdminSubscriptionsState {
                     config_repo: repo.clone(),
@@ -180,29 +266,44 @@ fn build_pg_dependent_states<S: Store + 'static>(
                 repo: repo.clone(),
                 store: store.clone(),
             });
-            let ai_assistant_state =
-                Arc::new(handlers::admin_ai_assistant::AdminAiAssistantState {
-                    repo: repo.clone(),
-                    store: store.clone(),
-                    product_repo: product_repo.clone(),
-                    ai_service: services::AiService::new(),
-                    rate_limiter: handlers::admin_ai_assistant::AiRateLimiter::default(),
-                    cache: handlers::admin_ai_assistant::AiResponseCache::default(),
-                });
-            let ai_service = Arc::new(services::AiService::new());
+            let assistant_ai_service = ai_service();
+            let ai_service = Arc::new(ai_service());
             let storefront_state =
                 Arc::new(handlers::storefront::StorefrontState { repo: repo.clone() });
             let image_service = Arc::new(services::ImageStorageService::new(repo.clone()));
+            let archive_state = Arc::new(handlers::admin_archive::ArchiveState {
+                archive: Arc::new(services::ColdArchiveService::new(
+                    store.clone() as Arc<dyn Store>,
+                    image_service.clone(),
+                    archive_batch_size,
+                )),
+            });
             let images_state = Arc::new(handlers::admin_images::ImageUploadState {
                 image_service,
             });
             let chat_state = Arc::new(handlers::chat::ChatState::new(
-                store,
-                repo,
-                product_repo,
+                store.clone(),
+                repo.clone(),
+                product_repo.clone(),
                 ai_service,
                 handlers::admin_ai_assistant::AiRateLimiter::default(),
+                chat_handoff,
+                cedros_login,
+                returns,
             ));
+            // One search service (and embedding client) shared by chat, the
+            // admin assistant and the public search endpoint
+            let search = chat_state.search.clone();
+            let ai_assistant_state =
+                Arc::new(handlers::admin_ai_assistant::AdminAiAssistantState {
+                    repo,
+                    store,
+                    product_repo,
+                    ai_service: assistant_ai_service,
+                    rate_limiter: handlers::admin_ai_assistant::AiRateLimiter::default(),
+                    cache: handlers::admin_ai_assistant::AiResponseCache::default(),
+                    search: search.clone(),
+                });
             (
                 Some(config_state),
                 Some(subscriptions_state),
@@ -211,8 +312,10 @@ fn build_pg_dependent_states<S: Store + 'static>(
                 Some(chat_state),
                 Some(storefront_state),
                 Some(images_state),
+                Some(archive_state),
+                Some(search),
             )
         }
-        None => (None, None, None, None, None, None, None),
+        None => (None, None, None, None, None, None, None, None, None),
     }
 }
@@ -10,16 +10,22 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 
 use crate::models::compliance::{ComplianceAction, TokenHolder};
-use crate::models::StripeRefundRequest;
+use crate::models::ProductImportJob;
 use crate::models::{
     AdminAuditEntry, CartQuote, ChatMessage, ChatSession, Collection, Customer, DisputeRecord, Faq,
     Fulfillment, GiftCard, GiftCardRedemption, InventoryAdjustment, InventoryReservation, Order,
     OrderHistoryEntry, PaymentTransaction, RefundQuote, ReturnRequest, ShippingProfile,
     ShippingRate, Subscription, SubscriptionStatus, TaxRate, TenantToken22Mint,
 };
+use crate::models::{Affiliate, AffiliateCommission};
+use crate::models::{JournalEntry, JournalSource, TrialBalanceRow};
+use crate::models::{PriceSchedule, PriceScheduleStatus};
+use crate::models::{StripeApplicationFee, StripeConnectAccount, StripeRefundRequest};
 use crate::storage::{
-    AdminNonce, AdminStats, CreditsHold, DlqWebhook, IdempotencyResponse, PendingEmail,
-    PendingWebhook, Purchase, StorageResult, Store, WebhookStatus,
+    AdminNonce, AdminStats, AiUsageRecord, ArchivableRecords, ArchivePurge, ArchivedPaymentRef,
+    CreditsHold, DlqWebhook, EmailSuppression, EmailTemplate, EmbeddingEntity, EmbeddingJob,
+    EmbeddingMatch, EmbeddingRecord, EventLogEntry, EventLogQuery, IdempotencyResponse,
+    PendingEmail, PendingWebhook, Purchase, StorageResult, Store, WebhookStatus,
 };
 use crate::ttl_cache::{CacheStats, TtlCache};
 
@@ -293,6 +299,15 @@ impl<S: Store + 'static> Store for CachedStore<S> {
         self.inner.list_pending_refunds(tenant_id, limit).await
     }
 
+    async fn list_processed_refunds(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<RefundQuote>> {
+        self.inner.list_processed_refunds(tenant_id, from, to).await
+    }
+
     async fn count_pending_refunds(&self, tenant_id: &str) -> StorageResult<i64> {
         self.inner.count_pending_refunds(tenant_id).await
     }
@@ -353,6 +368,17 @@ impl<S: Store + 'static> Store for CachedStore<S> {
             .await
     }
 
+    async fn list_processed_stripe_refund_requests(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<StripeRefundRequest>> {
+        self.inner
+            .list_processed_stripe_refund_requests(tenant_id, from, to)
+            .await
+    }
+
     async fn get_pending_stripe_refund_request_by_original_purchase_id(
         &self,
         tenant_id: &str,
@@ -376,6 +402,141 @@ impl<S: Store + 'static> Store for CachedStore<S> {
             .await
     }
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Stripe Connect
+    // ─────────────────────────────────────────────────────────────────────────
+
+    async fn upsert_stripe_connect_account(
+        &self,
+        account: StripeConnectAccount,
+    ) -> StorageResult<()> {
+        self.inner.upsert_stripe_connect_account(account).await
+    }
+
+    async fn get_stripe_connect_account(
+        &self,
+        tenant_id: &str,
+    ) -> StorageResult<Option<StripeConnectAccount>> {
+        self.inner.get_stripe_connect_account(tenant_id).await
+    }
+
+    async fn find_stripe_connect_account(
+        &self,
+        account_id: &str,
+    ) -> StorageResult<Option<StripeConnectAccount>> {
+        self.inner.find_stripe_connect_account(account_id).await
+    }
+
+    async fn upsert_stripe_application_fee(&self, fee: StripeApplicationFee) -> StorageResult<()> {
+        self.inner.upsert_stripe_application_fee(fee).await
+    }
+
+    async fn list_stripe_application_fees(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<StripeApplicationFee>> {
+        self.inner
+            .list_stripe_application_fees(tenant_id, from, to)
+            .await
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Affiliates
+    // ─────────────────────────────────────────────────────────────────────────
+
+    async fn upsert_affiliate(&self, affiliate: Affiliate) -> StorageResult<()> {
+        self.inner.upsert_affiliate(affiliate).await
+    }
+
+    async fn get_affiliate(&self, tenant_id: &str, id: &str) -> StorageResult<Option<Affiliate>> {
+        self.inner.get_affiliate(tenant_id, id).await
+    }
+
+    async fn get_affiliate_by_code(
+        &self,
+        tenant_id: &str,
+        code: &str,
+    ) -> StorageResult<Option<Affiliate>> {
+        self.inner.get_affiliate_by_code(tenant_id, code).await
+    }
+
+    async fn list_affiliates(
+        &self,
+        tenant_id: &str,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<Affiliate>> {
+        self.inner.list_affiliates(tenant_id, limit, offset).await
+    }
+
+    async fn record_affiliate_commission(&self, entry: AffiliateCommission) -> StorageResult<bool> {
+        self.inner.record_affiliate_commission(entry).await
+    }
+
+    async fn list_affiliate_commissions(
+        &self,
+        tenant_id: &str,
+        affiliate_id: Option<&str>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<AffiliateCommission>> {
+        self.inner
+            .list_affiliate_commissions(tenant_id, affiliate_id, from, to)
+            .await
+    }
+
+    async fn list_affiliate_commissions_for_purchase(
+        &self,
+        tenant_id: &str,
+        purchase_id: &str,
+    ) -> StorageResult<Vec<AffiliateCommission>> {
+        self.inner
+            .list_affiliate_commissions_for_purchase(tenant_id, purchase_id)
+            .await
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Ledger
+    // ─────────────────────────────────────────────────────────────────────────
+
+    async fn append_journal_entry(&self, entry: JournalEntry) -> StorageResult<bool> {
+        self.inner.append_journal_entry(entry).await
+    }
+
+    async fn list_journal_entries(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<JournalEntry>> {
+        self.inner
+            .list_journal_entries(tenant_id, from, to, limit, offset)
+            .await
+    }
+
+    async fn list_journal_entries_for_source(
+        &self,
+        tenant_id: &str,
+        source: JournalSource,
+        source_id: &str,
+    ) -> StorageResult<Vec<JournalEntry>> {
+        self.inner
+            .list_journal_entries_for_source(tenant_id, source, source_id)
+            .await
+    }
+
+    async fn trial_balance(
+        &self,
+        tenant_id: &str,
+        as_of: DateTime<Utc>,
+    ) -> StorageResult<Vec<TrialBalanceRow>> {
+        self.inner.trial_balance(tenant_id, as_of).await
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Orders
     // ─────────────────────────────────────────────────────────────────────────
@@ -388,6 +549,16 @@ impl<S: Store + 'static> Store for CachedStore<S> {
         self.inner.get_order(tenant_id, order_id).await
     }
 
+    async fn get_order_by_purchase_id(
+        &self,
+        tenant_id: &str,
+        purchase_id: &str,
+    ) -> StorageResult<Option<Order>> {
+        self.inner
+            .get_order_by_purchase_id(tenant_id, purchase_id)
+            .await
+    }
+
     async fn list_orders(
         &self,
         tenant_id: &str,
@@ -397,6 +568,18 @@ impl<S: Store + 'static> Store for CachedStore<S> {
         self.inner.list_orders(tenant_id, limit, offset).await
     }
 
+    async fn list_orders_by_user_id(
+        &self,
+        tenant_id: &str,
+        user_id: &str,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<Order>> {
+        self.inner
+            .list_orders_by_user_id(tenant_id, user_id, limit, offset)
+            .await
+    }
+
     async fn list_orders_filtered(
         &self,
         tenant_id: &str,
@@ -536,6 +719,20 @@ impl<S: Store + 'static> Store for CachedStore<S> {
             .await
     }
 
+    async fn update_return_request(&self, request: ReturnRequest) -> StorageResult<()> {
+        self.inner.update_return_request(request).await
+    }
+
+    async fn update_return_request_if_status(
+        &self,
+        request: ReturnRequest,
+        expected_status: &str,
+    ) -> StorageResult<bool> {
+        self.inner
+            .update_return_request_if_status(request, expected_status)
+            .await
+    }
+
     async fn get_return_request(
         &self,
         tenant_id: &str,
@@ -1164,7 +1361,7 @@ impl<S: Store + 'static> Store for CachedStore<S> {
         self.inner.delete_credits_hold(tenant_id, hold_id).await
     }
 
-    async fn cleanup_expired_credits_holds(&self) -> StorageResult<u64> {
+    async fn cleanup_expired_credits_holds(&self) -> StorageResult<Vec<CreditsHold>> {
         self.inner.cleanup_expired_credits_holds().await
     }
 
@@ -1195,6 +1392,33 @@ impl<S: Store + 'static> Store for CachedStore<S> {
         self.inner.archive_old_payments(older_than).await
     }
 
+    async fn list_archivable_records(
+        &self,
+        tenant_id: &str,
+        older_than: DateTime<Utc>,
+        limit: i64,
+    ) -> StorageResult<ArchivableRecords> {
+        self.inner
+            .list_archivable_records(tenant_id, older_than, limit)
+            .await
+    }
+
+    async fn purge_archived_records(
+        &self,
+        tenant_id: &str,
+        purge: &ArchivePurge,
+    ) -> StorageResult<u64> {
+        self.inner.purge_archived_records(tenant_id, purge).await
+    }
+
+    async fn find_archived_payment(
+        &self,
+        tenant_id: &str,
+        signature: &str,
+    ) -> StorageResult<Option<ArchivedPaymentRef>> {
+        self.inner.find_archived_payment(tenant_id, signature).await
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Admin nonces - cached
     // ─────────────────────────────────────────────────────────────────────────
@@ -1307,6 +1531,26 @@ impl<S: Store + 'static> Store for CachedStore<S> {
         self.inner.cleanup_old_webhooks(retention_days).await
     }
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Event log - not cached
+    // ─────────────────────────────────────────────────────────────────────────
+
+    async fn append_event(&self, entry: EventLogEntry) -> StorageResult<i64> {
+        self.inner.append_event(entry).await
+    }
+
+    async fn list_events(
+        &self,
+        tenant_id: &str,
+        query: &EventLogQuery,
+    ) -> StorageResult<Vec<EventLogEntry>> {
+        self.inner.list_events(tenant_id, query).await
+    }
+
+    async fn cleanup_old_events(&self, older_than: DateTime<Utc>) -> StorageResult<u64> {
+        self.inner.cleanup_old_events(older_than).await
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Email Queue - not cached
     // ─────────────────────────────────────────────────────────────────────────
@@ -1323,8 +1567,15 @@ impl<S: Store + 'static> Store for CachedStore<S> {
         self.inner.mark_email_processing(email_id).await
     }
 
-    async fn mark_email_success(&self, email_id: &str) -> StorageResult<()> {
-        self.inner.mark_email_success(email_id).await
+    async fn mark_email_success(
+        &self,
+        email_id: &str,
+        provider: &str,
+        provider_message_id: Option<&str>,
+    ) -> StorageResult<()> {
+        self.inner
+            .mark_email_success(email_id, provider, provider_message_id)
+            .await
     }
 
     async fn mark_email_retry(
@@ -1342,6 +1593,21 @@ impl<S: Store + 'static> Store for CachedStore<S> {
         self.inner.mark_email_failed(email_id, error).await
     }
 
+    async fn mark_email_suppressed(&self, email_id: &str, reason: &str) -> StorageResult<()> {
+        self.inner.mark_email_suppressed(email_id, reason).await
+    }
+
+    async fn mark_email_bounced(
+        &self,
+        tenant_id: &str,
+        provider_message_id: &str,
+        reason: &str,
+    ) -> StorageResult<bool> {
+        self.inner
+            .mark_email_bounced(tenant_id, provider_message_id, reason)
+            .await
+    }
+
     async fn get_email(&self, email_id: &str) -> StorageResult<Option<PendingEmail>> {
         self.inner.get_email(email_id).await
     }
@@ -1350,6 +1616,69 @@ impl<S: Store + 'static> Store for CachedStore<S> {
         self.inner.cleanup_old_emails(retention_days).await
     }
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Email templates - not cached (edits must apply to the next email)
+    // ─────────────────────────────────────────────────────────────────────────
+
+    async fn upsert_email_template(&self, template: EmailTemplate) -> StorageResult<()> {
+        self.inner.upsert_email_template(template).await
+    }
+
+    async fn get_email_template(
+        &self,
+        tenant_id: &str,
+        kind: &str,
+        locale: &str,
+    ) -> StorageResult<Option<EmailTemplate>> {
+        self.inner.get_email_template(tenant_id, kind, locale).await
+    }
+
+    async fn list_email_templates(&self, tenant_id: &str) -> StorageResult<Vec<EmailTemplate>> {
+        self.inner.list_email_templates(tenant_id).await
+    }
+
+    async fn delete_email_template(
+        &self,
+        tenant_id: &str,
+        kind: &str,
+        locale: &str,
+    ) -> StorageResult<bool> {
+        self.inner
+            .delete_email_template(tenant_id, kind, locale)
+            .await
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Email suppressions - not cached (the worker must see new bounces)
+    // ─────────────────────────────────────────────────────────────────────────
+
+    async fn upsert_email_suppression(&self, suppression: EmailSuppression) -> StorageResult<()> {
+        self.inner.upsert_email_suppression(suppression).await
+    }
+
+    async fn get_email_suppression(
+        &self,
+        tenant_id: &str,
+        email: &str,
+    ) -> StorageResult<Option<EmailSuppression>> {
+        self.inner.get_email_suppression(tenant_id, email).await
+    }
+
+    async fn list_email_suppressions(
+        &self,
+        tenant_id: &str,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<EmailSuppression>> {
+        self.inner
+            .list_email_suppressions(tenant_id, limit, offset)
+            .await
+    }
+
+    async fn delete_email_suppression(&self, tenant_id: &str, email: &str) -> StorageResult<bool> {
+        self.inner.delete_email_suppression(tenant_id, email).await
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Idempotency - not cached
     // ─────────────────────────────────────────────────────────────────────────
@@ -1492,6 +1821,16 @@ impl<S: Store + 'static> Store for CachedStore<S> {
             .await
     }
 
+    async fn get_subscriptions_by_user_id(
+        &self,
+        tenant_id: &str,
+        user_id: &str,
+    ) -> StorageResult<Vec<Subscription>> {
+        self.inner
+            .get_subscriptions_by_user_id(tenant_id, user_id)
+            .await
+    }
+
     async fn get_subscription_by_stripe_id(
         &self,
         tenant_id: &str,
@@ -1755,6 +2094,53 @@ impl<S: Store + 'static> Store for CachedStore<S> {
             .await
     }
 
+    async fn request_chat_handoff(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        reason: Option<&str>,
+        requested_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        self.inner
+            .request_chat_handoff(tenant_id, session_id, reason, requested_at)
+            .await
+    }
+
+    async fn claim_chat_session(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        agent_id: &str,
+        claimed_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        self.inner
+            .claim_chat_session(tenant_id, session_id, agent_id, claimed_at)
+            .await
+    }
+
+    async fn release_chat_session(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        updated_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        self.inner
+            .release_chat_session(tenant_id, session_id, updated_at)
+            .await
+    }
+
+    async fn bind_chat_session_customer(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        customer_id: &str,
+        updated_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        self.inner
+            .bind_chat_session_customer(tenant_id, session_id, customer_id, updated_at)
+            .await
+    }
+
     // ==================== Chat Messages (no caching) ====================
 
     async fn create_chat_message(&self, message: ChatMessage) -> StorageResult<()> {
@@ -1773,6 +2159,18 @@ impl<S: Store + 'static> Store for CachedStore<S> {
             .await
     }
 
+    async fn list_chat_messages_since(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        since: DateTime<Utc>,
+        limit: i32,
+    ) -> StorageResult<Vec<ChatMessage>> {
+        self.inner
+            .list_chat_messages_since(tenant_id, session_id, since, limit)
+            .await
+    }
+
     // ==================== FAQs (no caching) ====================
 
     async fn create_faq(&self, faq: Faq) -> StorageResult<()> {
@@ -1821,6 +2219,137 @@ impl<S: Store + 'static> Store for CachedStore<S> {
         self.inner.list_public_faqs(tenant_id, limit, offset).await
     }
 
+    // ─── Embeddings (pass-through, no caching) ────────────────────────────
+    async fn upsert_embedding(&self, record: EmbeddingRecord) -> StorageResult<()> {
+        self.inner.upsert_embedding(record).await
+    }
+
+    async fn list_source_embeddings(
+        &self,
+        tenant_id: &str,
+        source_id: &str,
+        entity_types: &[EmbeddingEntity],
+    ) -> StorageResult<Vec<EmbeddingRecord>> {
+        self.inner
+            .list_source_embeddings(tenant_id, source_id, entity_types)
+            .await
+    }
+
+    async fn delete_embedding(
+        &self,
+        tenant_id: &str,
+        entity_type: EmbeddingEntity,
+        entity_id: &str,
+    ) -> StorageResult<bool> {
+        self.inner
+            .delete_embedding(tenant_id, entity_type, entity_id)
+            .await
+    }
+
+    async fn search_embeddings(
+        &self,
+        tenant_id: &str,
+        model: &str,
+        entity_types: &[EmbeddingEntity],
+        query: &[f32],
+        limit: i32,
+    ) -> StorageResult<Vec<EmbeddingMatch>> {
+        self.inner
+            .search_embeddings(tenant_id, model, entity_types, query, limit)
+            .await
+    }
+
+    async fn enqueue_embedding_job(&self, job: EmbeddingJob) -> StorageResult<()> {
+        self.inner.enqueue_embedding_job(job).await
+    }
+
+    async fn claim_embedding_jobs(
+        &self,
+        now: DateTime<Utc>,
+        limit: i32,
+    ) -> StorageResult<Vec<EmbeddingJob>> {
+        self.inner.claim_embedding_jobs(now, limit).await
+    }
+
+    // ─── AI usage (pass-through, no caching) ──────────────────────────────
+    async fn add_ai_usage(&self, usage: AiUsageRecord) -> StorageResult<()> {
+        self.inner.add_ai_usage(usage).await
+    }
+
+    async fn list_ai_usage(
+        &self,
+        tenant_id: &str,
+        period: &str,
+    ) -> StorageResult<Vec<AiUsageRecord>> {
+        self.inner.list_ai_usage(tenant_id, period).await
+    }
+
+    // ─── Bulk product import jobs ─────────────────────────────────────────
+
+    async fn save_product_import_job(&self, job: ProductImportJob) -> StorageResult<()> {
+        self.inner.save_product_import_job(job).await
+    }
+
+    async fn fail_stale_product_import_jobs(
+        &self,
+        updated_before: DateTime<Utc>,
+        error: &str,
+    ) -> StorageResult<u64> {
+        self.inner
+            .fail_stale_product_import_jobs(updated_before, error)
+            .await
+    }
+
+    async fn get_product_import_job(
+        &self,
+        tenant_id: &str,
+        job_id: &str,
+    ) -> StorageResult<Option<ProductImportJob>> {
+        self.inner.get_product_import_job(tenant_id, job_id).await
+    }
+
+    // ─── Scheduled price changes ──────────────────────────────────────────
+
+    async fn save_price_schedule(&self, schedule: PriceSchedule) -> StorageResult<()> {
+        self.inner.save_price_schedule(schedule).await
+    }
+
+    async fn update_price_schedule_if_status(
+        &self,
+        schedule: PriceSchedule,
+        expected: PriceScheduleStatus,
+    ) -> StorageResult<bool> {
+        self.inner
+            .update_price_schedule_if_status(schedule, expected)
+            .await
+    }
+
+    async fn get_price_schedule(
+        &self,
+        tenant_id: &str,
+        schedule_id: &str,
+    ) -> StorageResult<Option<PriceSchedule>> {
+        self.inner.get_price_schedule(tenant_id, schedule_id).await
+    }
+
+    async fn list_product_price_schedules(
+        &self,
+        tenant_id: &str,
+        product_id: &str,
+    ) -> StorageResult<Vec<PriceSchedule>> {
+        self.inner
+            .list_product_price_schedules(tenant_id, product_id)
+            .await
+    }
+
+    async fn list_due_price_schedules(
+        &self,
+        now: DateTime<Utc>,
+        limit: i32,
+    ) -> StorageResult<Vec<PriceSchedule>> {
+        self.inner.list_due_price_schedules(now, limit).await
+    }
+
     // ─── Compliance (pass-through, no caching) ────────────────────────────
     async fn record_token_holder(&self, holder: TokenHolder) -> StorageResult<()> {
         self.inner.record_token_holder(holder).await
@@ -0,0 +1,1306 @@
+//! Shared storage behaviour suite
+//!
+//! Backend-agnostic checks of the `Store` contract. Each backend's tests call
+//! [`run_all`] with a factory for fresh stores, so `InMemoryStore`,
+//! `SqliteStore` and `PostgresStore` are held to the same behaviour.
+//!
+//! Products live outside `Store`, so the factory must seed [`SEED_PRODUCT`]
+//! for [`SEED_TENANT`] with [`SEED_QUANTITY`] units of tracked inventory.
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use chrono::{Duration as ChronoDuration, Utc};
+
+use super::{
+    AiUsageRecord, ArchivePurge, ArchivedPaymentRef, CreditsHold, EmailStatus, EmailSuppression,
+    EmailTemplate, EmbeddingEntity, EmbeddingJob, EmbeddingRecord, EventLogEntry, EventLogQuery,
+    PendingEmail, PendingWebhook, StorageError, Store, SuppressionReason, WebhookStatus,
+};
+use crate::models::{
+    get_asset, CartQuote, ChatMessage, ChatSession, GiftCard, InventoryReservation, Money, Order,
+    PaymentTransaction, PriceSchedule, PriceScheduleStatus, PriceSnapshot, ProductImportFormat,
+    ProductImportJob, ProductImportRowError, ProductImportStatus, RefundQuote, ReturnRequest,
+};
+
+pub(crate) const SEED_TENANT: &str = "tenant-a";
+pub(crate) const SEED_PRODUCT: &str = "prod-1";
+pub(crate) const SEED_QUANTITY: i32 = 10;
+
+/// Run every check, each against a fresh store from `make_store`.
+pub(crate) async fn run_all<S, F, Fut>(make_store: F)
+where
+    S: Store,
+    F: Fn() -> Fut,
+    Fut: Future<Output = S>,
+{
+    cart_quote_tenant_isolation(&make_store().await).await;
+    webhook_retry_requeues_pending(&make_store().await).await;
+    dequeue_emails_claims_processing_state_once(&make_store().await).await;
+    enqueue_webhook_is_idempotent_on_duplicate_id(&make_store().await).await;
+    inventory_reservations_convert(&make_store().await).await;
+    inventory_reservations_cleanup_expired(&make_store().await).await;
+    list_pending_refunds_respects_limit(&make_store().await).await;
+    store_credits_hold_conflicts_on_binding_change(&make_store().await).await;
+    store_credits_hold_refreshes_expiry_on_matching_binding(&make_store().await).await;
+    adjust_inventory_atomic_rejects_oversell(&make_store().await).await;
+    gift_card_balance_deduction_is_guarded(&make_store().await).await;
+    try_store_order_is_idempotent(&make_store().await).await;
+    archive_purge_indexes_payments(&make_store().await).await;
+    event_log_pages_by_cursor(&make_store().await).await;
+    email_templates_upsert_per_locale(&make_store().await).await;
+    email_delivery_status_and_suppressions(&make_store().await).await;
+    chat_handoff_transitions(&make_store().await).await;
+    embeddings_search_by_model_and_type(&make_store().await).await;
+    embedding_jobs_claim_once(&make_store().await).await;
+    ai_usage_accumulates_per_task_and_model(&make_store().await).await;
+    product_import_jobs_save_progress(&make_store().await).await;
+    price_schedules_due_and_guarded_updates(&make_store().await).await;
+    return_request_status_claim_is_exclusive(&make_store().await).await;
+}
+
+async fn cart_quote_tenant_isolation(store: &dyn Store) {
+    let cart_id = "shared-cart";
+
+    let cart_a = CartQuote {
+        id: cart_id.to_string(),
+        tenant_id: "tenant-a".to_string(),
+        ..Default::default()
+    };
+    store.store_cart_quote(cart_a.clone()).await.unwrap();
+
+    let cart_b = CartQuote {
+        id: cart_id.to_string(),
+        tenant_id: "tenant-b".to_string(),
+        ..Default::default()
+    };
+    store.store_cart_quote(cart_b.clone()).await.unwrap();
+
+    let fetched_a = store
+        .get_cart_quote("tenant-a", cart_id)
+        .await
+        .unwrap()
+        .expect("tenant-a cart");
+    let fetched_b = store
+        .get_cart_quote("tenant-b", cart_id)
+        .await
+        .unwrap()
+        .expect("tenant-b cart");
+
+    assert_eq!(fetched_a.tenant_id, "tenant-a");
+    assert_eq!(fetched_b.tenant_id, "tenant-b");
+}
+
+async fn webhook_retry_requeues_pending(store: &dyn Store) {
+    let webhook = PendingWebhook {
+        id: "wh-1".to_string(),
+        tenant_id: "default".to_string(),
+        url: "https://example.com".to_string(),
+        payload: serde_json::json!({ "ok": true }),
+        payload_bytes: Vec::new(),
+        headers: std::collections::HashMap::new(),
+        event_type: "payment.succeeded".to_string(),
+        status: WebhookStatus::Failed,
+        attempts: 1,
+        max_attempts: 3,
+        last_error: Some("failed".to_string()),
+        last_attempt_at: Some(Utc::now()),
+        next_attempt_at: None,
+        created_at: Utc::now(),
+        completed_at: None,
+    };
+
+    store.enqueue_webhook(webhook).await.unwrap();
+
+    let retry_at = Utc::now() - chrono::Duration::seconds(1);
+    store
+        .mark_webhook_retry("wh-1", "retry", retry_at)
+        .await
+        .unwrap();
+
+    let mut items = store.dequeue_webhooks(1).await.unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items.remove(0).status, WebhookStatus::Processing);
+}
+
+async fn dequeue_emails_claims_processing_state_once(store: &dyn Store) {
+    let now = Utc::now();
+    store
+        .enqueue_email(PendingEmail {
+            id: "email-1".to_string(),
+            tenant_id: "default".to_string(),
+            to_email: "user@example.com".to_string(),
+            from_email: "noreply@example.com".to_string(),
+            from_name: "Cedros".to_string(),
+            subject: "Hello".to_string(),
+            body_text: "Body".to_string(),
+            body_html: None,
+            status: EmailStatus::Pending,
+            attempts: 0,
+            max_attempts: 3,
+            last_error: None,
+            last_attempt_at: None,
+            next_attempt_at: Some(now - ChronoDuration::seconds(1)),
+            created_at: now,
+            completed_at: None,
+            traceparent: None,
+            provider: None,
+            provider_message_id: None,
+        })
+        .await
+        .unwrap();
+
+    let first = store.dequeue_emails(1).await.unwrap();
+    assert_eq!(first.len(), 1);
+    assert_eq!(first[0].status, EmailStatus::Pending);
+    assert!(first[0].last_attempt_at.is_some());
+
+    let second = store.dequeue_emails(1).await.unwrap();
+    assert!(second.is_empty());
+}
+
+async fn enqueue_webhook_is_idempotent_on_duplicate_id(store: &dyn Store) {
+    let now = Utc::now();
+
+    let webhook = PendingWebhook {
+        id: "wh-dup".to_string(),
+        tenant_id: "default".to_string(),
+        url: "https://example.com".to_string(),
+        payload: serde_json::json!({"ok": true}),
+        payload_bytes: Vec::new(),
+        headers: std::collections::HashMap::new(),
+        event_type: "payment.succeeded".to_string(),
+        status: WebhookStatus::Pending,
+        attempts: 0,
+        max_attempts: 3,
+        last_error: None,
+        last_attempt_at: None,
+        next_attempt_at: Some(now),
+        created_at: now,
+        completed_at: None,
+    };
+
+    store.enqueue_webhook(webhook.clone()).await.unwrap();
+
+    let mut replacement = webhook;
+    replacement.attempts = 99;
+    replacement.last_error = Some("overwrite".to_string());
+    store.enqueue_webhook(replacement).await.unwrap();
+
+    let stored = store.get_webhook("wh-dup").await.unwrap().expect("webhook");
+    assert_eq!(stored.attempts, 0);
+    assert_eq!(stored.last_error, None);
+}
+
+async fn inventory_reservations_convert(store: &dyn Store) {
+    let now = Utc::now();
+    let reservation = InventoryReservation {
+        id: "res-1".to_string(),
+        tenant_id: "tenant-a".to_string(),
+        product_id: "prod-1".to_string(),
+        variant_id: None,
+        quantity: 2,
+        expires_at: now + ChronoDuration::minutes(5),
+        cart_id: Some("cart-1".to_string()),
+        status: "active".to_string(),
+        created_at: now,
+    };
+
+    store.reserve_inventory(reservation).await.unwrap();
+    let active = store
+        .list_active_reservations_for_cart("tenant-a", "cart-1")
+        .await
+        .unwrap();
+    assert_eq!(active.len(), 1);
+
+    let converted = store
+        .convert_inventory_reservations("tenant-a", "cart-1", now)
+        .await
+        .unwrap();
+    assert_eq!(converted, 1);
+
+    let active_after = store
+        .list_active_reservations_for_cart("tenant-a", "cart-1")
+        .await
+        .unwrap();
+    assert!(active_after.is_empty());
+}
+
+async fn inventory_reservations_cleanup_expired(store: &dyn Store) {
+    let now = Utc::now();
+    let reservation = InventoryReservation {
+        id: "res-expired".to_string(),
+        tenant_id: "tenant-a".to_string(),
+        product_id: "prod-1".to_string(),
+        variant_id: None,
+        quantity: 1,
+        expires_at: now - ChronoDuration::minutes(1),
+        cart_id: Some("cart-2".to_string()),
+        status: "active".to_string(),
+        created_at: now - ChronoDuration::minutes(2),
+    };
+
+    store.reserve_inventory(reservation).await.unwrap();
+    let cleaned = store
+        .cleanup_expired_inventory_reservations(now)
+        .await
+        .unwrap();
+    assert_eq!(cleaned, 1);
+
+    let active = store
+        .list_active_reservations_for_cart("tenant-a", "cart-2")
+        .await
+        .unwrap();
+    assert!(active.is_empty());
+}
+
+async fn list_pending_refunds_respects_limit(store: &dyn Store) {
+    let asset = crate::models::get_asset("USDC").expect("asset registered");
+    let now = Utc::now();
+
+    for idx in 0..2 {
+        let refund = RefundQuote {
+            id: format!("refund-{}", idx),
+            tenant_id: "tenant-a".to_string(),
+            original_purchase_id: "orig-1".to_string(),
+            recipient_wallet: "wallet-1".to_string(),
+            amount: crate::models::Money::new(asset.clone(), 100),
+            reason: None,
+            metadata: HashMap::new(),
+            created_at: now + ChronoDuration::seconds(idx),
+            expires_at: now + ChronoDuration::hours(1),
+            processed_by: None,
+            processed_at: None,
+            signature: None,
+        };
+        store.store_refund_quote(refund).await.unwrap();
+    }
+
+    let results = store.list_pending_refunds("tenant-a", 1).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, "refund-0");
+}
+
+async fn store_credits_hold_conflicts_on_binding_change(store: &dyn Store) {
+    let now = Utc::now();
+
+    let original = CreditsHold {
+        tenant_id: "tenant-a".to_string(),
+        hold_id: "hold-1".to_string(),
+        user_id: "user-1".to_string(),
+        resource_id: "prod-1".to_string(),
+        amount: 100,
+        amount_asset: "USDC".to_string(),
+        created_at: now,
+        expires_at: now + ChronoDuration::minutes(5),
+    };
+
+    store.store_credits_hold(original).await.unwrap();
+
+    let conflicting = CreditsHold {
+        tenant_id: "tenant-a".to_string(),
+        hold_id: "hold-1".to_string(),
+        user_id: "user-2".to_string(),
+        resource_id: "prod-1".to_string(),
+        amount: 100,
+        amount_asset: "USDC".to_string(),
+        created_at: now,
+        expires_at: now + ChronoDuration::minutes(10),
+    };
+
+    let err = store.store_credits_hold(conflicting).await.unwrap_err();
+    assert!(matches!(err, StorageError::Conflict));
+
+    let persisted = store
+        .get_credits_hold("tenant-a", "hold-1")
+        .await
+        .unwrap()
+        .expect("hold");
+    assert_eq!(persisted.user_id, "user-1");
+}
+
+async fn store_credits_hold_refreshes_expiry_on_matching_binding(store: &dyn Store) {
+    let now = Utc::now();
+
+    let original = CreditsHold {
+        tenant_id: "tenant-a".to_string(),
+        hold_id: "hold-2".to_string(),
+        user_id: "user-1".to_string(),
+        resource_id: "prod-1".to_string(),
+        amount: 100,
+        amount_asset: "USDC".to_string(),
+        created_at: now,
+        expires_at: now + ChronoDuration::minutes(5),
+    };
+
+    store.store_credits_hold(original).await.unwrap();
+
+    let refreshed_expiry = now + ChronoDuration::minutes(15);
+    let refresh = CreditsHold {
+        tenant_id: "tenant-a".to_string(),
+        hold_id: "hold-2".to_string(),
+        user_id: "user-1".to_string(),
+        resource_id: "prod-1".to_string(),
+        amount: 100,
+        amount_asset: "USDC".to_string(),
+        created_at: now,
+        expires_at: refreshed_expiry,
+    };
+
+    store.store_credits_hold(refresh).await.unwrap();
+
+    let persisted = store
+        .get_credits_hold("tenant-a", "hold-2")
+        .await
+        .unwrap()
+        .expect("hold");
+    assert_eq!(persisted.expires_at, refreshed_expiry);
+}
+
+async fn adjust_inventory_atomic_rejects_oversell(store: &dyn Store) {
+    let (before, after) = store
+        .adjust_inventory_atomic(SEED_TENANT, SEED_PRODUCT, -3)
+        .await
+        .unwrap();
+    assert_eq!((before, after), (SEED_QUANTITY, SEED_QUANTITY - 3));
+
+    let oversell = store
+        .adjust_inventory_atomic(SEED_TENANT, SEED_PRODUCT, -SEED_QUANTITY)
+        .await;
+    assert!(matches!(oversell, Err(StorageError::Validation(_))));
+
+    let missing = store
+        .adjust_inventory_atomic(SEED_TENANT, "prod-missing", 1)
+        .await;
+    assert!(matches!(missing, Err(StorageError::NotFound)));
+}
+
+async fn gift_card_balance_deduction_is_guarded(store: &dyn Store) {
+    let now = Utc::now();
+    store
+        .create_gift_card(GiftCard {
+            code: "GC-1".to_string(),
+            tenant_id: "tenant-a".to_string(),
+            initial_balance: 500,
+            balance: 500,
+            currency: "USD".to_string(),
+            active: true,
+            expires_at: None,
+            metadata: HashMap::new(),
+            created_at: now,
+            updated_at: now,
+        })
+        .await
+        .unwrap();
+
+    let remaining = store
+        .try_adjust_gift_card_balance("tenant-a", "GC-1", 200, now)
+        .await
+        .unwrap();
+    assert_eq!(remaining, Some(300));
+
+    let insufficient = store
+        .try_adjust_gift_card_balance("tenant-a", "GC-1", 301, now)
+        .await
+        .unwrap();
+    assert_eq!(insufficient, None);
+
+    // Backends differ on whether a missing card is NotFound or Ok(None);
+    // either way another tenant must not be able to debit it.
+    let other_tenant = store
+        .try_adjust_gift_card_balance("tenant-b", "GC-1", 1, now)
+        .await;
+    assert!(!matches!(other_tenant, Ok(Some(_))));
+
+    let card = store
+        .get_gift_card("tenant-a", "GC-1")
+        .await
+        .unwrap()
+        .expect("gift card");
+    assert_eq!(card.balance, 300);
+}
+
+async fn try_store_order_is_idempotent(store: &dyn Store) {
+    let now = Utc::now();
+    let order = Order {
+        id: "order-1".to_string(),
+        tenant_id: "tenant-a".to_string(),
+        source: "x402".to_string(),
+        purchase_id: "purchase-1".to_string(),
+        resource_id: "prod-1".to_string(),
+        user_id: None,
+        customer: None,
+        status: "paid".to_string(),
+        items: Vec::new(),
+        amount: 100,
+        amount_asset: "USDC".to_string(),
+        customer_email: None,
+        customer_name: None,
+        receipt_url: None,
+        shipping: None,
+        metadata: HashMap::new(),
+        created_at: now,
+        updated_at: Some(now),
+        status_updated_at: Some(now),
+    };
+
+    assert!(store.try_store_order(order.clone()).await.unwrap());
+    assert!(!store.try_store_order(order).await.unwrap());
+
+    let fetched = store
+        .get_order_by_purchase_id("tenant-a", "purchase-1")
+        .await
+        .unwrap()
+        .expect("order");
+    assert_eq!(fetched.id, "order-1");
+    assert!(store
+        .get_order("tenant-b", "order-1")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+async fn archive_purge_indexes_payments(store: &dyn Store) {
+    let now = Utc::now();
+    let asset = get_asset("USDC").expect("USDC asset registered");
+    for (signature, age_days) in [("sig-old", 90), ("sig-new", 1)] {
+        store
+            .record_payment(PaymentTransaction {
+                signature: signature.to_string(),
+                tenant_id: "tenant-a".to_string(),
+                resource_id: "prod-1".to_string(),
+                wallet: "wallet-1".to_string(),
+                user_id: None,
+                amount: Money {
+                    asset: asset.clone(),
+                    atomic: 100,
+                },
+                created_at: now - ChronoDuration::days(age_days),
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+    }
+
+    let cutoff = now - ChronoDuration::days(30);
+    let records = store
+        .list_archivable_records("tenant-a", cutoff, 10)
+        .await
+        .unwrap();
+    assert_eq!(records.payments.len(), 1);
+    assert_eq!(records.payments[0].signature, "sig-old");
+    assert!(store
+        .list_archivable_records("tenant-b", cutoff, 10)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let archived = ArchivedPaymentRef {
+        tenant_id: "tenant-a".to_string(),
+        signature: "sig-old".to_string(),
+        object_key: "tenant-a/archive/payments.jsonl.gz".to_string(),
+        archived_at: now,
+    };
+    let purge = ArchivePurge {
+        payments: vec![archived],
+        ..Default::default()
+    };
+    assert_eq!(
+        store
+            .purge_archived_records("tenant-a", &purge)
+            .await
+            .unwrap(),
+        1
+    );
+
+    assert!(store
+        .get_payment("tenant-a", "sig-old")
+        .await
+        .unwrap()
+        .is_none());
+    assert!(store
+        .get_payment("tenant-a", "sig-new")
+        .await
+        .unwrap()
+        .is_some());
+    let found = store
+        .find_archived_payment("tenant-a", "sig-old")
+        .await
+        .unwrap()
+        .expect("archived payment indexed");
+    assert_eq!(found.object_key, "tenant-a/archive/payments.jsonl.gz");
+    assert!(store
+        .find_archived_payment("tenant-b", "sig-old")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+async fn event_log_pages_by_cursor(store: &dyn Store) {
+    let now = Utc::now();
+    let events = [
+        ("tenant-a", "evt-1", "payment.succeeded", 10),
+        ("tenant-a", "evt-2", "refund.processed", 5),
+        ("tenant-b", "evt-3", "payment.succeeded", 4),
+        ("tenant-a", "evt-4", "payment.succeeded", 1),
+    ];
+    let mut sequences = Vec::new();
+    for (tenant_id, event_id, event_type, age_days) in events {
+        let sequence = store
+            .append_event(EventLogEntry {
+                sequence: 0,
+                tenant_id: tenant_id.to_string(),
+                event_id: event_id.to_string(),
+                event_type: event_type.to_string(),
+                payload: serde_json::json!({ "eventId": event_id }),
+                created_at: now - ChronoDuration::days(age_days),
+            })
+            .await
+            .unwrap();
+        sequences.push(sequence);
+    }
+    assert!(sequences.windows(2).all(|w| w[0] < w[1]));
+
+    // Re-appending the same event keeps its original position
+    let again = store
+        .append_event(EventLogEntry {
+            sequence: 0,
+            tenant_id: "tenant-a".to_string(),
+            event_id: "evt-1".to_string(),
+            event_type: "payment.succeeded".to_string(),
+            payload: serde_json::json!({ "eventId": "evt-1" }),
+            created_at: now,
+        })
+        .await
+        .unwrap();
+    assert_eq!(again, sequences[0]);
+
+    let page = |after, limit| EventLogQuery {
+        after,
+        limit,
+        ..Default::default()
+    };
+    let first = store.list_events("tenant-a", &page(None, 2)).await.unwrap();
+    let ids: Vec<&str> = first.iter().map(|e| e.event_id.as_str()).collect();
+    assert_eq!(ids, ["evt-1", "evt-2"]);
+    let rest = store
+        .list_events("tenant-a", &page(Some(first[1].sequence), 2))
+        .await
+        .unwrap();
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest[0].event_id, "evt-4");
+    assert_eq!(rest[0].payload["eventId"], "evt-4");
+
+    let filtered = store
+        .list_events(
+            "tenant-a",
+            &EventLogQuery {
+                event_type: Some("payment.succeeded".to_string()),
+                since: Some(now - ChronoDuration::days(7)),
+                until: Some(now),
+                limit: 10,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(filtered[0].event_id, "evt-4");
+
+    let removed = store
+        .cleanup_old_events(now - ChronoDuration::days(3))
+        .await
+        .unwrap();
+    assert_eq!(removed, 3);
+    let remaining = store
+        .list_events("tenant-a", &page(None, 10))
+        .await
+        .unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert!(store
+        .list_events("tenant-b", &page(None, 10))
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+async fn email_templates_upsert_per_locale(store: &dyn Store) {
+    let template = |tenant_id: &str, locale: &str, subject: &str| EmailTemplate {
+        tenant_id: tenant_id.to_string(),
+        kind: "order_confirmation".to_string(),
+        locale: locale.to_string(),
+        subject: subject.to_string(),
+        body_text: "Order {{orderId}}".to_string(),
+        body_html: None,
+        updated_at: Utc::now(),
+    };
+    store
+        .upsert_email_template(template("tenant-a", "en", "Thanks"))
+        .await
+        .unwrap();
+    store
+        .upsert_email_template(template("tenant-a", "de", "Danke"))
+        .await
+        .unwrap();
+    store
+        .upsert_email_template(template("tenant-b", "en", "Other tenant"))
+        .await
+        .unwrap();
+
+    // Upsert replaces the existing (tenant, kind, locale) row
+    store
+        .upsert_email_template(EmailTemplate {
+            body_html: Some("<p>{{orderId}}</p>".to_string()),
+            ..template("tenant-a", "en", "Thank you")
+        })
+        .await
+        .unwrap();
+    let fetched = store
+        .get_email_template("tenant-a", "order_confirmation", "en")
+        .await
+        .unwrap()
+        .expect("tenant-a en template");
+    assert_eq!(fetched.subject, "Thank you");
+    assert_eq!(fetched.body_html.as_deref(), Some("<p>{{orderId}}</p>"));
+
+    let listed = store.list_email_templates("tenant-a").await.unwrap();
+    let locales: Vec<&str> = listed.iter().map(|t| t.locale.as_str()).collect();
+    assert_eq!(locales, ["de", "en"]);
+
+    assert!(store
+        .delete_email_template("tenant-a", "order_confirmation", "de")
+        .await
+        .unwrap());
+    assert!(!store
+        .delete_email_template("tenant-a", "order_confirmation", "de")
+        .await
+        .unwrap());
+    assert!(store
+        .get_email_template("tenant-a", "order_confirmation", "de")
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        store.list_email_templates("tenant-b").await.unwrap().len(),
+        1
+    );
+}
+
+async fn email_delivery_status_and_suppressions(store: &dyn Store) {
+    let now = Utc::now();
+    for id in ["email-sent", "email-skipped"] {
+        store
+            .enqueue_email(PendingEmail {
+                id: id.to_string(),
+                tenant_id: SEED_TENANT.to_string(),
+                to_email: "user@example.com".to_string(),
+                from_email: "noreply@example.com".to_string(),
+                from_name: "Cedros".to_string(),
+                subject: "Hello".to_string(),
+                body_text: "Body".to_string(),
+                body_html: None,
+                status: EmailStatus::Pending,
+                attempts: 0,
+                max_attempts: 3,
+                last_error: None,
+                last_attempt_at: None,
+                next_attempt_at: None,
+                created_at: now,
+                completed_at: None,
+                traceparent: None,
+                provider: None,
+                provider_message_id: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    store
+        .mark_email_success("email-sent", "http", Some("msg-1"))
+        .await
+        .unwrap();
+    let sent = store.get_email("email-sent").await.unwrap().unwrap();
+    assert_eq!(sent.status, EmailStatus::Completed);
+    assert_eq!(sent.provider.as_deref(), Some("http"));
+    assert_eq!(sent.provider_message_id.as_deref(), Some("msg-1"));
+
+    // Bounces only match the owning tenant's emails
+    assert!(!store
+        .mark_email_bounced("tenant-b", "msg-1", "550 no such user")
+        .await
+        .unwrap());
+    assert!(store
+        .mark_email_bounced(SEED_TENANT, "msg-1", "550 no such user")
+        .await
+        .unwrap());
+    let bounced = store.get_email("email-sent").await.unwrap().unwrap();
+    assert_eq!(bounced.status, EmailStatus::Bounced);
+    assert_eq!(bounced.last_error.as_deref(), Some("550 no such user"));
+
+    store
+        .mark_email_suppressed("email-skipped", "recipient suppressed: bounce")
+        .await
+        .unwrap();
+    let skipped = store.get_email("email-skipped").await.unwrap().unwrap();
+    assert_eq!(skipped.status, EmailStatus::Suppressed);
+    assert!(skipped.completed_at.is_some());
+
+    for (email, reason, age) in [
+        ("a@example.com", SuppressionReason::Bounce, 2),
+        ("b@example.com", SuppressionReason::Complaint, 1),
+    ] {
+        store
+            .upsert_email_suppression(EmailSuppression {
+                tenant_id: SEED_TENANT.to_string(),
+                email: email.to_string(),
+                reason,
+                detail: None,
+                created_at: now - ChronoDuration::minutes(age),
+            })
+            .await
+            .unwrap();
+    }
+    let found = store
+        .get_email_suppression(SEED_TENANT, "b@example.com")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.reason, SuppressionReason::Complaint);
+    assert!(store
+        .get_email_suppression("tenant-b", "b@example.com")
+        .await
+        .unwrap()
+        .is_none());
+
+    let listed = store
+        .list_email_suppressions(SEED_TENANT, 10, 0)
+        .await
+        .unwrap();
+    let emails: Vec<&str> = listed.iter().map(|s| s.email.as_str()).collect();
+    assert_eq!(emails, ["b@example.com", "a@example.com"]);
+    assert_eq!(
+        store
+            .list_email_suppressions(SEED_TENANT, 1, 1)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    assert!(store
+        .delete_email_suppression(SEED_TENANT, "a@example.com")
+        .await
+        .unwrap());
+    assert!(!store
+        .delete_email_suppression(SEED_TENANT, "a@example.com")
+        .await
+        .unwrap());
+}
+
+async fn chat_handoff_transitions(store: &dyn Store) {
+    let now = Utc::now();
+    store
+        .create_chat_session(ChatSession::new(SEED_TENANT.into(), "chat-1".into()))
+        .await
+        .unwrap();
+
+    // Claiming needs a pending handoff; unknown sessions are untouched
+    assert!(!store
+        .claim_chat_session(SEED_TENANT, "chat-1", "alice", now)
+        .await
+        .unwrap());
+    assert!(!store
+        .request_chat_handoff(SEED_TENANT, "missing", None, now)
+        .await
+        .unwrap());
+
+    assert!(store
+        .request_chat_handoff(SEED_TENANT, "chat-1", Some("refund"), now)
+        .await
+        .unwrap());
+    assert!(!store
+        .request_chat_handoff(SEED_TENANT, "chat-1", None, now)
+        .await
+        .unwrap());
+    let waiting = store
+        .get_chat_session(SEED_TENANT, "chat-1")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(waiting.status, "waiting_for_agent");
+    assert_eq!(waiting.handoff_reason.as_deref(), Some("refund"));
+    assert!(waiting.handoff_requested_at.is_some());
+
+    assert!(store
+        .claim_chat_session(SEED_TENANT, "chat-1", "alice", now)
+        .await
+        .unwrap());
+    assert!(!store
+        .claim_chat_session(SEED_TENANT, "chat-1", "bob", now)
+        .await
+        .unwrap());
+    let claimed = store
+        .get_chat_session(SEED_TENANT, "chat-1")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(claimed.status, "with_agent");
+    assert_eq!(claimed.assigned_agent.as_deref(), Some("alice"));
+
+    let mut first = ChatMessage::user(
+        SEED_TENANT.into(),
+        "chat-1".into(),
+        "msg-1".into(),
+        "hello".into(),
+    );
+    first.created_at = now - ChronoDuration::seconds(10);
+    store.create_chat_message(first).await.unwrap();
+    let mut reply = ChatMessage::agent(
+        SEED_TENANT.into(),
+        "chat-1".into(),
+        "msg-2".into(),
+        "hi, Alice here".into(),
+        "alice".into(),
+    );
+    reply.created_at = now;
+    store.create_chat_message(reply).await.unwrap();
+
+    let newer = store
+        .list_chat_messages_since(SEED_TENANT, "chat-1", now - ChronoDuration::seconds(5), 10)
+        .await
+        .unwrap();
+    assert_eq!(newer.len(), 1);
+    assert_eq!(newer[0].role, "agent");
+    assert_eq!(newer[0].author_id.as_deref(), Some("alice"));
+
+    assert!(store
+        .release_chat_session(SEED_TENANT, "chat-1", now)
+        .await
+        .unwrap());
+    assert!(!store
+        .release_chat_session(SEED_TENANT, "chat-1", now)
+        .await
+        .unwrap());
+    let released = store
+        .get_chat_session(SEED_TENANT, "chat-1")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(released.status, "active");
+    assert!(released.assigned_agent.is_none());
+}
+
+fn embedding(
+    entity_type: EmbeddingEntity,
+    entity_id: &str,
+    source_id: &str,
+    v: [f32; 3],
+) -> EmbeddingRecord {
+    EmbeddingRecord {
+        tenant_id: SEED_TENANT.to_string(),
+        entity_type,
+        entity_id: entity_id.to_string(),
+        source_id: source_id.to_string(),
+        model: "embed-1".to_string(),
+        content_hash: format!("hash-{}", entity_id),
+        embedding: v.to_vec(),
+        updated_at: Utc::now(),
+    }
+}
+
+async fn embeddings_search_by_model_and_type(store: &dyn Store) {
+    use EmbeddingEntity::{Faq, Product, Variant};
+    for record in [
+        embedding(Product, "p1", "p1", [1.0, 0.0, 0.0]),
+        embedding(Variant, "p1-blue", "p1", [0.6, 0.8, 0.0]),
+        embedding(Product, "p2", "p2", [0.0, 1.0, 0.0]),
+        embedding(Faq, "f1", "f1", [1.0, 0.0, 0.0]),
+    ] {
+        store.upsert_embedding(record).await.unwrap();
+    }
+    let mut other_model = embedding(Product, "p3", "p3", [1.0, 0.0, 0.0]);
+    other_model.model = "embed-2".to_string();
+    store.upsert_embedding(other_model).await.unwrap();
+
+    let matches = store
+        .search_embeddings(
+            SEED_TENANT,
+            "embed-1",
+            &[Product, Variant],
+            &[1.0, 0.1, 0.0],
+            10,
+        )
+        .await
+        .unwrap();
+    let ids: Vec<&str> = matches.iter().map(|m| m.entity_id.as_str()).collect();
+    assert_eq!(ids, ["p1", "p1-blue", "p2"]);
+    assert_eq!(matches[1].source_id, "p1");
+    assert!(matches[0].score > 0.99 && matches[0].score <= 1.0 + f32::EPSILON);
+    assert_eq!(
+        store
+            .search_embeddings(SEED_TENANT, "embed-1", &[Product], &[1.0, 0.0, 0.0], 1)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(store
+        .search_embeddings("tenant-b", "embed-1", &[Product], &[1.0, 0.0, 0.0], 10)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // Re-embedding replaces the stored vector
+    let mut updated = embedding(Variant, "p1-blue", "p1", [0.0, 0.0, 1.0]);
+    updated.content_hash = "hash-2".to_string();
+    store.upsert_embedding(updated).await.unwrap();
+    let source = store
+        .list_source_embeddings(SEED_TENANT, "p1", &[Product, Variant])
+        .await
+        .unwrap();
+    assert_eq!(source.len(), 2);
+    let variant = source.iter().find(|r| r.entity_type == Variant).unwrap();
+    assert_eq!(variant.content_hash, "hash-2");
+    assert_eq!(variant.embedding, vec![0.0, 0.0, 1.0]);
+
+    assert!(store
+        .delete_embedding(SEED_TENANT, Variant, "p1-blue")
+        .await
+        .unwrap());
+    assert!(!store
+        .delete_embedding(SEED_TENANT, Variant, "p1-blue")
+        .await
+        .unwrap());
+}
+
+async fn embedding_jobs_claim_once(store: &dyn Store) {
+    let now = Utc::now();
+    let mut first = EmbeddingJob::new(SEED_TENANT, EmbeddingEntity::Product, "p1");
+    first.available_at = now - ChronoDuration::minutes(2);
+    let mut second = EmbeddingJob::new(SEED_TENANT, EmbeddingEntity::Faq, "f1");
+    second.available_at = now - ChronoDuration::minutes(1);
+    let mut later = EmbeddingJob::new(SEED_TENANT, EmbeddingEntity::Product, "p2");
+    later.available_at = now + ChronoDuration::minutes(5);
+    for job in [second.clone(), first.clone(), later] {
+        store.enqueue_embedding_job(job).await.unwrap();
+    }
+    // Re-enqueueing a queued source replaces its job rather than duplicating it
+    store.enqueue_embedding_job(first.clone()).await.unwrap();
+
+    let claimed = store.claim_embedding_jobs(now, 10).await.unwrap();
+    let ids: Vec<&str> = claimed.iter().map(|j| j.source_id.as_str()).collect();
+    assert_eq!(ids, ["p1", "f1"]);
+    assert_eq!(claimed[1].source_type, EmbeddingEntity::Faq);
+    assert!(store
+        .claim_embedding_jobs(now, 10)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let claimed = store
+        .claim_embedding_jobs(now + ChronoDuration::minutes(10), 10)
+        .await
+        .unwrap();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].source_id, "p2");
+}
+
+fn ai_usage(period: &str, task: &str, model: &str, input: i64, output: i64) -> AiUsageRecord {
+    AiUsageRecord {
+        tenant_id: SEED_TENANT.to_string(),
+        period: period.to_string(),
+        task: task.to_string(),
+        model: model.to_string(),
+        calls: 1,
+        input_tokens: input,
+        output_tokens: output,
+        cost_micros: input + 4 * output,
+        updated_at: Utc::now(),
+    }
+}
+
+async fn ai_usage_accumulates_per_task_and_model(store: &dyn Store) {
+    for usage in [
+        ai_usage("2026-03", "chat", "gpt-4o", 100, 20),
+        ai_usage("2026-03", "chat", "gpt-4o", 50, 10),
+        ai_usage("2026-03", "chat", "claude-haiku-4-5", 10, 5),
+        ai_usage("2026-03", "seo", "gpt-4o", 30, 30),
+        ai_usage("2026-02", "chat", "gpt-4o", 999, 999),
+    ] {
+        store.add_ai_usage(usage).await.unwrap();
+    }
+
+    let rows = store.list_ai_usage(SEED_TENANT, "2026-03").await.unwrap();
+    let keys: Vec<(&str, &str)> = rows
+        .iter()
+        .map(|r| (r.task.as_str(), r.model.as_str()))
+        .collect();
+    assert_eq!(
+        keys,
+        [
+            ("chat", "claude-haiku-4-5"),
+            ("chat", "gpt-4o"),
+            ("seo", "gpt-4o")
+        ]
+    );
+    let chat = &rows[1];
+    assert_eq!(chat.calls, 2);
+    assert_eq!(chat.input_tokens, 150);
+    assert_eq!(chat.output_tokens, 30);
+    assert_eq!(chat.cost_micros, 270);
+
+    assert_eq!(
+        store
+            .list_ai_usage(SEED_TENANT, "2026-02")
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(store
+        .list_ai_usage("tenant-b", "2026-03")
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+async fn product_import_jobs_save_progress(store: &dyn Store) {
+    let now = Utc::now();
+    let mut job = ProductImportJob {
+        id: "import-1".to_string(),
+        tenant_id: SEED_TENANT.to_string(),
+        format: ProductImportFormat::Csv,
+        dry_run: true,
+        status: ProductImportStatus::Queued,
+        total: 3,
+        processed: 0,
+        created: 0,
+        updated: 0,
+        failed: 0,
+        errors: Vec::new(),
+        error: None,
+        created_at: now,
+        updated_at: now,
+        completed_at: None,
+    };
+    store.save_product_import_job(job.clone()).await.unwrap();
+
+    job.status = ProductImportStatus::Completed;
+    job.processed = 3;
+    job.created = 1;
+    job.updated = 1;
+    job.failed = 1;
+    job.errors.push(ProductImportRowError {
+        row: 3,
+        product_id: Some("prod-x".to_string()),
+        message: "title is required".to_string(),
+    });
+    job.completed_at = Some(now);
+    store.save_product_import_job(job.clone()).await.unwrap();
+
+    let fetched = store
+        .get_product_import_job(SEED_TENANT, "import-1")
+        .await
+        .unwrap()
+        .expect("import job");
+    assert_eq!(fetched.format, ProductImportFormat::Csv);
+    assert!(fetched.dry_run);
+    assert_eq!(fetched.status, ProductImportStatus::Completed);
+    assert_eq!((fetched.total, fetched.processed), (3, 3));
+    assert_eq!(
+        (fetched.created, fetched.updated, fetched.failed),
+        (1, 1, 1)
+    );
+    assert_eq!(fetched.errors, job.errors);
+    assert!(fetched.error.is_none());
+    assert!(fetched.completed_at.is_some());
+
+    assert!(store
+        .get_product_import_job("tenant-b", "import-1")
+        .await
+        .unwrap()
+        .is_none());
+
+    // Only unfinished jobs that stopped reporting progress are failed
+    let stale = ProductImportJob {
+        id: "import-2".to_string(),
+        status: ProductImportStatus::Running,
+        completed_at: None,
+        updated_at: now - ChronoDuration::minutes(30),
+        ..job.clone()
+    };
+    let live = ProductImportJob {
+        id: "import-3".to_string(),
+        updated_at: now,
+        ..stale.clone()
+    };
+    store.save_product_import_job(stale).await.unwrap();
+    store.save_product_import_job(live).await.unwrap();
+    let failed = store
+        .fail_stale_product_import_jobs(now - ChronoDuration::minutes(10), "interrupted")
+        .await
+        .unwrap();
+    assert_eq!(failed, 1);
+    let stale = store
+        .get_product_import_job(SEED_TENANT, "import-2")
+        .await
+        .unwrap()
+        .expect("stale job");
+    assert_eq!(stale.status, ProductImportStatus::Failed);
+    assert_eq!(stale.error.as_deref(), Some("interrupted"));
+    assert!(stale.completed_at.is_some());
+    for id in ["import-1", "import-3"] {
+        let job = store
+            .get_product_import_job(SEED_TENANT, id)
+            .await
+            .unwrap()
+            .expect("job");
+        assert_ne!(job.status, ProductImportStatus::Failed, "{}", id);
+    }
+}
+
+async fn price_schedules_due_and_guarded_updates(store: &dyn Store) {
+    let now = Utc::now();
+    let usd = get_asset("USD").unwrap();
+    let schedule = |id: &str, starts_at, ends_at| PriceSchedule {
+        id: id.to_string(),
+        tenant_id: SEED_TENANT.to_string(),
+        product_id: SEED_PRODUCT.to_string(),
+        variant_id: None,
+        fiat_price: Some(Money::new(usd.clone(), 800)),
+        crypto_price: None,
+        variant_price: None,
+        show_as_sale: true,
+        starts_at,
+        ends_at,
+        status: PriceScheduleStatus::Scheduled,
+        original: None,
+        stripe_price_id: None,
+        note: Some("spring sale".to_string()),
+        error: None,
+        created_at: now,
+        updated_at: now,
+        applied_at: None,
+        completed_at: None,
+    };
+    let due = schedule("sched-due", now - ChronoDuration::hours(1), None);
+    let later = schedule(
+        "sched-later",
+        now + ChronoDuration::hours(1),
+        Some(now + ChronoDuration::hours(2)),
+    );
+    store.save_price_schedule(due.clone()).await.unwrap();
+    store.save_price_schedule(later).await.unwrap();
+
+    let ids = |schedules: Vec<PriceSchedule>| -> Vec<String> {
+        schedules.into_iter().map(|s| s.id).collect()
+    };
+    let listed = store.list_due_price_schedules(now, 10).await.unwrap();
+    assert_eq!(ids(listed), vec!["sched-due".to_string()]);
+
+    let mut active = due.clone();
+    active.status = PriceScheduleStatus::Active;
+    active.applied_at = Some(now);
+    active.ends_at = Some(now + ChronoDuration::minutes(30));
+    active.original = Some(PriceSnapshot {
+        fiat_price: Some(Money::new(usd.clone(), 1000)),
+        stripe_price_id: Some("price_old".to_string()),
+        ..Default::default()
+    });
+    active.stripe_price_id = Some("price_new".to_string());
+    assert!(store
+        .update_price_schedule_if_status(active.clone(), PriceScheduleStatus::Scheduled)
+        .await
+        .unwrap());
+    // A second worker that saw the same scheduled row loses the race
+    assert!(!store
+        .update_price_schedule_if_status(active.clone(), PriceScheduleStatus::Scheduled)
+        .await
+        .unwrap());
+
+    let fetched = store
+        .get_price_schedule(SEED_TENANT, "sched-due")
+        .await
+        .unwrap()
+        .expect("price schedule");
+    assert_eq!(fetched.status, PriceScheduleStatus::Active);
+    assert_eq!(fetched.original, active.original);
+    assert_eq!(fetched.stripe_price_id.as_deref(), Some("price_new"));
+    assert_eq!(fetched.fiat_price, due.fiat_price);
+    assert!(fetched.show_as_sale);
+    // Guarded updates only save progress; the window stays as created
+    assert!(fetched.ends_at.is_none());
+    assert!(store
+        .list_due_price_schedules(now, 10)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let listed = store
+        .list_product_price_schedules(SEED_TENANT, SEED_PRODUCT)
+        .await
+        .unwrap();
+    assert_eq!(
+        ids(listed),
+        vec!["sched-later".to_string(), "sched-due".to_string()]
+    );
+    assert!(store
+        .get_price_schedule("tenant-b", "sched-due")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+async fn return_request_status_claim_is_exclusive(store: &dyn Store) {
+    let now = Utc::now();
+    let request = ReturnRequest {
+        id: "ret-1".to_string(),
+        tenant_id: SEED_TENANT.to_string(),
+        order_id: "ord-1".to_string(),
+        status: "approved".to_string(),
+        items: Vec::new(),
+        reason: None,
+        metadata: HashMap::new(),
+        created_at: now,
+        updated_at: Some(now),
+        status_updated_at: Some(now),
+        restocking_fee: Some(100),
+        refund_amount: Some(900),
+        refund_method: None,
+        refund_id: None,
+        restocked_at: None,
+    };
+    store.create_return_request(request.clone()).await.unwrap();
+
+    let mut received = request.clone();
+    received.status = "received".to_string();
+    assert!(store
+        .update_return_request_if_status(received.clone(), "approved")
+        .await
+        .unwrap());
+    // A second claim from the same starting status loses
+    assert!(!store
+        .update_return_request_if_status(received.clone(), "approved")
+        .await
+        .unwrap());
+
+    received.restocked_at = Some(now);
+    assert!(store
+        .update_return_request_if_status(received, "received")
+        .await
+        .unwrap());
+    let stored = store
+        .get_return_request(SEED_TENANT, "ret-1")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.status, "received");
+    assert!(stored.restocked_at.is_some());
+
+    let mut missing = request;
+    missing.id = "ret-missing".to_string();
+    assert!(!store
+        .update_return_request_if_status(missing, "approved")
+        .await
+        .unwrap());
+}
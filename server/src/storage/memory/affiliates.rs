@@ -0,0 +1,115 @@
+use super::*;
+
+pub(super) async fn upsert_affiliate(
+    store: &InMemoryStore,
+    affiliate: Affiliate,
+) -> StorageResult<()> {
+    let mut affiliates = store.affiliates.lock();
+    let code_taken = affiliates.values().any(|a| {
+        a.tenant_id == affiliate.tenant_id && a.code == affiliate.code && a.id != affiliate.id
+    });
+    if code_taken {
+        return Err(StorageError::Conflict);
+    }
+    let key = tenant_key(&affiliate.tenant_id, &affiliate.id);
+    affiliates.insert(key, affiliate);
+    Ok(())
+}
+
+pub(super) async fn get_affiliate(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    id: &str,
+) -> StorageResult<Option<Affiliate>> {
+    Ok(store
+        .affiliates
+        .lock()
+        .get(&tenant_key(tenant_id, id))
+        .cloned())
+}
+
+pub(super) async fn get_affiliate_by_code(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    code: &str,
+) -> StorageResult<Option<Affiliate>> {
+    Ok(store
+        .affiliates
+        .lock()
+        .values()
+        .find(|a| a.tenant_id == tenant_id && a.code == code)
+        .cloned())
+}
+
+pub(super) async fn list_affiliates(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    limit: i32,
+    offset: i32,
+) -> StorageResult<Vec<Affiliate>> {
+    let mut affiliates: Vec<Affiliate> = store
+        .affiliates
+        .lock()
+        .values()
+        .filter(|a| a.tenant_id == tenant_id)
+        .cloned()
+        .collect();
+    affiliates.sort_by(|a, b| a.code.cmp(&b.code));
+    Ok(affiliates
+        .into_iter()
+        .skip(offset.max(0) as usize)
+        .take(limit.max(0) as usize)
+        .collect())
+}
+
+pub(super) async fn record_affiliate_commission(
+    store: &InMemoryStore,
+    entry: AffiliateCommission,
+) -> StorageResult<bool> {
+    let key = tenant_key(&entry.tenant_id, &entry.id);
+    let mut commissions = store.affiliate_commissions.lock();
+    if commissions.contains_key(&key) {
+        return Ok(false);
+    }
+    commissions.insert(key, entry);
+    Ok(true)
+}
+
+pub(super) async fn list_affiliate_commissions(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    affiliate_id: Option<&str>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> StorageResult<Vec<AffiliateCommission>> {
+    let mut entries: Vec<AffiliateCommission> = store
+        .affiliate_commissions
+        .lock()
+        .values()
+        .filter(|c| {
+            c.tenant_id == tenant_id
+                && affiliate_id.map_or(true, |id| c.affiliate_id == id)
+                && c.created_at >= from
+                && c.created_at < to
+        })
+        .cloned()
+        .collect();
+    entries.sort_by_key(|c| c.created_at);
+    Ok(entries)
+}
+
+pub(super) async fn list_affiliate_commissions_for_purchase(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    purchase_id: &str,
+) -> StorageResult<Vec<AffiliateCommission>> {
+    let mut entries: Vec<AffiliateCommission> = store
+        .affiliate_commissions
+        .lock()
+        .values()
+        .filter(|c| c.tenant_id == tenant_id && c.purchase_id == purchase_id)
+        .cloned()
+        .collect();
+    entries.sort_by_key(|c| c.created_at);
+    Ok(entries)
+}
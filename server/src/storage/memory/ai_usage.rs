@@ -0,0 +1,42 @@
+use super::*;
+
+fn usage_key(usage: &AiUsageRecord) -> String {
+    tenant_key(
+        &usage.tenant_id,
+        &format!("{}:{}:{}", usage.period, usage.task, usage.model),
+    )
+}
+
+pub(super) async fn add_ai_usage(store: &InMemoryStore, usage: AiUsageRecord) -> StorageResult<()> {
+    let key = usage_key(&usage);
+    let mut rows = store.ai_usage.lock();
+    match rows.get_mut(&key) {
+        Some(row) => {
+            row.calls += usage.calls;
+            row.input_tokens += usage.input_tokens;
+            row.output_tokens += usage.output_tokens;
+            row.cost_micros += usage.cost_micros;
+            row.updated_at = usage.updated_at;
+        }
+        None => {
+            rows.insert(key, usage);
+        }
+    }
+    Ok(())
+}
+
+pub(super) async fn list_ai_usage(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    period: &str,
+) -> StorageResult<Vec<AiUsageRecord>> {
+    let mut rows: Vec<AiUsageRecord> = store
+        .ai_usage
+        .lock()
+        .values()
+        .filter(|r| r.tenant_id == tenant_id && r.period == period)
+        .cloned()
+        .collect();
+    rows.sort_by(|a, b| (&a.task, &a.model).cmp(&(&b.task, &b.model)));
+    Ok(rows)
+}
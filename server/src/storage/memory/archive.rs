@@ -0,0 +1,134 @@
+use super::*;
+
+pub(super) async fn list_archivable_records(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    older_than: DateTime<Utc>,
+    limit: i64,
+) -> StorageResult<ArchivableRecords> {
+    let limit = limit.max(0) as usize;
+
+    let mut payments: Vec<PaymentTransaction> = store
+        .payments
+        .lock()
+        .values()
+        .filter(|p| p.tenant_id == tenant_id && p.created_at < older_than)
+        .cloned()
+        .collect();
+    payments.sort_by(|a, b| {
+        a.created_at
+            .cmp(&b.created_at)
+            .then_with(|| a.signature.cmp(&b.signature))
+    });
+    payments.truncate(limit);
+
+    let mut orders: Vec<Order> = store
+        .orders
+        .lock()
+        .values()
+        .filter(|o| o.tenant_id == tenant_id && o.updated_at.unwrap_or(o.created_at) < older_than)
+        .cloned()
+        .collect();
+    orders.sort_by(|a, b| {
+        a.updated_at
+            .unwrap_or(a.created_at)
+            .cmp(&b.updated_at.unwrap_or(b.created_at))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    orders.truncate(limit);
+
+    let order_history: Vec<OrderHistoryEntry> = {
+        let history = store.order_history.lock();
+        orders
+            .iter()
+            .flat_map(|o| {
+                history
+                    .get(&tenant_key(tenant_id, &o.id))
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .collect()
+    };
+
+    let mut webhooks: Vec<PendingWebhook> = store
+        .webhooks
+        .lock()
+        .values()
+        .filter(|w| {
+            w.tenant_id == tenant_id
+                && matches!(w.status, WebhookStatus::Success | WebhookStatus::Failed)
+                && w.completed_at.is_some_and(|at| at < older_than)
+        })
+        .cloned()
+        .collect();
+    webhooks.sort_by(|a, b| {
+        a.completed_at
+            .cmp(&b.completed_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    webhooks.truncate(limit);
+
+    Ok(ArchivableRecords {
+        payments,
+        orders,
+        order_history,
+        webhooks,
+    })
+}
+
+pub(super) async fn purge_archived_records(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    purge: &ArchivePurge,
+) -> StorageResult<u64> {
+    let mut deleted = 0u64;
+    {
+        let mut payments = store.payments.lock();
+        let mut index = store.archived_payments.lock();
+        for archived in &purge.payments {
+            let key = tenant_key(tenant_id, &archived.signature);
+            if payments.remove(&key).is_some() {
+                deleted += 1;
+            }
+            index.insert(key, archived.clone());
+        }
+    }
+    {
+        let mut orders = store.orders.lock();
+        let mut history = store.order_history.lock();
+        for order_id in &purge.order_ids {
+            let key = tenant_key(tenant_id, order_id);
+            if orders.remove(&key).is_some() {
+                deleted += 1;
+            }
+            if let Some(entries) = history.remove(&key) {
+                deleted += entries.len() as u64;
+            }
+        }
+    }
+    {
+        let mut webhooks = store.webhooks.lock();
+        for webhook_id in &purge.webhook_ids {
+            if webhooks
+                .get(webhook_id)
+                .is_some_and(|w| w.tenant_id == tenant_id)
+            {
+                webhooks.remove(webhook_id);
+                deleted += 1;
+            }
+        }
+    }
+    Ok(deleted)
+}
+
+pub(super) async fn find_archived_payment(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    signature: &str,
+) -> StorageResult<Option<ArchivedPaymentRef>> {
+    Ok(store
+        .archived_payments
+        .lock()
+        .get(&tenant_key(tenant_id, signature))
+        .cloned())
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::models::chat::status as chat_status;
 
 pub(super) async fn create_chat_session(
     store: &InMemoryStore,
@@ -65,6 +66,86 @@ pub(super) async fn list_chat_sessions(
     Ok((result, total))
 }
 
+pub(super) async fn request_chat_handoff(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    session_id: &str,
+    reason: Option<&str>,
+    requested_at: DateTime<Utc>,
+) -> StorageResult<bool> {
+    let key = tenant_key(tenant_id, session_id);
+    let mut sessions = store.chat_sessions.lock();
+    match sessions.get_mut(&key) {
+        Some(session) if !session.is_handed_off() => {
+            session.status = chat_status::WAITING_FOR_AGENT.to_string();
+            session.assigned_agent = None;
+            session.handoff_reason = reason.map(str::to_string);
+            session.handoff_requested_at = Some(requested_at);
+            session.updated_at = requested_at;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+pub(super) async fn claim_chat_session(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    session_id: &str,
+    agent_id: &str,
+    claimed_at: DateTime<Utc>,
+) -> StorageResult<bool> {
+    let key = tenant_key(tenant_id, session_id);
+    let mut sessions = store.chat_sessions.lock();
+    match sessions.get_mut(&key) {
+        Some(session) if session.status == chat_status::WAITING_FOR_AGENT => {
+            session.status = chat_status::WITH_AGENT.to_string();
+            session.assigned_agent = Some(agent_id.to_string());
+            session.updated_at = claimed_at;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+pub(super) async fn release_chat_session(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    session_id: &str,
+    updated_at: DateTime<Utc>,
+) -> StorageResult<bool> {
+    let key = tenant_key(tenant_id, session_id);
+    let mut sessions = store.chat_sessions.lock();
+    match sessions.get_mut(&key) {
+        Some(session) if session.is_handed_off() => {
+            session.status = chat_status::ACTIVE.to_string();
+            session.assigned_agent = None;
+            session.updated_at = updated_at;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+pub(super) async fn bind_chat_session_customer(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    session_id: &str,
+    customer_id: &str,
+    updated_at: DateTime<Utc>,
+) -> StorageResult<bool> {
+    let key = tenant_key(tenant_id, session_id);
+    let mut sessions = store.chat_sessions.lock();
+    match sessions.get_mut(&key) {
+        Some(session) if session.customer_id.is_none() => {
+            session.customer_id = Some(customer_id.to_string());
+            session.updated_at = updated_at;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
 pub(super) async fn create_chat_message(
     store: &InMemoryStore,
     message: ChatMessage,
@@ -97,3 +178,23 @@ pub(super) async fn list_chat_messages(
         .take(limit as usize)
         .collect())
 }
+
+pub(super) async fn list_chat_messages_since(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    session_id: &str,
+    since: DateTime<Utc>,
+    limit: i32,
+) -> StorageResult<Vec<ChatMessage>> {
+    let messages = store.chat_messages.lock();
+    let mut filtered: Vec<ChatMessage> = messages
+        .values()
+        .filter(|m| m.tenant_id == tenant_id && m.session_id == session_id)
+        .filter(|m| m.created_at > since)
+        .cloned()
+        .collect();
+
+    filtered.sort_by_key(|m| m.created_at);
+    filtered.truncate(limit.max(0) as usize);
+    Ok(filtered)
+}
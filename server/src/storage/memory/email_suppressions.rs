@@ -0,0 +1,53 @@
+use super::*;
+
+pub(super) async fn upsert_email_suppression(
+    store: &InMemoryStore,
+    suppression: EmailSuppression,
+) -> StorageResult<()> {
+    let key = tenant_key(&suppression.tenant_id, &suppression.email);
+    store.email_suppressions.lock().insert(key, suppression);
+    Ok(())
+}
+
+pub(super) async fn get_email_suppression(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    email: &str,
+) -> StorageResult<Option<EmailSuppression>> {
+    let key = tenant_key(tenant_id, email);
+    Ok(store.email_suppressions.lock().get(&key).cloned())
+}
+
+pub(super) async fn list_email_suppressions(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    limit: i32,
+    offset: i32,
+) -> StorageResult<Vec<EmailSuppression>> {
+    let mut suppressions: Vec<EmailSuppression> = store
+        .email_suppressions
+        .lock()
+        .values()
+        .filter(|s| s.tenant_id == tenant_id)
+        .cloned()
+        .collect();
+    suppressions.sort_by(|a, b| {
+        b.created_at
+            .cmp(&a.created_at)
+            .then_with(|| a.email.cmp(&b.email))
+    });
+    Ok(suppressions
+        .into_iter()
+        .skip(offset.max(0) as usize)
+        .take(limit.max(0) as usize)
+        .collect())
+}
+
+pub(super) async fn delete_email_suppression(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    email: &str,
+) -> StorageResult<bool> {
+    let key = tenant_key(tenant_id, email);
+    Ok(store.email_suppressions.lock().remove(&key).is_some())
+}
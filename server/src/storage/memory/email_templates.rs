@@ -0,0 +1,49 @@
+use super::*;
+
+fn template_key(tenant_id: &str, kind: &str, locale: &str) -> String {
+    tenant_key(tenant_id, &format!("{}:{}", kind, locale))
+}
+
+pub(super) async fn upsert_email_template(
+    store: &InMemoryStore,
+    template: EmailTemplate,
+) -> StorageResult<()> {
+    let key = template_key(&template.tenant_id, &template.kind, &template.locale);
+    store.email_templates.lock().insert(key, template);
+    Ok(())
+}
+
+pub(super) async fn get_email_template(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    kind: &str,
+    locale: &str,
+) -> StorageResult<Option<EmailTemplate>> {
+    let key = template_key(tenant_id, kind, locale);
+    Ok(store.email_templates.lock().get(&key).cloned())
+}
+
+pub(super) async fn list_email_templates(
+    store: &InMemoryStore,
+    tenant_id: &str,
+) -> StorageResult<Vec<EmailTemplate>> {
+    let mut templates: Vec<EmailTemplate> = store
+        .email_templates
+        .lock()
+        .values()
+        .filter(|t| t.tenant_id == tenant_id)
+        .cloned()
+        .collect();
+    templates.sort_by(|a, b| (&a.kind, &a.locale).cmp(&(&b.kind, &b.locale)));
+    Ok(templates)
+}
+
+pub(super) async fn delete_email_template(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    kind: &str,
+    locale: &str,
+) -> StorageResult<bool> {
+    let key = template_key(tenant_id, kind, locale);
+    Ok(store.email_templates.lock().remove(&key).is_some())
+}
@@ -0,0 +1,90 @@
+use super::*;
+use crate::storage::rank_embeddings;
+
+fn embedding_key(tenant_id: &str, entity_type: EmbeddingEntity, entity_id: &str) -> String {
+    tenant_key(tenant_id, &format!("{}:{}", entity_type, entity_id))
+}
+
+pub(super) async fn upsert_embedding(
+    store: &InMemoryStore,
+    record: EmbeddingRecord,
+) -> StorageResult<()> {
+    let key = embedding_key(&record.tenant_id, record.entity_type, &record.entity_id);
+    store.embeddings.lock().insert(key, record);
+    Ok(())
+}
+
+pub(super) async fn list_source_embeddings(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    source_id: &str,
+    entity_types: &[EmbeddingEntity],
+) -> StorageResult<Vec<EmbeddingRecord>> {
+    Ok(store
+        .embeddings
+        .lock()
+        .values()
+        .filter(|r| {
+            r.tenant_id == tenant_id
+                && r.source_id == source_id
+                && entity_types.contains(&r.entity_type)
+        })
+        .cloned()
+        .collect())
+}
+
+pub(super) async fn delete_embedding(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    entity_type: EmbeddingEntity,
+    entity_id: &str,
+) -> StorageResult<bool> {
+    let key = embedding_key(tenant_id, entity_type, entity_id);
+    Ok(store.embeddings.lock().remove(&key).is_some())
+}
+
+pub(super) async fn search_embeddings(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    model: &str,
+    entity_types: &[EmbeddingEntity],
+    query: &[f32],
+    limit: i32,
+) -> StorageResult<Vec<EmbeddingMatch>> {
+    let embeddings = store.embeddings.lock();
+    let candidates = embeddings.values().filter(|r| {
+        r.tenant_id == tenant_id
+            && r.model == model
+            && r.embedding.len() == query.len()
+            && entity_types.contains(&r.entity_type)
+    });
+    Ok(rank_embeddings(candidates, query, limit))
+}
+
+pub(super) async fn enqueue_embedding_job(
+    store: &InMemoryStore,
+    job: EmbeddingJob,
+) -> StorageResult<()> {
+    let key = embedding_key(&job.tenant_id, job.source_type, &job.source_id);
+    store.embedding_jobs.lock().insert(key, job);
+    Ok(())
+}
+
+pub(super) async fn claim_embedding_jobs(
+    store: &InMemoryStore,
+    now: DateTime<Utc>,
+    limit: i32,
+) -> StorageResult<Vec<EmbeddingJob>> {
+    let mut jobs = store.embedding_jobs.lock();
+    let mut due: Vec<(String, DateTime<Utc>)> = jobs
+        .iter()
+        .filter(|(_, job)| job.available_at <= now)
+        .map(|(key, job)| (key.clone(), job.available_at))
+        .collect();
+    due.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    Ok(due
+        .into_iter()
+        .take(limit.max(0) as usize)
+        .filter_map(|(key, _)| jobs.remove(&key))
+        .collect())
+}
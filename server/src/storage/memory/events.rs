@@ -0,0 +1,55 @@
+use std::sync::atomic::Ordering;
+
+use super::*;
+
+pub(super) async fn append_event(
+    store: &InMemoryStore,
+    entry: EventLogEntry,
+) -> StorageResult<i64> {
+    let mut log = store.event_log.lock();
+    if let Some(existing) = log
+        .iter()
+        .find(|e| e.tenant_id == entry.tenant_id && e.event_id == entry.event_id)
+    {
+        return Ok(existing.sequence);
+    }
+
+    // Sequences keep increasing after cleanup removes the newest entries
+    let sequence = store.event_log_sequence.fetch_add(1, Ordering::SeqCst) + 1;
+    log.push(EventLogEntry { sequence, ..entry });
+    Ok(sequence)
+}
+
+pub(super) async fn list_events(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    query: &EventLogQuery,
+) -> StorageResult<Vec<EventLogEntry>> {
+    Ok(store
+        .event_log
+        .lock()
+        .iter()
+        .filter(|e| {
+            e.tenant_id == tenant_id
+                && query.after.map_or(true, |after| e.sequence > after)
+                && query
+                    .event_type
+                    .as_ref()
+                    .map_or(true, |t| &e.event_type == t)
+                && query.since.map_or(true, |since| e.created_at >= since)
+                && query.until.map_or(true, |until| e.created_at < until)
+        })
+        .take(query.limit.max(0) as usize)
+        .cloned()
+        .collect())
+}
+
+pub(super) async fn cleanup_old_events(
+    store: &InMemoryStore,
+    older_than: DateTime<Utc>,
+) -> StorageResult<u64> {
+    let mut log = store.event_log.lock();
+    let before = log.len();
+    log.retain(|e| e.created_at >= older_than);
+    Ok((before - log.len()) as u64)
+}
@@ -211,6 +211,13 @@ pub(super) async fn update_inventory_batch(
     let now = Utc::now();
 
     for (product_id, variant_id, delta) in updates {
+        #[cfg(test)]
+        if store.fail_inventory_update_for.lock().as_deref() == Some(product_id.as_str()) {
+            return Err(StorageError::Unknown(
+                "forced update_inventory_batch failure".to_string(),
+            ));
+        }
+
         #[cfg(test)]
         {
             // Get current inventory from test-only storage if available
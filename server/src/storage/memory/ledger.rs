@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use super::*;
+
+pub(super) async fn append_journal_entry(
+    store: &InMemoryStore,
+    entry: JournalEntry,
+) -> StorageResult<bool> {
+    let key = tenant_key(&entry.tenant_id, &entry.id);
+    let mut entries = store.journal_entries.lock();
+    if entries.contains_key(&key) {
+        return Ok(false);
+    }
+    entries.insert(key, entry);
+    Ok(true)
+}
+
+pub(super) async fn list_journal_entries(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    limit: i32,
+    offset: i32,
+) -> StorageResult<Vec<JournalEntry>> {
+    let mut entries: Vec<JournalEntry> = store
+        .journal_entries
+        .lock()
+        .values()
+        .filter(|e| e.tenant_id == tenant_id && e.occurred_at >= from && e.occurred_at < to)
+        .cloned()
+        .collect();
+    entries.sort_by(|a, b| {
+        a.occurred_at
+            .cmp(&b.occurred_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    Ok(entries
+        .into_iter()
+        .skip(offset.max(0) as usize)
+        .take(limit.max(0) as usize)
+        .collect())
+}
+
+pub(super) async fn list_journal_entries_for_source(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    source: JournalSource,
+    source_id: &str,
+) -> StorageResult<Vec<JournalEntry>> {
+    let mut entries: Vec<JournalEntry> = store
+        .journal_entries
+        .lock()
+        .values()
+        .filter(|e| e.tenant_id == tenant_id && e.source == source && e.source_id == source_id)
+        .cloned()
+        .collect();
+    entries.sort_by(|a, b| {
+        a.occurred_at
+            .cmp(&b.occurred_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    Ok(entries)
+}
+
+pub(super) async fn trial_balance(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    as_of: DateTime<Utc>,
+) -> StorageResult<Vec<TrialBalanceRow>> {
+    let mut totals: BTreeMap<(String, String), (i64, i64)> = BTreeMap::new();
+    for entry in store
+        .journal_entries
+        .lock()
+        .values()
+        .filter(|e| e.tenant_id == tenant_id && e.occurred_at < as_of)
+    {
+        for line in &entry.lines {
+            let total = totals
+                .entry((line.account.clone(), entry.currency.clone()))
+                .or_default();
+            total.0 += line.debit;
+            total.1 += line.credit;
+        }
+    }
+    Ok(totals
+        .into_iter()
+        .map(|((account, currency), (debit, credit))| {
+            TrialBalanceRow::new(account, currency, debit, credit)
+        })
+        .collect())
+}
@@ -17,33 +17,51 @@ use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 
 use crate::models::compliance::{ComplianceAction, TokenHolder};
-use crate::models::StripeRefundRequest;
+use crate::models::ProductImportJob;
 use crate::models::{
     AdminAuditEntry, CartQuote, ChatMessage, ChatSession, Collection, Customer, DisputeRecord, Faq,
     Fulfillment, GiftCard, GiftCardRedemption, InventoryAdjustment, InventoryReservation, Order,
     OrderHistoryEntry, PaymentTransaction, RefundQuote, ReturnRequest, Subscription,
     SubscriptionStatus, TaxRate, TenantToken22Mint,
 };
+use crate::models::{Affiliate, AffiliateCommission};
+use crate::models::{JournalEntry, JournalSource, TrialBalanceRow};
+use crate::models::{PriceSchedule, PriceScheduleStatus};
+use crate::models::{StripeApplicationFee, StripeConnectAccount, StripeRefundRequest};
 use crate::storage::{
-    AdminNonce, AdminStats, CreditsHold, DlqWebhook, EmailStatus, IdempotencyResponse,
-    PendingEmail, PendingWebhook, Purchase, StorageError, StorageResult, Store, WebhookStatus,
+    AdminNonce, AdminStats, AiUsageRecord, ArchivableRecords, ArchivePurge, ArchivedPaymentRef,
+    CreditsHold, DlqWebhook, EmailStatus, EmailSuppression, EmailTemplate, EmbeddingEntity,
+    EmbeddingJob, EmbeddingMatch, EmbeddingRecord, EventLogEntry, EventLogQuery,
+    IdempotencyResponse, PendingEmail, PendingWebhook, Purchase, StorageError, StorageResult,
+    Store, WebhookStatus,
 };
 
 // C-02: to_chrono_duration moved to crate::services::paywall::types
 pub(crate) use crate::services::paywall::types::to_chrono_duration;
 
 mod admin;
+mod affiliates;
+mod ai_usage;
+mod archive;
 mod cart;
 mod catalog;
 mod chat;
 mod compliance;
 mod customers;
+mod email_suppressions;
+mod email_templates;
+mod embeddings;
+mod events;
 mod faqs;
 mod inventory;
+mod ledger;
 mod orders;
 mod payments;
+mod price_schedules;
+mod product_imports;
 mod refunds;
 mod shipping;
+mod stripe_connect;
 mod subscriptions;
 mod webhooks;
 
@@ -65,6 +83,11 @@ pub struct InMemoryStore {
     pub(super) carts: Arc<Mutex<HashMap<String, CartQuote>>>,
     pub(super) refunds: Arc<Mutex<HashMap<String, RefundQuote>>>,
     pub(super) stripe_refund_requests: Arc<Mutex<HashMap<String, StripeRefundRequest>>>,
+    pub(super) stripe_connect_accounts: Arc<Mutex<HashMap<String, StripeConnectAccount>>>,
+    pub(super) stripe_application_fees: Arc<Mutex<HashMap<String, StripeApplicationFee>>>,
+    pub(super) affiliates: Arc<Mutex<HashMap<String, Affiliate>>>,
+    pub(super) affiliate_commissions: Arc<Mutex<HashMap<String, AffiliateCommission>>>,
+    pub(super) journal_entries: Arc<Mutex<HashMap<String, JournalEntry>>>,
     pub(super) orders: Arc<Mutex<HashMap<String, Order>>>,
     pub(super) order_history: Arc<Mutex<HashMap<String, Vec<OrderHistoryEntry>>>>,
     pub(super) fulfillments: Arc<Mutex<HashMap<String, Fulfillment>>>,
@@ -80,10 +103,21 @@ pub struct InMemoryStore {
     pub(super) gift_cards: Arc<Mutex<HashMap<String, GiftCard>>>,
     pub(super) collections: Arc<Mutex<HashMap<String, Collection>>>,
     pub(super) payments: Arc<Mutex<HashMap<String, PaymentTransaction>>>,
+    pub(super) archived_payments: Arc<Mutex<HashMap<String, ArchivedPaymentRef>>>,
     pub(super) nonces: Arc<Mutex<HashMap<String, AdminNonce>>>,
     pub(super) webhooks: Arc<Mutex<HashMap<String, PendingWebhook>>>,
     pub(super) emails: Arc<Mutex<HashMap<String, PendingEmail>>>,
+    pub(super) email_templates: Arc<Mutex<HashMap<String, EmailTemplate>>>,
+    pub(super) email_suppressions: Arc<Mutex<HashMap<String, EmailSuppression>>>,
+    pub(super) embeddings: Arc<Mutex<HashMap<String, EmbeddingRecord>>>,
+    pub(super) embedding_jobs: Arc<Mutex<HashMap<String, EmbeddingJob>>>,
+    pub(super) ai_usage: Arc<Mutex<HashMap<String, AiUsageRecord>>>,
+    pub(super) product_import_jobs: Arc<Mutex<HashMap<String, ProductImportJob>>>,
+    pub(super) price_schedules: Arc<Mutex<HashMap<String, PriceSchedule>>>,
     pub(super) dlq: Arc<Mutex<HashMap<String, DlqWebhook>>>,
+    /// Event log in sequence order
+    pub(super) event_log: Arc<Mutex<Vec<EventLogEntry>>>,
+    pub(super) event_log_sequence: Arc<std::sync::atomic::AtomicI64>,
     pub(super) idempotency: Arc<Mutex<IdempotencyCache>>,
     pub(super) subscriptions: Arc<Mutex<HashMap<String, Subscription>>>,
     pub(super) credits_holds: Arc<Mutex<HashMap<String, CreditsHold>>>,
@@ -102,6 +136,8 @@ pub struct InMemoryStore {
     #[cfg(test)]
     pub(super) fail_reserve_inventory: Arc<AtomicBool>,
     #[cfg(test)]
+    pub(super) fail_inventory_update_for: Arc<Mutex<Option<String>>>,
+    #[cfg(test)]
     pub(super) release_inventory_calls: Arc<AtomicUsize>,
     /// Test-only: inventory levels for products (tenant_id:product_id -> quantity)
     #[cfg(test)]
@@ -126,6 +162,11 @@ impl InMemoryStore {
             carts: Arc::new(Mutex::new(HashMap::new())),
             refunds: Arc::new(Mutex::new(HashMap::new())),
             stripe_refund_requests: Arc::new(Mutex::new(HashMap::new())),
+            stripe_connect_accounts: Arc::new(Mutex::new(HashMap::new())),
+            stripe_application_fees: Arc::new(Mutex::new(HashMap::new())),
+            affiliates: Arc::new(Mutex::new(HashMap::new())),
+            affiliate_commissions: Arc::new(Mutex::new(HashMap::new())),
+            journal_entries: Arc::new(Mutex::new(HashMap::new())),
             orders: Arc::new(Mutex::new(HashMap::new())),
             order_history: Arc::new(Mutex::new(HashMap::new())),
             fulfillments: Arc::new(Mutex::new(HashMap::new())),
@@ -141,10 +182,20 @@ impl InMemoryStore {
             gift_cards: Arc::new(Mutex::new(HashMap::new())),
             collections: Arc::new(Mutex::new(HashMap::new())),
             payments: Arc::new(Mutex::new(HashMap::new())),
+            archived_payments: Arc::new(Mutex::new(HashMap::new())),
             nonces: Arc::new(Mutex::new(HashMap::new())),
             webhooks: Arc::new(Mutex::new(HashMap::new())),
             emails: Arc::new(Mutex::new(HashMap::new())),
+            email_templates: Arc::new(Mutex::new(HashMap::new())),
+            email_suppressions: Arc::new(Mutex::new(HashMap::new())),
+            embeddings: Arc::new(Mutex::new(HashMap::new())),
+            embedding_jobs: Arc::new(Mutex::new(HashMap::new())),
+            ai_usage: Arc::new(Mutex::new(HashMap::new())),
+            product_import_jobs: Arc::new(Mutex::new(HashMap::new())),
+            price_schedules: Arc::new(Mutex::new(HashMap::new())),
             dlq: Arc::new(Mutex::new(HashMap::new())),
+            event_log: Arc::new(Mutex::new(Vec::new())),
+            event_log_sequence: Arc::new(std::sync::atomic::AtomicI64::new(0)),
             idempotency: Arc::new(Mutex::new(HashMap::new())),
             subscriptions: Arc::new(Mutex::new(HashMap::new())),
             credits_holds: Arc::new(Mutex::new(HashMap::new())),
@@ -163,6 +214,8 @@ impl InMemoryStore {
             #[cfg(test)]
             fail_reserve_inventory: Arc::new(AtomicBool::new(false)),
             #[cfg(test)]
+            fail_inventory_update_for: Arc::new(Mutex::new(None)),
+            #[cfg(test)]
             release_inventory_calls: Arc::new(AtomicUsize::new(0)),
             #[cfg(test)]
             product_inventory: Arc::new(Mutex::new(HashMap::new())),
@@ -184,6 +237,12 @@ impl InMemoryStore {
         self.fail_reserve_inventory.store(fail, Ordering::SeqCst);
     }
 
+    /// Fail batch inventory updates that touch `product_id` (test-only)
+    #[cfg(test)]
+    pub fn set_fail_inventory_update_for(&self, product_id: Option<&str>) {
+        *self.fail_inventory_update_for.lock() = product_id.map(str::to_string);
+    }
+
     #[cfg(test)]
     pub fn release_inventory_call_count(&self) -> usize {
         self.release_inventory_calls.load(Ordering::SeqCst)
@@ -278,6 +337,14 @@ impl Store for InMemoryStore {
     ) -> StorageResult<Vec<RefundQuote>> {
         refunds::list_pending_refunds(self, tenant_id, limit).await
     }
+    async fn list_processed_refunds(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<RefundQuote>> {
+        refunds::list_processed_refunds(self, tenant_id, from, to).await
+    }
     async fn list_credits_refund_requests(
         &self,
         tenant_id: &str,
@@ -319,6 +386,14 @@ impl Store for InMemoryStore {
     ) -> StorageResult<Vec<StripeRefundRequest>> {
         refunds::list_pending_stripe_refund_requests(self, tenant_id, limit).await
     }
+    async fn list_processed_stripe_refund_requests(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<StripeRefundRequest>> {
+        refunds::list_processed_stripe_refund_requests(self, tenant_id, from, to).await
+    }
     async fn get_pending_stripe_refund_request_by_original_purchase_id(
         &self,
         tenant_id: &str,
@@ -339,6 +414,109 @@ impl Store for InMemoryStore {
         refunds::get_stripe_refund_request_by_charge_id(self, tenant_id, stripe_charge_id).await
     }
 
+    // ─── Stripe Connect ─────────────────────────────────────────────────────
+    async fn upsert_stripe_connect_account(
+        &self,
+        account: StripeConnectAccount,
+    ) -> StorageResult<()> {
+        stripe_connect::upsert_stripe_connect_account(self, account).await
+    }
+    async fn get_stripe_connect_account(
+        &self,
+        tenant_id: &str,
+    ) -> StorageResult<Option<StripeConnectAccount>> {
+        stripe_connect::get_stripe_connect_account(self, tenant_id).await
+    }
+    async fn find_stripe_connect_account(
+        &self,
+        account_id: &str,
+    ) -> StorageResult<Option<StripeConnectAccount>> {
+        stripe_connect::find_stripe_connect_account(self, account_id).await
+    }
+    async fn upsert_stripe_application_fee(&self, fee: StripeApplicationFee) -> StorageResult<()> {
+        stripe_connect::upsert_stripe_application_fee(self, fee).await
+    }
+    async fn list_stripe_application_fees(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<StripeApplicationFee>> {
+        stripe_connect::list_stripe_application_fees(self, tenant_id, from, to).await
+    }
+
+    // ─── Affiliates ─────────────────────────────────────────────────────────
+    async fn upsert_affiliate(&self, affiliate: Affiliate) -> StorageResult<()> {
+        affiliates::upsert_affiliate(self, affiliate).await
+    }
+    async fn get_affiliate(&self, tenant_id: &str, id: &str) -> StorageResult<Option<Affiliate>> {
+        affiliates::get_affiliate(self, tenant_id, id).await
+    }
+    async fn get_affiliate_by_code(
+        &self,
+        tenant_id: &str,
+        code: &str,
+    ) -> StorageResult<Option<Affiliate>> {
+        affiliates::get_affiliate_by_code(self, tenant_id, code).await
+    }
+    async fn list_affiliates(
+        &self,
+        tenant_id: &str,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<Affiliate>> {
+        affiliates::list_affiliates(self, tenant_id, limit, offset).await
+    }
+    async fn record_affiliate_commission(&self, entry: AffiliateCommission) -> StorageResult<bool> {
+        affiliates::record_affiliate_commission(self, entry).await
+    }
+    async fn list_affiliate_commissions(
+        &self,
+        tenant_id: &str,
+        affiliate_id: Option<&str>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<AffiliateCommission>> {
+        affiliates::list_affiliate_commissions(self, tenant_id, affiliate_id, from, to).await
+    }
+    async fn list_affiliate_commissions_for_purchase(
+        &self,
+        tenant_id: &str,
+        purchase_id: &str,
+    ) -> StorageResult<Vec<AffiliateCommission>> {
+        affiliates::list_affiliate_commissions_for_purchase(self, tenant_id, purchase_id).await
+    }
+
+    // ─── Ledger ─────────────────────────────────────────────────────────────
+    async fn append_journal_entry(&self, entry: JournalEntry) -> StorageResult<bool> {
+        ledger::append_journal_entry(self, entry).await
+    }
+    async fn list_journal_entries(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<JournalEntry>> {
+        ledger::list_journal_entries(self, tenant_id, from, to, limit, offset).await
+    }
+    async fn list_journal_entries_for_source(
+        &self,
+        tenant_id: &str,
+        source: JournalSource,
+        source_id: &str,
+    ) -> StorageResult<Vec<JournalEntry>> {
+        ledger::list_journal_entries_for_source(self, tenant_id, source, source_id).await
+    }
+    async fn trial_balance(
+        &self,
+        tenant_id: &str,
+        as_of: DateTime<Utc>,
+    ) -> StorageResult<Vec<TrialBalanceRow>> {
+        ledger::trial_balance(self, tenant_id, as_of).await
+    }
+
     // ─── Orders ─────────────────────────────────────────────────────────────
     async fn try_store_order(&self, order: Order) -> StorageResult<bool> {
         orders::try_store_order(self, order).await
@@ -346,6 +524,13 @@ impl Store for InMemoryStore {
     async fn get_order(&self, tenant_id: &str, order_id: &str) -> StorageResult<Option<Order>> {
         orders::get_order(self, tenant_id, order_id).await
     }
+    async fn get_order_by_purchase_id(
+        &self,
+        tenant_id: &str,
+        purchase_id: &str,
+    ) -> StorageResult<Option<Order>> {
+        orders::get_order_by_purchase_id(self, tenant_id, purchase_id).await
+    }
     async fn list_orders(
         &self,
         tenant_id: &str,
@@ -354,6 +539,15 @@ impl Store for InMemoryStore {
     ) -> StorageResult<Vec<Order>> {
         orders::list_orders(self, tenant_id, limit, offset).await
     }
+    async fn list_orders_by_user_id(
+        &self,
+        tenant_id: &str,
+        user_id: &str,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<Order>> {
+        orders::list_orders_by_user_id(self, tenant_id, user_id, limit, offset).await
+    }
     async fn list_orders_filtered(
         &self,
         tenant_id: &str,
@@ -491,6 +685,16 @@ impl Store for InMemoryStore {
         )
         .await
     }
+    async fn update_return_request(&self, request: ReturnRequest) -> StorageResult<()> {
+        orders::update_return_request(self, request).await
+    }
+    async fn update_return_request_if_status(
+        &self,
+        request: ReturnRequest,
+        expected_status: &str,
+    ) -> StorageResult<bool> {
+        orders::update_return_request_if_status(self, request, expected_status).await
+    }
     async fn get_return_request(
         &self,
         tenant_id: &str,
@@ -973,6 +1177,30 @@ impl Store for InMemoryStore {
     async fn archive_old_payments(&self, older_than: DateTime<Utc>) -> StorageResult<u64> {
         payments::archive_old_payments(self, older_than).await
     }
+
+    // ─── Cold Archival ──────────────────────────────────────────────────────
+    async fn list_archivable_records(
+        &self,
+        tenant_id: &str,
+        older_than: DateTime<Utc>,
+        limit: i64,
+    ) -> StorageResult<ArchivableRecords> {
+        archive::list_archivable_records(self, tenant_id, older_than, limit).await
+    }
+    async fn purge_archived_records(
+        &self,
+        tenant_id: &str,
+        purge: &ArchivePurge,
+    ) -> StorageResult<u64> {
+        archive::purge_archived_records(self, tenant_id, purge).await
+    }
+    async fn find_archived_payment(
+        &self,
+        tenant_id: &str,
+        signature: &str,
+    ) -> StorageResult<Option<ArchivedPaymentRef>> {
+        archive::find_archived_payment(self, tenant_id, signature).await
+    }
     async fn get_purchase_by_signature(
         &self,
         tenant_id: &str,
@@ -993,7 +1221,7 @@ impl Store for InMemoryStore {
     async fn delete_credits_hold(&self, tenant_id: &str, hold_id: &str) -> StorageResult<()> {
         payments::delete_credits_hold(self, tenant_id, hold_id).await
     }
-    async fn cleanup_expired_credits_holds(&self) -> StorageResult<u64> {
+    async fn cleanup_expired_credits_holds(&self) -> StorageResult<Vec<CreditsHold>> {
         payments::cleanup_expired_credits_holds(self).await
     }
     async fn list_purchases_by_user_id(
@@ -1073,6 +1301,19 @@ impl Store for InMemoryStore {
     async fn cleanup_old_webhooks(&self, retention_days: i32) -> StorageResult<u64> {
         webhooks::cleanup_old_webhooks(self, retention_days).await
     }
+    async fn append_event(&self, entry: EventLogEntry) -> StorageResult<i64> {
+        events::append_event(self, entry).await
+    }
+    async fn list_events(
+        &self,
+        tenant_id: &str,
+        query: &EventLogQuery,
+    ) -> StorageResult<Vec<EventLogEntry>> {
+        events::list_events(self, tenant_id, query).await
+    }
+    async fn cleanup_old_events(&self, older_than: DateTime<Utc>) -> StorageResult<u64> {
+        events::cleanup_old_events(self, older_than).await
+    }
     async fn enqueue_email(&self, email: PendingEmail) -> StorageResult<String> {
         webhooks::enqueue_email(self, email).await
     }
@@ -1082,8 +1323,13 @@ impl Store for InMemoryStore {
     async fn mark_email_processing(&self, email_id: &str) -> StorageResult<()> {
         webhooks::mark_email_processing(self, email_id).await
     }
-    async fn mark_email_success(&self, email_id: &str) -> StorageResult<()> {
-        webhooks::mark_email_success(self, email_id).await
+    async fn mark_email_success(
+        &self,
+        email_id: &str,
+        provider: &str,
+        provider_message_id: Option<&str>,
+    ) -> StorageResult<()> {
+        webhooks::mark_email_success(self, email_id, provider, provider_message_id).await
     }
     async fn mark_email_retry(
         &self,
@@ -1096,6 +1342,17 @@ impl Store for InMemoryStore {
     async fn mark_email_failed(&self, email_id: &str, error: &str) -> StorageResult<()> {
         webhooks::mark_email_failed(self, email_id, error).await
     }
+    async fn mark_email_suppressed(&self, email_id: &str, reason: &str) -> StorageResult<()> {
+        webhooks::mark_email_suppressed(self, email_id, reason).await
+    }
+    async fn mark_email_bounced(
+        &self,
+        tenant_id: &str,
+        provider_message_id: &str,
+        reason: &str,
+    ) -> StorageResult<bool> {
+        webhooks::mark_email_bounced(self, tenant_id, provider_message_id, reason).await
+    }
     async fn get_email(&self, email_id: &str) -> StorageResult<Option<PendingEmail>> {
         webhooks::get_email(self, email_id).await
     }
@@ -1103,6 +1360,53 @@ impl Store for InMemoryStore {
         webhooks::cleanup_old_emails(self, retention_days).await
     }
 
+    // ─── Email templates ─────────────────────────────────────────────────────
+    async fn upsert_email_template(&self, template: EmailTemplate) -> StorageResult<()> {
+        email_templates::upsert_email_template(self, template).await
+    }
+    async fn get_email_template(
+        &self,
+        tenant_id: &str,
+        kind: &str,
+        locale: &str,
+    ) -> StorageResult<Option<EmailTemplate>> {
+        email_templates::get_email_template(self, tenant_id, kind, locale).await
+    }
+    async fn list_email_templates(&self, tenant_id: &str) -> StorageResult<Vec<EmailTemplate>> {
+        email_templates::list_email_templates(self, tenant_id).await
+    }
+    async fn delete_email_template(
+        &self,
+        tenant_id: &str,
+        kind: &str,
+        locale: &str,
+    ) -> StorageResult<bool> {
+        email_templates::delete_email_template(self, tenant_id, kind, locale).await
+    }
+
+    // Email suppressions
+    async fn upsert_email_suppression(&self, suppression: EmailSuppression) -> StorageResult<()> {
+        email_suppressions::upsert_email_suppression(self, suppression).await
+    }
+    async fn get_email_suppression(
+        &self,
+        tenant_id: &str,
+        email: &str,
+    ) -> StorageResult<Option<EmailSuppression>> {
+        email_suppressions::get_email_suppression(self, tenant_id, email).await
+    }
+    async fn list_email_suppressions(
+        &self,
+        tenant_id: &str,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<EmailSuppression>> {
+        email_suppressions::list_email_suppressions(self, tenant_id, limit, offset).await
+    }
+    async fn delete_email_suppression(&self, tenant_id: &str, email: &str) -> StorageResult<bool> {
+        email_suppressions::delete_email_suppression(self, tenant_id, email).await
+    }
+
     // ─── Idempotency ─────────────────────────────────────────────────────────
     async fn save_idempotency_key(
         &self,
@@ -1156,6 +1460,13 @@ impl Store for InMemoryStore {
     ) -> StorageResult<Vec<Subscription>> {
         subscriptions::get_subscriptions_by_wallet(self, tenant_id, wallet).await
     }
+    async fn get_subscriptions_by_user_id(
+        &self,
+        tenant_id: &str,
+        user_id: &str,
+    ) -> StorageResult<Vec<Subscription>> {
+        subscriptions::get_subscriptions_by_user_id(self, tenant_id, user_id).await
+    }
     async fn get_subscription_by_stripe_id(
         &self,
         tenant_id: &str,
@@ -1306,6 +1617,41 @@ impl Store for InMemoryStore {
     ) -> StorageResult<(Vec<ChatSession>, i64)> {
         chat::list_chat_sessions(self, tenant_id, customer_id, status, limit, offset).await
     }
+    async fn request_chat_handoff(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        reason: Option<&str>,
+        requested_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        chat::request_chat_handoff(self, tenant_id, session_id, reason, requested_at).await
+    }
+    async fn claim_chat_session(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        agent_id: &str,
+        claimed_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        chat::claim_chat_session(self, tenant_id, session_id, agent_id, claimed_at).await
+    }
+    async fn release_chat_session(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        updated_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        chat::release_chat_session(self, tenant_id, session_id, updated_at).await
+    }
+    async fn bind_chat_session_customer(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        customer_id: &str,
+        updated_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        chat::bind_chat_session_customer(self, tenant_id, session_id, customer_id, updated_at).await
+    }
     async fn create_chat_message(&self, message: ChatMessage) -> StorageResult<()> {
         chat::create_chat_message(self, message).await
     }
@@ -1318,6 +1664,15 @@ impl Store for InMemoryStore {
     ) -> StorageResult<Vec<ChatMessage>> {
         chat::list_chat_messages(self, tenant_id, session_id, limit, offset).await
     }
+    async fn list_chat_messages_since(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        since: DateTime<Utc>,
+        limit: i32,
+    ) -> StorageResult<Vec<ChatMessage>> {
+        chat::list_chat_messages_since(self, tenant_id, session_id, since, limit).await
+    }
 
     // ─── FAQs ────────────────────────────────────────────────────────────────
     async fn create_faq(&self, faq: Faq) -> StorageResult<()> {
@@ -1358,6 +1713,118 @@ impl Store for InMemoryStore {
         faqs::list_public_faqs(self, tenant_id, limit, offset).await
     }
 
+    // ─── Embeddings ──────────────────────────────────────────────────────
+    async fn upsert_embedding(&self, record: EmbeddingRecord) -> StorageResult<()> {
+        embeddings::upsert_embedding(self, record).await
+    }
+    async fn list_source_embeddings(
+        &self,
+        tenant_id: &str,
+        source_id: &str,
+        entity_types: &[EmbeddingEntity],
+    ) -> StorageResult<Vec<EmbeddingRecord>> {
+        embeddings::list_source_embeddings(self, tenant_id, source_id, entity_types).await
+    }
+    async fn delete_embedding(
+        &self,
+        tenant_id: &str,
+        entity_type: EmbeddingEntity,
+        entity_id: &str,
+    ) -> StorageResult<bool> {
+        embeddings::delete_embedding(self, tenant_id, entity_type, entity_id).await
+    }
+    async fn search_embeddings(
+        &self,
+        tenant_id: &str,
+        model: &str,
+        entity_types: &[EmbeddingEntity],
+        query: &[f32],
+        limit: i32,
+    ) -> StorageResult<Vec<EmbeddingMatch>> {
+        embeddings::search_embeddings(self, tenant_id, model, entity_types, query, limit).await
+    }
+    async fn enqueue_embedding_job(&self, job: EmbeddingJob) -> StorageResult<()> {
+        embeddings::enqueue_embedding_job(self, job).await
+    }
+    async fn claim_embedding_jobs(
+        &self,
+        now: DateTime<Utc>,
+        limit: i32,
+    ) -> StorageResult<Vec<EmbeddingJob>> {
+        embeddings::claim_embedding_jobs(self, now, limit).await
+    }
+
+    // ─── AI usage ────────────────────────────────────────────────────────
+    async fn add_ai_usage(&self, usage: AiUsageRecord) -> StorageResult<()> {
+        ai_usage::add_ai_usage(self, usage).await
+    }
+    async fn list_ai_usage(
+        &self,
+        tenant_id: &str,
+        period: &str,
+    ) -> StorageResult<Vec<AiUsageRecord>> {
+        ai_usage::list_ai_usage(self, tenant_id, period).await
+    }
+
+    // ─── Bulk product import jobs ─────────────────────────────────────────
+
+    async fn save_product_import_job(&self, job: ProductImportJob) -> StorageResult<()> {
+        product_imports::save_product_import_job(self, job).await
+    }
+
+    async fn get_product_import_job(
+        &self,
+        tenant_id: &str,
+        job_id: &str,
+    ) -> StorageResult<Option<ProductImportJob>> {
+        product_imports::get_product_import_job(self, tenant_id, job_id).await
+    }
+    async fn fail_stale_product_import_jobs(
+        &self,
+        updated_before: DateTime<Utc>,
+        error: &str,
+    ) -> StorageResult<u64> {
+        product_imports::fail_stale_product_import_jobs(self, updated_before, error).await
+    }
+
+    // ─── Scheduled price changes ──────────────────────────────────────────
+
+    async fn save_price_schedule(&self, schedule: PriceSchedule) -> StorageResult<()> {
+        price_schedules::save_price_schedule(self, schedule).await
+    }
+
+    async fn update_price_schedule_if_status(
+        &self,
+        schedule: PriceSchedule,
+        expected: PriceScheduleStatus,
+    ) -> StorageResult<bool> {
+        price_schedules::update_price_schedule_if_status(self, schedule, expected).await
+    }
+
+    async fn get_price_schedule(
+        &self,
+        tenant_id: &str,
+        schedule_id: &str,
+    ) -> StorageResult<Option<PriceSchedule>> {
+        price_schedules::get_price_schedule(self, tenant_id, schedule_id).await
+    }
+
+    async fn list_product_price_schedules(
+        &self,
+        tenant_id: &str,
+        product_id: &str,
+    ) -> StorageResult<Vec<PriceSchedule>> {
+        price_schedules::list_product_price_schedules(self, tenant_id, product_id).await
+    }
+
+    async fn list_due_price_schedules(
+        &self,
+        now: DateTime<Utc>,
+        limit: i32,
+    ) -> StorageResult<Vec<PriceSchedule>> {
+        price_schedules::list_due_price_schedules(self, now, limit).await
+    }
+
     // ─── Compliance ───────────────────────────────────────────────────────
     async fn record_token_holder(&self, holder: TokenHolder) -> StorageResult<()> {
         compliance::record_token_holder(self, holder).await
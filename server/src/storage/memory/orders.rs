@@ -35,6 +35,20 @@ pub(super) async fn get_order(
         .cloned())
 }
 
+pub(super) async fn get_order_by_purchase_id(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    purchase_id: &str,
+) -> StorageResult<Option<Order>> {
+    Ok(store
+        .orders
+        .lock()
+        .values()
+        .filter(|o| o.tenant_id == tenant_id && o.purchase_id == purchase_id)
+        .min_by_key(|o| o.created_at)
+        .cloned())
+}
+
 pub(super) async fn list_orders(
     store: &InMemoryStore,
     tenant_id: &str,
@@ -65,6 +79,37 @@ pub(super) async fn list_orders(
     Ok(orders[offset..end].to_vec())
 }
 
+pub(super) async fn list_orders_by_user_id(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    user_id: &str,
+    limit: i32,
+    offset: i32,
+) -> StorageResult<Vec<Order>> {
+    if limit <= 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut orders: Vec<Order> = store
+        .orders
+        .lock()
+        .values()
+        .filter(|o| o.tenant_id == tenant_id && o.user_id.as_deref() == Some(user_id))
+        .cloned()
+        .collect();
+
+    orders.sort_by_key(|o| std::cmp::Reverse(o.created_at));
+
+    let offset = offset.max(0) as usize;
+    let limit = limit as usize;
+    if offset >= orders.len() {
+        return Ok(Vec::new());
+    }
+
+    let end = (offset + limit).min(orders.len());
+    Ok(orders[offset..end].to_vec())
+}
+
 pub(super) async fn list_orders_filtered(
     store: &InMemoryStore,
     tenant_id: &str,
@@ -272,6 +317,37 @@ pub(super) async fn update_return_status(
     }
 }
 
+pub(super) async fn update_return_request(
+    store: &InMemoryStore,
+    request: ReturnRequest,
+) -> StorageResult<()> {
+    let key = tenant_key(&request.tenant_id, &request.id);
+    let mut returns = store.returns.lock();
+    match returns.get_mut(&key) {
+        Some(existing) => {
+            *existing = request;
+            Ok(())
+        }
+        None => Err(StorageError::NotFound),
+    }
+}
+
+pub(super) async fn update_return_request_if_status(
+    store: &InMemoryStore,
+    request: ReturnRequest,
+    expected_status: &str,
+) -> StorageResult<bool> {
+    let key = tenant_key(&request.tenant_id, &request.id);
+    let mut returns = store.returns.lock();
+    match returns.get_mut(&key) {
+        Some(existing) if existing.status == expected_status => {
+            *existing = request;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
 pub(super) async fn get_return_request(
     store: &InMemoryStore,
     tenant_id: &str,
@@ -170,12 +170,20 @@ pub(super) async fn delete_credits_hold(
     Ok(())
 }
 
-pub(super) async fn cleanup_expired_credits_holds(store: &InMemoryStore) -> StorageResult<u64> {
+pub(super) async fn cleanup_expired_credits_holds(
+    store: &InMemoryStore,
+) -> StorageResult<Vec<CreditsHold>> {
     let now = Utc::now();
     let mut map = store.credits_holds.lock();
-    let before = map.len();
-    map.retain(|_, v| v.expires_at > now);
-    Ok((before - map.len()) as u64)
+    let mut expired = Vec::new();
+    map.retain(|_, v| {
+        let keep = v.expires_at > now;
+        if !keep {
+            expired.push(v.clone());
+        }
+        keep
+    });
+    Ok(expired)
 }
 
 pub(super) async fn list_purchases_by_user_id(
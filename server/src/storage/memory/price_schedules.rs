@@ -0,0 +1,78 @@
+use super::*;
+
+pub(super) async fn save_price_schedule(
+    store: &InMemoryStore,
+    schedule: PriceSchedule,
+) -> StorageResult<()> {
+    let key = tenant_key(&schedule.tenant_id, &schedule.id);
+    store.price_schedules.lock().insert(key, schedule);
+    Ok(())
+}
+
+pub(super) async fn update_price_schedule_if_status(
+    store: &InMemoryStore,
+    schedule: PriceSchedule,
+    expected: PriceScheduleStatus,
+) -> StorageResult<bool> {
+    let key = tenant_key(&schedule.tenant_id, &schedule.id);
+    let mut schedules = store.price_schedules.lock();
+    match schedules.get_mut(&key) {
+        Some(existing) if existing.status == expected => {
+            existing.status = schedule.status;
+            existing.original = schedule.original;
+            existing.stripe_price_id = schedule.stripe_price_id;
+            existing.error = schedule.error;
+            existing.updated_at = schedule.updated_at;
+            existing.applied_at = schedule.applied_at;
+            existing.completed_at = schedule.completed_at;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+pub(super) async fn get_price_schedule(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    schedule_id: &str,
+) -> StorageResult<Option<PriceSchedule>> {
+    let key = tenant_key(tenant_id, schedule_id);
+    Ok(store.price_schedules.lock().get(&key).cloned())
+}
+
+pub(super) async fn list_product_price_schedules(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    product_id: &str,
+) -> StorageResult<Vec<PriceSchedule>> {
+    let mut schedules: Vec<PriceSchedule> = store
+        .price_schedules
+        .lock()
+        .values()
+        .filter(|s| s.tenant_id == tenant_id && s.product_id == product_id)
+        .cloned()
+        .collect();
+    schedules.sort_by_key(|s| std::cmp::Reverse(s.starts_at));
+    Ok(schedules)
+}
+
+pub(super) async fn list_due_price_schedules(
+    store: &InMemoryStore,
+    now: DateTime<Utc>,
+    limit: i32,
+) -> StorageResult<Vec<PriceSchedule>> {
+    let mut due: Vec<PriceSchedule> = store
+        .price_schedules
+        .lock()
+        .values()
+        .filter(|s| match s.status {
+            PriceScheduleStatus::Scheduled => s.starts_at <= now,
+            PriceScheduleStatus::Active => s.ends_at.is_some_and(|end| end <= now),
+            _ => false,
+        })
+        .cloned()
+        .collect();
+    due.sort_by_key(|s| s.starts_at);
+    due.truncate(limit.max(0) as usize);
+    Ok(due)
+}
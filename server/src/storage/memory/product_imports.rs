@@ -0,0 +1,43 @@
+use super::*;
+use crate::models::ProductImportStatus;
+
+pub(super) async fn save_product_import_job(
+    store: &InMemoryStore,
+    job: ProductImportJob,
+) -> StorageResult<()> {
+    let key = tenant_key(&job.tenant_id, &job.id);
+    store.product_import_jobs.lock().insert(key, job);
+    Ok(())
+}
+
+pub(super) async fn get_product_import_job(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    job_id: &str,
+) -> StorageResult<Option<ProductImportJob>> {
+    let key = tenant_key(tenant_id, job_id);
+    Ok(store.product_import_jobs.lock().get(&key).cloned())
+}
+
+pub(super) async fn fail_stale_product_import_jobs(
+    store: &InMemoryStore,
+    updated_before: DateTime<Utc>,
+    error: &str,
+) -> StorageResult<u64> {
+    let now = Utc::now();
+    let mut count = 0;
+    for job in store.product_import_jobs.lock().values_mut() {
+        let unfinished = matches!(
+            job.status,
+            ProductImportStatus::Queued | ProductImportStatus::Running
+        );
+        if unfinished && job.updated_at < updated_before {
+            job.status = ProductImportStatus::Failed;
+            job.error = Some(error.to_string());
+            job.updated_at = now;
+            job.completed_at = Some(now);
+            count += 1;
+        }
+    }
+    Ok(count)
+}
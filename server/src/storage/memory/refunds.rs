@@ -81,6 +81,27 @@ pub(super) async fn list_pending_refunds(
     Ok(refunds)
 }
 
+pub(super) async fn list_processed_refunds(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> StorageResult<Vec<RefundQuote>> {
+    let mut refunds: Vec<RefundQuote> = store
+        .refunds
+        .lock()
+        .values()
+        .filter(|r| {
+            r.tenant_id == tenant_id
+                && r.is_processed()
+                && r.processed_at.is_some_and(|at| at >= from && at < to)
+        })
+        .cloned()
+        .collect();
+    refunds.sort_by_key(|r| r.processed_at);
+    Ok(refunds)
+}
+
 pub(super) async fn list_credits_refund_requests(
     store: &InMemoryStore,
     tenant_id: &str,
@@ -199,6 +220,27 @@ pub(super) async fn list_pending_stripe_refund_requests(
     Ok(reqs)
 }
 
+pub(super) async fn list_processed_stripe_refund_requests(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> StorageResult<Vec<StripeRefundRequest>> {
+    let mut reqs: Vec<StripeRefundRequest> = store
+        .stripe_refund_requests
+        .lock()
+        .values()
+        .filter(|r| {
+            r.tenant_id == tenant_id
+                && !matches!(r.status.as_str(), "failed" | "canceled")
+                && r.processed_at.is_some_and(|at| at >= from && at < to)
+        })
+        .cloned()
+        .collect();
+    reqs.sort_by_key(|r| r.processed_at);
+    Ok(reqs)
+}
+
 pub(super) async fn get_pending_stripe_refund_request_by_original_purchase_id(
     store: &InMemoryStore,
     tenant_id: &str,
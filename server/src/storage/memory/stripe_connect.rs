@@ -0,0 +1,57 @@
+use super::*;
+
+pub(super) async fn upsert_stripe_connect_account(
+    store: &InMemoryStore,
+    account: StripeConnectAccount,
+) -> StorageResult<()> {
+    store
+        .stripe_connect_accounts
+        .lock()
+        .insert(account.tenant_id.clone(), account);
+    Ok(())
+}
+
+pub(super) async fn get_stripe_connect_account(
+    store: &InMemoryStore,
+    tenant_id: &str,
+) -> StorageResult<Option<StripeConnectAccount>> {
+    Ok(store.stripe_connect_accounts.lock().get(tenant_id).cloned())
+}
+
+pub(super) async fn find_stripe_connect_account(
+    store: &InMemoryStore,
+    account_id: &str,
+) -> StorageResult<Option<StripeConnectAccount>> {
+    Ok(store
+        .stripe_connect_accounts
+        .lock()
+        .values()
+        .find(|a| a.account_id == account_id)
+        .cloned())
+}
+
+pub(super) async fn upsert_stripe_application_fee(
+    store: &InMemoryStore,
+    fee: StripeApplicationFee,
+) -> StorageResult<()> {
+    let key = tenant_key(&fee.tenant_id, &fee.id);
+    store.stripe_application_fees.lock().insert(key, fee);
+    Ok(())
+}
+
+pub(super) async fn list_stripe_application_fees(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> StorageResult<Vec<StripeApplicationFee>> {
+    let mut fees: Vec<StripeApplicationFee> = store
+        .stripe_application_fees
+        .lock()
+        .values()
+        .filter(|f| f.tenant_id == tenant_id && f.created_at >= from && f.created_at < to)
+        .cloned()
+        .collect();
+    fees.sort_by_key(|f| f.created_at);
+    Ok(fees)
+}
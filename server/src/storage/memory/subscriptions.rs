@@ -53,6 +53,22 @@ pub(super) async fn get_subscriptions_by_wallet(
         .collect())
 }
 
+pub(super) async fn get_subscriptions_by_user_id(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    user_id: &str,
+) -> StorageResult<Vec<Subscription>> {
+    let mut subs: Vec<Subscription> = store
+        .subscriptions
+        .lock()
+        .values()
+        .filter(|s| s.tenant_id == tenant_id && s.user_id.as_deref() == Some(user_id))
+        .cloned()
+        .collect();
+    subs.sort_by_key(|s| std::cmp::Reverse(s.created_at));
+    Ok(subs)
+}
+
 pub(super) async fn get_subscription_by_stripe_id(
     store: &InMemoryStore,
     tenant_id: &str,
@@ -198,10 +198,17 @@ pub(super) async fn mark_email_processing(
     Ok(())
 }
 
-pub(super) async fn mark_email_success(store: &InMemoryStore, email_id: &str) -> StorageResult<()> {
+pub(super) async fn mark_email_success(
+    store: &InMemoryStore,
+    email_id: &str,
+    provider: &str,
+    provider_message_id: Option<&str>,
+) -> StorageResult<()> {
     if let Some(email) = store.emails.lock().get_mut(email_id) {
         email.status = EmailStatus::Completed;
         email.completed_at = Some(Utc::now());
+        email.provider = Some(provider.to_string());
+        email.provider_message_id = provider_message_id.map(str::to_string);
     }
     Ok(())
 }
@@ -233,6 +240,38 @@ pub(super) async fn mark_email_failed(
     Ok(())
 }
 
+pub(super) async fn mark_email_suppressed(
+    store: &InMemoryStore,
+    email_id: &str,
+    reason: &str,
+) -> StorageResult<()> {
+    if let Some(email) = store.emails.lock().get_mut(email_id) {
+        email.status = EmailStatus::Suppressed;
+        email.last_error = Some(reason.to_string());
+        email.completed_at = Some(Utc::now());
+    }
+    Ok(())
+}
+
+pub(super) async fn mark_email_bounced(
+    store: &InMemoryStore,
+    tenant_id: &str,
+    provider_message_id: &str,
+    reason: &str,
+) -> StorageResult<bool> {
+    let mut found = false;
+    for email in store.emails.lock().values_mut() {
+        if email.tenant_id == tenant_id
+            && email.provider_message_id.as_deref() == Some(provider_message_id)
+        {
+            email.status = EmailStatus::Bounced;
+            email.last_error = Some(reason.to_string());
+            found = true;
+        }
+    }
+    Ok(found)
+}
+
 pub(super) async fn get_email(
     store: &InMemoryStore,
     email_id: &str,
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::models::compliance::{ComplianceAction, TokenHolder};
-use crate::models::StripeRefundRequest;
+use crate::models::ProductImportJob;
 use crate::models::{
     AdminAuditEntry, AssetRedemption, CartQuote, ChatMessage, ChatSession, Collection, Customer,
     DisputeRecord, Faq, Fulfillment, GiftCard, GiftCardRedemption, InventoryAdjustment,
@@ -16,14 +16,22 @@ use crate::models::{
     ReturnRequest, ShippingProfile, ShippingRate, Subscription, SubscriptionStatus, TaxRate,
     TenantToken22Mint,
 };
+use crate::models::{Affiliate, AffiliateCommission};
+use crate::models::{JournalEntry, JournalSource, TrialBalanceRow};
+use crate::models::{PriceSchedule, PriceScheduleStatus};
+use crate::models::{StripeApplicationFee, StripeConnectAccount, StripeRefundRequest};
 
 pub mod cached;
+#[cfg(test)]
+pub(crate) mod conformance;
 pub mod memory;
 pub mod postgres;
+pub mod sqlite;
 
 pub use cached::{CacheConfig, CachedStore, CachedStoreStats};
 pub use memory::InMemoryStore;
 pub use postgres::{InventoryAdjustmentRequest, PostgresConfig, PostgresPool, PostgresStore};
+pub use sqlite::{SqliteConfig, SqlitePool, SqliteStore};
 
 #[derive(Debug, Error)]
 pub enum StorageError {
@@ -100,6 +108,10 @@ pub enum EmailStatus {
     Pending,
     Completed,
     Failed,
+    /// Skipped because the recipient is on the tenant's suppression list
+    Suppressed,
+    /// Accepted by the provider, then reported as bounced or complained about
+    Bounced,
 }
 
 impl std::fmt::Display for EmailStatus {
@@ -108,6 +120,8 @@ impl std::fmt::Display for EmailStatus {
             EmailStatus::Pending => write!(f, "pending"),
             EmailStatus::Completed => write!(f, "completed"),
             EmailStatus::Failed => write!(f, "failed"),
+            EmailStatus::Suppressed => write!(f, "suppressed"),
+            EmailStatus::Bounced => write!(f, "bounced"),
         }
     }
 }
@@ -119,6 +133,8 @@ impl std::str::FromStr for EmailStatus {
             "pending" => Ok(Self::Pending),
             "completed" => Ok(Self::Completed),
             "failed" => Ok(Self::Failed),
+            "suppressed" => Ok(Self::Suppressed),
+            "bounced" => Ok(Self::Bounced),
             other => Err(format!("unknown email status: {}", other)),
         }
     }
@@ -151,6 +167,215 @@ pub struct PendingEmail {
     pub created_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub completed_at: Option<DateTime<Utc>>,
+    /// W3C `traceparent` of the request that queued the email, so delivery
+    /// shows up in the originating trace
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub traceparent: Option<String>,
+    /// Transport that delivered the email (`smtp` or `http`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    /// Message id returned by the provider; bounce webhooks reference it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_message_id: Option<String>,
+}
+
+/// Why an address stopped receiving email
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SuppressionReason {
+    /// Permanent (hard) bounce
+    Bounce,
+    /// Recipient marked an email as spam
+    Complaint,
+    /// Added by an admin
+    Manual,
+}
+
+impl std::fmt::Display for SuppressionReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SuppressionReason::Bounce => write!(f, "bounce"),
+            SuppressionReason::Complaint => write!(f, "complaint"),
+            SuppressionReason::Manual => write!(f, "manual"),
+        }
+    }
+}
+
+impl std::str::FromStr for SuppressionReason {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "bounce" => Ok(Self::Bounce),
+            "complaint" => Ok(Self::Complaint),
+            "manual" => Ok(Self::Manual),
+            other => Err(format!("unknown suppression reason: {}", other)),
+        }
+    }
+}
+
+/// Recipient the email worker no longer sends to.
+///
+/// Addresses are stored trimmed and lowercased; see [`normalize_email_address`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailSuppression {
+    pub tenant_id: String,
+    pub email: String,
+    pub reason: SuppressionReason,
+    /// Provider diagnostic, e.g. the SMTP status of a bounce
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Canonical form of an address for suppression lookups
+pub fn normalize_email_address(email: &str) -> String {
+    email.trim().to_ascii_lowercase()
+}
+
+/// Kind of record an embedding was computed from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmbeddingEntity {
+    Product,
+    /// Embedded separately so option values ("Blue", "XL") are searchable;
+    /// matches resolve to the parent product
+    Variant,
+    Faq,
+}
+
+impl EmbeddingEntity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmbeddingEntity::Product => "product",
+            EmbeddingEntity::Variant => "variant",
+            EmbeddingEntity::Faq => "faq",
+        }
+    }
+}
+
+impl std::fmt::Display for EmbeddingEntity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for EmbeddingEntity {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "product" => Ok(Self::Product),
+            "variant" => Ok(Self::Variant),
+            "faq" => Ok(Self::Faq),
+            other => Err(format!("unknown embedding entity: {}", other)),
+        }
+    }
+}
+
+/// Stored embedding of a product, variant or FAQ
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddingRecord {
+    pub tenant_id: String,
+    pub entity_type: EmbeddingEntity,
+    pub entity_id: String,
+    /// Product a variant belongs to; the entity itself for products and FAQs
+    pub source_id: String,
+    /// Provider model id; vectors of different models are never compared
+    pub model: String,
+    /// Hash of the embedded text, so unchanged records are not re-embedded
+    pub content_hash: String,
+    pub embedding: Vec<f32>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Result of a vector search, best first
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddingMatch {
+    pub entity_type: EmbeddingEntity,
+    pub entity_id: String,
+    pub source_id: String,
+    /// Cosine similarity to the query
+    pub score: f32,
+}
+
+/// Product or FAQ waiting to be (re-)embedded
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddingJob {
+    pub tenant_id: String,
+    /// `Product` (including its variants) or `Faq`
+    pub source_type: EmbeddingEntity,
+    pub source_id: String,
+    /// Failed attempts so far
+    pub attempts: i32,
+    pub available_at: DateTime<Utc>,
+}
+
+impl EmbeddingJob {
+    pub fn new(tenant_id: &str, source_type: EmbeddingEntity, source_id: &str) -> Self {
+        Self {
+            tenant_id: tenant_id.to_string(),
+            source_type,
+            source_id: source_id.to_string(),
+            attempts: 0,
+            available_at: Utc::now(),
+        }
+    }
+}
+
+/// Cosine similarity of two vectors; 0 when either is zero or their lengths differ
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let (mut dot, mut norm_a, mut norm_b) = (0.0f32, 0.0f32, 0.0f32);
+    for (x, y) in a.iter().zip(b) {
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
+/// Brute-force nearest neighbours for backends without a vector index
+pub(crate) fn rank_embeddings<'a>(
+    records: impl Iterator<Item = &'a EmbeddingRecord>,
+    query: &[f32],
+    limit: i32,
+) -> Vec<EmbeddingMatch> {
+    let mut matches: Vec<EmbeddingMatch> = records
+        .map(|record| EmbeddingMatch {
+            entity_type: record.entity_type,
+            entity_id: record.entity_id.clone(),
+            source_id: record.source_id.clone(),
+            score: cosine_similarity(&record.embedding, query),
+        })
+        .collect();
+    matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+    matches.truncate(limit.max(0) as usize);
+    matches
+}
+
+/// Monthly AI token usage and cost of one task and model for a tenant.
+///
+/// Rows are aggregates: recording a call adds to the row for
+/// (tenant, period, task, model).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AiUsageRecord {
+    pub tenant_id: String,
+    /// Calendar month (UTC), `YYYY-MM`
+    pub period: String,
+    pub task: String,
+    /// Provider model id
+    pub model: String,
+    pub calls: i64,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    /// Priced cost in millionths of a US dollar
+    pub cost_micros: i64,
+    pub updated_at: DateTime<Utc>,
 }
 
 /// Dead Letter Queue webhook entry
@@ -175,6 +400,55 @@ pub struct DlqWebhook {
     pub moved_to_dlq_at: DateTime<Utc>,
 }
 
+/// Notification recorded in the durable event log.
+///
+/// Unlike the webhook queue, entries are kept for the event log retention
+/// period whether or not they were delivered, so consumers can re-read them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventLogEntry {
+    /// Monotonic cursor assigned by the store on append
+    pub sequence: i64,
+    pub tenant_id: String,
+    /// Stable event ID (matches `eventId` in the payload)
+    pub event_id: String,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Filter for reading the event log, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct EventLogQuery {
+    /// Only entries with a sequence greater than this cursor
+    pub after: Option<i64>,
+    pub event_type: Option<String>,
+    /// Inclusive lower bound on `created_at`
+    pub since: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on `created_at`
+    pub until: Option<DateTime<Utc>>,
+    pub limit: i64,
+}
+
+/// Tenant override for a transactional email template.
+///
+/// Keyed by (tenant_id, kind, locale). Kinds and locales without an override
+/// fall back to the built-in templates in `services::messaging::email_templates`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailTemplate {
+    pub tenant_id: String,
+    /// Template kind (e.g. `order_confirmation`)
+    pub kind: String,
+    /// Lowercase language tag (e.g. `en`, `pt-br`)
+    pub locale: String,
+    pub subject: String,
+    pub body_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_html: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct IdempotencyResponse {
     pub status_code: i32,
@@ -274,6 +548,51 @@ pub struct AdminStats {
     pub top_products: Vec<TopProduct>,
 }
 
+/// Records of one tenant that are past retention and due for cold archival
+#[derive(Debug, Clone, Default)]
+pub struct ArchivableRecords {
+    pub payments: Vec<PaymentTransaction>,
+    pub orders: Vec<Order>,
+    /// History of the orders in `orders`
+    pub order_history: Vec<OrderHistoryEntry>,
+    /// Delivered or permanently failed webhooks
+    pub webhooks: Vec<PendingWebhook>,
+}
+
+impl ArchivableRecords {
+    pub fn is_empty(&self) -> bool {
+        self.payments.is_empty()
+            && self.orders.is_empty()
+            && self.order_history.is_empty()
+            && self.webhooks.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.payments.len() + self.orders.len() + self.order_history.len() + self.webhooks.len()
+    }
+}
+
+/// Location of an archived payment in cold storage
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedPaymentRef {
+    pub tenant_id: String,
+    pub signature: String,
+    /// Object key of the compressed JSONL file holding the payment
+    pub object_key: String,
+    pub archived_at: DateTime<Utc>,
+}
+
+/// Records that have been written to cold storage and can be removed
+#[derive(Debug, Clone, Default)]
+pub struct ArchivePurge {
+    /// Archived payments; each is deleted and indexed by signature
+    pub payments: Vec<ArchivedPaymentRef>,
+    /// Archived orders; their history is deleted with them
+    pub order_ids: Vec<String>,
+    pub webhook_ids: Vec<String>,
+}
+
 /// Core storage trait for all persistent data
 /// Per spec (08-storage.md): All query methods include tenant_id for multi-tenant isolation
 #[async_trait]
@@ -346,6 +665,13 @@ pub trait Store: Send + Sync {
     async fn count_pending_refunds(&self, tenant_id: &str) -> StorageResult<i64> {
         Ok(self.list_pending_refunds(tenant_id, i32::MAX).await?.len() as i64)
     }
+    /// List refunds executed (processed with a signature) in `[from, to)`
+    async fn list_processed_refunds(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<RefundQuote>>;
     /// Mark refund as processed with tenant isolation
     async fn mark_refund_processed(
         &self,
@@ -384,23 +710,136 @@ pub trait Store: Send + Sync {
         limit: i32,
     ) -> StorageResult<Vec<StripeRefundRequest>>;
 
+    /// List Stripe refund requests processed in `[from, to)`, excluding
+    /// failed and canceled refunds
+    async fn list_processed_stripe_refund_requests(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<StripeRefundRequest>>;
+
     async fn get_pending_stripe_refund_request_by_original_purchase_id(
         &self,
         tenant_id: &str,
         original_purchase_id: &str,
     ) -> StorageResult<Option<StripeRefundRequest>>;
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Stripe Connect (per-tenant connected accounts)
+    // ─────────────────────────────────────────────────────────────────────────
+    async fn upsert_stripe_connect_account(
+        &self,
+        account: StripeConnectAccount,
+    ) -> StorageResult<()>;
+    async fn get_stripe_connect_account(
+        &self,
+        tenant_id: &str,
+    ) -> StorageResult<Option<StripeConnectAccount>>;
+    /// Look up a connected account by Stripe account ID across all tenants
+    /// (webhooks for connected accounts only carry the account ID).
+    async fn find_stripe_connect_account(
+        &self,
+        account_id: &str,
+    ) -> StorageResult<Option<StripeConnectAccount>>;
+    /// Insert or update an application fee (keyed by tenant + fee ID).
+    async fn upsert_stripe_application_fee(&self, fee: StripeApplicationFee) -> StorageResult<()>;
+    /// List application fees created in `[from, to)`, oldest first.
+    async fn list_stripe_application_fees(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<StripeApplicationFee>>;
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Affiliates (referral codes and commission ledger)
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Insert or update an affiliate. Returns `Conflict` if another affiliate
+    /// in the tenant already uses the referral code.
+    async fn upsert_affiliate(&self, affiliate: Affiliate) -> StorageResult<()>;
+    async fn get_affiliate(&self, tenant_id: &str, id: &str) -> StorageResult<Option<Affiliate>>;
+    async fn get_affiliate_by_code(
+        &self,
+        tenant_id: &str,
+        code: &str,
+    ) -> StorageResult<Option<Affiliate>>;
+    async fn list_affiliates(
+        &self,
+        tenant_id: &str,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<Affiliate>>;
+    /// Append a ledger entry. Returns `false` if an entry with the same ID exists.
+    async fn record_affiliate_commission(&self, entry: AffiliateCommission) -> StorageResult<bool>;
+    /// List ledger entries created in `[from, to)`, oldest first.
+    async fn list_affiliate_commissions(
+        &self,
+        tenant_id: &str,
+        affiliate_id: Option<&str>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<AffiliateCommission>>;
+    async fn list_affiliate_commissions_for_purchase(
+        &self,
+        tenant_id: &str,
+        purchase_id: &str,
+    ) -> StorageResult<Vec<AffiliateCommission>>;
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Accounting ledger (append-only double-entry journal)
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Append a journal entry. Returns `false` if an entry with the same ID exists.
+    async fn append_journal_entry(&self, entry: JournalEntry) -> StorageResult<bool>;
+    /// List entries that occurred in `[from, to)`, oldest first.
+    async fn list_journal_entries(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<JournalEntry>>;
+    /// List entries posted for one source event, oldest first.
+    async fn list_journal_entries_for_source(
+        &self,
+        tenant_id: &str,
+        source: JournalSource,
+        source_id: &str,
+    ) -> StorageResult<Vec<JournalEntry>>;
+    /// Debit and credit totals per account and currency for entries that
+    /// occurred before `as_of`, sorted by account then currency.
+    async fn trial_balance(
+        &self,
+        tenant_id: &str,
+        as_of: DateTime<Utc>,
+    ) -> StorageResult<Vec<TrialBalanceRow>>;
+
     // ─────────────────────────────────────────────────────────────────────────
     // Orders
     // ─────────────────────────────────────────────────────────────────────────
     async fn try_store_order(&self, order: Order) -> StorageResult<bool>;
     async fn get_order(&self, tenant_id: &str, order_id: &str) -> StorageResult<Option<Order>>;
+    /// Look up an order by the payment it was created from
+    async fn get_order_by_purchase_id(
+        &self,
+        tenant_id: &str,
+        purchase_id: &str,
+    ) -> StorageResult<Option<Order>>;
     async fn list_orders(
         &self,
         tenant_id: &str,
         limit: i32,
         offset: i32,
     ) -> StorageResult<Vec<Order>>;
+    /// List orders placed by a cedros-login user (newest first).
+    async fn list_orders_by_user_id(
+        &self,
+        tenant_id: &str,
+        user_id: &str,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<Order>>;
     #[allow(clippy::too_many_arguments)]
     async fn list_orders_filtered(
         &self,
@@ -477,6 +916,16 @@ pub trait Store: Send + Sync {
         status_updated_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> StorageResult<()>;
+    /// Persist a full return record (items, amounts, refund linkage, status).
+    async fn update_return_request(&self, request: ReturnRequest) -> StorageResult<()>;
+    /// Persist a full return record only while its stored status is
+    /// `expected_status`. Returns false when another request changed the
+    /// status first (or the return does not exist).
+    async fn update_return_request_if_status(
+        &self,
+        request: ReturnRequest,
+        expected_status: &str,
+    ) -> StorageResult<bool>;
     async fn get_return_request(
         &self,
         tenant_id: &str,
@@ -883,8 +1332,9 @@ pub trait Store: Send + Sync {
         hold_id: &str,
     ) -> StorageResult<Option<CreditsHold>>;
     async fn delete_credits_hold(&self, tenant_id: &str, hold_id: &str) -> StorageResult<()>;
-    /// Cleanup expired credits holds (admin operation across all tenants)
-    async fn cleanup_expired_credits_holds(&self) -> StorageResult<u64>;
+    /// Cleanup expired credits holds (admin operation across all tenants),
+    /// returning the removed holds
+    async fn cleanup_expired_credits_holds(&self) -> StorageResult<Vec<CreditsHold>>;
 
     /// List purchases for a given user_id with tenant isolation.
     async fn list_purchases_by_user_id(
@@ -904,6 +1354,32 @@ pub trait Store: Send + Sync {
     /// Archive old payments (admin operation across all tenants)
     async fn archive_old_payments(&self, older_than: DateTime<Utc>) -> StorageResult<u64>;
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Cold archival (payments, orders and webhooks moved to object storage)
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Records older than `older_than`, oldest first and at most `limit` per
+    /// kind: payments by creation time, orders not updated since, and
+    /// finished webhooks by completion time.
+    async fn list_archivable_records(
+        &self,
+        tenant_id: &str,
+        older_than: DateTime<Utc>,
+        limit: i64,
+    ) -> StorageResult<ArchivableRecords>;
+    /// Delete archived records and index the archived payments by signature,
+    /// atomically where the backend supports it. Returns the rows deleted.
+    async fn purge_archived_records(
+        &self,
+        tenant_id: &str,
+        purge: &ArchivePurge,
+    ) -> StorageResult<u64>;
+    /// Find where an archived payment was written
+    async fn find_archived_payment(
+        &self,
+        tenant_id: &str,
+        signature: &str,
+    ) -> StorageResult<Option<ArchivedPaymentRef>>;
+
     // ─────────────────────────────────────────────────────────────────────────
     // Admin nonces
     // Per spec (08-storage.md): All queries must filter by tenant_id
@@ -954,13 +1430,36 @@ pub trait Store: Send + Sync {
         Ok(0)
     }
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Event log
+    // Per spec (20-webhooks.md): Entries are scoped by tenant_id
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Append an event and return its sequence. `entry.sequence` is ignored;
+    /// appending an event ID already logged for the tenant returns the
+    /// existing sequence.
+    async fn append_event(&self, entry: EventLogEntry) -> StorageResult<i64>;
+    /// List events matching `query` in sequence order
+    async fn list_events(
+        &self,
+        tenant_id: &str,
+        query: &EventLogQuery,
+    ) -> StorageResult<Vec<EventLogEntry>>;
+    /// Delete events created before `older_than` (across all tenants)
+    async fn cleanup_old_events(&self, older_than: DateTime<Utc>) -> StorageResult<u64>;
+
     // ─────────────────────────────────────────────────────────────────────────
     // Email queue
     // ─────────────────────────────────────────────────────────────────────────
     async fn enqueue_email(&self, email: PendingEmail) -> StorageResult<String>;
     async fn dequeue_emails(&self, limit: i32) -> StorageResult<Vec<PendingEmail>>;
     async fn mark_email_processing(&self, email_id: &str) -> StorageResult<()>;
-    async fn mark_email_success(&self, email_id: &str) -> StorageResult<()>;
+    /// Record delivery through `provider`, with the provider's message id when it returned one
+    async fn mark_email_success(
+        &self,
+        email_id: &str,
+        provider: &str,
+        provider_message_id: Option<&str>,
+    ) -> StorageResult<()>;
     async fn mark_email_retry(
         &self,
         email_id: &str,
@@ -968,9 +1467,62 @@ pub trait Store: Send + Sync {
         next_attempt_at: DateTime<Utc>,
     ) -> StorageResult<()>;
     async fn mark_email_failed(&self, email_id: &str, error: &str) -> StorageResult<()>;
+    /// Complete an email without sending it because the recipient is suppressed
+    async fn mark_email_suppressed(&self, email_id: &str, reason: &str) -> StorageResult<()>;
+    /// Mark the email the provider knows as `provider_message_id` as bounced.
+    /// Returns false when no email of the tenant carries that id.
+    async fn mark_email_bounced(
+        &self,
+        tenant_id: &str,
+        provider_message_id: &str,
+        reason: &str,
+    ) -> StorageResult<bool>;
     async fn get_email(&self, email_id: &str) -> StorageResult<Option<PendingEmail>>;
     async fn cleanup_old_emails(&self, retention_days: i32) -> StorageResult<u64>;
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Email suppressions
+    // Addresses are normalized with `normalize_email_address` by callers
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Insert or replace the suppression for (tenant, email)
+    async fn upsert_email_suppression(&self, suppression: EmailSuppression) -> StorageResult<()>;
+    async fn get_email_suppression(
+        &self,
+        tenant_id: &str,
+        email: &str,
+    ) -> StorageResult<Option<EmailSuppression>>;
+    /// Newest first
+    async fn list_email_suppressions(
+        &self,
+        tenant_id: &str,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<EmailSuppression>>;
+    /// Returns false when the address was not suppressed
+    async fn delete_email_suppression(&self, tenant_id: &str, email: &str) -> StorageResult<bool>;
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Email templates
+    // Per spec (09-configuration.md): Templates are scoped by tenant_id
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Insert or replace the template for (tenant, kind, locale)
+    async fn upsert_email_template(&self, template: EmailTemplate) -> StorageResult<()>;
+    async fn get_email_template(
+        &self,
+        tenant_id: &str,
+        kind: &str,
+        locale: &str,
+    ) -> StorageResult<Option<EmailTemplate>>;
+    /// List a tenant's overrides ordered by kind, then locale
+    async fn list_email_templates(&self, tenant_id: &str) -> StorageResult<Vec<EmailTemplate>>;
+    /// Returns false when no override existed
+    async fn delete_email_template(
+        &self,
+        tenant_id: &str,
+        kind: &str,
+        locale: &str,
+    ) -> StorageResult<bool>;
+
     // ─────────────────────────────────────────────────────────────────────────
     // Idempotency
     // ─────────────────────────────────────────────────────────────────────────
@@ -1039,6 +1591,12 @@ pub trait Store: Send + Sync {
         tenant_id: &str,
         wallet: &str,
     ) -> StorageResult<Vec<Subscription>>;
+    /// Get all subscriptions owned by a cedros-login user, newest first
+    async fn get_subscriptions_by_user_id(
+        &self,
+        tenant_id: &str,
+        user_id: &str,
+    ) -> StorageResult<Vec<Subscription>>;
     /// Get subscription by Stripe ID with tenant isolation
     async fn get_subscription_by_stripe_id(
         &self,
@@ -1193,6 +1751,40 @@ pub trait Store: Send + Sync {
         limit: i32,
         offset: i32,
     ) -> StorageResult<(Vec<ChatSession>, i64)>;
+    /// Move a session to "waiting_for_agent". Returns false if the session
+    /// does not exist or a human is already requested/assigned.
+    async fn request_chat_handoff(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        reason: Option<&str>,
+        requested_at: DateTime<Utc>,
+    ) -> StorageResult<bool>;
+    /// Assign a waiting session to an agent. Returns false unless the session
+    /// was "waiting_for_agent" (so two agents cannot claim the same session).
+    async fn claim_chat_session(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        agent_id: &str,
+        claimed_at: DateTime<Utc>,
+    ) -> StorageResult<bool>;
+    /// Attach a verified customer to a session that has none. Returns false if
+    /// the session is missing or already belongs to a customer.
+    async fn bind_chat_session_customer(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        customer_id: &str,
+        updated_at: DateTime<Utc>,
+    ) -> StorageResult<bool>;
+    /// Hand a waiting/claimed session back to the assistant
+    async fn release_chat_session(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        updated_at: DateTime<Utc>,
+    ) -> StorageResult<bool>;
 
     // ─────────────────────────────────────────────────────────────────────────
     // Chat messages
@@ -1205,6 +1797,14 @@ pub trait Store: Send + Sync {
         limit: i32,
         offset: i32,
     ) -> StorageResult<Vec<ChatMessage>>;
+    /// Messages created strictly after `since`, oldest first
+    async fn list_chat_messages_since(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        since: DateTime<Utc>,
+        limit: i32,
+    ) -> StorageResult<Vec<ChatMessage>>;
 
     // ─────────────────────────────────────────────────────────────────────────
     // FAQs (knowledge base)
@@ -1235,6 +1835,106 @@ pub trait Store: Send + Sync {
         offset: i32,
     ) -> StorageResult<(Vec<Faq>, i64)>;
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Embeddings (semantic search)
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Insert or replace the embedding for (tenant, entity_type, entity_id)
+    async fn upsert_embedding(&self, record: EmbeddingRecord) -> StorageResult<()>;
+    /// Embeddings computed from one source (a product and its variants, or a FAQ)
+    async fn list_source_embeddings(
+        &self,
+        tenant_id: &str,
+        source_id: &str,
+        entity_types: &[EmbeddingEntity],
+    ) -> StorageResult<Vec<EmbeddingRecord>>;
+    /// Returns false when no embedding was stored
+    async fn delete_embedding(
+        &self,
+        tenant_id: &str,
+        entity_type: EmbeddingEntity,
+        entity_id: &str,
+    ) -> StorageResult<bool>;
+    /// Embeddings of `model` most similar to `query` (cosine), best first
+    async fn search_embeddings(
+        &self,
+        tenant_id: &str,
+        model: &str,
+        entity_types: &[EmbeddingEntity],
+        query: &[f32],
+        limit: i32,
+    ) -> StorageResult<Vec<EmbeddingMatch>>;
+    /// Queue a source for embedding, replacing any queued job for it
+    async fn enqueue_embedding_job(&self, job: EmbeddingJob) -> StorageResult<()>;
+    /// Remove and return up to `limit` jobs available at `now`, oldest first
+    async fn claim_embedding_jobs(
+        &self,
+        now: DateTime<Utc>,
+        limit: i32,
+    ) -> StorageResult<Vec<EmbeddingJob>>;
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // AI usage accounting
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Add the calls, tokens and cost of `usage` to its (tenant, period, task, model) row
+    async fn add_ai_usage(&self, usage: AiUsageRecord) -> StorageResult<()>;
+    /// Usage rows of a tenant for one period
+    async fn list_ai_usage(
+        &self,
+        tenant_id: &str,
+        period: &str,
+    ) -> StorageResult<Vec<AiUsageRecord>>;
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Bulk product import jobs
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Insert or replace an import job (progress is saved as the job runs)
+    async fn save_product_import_job(&self, job: ProductImportJob) -> StorageResult<()>;
+    async fn get_product_import_job(
+        &self,
+        tenant_id: &str,
+        job_id: &str,
+    ) -> StorageResult<Option<ProductImportJob>>;
+    /// Mark queued or running jobs last updated before `updated_before` as
+    /// failed with `error` (admin operation across all tenants). Returns the
+    /// number of jobs marked.
+    async fn fail_stale_product_import_jobs(
+        &self,
+        updated_before: DateTime<Utc>,
+        error: &str,
+    ) -> StorageResult<u64>;
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Scheduled price changes
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Insert or replace a price schedule
+    async fn save_price_schedule(&self, schedule: PriceSchedule) -> StorageResult<()>;
+    /// Save the progress of a schedule (status, captured prices, Stripe price,
+    /// error and timestamps) only while its stored status is still `expected`.
+    /// Returns false when another worker (or an admin) changed it first.
+    async fn update_price_schedule_if_status(
+        &self,
+        schedule: PriceSchedule,
+        expected: PriceScheduleStatus,
+    ) -> StorageResult<bool>;
+    async fn get_price_schedule(
+        &self,
+        tenant_id: &str,
+        schedule_id: &str,
+    ) -> StorageResult<Option<PriceSchedule>>;
+    /// Schedules of a product (including its variants), latest start first
+    async fn list_product_price_schedules(
+        &self,
+        tenant_id: &str,
+        product_id: &str,
+    ) -> StorageResult<Vec<PriceSchedule>>;
+    /// Schedules across tenants with work due at `now`: scheduled ones whose
+    /// start has passed and active ones whose end has passed, earliest first
+    async fn list_due_price_schedules(
+        &self,
+        now: DateTime<Utc>,
+        limit: i32,
+    ) -> StorageResult<Vec<PriceSchedule>>;
+
     // ─────────────────────────────────────────────────────────────────────────
     // Compliance: token holders + compliance actions
     // ─────────────────────────────────────────────────────────────────────────
@@ -3,11 +3,13 @@
 use std::collections::HashMap;
 
 use crate::models::{
-    get_asset, AdminAuditEntry, BillingPeriod, CartItem, CartQuote, ChatMessage, ChatSession,
-    Collection, Customer, CustomerAddress, DisputeRecord, Faq, Fulfillment, GiftCard,
-    InventoryAdjustment, InventoryReservation, Money, Order, OrderHistoryEntry, OrderItem,
-    OrderShipping, PaymentMethod, PaymentTransaction, RefundQuote, ReturnRequest, ShippingProfile,
-    ShippingRate, StripeRefundRequest, Subscription, SubscriptionStatus, TaxRate,
+    get_asset, AdminAuditEntry, Affiliate, AffiliateCommission, BillingPeriod, CartItem, CartQuote,
+    ChatMessage, ChatSession, Collection, CommissionEntryKind, CommissionType, Customer,
+    CustomerAddress, DisputeRecord, Faq, Fulfillment, GiftCard, InventoryAdjustment,
+    InventoryReservation, JournalEntry, JournalSource, Money, Order, OrderHistoryEntry, OrderItem,
+    OrderShipping, PaymentMethod, PaymentTransaction, RefundQuote, ReturnItem, ReturnRequest,
+    ShippingProfile, ShippingRate, StripeApplicationFee, StripeConnectAccount, StripeRefundRequest,
+    Subscription, SubscriptionStatus, TaxRate,
 };
 use crate::storage::{
     AdminNonce, CreditsHold, DlqWebhook, EmailStatus, IdempotencyResponse, PendingEmail,
@@ -224,7 +226,7 @@ pub fn parse_fulfillment(row: PgRow) -> StorageResult<Fulfillment> {
 
 pub fn parse_return_request(row: PgRow) -> StorageResult<ReturnRequest> {
     let items_json: serde_json::Value = row.get("items");
-    let items: Vec<OrderItem> = serde_json::from_value(items_json)
+    let items: Vec<ReturnItem> = serde_json::from_value(items_json)
         .map_err(|e| StorageError::internal("failed to parse return items", e))?;
     let metadata_json: serde_json::Value = row.get("metadata");
     let metadata = parse_string_map(metadata_json, "return metadata")?;
@@ -240,6 +242,11 @@ pub fn parse_return_request(row: PgRow) -> StorageResult<ReturnRequest> {
         created_at: row.get("created_at"),
         updated_at: row.try_get("updated_at").ok(),
         status_updated_at: row.try_get("status_updated_at").ok(),
+        restocking_fee: row.try_get("restocking_fee").ok().flatten(),
+        refund_amount: row.try_get("refund_amount").ok().flatten(),
+        refund_method: row.try_get("refund_method").ok().flatten(),
+        refund_id: row.try_get("refund_id").ok().flatten(),
+        restocked_at: row.try_get("restocked_at").ok().flatten(),
     })
 }
 
@@ -400,6 +407,11 @@ pub fn parse_collection(row: PgRow) -> StorageResult<Collection> {
         .ok()
         .flatten()
         .and_then(|v| serde_json::from_value(v).ok());
+    let payment_split: Option<crate::models::PaymentSplit> = row
+        .try_get::<Option<serde_json::Value>, _>("payment_split")
+        .ok()
+        .flatten()
+        .and_then(|v| serde_json::from_value(v).ok());
 
     Ok(Collection {
         id: row.get("id"),
@@ -409,6 +421,7 @@ pub fn parse_collection(row: PgRow) -> StorageResult<Collection> {
         product_ids,
         active: row.get("active"),
         tokenization_config,
+        payment_split,
         created_at: row.get("created_at"),
         updated_at: row.get("updated_at"),
     })
@@ -441,6 +454,108 @@ pub fn parse_payment_transaction(row: PgRow) -> StorageResult<PaymentTransaction
     })
 }
 
+pub fn parse_affiliate(row: PgRow) -> StorageResult<Affiliate> {
+    let tenant_id = parse_tenant_id(&row, "affiliate")?;
+    let commission_type: String = row.get("commission_type");
+    let commission_type = match commission_type.as_str() {
+        "fixed" => CommissionType::Fixed,
+        _ => CommissionType::Percentage,
+    };
+    let rules_json: serde_json::Value = row.get("rules");
+    let rules = serde_json::from_value(rules_json)
+        .map_err(|e| StorageError::internal("failed to parse affiliate rules", e))?;
+
+    Ok(Affiliate {
+        id: row.get("id"),
+        tenant_id,
+        code: row.get("code"),
+        name: row.get("name"),
+        email: row.get("email"),
+        payout_wallet: row.get("payout_wallet"),
+        active: row.get("active"),
+        commission_type,
+        commission_value: row.get("commission_value"),
+        rules,
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+pub fn parse_affiliate_commission(row: PgRow) -> StorageResult<AffiliateCommission> {
+    let tenant_id = parse_tenant_id(&row, "affiliate_commission")?;
+    let kind: String = row.get("kind");
+    let kind = CommissionEntryKind::parse(&kind).ok_or_else(|| {
+        StorageError::Database(format!("unknown affiliate commission kind: {}", kind))
+    })?;
+
+    Ok(AffiliateCommission {
+        id: row.get("id"),
+        tenant_id,
+        affiliate_id: row.get("affiliate_id"),
+        order_id: row.get("order_id"),
+        purchase_id: row.get("purchase_id"),
+        kind,
+        amount: row.get("amount"),
+        order_amount: row.get("order_amount"),
+        currency: row.get("currency"),
+        refund_id: row.get("refund_id"),
+        created_at: row.get("created_at"),
+    })
+}
+
+pub fn parse_journal_entry(row: PgRow) -> StorageResult<JournalEntry> {
+    let tenant_id = parse_tenant_id(&row, "journal_entry")?;
+    let source: String = row.get("source");
+    let source = JournalSource::parse(&source)
+        .ok_or_else(|| StorageError::Database(format!("unknown journal source: {}", source)))?;
+    let lines_json: serde_json::Value = row.get("lines");
+    let lines = serde_json::from_value(lines_json)
+        .map_err(|e| StorageError::internal("failed to parse journal lines", e))?;
+
+    Ok(JournalEntry {
+        id: row.get("id"),
+        tenant_id,
+        source,
+        source_id: row.get("source_id"),
+        currency: row.get("currency"),
+        description: row.get("description"),
+        lines,
+        occurred_at: row.get("occurred_at"),
+        created_at: row.get("created_at"),
+    })
+}
+
+pub fn parse_stripe_connect_account(row: PgRow) -> StorageResult<StripeConnectAccount> {
+    let tenant_id = parse_tenant_id(&row, "stripe_connect_account")?;
+    let application_fee_bps: Option<i32> = row.get("application_fee_bps");
+
+    Ok(StripeConnectAccount {
+        tenant_id,
+        account_id: row.get("account_id"),
+        charges_enabled: row.get("charges_enabled"),
+        payouts_enabled: row.get("payouts_enabled"),
+        details_submitted: row.get("details_submitted"),
+        application_fee_bps: application_fee_bps.map(|v| v.max(0) as u32),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+pub fn parse_stripe_application_fee(row: PgRow) -> StorageResult<StripeApplicationFee> {
+    let tenant_id = parse_tenant_id(&row, "stripe_application_fee")?;
+
+    Ok(StripeApplicationFee {
+        id: row.get("id"),
+        tenant_id,
+        account_id: row.get("account_id"),
+        charge_id: row.get("charge_id"),
+        amount: row.get("amount"),
+        amount_refunded: row.get("amount_refunded"),
+        currency: row.get("currency"),
+        created_at: row.get("created_at"),
+    })
+}
+
 pub fn parse_stripe_refund_request(row: PgRow) -> StorageResult<StripeRefundRequest> {
     let id: String = row.get("id");
     let tenant_id = parse_tenant_id(&row, "stripe_refund_request")?;
@@ -564,6 +679,8 @@ pub fn parse_email(row: PgRow) -> StorageResult<PendingEmail> {
         "pending" => EmailStatus::Pending,
         "completed" => EmailStatus::Completed,
         "failed" => EmailStatus::Failed,
+        "suppressed" => EmailStatus::Suppressed,
+        "bounced" => EmailStatus::Bounced,
         unknown => {
             tracing::warn!(
                 status = %unknown,
@@ -590,6 +707,9 @@ pub fn parse_email(row: PgRow) -> StorageResult<PendingEmail> {
         next_attempt_at: row.get("next_attempt_at"),
         created_at: row.get("created_at"),
         completed_at: row.get("completed_at"),
+        traceparent: row.get("traceparent"),
+        provider: row.get("provider"),
+        provider_message_id: row.get("provider_message_id"),
     })
 }
 
@@ -715,6 +835,9 @@ pub fn parse_chat_session(row: PgRow) -> StorageResult<ChatSession> {
         customer_email: row.get("customer_email"),
         status: row.get("status"),
         message_count: row.get("message_count"),
+        assigned_agent: row.get("assigned_agent"),
+        handoff_reason: row.get("handoff_reason"),
+        handoff_requested_at: row.get("handoff_requested_at"),
         last_message_at: row.get("last_message_at"),
         created_at: row.get("created_at"),
         updated_at: row.get("updated_at"),
@@ -731,6 +854,7 @@ pub fn parse_chat_message(row: PgRow) -> StorageResult<ChatMessage> {
         content: row.get("content"),
         tool_calls: row.get("tool_calls"),
         tool_results: row.get("tool_results"),
+        author_id: row.get("author_id"),
         created_at: row.get("created_at"),
     })
 }
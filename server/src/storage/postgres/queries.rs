@@ -90,6 +90,16 @@ pub mod refund {
         LIMIT $2
     "#;
 
+    /// Executed refunds in a processing window (financial reporting)
+    pub const LIST_PROCESSED_BETWEEN: &str = r#"
+        SELECT id, tenant_id, original_purchase_id, recipient_wallet, amount, amount_asset, reason,
+               metadata, created_at, expires_at, processed_by, processed_at, signature
+        FROM refund_quotes
+        WHERE tenant_id = $1 AND signature IS NOT NULL
+          AND processed_at >= $2 AND processed_at < $3
+        ORDER BY processed_at ASC
+    "#;
+
     pub const COUNT_PENDING: &str = r#"
         SELECT COUNT(*)
         FROM refund_quotes
@@ -158,6 +168,230 @@ pub mod refund {
 
 /// Stripe refund request queries
 /// Per spec (08-storage.md): All queries must include tenant_id for multi-tenant isolation
+pub mod stripe_connect {
+    pub const UPSERT_ACCOUNT: &str = r#"
+        INSERT INTO stripe_connect_accounts (
+            tenant_id, account_id, charges_enabled, payouts_enabled, details_submitted,
+            application_fee_bps, created_at, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ON CONFLICT (tenant_id) DO UPDATE SET
+            account_id = EXCLUDED.account_id,
+            charges_enabled = EXCLUDED.charges_enabled,
+            payouts_enabled = EXCLUDED.payouts_enabled,
+            details_submitted = EXCLUDED.details_submitted,
+            application_fee_bps = EXCLUDED.application_fee_bps,
+            updated_at = EXCLUDED.updated_at
+    "#;
+
+    pub const GET_ACCOUNT_BY_TENANT: &str = r#"
+        SELECT tenant_id, account_id, charges_enabled, payouts_enabled, details_submitted,
+               application_fee_bps, created_at, updated_at
+        FROM stripe_connect_accounts
+        WHERE tenant_id = $1
+    "#;
+
+    pub const GET_ACCOUNT_BY_ACCOUNT_ID: &str = r#"
+        SELECT tenant_id, account_id, charges_enabled, payouts_enabled, details_submitted,
+               application_fee_bps, created_at, updated_at
+        FROM stripe_connect_accounts
+        WHERE account_id = $1
+    "#;
+
+    pub const UPSERT_FEE: &str = r#"
+        INSERT INTO stripe_application_fees (
+            id, tenant_id, account_id, charge_id, amount, amount_refunded, currency, created_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ON CONFLICT (tenant_id, id) DO UPDATE SET
+            amount = EXCLUDED.amount,
+            amount_refunded = EXCLUDED.amount_refunded
+    "#;
+
+    pub const LIST_FEES: &str = r#"
+        SELECT id, tenant_id, account_id, charge_id, amount, amount_refunded, currency, created_at
+        FROM stripe_application_fees
+        WHERE tenant_id = $1 AND created_at >= $2 AND created_at < $3
+        ORDER BY created_at ASC
+    "#;
+}
+
+/// Affiliate and commission ledger queries
+pub mod affiliates {
+    pub const UPSERT: &str = r#"
+        INSERT INTO affiliates (
+            id, tenant_id, code, name, email, payout_wallet, active,
+            commission_type, commission_value, rules, created_at, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+        ON CONFLICT (tenant_id, id) DO UPDATE SET
+            code = EXCLUDED.code,
+            name = EXCLUDED.name,
+            email = EXCLUDED.email,
+            payout_wallet = EXCLUDED.payout_wallet,
+            active = EXCLUDED.active,
+            commission_type = EXCLUDED.commission_type,
+            commission_value = EXCLUDED.commission_value,
+            rules = EXCLUDED.rules,
+            updated_at = EXCLUDED.updated_at
+    "#;
+
+    pub const GET_BY_ID: &str = r#"
+        SELECT id, tenant_id, code, name, email, payout_wallet, active,
+               commission_type, commission_value, rules, created_at, updated_at
+        FROM affiliates
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const GET_BY_CODE: &str = r#"
+        SELECT id, tenant_id, code, name, email, payout_wallet, active,
+               commission_type, commission_value, rules, created_at, updated_at
+        FROM affiliates
+        WHERE tenant_id = $1 AND code = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, code, name, email, payout_wallet, active,
+               commission_type, commission_value, rules, created_at, updated_at
+        FROM affiliates
+        WHERE tenant_id = $1
+        ORDER BY code ASC
+        LIMIT $2 OFFSET $3
+    "#;
+
+    pub const INSERT_COMMISSION: &str = r#"
+        INSERT INTO affiliate_commissions (
+            id, tenant_id, affiliate_id, order_id, purchase_id, kind, amount,
+            order_amount, currency, refund_id, created_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        ON CONFLICT (tenant_id, id) DO NOTHING
+    "#;
+
+    pub const LIST_COMMISSIONS: &str = r#"
+        SELECT id, tenant_id, affiliate_id, order_id, purchase_id, kind, amount,
+               order_amount, currency, refund_id, created_at
+        FROM affiliate_commissions
+        WHERE tenant_id = $1
+          AND ($2::TEXT IS NULL OR affiliate_id = $2)
+          AND created_at >= $3 AND created_at < $4
+        ORDER BY created_at ASC
+    "#;
+
+    pub const LIST_COMMISSIONS_BY_PURCHASE: &str = r#"
+        SELECT id, tenant_id, affiliate_id, order_id, purchase_id, kind, amount,
+               order_amount, currency, refund_id, created_at
+        FROM affiliate_commissions
+        WHERE tenant_id = $1 AND purchase_id = $2
+        ORDER BY created_at ASC
+    "#;
+}
+
+pub mod ledger {
+    pub const INSERT: &str = r#"
+        INSERT INTO ledger_entries (
+            id, tenant_id, source, source_id, currency, description, lines,
+            occurred_at, created_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (tenant_id, id) DO NOTHING
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, source, source_id, currency, description, lines,
+               occurred_at, created_at
+        FROM ledger_entries
+        WHERE tenant_id = $1 AND occurred_at >= $2 AND occurred_at < $3
+        ORDER BY occurred_at ASC, id ASC
+        LIMIT $4 OFFSET $5
+    "#;
+
+    pub const LIST_BY_SOURCE: &str = r#"
+        SELECT id, tenant_id, source, source_id, currency, description, lines,
+               occurred_at, created_at
+        FROM ledger_entries
+        WHERE tenant_id = $1 AND source = $2 AND source_id = $3
+        ORDER BY occurred_at ASC, id ASC
+    "#;
+
+    pub const TRIAL_BALANCE: &str = r#"
+        SELECT line->>'account' AS account, currency,
+               COALESCE(SUM((line->>'debit')::BIGINT), 0)::BIGINT AS debit,
+               COALESCE(SUM((line->>'credit')::BIGINT), 0)::BIGINT AS credit
+        FROM ledger_entries, jsonb_array_elements(lines) AS line
+        WHERE tenant_id = $1 AND occurred_at < $2
+        GROUP BY line->>'account', currency
+        ORDER BY account ASC, currency ASC
+    "#;
+}
+
+/// Cold archival: records past retention and the archived payment index
+pub mod archive {
+    pub const LIST_PAYMENTS: &str = r#"
+        SELECT signature, tenant_id, resource_id, wallet, user_id, amount, amount_asset, created_at, metadata
+        FROM payment_transactions
+        WHERE tenant_id = $1 AND created_at < $2
+        ORDER BY created_at ASC, signature ASC
+        LIMIT $3
+    "#;
+
+    pub const LIST_ORDERS: &str = r#"
+        SELECT id, tenant_id, source, purchase_id, resource_id, user_id, customer, status,
+               items, amount, amount_asset, customer_email, customer_name, receipt_url,
+               shipping, metadata, created_at, updated_at, status_updated_at
+        FROM orders
+        WHERE tenant_id = $1 AND COALESCE(updated_at, created_at) < $2
+        ORDER BY COALESCE(updated_at, created_at) ASC, id ASC
+        LIMIT $3
+    "#;
+
+    pub const LIST_ORDER_HISTORY: &str = r#"
+        SELECT id, tenant_id, order_id, from_status, to_status, note, actor, created_at
+        FROM order_history
+        WHERE tenant_id = $1 AND order_id = ANY($2)
+        ORDER BY order_id ASC, created_at ASC
+    "#;
+
+    pub const LIST_WEBHOOKS: &str = r#"
+        SELECT id, tenant_id, url, payload, payload_bytes, headers, event_type, status, attempts, max_attempts,
+               last_error, last_attempt_at, next_attempt_at, created_at, completed_at
+        FROM webhook_queue
+        WHERE tenant_id = $1 AND status IN ('success', 'failed') AND completed_at < $2
+        ORDER BY completed_at ASC, id ASC
+        LIMIT $3
+    "#;
+
+    pub const INDEX_PAYMENTS: &str = r#"
+        INSERT INTO archived_payments (tenant_id, signature, object_key, archived_at)
+        SELECT $1, signature, object_key, archived_at
+        FROM UNNEST($2::text[], $3::text[], $4::timestamptz[]) AS t(signature, object_key, archived_at)
+        ON CONFLICT (tenant_id, signature) DO UPDATE
+        SET object_key = EXCLUDED.object_key, archived_at = EXCLUDED.archived_at
+    "#;
+
+    pub const DELETE_PAYMENTS: &str = r#"
+        DELETE FROM payment_transactions WHERE tenant_id = $1 AND signature = ANY($2)
+    "#;
+
+    pub const DELETE_ORDER_HISTORY: &str = r#"
+        DELETE FROM order_history WHERE tenant_id = $1 AND order_id = ANY($2)
+    "#;
+
+    pub const DELETE_ORDERS: &str = r#"
+        DELETE FROM orders WHERE tenant_id = $1 AND id = ANY($2)
+    "#;
+
+    pub const DELETE_WEBHOOKS: &str = r#"
+        DELETE FROM webhook_queue WHERE tenant_id = $1 AND id = ANY($2)
+    "#;
+
+    pub const GET_ARCHIVED_PAYMENT: &str = r#"
+        SELECT tenant_id, signature, object_key, archived_at
+        FROM archived_payments
+        WHERE tenant_id = $1 AND signature = $2
+    "#;
+}
+
 pub mod stripe_refund_request {
     pub const UPSERT: &str = r#"
         INSERT INTO stripe_refund_requests (
@@ -199,6 +433,16 @@ pub mod stripe_refund_request {
         LIMIT $2
     "#;
 
+    pub const LIST_PROCESSED_BETWEEN: &str = r#"
+        SELECT id, tenant_id, original_purchase_id, stripe_payment_intent_id, stripe_refund_id,
+               stripe_charge_id, amount, currency, status, reason, metadata,
+               created_at, processed_by, processed_at, last_error
+        FROM stripe_refund_requests
+        WHERE tenant_id = $1 AND status NOT IN ('failed', 'canceled')
+          AND processed_at >= $2 AND processed_at < $3
+        ORDER BY processed_at ASC
+    "#;
+
     pub const GET_PENDING_BY_ORIGINAL_PURCHASE_ID: &str = r#"
         SELECT id, tenant_id, original_purchase_id, stripe_payment_intent_id, stripe_refund_id,
                stripe_charge_id, amount, currency, status, reason, metadata,
@@ -241,6 +485,16 @@ pub mod orders {
         WHERE tenant_id = $1 AND id = $2
     "#;
 
+    pub const GET_BY_PURCHASE_ID: &str = r#"
+        SELECT id, tenant_id, source, purchase_id, resource_id, user_id, customer, status,
+               items, amount, amount_asset, customer_email, customer_name, receipt_url,
+               shipping, metadata, created_at, updated_at, status_updated_at
+        FROM orders
+        WHERE tenant_id = $1 AND purchase_id = $2
+        ORDER BY created_at ASC
+        LIMIT 1
+    "#;
+
     pub const LIST: &str = r#"
         SELECT id, tenant_id, source, purchase_id, resource_id, user_id, customer, status,
                items, amount, amount_asset, customer_email, customer_name, receipt_url,
@@ -251,6 +505,16 @@ pub mod orders {
         LIMIT $2 OFFSET $3
     "#;
 
+    pub const LIST_BY_USER: &str = r#"
+        SELECT id, tenant_id, source, purchase_id, resource_id, user_id, customer, status,
+               items, amount, amount_asset, customer_email, customer_name, receipt_url,
+               shipping, metadata, created_at, updated_at, status_updated_at
+        FROM orders
+        WHERE tenant_id = $1 AND user_id = $2
+        ORDER BY created_at DESC
+        LIMIT $3 OFFSET $4
+    "#;
+
     pub const LIST_FILTERED: &str = r#"
         SELECT id, tenant_id, source, purchase_id, resource_id, user_id, customer, status,
                items, amount, amount_asset, customer_email, customer_name, receipt_url,
@@ -356,9 +620,43 @@ pub mod fulfillments {
 pub mod returns {
     pub const INSERT: &str = r#"
         INSERT INTO returns (
-            id, tenant_id, order_id, status, items, reason, metadata, created_at, updated_at, status_updated_at
+            id, tenant_id, order_id, status, items, reason, metadata, created_at, updated_at, status_updated_at,
+            restocking_fee, refund_amount, refund_method, refund_id, restocked_at
         )
-        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10)
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15)
+    "#;
+
+    pub const UPDATE: &str = r#"
+        UPDATE returns
+        SET status = $3,
+            items = $4,
+            reason = $5,
+            metadata = $6,
+            updated_at = $7,
+            status_updated_at = $8,
+            restocking_fee = $9,
+            refund_amount = $10,
+            refund_method = $11,
+            refund_id = $12,
+            restocked_at = $13
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    /// UPDATE that only applies while the return is still in status $14
+    pub const UPDATE_IF_STATUS: &str = r#"
+        UPDATE returns
+        SET status = $3,
+            items = $4,
+            reason = $5,
+            metadata = $6,
+            updated_at = $7,
+            status_updated_at = $8,
+            restocking_fee = $9,
+            refund_amount = $10,
+            refund_method = $11,
+            refund_id = $12,
+            restocked_at = $13
+        WHERE tenant_id = $1 AND id = $2 AND status = $14
     "#;
 
     pub const UPDATE_STATUS: &str = r#"
@@ -370,13 +668,15 @@ pub mod returns {
     "#;
 
     pub const GET: &str = r#"
-        SELECT id, tenant_id, order_id, status, items, reason, metadata, created_at, updated_at, status_updated_at
+        SELECT id, tenant_id, order_id, status, items, reason, metadata, created_at, updated_at, status_updated_at,
+               restocking_fee, refund_amount, refund_method, refund_id, restocked_at
         FROM returns
         WHERE tenant_id = $1 AND id = $2
     "#;
 
     pub const LIST: &str = r#"
-        SELECT id, tenant_id, order_id, status, items, reason, metadata, created_at, updated_at, status_updated_at
+        SELECT id, tenant_id, order_id, status, items, reason, metadata, created_at, updated_at, status_updated_at,
+               restocking_fee, refund_amount, refund_method, refund_id, restocked_at
         FROM returns
         WHERE tenant_id = $1
           AND ($2::text IS NULL OR status = $2)
@@ -724,9 +1024,10 @@ pub mod gift_cards {
 pub mod collections {
     pub const INSERT: &str = r#"
         INSERT INTO collections (
-            id, tenant_id, name, description, product_ids, active, tokenization_config, created_at, updated_at
+            id, tenant_id, name, description, product_ids, active, tokenization_config, payment_split,
+            created_at, updated_at
         )
-        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9)
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10)
     "#;
 
     pub const UPDATE: &str = r#"
@@ -736,18 +1037,21 @@ pub mod collections {
             product_ids = $5,
             active = $6,
             tokenization_config = $7,
-            updated_at = $8
+            payment_split = $8,
+            updated_at = $9
         WHERE tenant_id = $1 AND id = $2
     "#;
 
     pub const GET: &str = r#"
-        SELECT id, tenant_id, name, description, product_ids, active, tokenization_config, created_at, updated_at
+        SELECT id, tenant_id, name, description, product_ids, active, tokenization_config, payment_split,
+               created_at, updated_at
         FROM collections
         WHERE tenant_id = $1 AND id = $2
     "#;
 
     pub const LIST: &str = r#"
-        SELECT id, tenant_id, name, description, product_ids, active, tokenization_config, created_at, updated_at
+        SELECT id, tenant_id, name, description, product_ids, active, tokenization_config, payment_split,
+               created_at, updated_at
         FROM collections
         WHERE tenant_id = $1
           AND ($2::boolean IS NULL OR active = $2)
@@ -852,13 +1156,15 @@ pub mod credits_hold {
         DELETE FROM credits_holds WHERE tenant_id = $1 AND hold_id = $2
     "#;
 
-    /// Batched to avoid long locks on large backlogs
+    /// Batched to avoid long locks on large backlogs; returns the removed
+    /// holds so their ledger entries can be released
     pub const CLEANUP_EXPIRED: &str = r#"
         DELETE FROM credits_holds WHERE ctid IN (
             SELECT ctid FROM credits_holds
             WHERE expires_at < $1
             LIMIT 1000
         )
+        RETURNING tenant_id, hold_id, user_id, resource_id, amount, amount_asset, created_at, expires_at
     "#;
 }
 
@@ -891,7 +1197,202 @@ pub mod nonce {
     "#;
 }
 
-/// Webhook queue queries
+/// Event log queries
+pub mod event_log {
+    /// Serializes appends per tenant until the transaction ends. BIGSERIAL
+    /// values are drawn before commit, so without it a reader could see a
+    /// later sequence first and move its `after` cursor past an earlier one
+    /// that commits afterwards.
+    pub const LOCK_TENANT: &str = r#"
+        SELECT pg_advisory_xact_lock(hashtext('cedros_event_log'), hashtext($1))
+    "#;
+
+    pub const APPEND: &str = r#"
+        INSERT INTO event_log (tenant_id, event_id, event_type, payload, created_at)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (tenant_id, event_id) DO NOTHING
+        RETURNING sequence
+    "#;
+
+    pub const GET_SEQUENCE: &str = r#"
+        SELECT sequence FROM event_log WHERE tenant_id = $1 AND event_id = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT sequence, tenant_id, event_id, event_type, payload, created_at
+        FROM event_log
+        WHERE tenant_id = $1
+          AND ($2::BIGINT IS NULL OR sequence > $2)
+          AND ($3::TEXT IS NULL OR event_type = $3)
+          AND ($4::TIMESTAMPTZ IS NULL OR created_at >= $4)
+          AND ($5::TIMESTAMPTZ IS NULL OR created_at < $5)
+        ORDER BY sequence ASC
+        LIMIT $6
+    "#;
+
+    pub const CLEANUP_OLD: &str = r#"
+        DELETE FROM event_log WHERE created_at < $1
+    "#;
+}
+
+pub mod email_template {
+    pub const UPSERT: &str = r#"
+        INSERT INTO email_templates (
+            tenant_id, kind, locale, subject, body_text, body_html, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (tenant_id, kind, locale) DO UPDATE SET
+            subject = EXCLUDED.subject,
+            body_text = EXCLUDED.body_text,
+            body_html = EXCLUDED.body_html,
+            updated_at = EXCLUDED.updated_at
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT tenant_id, kind, locale, subject, body_text, body_html, updated_at
+        FROM email_templates
+        WHERE tenant_id = $1 AND kind = $2 AND locale = $3
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT tenant_id, kind, locale, subject, body_text, body_html, updated_at
+        FROM email_templates
+        WHERE tenant_id = $1
+        ORDER BY kind ASC, locale ASC
+    "#;
+
+    pub const DELETE: &str = r#"
+        DELETE FROM email_templates WHERE tenant_id = $1 AND kind = $2 AND locale = $3
+    "#;
+}
+
+pub mod email_suppression {
+    pub const UPSERT: &str = r#"
+        INSERT INTO email_suppressions (tenant_id, email, reason, detail, created_at)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (tenant_id, email) DO UPDATE SET
+            reason = EXCLUDED.reason,
+            detail = EXCLUDED.detail,
+            created_at = EXCLUDED.created_at
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT tenant_id, email, reason, detail, created_at
+        FROM email_suppressions
+        WHERE tenant_id = $1 AND email = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT tenant_id, email, reason, detail, created_at
+        FROM email_suppressions
+        WHERE tenant_id = $1
+        ORDER BY created_at DESC, email ASC
+        LIMIT $2 OFFSET $3
+    "#;
+
+    pub const DELETE: &str = r#"
+        DELETE FROM email_suppressions WHERE tenant_id = $1 AND email = $2
+    "#;
+}
+
+pub mod embedding {
+    pub const UPSERT: &str = r#"
+        INSERT INTO embeddings (
+            tenant_id, entity_type, entity_id, source_id, model, content_hash,
+            dimensions, embedding, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (tenant_id, entity_type, entity_id) DO UPDATE SET
+            source_id = EXCLUDED.source_id,
+            model = EXCLUDED.model,
+            content_hash = EXCLUDED.content_hash,
+            dimensions = EXCLUDED.dimensions,
+            embedding = EXCLUDED.embedding,
+            updated_at = EXCLUDED.updated_at
+    "#;
+
+    pub const LIST_BY_SOURCE: &str = r#"
+        SELECT tenant_id, entity_type, entity_id, source_id, model, content_hash,
+               embedding, updated_at
+        FROM embeddings
+        WHERE tenant_id = $1 AND source_id = $2 AND entity_type = ANY($3)
+    "#;
+
+    pub const DELETE: &str = r#"
+        DELETE FROM embeddings
+        WHERE tenant_id = $1 AND entity_type = $2 AND entity_id = $3
+    "#;
+
+    /// Whether the pgvector extension is installed
+    pub const HAS_PGVECTOR: &str = r#"
+        SELECT EXISTS (SELECT 1 FROM pg_extension WHERE extname = 'vector')
+    "#;
+
+    /// Ranked in the database; requires the pgvector extension.
+    /// $1: tenant_id, $2: model, $3: entity types, $4: dimensions, $5: query vector, $6: limit
+    pub const SEARCH_PGVECTOR: &str = r#"
+        SELECT entity_type, entity_id, source_id,
+               (1 - (embedding::vector <=> $5::real[]::vector))::REAL AS score
+        FROM embeddings
+        WHERE tenant_id = $1 AND model = $2 AND entity_type = ANY($3) AND dimensions = $4
+        ORDER BY embedding::vector <=> $5::real[]::vector
+        LIMIT $6
+    "#;
+
+    /// Candidates ranked by the server when pgvector is not installed
+    pub const LIST_FOR_SEARCH: &str = r#"
+        SELECT tenant_id, entity_type, entity_id, source_id, model, content_hash,
+               embedding, updated_at
+        FROM embeddings
+        WHERE tenant_id = $1 AND model = $2 AND entity_type = ANY($3) AND dimensions = $4
+    "#;
+
+    pub const ENQUEUE_JOB: &str = r#"
+        INSERT INTO embedding_jobs (tenant_id, source_type, source_id, attempts, available_at)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (tenant_id, source_type, source_id) DO UPDATE SET
+            attempts = EXCLUDED.attempts,
+            available_at = EXCLUDED.available_at
+    "#;
+
+    /// SKIP LOCKED lets several workers claim disjoint batches
+    pub const CLAIM_JOBS: &str = r#"
+        DELETE FROM embedding_jobs
+        WHERE (tenant_id, source_type, source_id) IN (
+            SELECT tenant_id, source_type, source_id FROM embedding_jobs
+            WHERE available_at <= $1
+            ORDER BY available_at ASC
+            LIMIT $2
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING tenant_id, source_type, source_id, attempts, available_at
+    "#;
+}
+
+pub mod ai_usage {
+    pub const ADD: &str = r#"
+        INSERT INTO ai_usage (
+            tenant_id, period, task, model, calls, input_tokens, output_tokens,
+            cost_micros, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (tenant_id, period, task, model) DO UPDATE SET
+            calls = ai_usage.calls + EXCLUDED.calls,
+            input_tokens = ai_usage.input_tokens + EXCLUDED.input_tokens,
+            output_tokens = ai_usage.output_tokens + EXCLUDED.output_tokens,
+            cost_micros = ai_usage.cost_micros + EXCLUDED.cost_micros,
+            updated_at = EXCLUDED.updated_at
+    "#;
+
+    pub const LIST_BY_PERIOD: &str = r#"
+        SELECT tenant_id, period, task, model, calls, input_tokens, output_tokens,
+               cost_micros, updated_at
+        FROM ai_usage
+        WHERE tenant_id = $1 AND period = $2
+        ORDER BY task ASC, model ASC
+    "#;
+}
+
 pub mod webhook {
     /// Per spec (20-webhooks.md): INSERT must include tenant_id for multi-tenancy
     pub const INSERT: &str = r#"
@@ -994,9 +1495,9 @@ pub mod email {
         INSERT INTO email_queue (
             id, tenant_id, to_email, from_email, from_name, subject, body_text, body_html,
             status, attempts, max_attempts, last_error, last_attempt_at, next_attempt_at,
-            created_at, completed_at
+            created_at, completed_at, traceparent, provider, provider_message_id
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
         ON CONFLICT (id) DO NOTHING
     "#;
 
@@ -1017,7 +1518,7 @@ pub mod email {
         )
         RETURNING id, tenant_id, to_email, from_email, from_name, subject, body_text, body_html,
                   status, attempts, max_attempts, last_error, last_attempt_at, next_attempt_at,
-                  created_at, completed_at
+                  created_at, completed_at, traceparent, provider, provider_message_id
     "#;
 
     pub const MARK_PROCESSING: &str = r#"
@@ -1025,7 +1526,9 @@ pub mod email {
     "#;
 
     pub const MARK_SUCCESS: &str = r#"
-        UPDATE email_queue SET status = 'completed', completed_at = NOW() WHERE id = $1
+        UPDATE email_queue
+        SET status = 'completed', completed_at = NOW(), provider = $2, provider_message_id = $3
+        WHERE id = $1
     "#;
 
     pub const MARK_RETRY: &str = r#"
@@ -1040,10 +1543,23 @@ pub mod email {
         WHERE id = $1
     "#;
 
+    pub const MARK_SUPPRESSED: &str = r#"
+        UPDATE email_queue
+        SET status = 'suppressed', last_error = $2, completed_at = NOW()
+        WHERE id = $1
+    "#;
+
+    /// $1: tenant_id, $2: provider_message_id, $3: reason
+    pub const MARK_BOUNCED: &str = r#"
+        UPDATE email_queue
+        SET status = 'bounced', last_error = $3
+        WHERE tenant_id = $1 AND provider_message_id = $2
+    "#;
+
     pub const GET_BY_ID: &str = r#"
         SELECT id, tenant_id, to_email, from_email, from_name, subject, body_text, body_html,
                status, attempts, max_attempts, last_error, last_attempt_at, next_attempt_at,
-               created_at, completed_at
+               created_at, completed_at, traceparent, provider, provider_message_id
         FROM email_queue WHERE id = $1
     "#;
 
@@ -1052,7 +1568,7 @@ pub mod email {
     pub const CLEANUP_OLD: &str = r#"
         DELETE FROM email_queue WHERE id IN (
             SELECT id FROM email_queue
-            WHERE status IN ('completed', 'failed')
+            WHERE status IN ('completed', 'failed', 'suppressed', 'bounced')
               AND completed_at < NOW() - $1 * INTERVAL '1 day'
             LIMIT 1000
         )
@@ -1161,6 +1677,17 @@ pub mod subscription {
         LIMIT 1000
     "#;
 
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const GET_BY_USER_ID: &str = r#"
+        SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+               payment_method, billing_period, billing_interval, status,
+               current_period_start, current_period_end, trial_end,
+               cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        FROM subscriptions WHERE tenant_id = $1 AND user_id = $2
+        ORDER BY created_at DESC
+        LIMIT 1000
+    "#;
+
     /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
     pub const GET_BY_STRIPE_ID: &str = r#"
         SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
@@ -1284,13 +1811,13 @@ pub mod subscription {
 pub mod chat {
     /// Insert a new chat session
     pub const INSERT_SESSION: &str = r#"
-        INSERT INTO chat_sessions (id, tenant_id, customer_id, customer_email, status, message_count, last_message_at, created_at, updated_at)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        INSERT INTO chat_sessions (id, tenant_id, customer_id, customer_email, status, message_count, assigned_agent, handoff_reason, handoff_requested_at, last_message_at, created_at, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
     "#;
 
     /// Get a chat session by ID
     pub const GET_SESSION: &str = r#"
-        SELECT id, tenant_id, customer_id, customer_email, status, message_count, last_message_at, created_at, updated_at
+        SELECT id, tenant_id, customer_id, customer_email, status, message_count, assigned_agent, handoff_reason, handoff_requested_at, last_message_at, created_at, updated_at
         FROM chat_sessions WHERE tenant_id = $1 AND id = $2
     "#;
 
@@ -1303,7 +1830,7 @@ pub mod chat {
 
     /// List sessions with optional filters, returns count
     pub const LIST_SESSIONS: &str = r#"
-        SELECT id, tenant_id, customer_id, customer_email, status, message_count, last_message_at, created_at, updated_at
+        SELECT id, tenant_id, customer_id, customer_email, status, message_count, assigned_agent, handoff_reason, handoff_requested_at, last_message_at, created_at, updated_at
         FROM chat_sessions
         WHERE tenant_id = $1
           AND ($2::text IS NULL OR customer_id = $2)
@@ -1323,18 +1850,58 @@ pub mod chat {
 
     /// Insert a new chat message
     pub const INSERT_MESSAGE: &str = r#"
-        INSERT INTO chat_messages (id, tenant_id, session_id, role, content, tool_calls, tool_results, created_at)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        INSERT INTO chat_messages (id, tenant_id, session_id, role, content, tool_calls, tool_results, author_id, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
     "#;
 
     /// List messages for a session (chronological order)
     pub const LIST_MESSAGES: &str = r#"
-        SELECT id, tenant_id, session_id, role, content, tool_calls, tool_results, created_at
+        SELECT id, tenant_id, session_id, role, content, tool_calls, tool_results, author_id, created_at
         FROM chat_messages
         WHERE tenant_id = $1 AND session_id = $2
         ORDER BY created_at ASC
         LIMIT $3 OFFSET $4
     "#;
+
+    /// List messages created after $3 (chronological order)
+    pub const LIST_MESSAGES_SINCE: &str = r#"
+        SELECT id, tenant_id, session_id, role, content, tool_calls, tool_results, author_id, created_at
+        FROM chat_messages
+        WHERE tenant_id = $1 AND session_id = $2 AND created_at > $3
+        ORDER BY created_at ASC
+        LIMIT $4
+    "#;
+
+    /// Ask for a human; no-op while one is already requested or assigned
+    pub const REQUEST_HANDOFF: &str = r#"
+        UPDATE chat_sessions
+        SET status = 'waiting_for_agent', assigned_agent = NULL, handoff_reason = $3,
+            handoff_requested_at = $4, updated_at = $4
+        WHERE tenant_id = $1 AND id = $2
+          AND status NOT IN ('waiting_for_agent', 'with_agent')
+    "#;
+
+    /// Claim a session waiting for an agent
+    pub const CLAIM: &str = r#"
+        UPDATE chat_sessions
+        SET status = 'with_agent', assigned_agent = $3, updated_at = $4
+        WHERE tenant_id = $1 AND id = $2 AND status = 'waiting_for_agent'
+    "#;
+
+    /// Attach a verified customer to an anonymous session
+    pub const BIND_CUSTOMER: &str = r#"
+        UPDATE chat_sessions
+        SET customer_id = $3, updated_at = $4
+        WHERE tenant_id = $1 AND id = $2 AND customer_id IS NULL
+    "#;
+
+    /// Hand a session back to the assistant
+    pub const RELEASE: &str = r#"
+        UPDATE chat_sessions
+        SET status = 'active', assigned_agent = NULL, updated_at = $3
+        WHERE tenant_id = $1 AND id = $2
+          AND status IN ('waiting_for_agent', 'with_agent')
+    "#;
 }
 
 /// FAQ/knowledge base queries
@@ -1527,3 +2094,106 @@ pub mod dlq {
         DELETE FROM webhook_queue WHERE id = $1
     "#;
 }
+
+pub mod product_imports {
+    pub const UPSERT: &str = r#"
+        INSERT INTO product_import_jobs (
+            id, tenant_id, format, dry_run, status, total_count, processed_count,
+            created_count, updated_count, failed_count, errors, error,
+            created_at, updated_at, completed_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+        ON CONFLICT (tenant_id, id) DO UPDATE SET
+            status = EXCLUDED.status,
+            total_count = EXCLUDED.total_count,
+            processed_count = EXCLUDED.processed_count,
+            created_count = EXCLUDED.created_count,
+            updated_count = EXCLUDED.updated_count,
+            failed_count = EXCLUDED.failed_count,
+            errors = EXCLUDED.errors,
+            error = EXCLUDED.error,
+            updated_at = EXCLUDED.updated_at,
+            completed_at = EXCLUDED.completed_at
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT id, tenant_id, format, dry_run, status, total_count, processed_count,
+               created_count, updated_count, failed_count, errors, error,
+               created_at, updated_at, completed_at
+        FROM product_import_jobs
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const FAIL_STALE: &str = r#"
+        UPDATE product_import_jobs
+        SET status = 'failed', error = $2, updated_at = $3, completed_at = $3
+        WHERE status IN ('queued', 'running') AND updated_at < $1
+    "#;
+}
+
+pub mod price_schedules {
+    pub const UPSERT: &str = r#"
+        INSERT INTO price_schedules (
+            id, tenant_id, product_id, variant_id, fiat_price, crypto_price, variant_price,
+            show_as_sale, starts_at, ends_at, status, original, stripe_price_id, note, error,
+            created_at, updated_at, applied_at, completed_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
+        ON CONFLICT (tenant_id, id) DO UPDATE SET
+            variant_id = EXCLUDED.variant_id,
+            fiat_price = EXCLUDED.fiat_price,
+            crypto_price = EXCLUDED.crypto_price,
+            variant_price = EXCLUDED.variant_price,
+            show_as_sale = EXCLUDED.show_as_sale,
+            starts_at = EXCLUDED.starts_at,
+            ends_at = EXCLUDED.ends_at,
+            status = EXCLUDED.status,
+            original = EXCLUDED.original,
+            stripe_price_id = EXCLUDED.stripe_price_id,
+            note = EXCLUDED.note,
+            error = EXCLUDED.error,
+            updated_at = EXCLUDED.updated_at,
+            applied_at = EXCLUDED.applied_at,
+            completed_at = EXCLUDED.completed_at
+    "#;
+
+    pub const UPDATE_PROGRESS_IF_STATUS: &str = r#"
+        UPDATE price_schedules SET
+            status = $4,
+            original = $5,
+            stripe_price_id = $6,
+            error = $7,
+            updated_at = $8,
+            applied_at = $9,
+            completed_at = $10
+        WHERE tenant_id = $1 AND id = $2 AND status = $3
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT id, tenant_id, product_id, variant_id, fiat_price, crypto_price, variant_price,
+               show_as_sale, starts_at, ends_at, status, original, stripe_price_id, note, error,
+               created_at, updated_at, applied_at, completed_at
+        FROM price_schedules
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const LIST_BY_PRODUCT: &str = r#"
+        SELECT id, tenant_id, product_id, variant_id, fiat_price, crypto_price, variant_price,
+               show_as_sale, starts_at, ends_at, status, original, stripe_price_id, note, error,
+               created_at, updated_at, applied_at, completed_at
+        FROM price_schedules
+        WHERE tenant_id = $1 AND product_id = $2
+        ORDER BY starts_at DESC
+    "#;
+
+    pub const LIST_DUE: &str = r#"
+        SELECT id, tenant_id, product_id, variant_id, fiat_price, crypto_price, variant_price,
+               show_as_sale, starts_at, ends_at, status, original, stripe_price_id, note, error,
+               created_at, updated_at, applied_at, completed_at
+        FROM price_schedules
+        WHERE (status = 'scheduled' AND starts_at <= $1)
+           OR (status = 'active' AND ends_at IS NOT NULL AND ends_at <= $1)
+        ORDER BY starts_at ASC
+        LIMIT $2
+    "#;
+}
@@ -0,0 +1,62 @@
+//! AI usage accounting methods for PostgresStore
+
+use super::*;
+
+type AiUsageRow = (
+    String,
+    String,
+    String,
+    String,
+    i64,
+    i64,
+    i64,
+    i64,
+    DateTime<Utc>,
+);
+
+fn to_record(
+    (tenant_id, period, task, model, calls, input_tokens, output_tokens, cost_micros, updated_at): AiUsageRow,
+) -> AiUsageRecord {
+    AiUsageRecord {
+        tenant_id,
+        period,
+        task,
+        model,
+        calls,
+        input_tokens,
+        output_tokens,
+        cost_micros,
+        updated_at,
+    }
+}
+
+pub(super) async fn add_ai_usage(store: &PostgresStore, usage: AiUsageRecord) -> StorageResult<()> {
+    sqlx::query(queries::ai_usage::ADD)
+        .bind(&usage.tenant_id)
+        .bind(&usage.period)
+        .bind(&usage.task)
+        .bind(&usage.model)
+        .bind(usage.calls)
+        .bind(usage.input_tokens)
+        .bind(usage.output_tokens)
+        .bind(usage.cost_micros)
+        .bind(usage.updated_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("add ai usage", e))?;
+    Ok(())
+}
+
+pub(super) async fn list_ai_usage(
+    store: &PostgresStore,
+    tenant_id: &str,
+    period: &str,
+) -> StorageResult<Vec<AiUsageRecord>> {
+    let rows: Vec<AiUsageRow> = sqlx::query_as(queries::ai_usage::LIST_BY_PERIOD)
+        .bind(tenant_id)
+        .bind(period)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list ai usage", e))?;
+    Ok(rows.into_iter().map(to_record).collect())
+}
@@ -0,0 +1,173 @@
+//! Cold archival storage methods for PostgresStore
+
+use super::*;
+
+pub(super) async fn list_archivable_records(
+    store: &PostgresStore,
+    tenant_id: &str,
+    older_than: DateTime<Utc>,
+    limit: i64,
+) -> StorageResult<ArchivableRecords> {
+    let query = store.payment_query(queries::archive::LIST_PAYMENTS);
+    let payments = sqlx::query(&query)
+        .bind(tenant_id)
+        .bind(older_than)
+        .bind(limit)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list archivable payments", e))?
+        .into_iter()
+        .map(parse_payment_transaction)
+        .collect::<StorageResult<Vec<_>>>()?;
+
+    let query = store.orders_query(queries::archive::LIST_ORDERS);
+    let orders = sqlx::query(&query)
+        .bind(tenant_id)
+        .bind(older_than)
+        .bind(limit)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list archivable orders", e))?
+        .into_iter()
+        .map(parse_order)
+        .collect::<StorageResult<Vec<_>>>()?;
+
+    let order_history = if orders.is_empty() {
+        Vec::new()
+    } else {
+        let order_ids: Vec<String> = orders.iter().map(|o| o.id.clone()).collect();
+        let query = store.orders_query(queries::archive::LIST_ORDER_HISTORY);
+        sqlx::query(&query)
+            .bind(tenant_id)
+            .bind(&order_ids)
+            .fetch_all(store.pool.inner())
+            .await
+            .map_err(|e| StorageError::internal("list archivable order history", e))?
+            .into_iter()
+            .map(parse_order_history)
+            .collect::<StorageResult<Vec<_>>>()?
+    };
+
+    let query = store.webhook_query(queries::archive::LIST_WEBHOOKS);
+    let webhooks = sqlx::query(&query)
+        .bind(tenant_id)
+        .bind(older_than)
+        .bind(limit)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list archivable webhooks", e))?
+        .into_iter()
+        .map(parse_webhook)
+        .collect::<StorageResult<Vec<_>>>()?;
+
+    Ok(ArchivableRecords {
+        payments,
+        orders,
+        order_history,
+        webhooks,
+    })
+}
+
+pub(super) async fn purge_archived_records(
+    store: &PostgresStore,
+    tenant_id: &str,
+    purge: &ArchivePurge,
+) -> StorageResult<u64> {
+    let mut tx = store
+        .pool
+        .inner()
+        .begin()
+        .await
+        .map_err(|e| StorageError::internal("begin archive purge", e))?;
+    let mut deleted = 0u64;
+
+    if !purge.payments.is_empty() {
+        let signatures: Vec<String> = purge.payments.iter().map(|p| p.signature.clone()).collect();
+        let object_keys: Vec<String> = purge
+            .payments
+            .iter()
+            .map(|p| p.object_key.clone())
+            .collect();
+        let archived_at: Vec<DateTime<Utc>> =
+            purge.payments.iter().map(|p| p.archived_at).collect();
+
+        let query = store.archive_query(queries::archive::INDEX_PAYMENTS);
+        sqlx::query(&query)
+            .bind(tenant_id)
+            .bind(&signatures)
+            .bind(&object_keys)
+            .bind(&archived_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::internal("index archived payments", e))?;
+
+        let query = store.payment_query(queries::archive::DELETE_PAYMENTS);
+        deleted += sqlx::query(&query)
+            .bind(tenant_id)
+            .bind(&signatures)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::internal("delete archived payments", e))?
+            .rows_affected();
+    }
+
+    if !purge.order_ids.is_empty() {
+        let query = store.orders_query(queries::archive::DELETE_ORDER_HISTORY);
+        deleted += sqlx::query(&query)
+            .bind(tenant_id)
+            .bind(&purge.order_ids)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::internal("delete archived order history", e))?
+            .rows_affected();
+
+        let query = store.orders_query(queries::archive::DELETE_ORDERS);
+        deleted += sqlx::query(&query)
+            .bind(tenant_id)
+            .bind(&purge.order_ids)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::internal("delete archived orders", e))?
+            .rows_affected();
+    }
+
+    if !purge.webhook_ids.is_empty() {
+        let query = store.webhook_query(queries::archive::DELETE_WEBHOOKS);
+        deleted += sqlx::query(&query)
+            .bind(tenant_id)
+            .bind(&purge.webhook_ids)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::internal("delete archived webhooks", e))?
+            .rows_affected();
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| StorageError::internal("commit archive purge", e))?;
+
+    Ok(deleted)
+}
+
+pub(super) async fn find_archived_payment(
+    store: &PostgresStore,
+    tenant_id: &str,
+    signature: &str,
+) -> StorageResult<Option<ArchivedPaymentRef>> {
+    let query = store.archive_query(queries::archive::GET_ARCHIVED_PAYMENT);
+    let row: Option<(String, String, String, DateTime<Utc>)> = sqlx::query_as(&query)
+        .bind(tenant_id)
+        .bind(signature)
+        .fetch_optional(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("find archived payment", e))?;
+
+    Ok(row.map(
+        |(tenant_id, signature, object_key, archived_at)| ArchivedPaymentRef {
+            tenant_id,
+            signature,
+            object_key,
+            archived_at,
+        },
+    ))
+}
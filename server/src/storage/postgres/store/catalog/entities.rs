@@ -315,6 +315,12 @@ pub(in super::super) async fn create_collection(
         .map(serde_json::to_value)
         .transpose()
         .map_err(|e| StorageError::internal("serialize tokenization_config", e))?;
+    let payment_split_json: Option<serde_json::Value> = collection
+        .payment_split
+        .as_ref()
+        .map(serde_json::to_value)
+        .transpose()
+        .map_err(|e| StorageError::internal("serialize payment_split", e))?;
     let query = store.orders_query(queries::collections::INSERT);
     sqlx::query(&query)
         .bind(&collection.id)
@@ -324,6 +330,7 @@ pub(in super::super) async fn create_collection(
         .bind(&product_ids_json)
         .bind(collection.active)
         .bind(&tokenization_config_json)
+        .bind(&payment_split_json)
         .bind(collection.created_at)
         .bind(collection.updated_at)
         .execute(store.pool.inner())
@@ -344,6 +351,12 @@ pub(in super::super) async fn update_collection(
         .map(serde_json::to_value)
         .transpose()
         .map_err(|e| StorageError::internal("serialize tokenization_config", e))?;
+    let payment_split_json: Option<serde_json::Value> = collection
+        .payment_split
+        .as_ref()
+        .map(serde_json::to_value)
+        .transpose()
+        .map_err(|e| StorageError::internal("serialize payment_split", e))?;
     let query = store.orders_query(queries::collections::UPDATE);
     let result = sqlx::query(&query)
         .bind(&collection.tenant_id)
@@ -353,6 +366,7 @@ pub(in super::super) async fn update_collection(
         .bind(&product_ids_json)
         .bind(collection.active)
         .bind(&tokenization_config_json)
+        .bind(&payment_split_json)
         .bind(collection.updated_at)
         .execute(store.pool.inner())
         .await
@@ -15,6 +15,9 @@ pub(super) async fn create_chat_session(
         .bind(&session.customer_email)
         .bind(&session.status)
         .bind(session.message_count)
+        .bind(&session.assigned_agent)
+        .bind(&session.handoff_reason)
+        .bind(session.handoff_requested_at)
         .bind(session.last_message_at)
         .bind(session.created_at)
         .bind(session.updated_at)
@@ -97,6 +100,76 @@ pub(super) async fn list_chat_sessions(
     Ok((sessions, total))
 }
 
+pub(super) async fn request_chat_handoff(
+    store: &PostgresStore,
+    tenant_id: &str,
+    session_id: &str,
+    reason: Option<&str>,
+    requested_at: DateTime<Utc>,
+) -> StorageResult<bool> {
+    let result = sqlx::query(queries::chat::REQUEST_HANDOFF)
+        .bind(tenant_id)
+        .bind(session_id)
+        .bind(reason)
+        .bind(requested_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("request chat handoff", e))?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub(super) async fn claim_chat_session(
+    store: &PostgresStore,
+    tenant_id: &str,
+    session_id: &str,
+    agent_id: &str,
+    claimed_at: DateTime<Utc>,
+) -> StorageResult<bool> {
+    let result = sqlx::query(queries::chat::CLAIM)
+        .bind(tenant_id)
+        .bind(session_id)
+        .bind(agent_id)
+        .bind(claimed_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("claim chat session", e))?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub(super) async fn release_chat_session(
+    store: &PostgresStore,
+    tenant_id: &str,
+    session_id: &str,
+    updated_at: DateTime<Utc>,
+) -> StorageResult<bool> {
+    let result = sqlx::query(queries::chat::RELEASE)
+        .bind(tenant_id)
+        .bind(session_id)
+        .bind(updated_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("release chat session", e))?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub(super) async fn bind_chat_session_customer(
+    store: &PostgresStore,
+    tenant_id: &str,
+    session_id: &str,
+    customer_id: &str,
+    updated_at: DateTime<Utc>,
+) -> StorageResult<bool> {
+    let result = sqlx::query(queries::chat::BIND_CUSTOMER)
+        .bind(tenant_id)
+        .bind(session_id)
+        .bind(customer_id)
+        .bind(updated_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("bind chat session customer", e))?;
+    Ok(result.rows_affected() > 0)
+}
+
 // ─── Chat messages ───────────────────────────────────────────────────────────
 
 pub(super) async fn create_chat_message(
@@ -111,6 +184,7 @@ pub(super) async fn create_chat_message(
         .bind(&message.content)
         .bind(&message.tool_calls)
         .bind(&message.tool_results)
+        .bind(&message.author_id)
         .bind(message.created_at)
         .execute(store.pool.inner())
         .await
@@ -139,6 +213,27 @@ pub(super) async fn list_chat_messages(
         .collect::<StorageResult<Vec<_>>>()
 }
 
+pub(super) async fn list_chat_messages_since(
+    store: &PostgresStore,
+    tenant_id: &str,
+    session_id: &str,
+    since: DateTime<Utc>,
+    limit: i32,
+) -> StorageResult<Vec<ChatMessage>> {
+    let rows = sqlx::query(queries::chat::LIST_MESSAGES_SINCE)
+        .bind(tenant_id)
+        .bind(session_id)
+        .bind(since)
+        .bind(limit)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list chat messages since", e))?;
+
+    rows.into_iter()
+        .map(parse_chat_message)
+        .collect::<StorageResult<Vec<_>>>()
+}
+
 // ─── FAQs ────────────────────────────────────────────────────────────────────
 
 pub(super) async fn create_faq(store: &PostgresStore, faq: Faq) -> StorageResult<()> {
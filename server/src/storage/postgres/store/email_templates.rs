@@ -0,0 +1,93 @@
+//! Email template storage methods for PostgresStore
+
+use super::*;
+
+type EmailTemplateRow = (
+    String,
+    String,
+    String,
+    String,
+    String,
+    Option<String>,
+    DateTime<Utc>,
+);
+
+fn to_template(
+    (tenant_id, kind, locale, subject, body_text, body_html, updated_at): EmailTemplateRow,
+) -> EmailTemplate {
+    EmailTemplate {
+        tenant_id,
+        kind,
+        locale,
+        subject,
+        body_text,
+        body_html,
+        updated_at,
+    }
+}
+
+pub(super) async fn upsert_email_template(
+    store: &PostgresStore,
+    template: EmailTemplate,
+) -> StorageResult<()> {
+    let query = store.email_templates_query(queries::email_template::UPSERT);
+    sqlx::query(&query)
+        .bind(&template.tenant_id)
+        .bind(&template.kind)
+        .bind(&template.locale)
+        .bind(&template.subject)
+        .bind(&template.body_text)
+        .bind(&template.body_html)
+        .bind(template.updated_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("upsert email template", e))?;
+    Ok(())
+}
+
+pub(super) async fn get_email_template(
+    store: &PostgresStore,
+    tenant_id: &str,
+    kind: &str,
+    locale: &str,
+) -> StorageResult<Option<EmailTemplate>> {
+    let query = store.email_templates_query(queries::email_template::GET);
+    let row: Option<EmailTemplateRow> = sqlx::query_as(&query)
+        .bind(tenant_id)
+        .bind(kind)
+        .bind(locale)
+        .fetch_optional(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("get email template", e))?;
+    Ok(row.map(to_template))
+}
+
+pub(super) async fn list_email_templates(
+    store: &PostgresStore,
+    tenant_id: &str,
+) -> StorageResult<Vec<EmailTemplate>> {
+    let query = store.email_templates_query(queries::email_template::LIST);
+    let rows: Vec<EmailTemplateRow> = sqlx::query_as(&query)
+        .bind(tenant_id)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list email templates", e))?;
+    Ok(rows.into_iter().map(to_template).collect())
+}
+
+pub(super) async fn delete_email_template(
+    store: &PostgresStore,
+    tenant_id: &str,
+    kind: &str,
+    locale: &str,
+) -> StorageResult<bool> {
+    let query = store.email_templates_query(queries::email_template::DELETE);
+    let result = sqlx::query(&query)
+        .bind(tenant_id)
+        .bind(kind)
+        .bind(locale)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("delete email template", e))?;
+    Ok(result.rows_affected() > 0)
+}
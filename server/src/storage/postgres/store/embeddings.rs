@@ -0,0 +1,218 @@
+//! Embedding storage methods for PostgresStore
+//!
+//! Vectors are stored as `REAL[]`. With the pgvector extension installed the
+//! search is ranked in the database; otherwise candidates are ranked here.
+//! The extension is detected once per store rather than probed on every search.
+
+use super::*;
+use crate::storage::rank_embeddings;
+
+type EmbeddingRow = (
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    Vec<f32>,
+    DateTime<Utc>,
+);
+
+type EmbeddingJobRow = (String, String, String, i32, DateTime<Utc>);
+
+fn parse_entity(value: &str) -> StorageResult<EmbeddingEntity> {
+    value
+        .parse()
+        .map_err(|e| StorageError::internal("parse embedding entity", e))
+}
+
+fn to_record(
+    (tenant_id, entity_type, entity_id, source_id, model, content_hash, embedding, updated_at): EmbeddingRow,
+) -> StorageResult<EmbeddingRecord> {
+    Ok(EmbeddingRecord {
+        tenant_id,
+        entity_type: parse_entity(&entity_type)?,
+        entity_id,
+        source_id,
+        model,
+        content_hash,
+        embedding,
+        updated_at,
+    })
+}
+
+fn to_job(
+    (tenant_id, source_type, source_id, attempts, available_at): EmbeddingJobRow,
+) -> StorageResult<EmbeddingJob> {
+    Ok(EmbeddingJob {
+        tenant_id,
+        source_type: parse_entity(&source_type)?,
+        source_id,
+        attempts,
+        available_at,
+    })
+}
+
+fn entity_names(entity_types: &[EmbeddingEntity]) -> Vec<String> {
+    entity_types.iter().map(|t| t.to_string()).collect()
+}
+
+pub(super) async fn upsert_embedding(
+    store: &PostgresStore,
+    record: EmbeddingRecord,
+) -> StorageResult<()> {
+    let query = store.embeddings_query(queries::embedding::UPSERT);
+    sqlx::query(&query)
+        .bind(&record.tenant_id)
+        .bind(record.entity_type.as_str())
+        .bind(&record.entity_id)
+        .bind(&record.source_id)
+        .bind(&record.model)
+        .bind(&record.content_hash)
+        .bind(record.embedding.len() as i32)
+        .bind(&record.embedding)
+        .bind(record.updated_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("upsert embedding", e))?;
+    Ok(())
+}
+
+pub(super) async fn list_source_embeddings(
+    store: &PostgresStore,
+    tenant_id: &str,
+    source_id: &str,
+    entity_types: &[EmbeddingEntity],
+) -> StorageResult<Vec<EmbeddingRecord>> {
+    let query = store.embeddings_query(queries::embedding::LIST_BY_SOURCE);
+    let rows: Vec<EmbeddingRow> = sqlx::query_as(&query)
+        .bind(tenant_id)
+        .bind(source_id)
+        .bind(entity_names(entity_types))
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list source embeddings", e))?;
+    rows.into_iter().map(to_record).collect()
+}
+
+pub(super) async fn delete_embedding(
+    store: &PostgresStore,
+    tenant_id: &str,
+    entity_type: EmbeddingEntity,
+    entity_id: &str,
+) -> StorageResult<bool> {
+    let query = store.embeddings_query(queries::embedding::DELETE);
+    let result = sqlx::query(&query)
+        .bind(tenant_id)
+        .bind(entity_type.as_str())
+        .bind(entity_id)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("delete embedding", e))?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Whether the pgvector extension is installed (detected once, then cached).
+async fn has_pgvector(store: &PostgresStore) -> StorageResult<bool> {
+    store
+        .pgvector
+        .get_or_try_init(|| async {
+            let installed: bool = sqlx::query_scalar(queries::embedding::HAS_PGVECTOR)
+                .fetch_one(store.pool.inner())
+                .await
+                .map_err(|e| StorageError::internal("detect pgvector", e))?;
+            if !installed {
+                tracing::info!("pgvector not installed, ranking embeddings in process");
+            }
+            Ok(installed)
+        })
+        .await
+        .copied()
+}
+
+pub(super) async fn search_embeddings(
+    store: &PostgresStore,
+    tenant_id: &str,
+    model: &str,
+    entity_types: &[EmbeddingEntity],
+    query: &[f32],
+    limit: i32,
+) -> StorageResult<Vec<EmbeddingMatch>> {
+    if !has_pgvector(store).await? {
+        let sql = store.embeddings_query(queries::embedding::LIST_FOR_SEARCH);
+        let rows: Vec<EmbeddingRow> = sqlx::query_as(&sql)
+            .bind(tenant_id)
+            .bind(model)
+            .bind(entity_names(entity_types))
+            .bind(query.len() as i32)
+            .fetch_all(store.pool.inner())
+            .await
+            .map_err(|e| StorageError::internal("search embeddings", e))?;
+        let records = rows
+            .into_iter()
+            .map(to_record)
+            .collect::<StorageResult<Vec<_>>>()?;
+        return Ok(rank_embeddings(records.iter(), query, limit));
+    }
+
+    let sql = store.embeddings_query(queries::embedding::SEARCH_PGVECTOR);
+    let rows: Vec<(String, String, String, f32)> = sqlx::query_as(&sql)
+        .bind(tenant_id)
+        .bind(model)
+        .bind(entity_names(entity_types))
+        .bind(query.len() as i32)
+        .bind(query)
+        .bind(limit)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, tenant_id = %tenant_id, "pgvector embedding search failed");
+            StorageError::internal("search embeddings", e)
+        })?;
+    rows.into_iter()
+        .map(|(entity_type, entity_id, source_id, score)| {
+            Ok(EmbeddingMatch {
+                entity_type: parse_entity(&entity_type)?,
+                entity_id,
+                source_id,
+                score,
+            })
+        })
+        .collect()
+}
+
+pub(super) async fn enqueue_embedding_job(
+    store: &PostgresStore,
+    job: EmbeddingJob,
+) -> StorageResult<()> {
+    sqlx::query(queries::embedding::ENQUEUE_JOB)
+        .bind(&job.tenant_id)
+        .bind(job.source_type.as_str())
+        .bind(&job.source_id)
+        .bind(job.attempts)
+        .bind(job.available_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("enqueue embedding job", e))?;
+    Ok(())
+}
+
+pub(super) async fn claim_embedding_jobs(
+    store: &PostgresStore,
+    now: DateTime<Utc>,
+    limit: i32,
+) -> StorageResult<Vec<EmbeddingJob>> {
+    let rows: Vec<EmbeddingJobRow> = sqlx::query_as(queries::embedding::CLAIM_JOBS)
+        .bind(now)
+        .bind(limit)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("claim embedding jobs", e))?;
+    let mut jobs = rows
+        .into_iter()
+        .map(to_job)
+        .collect::<StorageResult<Vec<_>>>()?;
+    // RETURNING does not preserve the subquery order
+    jobs.sort_by_key(|job| job.available_at);
+    Ok(jobs)
+}
@@ -3,50 +3,69 @@
 //! The `impl Store for PostgresStore` block lives here and delegates every method
 //! to inherent `impl PostgresStore` blocks defined in the domain sub-modules.
 //! Rust allows inherent impls to be split across files in the same module tree.
+//! Each trait method is wrapped in a tracing span named after the method.
 
+use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use sqlx::{QueryBuilder, Row};
+use tracing::instrument;
 
 use super::connection::PostgresPool;
 use super::parsers::{
-    parse_admin_audit_entry, parse_admin_nonce, parse_cart_quote, parse_chat_message,
-    parse_chat_session, parse_collection, parse_credits_hold, parse_customer, parse_dispute,
-    parse_dlq_webhook, parse_email, parse_faq, parse_fulfillment, parse_gift_card,
-    parse_idempotency_response, parse_inventory_adjustment, parse_inventory_reservation,
-    parse_order, parse_order_history, parse_payment_transaction, parse_refund_quote,
-    parse_return_request, parse_shipping_profile, parse_shipping_rate, parse_stripe_refund_request,
-    parse_subscription, parse_tax_rate, parse_webhook,
+    parse_admin_audit_entry, parse_admin_nonce, parse_affiliate, parse_affiliate_commission,
+    parse_cart_quote, parse_chat_message, parse_chat_session, parse_collection, parse_credits_hold,
+    parse_customer, parse_dispute, parse_dlq_webhook, parse_email, parse_faq, parse_fulfillment,
+    parse_gift_card, parse_idempotency_response, parse_inventory_adjustment,
+    parse_inventory_reservation, parse_journal_entry, parse_order, parse_order_history,
+    parse_payment_transaction, parse_refund_quote, parse_return_request, parse_shipping_profile,
+    parse_shipping_rate, parse_stripe_application_fee, parse_stripe_connect_account,
+    parse_stripe_refund_request, parse_subscription, parse_tax_rate, parse_webhook,
 };
 use super::queries;
 use crate::config::SchemaMapping;
 use crate::models::compliance::{ComplianceAction, TokenHolder};
 use crate::models::{
-    AdminAuditEntry, AssetRedemption, CartQuote, ChatMessage, ChatSession, Collection, Customer,
-    DisputeRecord, Faq, Fulfillment, GiftCard, GiftCardRedemption, InventoryAdjustment,
-    InventoryReservation, Order, OrderHistoryEntry, PaymentTransaction, RefundQuote, ReturnRequest,
-    ShippingProfile, ShippingRate, StripeRefundRequest, Subscription, SubscriptionStatus, TaxRate,
-    TenantToken22Mint,
+    AdminAuditEntry, Affiliate, AffiliateCommission, AssetRedemption, CartQuote, ChatMessage,
+    ChatSession, Collection, Customer, DisputeRecord, Faq, Fulfillment, GiftCard,
+    GiftCardRedemption, InventoryAdjustment, InventoryReservation, JournalEntry, JournalSource,
+    Order, OrderHistoryEntry, PaymentTransaction, PriceSchedule, PriceScheduleStatus,
+    ProductImportJob, RefundQuote, ReturnRequest, ShippingProfile, ShippingRate,
+    StripeApplicationFee, StripeConnectAccount, StripeRefundRequest, Subscription,
+    SubscriptionStatus, TaxRate, TenantToken22Mint, TrialBalanceRow,
 };
 use crate::storage::{
-    AdminNonce, AdminStats, CreditsHold, DlqWebhook, IdempotencyResponse, PendingEmail,
-    PendingWebhook, Purchase, StorageError, StorageResult, Store, WebhookStatus,
+    AdminNonce, AdminStats, AiUsageRecord, ArchivableRecords, ArchivePurge, ArchivedPaymentRef,
+    CreditsHold, DlqWebhook, EmailSuppression, EmailTemplate, EmbeddingEntity, EmbeddingJob,
+    EmbeddingMatch, EmbeddingRecord, EventLogEntry, EventLogQuery, IdempotencyResponse,
+    PendingEmail, PendingWebhook, Purchase, StorageError, StorageResult, Store, WebhookStatus,
 };
 use crate::{constants::DEFAULT_ACCESS_TTL, storage::memory::to_chrono_duration};
 
 mod admin;
 mod admin_audit;
+mod affiliates;
+mod ai_usage;
+mod archive;
 mod auth;
 mod cart;
 mod catalog;
 mod chat;
 mod compliance;
+mod email_suppressions;
+mod email_templates;
+mod embeddings;
+mod events;
 mod inventory;
+mod ledger;
 mod orders;
 mod payments;
+mod price_schedules;
+mod product_imports;
 mod refunds;
+mod stripe_connect;
 mod subscriptions;
 mod webhooks;
 
@@ -93,6 +112,8 @@ pub(super) fn replace_identifier(query: &str, from: &str, to: &str) -> String {
 pub struct PostgresStore {
     pub(super) pool: PostgresPool,
     pub(super) tables: SchemaMapping,
+    /// Whether pgvector is installed; detected on the first embedding search.
+    pub(super) pgvector: Arc<tokio::sync::OnceCell<bool>>,
 }
 
 #[derive(Clone, Debug)]
@@ -109,7 +130,11 @@ pub struct InventoryAdjustmentRequest {
 impl PostgresStore {
     /// Create a new PostgreSQL store
     pub fn new(pool: PostgresPool, tables: SchemaMapping) -> Self {
-        Self { pool, tables }
+        Self {
+            pool,
+            tables,
+            pgvector: Arc::new(tokio::sync::OnceCell::new()),
+        }
     }
 
     /// Create a PostgresStore from an existing PgPool
@@ -176,6 +201,48 @@ impl PostgresStore {
         self.map_table(query, "stripe_refund_requests", "stripe_refund_requests")
     }
 
+    pub(super) fn stripe_connect_query(&self, query: &str) -> String {
+        // Stripe Connect tables are not currently configurable via SchemaMapping.
+        let query = self.map_table(query, "stripe_connect_accounts", "stripe_connect_accounts");
+        self.map_table(&query, "stripe_application_fees", "stripe_application_fees")
+    }
+
+    pub(super) fn affiliates_query(&self, query: &str) -> String {
+        // Affiliate tables are not currently configurable via SchemaMapping.
+        let query = self.map_table(query, "affiliates", "affiliates");
+        self.map_table(&query, "affiliate_commissions", "affiliate_commissions")
+    }
+
+    pub(super) fn ledger_query(&self, query: &str) -> String {
+        // Ledger table is not currently configurable via SchemaMapping.
+        self.map_table(query, "ledger_entries", "ledger_entries")
+    }
+
+    pub(super) fn event_log_query(&self, query: &str) -> String {
+        // Event log table is not currently configurable via SchemaMapping.
+        self.map_table(query, "event_log", "event_log")
+    }
+
+    pub(super) fn email_suppressions_query(&self, query: &str) -> String {
+        // Email suppression table is not currently configurable via SchemaMapping.
+        self.map_table(query, "email_suppressions", "email_suppressions")
+    }
+
+    pub(super) fn embeddings_query(&self, query: &str) -> String {
+        // Embedding tables are not currently configurable via SchemaMapping.
+        self.map_table(query, "embeddings", "embeddings")
+    }
+
+    pub(super) fn email_templates_query(&self, query: &str) -> String {
+        // Email template table is not currently configurable via SchemaMapping.
+        self.map_table(query, "email_templates", "email_templates")
+    }
+
+    pub(super) fn archive_query(&self, query: &str) -> String {
+        // Archive index table is not currently configurable via SchemaMapping.
+        self.map_table(query, "archived_payments", "archived_payments")
+    }
+
     pub(super) fn orders_query(&self, query: &str) -> String {
         // Orders table is not currently configurable via SchemaMapping.
         self.map_table(query, "orders", "orders")
@@ -189,12 +256,15 @@ impl PostgresStore {
 #[async_trait]
 impl Store for PostgresStore {
     // ─── Cart quotes ────────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn store_cart_quote(&self, quote: CartQuote) -> StorageResult<()> {
         cart::store_cart_quote(self, quote).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn store_cart_quotes(&self, quotes: Vec<CartQuote>) -> StorageResult<()> {
         cart::store_cart_quotes(self, quotes).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_cart_quote(
         &self,
         tenant_id: &str,
@@ -202,6 +272,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<CartQuote>> {
         cart::get_cart_quote(self, tenant_id, cart_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_cart_quotes(
         &self,
         tenant_id: &str,
@@ -209,6 +280,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<CartQuote>> {
         cart::get_cart_quotes(self, tenant_id, cart_ids).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn mark_cart_paid(
         &self,
         tenant_id: &str,
@@ -217,6 +289,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<()> {
         cart::mark_cart_paid(self, tenant_id, cart_id, wallet).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn has_cart_access(
         &self,
         tenant_id: &str,
@@ -225,17 +298,21 @@ impl Store for PostgresStore {
     ) -> StorageResult<bool> {
         cart::has_cart_access(self, tenant_id, cart_id, wallet).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn cleanup_expired_cart_quotes(&self) -> StorageResult<u64> {
         cart::cleanup_expired_cart_quotes(self).await
     }
 
     // ─── Refunds ────────────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn store_refund_quote(&self, quote: RefundQuote) -> StorageResult<()> {
         refunds::store_refund_quote(self, quote).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn store_refund_quotes(&self, quotes: Vec<RefundQuote>) -> StorageResult<()> {
         refunds::store_refund_quotes(self, quotes).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_refund_quote(
         &self,
         tenant_id: &str,
@@ -243,6 +320,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<RefundQuote>> {
         refunds::get_refund_quote(self, tenant_id, refund_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_refund_by_original_purchase_id(
         &self,
         tenant_id: &str,
@@ -250,6 +328,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<RefundQuote>> {
         refunds::get_refund_by_original_purchase_id(self, tenant_id, original_purchase_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_all_refunds_for_purchase(
         &self,
         tenant_id: &str,
@@ -257,6 +336,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<RefundQuote>> {
         refunds::get_all_refunds_for_purchase(self, tenant_id, original_purchase_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_pending_refunds(
         &self,
         tenant_id: &str,
@@ -264,9 +344,20 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<RefundQuote>> {
         refunds::list_pending_refunds(self, tenant_id, limit).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_processed_refunds(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<RefundQuote>> {
+        refunds::list_processed_refunds(self, tenant_id, from, to).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn count_pending_refunds(&self, tenant_id: &str) -> StorageResult<i64> {
         refunds::count_pending_refunds(self, tenant_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_credits_refund_requests(
         &self,
         tenant_id: &str,
@@ -276,6 +367,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<(Vec<RefundQuote>, i64)> {
         refunds::list_credits_refund_requests(self, tenant_id, status, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn mark_refund_processed(
         &self,
         tenant_id: &str,
@@ -285,15 +377,19 @@ impl Store for PostgresStore {
     ) -> StorageResult<()> {
         refunds::mark_refund_processed(self, tenant_id, refund_id, processed_by, signature).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn delete_refund_quote(&self, tenant_id: &str, refund_id: &str) -> StorageResult<()> {
         refunds::delete_refund_quote(self, tenant_id, refund_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn cleanup_expired_refund_quotes(&self) -> StorageResult<u64> {
         refunds::cleanup_expired_refund_quotes(self).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn store_stripe_refund_request(&self, req: StripeRefundRequest) -> StorageResult<()> {
         refunds::store_stripe_refund_request(self, req).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_stripe_refund_request(
         &self,
         tenant_id: &str,
@@ -301,6 +397,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<StripeRefundRequest>> {
         refunds::get_stripe_refund_request(self, tenant_id, request_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_pending_stripe_refund_requests(
         &self,
         tenant_id: &str,
@@ -308,6 +405,16 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<StripeRefundRequest>> {
         refunds::list_pending_stripe_refund_requests(self, tenant_id, limit).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_processed_stripe_refund_requests(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<StripeRefundRequest>> {
+        refunds::list_processed_stripe_refund_requests(self, tenant_id, from, to).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_pending_stripe_refund_request_by_original_purchase_id(
         &self,
         tenant_id: &str,
@@ -320,6 +427,7 @@ impl Store for PostgresStore {
         )
         .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_stripe_refund_request_by_charge_id(
         &self,
         tenant_id: &str,
@@ -328,13 +436,143 @@ impl Store for PostgresStore {
         refunds::get_stripe_refund_request_by_charge_id(self, tenant_id, stripe_charge_id).await
     }
 
+    // ─── Stripe Connect ─────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn upsert_stripe_connect_account(
+        &self,
+        account: StripeConnectAccount,
+    ) -> StorageResult<()> {
+        stripe_connect::upsert_stripe_connect_account(self, account).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn get_stripe_connect_account(
+        &self,
+        tenant_id: &str,
+    ) -> StorageResult<Option<StripeConnectAccount>> {
+        stripe_connect::get_stripe_connect_account(self, tenant_id).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn find_stripe_connect_account(
+        &self,
+        account_id: &str,
+    ) -> StorageResult<Option<StripeConnectAccount>> {
+        stripe_connect::find_stripe_connect_account(self, account_id).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn upsert_stripe_application_fee(&self, fee: StripeApplicationFee) -> StorageResult<()> {
+        stripe_connect::upsert_stripe_application_fee(self, fee).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_stripe_application_fees(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<StripeApplicationFee>> {
+        stripe_connect::list_stripe_application_fees(self, tenant_id, from, to).await
+    }
+
+    // ─── Affiliates ─────────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn upsert_affiliate(&self, affiliate: Affiliate) -> StorageResult<()> {
+        affiliates::upsert_affiliate(self, affiliate).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn get_affiliate(&self, tenant_id: &str, id: &str) -> StorageResult<Option<Affiliate>> {
+        affiliates::get_affiliate(self, tenant_id, id).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn get_affiliate_by_code(
+        &self,
+        tenant_id: &str,
+        code: &str,
+    ) -> StorageResult<Option<Affiliate>> {
+        affiliates::get_affiliate_by_code(self, tenant_id, code).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_affiliates(
+        &self,
+        tenant_id: &str,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<Affiliate>> {
+        affiliates::list_affiliates(self, tenant_id, limit, offset).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn record_affiliate_commission(&self, entry: AffiliateCommission) -> StorageResult<bool> {
+        affiliates::record_affiliate_commission(self, entry).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_affiliate_commissions(
+        &self,
+        tenant_id: &str,
+        affiliate_id: Option<&str>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> StorageResult<Vec<AffiliateCommission>> {
+        affiliates::list_affiliate_commissions(self, tenant_id, affiliate_id, from, to).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_affiliate_commissions_for_purchase(
+        &self,
+        tenant_id: &str,
+        purchase_id: &str,
+    ) -> StorageResult<Vec<AffiliateCommission>> {
+        affiliates::list_affiliate_commissions_for_purchase(self, tenant_id, purchase_id).await
+    }
+
+    // ─── Ledger ─────────────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn append_journal_entry(&self, entry: JournalEntry) -> StorageResult<bool> {
+        ledger::append_journal_entry(self, entry).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_journal_entries(
+        &self,
+        tenant_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<JournalEntry>> {
+        ledger::list_journal_entries(self, tenant_id, from, to, limit, offset).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_journal_entries_for_source(
+        &self,
+        tenant_id: &str,
+        source: JournalSource,
+        source_id: &str,
+    ) -> StorageResult<Vec<JournalEntry>> {
+        ledger::list_journal_entries_for_source(self, tenant_id, source, source_id).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn trial_balance(
+        &self,
+        tenant_id: &str,
+        as_of: DateTime<Utc>,
+    ) -> StorageResult<Vec<TrialBalanceRow>> {
+        ledger::trial_balance(self, tenant_id, as_of).await
+    }
+
     // ─── Orders ─────────────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn try_store_order(&self, order: Order) -> StorageResult<bool> {
         orders::try_store_order(self, order).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_order(&self, tenant_id: &str, order_id: &str) -> StorageResult<Option<Order>> {
         orders::get_order(self, tenant_id, order_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn get_order_by_purchase_id(
+        &self,
+        tenant_id: &str,
+        purchase_id: &str,
+    ) -> StorageResult<Option<Order>> {
+        orders::get_order_by_purchase_id(self, tenant_id, purchase_id).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_orders(
         &self,
         tenant_id: &str,
@@ -343,6 +581,17 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<Order>> {
         orders::list_orders(self, tenant_id, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_orders_by_user_id(
+        &self,
+        tenant_id: &str,
+        user_id: &str,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<Order>> {
+        orders::list_orders_by_user_id(self, tenant_id, user_id, limit, offset).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_orders_filtered(
         &self,
         tenant_id: &str,
@@ -365,6 +614,7 @@ impl Store for PostgresStore {
         )
         .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn update_order_status(
         &self,
         tenant_id: &str,
@@ -383,9 +633,11 @@ impl Store for PostgresStore {
         )
         .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn append_order_history(&self, entry: OrderHistoryEntry) -> StorageResult<()> {
         orders::append_order_history(self, entry).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn update_order_status_with_history(
         &self,
         tenant_id: &str,
@@ -406,6 +658,7 @@ impl Store for PostgresStore {
         )
         .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_order_history(
         &self,
         tenant_id: &str,
@@ -414,9 +667,11 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<OrderHistoryEntry>> {
         orders::list_order_history(self, tenant_id, order_id, limit).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_fulfillment(&self, fulfillment: Fulfillment) -> StorageResult<()> {
         orders::create_fulfillment(self, fulfillment).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_fulfillment(
         &self,
         tenant_id: &str,
@@ -424,6 +679,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<Fulfillment>> {
         orders::get_fulfillment(self, tenant_id, fulfillment_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_fulfillments(
         &self,
         tenant_id: &str,
@@ -432,6 +688,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<Fulfillment>> {
         orders::list_fulfillments(self, tenant_id, order_id, limit).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn update_fulfillment_status(
         &self,
         tenant_id: &str,
@@ -458,9 +715,11 @@ impl Store for PostgresStore {
         )
         .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_return_request(&self, request: ReturnRequest) -> StorageResult<()> {
         orders::create_return_request(self, request).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn update_return_status(
         &self,
         tenant_id: &str,
@@ -479,6 +738,19 @@ impl Store for PostgresStore {
         )
         .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn update_return_request(&self, request: ReturnRequest) -> StorageResult<()> {
+        orders::update_return_request(self, request).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn update_return_request_if_status(
+        &self,
+        request: ReturnRequest,
+        expected_status: &str,
+    ) -> StorageResult<bool> {
+        orders::update_return_request_if_status(self, request, expected_status).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_return_request(
         &self,
         tenant_id: &str,
@@ -486,6 +758,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<ReturnRequest>> {
         orders::get_return_request(self, tenant_id, return_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_return_requests(
         &self,
         tenant_id: &str,
@@ -498,9 +771,11 @@ impl Store for PostgresStore {
     }
 
     // ─── Inventory ──────────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn reserve_inventory(&self, reservation: InventoryReservation) -> StorageResult<()> {
         inventory::reserve_inventory(self, reservation).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_active_inventory_reservation_quantity(
         &self,
         tenant_id: &str,
@@ -513,6 +788,7 @@ impl Store for PostgresStore {
         )
         .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_active_inventory_reservation_quantity_excluding_cart(
         &self,
         tenant_id: &str,
@@ -531,6 +807,7 @@ impl Store for PostgresStore {
         )
         .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_active_inventory_reservation_quantities_excluding_cart(
         &self,
         tenant_id: &str,
@@ -547,6 +824,7 @@ impl Store for PostgresStore {
         )
         .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_active_reservations_for_cart(
         &self,
         tenant_id: &str,
@@ -554,6 +832,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<InventoryReservation>> {
         inventory::list_active_reservations_for_cart(self, tenant_id, cart_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn release_inventory_reservations(
         &self,
         tenant_id: &str,
@@ -562,6 +841,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<u64> {
         inventory::release_inventory_reservations(self, tenant_id, cart_id, released_at).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn convert_inventory_reservations(
         &self,
         tenant_id: &str,
@@ -570,18 +850,21 @@ impl Store for PostgresStore {
     ) -> StorageResult<u64> {
         inventory::convert_inventory_reservations(self, tenant_id, cart_id, converted_at).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn cleanup_expired_inventory_reservations(
         &self,
         now: DateTime<Utc>,
     ) -> StorageResult<u64> {
         inventory::cleanup_expired_inventory_reservations(self, now).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn record_inventory_adjustment(
         &self,
         adjustment: InventoryAdjustment,
     ) -> StorageResult<()> {
         inventory::record_inventory_adjustment(self, adjustment).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_inventory_adjustments(
         &self,
         tenant_id: &str,
@@ -591,6 +874,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<InventoryAdjustment>> {
         inventory::list_inventory_adjustments(self, tenant_id, product_id, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn update_inventory_batch(
         &self,
         tenant_id: &str,
@@ -600,6 +884,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<std::collections::HashMap<String, (i32, i32)>> {
         inventory::update_inventory_batch(self, tenant_id, updates, reason, actor).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn adjust_inventory_atomic(
         &self,
         tenant_id: &str,
@@ -610,9 +895,11 @@ impl Store for PostgresStore {
     }
 
     // ─── Admin audit trail (R12)
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn record_admin_audit(&self, entry: AdminAuditEntry) -> StorageResult<()> {
         admin_audit::record_admin_audit(self, entry).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_admin_audit(
         &self,
         tenant_id: &str,
@@ -635,12 +922,15 @@ impl Store for PostgresStore {
     }
 
     // ─── Catalog (shipping, tax, customers, disputes, gift cards, collections)
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_shipping_profile(&self, profile: ShippingProfile) -> StorageResult<()> {
         catalog::create_shipping_profile(self, profile).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn update_shipping_profile(&self, profile: ShippingProfile) -> StorageResult<()> {
         catalog::update_shipping_profile(self, profile).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_shipping_profile(
         &self,
         tenant_id: &str,
@@ -648,6 +938,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<ShippingProfile>> {
         catalog::get_shipping_profile(self, tenant_id, profile_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_shipping_profiles(
         &self,
         tenant_id: &str,
@@ -656,6 +947,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<ShippingProfile>> {
         catalog::list_shipping_profiles(self, tenant_id, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn delete_shipping_profile(
         &self,
         tenant_id: &str,
@@ -663,12 +955,15 @@ impl Store for PostgresStore {
     ) -> StorageResult<()> {
         catalog::delete_shipping_profile(self, tenant_id, profile_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_shipping_rate(&self, rate: ShippingRate) -> StorageResult<()> {
         catalog::create_shipping_rate(self, rate).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn update_shipping_rate(&self, rate: ShippingRate) -> StorageResult<()> {
         catalog::update_shipping_rate(self, rate).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_shipping_rates(
         &self,
         tenant_id: &str,
@@ -678,18 +973,23 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<ShippingRate>> {
         catalog::list_shipping_rates(self, tenant_id, profile_id, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn delete_shipping_rate(&self, tenant_id: &str, rate_id: &str) -> StorageResult<()> {
         catalog::delete_shipping_rate(self, tenant_id, rate_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_tax_rate(&self, rate: TaxRate) -> StorageResult<()> {
         catalog::create_tax_rate(self, rate).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn update_tax_rate(&self, rate: TaxRate) -> StorageResult<()> {
         catalog::update_tax_rate(self, rate).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_tax_rate(&self, tenant_id: &str, rate_id: &str) -> StorageResult<Option<TaxRate>> {
         catalog::get_tax_rate(self, tenant_id, rate_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_tax_rates(
         &self,
         tenant_id: &str,
@@ -698,15 +998,19 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<TaxRate>> {
         catalog::list_tax_rates(self, tenant_id, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn delete_tax_rate(&self, tenant_id: &str, rate_id: &str) -> StorageResult<()> {
         catalog::delete_tax_rate(self, tenant_id, rate_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_customer(&self, customer: Customer) -> StorageResult<()> {
         catalog::create_customer(self, customer).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn update_customer(&self, customer: Customer) -> StorageResult<()> {
         catalog::update_customer(self, customer).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_customer(
         &self,
         tenant_id: &str,
@@ -714,6 +1018,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<Customer>> {
         catalog::get_customer(self, tenant_id, customer_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_customers(
         &self,
         tenant_id: &str,
@@ -722,9 +1027,11 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<Customer>> {
         catalog::list_customers(self, tenant_id, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_dispute(&self, dispute: DisputeRecord) -> StorageResult<()> {
         catalog::create_dispute(self, dispute).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn update_dispute_status(
         &self,
         tenant_id: &str,
@@ -743,6 +1050,7 @@ impl Store for PostgresStore {
         )
         .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_dispute(
         &self,
         tenant_id: &str,
@@ -750,6 +1058,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<DisputeRecord>> {
         catalog::get_dispute(self, tenant_id, dispute_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_disputes(
         &self,
         tenant_id: &str,
@@ -761,15 +1070,19 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<DisputeRecord>> {
         catalog::list_disputes(self, tenant_id, status, source, order_id, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_gift_card(&self, card: GiftCard) -> StorageResult<()> {
         catalog::create_gift_card(self, card).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn update_gift_card(&self, card: GiftCard) -> StorageResult<()> {
         catalog::update_gift_card(self, card).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_gift_card(&self, tenant_id: &str, code: &str) -> StorageResult<Option<GiftCard>> {
         catalog::get_gift_card(self, tenant_id, code).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_gift_cards(
         &self,
         tenant_id: &str,
@@ -779,6 +1092,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<GiftCard>> {
         catalog::list_gift_cards(self, tenant_id, active_only, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn adjust_gift_card_balance(
         &self,
         tenant_id: &str,
@@ -788,6 +1102,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<()> {
         catalog::adjust_gift_card_balance(self, tenant_id, code, new_balance, updated_at).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn try_adjust_gift_card_balance(
         &self,
         tenant_id: &str,
@@ -797,12 +1112,15 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<i64>> {
         catalog::try_adjust_gift_card_balance(self, tenant_id, code, deduction, updated_at).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_collection(&self, collection: Collection) -> StorageResult<()> {
         catalog::create_collection(self, collection).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn update_collection(&self, collection: Collection) -> StorageResult<()> {
         catalog::update_collection(self, collection).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_collection(
         &self,
         tenant_id: &str,
@@ -810,6 +1128,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<Collection>> {
         catalog::get_collection(self, tenant_id, collection_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_collections(
         &self,
         tenant_id: &str,
@@ -819,12 +1138,15 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<Collection>> {
         catalog::list_collections(self, tenant_id, active_only, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn delete_collection(&self, tenant_id: &str, collection_id: &str) -> StorageResult<()> {
         catalog::delete_collection(self, tenant_id, collection_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn record_gift_card_redemption(&self, r: GiftCardRedemption) -> StorageResult<()> {
         catalog::record_gift_card_redemption(self, r).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_gift_card_redemptions(
         &self,
         tenant_id: &str,
@@ -833,12 +1155,14 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<GiftCardRedemption>> {
         catalog::list_gift_card_redemptions(self, tenant_id, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_gift_card_redemption_by_token(
         &self,
         token: &str,
     ) -> StorageResult<Option<GiftCardRedemption>> {
         catalog::get_gift_card_redemption_by_token(self, token).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn claim_gift_card_redemption(
         &self,
         id: &str,
@@ -847,15 +1171,18 @@ impl Store for PostgresStore {
     ) -> StorageResult<()> {
         catalog::claim_gift_card_redemption(self, id, recipient_user_id, credits_issued).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_tenant_token22_mint(
         &self,
         tenant_id: &str,
     ) -> StorageResult<Option<TenantToken22Mint>> {
         catalog::get_tenant_token22_mint(self, tenant_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn upsert_tenant_token22_mint(&self, mint: TenantToken22Mint) -> StorageResult<()> {
         catalog::upsert_tenant_token22_mint(self, mint).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_token22_mint_for_collection(
         &self,
         tenant_id: &str,
@@ -863,6 +1190,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<TenantToken22Mint>> {
         catalog::get_token22_mint_for_collection(self, tenant_id, collection_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn upsert_token22_mint_for_collection(
         &self,
         mint: TenantToken22Mint,
@@ -871,9 +1199,11 @@ impl Store for PostgresStore {
     }
 
     // ─── Asset redemptions ──────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn record_asset_redemption(&self, r: AssetRedemption) -> StorageResult<()> {
         catalog::record_asset_redemption(self, r).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_asset_redemption(
         &self,
         tenant_id: &str,
@@ -881,6 +1211,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<AssetRedemption>> {
         catalog::get_asset_redemption(self, tenant_id, id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_asset_redemptions(
         &self,
         tenant_id: &str,
@@ -891,6 +1222,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<AssetRedemption>> {
         catalog::list_asset_redemptions(self, tenant_id, status, collection_id, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn update_asset_redemption_status(
         &self,
         tenant_id: &str,
@@ -900,6 +1232,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<()> {
         catalog::update_asset_redemption_status(self, tenant_id, id, status, admin_notes).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn update_asset_redemption_form_data(
         &self,
         tenant_id: &str,
@@ -908,6 +1241,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<()> {
         catalog::update_asset_redemption_form_data(self, tenant_id, id, form_data).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn record_token_burn_signature(
         &self,
         tenant_id: &str,
@@ -918,18 +1252,23 @@ impl Store for PostgresStore {
     }
 
     // ─── Payments ───────────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn record_payment(&self, tx: PaymentTransaction) -> StorageResult<()> {
         payments::record_payment(self, tx).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn record_payments(&self, txs: Vec<PaymentTransaction>) -> StorageResult<()> {
         payments::record_payments(self, txs).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn try_record_payment(&self, tx: PaymentTransaction) -> StorageResult<bool> {
         payments::try_record_payment(self, tx).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn delete_payment(&self, tenant_id: &str, signature: &str) -> StorageResult<()> {
         payments::delete_payment(self, tenant_id, signature).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn has_payment_been_processed(
         &self,
         tenant_id: &str,
@@ -937,6 +1276,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<bool> {
         payments::has_payment_been_processed(self, tenant_id, signature).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_payment(
         &self,
         tenant_id: &str,
@@ -944,6 +1284,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<PaymentTransaction>> {
         payments::get_payment(self, tenant_id, signature).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_purchase_by_signature(
         &self,
         tenant_id: &str,
@@ -951,9 +1292,11 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<Purchase>> {
         payments::get_purchase_by_signature(self, tenant_id, signature).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn store_credits_hold(&self, hold: CreditsHold) -> StorageResult<()> {
         payments::store_credits_hold(self, hold).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_credits_hold(
         &self,
         tenant_id: &str,
@@ -961,12 +1304,15 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<CreditsHold>> {
         payments::get_credits_hold(self, tenant_id, hold_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn delete_credits_hold(&self, tenant_id: &str, hold_id: &str) -> StorageResult<()> {
         payments::delete_credits_hold(self, tenant_id, hold_id).await
     }
-    async fn cleanup_expired_credits_holds(&self) -> StorageResult<u64> {
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn cleanup_expired_credits_holds(&self) -> StorageResult<Vec<CreditsHold>> {
         payments::cleanup_expired_credits_holds(self).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_purchases_by_user_id(
         &self,
         tenant_id: &str,
@@ -976,6 +1322,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<Purchase>> {
         payments::list_purchases_by_user_id(self, tenant_id, user_id, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn has_valid_access(
         &self,
         tenant_id: &str,
@@ -984,14 +1331,44 @@ impl Store for PostgresStore {
     ) -> StorageResult<bool> {
         payments::has_valid_access(self, tenant_id, resource, wallet).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn archive_old_payments(&self, older_than: DateTime<Utc>) -> StorageResult<u64> {
         payments::archive_old_payments(self, older_than).await
     }
 
+    // ─── Cold Archival ──────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn list_archivable_records(
+        &self,
+        tenant_id: &str,
+        older_than: DateTime<Utc>,
+        limit: i64,
+    ) -> StorageResult<ArchivableRecords> {
+        archive::list_archivable_records(self, tenant_id, older_than, limit).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn purge_archived_records(
+        &self,
+        tenant_id: &str,
+        purge: &ArchivePurge,
+    ) -> StorageResult<u64> {
+        archive::purge_archived_records(self, tenant_id, purge).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn find_archived_payment(
+        &self,
+        tenant_id: &str,
+        signature: &str,
+    ) -> StorageResult<Option<ArchivedPaymentRef>> {
+        archive::find_archived_payment(self, tenant_id, signature).await
+    }
+
     // ─── Auth / Nonces ──────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_nonce(&self, nonce: AdminNonce) -> StorageResult<()> {
         auth::create_nonce(self, nonce).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_nonce(
         &self,
         tenant_id: &str,
@@ -999,26 +1376,33 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<AdminNonce>> {
         auth::get_nonce(self, tenant_id, nonce_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn consume_nonce(&self, tenant_id: &str, nonce_id: &str) -> StorageResult<()> {
         auth::consume_nonce(self, tenant_id, nonce_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn cleanup_expired_nonces(&self) -> StorageResult<u64> {
         auth::cleanup_expired_nonces(self).await
     }
 
     // ─── Webhooks, emails, idempotency, DLQ ─────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn enqueue_webhook(&self, webhook: PendingWebhook) -> StorageResult<String> {
         webhooks::enqueue_webhook(self, webhook).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn dequeue_webhooks(&self, limit: i32) -> StorageResult<Vec<PendingWebhook>> {
         webhooks::dequeue_webhooks(self, limit).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn mark_webhook_processing(&self, webhook_id: &str) -> StorageResult<()> {
         webhooks::mark_webhook_processing(self, webhook_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn mark_webhook_success(&self, webhook_id: &str) -> StorageResult<()> {
         webhooks::mark_webhook_success(self, webhook_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn mark_webhook_failed(
         &self,
         webhook_id: &str,
@@ -1027,6 +1411,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<()> {
         webhooks::mark_webhook_failed(self, webhook_id, error, next_attempt_at).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn mark_webhook_retry(
         &self,
         webhook_id: &str,
@@ -1035,9 +1420,11 @@ impl Store for PostgresStore {
     ) -> StorageResult<()> {
         webhooks::mark_webhook_retry(self, webhook_id, error, next_attempt_at).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_webhook(&self, webhook_id: &str) -> StorageResult<Option<PendingWebhook>> {
         webhooks::get_webhook(self, webhook_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_webhooks(
         &self,
         tenant_id: &str,
@@ -1046,30 +1433,62 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<PendingWebhook>> {
         webhooks::list_webhooks(self, tenant_id, status, limit).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn retry_webhook(&self, webhook_id: &str) -> StorageResult<()> {
         webhooks::retry_webhook(self, webhook_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn delete_webhook(&self, webhook_id: &str) -> StorageResult<()> {
         webhooks::delete_webhook(self, webhook_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn cleanup_old_webhooks(&self, retention_days: i32) -> StorageResult<u64> {
         webhooks::cleanup_old_webhooks(self, retention_days).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn count_pending_webhooks(&self) -> StorageResult<i64> {
         webhooks::count_pending_webhooks(self).await
     }
+
+    // ─── Event log ──────────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %entry.tenant_id))]
+    async fn append_event(&self, entry: EventLogEntry) -> StorageResult<i64> {
+        events::append_event(self, entry).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_events(
+        &self,
+        tenant_id: &str,
+        query: &EventLogQuery,
+    ) -> StorageResult<Vec<EventLogEntry>> {
+        events::list_events(self, tenant_id, query).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn cleanup_old_events(&self, older_than: DateTime<Utc>) -> StorageResult<u64> {
+        events::cleanup_old_events(self, older_than).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn enqueue_email(&self, email: PendingEmail) -> StorageResult<String> {
         webhooks::enqueue_email(self, email).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn dequeue_emails(&self, limit: i32) -> StorageResult<Vec<PendingEmail>> {
         webhooks::dequeue_emails(self, limit).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn mark_email_processing(&self, email_id: &str) -> StorageResult<()> {
         webhooks::mark_email_processing(self, email_id).await
     }
-    async fn mark_email_success(&self, email_id: &str) -> StorageResult<()> {
-        webhooks::mark_email_success(self, email_id).await
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn mark_email_success(
+        &self,
+        email_id: &str,
+        provider: &str,
+        provider_message_id: Option<&str>,
+    ) -> StorageResult<()> {
+        webhooks::mark_email_success(self, email_id, provider, provider_message_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn mark_email_retry(
         &self,
         email_id: &str,
@@ -1078,15 +1497,87 @@ impl Store for PostgresStore {
     ) -> StorageResult<()> {
         webhooks::mark_email_retry(self, email_id, error, next_attempt_at).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn mark_email_failed(&self, email_id: &str, error: &str) -> StorageResult<()> {
         webhooks::mark_email_failed(self, email_id, error).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn mark_email_suppressed(&self, email_id: &str, reason: &str) -> StorageResult<()> {
+        webhooks::mark_email_suppressed(self, email_id, reason).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn mark_email_bounced(
+        &self,
+        tenant_id: &str,
+        provider_message_id: &str,
+        reason: &str,
+    ) -> StorageResult<bool> {
+        webhooks::mark_email_bounced(self, tenant_id, provider_message_id, reason).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_email(&self, email_id: &str) -> StorageResult<Option<PendingEmail>> {
         webhooks::get_email(self, email_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn cleanup_old_emails(&self, retention_days: i32) -> StorageResult<u64> {
         webhooks::cleanup_old_emails(self, retention_days).await
     }
+
+    // ─── Email templates ────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %template.tenant_id))]
+    async fn upsert_email_template(&self, template: EmailTemplate) -> StorageResult<()> {
+        email_templates::upsert_email_template(self, template).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn get_email_template(
+        &self,
+        tenant_id: &str,
+        kind: &str,
+        locale: &str,
+    ) -> StorageResult<Option<EmailTemplate>> {
+        email_templates::get_email_template(self, tenant_id, kind, locale).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_email_templates(&self, tenant_id: &str) -> StorageResult<Vec<EmailTemplate>> {
+        email_templates::list_email_templates(self, tenant_id).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn delete_email_template(
+        &self,
+        tenant_id: &str,
+        kind: &str,
+        locale: &str,
+    ) -> StorageResult<bool> {
+        email_templates::delete_email_template(self, tenant_id, kind, locale).await
+    }
+
+    // ─── Email suppressions ─────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %suppression.tenant_id))]
+    async fn upsert_email_suppression(&self, suppression: EmailSuppression) -> StorageResult<()> {
+        email_suppressions::upsert_email_suppression(self, suppression).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn get_email_suppression(
+        &self,
+        tenant_id: &str,
+        email: &str,
+    ) -> StorageResult<Option<EmailSuppression>> {
+        email_suppressions::get_email_suppression(self, tenant_id, email).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_email_suppressions(
+        &self,
+        tenant_id: &str,
+        limit: i32,
+        offset: i32,
+    ) -> StorageResult<Vec<EmailSuppression>> {
+        email_suppressions::list_email_suppressions(self, tenant_id, limit, offset).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn delete_email_suppression(&self, tenant_id: &str, email: &str) -> StorageResult<bool> {
+        email_suppressions::delete_email_suppression(self, tenant_id, email).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn save_idempotency_key(
         &self,
         key: &str,
@@ -1095,6 +1586,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<()> {
         webhooks::save_idempotency_key(self, key, response, ttl).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn try_save_idempotency_key(
         &self,
         key: &str,
@@ -1103,35 +1595,45 @@ impl Store for PostgresStore {
     ) -> StorageResult<bool> {
         webhooks::try_save_idempotency_key(self, key, response, ttl).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_idempotency_key(&self, key: &str) -> StorageResult<Option<IdempotencyResponse>> {
         webhooks::get_idempotency_key(self, key).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn delete_idempotency_key(&self, key: &str) -> StorageResult<()> {
         webhooks::delete_idempotency_key(self, key).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn cleanup_expired_idempotency_keys(&self) -> StorageResult<u64> {
         webhooks::cleanup_expired_idempotency_keys(self).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn move_to_dlq(&self, webhook: PendingWebhook, final_error: &str) -> StorageResult<()> {
         webhooks::move_to_dlq(self, webhook, final_error).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_dlq(&self, tenant_id: &str, limit: i32) -> StorageResult<Vec<DlqWebhook>> {
         webhooks::list_dlq(self, tenant_id, limit).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_dlq_entry(&self, dlq_id: &str) -> StorageResult<Option<DlqWebhook>> {
         webhooks::get_dlq_entry(self, dlq_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn retry_from_dlq(&self, dlq_id: &str) -> StorageResult<()> {
         webhooks::retry_from_dlq(self, dlq_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn delete_from_dlq(&self, dlq_id: &str) -> StorageResult<()> {
         webhooks::delete_from_dlq(self, dlq_id).await
     }
 
     // ─── Subscriptions ──────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn save_subscription(&self, sub: Subscription) -> StorageResult<()> {
         subscriptions::save_subscription(self, sub).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_subscription(
         &self,
         tenant_id: &str,
@@ -1139,6 +1641,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<Subscription>> {
         subscriptions::get_subscription(self, tenant_id, id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_subscription_by_wallet(
         &self,
         tenant_id: &str,
@@ -1147,6 +1650,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<Subscription>> {
         subscriptions::get_subscription_by_wallet(self, tenant_id, wallet, product_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_subscriptions_by_wallet(
         &self,
         tenant_id: &str,
@@ -1154,6 +1658,15 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<Subscription>> {
         subscriptions::get_subscriptions_by_wallet(self, tenant_id, wallet).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn get_subscriptions_by_user_id(
+        &self,
+        tenant_id: &str,
+        user_id: &str,
+    ) -> StorageResult<Vec<Subscription>> {
+        subscriptions::get_subscriptions_by_user_id(self, tenant_id, user_id).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_subscription_by_stripe_id(
         &self,
         tenant_id: &str,
@@ -1161,12 +1674,14 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<Subscription>> {
         subscriptions::get_subscription_by_stripe_id(self, tenant_id, stripe_sub_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn find_subscription_by_stripe_id(
         &self,
         stripe_sub_id: &str,
     ) -> StorageResult<Option<Subscription>> {
         subscriptions::find_subscription_by_stripe_id(self, stripe_sub_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_subscription_by_payment_signature(
         &self,
         tenant_id: &str,
@@ -1175,6 +1690,7 @@ impl Store for PostgresStore {
         subscriptions::get_subscription_by_payment_signature(self, tenant_id, payment_signature)
             .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_active_subscriptions(
         &self,
         tenant_id: &str,
@@ -1182,6 +1698,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<Subscription>> {
         subscriptions::list_active_subscriptions(self, tenant_id, product_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_expiring_subscriptions(
         &self,
         tenant_id: &str,
@@ -1189,6 +1706,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<Subscription>> {
         subscriptions::list_expiring_subscriptions(self, tenant_id, before).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_expiring_local_subscriptions_limited(
         &self,
         tenant_id: &str,
@@ -1198,6 +1716,7 @@ impl Store for PostgresStore {
         subscriptions::list_expiring_local_subscriptions_limited(self, tenant_id, before, limit)
             .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn update_subscription_status(
         &self,
         tenant_id: &str,
@@ -1206,6 +1725,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<()> {
         subscriptions::update_subscription_status(self, tenant_id, id, status).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn update_subscription_statuses(
         &self,
         tenant_id: &str,
@@ -1214,9 +1734,11 @@ impl Store for PostgresStore {
     ) -> StorageResult<()> {
         subscriptions::update_subscription_statuses(self, tenant_id, ids, status).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn delete_subscription(&self, tenant_id: &str, id: &str) -> StorageResult<()> {
         subscriptions::delete_subscription(self, tenant_id, id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_subscriptions_by_stripe_customer_id(
         &self,
         tenant_id: &str,
@@ -1224,6 +1746,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<Subscription>> {
         subscriptions::get_subscriptions_by_stripe_customer_id(self, tenant_id, customer_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_subscriptions_by_product(
         &self,
         tenant_id: &str,
@@ -1231,6 +1754,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<Subscription>> {
         subscriptions::list_subscriptions_by_product(self, tenant_id, product_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn count_subscriptions_by_plan(
         &self,
         tenant_id: &str,
@@ -1238,14 +1762,17 @@ impl Store for PostgresStore {
     ) -> StorageResult<i64> {
         subscriptions::count_subscriptions_by_plan(self, tenant_id, plan_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_tenant_ids(&self) -> StorageResult<Vec<String>> {
         subscriptions::list_tenant_ids(self).await
     }
 
     // ─── Admin ──────────────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_admin_stats(&self, tenant_id: &str) -> StorageResult<AdminStats> {
         admin::get_admin_stats(self, tenant_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_purchases(
         &self,
         tenant_id: &str,
@@ -1257,10 +1784,12 @@ impl Store for PostgresStore {
     }
 
     // ─── Lifecycle ──────────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn close(&self) -> StorageResult<()> {
         self.pool.close().await;
         Ok(())
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn health_check(&self) -> StorageResult<()> {
         sqlx::query("SELECT 1")
             .execute(self.pool.inner())
@@ -1270,9 +1799,11 @@ impl Store for PostgresStore {
     }
 
     // ─── Chat ────────────────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_chat_session(&self, session: ChatSession) -> StorageResult<()> {
         chat::create_chat_session(self, session).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_chat_session(
         &self,
         tenant_id: &str,
@@ -1280,6 +1811,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<ChatSession>> {
         chat::get_chat_session(self, tenant_id, session_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn update_chat_session(
         &self,
         tenant_id: &str,
@@ -1298,6 +1830,7 @@ impl Store for PostgresStore {
         )
         .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_chat_sessions(
         &self,
         tenant_id: &str,
@@ -1308,9 +1841,50 @@ impl Store for PostgresStore {
     ) -> StorageResult<(Vec<ChatSession>, i64)> {
         chat::list_chat_sessions(self, tenant_id, customer_id, status, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn request_chat_handoff(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        reason: Option<&str>,
+        requested_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        chat::request_chat_handoff(self, tenant_id, session_id, reason, requested_at).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn claim_chat_session(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        agent_id: &str,
+        claimed_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        chat::claim_chat_session(self, tenant_id, session_id, agent_id, claimed_at).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn release_chat_session(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        updated_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        chat::release_chat_session(self, tenant_id, session_id, updated_at).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn bind_chat_session_customer(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        customer_id: &str,
+        updated_at: DateTime<Utc>,
+    ) -> StorageResult<bool> {
+        chat::bind_chat_session_customer(self, tenant_id, session_id, customer_id, updated_at).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_chat_message(&self, message: ChatMessage) -> StorageResult<()> {
         chat::create_chat_message(self, message).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_chat_messages(
         &self,
         tenant_id: &str,
@@ -1320,18 +1894,33 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<ChatMessage>> {
         chat::list_chat_messages(self, tenant_id, session_id, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_chat_messages_since(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        since: DateTime<Utc>,
+        limit: i32,
+    ) -> StorageResult<Vec<ChatMessage>> {
+        chat::list_chat_messages_since(self, tenant_id, session_id, since, limit).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_faq(&self, faq: Faq) -> StorageResult<()> {
         chat::create_faq(self, faq).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_faq(&self, tenant_id: &str, faq_id: &str) -> StorageResult<Option<Faq>> {
         chat::get_faq(self, tenant_id, faq_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn update_faq(&self, faq: Faq) -> StorageResult<()> {
         chat::update_faq(self, faq).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn delete_faq(&self, tenant_id: &str, faq_id: &str) -> StorageResult<()> {
         chat::delete_faq(self, tenant_id, faq_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_faqs(
         &self,
         tenant_id: &str,
@@ -1341,6 +1930,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<(Vec<Faq>, i64)> {
         chat::list_faqs(self, tenant_id, active_only, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn search_faqs(
         &self,
         tenant_id: &str,
@@ -1349,6 +1939,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<Faq>> {
         chat::search_faqs(self, tenant_id, query, limit).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_public_faqs(
         &self,
         tenant_id: &str,
@@ -1358,10 +1949,132 @@ impl Store for PostgresStore {
         chat::list_public_faqs(self, tenant_id, limit, offset).await
     }
 
+    // ─── Embeddings ──────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn upsert_embedding(&self, record: EmbeddingRecord) -> StorageResult<()> {
+        embeddings::upsert_embedding(self, record).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_source_embeddings(
+        &self,
+        tenant_id: &str,
+        source_id: &str,
+        entity_types: &[EmbeddingEntity],
+    ) -> StorageResult<Vec<EmbeddingRecord>> {
+        embeddings::list_source_embeddings(self, tenant_id, source_id, entity_types).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn delete_embedding(
+        &self,
+        tenant_id: &str,
+        entity_type: EmbeddingEntity,
+        entity_id: &str,
+    ) -> StorageResult<bool> {
+        embeddings::delete_embedding(self, tenant_id, entity_type, entity_id).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn search_embeddings(
+        &self,
+        tenant_id: &str,
+        model: &str,
+        entity_types: &[EmbeddingEntity],
+        query: &[f32],
+        limit: i32,
+    ) -> StorageResult<Vec<EmbeddingMatch>> {
+        embeddings::search_embeddings(self, tenant_id, model, entity_types, query, limit).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn enqueue_embedding_job(&self, job: EmbeddingJob) -> StorageResult<()> {
+        embeddings::enqueue_embedding_job(self, job).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn claim_embedding_jobs(
+        &self,
+        now: DateTime<Utc>,
+        limit: i32,
+    ) -> StorageResult<Vec<EmbeddingJob>> {
+        embeddings::claim_embedding_jobs(self, now, limit).await
+    }
+
+    // ─── AI usage ────────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %usage.tenant_id))]
+    async fn add_ai_usage(&self, usage: AiUsageRecord) -> StorageResult<()> {
+        ai_usage::add_ai_usage(self, usage).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_ai_usage(
+        &self,
+        tenant_id: &str,
+        period: &str,
+    ) -> StorageResult<Vec<AiUsageRecord>> {
+        ai_usage::list_ai_usage(self, tenant_id, period).await
+    }
+
+    // ─── Bulk product import jobs ─────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %job.tenant_id))]
+    async fn save_product_import_job(&self, job: ProductImportJob) -> StorageResult<()> {
+        product_imports::save_product_import_job(self, job).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn get_product_import_job(
+        &self,
+        tenant_id: &str,
+        job_id: &str,
+    ) -> StorageResult<Option<ProductImportJob>> {
+        product_imports::get_product_import_job(self, tenant_id, job_id).await
+    }
+    async fn fail_stale_product_import_jobs(
+        &self,
+        updated_before: DateTime<Utc>,
+        error: &str,
+    ) -> StorageResult<u64> {
+        product_imports::fail_stale_product_import_jobs(self, updated_before, error).await
+    }
+
+    // ─── Scheduled price changes ──────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %schedule.tenant_id))]
+    async fn save_price_schedule(&self, schedule: PriceSchedule) -> StorageResult<()> {
+        price_schedules::save_price_schedule(self, schedule).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %schedule.tenant_id))]
+    async fn update_price_schedule_if_status(
+        &self,
+        schedule: PriceSchedule,
+        expected: PriceScheduleStatus,
+    ) -> StorageResult<bool> {
+        price_schedules::update_price_schedule_if_status(self, schedule, expected).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn get_price_schedule(
+        &self,
+        tenant_id: &str,
+        schedule_id: &str,
+    ) -> StorageResult<Option<PriceSchedule>> {
+        price_schedules::get_price_schedule(self, tenant_id, schedule_id).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
+    async fn list_product_price_schedules(
+        &self,
+        tenant_id: &str,
+        product_id: &str,
+    ) -> StorageResult<Vec<PriceSchedule>> {
+        price_schedules::list_product_price_schedules(self, tenant_id, product_id).await
+    }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn list_due_price_schedules(
+        &self,
+        now: DateTime<Utc>,
+        limit: i32,
+    ) -> StorageResult<Vec<PriceSchedule>> {
+        price_schedules::list_due_price_schedules(self, now, limit).await
+    }
+
     // ─── Compliance ───────────────────────────────────────────────────────
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn record_token_holder(&self, holder: TokenHolder) -> StorageResult<()> {
         compliance::record_token_holder(self, holder).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_token_holders(
         &self,
         tenant_id: &str,
@@ -1374,6 +2087,7 @@ impl Store for PostgresStore {
         compliance::list_token_holders(self, tenant_id, status, wallet, collection_id, limit, offset)
             .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_unfrozen_token_holders(
         &self,
         tenant_id: &str,
@@ -1382,6 +2096,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<Vec<TokenHolder>> {
         compliance::list_unfrozen_token_holders(self, tenant_id, limit, offset).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn count_token_holders(
         &self,
         tenant_id: &str,
@@ -1389,6 +2104,7 @@ impl Store for PostgresStore {
     ) -> StorageResult<i64> {
         compliance::count_token_holders(self, tenant_id, status).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn update_token_holder_status(
         &self,
         tenant_id: &str,
@@ -1403,6 +2119,7 @@ impl Store for PostgresStore {
         )
         .await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn get_token_holder(
         &self,
         tenant_id: &str,
@@ -1410,9 +2127,11 @@ impl Store for PostgresStore {
     ) -> StorageResult<Option<TokenHolder>> {
         compliance::get_token_holder(self, tenant_id, holder_id).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql"))]
     async fn record_compliance_action(&self, action: ComplianceAction) -> StorageResult<()> {
         compliance::record_compliance_action(self, action).await
     }
+    #[instrument(skip_all, fields(db.system = "postgresql", tenant_id = %tenant_id))]
     async fn list_compliance_actions(
         &self,
         tenant_id: &str,
@@ -1497,4 +2216,30 @@ mod tests {
             Err(other) => panic!("unexpected error: {other:?}"),
         }
     }
+
+    /// Requires a freshly migrated, empty database at `POSTGRES_URL`.
+    #[tokio::test]
+    #[ignore = "requires integration test database"]
+    async fn test_storage_conformance() {
+        let url = std::env::var("POSTGRES_URL").expect("POSTGRES_URL must be set");
+        let config = crate::storage::PostgresConfig::from_url(&url).expect("valid url");
+        let pool = PostgresPool::new(&config).await.expect("connect");
+        pool.migrate().await.expect("migrate");
+        crate::storage::conformance::run_all(|| async {
+            // Reset the seeded product so each check starts from the same stock level.
+            sqlx::query(
+                "INSERT INTO products (id, tenant_id, description, inventory_quantity, active, created_at, updated_at) \
+                 VALUES ($1, $2, 'Seed product', $3, true, NOW(), NOW()) \
+                 ON CONFLICT (tenant_id, id) DO UPDATE SET inventory_quantity = EXCLUDED.inventory_quantity",
+            )
+            .bind(crate::storage::conformance::SEED_PRODUCT)
+            .bind(crate::storage::conformance::SEED_TENANT)
+            .bind(crate::storage::conformance::SEED_QUANTITY)
+            .execute(pool.inner())
+            .await
+            .expect("seed product");
+            PostgresStore::new(pool.clone(), SchemaMapping::default())
+        })
+        .await;
+    }
 }
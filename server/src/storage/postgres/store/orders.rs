@@ -59,6 +59,22 @@ pub(super) async fn get_order(
     row.map(parse_order).transpose()
 }
 
+pub(super) async fn get_order_by_purchase_id(
+    store: &PostgresStore,
+    tenant_id: &str,
+    purchase_id: &str,
+) -> StorageResult<Option<Order>> {
+    let query = store.orders_query(queries::orders::GET_BY_PURCHASE_ID);
+    let row = sqlx::query(&query)
+        .bind(tenant_id)
+        .bind(purchase_id)
+        .fetch_optional(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("get order by purchase id", e))?;
+
+    row.map(parse_order).transpose()
+}
+
 pub(super) async fn list_orders(
     store: &PostgresStore,
     tenant_id: &str,
@@ -77,6 +93,26 @@ pub(super) async fn list_orders(
     rows.into_iter().map(parse_order).collect()
 }
 
+pub(super) async fn list_orders_by_user_id(
+    store: &PostgresStore,
+    tenant_id: &str,
+    user_id: &str,
+    limit: i32,
+    offset: i32,
+) -> StorageResult<Vec<Order>> {
+    let query = store.orders_query(queries::orders::LIST_BY_USER);
+    let rows = sqlx::query(&query)
+        .bind(tenant_id)
+        .bind(user_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list orders by user", e))?;
+
+    rows.into_iter().map(parse_order).collect()
+}
+
 pub(super) async fn list_orders_filtered(
     store: &PostgresStore,
     tenant_id: &str,
@@ -349,12 +385,72 @@ pub(super) async fn create_return_request(
         .bind(request.created_at)
         .bind(request.updated_at)
         .bind(request.status_updated_at)
+        .bind(request.restocking_fee)
+        .bind(request.refund_amount)
+        .bind(&request.refund_method)
+        .bind(&request.refund_id)
+        .bind(request.restocked_at)
         .execute(store.pool.inner())
         .await
         .map_err(|e| StorageError::internal("insert return request", e))?;
     Ok(())
 }
 
+pub(super) async fn update_return_request(
+    store: &PostgresStore,
+    request: ReturnRequest,
+) -> StorageResult<()> {
+    if write_return_request(store, &request, None).await? == 0 {
+        return Err(StorageError::NotFound);
+    }
+    Ok(())
+}
+
+pub(super) async fn update_return_request_if_status(
+    store: &PostgresStore,
+    request: ReturnRequest,
+    expected_status: &str,
+) -> StorageResult<bool> {
+    Ok(write_return_request(store, &request, Some(expected_status)).await? > 0)
+}
+
+async fn write_return_request(
+    store: &PostgresStore,
+    request: &ReturnRequest,
+    expected_status: Option<&str>,
+) -> StorageResult<u64> {
+    let items_json = serde_json::to_value(&request.items)
+        .map_err(|e| StorageError::internal("serialize return items", e))?;
+    let metadata_json = serde_json::to_value(&request.metadata)
+        .map_err(|e| StorageError::internal("serialize return metadata", e))?;
+    let sql = store.orders_query(match expected_status {
+        Some(_) => queries::returns::UPDATE_IF_STATUS,
+        None => queries::returns::UPDATE,
+    });
+    let mut query = sqlx::query(&sql)
+        .bind(&request.tenant_id)
+        .bind(&request.id)
+        .bind(&request.status)
+        .bind(&items_json)
+        .bind(&request.reason)
+        .bind(&metadata_json)
+        .bind(request.updated_at)
+        .bind(request.status_updated_at)
+        .bind(request.restocking_fee)
+        .bind(request.refund_amount)
+        .bind(&request.refund_method)
+        .bind(&request.refund_id)
+        .bind(request.restocked_at);
+    if let Some(expected) = expected_status {
+        query = query.bind(expected);
+    }
+    let result = query
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("update return request", e))?;
+    Ok(result.rows_affected())
+}
+
 pub(super) async fn update_return_status(
     store: &PostgresStore,
     tenant_id: &str,
@@ -271,14 +271,16 @@ pub(super) async fn delete_credits_hold(
     Ok(())
 }
 
-pub(super) async fn cleanup_expired_credits_holds(store: &PostgresStore) -> StorageResult<u64> {
+pub(super) async fn cleanup_expired_credits_holds(
+    store: &PostgresStore,
+) -> StorageResult<Vec<CreditsHold>> {
     let query = store.credits_hold_query(queries::credits_hold::CLEANUP_EXPIRED);
-    let res = sqlx::query(&query)
+    let rows = sqlx::query(&query)
         .bind(Utc::now())
-        .execute(store.pool.inner())
+        .fetch_all(store.pool.inner())
         .await
         .map_err(|e| StorageError::internal("cleanup credits holds", e))?;
-    Ok(res.rows_affected())
+    rows.into_iter().map(parse_credits_hold).collect()
 }
 
 pub(super) async fn list_purchases_by_user_id(
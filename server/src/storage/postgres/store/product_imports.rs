@@ -0,0 +1,91 @@
+//! Bulk product import job methods for PostgresStore
+
+use super::*;
+use crate::models::{ProductImportFormat, ProductImportStatus};
+
+pub(super) async fn save_product_import_job(
+    store: &PostgresStore,
+    job: ProductImportJob,
+) -> StorageResult<()> {
+    let errors_json = serde_json::to_value(&job.errors)
+        .map_err(|e| StorageError::internal("serialize import errors", e))?;
+    sqlx::query(queries::product_imports::UPSERT)
+        .bind(&job.id)
+        .bind(&job.tenant_id)
+        .bind(job.format.as_str())
+        .bind(job.dry_run)
+        .bind(job.status.as_str())
+        .bind(job.total)
+        .bind(job.processed)
+        .bind(job.created)
+        .bind(job.updated)
+        .bind(job.failed)
+        .bind(&errors_json)
+        .bind(&job.error)
+        .bind(job.created_at)
+        .bind(job.updated_at)
+        .bind(job.completed_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("save product import job", e))?;
+    Ok(())
+}
+
+pub(super) async fn get_product_import_job(
+    store: &PostgresStore,
+    tenant_id: &str,
+    job_id: &str,
+) -> StorageResult<Option<ProductImportJob>> {
+    let row = sqlx::query(queries::product_imports::GET)
+        .bind(tenant_id)
+        .bind(job_id)
+        .fetch_optional(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("get product import job", e))?;
+    row.map(parse_product_import_job).transpose()
+}
+
+pub(super) async fn fail_stale_product_import_jobs(
+    store: &PostgresStore,
+    updated_before: DateTime<Utc>,
+    error: &str,
+) -> StorageResult<u64> {
+    let result = sqlx::query(queries::product_imports::FAIL_STALE)
+        .bind(updated_before)
+        .bind(error)
+        .bind(Utc::now())
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("fail stale product import jobs", e))?;
+    Ok(result.rows_affected())
+}
+
+fn parse_product_import_job(row: sqlx::postgres::PgRow) -> StorageResult<ProductImportJob> {
+    let format: String = row.get("format");
+    let format = ProductImportFormat::parse(&format)
+        .ok_or_else(|| StorageError::Database(format!("unknown import format: {}", format)))?;
+    let status: String = row.get("status");
+    let status = ProductImportStatus::parse(&status)
+        .ok_or_else(|| StorageError::Database(format!("unknown import status: {}", status)))?;
+    let errors_json: serde_json::Value = row.get("errors");
+    let errors = serde_json::from_value(errors_json)
+        .map_err(|e| StorageError::internal("failed to parse import errors", e))?;
+
+    Ok(ProductImportJob {
+        id: row.get("id"),
+        tenant_id: row.get("tenant_id"),
+        format,
+        dry_run: row.get("dry_run"),
+        status,
+        total: row.get("total_count"),
+        processed: row.get("processed_count"),
+        created: row.get("created_count"),
+        updated: row.get("updated_count"),
+        failed: row.get("failed_count"),
+        errors,
+        error: row.get("error"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+        completed_at: row.get("completed_at"),
+    })
+}
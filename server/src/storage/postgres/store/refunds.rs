@@ -209,6 +209,24 @@ pub(super) async fn list_pending_refunds(
     rows.into_iter().map(parse_refund_quote).collect()
 }
 
+pub(super) async fn list_processed_refunds(
+    store: &PostgresStore,
+    tenant_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> StorageResult<Vec<RefundQuote>> {
+    let query = store.refund_query(queries::refund::LIST_PROCESSED_BETWEEN);
+    let rows = sqlx::query(&query)
+        .bind(tenant_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list processed refunds", e))?;
+
+    rows.into_iter().map(parse_refund_quote).collect()
+}
+
 pub(super) async fn count_pending_refunds(
     store: &PostgresStore,
     tenant_id: &str,
@@ -370,6 +388,27 @@ pub(super) async fn list_pending_stripe_refund_requests(
         .collect::<StorageResult<Vec<_>>>()
 }
 
+pub(super) async fn list_processed_stripe_refund_requests(
+    store: &PostgresStore,
+    tenant_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> StorageResult<Vec<StripeRefundRequest>> {
+    let query =
+        store.stripe_refund_request_query(queries::stripe_refund_request::LIST_PROCESSED_BETWEEN);
+    let rows = sqlx::query(&query)
+        .bind(tenant_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list processed stripe refund requests", e))?;
+
+    rows.into_iter()
+        .map(parse_stripe_refund_request)
+        .collect::<StorageResult<Vec<_>>>()
+}
+
 pub(super) async fn get_pending_stripe_refund_request_by_original_purchase_id(
     store: &PostgresStore,
     tenant_id: &str,
@@ -93,6 +93,22 @@ pub(super) async fn get_subscriptions_by_wallet(
     rows.into_iter().map(parse_subscription).collect()
 }
 
+pub(super) async fn get_subscriptions_by_user_id(
+    store: &PostgresStore,
+    tenant_id: &str,
+    user_id: &str,
+) -> StorageResult<Vec<Subscription>> {
+    // Per spec (08-storage.md): Query filters by tenant_id for isolation
+    let rows = sqlx::query(queries::subscription::GET_BY_USER_ID)
+        .bind(tenant_id)
+        .bind(user_id)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("get subscriptions by user id", e))?;
+
+    rows.into_iter().map(parse_subscription).collect()
+}
+
 pub(super) async fn get_subscription_by_stripe_id(
     store: &PostgresStore,
     tenant_id: &str,
@@ -237,6 +237,9 @@ pub(in super::super) async fn enqueue_email(
         .bind(email.next_attempt_at)
         .bind(email.created_at)
         .bind(email.completed_at)
+        .bind(&email.traceparent)
+        .bind(&email.provider)
+        .bind(&email.provider_message_id)
         .execute(store.pool.inner())
         .await
         .map_err(|e| StorageError::internal("enqueue email", e))?;
@@ -275,10 +278,14 @@ pub(in super::super) async fn mark_email_processing(
 pub(in super::super) async fn mark_email_success(
     store: &PostgresStore,
     email_id: &str,
+    provider: &str,
+    provider_message_id: Option<&str>,
 ) -> StorageResult<()> {
     let query = store.email_query(queries::email::MARK_SUCCESS);
     sqlx::query(&query)
         .bind(email_id)
+        .bind(provider)
+        .bind(provider_message_id)
         .execute(store.pool.inner())
         .await
         .map_err(|e| StorageError::internal("mark email success", e))?;
@@ -320,6 +327,40 @@ pub(in super::super) async fn mark_email_failed(
     Ok(())
 }
 
+pub(in super::super) async fn mark_email_suppressed(
+    store: &PostgresStore,
+    email_id: &str,
+    reason: &str,
+) -> StorageResult<()> {
+    let query = store.email_query(queries::email::MARK_SUPPRESSED);
+    sqlx::query(&query)
+        .bind(email_id)
+        .bind(reason)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("mark email suppressed", e))?;
+
+    Ok(())
+}
+
+pub(in super::super) async fn mark_email_bounced(
+    store: &PostgresStore,
+    tenant_id: &str,
+    provider_message_id: &str,
+    reason: &str,
+) -> StorageResult<bool> {
+    let query = store.email_query(queries::email::MARK_BOUNCED);
+    let result = sqlx::query(&query)
+        .bind(tenant_id)
+        .bind(provider_message_id)
+        .bind(reason)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("mark email bounced", e))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
 pub(in super::super) async fn get_email(
     store: &PostgresStore,
     email_id: &str,
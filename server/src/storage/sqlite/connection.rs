@@ -0,0 +1,192 @@
+//! SQLite connection pool configuration and management
+
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::storage::StorageError;
+
+/// SQLite configuration
+#[derive(Debug, Clone)]
+pub struct SqliteConfig {
+    /// Path to the database file. Created on first connect if missing.
+    pub path: String,
+    pub max_connections: u32,
+    pub acquire_timeout: Duration,
+    /// How long a writer waits for the database lock before failing with
+    /// `SQLITE_BUSY`.
+    pub busy_timeout: Duration,
+}
+
+impl Default for SqliteConfig {
+    fn default() -> Self {
+        Self {
+            path: "cedros_pay.db".to_string(),
+            // SQLite serialises writers; a handful of connections covers
+            // concurrent readers under WAL without contending on the lock.
+            max_connections: 8,
+            acquire_timeout: Duration::from_secs(5),
+            busy_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+impl SqliteConfig {
+    /// Create config for a database file
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Create config from environment variables
+    pub fn from_env() -> Self {
+        Self {
+            path: std::env::var("SQLITE_PATH").unwrap_or_else(|_| "cedros_pay.db".to_string()),
+            max_connections: std::env::var("SQLITE_MAX_CONNECTIONS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(8),
+            acquire_timeout: Duration::from_secs(
+                std::env::var("SQLITE_ACQUIRE_TIMEOUT")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(5),
+            ),
+            busy_timeout: Duration::from_millis(
+                std::env::var("SQLITE_BUSY_TIMEOUT_MS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(5_000),
+            ),
+        }
+    }
+}
+
+/// SQLite connection pool wrapper
+#[derive(Clone)]
+pub struct SqlitePool {
+    pool: sqlx::SqlitePool,
+}
+
+impl SqlitePool {
+    /// Open (or create) the database file and build a connection pool
+    pub async fn new(config: &SqliteConfig) -> Result<Self, StorageError> {
+        let path = config.path.trim_start_matches("sqlite://");
+        let options = SqliteConnectOptions::from_str(&format!("sqlite://{path}"))
+            .map_err(|e| StorageError::internal("invalid SQLite path", e))?
+            .create_if_missing(true)
+            .journal_mode(SqliteJournalMode::Wal)
+            .synchronous(SqliteSynchronous::Normal)
+            .foreign_keys(true)
+            .busy_timeout(config.busy_timeout);
+
+        let pool = SqlitePoolOptions::new()
+            .max_connections(config.max_connections)
+            .acquire_timeout(config.acquire_timeout)
+            .connect_with(options)
+            .await
+            .map_err(|e| StorageError::internal("connection failed", e))?;
+
+        Ok(Self { pool })
+    }
+
+    /// Open a private in-memory database.
+    ///
+    /// The pool is pinned to a single connection that never expires, because
+    /// every new in-memory connection would otherwise see an empty database.
+    pub async fn in_memory() -> Result<Self, StorageError> {
+        let options = SqliteConnectOptions::from_str("sqlite::memory:")
+            .map_err(|e| StorageError::internal("invalid SQLite path", e))?
+            .foreign_keys(true);
+
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .min_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(options)
+            .await
+            .map_err(|e| StorageError::internal("connection failed", e))?;
+
+        Ok(Self { pool })
+    }
+
+    /// Get the underlying pool reference
+    pub fn inner(&self) -> &sqlx::SqlitePool {
+        &self.pool
+    }
+
+    /// Create a SqlitePool from an existing sqlx pool
+    pub fn from_pool(pool: sqlx::SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Run database migrations from `migrations_sqlite`
+    pub async fn migrate(&self) -> Result<(), StorageError> {
+        let mut migrator = sqlx::migrate!("./migrations_sqlite");
+        migrator.ignore_missing = true;
+        migrator
+            .run(&self.pool)
+            .await
+            .map_err(|e| StorageError::internal("migration failed", e))
+    }
+
+    /// Check if the database is healthy
+    pub async fn health_check(&self) -> Result<(), StorageError> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| StorageError::internal("health check failed", e))?;
+        Ok(())
+    }
+
+    /// Close the connection pool
+    pub async fn close(&self) {
+        self.pool.close().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_migrations_apply_to_fresh_database() {
+        let pool = SqlitePool::in_memory().await.expect("open");
+        pool.migrate().await.expect("migrate");
+
+        let tables: Vec<String> =
+            sqlx::query_scalar("SELECT name FROM sqlite_master WHERE type = 'table'")
+                .fetch_all(pool.inner())
+                .await
+                .expect("list tables");
+        for expected in [
+            "payment_transactions",
+            "products",
+            "orders",
+            "ledger_entries",
+        ] {
+            assert!(tables.iter().any(|t| t == expected), "missing {expected}");
+        }
+    }
+
+    #[test]
+    fn test_sqlite_migrations_mirror_postgres_set() {
+        let names = |dir: &str| -> Vec<String> {
+            let mut names: Vec<String> = std::fs::read_dir(dir)
+                .expect("read migrations dir")
+                .filter_map(|e| e.ok())
+                .map(|e| e.file_name().to_string_lossy().into_owned())
+                .collect();
+            names.sort();
+            names
+        };
+        let root = env!("CARGO_MANIFEST_DIR");
+        assert_eq!(
+            names(&format!("{root}/migrations")),
+            names(&format!("{root}/migrations_sqlite"))
+        );
+    }
+}
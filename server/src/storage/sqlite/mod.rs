@@ -0,0 +1,12 @@
+//! SQLite storage backend implementation
+//!
+//! A single-file alternative to PostgreSQL for small deployments. The schema
+//! lives in `migrations_sqlite/`, which mirrors `migrations/` file for file.
+
+mod connection;
+mod parsers;
+mod queries;
+mod store;
+
+pub use connection::{SqliteConfig, SqlitePool};
+pub use store::SqliteStore;
@@ -0,0 +1,2122 @@
+//! SQL query constants for SQLite storage
+//!
+//! These mirror the PostgreSQL queries. Timestamps are RFC 3339 UTC text, so
+//! `strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')` becomes `strftime(...)` in the same format and range checks compare
+//! as strings. Array parameters are bound as JSON text and expanded with
+//! `json_each`.
+//!
+//! Note: Queries are structured to support multi-tenant isolation (tenant_id in WHERE/PK).
+
+/// Cart quote queries
+/// Per spec (08-storage.md): All queries must include tenant_id for multi-tenant isolation
+pub mod cart {
+    pub const INSERT: &str = r#"
+        INSERT INTO cart_quotes (id, tenant_id, items, total_amount, total_asset, metadata, created_at, expires_at, wallet_paid_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (tenant_id, id) DO UPDATE SET
+            items = EXCLUDED.items,
+            total_amount = EXCLUDED.total_amount,
+            total_asset = EXCLUDED.total_asset,
+            metadata = EXCLUDED.metadata,
+            expires_at = EXCLUDED.expires_at,
+            wallet_paid_by = EXCLUDED.wallet_paid_by
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const GET_BY_ID: &str = r#"
+        SELECT id, tenant_id, items, total_amount, total_asset, metadata, created_at, expires_at, wallet_paid_by
+        FROM cart_quotes WHERE id = $1 AND tenant_id = $2
+    "#;
+
+    /// Batch query for multiple cart IDs (avoids N+1 queries)
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const GET_BY_IDS: &str = r#"
+        SELECT id, tenant_id, items, total_amount, total_asset, metadata, created_at, expires_at, wallet_paid_by
+        FROM cart_quotes WHERE id IN (SELECT value FROM json_each($1)) AND tenant_id = $2
+    "#;
+
+    /// Per spec (08-storage.md): Update must filter by tenant_id for isolation
+    /// SECURITY: Only mark as paid if not already paid (prevents race condition / double payment)
+    pub const MARK_PAID: &str = r#"
+        UPDATE cart_quotes SET wallet_paid_by = $3 WHERE id = $1 AND tenant_id = $2 AND wallet_paid_by IS NULL
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const HAS_ACCESS: &str = r#"
+        SELECT wallet_paid_by FROM cart_quotes WHERE id = $1 AND tenant_id = $2
+    "#;
+
+    /// Delete expired cart quotes across tenants (admin only, batched to avoid long locks)
+    pub const CLEANUP_EXPIRED_ALL: &str = r#"
+        DELETE FROM cart_quotes WHERE id IN (
+            SELECT id FROM cart_quotes
+            WHERE expires_at < strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now') AND wallet_paid_by IS NULL
+            LIMIT 1000
+        )
+    "#;
+}
+
+/// Refund quote queries
+/// Per spec (08-storage.md): All queries must include tenant_id for multi-tenant isolation
+pub mod refund {
+    pub const INSERT: &str = r#"
+        INSERT INTO refund_quotes (
+            id, tenant_id, original_purchase_id, recipient_wallet, amount, amount_asset, reason,
+            metadata, created_at, expires_at, processed_by, processed_at, signature
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+        ON CONFLICT (tenant_id, id) DO UPDATE SET
+            original_purchase_id = EXCLUDED.original_purchase_id,
+            recipient_wallet = EXCLUDED.recipient_wallet,
+            amount = EXCLUDED.amount,
+            amount_asset = EXCLUDED.amount_asset,
+            reason = EXCLUDED.reason,
+            metadata = EXCLUDED.metadata,
+            expires_at = EXCLUDED.expires_at,
+            processed_by = EXCLUDED.processed_by,
+            processed_at = EXCLUDED.processed_at,
+            signature = EXCLUDED.signature
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const GET_BY_ID: &str = r#"
+        SELECT id, tenant_id, original_purchase_id, recipient_wallet, amount, amount_asset, reason,
+               metadata, created_at, expires_at, processed_by, processed_at, signature
+        FROM refund_quotes WHERE id = $1 AND tenant_id = $2
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const LIST_PENDING: &str = r#"
+        SELECT id, tenant_id, original_purchase_id, recipient_wallet, amount, amount_asset, reason,
+               metadata, created_at, expires_at, processed_by, processed_at, signature
+        FROM refund_quotes
+        WHERE tenant_id = $1 AND processed_at IS NULL AND expires_at > strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+        ORDER BY created_at ASC
+        LIMIT $2
+    "#;
+
+    /// Executed refunds in a processing window (financial reporting)
+    pub const LIST_PROCESSED_BETWEEN: &str = r#"
+        SELECT id, tenant_id, original_purchase_id, recipient_wallet, amount, amount_asset, reason,
+               metadata, created_at, expires_at, processed_by, processed_at, signature
+        FROM refund_quotes
+        WHERE tenant_id = $1 AND signature IS NOT NULL
+          AND processed_at >= $2 AND processed_at < $3
+        ORDER BY processed_at ASC
+    "#;
+
+    pub const COUNT_PENDING: &str = r#"
+        SELECT COUNT(*)
+        FROM refund_quotes
+        WHERE tenant_id = $1 AND processed_at IS NULL AND expires_at > strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+    "#;
+
+    /// Per spec (08-storage.md): Update must filter by tenant_id for isolation
+    pub const MARK_PROCESSED: &str = r#"
+        UPDATE refund_quotes
+        SET processed_by = $3, processed_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'), signature = $4
+        WHERE id = $1 AND tenant_id = $2
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const GET_BY_ORIGINAL_PURCHASE_ID: &str = r#"
+        SELECT id, tenant_id, original_purchase_id, recipient_wallet, amount, amount_asset, reason,
+               metadata, created_at, expires_at, processed_by, processed_at, signature
+        FROM refund_quotes WHERE original_purchase_id = $1 AND tenant_id = $2
+        ORDER BY created_at DESC LIMIT 1
+    "#;
+
+    /// Get ALL refunds for a purchase (for cumulative tracking)
+    // DB-08d: Added LIMIT 1000 to bound unbounded query
+    pub const GET_ALL_BY_ORIGINAL_PURCHASE_ID: &str = r#"
+        SELECT id, tenant_id, original_purchase_id, recipient_wallet, amount, amount_asset, reason,
+               metadata, created_at, expires_at, processed_by, processed_at, signature
+        FROM refund_quotes WHERE original_purchase_id = $1 AND tenant_id = $2
+        ORDER BY created_at ASC
+        LIMIT 1000
+    "#;
+
+    /// Per spec (08-storage.md): Delete must filter by tenant_id for isolation
+    pub const DELETE: &str = r#"
+        DELETE FROM refund_quotes WHERE id = $1 AND tenant_id = $2
+    "#;
+
+    /// List credits refund requests with optional status filter and pagination.
+    /// Credits refunds have `original_purchase_id LIKE 'credits:%'`.
+    /// Uses COUNT(*) OVER() for atomic count+paginate in a single query.
+    pub const LIST_CREDITS_REFUNDS: &str = r#"
+        SELECT id, tenant_id, original_purchase_id, recipient_wallet, amount, amount_asset, reason,
+               metadata, created_at, expires_at, processed_by, processed_at, signature,
+               COUNT(*) OVER() AS total_count
+        FROM refund_quotes
+        WHERE tenant_id = $1
+          AND original_purchase_id LIKE 'credits:%'
+          AND (
+            $2 IS NULL
+            OR ($2 = 'pending' AND processed_at IS NULL AND expires_at > strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'))
+            OR ($2 = 'completed' AND processed_at IS NOT NULL AND signature IS NOT NULL)
+            OR ($2 = 'denied' AND processed_at IS NOT NULL AND signature IS NULL)
+          )
+        ORDER BY created_at DESC
+        LIMIT $3 OFFSET $4
+    "#;
+
+    /// Delete expired refund quotes across tenants (admin only, batched to avoid long locks)
+    pub const CLEANUP_EXPIRED_ALL: &str = r#"
+        DELETE FROM refund_quotes WHERE id IN (
+            SELECT id FROM refund_quotes
+            WHERE expires_at < strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now') AND processed_at IS NULL
+            LIMIT 1000
+        )
+    "#;
+}
+
+/// Stripe refund request queries
+/// Per spec (08-storage.md): All queries must include tenant_id for multi-tenant isolation
+pub mod stripe_connect {
+    pub const UPSERT_ACCOUNT: &str = r#"
+        INSERT INTO stripe_connect_accounts (
+            tenant_id, account_id, charges_enabled, payouts_enabled, details_submitted,
+            application_fee_bps, created_at, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ON CONFLICT (tenant_id) DO UPDATE SET
+            account_id = EXCLUDED.account_id,
+            charges_enabled = EXCLUDED.charges_enabled,
+            payouts_enabled = EXCLUDED.payouts_enabled,
+            details_submitted = EXCLUDED.details_submitted,
+            application_fee_bps = EXCLUDED.application_fee_bps,
+            updated_at = EXCLUDED.updated_at
+    "#;
+
+    pub const GET_ACCOUNT_BY_TENANT: &str = r#"
+        SELECT tenant_id, account_id, charges_enabled, payouts_enabled, details_submitted,
+               application_fee_bps, created_at, updated_at
+        FROM stripe_connect_accounts
+        WHERE tenant_id = $1
+    "#;
+
+    pub const GET_ACCOUNT_BY_ACCOUNT_ID: &str = r#"
+        SELECT tenant_id, account_id, charges_enabled, payouts_enabled, details_submitted,
+               application_fee_bps, created_at, updated_at
+        FROM stripe_connect_accounts
+        WHERE account_id = $1
+    "#;
+
+    pub const UPSERT_FEE: &str = r#"
+        INSERT INTO stripe_application_fees (
+            id, tenant_id, account_id, charge_id, amount, amount_refunded, currency, created_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ON CONFLICT (tenant_id, id) DO UPDATE SET
+            amount = EXCLUDED.amount,
+            amount_refunded = EXCLUDED.amount_refunded
+    "#;
+
+    pub const LIST_FEES: &str = r#"
+        SELECT id, tenant_id, account_id, charge_id, amount, amount_refunded, currency, created_at
+        FROM stripe_application_fees
+        WHERE tenant_id = $1 AND created_at >= $2 AND created_at < $3
+        ORDER BY created_at ASC
+    "#;
+}
+
+/// Affiliate and commission ledger queries
+pub mod affiliates {
+    pub const UPSERT: &str = r#"
+        INSERT INTO affiliates (
+            id, tenant_id, code, name, email, payout_wallet, active,
+            commission_type, commission_value, rules, created_at, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+        ON CONFLICT (tenant_id, id) DO UPDATE SET
+            code = EXCLUDED.code,
+            name = EXCLUDED.name,
+            email = EXCLUDED.email,
+            payout_wallet = EXCLUDED.payout_wallet,
+            active = EXCLUDED.active,
+            commission_type = EXCLUDED.commission_type,
+            commission_value = EXCLUDED.commission_value,
+            rules = EXCLUDED.rules,
+            updated_at = EXCLUDED.updated_at
+    "#;
+
+    pub const GET_BY_ID: &str = r#"
+        SELECT id, tenant_id, code, name, email, payout_wallet, active,
+               commission_type, commission_value, rules, created_at, updated_at
+        FROM affiliates
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const GET_BY_CODE: &str = r#"
+        SELECT id, tenant_id, code, name, email, payout_wallet, active,
+               commission_type, commission_value, rules, created_at, updated_at
+        FROM affiliates
+        WHERE tenant_id = $1 AND code = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, code, name, email, payout_wallet, active,
+               commission_type, commission_value, rules, created_at, updated_at
+        FROM affiliates
+        WHERE tenant_id = $1
+        ORDER BY code ASC
+        LIMIT $2 OFFSET $3
+    "#;
+
+    pub const INSERT_COMMISSION: &str = r#"
+        INSERT INTO affiliate_commissions (
+            id, tenant_id, affiliate_id, order_id, purchase_id, kind, amount,
+            order_amount, currency, refund_id, created_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        ON CONFLICT (tenant_id, id) DO NOTHING
+    "#;
+
+    pub const LIST_COMMISSIONS: &str = r#"
+        SELECT id, tenant_id, affiliate_id, order_id, purchase_id, kind, amount,
+               order_amount, currency, refund_id, created_at
+        FROM affiliate_commissions
+        WHERE tenant_id = $1
+          AND ($2 IS NULL OR affiliate_id = $2)
+          AND created_at >= $3 AND created_at < $4
+        ORDER BY created_at ASC
+    "#;
+
+    pub const LIST_COMMISSIONS_BY_PURCHASE: &str = r#"
+        SELECT id, tenant_id, affiliate_id, order_id, purchase_id, kind, amount,
+               order_amount, currency, refund_id, created_at
+        FROM affiliate_commissions
+        WHERE tenant_id = $1 AND purchase_id = $2
+        ORDER BY created_at ASC
+    "#;
+}
+
+pub mod ledger {
+    pub const INSERT: &str = r#"
+        INSERT INTO ledger_entries (
+            id, tenant_id, source, source_id, currency, description, lines,
+            occurred_at, created_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (tenant_id, id) DO NOTHING
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, source, source_id, currency, description, lines,
+               occurred_at, created_at
+        FROM ledger_entries
+        WHERE tenant_id = $1 AND occurred_at >= $2 AND occurred_at < $3
+        ORDER BY occurred_at ASC, id ASC
+        LIMIT $4 OFFSET $5
+    "#;
+
+    pub const LIST_BY_SOURCE: &str = r#"
+        SELECT id, tenant_id, source, source_id, currency, description, lines,
+               occurred_at, created_at
+        FROM ledger_entries
+        WHERE tenant_id = $1 AND source = $2 AND source_id = $3
+        ORDER BY occurred_at ASC, id ASC
+    "#;
+
+    pub const TRIAL_BALANCE: &str = r#"
+        SELECT line.value ->> 'account' AS account, currency,
+               COALESCE(SUM(CAST(line.value ->> 'debit' AS INTEGER)), 0) AS debit,
+               COALESCE(SUM(CAST(line.value ->> 'credit' AS INTEGER)), 0) AS credit
+        FROM ledger_entries, json_each(ledger_entries.lines) AS line
+        WHERE tenant_id = $1 AND occurred_at < $2
+        GROUP BY line.value ->> 'account', currency
+        ORDER BY account ASC, currency ASC
+    "#;
+}
+
+/// Cold archival: records past retention and the archived payment index
+pub mod archive {
+    pub const LIST_PAYMENTS: &str = r#"
+        SELECT signature, tenant_id, resource_id, wallet, user_id, amount, amount_asset, created_at, metadata
+        FROM payment_transactions
+        WHERE tenant_id = $1 AND created_at < $2
+        ORDER BY created_at ASC, signature ASC
+        LIMIT $3
+    "#;
+
+    pub const LIST_ORDERS: &str = r#"
+        SELECT id, tenant_id, source, purchase_id, resource_id, user_id, customer, status,
+               items, amount, amount_asset, customer_email, customer_name, receipt_url,
+               shipping, metadata, created_at, updated_at, status_updated_at
+        FROM orders
+        WHERE tenant_id = $1 AND COALESCE(updated_at, created_at) < $2
+        ORDER BY COALESCE(updated_at, created_at) ASC, id ASC
+        LIMIT $3
+    "#;
+
+    pub const LIST_ORDER_HISTORY: &str = r#"
+        SELECT id, tenant_id, order_id, from_status, to_status, note, actor, created_at
+        FROM order_history
+        WHERE tenant_id = $1 AND order_id IN (SELECT value FROM json_each($2))
+        ORDER BY order_id ASC, created_at ASC
+    "#;
+
+    pub const LIST_WEBHOOKS: &str = r#"
+        SELECT id, tenant_id, url, payload, payload_bytes, headers, event_type, status, attempts, max_attempts,
+               last_error, last_attempt_at, next_attempt_at, created_at, completed_at
+        FROM webhook_queue
+        WHERE tenant_id = $1 AND status IN ('success', 'failed') AND completed_at < $2
+        ORDER BY completed_at ASC, id ASC
+        LIMIT $3
+    "#;
+
+    pub const INDEX_PAYMENT: &str = r#"
+        INSERT INTO archived_payments (tenant_id, signature, object_key, archived_at)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (tenant_id, signature) DO UPDATE
+        SET object_key = excluded.object_key, archived_at = excluded.archived_at
+    "#;
+
+    pub const DELETE_PAYMENTS: &str = r#"
+        DELETE FROM payment_transactions
+        WHERE tenant_id = $1 AND signature IN (SELECT value FROM json_each($2))
+    "#;
+
+    pub const DELETE_ORDER_HISTORY: &str = r#"
+        DELETE FROM order_history
+        WHERE tenant_id = $1 AND order_id IN (SELECT value FROM json_each($2))
+    "#;
+
+    pub const DELETE_ORDERS: &str = r#"
+        DELETE FROM orders
+        WHERE tenant_id = $1 AND id IN (SELECT value FROM json_each($2))
+    "#;
+
+    pub const DELETE_WEBHOOKS: &str = r#"
+        DELETE FROM webhook_queue
+        WHERE tenant_id = $1 AND id IN (SELECT value FROM json_each($2))
+    "#;
+
+    pub const GET_ARCHIVED_PAYMENT: &str = r#"
+        SELECT tenant_id, signature, object_key, archived_at
+        FROM archived_payments
+        WHERE tenant_id = $1 AND signature = $2
+    "#;
+}
+
+pub mod stripe_refund_request {
+    pub const UPSERT: &str = r#"
+        INSERT INTO stripe_refund_requests (
+            id, tenant_id, original_purchase_id, stripe_payment_intent_id, stripe_refund_id,
+            stripe_charge_id, amount, currency, status, reason, metadata,
+            created_at, processed_by, processed_at, last_error
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+        ON CONFLICT (tenant_id, id) DO UPDATE SET
+            original_purchase_id = EXCLUDED.original_purchase_id,
+            stripe_payment_intent_id = EXCLUDED.stripe_payment_intent_id,
+            stripe_refund_id = EXCLUDED.stripe_refund_id,
+            stripe_charge_id = EXCLUDED.stripe_charge_id,
+            amount = EXCLUDED.amount,
+            currency = EXCLUDED.currency,
+            status = EXCLUDED.status,
+            reason = EXCLUDED.reason,
+            metadata = EXCLUDED.metadata,
+            processed_by = EXCLUDED.processed_by,
+            processed_at = EXCLUDED.processed_at,
+            last_error = EXCLUDED.last_error
+    "#;
+
+    pub const GET_BY_ID: &str = r#"
+        SELECT id, tenant_id, original_purchase_id, stripe_payment_intent_id, stripe_refund_id,
+               stripe_charge_id, amount, currency, status, reason, metadata,
+               created_at, processed_by, processed_at, last_error
+        FROM stripe_refund_requests
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const LIST_PENDING: &str = r#"
+        SELECT id, tenant_id, original_purchase_id, stripe_payment_intent_id, stripe_refund_id,
+               stripe_charge_id, amount, currency, status, reason, metadata,
+               created_at, processed_by, processed_at, last_error
+        FROM stripe_refund_requests
+        WHERE tenant_id = $1 AND processed_at IS NULL
+        ORDER BY created_at ASC
+        LIMIT $2
+    "#;
+
+    pub const LIST_PROCESSED_BETWEEN: &str = r#"
+        SELECT id, tenant_id, original_purchase_id, stripe_payment_intent_id, stripe_refund_id,
+               stripe_charge_id, amount, currency, status, reason, metadata,
+               created_at, processed_by, processed_at, last_error
+        FROM stripe_refund_requests
+        WHERE tenant_id = $1 AND status NOT IN ('failed', 'canceled')
+          AND processed_at >= $2 AND processed_at < $3
+        ORDER BY processed_at ASC
+    "#;
+
+    pub const GET_PENDING_BY_ORIGINAL_PURCHASE_ID: &str = r#"
+        SELECT id, tenant_id, original_purchase_id, stripe_payment_intent_id, stripe_refund_id,
+               stripe_charge_id, amount, currency, status, reason, metadata,
+               created_at, processed_by, processed_at, last_error
+        FROM stripe_refund_requests
+        WHERE tenant_id = $1 AND original_purchase_id = $2 AND processed_at IS NULL
+        ORDER BY created_at DESC
+        LIMIT 1
+    "#;
+
+    pub const GET_BY_CHARGE_ID: &str = r#"
+        SELECT id, tenant_id, original_purchase_id, stripe_payment_intent_id, stripe_refund_id,
+               stripe_charge_id, amount, currency, status, reason, metadata,
+               created_at, processed_by, processed_at, last_error
+        FROM stripe_refund_requests
+        WHERE tenant_id = $1 AND stripe_charge_id = $2
+        ORDER BY created_at DESC
+        LIMIT 1
+    "#;
+}
+
+/// Order queries
+/// Per spec (08-storage.md): All queries must include tenant_id for multi-tenant isolation
+pub mod orders {
+    pub const INSERT_IF_ABSENT: &str = r#"
+        INSERT INTO orders (
+            id, tenant_id, source, purchase_id, resource_id, user_id, customer, status,
+            items, amount, amount_asset, customer_email, customer_name, receipt_url,
+            shipping, metadata, created_at, updated_at, status_updated_at
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19)
+        ON CONFLICT (tenant_id, source, purchase_id) DO NOTHING
+    "#;
+
+    pub const GET_BY_ID: &str = r#"
+        SELECT id, tenant_id, source, purchase_id, resource_id, user_id, customer, status,
+               items, amount, amount_asset, customer_email, customer_name, receipt_url,
+               shipping, metadata, created_at, updated_at, status_updated_at
+        FROM orders
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const GET_BY_PURCHASE_ID: &str = r#"
+        SELECT id, tenant_id, source, purchase_id, resource_id, user_id, customer, status,
+               items, amount, amount_asset, customer_email, customer_name, receipt_url,
+               shipping, metadata, created_at, updated_at, status_updated_at
+        FROM orders
+        WHERE tenant_id = $1 AND purchase_id = $2
+        ORDER BY created_at ASC
+        LIMIT 1
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, source, purchase_id, resource_id, user_id, customer, status,
+               items, amount, amount_asset, customer_email, customer_name, receipt_url,
+               shipping, metadata, created_at, updated_at, status_updated_at
+        FROM orders
+        WHERE tenant_id = $1
+        ORDER BY created_at DESC
+        LIMIT $2 OFFSET $3
+    "#;
+
+    pub const LIST_BY_USER: &str = r#"
+        SELECT id, tenant_id, source, purchase_id, resource_id, user_id, customer, status,
+               items, amount, amount_asset, customer_email, customer_name, receipt_url,
+               shipping, metadata, created_at, updated_at, status_updated_at
+        FROM orders
+        WHERE tenant_id = $1 AND user_id = $2
+        ORDER BY created_at DESC
+        LIMIT $3 OFFSET $4
+    "#;
+
+    pub const LIST_FILTERED: &str = r#"
+        SELECT id, tenant_id, source, purchase_id, resource_id, user_id, customer, status,
+               items, amount, amount_asset, customer_email, customer_name, receipt_url,
+               shipping, metadata, created_at, updated_at, status_updated_at,
+               COUNT(*) OVER() AS total_count
+        FROM orders
+        WHERE tenant_id = $1
+          AND ($2 IS NULL OR status = $2)
+          AND (
+            $3 IS NULL
+            OR id LIKE $3
+            OR purchase_id LIKE $3
+            OR customer_email LIKE $3
+          )
+          AND ($4 IS NULL OR created_at < $4)
+          AND ($5 IS NULL OR created_at > $5)
+        ORDER BY created_at DESC
+        LIMIT $6 OFFSET $7
+    "#;
+
+    #[allow(dead_code)] // Superseded by COUNT(*) OVER() in LIST_FILTERED
+    pub const COUNT_FILTERED: &str = r#"
+        SELECT COUNT(1)
+        FROM orders
+        WHERE tenant_id = $1
+          AND ($2 IS NULL OR status = $2)
+          AND (
+            $3 IS NULL
+            OR id LIKE $3
+            OR purchase_id LIKE $3
+            OR customer_email LIKE $3
+          )
+          AND ($4 IS NULL OR created_at < $4)
+          AND ($5 IS NULL OR created_at > $5)
+    "#;
+
+    pub const UPDATE_STATUS: &str = r#"
+        UPDATE orders
+        SET status = $3,
+            status_updated_at = $4,
+            updated_at = $5
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+}
+
+pub mod order_history {
+    pub const INSERT: &str = r#"
+        INSERT INTO order_history (
+            id, tenant_id, order_id, from_status, to_status, note, actor, created_at
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8)
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, order_id, from_status, to_status, note, actor, created_at
+        FROM order_history
+        WHERE tenant_id = $1 AND order_id = $2
+        ORDER BY created_at DESC
+        LIMIT $3
+    "#;
+}
+
+pub mod fulfillments {
+    pub const INSERT: &str = r#"
+        INSERT INTO fulfillments (
+            id, tenant_id, order_id, status, carrier, tracking_number, tracking_url,
+            items, shipped_at, delivered_at, metadata, created_at, updated_at
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13)
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, order_id, status, carrier, tracking_number, tracking_url,
+               items, shipped_at, delivered_at, metadata, created_at, updated_at
+        FROM fulfillments
+        WHERE tenant_id = $1 AND order_id = $2
+        ORDER BY created_at DESC
+        LIMIT $3
+    "#;
+
+    pub const GET_BY_ID: &str = r#"
+        SELECT id, tenant_id, order_id, status, carrier, tracking_number, tracking_url,
+               items, shipped_at, delivered_at, metadata, created_at, updated_at
+        FROM fulfillments
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const UPDATE_STATUS: &str = r#"
+        UPDATE fulfillments
+        SET status = $3,
+            shipped_at = $4,
+            delivered_at = $5,
+            updated_at = $6,
+            tracking_number = $7,
+            tracking_url = $8,
+            carrier = $9
+        WHERE tenant_id = $1 AND id = $2
+        RETURNING id, tenant_id, order_id, status, carrier, tracking_number, tracking_url,
+                  items, shipped_at, delivered_at, metadata, created_at, updated_at
+    "#;
+}
+
+pub mod returns {
+    pub const INSERT: &str = r#"
+        INSERT INTO returns (
+            id, tenant_id, order_id, status, items, reason, metadata, created_at, updated_at, status_updated_at,
+            restocking_fee, refund_amount, refund_method, refund_id, restocked_at
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15)
+    "#;
+
+    pub const UPDATE: &str = r#"
+        UPDATE returns
+        SET status = $3,
+            items = $4,
+            reason = $5,
+            metadata = $6,
+            updated_at = $7,
+            status_updated_at = $8,
+            restocking_fee = $9,
+            refund_amount = $10,
+            refund_method = $11,
+            refund_id = $12,
+            restocked_at = $13
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    /// UPDATE that only applies while the return is still in status $14
+    pub const UPDATE_IF_STATUS: &str = r#"
+        UPDATE returns
+        SET status = $3,
+            items = $4,
+            reason = $5,
+            metadata = $6,
+            updated_at = $7,
+            status_updated_at = $8,
+            restocking_fee = $9,
+            refund_amount = $10,
+            refund_method = $11,
+            refund_id = $12,
+            restocked_at = $13
+        WHERE tenant_id = $1 AND id = $2 AND status = $14
+    "#;
+
+    pub const UPDATE_STATUS: &str = r#"
+        UPDATE returns
+        SET status = $3,
+            status_updated_at = $4,
+            updated_at = $5
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT id, tenant_id, order_id, status, items, reason, metadata, created_at, updated_at, status_updated_at,
+               restocking_fee, refund_amount, refund_method, refund_id, restocked_at
+        FROM returns
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, order_id, status, items, reason, metadata, created_at, updated_at, status_updated_at,
+               restocking_fee, refund_amount, refund_method, refund_id, restocked_at
+        FROM returns
+        WHERE tenant_id = $1
+          AND ($2 IS NULL OR status = $2)
+          AND ($3 IS NULL OR order_id = $3)
+        ORDER BY created_at DESC
+        LIMIT $4 OFFSET $5
+    "#;
+}
+
+pub mod inventory_reservations {
+    pub const INSERT: &str = r#"
+        INSERT INTO inventory_reservations (
+            id, tenant_id, product_id, variant_id, quantity, expires_at, cart_id, status, created_at
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9)
+    "#;
+
+    pub const LIST_ACTIVE_BY_CART: &str = r#"
+        SELECT id, tenant_id, product_id, variant_id, quantity, expires_at, cart_id, status, created_at
+        FROM inventory_reservations
+        WHERE tenant_id = $1 AND cart_id = $2 AND status = 'active'
+        ORDER BY created_at DESC
+    "#;
+
+    pub const SUM_ACTIVE_BY_PRODUCT: &str = r#"
+        SELECT COALESCE(SUM(quantity), 0)
+        FROM inventory_reservations
+        WHERE tenant_id = $1
+          AND product_id = $2
+          AND variant_id IS $3
+          AND status = 'active'
+          AND expires_at > $4
+    "#;
+
+    /// Sum active reservations excluding a specific cart (for pre-checkout validation)
+    pub const SUM_ACTIVE_BY_PRODUCT_EXCLUDING_CART: &str = r#"
+        SELECT COALESCE(SUM(quantity), 0)
+        FROM inventory_reservations
+        WHERE tenant_id = $1
+          AND product_id = $2
+          AND variant_id IS $3
+          AND status = 'active'
+          AND expires_at > $4
+          AND (cart_id IS NULL OR cart_id != $5)
+    "#;
+
+    pub const SUM_ACTIVE_BY_PRODUCTS_EXCLUDING_CART: &str = r#"
+        WITH requested AS (
+            SELECT p.value AS product_id, v.value AS variant_id
+            FROM json_each($2) AS p
+            JOIN json_each($3) AS v ON v.key = p.key
+        )
+        SELECT
+            requested.product_id,
+            requested.variant_id,
+            COALESCE(SUM(r.quantity), 0) AS reserved_quantity
+        FROM requested
+        LEFT JOIN inventory_reservations r
+          ON r.tenant_id = $1
+         AND r.product_id = requested.product_id
+         AND r.variant_id IS requested.variant_id
+         AND r.status = 'active'
+         AND r.expires_at > $4
+         AND (r.cart_id IS NULL OR r.cart_id != $5)
+        GROUP BY requested.product_id, requested.variant_id
+    "#;
+
+    pub const RELEASE_BY_CART: &str = r#"
+        UPDATE inventory_reservations
+        SET status = 'released'
+        WHERE tenant_id = $1 AND cart_id = $2 AND status = 'active'
+    "#;
+
+    pub const CONVERT_BY_CART: &str = r#"
+        UPDATE inventory_reservations
+        SET status = 'converted'
+        WHERE tenant_id = $1 AND cart_id = $2 AND status = 'active'
+    "#;
+
+    // DB-09b: Batch LIMIT to prevent unbounded cleanup affecting all rows at once
+    pub const CLEANUP_EXPIRED: &str = r#"
+        UPDATE inventory_reservations
+        SET status = 'released'
+        WHERE rowid IN (
+            SELECT rowid FROM inventory_reservations
+            WHERE status = 'active' AND expires_at < $1
+            LIMIT 1000
+        )
+    "#;
+}
+
+pub mod inventory_adjustments {
+    pub const INSERT: &str = r#"
+        INSERT INTO inventory_adjustments (
+            id, tenant_id, product_id, variant_id, delta, quantity_before, quantity_after, reason, actor, created_at
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10)
+    "#;
+
+    pub const LIST_BY_PRODUCT: &str = r#"
+        SELECT id, tenant_id, product_id, variant_id, delta, quantity_before, quantity_after, reason, actor, created_at
+        FROM inventory_adjustments
+        WHERE tenant_id = $1 AND product_id = $2
+        ORDER BY created_at DESC
+        LIMIT $3 OFFSET $4
+    "#;
+}
+
+pub mod shipping_profiles {
+    pub const INSERT: &str = r#"
+        INSERT INTO shipping_profiles (
+            id, tenant_id, name, description, countries, active, created_at, updated_at
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8)
+    "#;
+
+    pub const UPDATE: &str = r#"
+        UPDATE shipping_profiles
+        SET name = $3,
+            description = $4,
+            countries = $5,
+            active = $6,
+            updated_at = $7
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT id, tenant_id, name, description, countries, active, created_at, updated_at
+        FROM shipping_profiles
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, name, description, countries, active, created_at, updated_at
+        FROM shipping_profiles
+        WHERE tenant_id = $1
+        ORDER BY created_at DESC
+        LIMIT $2 OFFSET $3
+    "#;
+
+    pub const DELETE: &str = r#"
+        DELETE FROM shipping_profiles
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+}
+
+pub mod shipping_rates {
+    pub const INSERT: &str = r#"
+        INSERT INTO shipping_rates (
+            id, tenant_id, profile_id, name, rate_type, amount_atomic, currency,
+            min_subtotal, max_subtotal, active, created_at, updated_at
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12)
+    "#;
+
+    pub const UPDATE: &str = r#"
+        UPDATE shipping_rates
+        SET name = $3,
+            rate_type = $4,
+            amount_atomic = $5,
+            currency = $6,
+            min_subtotal = $7,
+            max_subtotal = $8,
+            active = $9,
+            updated_at = $10
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, profile_id, name, rate_type, amount_atomic, currency,
+               min_subtotal, max_subtotal, active, created_at, updated_at
+        FROM shipping_rates
+        WHERE tenant_id = $1 AND profile_id = $2
+        ORDER BY created_at DESC
+        LIMIT $3 OFFSET $4
+    "#;
+
+    pub const DELETE: &str = r#"
+        DELETE FROM shipping_rates
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+}
+
+pub mod tax_rates {
+    pub const INSERT: &str = r#"
+        INSERT INTO tax_rates (
+            id, tenant_id, name, country, region, rate_bps, active, created_at, updated_at
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9)
+    "#;
+
+    pub const UPDATE: &str = r#"
+        UPDATE tax_rates
+        SET name = $3,
+            country = $4,
+            region = $5,
+            rate_bps = $6,
+            active = $7,
+            updated_at = $8
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT id, tenant_id, name, country, region, rate_bps, active, created_at, updated_at
+        FROM tax_rates
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, name, country, region, rate_bps, active, created_at, updated_at
+        FROM tax_rates
+        WHERE tenant_id = $1
+        ORDER BY created_at DESC
+        LIMIT $2 OFFSET $3
+    "#;
+
+    pub const DELETE: &str = r#"
+        DELETE FROM tax_rates
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+}
+
+pub mod customers {
+    pub const INSERT: &str = r#"
+        INSERT INTO customers (
+            id, tenant_id, email, name, phone, addresses, created_at, updated_at
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8)
+    "#;
+
+    pub const UPDATE: &str = r#"
+        UPDATE customers
+        SET email = $3,
+            name = $4,
+            phone = $5,
+            addresses = $6,
+            updated_at = $7
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT id, tenant_id, email, name, phone, addresses, created_at, updated_at
+        FROM customers
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, email, name, phone, addresses, created_at, updated_at
+        FROM customers
+        WHERE tenant_id = $1
+        ORDER BY created_at DESC
+        LIMIT $2 OFFSET $3
+    "#;
+}
+
+pub mod disputes {
+    pub const INSERT: &str = r#"
+        INSERT INTO disputes (
+            id, tenant_id, source, order_id, payment_intent_id, charge_id, status, reason,
+            amount, currency, metadata, created_at, updated_at, status_updated_at
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14)
+    "#;
+
+    pub const UPDATE_STATUS: &str = r#"
+        UPDATE disputes
+        SET status = $3,
+            status_updated_at = $4,
+            updated_at = $5
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT id, tenant_id, source, order_id, payment_intent_id, charge_id, status, reason,
+               amount, currency, metadata, created_at, updated_at, status_updated_at
+        FROM disputes
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, source, order_id, payment_intent_id, charge_id, status, reason,
+               amount, currency, metadata, created_at, updated_at, status_updated_at
+        FROM disputes
+        WHERE tenant_id = $1
+          AND ($2 IS NULL OR status = $2)
+          AND ($3 IS NULL OR source = $3)
+          AND ($4 IS NULL OR order_id = $4)
+        ORDER BY created_at DESC
+        LIMIT $5 OFFSET $6
+    "#;
+}
+
+pub mod gift_cards {
+    pub const INSERT: &str = r#"
+        INSERT INTO gift_cards (
+            code, tenant_id, initial_balance, balance, currency, active, expires_at, metadata,
+            created_at, updated_at
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10)
+    "#;
+
+    pub const UPDATE: &str = r#"
+        UPDATE gift_cards
+        SET initial_balance = $3,
+            balance = $4,
+            currency = $5,
+            active = $6,
+            expires_at = $7,
+            metadata = $8,
+            updated_at = $9
+        WHERE tenant_id = $1 AND code = $2
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT code, tenant_id, initial_balance, balance, currency, active, expires_at, metadata,
+               created_at, updated_at
+        FROM gift_cards
+        WHERE tenant_id = $1 AND code = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT code, tenant_id, initial_balance, balance, currency, active, expires_at, metadata,
+               created_at, updated_at
+        FROM gift_cards
+        WHERE tenant_id = $1
+          AND ($2 IS NULL OR active = $2)
+        ORDER BY created_at DESC
+        LIMIT $3 OFFSET $4
+    "#;
+
+    pub const UPDATE_BALANCE: &str = r#"
+        UPDATE gift_cards
+        SET balance = $3,
+            updated_at = $4
+        WHERE tenant_id = $1 AND code = $2
+    "#;
+
+    /// Atomically deduct from gift card balance only if sufficient funds exist.
+    /// SECURITY: Prevents race condition / over-redemption (H-001 fix).
+    /// Returns the new balance if successful, or no rows if insufficient funds.
+    pub const TRY_ADJUST_BALANCE: &str = r#"
+        UPDATE gift_cards
+        SET balance = balance - $3,
+            updated_at = $4
+        WHERE tenant_id = $1 AND code = $2 AND balance >= $3
+        RETURNING balance
+    "#;
+}
+
+pub mod collections {
+    pub const INSERT: &str = r#"
+        INSERT INTO collections (
+            id, tenant_id, name, description, product_ids, active, tokenization_config, payment_split,
+            created_at, updated_at
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10)
+    "#;
+
+    pub const UPDATE: &str = r#"
+        UPDATE collections
+        SET name = $3,
+            description = $4,
+            product_ids = $5,
+            active = $6,
+            tokenization_config = $7,
+            payment_split = $8,
+            updated_at = $9
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT id, tenant_id, name, description, product_ids, active, tokenization_config, payment_split,
+               created_at, updated_at
+        FROM collections
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, name, description, product_ids, active, tokenization_config, payment_split,
+               created_at, updated_at
+        FROM collections
+        WHERE tenant_id = $1
+          AND ($2 IS NULL OR active = $2)
+        ORDER BY created_at DESC
+        LIMIT $3 OFFSET $4
+    "#;
+
+    pub const DELETE: &str = r#"
+        DELETE FROM collections
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+}
+
+/// Payment transaction queries
+/// Per spec (08-storage.md): All queries must include tenant_id for multi-tenant isolation
+pub mod payment {
+    /// Per spec (09-storage-postgres.md): INSERT must include tenant_id for multi-tenancy
+    pub const INSERT: &str = r#"
+        INSERT INTO payment_transactions (signature, tenant_id, resource_id, wallet, user_id, amount, amount_asset, created_at, metadata)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (tenant_id, signature) DO NOTHING
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    /// Note: Signature is globally unique, but we still filter by tenant for isolation
+    pub const EXISTS: &str = r#"
+        SELECT EXISTS(SELECT 1 FROM payment_transactions WHERE signature = $1 AND tenant_id = $2)
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const GET_BY_SIGNATURE: &str = r#"
+        SELECT signature, tenant_id, resource_id, wallet, user_id, amount, amount_asset, created_at, metadata
+        FROM payment_transactions WHERE signature = $1 AND tenant_id = $2
+    "#;
+
+    /// Delete payment by signature with tenant isolation (used for webhook idempotency cleanup)
+    pub const DELETE_BY_SIGNATURE: &str = r#"
+        DELETE FROM payment_transactions WHERE signature = $1 AND tenant_id = $2
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const HAS_ACCESS: &str = r#"
+        SELECT EXISTS(
+            SELECT 1 FROM payment_transactions
+            WHERE tenant_id = $1 AND resource_id = $2 AND wallet = $3 AND created_at > $4
+        )
+    "#;
+
+    /// Archive old payments across all tenants (admin only, batched to avoid long locks)
+    pub const ARCHIVE_OLD_ALL: &str = r#"
+        DELETE FROM payment_transactions WHERE rowid IN (
+            SELECT rowid FROM payment_transactions
+            WHERE created_at < $1
+            LIMIT 1000
+        )
+    "#;
+
+    pub const LIST_BY_USER_ID: &str = r#"
+        SELECT signature, tenant_id, resource_id, wallet, user_id, amount, amount_asset, created_at, metadata
+        FROM payment_transactions
+        WHERE tenant_id = $1 AND user_id = $2
+        ORDER BY created_at DESC
+        LIMIT $3 OFFSET $4
+    "#;
+}
+
+/// Credits hold binding queries
+///
+/// Holds are tenant-scoped and used to bind cedros-login hold IDs to
+/// (user_id, resource_id, amount) to prevent replay/mismatch attacks.
+pub mod credits_hold {
+    pub const UPSERT: &str = r#"
+        INSERT INTO credits_holds (
+            tenant_id, hold_id, user_id, resource_id, amount, amount_asset, created_at, expires_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ON CONFLICT (tenant_id, hold_id) DO NOTHING
+    "#;
+
+    /// Refresh `expires_at` for an existing hold only when the binding matches.
+    ///
+    /// SECURITY: prevents overwriting an existing (tenant_id, hold_id) binding to a different
+    /// (user_id, resource_id, amount) tuple.
+    pub const UPDATE_EXPIRES_AT_IF_MATCH: &str = r#"
+        UPDATE credits_holds
+        SET expires_at = $7
+        WHERE tenant_id = $1
+          AND hold_id = $2
+          AND user_id = $3
+          AND resource_id = $4
+          AND amount = $5
+          AND amount_asset = $6
+    "#;
+
+    pub const GET_BY_ID: &str = r#"
+        SELECT tenant_id, hold_id, user_id, resource_id, amount, amount_asset, created_at, expires_at
+        FROM credits_holds
+        WHERE tenant_id = $1 AND hold_id = $2
+    "#;
+
+    pub const DELETE_BY_ID: &str = r#"
+        DELETE FROM credits_holds WHERE tenant_id = $1 AND hold_id = $2
+    "#;
+
+    /// Batched to avoid long locks on large backlogs; returns the removed
+    /// holds so their ledger entries can be released
+    pub const CLEANUP_EXPIRED: &str = r#"
+        DELETE FROM credits_holds WHERE rowid IN (
+            SELECT rowid FROM credits_holds
+            WHERE expires_at < $1
+            LIMIT 1000
+        )
+        RETURNING tenant_id, hold_id, user_id, resource_id, amount, amount_asset, created_at, expires_at
+    "#;
+}
+
+/// Admin nonce queries
+/// Per spec (08-storage.md): All queries must include tenant_id for multi-tenant isolation
+pub mod nonce {
+    pub const INSERT: &str = r#"
+        INSERT INTO admin_nonces (id, tenant_id, purpose, created_at, expires_at, consumed_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const GET_BY_ID: &str = r#"
+        SELECT id, tenant_id, purpose, created_at, expires_at, consumed_at
+        FROM admin_nonces WHERE id = $1 AND tenant_id = $2
+    "#;
+
+    /// Per spec (08-storage.md): Update must filter by tenant_id for isolation
+    pub const CONSUME: &str = r#"
+        UPDATE admin_nonces SET consumed_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now') WHERE id = $1 AND tenant_id = $2 AND consumed_at IS NULL
+    "#;
+
+    /// Cleanup expired nonces across all tenants (admin only, batched to avoid long locks)
+    pub const CLEANUP_EXPIRED_ALL: &str = r#"
+        DELETE FROM admin_nonces WHERE id IN (
+            SELECT id FROM admin_nonces
+            WHERE expires_at < strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now') OR consumed_at IS NOT NULL
+            LIMIT 1000
+        )
+    "#;
+}
+
+/// Webhook queue queries
+pub mod event_log {
+    pub const APPEND: &str = r#"
+        INSERT INTO event_log (tenant_id, event_id, event_type, payload, created_at)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (tenant_id, event_id) DO NOTHING
+        RETURNING sequence
+    "#;
+
+    pub const GET_SEQUENCE: &str = r#"
+        SELECT sequence FROM event_log WHERE tenant_id = $1 AND event_id = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT sequence, tenant_id, event_id, event_type, payload, created_at
+        FROM event_log
+        WHERE tenant_id = $1
+          AND ($2 IS NULL OR sequence > $2)
+          AND ($3 IS NULL OR event_type = $3)
+          AND ($4 IS NULL OR created_at >= $4)
+          AND ($5 IS NULL OR created_at < $5)
+        ORDER BY sequence ASC
+        LIMIT $6
+    "#;
+
+    pub const CLEANUP_OLD: &str = r#"
+        DELETE FROM event_log WHERE created_at < $1
+    "#;
+}
+
+pub mod email_template {
+    pub const UPSERT: &str = r#"
+        INSERT INTO email_templates (
+            tenant_id, kind, locale, subject, body_text, body_html, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (tenant_id, kind, locale) DO UPDATE SET
+            subject = EXCLUDED.subject,
+            body_text = EXCLUDED.body_text,
+            body_html = EXCLUDED.body_html,
+            updated_at = EXCLUDED.updated_at
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT tenant_id, kind, locale, subject, body_text, body_html, updated_at
+        FROM email_templates
+        WHERE tenant_id = $1 AND kind = $2 AND locale = $3
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT tenant_id, kind, locale, subject, body_text, body_html, updated_at
+        FROM email_templates
+        WHERE tenant_id = $1
+        ORDER BY kind ASC, locale ASC
+    "#;
+
+    pub const DELETE: &str = r#"
+        DELETE FROM email_templates WHERE tenant_id = $1 AND kind = $2 AND locale = $3
+    "#;
+}
+
+pub mod email_suppression {
+    pub const UPSERT: &str = r#"
+        INSERT INTO email_suppressions (tenant_id, email, reason, detail, created_at)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (tenant_id, email) DO UPDATE SET
+            reason = EXCLUDED.reason,
+            detail = EXCLUDED.detail,
+            created_at = EXCLUDED.created_at
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT tenant_id, email, reason, detail, created_at
+        FROM email_suppressions
+        WHERE tenant_id = $1 AND email = $2
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT tenant_id, email, reason, detail, created_at
+        FROM email_suppressions
+        WHERE tenant_id = $1
+        ORDER BY created_at DESC, email ASC
+        LIMIT $2 OFFSET $3
+    "#;
+
+    pub const DELETE: &str = r#"
+        DELETE FROM email_suppressions WHERE tenant_id = $1 AND email = $2
+    "#;
+}
+
+pub mod embedding {
+    pub const UPSERT: &str = r#"
+        INSERT INTO embeddings (
+            tenant_id, entity_type, entity_id, source_id, model, content_hash,
+            dimensions, embedding, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (tenant_id, entity_type, entity_id) DO UPDATE SET
+            source_id = EXCLUDED.source_id,
+            model = EXCLUDED.model,
+            content_hash = EXCLUDED.content_hash,
+            dimensions = EXCLUDED.dimensions,
+            embedding = EXCLUDED.embedding,
+            updated_at = EXCLUDED.updated_at
+    "#;
+
+    pub const LIST_BY_SOURCE: &str = r#"
+        SELECT tenant_id, entity_type, entity_id, source_id, model, content_hash,
+               embedding, updated_at
+        FROM embeddings
+        WHERE tenant_id = $1 AND source_id = $2
+          AND entity_type IN (SELECT value FROM json_each($3))
+    "#;
+
+    pub const DELETE: &str = r#"
+        DELETE FROM embeddings
+        WHERE tenant_id = $1 AND entity_type = $2 AND entity_id = $3
+    "#;
+
+    /// Candidates for a vector search; ranked by the server
+    pub const LIST_FOR_SEARCH: &str = r#"
+        SELECT tenant_id, entity_type, entity_id, source_id, model, content_hash,
+               embedding, updated_at
+        FROM embeddings
+        WHERE tenant_id = $1 AND model = $2
+          AND entity_type IN (SELECT value FROM json_each($3))
+          AND dimensions = $4
+    "#;
+
+    pub const ENQUEUE_JOB: &str = r#"
+        INSERT INTO embedding_jobs (tenant_id, source_type, source_id, attempts, available_at)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (tenant_id, source_type, source_id) DO UPDATE SET
+            attempts = EXCLUDED.attempts,
+            available_at = EXCLUDED.available_at
+    "#;
+
+    pub const CLAIM_JOBS: &str = r#"
+        DELETE FROM embedding_jobs
+        WHERE rowid IN (
+            SELECT rowid FROM embedding_jobs
+            WHERE available_at <= $1
+            ORDER BY available_at ASC
+            LIMIT $2
+        )
+        RETURNING tenant_id, source_type, source_id, attempts, available_at
+    "#;
+}
+
+pub mod ai_usage {
+    pub const ADD: &str = r#"
+        INSERT INTO ai_usage (
+            tenant_id, period, task, model, calls, input_tokens, output_tokens,
+            cost_micros, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (tenant_id, period, task, model) DO UPDATE SET
+            calls = ai_usage.calls + EXCLUDED.calls,
+            input_tokens = ai_usage.input_tokens + EXCLUDED.input_tokens,
+            output_tokens = ai_usage.output_tokens + EXCLUDED.output_tokens,
+            cost_micros = ai_usage.cost_micros + EXCLUDED.cost_micros,
+            updated_at = EXCLUDED.updated_at
+    "#;
+
+    pub const LIST_BY_PERIOD: &str = r#"
+        SELECT tenant_id, period, task, model, calls, input_tokens, output_tokens,
+               cost_micros, updated_at
+        FROM ai_usage
+        WHERE tenant_id = $1 AND period = $2
+        ORDER BY task ASC, model ASC
+    "#;
+}
+
+pub mod webhook {
+    /// Per spec (20-webhooks.md): INSERT must include tenant_id for multi-tenancy
+    pub const INSERT: &str = r#"
+        INSERT INTO webhook_queue (
+            id, tenant_id, url, payload, payload_bytes, headers, event_type, status, attempts, max_attempts,
+            last_error, last_attempt_at, next_attempt_at, created_at, completed_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+        ON CONFLICT (id) DO NOTHING
+    "#;
+
+    /// Per spec (20-webhooks.md): DEQUEUE returns tenant_id for proper isolation
+    /// Also recovers orphaned "processing" webhooks stuck for > 5 minutes (crash recovery)
+    /// SQLite serialises writers, so the single UPDATE claims rows without row locks.
+    pub const DEQUEUE: &str = r#"
+        UPDATE webhook_queue
+        SET status = 'processing', last_attempt_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+        WHERE id IN (
+            SELECT id FROM webhook_queue
+            WHERE (
+                -- Normal pending webhooks ready for processing
+                (status = 'pending' AND (next_attempt_at IS NULL OR next_attempt_at <= strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')))
+                OR
+                -- Orphaned processing webhooks stuck for > 5 minutes (crash recovery)
+                (status = 'processing' AND last_attempt_at < strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now', '-5 minutes'))
+            )
+            -- STOR-002: Include id for deterministic ordering when timestamps match
+            ORDER BY created_at ASC, id ASC
+            LIMIT $1
+        )
+        RETURNING id, tenant_id, url, payload, payload_bytes, headers, event_type, status, attempts, max_attempts,
+                  last_error, last_attempt_at, next_attempt_at, created_at, completed_at
+    "#;
+
+    pub const MARK_PROCESSING: &str = r#"
+        UPDATE webhook_queue SET status = 'processing', last_attempt_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now') WHERE id = $1
+    "#;
+
+    pub const MARK_SUCCESS: &str = r#"
+        UPDATE webhook_queue SET status = 'success', completed_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now') WHERE id = $1
+    "#;
+
+    pub const MARK_FAILED: &str = r#"
+        UPDATE webhook_queue
+        SET status = 'failed', last_error = $2, next_attempt_at = $3, attempts = attempts + 1
+        WHERE id = $1
+    "#;
+
+    pub const MARK_RETRY: &str = r#"
+        UPDATE webhook_queue
+        SET status = 'pending', last_error = $2, next_attempt_at = $3, attempts = attempts + 1
+        WHERE id = $1
+    "#;
+
+    pub const GET_BY_ID: &str = r#"
+        SELECT id, tenant_id, url, payload, payload_bytes, headers, event_type, status, attempts, max_attempts,
+               last_error, last_attempt_at, next_attempt_at, created_at, completed_at
+        FROM webhook_queue WHERE id = $1
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, url, payload, payload_bytes, headers, event_type, status, attempts, max_attempts,
+               last_error, last_attempt_at, next_attempt_at, created_at, completed_at
+        FROM webhook_queue
+        WHERE tenant_id = $1 AND ($2 IS NULL OR status = $2)
+        ORDER BY created_at ASC
+        LIMIT $3
+    "#;
+
+    pub const RETRY: &str = r#"
+        UPDATE webhook_queue SET status = 'pending', next_attempt_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now') WHERE id = $1
+    "#;
+
+    pub const DELETE: &str = r#"
+        DELETE FROM webhook_queue WHERE id = $1
+    "#;
+
+    /// Cleanup old completed/failed webhooks (batched to avoid long locks)
+    /// $1: retention_days (integer, e.g., 7 for 7-day retention)
+    pub const CLEANUP_OLD: &str = r#"
+        DELETE FROM webhook_queue WHERE id IN (
+            SELECT id FROM webhook_queue
+            WHERE status IN ('success', 'failed')
+              AND completed_at < strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now', '-' || $1 || ' days')
+            LIMIT 1000
+        )
+    "#;
+
+    /// Count pending/retry-ready webhooks for backlog metric
+    pub const COUNT_PENDING: &str = r#"
+        SELECT COUNT(*) FROM webhook_queue
+        WHERE status IN ('pending', 'processing')
+           OR (status = 'failed' AND next_attempt_at <= strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'))
+    "#;
+}
+
+/// Email queue queries for async email delivery
+pub mod email {
+    pub const INSERT: &str = r#"
+        INSERT INTO email_queue (
+            id, tenant_id, to_email, from_email, from_name, subject, body_text, body_html,
+            status, attempts, max_attempts, last_error, last_attempt_at, next_attempt_at,
+            created_at, completed_at, traceparent, provider, provider_message_id
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
+        ON CONFLICT (id) DO NOTHING
+    "#;
+
+    /// Dequeue emails ready for processing with crash recovery
+    pub const DEQUEUE: &str = r#"
+        UPDATE email_queue
+        SET status = 'processing', last_attempt_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+        WHERE id IN (
+            SELECT id FROM email_queue
+            WHERE (
+                (status = 'pending' AND (next_attempt_at IS NULL OR next_attempt_at <= strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')))
+                OR
+                (status = 'processing' AND last_attempt_at < strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now', '-5 minutes'))
+            )
+            ORDER BY created_at ASC, id ASC
+            LIMIT $1
+        )
+        RETURNING id, tenant_id, to_email, from_email, from_name, subject, body_text, body_html,
+                  status, attempts, max_attempts, last_error, last_attempt_at, next_attempt_at,
+                  created_at, completed_at, traceparent, provider, provider_message_id
+    "#;
+
+    pub const MARK_PROCESSING: &str = r#"
+        UPDATE email_queue SET status = 'processing', last_attempt_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now') WHERE id = $1
+    "#;
+
+    pub const MARK_SUCCESS: &str = r#"
+        UPDATE email_queue
+        SET status = 'completed', completed_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'), provider = $2, provider_message_id = $3
+        WHERE id = $1
+    "#;
+
+    pub const MARK_RETRY: &str = r#"
+        UPDATE email_queue
+        SET status = 'pending', last_error = $2, next_attempt_at = $3, attempts = attempts + 1
+        WHERE id = $1
+    "#;
+
+    pub const MARK_FAILED: &str = r#"
+        UPDATE email_queue
+        SET status = 'failed', last_error = $2, completed_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'), attempts = attempts + 1
+        WHERE id = $1
+    "#;
+
+    pub const MARK_SUPPRESSED: &str = r#"
+        UPDATE email_queue
+        SET status = 'suppressed', last_error = $2, completed_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+        WHERE id = $1
+    "#;
+
+    /// $1: tenant_id, $2: provider_message_id, $3: reason
+    pub const MARK_BOUNCED: &str = r#"
+        UPDATE email_queue
+        SET status = 'bounced', last_error = $3
+        WHERE tenant_id = $1 AND provider_message_id = $2
+    "#;
+
+    pub const GET_BY_ID: &str = r#"
+        SELECT id, tenant_id, to_email, from_email, from_name, subject, body_text, body_html,
+               status, attempts, max_attempts, last_error, last_attempt_at, next_attempt_at,
+               created_at, completed_at, traceparent, provider, provider_message_id
+        FROM email_queue WHERE id = $1
+    "#;
+
+    /// Cleanup old completed/failed emails (batched to avoid long locks)
+    /// $1: retention_days (integer, e.g., 30 for 30-day retention)
+    pub const CLEANUP_OLD: &str = r#"
+        DELETE FROM email_queue WHERE id IN (
+            SELECT id FROM email_queue
+            WHERE status IN ('completed', 'failed', 'suppressed', 'bounced')
+              AND completed_at < strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now', '-' || $1 || ' days')
+            LIMIT 1000
+        )
+    "#;
+}
+
+/// Idempotency queries
+pub mod idempotency {
+    pub const INSERT: &str = r#"
+        INSERT INTO idempotency_keys (key, status_code, headers, body, cached_at, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (key) DO UPDATE SET
+            status_code = EXCLUDED.status_code,
+            headers = EXCLUDED.headers,
+            body = EXCLUDED.body,
+            cached_at = EXCLUDED.cached_at,
+            expires_at = EXCLUDED.expires_at
+    "#;
+
+    pub const INSERT_IF_ABSENT: &str = r#"
+        INSERT INTO idempotency_keys (key, status_code, headers, body, cached_at, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (key) DO NOTHING
+    "#;
+
+    pub const GET_BY_KEY: &str = r#"
+        SELECT key, status_code, headers, body, cached_at
+        FROM idempotency_keys
+        WHERE key = $1 AND expires_at > strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+    "#;
+
+    pub const DELETE: &str = r#"
+        DELETE FROM idempotency_keys WHERE key = $1
+    "#;
+
+    /// Batched to avoid long locks on large backlogs
+    pub const CLEANUP_EXPIRED: &str = r#"
+        DELETE FROM idempotency_keys WHERE key IN (
+            SELECT key FROM idempotency_keys
+            WHERE expires_at < strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+            LIMIT 1000
+        )
+    "#;
+}
+
+/// Subscription queries
+/// Per spec (08-storage.md): All queries must include tenant_id for multi-tenant isolation
+pub mod subscription {
+    pub const INSERT: &str = r#"
+        INSERT INTO subscriptions (
+            id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+            payment_method, billing_period, billing_interval, status,
+            current_period_start, current_period_end, trial_end,
+            cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
+        ON CONFLICT (tenant_id, id) DO UPDATE SET
+            product_id = EXCLUDED.product_id,
+            plan_id = EXCLUDED.plan_id,
+            wallet = EXCLUDED.wallet,
+            user_id = EXCLUDED.user_id,
+            stripe_customer_id = EXCLUDED.stripe_customer_id,
+            stripe_subscription_id = EXCLUDED.stripe_subscription_id,
+            payment_method = EXCLUDED.payment_method,
+            billing_period = EXCLUDED.billing_period,
+            billing_interval = EXCLUDED.billing_interval,
+            status = EXCLUDED.status,
+            current_period_start = EXCLUDED.current_period_start,
+            current_period_end = EXCLUDED.current_period_end,
+            trial_end = EXCLUDED.trial_end,
+            cancelled_at = EXCLUDED.cancelled_at,
+            cancel_at_period_end = EXCLUDED.cancel_at_period_end,
+            metadata = EXCLUDED.metadata,
+            payment_signature = EXCLUDED.payment_signature,
+            updated_at = EXCLUDED.updated_at
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const GET_BY_ID: &str = r#"
+        SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+               payment_method, billing_period, billing_interval, status,
+               current_period_start, current_period_end, trial_end,
+               cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        FROM subscriptions WHERE id = $1 AND tenant_id = $2
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const GET_BY_WALLET_PRODUCT: &str = r#"
+        SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+               payment_method, billing_period, billing_interval, status,
+               current_period_start, current_period_end, trial_end,
+               cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        FROM subscriptions
+        WHERE tenant_id = $1 AND wallet = $2 AND product_id = $3 AND status IN ('active', 'trialing', 'past_due')
+        ORDER BY created_at DESC LIMIT 1
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const GET_BY_WALLET: &str = r#"
+        SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+               payment_method, billing_period, billing_interval, status,
+               current_period_start, current_period_end, trial_end,
+               cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        FROM subscriptions WHERE tenant_id = $1 AND wallet = $2
+        ORDER BY created_at DESC
+        LIMIT 1000
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const GET_BY_USER_ID: &str = r#"
+        SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+               payment_method, billing_period, billing_interval, status,
+               current_period_start, current_period_end, trial_end,
+               cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        FROM subscriptions WHERE tenant_id = $1 AND user_id = $2
+        ORDER BY created_at DESC
+        LIMIT 1000
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const GET_BY_STRIPE_ID: &str = r#"
+        SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+               payment_method, billing_period, billing_interval, status,
+               current_period_start, current_period_end, trial_end,
+               cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        FROM subscriptions WHERE tenant_id = $1 AND stripe_subscription_id = $2
+    "#;
+
+    /// Find subscription by Stripe ID across all tenants (for webhook handling)
+    /// Note: This bypasses tenant isolation because Stripe webhooks don't include tenant context.
+    /// Stripe subscription IDs are globally unique, so this is safe.
+    pub const FIND_BY_STRIPE_ID: &str = r#"
+        SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+               payment_method, billing_period, billing_interval, status,
+               current_period_start, current_period_end, trial_end,
+               cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        FROM subscriptions WHERE stripe_subscription_id = $1
+    "#;
+
+    /// SECURITY (H-004): Query must filter by tenant_id for isolation.
+    /// Used for idempotency - prevents duplicate subscriptions for the same payment.
+    pub const GET_BY_PAYMENT_SIGNATURE: &str = r#"
+        SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+               payment_method, billing_period, billing_interval, status,
+               current_period_start, current_period_end, trial_end,
+               cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        FROM subscriptions WHERE tenant_id = $1 AND payment_signature = $2
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const LIST_ACTIVE: &str = r#"
+        SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+               payment_method, billing_period, billing_interval, status,
+               current_period_start, current_period_end, trial_end,
+               cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        FROM subscriptions
+        WHERE tenant_id = $1 AND status IN ('active', 'trialing', 'past_due')
+          AND ($2 IS NULL OR product_id = $2)
+        ORDER BY created_at DESC
+        LIMIT 1000
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const LIST_EXPIRING: &str = r#"
+        SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+               payment_method, billing_period, billing_interval, status,
+               current_period_start, current_period_end, trial_end,
+               cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        FROM subscriptions
+        WHERE tenant_id = $1 AND current_period_end <= $2 AND status IN ('active', 'trialing', 'past_due')
+        ORDER BY current_period_end ASC
+        LIMIT 1000
+    "#;
+
+    /// List expiring x402/credits subscriptions eligible for expiry (limited).
+    pub const LIST_EXPIRING_LOCAL_LIMITED: &str = r#"
+        SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+               payment_method, billing_period, billing_interval, status,
+               current_period_start, current_period_end, trial_end,
+               cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        FROM subscriptions
+        WHERE tenant_id = $1
+          AND current_period_end <= $2
+          AND status = 'active'
+          AND payment_method IN ('x402', 'credits')
+        ORDER BY current_period_end ASC
+        LIMIT $3
+    "#;
+
+    /// Per spec (08-storage.md): Update must filter by tenant_id for isolation
+    pub const UPDATE_STATUS: &str = r#"
+        UPDATE subscriptions SET status = $3, updated_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now') WHERE id = $1 AND tenant_id = $2
+    "#;
+
+    /// Batch update subscription statuses (tenant isolated).
+    pub const UPDATE_STATUS_BATCH: &str = r#"
+        UPDATE subscriptions SET status = $3, updated_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+        WHERE tenant_id = $1 AND id IN (SELECT value FROM json_each($2))
+    "#;
+
+    /// Per spec (08-storage.md): Soft delete - set status to 'cancelled'
+    /// Per spec (08-storage.md line 143): Delete(ctx, id) - Soft delete (set status=cancelled)
+    pub const DELETE: &str = r#"
+        UPDATE subscriptions SET status = 'cancelled', cancelled_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'), updated_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now') WHERE id = $1 AND tenant_id = $2
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const GET_BY_STRIPE_CUSTOMER_ID: &str = r#"
+        SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+               payment_method, billing_period, billing_interval, status,
+               current_period_start, current_period_end, trial_end,
+               cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        FROM subscriptions WHERE tenant_id = $1 AND stripe_customer_id = $2
+        ORDER BY created_at DESC
+        LIMIT 1000
+    "#;
+
+    /// Per spec (08-storage.md): Query must filter by tenant_id for isolation
+    pub const LIST_BY_PRODUCT: &str = r#"
+        SELECT id, tenant_id, product_id, plan_id, wallet, user_id, stripe_customer_id, stripe_subscription_id,
+               payment_method, billing_period, billing_interval, status,
+               current_period_start, current_period_end, trial_end,
+               cancelled_at, cancel_at_period_end, metadata, payment_signature, created_at, updated_at
+        FROM subscriptions WHERE tenant_id = $1 AND product_id = $2
+        ORDER BY created_at DESC
+        LIMIT 1000
+    "#;
+
+    /// Count subscriptions by plan_id for inventory tracking
+    /// Only counts non-cancelled subscriptions (active, trialing, past_due, unpaid, expired)
+    pub const COUNT_BY_PLAN_ID: &str = r#"
+        SELECT COUNT(*) as count
+        FROM subscriptions
+        WHERE tenant_id = $1 AND plan_id = $2 AND status != 'cancelled'
+    "#;
+}
+
+/// Chat session and message queries
+/// Per spec (08-storage.md): All queries must include tenant_id for multi-tenant isolation
+pub mod chat {
+    /// Insert a new chat session
+    pub const INSERT_SESSION: &str = r#"
+        INSERT INTO chat_sessions (id, tenant_id, customer_id, customer_email, status, message_count, assigned_agent, handoff_reason, handoff_requested_at, last_message_at, created_at, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+    "#;
+
+    /// Get a chat session by ID
+    pub const GET_SESSION: &str = r#"
+        SELECT id, tenant_id, customer_id, customer_email, status, message_count, assigned_agent, handoff_reason, handoff_requested_at, last_message_at, created_at, updated_at
+        FROM chat_sessions WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    /// Update session message count and last_message_at
+    pub const UPDATE_SESSION: &str = r#"
+        UPDATE chat_sessions
+        SET message_count = $3, last_message_at = $4, updated_at = $5
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    /// List sessions with optional filters, returns count
+    pub const LIST_SESSIONS: &str = r#"
+        SELECT id, tenant_id, customer_id, customer_email, status, message_count, assigned_agent, handoff_reason, handoff_requested_at, last_message_at, created_at, updated_at
+        FROM chat_sessions
+        WHERE tenant_id = $1
+          AND ($2 IS NULL OR customer_id = $2)
+          AND ($3 IS NULL OR status = $3)
+        ORDER BY last_message_at DESC
+        LIMIT $4 OFFSET $5
+    "#;
+
+    /// Count sessions for pagination
+    pub const COUNT_SESSIONS: &str = r#"
+        SELECT COUNT(*) as count
+        FROM chat_sessions
+        WHERE tenant_id = $1
+          AND ($2 IS NULL OR customer_id = $2)
+          AND ($3 IS NULL OR status = $3)
+    "#;
+
+    /// Insert a new chat message
+    pub const INSERT_MESSAGE: &str = r#"
+        INSERT INTO chat_messages (id, tenant_id, session_id, role, content, tool_calls, tool_results, author_id, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+    "#;
+
+    /// List messages for a session (chronological order)
+    pub const LIST_MESSAGES: &str = r#"
+        SELECT id, tenant_id, session_id, role, content, tool_calls, tool_results, author_id, created_at
+        FROM chat_messages
+        WHERE tenant_id = $1 AND session_id = $2
+        ORDER BY created_at ASC
+        LIMIT $3 OFFSET $4
+    "#;
+
+    /// List messages created after $3 (chronological order)
+    pub const LIST_MESSAGES_SINCE: &str = r#"
+        SELECT id, tenant_id, session_id, role, content, tool_calls, tool_results, author_id, created_at
+        FROM chat_messages
+        WHERE tenant_id = $1 AND session_id = $2 AND created_at > $3
+        ORDER BY created_at ASC
+        LIMIT $4
+    "#;
+
+    /// Ask for a human; no-op while one is already requested or assigned
+    pub const REQUEST_HANDOFF: &str = r#"
+        UPDATE chat_sessions
+        SET status = 'waiting_for_agent', assigned_agent = NULL, handoff_reason = $3,
+            handoff_requested_at = $4, updated_at = $4
+        WHERE tenant_id = $1 AND id = $2
+          AND status NOT IN ('waiting_for_agent', 'with_agent')
+    "#;
+
+    /// Claim a session waiting for an agent
+    pub const CLAIM: &str = r#"
+        UPDATE chat_sessions
+        SET status = 'with_agent', assigned_agent = $3, updated_at = $4
+        WHERE tenant_id = $1 AND id = $2 AND status = 'waiting_for_agent'
+    "#;
+
+    /// Attach a verified customer to an anonymous session
+    pub const BIND_CUSTOMER: &str = r#"
+        UPDATE chat_sessions
+        SET customer_id = $3, updated_at = $4
+        WHERE tenant_id = $1 AND id = $2 AND customer_id IS NULL
+    "#;
+
+    /// Hand a session back to the assistant
+    pub const RELEASE: &str = r#"
+        UPDATE chat_sessions
+        SET status = 'active', assigned_agent = NULL, updated_at = $3
+        WHERE tenant_id = $1 AND id = $2
+          AND status IN ('waiting_for_agent', 'with_agent')
+    "#;
+}
+
+/// FAQ/knowledge base queries
+/// Per spec (08-storage.md): All queries must include tenant_id for multi-tenant isolation
+pub mod faq {
+    /// Insert a new FAQ
+    pub const INSERT: &str = r#"
+        INSERT INTO faqs (id, tenant_id, question, answer, keywords, active, use_in_chat, display_on_page, created_at, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+    "#;
+
+    /// Get a FAQ by ID
+    pub const GET_BY_ID: &str = r#"
+        SELECT id, tenant_id, question, answer, keywords, active, use_in_chat, display_on_page, created_at, updated_at
+        FROM faqs WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    /// Update a FAQ
+    pub const UPDATE: &str = r#"
+        UPDATE faqs
+        SET question = $3, answer = $4, keywords = $5, active = $6, use_in_chat = $7, display_on_page = $8, updated_at = $9
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    /// Delete a FAQ
+    pub const DELETE: &str = r#"
+        DELETE FROM faqs WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    /// List FAQs with optional active filter
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, question, answer, keywords, active, use_in_chat, display_on_page, created_at, updated_at
+        FROM faqs
+        WHERE tenant_id = $1 AND ($2 IS NULL OR active = $2)
+        ORDER BY updated_at DESC
+        LIMIT $3 OFFSET $4
+    "#;
+
+    /// Count FAQs for pagination
+    pub const COUNT: &str = r#"
+        SELECT COUNT(*) as count
+        FROM faqs
+        WHERE tenant_id = $1 AND ($2 IS NULL OR active = $2)
+    "#;
+
+    /// Search FAQs by keyword or text match (for chat AI)
+    ///
+    /// SQLite has no tsvector; $3 is matched as a case-insensitive substring of
+    /// the question and answer, and keyword hits rank first.
+    pub const SEARCH: &str = r#"
+        SELECT id, tenant_id, question, answer, keywords, active, use_in_chat, display_on_page, created_at, updated_at
+        FROM faqs
+        WHERE tenant_id = $1
+          AND active = true
+          AND use_in_chat = true
+          AND (
+            EXISTS (
+                SELECT 1 FROM json_each(faqs.keywords) AS k
+                WHERE lower(k.value) IN (SELECT lower(value) FROM json_each($2))
+            )
+            OR (question || ' ' || answer) LIKE '%' || $3 || '%'
+          )
+        ORDER BY
+          CASE WHEN EXISTS (
+              SELECT 1 FROM json_each(faqs.keywords) AS k
+              WHERE lower(k.value) IN (SELECT lower(value) FROM json_each($2))
+          ) THEN 0 ELSE 1 END,
+          updated_at DESC
+        LIMIT $4
+    "#;
+
+    /// List FAQs for public display (active + display_on_page)
+    pub const LIST_PUBLIC: &str = r#"
+        SELECT id, tenant_id, question, answer, keywords, active, use_in_chat, display_on_page, created_at, updated_at
+        FROM faqs
+        WHERE tenant_id = $1
+          AND active = true
+          AND display_on_page = true
+        ORDER BY updated_at DESC
+        LIMIT $2 OFFSET $3
+    "#;
+
+    /// Count public FAQs
+    pub const COUNT_PUBLIC: &str = r#"
+        SELECT COUNT(*) as count
+        FROM faqs
+        WHERE tenant_id = $1
+          AND active = true
+          AND display_on_page = true
+    "#;
+}
+
+/// Dead Letter Queue queries
+pub mod dlq {
+    /// Per spec (20-webhooks.md): INSERT must include tenant_id for multi-tenancy
+    pub const INSERT: &str = r#"
+        INSERT INTO webhook_dlq (
+            id, tenant_id, original_webhook_id, url, payload, payload_bytes, headers, event_type,
+            final_error, total_attempts, first_attempt_at, last_attempt_at, moved_to_dlq_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+    "#;
+
+    pub const LIST: &str = r#"
+        SELECT id, tenant_id, original_webhook_id, url, payload, payload_bytes, headers, event_type,
+               final_error, total_attempts, first_attempt_at, last_attempt_at, moved_to_dlq_at
+        FROM webhook_dlq
+        WHERE tenant_id = $1
+        ORDER BY moved_to_dlq_at DESC
+        LIMIT $2
+    "#;
+
+    pub const GET_BY_ID: &str = r#"
+        SELECT id, tenant_id, original_webhook_id, url, payload, payload_bytes, headers, event_type,
+               final_error, total_attempts, first_attempt_at, last_attempt_at, moved_to_dlq_at
+        FROM webhook_dlq WHERE id = $1
+    "#;
+
+    pub const DELETE: &str = r#"
+        DELETE FROM webhook_dlq WHERE id = $1
+    "#;
+
+    pub const DELETE_WEBHOOK: &str = r#"
+        DELETE FROM webhook_queue WHERE id = $1
+    "#;
+}
+
+pub mod product_imports {
+    pub const UPSERT: &str = r#"
+        INSERT INTO product_import_jobs (
+            id, tenant_id, format, dry_run, status, total_count, processed_count,
+            created_count, updated_count, failed_count, errors, error,
+            created_at, updated_at, completed_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+        ON CONFLICT (tenant_id, id) DO UPDATE SET
+            status = EXCLUDED.status,
+            total_count = EXCLUDED.total_count,
+            processed_count = EXCLUDED.processed_count,
+            created_count = EXCLUDED.created_count,
+            updated_count = EXCLUDED.updated_count,
+            failed_count = EXCLUDED.failed_count,
+            errors = EXCLUDED.errors,
+            error = EXCLUDED.error,
+            updated_at = EXCLUDED.updated_at,
+            completed_at = EXCLUDED.completed_at
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT id, tenant_id, format, dry_run, status, total_count, processed_count,
+               created_count, updated_count, failed_count, errors, error,
+               created_at, updated_at, completed_at
+        FROM product_import_jobs
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const FAIL_STALE: &str = r#"
+        UPDATE product_import_jobs
+        SET status = 'failed', error = $2, updated_at = $3, completed_at = $3
+        WHERE status IN ('queued', 'running') AND updated_at < $1
+    "#;
+}
+
+pub mod price_schedules {
+    pub const UPSERT: &str = r#"
+        INSERT INTO price_schedules (
+            id, tenant_id, product_id, variant_id, fiat_price, crypto_price, variant_price,
+            show_as_sale, starts_at, ends_at, status, original, stripe_price_id, note, error,
+            created_at, updated_at, applied_at, completed_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
+        ON CONFLICT (tenant_id, id) DO UPDATE SET
+            variant_id = EXCLUDED.variant_id,
+            fiat_price = EXCLUDED.fiat_price,
+            crypto_price = EXCLUDED.crypto_price,
+            variant_price = EXCLUDED.variant_price,
+            show_as_sale = EXCLUDED.show_as_sale,
+            starts_at = EXCLUDED.starts_at,
+            ends_at = EXCLUDED.ends_at,
+            status = EXCLUDED.status,
+            original = EXCLUDED.original,
+            stripe_price_id = EXCLUDED.stripe_price_id,
+            note = EXCLUDED.note,
+            error = EXCLUDED.error,
+            updated_at = EXCLUDED.updated_at,
+            applied_at = EXCLUDED.applied_at,
+            completed_at = EXCLUDED.completed_at
+    "#;
+
+    pub const UPDATE_PROGRESS_IF_STATUS: &str = r#"
+        UPDATE price_schedules SET
+            status = $4,
+            original = $5,
+            stripe_price_id = $6,
+            error = $7,
+            updated_at = $8,
+            applied_at = $9,
+            completed_at = $10
+        WHERE tenant_id = $1 AND id = $2 AND status = $3
+    "#;
+
+    pub const GET: &str = r#"
+        SELECT id, tenant_id, product_id, variant_id, fiat_price, crypto_price, variant_price,
+               show_as_sale, starts_at, ends_at, status, original, stripe_price_id, note, error,
+               created_at, updated_at, applied_at, completed_at
+        FROM price_schedules
+        WHERE tenant_id = $1 AND id = $2
+    "#;
+
+    pub const LIST_BY_PRODUCT: &str = r#"
+        SELECT id, tenant_id, product_id, variant_id, fiat_price, crypto_price, variant_price,
+               show_as_sale, starts_at, ends_at, status, original, stripe_price_id, note, error,
+               created_at, updated_at, applied_at, completed_at
+        FROM price_schedules
+        WHERE tenant_id = $1 AND product_id = $2
+        ORDER BY starts_at DESC
+    "#;
+
+    pub const LIST_DUE: &str = r#"
+        SELECT id, tenant_id, product_id, variant_id, fiat_price, crypto_price, variant_price,
+               show_as_sale, starts_at, ends_at, status, original, stripe_price_id, note, error,
+               created_at, updated_at, applied_at, completed_at
+        FROM price_schedules
+        WHERE (status = 'scheduled' AND starts_at <= $1)
+           OR (status = 'active' AND ends_at IS NOT NULL AND ends_at <= $1)
+        ORDER BY starts_at ASC
+        LIMIT $2
+    "#;
+}
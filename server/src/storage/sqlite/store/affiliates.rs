@@ -0,0 +1,154 @@
+//! Affiliate and commission ledger storage methods for SqliteStore
+
+use super::*;
+
+pub(super) async fn upsert_affiliate(
+    store: &SqliteStore,
+    affiliate: Affiliate,
+) -> StorageResult<()> {
+    let rules_json = serde_json::to_value(&affiliate.rules)
+        .map_err(|e| StorageError::internal("serialize affiliate rules", e))?;
+    let commission_type = match affiliate.commission_type {
+        crate::models::CommissionType::Percentage => "percentage",
+        crate::models::CommissionType::Fixed => "fixed",
+    };
+    let query = queries::affiliates::UPSERT;
+    let result = sqlx::query(query)
+        .bind(&affiliate.id)
+        .bind(&affiliate.tenant_id)
+        .bind(&affiliate.code)
+        .bind(&affiliate.name)
+        .bind(&affiliate.email)
+        .bind(&affiliate.payout_wallet)
+        .bind(affiliate.active)
+        .bind(commission_type)
+        .bind(affiliate.commission_value)
+        .bind(&rules_json)
+        .bind(affiliate.created_at)
+        .bind(affiliate.updated_at)
+        .execute(store.pool.inner())
+        .await;
+
+    match result {
+        Ok(_) => Ok(()),
+        // Referral codes are unique per tenant (idx_affiliates_tenant_code).
+        Err(sqlx::Error::Database(db)) if db.is_unique_violation() => Err(StorageError::Conflict),
+        Err(e) => Err(StorageError::internal("upsert affiliate", e)),
+    }
+}
+
+pub(super) async fn get_affiliate(
+    store: &SqliteStore,
+    tenant_id: &str,
+    id: &str,
+) -> StorageResult<Option<Affiliate>> {
+    let query = queries::affiliates::GET_BY_ID;
+    let row = sqlx::query(query)
+        .bind(tenant_id)
+        .bind(id)
+        .fetch_optional(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("get affiliate", e))?;
+
+    row.map(parse_affiliate).transpose()
+}
+
+pub(super) async fn get_affiliate_by_code(
+    store: &SqliteStore,
+    tenant_id: &str,
+    code: &str,
+) -> StorageResult<Option<Affiliate>> {
+    let query = queries::affiliates::GET_BY_CODE;
+    let row = sqlx::query(query)
+        .bind(tenant_id)
+        .bind(code)
+        .fetch_optional(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("get affiliate by code", e))?;
+
+    row.map(parse_affiliate).transpose()
+}
+
+pub(super) async fn list_affiliates(
+    store: &SqliteStore,
+    tenant_id: &str,
+    limit: i32,
+    offset: i32,
+) -> StorageResult<Vec<Affiliate>> {
+    let query = queries::affiliates::LIST;
+    let rows = sqlx::query(query)
+        .bind(tenant_id)
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list affiliates", e))?;
+
+    rows.into_iter()
+        .map(parse_affiliate)
+        .collect::<StorageResult<Vec<_>>>()
+}
+
+pub(super) async fn record_affiliate_commission(
+    store: &SqliteStore,
+    entry: AffiliateCommission,
+) -> StorageResult<bool> {
+    let query = queries::affiliates::INSERT_COMMISSION;
+    let result = sqlx::query(query)
+        .bind(&entry.id)
+        .bind(&entry.tenant_id)
+        .bind(&entry.affiliate_id)
+        .bind(&entry.order_id)
+        .bind(&entry.purchase_id)
+        .bind(entry.kind.as_str())
+        .bind(entry.amount)
+        .bind(entry.order_amount)
+        .bind(&entry.currency)
+        .bind(&entry.refund_id)
+        .bind(entry.created_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("record affiliate commission", e))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+pub(super) async fn list_affiliate_commissions(
+    store: &SqliteStore,
+    tenant_id: &str,
+    affiliate_id: Option<&str>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> StorageResult<Vec<AffiliateCommission>> {
+    let query = queries::affiliates::LIST_COMMISSIONS;
+    let rows = sqlx::query(query)
+        .bind(tenant_id)
+        .bind(affiliate_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list affiliate commissions", e))?;
+
+    rows.into_iter()
+        .map(parse_affiliate_commission)
+        .collect::<StorageResult<Vec<_>>>()
+}
+
+pub(super) async fn list_affiliate_commissions_for_purchase(
+    store: &SqliteStore,
+    tenant_id: &str,
+    purchase_id: &str,
+) -> StorageResult<Vec<AffiliateCommission>> {
+    let query = queries::affiliates::LIST_COMMISSIONS_BY_PURCHASE;
+    let rows = sqlx::query(query)
+        .bind(tenant_id)
+        .bind(purchase_id)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list affiliate commissions for purchase", e))?;
+
+    rows.into_iter()
+        .map(parse_affiliate_commission)
+        .collect::<StorageResult<Vec<_>>>()
+}
@@ -0,0 +1,158 @@
+//! Cold archival storage methods for SqliteStore
+
+use super::*;
+
+fn json_ids(ids: &[String], context: &'static str) -> StorageResult<String> {
+    serde_json::to_string(ids).map_err(|e| StorageError::internal(context, e))
+}
+
+pub(super) async fn list_archivable_records(
+    store: &SqliteStore,
+    tenant_id: &str,
+    older_than: DateTime<Utc>,
+    limit: i64,
+) -> StorageResult<ArchivableRecords> {
+    let payments = sqlx::query(queries::archive::LIST_PAYMENTS)
+        .bind(tenant_id)
+        .bind(older_than)
+        .bind(limit)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list archivable payments", e))?
+        .into_iter()
+        .map(parse_payment_transaction)
+        .collect::<StorageResult<Vec<_>>>()?;
+
+    let orders = sqlx::query(queries::archive::LIST_ORDERS)
+        .bind(tenant_id)
+        .bind(older_than)
+        .bind(limit)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list archivable orders", e))?
+        .into_iter()
+        .map(parse_order)
+        .collect::<StorageResult<Vec<_>>>()?;
+
+    let order_history = if orders.is_empty() {
+        Vec::new()
+    } else {
+        let order_ids: Vec<String> = orders.iter().map(|o| o.id.clone()).collect();
+        sqlx::query(queries::archive::LIST_ORDER_HISTORY)
+            .bind(tenant_id)
+            .bind(json_ids(&order_ids, "serialize order ids")?)
+            .fetch_all(store.pool.inner())
+            .await
+            .map_err(|e| StorageError::internal("list archivable order history", e))?
+            .into_iter()
+            .map(parse_order_history)
+            .collect::<StorageResult<Vec<_>>>()?
+    };
+
+    let webhooks = sqlx::query(queries::archive::LIST_WEBHOOKS)
+        .bind(tenant_id)
+        .bind(older_than)
+        .bind(limit)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list archivable webhooks", e))?
+        .into_iter()
+        .map(parse_webhook)
+        .collect::<StorageResult<Vec<_>>>()?;
+
+    Ok(ArchivableRecords {
+        payments,
+        orders,
+        order_history,
+        webhooks,
+    })
+}
+
+pub(super) async fn purge_archived_records(
+    store: &SqliteStore,
+    tenant_id: &str,
+    purge: &ArchivePurge,
+) -> StorageResult<u64> {
+    let mut tx = store.begin_write().await?;
+    let mut deleted = 0u64;
+
+    if !purge.payments.is_empty() {
+        for archived in &purge.payments {
+            sqlx::query(queries::archive::INDEX_PAYMENT)
+                .bind(tenant_id)
+                .bind(&archived.signature)
+                .bind(&archived.object_key)
+                .bind(archived.archived_at)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| StorageError::internal("index archived payment", e))?;
+        }
+
+        let signatures: Vec<String> = purge.payments.iter().map(|p| p.signature.clone()).collect();
+        deleted += sqlx::query(queries::archive::DELETE_PAYMENTS)
+            .bind(tenant_id)
+            .bind(json_ids(&signatures, "serialize signatures")?)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::internal("delete archived payments", e))?
+            .rows_affected();
+    }
+
+    if !purge.order_ids.is_empty() {
+        let order_ids = json_ids(&purge.order_ids, "serialize order ids")?;
+        deleted += sqlx::query(queries::archive::DELETE_ORDER_HISTORY)
+            .bind(tenant_id)
+            .bind(&order_ids)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::internal("delete archived order history", e))?
+            .rows_affected();
+
+        deleted += sqlx::query(queries::archive::DELETE_ORDERS)
+            .bind(tenant_id)
+            .bind(&order_ids)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::internal("delete archived orders", e))?
+            .rows_affected();
+    }
+
+    if !purge.webhook_ids.is_empty() {
+        deleted += sqlx::query(queries::archive::DELETE_WEBHOOKS)
+            .bind(tenant_id)
+            .bind(json_ids(&purge.webhook_ids, "serialize webhook ids")?)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::internal("delete archived webhooks", e))?
+            .rows_affected();
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| StorageError::internal("commit archive purge", e))?;
+
+    Ok(deleted)
+}
+
+pub(super) async fn find_archived_payment(
+    store: &SqliteStore,
+    tenant_id: &str,
+    signature: &str,
+) -> StorageResult<Option<ArchivedPaymentRef>> {
+    let row: Option<(String, String, String, DateTime<Utc>)> =
+        sqlx::query_as(queries::archive::GET_ARCHIVED_PAYMENT)
+            .bind(tenant_id)
+            .bind(signature)
+            .fetch_optional(store.pool.inner())
+            .await
+            .map_err(|e| StorageError::internal("find archived payment", e))?;
+
+    Ok(row.map(
+        |(tenant_id, signature, object_key, archived_at)| ArchivedPaymentRef {
+            tenant_id,
+            signature,
+            object_key,
+            archived_at,
+        },
+    ))
+}
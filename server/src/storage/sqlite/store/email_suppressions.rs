@@ -0,0 +1,83 @@
+//! Email suppression storage methods for SqliteStore
+
+use super::*;
+
+type EmailSuppressionRow = (String, String, String, Option<String>, DateTime<Utc>);
+
+fn to_suppression(
+    (tenant_id, email, reason, detail, created_at): EmailSuppressionRow,
+) -> StorageResult<EmailSuppression> {
+    Ok(EmailSuppression {
+        tenant_id,
+        email,
+        reason: reason
+            .parse()
+            .map_err(|e| StorageError::internal("parse suppression reason", e))?,
+        detail,
+        created_at,
+    })
+}
+
+pub(super) async fn upsert_email_suppression(
+    store: &SqliteStore,
+    suppression: EmailSuppression,
+) -> StorageResult<()> {
+    let query = queries::email_suppression::UPSERT;
+    sqlx::query(query)
+        .bind(&suppression.tenant_id)
+        .bind(&suppression.email)
+        .bind(suppression.reason.to_string())
+        .bind(&suppression.detail)
+        .bind(suppression.created_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("upsert email suppression", e))?;
+    Ok(())
+}
+
+pub(super) async fn get_email_suppression(
+    store: &SqliteStore,
+    tenant_id: &str,
+    email: &str,
+) -> StorageResult<Option<EmailSuppression>> {
+    let query = queries::email_suppression::GET;
+    let row: Option<EmailSuppressionRow> = sqlx::query_as(query)
+        .bind(tenant_id)
+        .bind(email)
+        .fetch_optional(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("get email suppression", e))?;
+    row.map(to_suppression).transpose()
+}
+
+pub(super) async fn list_email_suppressions(
+    store: &SqliteStore,
+    tenant_id: &str,
+    limit: i32,
+    offset: i32,
+) -> StorageResult<Vec<EmailSuppression>> {
+    let query = queries::email_suppression::LIST;
+    let rows: Vec<EmailSuppressionRow> = sqlx::query_as(query)
+        .bind(tenant_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list email suppressions", e))?;
+    rows.into_iter().map(to_suppression).collect()
+}
+
+pub(super) async fn delete_email_suppression(
+    store: &SqliteStore,
+    tenant_id: &str,
+    email: &str,
+) -> StorageResult<bool> {
+    let query = queries::email_suppression::DELETE;
+    let result = sqlx::query(query)
+        .bind(tenant_id)
+        .bind(email)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("delete email suppression", e))?;
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,180 @@
+//! Embedding storage methods for SqliteStore
+//!
+//! Vectors are stored as little-endian `f32` blobs and searches rank the
+//! candidates in process.
+
+use super::*;
+use crate::storage::rank_embeddings;
+
+type EmbeddingRow = (
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    Vec<u8>,
+    DateTime<Utc>,
+);
+
+type EmbeddingJobRow = (String, String, String, i32, DateTime<Utc>);
+
+fn encode_vector(vector: &[f32]) -> Vec<u8> {
+    vector.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn decode_vector(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+fn parse_entity(value: &str) -> StorageResult<EmbeddingEntity> {
+    value
+        .parse()
+        .map_err(|e| StorageError::internal("parse embedding entity", e))
+}
+
+fn to_record(
+    (tenant_id, entity_type, entity_id, source_id, model, content_hash, embedding, updated_at): EmbeddingRow,
+) -> StorageResult<EmbeddingRecord> {
+    Ok(EmbeddingRecord {
+        tenant_id,
+        entity_type: parse_entity(&entity_type)?,
+        entity_id,
+        source_id,
+        model,
+        content_hash,
+        embedding: decode_vector(&embedding),
+        updated_at,
+    })
+}
+
+fn to_job(
+    (tenant_id, source_type, source_id, attempts, available_at): EmbeddingJobRow,
+) -> StorageResult<EmbeddingJob> {
+    Ok(EmbeddingJob {
+        tenant_id,
+        source_type: parse_entity(&source_type)?,
+        source_id,
+        attempts,
+        available_at,
+    })
+}
+
+fn entity_names(entity_types: &[EmbeddingEntity]) -> String {
+    let names: Vec<&str> = entity_types.iter().map(|t| t.as_str()).collect();
+    serde_json::to_string(&names).unwrap_or_else(|_| "[]".to_string())
+}
+
+pub(super) async fn upsert_embedding(
+    store: &SqliteStore,
+    record: EmbeddingRecord,
+) -> StorageResult<()> {
+    sqlx::query(queries::embedding::UPSERT)
+        .bind(&record.tenant_id)
+        .bind(record.entity_type.as_str())
+        .bind(&record.entity_id)
+        .bind(&record.source_id)
+        .bind(&record.model)
+        .bind(&record.content_hash)
+        .bind(record.embedding.len() as i32)
+        .bind(encode_vector(&record.embedding))
+        .bind(record.updated_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("upsert embedding", e))?;
+    Ok(())
+}
+
+pub(super) async fn list_source_embeddings(
+    store: &SqliteStore,
+    tenant_id: &str,
+    source_id: &str,
+    entity_types: &[EmbeddingEntity],
+) -> StorageResult<Vec<EmbeddingRecord>> {
+    let rows: Vec<EmbeddingRow> = sqlx::query_as(queries::embedding::LIST_BY_SOURCE)
+        .bind(tenant_id)
+        .bind(source_id)
+        .bind(entity_names(entity_types))
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list source embeddings", e))?;
+    rows.into_iter().map(to_record).collect()
+}
+
+pub(super) async fn delete_embedding(
+    store: &SqliteStore,
+    tenant_id: &str,
+    entity_type: EmbeddingEntity,
+    entity_id: &str,
+) -> StorageResult<bool> {
+    let result = sqlx::query(queries::embedding::DELETE)
+        .bind(tenant_id)
+        .bind(entity_type.as_str())
+        .bind(entity_id)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("delete embedding", e))?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub(super) async fn search_embeddings(
+    store: &SqliteStore,
+    tenant_id: &str,
+    model: &str,
+    entity_types: &[EmbeddingEntity],
+    query: &[f32],
+    limit: i32,
+) -> StorageResult<Vec<EmbeddingMatch>> {
+    let rows: Vec<EmbeddingRow> = sqlx::query_as(queries::embedding::LIST_FOR_SEARCH)
+        .bind(tenant_id)
+        .bind(model)
+        .bind(entity_names(entity_types))
+        .bind(query.len() as i32)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("search embeddings", e))?;
+    let records = rows
+        .into_iter()
+        .map(to_record)
+        .collect::<StorageResult<Vec<_>>>()?;
+    Ok(rank_embeddings(records.iter(), query, limit))
+}
+
+pub(super) async fn enqueue_embedding_job(
+    store: &SqliteStore,
+    job: EmbeddingJob,
+) -> StorageResult<()> {
+    sqlx::query(queries::embedding::ENQUEUE_JOB)
+        .bind(&job.tenant_id)
+        .bind(job.source_type.as_str())
+        .bind(&job.source_id)
+        .bind(job.attempts)
+        .bind(job.available_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("enqueue embedding job", e))?;
+    Ok(())
+}
+
+pub(super) async fn claim_embedding_jobs(
+    store: &SqliteStore,
+    now: DateTime<Utc>,
+    limit: i32,
+) -> StorageResult<Vec<EmbeddingJob>> {
+    let rows: Vec<EmbeddingJobRow> = sqlx::query_as(queries::embedding::CLAIM_JOBS)
+        .bind(now)
+        .bind(limit)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("claim embedding jobs", e))?;
+    let mut jobs = rows
+        .into_iter()
+        .map(to_job)
+        .collect::<StorageResult<Vec<_>>>()?;
+    // RETURNING does not preserve the subquery order
+    jobs.sort_by_key(|job| job.available_at);
+    Ok(jobs)
+}
@@ -0,0 +1,83 @@
+//! Event log storage methods for SqliteStore
+
+use super::*;
+
+type EventLogRow = (
+    i64,
+    String,
+    String,
+    String,
+    serde_json::Value,
+    DateTime<Utc>,
+);
+
+fn to_entry(
+    (sequence, tenant_id, event_id, event_type, payload, created_at): EventLogRow,
+) -> EventLogEntry {
+    EventLogEntry {
+        sequence,
+        tenant_id,
+        event_id,
+        event_type,
+        payload,
+        created_at,
+    }
+}
+
+pub(super) async fn append_event(store: &SqliteStore, entry: EventLogEntry) -> StorageResult<i64> {
+    let query = queries::event_log::APPEND;
+    let inserted: Option<(i64,)> = sqlx::query_as(query)
+        .bind(&entry.tenant_id)
+        .bind(&entry.event_id)
+        .bind(&entry.event_type)
+        .bind(&entry.payload)
+        .bind(entry.created_at)
+        .fetch_optional(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("append event", e))?;
+    if let Some((sequence,)) = inserted {
+        return Ok(sequence);
+    }
+
+    // Already logged: return the original position
+    let query = queries::event_log::GET_SEQUENCE;
+    let (sequence,): (i64,) = sqlx::query_as(query)
+        .bind(&entry.tenant_id)
+        .bind(&entry.event_id)
+        .fetch_one(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("get event sequence", e))?;
+    Ok(sequence)
+}
+
+pub(super) async fn list_events(
+    store: &SqliteStore,
+    tenant_id: &str,
+    query: &EventLogQuery,
+) -> StorageResult<Vec<EventLogEntry>> {
+    let sql = queries::event_log::LIST;
+    let rows: Vec<EventLogRow> = sqlx::query_as(sql)
+        .bind(tenant_id)
+        .bind(query.after)
+        .bind(&query.event_type)
+        .bind(query.since)
+        .bind(query.until)
+        .bind(query.limit)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list events", e))?;
+    Ok(rows.into_iter().map(to_entry).collect())
+}
+
+pub(super) async fn cleanup_old_events(
+    store: &SqliteStore,
+    older_than: DateTime<Utc>,
+) -> StorageResult<u64> {
+    let query = queries::event_log::CLEANUP_OLD;
+    let result = sqlx::query(query)
+        .bind(older_than)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("cleanup old events", e))?;
+    Ok(result.rows_affected())
+}
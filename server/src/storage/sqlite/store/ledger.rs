@@ -0,0 +1,97 @@
+//! Accounting journal storage methods for SqliteStore
+
+use super::*;
+
+pub(super) async fn append_journal_entry(
+    store: &SqliteStore,
+    entry: JournalEntry,
+) -> StorageResult<bool> {
+    let lines_json = serde_json::to_value(&entry.lines)
+        .map_err(|e| StorageError::internal("serialize journal lines", e))?;
+    let query = queries::ledger::INSERT;
+    let result = sqlx::query(query)
+        .bind(&entry.id)
+        .bind(&entry.tenant_id)
+        .bind(entry.source.as_str())
+        .bind(&entry.source_id)
+        .bind(&entry.currency)
+        .bind(&entry.description)
+        .bind(&lines_json)
+        .bind(entry.occurred_at)
+        .bind(entry.created_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("append journal entry", e))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+pub(super) async fn list_journal_entries(
+    store: &SqliteStore,
+    tenant_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    limit: i32,
+    offset: i32,
+) -> StorageResult<Vec<JournalEntry>> {
+    let query = queries::ledger::LIST;
+    let rows = sqlx::query(query)
+        .bind(tenant_id)
+        .bind(from)
+        .bind(to)
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list journal entries", e))?;
+
+    rows.into_iter()
+        .map(parse_journal_entry)
+        .collect::<StorageResult<Vec<_>>>()
+}
+
+pub(super) async fn list_journal_entries_for_source(
+    store: &SqliteStore,
+    tenant_id: &str,
+    source: JournalSource,
+    source_id: &str,
+) -> StorageResult<Vec<JournalEntry>> {
+    let query = queries::ledger::LIST_BY_SOURCE;
+    let rows = sqlx::query(query)
+        .bind(tenant_id)
+        .bind(source.as_str())
+        .bind(source_id)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list journal entries for source", e))?;
+
+    rows.into_iter()
+        .map(parse_journal_entry)
+        .collect::<StorageResult<Vec<_>>>()
+}
+
+pub(super) async fn trial_balance(
+    store: &SqliteStore,
+    tenant_id: &str,
+    as_of: DateTime<Utc>,
+) -> StorageResult<Vec<TrialBalanceRow>> {
+    let query = queries::ledger::TRIAL_BALANCE;
+    let rows = sqlx::query(query)
+        .bind(tenant_id)
+        .bind(as_of)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("trial balance", e))?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            TrialBalanceRow::new(
+                row.get("account"),
+                row.get("currency"),
+                row.get("debit"),
+                row.get("credit"),
+            )
+        })
+        .collect())
+}
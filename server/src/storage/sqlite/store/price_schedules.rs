@@ -0,0 +1,143 @@
+//! Scheduled price change methods for SqliteStore
+
+use super::*;
+
+fn to_json<T: serde::Serialize>(value: &Option<T>) -> StorageResult<Option<serde_json::Value>> {
+    value
+        .as_ref()
+        .map(serde_json::to_value)
+        .transpose()
+        .map_err(|e| StorageError::internal("serialize price schedule", e))
+}
+
+fn from_json<T: serde::de::DeserializeOwned>(
+    value: Option<serde_json::Value>,
+) -> StorageResult<Option<T>> {
+    value
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(|e| StorageError::internal("failed to parse price schedule", e))
+}
+
+pub(super) async fn save_price_schedule(
+    store: &SqliteStore,
+    schedule: PriceSchedule,
+) -> StorageResult<()> {
+    sqlx::query(queries::price_schedules::UPSERT)
+        .bind(&schedule.id)
+        .bind(&schedule.tenant_id)
+        .bind(&schedule.product_id)
+        .bind(&schedule.variant_id)
+        .bind(to_json(&schedule.fiat_price)?)
+        .bind(to_json(&schedule.crypto_price)?)
+        .bind(to_json(&schedule.variant_price)?)
+        .bind(schedule.show_as_sale)
+        .bind(schedule.starts_at)
+        .bind(schedule.ends_at)
+        .bind(schedule.status.as_str())
+        .bind(to_json(&schedule.original)?)
+        .bind(&schedule.stripe_price_id)
+        .bind(&schedule.note)
+        .bind(&schedule.error)
+        .bind(schedule.created_at)
+        .bind(schedule.updated_at)
+        .bind(schedule.applied_at)
+        .bind(schedule.completed_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("save price schedule", e))?;
+    Ok(())
+}
+
+pub(super) async fn update_price_schedule_if_status(
+    store: &SqliteStore,
+    schedule: PriceSchedule,
+    expected: PriceScheduleStatus,
+) -> StorageResult<bool> {
+    let result = sqlx::query(queries::price_schedules::UPDATE_PROGRESS_IF_STATUS)
+        .bind(&schedule.tenant_id)
+        .bind(&schedule.id)
+        .bind(expected.as_str())
+        .bind(schedule.status.as_str())
+        .bind(to_json(&schedule.original)?)
+        .bind(&schedule.stripe_price_id)
+        .bind(&schedule.error)
+        .bind(schedule.updated_at)
+        .bind(schedule.applied_at)
+        .bind(schedule.completed_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("update price schedule", e))?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub(super) async fn get_price_schedule(
+    store: &SqliteStore,
+    tenant_id: &str,
+    schedule_id: &str,
+) -> StorageResult<Option<PriceSchedule>> {
+    let row = sqlx::query(queries::price_schedules::GET)
+        .bind(tenant_id)
+        .bind(schedule_id)
+        .fetch_optional(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("get price schedule", e))?;
+    row.map(parse_price_schedule).transpose()
+}
+
+pub(super) async fn list_product_price_schedules(
+    store: &SqliteStore,
+    tenant_id: &str,
+    product_id: &str,
+) -> StorageResult<Vec<PriceSchedule>> {
+    let rows = sqlx::query(queries::price_schedules::LIST_BY_PRODUCT)
+        .bind(tenant_id)
+        .bind(product_id)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list price schedules", e))?;
+    rows.into_iter().map(parse_price_schedule).collect()
+}
+
+pub(super) async fn list_due_price_schedules(
+    store: &SqliteStore,
+    now: DateTime<Utc>,
+    limit: i32,
+) -> StorageResult<Vec<PriceSchedule>> {
+    let rows = sqlx::query(queries::price_schedules::LIST_DUE)
+        .bind(now)
+        .bind(limit)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list due price schedules", e))?;
+    rows.into_iter().map(parse_price_schedule).collect()
+}
+
+fn parse_price_schedule(row: sqlx::sqlite::SqliteRow) -> StorageResult<PriceSchedule> {
+    let status: String = row.get("status");
+    let status = PriceScheduleStatus::parse(&status).ok_or_else(|| {
+        StorageError::Database(format!("unknown price schedule status: {}", status))
+    })?;
+
+    Ok(PriceSchedule {
+        id: row.get("id"),
+        tenant_id: row.get("tenant_id"),
+        product_id: row.get("product_id"),
+        variant_id: row.get("variant_id"),
+        fiat_price: from_json(row.get("fiat_price"))?,
+        crypto_price: from_json(row.get("crypto_price"))?,
+        variant_price: from_json(row.get("variant_price"))?,
+        show_as_sale: row.get("show_as_sale"),
+        starts_at: row.get("starts_at"),
+        ends_at: row.get("ends_at"),
+        status,
+        original: from_json(row.get("original"))?,
+        stripe_price_id: row.get("stripe_price_id"),
+        note: row.get("note"),
+        error: row.get("error"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+        applied_at: row.get("applied_at"),
+        completed_at: row.get("completed_at"),
+    })
+}
@@ -0,0 +1,93 @@
+//! Stripe Connect account and application fee storage methods for SqliteStore
+
+use super::*;
+
+pub(super) async fn upsert_stripe_connect_account(
+    store: &SqliteStore,
+    account: StripeConnectAccount,
+) -> StorageResult<()> {
+    let query = queries::stripe_connect::UPSERT_ACCOUNT;
+    sqlx::query(query)
+        .bind(&account.tenant_id)
+        .bind(&account.account_id)
+        .bind(account.charges_enabled)
+        .bind(account.payouts_enabled)
+        .bind(account.details_submitted)
+        .bind(account.application_fee_bps.map(|v| v as i32))
+        .bind(account.created_at)
+        .bind(account.updated_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("upsert stripe connect account", e))?;
+
+    Ok(())
+}
+
+pub(super) async fn get_stripe_connect_account(
+    store: &SqliteStore,
+    tenant_id: &str,
+) -> StorageResult<Option<StripeConnectAccount>> {
+    let query = queries::stripe_connect::GET_ACCOUNT_BY_TENANT;
+    let row = sqlx::query(query)
+        .bind(tenant_id)
+        .fetch_optional(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("get stripe connect account", e))?;
+
+    row.map(parse_stripe_connect_account).transpose()
+}
+
+pub(super) async fn find_stripe_connect_account(
+    store: &SqliteStore,
+    account_id: &str,
+) -> StorageResult<Option<StripeConnectAccount>> {
+    let query = queries::stripe_connect::GET_ACCOUNT_BY_ACCOUNT_ID;
+    let row = sqlx::query(query)
+        .bind(account_id)
+        .fetch_optional(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("find stripe connect account", e))?;
+
+    row.map(parse_stripe_connect_account).transpose()
+}
+
+pub(super) async fn upsert_stripe_application_fee(
+    store: &SqliteStore,
+    fee: StripeApplicationFee,
+) -> StorageResult<()> {
+    let query = queries::stripe_connect::UPSERT_FEE;
+    sqlx::query(query)
+        .bind(&fee.id)
+        .bind(&fee.tenant_id)
+        .bind(&fee.account_id)
+        .bind(&fee.charge_id)
+        .bind(fee.amount)
+        .bind(fee.amount_refunded)
+        .bind(&fee.currency)
+        .bind(fee.created_at)
+        .execute(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("upsert stripe application fee", e))?;
+
+    Ok(())
+}
+
+pub(super) async fn list_stripe_application_fees(
+    store: &SqliteStore,
+    tenant_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> StorageResult<Vec<StripeApplicationFee>> {
+    let query = queries::stripe_connect::LIST_FEES;
+    let rows = sqlx::query(query)
+        .bind(tenant_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(store.pool.inner())
+        .await
+        .map_err(|e| StorageError::internal("list stripe application fees", e))?;
+
+    rows.into_iter()
+        .map(parse_stripe_application_fee)
+        .collect::<StorageResult<Vec<_>>>()
+}
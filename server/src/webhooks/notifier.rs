@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -8,7 +9,8 @@ use serde_json::Value;
 use sha2::Sha256;
 
 use crate::models::{PaymentEvent, RefundEvent};
-use crate::storage::{PendingWebhook, Store, WebhookStatus};
+use crate::observability::otel;
+use crate::storage::{EventLogEntry, PendingWebhook, Store, WebhookStatus};
 use crate::x402::utils::{generate_event_id, hex_encode};
 
 type HmacSha256 = Hmac<Sha256>;
@@ -113,6 +115,17 @@ pub trait Notifier: Send + Sync {
 
     // Refund events (include tenant_id for multi-tenant isolation)
     async fn refund_processed(&self, tenant_id: &str, charge_id: &str, amount: i64, currency: &str);
+
+    // Chat events
+    /// A chat session is waiting for a human agent; `requested_by` is
+    /// "assistant" or "customer"
+    async fn chat_handoff_requested(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        reason: Option<&str>,
+        requested_by: &str,
+    );
 }
 
 /// No-op notifier for when webhooks are disabled
@@ -171,12 +184,30 @@ impl Notifier for NoopNotifier {
         _currency: &str,
     ) {
     }
+    async fn chat_handoff_requested(
+        &self,
+        _tenant: &str,
+        _session_id: &str,
+        _reason: Option<&str>,
+        _requested_by: &str,
+    ) {
+    }
 }
 
 /// HTTP webhook notifier
+///
+/// Every event is appended to the store's event log (when enabled) and, if a
+/// callback URL is configured, queued for signed delivery.
 pub struct HttpNotifier<S: Store> {
     store: Arc<S>,
-    webhook_url: String,
+    /// Swapped as a whole on reconfigure so each webhook sees one consistent set
+    settings: parking_lot::RwLock<Arc<NotifierSettings>>,
+    event_log: AtomicBool,
+}
+
+struct NotifierSettings {
+    /// `None` records events without delivering them
+    webhook_url: Option<String>,
     webhook_secret: Option<String>,
     default_headers: HashMap<String, String>,
     max_attempts: i32,
@@ -205,18 +236,74 @@ impl<S: Store> HttpNotifier<S> {
         default_headers: HashMap<String, String>,
         max_attempts: i32,
     ) -> Self {
+        Self::from_settings(
+            store,
+            NotifierSettings {
+                webhook_url: Some(webhook_url),
+                webhook_secret,
+                default_headers,
+                max_attempts,
+            },
+        )
+    }
+
+    /// Notifier with no callback URL: events are only recorded in the event
+    /// log until [`Self::reconfigure`] sets a destination.
+    pub fn without_url(
+        store: Arc<S>,
+        webhook_secret: Option<String>,
+        default_headers: HashMap<String, String>,
+        max_attempts: i32,
+    ) -> Self {
+        Self::from_settings(
+            store,
+            NotifierSettings {
+                webhook_url: None,
+                webhook_secret,
+                default_headers,
+                max_attempts,
+            },
+        )
+    }
+
+    fn from_settings(store: Arc<S>, settings: NotifierSettings) -> Self {
         Self {
             store,
+            settings: parking_lot::RwLock::new(Arc::new(settings)),
+            event_log: AtomicBool::new(false),
+        }
+    }
+
+    /// Record emitted events in the store's event log.
+    pub fn with_event_log(self, enabled: bool) -> Self {
+        self.set_event_log(enabled);
+        self
+    }
+
+    pub fn set_event_log(&self, enabled: bool) {
+        self.event_log.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Replace the destination, signing secret, headers and retry budget.
+    /// Applies to webhooks enqueued afterwards; queued ones keep their values.
+    pub fn reconfigure(
+        &self,
+        webhook_url: Option<String>,
+        webhook_secret: Option<String>,
+        default_headers: HashMap<String, String>,
+        max_attempts: i32,
+    ) {
+        *self.settings.write() = Arc::new(NotifierSettings {
             webhook_url,
             webhook_secret,
             default_headers,
             max_attempts,
-        }
+        });
     }
 
     /// Sign the raw payload body per spec (HMAC-SHA256 of body only)
-    fn sign_payload(&self, payload_bytes: &[u8]) -> Option<String> {
-        let secret = self.webhook_secret.as_ref()?;
+    fn sign_payload(secret: Option<&String>, payload_bytes: &[u8]) -> Option<String> {
+        let secret = secret?;
 
         let mac = match HmacSha256::new_from_slice(secret.as_bytes()) {
             Ok(m) => m,
@@ -234,7 +321,8 @@ impl<S: Store> HttpNotifier<S> {
         Some(hex_encode(result.into_bytes()))
     }
 
-    /// Enqueue a webhook with an existing event_id (preserves idempotency)
+    /// Record an event and enqueue its webhook with the existing event_id as
+    /// delivery ID (preserves idempotency)
     async fn enqueue_webhook_with_id(
         &self,
         tenant_id: &str,
@@ -242,6 +330,59 @@ impl<S: Store> HttpNotifier<S> {
         event_type: &str,
         payload: serde_json::Value,
     ) -> Result<String, String> {
+        if self.event_log.load(Ordering::Relaxed) {
+            let entry = EventLogEntry {
+                sequence: 0,
+                tenant_id: tenant_id.to_string(),
+                event_id: event_id.to_string(),
+                event_type: event_type.to_string(),
+                payload: payload.clone(),
+                created_at: Utc::now(),
+            };
+            // Delivery still proceeds; the log is for consumers catching up
+            if let Err(e) = self.store.append_event(entry).await {
+                tracing::error!(error = %e, event_id, event_type, "Failed to append event to event log");
+            }
+        }
+
+        let settings = self.settings.read().clone();
+        let Some(url) = settings.webhook_url.as_deref() else {
+            return Ok(event_id.to_string());
+        };
+        self.enqueue_delivery(&settings, tenant_id, event_id, url, event_type, payload)
+            .await?;
+        Ok(event_id.to_string())
+    }
+
+    /// Enqueue a fresh delivery of a logged event to `url`.
+    ///
+    /// The payload (and its `eventId`) is sent unchanged so receivers can
+    /// dedupe; the delivery ID is new since the original may still be queued.
+    pub async fn replay_event(&self, entry: &EventLogEntry, url: &str) -> Result<String, String> {
+        let settings = self.settings.read().clone();
+        let delivery_id = generate_event_id();
+        self.enqueue_delivery(
+            &settings,
+            &entry.tenant_id,
+            &delivery_id,
+            url,
+            &entry.event_type,
+            entry.payload.clone(),
+        )
+        .await?;
+        Ok(delivery_id)
+    }
+
+    /// Sign `payload` and queue it for the webhook worker
+    async fn enqueue_delivery(
+        &self,
+        settings: &NotifierSettings,
+        tenant_id: &str,
+        delivery_id: &str,
+        url: &str,
+        event_type: &str,
+        payload: serde_json::Value,
+    ) -> Result<(), String> {
         let now = Utc::now();
         let timestamp = now.timestamp();
 
@@ -250,28 +391,32 @@ impl<S: Store> HttpNotifier<S> {
         // regardless of object key ordering or whitespace in the original Value.
         let payload_bytes = canonical_json(&payload);
 
-        let mut headers = self.default_headers.clone();
+        let mut headers = settings.default_headers.clone();
         headers.insert("Content-Type".to_string(), "application/json".to_string());
         headers.insert("X-Cedros-Event-Type".to_string(), event_type.to_string());
-        headers.insert("X-Cedros-Delivery-ID".to_string(), event_id.to_string());
+        headers.insert("X-Cedros-Delivery-ID".to_string(), delivery_id.to_string());
         headers.insert("X-Cedros-Timestamp".to_string(), timestamp.to_string());
 
         // Sign per spec: sha256={hex-encoded-signature}
-        if let Some(sig) = self.sign_payload(&payload_bytes) {
+        if let Some(sig) = Self::sign_payload(settings.webhook_secret.as_ref(), &payload_bytes) {
             headers.insert("X-Cedros-Signature".to_string(), format!("sha256={}", sig));
         }
 
+        // Link queued delivery to the originating trace; the worker re-parents
+        // on this and replaces it with the delivery span's context when sending.
+        otel::inject_current_context(&mut headers);
+
         let webhook = PendingWebhook {
-            id: event_id.to_string(),
+            id: delivery_id.to_string(),
             tenant_id: tenant_id.to_string(),
-            url: self.webhook_url.clone(),
+            url: url.to_string(),
             payload,
             payload_bytes,
             headers,
             event_type: event_type.to_string(),
             status: WebhookStatus::Pending,
             attempts: 0,
-            max_attempts: self.max_attempts,
+            max_attempts: settings.max_attempts,
             last_error: None,
             last_attempt_at: None,
             next_attempt_at: Some(now),
@@ -284,7 +429,7 @@ impl<S: Store> HttpNotifier<S> {
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(event_id.to_string())
+        Ok(())
     }
 
     // Intentionally no "generate ID" helper here: callers must ensure payload.eventId and
@@ -509,6 +654,31 @@ impl<S: Store + 'static> Notifier for HttpNotifier<S> {
             tracing::error!(error = %e, "Failed to enqueue refund.processed webhook");
         }
     }
+
+    async fn chat_handoff_requested(
+        &self,
+        tenant_id: &str,
+        session_id: &str,
+        reason: Option<&str>,
+        requested_by: &str,
+    ) {
+        let event_id = generate_event_id();
+        let payload = serde_json::json!({
+            "eventId": event_id,
+            "eventType": "chat.handoff_requested",
+            "eventTimestamp": Utc::now(),
+            "sessionId": session_id,
+            "reason": reason,
+            "requestedBy": requested_by
+        });
+
+        if let Err(e) = self
+            .enqueue_webhook_with_id(tenant_id, &event_id, "chat.handoff_requested", payload)
+            .await
+        {
+            tracing::error!(error = %e, "Failed to enqueue chat.handoff_requested webhook");
+        }
+    }
 }
 
 #[cfg(test)]
@@ -543,6 +713,43 @@ mod tests {
         assert_eq!(stored.max_attempts, 7);
     }
 
+    #[tokio::test]
+    async fn test_notifier_reconfigure_applies_to_new_webhooks() {
+        let store = Arc::new(InMemoryStore::new());
+        let notifier = HttpNotifier::new(
+            store.clone(),
+            "https://example.com/old".to_string(),
+            None,
+            3,
+        );
+        notifier.reconfigure(
+            Some("https://example.com/new".to_string()),
+            Some("secret".to_string()),
+            HashMap::new(),
+            5,
+        );
+
+        let event = PaymentEvent {
+            event_id: "event-reconfigured".to_string(),
+            event_type: "payment.succeeded".to_string(),
+            event_timestamp: Utc::now(),
+            resource_id: "resource-1".to_string(),
+            method: "x402".to_string(),
+            paid_at: Utc::now(),
+            ..Default::default()
+        };
+        notifier.payment_succeeded(event).await;
+
+        let stored = store
+            .get_webhook("event-reconfigured")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.url, "https://example.com/new");
+        assert_eq!(stored.max_attempts, 5);
+        assert!(stored.headers.contains_key("X-Cedros-Signature"));
+    }
+
     #[tokio::test]
     async fn test_notifier_stores_payload_bytes_and_signature() {
         let store = Arc::new(InMemoryStore::new());
@@ -568,7 +775,11 @@ mod tests {
 
         let stored = store.get_webhook("event-2").await.unwrap().unwrap();
         assert!(!stored.payload_bytes.is_empty());
-        let expected = notifier.sign_payload(&stored.payload_bytes).unwrap();
+        let expected = HttpNotifier::<InMemoryStore>::sign_payload(
+            Some(&"secret".to_string()),
+            &stored.payload_bytes,
+        )
+        .unwrap();
         let header = stored
             .headers
             .get("X-Cedros-Signature")
@@ -625,4 +836,98 @@ mod tests {
             .expect("payload eventId");
         assert_eq!(wh.id, payload_event_id);
     }
+
+    #[tokio::test]
+    async fn test_chat_handoff_requested_webhook_payload() {
+        let store = Arc::new(InMemoryStore::new());
+        let notifier = HttpNotifier::new_with_headers(
+            store.clone(),
+            "https://example.com/webhook".to_string(),
+            None,
+            HashMap::new(),
+            3,
+        );
+
+        notifier
+            .chat_handoff_requested("tenant-1", "chat_1", Some("refund question"), "assistant")
+            .await;
+
+        let items = store.list_webhooks("tenant-1", None, 10).await.unwrap();
+        let wh = items.first().expect("webhook");
+        assert_eq!(wh.event_type, "chat.handoff_requested");
+        assert_eq!(wh.payload["sessionId"], "chat_1");
+        assert_eq!(wh.payload["reason"], "refund question");
+        assert_eq!(wh.payload["requestedBy"], "assistant");
+    }
+
+    #[tokio::test]
+    async fn test_notifier_without_url_only_records_event() {
+        let store = Arc::new(InMemoryStore::new());
+        let notifier =
+            HttpNotifier::without_url(store.clone(), None, HashMap::new(), 3).with_event_log(true);
+
+        notifier
+            .subscription_created("tenant-1", "sub-1", "prod-1", None)
+            .await;
+
+        let events = store
+            .list_events(
+                "tenant-1",
+                &crate::storage::EventLogQuery {
+                    limit: 10,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, "subscription.created");
+        assert_eq!(
+            events[0].payload.get("eventId").and_then(|v| v.as_str()),
+            Some(events[0].event_id.as_str())
+        );
+        assert!(store
+            .list_webhooks("tenant-1", None, 10)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_replay_event_keeps_event_id_with_new_delivery_id() {
+        let store = Arc::new(InMemoryStore::new());
+        let notifier = HttpNotifier::new(
+            store.clone(),
+            "https://example.com/webhook".to_string(),
+            Some("secret".to_string()),
+            3,
+        )
+        .with_event_log(true);
+
+        notifier
+            .refund_processed("tenant-1", "ch_1", 123, "USD")
+            .await;
+        let entry = store
+            .list_events(
+                "tenant-1",
+                &crate::storage::EventLogQuery {
+                    limit: 10,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap()
+            .remove(0);
+
+        let delivery_id = notifier
+            .replay_event(&entry, "https://replay.example.com/hook")
+            .await
+            .unwrap();
+        assert_ne!(delivery_id, entry.event_id);
+
+        let replayed = store.get_webhook(&delivery_id).await.unwrap().unwrap();
+        assert_eq!(replayed.url, "https://replay.example.com/hook");
+        assert_eq!(replayed.payload, entry.payload);
+        assert!(replayed.headers.contains_key("X-Cedros-Signature"));
+    }
 }
@@ -7,11 +7,16 @@ use chrono::Utc;
 use tokio::sync::watch;
 use tokio::time::timeout;
 
+use crate::models::{PRODUCT_IMPORT_INTERRUPTED_ERROR, PRODUCT_IMPORT_STALE_AFTER_SECS};
+use crate::services::ColdArchiveService;
 use crate::storage::Store;
 
 /// Timeout for individual cleanup operations to prevent worker hangs
 const CLEANUP_OPERATION_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// Timeout for a cold archival run, which uploads to object storage
+const COLD_ARCHIVE_TIMEOUT: Duration = Duration::from_secs(600);
+
 /// Handle for controlling the cleanup worker
 pub struct CleanupWorkerHandle {
     shutdown_tx: watch::Sender<bool>,
@@ -46,6 +51,9 @@ pub struct CleanupWorker<S: Store> {
     store: Arc<S>,
     payment_retention_period: Duration,
     archival_enabled: bool,
+    cold_archive: Option<Arc<ColdArchiveService>>,
+    /// Event log entries older than this are deleted with payment archival
+    event_log_retention: Option<Duration>,
     nonce_cleanup_interval: Duration,
     idempotency_cleanup_interval: Duration,
     payment_cleanup_interval: Duration,
@@ -64,6 +72,8 @@ impl<S: Store> CleanupWorker<S> {
             store,
             payment_retention_period,
             archival_enabled,
+            cold_archive: None,
+            event_log_retention: None,
             // Per spec (11-background-workers.md): Poll every CEDROS_STORAGE_CLEANUP_INTERVAL (default: 5m)
             nonce_cleanup_interval: Duration::from_secs(300),
             idempotency_cleanup_interval: Duration::from_secs(300),
@@ -85,6 +95,8 @@ impl<S: Store> CleanupWorker<S> {
             store,
             payment_retention_period,
             archival_enabled,
+            cold_archive: None,
+            event_log_retention: None,
             // Per spec (11-background-workers.md): Poll every CEDROS_STORAGE_CLEANUP_INTERVAL (default: 5m)
             nonce_cleanup_interval: Duration::from_secs(300),
             idempotency_cleanup_interval: Duration::from_secs(300),
@@ -99,6 +111,21 @@ impl<S: Store> CleanupWorker<S> {
         (worker, handle)
     }
 
+    /// Write old records to object storage before removing them, instead of
+    /// deleting old payments outright
+    pub fn with_cold_archive(mut self, cold_archive: Arc<ColdArchiveService>) -> Self {
+        self.cold_archive = Some(cold_archive);
+        self
+    }
+
+    /// Delete event log entries older than `retention` on each archival run.
+    /// Runs regardless of `archival_enabled`, since the event log has its
+    /// own retention.
+    pub fn with_event_log_retention(mut self, retention: Duration) -> Self {
+        self.event_log_retention = Some(retention);
+        self
+    }
+
     /// Check if shutdown has been requested
     fn should_shutdown(&self) -> bool {
         if let Some(ref rx) = self.shutdown_rx {
@@ -154,6 +181,11 @@ impl<S: Store> CleanupWorker<S> {
             return;
         }
         self.cleanup_credits_holds().await;
+        if self.should_shutdown() {
+            tracing::info!("Cleanup worker shutdown during startup");
+            return;
+        }
+        self.fail_interrupted_imports().await;
         // Note: payment archival is expensive, skip on startup
         tracing::debug!("Initial cleanup complete");
 
@@ -167,9 +199,11 @@ impl<S: Store> CleanupWorker<S> {
                 }
                 _ = payment_timer.tick() => {
                     self.cleanup_payments().await;
+                    self.cleanup_events().await;
                 }
                 _ = quote_timer.tick() => {
                     self.cleanup_expired_quotes().await;
+                    self.fail_interrupted_imports().await;
                 }
                 _ = credits_hold_timer.tick() => {
                     self.cleanup_credits_holds().await;
@@ -246,6 +280,35 @@ impl<S: Store> CleanupWorker<S> {
         }
         let retention = crate::storage::memory::to_chrono_duration(self.payment_retention_period);
         let cutoff = Utc::now() - retention;
+        if let Some(ref cold_archive) = self.cold_archive {
+            match timeout(
+                COLD_ARCHIVE_TIMEOUT,
+                cold_archive.archive_older_than(cutoff),
+            )
+            .await
+            {
+                Ok(Ok(summary)) if summary.records > 0 => {
+                    tracing::info!(
+                        tenants = summary.tenants,
+                        files = summary.files,
+                        records = summary.records,
+                        deleted = summary.deleted,
+                        "Archived old records to cold storage"
+                    );
+                }
+                Ok(Err(e)) => {
+                    tracing::error!(error = %e, "Failed to archive to cold storage");
+                }
+                Err(_) => {
+                    tracing::warn!(
+                        timeout_secs = COLD_ARCHIVE_TIMEOUT.as_secs(),
+                        "Cold archival timed out"
+                    );
+                }
+                _ => {}
+            }
+            return;
+        }
         // Payment archival can be slower, use 60s timeout
         let payment_timeout = Duration::from_secs(60);
         match timeout(payment_timeout, self.store.archive_old_payments(cutoff)).await {
@@ -265,6 +328,34 @@ impl<S: Store> CleanupWorker<S> {
         }
     }
 
+    /// Delete event log entries past their retention
+    async fn cleanup_events(&self) {
+        let Some(retention) = self.event_log_retention else {
+            return;
+        };
+        let cutoff = Utc::now() - crate::storage::memory::to_chrono_duration(retention);
+        match timeout(
+            CLEANUP_OPERATION_TIMEOUT,
+            self.store.cleanup_old_events(cutoff),
+        )
+        .await
+        {
+            Ok(Ok(count)) if count > 0 => {
+                tracing::info!(count, "Cleaned up old event log entries");
+            }
+            Ok(Err(e)) => {
+                tracing::error!(error = %e, "Failed to cleanup event log");
+            }
+            Err(_) => {
+                tracing::warn!(
+                    timeout_secs = CLEANUP_OPERATION_TIMEOUT.as_secs(),
+                    "Event log cleanup timed out"
+                );
+            }
+            _ => {}
+        }
+    }
+
     /// Cleanup expired cart and refund quotes per spec (11-background-workers.md)
     async fn cleanup_expired_quotes(&self) {
         // Cleanup expired cart quotes
@@ -335,6 +426,35 @@ impl<S: Store> CleanupWorker<S> {
         }
     }
 
+    /// Fail product imports whose process stopped before they finished; an
+    /// import's records live only in the process that accepted it, so such a
+    /// job would otherwise stay queued or running forever
+    async fn fail_interrupted_imports(&self) {
+        let updated_before =
+            Utc::now() - chrono::Duration::seconds(PRODUCT_IMPORT_STALE_AFTER_SECS);
+        match timeout(
+            CLEANUP_OPERATION_TIMEOUT,
+            self.store
+                .fail_stale_product_import_jobs(updated_before, PRODUCT_IMPORT_INTERRUPTED_ERROR),
+        )
+        .await
+        {
+            Ok(Ok(count)) if count > 0 => {
+                tracing::warn!(count, "Marked interrupted product imports as failed");
+            }
+            Ok(Err(e)) => {
+                tracing::error!(error = %e, "Failed to mark interrupted product imports");
+            }
+            Err(_) => {
+                tracing::warn!(
+                    timeout_secs = CLEANUP_OPERATION_TIMEOUT.as_secs(),
+                    "Interrupted product import cleanup timed out"
+                );
+            }
+            _ => {}
+        }
+    }
+
     async fn cleanup_credits_holds(&self) {
         match timeout(
             CLEANUP_OPERATION_TIMEOUT,
@@ -342,8 +462,19 @@ impl<S: Store> CleanupWorker<S> {
         )
         .await
         {
-            Ok(Ok(count)) if count > 0 => {
-                tracing::debug!(count, "Cleaned up expired credits holds");
+            Ok(Ok(expired)) => {
+                if !expired.is_empty() {
+                    tracing::debug!(count = expired.len(), "Cleaned up expired credits holds");
+                }
+                // An expired hold is released by cedros-login
+                for hold in &expired {
+                    crate::services::ledger::post_credits_release(
+                        &*self.store,
+                        &hold.tenant_id,
+                        &hold.hold_id,
+                    )
+                    .await;
+                }
             }
             Ok(Err(e)) => {
                 tracing::error!(error = %e, "Failed to cleanup expired credits holds");
@@ -354,7 +485,6 @@ impl<S: Store> CleanupWorker<S> {
                     "Credits hold cleanup timed out"
                 );
             }
-            _ => {}
         }
     }
 }
@@ -456,6 +586,51 @@ mod tests {
         assert!(recent.is_some());
     }
 
+    #[tokio::test]
+    async fn test_cleanup_payments_with_cold_archive_keeps_lookup() {
+        use crate::services::{ColdArchiveService, InMemoryObjectStore};
+
+        let store = Arc::new(InMemoryStore::new());
+        let asset = get_asset("USDC").expect("USDC asset registered");
+        store
+            .record_payment(PaymentTransaction {
+                signature: "sig-old".to_string(),
+                tenant_id: "default".to_string(),
+                resource_id: "res-old".to_string(),
+                wallet: "wallet-old".to_string(),
+                user_id: None,
+                amount: Money { asset, atomic: 100 },
+                created_at: Utc::now() - ChronoDuration::days(10),
+                metadata: HashMap::new(),
+            })
+            .await
+            .expect("record payment");
+
+        let objects = Arc::new(InMemoryObjectStore::new(vec!["default".to_string()]));
+        let archive = Arc::new(ColdArchiveService::new(store.clone(), objects.clone(), 100));
+        let worker = CleanupWorker::new(
+            store.clone(),
+            Duration::from_secs(86400),
+            Duration::from_secs(86400),
+            true,
+        )
+        .with_cold_archive(archive.clone());
+        worker.cleanup_payments().await;
+
+        assert!(store
+            .get_payment("default", "sig-old")
+            .await
+            .expect("get payment")
+            .is_none());
+        assert!(!objects.keys("default").is_empty());
+        let archived = archive
+            .find_purchase("default", "sig-old")
+            .await
+            .expect("lookup")
+            .expect("archived purchase");
+        assert_eq!(archived.purchase.resource_id, "res-old");
+    }
+
     #[tokio::test]
     async fn test_cleanup_expired_credits_holds_removes_old() {
         let store = Arc::new(InMemoryStore::new());
@@ -495,8 +670,29 @@ mod tests {
             false,
         );
 
+        for hold_id in ["hold-expired", "hold-active"] {
+            let hold = store
+                .get_credits_hold("default", hold_id)
+                .await
+                .unwrap()
+                .unwrap();
+            crate::services::ledger::post_credits_hold(&*store, &hold).await;
+        }
+
         worker.cleanup_credits_holds().await;
 
+        // Only the expired hold's reservation is released in the ledger
+        for (hold_id, releases) in [("hold-expired", 1), ("hold-active", 0)] {
+            let entries = store
+                .list_journal_entries_for_source(
+                    "default",
+                    crate::models::JournalSource::CreditsRelease,
+                    hold_id,
+                )
+                .await
+                .unwrap();
+            assert_eq!(entries.len(), releases, "{}", hold_id);
+        }
         assert!(store
             .get_credits_hold("default", "hold-expired")
             .await
@@ -508,4 +704,76 @@ mod tests {
             .unwrap()
             .is_some());
     }
+
+    #[tokio::test]
+    async fn test_interrupted_product_imports_fail_on_startup() {
+        use crate::models::{ProductImportFormat, ProductImportJob, ProductImportStatus};
+
+        let store = Arc::new(InMemoryStore::new());
+        let job = |id: &str, status: ProductImportStatus, updated_at| ProductImportJob {
+            id: id.to_string(),
+            tenant_id: "default".to_string(),
+            format: ProductImportFormat::Json,
+            dry_run: false,
+            status,
+            total: 10,
+            processed: 4,
+            created: 4,
+            updated: 0,
+            failed: 0,
+            errors: Vec::new(),
+            error: None,
+            created_at: updated_at,
+            updated_at,
+            completed_at: None,
+        };
+        let stale = Utc::now() - ChronoDuration::hours(1);
+        for job in [
+            job("queued-stale", ProductImportStatus::Queued, stale),
+            job("running-stale", ProductImportStatus::Running, stale),
+            job("running-live", ProductImportStatus::Running, Utc::now()),
+            job("completed", ProductImportStatus::Completed, stale),
+        ] {
+            store.save_product_import_job(job).await.unwrap();
+        }
+
+        // A worker started on a fresh process runs this before its first tick
+        let (worker, handle) = CleanupWorker::with_shutdown(
+            store.clone(),
+            Duration::from_secs(86400),
+            Duration::from_secs(86400),
+            false,
+        );
+        let join = tokio::spawn(worker.run());
+        async fn fetch(store: &InMemoryStore, id: &str) -> ProductImportJob {
+            store
+                .get_product_import_job("default", id)
+                .await
+                .unwrap()
+                .unwrap()
+        }
+        for _ in 0..100 {
+            if fetch(&store, "running-stale").await.status == ProductImportStatus::Failed {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        handle.shutdown();
+        join.await.unwrap();
+
+        for id in ["queued-stale", "running-stale"] {
+            let job = fetch(&store, id).await;
+            assert_eq!(job.status, ProductImportStatus::Failed, "{}", id);
+            assert_eq!(job.error.as_deref(), Some(PRODUCT_IMPORT_INTERRUPTED_ERROR));
+            assert!(job.completed_at.is_some());
+        }
+        assert_eq!(
+            fetch(&store, "running-live").await.status,
+            ProductImportStatus::Running
+        );
+        assert_eq!(
+            fetch(&store, "completed").await.status,
+            ProductImportStatus::Completed
+        );
+    }
 }
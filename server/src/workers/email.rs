@@ -1,6 +1,8 @@
 //! Email delivery worker with retry support
 //!
-//! Polls the email queue and sends emails via SMTP with exponential backoff retry.
+//! Polls the email queue and sends emails through each tenant's transport
+//! (SMTP or HTTP API) with exponential backoff retry. Suppressed recipients
+//! are skipped and permanent provider rejections fail without retrying.
 
 use std::sync::Arc;
 use std::time::Duration;
@@ -10,52 +12,17 @@ use rand::Rng;
 use tokio::sync::watch;
 use tokio::task::JoinHandle;
 use tokio::time::interval;
-use tracing::{debug, error, info, warn};
-use zeroize::Zeroize;
+use tracing::{debug, error, info, warn, Instrument};
 
-use crate::config::{MessagingConfig, RetryConfig};
-use crate::storage::{PendingEmail, Store};
-
-/// Zeroizing container for SMTP credentials (L-005 fix).
-///
-/// Wraps sensitive credentials to ensure they are cleared from memory
-/// after use, preventing potential memory scraping attacks.
-struct ZeroizingCredentials {
-    username: Option<String>,
-    password: Option<String>,
-}
-
-impl ZeroizingCredentials {
-    fn new(username: String, password: String) -> Self {
-        Self {
-            username: Some(username),
-            password: Some(password),
-        }
-    }
-
-    fn take_credentials(&mut self) -> lettre::transport::smtp::authentication::Credentials {
-        lettre::transport::smtp::authentication::Credentials::new(
-            self.username.take().unwrap_or_default(),
-            self.password.take().unwrap_or_default(),
-        )
-    }
-}
-
-impl Drop for ZeroizingCredentials {
-    fn drop(&mut self) {
-        if let Some(ref mut u) = self.username {
-            u.zeroize();
-        }
-        if let Some(ref mut p) = self.password {
-            p.zeroize();
-        }
-    }
-}
+use crate::config::RetryConfig;
+use crate::observability::otel;
+use crate::services::messaging::transport::{EmailTransportResolver, SendError};
+use crate::storage::{normalize_email_address, PendingEmail, Store};
 
 /// Email delivery worker with graceful shutdown support
 pub struct EmailWorker<S: Store> {
     store: Arc<S>,
-    config: MessagingConfig,
+    transports: Arc<EmailTransportResolver>,
     poll_interval: Duration,
     batch_size: i32,
     shutdown_rx: Option<watch::Receiver<bool>>,
@@ -96,6 +63,16 @@ impl EmailWorkerHandle {
     }
 }
 
+/// Outcome of a delivery attempt that did not fail
+enum Delivery {
+    Sent {
+        provider: &'static str,
+        message_id: Option<String>,
+    },
+    /// Recipient is on the suppression list; carries the recorded reason
+    Suppressed(String),
+}
+
 /// Default email retention period: 30 days
 const DEFAULT_RETENTION_DAYS: i32 = 30;
 /// Default cleanup interval: every 720 poll cycles (~1 hour at 5s poll interval)
@@ -104,7 +81,7 @@ const DEFAULT_CLEANUP_CYCLES: u32 = 720;
 impl<S: Store + 'static> EmailWorker<S> {
     pub fn with_shutdown(
         store: Arc<S>,
-        config: MessagingConfig,
+        transports: Arc<EmailTransportResolver>,
     ) -> Result<(Self, EmailWorkerHandle), String> {
         let (shutdown_tx, shutdown_rx) = watch::channel(false);
 
@@ -120,7 +97,7 @@ impl<S: Store + 'static> EmailWorker<S> {
 
         let worker = Self {
             store,
-            config,
+            transports,
             poll_interval: Duration::from_secs(10), // Check every 10 seconds
             batch_size: 10,
             shutdown_rx: Some(shutdown_rx),
@@ -147,42 +124,18 @@ impl<S: Store + 'static> EmailWorker<S> {
         self
     }
 
-    /// Build a reusable SMTP transport from current config.
-    fn build_transport(
-        &self,
-    ) -> Result<lettre::AsyncSmtpTransport<lettre::Tokio1Executor>, String> {
-        use lettre::{AsyncSmtpTransport, Tokio1Executor};
-
-        let mut zeroizing_creds = ZeroizingCredentials::new(
-            self.config.smtp_username.clone(),
-            self.config.smtp_password.clone(),
-        );
-        let creds = zeroizing_creds.take_credentials();
-
-        AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&self.config.smtp_host)
-            .map_err(|e| format!("smtp transport: {}", e))
-            .map(|builder| {
-                builder
-                    .port(self.config.smtp_port)
-                    .credentials(creds)
-                    .build()
-            })
-    }
-
     /// Start the email worker with graceful shutdown support
     pub async fn run(mut self) {
         let mut poll_timer = interval(self.poll_interval);
         poll_timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
         let mut cycles_since_cleanup: u32 = 0;
 
-        // P-01 fix: build SMTP transport once and reuse across all sends
-        let mailer = match self.build_transport() {
-            Ok(m) => m,
-            Err(e) => {
-                error!(error = %e, "Failed to build SMTP transport; email worker exiting");
-                return;
-            }
-        };
+        // Fail fast on a broken global transport; tenant transports are
+        // resolved (and cached) per email
+        if let Err(e) = self.transports.global_transport() {
+            error!(error = %e, "Failed to build email transport; email worker exiting");
+            return;
+        }
 
         info!(
             "Email worker started with poll_interval={}s, cleanup every {} cycles ({} days retention)",
@@ -201,7 +154,7 @@ impl<S: Store + 'static> EmailWorker<S> {
 
             tokio::select! {
                 _ = poll_timer.tick() => {
-                    if let Err(e) = self.process_batch(&mailer).await {
+                    if let Err(e) = self.process_batch().await {
                         error!(error = %e, "Email batch processing failed");
                         // OPS-02: Make error backoff interruptible by shutdown signal
                         let sleep = tokio::time::sleep(Duration::from_secs(5));
@@ -252,10 +205,7 @@ impl<S: Store + 'static> EmailWorker<S> {
     }
 
     /// Process a batch of pending emails
-    async fn process_batch(
-        &self,
-        mailer: &lettre::AsyncSmtpTransport<lettre::Tokio1Executor>,
-    ) -> Result<(), String> {
+    async fn process_batch(&self) -> Result<(), String> {
         let emails = self
             .store
             .dequeue_emails(self.batch_size)
@@ -270,21 +220,41 @@ impl<S: Store + 'static> EmailWorker<S> {
         debug!(count = emails.len(), "Processing email batch");
 
         for email in emails {
-            let result = self.send_email(&email, mailer).await;
+            let result = self
+                .deliver(&email)
+                .instrument(Self::delivery_span(&email))
+                .await;
 
             match result {
-                Ok(_) => {
-                    if let Err(e) = self.store.mark_email_success(&email.id).await {
+                Ok(Delivery::Sent {
+                    provider,
+                    message_id,
+                }) => {
+                    if let Err(e) = self
+                        .store
+                        .mark_email_success(&email.id, provider, message_id.as_deref())
+                        .await
+                    {
                         warn!(email_id = %email.id, error = %e, "Failed to mark email success");
                     } else {
-                        info!(email_id = %email.id, to = %email.to_email, "Email sent successfully");
+                        info!(email_id = %email.id, to = %email.to_email, provider, "Email sent successfully");
+                    }
+                }
+                Ok(Delivery::Suppressed(reason)) => {
+                    if let Err(e) = self.store.mark_email_suppressed(&email.id, &reason).await {
+                        warn!(email_id = %email.id, error = %e, "Failed to mark email suppressed");
+                    } else {
+                        info!(email_id = %email.id, to = %email.to_email, reason = %reason, "Email skipped for suppressed recipient");
                     }
                 }
-                Err(err) => {
+                Err(SendError {
+                    message: err,
+                    permanent,
+                }) => {
                     let new_attempts = email.attempts + 1;
                     let max_attempts = email.max_attempts.max(1);
 
-                    if new_attempts >= max_attempts {
+                    if permanent || new_attempts >= max_attempts {
                         if let Err(e) = self.store.mark_email_failed(&email.id, &err).await {
                             warn!(email_id = %email.id, error = %e, "Failed to mark email failed");
                         } else {
@@ -326,61 +296,51 @@ impl<S: Store + 'static> EmailWorker<S> {
         Ok(())
     }
 
-    /// Send a single email via the reusable SMTP transport
-    async fn send_email(
-        &self,
-        email: &PendingEmail,
-        mailer: &lettre::AsyncSmtpTransport<lettre::Tokio1Executor>,
-    ) -> Result<(), String> {
-        use lettre::message::header::ContentType;
-        use lettre::message::{Mailbox, MultiPart, SinglePart};
-        use lettre::{AsyncTransport, Message};
-
-        let from_mailbox: Mailbox = format!("{} <{}>", email.from_name, email.from_email)
-            .parse()
-            .map_err(|e| format!("invalid from address: {}", e))?;
-
-        let to_mailbox: Mailbox = email
-            .to_email
-            .parse()
-            .map_err(|e| format!("invalid to address: {}", e))?;
-
-        let message = if let Some(ref html) = email.body_html {
-            Message::builder()
-                .from(from_mailbox)
-                .to(to_mailbox)
-                .subject(&email.subject)
-                .multipart(
-                    MultiPart::alternative()
-                        .singlepart(
-                            SinglePart::builder()
-                                .header(ContentType::TEXT_PLAIN)
-                                .body(email.body_text.clone()),
-                        )
-                        .singlepart(
-                            SinglePart::builder()
-                                .header(ContentType::TEXT_HTML)
-                                .body(html.clone()),
-                        ),
-                )
-                .map_err(|e| format!("build email: {}", e))?
-        } else {
-            Message::builder()
-                .from(from_mailbox)
-                .to(to_mailbox)
-                .subject(&email.subject)
-                .header(ContentType::TEXT_PLAIN)
-                .body(email.body_text.clone())
-                .map_err(|e| format!("build email: {}", e))?
-        };
+    /// Span for one delivery attempt, continuing the trace that queued the email.
+    fn delivery_span(email: &PendingEmail) -> tracing::Span {
+        let span = tracing::info_span!(
+            "email.deliver",
+            otel.kind = "client",
+            email_id = %email.id,
+            tenant_id = %email.tenant_id,
+            attempt = email.attempts + 1,
+        );
+        if let Some(traceparent) = email.traceparent.as_deref() {
+            otel::set_parent_from_traceparent(&span, traceparent);
+        }
+        span
+    }
 
-        // OPS-03: Wrap SMTP send in timeout to prevent unbounded hangs
-        tokio::time::timeout(self.retry.timeout, mailer.send(message))
+    /// Deliver one email unless its recipient is suppressed
+    async fn deliver(&self, email: &PendingEmail) -> Result<Delivery, SendError> {
+        let suppression = self
+            .store
+            .get_email_suppression(&email.tenant_id, &normalize_email_address(&email.to_email))
             .await
-            .map_err(|_| format!("smtp send timed out after {:?}", self.retry.timeout))?
-            .map_err(|e| format!("smtp send: {}", e))?;
+            .map_err(|e| SendError {
+                message: format!("suppression lookup: {}", e),
+                permanent: false,
+            })?;
+        if let Some(suppression) = suppression {
+            return Ok(Delivery::Suppressed(format!(
+                "recipient suppressed: {}",
+                suppression.reason
+            )));
+        }
 
-        Ok(())
+        let transport = self
+            .transports
+            .transport(&email.tenant_id)
+            .await
+            .map_err(|message| SendError {
+                message,
+                permanent: false,
+            })?;
+        let message_id = transport.send(email).await?;
+        Ok(Delivery::Sent {
+            provider: transport.provider(),
+            message_id,
+        })
     }
 
     fn retry_delay(&self, attempt: i32) -> Duration {
@@ -417,14 +377,14 @@ impl<S: Store + 'static> EmailWorker<S> {
 /// Returns `None` if email is disabled or worker creation failed.
 pub fn spawn_email_worker<S: Store + 'static>(
     store: Arc<S>,
-    config: MessagingConfig,
+    transports: Arc<EmailTransportResolver>,
 ) -> Option<EmailWorkerHandle> {
-    if !config.email_enabled {
+    if !transports.config().email_enabled {
         info!("Email worker not started: email_enabled is false");
         return None;
     }
 
-    match EmailWorker::with_shutdown(store, config) {
+    match EmailWorker::with_shutdown(store, transports) {
         Ok((worker, handle)) => {
             let join_handle = tokio::spawn(async move {
                 worker.run().await;
@@ -441,12 +401,13 @@ pub fn spawn_email_worker<S: Store + 'static>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::MessagingConfig;
 
     #[test]
     fn test_retry_delay_calculation() {
-        let config = MessagingConfig::default();
+        let transports = Arc::new(EmailTransportResolver::new(MessagingConfig::default()));
         let store = Arc::new(crate::storage::InMemoryStore::new());
-        let (worker, _) = EmailWorker::with_shutdown(store, config).unwrap();
+        let (worker, _) = EmailWorker::with_shutdown(store, transports).unwrap();
 
         // With jitter=0.1, delays vary by ±10%. Check that they're in expected ranges.
 
@@ -462,4 +423,96 @@ mod tests {
         let delay3 = worker.retry_delay(3).as_secs();
         assert!((216..=264).contains(&delay3), "delay3 = {}", delay3); // CLEAN-003
     }
+
+    fn queued(id: &str, to: &str) -> PendingEmail {
+        PendingEmail {
+            id: id.into(),
+            tenant_id: "default".into(),
+            to_email: to.into(),
+            from_email: "shop@example.com".into(),
+            from_name: "Shop".into(),
+            subject: "Receipt".into(),
+            body_text: "Thanks".into(),
+            body_html: None,
+            status: crate::storage::EmailStatus::Pending,
+            attempts: 0,
+            max_attempts: 3,
+            last_error: None,
+            last_attempt_at: None,
+            next_attempt_at: None,
+            created_at: Utc::now(),
+            completed_at: None,
+            traceparent: None,
+            provider: None,
+            provider_message_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_skips_suppressed_and_records_provider() {
+        use axum::routing::post;
+        use axum::{Json, Router};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let sent = Arc::new(AtomicUsize::new(0));
+        let counter = sent.clone();
+        let app = Router::new().route(
+            "/send",
+            post(move || {
+                let counter = counter.clone();
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Json(serde_json::json!({ "messageId": "provider-1" }))
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let config = MessagingConfig {
+            email_enabled: true,
+            email_provider: "http".into(),
+            email_api_url: format!("http://{}/send", addr),
+            ..Default::default()
+        };
+        let store = Arc::new(crate::storage::InMemoryStore::new());
+        store
+            .upsert_email_suppression(crate::storage::EmailSuppression {
+                tenant_id: "default".into(),
+                email: "bounced@example.com".into(),
+                reason: crate::storage::SuppressionReason::Bounce,
+                detail: None,
+                created_at: Utc::now(),
+            })
+            .await
+            .unwrap();
+        store
+            .enqueue_email(queued("e-ok", "buyer@example.com"))
+            .await
+            .unwrap();
+        store
+            .enqueue_email(queued("e-suppressed", " Bounced@Example.com"))
+            .await
+            .unwrap();
+
+        let transports = Arc::new(EmailTransportResolver::new(config));
+        let (worker, _) = EmailWorker::with_shutdown(store.clone(), transports).unwrap();
+        worker.process_batch().await.unwrap();
+
+        assert_eq!(sent.load(Ordering::SeqCst), 1);
+        let delivered = store.get_email("e-ok").await.unwrap().unwrap();
+        assert_eq!(delivered.status, crate::storage::EmailStatus::Completed);
+        assert_eq!(delivered.provider.as_deref(), Some("http"));
+        assert_eq!(delivered.provider_message_id.as_deref(), Some("provider-1"));
+
+        let skipped = store.get_email("e-suppressed").await.unwrap().unwrap();
+        assert_eq!(skipped.status, crate::storage::EmailStatus::Suppressed);
+        assert_eq!(
+            skipped.last_error.as_deref(),
+            Some("recipient suppressed: bounce")
+        );
+    }
 }
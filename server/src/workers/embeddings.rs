@@ -0,0 +1,334 @@
+//! Background worker that keeps product, variant and FAQ embeddings current.
+//!
+//! Product and FAQ writes enqueue an embedding job for the changed source.
+//! The worker claims queued jobs, rebuilds the source's documents, embeds the
+//! ones whose content hash changed with the tenant's embeddings model, and
+//! deletes embeddings for variants (or whole sources) that no longer exist.
+//! Tenants without an embeddings model are skipped; assigning one and calling
+//! the reindex endpoint queues their catalog again.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use crate::config::PostgresConfigRepository;
+use crate::handlers::admin_ai::AiTask;
+use crate::handlers::admin_ai_assistant::load_task_endpoint;
+use crate::repositories::{ProductRepository, ProductRepositoryError};
+use crate::services::ai::{AiEndpoint, AiService};
+use crate::services::semantic_search::{
+    embedding_content_hash, faq_embedding_text, product_embedding_text, variant_embedding_text,
+};
+use crate::storage::{EmbeddingEntity, EmbeddingJob, EmbeddingRecord, Store};
+
+/// Jobs claimed per poll.
+const JOB_BATCH_SIZE: i32 = 50;
+
+/// Texts sent per embeddings request.
+const EMBED_BATCH_SIZE: usize = 64;
+
+/// Failed jobs are dropped after this many attempts.
+const MAX_ATTEMPTS: i32 = 5;
+
+/// Retry delay after the first failure; doubles with each attempt.
+const RETRY_BASE_DELAY_SECS: i64 = 60;
+
+/// Handle for controlling the embedding worker.
+pub struct EmbeddingWorkerHandle {
+    shutdown_tx: watch::Sender<bool>,
+    join_handle: Option<JoinHandle<()>>,
+}
+
+impl EmbeddingWorkerHandle {
+    pub fn shutdown(&self) {
+        let _ = self.shutdown_tx.send(true);
+    }
+
+    pub fn with_join_handle(mut self, join_handle: JoinHandle<()>) -> Self {
+        self.join_handle = Some(join_handle);
+        self
+    }
+
+    pub async fn wait(mut self) {
+        if let Some(handle) = self.join_handle.take() {
+            let _ = handle.await;
+        }
+    }
+}
+
+/// A document to embed: entity type, entity id and text
+type Document = (EmbeddingEntity, String, String);
+
+/// Embedding worker — drains the embedding job queue.
+pub struct EmbeddingWorker {
+    store: Arc<dyn Store>,
+    product_repo: Arc<dyn ProductRepository>,
+    config_repo: Arc<PostgresConfigRepository>,
+    ai_service: Arc<AiService>,
+    poll_interval: Duration,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl EmbeddingWorker {
+    /// Create worker + handle with shutdown capability.
+    pub fn with_shutdown(
+        store: Arc<dyn Store>,
+        product_repo: Arc<dyn ProductRepository>,
+        config_repo: Arc<PostgresConfigRepository>,
+        ai_service: Arc<AiService>,
+        poll_interval: Duration,
+    ) -> (Self, EmbeddingWorkerHandle) {
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let worker = Self {
+            store,
+            product_repo,
+            config_repo,
+            ai_service,
+            poll_interval,
+            shutdown_rx,
+        };
+        let handle = EmbeddingWorkerHandle {
+            shutdown_tx,
+            join_handle: None,
+        };
+        (worker, handle)
+    }
+
+    fn should_shutdown(&self) -> bool {
+        *self.shutdown_rx.borrow()
+    }
+
+    /// Main loop: drain queued jobs on every interval with graceful shutdown.
+    pub async fn run(mut self) {
+        let mut timer = tokio::time::interval(self.poll_interval);
+        timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        tracing::info!(
+            interval_secs = self.poll_interval.as_secs(),
+            "Embedding worker started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = timer.tick() => {
+                    if self.should_shutdown() { break; }
+                    self.drain_queue().await;
+                }
+                _ = self.shutdown_rx.changed() => {
+                    tracing::info!("Embedding worker received shutdown signal");
+                    break;
+                }
+            }
+        }
+
+        tracing::info!("Embedding worker stopped");
+    }
+
+    /// Process batches until a partial batch shows the queue is drained.
+    async fn drain_queue(&self) {
+        while !self.should_shutdown() {
+            if self.process_batch().await < JOB_BATCH_SIZE as usize {
+                break;
+            }
+        }
+    }
+
+    /// Claim and process one batch of jobs; returns the number claimed.
+    async fn process_batch(&self) -> usize {
+        let jobs = match self
+            .store
+            .claim_embedding_jobs(Utc::now(), JOB_BATCH_SIZE)
+            .await
+        {
+            Ok(jobs) => jobs,
+            Err(e) => {
+                tracing::error!(error = %e, "Embedding worker: failed to claim jobs");
+                return 0;
+            }
+        };
+        let claimed = jobs.len();
+
+        let mut endpoints: HashMap<String, Option<AiEndpoint>> = HashMap::new();
+        for job in jobs {
+            if !endpoints.contains_key(&job.tenant_id) {
+                let endpoint = load_task_endpoint(
+                    &self.config_repo,
+                    &self.ai_service,
+                    &job.tenant_id,
+                    AiTask::Embeddings,
+                )
+                .await
+                .ok();
+                endpoints.insert(job.tenant_id.clone(), endpoint);
+            }
+            let Some(Some(endpoint)) = endpoints.get(&job.tenant_id) else {
+                // No embeddings model; the job is dropped until a reindex
+                continue;
+            };
+
+            if let Err(e) = self.index_source(endpoint, &job).await {
+                self.retry(job, &e).await;
+            }
+        }
+        claimed
+    }
+
+    /// Re-queue a failed job with exponential backoff, or drop it after MAX_ATTEMPTS.
+    async fn retry(&self, mut job: EmbeddingJob, error: &str) {
+        job.attempts += 1;
+        if job.attempts >= MAX_ATTEMPTS {
+            tracing::error!(
+                tenant_id = %job.tenant_id,
+                source_type = %job.source_type,
+                source_id = %job.source_id,
+                error = %error,
+                "Embedding job failed permanently"
+            );
+            return;
+        }
+        tracing::warn!(
+            tenant_id = %job.tenant_id,
+            source_type = %job.source_type,
+            source_id = %job.source_id,
+            attempts = job.attempts,
+            error = %error,
+            "Embedding job failed, will retry"
+        );
+        let delay = RETRY_BASE_DELAY_SECS << (job.attempts - 1);
+        job.available_at = Utc::now() + chrono::Duration::seconds(delay);
+        if let Err(e) = self.store.enqueue_embedding_job(job).await {
+            tracing::error!(error = %e, "Embedding worker: failed to re-queue job");
+        }
+    }
+
+    /// Bring the stored embeddings of one product or FAQ in line with its current content.
+    async fn index_source(&self, endpoint: &AiEndpoint, job: &EmbeddingJob) -> Result<(), String> {
+        let (documents, entity_types) = match job.source_type {
+            EmbeddingEntity::Faq => (self.faq_documents(job).await?, vec![EmbeddingEntity::Faq]),
+            EmbeddingEntity::Product | EmbeddingEntity::Variant => (
+                self.product_documents(job).await?,
+                vec![EmbeddingEntity::Product, EmbeddingEntity::Variant],
+            ),
+        };
+
+        let existing = self
+            .store
+            .list_source_embeddings(&job.tenant_id, &job.source_id, &entity_types)
+            .await
+            .map_err(|e| e.to_string())?;
+        let existing_hashes: HashMap<(EmbeddingEntity, &str), &str> = existing
+            .iter()
+            .map(|r| {
+                (
+                    (r.entity_type, r.entity_id.as_str()),
+                    r.content_hash.as_str(),
+                )
+            })
+            .collect();
+
+        let model = endpoint.model_id();
+        let changed: Vec<(&Document, String)> = documents
+            .iter()
+            .map(|doc| (doc, embedding_content_hash(model, &doc.2)))
+            .filter(|(doc, hash)| {
+                existing_hashes.get(&(doc.0, doc.1.as_str())) != Some(&hash.as_str())
+            })
+            .collect();
+
+        for chunk in changed.chunks(EMBED_BATCH_SIZE) {
+            let inputs: Vec<String> = chunk.iter().map(|(doc, _)| doc.2.clone()).collect();
+            let vectors = self
+                .ai_service
+                .embed(endpoint, &inputs)
+                .await
+                .map_err(|e| e.to_string())?;
+            for ((doc, hash), embedding) in chunk.iter().zip(vectors) {
+                let record = EmbeddingRecord {
+                    tenant_id: job.tenant_id.clone(),
+                    entity_type: doc.0,
+                    entity_id: doc.1.clone(),
+                    source_id: job.source_id.clone(),
+                    model: model.to_string(),
+                    content_hash: hash.clone(),
+                    embedding,
+                    updated_at: Utc::now(),
+                };
+                self.store
+                    .upsert_embedding(record)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+
+        let current: HashSet<(EmbeddingEntity, &str)> = documents
+            .iter()
+            .map(|doc| (doc.0, doc.1.as_str()))
+            .collect();
+        for record in &existing {
+            if !current.contains(&(record.entity_type, record.entity_id.as_str())) {
+                self.store
+                    .delete_embedding(&job.tenant_id, record.entity_type, &record.entity_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+
+        if !changed.is_empty() {
+            tracing::debug!(
+                tenant_id = %job.tenant_id,
+                source_id = %job.source_id,
+                embedded = changed.len(),
+                "Updated embeddings"
+            );
+        }
+        Ok(())
+    }
+
+    /// Documents of a product and its variants; none once it is deleted or inactive
+    async fn product_documents(&self, job: &EmbeddingJob) -> Result<Vec<Document>, String> {
+        let product = match self
+            .product_repo
+            .get_product(&job.tenant_id, &job.source_id)
+            .await
+        {
+            Ok(product) if product.active => product,
+            Ok(_) | Err(ProductRepositoryError::NotFound) => return Ok(vec![]),
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let mut documents = vec![(
+            EmbeddingEntity::Product,
+            product.id.clone(),
+            product_embedding_text(&product),
+        )];
+        for variant in &product.variants {
+            if let Some(text) = variant_embedding_text(&product, variant) {
+                documents.push((EmbeddingEntity::Variant, variant.id.clone(), text));
+            }
+        }
+        Ok(documents)
+    }
+
+    /// Document of an FAQ; none once it is deleted or inactive
+    async fn faq_documents(&self, job: &EmbeddingJob) -> Result<Vec<Document>, String> {
+        let faq = self
+            .store
+            .get_faq(&job.tenant_id, &job.source_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(match faq {
+            Some(faq) if faq.active => {
+                vec![(
+                    EmbeddingEntity::Faq,
+                    faq.id.clone(),
+                    faq_embedding_text(&faq),
+                )]
+            }
+            _ => vec![],
+        })
+    }
+}
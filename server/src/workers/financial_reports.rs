@@ -0,0 +1,395 @@
+//! Background worker that emails scheduled financial reports.
+//!
+//! Tenants opt in with the `reports.financial_schedule` config entry. Each
+//! hour the worker checks whether the tenant's previous daily, weekly or
+//! monthly period has closed and, within the first day after it closes,
+//! queues one report email per recipient. Email IDs are derived from the
+//! tenant, cadence, period and recipient, so reruns never send duplicates.
+
+use std::fmt::Write as _;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use crate::config::{MessagingConfig, PostgresConfigRepository};
+use crate::repositories::ProductRepository;
+use crate::services::financial_reports::{
+    build_financial_report, report_to_csv, FinancialReport, FinancialReportSchedule, ReportParams,
+};
+use crate::storage::{EmailStatus, PendingEmail, Store};
+use crate::x402::utils::hex_encode;
+
+const SETTINGS_CATEGORY: &str = "reports";
+const SETTINGS_KEY: &str = "financial_schedule";
+
+/// Reports are only sent during this window after a period closes, so a
+/// long outage does not flood recipients with stale reports.
+const SEND_WINDOW: chrono::Duration = chrono::Duration::hours(24);
+
+/// Handle for controlling the financial report worker.
+pub struct FinancialReportWorkerHandle {
+    shutdown_tx: watch::Sender<bool>,
+    join_handle: Option<JoinHandle<()>>,
+}
+
+impl FinancialReportWorkerHandle {
+    pub fn shutdown(&self) {
+        let _ = self.shutdown_tx.send(true);
+    }
+
+    pub fn with_join_handle(mut self, join_handle: JoinHandle<()>) -> Self {
+        self.join_handle = Some(join_handle);
+        self
+    }
+
+    pub async fn wait(mut self) {
+        if let Some(handle) = self.join_handle.take() {
+            let _ = handle.await;
+        }
+    }
+}
+
+/// Financial report worker — queues scheduled report emails per tenant.
+pub struct FinancialReportWorker<S: Store> {
+    store: Arc<S>,
+    products: Option<Arc<dyn ProductRepository>>,
+    config_repo: Arc<PostgresConfigRepository>,
+    messaging: MessagingConfig,
+    check_interval: Duration,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl<S: Store + 'static> FinancialReportWorker<S> {
+    /// Create worker + handle with shutdown capability.
+    pub fn with_shutdown(
+        store: Arc<S>,
+        products: Option<Arc<dyn ProductRepository>>,
+        config_repo: Arc<PostgresConfigRepository>,
+        messaging: MessagingConfig,
+        check_interval: Duration,
+    ) -> (Self, FinancialReportWorkerHandle) {
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let worker = Self {
+            store,
+            products,
+            config_repo,
+            messaging,
+            check_interval,
+            shutdown_rx,
+        };
+        let handle = FinancialReportWorkerHandle {
+            shutdown_tx,
+            join_handle: None,
+        };
+        (worker, handle)
+    }
+
+    fn should_shutdown(&self) -> bool {
+        *self.shutdown_rx.borrow()
+    }
+
+    /// Main loop: check schedules on interval with graceful shutdown.
+    pub async fn run(mut self) {
+        let mut timer = tokio::time::interval(self.check_interval);
+        timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        tracing::info!(
+            interval_secs = self.check_interval.as_secs(),
+            "Financial report worker started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = timer.tick() => {
+                    if self.should_shutdown() { break; }
+                    self.run_schedules(Utc::now()).await;
+                }
+                _ = self.shutdown_rx.changed() => {
+                    tracing::info!("Financial report worker received shutdown signal");
+                    break;
+                }
+            }
+        }
+
+        tracing::info!("Financial report worker stopped");
+    }
+
+    async fn run_schedules(&self, now: DateTime<Utc>) {
+        let tenants = match self
+            .config_repo
+            .list_tenants_with_config(SETTINGS_CATEGORY, SETTINGS_KEY)
+            .await
+        {
+            Ok(t) => t,
+            Err(e) => {
+                tracing::error!(error = %e, "Financial reports: failed to list tenants");
+                return;
+            }
+        };
+
+        for tenant_id in &tenants {
+            if self.should_shutdown() {
+                return;
+            }
+            let Some(schedule) = self.load_schedule(tenant_id).await else {
+                continue;
+            };
+            self.run_tenant(tenant_id, &schedule, now).await;
+        }
+    }
+
+    async fn load_schedule(&self, tenant_id: &str) -> Option<FinancialReportSchedule> {
+        match self
+            .config_repo
+            .get_config(tenant_id, SETTINGS_CATEGORY)
+            .await
+        {
+            Ok(entries) => entries
+                .iter()
+                .find(|e| e.config_key == SETTINGS_KEY)
+                .and_then(|e| serde_json::from_value(e.value.clone()).ok()),
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    tenant_id = %tenant_id,
+                    "Financial reports: failed to load schedule"
+                );
+                None
+            }
+        }
+    }
+
+    async fn run_tenant(
+        &self,
+        tenant_id: &str,
+        schedule: &FinancialReportSchedule,
+        now: DateTime<Utc>,
+    ) {
+        let Some((params, pending)) = due_recipients(&*self.store, tenant_id, schedule, now).await
+        else {
+            return;
+        };
+
+        let report =
+            match build_financial_report(&*self.store, self.products.as_deref(), tenant_id, params)
+                .await
+            {
+                Ok(report) => report,
+                Err(e) => {
+                    tracing::warn!(
+                        error = %e,
+                        tenant_id = %tenant_id,
+                        "Financial reports: failed to build scheduled report"
+                    );
+                    return;
+                }
+            };
+
+        for (email_id, recipient) in pending {
+            let email = report_email(&self.messaging, &report, email_id, recipient, now);
+            if let Err(e) = self.store.enqueue_email(email).await {
+                tracing::warn!(
+                    error = %e,
+                    tenant_id = %tenant_id,
+                    "Financial reports: failed to queue report email"
+                );
+            }
+        }
+    }
+}
+
+/// Report window and the `(email_id, recipient)` pairs still to be sent, or
+/// `None` when nothing is due.
+async fn due_recipients<S: Store + ?Sized>(
+    store: &S,
+    tenant_id: &str,
+    schedule: &FinancialReportSchedule,
+    now: DateTime<Utc>,
+) -> Option<(ReportParams, Vec<(String, String)>)> {
+    if !schedule.enabled {
+        return None;
+    }
+    let (from, to) = schedule.frequency.previous(now);
+    if now - to > SEND_WINDOW {
+        return None;
+    }
+
+    let mut pending = Vec::new();
+    for recipient in &schedule.recipients {
+        let recipient = recipient.trim();
+        if recipient.is_empty() {
+            continue;
+        }
+        let email_id = report_email_id(tenant_id, schedule, from, recipient);
+        match store.get_email(&email_id).await {
+            Ok(None) => pending.push((email_id, recipient.to_string())),
+            Ok(Some(_)) => {}
+            Err(e) => {
+                tracing::warn!(error = %e, tenant_id = %tenant_id, "Financial reports: email lookup failed");
+            }
+        }
+    }
+    if pending.is_empty() {
+        return None;
+    }
+
+    let params = ReportParams {
+        from,
+        to,
+        period: schedule.frequency,
+        group_by: schedule.group_by,
+    };
+    Some((params, pending))
+}
+
+fn report_email_id(
+    tenant_id: &str,
+    schedule: &FinancialReportSchedule,
+    period_start: DateTime<Utc>,
+    recipient: &str,
+) -> String {
+    let digest = Sha256::digest(recipient.to_lowercase().as_bytes());
+    format!(
+        "report_{}_{}_{}_{}",
+        tenant_id,
+        schedule.frequency.as_str(),
+        period_start.format("%Y%m%d"),
+        hex_encode(&digest[..8])
+    )
+}
+
+fn report_email(
+    messaging: &MessagingConfig,
+    report: &FinancialReport,
+    email_id: String,
+    recipient: String,
+    now: DateTime<Utc>,
+) -> PendingEmail {
+    let last_day = report.to - chrono::Duration::days(1);
+    let subject = format!(
+        "Financial report ({}) {} to {}",
+        report.period.as_str(),
+        report.from.format("%Y-%m-%d"),
+        last_day.format("%Y-%m-%d")
+    );
+
+    let mut body = format!("{}\n\nTotals (amounts in major units):\n", subject);
+    if report.totals.is_empty() {
+        body.push_str("  No activity in this period.\n");
+    }
+    for t in &report.totals {
+        let major = |atomic: i64| atomic as f64 / 10f64.powi(t.decimals as i32);
+        let _ = writeln!(
+            body,
+            "  {}: orders {}, gross {:.*}, refunds {:.*}, disputes {:.*}, net {:.*}",
+            t.currency,
+            t.amounts.orders,
+            t.decimals as usize,
+            major(t.amounts.gross_sales),
+            t.decimals as usize,
+            major(t.amounts.refunds),
+            t.decimals as usize,
+            major(t.amounts.disputes),
+            t.decimals as usize,
+            major(t.amounts.net),
+        );
+    }
+    body.push_str("\nDetail (CSV, atomic units):\n\n");
+    body.push_str(&report_to_csv(report));
+
+    PendingEmail {
+        id: email_id,
+        tenant_id: report.tenant_id.clone(),
+        to_email: recipient,
+        from_email: messaging.from_email.clone(),
+        from_name: messaging.from_name.clone(),
+        subject,
+        body_text: body,
+        body_html: None,
+        status: EmailStatus::Pending,
+        attempts: 0,
+        max_attempts: 5,
+        last_error: None,
+        last_attempt_at: None,
+        next_attempt_at: None,
+        created_at: now,
+        completed_at: None,
+        traceparent: None,
+        provider: None,
+        provider_message_id: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::TimeZone;
+
+    use crate::services::financial_reports::ReportPeriod;
+    use crate::storage::InMemoryStore;
+
+    fn schedule() -> FinancialReportSchedule {
+        FinancialReportSchedule {
+            enabled: true,
+            frequency: ReportPeriod::Week,
+            recipients: vec!["finance@example.com".to_string(), " ".to_string()],
+            group_by: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_due_recipients_once_per_period() {
+        let store = InMemoryStore::new();
+        // Monday 2026-03-16, 02:00 UTC: last week closed two hours ago
+        let now = Utc.with_ymd_and_hms(2026, 3, 16, 2, 0, 0).unwrap();
+
+        let (params, pending) = due_recipients(&store, "t1", &schedule(), now)
+            .await
+            .expect("report due");
+        assert_eq!(
+            params.from,
+            Utc.with_ymd_and_hms(2026, 3, 9, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            params.to,
+            Utc.with_ymd_and_hms(2026, 3, 16, 0, 0, 0).unwrap()
+        );
+        assert_eq!(pending.len(), 1);
+
+        let report = build_financial_report(&store, None, "t1", params)
+            .await
+            .unwrap();
+        let (id, recipient) = pending.into_iter().next().unwrap();
+        let email = report_email(&MessagingConfig::default(), &report, id, recipient, now);
+        assert!(email.subject.contains("2026-03-09 to 2026-03-15"));
+        assert!(email.body_text.contains("tenant_id,period_start"));
+        store.enqueue_email(email).await.unwrap();
+
+        // Already queued for this period
+        assert!(due_recipients(&store, "t1", &schedule(), now)
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_due_recipients_skips_stale_and_disabled() {
+        let store = InMemoryStore::new();
+        // Wednesday: last week closed more than a day ago
+        let now = Utc.with_ymd_and_hms(2026, 3, 18, 2, 0, 0).unwrap();
+        assert!(due_recipients(&store, "t1", &schedule(), now)
+            .await
+            .is_none());
+
+        let monday = Utc.with_ymd_and_hms(2026, 3, 16, 2, 0, 0).unwrap();
+        let mut disabled = schedule();
+        disabled.enabled = false;
+        assert!(due_recipients(&store, "t1", &disabled, monday)
+            .await
+            .is_none());
+    }
+}
@@ -0,0 +1,117 @@
+//! Background worker that re-wraps config DEKs under the active KEK.
+//!
+//! After `CEDROS_CONFIG_KEK` is replaced (with the old key moved to
+//! `CEDROS_CONFIG_KEK_PREVIOUS`), DEKs wrapped under the retired key are
+//! re-wrapped in batches at startup and on every interval. Once no DEK
+//! references the retired KEK it can be removed from the environment.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use crate::config::PostgresConfigRepository;
+
+/// DEKs re-wrapped per database round trip.
+const REWRAP_BATCH_SIZE: i64 = 100;
+
+/// Handle for controlling the key re-wrap worker.
+pub struct KeyRewrapWorkerHandle {
+    shutdown_tx: watch::Sender<bool>,
+    join_handle: Option<JoinHandle<()>>,
+}
+
+impl KeyRewrapWorkerHandle {
+    pub fn shutdown(&self) {
+        let _ = self.shutdown_tx.send(true);
+    }
+
+    pub fn with_join_handle(mut self, join_handle: JoinHandle<()>) -> Self {
+        self.join_handle = Some(join_handle);
+        self
+    }
+
+    pub async fn wait(mut self) {
+        if let Some(handle) = self.join_handle.take() {
+            let _ = handle.await;
+        }
+    }
+}
+
+/// Key re-wrap worker — moves DEKs off retired KEKs.
+pub struct KeyRewrapWorker {
+    config_repo: Arc<PostgresConfigRepository>,
+    check_interval: Duration,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl KeyRewrapWorker {
+    /// Create worker + handle with shutdown capability.
+    pub fn with_shutdown(
+        config_repo: Arc<PostgresConfigRepository>,
+        check_interval: Duration,
+    ) -> (Self, KeyRewrapWorkerHandle) {
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let worker = Self {
+            config_repo,
+            check_interval,
+            shutdown_rx,
+        };
+        let handle = KeyRewrapWorkerHandle {
+            shutdown_tx,
+            join_handle: None,
+        };
+        (worker, handle)
+    }
+
+    fn should_shutdown(&self) -> bool {
+        *self.shutdown_rx.borrow()
+    }
+
+    /// Main loop: re-wrap on startup and on interval with graceful shutdown.
+    pub async fn run(mut self) {
+        let mut timer = tokio::time::interval(self.check_interval);
+        timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        tracing::info!(
+            interval_secs = self.check_interval.as_secs(),
+            "Key re-wrap worker started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = timer.tick() => {
+                    if self.should_shutdown() { break; }
+                    self.rewrap_all().await;
+                }
+                _ = self.shutdown_rx.changed() => {
+                    tracing::info!("Key re-wrap worker received shutdown signal");
+                    break;
+                }
+            }
+        }
+
+        tracing::info!("Key re-wrap worker stopped");
+    }
+
+    async fn rewrap_all(&self) {
+        let mut total = 0usize;
+        loop {
+            if self.should_shutdown() {
+                break;
+            }
+            match self.config_repo.rewrap_deks(REWRAP_BATCH_SIZE).await {
+                Ok(0) => break,
+                Ok(count) => total += count,
+                Err(e) => {
+                    tracing::error!(error = %e, "Key re-wrap: failed to re-wrap DEKs");
+                    break;
+                }
+            }
+        }
+        if total > 0 {
+            tracing::info!(count = total, "Re-wrapped config DEKs under the active KEK");
+        }
+    }
+}
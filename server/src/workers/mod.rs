@@ -1,26 +1,38 @@
 pub mod balance_alert;
 pub mod cleanup;
 pub mod email;
+pub mod embeddings;
+pub mod financial_reports;
 pub mod health_checker;
+pub mod key_rewrap;
 pub mod lifecycle;
+pub mod price_schedules;
 pub mod sanctions_refresh;
 pub mod sanctions_sweep;
+pub mod secrets_refresh;
 pub mod subscription;
 pub mod webhook;
 
 pub use balance_alert::{create_webhook_callback, BalanceAlertSender};
 pub use cleanup::{CleanupWorker, CleanupWorkerHandle};
 pub use email::{spawn_email_worker, EmailWorker, EmailWorkerHandle};
+pub use embeddings::{EmbeddingWorker, EmbeddingWorkerHandle};
+pub use financial_reports::{FinancialReportWorker, FinancialReportWorkerHandle};
 pub use health_checker::{
     AlertCallback, HealthChecker, HealthCheckerHandle, HealthState, LowBalanceAlert, WalletHealth,
     WalletHealthStatus,
 };
+pub use key_rewrap::{KeyRewrapWorker, KeyRewrapWorkerHandle};
 pub use lifecycle::{
     GracefulShutdown, WorkerLifecycle, WorkerLifecycleBuilder, WorkerLifecycleHandle,
     WorkerRegistration,
 };
+pub use price_schedules::{PriceScheduleWorker, PriceScheduleWorkerHandle};
 pub use sanctions_refresh::{SanctionsRefreshWorker, SanctionsRefreshWorkerHandle};
 pub use sanctions_sweep::{SanctionsSweepWorker, SanctionsSweepWorkerHandle};
+pub use secrets_refresh::{SecretsRefreshWorker, SecretsRefreshWorkerHandle};
 pub use subscription::{SubscriptionWorker, SubscriptionWorkerHandle};
 #[allow(deprecated)]
-pub use webhook::{spawn_webhook_worker, WebhookWorker, WebhookWorkerHandle};
+pub use webhook::{
+    spawn_webhook_worker, WebhookSettingsHandle, WebhookWorker, WebhookWorkerHandle,
+};
@@ -0,0 +1,110 @@
+//! Background worker that applies and reverts scheduled price changes.
+//!
+//! Each tick the worker loads schedules whose start (or end) has passed and
+//! hands them to [`PriceScheduler`], which updates the product, syncs the new
+//! fiat price to Stripe and writes the audit log entry.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use crate::repositories::ProductRepository;
+use crate::services::price_schedules::PriceScheduler;
+use crate::services::StripeClient;
+use crate::storage::Store;
+
+/// Schedules processed per tick; the rest wait for the next one.
+const BATCH_SIZE: i32 = 100;
+
+/// Handle for controlling the price schedule worker.
+pub struct PriceScheduleWorkerHandle {
+    shutdown_tx: watch::Sender<bool>,
+    join_handle: Option<JoinHandle<()>>,
+}
+
+impl PriceScheduleWorkerHandle {
+    pub fn shutdown(&self) {
+        let _ = self.shutdown_tx.send(true);
+    }
+
+    pub fn with_join_handle(mut self, join_handle: JoinHandle<()>) -> Self {
+        self.join_handle = Some(join_handle);
+        self
+    }
+
+    pub async fn wait(mut self) {
+        if let Some(handle) = self.join_handle.take() {
+            let _ = handle.await;
+        }
+    }
+}
+
+/// Price schedule worker — applies due price changes and reverts ended ones.
+pub struct PriceScheduleWorker {
+    scheduler: PriceScheduler,
+    check_interval: Duration,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl PriceScheduleWorker {
+    /// Create worker + handle with shutdown capability.
+    pub fn with_shutdown(
+        store: Arc<dyn Store>,
+        products: Arc<dyn ProductRepository>,
+        stripe_client: Option<Arc<StripeClient>>,
+        check_interval: Duration,
+    ) -> (Self, PriceScheduleWorkerHandle) {
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let worker = Self {
+            scheduler: PriceScheduler::new(store, products, stripe_client),
+            check_interval,
+            shutdown_rx,
+        };
+        let handle = PriceScheduleWorkerHandle {
+            shutdown_tx,
+            join_handle: None,
+        };
+        (worker, handle)
+    }
+
+    fn should_shutdown(&self) -> bool {
+        *self.shutdown_rx.borrow()
+    }
+
+    /// Main loop: process due schedules on interval with graceful shutdown.
+    pub async fn run(mut self) {
+        let mut timer = tokio::time::interval(self.check_interval);
+        timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        tracing::info!(
+            interval_secs = self.check_interval.as_secs(),
+            "Price schedule worker started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = timer.tick() => {
+                    if self.should_shutdown() { break; }
+                    self.run_due().await;
+                }
+                _ = self.shutdown_rx.changed() => {
+                    tracing::info!("Price schedule worker received shutdown signal");
+                    break;
+                }
+            }
+        }
+
+        tracing::info!("Price schedule worker stopped");
+    }
+
+    async fn run_due(&self) {
+        match self.scheduler.run_due(Utc::now(), BATCH_SIZE).await {
+            Ok(0) => {}
+            Ok(n) => tracing::info!(processed = n, "Price schedules processed"),
+            Err(e) => tracing::error!(error = %e, "Price schedules: failed to list due schedules"),
+        }
+    }
+}
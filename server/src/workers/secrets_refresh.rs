@@ -0,0 +1,115 @@
+//! Background worker that polls the secret provider for changed values.
+//!
+//! Changed secrets are published through the [`SecretStore`] watch channel;
+//! consuming services read the latest snapshot on use, so rotated Stripe
+//! keys, server wallets, SMTP passwords and AI keys apply without a restart.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use crate::config::SecretStore;
+
+/// Handle for controlling the secrets refresh worker.
+pub struct SecretsRefreshWorkerHandle {
+    shutdown_tx: watch::Sender<bool>,
+    join_handle: Option<JoinHandle<()>>,
+}
+
+impl SecretsRefreshWorkerHandle {
+    pub fn shutdown(&self) {
+        let _ = self.shutdown_tx.send(true);
+    }
+
+    pub fn with_join_handle(mut self, join_handle: JoinHandle<()>) -> Self {
+        self.join_handle = Some(join_handle);
+        self
+    }
+
+    pub async fn wait(mut self) {
+        if let Some(handle) = self.join_handle.take() {
+            let _ = handle.await;
+        }
+    }
+}
+
+/// Secrets refresh worker — reloads the provider on an interval.
+pub struct SecretsRefreshWorker {
+    secrets: Arc<SecretStore>,
+    refresh_interval: Duration,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl SecretsRefreshWorker {
+    /// Create worker + handle with shutdown capability.
+    pub fn with_shutdown(
+        secrets: Arc<SecretStore>,
+        refresh_interval: Duration,
+    ) -> (Self, SecretsRefreshWorkerHandle) {
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let worker = Self {
+            secrets,
+            refresh_interval,
+            shutdown_rx,
+        };
+        let handle = SecretsRefreshWorkerHandle {
+            shutdown_tx,
+            join_handle: None,
+        };
+        (worker, handle)
+    }
+
+    /// Main loop: refresh on interval with graceful shutdown.
+    ///
+    /// The initial load happens when the store is built, so the first
+    /// immediate tick is skipped.
+    pub async fn run(mut self) {
+        let mut timer = tokio::time::interval(self.refresh_interval);
+        timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        timer.tick().await;
+
+        tracing::info!(
+            provider = self.secrets.provider_name(),
+            interval_secs = self.refresh_interval.as_secs(),
+            "Secrets refresh worker started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = timer.tick() => {
+                    if *self.shutdown_rx.borrow() { break; }
+                    self.refresh().await;
+                }
+                _ = self.shutdown_rx.changed() => {
+                    tracing::info!("Secrets refresh worker received shutdown signal");
+                    break;
+                }
+            }
+        }
+
+        tracing::info!("Secrets refresh worker stopped");
+    }
+
+    async fn refresh(&self) {
+        match self.secrets.refresh().await {
+            Ok(changed) if changed.is_empty() => {}
+            Ok(changed) => {
+                tracing::info!(
+                    provider = self.secrets.provider_name(),
+                    secrets = ?changed,
+                    version = self.secrets.snapshot().version(),
+                    "Secrets changed; reloaded"
+                );
+            }
+            Err(e) => {
+                tracing::warn!(
+                    provider = self.secrets.provider_name(),
+                    error = %e,
+                    "Secrets refresh failed; keeping previous values"
+                );
+            }
+        }
+    }
+}
@@ -4,36 +4,81 @@ use std::time::{Duration, Instant};
 use chrono::Utc;
 use futures_util::StreamExt;
 use indexmap::IndexMap;
-use parking_lot::Mutex;
+use parking_lot::{Mutex, RwLock};
 use rand::Rng;
 use tokio::sync::watch;
 use tokio::task::JoinHandle;
 use tokio::time::interval;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, warn, Instrument};
 
 use crate::config::{CallbacksConfig, RetryConfig};
 use crate::middleware::circuit_breaker::{
     new_circuit_breaker, CircuitBreakerConfig, SharedCircuitBreaker,
 };
+use crate::observability::otel;
 use crate::storage::Store;
 
 /// Webhook delivery worker with graceful shutdown support per spec (20-webhooks.md)
 pub struct WebhookWorker<S: Store> {
     store: Arc<S>,
-    http_client: reqwest::Client,
     poll_interval: Duration,
     batch_size: i32,
     shutdown_rx: Option<watch::Receiver<bool>>,
+    /// Delivery settings, swappable while running via [`WebhookSettingsHandle`]
+    settings: Arc<RwLock<Arc<DeliverySettings>>>,
     /// Circuit breaker per spec (20-webhooks.md) to prevent cascading failures
-    circuit_breaker_config: CircuitBreakerConfig,
-    circuit_breakers: Mutex<IndexMap<String, SharedCircuitBreaker>>,
+    circuit_breakers: Arc<Mutex<IndexMap<String, SharedCircuitBreaker>>>,
     /// Retention period for completed webhooks (days)
     retention_days: i32,
     /// Cleanup interval (number of poll cycles between cleanups)
     cleanup_interval_cycles: u32,
+    /// False when cold archival owns webhook expiry
+    cleanup_enabled: bool,
     next_backlog_metric_at: Mutex<Instant>,
+}
+
+/// Settings derived from `CallbacksConfig` and the webhook circuit breaker config
+struct DeliverySettings {
+    http_client: reqwest::Client,
     retry: RetryConfig,
     dlq_enabled: bool,
+    circuit_breaker_config: CircuitBreakerConfig,
+}
+
+impl DeliverySettings {
+    fn new(callbacks: &CallbacksConfig, cb_config: CircuitBreakerConfig) -> Result<Self, String> {
+        Ok(Self {
+            // WK-002: Configure connection pool limits to prevent resource exhaustion
+            http_client: build_webhook_http_client(callbacks.timeout)?,
+            retry: callbacks.retry.clone(),
+            dlq_enabled: callbacks.dlq_enabled,
+            circuit_breaker_config: cb_config,
+        })
+    }
+}
+
+/// Applies config changes to a running [`WebhookWorker`].
+#[derive(Clone)]
+pub struct WebhookSettingsHandle {
+    settings: Arc<RwLock<Arc<DeliverySettings>>>,
+    circuit_breakers: Arc<Mutex<IndexMap<String, SharedCircuitBreaker>>>,
+}
+
+impl WebhookSettingsHandle {
+    /// Swap in new timeout, retry, DLQ and circuit breaker settings. Batches
+    /// already in flight finish with the previous settings.
+    pub fn reconfigure(
+        &self,
+        callbacks: &CallbacksConfig,
+        cb_config: CircuitBreakerConfig,
+    ) -> Result<(), String> {
+        let settings = DeliverySettings::new(callbacks, cb_config.clone())?;
+        for breaker in self.circuit_breakers.lock().values() {
+            breaker.reconfigure(cb_config.clone());
+        }
+        *self.settings.write() = Arc::new(settings);
+        Ok(())
+    }
 }
 
 /// Handle for controlling the worker
@@ -110,22 +155,18 @@ impl<S: Store + 'static> WebhookWorker<S> {
         callbacks: &CallbacksConfig,
         cb_config: CircuitBreakerConfig,
     ) -> Result<Self, String> {
-        let timeout = callbacks.timeout;
-        let http_client = build_webhook_http_client(timeout)?;
+        let settings = DeliverySettings::new(callbacks, cb_config)?;
         Ok(Self {
             store,
-            // WK-002: Configure connection pool limits to prevent resource exhaustion
-            http_client,
             poll_interval: Duration::from_secs(5),
             batch_size: 10,
             shutdown_rx: None,
-            circuit_breaker_config: cb_config.clone(),
-            circuit_breakers: Mutex::new(IndexMap::new()),
+            settings: Arc::new(RwLock::new(Arc::new(settings))),
+            circuit_breakers: Arc::new(Mutex::new(IndexMap::new())),
             retention_days: DEFAULT_RETENTION_DAYS,
             cleanup_interval_cycles: DEFAULT_CLEANUP_CYCLES,
+            cleanup_enabled: true,
             next_backlog_metric_at: Mutex::new(Instant::now()),
-            retry: callbacks.retry.clone(),
-            dlq_enabled: callbacks.dlq_enabled,
         })
     }
 
@@ -144,22 +185,18 @@ impl<S: Store + 'static> WebhookWorker<S> {
         cb_config: CircuitBreakerConfig,
     ) -> Result<(Self, WebhookWorkerHandle), String> {
         let (shutdown_tx, shutdown_rx) = watch::channel(false);
-        let timeout = callbacks.timeout;
-        let http_client = build_webhook_http_client(timeout)?;
+        let settings = DeliverySettings::new(callbacks, cb_config)?;
         let worker = Self {
             store,
-            // WK-002: Configure connection pool limits to prevent resource exhaustion
-            http_client,
             poll_interval: Duration::from_secs(5),
             batch_size: 10,
             shutdown_rx: Some(shutdown_rx),
-            circuit_breaker_config: cb_config.clone(),
-            circuit_breakers: Mutex::new(IndexMap::new()),
+            settings: Arc::new(RwLock::new(Arc::new(settings))),
+            circuit_breakers: Arc::new(Mutex::new(IndexMap::new())),
             retention_days: DEFAULT_RETENTION_DAYS,
             cleanup_interval_cycles: DEFAULT_CLEANUP_CYCLES,
+            cleanup_enabled: true,
             next_backlog_metric_at: Mutex::new(Instant::now()),
-            retry: callbacks.retry.clone(),
-            dlq_enabled: callbacks.dlq_enabled,
         };
         let handle = WebhookWorkerHandle {
             shutdown_tx,
@@ -168,6 +205,18 @@ impl<S: Store + 'static> WebhookWorker<S> {
         Ok((worker, handle))
     }
 
+    /// Handle for applying config changes after the worker is started
+    pub fn settings_handle(&self) -> WebhookSettingsHandle {
+        WebhookSettingsHandle {
+            settings: self.settings.clone(),
+            circuit_breakers: self.circuit_breakers.clone(),
+        }
+    }
+
+    fn settings(&self) -> Arc<DeliverySettings> {
+        self.settings.read().clone()
+    }
+
     pub fn with_poll_interval(mut self, interval: Duration) -> Self {
         self.poll_interval = interval;
         self
@@ -178,6 +227,15 @@ impl<S: Store + 'static> WebhookWorker<S> {
         self
     }
 
+    /// Leave old webhooks to cold archival, which deletes them only after
+    /// writing them to object storage. Without this the worker would remove
+    /// them after `DEFAULT_RETENTION_DAYS`, long before the archival
+    /// retention period is reached.
+    pub fn with_cold_archive(mut self) -> Self {
+        self.cleanup_enabled = false;
+        self
+    }
+
     /// Start the webhook worker with graceful shutdown support
     pub async fn run(mut self) {
         let mut poll_timer = interval(self.poll_interval);
@@ -231,7 +289,7 @@ impl<S: Store + 'static> WebhookWorker<S> {
 
                     // Periodic cleanup of old completed/failed webhooks
                     cycles_since_cleanup += 1;
-                    if cycles_since_cleanup >= self.cleanup_interval_cycles {
+                    if self.cleanup_enabled && cycles_since_cleanup >= self.cleanup_interval_cycles {
                         cycles_since_cleanup = 0;
                         match self.store.cleanup_old_webhooks(self.retention_days).await {
                             Ok(deleted) if deleted > 0 => {
@@ -295,95 +353,100 @@ impl<S: Store + 'static> WebhookWorker<S> {
         let max_concurrency = std::cmp::min(4, webhooks.len());
         let worker = self;
         futures_util::stream::iter(webhooks)
-            .for_each_concurrent(max_concurrency, |webhook| async move {
-                let result = match Self::payload_bytes(&webhook) {
-                    Ok(payload_bytes) => {
-                        worker
-                            .deliver_webhook(
-                                &webhook.id,
-                                &webhook.url,
-                                &payload_bytes,
-                                &webhook.headers,
-                            )
-                            .await
-                    }
-                    Err(err) => Err(err),
-                };
-
-                match result {
-                    Ok(_) => {
-                        if let Err(e) = worker.store.mark_webhook_success(&webhook.id).await {
-                            warn!(webhook_id = %webhook.id, error = %e, "Failed to mark webhook success");
-                        } else {
-                            info!(webhook_id = %webhook.id, "Webhook delivered successfully");
+            .for_each_concurrent(max_concurrency, |webhook| {
+                let span = Self::delivery_span(&webhook);
+                async move {
+                    let settings = worker.settings();
+                    let result = match Self::payload_bytes(&webhook) {
+                        Ok(payload_bytes) => {
+                            worker
+                                .deliver_webhook(
+                                    &webhook.id,
+                                    &webhook.url,
+                                    &payload_bytes,
+                                    &webhook.headers,
+                                )
+                                .await
                         }
-                    }
-                    Err(err) => {
-                        let new_attempts = webhook.attempts + 1;
-                        let max_attempts = if worker.retry.enabled {
-                            webhook.max_attempts.max(1)
-                        } else {
-                            1
-                        };
-                        let webhook_id = webhook.id.clone();
-
-                        if new_attempts >= max_attempts {
-                            if worker.dlq_enabled {
-                                // Move to DLQ per spec (20-webhooks.md)
-                                // BUG-002: ensure DLQ records the final attempt count.
-                                let mut webhook = webhook;
-                                webhook.attempts = new_attempts;
-                                if let Err(e) = worker.store.move_to_dlq(webhook, &err).await {
-                                    warn!(webhook_id = %webhook_id, error = %e, "Failed to move webhook to DLQ");
+                        Err(err) => Err(err),
+                    };
+
+                    match result {
+                        Ok(_) => {
+                            if let Err(e) = worker.store.mark_webhook_success(&webhook.id).await {
+                                warn!(webhook_id = %webhook.id, error = %e, "Failed to mark webhook success");
+                            } else {
+                                info!(webhook_id = %webhook.id, "Webhook delivered successfully");
+                            }
+                        }
+                        Err(err) => {
+                            let new_attempts = webhook.attempts + 1;
+                            let max_attempts = if settings.retry.enabled {
+                                webhook.max_attempts.max(1)
+                            } else {
+                                1
+                            };
+                            let webhook_id = webhook.id.clone();
+
+                            if new_attempts >= max_attempts {
+                                if settings.dlq_enabled {
+                                    // Move to DLQ per spec (20-webhooks.md)
+                                    // BUG-002: ensure DLQ records the final attempt count.
+                                    let mut webhook = webhook;
+                                    webhook.attempts = new_attempts;
+                                    if let Err(e) = worker.store.move_to_dlq(webhook, &err).await {
+                                        warn!(webhook_id = %webhook_id, error = %e, "Failed to move webhook to DLQ");
+                                    } else {
+                                        error!(
+                                            webhook_id = %webhook_id,
+                                            attempts = new_attempts,
+                                            error = %err,
+                                            "Webhook failed permanently, moved to DLQ"
+                                        );
+                                    }
                                 } else {
-                                    error!(
-                                        webhook_id = %webhook_id,
-                                        attempts = new_attempts,
-                                        error = %err,
-                                        "Webhook failed permanently, moved to DLQ"
-                                    );
+                                    let now = Utc::now();
+                                    if let Err(e) =
+                                        worker.store.mark_webhook_failed(&webhook_id, &err, now).await
+                                    {
+                                        warn!(webhook_id = %webhook_id, error = %e, "Failed to mark webhook failed");
+                                    } else {
+                                        error!(
+                                            webhook_id = %webhook_id,
+                                            attempts = new_attempts,
+                                            error = %err,
+                                            "Webhook failed permanently, DLQ disabled"
+                                        );
+                                    }
                                 }
                             } else {
-                                let now = Utc::now();
-                                if let Err(e) =
-                                    worker.store.mark_webhook_failed(&webhook_id, &err, now).await
+                                // Schedule retry with exponential backoff
+                                let delay = compute_retry_delay(&settings.retry, new_attempts);
+                                // Convert std Duration to chrono Duration, falling back to 1 minute if conversion fails
+                                let next_attempt = Utc::now()
+                                    + chrono::Duration::from_std(delay)
+                                        .unwrap_or_else(|_| chrono::Duration::minutes(1));
+
+                                if let Err(e) = worker
+                                    .store
+                                    .mark_webhook_retry(&webhook_id, &err, next_attempt)
+                                    .await
                                 {
-                                    warn!(webhook_id = %webhook_id, error = %e, "Failed to mark webhook failed");
+                                    warn!(webhook_id = %webhook_id, error = %e, "Failed to schedule webhook retry");
                                 } else {
-                                    error!(
-                                        webhook_id = %webhook_id,
+                                    warn!(
+                                        webhook_id = %webhook.id,
                                         attempts = new_attempts,
+                                        next_attempt = %next_attempt,
                                         error = %err,
-                                        "Webhook failed permanently, DLQ disabled"
+                                        "Webhook delivery failed, scheduled retry"
                                     );
                                 }
                             }
-                        } else {
-                            // Schedule retry with exponential backoff
-                            let delay = worker.retry_delay(new_attempts);
-                            // Convert std Duration to chrono Duration, falling back to 1 minute if conversion fails
-                            let next_attempt = Utc::now()
-                                + chrono::Duration::from_std(delay)
-                                    .unwrap_or_else(|_| chrono::Duration::minutes(1));
-
-                            if let Err(e) = worker
-                                .store
-                                .mark_webhook_retry(&webhook_id, &err, next_attempt)
-                                .await
-                            {
-                                warn!(webhook_id = %webhook_id, error = %e, "Failed to schedule webhook retry");
-                            } else {
-                                warn!(
-                                    webhook_id = %webhook.id,
-                                    attempts = new_attempts,
-                                    next_attempt = %next_attempt,
-                                    error = %err,
-                                    "Webhook delivery failed, scheduled retry"
-                                );
-                            }
                         }
                     }
                 }
+                .instrument(span)
             })
             .await;
 
@@ -403,6 +466,21 @@ impl<S: Store + 'static> WebhookWorker<S> {
         }
     }
 
+    /// Client span for one delivery attempt, continuing the trace that
+    /// enqueued the webhook (its persisted `traceparent` header).
+    fn delivery_span(webhook: &crate::storage::PendingWebhook) -> tracing::Span {
+        let span = tracing::info_span!(
+            "webhook.deliver",
+            otel.kind = "client",
+            webhook_id = %webhook.id,
+            tenant_id = %webhook.tenant_id,
+            event_type = %webhook.event_type,
+            attempt = webhook.attempts + 1,
+        );
+        otel::set_parent_from_map(&span, &webhook.headers);
+        span
+    }
+
     /// Deliver a single webhook with circuit breaker protection per spec (20-webhooks.md)
     async fn deliver_webhook(
         &self,
@@ -411,15 +489,20 @@ impl<S: Store + 'static> WebhookWorker<S> {
         payload_bytes: &[u8],
         headers: &std::collections::HashMap<String, String>,
     ) -> Result<(), String> {
+        let settings = self.settings();
+
         // Check circuit breaker before attempting delivery
-        let circuit_breaker = self.circuit_breaker_for(url);
+        let circuit_breaker = self.circuit_breaker_for(url, &settings.circuit_breaker_config);
         if !circuit_breaker.allow() {
             return Err("webhook circuit breaker is open".to_string());
         }
 
-        let mut request = self.http_client.post(url).body(payload_bytes.to_vec());
+        let mut request = settings.http_client.post(url).body(payload_bytes.to_vec());
 
-        for (key, value) in headers {
+        // Receivers see this delivery attempt as the parent span.
+        let mut headers = headers.clone();
+        otel::inject_current_context(&mut headers);
+        for (key, value) in &headers {
             request = request.header(key.as_str(), value.as_str());
         }
 
@@ -463,7 +546,11 @@ impl<S: Store + 'static> WebhookWorker<S> {
 
     /// R-02 fix: key circuit breaker by host/origin, not full URL, so that
     /// failures on one path of a host correctly trip the breaker for all paths.
-    fn circuit_breaker_for(&self, url: &str) -> SharedCircuitBreaker {
+    fn circuit_breaker_for(
+        &self,
+        url: &str,
+        cb_config: &CircuitBreakerConfig,
+    ) -> SharedCircuitBreaker {
         let key = url::Url::parse(url)
             .ok()
             .and_then(|u| {
@@ -484,7 +571,7 @@ impl<S: Store + 'static> WebhookWorker<S> {
             breakers.shift_remove_index(0);
         }
 
-        let breaker = new_circuit_breaker(self.circuit_breaker_config.clone());
+        let breaker = new_circuit_breaker(cb_config.clone());
         breakers.insert(key, breaker.clone());
         breaker
     }
@@ -528,10 +615,6 @@ impl<S: Store + 'static> WebhookWorker<S> {
         }
     }
 
-    fn retry_delay(&self, attempt: i32) -> Duration {
-        compute_retry_delay(&self.retry, attempt)
-    }
-
     fn should_refresh_backlog_metric(&self) -> bool {
         let now = Instant::now();
         let mut next_refresh = self.next_backlog_metric_at.lock();
@@ -657,6 +740,84 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_delivery_continues_enqueuing_trace() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let received = Arc::new(Mutex::new(None::<String>));
+        let received_clone = received.clone();
+        let app = Router::new().route(
+            "/hook",
+            post(move |headers: axum::http::HeaderMap| {
+                let received = received_clone.clone();
+                async move {
+                    *received.lock() = headers
+                        .get(otel::TRACEPARENT_HEADER)
+                        .and_then(|v| v.to_str().ok())
+                        .map(str::to_string);
+                    StatusCode::OK
+                }
+            }),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let guard = otel::OtelGuard::for_tests();
+        let _subscriber =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(guard.layer()));
+
+        let store = Arc::new(InMemoryStore::new());
+        let worker = WebhookWorker::new_with_config(
+            store,
+            &CallbacksConfig::default(),
+            CircuitBreakerConfig::webhook(),
+        )
+        .unwrap();
+
+        let enqueued_by = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let now = Utc::now();
+        let webhook = crate::storage::PendingWebhook {
+            id: "webhook-1".to_string(),
+            tenant_id: "default".to_string(),
+            url: format!("http://{}/hook", addr),
+            payload: serde_json::json!({}),
+            payload_bytes: b"{}".to_vec(),
+            headers: HashMap::from([(
+                otel::TRACEPARENT_HEADER.to_string(),
+                enqueued_by.to_string(),
+            )]),
+            event_type: "payment.succeeded".to_string(),
+            status: crate::storage::WebhookStatus::Pending,
+            attempts: 0,
+            max_attempts: 3,
+            last_error: None,
+            last_attempt_at: None,
+            next_attempt_at: None,
+            created_at: now,
+            completed_at: None,
+        };
+
+        worker
+            .deliver_webhook(
+                &webhook.id,
+                &webhook.url,
+                &webhook.payload_bytes,
+                &webhook.headers,
+            )
+            .instrument(WebhookWorker::<InMemoryStore>::delivery_span(&webhook))
+            .await
+            .unwrap();
+
+        let traceparent = received.lock().clone().expect("traceparent header");
+        assert!(traceparent.starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+        assert_ne!(traceparent, enqueued_by);
+    }
+
     #[tokio::test]
     async fn test_read_limited_body_stream_truncates() {
         let app = Router::new().route(
@@ -758,6 +919,40 @@ mod tests {
         assert!(ok_result.is_ok(), "expected success on different host");
     }
 
+    #[test]
+    fn test_settings_handle_reconfigures_running_worker() {
+        let store = Arc::new(InMemoryStore::new());
+        let worker = WebhookWorker::new(store).unwrap();
+        let breaker = worker.circuit_breaker_for(
+            "https://example.com/webhook",
+            &CircuitBreakerConfig::webhook(),
+        );
+
+        let callbacks = CallbacksConfig {
+            dlq_enabled: false,
+            retry: RetryConfig {
+                initial_interval: Duration::from_secs(9),
+                ..CallbacksConfig::default().retry
+            },
+            ..Default::default()
+        };
+        let mut cb_config = CircuitBreakerConfig::webhook();
+        cb_config.consecutive_failures = 1;
+        worker
+            .settings_handle()
+            .reconfigure(&callbacks, cb_config)
+            .unwrap();
+
+        let settings = worker.settings();
+        assert!(!settings.dlq_enabled);
+        assert_eq!(settings.retry.initial_interval, Duration::from_secs(9));
+        assert_eq!(settings.circuit_breaker_config.consecutive_failures, 1);
+
+        // Existing per-host breakers pick up the new thresholds too
+        breaker.record_failure();
+        assert!(!breaker.allow());
+    }
+
     #[test]
     fn test_should_refresh_backlog_metric_is_throttled() {
         let store = Arc::new(InMemoryStore::new());
@@ -766,4 +961,65 @@ mod tests {
         assert!(worker.should_refresh_backlog_metric());
         assert!(!worker.should_refresh_backlog_metric());
     }
+
+    #[tokio::test]
+    async fn test_cold_archive_receives_webhooks_past_worker_retention() {
+        use crate::services::{ColdArchiveService, InMemoryObjectStore};
+        use crate::workers::CleanupWorker;
+
+        let store = Arc::new(InMemoryStore::new());
+        let completed = Utc::now() - chrono::Duration::days(40);
+        store
+            .enqueue_webhook(crate::storage::PendingWebhook {
+                id: "wh-old".to_string(),
+                tenant_id: "default".to_string(),
+                url: "https://example.com/hook".to_string(),
+                payload: serde_json::json!({}),
+                payload_bytes: b"{}".to_vec(),
+                headers: HashMap::new(),
+                event_type: "payment.succeeded".to_string(),
+                status: crate::storage::WebhookStatus::Success,
+                attempts: 1,
+                max_attempts: 3,
+                last_error: None,
+                last_attempt_at: Some(completed),
+                next_attempt_at: None,
+                created_at: completed,
+                completed_at: Some(completed),
+            })
+            .await
+            .unwrap();
+
+        let objects = Arc::new(InMemoryObjectStore::new(vec!["default".to_string()]));
+        let archive = Arc::new(ColdArchiveService::new(store.clone(), objects.clone(), 100));
+        let (cleanup, cleanup_handle) = CleanupWorker::with_shutdown(
+            store.clone(),
+            Duration::from_secs(30 * 24 * 3600),
+            Duration::from_secs(3600),
+            true,
+        );
+        let cleanup = cleanup.with_cold_archive(archive);
+        let (mut webhooks, webhook_handle) = WebhookWorker::with_shutdown(store.clone()).unwrap();
+        webhooks.cleanup_interval_cycles = 1;
+        let webhooks = webhooks
+            .with_poll_interval(Duration::from_millis(10))
+            .with_cold_archive();
+
+        // The webhook worker gets to clean up first; the archiver's first
+        // pass follows the startup sweep
+        let webhook_handle =
+            webhook_handle.with_join_handle(tokio::spawn(async move { webhooks.run().await }));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let cleanup_handle =
+            cleanup_handle.with_join_handle(tokio::spawn(async move { cleanup.run().await }));
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        webhook_handle.shutdown_and_wait().await;
+        cleanup_handle.shutdown_and_wait().await;
+
+        assert!(store.get_webhook("wh-old").await.unwrap().is_none());
+        assert!(objects
+            .keys("default")
+            .iter()
+            .any(|key| key.contains("/webhooks-")));
+    }
 }
@@ -9,21 +9,22 @@ use solana_sdk::hash::Hash;
 use solana_sdk::instruction::Instruction;
 use solana_sdk::message::Message;
 use solana_sdk::pubkey::Pubkey;
-use solana_sdk::signature::{Keypair, Signature};
-use solana_sdk::signer::Signer;
+use solana_sdk::signature::Signature;
 use solana_sdk::transaction::VersionedTransaction;
 use thiserror::Error;
 use tokio::sync::RwLock;
 use tracing::{debug, info};
 
-use crate::config::X402Config;
+use crate::config::secrets::{split_wallets, SECRET_SERVER_WALLETS};
+use crate::config::{SecretStore, SignerConfig, X402Config};
 use crate::constants::{
     ATA_PROPAGATION_INITIAL_BACKOFF, ATA_PROPAGATION_MAX_BACKOFF, MAX_ATA_PROPAGATION_ATTEMPTS,
     TX_CONFIRM_TIMEOUT,
 };
 
+use super::signer::{load_server_wallets, ServerWallet};
 use super::utils::{rpc_attempt_with_timeout, RpcAttemptError};
-use super::verifier::{parse_commitment, ServerWallet};
+use super::verifier::parse_commitment;
 
 /// Cached blockhash entry with expiration.
 ///
@@ -53,6 +54,24 @@ pub enum GaslessError {
     SendFailed(String),
     #[error("timeout")]
     Timeout,
+    #[error("signing failed: {0}")]
+    SigningFailed(String),
+}
+
+/// Compute budget added to server-built transactions (`x402.compute_unit_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComputeBudget {
+    pub unit_limit: u32,
+    pub unit_price: u64,
+}
+
+impl ComputeBudget {
+    pub fn from_config(config: &X402Config) -> Self {
+        Self {
+            unit_limit: config.compute_unit_limit,
+            unit_price: config.compute_unit_price_micro_lamports,
+        }
+    }
 }
 
 /// Build and submit gasless transactions for Solana.
@@ -60,13 +79,24 @@ pub enum GaslessError {
 /// but individual fields may not be accessed via public methods currently.
 pub struct GaslessTransactionBuilder {
     rpc_client: Arc<RpcClient>,
-    server_wallets: Vec<ServerWallet>,
-    compute_unit_limit: u32,
-    compute_unit_price: u64,
+    server_wallets: parking_lot::RwLock<LoadedWallets>,
+    /// Secret provider supplying rotated wallets; re-parsed when its version changes
+    secrets: Option<Arc<SecretStore>>,
+    /// Signer settings used to rebuild rotated wallets
+    signer_config: SignerConfig,
+    /// Replaced on config reload; read once per transaction
+    compute_budget: parking_lot::RwLock<ComputeBudget>,
     /// Cached blockhash to avoid fetching more than once per second (like Go does)
     blockhash_cache: Arc<RwLock<Option<CachedBlockhashEntry>>>,
 }
 
+/// Parsed server wallets and the secret snapshot version they came from
+/// (0 = config only).
+struct LoadedWallets {
+    version: u64,
+    wallets: Arc<Vec<ServerWallet>>,
+}
+
 /// Memo Program v1 (legacy) pubkey, parsed once at startup.
 static MEMO_V1_PUBKEY: std::sync::LazyLock<Pubkey> = std::sync::LazyLock::new(|| {
     Pubkey::from_str("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo").expect("valid memo v1 pubkey")
@@ -118,6 +148,14 @@ pub(crate) fn validate_transaction_programs(tx: &VersionedTransaction) -> Result
     Ok(())
 }
 
+fn parse_wallets(
+    signer_config: &SignerConfig,
+    wallet_strs: &[String],
+) -> Result<Vec<ServerWallet>, GaslessError> {
+    load_server_wallets(signer_config, wallet_strs)
+        .map_err(|e| GaslessError::InvalidPubkey(e.to_string()))
+}
+
 impl GaslessTransactionBuilder {
     pub fn new(config: &X402Config) -> Result<Self, GaslessError> {
         if config.rpc_url.is_empty() {
@@ -131,28 +169,74 @@ impl GaslessTransactionBuilder {
             commitment,
         ));
 
-        let mut server_wallets = Vec::new();
-        for wallet_str in &config.server_wallets {
-            let wallet = ServerWallet::from_string(wallet_str)
-                .map_err(|e| GaslessError::InvalidPubkey(e.to_string()))?;
-            server_wallets.push(wallet);
-        }
+        let server_wallets = parse_wallets(&config.signer, &config.server_wallets)?;
 
         Ok(Self {
             rpc_client,
-            server_wallets,
-            compute_unit_limit: config.compute_unit_limit,
-            compute_unit_price: config.compute_unit_price_micro_lamports,
+            server_wallets: parking_lot::RwLock::new(LoadedWallets {
+                version: 0,
+                wallets: Arc::new(server_wallets),
+            }),
+            secrets: None,
+            signer_config: config.signer.clone(),
+            compute_budget: parking_lot::RwLock::new(ComputeBudget::from_config(config)),
             blockhash_cache: Arc::new(RwLock::new(None)),
         })
     }
 
+    /// Use a new compute budget for transactions built from now on
+    pub fn set_compute_budget(&self, budget: ComputeBudget) {
+        *self.compute_budget.write() = budget;
+    }
+
+    /// Take server wallets from a hot-reloaded secret provider when it has them
+    pub fn with_secrets(mut self, secrets: Arc<SecretStore>) -> Self {
+        self.secrets = Some(secrets);
+        self
+    }
+
+    /// Current server wallets, re-parsed from the secret provider after a change.
+    ///
+    /// An unparseable rotated value is logged and the previous wallets are kept.
+    fn wallets(&self) -> Arc<Vec<ServerWallet>> {
+        let Some(ref secrets) = self.secrets else {
+            return self.server_wallets.read().wallets.clone();
+        };
+        let snapshot = secrets.snapshot();
+        {
+            let loaded = self.server_wallets.read();
+            if loaded.version == snapshot.version() {
+                return loaded.wallets.clone();
+            }
+        }
+
+        let mut loaded = self.server_wallets.write();
+        if loaded.version != snapshot.version() {
+            if let Some(value) = snapshot.get(SECRET_SERVER_WALLETS) {
+                match parse_wallets(&self.signer_config, &split_wallets(value)) {
+                    Ok(wallets) => {
+                        info!(
+                            count = wallets.len(),
+                            "Server wallets reloaded from secrets"
+                        );
+                        loaded.wallets = Arc::new(wallets);
+                    }
+                    Err(e) => {
+                        tracing::error!(error = %e, "Invalid rotated server wallets; keeping previous");
+                    }
+                }
+            }
+            loaded.version = snapshot.version();
+        }
+        loaded.wallets.clone()
+    }
+
     /// Create Associated Token Account if it doesn't exist
     pub async fn create_ata_if_needed(
         &self,
         owner: &Pubkey,
         mint: &Pubkey,
-        fee_payer: &Keypair,
+        fee_payer: &ServerWallet,
     ) -> Result<Pubkey, GaslessError> {
         const RPC_CALL_TIMEOUT: Duration = Duration::from_secs(2);
 
@@ -178,7 +262,7 @@ impl GaslessTransactionBuilder {
 
         // Build create ATA instruction
         let create_ix = spl_associated_token_account::instruction::create_associated_token_account(
-            &fee_payer.pubkey(),
+            &fee_payer.pubkey,
             owner,
             mint,
             &spl_token::id(),
@@ -199,16 +283,15 @@ impl GaslessTransactionBuilder {
         let mut instructions = self.build_compute_budget_instructions();
         instructions.push(create_ix);
 
+        let tx = fee_payer
+            .sign_transaction(&instructions, &[], recent_blockhash)
+            .await
+            .map_err(|e| GaslessError::SigningFailed(e.to_string()))?;
+
         // Send transaction
         let sig = match rpc_attempt_with_timeout(
             TX_CONFIRM_TIMEOUT,
-            self.rpc_client.send_and_confirm_transaction(
-                &solana_sdk::transaction::Transaction::new(
-                    &[fee_payer],
-                    Message::new(&instructions, Some(&fee_payer.pubkey())),
-                    recent_blockhash,
-                ),
-            ),
+            self.rpc_client.send_and_confirm_transaction(&tx),
         )
         .await
         {
@@ -254,9 +337,10 @@ impl GaslessTransactionBuilder {
 
     /// Build compute budget instructions
     fn build_compute_budget_instructions(&self) -> Vec<Instruction> {
+        let budget = *self.compute_budget.read();
         vec![
-            ComputeBudgetInstruction::set_compute_unit_limit(self.compute_unit_limit),
-            ComputeBudgetInstruction::set_compute_unit_price(self.compute_unit_price),
+            ComputeBudgetInstruction::set_compute_unit_limit(budget.unit_limit),
+            ComputeBudgetInstruction::set_compute_unit_price(budget.unit_price),
         ]
     }
 
@@ -329,7 +413,7 @@ impl GaslessTransactionBuilder {
     /// - Associated Token Account Program (for ATA creation)
     ///
     /// Any other program would be rejected to prevent malicious transactions.
-    pub fn co_sign_transaction(
+    pub async fn co_sign_transaction(
         &self,
         tx_base64: &str,
         fee_payer_pubkey: &str,
@@ -337,8 +421,8 @@ impl GaslessTransactionBuilder {
         let fee_payer = Pubkey::from_str(fee_payer_pubkey)
             .map_err(|e| GaslessError::InvalidPubkey(e.to_string()))?;
 
-        let server_wallet = self
-            .server_wallets
+        let wallets = self.wallets();
+        let server_wallet = wallets
             .iter()
             .find(|w| w.pubkey == fee_payer)
             .ok_or(GaslessError::NoServerWallet)?;
@@ -372,8 +456,10 @@ impl GaslessTransactionBuilder {
         }
 
         // Sign with server wallet
-        let message_data = tx.message.serialize();
-        let sig = server_wallet.keypair.sign_message(&message_data);
+        let sig = server_wallet
+            .sign_message(&tx.message)
+            .await
+            .map_err(|e| GaslessError::SigningFailed(e.to_string()))?;
 
         // BUG-008: Replace first signature (fee payer) - error if empty
         if tx.signatures.is_empty() {
@@ -392,7 +478,7 @@ impl GaslessTransactionBuilder {
 
     /// Get available fee payers (server wallet pubkeys)
     pub fn get_fee_payers(&self) -> Vec<String> {
-        self.server_wallets
+        self.wallets()
             .iter()
             .map(|w| w.pubkey.to_string())
             .collect()
@@ -400,7 +486,7 @@ impl GaslessTransactionBuilder {
 
     /// Get first fee payer
     pub fn get_default_fee_payer(&self) -> Option<String> {
-        self.server_wallets.first().map(|w| w.pubkey.to_string())
+        self.wallets().first().map(|w| w.pubkey.to_string())
     }
 
     /// Execute a refund transaction (server-signed transfer to recipient)
@@ -412,10 +498,8 @@ impl GaslessTransactionBuilder {
         amount: u64,
         decimals: u8,
     ) -> Result<Signature, GaslessError> {
-        let server_wallet = self
-            .server_wallets
-            .first()
-            .ok_or(GaslessError::NoServerWallet)?;
+        let wallets = self.wallets();
+        let server_wallet = wallets.first().ok_or(GaslessError::NoServerWallet)?;
 
         // Get server's token account (source)
         let source_ata =
@@ -426,7 +510,7 @@ impl GaslessTransactionBuilder {
             spl_associated_token_account::get_associated_token_address(recipient_wallet, mint);
 
         // Ensure recipient ATA exists (create if needed)
-        self.create_ata_if_needed(recipient_wallet, mint, &server_wallet.keypair)
+        self.create_ata_if_needed(recipient_wallet, mint, server_wallet)
             .await?;
 
         // Build transfer instruction
@@ -457,12 +541,10 @@ impl GaslessTransactionBuilder {
         let mut instructions = self.build_compute_budget_instructions();
         instructions.push(transfer_ix);
 
-        let message = Message::new(&instructions, Some(&server_wallet.pubkey));
-        let tx = solana_sdk::transaction::Transaction::new(
-            &[&server_wallet.keypair],
-            message,
-            recent_blockhash,
-        );
+        let tx = server_wallet
+            .sign_transaction(&instructions, &[], recent_blockhash)
+            .await
+            .map_err(|e| GaslessError::SigningFailed(e.to_string()))?;
 
         // Send and confirm
         let signature = match rpc_attempt_with_timeout(
@@ -488,19 +570,25 @@ impl GaslessTransactionBuilder {
 
     /// Build an unsigned gasless transaction for user payment
     /// Returns transaction data that the user must sign
+    ///
+    /// `transfers` holds one `(recipient_ata, amount)` pair per payment leg; split
+    /// payments get one `TransferChecked` per leg in the order given.
     pub async fn build_payment_transaction(
         &self,
         user_wallet: &Pubkey,
-        recipient_ata: &Pubkey,
+        transfers: &[(Pubkey, u64)],
         mint: &Pubkey,
-        amount: u64,
         decimals: u8,
         memo: Option<&str>,
     ) -> Result<GaslessTxData, GaslessError> {
-        let server_wallet = self
-            .server_wallets
-            .first()
-            .ok_or(GaslessError::NoServerWallet)?;
+        let wallets = self.wallets();
+        let server_wallet = wallets.first().ok_or(GaslessError::NoServerWallet)?;
+
+        if transfers.is_empty() {
+            return Err(GaslessError::SendFailed(
+                "payment requires at least one transfer".into(),
+            ));
+        }
 
         // Get user's token account (source)
         let source_ata =
@@ -512,19 +600,21 @@ impl GaslessTransactionBuilder {
         // Build instructions
         let mut instructions = self.build_compute_budget_instructions();
 
-        // Transfer instruction (user is authority, server is fee payer)
-        let transfer_ix = spl_token::instruction::transfer_checked(
-            &spl_token::id(),
-            &source_ata,
-            mint,
-            recipient_ata,
-            user_wallet,
-            &[],
-            amount,
-            decimals,
-        )
-        .map_err(|e| GaslessError::SendFailed(format!("build transfer ix: {}", e)))?;
-        instructions.push(transfer_ix);
+        // Transfer instructions (user is authority, server is fee payer)
+        for (recipient_ata, amount) in transfers {
+            let transfer_ix = spl_token::instruction::transfer_checked(
+                &spl_token::id(),
+                &source_ata,
+                mint,
+                recipient_ata,
+                user_wallet,
+                &[],
+                *amount,
+                decimals,
+            )
+            .map_err(|e| GaslessError::SendFailed(format!("build transfer ix: {}", e)))?;
+            instructions.push(transfer_ix);
+        }
 
         // Optional memo
         if let Some(memo_text) = memo {
@@ -581,9 +671,8 @@ pub struct GaslessTxData {
 impl std::fmt::Debug for GaslessTransactionBuilder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("GaslessTransactionBuilder")
-            .field("wallet_count", &self.server_wallets.len())
-            .field("compute_unit_limit", &self.compute_unit_limit)
-            .field("compute_unit_price", &self.compute_unit_price)
+            .field("wallet_count", &self.server_wallets.read().wallets.len())
+            .field("compute_budget", &*self.compute_budget.read())
             .finish()
     }
 }
@@ -1,11 +1,15 @@
 pub mod gasless;
+pub mod signer;
 pub mod transaction_queue;
 pub mod utils;
 pub mod verifier;
 pub mod wallet_health;
 pub mod ws_confirmation;
 
-pub use gasless::{GaslessError, GaslessTransactionBuilder};
+pub use gasless::{ComputeBudget, GaslessError, GaslessTransactionBuilder};
+pub use signer::{
+    LocalKeypairSigner, RemoteSigner, ServerWallet, SignerError, SigningPolicy, TransactionSigner,
+};
 pub use transaction_queue::{TransactionQueue, TxQueueError};
 pub use utils::{
     amount_sufficient, derive_ata, derive_ata_safe, generate_cart_id, generate_event_id,
@@ -13,6 +17,6 @@ pub use utils::{
     generate_webhook_id, interpolate_memo, is_rate_limit_error, parse_payment_proof,
     validate_signature, validate_wallet_address,
 };
-pub use verifier::{ServerWallet, SolanaVerifier, Verifier, VerifierError};
+pub use verifier::{SolanaVerifier, Verifier, VerifierError};
 pub use wallet_health::{WalletHealth, WalletHealthChecker, WalletStatus};
 pub use ws_confirmation::{ConfirmationResult, WsConfirmConfig, WsConfirmationService};
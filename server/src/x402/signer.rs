@@ -0,0 +1,670 @@
+//! Server wallet transaction signing.
+//!
+//! Every signature made with a server wallet goes through a [`TransactionSigner`]:
+//! either an in-process keypair or an external signing service reached over
+//! HTTP with mTLS, so the private keys never have to live in this process.
+//! Each wallet also carries a [`SigningPolicy`] that is checked before the
+//! signer is asked for anything.
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{Deserialize, Serialize};
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::{Message, VersionedMessage};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature};
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
+use thiserror::Error;
+
+use crate::config::{SignerConfig, SignerMode, SigningPolicyConfig};
+
+/// Token-22 program ID (amount checks apply to both token programs).
+const TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+#[derive(Debug, Error)]
+pub enum SignerError {
+    #[error("signing policy violation: {0}")]
+    PolicyViolation(String),
+    #[error("invalid signer key: {0}")]
+    InvalidKey(String),
+    #[error("remote signer error: {0}")]
+    Remote(String),
+    #[error("remote signer returned an invalid signature")]
+    InvalidSignature,
+    #[error("signer configuration error: {0}")]
+    Config(String),
+}
+
+/// Produces ed25519 signatures over serialized Solana messages for one public key.
+#[async_trait]
+pub trait TransactionSigner: Send + Sync {
+    /// Public key whose signature this signer produces
+    fn pubkey(&self) -> Pubkey;
+
+    /// Short name for logs (`local`, `remote`)
+    fn kind(&self) -> &'static str;
+
+    /// Sign serialized message bytes
+    async fn sign(&self, message: &[u8]) -> Result<Signature, SignerError>;
+}
+
+/// Signs with a keypair held in process memory.
+pub struct LocalKeypairSigner {
+    keypair: Keypair,
+}
+
+impl LocalKeypairSigner {
+    pub fn new(keypair: Keypair) -> Self {
+        Self { keypair }
+    }
+}
+
+#[async_trait]
+impl TransactionSigner for LocalKeypairSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.keypair.pubkey()
+    }
+
+    fn kind(&self) -> &'static str {
+        "local"
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        Ok(self.keypair.sign_message(message))
+    }
+}
+
+/// Asks an external signing service to sign on behalf of `pubkey`.
+///
+/// Protocol: `POST {remote_url}/v1/sign` with `{"pubkey", "message"}` (message
+/// base64-encoded) returning `{"signature"}` in base58. The returned signature
+/// is verified against `pubkey` before use.
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    url: String,
+    pubkey: Pubkey,
+}
+
+#[derive(Serialize)]
+struct RemoteSignRequest {
+    pubkey: String,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct RemoteSignResponse {
+    signature: String,
+}
+
+impl RemoteSigner {
+    pub fn new(client: reqwest::Client, base_url: &str, pubkey: Pubkey) -> Self {
+        Self {
+            client,
+            url: format!("{}/v1/sign", base_url.trim_end_matches('/')),
+            pubkey,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionSigner for RemoteSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    fn kind(&self) -> &'static str {
+        "remote"
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let request = RemoteSignRequest {
+            pubkey: self.pubkey.to_string(),
+            message: BASE64.encode(message),
+        };
+        let response = self
+            .client
+            .post(&self.url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| SignerError::Remote(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(SignerError::Remote(format!(
+                "{} returned {}",
+                self.url, status
+            )));
+        }
+
+        let body: RemoteSignResponse = response
+            .json()
+            .await
+            .map_err(|e| SignerError::Remote(format!("invalid response: {e}")))?;
+        decode_remote_signature(&self.pubkey, message, &body.signature)
+    }
+}
+
+/// Parse a base58 signature from the signing service and check it is a valid
+/// signature by `pubkey` over `message`.
+fn decode_remote_signature(
+    pubkey: &Pubkey,
+    message: &[u8],
+    encoded: &str,
+) -> Result<Signature, SignerError> {
+    let signature =
+        Signature::from_str(encoded.trim()).map_err(|_| SignerError::InvalidSignature)?;
+    if !signature.verify(pubkey.as_ref(), message) {
+        return Err(SignerError::InvalidSignature);
+    }
+    Ok(signature)
+}
+
+/// Build the HTTP client for the remote signer, with a client certificate
+/// (mTLS) and private CA when configured. Plain HTTP is refused unless
+/// `allow_insecure` is set.
+pub fn build_remote_client(cfg: &SignerConfig) -> Result<reqwest::Client, SignerError> {
+    let read = |path: &str| {
+        std::fs::read(path).map_err(|e| SignerError::Config(format!("read {path}: {e}")))
+    };
+
+    let mut builder = reqwest::Client::builder()
+        .timeout(cfg.timeout)
+        .https_only(!cfg.allow_insecure);
+    if let (Some(cert_path), Some(key_path)) = (&cfg.client_cert_path, &cfg.client_key_path) {
+        let mut pem = read(cert_path)?;
+        pem.push(b'\n');
+        pem.extend(read(key_path)?);
+        let identity = reqwest::Identity::from_pem(&pem)
+            .map_err(|e| SignerError::Config(format!("client certificate: {e}")))?;
+        builder = builder.identity(identity);
+    }
+    if let Some(ca_path) = &cfg.ca_cert_path {
+        let ca = reqwest::Certificate::from_pem(&read(ca_path)?)
+            .map_err(|e| SignerError::Config(format!("CA certificate: {e}")))?;
+        builder = builder.add_root_certificate(ca);
+    }
+    builder
+        .build()
+        .map_err(|e| SignerError::Config(format!("HTTP client: {e}")))
+}
+
+/// Limits a server wallet enforces before signing.
+#[derive(Debug, Clone, Default)]
+pub struct SigningPolicy {
+    /// Programs a signed message may invoke (empty = no restriction)
+    allowed_programs: Vec<Pubkey>,
+    /// Largest raw amount for one SPL Token / Token-22 transfer, mint or burn
+    max_token_amount: Option<u64>,
+}
+
+impl SigningPolicy {
+    pub fn new(allowed_programs: Vec<Pubkey>, max_token_amount: Option<u64>) -> Self {
+        Self {
+            allowed_programs,
+            max_token_amount,
+        }
+    }
+
+    pub fn from_config(cfg: &SigningPolicyConfig) -> Result<Self, SignerError> {
+        let allowed_programs = cfg
+            .allowed_programs
+            .iter()
+            .map(|p| {
+                Pubkey::from_str(p.trim())
+                    .map_err(|_| SignerError::Config(format!("invalid allowed program: {p}")))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(allowed_programs, cfg.max_token_amount))
+    }
+
+    /// Check every instruction in `message` against this policy.
+    pub fn check(&self, message: &VersionedMessage) -> Result<(), SignerError> {
+        let account_keys = message.static_account_keys();
+        for ix in message.instructions() {
+            let program_id = account_keys
+                .get(ix.program_id_index as usize)
+                .ok_or_else(|| {
+                    SignerError::PolicyViolation("invalid program_id_index".to_string())
+                })?;
+
+            if !self.allowed_programs.is_empty() && !self.allowed_programs.contains(program_id) {
+                return Err(SignerError::PolicyViolation(format!(
+                    "program {program_id} not allowed"
+                )));
+            }
+
+            if let Some(max) = self.max_token_amount {
+                let is_token_program =
+                    *program_id == spl_token::id() || *program_id == TOKEN_2022_PROGRAM_ID;
+                if let (true, Some(amount)) = (is_token_program, token_instruction_amount(&ix.data))
+                {
+                    if amount > max {
+                        return Err(SignerError::PolicyViolation(format!(
+                            "token amount {amount} exceeds limit {max}"
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Raw amount of a token Transfer (3), MintTo (7), Burn (8), TransferChecked (12),
+/// MintToChecked (14) or BurnChecked (15) instruction; `None` for anything else.
+fn token_instruction_amount(data: &[u8]) -> Option<u64> {
+    match data.first()? {
+        3 | 7 | 8 | 12 | 14 | 15 => {
+            let bytes: [u8; 8] = data.get(1..9)?.try_into().ok()?;
+            Some(u64::from_le_bytes(bytes))
+        }
+        _ => None,
+    }
+}
+
+/// A server wallet: its public key, the signer that holds its key, and the
+/// policy checked before every signature.
+pub struct ServerWallet {
+    pub pubkey: Pubkey,
+    signer: Arc<dyn TransactionSigner>,
+    policy: SigningPolicy,
+}
+
+impl ServerWallet {
+    pub fn new(signer: Arc<dyn TransactionSigner>, policy: SigningPolicy) -> Self {
+        Self {
+            pubkey: signer.pubkey(),
+            signer,
+            policy,
+        }
+    }
+
+    /// Wallet signed in process with no policy restrictions
+    pub fn from_keypair(keypair: Keypair) -> Self {
+        Self::new(
+            Arc::new(LocalKeypairSigner::new(keypair)),
+            SigningPolicy::default(),
+        )
+    }
+
+    pub fn from_base58(key: &str) -> Result<Self, SignerError> {
+        let bytes = bs58::decode(key)
+            .into_vec()
+            .map_err(|e| SignerError::InvalidKey(format!("invalid base58 key: {}", e)))?;
+
+        let keypair = Keypair::try_from(bytes.as_slice())
+            .map_err(|e| SignerError::InvalidKey(format!("invalid keypair: {}", e)))?;
+
+        Ok(Self::from_keypair(keypair))
+    }
+
+    pub fn from_json_array(json: &str) -> Result<Self, SignerError> {
+        let bytes: Vec<u8> = serde_json::from_str(json)
+            .map_err(|e| SignerError::InvalidKey(format!("invalid json array: {}", e)))?;
+
+        if bytes.len() != 64 {
+            return Err(SignerError::InvalidKey("keypair must be 64 bytes".into()));
+        }
+
+        let keypair = Keypair::try_from(bytes.as_slice())
+            .map_err(|e| SignerError::InvalidKey(format!("invalid keypair: {}", e)))?;
+
+        Ok(Self::from_keypair(keypair))
+    }
+
+    pub fn from_string(s: &str) -> Result<Self, SignerError> {
+        let trimmed = s.trim();
+        if trimmed.starts_with('[') {
+            Self::from_json_array(trimmed)
+        } else {
+            Self::from_base58(trimmed)
+        }
+    }
+
+    pub fn with_policy(mut self, policy: SigningPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn signer_kind(&self) -> &'static str {
+        self.signer.kind()
+    }
+
+    /// Check `message` against the wallet policy, then sign it.
+    pub async fn sign_message(&self, message: &VersionedMessage) -> Result<Signature, SignerError> {
+        self.policy.check(message)?;
+        self.signer.sign(&message.serialize()).await
+    }
+
+    /// Build a legacy transaction paid for by this wallet and sign it.
+    ///
+    /// `extra_signers` are ephemeral keypairs (e.g. a new mint account) that
+    /// must co-sign; they are applied before the wallet signature.
+    pub async fn sign_transaction(
+        &self,
+        instructions: &[Instruction],
+        extra_signers: &[&Keypair],
+        blockhash: Hash,
+    ) -> Result<Transaction, SignerError> {
+        let message = Message::new_with_blockhash(instructions, Some(&self.pubkey), &blockhash);
+        let mut tx = Transaction::new_unsigned(message);
+        if !extra_signers.is_empty() {
+            tx.try_partial_sign(extra_signers, blockhash)
+                .map_err(|e| SignerError::InvalidKey(e.to_string()))?;
+        }
+
+        // The fee payer is always the first signer
+        let signature = self
+            .sign_message(&VersionedMessage::Legacy(tx.message.clone()))
+            .await?;
+        tx.signatures[0] = signature;
+        Ok(tx)
+    }
+}
+
+/// Build server wallets from `x402.server_wallets` entries.
+///
+/// In local mode each entry is a private key; in remote mode each entry is a
+/// base58 public key served by the signing service. Per-wallet policies in
+/// `signer.policies` override the default `signer.policy`.
+pub fn load_server_wallets(
+    cfg: &SignerConfig,
+    wallets: &[String],
+) -> Result<Vec<ServerWallet>, SignerError> {
+    let client = match cfg.mode {
+        SignerMode::Local => None,
+        SignerMode::Remote => Some(build_remote_client(cfg)?),
+    };
+    let default_policy = SigningPolicy::from_config(&cfg.policy)?;
+
+    wallets
+        .iter()
+        .map(|entry| {
+            let wallet = match &client {
+                None => ServerWallet::from_string(entry)?,
+                Some(client) => {
+                    let pubkey = Pubkey::from_str(entry.trim()).map_err(|_| {
+                        SignerError::InvalidKey(
+                            "remote signer wallets must be base58 public keys".into(),
+                        )
+                    })?;
+                    ServerWallet::new(
+                        Arc::new(RemoteSigner::new(client.clone(), &cfg.remote_url, pubkey)),
+                        SigningPolicy::default(),
+                    )
+                }
+            };
+            let policy = match cfg.policies.get(&wallet.pubkey.to_string()) {
+                Some(policy) => SigningPolicy::from_config(policy)?,
+                None => default_policy.clone(),
+            };
+            Ok(wallet.with_policy(policy))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Local stand-in for the signing service that counts requests.
+    struct CountingSigner {
+        inner: LocalKeypairSigner,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl TransactionSigner for CountingSigner {
+        fn pubkey(&self) -> Pubkey {
+            self.inner.pubkey()
+        }
+
+        fn kind(&self) -> &'static str {
+            "counting"
+        }
+
+        async fn sign(&self, message: &[u8]) -> Result<Signature, SignerError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.inner.sign(message).await
+        }
+    }
+
+    fn transfer_message(authority: &Pubkey, amount: u64) -> VersionedMessage {
+        let ix = spl_token::instruction::transfer_checked(
+            &spl_token::id(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            authority,
+            &[],
+            amount,
+            6,
+        )
+        .unwrap();
+        VersionedMessage::Legacy(Message::new(&[ix], Some(authority)))
+    }
+
+    #[tokio::test]
+    async fn test_policy_rejects_before_signer_is_called() {
+        let signer = Arc::new(CountingSigner {
+            inner: LocalKeypairSigner::new(Keypair::new()),
+            calls: AtomicUsize::new(0),
+        });
+        let wallet = ServerWallet::new(signer.clone(), SigningPolicy::new(vec![], Some(1_000)));
+
+        let over = transfer_message(&wallet.pubkey, 1_001);
+        let err = wallet.sign_message(&over).await.unwrap_err();
+        assert!(matches!(err, SignerError::PolicyViolation(_)));
+        assert_eq!(signer.calls.load(Ordering::SeqCst), 0);
+
+        let within = transfer_message(&wallet.pubkey, 1_000);
+        let sig = wallet.sign_message(&within).await.unwrap();
+        assert!(sig.verify(wallet.pubkey.as_ref(), &within.serialize()));
+        assert_eq!(signer.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_policy_allowed_programs() {
+        let authority = Pubkey::new_unique();
+        let message = transfer_message(&authority, 5);
+
+        let memo_only = SigningPolicy::new(vec![spl_memo::id()], None);
+        assert!(matches!(
+            memo_only.check(&message),
+            Err(SignerError::PolicyViolation(_))
+        ));
+
+        let token = SigningPolicy::new(vec![spl_token::id()], None);
+        assert!(token.check(&message).is_ok());
+        assert!(SigningPolicy::default().check(&message).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_sign_transaction_with_extra_signer() {
+        let wallet = ServerWallet::from_keypair(Keypair::new());
+        let mint = Keypair::new();
+        let ix = solana_sdk::instruction::Instruction::new_with_bytes(
+            spl_memo::id(),
+            b"hello",
+            vec![
+                solana_sdk::instruction::AccountMeta::new(wallet.pubkey, true),
+                solana_sdk::instruction::AccountMeta::new(mint.pubkey(), true),
+            ],
+        );
+
+        let tx = wallet
+            .sign_transaction(&[ix], &[&mint], Hash::new_unique())
+            .await
+            .unwrap();
+        assert_eq!(tx.message.account_keys[0], wallet.pubkey);
+        assert!(tx.verify().is_ok());
+    }
+
+    #[test]
+    fn test_decode_remote_signature_verifies_signer() {
+        let keypair = Keypair::new();
+        let message = b"message bytes";
+        let sig = keypair.sign_message(message);
+
+        let decoded = decode_remote_signature(&keypair.pubkey(), message, &sig.to_string());
+        assert_eq!(decoded.unwrap(), sig);
+
+        let other = Pubkey::new_unique();
+        assert!(matches!(
+            decode_remote_signature(&other, message, &sig.to_string()),
+            Err(SignerError::InvalidSignature)
+        ));
+        assert!(matches!(
+            decode_remote_signature(&keypair.pubkey(), message, "not-a-signature"),
+            Err(SignerError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn test_load_server_wallets_applies_per_wallet_policy() {
+        let keypair = Keypair::new();
+        let pubkey = keypair.pubkey();
+        let mut cfg = SignerConfig::default();
+        cfg.policy.max_token_amount = Some(10);
+        cfg.policies.insert(
+            pubkey.to_string(),
+            SigningPolicyConfig {
+                allowed_programs: vec![],
+                max_token_amount: Some(1_000),
+            },
+        );
+        let other = Keypair::new();
+        let wallets = load_server_wallets(
+            &cfg,
+            &[keypair.to_base58_string(), other.to_base58_string()],
+        )
+        .unwrap();
+
+        assert_eq!(wallets[0].pubkey, pubkey);
+        assert_eq!(wallets[0].signer_kind(), "local");
+        assert!(wallets[0]
+            .policy
+            .check(&transfer_message(&pubkey, 500))
+            .is_ok());
+        assert!(wallets[1]
+            .policy
+            .check(&transfer_message(&other.pubkey(), 500))
+            .is_err());
+    }
+
+    #[test]
+    fn test_remote_mode_takes_public_keys() {
+        let pubkey = Pubkey::new_unique();
+        let cfg = SignerConfig {
+            mode: SignerMode::Remote,
+            remote_url: "https://signer.internal:8443/".to_string(),
+            ..SignerConfig::default()
+        };
+
+        let wallets = load_server_wallets(&cfg, &[pubkey.to_string()]).unwrap();
+        assert_eq!(wallets[0].pubkey, pubkey);
+        assert_eq!(wallets[0].signer_kind(), "remote");
+
+        // Private keys are refused in remote mode
+        let keypair = Keypair::new();
+        assert!(load_server_wallets(&cfg, &[keypair.to_base58_string()]).is_err());
+    }
+
+    /// Serves `/{mode}/v1/sign` like the signing service, signing with
+    /// `keypair` (or misbehaving, depending on `mode`) and recording requests.
+    async fn spawn_signing_service(
+        keypair: Arc<Keypair>,
+        requests: Arc<parking_lot::Mutex<Vec<serde_json::Value>>>,
+    ) -> std::net::SocketAddr {
+        use axum::extract::Path;
+        use axum::http::StatusCode;
+        use axum::routing::post;
+
+        let app = axum::Router::new().route(
+            "/{mode}/v1/sign",
+            post(
+                move |Path(mode): Path<String>, axum::Json(body): axum::Json<serde_json::Value>| {
+                    let keypair = keypair.clone();
+                    let requests = requests.clone();
+                    async move {
+                        requests.lock().push(body.clone());
+                        let message = BASE64
+                            .decode(body["message"].as_str().unwrap_or_default())
+                            .unwrap_or_default();
+                        let signature = match mode.as_str() {
+                            "ok" => keypair.sign_message(&message).to_string(),
+                            "foreign" => Keypair::new().sign_message(&message).to_string(),
+                            "garbage" => "not-a-signature".to_string(),
+                            _ => return Err(StatusCode::SERVICE_UNAVAILABLE),
+                        };
+                        Ok(axum::Json(serde_json::json!({ "signature": signature })))
+                    }
+                },
+            ),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        addr
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_wire_protocol() {
+        let keypair = Arc::new(Keypair::new());
+        let requests = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let addr = spawn_signing_service(keypair.clone(), requests.clone()).await;
+        let client = build_remote_client(&SignerConfig {
+            allow_insecure: true,
+            ..SignerConfig::default()
+        })
+        .unwrap();
+        let signer = |mode: &str| {
+            RemoteSigner::new(
+                client.clone(),
+                &format!("http://{addr}/{mode}/"),
+                keypair.pubkey(),
+            )
+        };
+        let message = b"serialized message";
+
+        let signature = signer("ok").sign(message).await.unwrap();
+        assert!(signature.verify(keypair.pubkey().as_ref(), message));
+        let sent = requests.lock()[0].clone();
+        assert_eq!(sent["pubkey"], keypair.pubkey().to_string());
+        assert_eq!(sent["message"], BASE64.encode(message));
+
+        assert!(matches!(
+            signer("down").sign(message).await,
+            Err(SignerError::Remote(_))
+        ));
+        assert!(matches!(
+            signer("foreign").sign(message).await,
+            Err(SignerError::InvalidSignature)
+        ));
+        assert!(matches!(
+            signer("garbage").sign(message).await,
+            Err(SignerError::InvalidSignature)
+        ));
+        assert_eq!(requests.lock().len(), 4);
+
+        // Without allow_insecure the client never speaks plain HTTP
+        let strict = build_remote_client(&SignerConfig::default()).unwrap();
+        let err = RemoteSigner::new(strict, &format!("http://{addr}/ok"), keypair.pubkey())
+            .sign(message)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SignerError::Remote(_)));
+        assert_eq!(requests.lock().len(), 4);
+    }
+}
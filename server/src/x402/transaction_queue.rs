@@ -8,6 +8,7 @@ use solana_sdk::signature::Signature;
 use solana_sdk::transaction::VersionedTransaction;
 use tokio::sync::{mpsc, oneshot, Semaphore};
 use tokio::time::timeout;
+use tracing::Instrument;
 
 use crate::constants::{
     MAX_TX_RETRIES, RATE_LIMIT_BACKOFF_MULTIPLIER, RATE_LIMIT_INITIAL_BACKOFF, TX_CONFIRM_TIMEOUT,
@@ -48,6 +49,8 @@ pub struct TxRequest {
     pub tx: VersionedTransaction,
     pub response: oneshot::Sender<Result<Signature, TxQueueError>>,
     pub created_at: Instant,
+    /// Submitter's span; queued sends are traced as its children
+    pub span: tracing::Span,
 }
 
 #[derive(Debug, Clone)]
@@ -184,8 +187,16 @@ impl TransactionQueue {
                 let tx = req.tx;
                 let response = req.response;
                 let queue_clone = queue.clone();
-
-                tokio::spawn(async move {
+                let send_span = tracing::info_span!(
+                    parent: &req.span,
+                    "solana.rpc",
+                    otel.kind = "client",
+                    rpc.system = "solana",
+                    rpc.method = "sendTransaction",
+                    queued_ms = req.created_at.elapsed().as_millis() as u64,
+                );
+
+                let send_task = async move {
                     // Move guard into spawned task - will decrement on drop (including panic)
                     let _guard = _in_flight_guard;
 
@@ -210,7 +221,8 @@ impl TransactionQueue {
                     }
                     drop(_permit);
                     // _guard drops here, decrementing in_flight counter
-                });
+                };
+                tokio::spawn(send_task.instrument(send_span));
             }
         });
     }
@@ -227,6 +239,7 @@ impl TransactionQueue {
             tx,
             response: response_tx,
             created_at: Instant::now(),
+            span: tracing::Span::current(),
         };
 
         self.sender
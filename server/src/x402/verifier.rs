@@ -12,7 +12,6 @@ use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::message::VersionedMessage;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
-use solana_sdk::signer::Signer;
 use solana_sdk::transaction::VersionedTransaction;
 use thiserror::Error;
 use tokio::time::timeout;
@@ -31,6 +30,8 @@ use crate::models::{PaymentProof, Requirement, VerificationResult};
 use crate::observability::{record_solana_rpc_call, record_solana_tx_confirmation};
 use crate::services::BlockhashCache;
 
+use super::gasless::ComputeBudget;
+use super::signer::{load_server_wallets, ServerWallet};
 use super::transaction_queue::TransactionQueue;
 use super::utils::{is_rate_limit_error, rpc_attempt_with_timeout, RpcAttemptError};
 use super::wallet_health::WalletHealthChecker;
@@ -85,50 +86,6 @@ pub trait Verifier: Send + Sync {
     ) -> Result<VerificationResult, VerifierError>;
 }
 
-/// Server wallet keypair
-pub struct ServerWallet {
-    pub keypair: solana_sdk::signer::keypair::Keypair,
-    pub pubkey: Pubkey,
-}
-
-impl ServerWallet {
-    pub fn from_base58(key: &str) -> Result<Self, VerifierError> {
-        let bytes = bs58::decode(key)
-            .into_vec()
-            .map_err(|e| VerifierError::Invalid(format!("invalid base58 key: {}", e)))?;
-
-        let keypair = solana_sdk::signer::keypair::Keypair::try_from(bytes.as_slice())
-            .map_err(|e| VerifierError::Invalid(format!("invalid keypair: {}", e)))?;
-
-        let pubkey = keypair.pubkey();
-        Ok(Self { keypair, pubkey })
-    }
-
-    pub fn from_json_array(json: &str) -> Result<Self, VerifierError> {
-        let bytes: Vec<u8> = serde_json::from_str(json)
-            .map_err(|e| VerifierError::Invalid(format!("invalid json array: {}", e)))?;
-
-        if bytes.len() != 64 {
-            return Err(VerifierError::Invalid("keypair must be 64 bytes".into()));
-        }
-
-        let keypair = solana_sdk::signer::keypair::Keypair::try_from(bytes.as_slice())
-            .map_err(|e| VerifierError::Invalid(format!("invalid keypair: {}", e)))?;
-
-        let pubkey = keypair.pubkey();
-        Ok(Self { keypair, pubkey })
-    }
-
-    pub fn from_string(s: &str) -> Result<Self, VerifierError> {
-        let trimmed = s.trim();
-        if trimmed.starts_with('[') {
-            Self::from_json_array(trimmed)
-        } else {
-            Self::from_base58(trimmed)
-        }
-    }
-}
-
 /// Solana x402 verifier
 pub struct SolanaVerifier {
     rpc_client: Arc<RpcClient>,
@@ -145,8 +102,7 @@ pub struct SolanaVerifier {
     skip_preflight: bool,
     circuit_breaker: SharedCircuitBreaker,
     // Compute budget settings (from config, like Go does)
-    compute_unit_limit: u32,
-    compute_unit_price: u64,
+    compute_budget: parking_lot::RwLock<ComputeBudget>,
 }
 
 impl SolanaVerifier {
@@ -171,11 +127,8 @@ impl SolanaVerifier {
         // Create blockhash cache using same RPC client (1 second TTL per CLAUDE.md)
         let blockhash_cache = Arc::new(BlockhashCache::with_default_ttl(rpc_client.clone()));
 
-        let mut server_wallets = Vec::new();
-        for wallet_str in &config.server_wallets {
-            let wallet = ServerWallet::from_string(wallet_str)?;
-            server_wallets.push(wallet);
-        }
+        let server_wallets = load_server_wallets(&config.signer, &config.server_wallets)
+            .map_err(|e| VerifierError::Invalid(e.to_string()))?;
 
         Ok(Self {
             rpc_client,
@@ -195,11 +148,21 @@ impl SolanaVerifier {
                 cb_config,
             )),
             // Use config values like Go does
-            compute_unit_limit: config.compute_unit_limit,
-            compute_unit_price: config.compute_unit_price_micro_lamports,
+            compute_budget: parking_lot::RwLock::new(ComputeBudget::from_config(config)),
         })
     }
 
+    /// Apply reloaded `x402` fee settings and `circuit_breaker.solana_rpc`
+    /// thresholds. Other settings (RPC URL, wallets) still require a restart.
+    pub fn reconfigure(&self, config: &X402Config, cb_config: &CircuitBreakerServiceConfig) {
+        *self.compute_budget.write() = ComputeBudget::from_config(config);
+        self.circuit_breaker
+            .reconfigure(CircuitBreakerConfig::from_service_config(
+                "solana_rpc",
+                cb_config,
+            ));
+    }
+
     /// Setup transaction queue
     pub fn setup_tx_queue(&mut self, min_time_between: Duration, max_in_flight: usize) {
         let queue = TransactionQueue::new(self.rpc_client.clone(), min_time_between, max_in_flight);
@@ -290,97 +253,205 @@ impl SolanaVerifier {
         tx: &VersionedTransaction,
         requirement: &Requirement,
     ) -> Result<TransferDetails, VerifierError> {
-        let message = tx.message.clone();
-        let account_keys = match &message {
-            VersionedMessage::Legacy(m) => m.account_keys.clone(),
-            VersionedMessage::V0(m) => m.account_keys.clone(),
-        };
-
-        let instructions = match &message {
-            VersionedMessage::Legacy(m) => m.instructions.clone(),
-            VersionedMessage::V0(m) => m.instructions.clone(),
-        };
+        let (account_keys, instructions) = Self::message_parts(tx);
 
         // Find SPL token transfer instruction
         for ix in &instructions {
-            let program_id = account_keys
-                .get(ix.program_id_index as usize)
-                .ok_or_else(|| VerifierError::Invalid("missing program id".into()))?;
+            if let Some(transfer) = Self::parse_token_transfer(ix, &account_keys, requirement)? {
+                return Ok(transfer);
+            }
+        }
 
-            // Check if this is a token program instruction
-            if *program_id == spl_token::id() && !ix.data.is_empty() {
-                let opcode = ix.data[0];
-
-                // Transfer (opcode 3): accounts = [source, dest, owner] - REJECTED for security
-                // TransferChecked (opcode 12): accounts = [source, mint, dest, owner]
-                match opcode {
-                    3 => {
-                        // SECURITY: Reject plain Transfer (opcode 3) instructions.
-                        // Plain Transfer doesn't include the mint address in the instruction,
-                        // so we cannot verify that the transferred token matches the expected
-                        // token. An attacker could send a worthless token and we would accept it.
-                        // Only TransferChecked (opcode 12) is secure because it includes the
-                        // mint and decimals in the instruction data for validation.
-                        return Err(VerifierError::Invalid(
-                            "plain Transfer (opcode 3) not accepted; use TransferChecked (opcode 12) for security".into()
-                        ));
-                    }
-                    12 if ix.accounts.len() >= 4 && ix.data.len() >= 10 => {
-                        // TransferChecked instruction
-                        let source_idx = ix.accounts[0] as usize;
-                        let mint_idx = ix.accounts[1] as usize;
-                        let dest_idx = ix.accounts[2] as usize;
-                        let owner_idx = ix.accounts[3] as usize;
-
-                        let source = account_keys
-                            .get(source_idx)
-                            .ok_or_else(|| VerifierError::Invalid("missing source".into()))?;
-                        let mint = account_keys
-                            .get(mint_idx)
-                            .ok_or_else(|| VerifierError::Invalid("missing mint".into()))?;
-                        let destination = account_keys
-                            .get(dest_idx)
-                            .ok_or_else(|| VerifierError::Invalid("missing dest".into()))?;
-                        let owner = account_keys
-                            .get(owner_idx)
-                            .ok_or_else(|| VerifierError::Invalid("missing owner".into()))?;
-
-                        // SECURITY: Validate mint matches expected token
-                        if let Some(expected_mint_str) = &requirement.token_mint {
-                            let expected_mint = Pubkey::from_str(expected_mint_str)
-                                .map_err(|_| VerifierError::InvalidTokenMint)?;
-                            if *mint != expected_mint {
-                                return Err(VerifierError::Invalid(format!(
-                                    "token mint mismatch: got {}, expected {}",
-                                    mint, expected_mint
-                                )));
-                            }
-                        }
+        Err(VerifierError::Invalid(
+            "no transfer instruction found".into(),
+        ))
+    }
+
+    /// Extract every SPL token transfer from a transaction (split payments).
+    fn extract_all_transfers(
+        tx: &VersionedTransaction,
+        requirement: &Requirement,
+    ) -> Result<Vec<TransferDetails>, VerifierError> {
+        let (account_keys, instructions) = Self::message_parts(tx);
+
+        let mut transfers = Vec::new();
+        for ix in &instructions {
+            if let Some(transfer) = Self::parse_token_transfer(ix, &account_keys, requirement)? {
+                transfers.push(transfer);
+            }
+        }
+
+        if transfers.is_empty() {
+            return Err(VerifierError::Invalid(
+                "no transfer instruction found".into(),
+            ));
+        }
+        Ok(transfers)
+    }
+
+    fn message_parts(
+        tx: &VersionedTransaction,
+    ) -> (
+        Vec<Pubkey>,
+        Vec<solana_sdk::instruction::CompiledInstruction>,
+    ) {
+        match &tx.message {
+            VersionedMessage::Legacy(m) => (m.account_keys.clone(), m.instructions.clone()),
+            VersionedMessage::V0(m) => (m.account_keys.clone(), m.instructions.clone()),
+        }
+    }
+
+    /// Parse a single instruction as an SPL token transfer.
+    ///
+    /// Returns `Ok(None)` for instructions that are not token transfers.
+    fn parse_token_transfer(
+        ix: &solana_sdk::instruction::CompiledInstruction,
+        account_keys: &[Pubkey],
+        requirement: &Requirement,
+    ) -> Result<Option<TransferDetails>, VerifierError> {
+        let program_id = account_keys
+            .get(ix.program_id_index as usize)
+            .ok_or_else(|| VerifierError::Invalid("missing program id".into()))?;
+
+        // Check if this is a token program instruction
+        if *program_id != spl_token::id() || ix.data.is_empty() {
+            return Ok(None);
+        }
 
-                        // Extract amount (u64 LE) and decimals (u8) from instruction data
-                        let amount =
-                            u64::from_le_bytes(ix.data[1..9].try_into().map_err(|_| {
-                                VerifierError::Invalid("invalid amount bytes".into())
-                            })?);
-                        let decimals = ix.data[9];
-
-                        return Ok(TransferDetails {
-                            _source: *source,
-                            destination: *destination,
-                            mint: *mint,
-                            owner: *owner,
-                            amount,
-                            decimals,
-                        });
+        let opcode = ix.data[0];
+
+        // Transfer (opcode 3): accounts = [source, dest, owner] - REJECTED for security
+        // TransferChecked (opcode 12): accounts = [source, mint, dest, owner]
+        match opcode {
+            3 => {
+                // SECURITY: Reject plain Transfer (opcode 3) instructions.
+                // Plain Transfer doesn't include the mint address in the instruction,
+                // so we cannot verify that the transferred token matches the expected
+                // token. An attacker could send a worthless token and we would accept it.
+                // Only TransferChecked (opcode 12) is secure because it includes the
+                // mint and decimals in the instruction data for validation.
+                Err(VerifierError::Invalid(
+                    "plain Transfer (opcode 3) not accepted; use TransferChecked (opcode 12) for security".into()
+                ))
+            }
+            12 if ix.accounts.len() >= 4 && ix.data.len() >= 10 => {
+                // TransferChecked instruction
+                let source_idx = ix.accounts[0] as usize;
+                let mint_idx = ix.accounts[1] as usize;
+                let dest_idx = ix.accounts[2] as usize;
+                let owner_idx = ix.accounts[3] as usize;
+
+                let source = account_keys
+                    .get(source_idx)
+                    .ok_or_else(|| VerifierError::Invalid("missing source".into()))?;
+                let mint = account_keys
+                    .get(mint_idx)
+                    .ok_or_else(|| VerifierError::Invalid("missing mint".into()))?;
+                let destination = account_keys
+                    .get(dest_idx)
+                    .ok_or_else(|| VerifierError::Invalid("missing dest".into()))?;
+                let owner = account_keys
+                    .get(owner_idx)
+                    .ok_or_else(|| VerifierError::Invalid("missing owner".into()))?;
+
+                // SECURITY: Validate mint matches expected token
+                if let Some(expected_mint_str) = &requirement.token_mint {
+                    let expected_mint = Pubkey::from_str(expected_mint_str)
+                        .map_err(|_| VerifierError::InvalidTokenMint)?;
+                    if *mint != expected_mint {
+                        return Err(VerifierError::Invalid(format!(
+                            "token mint mismatch: got {}, expected {}",
+                            mint, expected_mint
+                        )));
                     }
-                    _ => continue,
                 }
+
+                // Extract amount (u64 LE) and decimals (u8) from instruction data
+                let amount = u64::from_le_bytes(
+                    ix.data[1..9]
+                        .try_into()
+                        .map_err(|_| VerifierError::Invalid("invalid amount bytes".into()))?,
+                );
+                let decimals = ix.data[9];
+
+                Ok(Some(TransferDetails {
+                    _source: *source,
+                    destination: *destination,
+                    mint: *mint,
+                    owner: *owner,
+                    amount,
+                    decimals,
+                }))
             }
+            _ => Ok(None),
         }
+    }
 
-        Err(VerifierError::Invalid(
-            "no transfer instruction found".into(),
-        ))
+    /// Verify a split payment: exactly one transfer per leg, each to the leg's
+    /// token account for at least the leg amount, all signed by the same payer.
+    /// Underpayment across all legs combined may not exceed 1 atomic unit.
+    ///
+    /// Returns the combined transfer (total amount, payer) on success.
+    fn verify_split_transfers(
+        transfers: &[TransferDetails],
+        requirement: &Requirement,
+    ) -> Result<TransferDetails, VerifierError> {
+        if transfers.len() != requirement.splits.len() {
+            return Err(VerifierError::Invalid(format!(
+                "split payment requires {} transfers, got {}",
+                requirement.splits.len(),
+                transfers.len()
+            )));
+        }
+
+        let first = transfers
+            .first()
+            .ok_or_else(|| VerifierError::Invalid("no transfer instruction found".into()))?;
+
+        let mut matched = vec![false; transfers.len()];
+        let mut total: u64 = 0;
+        let mut shortfall: u64 = 0;
+        for leg in &requirement.splits {
+            let expected = Pubkey::from_str(&leg.recipient_token_account)
+                .map_err(|_| VerifierError::InvalidRecipient)?;
+            let idx = transfers
+                .iter()
+                .enumerate()
+                .position(|(i, t)| !matched[i] && t.destination == expected)
+                .ok_or(VerifierError::InvalidRecipient)?;
+            matched[idx] = true;
+
+            let transfer = &transfers[idx];
+            if transfer.decimals != requirement.token_decimals {
+                return Err(VerifierError::Invalid(format!(
+                    "token decimals mismatch: got {}, expected {}",
+                    transfer.decimals, requirement.token_decimals
+                )));
+            }
+            if transfer.owner != first.owner {
+                return Err(VerifierError::Invalid(
+                    "split transfers must come from a single payer".into(),
+                ));
+            }
+            shortfall = shortfall.saturating_add(leg.amount_atomic.saturating_sub(transfer.amount));
+            total = total
+                .checked_add(transfer.amount)
+                .ok_or_else(|| VerifierError::Invalid("split payment total overflows".into()))?;
+        }
+        // Same 1 atomic unit rounding tolerance as single-recipient payments,
+        // applied once across all legs rather than per leg.
+        if shortfall > 1 {
+            return Err(VerifierError::AmountMismatch);
+        }
+
+        Ok(TransferDetails {
+            _source: first._source,
+            destination: first.destination,
+            mint: first.mint,
+            owner: first.owner,
+            amount: total,
+            decimals: first.decimals,
+        })
     }
 
     fn extract_memo_text(tx: &VersionedTransaction) -> Result<Option<String>, VerifierError> {
@@ -622,6 +693,11 @@ impl SolanaVerifier {
 
     /// Send transaction with retry and circuit breaker protection
     /// Per spec (22-x402-verifier.md lines 255-266): Different retry policies for different errors
+    #[tracing::instrument(
+        name = "solana.rpc",
+        skip_all,
+        fields(otel.kind = "client", rpc.system = "solana", rpc.method = "sendTransaction")
+    )]
     async fn send_transaction(
         &self,
         tx: &VersionedTransaction,
@@ -775,6 +851,11 @@ impl SolanaVerifier {
     }
 
     /// Poll for transaction confirmation using GetSignatureStatuses (like Go does)
+    #[tracing::instrument(
+        name = "solana.rpc",
+        skip_all,
+        fields(otel.kind = "client", rpc.system = "solana", rpc.method = "getSignatureStatuses")
+    )]
     async fn poll_confirmation(&self, signature: &Signature) -> Result<(), VerifierError> {
         let poll_start = std::time::Instant::now();
         let mut consecutive_network_errors = 0u32;
@@ -959,18 +1040,19 @@ impl SolanaVerifier {
 
         // Build compute budget instructions (use config values like Go does)
         let mut instructions = Vec::with_capacity(4);
+        let budget = *self.compute_budget.read();
 
-        if self.compute_unit_limit > 0 {
+        if budget.unit_limit > 0 {
             instructions.push(
                 solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(
-                    self.compute_unit_limit,
+                    budget.unit_limit,
                 ),
             );
         }
-        if self.compute_unit_price > 0 {
+        if budget.unit_price > 0 {
             instructions.push(
                 solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_price(
-                    self.compute_unit_price,
+                    budget.unit_price,
                 ),
             );
         }
@@ -1049,6 +1131,11 @@ pub struct GaslessTxResponse {
 
 #[async_trait]
 impl Verifier for SolanaVerifier {
+    #[tracing::instrument(
+        name = "x402.verify",
+        skip_all,
+        fields(resource_id = %requirement.resource_id, payment_method = "x402")
+    )]
     async fn verify(
         &self,
         proof: PaymentProof,
@@ -1076,17 +1163,26 @@ impl Verifier for SolanaVerifier {
         // Decode transaction
         let mut tx = Self::decode_transaction(&proof.transaction)?;
 
-        // Extract transfer details (supports both Transfer and TransferChecked like Go)
-        let transfer = self.extract_transfer_details(&tx, &requirement)?;
+        let transfer = if requirement.splits.is_empty() {
+            // Extract transfer details (supports both Transfer and TransferChecked like Go)
+            let transfer = self.extract_transfer_details(&tx, &requirement)?;
 
-        // Verify amount
-        self.verify_amount(&transfer, &requirement)?;
+            // Verify amount
+            self.verify_amount(&transfer, &requirement)?;
 
-        // Verify recipient
-        self.verify_recipient(&transfer, &requirement)?;
+            // Verify recipient
+            self.verify_recipient(&transfer, &requirement)?;
 
-        // Verify mint
-        self.verify_mint(&transfer, &requirement)?;
+            // Verify mint
+            self.verify_mint(&transfer, &requirement)?;
+            transfer
+        } else {
+            // Split payment: every leg must be paid by its own transfer
+            let transfers = Self::extract_all_transfers(&tx, &requirement)?;
+            let transfer = Self::verify_split_transfers(&transfers, &requirement)?;
+            self.verify_mint(&transfer, &requirement)?;
+            transfer
+        };
 
         // Verify memo includes resource id (binds payment to resource)
         Self::verify_memo(&tx, &requirement)?;
@@ -1123,8 +1219,10 @@ impl Verifier for SolanaVerifier {
             }
 
             // Partially sign with server wallet
-            let message_data = tx.message.serialize();
-            let sig = server_wallet.keypair.sign_message(&message_data);
+            let sig = server_wallet
+                .sign_message(&tx.message)
+                .await
+                .map_err(|e| VerifierError::Failed(format!("co-sign failed: {e}")))?;
             set_primary_signature(&mut tx, sig)?;
         }
 
@@ -1144,14 +1242,25 @@ impl Verifier for SolanaVerifier {
                     VerifierError::Invalid("no server wallet for ATA creation".into())
                 })?;
 
-                // Get recipient and mint from requirement
-                let recipient_owner = Pubkey::from_str(
+                // Get recipient owners (one per split leg) and mint from requirement
+                let recipient_owners: Vec<Pubkey> = if requirement.splits.is_empty() {
+                    vec![Pubkey::from_str(
+                        requirement.recipient_owner.as_ref().ok_or_else(|| {
+                            VerifierError::Invalid("missing recipient owner".into())
+                        })?,
+                    )
+                    .map_err(|_| VerifierError::Invalid("invalid recipient owner".into()))?]
+                } else {
                     requirement
-                        .recipient_owner
-                        .as_ref()
-                        .ok_or_else(|| VerifierError::Invalid("missing recipient owner".into()))?,
-                )
-                .map_err(|_| VerifierError::Invalid("invalid recipient owner".into()))?;
+                        .splits
+                        .iter()
+                        .map(|leg| {
+                            Pubkey::from_str(&leg.recipient_owner).map_err(|_| {
+                                VerifierError::Invalid("invalid split recipient owner".into())
+                            })
+                        })
+                        .collect::<Result<_, _>>()?
+                };
                 let mint = Pubkey::from_str(
                     requirement
                         .token_mint
@@ -1160,14 +1269,18 @@ impl Verifier for SolanaVerifier {
                 )
                 .map_err(|_| VerifierError::Invalid("invalid token mint".into()))?;
 
-                // Create ATA instruction
-                let create_ata_ix =
-                    spl_associated_token_account::instruction::create_associated_token_account(
-                        &server_wallet.pubkey,
-                        &recipient_owner,
-                        &mint,
-                        &spl_token::id(),
-                    );
+                // Create ATA instructions (idempotent so existing leg accounts don't fail the tx)
+                let create_ata_ixs: Vec<_> = recipient_owners
+                    .iter()
+                    .map(|owner| {
+                        spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                            &server_wallet.pubkey,
+                            owner,
+                            &mint,
+                            &spl_token::id(),
+                        )
+                    })
+                    .collect();
 
                 // Bound per-RPC await so ATA auto-create can't hang request paths.
                 // Route-level payment timeout is 60s, so keep per-call timeouts tight.
@@ -1190,12 +1303,10 @@ impl Verifier for SolanaVerifier {
                 };
 
                 // Build and send ATA creation transaction
-                let ata_tx = solana_sdk::transaction::Transaction::new_signed_with_payer(
-                    &[create_ata_ix],
-                    Some(&server_wallet.pubkey),
-                    &[&server_wallet.keypair],
-                    recent_blockhash,
-                );
+                let ata_tx = server_wallet
+                    .sign_transaction(&create_ata_ixs, &[], recent_blockhash)
+                    .await
+                    .map_err(|e| VerifierError::Failed(format!("ATA creation signing: {e}")))?;
 
                 match rpc_attempt_with_timeout(
                     DEFAULT_CONFIRMATION_TIMEOUT,
@@ -1215,10 +1326,12 @@ impl Verifier for SolanaVerifier {
                 tracing::info!("ATA created successfully, waiting for propagation");
 
                 // Wait for account propagation with exponential backoff per spec
-                let ata = spl_associated_token_account::get_associated_token_address(
-                    &recipient_owner,
-                    &mint,
-                );
+                // All accounts are created in one transaction; poll the last one.
+                let last_owner = recipient_owners
+                    .last()
+                    .ok_or_else(|| VerifierError::Invalid("missing recipient owner".into()))?;
+                let ata =
+                    spl_associated_token_account::get_associated_token_address(last_owner, &mint);
                 let mut backoff = Duration::from_millis(500);
                 let max_backoff = Duration::from_secs(2);
                 let max_attempts = 30;
@@ -1437,6 +1550,7 @@ mod tests {
     use solana_sdk::hash::Hash;
     use solana_sdk::message::Message;
     use solana_sdk::signature::Keypair;
+    use solana_sdk::signer::Signer;
     use solana_sdk::transaction::Transaction;
 
     fn build_memo_tx(memo: Option<&str>) -> VersionedTransaction {
@@ -1450,6 +1564,135 @@ mod tests {
         VersionedTransaction::from(tx)
     }
 
+    fn build_split_tx(
+        payer: &Keypair,
+        mint: &Pubkey,
+        transfers: &[(Pubkey, u64)],
+    ) -> VersionedTransaction {
+        let source =
+            spl_associated_token_account::get_associated_token_address(&payer.pubkey(), mint);
+        let instructions: Vec<_> = transfers
+            .iter()
+            .map(|(dest, amount)| {
+                spl_token::instruction::transfer_checked(
+                    &spl_token::id(),
+                    &source,
+                    mint,
+                    dest,
+                    &payer.pubkey(),
+                    &[],
+                    *amount,
+                    6,
+                )
+                .expect("transfer ix")
+            })
+            .collect();
+        let message = Message::new(&instructions, Some(&payer.pubkey()));
+        VersionedTransaction::from(Transaction::new_unsigned(message))
+    }
+
+    fn split_requirement(mint: &Pubkey, legs: &[(Pubkey, u64)]) -> Requirement {
+        Requirement {
+            token_mint: Some(mint.to_string()),
+            token_decimals: 6,
+            splits: legs
+                .iter()
+                .map(|(ata, amount)| crate::models::PaymentLeg {
+                    role: crate::models::SplitRole::Seller,
+                    recipient_owner: Pubkey::new_unique().to_string(),
+                    recipient_token_account: ata.to_string(),
+                    amount_atomic: *amount,
+                })
+                .collect(),
+            ..Requirement::default()
+        }
+    }
+
+    #[test]
+    fn test_verify_split_transfers_accepts_all_legs_in_any_order() {
+        let payer = Keypair::new();
+        let mint = Pubkey::new_unique();
+        let (platform, seller) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let requirement = split_requirement(&mint, &[(platform, 100), (seller, 900)]);
+        let tx = build_split_tx(&payer, &mint, &[(seller, 900), (platform, 100)]);
+
+        let transfers = SolanaVerifier::extract_all_transfers(&tx, &requirement).expect("parse");
+        let combined =
+            SolanaVerifier::verify_split_transfers(&transfers, &requirement).expect("verify");
+        assert_eq!(combined.amount, 1_000);
+        assert_eq!(combined.owner, payer.pubkey());
+    }
+
+    #[test]
+    fn test_verify_split_transfers_rejects_wrong_recipient_short_leg_and_missing_leg() {
+        let payer = Keypair::new();
+        let mint = Pubkey::new_unique();
+        let (platform, seller) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let requirement = split_requirement(&mint, &[(platform, 100), (seller, 900)]);
+
+        // Seller share redirected to an attacker-controlled account
+        let tx = build_split_tx(
+            &payer,
+            &mint,
+            &[(platform, 100), (Pubkey::new_unique(), 900)],
+        );
+        let transfers = SolanaVerifier::extract_all_transfers(&tx, &requirement).expect("parse");
+        assert!(matches!(
+            SolanaVerifier::verify_split_transfers(&transfers, &requirement),
+            Err(VerifierError::InvalidRecipient)
+        ));
+
+        // Platform leg underpaid while the total is unchanged
+        let tx = build_split_tx(&payer, &mint, &[(platform, 10), (seller, 990)]);
+        let transfers = SolanaVerifier::extract_all_transfers(&tx, &requirement).expect("parse");
+        assert!(matches!(
+            SolanaVerifier::verify_split_transfers(&transfers, &requirement),
+            Err(VerifierError::AmountMismatch)
+        ));
+
+        // Single transfer of the full amount to the seller
+        let tx = build_split_tx(&payer, &mint, &[(seller, 1_000)]);
+        let transfers = SolanaVerifier::extract_all_transfers(&tx, &requirement).expect("parse");
+        assert!(matches!(
+            SolanaVerifier::verify_split_transfers(&transfers, &requirement),
+            Err(VerifierError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_split_transfers_applies_rounding_tolerance_once() {
+        let payer = Keypair::new();
+        let mint = Pubkey::new_unique();
+        let (platform, seller, creator) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let requirement =
+            split_requirement(&mint, &[(platform, 100), (seller, 800), (creator, 100)]);
+
+        // One leg short by a single unit is within tolerance
+        let tx = build_split_tx(
+            &payer,
+            &mint,
+            &[(platform, 100), (seller, 799), (creator, 100)],
+        );
+        let transfers = SolanaVerifier::extract_all_transfers(&tx, &requirement).expect("parse");
+        assert!(SolanaVerifier::verify_split_transfers(&transfers, &requirement).is_ok());
+
+        // Each leg short by a single unit adds up beyond the tolerance
+        let tx = build_split_tx(
+            &payer,
+            &mint,
+            &[(platform, 99), (seller, 799), (creator, 99)],
+        );
+        let transfers = SolanaVerifier::extract_all_transfers(&tx, &requirement).expect("parse");
+        assert!(matches!(
+            SolanaVerifier::verify_split_transfers(&transfers, &requirement),
+            Err(VerifierError::AmountMismatch)
+        ));
+    }
+
     #[test]
     fn test_required_amount_atomic_prefers_atomic() {
         let req = Requirement {